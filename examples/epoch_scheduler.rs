@@ -0,0 +1,99 @@
+//! Evaluate at epoch start, commit CRv4 weights 30 blocks before the epoch
+//! ends, and check the reveal in the following epoch
+//!
+//! ```bash
+//! HOTKEY_SEED="//Alice" NETUID=1 cargo run --example epoch_scheduler
+//! ```
+
+use anyhow::Result;
+use bittensor_rs::blocks::{EpochScheduler, Phase};
+use bittensor_rs::chain::{signer_from_seed, BittensorClient, ExtrinsicWait};
+use bittensor_rs::crv4::prepare_and_commit_crv4_weights;
+use bittensor_rs::utils::WeightValidation;
+use std::sync::{Arc, Mutex};
+
+/// Latest (uids, weights) from evaluation
+type Scores = Option<(Vec<u16>, Vec<u16>)>;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let client = Arc::new(BittensorClient::with_default().await?);
+    let netuid: u16 = std::env::var("NETUID")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(1);
+    let seed = std::env::var("HOTKEY_SEED").unwrap_or_else(|_| "//Alice".to_string());
+    let signer = Arc::new(signer_from_seed(&seed)?);
+
+    // Latest (uids, weights) from evaluation, read by the commit callback
+    let scores: Arc<Mutex<Scores>> = Arc::default();
+
+    let evaluation_scores = scores.clone();
+    let commit_client = client.clone();
+    let commit_scores = scores.clone();
+    let scheduler = EpochScheduler::new(netuid)
+        .on_phase(Phase::EvaluationStart, move |ctx| {
+            let scores = evaluation_scores.clone();
+            async move {
+                println!(
+                    "epoch {}: evaluating at block {}{}",
+                    ctx.epoch.epoch_number,
+                    ctx.block,
+                    if ctx.late { " (late)" } else { "" }
+                );
+                // Replace with real evaluation
+                *scores.lock().unwrap() = Some((vec![0, 1], vec![32_768, 32_767]));
+                Ok(())
+            }
+        })
+        .on_phase(
+            Phase::CommitWindow {
+                blocks_before_end: 30,
+            },
+            move |ctx| {
+                let client = commit_client.clone();
+                let signer = signer.clone();
+                let scores = commit_scores.lock().unwrap().clone();
+                async move {
+                    let Some((uids, weights)) = scores else {
+                        println!("epoch {}: nothing to commit", ctx.epoch.epoch_number);
+                        return Ok(());
+                    };
+                    let commit = prepare_and_commit_crv4_weights(
+                        &client,
+                        &signer,
+                        netuid,
+                        &uids,
+                        &weights,
+                        None,
+                        WeightValidation::Strict,
+                        ExtrinsicWait::Included,
+                    )
+                    .await?;
+                    println!(
+                        "epoch {}: committed {} weights, reveal round {} (tx {})",
+                        ctx.epoch.epoch_number,
+                        commit.uids.len(),
+                        commit.reveal_round,
+                        commit.tx_hash
+                    );
+                    Ok(())
+                }
+            },
+        )
+        .on_phase(Phase::RevealWindow, |ctx| async move {
+            // CRv4 commits are revealed by the chain; check the weights here
+            println!(
+                "epoch {}: commits from epoch {} are now revealable",
+                ctx.epoch.epoch_number,
+                ctx.reveal_epoch()
+            );
+            Ok(())
+        });
+
+    let handle = scheduler.start(client).await?;
+    println!("Scheduling subnet {} epochs; Ctrl-C to stop", netuid);
+    tokio::signal::ctrl_c().await?;
+    handle.shutdown().await;
+    Ok(())
+}
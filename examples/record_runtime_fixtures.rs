@@ -0,0 +1,112 @@
+//! Record raw runtime API responses into `tests/fixtures/runtime/finney`
+//!
+//! Every call is made at the same finalized block. `recording.json` notes the
+//! endpoint, genesis hash, block and runtime version the bytes came from, and
+//! `tests/runtime_decode_tests.rs` decodes whatever is recorded there.
+
+use anyhow::Result;
+use bittensor_rs::chain::BittensorClient;
+use parity_scale_codec::Encode;
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use std::path::Path;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let client = BittensorClient::with_default().await?;
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/runtime/finney");
+    std::fs::create_dir_all(&dir)?;
+    let netuid: u16 = 1;
+
+    let (block_number, block_hash) = client.finalized_head().await?;
+    let version = client.api().runtime_version();
+    println!(
+        "Recording from {} at block {} ({:?}), spec version {}",
+        client.rpc_url(),
+        block_number,
+        block_hash,
+        version.spec_version
+    );
+
+    // Any coldkey with stake works; override with COLDKEY=<ss58>
+    let coldkey = match std::env::var("COLDKEY") {
+        Ok(ss58) => AccountId32::from_ss58check(&ss58)
+            .map_err(|e| anyhow::anyhow!("Invalid COLDKEY: {:?}", e))?,
+        Err(_) => {
+            let neuron = bittensor_rs::queries::neurons::neuron(&client, netuid, 0, None)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("netuid {} has no uid 0", netuid))?;
+            neuron.coldkey
+        }
+    };
+
+    let calls: [(&str, &str, &str, Option<Vec<u8>>); 7] = [
+        (
+            "neuron_lite_netuid1_uid0.scale",
+            "NeuronInfoRuntimeApi",
+            "get_neuron_lite",
+            Some((netuid, 0u16).encode()),
+        ),
+        (
+            "neurons_lite_netuid1.scale",
+            "NeuronInfoRuntimeApi",
+            "get_neurons_lite",
+            Some(netuid.encode()),
+        ),
+        (
+            "subnet_info_netuid1.scale",
+            "SubnetInfoRuntimeApi",
+            "get_subnet_info",
+            Some(netuid.encode()),
+        ),
+        (
+            "stake_info_for_coldkey.scale",
+            "StakeInfoRuntimeApi",
+            "get_stake_info_for_coldkey",
+            Some(coldkey.encode()),
+        ),
+        (
+            "delegates.scale",
+            "DelegateInfoRuntimeApi",
+            "get_delegates",
+            None,
+        ),
+        (
+            "dynamic_info_netuid1.scale",
+            "SubnetInfoRuntimeApi",
+            "get_dynamic_info",
+            Some(netuid.encode()),
+        ),
+        (
+            "all_dynamic_info.scale",
+            "SubnetInfoRuntimeApi",
+            "get_all_dynamic_info",
+            None,
+        ),
+    ];
+
+    let runtime_api = client.api().runtime_api().at(block_hash);
+    for (file, api, method, params) in calls {
+        let name = format!("{}_{}", api, method);
+        let bytes = runtime_api.call_raw(&name, params.as_deref()).await?;
+        std::fs::write(dir.join(file), &bytes)?;
+        println!("{}.{} -> {} ({} bytes)", api, method, file, bytes.len());
+    }
+
+    let recording = serde_json::json!({
+        "endpoint": client.rpc_url(),
+        "genesis_hash": format!("{:?}", client.api().genesis_hash()),
+        "block_number": block_number,
+        "block_hash": format!("{:?}", block_hash),
+        "spec_version": version.spec_version,
+        "transaction_version": version.transaction_version,
+        "netuid": netuid,
+        "coldkey": coldkey.to_ss58check(),
+        "recorded_at": chrono::Utc::now().to_rfc3339(),
+    });
+    std::fs::write(
+        dir.join("recording.json"),
+        serde_json::to_string_pretty(&recording)?,
+    )?;
+    println!("Wrote {}", dir.join("recording.json").display());
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Score against a metagraph kept in sync in the background
+//!
+//! ```bash
+//! NETUID=1 cargo run --example validator_loop
+//! ```
+
+use anyhow::Result;
+use bittensor_rs::chain::BittensorClient;
+use bittensor_rs::metagraph::MetagraphSyncer;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let client = Arc::new(BittensorClient::with_default().await?);
+    let netuid: u16 = std::env::var("NETUID")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(1);
+
+    // Re-sync every 100 blocks, or as soon as a neuron registers
+    let (metagraph, mut synced, syncer) = MetagraphSyncer::spawn(client, netuid, 100, true);
+    println!("Syncing subnet {} metagraph; Ctrl-C to stop", netuid);
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            changed = synced.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+                let block = *synced.borrow_and_update();
+                // Hold the read lock only while scoring
+                let metagraph = metagraph.read().await;
+                let validators = metagraph.validator_permit.iter().filter(|p| **p).count();
+                println!(
+                    "block {}: {} neurons, {} validators",
+                    block, metagraph.n, validators
+                );
+                // Replace with real scoring
+            }
+            _ = tokio::time::sleep(std::time::Duration::from_secs(60)) => {
+                if let Some(e) = syncer.last_error() {
+                    println!("last sync failed: {}", e);
+                }
+            }
+        }
+    }
+
+    syncer.shutdown().await;
+    Ok(())
+}
@@ -0,0 +1,328 @@
+//! Operator endpoints for a running Axon
+//!
+//! Mounted with [`Axon::serve_admin`](crate::axon::Axon::serve_admin). Only
+//! connections from a loopback address are served; everything else gets
+//! `403 Forbidden`. Loopback does not identify the operator, as any local
+//! process or a reverse proxy on the host connects from there, so each
+//! request must also carry the admin token as `Authorization: Bearer <token>`
+//! or it gets `401 Unauthorized`.
+//!
+//! - `PUT /admin/log_level` with `{"level": "debug"}` or
+//!   `{"filter": "info,bittensor_rs::axon=trace"}` changes the log filter
+//!   and responds with `{"filter": "<active directives>"}`.
+//! - `GET /admin/blacklist` lists the bans in the axon's
+//!   [`BlacklistStore`] as `{"entries": [...]}`.
+//! - `POST /admin/blacklist` with `{"action": "ban", "hotkey": "5F..."}`,
+//!   `{"action": "ban", "ip": "10.0.0.1", "ttl_secs": 3600}` or
+//!   `{"action": "unban", "ip": "10.0.0.1"}` changes a ban and responds
+//!   with the updated list. Without a store set with
+//!   [`Axon::set_blacklist_store`](crate::axon::Axon::set_blacklist_store)
+//!   both respond `404 Not Found`.
+
+use crate::axon::blacklist::BlacklistStore;
+use crate::axon::server::AxonState;
+use crate::logging::{filter_handle, FilterHandle, LoggingError};
+use crate::types::synapse::constant_time_compare;
+use axum::body::Body;
+use axum::extract::{ConnectInfo, State};
+use axum::http::{header, HeaderMap, HeaderValue, Request, StatusCode};
+use axum::middleware::{self as axum_middleware, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, put};
+use axum::{Json, Router};
+use serde::Deserialize;
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::Level;
+
+/// Body of `PUT /admin/log_level`; exactly one field must be set
+#[derive(Debug, Default, Deserialize)]
+pub struct LogLevelRequest {
+    /// Minimum level, e.g. `debug`
+    pub level: Option<String>,
+    /// Full filter in `EnvFilter` syntax
+    pub filter: Option<String>,
+}
+
+/// Change to make with `POST /admin/blacklist`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlacklistAction {
+    Ban,
+    Unban,
+}
+
+/// Body of `POST /admin/blacklist`; exactly one of `hotkey` or `ip` must
+/// be set
+#[derive(Debug, Deserialize)]
+pub struct BlacklistRequest {
+    pub action: BlacklistAction,
+    /// SS58 address of the hotkey
+    pub hotkey: Option<String>,
+    pub ip: Option<IpAddr>,
+    /// Length of the ban in seconds, permanent if unset
+    pub ttl_secs: Option<u64>,
+}
+
+/// Router for the admin endpoints, accepting requests that carry `token`
+pub(crate) fn router(state: Arc<RwLock<AxonState>>, token: Arc<str>) -> Router {
+    Router::new()
+        .route("/admin/log_level", put(put_log_level))
+        .route("/admin/blacklist", get(get_blacklist).post(post_blacklist))
+        .layer(axum_middleware::from_fn_with_state(token, authorize))
+        .with_state(state)
+}
+
+/// Admin middleware - serve only loopback peers that send the admin token
+async fn authorize(
+    State(token): State<Arc<str>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    if !peer.ip().is_loopback() {
+        tracing::warn!("Rejected admin request from {}", peer);
+        return error_response(StatusCode::FORBIDDEN, "Admin endpoints are local-only");
+    }
+    if !has_token(req.headers(), &token) {
+        tracing::warn!("Rejected admin request without a valid token from {}", peer);
+        let mut response = error_response(StatusCode::UNAUTHORIZED, "Invalid admin token");
+        response
+            .headers_mut()
+            .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+        return response;
+    }
+    next.run(req).await
+}
+
+/// Whether `headers` carry `token` as a bearer token; an empty token
+/// matches nothing
+fn has_token(headers: &HeaderMap, token: &str) -> bool {
+    let presented = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match presented {
+        Some(presented) if !token.is_empty() => {
+            constant_time_compare(presented.as_bytes(), token.as_bytes())
+        }
+        _ => false,
+    }
+}
+
+async fn put_log_level(
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    Json(request): Json<LogLevelRequest>,
+) -> Response {
+    let result = filter_handle()
+        .map_err(|e| (StatusCode::SERVICE_UNAVAILABLE, e.to_string()))
+        .and_then(|handle| apply_log_level(handle, &request));
+    match result {
+        Ok(filter) => {
+            tracing::info!("Log filter set to '{}' by {}", filter, peer);
+            Json(serde_json::json!({ "filter": filter })).into_response()
+        }
+        Err((status, message)) => error_response(status, &message),
+    }
+}
+
+/// Apply a log level request to `handle`, returning the new filter
+fn apply_log_level(
+    handle: &FilterHandle,
+    request: &LogLevelRequest,
+) -> Result<String, (StatusCode, String)> {
+    let result = match (&request.level, &request.filter) {
+        (Some(level), None) => {
+            let level = Level::from_str(level).map_err(|_| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    format!("Invalid log level '{}'", level),
+                )
+            })?;
+            handle.set_level(level)
+        }
+        (None, Some(filter)) => handle.set_filter(filter),
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "Set exactly one of 'level' or 'filter'".to_string(),
+            ))
+        }
+    };
+
+    result
+        .and_then(|_| handle.current_filter())
+        .map_err(|e| match e {
+            LoggingError::InvalidFilter(_, _) => (StatusCode::BAD_REQUEST, e.to_string()),
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        })
+}
+
+async fn get_blacklist(State(state): State<Arc<RwLock<AxonState>>>) -> Response {
+    match blacklist_store(&state).await {
+        Ok(store) => blacklist_response(&store),
+        Err((status, message)) => error_response(status, &message),
+    }
+}
+
+async fn post_blacklist(
+    State(state): State<Arc<RwLock<AxonState>>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    Json(request): Json<BlacklistRequest>,
+) -> Response {
+    let result = match blacklist_store(&state).await {
+        Ok(store) => apply_blacklist(&store, &request).map(|_| store),
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(store) => {
+            tracing::info!("Blacklist changed by {}: {:?}", peer, request);
+            blacklist_response(&store)
+        }
+        Err((status, message)) => error_response(status, &message),
+    }
+}
+
+async fn blacklist_store(
+    state: &RwLock<AxonState>,
+) -> Result<Arc<BlacklistStore>, (StatusCode, String)> {
+    state.read().await.blacklist_store.clone().ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            "No blacklist store is configured".to_string(),
+        )
+    })
+}
+
+fn blacklist_response(store: &BlacklistStore) -> Response {
+    Json(serde_json::json!({ "entries": store.list() })).into_response()
+}
+
+/// Apply a blacklist request to `store`
+fn apply_blacklist(
+    store: &BlacklistStore,
+    request: &BlacklistRequest,
+) -> Result<(), (StatusCode, String)> {
+    let ttl = request.ttl_secs.map(Duration::from_secs);
+    let result = match (request.action, &request.hotkey, request.ip) {
+        (BlacklistAction::Ban, Some(hotkey), None) => store.ban_hotkey(hotkey, ttl),
+        (BlacklistAction::Ban, None, Some(ip)) => store.ban_ip(ip, ttl),
+        (BlacklistAction::Unban, Some(hotkey), None) => store.unban(hotkey).map(|_| ()),
+        (BlacklistAction::Unban, None, Some(ip)) => store.unban(&ip.to_string()).map(|_| ()),
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "Set exactly one of 'hotkey' or 'ip'".to_string(),
+            ))
+        }
+    };
+
+    result.map_err(|e| {
+        let status = e
+            .status_code
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (status, e.message)
+    })
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response {
+    (status, Json(serde_json::json!({ "error": message }))).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::EnvFilter;
+
+    #[test]
+    fn test_apply_log_level() {
+        let (_layer, handle) = FilterHandle::new(EnvFilter::new("info"));
+
+        let request = LogLevelRequest {
+            level: Some("debug".to_string()),
+            ..Default::default()
+        };
+        assert!(apply_log_level(&handle, &request)
+            .unwrap()
+            .contains("debug"));
+
+        let request = LogLevelRequest {
+            filter: Some("warn,bittensor_rs::axon=trace".to_string()),
+            ..Default::default()
+        };
+        assert!(apply_log_level(&handle, &request)
+            .unwrap()
+            .contains("bittensor_rs::axon=trace"));
+
+        let invalid = [
+            LogLevelRequest::default(),
+            LogLevelRequest {
+                level: Some("loud".to_string()),
+                ..Default::default()
+            },
+            LogLevelRequest {
+                level: Some("info".to_string()),
+                filter: Some("info".to_string()),
+            },
+        ];
+        for request in &invalid {
+            let (status, _) = apply_log_level(&handle, request).unwrap_err();
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[test]
+    fn test_has_token() {
+        let headers = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::AUTHORIZATION, value.parse().unwrap());
+            headers
+        };
+
+        assert!(has_token(&headers("Bearer secret"), "secret"));
+        assert!(!has_token(&headers("Bearer secreT"), "secret"));
+        assert!(!has_token(&headers("Bearer secret2"), "secret"));
+        assert!(!has_token(&headers("Basic secret"), "secret"));
+        assert!(!has_token(&HeaderMap::new(), "secret"));
+        assert!(!has_token(&headers("Bearer "), ""));
+    }
+
+    #[test]
+    fn test_apply_blacklist() {
+        let store = BlacklistStore::new();
+        let hotkey = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        let request = |action, hotkey: Option<&str>, ip: Option<IpAddr>| BlacklistRequest {
+            action,
+            hotkey: hotkey.map(str::to_string),
+            ip,
+            ttl_secs: Some(60),
+        };
+
+        apply_blacklist(&store, &request(BlacklistAction::Ban, Some(hotkey), None)).unwrap();
+        apply_blacklist(&store, &request(BlacklistAction::Ban, None, Some(ip))).unwrap();
+        assert!(store.is_hotkey_banned(hotkey));
+        assert!(store.is_ip_banned(&ip));
+        assert!(store
+            .list()
+            .iter()
+            .all(|entry| entry.expires_at_ms.is_some()));
+
+        apply_blacklist(&store, &request(BlacklistAction::Unban, None, Some(ip))).unwrap();
+        assert!(!store.is_ip_banned(&ip));
+
+        let invalid = [
+            request(BlacklistAction::Ban, None, None),
+            request(BlacklistAction::Ban, Some(hotkey), Some(ip)),
+            request(BlacklistAction::Ban, Some("not-an-address"), None),
+        ];
+        for request in &invalid {
+            let (status, _) = apply_blacklist(&store, request).unwrap_err();
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+        }
+    }
+}
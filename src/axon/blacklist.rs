@@ -0,0 +1,312 @@
+//! Managed blacklist of hotkeys and IPs
+//!
+//! A [`BlacklistStore`] holds bans that can be added and lifted while the
+//! axon is running, from code or through the local-only `/admin/blacklist`
+//! routes (see [`crate::axon::admin`]). Bans may expire after a TTL.
+//!
+//! With [`BlacklistStore::open`] the bans are kept in a JSON file: it is
+//! loaded at startup and rewritten atomically after every change, so bans
+//! survive restarts. Expired bans are dropped when the file is loaded or
+//! saved.
+
+use crate::errors::AxonError;
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// What a ban applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BanKind {
+    Hotkey,
+    Ip,
+}
+
+/// One ban in a [`BlacklistStore`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlacklistEntry {
+    pub kind: BanKind,
+    /// SS58 address or IP address
+    pub value: String,
+    /// When the ban ends, in milliseconds since the Unix epoch (None for
+    /// a permanent ban)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at_ms: Option<u64>,
+}
+
+impl BlacklistEntry {
+    fn is_expired(&self, now_ms: u64) -> bool {
+        self.expires_at_ms.is_some_and(|at| at <= now_ms)
+    }
+}
+
+/// Contents of the blacklist file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BlacklistFile {
+    entries: Vec<BlacklistEntry>,
+}
+
+/// Hotkey and IP bans, optionally persisted to a JSON file
+#[derive(Debug, Default)]
+pub struct BlacklistStore {
+    /// Expiry per (kind, value), None for permanent bans
+    bans: RwLock<HashMap<(BanKind, String), Option<u64>>>,
+    path: Option<PathBuf>,
+}
+
+impl BlacklistStore {
+    /// An empty store kept in memory only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A store persisted to `path`, loading the bans already saved there
+    ///
+    /// A missing file starts an empty store; the file is created on the
+    /// first change.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, AxonError> {
+        let path = path.into();
+        let file = match std::fs::read(&path) {
+            Ok(contents) => serde_json::from_slice::<BlacklistFile>(&contents).map_err(|e| {
+                AxonError::new(format!("Invalid blacklist file {}: {}", path.display(), e))
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BlacklistFile::default(),
+            Err(e) => {
+                return Err(AxonError::new(format!(
+                    "Failed to read blacklist file {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+
+        let now = now_ms();
+        let bans = file
+            .entries
+            .into_iter()
+            .filter(|entry| !entry.is_expired(now))
+            .map(|entry| ((entry.kind, entry.value), entry.expires_at_ms))
+            .collect();
+        Ok(Self {
+            bans: RwLock::new(bans),
+            path: Some(path),
+        })
+    }
+
+    /// File the bans are saved to, if any
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Ban a hotkey, for `ttl` or permanently
+    ///
+    /// The ban is in effect even if saving the file fails.
+    pub fn ban_hotkey(&self, ss58: &str, ttl: Option<Duration>) -> Result<(), AxonError> {
+        let account = AccountId32::from_ss58check(ss58).map_err(|e| {
+            AxonError::with_status(format!("Invalid hotkey '{}': {:?}", ss58, e), 400)
+        })?;
+        self.ban(BanKind::Hotkey, account.to_ss58check(), ttl)
+    }
+
+    /// Ban an IP address, for `ttl` or permanently
+    ///
+    /// The ban is in effect even if saving the file fails.
+    pub fn ban_ip(&self, ip: IpAddr, ttl: Option<Duration>) -> Result<(), AxonError> {
+        self.ban(BanKind::Ip, ip.to_string(), ttl)
+    }
+
+    /// Lift the ban on a hotkey or IP address, returning whether it was banned
+    pub fn unban(&self, value: &str) -> Result<bool, AxonError> {
+        let ip = value.parse::<IpAddr>().ok();
+        let removed = {
+            let mut bans = self.write();
+            let hotkey = AccountId32::from_ss58check(value)
+                .map(|account| account.to_ss58check())
+                .unwrap_or_else(|_| value.to_string());
+            let by_hotkey = bans.remove(&(BanKind::Hotkey, hotkey)).is_some();
+            let by_ip = ip.is_some_and(|ip| bans.remove(&(BanKind::Ip, ip.to_string())).is_some());
+            by_hotkey || by_ip
+        };
+        if removed {
+            self.save()?;
+        }
+        Ok(removed)
+    }
+
+    /// Active bans, sorted by kind and value
+    pub fn list(&self) -> Vec<BlacklistEntry> {
+        let now = now_ms();
+        let mut entries: Vec<BlacklistEntry> = self
+            .read()
+            .iter()
+            .map(|((kind, value), expires_at_ms)| BlacklistEntry {
+                kind: *kind,
+                value: value.clone(),
+                expires_at_ms: *expires_at_ms,
+            })
+            .filter(|entry| !entry.is_expired(now))
+            .collect();
+        entries.sort_by(|a, b| (a.kind, &a.value).cmp(&(b.kind, &b.value)));
+        entries
+    }
+
+    /// Whether `ss58` is currently banned
+    pub fn is_hotkey_banned(&self, ss58: &str) -> bool {
+        self.is_banned(BanKind::Hotkey, ss58)
+    }
+
+    /// Whether `ip` is currently banned
+    pub fn is_ip_banned(&self, ip: &IpAddr) -> bool {
+        self.is_banned(BanKind::Ip, &ip.to_string())
+    }
+
+    fn is_banned(&self, kind: BanKind, value: &str) -> bool {
+        match self.read().get(&(kind, value.to_string())) {
+            Some(Some(expires_at_ms)) => *expires_at_ms > now_ms(),
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    fn ban(&self, kind: BanKind, value: String, ttl: Option<Duration>) -> Result<(), AxonError> {
+        let expires_at_ms = ttl.map(|ttl| now_ms().saturating_add(ttl.as_millis() as u64));
+        self.write().insert((kind, value), expires_at_ms);
+        self.save()
+    }
+
+    /// Write the active bans to the file, replacing it atomically
+    fn save(&self) -> Result<(), AxonError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let file = BlacklistFile {
+            entries: self.list(),
+        };
+        let contents = serde_json::to_vec_pretty(&file)
+            .map_err(|e| AxonError::new(format!("Failed to encode blacklist: {}", e)))?;
+
+        let write = || -> std::io::Result<()> {
+            if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                std::fs::create_dir_all(dir)?;
+            }
+            let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+            std::fs::write(&tmp, &contents)?;
+            std::fs::rename(&tmp, path).inspect_err(|_| {
+                let _ = std::fs::remove_file(&tmp);
+            })
+        };
+        write().map_err(|e| {
+            AxonError::new(format!(
+                "Failed to save blacklist to {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<(BanKind, String), Option<u64>>> {
+        self.bans.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<(BanKind, String), Option<u64>>> {
+        self.bans.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+    const BOB: &str = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+
+    #[test]
+    fn test_bans_expire_after_ttl() {
+        let store = BlacklistStore::new();
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        store.ban_hotkey(ALICE, None).unwrap();
+        store
+            .ban_hotkey(BOB, Some(Duration::from_millis(50)))
+            .unwrap();
+        store.ban_ip(ip, Some(Duration::from_millis(50))).unwrap();
+
+        assert!(store.is_hotkey_banned(ALICE));
+        assert!(store.is_hotkey_banned(BOB));
+        assert!(store.is_ip_banned(&ip));
+        assert_eq!(store.list().len(), 3);
+
+        std::thread::sleep(Duration::from_millis(80));
+        assert!(store.is_hotkey_banned(ALICE));
+        assert!(!store.is_hotkey_banned(BOB));
+        assert!(!store.is_ip_banned(&ip));
+        assert_eq!(
+            store.list(),
+            [BlacklistEntry {
+                kind: BanKind::Hotkey,
+                value: ALICE.to_string(),
+                expires_at_ms: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unban_and_validation() {
+        let store = BlacklistStore::new();
+        let ip: IpAddr = "::1".parse().unwrap();
+        store.ban_hotkey(ALICE, None).unwrap();
+        store.ban_ip(ip, None).unwrap();
+
+        assert!(store.unban(ALICE).unwrap());
+        assert!(!store.unban(ALICE).unwrap());
+        assert!(store.unban("0:0:0:0:0:0:0:1").unwrap());
+        assert!(store.list().is_empty());
+
+        assert!(store.ban_hotkey("not-an-address", None).is_err());
+    }
+
+    #[test]
+    fn test_persistence_round_trip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("blacklist.json");
+        let ip: IpAddr = "192.168.1.7".parse().unwrap();
+
+        let store = BlacklistStore::open(&path).unwrap();
+        assert!(store.list().is_empty());
+        store.ban_hotkey(ALICE, None).unwrap();
+        store.ban_ip(ip, Some(Duration::from_secs(3600))).unwrap();
+        store
+            .ban_hotkey(BOB, Some(Duration::from_millis(20)))
+            .unwrap();
+        let saved = store.list();
+        drop(store);
+
+        // Expired bans are not loaded
+        std::thread::sleep(Duration::from_millis(40));
+        let reopened = BlacklistStore::open(&path).unwrap();
+        let entries = reopened.list();
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|entry| saved.contains(entry)));
+        assert!(reopened.is_hotkey_banned(ALICE));
+        assert!(reopened.is_ip_banned(&ip));
+
+        reopened.unban(ALICE).unwrap();
+        let reopened = BlacklistStore::open(&path).unwrap();
+        assert!(!reopened.is_hotkey_banned(ALICE));
+        assert!(reopened.is_ip_banned(&ip));
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(BlacklistStore::open(&path).is_err());
+    }
+}
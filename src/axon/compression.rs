@@ -0,0 +1,271 @@
+//! Request body limits and transport compression
+//!
+//! [`body_limit_middleware`] buffers each request body up to
+//! `AxonConfig::max_body_bytes` and decodes `Content-Encoding: gzip` or
+//! `zstd`, so signature checks and handlers always see the uncompressed body
+//! the dendrite hashed. The limit applies to both the bytes on the wire and
+//! the decompressed body, and a declared `Content-Length` over the limit is
+//! rejected before anything is read.
+//!
+//! Responses are compressed by [`compression_layer`] when the caller sends a
+//! matching `Accept-Encoding`. Streaming responses are left as-is so chunks
+//! and trailers reach the dendrite unbuffered.
+
+use crate::axon::handlers::{build_error_response, status_codes, status_messages};
+use crate::axon::server::AxonState;
+use axum::body::{Body, Bytes};
+use axum::extract::State;
+use axum::http::{header, HeaderMap, HeaderValue, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::sync::Arc;
+use std::time::Instant;
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tower_http::compression::predicate::{DefaultPredicate, Predicate};
+use tower_http::compression::CompressionLayer;
+use tracing::debug;
+
+/// Default `AxonConfig::max_body_bytes` (10 MiB)
+pub const DEFAULT_MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// Body encodings understood by the axon and dendrite
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentEncoding {
+    Gzip,
+    Zstd,
+}
+
+/// A compressed body could not be decoded
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error("Decompressed body exceeds {0} bytes")]
+    TooLarge(usize),
+    #[error("Invalid compressed body: {0}")]
+    Invalid(#[from] std::io::Error),
+}
+
+impl ContentEncoding {
+    /// `Content-Encoding` header value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Zstd => "zstd",
+        }
+    }
+
+    /// Parse a `Content-Encoding` header value
+    pub fn from_header(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "gzip" | "x-gzip" => Some(ContentEncoding::Gzip),
+            "zstd" => Some(ContentEncoding::Zstd),
+            _ => None,
+        }
+    }
+
+    /// Compress `data`
+    pub fn encode(&self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            ContentEncoding::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+            ContentEncoding::Zstd => zstd::stream::encode_all(data, 0),
+        }
+    }
+
+    /// Decompress `data`, failing once the output would exceed `limit` bytes
+    ///
+    /// Output is read incrementally so a small body that inflates to
+    /// gigabytes is rejected without being expanded in memory.
+    pub fn decode(&self, data: &[u8], limit: usize) -> Result<Vec<u8>, DecodeError> {
+        let reader: Box<dyn Read + '_> = match self {
+            ContentEncoding::Gzip => Box::new(flate2::read::GzDecoder::new(data)),
+            ContentEncoding::Zstd => Box::new(zstd::stream::read::Decoder::new(data)?),
+        };
+        let mut out = Vec::new();
+        reader
+            .take(limit as u64 + 1)
+            .read_to_end(&mut out)
+            .map_err(DecodeError::Invalid)?;
+        if out.len() > limit {
+            return Err(DecodeError::TooLarge(limit));
+        }
+        Ok(out)
+    }
+}
+
+impl std::fmt::Display for ContentEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Body limit middleware - enforce the size limit and decompress bodies
+///
+/// Rejects bodies over the limit with 413 Payload Too Large and unknown
+/// encodings with 415 Unsupported Media Type. Decoded bodies are passed on
+/// without `Content-Encoding`.
+pub async fn body_limit_middleware(
+    State(state): State<Arc<RwLock<AxonState>>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let start_time = Instant::now();
+    let (axon_hotkey, limit) = {
+        let state_read = state.read().await;
+        (state_read.axon_hotkey.clone(), state_read.max_body_bytes)
+    };
+    let reject = |status: StatusCode, code: i32, message: &str| {
+        build_error_response(
+            &axon_hotkey,
+            status,
+            code,
+            message,
+            start_time.elapsed().as_secs_f64(),
+        )
+    };
+    let too_large = || {
+        reject(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            status_codes::PAYLOAD_TOO_LARGE,
+            status_messages::PAYLOAD_TOO_LARGE,
+        )
+    };
+
+    // Reject on the declared length before reading anything
+    if declared_length(req.headers()).is_some_and(|len| len > limit as u64) {
+        debug!("Rejected request declaring a body over {} bytes", limit);
+        return too_large();
+    }
+
+    let encoding = match req.headers().get(header::CONTENT_ENCODING) {
+        None => None,
+        Some(value) => {
+            let value = value.to_str().unwrap_or_default().trim();
+            if value.eq_ignore_ascii_case("identity") {
+                None
+            } else if let Some(encoding) = ContentEncoding::from_header(value) {
+                Some(encoding)
+            } else {
+                debug!("Rejected request with Content-Encoding {:?}", value);
+                return reject(
+                    StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                    status_codes::UNSUPPORTED_MEDIA_TYPE,
+                    status_messages::UNSUPPORTED_MEDIA_TYPE,
+                );
+            }
+        }
+    };
+
+    let (mut parts, body) = req.into_parts();
+    let bytes = match axum::body::to_bytes(body, limit).await {
+        Ok(bytes) => bytes,
+        // Over the limit; the only other failure is a caller that went away
+        // mid-body and never reads the response
+        Err(e) => {
+            debug!("Failed to read request body within {} bytes: {}", limit, e);
+            return too_large();
+        }
+    };
+
+    let bytes = match encoding {
+        None => bytes,
+        Some(encoding) => match encoding.decode(&bytes, limit) {
+            Ok(decoded) => {
+                parts.headers.remove(header::CONTENT_ENCODING);
+                parts
+                    .headers
+                    .insert(header::CONTENT_LENGTH, HeaderValue::from(decoded.len()));
+                Bytes::from(decoded)
+            }
+            Err(DecodeError::TooLarge(_)) => {
+                debug!("Rejected {} body inflating past {} bytes", encoding, limit);
+                return too_large();
+            }
+            Err(e) => {
+                debug!("Failed to decode {} request body: {}", encoding, e);
+                return reject(
+                    StatusCode::BAD_REQUEST,
+                    status_codes::BAD_REQUEST,
+                    status_messages::BAD_REQUEST,
+                );
+            }
+        },
+    };
+
+    next.run(Request::from_parts(parts, Body::from(bytes)))
+        .await
+}
+
+/// Response compression negotiated from `Accept-Encoding` (gzip or zstd)
+///
+/// Streaming responses, which announce a `Trailer` header, are never
+/// compressed.
+pub fn compression_layer() -> CompressionLayer<impl Predicate> {
+    let not_streaming =
+        |_: StatusCode, _: axum::http::Version, headers: &HeaderMap, _: &axum::http::Extensions| {
+            !headers.contains_key(header::TRAILER)
+        };
+    CompressionLayer::new()
+        .gzip(true)
+        .zstd(true)
+        .compress_when(DefaultPredicate::new().and(not_streaming))
+}
+
+fn declared_length(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let data = br#"{"name":"Query","input":"hello hello hello hello"}"#.repeat(20);
+        for encoding in [ContentEncoding::Gzip, ContentEncoding::Zstd] {
+            let compressed = encoding.encode(&data).unwrap();
+            assert!(compressed.len() < data.len());
+            assert_eq!(encoding.decode(&compressed, data.len()).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_decode_limit() {
+        // 1 MiB of zeros compresses to a few hundred bytes
+        let bomb = vec![0u8; 1024 * 1024];
+        for encoding in [ContentEncoding::Gzip, ContentEncoding::Zstd] {
+            let compressed = encoding.encode(&bomb).unwrap();
+            assert!(matches!(
+                encoding.decode(&compressed, 64 * 1024),
+                Err(DecodeError::TooLarge(65_536))
+            ));
+        }
+        assert!(matches!(
+            ContentEncoding::Gzip.decode(b"not gzip", 1024),
+            Err(DecodeError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_header() {
+        assert_eq!(
+            ContentEncoding::from_header(" GZIP "),
+            Some(ContentEncoding::Gzip)
+        );
+        assert_eq!(
+            ContentEncoding::from_header("zstd"),
+            Some(ContentEncoding::Zstd)
+        );
+        assert_eq!(ContentEncoding::from_header("br"), None);
+    }
+}
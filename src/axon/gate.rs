@@ -0,0 +1,219 @@
+//! Metagraph-based request gating
+//!
+//! Rejects requests from callers that are not registered on the axon's
+//! subnet, or whose stake is below a minimum, using a shared metagraph that is
+//! refreshed in the background. Unregistered callers get a 401
+//! ([`SynapseUnauthorized`]) and under-staked callers a 403
+//! ([`SynapseBlacklisted`]).
+
+use crate::errors::{SynapseBlacklisted, SynapseUnauthorized};
+use crate::metagraph::Metagraph;
+use crate::utils::balance_newtypes::Rao;
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::{AccountId32, Ss58Codec};
+
+/// Default number of blocks after which the metagraph is considered stale
+pub const DEFAULT_MAX_STALENESS_BLOCKS: u64 = 300;
+
+/// Registration and stake requirements for callers
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MetagraphGate {
+    /// Subnet the caller must be registered on
+    pub netuid: u16,
+    /// Minimum stake the caller's hotkey must hold
+    pub min_stake: Rao,
+    /// Maximum metagraph age in blocks before it is considered stale
+    pub max_staleness_blocks: u64,
+    /// Whether to allow requests when the metagraph is stale or unsynced
+    pub fail_open: bool,
+}
+
+/// Reason a caller was rejected by the gate
+#[derive(Debug, Clone)]
+pub enum GateRejection {
+    /// The caller could not be verified as registered (401)
+    Unauthorized(SynapseUnauthorized),
+    /// The caller is registered but does not meet the stake threshold (403)
+    Blacklisted(SynapseBlacklisted),
+}
+
+impl MetagraphGate {
+    /// Create a gate requiring registration on `netuid`, with no minimum stake
+    pub fn new(netuid: u16) -> Self {
+        Self {
+            netuid,
+            min_stake: Rao::ZERO,
+            max_staleness_blocks: DEFAULT_MAX_STALENESS_BLOCKS,
+            fail_open: false,
+        }
+    }
+
+    /// Set the minimum stake
+    pub fn with_min_stake(mut self, min_stake: Rao) -> Self {
+        self.min_stake = min_stake;
+        self
+    }
+
+    /// Set the maximum metagraph age in blocks
+    pub fn with_max_staleness_blocks(mut self, blocks: u64) -> Self {
+        self.max_staleness_blocks = blocks;
+        self
+    }
+
+    /// Allow (`true`) or reject (`false`) requests while the metagraph is stale
+    pub fn with_fail_open(mut self, fail_open: bool) -> Self {
+        self.fail_open = fail_open;
+        self
+    }
+
+    /// Check whether the metagraph can be used to gate requests
+    ///
+    /// # Arguments
+    ///
+    /// * `metagraph` - The shared metagraph
+    /// * `current_block` - The latest known chain block (0 if unknown)
+    pub fn is_stale(&self, metagraph: &Metagraph, current_block: u64) -> bool {
+        if metagraph.netuid != self.netuid || metagraph.block == 0 {
+            return true;
+        }
+        current_block.saturating_sub(metagraph.block) > self.max_staleness_blocks
+    }
+
+    /// Check a caller against the gate
+    ///
+    /// # Arguments
+    ///
+    /// * `metagraph` - The shared metagraph
+    /// * `hotkey` - The caller's hotkey SS58 address, if provided
+    /// * `current_block` - The latest known chain block (0 if unknown)
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the request may proceed, or the rejection to respond with
+    pub fn check(
+        &self,
+        metagraph: &Metagraph,
+        hotkey: Option<&str>,
+        current_block: u64,
+    ) -> Result<(), GateRejection> {
+        if self.is_stale(metagraph, current_block) {
+            if self.fail_open {
+                return Ok(());
+            }
+            let message = format!(
+                "Metagraph for subnet {} is stale (synced at block {}, current block {})",
+                self.netuid, metagraph.block, current_block
+            );
+            return Err(GateRejection::Unauthorized(match hotkey {
+                Some(hotkey) => SynapseUnauthorized::with_hotkey(message, hotkey),
+                None => SynapseUnauthorized::new(message),
+            }));
+        }
+
+        let Some(hotkey) = hotkey else {
+            return Err(GateRejection::Unauthorized(SynapseUnauthorized::new(
+                "Missing dendrite hotkey",
+            )));
+        };
+
+        let neuron = AccountId32::from_ss58check(hotkey)
+            .ok()
+            .and_then(|account| metagraph.get_neuron_by_hotkey(&account));
+        let Some(neuron) = neuron else {
+            return Err(GateRejection::Unauthorized(
+                SynapseUnauthorized::with_hotkey(
+                    format!("Hotkey is not registered on subnet {}", self.netuid),
+                    hotkey,
+                ),
+            ));
+        };
+
+        if neuron.stake < self.min_stake {
+            return Err(GateRejection::Blacklisted(SynapseBlacklisted::with_hotkey(
+                format!(
+                    "Stake {} is below the required minimum {}",
+                    neuron.stake, self.min_stake
+                ),
+                hotkey,
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metagraph::test_neuron;
+    use crate::types::NeuronInfo;
+
+    const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+    const BOB: &str = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+
+    fn metagraph_with(hotkey: &str, stake: Rao, block: u64) -> Metagraph {
+        let mut metagraph = Metagraph::new(1);
+        metagraph.block = block;
+        let account = AccountId32::from_ss58check(hotkey).unwrap();
+        let neuron = NeuronInfo {
+            last_update: block,
+            ..test_neuron(0, &account, stake)
+        };
+        metagraph.neurons.insert(0, neuron);
+        metagraph.n = 1;
+        metagraph
+    }
+
+    #[test]
+    fn test_gate_allows_registered_caller() {
+        let gate = MetagraphGate::new(1).with_min_stake(Rao::from(1_000u128));
+        let metagraph = metagraph_with(ALICE, Rao::from(5_000u128), 100);
+
+        assert!(gate.check(&metagraph, Some(ALICE), 110).is_ok());
+    }
+
+    #[test]
+    fn test_gate_rejects_unregistered_caller() {
+        let gate = MetagraphGate::new(1);
+        let metagraph = metagraph_with(ALICE, Rao::from(5_000u128), 100);
+
+        match gate.check(&metagraph, Some(BOB), 110) {
+            Err(GateRejection::Unauthorized(e)) => assert_eq!(e.hotkey.as_deref(), Some(BOB)),
+            other => panic!("expected unauthorized, got {:?}", other),
+        }
+        assert!(matches!(
+            gate.check(&metagraph, None, 110),
+            Err(GateRejection::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_gate_rejects_low_stake() {
+        let gate = MetagraphGate::new(1).with_min_stake(Rao::from(10_000u128));
+        let metagraph = metagraph_with(ALICE, Rao::from(5_000u128), 100);
+
+        match gate.check(&metagraph, Some(ALICE), 110) {
+            Err(GateRejection::Blacklisted(e)) => assert_eq!(e.hotkey.as_deref(), Some(ALICE)),
+            other => panic!("expected blacklisted, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_gate_stale_metagraph() {
+        let metagraph = metagraph_with(ALICE, Rao::from(5_000u128), 100);
+        let closed = MetagraphGate::new(1).with_max_staleness_blocks(50);
+        let open = closed.with_fail_open(true);
+
+        assert!(!closed.is_stale(&metagraph, 150));
+        assert!(closed.is_stale(&metagraph, 151));
+        assert!(matches!(
+            closed.check(&metagraph, Some(BOB), 151),
+            Err(GateRejection::Unauthorized(_))
+        ));
+        assert!(open.check(&metagraph, Some(BOB), 151).is_ok());
+
+        // An unsynced metagraph or one for another subnet is always stale
+        assert!(closed.is_stale(&Metagraph::new(1), 0));
+        assert!(MetagraphGate::new(2).is_stale(&metagraph, 100));
+    }
+}
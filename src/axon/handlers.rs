@@ -3,15 +3,19 @@
 //! This module provides the core request handling logic including
 //! signature verification, synapse extraction, and response building.
 
-use crate::dendrite::request::header_names;
+use crate::dendrite::request::{
+    body_to_extra, create_signature_message, header_names, timeout_from_headers,
+};
 use crate::errors::{AxonError, SynapseUnauthorized};
+use crate::types::synapse::{listed_body_hash, python_body_hash, python_float_repr};
 use crate::types::{Synapse, TerminalInfo};
 use axum::body::Bytes;
 use axum::response::{IntoResponse, Response};
 use http::{HeaderMap, HeaderValue, StatusCode};
 use sha2::{Digest, Sha256};
 use sp_core::{sr25519, Pair};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use thiserror::Error;
 
 /// Bittensor protocol version
 pub const AXON_VERSION: u64 = 100;
@@ -19,10 +23,14 @@ pub const AXON_VERSION: u64 = 100;
 /// Status codes matching the Python SDK
 pub mod status_codes {
     pub const SUCCESS: i32 = 200;
+    pub const BAD_REQUEST: i32 = 400;
     pub const UNAUTHORIZED: i32 = 401;
     pub const FORBIDDEN: i32 = 403;
     pub const NOT_FOUND: i32 = 404;
     pub const TIMEOUT: i32 = 408;
+    pub const PAYLOAD_TOO_LARGE: i32 = 413;
+    pub const UNSUPPORTED_MEDIA_TYPE: i32 = 415;
+    pub const TOO_MANY_REQUESTS: i32 = 429;
     pub const INTERNAL_ERROR: i32 = 500;
     pub const SERVICE_UNAVAILABLE: i32 = 503;
 }
@@ -30,10 +38,16 @@ pub mod status_codes {
 /// Status messages for response headers
 pub mod status_messages {
     pub const SUCCESS: &str = "Success";
+    pub const BAD_REQUEST: &str = "Invalid synapse";
     pub const UNAUTHORIZED: &str = "Signature verification failed";
+    pub const REPLAYED: &str = "Replayed request";
+    pub const BODY_HASH_MISMATCH: &str = "Body hash mismatch";
     pub const FORBIDDEN: &str = "Blacklisted";
     pub const NOT_FOUND: &str = "Synapse not found";
     pub const TIMEOUT: &str = "Request timeout";
+    pub const PAYLOAD_TOO_LARGE: &str = "Request body too large";
+    pub const UNSUPPORTED_MEDIA_TYPE: &str = "Unsupported content encoding";
+    pub const TOO_MANY_REQUESTS: &str = "Rate limit exceeded";
     pub const INTERNAL_ERROR: &str = "Internal server error";
     pub const SERVICE_UNAVAILABLE: &str = "Service unavailable";
 }
@@ -49,12 +63,60 @@ pub struct VerifiedRequest {
     pub signature: String,
     /// Request UUID
     pub uuid: String,
-    /// Computed body hash
+    /// Body hash the dendrite sent and signed
     pub body_hash: String,
 }
 
+/// Reason a request failed verification
+#[derive(Debug, Clone, Error)]
+pub enum RequestRejection {
+    /// Missing headers or an invalid signature (401)
+    #[error(transparent)]
+    Unauthorized(SynapseUnauthorized),
+    /// The body does not hash to its `computed_body_hash` header (400)
+    #[error("body hash mismatch: header {expected}, body {actual}")]
+    BodyHashMismatch {
+        /// Hash sent in the header
+        expected: String,
+        /// Hash of the received body
+        actual: String,
+    },
+}
+
+impl RequestRejection {
+    /// HTTP status and status code to respond with
+    pub fn status(&self) -> (StatusCode, i32) {
+        match self {
+            RequestRejection::Unauthorized(_) => {
+                (StatusCode::UNAUTHORIZED, status_codes::UNAUTHORIZED)
+            }
+            RequestRejection::BodyHashMismatch { .. } => {
+                (StatusCode::BAD_REQUEST, status_codes::BAD_REQUEST)
+            }
+        }
+    }
+
+    /// Status message for the response headers
+    pub fn message(&self) -> &str {
+        match self {
+            RequestRejection::Unauthorized(e) => &e.message,
+            RequestRejection::BodyHashMismatch { .. } => status_messages::BODY_HASH_MISMATCH,
+        }
+    }
+}
+
+impl From<SynapseUnauthorized> for RequestRejection {
+    fn from(err: SynapseUnauthorized) -> Self {
+        RequestRejection::Unauthorized(err)
+    }
+}
+
 /// Extract and verify a request from headers
 ///
+/// The body must hash to the `computed_body_hash` header, over the
+/// `required_hash_fields` the body lists; see [`verify_request_with`] to
+/// hash a route's own fields or accept requests that leave the header empty.
+///
 /// # Arguments
 ///
 /// * `headers` - The HTTP request headers
@@ -68,7 +130,29 @@ pub fn verify_request(
     headers: &HeaderMap,
     body: &[u8],
     axon_hotkey: &str,
-) -> Result<VerifiedRequest, SynapseUnauthorized> {
+) -> Result<VerifiedRequest, RequestRejection> {
+    verify_request_with(headers, body, axon_hotkey, None, false)
+}
+
+/// Extract and verify a request from headers
+///
+/// The received body is hashed the way the Python SDK's axon does (see
+/// [`compute_synapse_body_hash`]) and compared with the `computed_body_hash`
+/// header before the signature is checked, so a body altered in transit is
+/// reported as [`RequestRejection::BodyHashMismatch`]. `hash_fields` are the
+/// route's required hash fields; `None` uses the `required_hash_fields` the
+/// body lists, which Python dendrites send. With `allow_empty_body_hash`, a
+/// missing or empty header skips the comparison.
+///
+/// As in the Python SDK, the signature covers the header's hash, which only
+/// matches the body if the comparison ran.
+pub fn verify_request_with(
+    headers: &HeaderMap,
+    body: &[u8],
+    axon_hotkey: &str,
+    hash_fields: Option<&[&str]>,
+    allow_empty_body_hash: bool,
+) -> Result<VerifiedRequest, RequestRejection> {
     // Extract required headers
     let dendrite_hotkey =
         get_header_string(headers, header_names::DENDRITE_HOTKEY).ok_or_else(|| {
@@ -100,16 +184,31 @@ pub fn verify_request(
 
     let uuid = get_header_string(headers, header_names::DENDRITE_UUID).unwrap_or_default();
 
-    // Compute body hash
-    let body_hash = compute_body_hash(body);
+    // Compare the body with the hash the dendrite sent
+    let body_hash = get_header_string(headers, header_names::BODY_HASH).unwrap_or_default();
+    if !(body_hash.is_empty() && allow_empty_body_hash) {
+        let actual = request_body_hash(body, hash_fields);
+        if body_hash != actual {
+            return Err(RequestRejection::BodyHashMismatch {
+                expected: body_hash,
+                actual,
+            });
+        }
+    }
 
     // Verify signature
-    verify_signature(&dendrite_hotkey, nonce, axon_hotkey, &body_hash, &signature).map_err(
-        |e| SynapseUnauthorized {
-            message: e.to_string(),
-            hotkey: Some(dendrite_hotkey.clone()),
-        },
-    )?;
+    verify_signature(
+        &dendrite_hotkey,
+        nonce,
+        axon_hotkey,
+        &uuid,
+        &body_hash,
+        &signature,
+    )
+    .map_err(|e| SynapseUnauthorized {
+        message: e.to_string(),
+        hotkey: Some(dendrite_hotkey.clone()),
+    })?;
 
     Ok(VerifiedRequest {
         dendrite_hotkey,
@@ -123,15 +222,16 @@ pub fn verify_request(
 /// Verify a request signature
 ///
 /// The signature format matches the Python SDK:
-/// `sign(message = "{nonce}.{dendrite_hotkey}.{axon_hotkey}.{body_hash}")`
+/// `sign(message = "{nonce}.{dendrite_hotkey}.{axon_hotkey}.{uuid}.{body_hash}")`
 ///
 /// # Arguments
 ///
 /// * `dendrite_hotkey` - The dendrite's hotkey SS58 address
 /// * `nonce` - The request nonce
 /// * `axon_hotkey` - The axon's hotkey SS58 address
-/// * `body_hash` - The SHA-256 hash of the request body
-/// * `signature` - The hex-encoded signature
+/// * `uuid` - The dendrite's UUID
+/// * `body_hash` - The `computed_body_hash` the dendrite sent
+/// * `signature` - The hex-encoded signature, with or without `0x`
 ///
 /// # Returns
 ///
@@ -140,10 +240,12 @@ pub fn verify_signature(
     dendrite_hotkey: &str,
     nonce: u64,
     axon_hotkey: &str,
+    uuid: &str,
     body_hash: &str,
     signature: &str,
 ) -> Result<(), AxonError> {
-    // Decode the signature from hex
+    // Decode the signature from hex; the Python SDK prefixes it with 0x
+    let signature = signature.strip_prefix("0x").unwrap_or(signature);
     let sig_bytes = hex::decode(signature)
         .map_err(|e| AxonError::new(format!("Invalid signature hex: {}", e)))?;
 
@@ -163,10 +265,7 @@ pub fn verify_signature(
         .map_err(|e| AxonError::new(format!("Invalid dendrite hotkey: {}", e)))?;
 
     // Create the message to verify
-    let message = format!(
-        "{}.{}.{}.{}",
-        nonce, dendrite_hotkey, axon_hotkey, body_hash
-    );
+    let message = create_signature_message(nonce, dendrite_hotkey, axon_hotkey, uuid, body_hash);
 
     // Verify the signature
     if sr25519::Pair::verify(&sig, message.as_bytes(), &public) {
@@ -189,6 +288,32 @@ pub fn compute_body_hash(data: &[u8]) -> String {
     hex::encode(hasher.finalize())
 }
 
+/// Compute the body hash the Python SDK sends with a request body
+///
+/// Each of `fields` is read from the JSON body and hashed as Python's
+/// `Synapse.body_hash` does (see [`SynapseType::body_hash`]). Fields the body
+/// lacks are skipped, so with no fields this is the SHA3-256 hash of the
+/// empty string whatever the body.
+///
+/// [`SynapseType::body_hash`]: crate::types::SynapseType::body_hash
+pub fn compute_synapse_body_hash(body: &[u8], fields: &[&str]) -> String {
+    let value = if fields.is_empty() {
+        serde_json::Value::Null
+    } else {
+        serde_json::from_slice(body).unwrap_or_default()
+    };
+    python_body_hash(&value, fields)
+}
+
+/// Python body hash of a request over `hash_fields`, or the
+/// `required_hash_fields` listed in the body if `None`
+fn request_body_hash(body: &[u8], hash_fields: Option<&[&str]>) -> String {
+    if let Some(fields) = hash_fields {
+        return compute_synapse_body_hash(body, fields);
+    }
+    listed_body_hash(&serde_json::from_slice(body).unwrap_or_default())
+}
+
 /// Extract a synapse from request headers and body
 ///
 /// # Arguments
@@ -201,12 +326,8 @@ pub fn compute_body_hash(data: &[u8]) -> String {
 /// The extracted Synapse
 pub fn extract_synapse(headers: &HeaderMap, body: &[u8]) -> Result<Synapse, AxonError> {
     // Parse body as JSON extra fields
-    let extra: std::collections::HashMap<String, serde_json::Value> = if body.is_empty() {
-        std::collections::HashMap::new()
-    } else {
-        serde_json::from_slice(body)
-            .map_err(|e| AxonError::new(format!("Invalid JSON body: {}", e)))?
-    };
+    let extra =
+        body_to_extra(body).map_err(|e| AxonError::new(format!("Invalid JSON body: {}", e)))?;
 
     // Build dendrite terminal info from headers
     let dendrite = TerminalInfo {
@@ -222,10 +343,11 @@ pub fn extract_synapse(headers: &HeaderMap, body: &[u8]) -> Result<Synapse, Axon
 
     Ok(Synapse {
         name: get_header_string(headers, header_names::NAME),
-        timeout: get_header_f64(headers, header_names::TIMEOUT),
+        timeout: timeout_from_headers(headers),
         total_size: get_header_u64(headers, header_names::TOTAL_SIZE),
         header_size: get_header_u64(headers, header_names::HEADER_SIZE),
-        computed_body_hash: get_header_string(headers, header_names::BODY_HASH),
+        computed_body_hash: get_header_string(headers, header_names::BODY_HASH)
+            .map(|hash| hash.to_ascii_lowercase()),
         dendrite: Some(dendrite),
         axon: Some(TerminalInfo::default()),
         extra,
@@ -265,7 +387,7 @@ pub fn build_response_headers(
     if let Ok(hv) = HeaderValue::from_str(status_message) {
         headers.insert(header_names::AXON_STATUS_MESSAGE, hv);
     }
-    if let Ok(hv) = HeaderValue::from_str(&format!("{:.6}", process_time)) {
+    if let Ok(hv) = HeaderValue::from_str(&python_float_repr(process_time)) {
         headers.insert(header_names::AXON_PROCESS_TIME, hv);
     }
     if let Ok(hv) = HeaderValue::from_str(hotkey) {
@@ -343,32 +465,75 @@ fn get_header_u16(headers: &HeaderMap, name: &str) -> Option<u16> {
     get_header_string(headers, name).and_then(|s| s.parse().ok())
 }
 
-fn get_header_f64(headers: &HeaderMap, name: &str) -> Option<f64> {
-    get_header_string(headers, name).and_then(|s| s.parse().ok())
-}
+/// Timeout applied to requests without a timeout header, in seconds
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: f64 = 12.0;
+
+/// Default time reserved for sending the response before the caller's timeout
+pub const DEFAULT_DEADLINE_MARGIN_MS: u64 = 250;
 
 /// Handler context for processing requests
-#[derive(Clone)]
+///
+/// The deadline is the request's timeout, counted from when the axon started
+/// processing it, less a safety margin so the response can reach the
+/// dendrite before it gives up. Handlers still running at the deadline are
+/// cancelled and the caller receives a 408.
+#[derive(Debug, Clone)]
 pub struct HandlerContext {
     /// The axon's hotkey
     pub hotkey: String,
     /// Request start time
     pub start_time: Instant,
+    /// When the handler is cancelled
+    pub deadline: Instant,
 }
 
 impl HandlerContext {
-    /// Create a new handler context
+    /// Create a new handler context with the default timeout and margin
     pub fn new(hotkey: impl Into<String>) -> Self {
+        Self::with_timeout(
+            hotkey,
+            Duration::from_secs_f64(DEFAULT_REQUEST_TIMEOUT_SECS),
+            Duration::from_millis(DEFAULT_DEADLINE_MARGIN_MS),
+        )
+    }
+
+    /// Create a handler context whose deadline is `timeout - margin` from now
+    pub fn with_timeout(hotkey: impl Into<String>, timeout: Duration, margin: Duration) -> Self {
+        let start_time = Instant::now();
         Self {
             hotkey: hotkey.into(),
-            start_time: Instant::now(),
+            start_time,
+            deadline: start_time + timeout.saturating_sub(margin),
         }
     }
 
+    /// Create a handler context from the request's timeout header
+    ///
+    /// Missing timeouts use [`DEFAULT_REQUEST_TIMEOUT_SECS`]; the timeout is
+    /// clamped to between 1 second and 5 minutes.
+    pub fn from_headers(hotkey: impl Into<String>, headers: &HeaderMap, margin: Duration) -> Self {
+        let timeout_secs = timeout_from_headers(headers)
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS)
+            .clamp(1.0, 300.0);
+        Self::with_timeout(hotkey, Duration::from_secs_f64(timeout_secs), margin)
+    }
+
     /// Get elapsed time in seconds
     pub fn elapsed_secs(&self) -> f64 {
         self.start_time.elapsed().as_secs_f64()
     }
+
+    /// Time left before the deadline (zero once it has passed)
+    ///
+    /// Handlers making several sub-calls can use this to budget each one.
+    pub fn remaining(&self) -> Duration {
+        self.deadline.saturating_duration_since(Instant::now())
+    }
+
+    /// Whether the deadline has passed
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
 }
 
 #[cfg(test)]
@@ -433,6 +598,28 @@ mod tests {
         // Small sleep to ensure elapsed time is > 0
         std::thread::sleep(std::time::Duration::from_millis(1));
         assert!(ctx.elapsed_secs() > 0.0);
+        assert!(ctx.remaining() <= Duration::from_millis(11_750));
+        assert!(!ctx.is_expired());
+    }
+
+    #[test]
+    fn test_handler_context_deadline_from_headers() {
+        let margin = Duration::from_millis(500);
+        let mut headers = HeaderMap::new();
+        headers.insert(header_names::TIMEOUT, "3.0".parse().unwrap());
+        let ctx = HandlerContext::from_headers("hotkey", &headers, margin);
+        assert_eq!(ctx.deadline - ctx.start_time, Duration::from_millis(2_500));
+
+        // Timeouts are clamped before the margin is taken off
+        headers.insert(header_names::TIMEOUT, "0.1".parse().unwrap());
+        let ctx = HandlerContext::from_headers("hotkey", &headers, margin);
+        assert_eq!(ctx.deadline - ctx.start_time, Duration::from_millis(500));
+
+        // A margin longer than the timeout expires immediately
+        let ctx =
+            HandlerContext::with_timeout("hotkey", Duration::from_secs(1), Duration::from_secs(2));
+        assert!(ctx.is_expired());
+        assert_eq!(ctx.remaining(), Duration::ZERO);
     }
 
     #[test]
@@ -443,4 +630,162 @@ mod tests {
         assert_eq!(status_codes::TIMEOUT, 408);
         assert_eq!(status_codes::INTERNAL_ERROR, 500);
     }
+
+    /// Headers for a request from `//Bob` to `//Alice` signed over `body_hash`
+    fn signed_headers(body_hash: &str) -> (HeaderMap, String) {
+        use crate::dendrite::request::sign_message;
+        use sp_core::crypto::Ss58Codec;
+
+        let bob = sr25519::Pair::from_string("//Bob", None).unwrap();
+        let alice = sr25519::Pair::from_string("//Alice", None)
+            .unwrap()
+            .public()
+            .to_ss58check();
+        let hotkey = bob.public().to_ss58check();
+        let message = create_signature_message(7, &hotkey, &alice, "uuid-7", body_hash);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header_names::DENDRITE_NONCE, HeaderValue::from_static("7"));
+        headers.insert(
+            header_names::DENDRITE_UUID,
+            HeaderValue::from_static("uuid-7"),
+        );
+        headers.insert(header_names::DENDRITE_HOTKEY, hotkey.parse().unwrap());
+        headers.insert(header_names::BODY_HASH, body_hash.parse().unwrap());
+        headers.insert(
+            header_names::DENDRITE_SIGNATURE,
+            sign_message(&bob, message.as_bytes()).parse().unwrap(),
+        );
+        (headers, alice)
+    }
+
+    #[test]
+    fn test_verify_request_signature_covers_uuid() {
+        let body = br#"{"query": "ping"}"#;
+        let (headers, alice) = signed_headers(&compute_synapse_body_hash(body, &[]));
+        assert!(verify_request(&headers, body, &alice).is_ok());
+
+        // Python sends 0x-prefixed signatures; bare hex is accepted as well
+        let signature = headers[header_names::DENDRITE_SIGNATURE].to_str().unwrap();
+        assert!(signature.starts_with("0x"));
+        let mut bare = headers.clone();
+        bare.insert(
+            header_names::DENDRITE_SIGNATURE,
+            signature[2..].parse().unwrap(),
+        );
+        assert!(verify_request(&bare, body, &alice).is_ok());
+
+        let mut other_uuid = headers.clone();
+        other_uuid.insert(
+            header_names::DENDRITE_UUID,
+            HeaderValue::from_static("uuid-8"),
+        );
+        assert!(matches!(
+            verify_request(&other_uuid, body, &alice),
+            Err(RequestRejection::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_compute_synapse_body_hash() {
+        // Python: Synapse().body_hash, i.e. sha3_256(b"").hexdigest()
+        let empty = "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a";
+        assert_eq!(compute_synapse_body_hash(b"", &[]), empty);
+        assert_eq!(
+            compute_synapse_body_hash(br#"{"query": "ping"}"#, &[]),
+            empty
+        );
+
+        let hash = compute_synapse_body_hash(br#"{"query": "ping"}"#, &["query"]);
+        assert_ne!(hash, empty);
+        assert_eq!(
+            compute_synapse_body_hash(br#"{"query": "ping", "other": 1}"#, &["query"]),
+            hash
+        );
+        assert_ne!(
+            compute_synapse_body_hash(br#"{"query": "pong"}"#, &["query"]),
+            hash
+        );
+    }
+
+    #[test]
+    fn test_verify_request_checks_body_hash() {
+        let body = br#"{"query": "ping"}"#;
+        let fields: &[&str] = &["query"];
+        let (headers, alice) = signed_headers(&compute_synapse_body_hash(body, fields));
+
+        let verified = verify_request_with(&headers, body, &alice, Some(fields), false).unwrap();
+        assert_eq!(verified.body_hash, compute_synapse_body_hash(body, fields));
+
+        let err = verify_request_with(
+            &headers,
+            br#"{"query": "pong"}"#,
+            &alice,
+            Some(fields),
+            false,
+        )
+        .unwrap_err();
+        assert!(matches!(err, RequestRejection::BodyHashMismatch { .. }));
+        assert_eq!(err.status(), (StatusCode::BAD_REQUEST, 400));
+        assert_eq!(err.message(), status_messages::BODY_HASH_MISMATCH);
+
+        // Fields outside the hash may change, as with the Python SDK
+        let body_with_extra = br#"{"query": "ping", "response": "pong"}"#;
+        assert!(
+            verify_request_with(&headers, body_with_extra, &alice, Some(fields), false).is_ok()
+        );
+
+        // A matching hash does not make up for a bad signature
+        let err = verify_request_with(
+            &headers,
+            body,
+            "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty",
+            Some(fields),
+            false,
+        )
+        .unwrap_err();
+        assert!(matches!(err, RequestRejection::Unauthorized(_)));
+        assert_eq!(err.status().0, StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn test_verify_request_uses_listed_hash_fields() {
+        // Python dendrites list the synapse's hash fields in the body
+        let body = br#"{"query": "ping", "required_hash_fields": ["query"]}"#;
+        let (headers, alice) = signed_headers(&compute_synapse_body_hash(body, &["query"]));
+        assert!(verify_request(&headers, body, &alice).is_ok());
+
+        let altered = br#"{"query": "pong", "required_hash_fields": ["query"]}"#;
+        assert!(matches!(
+            verify_request(&headers, altered, &alice),
+            Err(RequestRejection::BodyHashMismatch { .. })
+        ));
+
+        // A route's own fields take precedence over the body's list
+        assert!(matches!(
+            verify_request_with(&headers, body, &alice, Some(&[]), false),
+            Err(RequestRejection::BodyHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_request_empty_body_hash() {
+        let body = br#"{"query": "ping"}"#;
+        let (mut headers, alice) = signed_headers("");
+        headers.remove(header_names::BODY_HASH);
+
+        assert!(matches!(
+            verify_request(&headers, body, &alice),
+            Err(RequestRejection::BodyHashMismatch { .. })
+        ));
+        assert!(verify_request_with(&headers, body, &alice, None, true).is_ok());
+
+        // The signature still has to cover the empty hash
+        let (mut headers, _) = signed_headers(&compute_synapse_body_hash(body, &[]));
+        headers.remove(header_names::BODY_HASH);
+        assert!(matches!(
+            verify_request_with(&headers, body, &alice, None, true),
+            Err(RequestRejection::Unauthorized(_))
+        ));
+    }
 }
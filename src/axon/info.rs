@@ -3,6 +3,11 @@
 //! This module provides configuration structures for the Axon HTTP server
 //! and re-exports the on-chain AxonInfo type.
 
+use crate::axon::compression::DEFAULT_MAX_BODY_BYTES;
+use crate::axon::gate::MetagraphGate;
+use crate::axon::handlers::DEFAULT_DEADLINE_MARGIN_MS;
+use crate::axon::rate_limit::RateLimit;
+use crate::axon::replay::{DEFAULT_NONCE_CACHE_SIZE, DEFAULT_REPLAY_WINDOW_SECS};
 use serde::{Deserialize, Serialize};
 
 /// Re-export the on-chain AxonInfo type
@@ -11,7 +16,7 @@ pub use crate::types::axon::AxonInfo;
 /// Axon server configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AxonConfig {
-    /// The port to listen on
+    /// The port to listen on (0 binds an ephemeral port)
     pub port: u16,
     /// The IP address to bind to (default: "0.0.0.0")
     pub ip: String,
@@ -25,12 +30,66 @@ pub struct AxonConfig {
     pub max_concurrent_requests: usize,
     /// Default request timeout in seconds
     pub default_timeout_secs: u64,
+    /// Seconds to let in-flight requests finish after shutdown is requested
+    #[serde(default = "default_shutdown_timeout_secs")]
+    pub shutdown_timeout_secs: u64,
     /// Whether to verify request signatures
     pub verify_signatures: bool,
     /// Whether to trust X-Forwarded-For and X-Real-IP headers.
     /// Only enable this when running behind a trusted reverse proxy.
     /// When disabled (default), only the direct connection IP is used for IP blacklisting.
     pub trust_proxy_headers: bool,
+    /// Default per-hotkey rate limit (None disables rate limiting unless a
+    /// custom rate limit function is set)
+    #[serde(default)]
+    pub rate_limit: Option<RateLimit>,
+    /// Registration and stake requirements for callers (None disables the check)
+    #[serde(default)]
+    pub metagraph_gate: Option<MetagraphGate>,
+    /// Largest request body accepted, before and after decompression
+    #[serde(default = "default_max_body_bytes")]
+    pub max_body_bytes: usize,
+    /// Whether to compress responses for callers that accept gzip or zstd
+    #[serde(default = "default_compression")]
+    pub compression: bool,
+    /// Seconds a signed request's nonce may differ from the axon's clock
+    /// (0 disables replay protection)
+    #[serde(default = "default_replay_window_secs")]
+    pub replay_window_secs: u64,
+    /// Nonces remembered per caller hotkey for replay detection
+    #[serde(default = "default_nonce_cache_size")]
+    pub nonce_cache_size: usize,
+    /// Milliseconds taken off each request's timeout to leave time for the
+    /// response to reach the caller
+    #[serde(default = "default_deadline_margin_ms")]
+    pub deadline_margin_ms: u64,
+    /// Whether signed requests may leave the body hash header empty
+    #[serde(default)]
+    pub allow_empty_body_hash: bool,
+}
+
+fn default_shutdown_timeout_secs() -> u64 {
+    30
+}
+
+fn default_max_body_bytes() -> usize {
+    DEFAULT_MAX_BODY_BYTES
+}
+
+fn default_compression() -> bool {
+    true
+}
+
+fn default_replay_window_secs() -> u64 {
+    DEFAULT_REPLAY_WINDOW_SECS
+}
+
+fn default_nonce_cache_size() -> usize {
+    DEFAULT_NONCE_CACHE_SIZE
+}
+
+fn default_deadline_margin_ms() -> u64 {
+    DEFAULT_DEADLINE_MARGIN_MS
 }
 
 impl Default for AxonConfig {
@@ -43,8 +102,17 @@ impl Default for AxonConfig {
             max_workers: 10,
             max_concurrent_requests: 256,
             default_timeout_secs: 12,
+            shutdown_timeout_secs: default_shutdown_timeout_secs(),
             verify_signatures: true,
             trust_proxy_headers: false,
+            rate_limit: None,
+            metagraph_gate: None,
+            max_body_bytes: default_max_body_bytes(),
+            compression: default_compression(),
+            replay_window_secs: default_replay_window_secs(),
+            nonce_cache_size: default_nonce_cache_size(),
+            deadline_margin_ms: default_deadline_margin_ms(),
+            allow_empty_body_hash: false,
         }
     }
 }
@@ -97,6 +165,12 @@ impl AxonConfig {
         self
     }
 
+    /// Set how long shutdown waits for in-flight requests
+    pub fn with_shutdown_timeout(mut self, timeout_secs: u64) -> Self {
+        self.shutdown_timeout_secs = timeout_secs;
+        self
+    }
+
     /// Enable or disable signature verification
     pub fn with_signature_verification(mut self, enabled: bool) -> Self {
         self.verify_signatures = enabled;
@@ -111,6 +185,69 @@ impl AxonConfig {
         self
     }
 
+    /// Set the default per-hotkey rate limit
+    ///
+    /// Each caller hotkey may make `burst` requests at once, refilling at
+    /// `rate_per_sec` requests per second.
+    pub fn with_rate_limit(mut self, rate_per_sec: f64, burst: u32) -> Self {
+        self.rate_limit = Some(RateLimit::new(rate_per_sec, burst));
+        self
+    }
+
+    /// Require callers to be registered, with a minimum stake
+    ///
+    /// Requires a metagraph to be set on the axon, see
+    /// `Axon::spawn_metagraph_refresh`.
+    pub fn with_metagraph_gate(mut self, gate: MetagraphGate) -> Self {
+        self.metagraph_gate = Some(gate);
+        self
+    }
+
+    /// Set the largest request body accepted
+    ///
+    /// Larger bodies, or compressed bodies that inflate past the limit, are
+    /// rejected with 413.
+    pub fn with_max_body_bytes(mut self, max: usize) -> Self {
+        self.max_body_bytes = max;
+        self
+    }
+
+    /// Enable or disable response compression
+    pub fn with_compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Set the replay window and how many nonces are remembered per caller
+    ///
+    /// Signed requests whose nonce timestamp is more than `window_secs` away
+    /// from the axon's clock, or whose nonce was already used, are rejected
+    /// with 401. A window of 0 disables the check.
+    pub fn with_replay_protection(mut self, window_secs: u64, nonce_cache_size: usize) -> Self {
+        self.replay_window_secs = window_secs;
+        self.nonce_cache_size = nonce_cache_size;
+        self
+    }
+
+    /// Set the margin taken off each request's timeout
+    ///
+    /// Handlers are cancelled with a 408 once the request's timeout, less
+    /// this margin, has passed; see `HandlerContext::remaining`.
+    pub fn with_deadline_margin(mut self, margin_ms: u64) -> Self {
+        self.deadline_margin_ms = margin_ms;
+        self
+    }
+
+    /// Accept signed requests with an empty body hash header
+    ///
+    /// Signed requests whose body does not hash to the header are rejected
+    /// with 400. Enable this for subnets whose dendrites send no hash; a
+    /// non-empty header must still match.
+    pub fn with_empty_body_hash_allowed(mut self, allowed: bool) -> Self {
+        self.allow_empty_body_hash = allowed;
+        self
+    }
+
     /// Get the socket address string for binding
     pub fn socket_addr(&self) -> String {
         format!("{}:{}", self.ip, self.port)
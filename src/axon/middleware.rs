@@ -2,18 +2,26 @@
 //!
 //! This module provides middleware functions for request processing including:
 //! - Blacklist checking
+//! - Registration and stake gating
 //! - Priority queuing
 //! - Signature verification
+//! - Per-hotkey rate limiting
 //! - Request logging
 
-use crate::axon::handlers::{build_error_response, status_codes, status_messages};
+pub use crate::axon::blacklist::{BanKind, BlacklistEntry, BlacklistStore};
+use crate::axon::gate::GateRejection;
+use crate::axon::handlers::{build_error_response, status_codes, status_messages, HandlerContext};
+use crate::axon::rate_limit::retry_after_secs;
 use crate::axon::server::AxonState;
 use crate::dendrite::request::header_names;
+use crate::metagraph::Metagraph;
 use axum::body::Body;
-use axum::extract::State;
-use axum::http::{Request, StatusCode};
+use axum::extract::{ConnectInfo, State};
+use axum::http::{header, HeaderValue, Request, StatusCode};
 use axum::middleware::Next;
 use axum::response::Response;
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::RwLock;
@@ -21,8 +29,9 @@ use tracing::{debug, info, warn};
 
 /// Blacklist middleware - reject requests from blacklisted hotkeys
 ///
-/// Checks if the dendrite's hotkey is in the blacklist and rejects
-/// the request with a 403 Forbidden status if so.
+/// Checks the dendrite's hotkey and the client IP against the static
+/// blacklists, the [`BlacklistStore`] and the custom blacklist function, and
+/// rejects the request with a 403 Forbidden status if any of them match.
 pub async fn blacklist_middleware(
     State(state): State<Arc<RwLock<AxonState>>>,
     req: Request<Body>,
@@ -53,16 +62,21 @@ pub async fn blacklist_middleware(
                 s.split(',').next().map(|ip| ip.trim().to_string())
             })
     } else {
-        // When trust_proxy_headers is disabled, we don't use proxy headers.
-        // The actual client IP would be obtained from the connection itself,
-        // but that's not available in this middleware context without ConnectInfo.
-        // For now, return None to avoid trusting spoofable headers.
-        None
+        // Use the peer address of the connection, set when serving with
+        // connect info; spoofable proxy headers are ignored.
+        req.extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(peer)| peer.ip().to_string())
     };
 
     // Check hotkey blacklist
     if let Some(ref hotkey) = dendrite_hotkey {
-        if state_read.blacklist.contains(hotkey) {
+        let banned = state_read.blacklist.contains(hotkey)
+            || state_read
+                .blacklist_store
+                .as_ref()
+                .is_some_and(|store| store.is_hotkey_banned(hotkey));
+        if banned {
             warn!("Blocked blacklisted hotkey: {}", hotkey);
             let process_time = start_time.elapsed().as_secs_f64();
             return build_error_response(
@@ -77,7 +91,12 @@ pub async fn blacklist_middleware(
 
     // Check IP blacklist
     if let Some(ref ip) = client_ip {
-        if state_read.ip_blacklist.contains(ip) {
+        let banned = state_read.ip_blacklist.contains(ip)
+            || state_read
+                .blacklist_store
+                .as_ref()
+                .is_some_and(|store| ip.parse::<IpAddr>().is_ok_and(|ip| store.is_ip_banned(&ip)));
+        if banned {
             warn!("Blocked blacklisted IP: {}", ip);
             let process_time = start_time.elapsed().as_secs_f64();
             return build_error_response(
@@ -120,6 +139,80 @@ pub async fn blacklist_middleware(
     next.run(req).await
 }
 
+/// Metagraph gate middleware - reject unregistered or under-staked callers
+///
+/// If a metagraph gate is configured, checks the dendrite's hotkey against
+/// the axon's metagraph. Unregistered callers are rejected with a 401 and
+/// callers below the minimum stake with a 403; the `SynapseUnauthorized` or
+/// `SynapseBlacklisted` error is attached to the response extensions. A stale
+/// or missing metagraph fails open or closed per the gate configuration.
+pub async fn metagraph_gate_middleware(
+    State(state): State<Arc<RwLock<AxonState>>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let start_time = Instant::now();
+
+    let (gate, metagraph, chain_block, axon_hotkey) = {
+        let state_read = state.read().await;
+        match state_read.metagraph_gate {
+            Some(gate) => (
+                gate,
+                state_read.metagraph.clone(),
+                state_read.chain_block,
+                state_read.axon_hotkey.clone(),
+            ),
+            None => {
+                drop(state_read);
+                return next.run(req).await;
+            }
+        }
+    };
+
+    let dendrite_hotkey = req
+        .headers()
+        .get(header_names::DENDRITE_HOTKEY)
+        .and_then(|v| v.to_str().ok());
+
+    let result = match metagraph {
+        Some(metagraph) => gate.check(&*metagraph.read().await, dendrite_hotkey, chain_block),
+        None => gate.check(&Metagraph::new(gate.netuid), dendrite_hotkey, chain_block),
+    };
+
+    let rejection = match result {
+        Ok(()) => return next.run(req).await,
+        Err(rejection) => rejection,
+    };
+
+    let process_time = start_time.elapsed().as_secs_f64();
+    match rejection {
+        GateRejection::Unauthorized(e) => {
+            warn!("Rejected caller: {}", e);
+            let mut response = build_error_response(
+                &axon_hotkey,
+                StatusCode::UNAUTHORIZED,
+                status_codes::UNAUTHORIZED,
+                &e.message,
+                process_time,
+            );
+            response.extensions_mut().insert(e);
+            response
+        }
+        GateRejection::Blacklisted(e) => {
+            warn!("Rejected caller: {}", e);
+            let mut response = build_error_response(
+                &axon_hotkey,
+                StatusCode::FORBIDDEN,
+                status_codes::FORBIDDEN,
+                &e.message,
+                process_time,
+            );
+            response.extensions_mut().insert(e);
+            response
+        }
+    }
+}
+
 /// Priority middleware - track request priority
 ///
 /// Extracts the priority for this request based on the dendrite's hotkey
@@ -253,68 +346,190 @@ pub async fn logging_middleware(req: Request<Body>, next: Next) -> Response {
         method, uri, synapse_name, dendrite_hotkey, status, process_time
     );
 
+    // Label by route rather than the caller-supplied name header so
+    // unrouted paths cannot grow the label set
+    #[cfg(feature = "metrics")]
+    {
+        let route = match status {
+            StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED => "unknown",
+            _ => uri.path().trim_start_matches('/'),
+        };
+        crate::metrics::global().observe_axon_request(route, status.as_u16(), start_time.elapsed());
+    }
+
     response
 }
 
+/// Key used to rate limit requests that carry no dendrite hotkey
+const ANONYMOUS_CALLER: &str = "anonymous";
+
+/// Rate limit middleware - throttle callers per hotkey
+///
+/// Applies a token bucket per dendrite hotkey. The limit comes from the
+/// custom rate limit function if one is set (with the caller's stake looked
+/// up in the axon's metagraph), otherwise from the default rate limit. With
+/// neither configured, all requests pass through. Callers over their limit
+/// are rejected with a 429 and a `Retry-After` header.
+pub async fn rate_limit_middleware(
+    State(state): State<Arc<RwLock<AxonState>>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let start_time = Instant::now();
+
+    let dendrite_hotkey = req
+        .headers()
+        .get(header_names::DENDRITE_HOTKEY)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or(ANONYMOUS_CALLER)
+        .to_string();
+
+    let (rate_limit_fn, default_rate_limit, metagraph) = {
+        let state_read = state.read().await;
+        (
+            state_read.rate_limit_fn.clone(),
+            state_read.default_rate_limit,
+            state_read.metagraph.clone(),
+        )
+    };
+
+    let limit = match rate_limit_fn {
+        Some(rate_limit_fn) => {
+            let stake = match (metagraph, AccountId32::from_ss58check(&dendrite_hotkey)) {
+                (Some(metagraph), Ok(account)) => metagraph
+                    .read()
+                    .await
+                    .get_neuron_by_hotkey(&account)
+                    .map(|neuron| neuron.stake),
+                _ => None,
+            };
+            rate_limit_fn(&dendrite_hotkey, stake)
+        }
+        None => match default_rate_limit {
+            Some(limit) => limit,
+            None => return next.run(req).await,
+        },
+    };
+
+    let mut state_write = state.write().await;
+    let result = state_write
+        .rate_limiter
+        .check(&dendrite_hotkey, &limit, start_time);
+
+    if let Err(retry_after) = result {
+        debug!(
+            "Rate limited hotkey {} (retry after {:.3}s)",
+            dendrite_hotkey,
+            retry_after.as_secs_f64()
+        );
+        let process_time = start_time.elapsed().as_secs_f64();
+        let mut response = build_error_response(
+            &state_write.axon_hotkey,
+            StatusCode::TOO_MANY_REQUESTS,
+            status_codes::TOO_MANY_REQUESTS,
+            status_messages::TOO_MANY_REQUESTS,
+            process_time,
+        );
+        response.headers_mut().insert(
+            header::RETRY_AFTER,
+            HeaderValue::from(retry_after_secs(retry_after)),
+        );
+        return response;
+    }
+
+    drop(state_write);
+    next.run(req).await
+}
+
 /// Request counter middleware - track request counts
 ///
-/// Increments the request counter in the axon state.
+/// Increments the request counter in the axon state, and counts requests
+/// rejected by the rate limiter per caller hotkey.
 pub async fn counter_middleware(
     State(state): State<Arc<RwLock<AxonState>>>,
     req: Request<Body>,
     next: Next,
 ) -> Response {
+    let dendrite_hotkey = req
+        .headers()
+        .get(header_names::DENDRITE_HOTKEY)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or(ANONYMOUS_CALLER)
+        .to_string();
+
     // Increment request counter
     {
         let mut state_write = state.write().await;
         state_write.request_count += 1;
         state_write.total_requests += 1;
     }
+    let active = ActiveRequest(state.clone());
 
     let response = next.run(req).await;
+    drop(active);
 
-    // Decrement active request counter
-    {
-        let mut state_write = state.write().await;
-        state_write.request_count = state_write.request_count.saturating_sub(1);
+    if response.status() == StatusCode::TOO_MANY_REQUESTS {
+        state.write().await.record_throttled(dendrite_hotkey);
     }
 
     response
 }
 
-/// Timeout middleware - enforce request timeouts
+/// Decrements the active request count when a request ends
+///
+/// Requests cancelled at their deadline, or dropped when the client
+/// disconnects, never return to `counter_middleware`; the guard still runs.
+struct ActiveRequest(Arc<RwLock<AxonState>>);
+
+impl Drop for ActiveRequest {
+    fn drop(&mut self) {
+        if let Ok(mut state_write) = self.0.try_write() {
+            state_write.request_count = state_write.request_count.saturating_sub(1);
+            return;
+        }
+        let state = self.0.clone();
+        tokio::spawn(async move {
+            let mut state_write = state.write().await;
+            state_write.request_count = state_write.request_count.saturating_sub(1);
+        });
+    }
+}
+
+/// Timeout middleware - enforce request deadlines
 ///
-/// Extracts the timeout from request headers and enforces it.
-/// Uses the default timeout if not specified.
+/// Derives the request deadline from the timeout header (or the default
+/// timeout) less the configured margin, and passes it to the handler as a
+/// [`HandlerContext`] request extension. If the handler has not responded by
+/// the deadline its future is dropped, cancelling it, and a 408 is returned.
 pub async fn timeout_middleware(
     State(state): State<Arc<RwLock<AxonState>>>,
-    req: Request<Body>,
+    mut req: Request<Body>,
     next: Next,
 ) -> Response {
-    let start_time = Instant::now();
-
-    // Extract timeout from headers or use default
-    let timeout_secs = req
-        .headers()
-        .get(header_names::TIMEOUT)
-        .and_then(|v| v.to_str().ok())
-        .and_then(|s| s.parse::<f64>().ok())
-        .unwrap_or(12.0);
-
-    // Clamp timeout to reasonable bounds: min 1 second, max 5 minutes
-    let timeout_secs = timeout_secs.clamp(1.0, 300.0);
-
-    let timeout_duration = std::time::Duration::from_secs_f64(timeout_secs);
+    let ctx = {
+        let state_read = state.read().await;
+        HandlerContext::from_headers(
+            state_read.axon_hotkey.clone(),
+            req.headers(),
+            state_read.deadline_margin,
+        )
+    };
+    let start_time = ctx.start_time;
+    let deadline = tokio::time::Instant::from_std(ctx.deadline);
+    req.extensions_mut().insert(ctx);
 
     // Create a future that completes when the request is done or times out
     let response_future = next.run(req);
 
-    match tokio::time::timeout(timeout_duration, response_future).await {
+    match tokio::time::timeout_at(deadline, response_future).await {
         Ok(response) => response,
         Err(_) => {
             let state_read = state.read().await;
             let process_time = start_time.elapsed().as_secs_f64();
-            warn!("Request timed out after {:.3}s", process_time);
+            warn!(
+                "Request cancelled at its deadline after {:.3}s",
+                process_time
+            );
             build_error_response(
                 &state_read.axon_hotkey,
                 StatusCode::REQUEST_TIMEOUT,
@@ -329,6 +544,10 @@ pub async fn timeout_middleware(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::axon::rate_limit::{RateLimit, RateLimiter};
+    use axum::middleware as axum_middleware;
+    use axum::routing::post;
+    use axum::{Extension, Router};
     use std::collections::{HashMap, HashSet};
 
     fn create_test_state() -> Arc<RwLock<AxonState>> {
@@ -344,6 +563,15 @@ mod tests {
             blacklist_fn: None,
             priority_fn: None,
             verify_fn: None,
+            default_rate_limit: None,
+            rate_limit_fn: None,
+            rate_limiter: RateLimiter::new(),
+            metagraph: None,
+            metagraph_gate: None,
+            chain_block: 0,
+            throttled_requests: 0,
+            throttle_counts: HashMap::new(),
+            ..Default::default()
         }))
     }
 
@@ -406,4 +634,148 @@ mod tests {
         assert_eq!(state_read.priority_list.get("low_priority"), Some(&0.1));
         assert_eq!(state_read.priority_list.get("unknown"), None);
     }
+
+    #[tokio::test]
+    async fn test_rate_limit_middleware_throttles_hotkey() {
+        let state = create_test_state();
+        state.write().await.default_rate_limit = Some(RateLimit::new(0.001, 2));
+
+        let router = Router::new()
+            .route("/Query", post(|| async { "OK" }))
+            .layer(axum_middleware::from_fn_with_state(
+                state.clone(),
+                rate_limit_middleware,
+            ))
+            .layer(axum_middleware::from_fn_with_state(
+                state.clone(),
+                counter_middleware,
+            ));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/Query", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        let client = reqwest::Client::new();
+        let send = |hotkey: &'static str| {
+            client
+                .post(&url)
+                .header(header_names::DENDRITE_HOTKEY, hotkey)
+                .send()
+        };
+
+        for _ in 0..2 {
+            let response = send("caller").await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = send("caller").await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(response.headers().contains_key(header::RETRY_AFTER));
+        assert_eq!(
+            response
+                .headers()
+                .get(header_names::AXON_STATUS_CODE)
+                .unwrap(),
+            "429"
+        );
+
+        // Other callers have their own bucket
+        let response = send("other").await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let state_read = state.read().await;
+        assert_eq!(state_read.throttled_requests, 1);
+        assert_eq!(state_read.throttle_counts.get("caller"), Some(&1));
+        assert_eq!(state_read.throttle_counts.get("other"), None);
+    }
+
+    /// Sets its flag when dropped, i.e. when the handler future is cancelled
+    struct DropFlag(Arc<std::sync::atomic::AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_timeout_middleware_cancels_handler_at_deadline() {
+        let state = create_test_state();
+        state.write().await.deadline_margin = std::time::Duration::from_millis(200);
+        let dropped = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let handler_dropped = dropped.clone();
+
+        let router = Router::new()
+            .route(
+                "/Slow",
+                post(move |Extension(ctx): Extension<HandlerContext>| {
+                    let guard = DropFlag(handler_dropped.clone());
+                    async move {
+                        tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+                        drop(guard);
+                        format!("{:?}", ctx.remaining())
+                    }
+                }),
+            )
+            .route(
+                "/Remaining",
+                post(|Extension(ctx): Extension<HandlerContext>| async move {
+                    ctx.remaining().as_millis().to_string()
+                }),
+            )
+            .layer(axum_middleware::from_fn_with_state(
+                state.clone(),
+                counter_middleware,
+            ))
+            .layer(axum_middleware::from_fn_with_state(
+                state.clone(),
+                timeout_middleware,
+            ));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        let client = reqwest::Client::new();
+
+        // The handler sees the caller's timeout less the margin
+        let remaining: u128 = client
+            .post(format!("http://{}/Remaining", addr))
+            .header(header_names::TIMEOUT, "2.0")
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(remaining <= 1_800 && remaining > 1_000, "{}", remaining);
+
+        let start = Instant::now();
+        let response = client
+            .post(format!("http://{}/Slow", addr))
+            .header(header_names::TIMEOUT, "1.0")
+            .send()
+            .await
+            .unwrap();
+        let elapsed = start.elapsed();
+
+        assert_eq!(response.status(), StatusCode::REQUEST_TIMEOUT);
+        assert_eq!(
+            response
+                .headers()
+                .get(header_names::AXON_STATUS_CODE)
+                .unwrap(),
+            "408"
+        );
+        assert!(elapsed >= std::time::Duration::from_millis(800));
+        assert!(elapsed < std::time::Duration::from_secs(2), "{:?}", elapsed);
+
+        // The handler was dropped, not left running, and is no longer counted
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        let state_read = state.read().await;
+        assert_eq!(state_read.request_count, 0);
+        assert_eq!(state_read.total_requests, 2);
+    }
 }
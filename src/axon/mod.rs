@@ -3,7 +3,7 @@
 //! The Axon is an HTTP server that receives requests from Dendrites in the
 //! Bittensor network. It handles:
 //!
-//! - Request signature verification
+//! - Request signature and body hash verification
 //! - Blacklist/whitelist enforcement
 //! - Priority-based request handling
 //! - Custom synapse handlers
@@ -47,8 +47,11 @@
 //!         1.0
 //!     });
 //!
-//!     // Start serving
-//!     axon.serve().await?;
+//!     // Start serving; the handle stops the server
+//!     let handle = axon.serve().await?;
+//!     tokio::signal::ctrl_c().await?;
+//!     handle.shutdown();
+//!     handle.await_terminated().await?;
 //!
 //!     Ok(())
 //! }
@@ -58,28 +61,65 @@
 //!
 //! The Axon uses the following middleware stack (in order):
 //!
-//! 1. **Logging** - Logs all incoming requests
-//! 2. **Blacklist** - Rejects blacklisted hotkeys/IPs
-//! 3. **Priority** - Assigns priority to requests
-//! 4. **Verify** - Verifies request signatures
-//! 5. **Timeout** - Enforces request timeouts
-//! 6. **Counter** - Tracks request counts
-//!
-//! Each synapse type has its own route handler registered via `attach()`.
+//! 1. **Compression** - Compresses responses per `Accept-Encoding`
+//! 2. **Logging** - Logs all incoming requests
+//! 3. **Body limit** - Rejects oversized bodies and decompresses requests
+//! 4. **Blacklist** - Rejects blacklisted hotkeys/IPs
+//! 5. **Metagraph gate** - Rejects unregistered or under-staked callers
+//! 6. **Priority** - Assigns priority to requests
+//! 7. **Verify** - Verifies request signatures and rejects replayed nonces
+//! 8. **Timeout** - Cancels handlers at the request deadline with a 408
+//! 9. **Counter** - Tracks request counts and per-hotkey throttle counts
+//! 10. **Rate limit** - Throttles each caller hotkey with a token bucket
+//!
+//! Each synapse type has its own route handler registered via `attach()`, or
+//! via `attach_streaming()` for handlers that stream their response in chunks.
+//! The `*_with_context` variants also pass a [`HandlerContext`] whose
+//! `remaining()` is the time left before the request deadline.
+//! `serve_admin(token)` adds local-only operator routes such as
+//! `PUT /admin/log_level`, which also require the token as a bearer token.
+//! Hotkey and IP bans kept in a [`BlacklistStore`] (set with
+//! `set_blacklist_store()`) can be listed and changed through
+//! `GET/POST /admin/blacklist` while the axon runs, and optionally persist
+//! to a JSON file.
+//!
+//! `serve_https()` serves over TLS with an [`AxonTlsConfig`], typically a
+//! self-signed certificate whose public key is published on chain with
+//! `serve_axon_tls` for dendrites to pin.
 
+pub mod admin;
+pub mod blacklist;
+pub mod compression;
+pub mod gate;
 pub mod handlers;
 pub mod info;
 pub mod middleware;
+pub mod rate_limit;
+pub mod replay;
 pub mod server;
+pub mod streaming;
+pub mod tls;
 
+pub use blacklist::{BanKind, BlacklistEntry, BlacklistStore};
+pub use compression::{body_limit_middleware, compression_layer, ContentEncoding};
+pub use gate::{GateRejection, MetagraphGate};
 pub use handlers::{
     build_error_response, build_response_headers, build_success_response, compute_body_hash,
-    extract_synapse, status_codes, status_messages, verify_request, verify_signature,
-    HandlerContext, VerifiedRequest, AXON_VERSION,
+    compute_synapse_body_hash, extract_synapse, status_codes, status_messages, verify_request,
+    verify_request_with, verify_signature, HandlerContext, RequestRejection, VerifiedRequest,
+    AXON_VERSION, DEFAULT_DEADLINE_MARGIN_MS, DEFAULT_REQUEST_TIMEOUT_SECS,
 };
 pub use info::{AxonConfig, AxonInfo};
 pub use middleware::{
-    blacklist_middleware, counter_middleware, logging_middleware, priority_middleware,
-    timeout_middleware, verify_middleware, RequestPriority,
+    blacklist_middleware, counter_middleware, logging_middleware, metagraph_gate_middleware,
+    priority_middleware, rate_limit_middleware, timeout_middleware, verify_middleware,
+    RequestPriority,
+};
+pub use rate_limit::{RateLimit, RateLimiter, TokenBucket};
+pub use replay::{ReplayGuard, ReplayRejection};
+pub use server::{
+    Axon, AxonHandle, AxonState, BlacklistFn, PriorityFn, RateLimitFn, StreamingSynapseHandler,
+    SynapseHandler, VerifyFn,
 };
-pub use server::{Axon, AxonState, BlacklistFn, PriorityFn, SynapseHandler, VerifyFn};
+pub use streaming::{build_streaming_response, deadline_error_chunk, ChunkStream, StreamingBody};
+pub use tls::{generate_certificate, AxonTlsConfig};
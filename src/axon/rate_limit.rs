@@ -0,0 +1,253 @@
+//! Per-hotkey rate limiting for the Axon
+//!
+//! Each caller hotkey gets its own token bucket. Buckets refill continuously
+//! at the configured rate and hold at most `burst` tokens; every request takes
+//! one token. The limit applied to a caller can be overridden per hotkey, e.g.
+//! to scale it by the caller's stake in the metagraph.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Number of tracked hotkeys above which idle buckets are pruned
+const MAX_TRACKED_HOTKEYS: usize = 10_000;
+
+/// Retry delay reported when a limit never refills
+const NO_REFILL_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Rate limit applied to a single caller
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// Sustained requests per second
+    pub rate_per_sec: f64,
+    /// Maximum number of requests that can be made in a burst
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// Create a new rate limit
+    ///
+    /// # Arguments
+    ///
+    /// * `rate_per_sec` - Sustained requests per second
+    /// * `burst` - Maximum burst size
+    pub fn new(rate_per_sec: f64, burst: u32) -> Self {
+        Self {
+            rate_per_sec,
+            burst,
+        }
+    }
+
+    /// Scale both the rate and the burst by a factor
+    pub fn scaled(&self, factor: f64) -> Self {
+        let factor = if factor.is_finite() {
+            factor.max(0.0)
+        } else {
+            1.0
+        };
+        Self {
+            rate_per_sec: self.rate_per_sec * factor,
+            burst: (self.burst as f64 * factor).round() as u32,
+        }
+    }
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self::new(10.0, 20)
+    }
+}
+
+/// Token bucket for a single caller
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    /// Tokens currently available
+    tokens: f64,
+    /// When the bucket was last refilled
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Create a full bucket for the given limit
+    pub fn new(limit: &RateLimit, now: Instant) -> Self {
+        Self {
+            tokens: limit.burst as f64,
+            last_refill: now,
+        }
+    }
+
+    /// Refill the bucket for the time elapsed since the last refill
+    fn refill(&mut self, limit: &RateLimit, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        if limit.rate_per_sec > 0.0 {
+            self.tokens += elapsed * limit.rate_per_sec;
+        }
+        self.tokens = self.tokens.min(limit.burst as f64);
+        self.last_refill = now;
+    }
+
+    /// Take a token from the bucket
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if a token was available, or `Err(retry_after)` with the time
+    /// until the next token becomes available
+    pub fn try_acquire(&mut self, limit: &RateLimit, now: Instant) -> Result<(), Duration> {
+        self.refill(limit, now);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+
+        if limit.rate_per_sec > 0.0 && limit.burst > 0 {
+            let wait = (1.0 - self.tokens) / limit.rate_per_sec;
+            Err(Duration::from_secs_f64(wait))
+        } else {
+            Err(NO_REFILL_RETRY_AFTER)
+        }
+    }
+
+    /// Check whether the bucket has refilled completely
+    fn is_full(&self, limit: &RateLimit) -> bool {
+        self.tokens >= limit.burst as f64
+    }
+}
+
+/// Token buckets for every caller seen by the axon
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    /// Buckets keyed by caller hotkey
+    buckets: HashMap<String, TokenBucket>,
+}
+
+impl RateLimiter {
+    /// Create an empty rate limiter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check a request from `hotkey` against `limit`
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the request is allowed, or `Err(retry_after)` if the caller
+    /// is over its limit
+    pub fn check(&mut self, hotkey: &str, limit: &RateLimit, now: Instant) -> Result<(), Duration> {
+        if !self.buckets.contains_key(hotkey) && self.buckets.len() >= MAX_TRACKED_HOTKEYS {
+            self.prune(limit, now);
+        }
+
+        self.buckets
+            .entry(hotkey.to_string())
+            .or_insert_with(|| TokenBucket::new(limit, now))
+            .try_acquire(limit, now)
+    }
+
+    /// Drop buckets that have refilled completely, as they carry no state
+    fn prune(&mut self, limit: &RateLimit, now: Instant) {
+        self.buckets.retain(|_, bucket| {
+            bucket.refill(limit, now);
+            !bucket.is_full(limit)
+        });
+    }
+
+    /// Number of hotkeys currently tracked
+    pub fn tracked_hotkeys(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Whether `hotkey` still has a bucket, i.e. may still be throttled
+    pub fn is_tracked(&self, hotkey: &str) -> bool {
+        self.buckets.contains_key(hotkey)
+    }
+}
+
+/// Format a retry delay as a `Retry-After` header value in whole seconds
+pub fn retry_after_secs(retry_after: Duration) -> u64 {
+    retry_after.as_secs_f64().ceil().max(1.0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_allows_burst_then_throttles() {
+        let limit = RateLimit::new(2.0, 3);
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(&limit, now);
+
+        for _ in 0..3 {
+            assert!(bucket.try_acquire(&limit, now).is_ok());
+        }
+
+        let retry_after = bucket.try_acquire(&limit, now).unwrap_err();
+        assert_eq!(retry_after, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_bucket_refills_over_time() {
+        let limit = RateLimit::new(2.0, 2);
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(&limit, now);
+
+        assert!(bucket.try_acquire(&limit, now).is_ok());
+        assert!(bucket.try_acquire(&limit, now).is_ok());
+        assert!(bucket.try_acquire(&limit, now).is_err());
+
+        let later = now + Duration::from_millis(500);
+        assert!(bucket.try_acquire(&limit, later).is_ok());
+        assert!(bucket.try_acquire(&limit, later).is_err());
+
+        // Refill is capped at the burst size
+        let much_later = later + Duration::from_secs(60);
+        assert!(bucket.try_acquire(&limit, much_later).is_ok());
+        assert!(bucket.try_acquire(&limit, much_later).is_ok());
+        assert!(bucket.try_acquire(&limit, much_later).is_err());
+    }
+
+    #[test]
+    fn test_zero_rate_never_refills() {
+        let limit = RateLimit::new(0.0, 1);
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(&limit, now);
+
+        assert!(bucket.try_acquire(&limit, now).is_ok());
+        let later = now + Duration::from_secs(3600);
+        assert_eq!(
+            bucket.try_acquire(&limit, later),
+            Err(NO_REFILL_RETRY_AFTER)
+        );
+    }
+
+    #[test]
+    fn test_rate_limiter_is_per_hotkey() {
+        let limit = RateLimit::new(1.0, 1);
+        let now = Instant::now();
+        let mut limiter = RateLimiter::new();
+
+        assert!(limiter.check("alice", &limit, now).is_ok());
+        assert!(limiter.check("alice", &limit, now).is_err());
+        assert!(limiter.check("bob", &limit, now).is_ok());
+        assert_eq!(limiter.tracked_hotkeys(), 2);
+    }
+
+    #[test]
+    fn test_rate_limit_scaled() {
+        let limit = RateLimit::new(10.0, 20).scaled(2.5);
+        assert_eq!(limit, RateLimit::new(25.0, 50));
+
+        let limit = RateLimit::new(10.0, 20).scaled(f64::NAN);
+        assert_eq!(limit, RateLimit::new(10.0, 20));
+    }
+
+    #[test]
+    fn test_retry_after_secs() {
+        assert_eq!(retry_after_secs(Duration::from_millis(1)), 1);
+        assert_eq!(retry_after_secs(Duration::from_millis(1500)), 2);
+        assert_eq!(retry_after_secs(Duration::from_secs(3)), 3);
+    }
+}
@@ -0,0 +1,324 @@
+//! Replay protection for signed requests
+//!
+//! Dendrites sign a nonce that is the send time in nanoseconds since the
+//! Unix epoch, the same scheme the Python SDK uses (`time.time_ns()`), so the
+//! nonce doubles as the request timestamp without changing the headers or
+//! the signed message. [`ReplayGuard`] rejects a request whose nonce lies
+//! outside the replay window around the axon's clock, or that was already
+//! seen from the same hotkey within the window.
+//!
+//! Nonces are remembered per hotkey up to a fixed count. When a hotkey's set
+//! overflows, its oldest nonce is dropped and every nonce at or below it is
+//! rejected from then on, so eviction never reopens a replay. At most
+//! [`MAX_TRACKED_HOTKEYS`] unregistered hotkeys are tracked. A hotkey is only
+//! forgotten once its newest nonce has left the window, when any replay of
+//! its nonces is rejected as expired anyway; while the table is full of
+//! hotkeys with live nonces, requests from new unregistered hotkeys are
+//! refused. Hotkeys registered on the axon's subnet (see
+//! [`ReplayGuard::check_caller`]) do not count towards the limit, since the
+//! subnet size already bounds them, so throwaway hotkeys cannot lock out
+//! registered callers.
+
+use std::collections::{BTreeSet, HashMap};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Default replay window, matching the Python SDK's allowed clock delta
+pub const DEFAULT_REPLAY_WINDOW_SECS: u64 = 8;
+
+/// Default number of nonces remembered per hotkey
+pub const DEFAULT_NONCE_CACHE_SIZE: usize = 1024;
+
+/// Most unregistered hotkeys whose nonces are remembered at once
+pub const MAX_TRACKED_HOTKEYS: usize = 10_000;
+
+/// Why a signed request was rejected as a replay
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum ReplayRejection {
+    #[error("nonce already used")]
+    Duplicate,
+    #[error("nonce is older than the {0}s replay window")]
+    Expired(u64),
+    #[error("nonce is more than {0}s in the future")]
+    FromFuture(u64),
+    #[error("already tracking {0} callers within the replay window")]
+    TooManyCallers(usize),
+}
+
+#[derive(Debug, Default)]
+struct HotkeyNonces {
+    nonces: BTreeSet<u64>,
+    /// Highest nonce evicted for space; nonces at or below it are rejected
+    floor: u64,
+    /// Whether the hotkey was registered on the subnet when last seen
+    registered: bool,
+}
+
+/// Per-hotkey record of recently used nonces
+#[derive(Debug)]
+pub struct ReplayGuard {
+    window: Duration,
+    cache_size: usize,
+    max_hotkeys: usize,
+    hotkeys: HashMap<String, HotkeyNonces>,
+    /// Number of tracked hotkeys that are not registered
+    unregistered: usize,
+    /// Newest nonce of every tracked hotkey, oldest first
+    by_newest: BTreeSet<(u64, String)>,
+}
+
+impl ReplayGuard {
+    /// Accept nonces within `window` of the current time, remembering up to
+    /// `cache_size` of them per hotkey
+    pub fn new(window: Duration, cache_size: usize) -> Self {
+        Self {
+            window,
+            cache_size: cache_size.max(1),
+            max_hotkeys: MAX_TRACKED_HOTKEYS,
+            hotkeys: HashMap::new(),
+            unregistered: 0,
+            by_newest: BTreeSet::new(),
+        }
+    }
+
+    /// Check a verified request's nonce against the current time and record it
+    pub fn check(&mut self, hotkey: &str, nonce: u64) -> Result<(), ReplayRejection> {
+        self.check_caller(hotkey, nonce, false)
+    }
+
+    /// Check a verified request's nonce, saying whether the caller is
+    /// registered on the axon's subnet
+    ///
+    /// Registered callers are never refused with
+    /// [`ReplayRejection::TooManyCallers`].
+    pub fn check_caller(
+        &mut self,
+        hotkey: &str,
+        nonce: u64,
+        registered: bool,
+    ) -> Result<(), ReplayRejection> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        self.check_caller_at(hotkey, nonce, registered, now)
+    }
+
+    /// Check `nonce` as if the current time were `now_ns`
+    pub fn check_at(
+        &mut self,
+        hotkey: &str,
+        nonce: u64,
+        now_ns: u64,
+    ) -> Result<(), ReplayRejection> {
+        self.check_caller_at(hotkey, nonce, false, now_ns)
+    }
+
+    /// [`ReplayGuard::check_caller`] as if the current time were `now_ns`
+    pub fn check_caller_at(
+        &mut self,
+        hotkey: &str,
+        nonce: u64,
+        registered: bool,
+        now_ns: u64,
+    ) -> Result<(), ReplayRejection> {
+        let window_ns = self.window.as_nanos() as u64;
+        let window_secs = self.window.as_secs();
+        if nonce < now_ns.saturating_sub(window_ns) {
+            return Err(ReplayRejection::Expired(window_secs));
+        }
+        if nonce > now_ns.saturating_add(window_ns) {
+            return Err(ReplayRejection::FromFuture(window_secs));
+        }
+
+        // Nonces outside the window are rejected as expired, no need to keep them
+        let expired = now_ns.saturating_sub(window_ns);
+        self.forget_expired(expired);
+        if !registered && !self.hotkeys.contains_key(hotkey) {
+            if self.unregistered >= self.max_hotkeys {
+                return Err(ReplayRejection::TooManyCallers(self.max_hotkeys));
+            }
+            self.unregistered += 1;
+        }
+        let entry = self
+            .hotkeys
+            .entry(hotkey.to_string())
+            .or_insert_with(|| HotkeyNonces {
+                registered,
+                ..Default::default()
+            });
+        // Registration only moves a hotkey out of the limited table, so a
+        // deregistered caller keeps its slot until it is forgotten
+        if registered && !entry.registered {
+            entry.registered = true;
+            self.unregistered -= 1;
+        }
+
+        let newest = entry.nonces.last().copied();
+        if nonce <= entry.floor || !entry.nonces.insert(nonce) {
+            return Err(ReplayRejection::Duplicate);
+        }
+        if newest.is_none_or(|newest| nonce > newest) {
+            if let Some(newest) = newest {
+                self.by_newest.remove(&(newest, hotkey.to_string()));
+            }
+            self.by_newest.insert((nonce, hotkey.to_string()));
+        }
+
+        while entry.nonces.first().is_some_and(|&n| n < expired) {
+            entry.nonces.pop_first();
+        }
+        while entry.nonces.len() > self.cache_size {
+            if let Some(oldest) = entry.nonces.pop_first() {
+                entry.floor = entry.floor.max(oldest);
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of hotkeys currently tracked
+    pub fn tracked_hotkeys(&self) -> usize {
+        self.hotkeys.len()
+    }
+
+    /// Forget hotkeys whose newest nonce is below `expired`
+    fn forget_expired(&mut self, expired: u64) {
+        while let Some((newest, _)) = self.by_newest.first() {
+            if *newest >= expired {
+                break;
+            }
+            if let Some((_, hotkey)) = self.by_newest.pop_first() {
+                if self
+                    .hotkeys
+                    .remove(&hotkey)
+                    .is_some_and(|entry| !entry.registered)
+                {
+                    self.unregistered -= 1;
+                }
+            }
+        }
+    }
+}
+
+impl Default for ReplayGuard {
+    fn default() -> Self {
+        Self::new(
+            Duration::from_secs(DEFAULT_REPLAY_WINDOW_SECS),
+            DEFAULT_NONCE_CACHE_SIZE,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEC: u64 = 1_000_000_000;
+    const NOW: u64 = 1_700_000_000 * SEC;
+
+    #[test]
+    fn test_duplicates_rejected_per_hotkey() {
+        let mut guard = ReplayGuard::default();
+        assert_eq!(guard.check_at("alice", NOW, NOW), Ok(()));
+        assert_eq!(
+            guard.check_at("alice", NOW, NOW + SEC),
+            Err(ReplayRejection::Duplicate)
+        );
+        // Same nonce from another hotkey is a different request
+        assert_eq!(guard.check_at("bob", NOW, NOW), Ok(()));
+        // Out-of-order nonces within the window are fine
+        assert_eq!(guard.check_at("alice", NOW - SEC, NOW), Ok(()));
+    }
+
+    #[test]
+    fn test_window() {
+        let mut guard = ReplayGuard::default();
+        assert_eq!(
+            guard.check_at("alice", NOW - 9 * SEC, NOW),
+            Err(ReplayRejection::Expired(8))
+        );
+        assert_eq!(
+            guard.check_at("alice", NOW + 9 * SEC, NOW),
+            Err(ReplayRejection::FromFuture(8))
+        );
+        assert_eq!(guard.check_at("alice", NOW - 7 * SEC, NOW), Ok(()));
+    }
+
+    #[test]
+    fn test_overflow_raises_floor() {
+        let mut guard = ReplayGuard::new(Duration::from_secs(8), 2);
+        for nonce in [NOW + 1, NOW + 2, NOW + 3] {
+            assert_eq!(guard.check_at("alice", nonce, NOW), Ok(()));
+        }
+        // NOW + 1 was evicted but is still refused
+        assert_eq!(
+            guard.check_at("alice", NOW + 1, NOW),
+            Err(ReplayRejection::Duplicate)
+        );
+        assert_eq!(
+            guard.check_at("alice", NOW, NOW),
+            Err(ReplayRejection::Duplicate)
+        );
+        assert_eq!(guard.check_at("alice", NOW + 4, NOW), Ok(()));
+    }
+
+    #[test]
+    fn test_hotkeys_forgotten_only_once_expired() {
+        let mut guard = ReplayGuard {
+            max_hotkeys: 2,
+            ..Default::default()
+        };
+        assert_eq!(guard.check_at("alice", NOW, NOW), Ok(()));
+        assert_eq!(guard.check_at("bob", NOW + SEC, NOW), Ok(()));
+        // Forgetting alice now would let her nonce be replayed
+        assert_eq!(
+            guard.check_at("carol", NOW, NOW),
+            Err(ReplayRejection::TooManyCallers(2))
+        );
+        assert_eq!(
+            guard.check_at("alice", NOW, NOW + SEC),
+            Err(ReplayRejection::Duplicate)
+        );
+
+        // Once alice's newest nonce leaves the window she is forgotten
+        let later = NOW + 8 * SEC + 1;
+        assert_eq!(guard.check_at("carol", later, later), Ok(()));
+        assert_eq!(guard.tracked_hotkeys(), 2);
+        assert_eq!(
+            guard.check_at("alice", NOW, later),
+            Err(ReplayRejection::Expired(8))
+        );
+        assert_eq!(
+            guard.check_at("bob", NOW + SEC, later),
+            Err(ReplayRejection::Duplicate)
+        );
+    }
+
+    #[test]
+    fn test_full_table_does_not_lock_out_registered_callers() {
+        let mut guard = ReplayGuard::default();
+        for i in 0..MAX_TRACKED_HOTKEYS {
+            let hotkey = format!("throwaway{}", i);
+            assert_eq!(guard.check_at(&hotkey, NOW, NOW), Ok(()));
+        }
+        assert_eq!(
+            guard.check_at("carol", NOW, NOW),
+            Err(ReplayRejection::TooManyCallers(MAX_TRACKED_HOTKEYS))
+        );
+
+        // Registered callers get a slot and are still replay protected
+        assert_eq!(guard.check_caller_at("alice", NOW, true, NOW), Ok(()));
+        assert_eq!(
+            guard.check_caller_at("alice", NOW, true, NOW),
+            Err(ReplayRejection::Duplicate)
+        );
+        assert_eq!(guard.tracked_hotkeys(), MAX_TRACKED_HOTKEYS + 1);
+
+        // A tracked caller that registers frees its slot
+        assert_eq!(
+            guard.check_caller_at("throwaway0", NOW + 1, true, NOW),
+            Ok(())
+        );
+        assert_eq!(guard.check_at("carol", NOW, NOW), Ok(()));
+    }
+}
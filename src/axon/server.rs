@@ -4,39 +4,70 @@
 //! Bittensor network. It handles request verification, routing, and response
 //! generation.
 
+use crate::axon::blacklist::BlacklistStore;
+use crate::axon::compression::{body_limit_middleware, compression_layer, DEFAULT_MAX_BODY_BYTES};
+use crate::axon::gate::MetagraphGate;
 use crate::axon::handlers::{
-    build_error_response, build_success_response, extract_synapse, status_codes, verify_request,
-    AXON_VERSION,
+    build_error_response, build_success_response, extract_synapse, status_codes, status_messages,
+    verify_request_with, HandlerContext, RequestRejection, AXON_VERSION,
+    DEFAULT_DEADLINE_MARGIN_MS,
 };
 use crate::axon::info::{AxonConfig, AxonInfo};
 use crate::axon::middleware::{
-    blacklist_middleware, counter_middleware, logging_middleware, priority_middleware,
-    timeout_middleware, verify_middleware,
+    blacklist_middleware, counter_middleware, logging_middleware, metagraph_gate_middleware,
+    priority_middleware, rate_limit_middleware, timeout_middleware, verify_middleware,
 };
+use crate::axon::rate_limit::{RateLimit, RateLimiter};
+use crate::axon::replay::{ReplayGuard, ReplayRejection};
+use crate::axon::streaming::{build_streaming_response, ChunkStream};
+use crate::axon::tls::AxonTlsConfig;
+use crate::chain::BittensorClient;
 use crate::errors::{AxonConfigError, AxonError};
-use crate::types::Synapse;
+use crate::metagraph::{sync_metagraph_with, Metagraph};
+use crate::types::{Synapse, SynapseType, TerminalInfo, AXON_PROTOCOL_HTTP, AXON_PROTOCOL_HTTPS};
+use crate::utils::balance_newtypes::Rao;
 use crate::wallet::Keypair;
 use axum::body::Bytes;
+use axum::Extension;
 
 use axum::http::{HeaderMap, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::routing::{get, post};
 use axum::{middleware as axum_middleware, Router};
+use futures::Stream;
+use sp_core::crypto::{AccountId32, Ss58Codec};
 use std::collections::{HashMap, HashSet};
-use std::future::Future;
+use std::future::{Future, IntoFuture};
 use std::net::{IpAddr, SocketAddr};
 use std::path::Path;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 use tower_http::cors::{Any, CorsLayer};
 
 use tower_http::trace::TraceLayer;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+/// Most callers kept in [`AxonState::throttle_counts`]
+pub const MAX_THROTTLE_COUNTS: usize = 10_000;
+
+/// Key [`AxonState::throttle_counts`] counts callers under once it is full
+pub const OTHER_CALLERS: &str = "<other>";
+
+/// How often the metagraph refresh task polls the chain head
+const METAGRAPH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(12);
 
 /// Type alias for synapse handler function
-pub type SynapseHandler =
-    Arc<dyn Fn(Synapse) -> Pin<Box<dyn Future<Output = Synapse> + Send>> + Send + Sync>;
+pub type SynapseHandler = Arc<
+    dyn Fn(Synapse, HandlerContext) -> Pin<Box<dyn Future<Output = Synapse> + Send>> + Send + Sync,
+>;
+
+/// Type alias for streaming synapse handler function
+pub type StreamingSynapseHandler =
+    Arc<dyn Fn(Synapse, HandlerContext) -> ChunkStream + Send + Sync>;
 
 /// Type alias for blacklist check function
 pub type BlacklistFn = Arc<dyn Fn(&str, &str) -> bool + Send + Sync>;
@@ -47,6 +78,12 @@ pub type PriorityFn = Arc<dyn Fn(&str, &str) -> f32 + Send + Sync>;
 /// Type alias for verify function
 pub type VerifyFn = Arc<dyn Fn(&str) -> bool + Send + Sync>;
 
+/// Type alias for rate limit function
+///
+/// Receives the caller's hotkey and its stake from the axon's metagraph
+/// (`None` if no metagraph is set or the hotkey is not registered).
+pub type RateLimitFn = Arc<dyn Fn(&str, Option<Rao>) -> RateLimit + Send + Sync>;
+
 /// Axon server state
 pub struct AxonState {
     /// Number of currently active requests
@@ -68,10 +105,39 @@ pub struct AxonState {
     pub trust_proxy_headers: bool,
     /// Custom blacklist function
     pub blacklist_fn: Option<BlacklistFn>,
+    /// Managed hotkey and IP bans, consulted with the blacklist function
+    pub blacklist_store: Option<Arc<BlacklistStore>>,
     /// Custom priority function
     pub priority_fn: Option<PriorityFn>,
     /// Custom verify function
     pub verify_fn: Option<VerifyFn>,
+    /// Default per-hotkey rate limit
+    pub default_rate_limit: Option<RateLimit>,
+    /// Custom rate limit function, overriding the default limit
+    pub rate_limit_fn: Option<RateLimitFn>,
+    /// Token buckets for callers seen so far
+    pub rate_limiter: RateLimiter,
+    /// Metagraph used to look up caller registration and stake
+    pub metagraph: Option<Arc<RwLock<Metagraph>>>,
+    /// Registration and stake requirements for callers
+    pub metagraph_gate: Option<MetagraphGate>,
+    /// Latest chain block seen by the metagraph refresh task (0 if unknown)
+    pub chain_block: u64,
+    /// Total requests rejected by the rate limiter since startup
+    pub throttled_requests: u64,
+    /// Rejected request counts per caller hotkey, for at most
+    /// [`MAX_THROTTLE_COUNTS`] callers (see [`AxonState::record_throttled`])
+    pub throttle_counts: HashMap<String, u64>,
+    /// Largest request body accepted, before and after decompression
+    pub max_body_bytes: usize,
+    /// Recently used nonces per caller (None disables replay protection)
+    ///
+    /// Behind its own lock so checking a nonce does not hold the state lock.
+    pub replay_guard: Option<Arc<std::sync::Mutex<ReplayGuard>>>,
+    /// Time taken off each request's timeout to form the handler deadline
+    pub deadline_margin: Duration,
+    /// Whether signed requests may leave the body hash header empty
+    pub allow_empty_body_hash: bool,
 }
 
 impl Default for AxonState {
@@ -86,9 +152,48 @@ impl Default for AxonState {
             verify_signatures: true,
             trust_proxy_headers: false,
             blacklist_fn: None,
+            blacklist_store: None,
             priority_fn: None,
             verify_fn: None,
+            default_rate_limit: None,
+            rate_limit_fn: None,
+            rate_limiter: RateLimiter::new(),
+            metagraph: None,
+            metagraph_gate: None,
+            chain_block: 0,
+            throttled_requests: 0,
+            throttle_counts: HashMap::new(),
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            replay_guard: Some(Arc::new(std::sync::Mutex::new(ReplayGuard::default()))),
+            deadline_margin: Duration::from_millis(DEFAULT_DEADLINE_MARGIN_MS),
+            allow_empty_body_hash: false,
+        }
+    }
+}
+
+impl AxonState {
+    /// Count a request from `hotkey` rejected by the rate limiter
+    ///
+    /// When a new caller would exceed [`MAX_THROTTLE_COUNTS`], callers the
+    /// rate limiter no longer tracks are dropped first; if the map is still
+    /// full the request is counted under [`OTHER_CALLERS`].
+    pub fn record_throttled(&mut self, hotkey: String) {
+        self.throttled_requests += 1;
+        if !self.throttle_counts.contains_key(&hotkey)
+            && self.throttle_counts.len() >= MAX_THROTTLE_COUNTS
+        {
+            let limiter = &self.rate_limiter;
+            self.throttle_counts
+                .retain(|caller, _| caller == OTHER_CALLERS || limiter.is_tracked(caller));
         }
+        let caller = if self.throttle_counts.contains_key(&hotkey)
+            || self.throttle_counts.len() < MAX_THROTTLE_COUNTS
+        {
+            hotkey
+        } else {
+            OTHER_CALLERS.to_string()
+        };
+        *self.throttle_counts.entry(caller).or_insert(0) += 1;
     }
 }
 
@@ -117,8 +222,13 @@ impl Default for AxonState {
 ///     synapse
 /// });
 ///
-/// // Start serving
-/// axon.serve().await?;
+/// // Start serving in the background
+/// let handle = axon.serve().await?;
+/// println!("Listening on {}", handle.local_addr());
+///
+/// // Later: stop accepting requests and drain in-flight ones
+/// handle.shutdown();
+/// handle.await_terminated().await?;
 /// ```
 pub struct Axon {
     /// The keypair for signing responses
@@ -129,6 +239,17 @@ pub struct Axon {
     state: Arc<RwLock<AxonState>>,
     /// Registered synapse handlers
     handlers: HashMap<String, SynapseHandler>,
+    /// Registered streaming synapse handlers
+    streaming_handlers: HashMap<String, StreamingSynapseHandler>,
+    /// Required hash fields of typed routes; other routes use the fields
+    /// listed in the request body
+    hash_fields: HashMap<String, Arc<[&'static str]>>,
+    /// Whether to mount `GET /metrics`
+    #[cfg(feature = "metrics")]
+    metrics_endpoint: bool,
+    /// Token required by the local-only `/admin` routes, which are only
+    /// mounted when set
+    admin_token: Option<Arc<str>>,
 }
 
 impl Axon {
@@ -147,6 +268,17 @@ impl Axon {
             axon_hotkey: keypair.ss58_address().to_string(),
             verify_signatures: config.verify_signatures,
             trust_proxy_headers: config.trust_proxy_headers,
+            default_rate_limit: config.rate_limit,
+            metagraph_gate: config.metagraph_gate,
+            max_body_bytes: config.max_body_bytes,
+            replay_guard: (config.replay_window_secs > 0).then(|| {
+                Arc::new(std::sync::Mutex::new(ReplayGuard::new(
+                    Duration::from_secs(config.replay_window_secs),
+                    config.nonce_cache_size,
+                )))
+            }),
+            deadline_margin: Duration::from_millis(config.deadline_margin_ms),
+            allow_empty_body_hash: config.allow_empty_body_hash,
             ..Default::default()
         };
 
@@ -155,11 +287,19 @@ impl Axon {
             config,
             state: Arc::new(RwLock::new(state)),
             handlers: HashMap::new(),
+            streaming_handlers: HashMap::new(),
+            hash_fields: HashMap::new(),
+            #[cfg(feature = "metrics")]
+            metrics_endpoint: false,
+            admin_token: None,
         }
     }
 
     /// Attach a synapse handler for a specific route
     ///
+    /// To respond with an error, set a non-2xx status on the returned
+    /// synapse's `axon` terminal info.
+    ///
     /// # Arguments
     ///
     /// * `name` - The synapse name (route path)
@@ -182,14 +322,161 @@ impl Axon {
         F: Fn(Synapse) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Synapse> + Send + 'static,
     {
-        let handler = Arc::new(move |synapse: Synapse| {
-            let fut = handler(synapse);
+        self.attach_with_context(name, move |synapse, _ctx| handler(synapse))
+    }
+
+    /// Attach a synapse handler that receives the request's [`HandlerContext`]
+    ///
+    /// The context carries the request deadline: the caller's timeout less
+    /// the configured margin (see [`AxonConfig::with_deadline_margin`]). A
+    /// handler still running at the deadline is dropped and the caller gets a
+    /// 408, so handlers doing several steps should check `ctx.remaining()`
+    /// and return early rather than lose all their work.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The synapse name (route path)
+    /// * `handler` - The async handler function
+    ///
+    /// # Returns
+    ///
+    /// Mutable reference to self for chaining
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// axon.attach_with_context("Query", |synapse, ctx| async move {
+    ///     let budget = ctx.remaining() / 2;
+    ///     let _ = tokio::time::timeout(budget, do_work(&synapse)).await;
+    ///     synapse
+    /// });
+    /// ```
+    pub fn attach_with_context<F, Fut>(&mut self, name: &str, handler: F) -> &mut Self
+    where
+        F: Fn(Synapse, HandlerContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Synapse> + Send + 'static,
+    {
+        let handler = Arc::new(move |synapse: Synapse, ctx: HandlerContext| {
+            let fut = handler(synapse, ctx);
             Box::pin(fut) as Pin<Box<dyn Future<Output = Synapse> + Send>>
         });
         self.handlers.insert(name.to_string(), handler);
         self
     }
 
+    /// Attach a typed synapse handler
+    ///
+    /// The handler is served on the route named by [`SynapseType::name`].
+    /// Incoming synapses are converted to `T` before the handler runs, and
+    /// the returned `T` is converted back for the response, so typed
+    /// handlers can serve both typed and dynamic dendrites. Requests that
+    /// cannot be converted to `T` are rejected with a 400. Request bodies are
+    /// hashed over [`SynapseType::required_hash_fields`], as the Python SDK's
+    /// axon does.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The async handler function
+    ///
+    /// # Returns
+    ///
+    /// Mutable reference to self for chaining
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// axon.attach_typed(|mut query: MyQuery| async move {
+    ///     query.result = Some(query.input * 2);
+    ///     query
+    /// });
+    /// ```
+    pub fn attach_typed<T, F, Fut>(&mut self, handler: F) -> &mut Self
+    where
+        T: SynapseType,
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        self.hash_fields
+            .insert(T::name().to_string(), T::required_hash_fields().into());
+        self.attach(T::name(), move |synapse: Synapse| {
+            let response = T::from_synapse(&synapse).map(&handler);
+            async move {
+                let result = match response {
+                    Ok(response) => response
+                        .await
+                        .to_synapse()
+                        .map_err(|e| (status_codes::INTERNAL_ERROR, e.to_string())),
+                    Err(e) => Err((status_codes::BAD_REQUEST, e.to_string())),
+                };
+                result.unwrap_or_else(|(code, message)| {
+                    warn!("Typed handler for {} failed: {}", T::name(), message);
+                    Synapse {
+                        axon: Some(TerminalInfo::new().with_status(code, &message)),
+                        ..synapse
+                    }
+                })
+            }
+        })
+    }
+
+    /// Attach a streaming synapse handler for a specific route
+    ///
+    /// The handler returns a stream of byte chunks which is sent to the
+    /// dendrite as it is produced. The response headers go out before the
+    /// first chunk, and the final status and body hash follow as trailers.
+    /// If the client disconnects, the stream is dropped without being polled
+    /// further.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The synapse name (route path)
+    /// * `handler` - The handler function returning a chunk stream
+    ///
+    /// # Returns
+    ///
+    /// Mutable reference to self for chaining
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// axon.attach_streaming("Generate", |_synapse| {
+    ///     futures::stream::iter(["hello\n", "world\n"].map(Bytes::from_static))
+    /// });
+    /// ```
+    pub fn attach_streaming<F, S>(&mut self, name: &str, handler: F) -> &mut Self
+    where
+        F: Fn(Synapse) -> S + Send + Sync + 'static,
+        S: Stream<Item = Bytes> + Send + 'static,
+    {
+        self.attach_streaming_with_context(name, move |synapse, _ctx| handler(synapse))
+    }
+
+    /// Attach a streaming synapse handler that receives the request's
+    /// [`HandlerContext`]
+    ///
+    /// A stream still running at the deadline is dropped; the response ends
+    /// with a final error chunk and a 408 status in the trailers.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The synapse name (route path)
+    /// * `handler` - The handler function returning a chunk stream
+    ///
+    /// # Returns
+    ///
+    /// Mutable reference to self for chaining
+    pub fn attach_streaming_with_context<F, S>(&mut self, name: &str, handler: F) -> &mut Self
+    where
+        F: Fn(Synapse, HandlerContext) -> S + Send + Sync + 'static,
+        S: Stream<Item = Bytes> + Send + 'static,
+    {
+        let handler = Arc::new(move |synapse: Synapse, ctx: HandlerContext| {
+            Box::pin(handler(synapse, ctx)) as ChunkStream
+        });
+        self.streaming_handlers.insert(name.to_string(), handler);
+        self
+    }
+
     /// Set a custom blacklist check function
     ///
     /// The function receives (hotkey, synapse_name) and returns true if blacklisted.
@@ -223,6 +510,29 @@ impl Axon {
         self
     }
 
+    /// Set the store of managed hotkey and IP bans
+    ///
+    /// Requests from a banned hotkey or IP are rejected with a 403, in
+    /// addition to the blacklist function. The store is shared, so bans can
+    /// be changed while serving, from code or through the
+    /// `/admin/blacklist` routes (see [`Axon::serve_admin`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - Shared blacklist store
+    pub fn set_blacklist_store(&mut self, store: Arc<BlacklistStore>) -> &mut Self {
+        if let Ok(mut state_write) = self.state.try_write() {
+            state_write.blacklist_store = Some(store);
+        } else {
+            let state = self.state.clone();
+            tokio::spawn(async move {
+                let mut state_write = state.write().await;
+                state_write.blacklist_store = Some(store);
+            });
+        }
+        self
+    }
+
     /// Set a custom priority function
     ///
     /// The function receives (hotkey, synapse_name) and returns a priority value.
@@ -291,6 +601,118 @@ impl Axon {
         self
     }
 
+    /// Set a custom rate limit function
+    ///
+    /// The function receives (hotkey, stake) and returns the limit to apply
+    /// to that caller. The stake is looked up in the metagraph set with
+    /// [`Axon::set_metagraph`], which lets operators scale limits by stake.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The rate limit function
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// axon.set_rate_limit_fn(|_hotkey, stake| {
+    ///     let base = RateLimit::new(2.0, 5);
+    ///     match stake {
+    ///         Some(stake) if stake.as_tao() >= 1000.0 => base.scaled(10.0),
+    ///         _ => base,
+    ///     }
+    /// });
+    /// ```
+    pub fn set_rate_limit_fn<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&str, Option<Rao>) -> RateLimit + Send + Sync + 'static,
+    {
+        let rate_limit_fn = Arc::new(f);
+        if let Ok(mut state_write) = self.state.try_write() {
+            state_write.rate_limit_fn = Some(rate_limit_fn);
+        } else {
+            let state = self.state.clone();
+            tokio::spawn(async move {
+                let mut state_write = state.write().await;
+                state_write.rate_limit_fn = Some(rate_limit_fn);
+            });
+        }
+        self
+    }
+
+    /// Set the metagraph used to look up caller stake
+    ///
+    /// The handle is shared, so keeping it synced (e.g. calling
+    /// `Metagraph::sync` from a background task every epoch) updates the
+    /// stake seen by the rate limit function.
+    ///
+    /// # Arguments
+    ///
+    /// * `metagraph` - Shared metagraph handle
+    pub fn set_metagraph(&mut self, metagraph: Arc<RwLock<Metagraph>>) -> &mut Self {
+        if let Ok(mut state_write) = self.state.try_write() {
+            state_write.metagraph = Some(metagraph);
+        } else {
+            let state = self.state.clone();
+            tokio::spawn(async move {
+                let mut state_write = state.write().await;
+                state_write.metagraph = Some(metagraph);
+            });
+        }
+        self
+    }
+
+    /// Keep the axon's metagraph synced in a background task
+    ///
+    /// Polls the chain head every block and re-syncs the metagraph (lite
+    /// mode) once it is `interval_blocks` behind. The new metagraph is built
+    /// without holding the lock, so request handling is never blocked on a
+    /// sync. If no metagraph is set yet, an empty one for `netuid` is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The chain client to sync with
+    /// * `netuid` - The subnet to sync
+    /// * `interval_blocks` - Number of blocks between syncs
+    ///
+    /// # Returns
+    ///
+    /// Handle of the background task; abort it to stop refreshing
+    pub async fn spawn_metagraph_refresh(
+        &self,
+        client: Arc<BittensorClient>,
+        netuid: u16,
+        interval_blocks: u64,
+    ) -> tokio::task::JoinHandle<()> {
+        let metagraph = {
+            let mut state_write = self.state.write().await;
+            state_write
+                .metagraph
+                .get_or_insert_with(|| Arc::new(RwLock::new(Metagraph::new(netuid))))
+                .clone()
+        };
+        let state = self.state.clone();
+
+        tokio::spawn(async move {
+            loop {
+                match client.block_number().await {
+                    Ok(block) => {
+                        state.write().await.chain_block = block;
+
+                        let synced_at = metagraph.read().await.block;
+                        if synced_at == 0 || block >= synced_at.saturating_add(interval_blocks) {
+                            match sync_metagraph_with(&client, netuid, true).await {
+                                Ok(fresh) => *metagraph.write().await = fresh,
+                                Err(e) => warn!("Failed to refresh metagraph: {}", e),
+                            }
+                        }
+                    }
+                    Err(e) => warn!("Failed to fetch block number: {}", e),
+                }
+                tokio::time::sleep(METAGRAPH_POLL_INTERVAL).await;
+            }
+        })
+    }
+
     /// Add a hotkey to the blacklist
     ///
     /// # Arguments
@@ -342,6 +764,35 @@ impl Axon {
         state_write.priority_list.insert(hotkey.into(), priority);
     }
 
+    /// Serve the global metrics registry at `GET /metrics`
+    ///
+    /// The endpoint bypasses the blacklist, verification and rate limit
+    /// middleware, so restrict access to it at the network level. A synapse
+    /// named `metrics` cannot be attached alongside it.
+    #[cfg(feature = "metrics")]
+    pub fn serve_metrics(&mut self) -> &mut Self {
+        self.metrics_endpoint = true;
+        self
+    }
+
+    /// Serve the operator endpoints under `/admin`, such as
+    /// `PUT /admin/log_level` and `GET/POST /admin/blacklist` (see
+    /// [`crate::axon::admin`])
+    ///
+    /// Requests are only accepted from loopback addresses, so operators
+    /// reach them from the host itself, e.g. with `curl` over SSH, and must
+    /// send `Authorization: Bearer <token>`. Any process on the host can
+    /// connect from loopback, so use a random token and keep it out of
+    /// command lines. An empty token rejects every request.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Bearer token the admin requests must carry
+    pub fn serve_admin(&mut self, token: impl Into<String>) -> &mut Self {
+        self.admin_token = Some(Arc::from(token.into()));
+        self
+    }
+
     /// Get the current request count
     pub async fn request_count(&self) -> u64 {
         self.state.read().await.request_count
@@ -352,6 +803,16 @@ impl Axon {
         self.state.read().await.total_requests
     }
 
+    /// Get the total number of rate limited requests
+    pub async fn throttled_requests(&self) -> u64 {
+        self.state.read().await.throttled_requests
+    }
+
+    /// Get the number of rate limited requests per caller hotkey
+    pub async fn throttle_counts(&self) -> HashMap<String, u64> {
+        self.state.read().await.throttle_counts.clone()
+    }
+
     /// Get the axon's hotkey SS58 address
     pub fn hotkey(&self) -> &str {
         self.keypair.ss58_address()
@@ -379,20 +840,53 @@ impl Axon {
             let handler = handler.clone();
             let keypair = keypair.clone();
             let state_clone = state.clone();
+            let hash_fields = self.hash_fields.get(name).cloned();
 
-            let route_handler = move |headers: HeaderMap, body: Bytes| {
+            let route_handler = move |ctx: Option<Extension<HandlerContext>>,
+                                      headers: HeaderMap,
+                                      body: Bytes| {
                 let handler = handler.clone();
                 let keypair = keypair.clone();
                 let state = state_clone.clone();
+                let ctx = ctx.map(|Extension(ctx)| ctx);
+                let hash_fields = hash_fields.clone();
 
-                async move { handle_synapse_request(state, keypair, headers, body, handler).await }
+                async move {
+                    handle_synapse_request(state, keypair, ctx, headers, body, hash_fields, handler)
+                        .await
+                }
             };
 
             router = router.route(&format!("/{}", name), post(route_handler));
         }
 
+        // Add streaming synapse handlers
+        for (name, handler) in self.streaming_handlers.iter() {
+            let handler = handler.clone();
+            let keypair = keypair.clone();
+            let state_clone = state.clone();
+
+            let route_handler =
+                move |ctx: Option<Extension<HandlerContext>>, headers: HeaderMap, body: Bytes| {
+                    let handler = handler.clone();
+                    let keypair = keypair.clone();
+                    let state = state_clone.clone();
+                    let ctx = ctx.map(|Extension(ctx)| ctx);
+
+                    async move {
+                        handle_streaming_request(state, keypair, ctx, headers, body, handler).await
+                    }
+                };
+
+            router = router.route(&format!("/{}", name), post(route_handler));
+        }
+
         // Add middleware layers
-        router
+        let router = router
+            .layer(axum_middleware::from_fn_with_state(
+                state.clone(),
+                rate_limit_middleware,
+            ))
             .layer(axum_middleware::from_fn_with_state(
                 state.clone(),
                 counter_middleware,
@@ -409,54 +903,106 @@ impl Axon {
                 state.clone(),
                 priority_middleware,
             ))
+            .layer(axum_middleware::from_fn_with_state(
+                state.clone(),
+                metagraph_gate_middleware,
+            ))
             .layer(axum_middleware::from_fn_with_state(
                 state.clone(),
                 blacklist_middleware,
             ))
-            .layer(axum_middleware::from_fn(logging_middleware))
-            .layer(TraceLayer::new_for_http())
-            .layer(
-                CorsLayer::new()
-                    .allow_origin(Any)
-                    .allow_methods(Any)
-                    .allow_headers(Any),
-            )
-            .with_state(state)
+            .layer(axum_middleware::from_fn_with_state(
+                state.clone(),
+                body_limit_middleware,
+            ))
+            .layer(axum_middleware::from_fn(logging_middleware));
+        let router = if self.config.compression {
+            router.layer(compression_layer())
+        } else {
+            router
+        };
+        let router = router.layer(TraceLayer::new_for_http()).layer(
+            CorsLayer::new()
+                .allow_origin(Any)
+                .allow_methods(Any)
+                .allow_headers(Any),
+        );
+
+        let mut router = router.with_state(state.clone());
+        #[cfg(feature = "metrics")]
+        if self.metrics_endpoint {
+            router = router.merge(crate::metrics::router());
+        }
+        if let Some(token) = &self.admin_token {
+            router = router.merge(crate::axon::admin::router(state, token.clone()));
+        }
+        router
     }
 
     /// Start the HTTP server
     ///
+    /// Binds the configured address and serves in the background. Port 0
+    /// binds an ephemeral port; the bound address is reported by
+    /// [`AxonHandle::local_addr`], and [`AxonHandle::info`] uses it for chain
+    /// registration unless an external port is configured.
+    ///
     /// # Returns
     ///
-    /// Ok(()) on successful shutdown, or an error
-    pub async fn serve(self) -> Result<(), AxonError> {
-        let addr: SocketAddr = self
-            .config
-            .socket_addr()
-            .parse()
-            .map_err(|e| AxonError::new(format!("Invalid socket address: {}", e)))?;
-
+    /// A handle to stop the server, or an error if binding failed
+    pub async fn serve(self) -> Result<AxonHandle, AxonError> {
+        let addr = self.bind_addr()?;
         let router = self.build_router();
 
-        info!(
-            "Axon server starting on {} (hotkey: {})",
-            addr,
-            self.keypair.ss58_address()
-        );
-
         let listener = tokio::net::TcpListener::bind(addr)
             .await
             .map_err(|e| AxonError::new(format!("Failed to bind to {}: {}", addr, e)))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|e| AxonError::new(format!("Failed to read bound address: {}", e)))?;
 
-        axum::serve(listener, router)
-            .await
-            .map_err(|e| AxonError::new(format!("Server error: {}", e)))?;
+        info!(
+            "Axon server listening on {} (hotkey: {})",
+            local_addr,
+            self.keypair.ss58_address()
+        );
+
+        let cancel = CancellationToken::new();
+        let drain_timeout = Duration::from_secs(self.config.shutdown_timeout_secs);
+        let server = axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(cancel.clone().cancelled_owned());
+
+        let shutdown = cancel.clone();
+        let task = tokio::spawn(async move {
+            let server = server.into_future();
+            tokio::pin!(server);
+            let result = tokio::select! {
+                result = &mut server => result,
+                _ = shutdown.cancelled() => {
+                    match tokio::time::timeout(drain_timeout, &mut server).await {
+                        Ok(result) => result,
+                        Err(_) => {
+                            warn!(
+                                "Axon shutdown timed out after {:?}; dropping in-flight requests",
+                                drain_timeout
+                            );
+                            Ok(())
+                        }
+                    }
+                }
+            };
+            result.map_err(|e| AxonError::new(format!("Server error: {}", e)))
+        });
 
-        Ok(())
+        Ok(AxonHandle::new(self, local_addr, cancel, task))
     }
 
     /// Start the HTTP server with TLS
     ///
+    /// Serves in the background like [`Axon::serve`].
+    ///
     /// # Arguments
     ///
     /// * `cert_path` - Path to the TLS certificate file
@@ -464,35 +1010,101 @@ impl Axon {
     ///
     /// # Returns
     ///
-    /// Ok(()) on successful shutdown, or an error
-    pub async fn serve_tls(self, cert_path: &Path, key_path: &Path) -> Result<(), AxonError> {
+    /// A handle to stop the server, or an error if loading TLS or binding failed
+    pub async fn serve_tls(
+        self,
+        cert_path: &Path,
+        key_path: &Path,
+    ) -> Result<AxonHandle, AxonError> {
         use axum_server::tls_rustls::RustlsConfig;
 
-        let addr: SocketAddr = self
-            .config
-            .socket_addr()
-            .parse()
-            .map_err(|e| AxonError::new(format!("Invalid socket address: {}", e)))?;
-
-        let router = self.build_router();
-
         // Load TLS configuration
         let tls_config = RustlsConfig::from_pem_file(cert_path, key_path)
             .await
             .map_err(|e| AxonError::new(format!("Failed to load TLS config: {}", e)))?;
+        self.serve_rustls(tls_config).await
+    }
+
+    /// Start the HTTPS server with an in-memory certificate
+    ///
+    /// Serves in the background like [`Axon::serve`]. [`AxonHandle::info`]
+    /// reports [`AXON_PROTOCOL_HTTPS`]; publish
+    /// [`AxonTlsConfig::chain_certificate`] with `serve_axon_tls` so
+    /// dendrites can pin the certificate.
+    ///
+    /// # Arguments
+    ///
+    /// * `tls` - The certificate and private key, e.g. from
+    ///   [`AxonTlsConfig::generate`]
+    ///
+    /// # Returns
+    ///
+    /// A handle to stop the server, or an error if loading TLS or binding failed
+    pub async fn serve_https(self, tls: &AxonTlsConfig) -> Result<AxonHandle, AxonError> {
+        let tls_config = tls.rustls_config()?;
+        self.serve_rustls(tls_config).await
+    }
+
+    /// Serve over TLS in the background
+    async fn serve_rustls(
+        self,
+        tls_config: axum_server::tls_rustls::RustlsConfig,
+    ) -> Result<AxonHandle, AxonError> {
+        let addr = self.bind_addr()?;
+        let router = self.build_router();
+
+        let server_handle = axum_server::Handle::new();
+        let mut server = tokio::spawn(
+            axum_server::bind_rustls(addr, tls_config)
+                .handle(server_handle.clone())
+                .serve(router.into_make_service_with_connect_info::<SocketAddr>()),
+        );
+
+        // `listening` resolves to None if the server failed to bind
+        let local_addr = tokio::select! {
+            Some(local_addr) = server_handle.listening() => local_addr,
+            result = &mut server => {
+                return Err(match result {
+                    Ok(Err(e)) => AxonError::new(format!("Failed to bind to {}: {}", addr, e)),
+                    _ => AxonError::new(format!("Axon TLS server on {} stopped", addr)),
+                });
+            }
+        };
 
         info!(
-            "Axon server starting on {} with TLS (hotkey: {})",
-            addr,
+            "Axon server listening on {} with TLS (hotkey: {})",
+            local_addr,
             self.keypair.ss58_address()
         );
 
-        axum_server::bind_rustls(addr, tls_config)
-            .serve(router.into_make_service())
-            .await
-            .map_err(|e| AxonError::new(format!("Server error: {}", e)))?;
+        let cancel = CancellationToken::new();
+        let drain_timeout = Duration::from_secs(self.config.shutdown_timeout_secs);
+        let shutdown = cancel.clone();
+        let task = tokio::spawn(async move {
+            tokio::select! {
+                result = &mut server => {
+                    return result
+                        .map_err(|e| AxonError::new(format!("Server task failed: {}", e)))?
+                        .map_err(|e| AxonError::new(format!("Server error: {}", e)));
+                }
+                _ = shutdown.cancelled() => {}
+            }
+            server_handle.graceful_shutdown(Some(drain_timeout));
+            server
+                .await
+                .map_err(|e| AxonError::new(format!("Server task failed: {}", e)))?
+                .map_err(|e| AxonError::new(format!("Server error: {}", e)))
+        });
+
+        Ok(AxonHandle::new(self, local_addr, cancel, task).with_protocol(AXON_PROTOCOL_HTTPS))
+    }
 
-        Ok(())
+    /// Parse the configured bind address
+    fn bind_addr(&self) -> Result<SocketAddr, AxonError> {
+        self.config
+            .socket_addr()
+            .parse()
+            .map_err(|e| AxonError::new(format!("Invalid socket address: {}", e)))
     }
 
     /// Get the AxonInfo for chain registration
@@ -507,32 +1119,132 @@ impl Axon {
     ///
     /// AxonInfo ready for chain registration
     pub fn info(&self, block: u64) -> Result<AxonInfo, AxonConfigError> {
-        let external_ip = self.config.get_external_ip();
-        let external_port = self.config.get_external_port();
-
-        let ip: IpAddr = external_ip.parse().map_err(|e| {
-            AxonConfigError::with_field(
-                format!("Invalid IP address '{}': {}", external_ip, e),
-                "external_ip",
-            )
-        })?;
-
-        let ip_type = match ip {
-            IpAddr::V4(_) => 4,
-            IpAddr::V6(_) => 6,
-        };
-
-        Ok(AxonInfo {
-            hotkey: Some(self.keypair.ss58_address().to_string()),
+        axon_info(
+            self.keypair.ss58_address(),
+            &self.config,
+            AXON_PROTOCOL_HTTP,
             block,
-            version: AXON_VERSION as u32,
-            ip,
-            port: external_port,
-            ip_type,
-            protocol: 4, // TCP
-            placeholder1: 0,
-            placeholder2: 0,
-        })
+        )
+    }
+}
+
+/// Build the chain registration info for an axon's config
+fn axon_info(
+    hotkey: &str,
+    config: &AxonConfig,
+    protocol: u8,
+    block: u64,
+) -> Result<AxonInfo, AxonConfigError> {
+    let external_ip = config.get_external_ip();
+    let external_port = config.get_external_port();
+
+    let ip: IpAddr = external_ip.parse().map_err(|e| {
+        AxonConfigError::with_field(
+            format!("Invalid IP address '{}': {}", external_ip, e),
+            "external_ip",
+        )
+    })?;
+
+    let ip_type = match ip {
+        IpAddr::V4(_) => 4,
+        IpAddr::V6(_) => 6,
+    };
+
+    Ok(AxonInfo {
+        hotkey: Some(hotkey.to_string()),
+        block,
+        version: AXON_VERSION as u32,
+        ip,
+        port: external_port,
+        ip_type,
+        protocol,
+        placeholder1: 0,
+        placeholder2: 0,
+    })
+}
+
+/// Handle to a running [`Axon`] server
+///
+/// Dropping the handle leaves the server running unless
+/// [`shutdown_on_drop`](AxonHandle::shutdown_on_drop) is enabled.
+pub struct AxonHandle {
+    local_addr: SocketAddr,
+    hotkey: String,
+    config: AxonConfig,
+    protocol: u8,
+    cancel: CancellationToken,
+    task: Option<JoinHandle<Result<(), AxonError>>>,
+    shutdown_on_drop: bool,
+}
+
+impl AxonHandle {
+    fn new(
+        axon: Axon,
+        local_addr: SocketAddr,
+        cancel: CancellationToken,
+        task: JoinHandle<Result<(), AxonError>>,
+    ) -> Self {
+        let mut config = axon.config;
+        config.port = local_addr.port();
+        Self {
+            local_addr,
+            hotkey: axon.keypair.ss58_address().to_string(),
+            config,
+            protocol: AXON_PROTOCOL_HTTP,
+            cancel,
+            task: Some(task),
+            shutdown_on_drop: false,
+        }
+    }
+
+    fn with_protocol(mut self, protocol: u8) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// The address the server is bound to, with the real port if port 0 was
+    /// configured
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Chain registration info using the bound port, with the HTTPS protocol
+    /// if the server uses TLS
+    pub fn info(&self, block: u64) -> Result<AxonInfo, AxonConfigError> {
+        axon_info(&self.hotkey, &self.config, self.protocol, block)
+    }
+
+    /// Stop the server when the handle is dropped
+    pub fn shutdown_on_drop(mut self, enabled: bool) -> Self {
+        self.shutdown_on_drop = enabled;
+        self
+    }
+
+    /// Stop accepting connections and drain in-flight requests
+    ///
+    /// Requests still running after the configured shutdown timeout are
+    /// dropped. Use [`await_terminated`](AxonHandle::await_terminated) to
+    /// wait for the server to stop.
+    pub fn shutdown(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Wait for the server to stop
+    pub async fn await_terminated(mut self) -> Result<(), AxonError> {
+        match self.task.take() {
+            Some(task) => task
+                .await
+                .map_err(|e| AxonError::new(format!("Server task failed: {}", e)))?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for AxonHandle {
+    fn drop(&mut self) {
+        if self.shutdown_on_drop {
+            self.cancel.cancel();
+        }
     }
 }
 
@@ -545,50 +1257,45 @@ async fn health_handler() -> impl IntoResponse {
 async fn handle_synapse_request(
     state: Arc<RwLock<AxonState>>,
     keypair: Keypair,
+    ctx: Option<HandlerContext>,
     headers: HeaderMap,
     body: Bytes,
+    hash_fields: Option<Arc<[&'static str]>>,
     handler: SynapseHandler,
 ) -> Response {
     let start_time = std::time::Instant::now();
     let hotkey = keypair.ss58_address().to_string();
 
-    // Verify the request signature if enabled
-    {
-        let state_read = state.read().await;
-        if state_read.verify_signatures {
-            match verify_request(&headers, &body, &hotkey) {
-                Ok(_verified) => {}
-                Err(e) => {
-                    let process_time = start_time.elapsed().as_secs_f64();
-                    return build_error_response(
-                        &hotkey,
-                        StatusCode::UNAUTHORIZED,
-                        status_codes::UNAUTHORIZED,
-                        &e.message,
-                        process_time,
-                    );
-                }
-            }
-        }
-    }
-
-    // Extract the synapse from the request
-    let synapse = match extract_synapse(&headers, &body) {
+    let request = prepare_request(
+        &state,
+        &hotkey,
+        &headers,
+        &body,
+        hash_fields.as_deref(),
+        start_time,
+    );
+    let synapse = match request.await {
         Ok(s) => s,
-        Err(e) => {
-            let process_time = start_time.elapsed().as_secs_f64();
-            return build_error_response(
-                &hotkey,
-                StatusCode::BAD_REQUEST,
-                status_codes::INTERNAL_ERROR,
-                &e.message,
-                process_time,
-            );
-        }
+        Err(response) => return response,
     };
+    let ctx = match ctx {
+        Some(ctx) => ctx,
+        None => request_context(&state, &hotkey, &headers).await,
+    };
+
+    // Call the handler; the timeout middleware drops it at the deadline
+    let response_synapse = handler(synapse, ctx).await;
 
-    // Call the handler
-    let response_synapse = handler(synapse).await;
+    // Handlers report failures through the axon terminal status
+    if let Some(axon) = response_synapse.axon.as_ref() {
+        if let Some(code) = axon.status_code.filter(|code| !(200..300).contains(code)) {
+            let process_time = start_time.elapsed().as_secs_f64();
+            let message = axon.status_message.as_deref().unwrap_or_default();
+            let status =
+                StatusCode::from_u16(code as u16).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            return build_error_response(&hotkey, status, code, message, process_time);
+        }
+    }
 
     // Serialize the response
     let response_body = match serde_json::to_vec(&response_synapse.extra) {
@@ -610,6 +1317,152 @@ async fn handle_synapse_request(
     build_success_response(&hotkey, response_body, process_time)
 }
 
+/// Handle a streaming synapse request
+async fn handle_streaming_request(
+    state: Arc<RwLock<AxonState>>,
+    keypair: Keypair,
+    ctx: Option<HandlerContext>,
+    headers: HeaderMap,
+    body: Bytes,
+    handler: StreamingSynapseHandler,
+) -> Response {
+    let start_time = std::time::Instant::now();
+    let hotkey = keypair.ss58_address().to_string();
+
+    let synapse = match prepare_request(&state, &hotkey, &headers, &body, None, start_time).await {
+        Ok(s) => s,
+        Err(response) => return response,
+    };
+    let ctx = match ctx {
+        Some(ctx) => ctx,
+        None => request_context(&state, &hotkey, &headers).await,
+    };
+
+    // The response is returned before the stream runs, so the body itself
+    // enforces the deadline
+    let deadline = ctx.deadline;
+    build_streaming_response(&hotkey, handler(synapse, ctx), start_time, Some(deadline))
+}
+
+/// Build the handler context for a request that did not pass through the
+/// timeout middleware
+async fn request_context(
+    state: &RwLock<AxonState>,
+    hotkey: &str,
+    headers: &HeaderMap,
+) -> HandlerContext {
+    let margin = state.read().await.deadline_margin;
+    HandlerContext::from_headers(hotkey, headers, margin)
+}
+
+/// Verify a request (if enabled) and extract its synapse
+///
+/// `hash_fields` are the route's required hash fields (see
+/// [`verify_request_with`]). Returns the error response to send back if
+/// either step fails.
+async fn prepare_request(
+    state: &RwLock<AxonState>,
+    hotkey: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+    hash_fields: Option<&[&str]>,
+    start_time: std::time::Instant,
+) -> Result<Synapse, Response> {
+    // Verify the request signature if enabled, without holding the state lock
+    let (verify_signatures, allow_empty_body_hash, replay_guard, metagraph) = {
+        let state = state.read().await;
+        (
+            state.verify_signatures,
+            state.allow_empty_body_hash,
+            state.replay_guard.clone(),
+            state.metagraph.clone(),
+        )
+    };
+    if verify_signatures {
+        match verify_request_with(headers, body, hotkey, hash_fields, allow_empty_body_hash) {
+            Ok(verified) => {
+                // Registered callers are tracked outside the replay guard's
+                // cap, so throwaway hotkeys cannot fill the table on them
+                let registered = match (
+                    &metagraph,
+                    AccountId32::from_ss58check(&verified.dendrite_hotkey),
+                ) {
+                    (Some(metagraph), Ok(account)) => metagraph
+                        .read()
+                        .await
+                        .get_neuron_by_hotkey(&account)
+                        .is_some(),
+                    _ => false,
+                };
+                // Only signed nonces are recorded, so a forged request
+                // cannot burn another caller's nonce
+                let replayed = replay_guard.and_then(|guard| {
+                    guard
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .check_caller(&verified.dendrite_hotkey, verified.nonce, registered)
+                        .err()
+                });
+                if let Some(e) = replayed {
+                    warn!("Rejected request from {}: {}", verified.dendrite_hotkey, e);
+                    let (status, code, message) = match e {
+                        ReplayRejection::TooManyCallers(_) => (
+                            StatusCode::SERVICE_UNAVAILABLE,
+                            status_codes::SERVICE_UNAVAILABLE,
+                            status_messages::SERVICE_UNAVAILABLE,
+                        ),
+                        _ => (
+                            StatusCode::UNAUTHORIZED,
+                            status_codes::UNAUTHORIZED,
+                            status_messages::REPLAYED,
+                        ),
+                    };
+                    let process_time = start_time.elapsed().as_secs_f64();
+                    return Err(build_error_response(
+                        hotkey,
+                        status,
+                        code,
+                        message,
+                        process_time,
+                    ));
+                }
+            }
+            Err(e) => {
+                if let RequestRejection::BodyHashMismatch { expected, actual } = &e {
+                    warn!(
+                        "Rejected request with body hash {} (header {})",
+                        actual, expected
+                    );
+                }
+                let (status, code) = e.status();
+                let process_time = start_time.elapsed().as_secs_f64();
+                return Err(build_error_response(
+                    hotkey,
+                    status,
+                    code,
+                    e.message(),
+                    process_time,
+                ));
+            }
+        }
+    }
+
+    // Extract the synapse from the request
+    match extract_synapse(headers, body) {
+        Ok(s) => Ok(s),
+        Err(e) => {
+            let process_time = start_time.elapsed().as_secs_f64();
+            Err(build_error_response(
+                hotkey,
+                StatusCode::BAD_REQUEST,
+                status_codes::INTERNAL_ERROR,
+                &e.message,
+                process_time,
+            ))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -618,6 +1471,45 @@ mod tests {
         Keypair::from_uri("//Alice").expect("Failed to create test keypair")
     }
 
+    #[test]
+    fn test_throttle_counts_are_bounded() {
+        let mut state = AxonState::default();
+        let limit = RateLimit::new(1.0, 1);
+        let now = std::time::Instant::now();
+        for i in 0..MAX_THROTTLE_COUNTS {
+            let hotkey = format!("caller{}", i);
+            // Only even callers still have a bucket
+            if i % 2 == 0 {
+                let _ = state.rate_limiter.check(&hotkey, &limit, now);
+            }
+            state.record_throttled(hotkey);
+        }
+
+        let _ = state.rate_limiter.check("new", &limit, now);
+        state.record_throttled("new".to_string());
+        assert_eq!(state.throttle_counts.len(), MAX_THROTTLE_COUNTS / 2 + 1);
+        assert_eq!(state.throttle_counts.get("new"), Some(&1));
+
+        for i in 0..MAX_THROTTLE_COUNTS / 2 - 1 {
+            let hotkey = format!("tracked{}", i);
+            let _ = state.rate_limiter.check(&hotkey, &limit, now);
+            state.record_throttled(hotkey);
+        }
+        // Full of callers that are still tracked
+        let _ = state.rate_limiter.check("late", &limit, now);
+        state.record_throttled("late".to_string());
+        state.record_throttled("caller0".to_string());
+
+        // Every caller slot is taken, plus the overflow key
+        assert_eq!(state.throttle_counts.len(), MAX_THROTTLE_COUNTS + 1);
+        assert_eq!(state.throttle_counts.get(OTHER_CALLERS), Some(&1));
+        assert_eq!(state.throttle_counts.get("caller0"), Some(&2));
+        assert_eq!(
+            state.throttled_requests,
+            (MAX_THROTTLE_COUNTS + MAX_THROTTLE_COUNTS / 2 + 2) as u64
+        );
+    }
+
     #[test]
     fn test_axon_new() {
         let keypair = create_test_keypair();
@@ -639,6 +1531,20 @@ mod tests {
         assert!(axon.handlers.contains_key("TestQuery"));
     }
 
+    #[test]
+    fn test_axon_attach_streaming_handler() {
+        let keypair = create_test_keypair();
+        let config = AxonConfig::default();
+        let mut axon = Axon::new(keypair, config);
+
+        axon.attach_streaming("TestStream", |_synapse| {
+            futures::stream::iter(vec![Bytes::from_static(b"chunk\n")])
+        });
+
+        assert!(axon.streaming_handlers.contains_key("TestStream"));
+        assert!(!axon.handlers.contains_key("TestStream"));
+    }
+
     #[test]
     fn test_axon_info() {
         let keypair = create_test_keypair();
@@ -0,0 +1,331 @@
+//! Streaming synapse responses
+//!
+//! Streaming handlers produce a stream of byte chunks which is forwarded to
+//! the dendrite using chunked transfer encoding. The standard axon headers are
+//! written before the first chunk; once the handler stream ends the final
+//! status, total process time and the hash of the concatenated body are sent
+//! as HTTP trailers.
+//!
+//! If the client disconnects mid-stream the response body is dropped by the
+//! server, which drops the handler stream and cancels any work driving it.
+//! The same happens at the request deadline, except the response is finished
+//! with [`deadline_error_chunk`] and a 408 status in the trailers.
+
+use crate::axon::handlers::{build_response_headers, status_codes, status_messages};
+use crate::dendrite::request::header_names;
+use axum::body::{Body, Bytes};
+use axum::response::{IntoResponse, Response};
+use futures::Stream;
+use http::{header, HeaderMap, HeaderValue, StatusCode};
+use http_body::Frame;
+use sha2::{Digest, Sha256};
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tokio::time::Sleep;
+use tracing::{debug, warn};
+
+/// Boxed stream of response chunks produced by a streaming handler
+pub type ChunkStream = Pin<Box<dyn Stream<Item = Bytes> + Send>>;
+
+/// Header fields sent as trailers once a streaming response completes
+pub const TRAILER_FIELDS: [&str; 4] = [
+    header_names::AXON_STATUS_CODE,
+    header_names::AXON_STATUS_MESSAGE,
+    header_names::AXON_PROCESS_TIME,
+    header_names::BODY_HASH,
+];
+
+/// Final chunk sent when a stream is cut off at the request deadline
+///
+/// A JSON object on its own line with the 408 status code and message.
+pub fn deadline_error_chunk() -> Bytes {
+    let error = serde_json::json!({
+        "status_code": status_codes::TIMEOUT,
+        "status_message": status_messages::TIMEOUT,
+    });
+    Bytes::from(format!("\n{}\n", error))
+}
+
+/// Response body that forwards a handler stream and hashes it on the way out
+pub struct StreamingBody {
+    /// The handler's chunk stream
+    inner: ChunkStream,
+    /// Running hash over every chunk sent so far
+    hasher: Sha256,
+    /// The axon's hotkey SS58 address
+    hotkey: String,
+    /// When request processing started
+    start_time: Instant,
+    /// Number of bytes sent so far
+    bytes_sent: usize,
+    /// Whether the trailers have been emitted
+    finished: bool,
+    /// Fires at the request deadline, if one is set
+    deadline: Option<Pin<Box<Sleep>>>,
+    /// Whether the stream was cut off at the deadline
+    timed_out: bool,
+}
+
+impl StreamingBody {
+    /// Create a new streaming body
+    ///
+    /// # Arguments
+    ///
+    /// * `hotkey` - The axon's hotkey SS58 address
+    /// * `inner` - The handler's chunk stream
+    /// * `start_time` - When request processing started
+    pub fn new(hotkey: impl Into<String>, inner: ChunkStream, start_time: Instant) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+            hotkey: hotkey.into(),
+            start_time,
+            bytes_sent: 0,
+            finished: false,
+            deadline: None,
+            timed_out: false,
+        }
+    }
+
+    /// Cut the stream off at `deadline`
+    ///
+    /// The handler stream is dropped at the deadline and the response ends
+    /// with [`deadline_error_chunk`], followed by trailers carrying a 408.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        let deadline = tokio::time::Instant::from_std(deadline);
+        self.deadline = Some(Box::pin(tokio::time::sleep_until(deadline)));
+        self
+    }
+
+    /// Build the trailing status headers, including the body hash
+    fn trailers(&self) -> HeaderMap {
+        let process_time = self.start_time.elapsed().as_secs_f64();
+        let (code, message) = if self.timed_out {
+            (status_codes::TIMEOUT, status_messages::TIMEOUT)
+        } else {
+            (status_codes::SUCCESS, status_messages::SUCCESS)
+        };
+        let headers = build_response_headers(&self.hotkey, code, message, process_time);
+
+        let mut trailers = HeaderMap::new();
+        for name in TRAILER_FIELDS {
+            if let Some(value) = headers.get(name) {
+                trailers.insert(name, value.clone());
+            }
+        }
+
+        let body_hash = hex::encode(self.hasher.clone().finalize());
+        if let Ok(hv) = HeaderValue::from_str(&body_hash) {
+            trailers.insert(header_names::BODY_HASH, hv);
+        }
+        trailers
+    }
+}
+
+impl http_body::Body for StreamingBody {
+    type Data = Bytes;
+    type Error = Infallible;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+
+        let expired = self
+            .deadline
+            .as_mut()
+            .is_some_and(|deadline| deadline.as_mut().poll(cx).is_ready());
+        if expired {
+            warn!(
+                "Streaming response reached its deadline after {} bytes, cancelling handler",
+                self.bytes_sent
+            );
+            // Dropping the handler stream cancels the work driving it
+            self.inner = Box::pin(futures::stream::empty());
+            self.deadline = None;
+            self.timed_out = true;
+
+            let chunk = deadline_error_chunk();
+            self.hasher.update(&chunk);
+            self.bytes_sent += chunk.len();
+            return Poll::Ready(Some(Ok(Frame::data(chunk))));
+        }
+
+        loop {
+            match self.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(chunk)) => {
+                    // Empty chunks would terminate chunked encoding early
+                    if chunk.is_empty() {
+                        continue;
+                    }
+                    self.hasher.update(&chunk);
+                    self.bytes_sent += chunk.len();
+                    return Poll::Ready(Some(Ok(Frame::data(chunk))));
+                }
+                Poll::Ready(None) => {
+                    self.finished = true;
+                    let trailers = self.trailers();
+                    return Poll::Ready(Some(Ok(Frame::trailers(trailers))));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.finished
+    }
+}
+
+impl Drop for StreamingBody {
+    fn drop(&mut self) {
+        if !self.finished {
+            debug!(
+                "Streaming response dropped after {} bytes, cancelling handler",
+                self.bytes_sent
+            );
+        }
+    }
+}
+
+/// Build a streaming success response
+///
+/// The standard axon headers are sent immediately, along with a `Trailer`
+/// header announcing the status fields that follow the final chunk.
+///
+/// # Arguments
+///
+/// * `hotkey` - The axon's hotkey SS58 address
+/// * `stream` - The handler's chunk stream
+/// * `start_time` - When request processing started
+/// * `deadline` - When to cut the stream off, if at all
+///
+/// # Returns
+///
+/// An axum Response with a chunked body
+pub fn build_streaming_response(
+    hotkey: &str,
+    stream: ChunkStream,
+    start_time: Instant,
+    deadline: Option<Instant>,
+) -> Response {
+    let mut headers = build_response_headers(
+        hotkey,
+        status_codes::SUCCESS,
+        status_messages::SUCCESS,
+        start_time.elapsed().as_secs_f64(),
+    );
+    if let Ok(hv) = HeaderValue::from_str(&TRAILER_FIELDS.join(", ")) {
+        headers.insert(header::TRAILER, hv);
+    }
+
+    let mut body = StreamingBody::new(hotkey, stream, start_time);
+    if let Some(deadline) = deadline {
+        body = body.with_deadline(deadline);
+    }
+    let body = Body::new(body);
+    (StatusCode::OK, headers, body).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::axon::handlers::compute_body_hash;
+    use futures::future::poll_fn;
+    use futures::StreamExt;
+    use http_body::Body as _;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_streaming_body_emits_chunks_then_trailers() {
+        let chunks = vec![
+            Bytes::from_static(b"hello "),
+            Bytes::new(),
+            Bytes::from_static(b"world"),
+        ];
+        let stream: ChunkStream = Box::pin(futures::stream::iter(chunks));
+        let mut body = StreamingBody::new("hotkey", stream, Instant::now());
+
+        let mut data = Vec::new();
+        let mut trailers = None;
+        while let Some(frame) = poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await {
+            let frame = frame.unwrap();
+            if let Some(chunk) = frame.data_ref() {
+                data.extend_from_slice(chunk);
+            } else {
+                trailers = frame.into_trailers().ok();
+            }
+        }
+
+        assert_eq!(data, b"hello world");
+        let trailers = trailers.expect("trailers should be sent");
+        assert_eq!(
+            trailers.get(header_names::BODY_HASH).unwrap(),
+            compute_body_hash(b"hello world").as_str()
+        );
+        assert_eq!(
+            trailers.get(header_names::AXON_STATUS_CODE).unwrap(),
+            status_codes::SUCCESS.to_string().as_str()
+        );
+        assert!(trailers.get(header_names::AXON_HOTKEY).is_none());
+        assert!(body.is_end_stream());
+    }
+
+    #[tokio::test]
+    async fn test_streaming_body_truncated_at_deadline() {
+        struct DropFlag(Arc<AtomicBool>);
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        // One chunk, then a stream that never finishes
+        let dropped = Arc::new(AtomicBool::new(false));
+        let guard = DropFlag(dropped.clone());
+        let stream = futures::stream::once(async { Bytes::from_static(b"partial") }).chain(
+            futures::stream::pending().map(move |chunk: Bytes| {
+                let _ = &guard;
+                chunk
+            }),
+        );
+        let start_time = Instant::now();
+        let mut body = StreamingBody::new("hotkey", Box::pin(stream), start_time)
+            .with_deadline(start_time + Duration::from_millis(100));
+
+        let mut data = Vec::new();
+        let mut trailers = None;
+        while let Some(frame) = poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await {
+            let frame = frame.unwrap();
+            if let Some(chunk) = frame.data_ref() {
+                data.extend_from_slice(chunk);
+            } else {
+                trailers = frame.into_trailers().ok();
+            }
+        }
+
+        assert!(dropped.load(Ordering::SeqCst), "handler stream still alive");
+        let mut expected = b"partial".to_vec();
+        expected.extend_from_slice(&deadline_error_chunk());
+        assert_eq!(data, expected);
+
+        let trailers = trailers.expect("trailers should be sent");
+        assert_eq!(
+            trailers.get(header_names::AXON_STATUS_CODE).unwrap(),
+            status_codes::TIMEOUT.to_string().as_str()
+        );
+        assert_eq!(
+            trailers.get(header_names::BODY_HASH).unwrap(),
+            compute_body_hash(&expected).as_str()
+        );
+        assert!(body.is_end_stream());
+    }
+}
@@ -0,0 +1,207 @@
+//! Self-signed TLS certificates for axons
+//!
+//! An axon serving HTTPS does not need a CA-signed certificate: it publishes
+//! the public key of its certificate on chain with `serve_axon_tls`, and
+//! dendrites pin that key with `Dendrite::call_tls`. [`generate_certificate`]
+//! creates such a certificate, and [`AxonTlsConfig`] carries it to
+//! [`Axon::serve_https`](crate::axon::Axon::serve_https).
+
+use crate::dendrite::tls::{crypto_provider, subject_public_key_info};
+use crate::errors::AxonError;
+use crate::queries::neurons::{
+    Certificate, CERTIFICATE_ALGORITHM_ED25519, MAX_CERTIFICATE_PUBLIC_KEY_LEN,
+};
+use axum_server::tls_rustls::RustlsConfig;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use std::sync::Arc;
+
+/// Generate a self-signed ed25519 certificate for `hotkey`
+///
+/// The hotkey is used as the certificate's common name. The certificate
+/// never expires, as dendrites pin its key instead of validating it.
+///
+/// # Arguments
+///
+/// * `hotkey` - The SS58 address of the axon's hotkey
+///
+/// # Returns
+///
+/// The DER encoded certificate and its PKCS#8 private key
+pub fn generate_certificate(
+    hotkey: &str,
+) -> Result<(CertificateDer<'static>, PrivateKeyDer<'static>), AxonError> {
+    let key_pair = rcgen::KeyPair::generate_for(&rcgen::PKCS_ED25519)
+        .map_err(|e| AxonError::new(format!("Failed to generate TLS key: {}", e)))?;
+    let mut params = rcgen::CertificateParams::default();
+    params
+        .distinguished_name
+        .push(rcgen::DnType::CommonName, hotkey);
+    let certificate = params
+        .self_signed(&key_pair)
+        .map_err(|e| AxonError::new(format!("Failed to generate TLS certificate: {}", e)))?;
+
+    let private_key = PrivatePkcs8KeyDer::from(key_pair.serialize_der());
+    Ok((certificate.der().clone(), PrivateKeyDer::Pkcs8(private_key)))
+}
+
+/// Certificate and private key an axon serves HTTPS with
+#[derive(Debug)]
+pub struct AxonTlsConfig {
+    /// DER encoded certificate
+    pub certificate: CertificateDer<'static>,
+    /// Private key of the certificate
+    pub private_key: PrivateKeyDer<'static>,
+}
+
+impl AxonTlsConfig {
+    /// Create a configuration from an existing certificate and key
+    pub fn new(certificate: CertificateDer<'static>, private_key: PrivateKeyDer<'static>) -> Self {
+        Self {
+            certificate,
+            private_key,
+        }
+    }
+
+    /// Create a configuration with a new self-signed certificate
+    ///
+    /// See [`generate_certificate`].
+    pub fn generate(hotkey: &str) -> Result<Self, AxonError> {
+        let (certificate, private_key) = generate_certificate(hotkey)?;
+        Ok(Self::new(certificate, private_key))
+    }
+
+    /// The certificate to publish on chain with `serve_axon_tls`
+    ///
+    /// Contains the DER `SubjectPublicKeyInfo` of the certificate. Only
+    /// ed25519 keys are small enough for the chain.
+    pub fn chain_certificate(&self) -> Result<Certificate, AxonError> {
+        let public_key = subject_public_key_info(self.certificate.as_ref())
+            .ok_or_else(|| AxonError::new("TLS certificate is not valid DER X.509"))?;
+        if public_key.len() > MAX_CERTIFICATE_PUBLIC_KEY_LEN {
+            return Err(AxonError::new(format!(
+                "TLS public key is {} bytes; the chain accepts at most {}",
+                public_key.len(),
+                MAX_CERTIFICATE_PUBLIC_KEY_LEN
+            )));
+        }
+        Ok(Certificate::from_der(
+            CERTIFICATE_ALGORITHM_ED25519,
+            public_key,
+        ))
+    }
+
+    /// Build the rustls server configuration
+    pub(crate) fn server_config(&self) -> Result<rustls::ServerConfig, AxonError> {
+        let certificates = vec![self.certificate.clone()];
+        let private_key = self.private_key.clone_key();
+        let mut config = rustls::ServerConfig::builder_with_provider(crypto_provider())
+            .with_safe_default_protocol_versions()
+            .and_then(|builder| {
+                builder
+                    .with_no_client_auth()
+                    .with_single_cert(certificates, private_key)
+            })
+            .map_err(|e| AxonError::new(format!("Failed to load TLS config: {}", e)))?;
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+        Ok(config)
+    }
+
+    /// Build the rustls server configuration for `axum_server`
+    pub(crate) fn rustls_config(&self) -> Result<RustlsConfig, AxonError> {
+        Ok(RustlsConfig::from_config(Arc::new(self.server_config()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dendrite::tls::pinned_client_config;
+    use rustls::pki_types::ServerName;
+    use rustls::{CertificateError, ClientConnection, Connection, ServerConnection};
+
+    // Made with OpenSSL, see tests/fixtures/tls/README.md
+    const OPENSSL_CERT: &[u8] = include_bytes!("../../tests/fixtures/tls/axon_cert.der");
+    const OPENSSL_KEY: &[u8] = include_bytes!("../../tests/fixtures/tls/axon_key.der");
+    const OPENSSL_SPKI: &[u8] = include_bytes!("../../tests/fixtures/tls/axon_spki.der");
+
+    /// Send everything `from` has buffered to `to`
+    fn transfer(from: &mut Connection, to: &mut Connection) -> Result<(), rustls::Error> {
+        let mut buf = Vec::new();
+        while from.wants_write() {
+            from.write_tls(&mut buf).unwrap();
+        }
+        let mut rd = &buf[..];
+        while !rd.is_empty() {
+            to.read_tls(&mut rd).unwrap();
+            to.process_new_packets()?;
+        }
+        Ok(())
+    }
+
+    /// Run an in-memory handshake between `tls` and a client pinning `pin`
+    fn handshake(tls: &AxonTlsConfig, pin: &[u8]) -> Result<(), rustls::Error> {
+        let client = ClientConnection::new(
+            Arc::new(pinned_client_config(pin)?),
+            ServerName::try_from("127.0.0.1").unwrap(),
+        )?;
+        let server = ServerConnection::new(Arc::new(tls.server_config().unwrap()))?;
+        let (mut client, mut server) = (Connection::from(client), Connection::from(server));
+        for _ in 0..10 {
+            if !client.is_handshaking() && !server.is_handshaking() {
+                return Ok(());
+            }
+            transfer(&mut client, &mut server)?;
+            transfer(&mut server, &mut client)?;
+        }
+        panic!("TLS handshake did not complete");
+    }
+
+    #[test]
+    fn test_openssl_certificate_is_pinned() {
+        let tls = AxonTlsConfig::new(
+            CertificateDer::from(OPENSSL_CERT),
+            PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(OPENSSL_KEY)),
+        );
+        let certificate = tls.chain_certificate().unwrap();
+        assert_eq!(certificate.algorithm, CERTIFICATE_ALGORITHM_ED25519);
+        assert_eq!(certificate.as_der(), OPENSSL_SPKI);
+
+        // Pinning the published key or the whole certificate both succeed
+        handshake(&tls, certificate.as_der()).unwrap();
+        handshake(&tls, OPENSSL_CERT).unwrap();
+
+        let other = AxonTlsConfig::generate("other").unwrap();
+        let result = handshake(&tls, other.chain_certificate().unwrap().as_der());
+        assert!(matches!(
+            result,
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::UnknownIssuer
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_generated_certificate_fits_on_chain() {
+        let tls =
+            AxonTlsConfig::generate("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+        let certificate = tls.chain_certificate().unwrap();
+
+        assert_eq!(certificate.algorithm, CERTIFICATE_ALGORITHM_ED25519);
+        assert!(certificate.as_der().len() <= MAX_CERTIFICATE_PUBLIC_KEY_LEN);
+        let encoded = certificate.encode();
+        assert_eq!(encoded[0], CERTIFICATE_ALGORITHM_ED25519);
+        assert_eq!(Certificate::decode(&encoded), Some(certificate));
+
+        assert!(tls.server_config().is_ok());
+    }
+
+    #[test]
+    fn test_certificates_are_unique() {
+        let a = AxonTlsConfig::generate("hotkey").unwrap();
+        let b = AxonTlsConfig::generate("hotkey").unwrap();
+        assert_ne!(
+            a.chain_certificate().unwrap(),
+            b.chain_certificate().unwrap()
+        );
+    }
+}
@@ -15,9 +15,12 @@
 //!   `epoch = (block + netuid + 1) / (tempo + 1)`
 //!   `epoch_start_block = epoch * (tempo + 1) - (netuid + 1)`
 //!
+//! The arithmetic lives in [`crate::core::epoch`].
+//!
 //! Reference: `pallets/subtensor/src/subnets/weights.rs`
 
 use crate::chain::BittensorClient;
+use crate::core::epoch;
 use anyhow::Result;
 
 /// Bittensor epoch phase
@@ -145,19 +148,13 @@ impl EpochTracker {
         if self.tempo == 0 {
             return 0;
         }
-        let tempo_plus_one = self.tempo.saturating_add(1);
-        let netuid_plus_one = (self.netuid as u64).saturating_add(1);
-        block.saturating_add(netuid_plus_one) / tempo_plus_one
+        epoch::epoch_index(self.netuid, self.tempo, block)
     }
 
     /// Calculate first block of an epoch matching subtensor exactly:
     /// `first_block = epoch * (tempo + 1) - (netuid + 1)`
     fn epoch_start_block(&self, epoch: u64) -> u64 {
-        let tempo_plus_one = self.tempo.saturating_add(1);
-        let netuid_plus_one = (self.netuid as u64).saturating_add(1);
-        epoch
-            .saturating_mul(tempo_plus_one)
-            .saturating_sub(netuid_plus_one)
+        epoch::epoch_start_block(self.netuid, self.tempo, epoch)
     }
 
     /// Get current epoch info for a block
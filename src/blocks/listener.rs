@@ -4,9 +4,11 @@
 //! - New blocks
 //! - Epoch transitions
 //! - Phase changes (evaluation -> commit -> reveal)
+//! - Decoded chain events per block (opt-in via `decode_events`)
 
 use crate::blocks::epoch_tracker::{EpochInfo, EpochPhase, EpochTracker, EpochTransition};
-use crate::chain::BittensorClient;
+use crate::chain::{BittensorClient, SubtensorEvent};
+use futures::stream::BoxStream;
 use futures::StreamExt;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
@@ -28,6 +30,12 @@ pub enum BlockEvent {
         new_phase: EpochPhase,
         epoch: u64,
     },
+    /// Decoded events of a finalized block, sent after its `NewBlock`
+    /// when `decode_events` is enabled
+    Events {
+        block_number: u64,
+        events: Vec<SubtensorEvent>,
+    },
     /// Connection error (will retry)
     ConnectionError(String),
     /// Listener stopped
@@ -45,6 +53,8 @@ pub struct BlockListenerConfig {
     pub auto_reconnect: bool,
     /// Reconnect delay in milliseconds
     pub reconnect_delay_ms: u64,
+    /// Fetch and decode the events of every block
+    pub decode_events: bool,
 }
 
 impl Default for BlockListenerConfig {
@@ -54,6 +64,7 @@ impl Default for BlockListenerConfig {
             channel_capacity: 100,
             auto_reconnect: true,
             reconnect_delay_ms: 5000,
+            decode_events: false,
         }
     }
 }
@@ -126,7 +137,18 @@ impl BlockListener {
                 }
 
                 // Subscribe to finalized blocks
-                match client.subscribe_finalized_blocks().await {
+                let subscription: Result<BoxStream<'_, _>, _> = if config.decode_events {
+                    client
+                        .subscribe_finalized_blocks_with_events()
+                        .await
+                        .map(|s| s.map(|r| r.map(|(n, events)| (n, Some(events)))).boxed())
+                } else {
+                    client
+                        .subscribe_finalized_blocks()
+                        .await
+                        .map(|s| s.map(|r| r.map(|n| (n, None))).boxed())
+                };
+                match subscription {
                     Ok(mut block_stream) => {
                         while let Some(result) = block_stream.next().await {
                             // Check if we should stop
@@ -135,7 +157,7 @@ impl BlockListener {
                             }
 
                             match result {
-                                Ok(block_number) => {
+                                Ok((block_number, events)) => {
                                     // Update epoch tracker and get info
                                     let mut tracker = epoch_tracker.write().await;
 
@@ -168,6 +190,13 @@ impl BlockListener {
                                         block_number,
                                         epoch_info,
                                     });
+
+                                    if let Some(events) = events {
+                                        let _ = event_tx.send(BlockEvent::Events {
+                                            block_number,
+                                            events,
+                                        });
+                                    }
                                 }
                                 Err(e) => {
                                     let _ =
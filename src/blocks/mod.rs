@@ -4,9 +4,12 @@
 //! - Block subscription via `subscribe_finalized_blocks`
 //! - Epoch tracking and phase detection (evaluation, commit, reveal)
 //! - Events for epoch transitions
+//! - Async callbacks at epoch phases via `EpochScheduler`
 
 mod epoch_tracker;
 mod listener;
+mod scheduler;
 
 pub use epoch_tracker::*;
 pub use listener::*;
+pub use scheduler::*;
@@ -0,0 +1,372 @@
+//! Epoch-phase callbacks for validators
+//!
+//! [`EpochScheduler`] follows finalized blocks for one subnet and runs async
+//! callbacks at fixed points of every epoch:
+//! - [`Phase::EvaluationStart`] on the first block of the epoch
+//! - [`Phase::CommitWindow`] `blocks_before_end` blocks before the next epoch
+//! - [`Phase::RevealWindow`] on the first block of the epoch, when commits
+//!   made `reveal_period_epochs` earlier become revealable
+//!
+//! Each callback runs at most once per epoch. If its block was not seen
+//! (started mid-epoch, reconnected after an outage), it fires on the first
+//! block seen after it in the same epoch with [`PhaseContext::late`] set.
+//! Epochs missed entirely are not replayed.
+
+use crate::blocks::epoch_tracker::{EpochInfo, EpochTracker};
+use crate::chain::BittensorClient;
+use anyhow::Result;
+use futures::future::BoxFuture;
+use futures::{FutureExt, StreamExt};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+
+/// Point in an epoch at which a callback runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// First block of the epoch
+    EvaluationStart,
+    /// `blocks_before_end` blocks before the next epoch starts
+    CommitWindow { blocks_before_end: u64 },
+    /// First block of the epoch, for commits from `reveal_period_epochs` ago
+    RevealWindow,
+}
+
+impl Phase {
+    /// Block at which this phase starts in the epoch described by `info`
+    pub fn target_block(&self, info: &EpochInfo) -> u64 {
+        match self {
+            Phase::EvaluationStart | Phase::RevealWindow => info.epoch_start_block,
+            Phase::CommitWindow { blocks_before_end } => info
+                .next_epoch_start_block
+                .saturating_sub(*blocks_before_end)
+                .max(info.epoch_start_block),
+        }
+    }
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::EvaluationStart => write!(f, "evaluation start"),
+            Phase::CommitWindow { blocks_before_end } => {
+                write!(f, "commit window ({} blocks before end)", blocks_before_end)
+            }
+            Phase::RevealWindow => write!(f, "reveal window"),
+        }
+    }
+}
+
+/// What a phase callback is invoked with
+#[derive(Debug, Clone)]
+pub struct PhaseContext {
+    /// Phase that fired
+    pub phase: Phase,
+    /// Finalized block that triggered the callback
+    pub block: u64,
+    /// Block the phase was scheduled for
+    pub target_block: u64,
+    /// The scheduled block was not seen, so the callback fires after it
+    pub late: bool,
+    /// Epoch the callback belongs to
+    pub epoch: EpochInfo,
+}
+
+impl PhaseContext {
+    /// Epoch whose commits become revealable in this epoch
+    pub fn reveal_epoch(&self) -> u64 {
+        self.epoch
+            .epoch_number
+            .saturating_sub(self.epoch.reveal_period_epochs)
+    }
+}
+
+type PhaseCallback = Arc<dyn Fn(PhaseContext) -> BoxFuture<'static, Result<()>> + Send + Sync>;
+
+struct Registration {
+    phase: Phase,
+    callback: PhaseCallback,
+    last_epoch: Option<u64>,
+}
+
+/// Runs callbacks at epoch phases of a subnet
+pub struct EpochScheduler {
+    tracker: EpochTracker,
+    registrations: Vec<Registration>,
+    reconnect_delay: Duration,
+}
+
+impl EpochScheduler {
+    /// Create a scheduler for `netuid` with no callbacks
+    pub fn new(netuid: u16) -> Self {
+        Self {
+            tracker: EpochTracker::new(netuid),
+            registrations: Vec::new(),
+            reconnect_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Initial delay before resubscribing after the block stream fails
+    ///
+    /// Doubles on each consecutive failure, up to one minute.
+    pub fn with_reconnect_delay(mut self, delay: Duration) -> Self {
+        self.reconnect_delay = delay;
+        self
+    }
+
+    /// Run `callback` once per epoch when `phase` is reached
+    ///
+    /// Callbacks run on their own tasks, so a slow callback does not delay
+    /// the others. An error is logged and the callback runs again next epoch.
+    pub fn on_phase<F, Fut>(mut self, phase: Phase, callback: F) -> Self
+    where
+        F: Fn(PhaseContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.registrations.push(Registration {
+            phase,
+            callback: Arc::new(move |ctx| callback(ctx).boxed()),
+            last_epoch: None,
+        });
+        self
+    }
+
+    /// Load the subnet's tempo and reveal period, then follow finalized blocks
+    /// in the background until the returned handle is shut down
+    pub async fn start(mut self, client: Arc<BittensorClient>) -> Result<SchedulerHandle> {
+        self.tracker.init(&client).await?;
+        if self.tracker.tempo() == 0 {
+            anyhow::bail!(
+                "Subnet {} has tempo 0; epochs never advance",
+                self.tracker.netuid()
+            );
+        }
+
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn(self.run(client, cancel.clone()));
+        Ok(SchedulerHandle { cancel, task })
+    }
+
+    async fn run(mut self, client: Arc<BittensorClient>, cancel: CancellationToken) {
+        let mut tasks = JoinSet::new();
+        let mut delay = self.reconnect_delay;
+        let mut reconnecting = false;
+
+        'connection: loop {
+            if reconnecting {
+                // Tempo or reveal period may have changed while disconnected
+                if let Err(e) = self.tracker.init(&client).await {
+                    warn!("Failed to refresh epoch parameters: {}", e);
+                }
+            }
+
+            // Catch up on the head before waiting for the next block
+            match client.block_number().await {
+                Ok(head) => self.dispatch(head, &mut tasks),
+                Err(e) => warn!("Failed to read head block: {}", e),
+            }
+
+            match client.subscribe_finalized_blocks().await {
+                Ok(stream) => {
+                    let mut stream = std::pin::pin!(stream);
+                    loop {
+                        let next = tokio::select! {
+                            _ = cancel.cancelled() => break 'connection,
+                            next = stream.next() => next,
+                        };
+                        match next {
+                            Some(Ok(block)) => {
+                                delay = self.reconnect_delay;
+                                self.dispatch(block, &mut tasks);
+                                while tasks.try_join_next().is_some() {}
+                            }
+                            Some(Err(e)) => {
+                                warn!("Block subscription error: {}", e);
+                                break;
+                            }
+                            None => {
+                                warn!("Block subscription ended");
+                                break;
+                            }
+                        }
+                    }
+                }
+                Err(e) => warn!("Failed to subscribe to finalized blocks: {}", e),
+            }
+
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = tokio::time::sleep(delay) => {}
+            }
+            delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+            reconnecting = true;
+        }
+
+        // Let callbacks already running finish
+        while let Some(result) = tasks.join_next().await {
+            if let Err(e) = result {
+                warn!("Epoch callback panicked: {}", e);
+            }
+        }
+    }
+
+    fn dispatch(&mut self, block: u64, tasks: &mut JoinSet<()>) {
+        let info = self.tracker.get_epoch_info(block);
+        for (callback, ctx) in self.due(&info) {
+            debug!(
+                "Running {} callback for epoch {} at block {} (late: {})",
+                ctx.phase, ctx.epoch.epoch_number, ctx.block, ctx.late
+            );
+            tasks.spawn(async move {
+                let (phase, epoch) = (ctx.phase, ctx.epoch.epoch_number);
+                if let Err(e) = callback(ctx).await {
+                    warn!("{} callback for epoch {} failed: {}", phase, epoch, e);
+                }
+            });
+        }
+    }
+
+    /// Callbacks due at `info.current_block`, marked as run for its epoch
+    fn due(&mut self, info: &EpochInfo) -> Vec<(PhaseCallback, PhaseContext)> {
+        let mut due = Vec::new();
+        for registration in &mut self.registrations {
+            let target_block = registration.phase.target_block(info);
+            if info.current_block < target_block
+                || registration.last_epoch >= Some(info.epoch_number)
+            {
+                continue;
+            }
+            registration.last_epoch = Some(info.epoch_number);
+            due.push((
+                registration.callback.clone(),
+                PhaseContext {
+                    phase: registration.phase,
+                    block: info.current_block,
+                    target_block,
+                    late: info.current_block > target_block,
+                    epoch: info.clone(),
+                },
+            ));
+        }
+        due
+    }
+}
+
+/// Handle to a running [`EpochScheduler`]
+pub struct SchedulerHandle {
+    cancel: CancellationToken,
+    task: JoinHandle<()>,
+}
+
+impl SchedulerHandle {
+    /// Stop following blocks and wait for running callbacks to finish
+    pub async fn shutdown(self) {
+        self.cancel.cancel();
+        if let Err(e) = self.task.await {
+            warn!("Epoch scheduler task failed: {}", e);
+        }
+    }
+
+    /// Whether the scheduler has stopped
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // netuid 1, tempo 360: epoch 1 spans blocks 359..720, epoch 2 720..1081
+    fn scheduler() -> EpochScheduler {
+        let mut scheduler = EpochScheduler::new(1)
+            .on_phase(Phase::EvaluationStart, |_| async { Ok(()) })
+            .on_phase(
+                Phase::CommitWindow {
+                    blocks_before_end: 30,
+                },
+                |_| async { Ok(()) },
+            )
+            .on_phase(Phase::RevealWindow, |_| async { Ok(()) });
+        scheduler.tracker.set_tempo(360);
+        scheduler
+    }
+
+    fn fired(scheduler: &mut EpochScheduler, block: u64) -> Vec<(Phase, bool)> {
+        let info = scheduler.tracker.get_epoch_info(block);
+        scheduler
+            .due(&info)
+            .into_iter()
+            .map(|(_, ctx)| (ctx.phase, ctx.late))
+            .collect()
+    }
+
+    #[test]
+    fn test_phases_fire_once_per_epoch() {
+        let mut s = scheduler();
+        let commit = Phase::CommitWindow {
+            blocks_before_end: 30,
+        };
+
+        assert_eq!(
+            fired(&mut s, 359),
+            [
+                (Phase::EvaluationStart, false),
+                (Phase::RevealWindow, false)
+            ]
+        );
+        assert!(fired(&mut s, 360).is_empty());
+        assert!(fired(&mut s, 689).is_empty());
+        assert_eq!(fired(&mut s, 690), [(commit, false)]);
+        // Same block delivered twice (head read, then subscription)
+        assert!(fired(&mut s, 690).is_empty());
+        assert!(fired(&mut s, 719).is_empty());
+        assert_eq!(fired(&mut s, 720).len(), 2);
+    }
+
+    #[test]
+    fn test_missed_blocks_fire_late() {
+        let mut s = scheduler();
+        let commit = Phase::CommitWindow {
+            blocks_before_end: 30,
+        };
+
+        // Started mid-epoch 1
+        assert_eq!(
+            fired(&mut s, 500),
+            [(Phase::EvaluationStart, true), (Phase::RevealWindow, true)]
+        );
+        // Disconnected over the commit block
+        assert_eq!(fired(&mut s, 700), [(commit, true)]);
+        // Epoch 2 skipped entirely: only epoch 3 fires
+        let fired_epoch3 = fired(&mut s, 1_100);
+        assert_eq!(fired_epoch3.len(), 2);
+        assert!(fired_epoch3.iter().all(|(_, late)| *late));
+    }
+
+    #[test]
+    fn test_commit_window_target() {
+        let s = scheduler();
+        let info = s.tracker.get_epoch_info(400);
+        assert_eq!(
+            Phase::CommitWindow {
+                blocks_before_end: 30
+            }
+            .target_block(&info),
+            690
+        );
+        // A window longer than the epoch opens at its start
+        assert_eq!(
+            Phase::CommitWindow {
+                blocks_before_end: 1_000
+            }
+            .target_block(&info),
+            359
+        );
+    }
+}
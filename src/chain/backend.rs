@@ -0,0 +1,230 @@
+//! Chain access abstraction
+//!
+//! [`ChainBackend`] covers the calls query and extrinsic helpers make on a
+//! node: storage reads, storage iteration, runtime API calls, extrinsic
+//! submission, fee estimation, the current block number and the runtime
+//! constants. [`BittensorClient`] implements it against a live node; with the
+//! `test-utils` feature, `MockChain` implements it in memory.
+//!
+//! [`Subtensor`](crate::Subtensor) and the free functions in `queries`,
+//! `metagraph`, `validator` and `crv4` take any backend. The few that decode
+//! through runtime metadata, read at a historical block or follow block
+//! subscriptions still need a [`BittensorClient`].
+
+use super::{BittensorClient, BittensorSigner, ChainConstants, Error, ExtrinsicWait};
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
+use subxt::dynamic::Value;
+
+/// Read and write access to a Subtensor chain
+pub trait ChainBackend: Send + Sync {
+    /// Fetch a storage value by its map keys
+    fn storage_with_keys(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> impl Future<Output = Result<Option<Value>, Error>> + Send;
+
+    /// Fetch a storage value, optionally by its map keys
+    fn storage(
+        &self,
+        module: &str,
+        entry: &str,
+        key: Option<Vec<Value>>,
+    ) -> impl Future<Output = Result<Option<Value>, Error>> + Send {
+        self.storage_with_keys(module, entry, key.unwrap_or_default())
+    }
+
+    /// Iterate a storage map under a key prefix, yielding raw keys and values
+    fn storage_iter(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> impl Future<Output = Result<Vec<(Vec<u8>, Value)>, Error>> + Send;
+
+    /// Call a runtime API with SCALE-encoded params, returning the raw result
+    fn runtime_api_call(
+        &self,
+        runtime_api: &str,
+        method: &str,
+        params: Option<Vec<u8>>,
+    ) -> impl Future<Output = Result<Vec<u8>, Error>> + Send;
+
+    /// Sign and submit an extrinsic, returning its hash
+    fn submit_extrinsic(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+        signer: &BittensorSigner,
+        wait_for: ExtrinsicWait,
+    ) -> impl Future<Output = Result<String, Error>> + Send;
+
+    /// Estimate the fee, in RAO, of an extrinsic from `signer`
+    ///
+    /// Backends that cannot build extrinsics report an error.
+    fn estimate_fee(
+        &self,
+        module: &str,
+        function: &str,
+        _args: Vec<Value>,
+        _signer: &BittensorSigner,
+    ) -> impl Future<Output = Result<u128, Error>> + Send {
+        let message = format!("Cannot estimate the fee of {}::{}", module, function);
+        async move { Err(Error::Transaction(message)) }
+    }
+
+    /// Latest finalized block number
+    fn block_number(&self) -> impl Future<Output = Result<u64, Error>> + Send;
+
+    /// Runtime constants of the chain
+    ///
+    /// Backends without runtime metadata report the Finney defaults.
+    fn constants(&self) -> &ChainConstants {
+        static DEFAULTS: OnceLock<ChainConstants> = OnceLock::new();
+        DEFAULTS.get_or_init(ChainConstants::default)
+    }
+}
+
+impl ChainBackend for BittensorClient {
+    fn storage_with_keys(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> impl Future<Output = Result<Option<Value>, Error>> + Send {
+        BittensorClient::storage_with_keys(self, module, entry, keys)
+    }
+
+    fn storage(
+        &self,
+        module: &str,
+        entry: &str,
+        key: Option<Vec<Value>>,
+    ) -> impl Future<Output = Result<Option<Value>, Error>> + Send {
+        BittensorClient::storage(self, module, entry, key)
+    }
+
+    fn storage_iter(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> impl Future<Output = Result<Vec<(Vec<u8>, Value)>, Error>> + Send {
+        BittensorClient::storage_iter(self, module, entry, keys)
+    }
+
+    fn runtime_api_call(
+        &self,
+        runtime_api: &str,
+        method: &str,
+        params: Option<Vec<u8>>,
+    ) -> impl Future<Output = Result<Vec<u8>, Error>> + Send {
+        BittensorClient::runtime_api_call(self, runtime_api, method, params)
+    }
+
+    fn submit_extrinsic(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+        signer: &BittensorSigner,
+        wait_for: ExtrinsicWait,
+    ) -> impl Future<Output = Result<String, Error>> + Send {
+        BittensorClient::submit_extrinsic(self, module, function, args, signer, wait_for)
+    }
+
+    fn estimate_fee(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+        signer: &BittensorSigner,
+    ) -> impl Future<Output = Result<u128, Error>> + Send {
+        BittensorClient::estimate_fee(self, module, function, args, signer)
+    }
+
+    fn block_number(&self) -> impl Future<Output = Result<u64, Error>> + Send {
+        BittensorClient::block_number(self)
+    }
+
+    fn constants(&self) -> &ChainConstants {
+        BittensorClient::constants(self)
+    }
+}
+
+/// Forward to the pointee, so `&client` and shared clients work alike
+macro_rules! forward_backend {
+    ($($ty:ty),*) => {$(
+        impl<T: ChainBackend + ?Sized> ChainBackend for $ty {
+            fn storage_with_keys(
+                &self,
+                module: &str,
+                entry: &str,
+                keys: Vec<Value>,
+            ) -> impl Future<Output = Result<Option<Value>, Error>> + Send {
+                (**self).storage_with_keys(module, entry, keys)
+            }
+
+            fn storage(
+                &self,
+                module: &str,
+                entry: &str,
+                key: Option<Vec<Value>>,
+            ) -> impl Future<Output = Result<Option<Value>, Error>> + Send {
+                (**self).storage(module, entry, key)
+            }
+
+            fn storage_iter(
+                &self,
+                module: &str,
+                entry: &str,
+                keys: Vec<Value>,
+            ) -> impl Future<Output = Result<Vec<(Vec<u8>, Value)>, Error>> + Send {
+                (**self).storage_iter(module, entry, keys)
+            }
+
+            fn runtime_api_call(
+                &self,
+                runtime_api: &str,
+                method: &str,
+                params: Option<Vec<u8>>,
+            ) -> impl Future<Output = Result<Vec<u8>, Error>> + Send {
+                (**self).runtime_api_call(runtime_api, method, params)
+            }
+
+            fn submit_extrinsic(
+                &self,
+                module: &str,
+                function: &str,
+                args: Vec<Value>,
+                signer: &BittensorSigner,
+                wait_for: ExtrinsicWait,
+            ) -> impl Future<Output = Result<String, Error>> + Send {
+                (**self).submit_extrinsic(module, function, args, signer, wait_for)
+            }
+
+            fn estimate_fee(
+                &self,
+                module: &str,
+                function: &str,
+                args: Vec<Value>,
+                signer: &BittensorSigner,
+            ) -> impl Future<Output = Result<u128, Error>> + Send {
+                (**self).estimate_fee(module, function, args, signer)
+            }
+
+            fn block_number(&self) -> impl Future<Output = Result<u64, Error>> + Send {
+                (**self).block_number()
+            }
+
+            fn constants(&self) -> &ChainConstants {
+                (**self).constants()
+            }
+        }
+    )*};
+}
+
+forward_backend!(&T, Arc<T>);
@@ -0,0 +1,241 @@
+//! Queries pinned to a historical block
+//!
+//! [`BittensorClient::at_height`] resolves a block number to a [`BlockRef`]
+//! whose storage, balance and runtime API queries all read state at that
+//! block. Heights whose state the node has pruned fail with
+//! [`BlockNotFound`]; historical analysis needs an archive node.
+//!
+//! A [`BlockRef`] is also a read-only [`ChainBackend`], so helpers generic
+//! over the backend (such as
+//! [`sync_metagraph`](crate::metagraph::sync_metagraph)) read a consistent
+//! view of one block.
+
+use super::{
+    decode_free_balance, BittensorClient, BittensorSigner, ChainBackend, ChainConstants, Error,
+    ExtrinsicWait,
+};
+use crate::errors::BlockNotFound;
+use sp_core::crypto::AccountId32;
+use sp_core::H256;
+use subxt::dynamic::Value;
+
+/// Handle for querying chain state at a fixed block
+#[derive(Clone, Copy)]
+pub struct BlockRef<'a> {
+    client: &'a BittensorClient,
+    number: u64,
+    hash: H256,
+}
+
+impl BittensorClient {
+    /// Pin queries to the block at `height`
+    ///
+    /// # Errors
+    /// [`Error::BlockNotFound`] if the block does not exist yet or its state
+    /// has been pruned by the node.
+    pub async fn at_height(&self, height: u64) -> Result<BlockRef<'_>, Error> {
+        let hash = self.block_hash(height).await?.ok_or_else(|| {
+            BlockNotFound::with_number(format!("no block at height {}", height), height)
+        })?;
+
+        // Non-archive nodes still know old block hashes but not their state
+        let probe = subxt::dynamic::storage("System", "Number", Vec::<Value>::new());
+        if let Err(e) = self.api.storage().at(hash).fetch(&probe).await {
+            return Err(if is_pruned_state_error(&e.to_string()) {
+                BlockNotFound::with_number(
+                    format!("state at height {} has been pruned ({})", height, e),
+                    height,
+                )
+                .into()
+            } else {
+                e.into()
+            });
+        }
+
+        Ok(BlockRef {
+            client: self,
+            number: height,
+            hash,
+        })
+    }
+}
+
+impl<'a> BlockRef<'a> {
+    /// Block number this handle is pinned to
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+
+    /// Block hash this handle is pinned to
+    pub fn hash(&self) -> H256 {
+        self.hash
+    }
+
+    /// Client this handle queries through
+    pub fn client(&self) -> &'a BittensorClient {
+        self.client
+    }
+
+    /// Read a storage entry at this block
+    pub async fn storage(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> Result<Option<Value>, Error> {
+        self.client
+            .storage_at_block(module, entry, keys, self.hash)
+            .await
+    }
+
+    /// Get the free balance of an account at this block
+    pub async fn account_balance(&self, account: &AccountId32) -> Result<u128, Error> {
+        use parity_scale_codec::Encode;
+
+        let keys = vec![Value::from_bytes(account.encode())];
+        match self.storage("System", "Account", keys).await? {
+            Some(value) => decode_free_balance(&value),
+            None => Err(Error::Decoding(
+                "Account balance not found in storage".to_string(),
+            )),
+        }
+    }
+
+    /// Call a runtime API method at this block, decoding the result
+    pub async fn runtime_api(
+        &self,
+        runtime_api: &str,
+        method: &str,
+        params: Vec<Value>,
+    ) -> Result<Option<Value>, Error> {
+        let api_call = subxt::dynamic::runtime_api_call(runtime_api, method, params);
+        let result = self
+            .client
+            .api
+            .runtime_api()
+            .at(self.hash)
+            .call(api_call)
+            .await?;
+
+        match result.to_value() {
+            Ok(v) => Ok(Some(v.remove_context())),
+            Err(e) => Err(Error::Decoding(format!(
+                "Failed to decode runtime API result: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Call a runtime API method at this block with SCALE-encoded parameters
+    pub async fn runtime_api_call(
+        &self,
+        runtime_api: &str,
+        method: &str,
+        params: Option<Vec<u8>>,
+    ) -> Result<Vec<u8>, Error> {
+        let runtime_api_call = format!("{}_{}", runtime_api, method);
+        let payload = self
+            .client
+            .api
+            .runtime_api()
+            .at(self.hash)
+            .call_raw(&runtime_api_call, params.as_deref())
+            .await?;
+        Ok(payload)
+    }
+
+    /// Runtime spec version active at this block
+    pub async fn spec_version(&self) -> Result<u32, Error> {
+        let version = self
+            .client
+            .legacy_rpc()
+            .state_get_runtime_version(Some(self.hash))
+            .await
+            .map_err(|e| Error::Rpc(format!("Failed to get runtime version: {}", e)))?;
+        Ok(version.spec_version)
+    }
+}
+
+impl ChainBackend for BlockRef<'_> {
+    async fn storage_with_keys(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> Result<Option<Value>, Error> {
+        BlockRef::storage(self, module, entry, keys).await
+    }
+
+    async fn storage_iter(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> Result<Vec<(Vec<u8>, Value)>, Error> {
+        self.client
+            .storage_iter_at_block(module, entry, keys, self.hash)
+            .await
+    }
+
+    async fn runtime_api_call(
+        &self,
+        runtime_api: &str,
+        method: &str,
+        params: Option<Vec<u8>>,
+    ) -> Result<Vec<u8>, Error> {
+        BlockRef::runtime_api_call(self, runtime_api, method, params).await
+    }
+
+    async fn submit_extrinsic(
+        &self,
+        module: &str,
+        function: &str,
+        _args: Vec<Value>,
+        _signer: &BittensorSigner,
+        _wait_for: ExtrinsicWait,
+    ) -> Result<String, Error> {
+        Err(Error::Transaction(format!(
+            "Cannot submit {}::{} at historical block {}",
+            module, function, self.number
+        )))
+    }
+
+    async fn block_number(&self) -> Result<u64, Error> {
+        Ok(self.number)
+    }
+
+    fn constants(&self) -> &ChainConstants {
+        self.client.constants()
+    }
+}
+
+impl std::fmt::Debug for BlockRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockRef")
+            .field("number", &self.number)
+            .field("hash", &self.hash)
+            .finish()
+    }
+}
+
+/// Whether an RPC error reports state discarded by a pruning node
+fn is_pruned_state_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    ["state already discarded", "unknown block", "pruned"]
+        .iter()
+        .any(|needle| message.contains(needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_pruned_state_error() {
+        assert!(is_pruned_state_error(
+            "Rpc error: State already discarded for 0x1234"
+        ));
+        assert!(is_pruned_state_error("UnknownBlock: Unknown block 0xab"));
+        assert!(!is_pruned_state_error("Connection reset by peer"));
+    }
+}
@@ -0,0 +1,385 @@
+//! Configurable construction of [`BittensorClient`]
+//!
+//! [`BittensorClient::new`] connects with the builder's defaults. The
+//! builder exposes the WebSocket transport settings that matter for private
+//! or misbehaving RPC providers: a bound on how long connecting may take,
+//! extra handshake headers (e.g. basic auth), the message size limit, and
+//! for local development, skipping TLS certificate verification.
+//!
+//! jsonrpsee closes the connection when a message exceeds the size limit, so
+//! clients built here reconnect on the next request. A query whose response
+//! was too large can then fall back to smaller requests on the same client.
+//!
+//! [`BittensorClientBuilder::metadata_cache`] loads the runtime metadata from
+//! disk instead of downloading it, see [`super::metadata_cache`].
+
+use super::metadata_cache::{self, MetadataCache, MetadataCacheDir};
+use super::upgrade::UpgradeWatcher;
+use super::{
+    BittensorClient, CallProfiler, ChainConstants, Error, NonceManager, RuntimeUpgradeMonitor,
+    CONNECTION_TIMEOUT,
+};
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, SignatureScheme};
+use serde_json::value::RawValue;
+use std::sync::Arc;
+use std::time::Duration;
+use subxt::backend::rpc::{RawRpcFuture, RawRpcSubscription, RpcClient, RpcClientT};
+use subxt::ext::subxt_rpcs::Error as RpcError;
+use subxt::PolkadotConfig;
+use tokio::sync::RwLock;
+
+/// Default request and response size limit
+///
+/// jsonrpsee's own default of 10 MiB is exceeded by `MetagraphInfo` and
+/// weight queries on 4096-UID subnets.
+pub const DEFAULT_MAX_MESSAGE_SIZE: u32 = 32 * 1024 * 1024;
+
+/// Subscription buffer size used by subxt's own client
+const SUBSCRIPTION_BUFFER_CAPACITY: usize = 4096;
+
+/// Builder for a [`BittensorClient`] with custom connection settings
+///
+/// ```ignore
+/// use std::time::Duration;
+///
+/// let client = BittensorClient::builder("wss://rpc.example.com:443")
+///     .timeout(Duration::from_secs(5))
+///     .header("authorization", "Basic dXNlcjpwYXNz")
+///     .max_message_size(64 * 1024 * 1024)
+///     .build()
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct BittensorClientBuilder {
+    url: String,
+    timeout: Duration,
+    headers: Vec<(String, String)>,
+    accept_invalid_certs: bool,
+    max_message_size: u32,
+    metadata_cache: MetadataCacheDir,
+}
+
+impl BittensorClientBuilder {
+    /// Start building a client for `rpc_url` (`ws://` or `wss://`)
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self {
+            url: rpc_url.into(),
+            timeout: CONNECTION_TIMEOUT,
+            headers: Vec::new(),
+            accept_invalid_certs: false,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            metadata_cache: MetadataCacheDir::Disabled,
+        }
+    }
+
+    /// Fail if connecting, including the initial metadata fetch, takes
+    /// longer than `timeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Send an extra header with the WebSocket handshake
+    ///
+    /// Invalid names or values are reported by [`BittensorClientBuilder::build`].
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Skip TLS certificate verification for `wss://` endpoints
+    ///
+    /// Only for local development, e.g. behind a proxy with a self-signed
+    /// certificate: any server can impersonate the endpoint.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Largest request or response accepted, in bytes
+    ///
+    /// Defaults to [`DEFAULT_MAX_MESSAGE_SIZE`]. A response over the limit
+    /// fails its request and closes the connection, which is reopened for the
+    /// next request. Values above `u32::MAX` are clamped.
+    pub fn max_message_size(mut self, bytes: usize) -> Self {
+        self.max_message_size = u32::try_from(bytes).unwrap_or(u32::MAX);
+        self
+    }
+
+    /// Cache the runtime metadata on disk
+    ///
+    /// `true` uses `~/.bittensor/metadata`, a path uses that directory and
+    /// `false` (the default) always downloads the metadata.
+    pub fn metadata_cache(mut self, cache: impl Into<MetadataCacheDir>) -> Self {
+        self.metadata_cache = cache.into();
+        self
+    }
+
+    /// Connect and fetch the chain metadata
+    pub async fn build(self) -> Result<BittensorClient, Error> {
+        let timeout = self.timeout;
+        let url = self.url.clone();
+        tokio::time::timeout(timeout, self.connect())
+            .await
+            .map_err(|_| timed_out(&url, timeout))?
+    }
+
+    /// Connect without fetching the chain metadata, for raw JSON-RPC calls
+    pub async fn build_rpc(&self) -> Result<RpcClient, Error> {
+        let client =
+            ReconnectingWsClient::connect(&self.url, self.ws_builder()?, self.timeout).await?;
+        Ok(RpcClient::new(client))
+    }
+
+    async fn connect(self) -> Result<BittensorClient, Error> {
+        let rpc = self.build_rpc().await?;
+        let cache = self.metadata_cache.dir().map(MetadataCache::new);
+        let api = match &cache {
+            Some(cache) => metadata_cache::online_client(rpc.clone(), cache).await?,
+            None => subxt::OnlineClient::<PolkadotConfig>::from_rpc_client(rpc.clone()).await?,
+        };
+        let constants = ChainConstants::from_metadata(&api.metadata());
+        let version = api.runtime_version();
+        let upgrades = Arc::new(RuntimeUpgradeMonitor::new(
+            version.spec_version,
+            version.transaction_version,
+        ));
+        let upgrade_watcher = UpgradeWatcher::spawn(api.clone(), upgrades.clone(), cache.clone());
+
+        Ok(BittensorClient {
+            api,
+            rpc_url: self.url,
+            rpc,
+            nonce_manager: Arc::new(NonceManager::new()),
+            rate_limiter: None,
+            max_message_size: self.max_message_size as usize,
+            constants,
+            profiler: Arc::new(CallProfiler::default()),
+            metadata_cache: cache,
+            upgrades,
+            _upgrade_watcher: upgrade_watcher,
+            #[cfg(feature = "static-metadata")]
+            static_interface: Arc::new(Default::default()),
+        })
+    }
+
+    fn ws_builder(&self) -> Result<WsClientBuilder, Error> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| Error::Rpc(format!("Invalid header name {:?}: {}", name, e)))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| Error::Rpc(format!("Invalid value for header {}: {}", name, e)))?;
+            headers.append(name, value);
+        }
+
+        let mut builder = WsClientBuilder::new()
+            .connection_timeout(self.timeout)
+            .set_headers(headers)
+            .max_request_size(self.max_message_size)
+            .max_response_size(self.max_message_size)
+            .max_buffer_capacity_per_subscription(SUBSCRIPTION_BUFFER_CAPACITY);
+        if self.accept_invalid_certs {
+            builder = builder.with_custom_cert_store(accept_any_cert_config()?);
+        }
+        Ok(builder)
+    }
+}
+
+fn timed_out(url: &str, timeout: Duration) -> Error {
+    Error::Rpc(format!(
+        "Connection to {} timed out after {:?}",
+        url, timeout
+    ))
+}
+
+/// WebSocket client that reconnects on the first request after its
+/// connection closed
+///
+/// Requests in flight when the connection closes fail; they are not resent,
+/// since resending an oversized query would fail the same way. Subscriptions
+/// end with the connection and have to be made again.
+struct ReconnectingWsClient {
+    url: String,
+    builder: WsClientBuilder,
+    timeout: Duration,
+    client: RwLock<Arc<WsClient>>,
+}
+
+impl ReconnectingWsClient {
+    async fn connect(
+        url: &str,
+        builder: WsClientBuilder,
+        timeout: Duration,
+    ) -> Result<Self, Error> {
+        let client = Self::open(url, &builder, timeout).await?;
+        Ok(Self {
+            url: url.to_string(),
+            builder,
+            timeout,
+            client: RwLock::new(Arc::new(client)),
+        })
+    }
+
+    async fn open(
+        url: &str,
+        builder: &WsClientBuilder,
+        timeout: Duration,
+    ) -> Result<WsClient, Error> {
+        // The transport timeout does not cover the WebSocket handshake
+        tokio::time::timeout(timeout, builder.clone().build(url))
+            .await
+            .map_err(|_| timed_out(url, timeout))?
+            .map_err(|e| Error::Rpc(format!("Failed to connect to {}: {}", url, e)))
+    }
+
+    /// The current connection, reopened first if it has closed
+    async fn connected(&self) -> Result<Arc<WsClient>, RpcError> {
+        let client = self.client.read().await.clone();
+        if client.is_connected() {
+            return Ok(client);
+        }
+
+        let mut client = self.client.write().await;
+        if !client.is_connected() {
+            tracing::info!("Connection to {} closed, reconnecting", self.url);
+            let reopened = Self::open(&self.url, &self.builder, self.timeout)
+                .await
+                .map_err(|e| RpcError::Client(Box::new(e)))?;
+            *client = Arc::new(reopened);
+        }
+        Ok(client.clone())
+    }
+}
+
+impl RpcClientT for ReconnectingWsClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RawRpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let client = self.connected().await?;
+            client.request_raw(method, params).await
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RawRpcFuture<'a, RawRpcSubscription> {
+        Box::pin(async move {
+            let client = self.connected().await?;
+            client.subscribe_raw(sub, params, unsub).await
+        })
+    }
+}
+
+/// Verifier that accepts any server certificate, still checking that the
+/// server holds the certificate's key
+#[derive(Debug)]
+struct AcceptAnyCert(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyCert {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+fn accept_any_cert_config() -> Result<rustls::ClientConfig, Error> {
+    let provider = CryptoProvider::get_default()
+        .cloned()
+        .unwrap_or_else(|| Arc::new(rustls::crypto::ring::default_provider()));
+    let config = rustls::ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| Error::Rpc(format!("TLS configuration error: {}", e)))?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptAnyCert(provider)))
+        .with_no_client_auth();
+    Ok(config)
+}
+
+impl BittensorClient {
+    /// Start building a client with custom connection settings
+    pub fn builder(rpc_url: impl Into<String>) -> BittensorClientBuilder {
+        BittensorClientBuilder::new(rpc_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let builder = BittensorClientBuilder::new("ws://127.0.0.1:9944");
+        assert_eq!(builder.timeout, CONNECTION_TIMEOUT);
+        assert_eq!(builder.max_message_size, DEFAULT_MAX_MESSAGE_SIZE);
+        assert!(!builder.accept_invalid_certs);
+        assert!(builder.headers.is_empty());
+        assert_eq!(builder.metadata_cache, MetadataCacheDir::Disabled);
+
+        let builder = builder.max_message_size(usize::MAX);
+        assert_eq!(builder.max_message_size, u32::MAX);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_header_rejected() {
+        let result = BittensorClient::builder("ws://127.0.0.1:9944")
+            .header("bad header", "value")
+            .build()
+            .await;
+        assert!(matches!(result, Err(Error::Rpc(msg)) if msg.contains("Invalid header name")));
+    }
+
+    #[test]
+    fn test_accept_any_cert_config() {
+        assert!(accept_any_cert_config().is_ok());
+    }
+}
@@ -6,16 +6,23 @@
 //! - Connection pooling for multiple concurrent connections
 //! - Circuit breaker pattern to prevent cascading failures
 //! - Rate limiting per 12-second block time
+//!
+//! [`Subtensor::with_connection_manager`](crate::Subtensor::with_connection_manager)
+//! routes the high-level API through a [`ConnectionManager`].
 
 use backoff::{
     future::retry, Error as BackoffError, ExponentialBackoff, ExponentialBackoffBuilder,
 };
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, error, info, trace, warn};
 
-use crate::chain::{BittensorClient, Error as ChainError};
+use crate::chain::rate_limit::{CategoryRateLimiter, ExtrinsicCategory};
+use crate::chain::{is_connection_error_message, BittensorClient, Error as ChainError};
+use crate::config::RateLimitConfig;
 
 /// Maximum number of retry attempts for RPC operations
 pub const MAX_RETRIES: u32 = 5;
@@ -270,7 +277,7 @@ impl ConnectionMetadata {
 /// Managed connection wrapper with health tracking
 #[derive(Debug)]
 pub struct ManagedConnection {
-    client: BittensorClient,
+    client: Arc<BittensorClient>,
     metadata: Mutex<ConnectionMetadata>,
     id: u64,
 }
@@ -278,7 +285,7 @@ pub struct ManagedConnection {
 impl ManagedConnection {
     fn new(client: BittensorClient, id: u64) -> Self {
         Self {
-            client,
+            client: Arc::new(client),
             metadata: Mutex::new(ConnectionMetadata::new()),
             id,
         }
@@ -289,6 +296,11 @@ impl ManagedConnection {
         &self.client
     }
 
+    /// Get a shared handle to the underlying client
+    pub fn shared_client(&self) -> Arc<BittensorClient> {
+        self.client.clone()
+    }
+
     /// Record a successful operation
     pub async fn record_success(&self) {
         self.metadata.lock().await.record_success();
@@ -640,7 +652,9 @@ impl Default for BlockRateLimiter {
 pub struct ConnectionManager {
     pool: Arc<ConnectionPool>,
     rate_limiter: BlockRateLimiter,
+    rate_limits: CategoryRateLimiter,
     health_check_interval: Duration,
+    health_checks_started: AtomicBool,
 }
 
 impl ConnectionManager {
@@ -660,60 +674,107 @@ impl ConnectionManager {
         Self {
             pool: Arc::new(ConnectionPool::with_config(&endpoint_str, max_pool_size)),
             rate_limiter: BlockRateLimiter::new(),
+            rate_limits: CategoryRateLimiter::default(),
             health_check_interval,
+            health_checks_started: AtomicBool::new(false),
         }
     }
 
+    /// Set the per-block extrinsic budgets used by `execute_write`
+    pub fn with_rate_limits(mut self, config: &RateLimitConfig) -> Self {
+        self.rate_limits = CategoryRateLimiter::new(config);
+        self
+    }
+
     /// Get a connection from the pool
     pub async fn get_connection(&self) -> Result<Arc<ManagedConnection>, ChainError> {
         self.pool.get_connection().await
     }
 
     /// Execute an operation with full retry and circuit breaker protection
-    pub async fn execute_with_retry<F, Fut, T>(&self, operation: F) -> Result<T, ChainError>
+    ///
+    /// Connection-level failures (dropped sockets, timeouts) are retried with
+    /// backoff on a healthy pooled connection. Other errors, such as a decode
+    /// failure or a dispatch error, would fail on any connection and are
+    /// returned immediately, so only use this for idempotent reads.
+    pub async fn execute_with_retry<F, Fut, T, E>(&self, operation: F) -> Result<T, E>
     where
-        F: Fn(&BittensorClient) -> Fut + Clone,
-        Fut: std::future::Future<Output = Result<T, ChainError>>,
+        F: Fn(Arc<BittensorClient>) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: From<ChainError> + std::fmt::Display,
     {
-        let config = RetryConfig::default();
+        let operation = &operation;
+        let pool = &self.pool;
 
-        let backoff = ExponentialBackoffBuilder::new()
-            .with_initial_interval(config.initial_delay)
-            .with_max_interval(config.max_delay)
-            .with_max_elapsed_time(Some(config.max_elapsed_time))
-            .build();
-
-        let operation = operation.clone();
-
-        retry(backoff, || {
-            let operation = operation.clone();
-            async move {
-                let conn = self
-                    .get_connection()
-                    .await
-                    .map_err(BackoffError::transient)?;
-
-                let result = operation(conn.client()).await;
+        retry_connection_errors(
+            create_backoff_config(),
+            move || async move { self.get_connection().await.map_err(E::from) },
+            move |conn: Arc<ManagedConnection>| async move {
+                let result = operation(conn.shared_client()).await;
 
                 match &result {
                     Ok(_) => conn.record_success().await,
-                    Err(e) => {
+                    Err(e) if is_connection_error_message(&e.to_string()) => {
                         conn.record_failure().await;
                         warn!("Operation failed on connection {}: {}", conn.id(), e);
 
                         // If connection is unhealthy, remove it from pool
                         if !conn.is_healthy().await {
-                            self.pool.remove_connection(conn.id()).await;
+                            pool.remove_connection(conn.id()).await;
                         }
                     }
+                    // The connection worked; the operation itself failed
+                    Err(_) => conn.record_success().await,
                 }
 
-                result.map_err(BackoffError::transient)
-            }
-        })
+                result
+            },
+        )
         .await
     }
 
+    /// Submit an extrinsic within `category`'s per-block budget
+    ///
+    /// Waits for budget (see [`CategoryRateLimiter::acquire`]), then runs
+    /// `operation` on a pooled connection. Unlike `execute_with_retry`, it is
+    /// never rerun: after a timeout or a dropped socket the node may already
+    /// have the extrinsic. The failed connection is dropped from the pool.
+    pub async fn execute_write<F, Fut, T, E>(
+        &self,
+        category: ExtrinsicCategory,
+        operation: F,
+    ) -> Result<T, E>
+    where
+        F: Fn(Arc<BittensorClient>) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: From<ChainError> + std::fmt::Display,
+    {
+        let conn = self.get_connection().await?;
+        self.rate_limits.acquire(conn.client(), category).await?;
+
+        match operation(conn.shared_client()).await {
+            Err(e) if is_connection_error_message(&e.to_string()) => {
+                conn.record_failure().await;
+                warn!(
+                    "Extrinsic failed on connection {}, not resubmitting: {}",
+                    conn.id(),
+                    e
+                );
+                self.pool.remove_connection(conn.id()).await;
+                Err(e)
+            }
+            result => {
+                conn.record_success().await;
+                result
+            }
+        }
+    }
+
+    /// Per-block extrinsic budgets
+    pub fn rate_limits(&self) -> &CategoryRateLimiter {
+        &self.rate_limits
+    }
+
     /// Check if operation can proceed based on rate limiting
     pub async fn check_rate_limit(&self, current_block: u64) -> bool {
         self.rate_limiter.check_and_record(current_block).await
@@ -735,7 +796,12 @@ impl ConnectionManager {
     }
 
     /// Start health check background task
+    ///
+    /// Only the first call spawns the task; later calls are no-ops.
     pub fn start_health_checks(&self) {
+        if self.health_checks_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
         let pool = self.pool.clone();
         let interval = self.health_check_interval;
 
@@ -766,6 +832,40 @@ impl Default for ConnectionManager {
     }
 }
 
+/// Run `operation` on connections from `acquire`, retrying with `backoff`
+/// while it fails with a connection-level error
+///
+/// Failing to acquire a connection is also retried; any other error is
+/// returned as is.
+async fn retry_connection_errors<C, T, E, A, AFut, Op, OpFut>(
+    backoff: ExponentialBackoff,
+    acquire: A,
+    operation: Op,
+) -> Result<T, E>
+where
+    A: Fn() -> AFut,
+    AFut: Future<Output = Result<C, E>>,
+    Op: Fn(C) -> OpFut,
+    OpFut: Future<Output = Result<T, E>>,
+    E: std::fmt::Display,
+{
+    let acquire = &acquire;
+    let operation = &operation;
+
+    retry(backoff, move || async move {
+        let conn = acquire().await.map_err(BackoffError::transient)?;
+        operation(conn).await.map_err(|e| {
+            if is_connection_error_message(&e.to_string()) {
+                debug!("Retrying after connection error: {}", e);
+                BackoffError::transient(e)
+            } else {
+                BackoffError::permanent(e)
+            }
+        })
+    })
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -847,6 +947,78 @@ mod tests {
         assert_eq!(meta.total_operations, 3);
     }
 
+    /// Endpoint that drops every other request
+    #[derive(Default)]
+    struct FlappingEndpoint {
+        requests: std::sync::atomic::AtomicU32,
+    }
+
+    impl FlappingEndpoint {
+        async fn block_number(&self) -> Result<u64, ChainError> {
+            let request = self.requests.fetch_add(1, Ordering::SeqCst);
+            if request % 2 == 0 {
+                Err(ChainError::Rpc("connection reset by peer".to_string()))
+            } else {
+                Ok(100 + request as u64)
+            }
+        }
+
+        fn requests(&self) -> u32 {
+            self.requests.load(Ordering::SeqCst)
+        }
+    }
+
+    fn test_backoff() -> ExponentialBackoff {
+        ExponentialBackoffBuilder::new()
+            .with_initial_interval(Duration::from_millis(1))
+            .with_max_interval(Duration::from_millis(5))
+            .with_max_elapsed_time(Some(Duration::from_secs(5)))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_reads_succeed_on_flapping_endpoint() {
+        let endpoint = Arc::new(FlappingEndpoint::default());
+
+        for _ in 0..10 {
+            let block = retry_connection_errors(
+                test_backoff(),
+                || {
+                    let endpoint = endpoint.clone();
+                    async move { Ok::<_, ChainError>(endpoint) }
+                },
+                |conn: Arc<FlappingEndpoint>| async move { conn.block_number().await },
+            )
+            .await
+            .unwrap();
+            assert!(block > 100);
+        }
+
+        // Each read hit one dropped request before succeeding
+        assert_eq!(endpoint.requests(), 20);
+    }
+
+    #[tokio::test]
+    async fn test_non_connection_errors_are_not_retried() {
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+        let attempts_ref = &attempts;
+
+        let result: Result<u64, ChainError> = retry_connection_errors(
+            test_backoff(),
+            || async { Ok(()) },
+            move |_| async move {
+                attempts_ref.fetch_add(1, Ordering::SeqCst);
+                Err(ChainError::Decoding(
+                    "unexpected storage layout".to_string(),
+                ))
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(ChainError::Decoding(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
     #[tokio::test]
     async fn test_retry_config_default() {
         let config = RetryConfig::default();
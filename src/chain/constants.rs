@@ -0,0 +1,166 @@
+//! Chain constants read from runtime metadata
+//!
+//! [`ChainConstants`] is decoded from the metadata a [`BittensorClient`] is
+//! connected with, so values such as the existential deposit and block time
+//! follow the runtime instead of being hardcoded. Constants missing from
+//! older runtimes fall back to the defaults in [`crate::core::constants`].
+//!
+//! [`BittensorClient`]: super::BittensorClient
+
+use crate::core::constants::{BLOCKTIME, EXISTENTIAL_DEPOSIT_RAO, SS58_FORMAT};
+use crate::utils::balance_newtypes::Rao;
+use crate::utils::ss58::encode_ss58_with_format;
+use sp_core::crypto::AccountId32;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use subxt::ext::scale_value::{self, ValueDef};
+use subxt::Metadata;
+use tracing::debug;
+
+/// Constants of the connected runtime
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainConstants {
+    /// Minimum balance an account must keep (`Balances.ExistentialDeposit`)
+    pub existential_deposit: Rao,
+    /// Expected time between blocks
+    pub block_time: Duration,
+    /// Address format of the chain (`System.SS58Prefix`)
+    pub ss58_prefix: u16,
+    /// Integer constants of `SubtensorModule`, by name
+    pub subtensor: BTreeMap<String, u128>,
+}
+
+impl Default for ChainConstants {
+    /// The hardcoded Finney values, used when metadata is unavailable
+    fn default() -> Self {
+        Self {
+            existential_deposit: Rao::from(EXISTENTIAL_DEPOSIT_RAO),
+            block_time: Duration::from_secs(BLOCKTIME),
+            ss58_prefix: SS58_FORMAT,
+            subtensor: BTreeMap::new(),
+        }
+    }
+}
+
+impl ChainConstants {
+    /// Read the constants from runtime metadata
+    ///
+    /// The block time is taken from `Babe.ExpectedBlockTime`, then
+    /// `Aura.SlotDuration`, then twice `Timestamp.MinimumPeriod`. Any constant
+    /// that is absent or not an integer keeps its default.
+    pub fn from_metadata(metadata: &Metadata) -> Self {
+        let defaults = Self::default();
+        let constant = |pallet: &str, name: &str| integer_constant(metadata, pallet, name);
+
+        let existential_deposit = constant("Balances", "ExistentialDeposit")
+            .map(Rao::from)
+            .unwrap_or_else(|| {
+                debug!("Balances.ExistentialDeposit not in metadata, using default");
+                defaults.existential_deposit
+            });
+
+        let block_time_ms = constant("Babe", "ExpectedBlockTime")
+            .or_else(|| constant("Aura", "SlotDuration"))
+            .or_else(|| constant("Timestamp", "MinimumPeriod").map(|p| p.saturating_mul(2)))
+            .filter(|ms| *ms > 0)
+            .and_then(|ms| u64::try_from(ms).ok());
+        let block_time = match block_time_ms {
+            Some(ms) => Duration::from_millis(ms),
+            None => {
+                debug!("Block time not in metadata, using default");
+                defaults.block_time
+            }
+        };
+
+        let ss58_prefix = constant("System", "SS58Prefix")
+            .and_then(|prefix| u16::try_from(prefix).ok())
+            .unwrap_or(defaults.ss58_prefix);
+
+        let subtensor = metadata
+            .pallet_by_name("SubtensorModule")
+            .map(|pallet| {
+                pallet
+                    .constants()
+                    .filter_map(|c| {
+                        let value = decode_integer(metadata, c.ty(), c.value())?;
+                        Some((c.name().to_string(), value))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            existential_deposit,
+            block_time,
+            ss58_prefix,
+            subtensor,
+        }
+    }
+
+    /// Block time in seconds
+    pub fn block_time_secs(&self) -> f64 {
+        self.block_time.as_secs_f64()
+    }
+
+    /// An integer constant of `SubtensorModule`, e.g. `"InitialTempo"`
+    pub fn subtensor_constant(&self, name: &str) -> Option<u128> {
+        self.subtensor.get(name).copied()
+    }
+
+    /// Encode an account with the chain's SS58 prefix
+    pub fn encode_address(&self, account: &AccountId32) -> String {
+        encode_ss58_with_format(account, self.ss58_prefix)
+    }
+}
+
+/// Decode `pallet.name` as an integer, if it exists
+fn integer_constant(metadata: &Metadata, pallet: &str, name: &str) -> Option<u128> {
+    let constant = metadata.pallet_by_name(pallet)?.constant_by_name(name)?;
+    decode_integer(metadata, constant.ty(), constant.value())
+}
+
+/// Decode a constant holding an integer or a newtype around one
+fn decode_integer(metadata: &Metadata, ty: u32, mut bytes: &[u8]) -> Option<u128> {
+    let value = scale_value::scale::decode_as_type(&mut bytes, ty, metadata.types()).ok()?;
+    if !bytes.is_empty() {
+        return None;
+    }
+    as_integer(&value.remove_context())
+}
+
+fn as_integer(value: &scale_value::Value) -> Option<u128> {
+    match &value.value {
+        ValueDef::Primitive(primitive) => primitive.as_u128(),
+        ValueDef::Composite(composite) if composite.len() == 1 => {
+            as_integer(composite.values().next()?)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let constants = ChainConstants::default();
+        assert_eq!(constants.existential_deposit, Rao::from(500u128));
+        assert_eq!(constants.block_time_secs(), 12.0);
+        assert_eq!(constants.ss58_prefix, 42);
+        assert_eq!(constants.subtensor_constant("InitialTempo"), None);
+    }
+
+    #[test]
+    fn test_encode_address_uses_prefix() {
+        let account = AccountId32::new([7; 32]);
+        let mut constants = ChainConstants::default();
+        assert_eq!(
+            constants.encode_address(&account),
+            crate::utils::ss58::encode_ss58(&account)
+        );
+
+        constants.ss58_prefix = 0;
+        assert!(constants.encode_address(&account).starts_with('1'));
+    }
+}
@@ -0,0 +1,496 @@
+//! Typed SubtensorModule events
+//!
+//! [`SubtensorEvent`] covers the SubtensorModule events most flows react to
+//! (registrations, staking, weights, serving). Events of other pallets, newer
+//! variants, and events whose fields fail to decode are kept as
+//! [`SubtensorEvent::Other`] with their dynamic field values, so nothing is
+//! dropped.
+
+use super::{BittensorClient, Error};
+use crate::utils::balance_newtypes::Rao;
+use crate::utils::decoders::{
+    decode_account_id32, decode_u16, decode_u64, extract_bytes_from_composite_sequence,
+};
+use sp_core::crypto::AccountId32;
+use subxt::dynamic::Value;
+use subxt::ext::scale_value::{Composite, ValueDef};
+use subxt::PolkadotConfig;
+
+const SUBTENSOR_MODULE: &str = "SubtensorModule";
+
+/// A decoded chain event
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubtensorEvent {
+    /// A new subnet was registered
+    NetworkAdded { netuid: u16, mechanism: u16 },
+    /// A subnet was removed
+    NetworkRemoved { netuid: u16 },
+    /// Stake was added from a coldkey to a hotkey
+    StakeAdded {
+        coldkey: AccountId32,
+        hotkey: AccountId32,
+        tao: Rao,
+        alpha: Rao,
+        netuid: u16,
+        fee: u64,
+    },
+    /// Stake was removed from a hotkey back to its coldkey
+    StakeRemoved {
+        coldkey: AccountId32,
+        hotkey: AccountId32,
+        tao: Rao,
+        alpha: Rao,
+        netuid: u16,
+        fee: u64,
+    },
+    /// Stake was moved between (hotkey, subnet) pairs of a coldkey
+    StakeMoved {
+        coldkey: AccountId32,
+        origin_hotkey: AccountId32,
+        origin_netuid: u16,
+        destination_hotkey: AccountId32,
+        destination_netuid: u16,
+        amount: Rao,
+    },
+    /// Stake was transferred to another coldkey
+    StakeTransferred {
+        origin_coldkey: AccountId32,
+        destination_coldkey: AccountId32,
+        hotkey: AccountId32,
+        origin_netuid: u16,
+        destination_netuid: u16,
+        amount: Rao,
+    },
+    /// Stake was swapped between subnets for the same coldkey-hotkey pair
+    StakeSwapped {
+        coldkey: AccountId32,
+        hotkey: AccountId32,
+        origin_netuid: u16,
+        destination_netuid: u16,
+        amount: Rao,
+    },
+    /// A neuron set weights; `netuid_index` is the mechanism storage index
+    WeightsSet { netuid_index: u16, uid: u16 },
+    /// Weights were committed (commit-reveal)
+    WeightsCommitted {
+        who: AccountId32,
+        netuid_index: u16,
+        commit_hash: [u8; 32],
+    },
+    /// Timelocked weights were committed (CRv3)
+    Crv3WeightsCommitted {
+        who: AccountId32,
+        netuid_index: u16,
+        commit_hash: [u8; 32],
+    },
+    /// Committed weights were revealed
+    WeightsRevealed {
+        who: AccountId32,
+        netuid_index: u16,
+        commit_hash: [u8; 32],
+    },
+    /// A neuron was registered on a subnet
+    NeuronRegistered {
+        netuid: u16,
+        uid: u16,
+        hotkey: AccountId32,
+    },
+    /// Several neurons were registered at once
+    BulkNeuronsRegistered { netuid: u16, count: u16 },
+    /// Axon info was served
+    AxonServed { netuid: u16, hotkey: AccountId32 },
+    /// Prometheus info was served
+    PrometheusServed { netuid: u16, hotkey: AccountId32 },
+    /// A hotkey was swapped
+    HotkeySwapped {
+        coldkey: AccountId32,
+        old_hotkey: AccountId32,
+        new_hotkey: AccountId32,
+    },
+    /// A coldkey was swapped
+    ColdkeySwapped {
+        old_coldkey: AccountId32,
+        new_coldkey: AccountId32,
+    },
+    /// A subnet identity was set
+    SubnetIdentitySet { netuid: u16 },
+    /// Any other event, with its dynamic field values
+    Other {
+        pallet: String,
+        variant: String,
+        fields: Value,
+    },
+}
+
+impl SubtensorEvent {
+    /// Decode an event from its pallet, variant and dynamic field values
+    ///
+    /// Never fails: unknown events and events whose fields do not match the
+    /// expected layout become [`SubtensorEvent::Other`].
+    pub fn decode(pallet: &str, variant: &str, fields: Value) -> Self {
+        if pallet == SUBTENSOR_MODULE {
+            if let Some(event) = decode_subtensor_event(variant, &fields) {
+                return event;
+            }
+        }
+        SubtensorEvent::Other {
+            pallet: pallet.to_string(),
+            variant: variant.to_string(),
+            fields,
+        }
+    }
+
+    /// Decode an event from subxt's event details
+    pub fn from_details(details: &subxt::events::EventDetails<PolkadotConfig>) -> Self {
+        let fields = match details.field_values() {
+            Ok(composite) => Value {
+                value: ValueDef::Composite(composite),
+                context: 0u32,
+            }
+            .remove_context(),
+            // Keep the raw field bytes if they cannot be decoded
+            Err(_) => Value::from_bytes(details.field_bytes()),
+        };
+        Self::decode(details.pallet_name(), details.variant_name(), fields)
+    }
+
+    /// Pallet and variant names of the event
+    pub fn name(&self) -> (&str, &str) {
+        let variant = match self {
+            SubtensorEvent::Other {
+                pallet, variant, ..
+            } => return (pallet, variant),
+            SubtensorEvent::NetworkAdded { .. } => "NetworkAdded",
+            SubtensorEvent::NetworkRemoved { .. } => "NetworkRemoved",
+            SubtensorEvent::StakeAdded { .. } => "StakeAdded",
+            SubtensorEvent::StakeRemoved { .. } => "StakeRemoved",
+            SubtensorEvent::StakeMoved { .. } => "StakeMoved",
+            SubtensorEvent::StakeTransferred { .. } => "StakeTransferred",
+            SubtensorEvent::StakeSwapped { .. } => "StakeSwapped",
+            SubtensorEvent::WeightsSet { .. } => "WeightsSet",
+            SubtensorEvent::WeightsCommitted { .. } => "WeightsCommitted",
+            SubtensorEvent::Crv3WeightsCommitted { .. } => "CRV3WeightsCommitted",
+            SubtensorEvent::WeightsRevealed { .. } => "WeightsRevealed",
+            SubtensorEvent::NeuronRegistered { .. } => "NeuronRegistered",
+            SubtensorEvent::BulkNeuronsRegistered { .. } => "BulkNeuronsRegistered",
+            SubtensorEvent::AxonServed { .. } => "AxonServed",
+            SubtensorEvent::PrometheusServed { .. } => "PrometheusServed",
+            SubtensorEvent::HotkeySwapped { .. } => "HotkeySwapped",
+            SubtensorEvent::ColdkeySwapped { .. } => "ColdkeySwapped",
+            SubtensorEvent::SubnetIdentitySet { .. } => "SubnetIdentitySet",
+        };
+        (SUBTENSOR_MODULE, variant)
+    }
+
+    /// Subnet the event refers to, if any
+    pub fn netuid(&self) -> Option<u16> {
+        match self {
+            SubtensorEvent::NetworkAdded { netuid, .. }
+            | SubtensorEvent::NetworkRemoved { netuid }
+            | SubtensorEvent::StakeAdded { netuid, .. }
+            | SubtensorEvent::StakeRemoved { netuid, .. }
+            | SubtensorEvent::NeuronRegistered { netuid, .. }
+            | SubtensorEvent::BulkNeuronsRegistered { netuid, .. }
+            | SubtensorEvent::AxonServed { netuid, .. }
+            | SubtensorEvent::PrometheusServed { netuid, .. }
+            | SubtensorEvent::SubnetIdentitySet { netuid } => Some(*netuid),
+            _ => None,
+        }
+    }
+}
+
+/// Decode every event of a block
+pub fn decode_events(events: &subxt::events::Events<PolkadotConfig>) -> Vec<SubtensorEvent> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Ok(details) => Some(SubtensorEvent::from_details(&details)),
+            Err(e) => {
+                tracing::warn!("Failed to decode event: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+impl BittensorClient {
+    /// Get the decoded events of a block
+    pub async fn events_at(&self, block_hash: sp_core::H256) -> Result<Vec<SubtensorEvent>, Error> {
+        let events = self.api.events().at(block_hash).await?;
+        Ok(decode_events(&events))
+    }
+}
+
+/// Positional access to the fields of an event
+struct Fields<'a>(Vec<&'a Value>);
+
+impl<'a> Fields<'a> {
+    fn new(value: &'a Value) -> Self {
+        let fields = match &value.value {
+            ValueDef::Composite(Composite::Named(fields)) => {
+                fields.iter().map(|(_, v)| v).collect()
+            }
+            ValueDef::Composite(Composite::Unnamed(fields)) => fields.iter().collect(),
+            _ => Vec::new(),
+        };
+        Self(fields)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn account(&self, i: usize) -> Option<AccountId32> {
+        decode_account_id32(self.0.get(i)?).ok()
+    }
+
+    fn u16(&self, i: usize) -> Option<u16> {
+        decode_u16(self.0.get(i)?).ok()
+    }
+
+    fn u64(&self, i: usize) -> Option<u64> {
+        decode_u64(self.0.get(i)?).ok()
+    }
+
+    fn rao(&self, i: usize) -> Option<Rao> {
+        self.u64(i).map(Rao::from)
+    }
+
+    fn hash(&self, i: usize) -> Option<[u8; 32]> {
+        extract_bytes_from_composite_sequence(self.0.get(i)?)
+    }
+}
+
+/// Decode a SubtensorModule event, or None if unknown or malformed
+fn decode_subtensor_event(variant: &str, value: &Value) -> Option<SubtensorEvent> {
+    let f = Fields::new(value);
+    let expected = match variant {
+        "NetworkRemoved" | "SubnetIdentitySet" => 1,
+        "NetworkAdded"
+        | "WeightsSet"
+        | "BulkNeuronsRegistered"
+        | "AxonServed"
+        | "PrometheusServed"
+        | "ColdkeySwapped" => 2,
+        "NeuronRegistered"
+        | "WeightsCommitted"
+        | "CRV3WeightsCommitted"
+        | "WeightsRevealed"
+        | "HotkeySwapped" => 3,
+        "StakeSwapped" => 5,
+        "StakeAdded" | "StakeRemoved" | "StakeMoved" | "StakeTransferred" => 6,
+        _ => return None,
+    };
+    if f.len() != expected {
+        return None;
+    }
+
+    let event = match variant {
+        "NetworkAdded" => SubtensorEvent::NetworkAdded {
+            netuid: f.u16(0)?,
+            mechanism: f.u16(1)?,
+        },
+        "NetworkRemoved" => SubtensorEvent::NetworkRemoved { netuid: f.u16(0)? },
+        "StakeAdded" => SubtensorEvent::StakeAdded {
+            coldkey: f.account(0)?,
+            hotkey: f.account(1)?,
+            tao: f.rao(2)?,
+            alpha: f.rao(3)?,
+            netuid: f.u16(4)?,
+            fee: f.u64(5)?,
+        },
+        "StakeRemoved" => SubtensorEvent::StakeRemoved {
+            coldkey: f.account(0)?,
+            hotkey: f.account(1)?,
+            tao: f.rao(2)?,
+            alpha: f.rao(3)?,
+            netuid: f.u16(4)?,
+            fee: f.u64(5)?,
+        },
+        "StakeMoved" => SubtensorEvent::StakeMoved {
+            coldkey: f.account(0)?,
+            origin_hotkey: f.account(1)?,
+            origin_netuid: f.u16(2)?,
+            destination_hotkey: f.account(3)?,
+            destination_netuid: f.u16(4)?,
+            amount: f.rao(5)?,
+        },
+        "StakeTransferred" => SubtensorEvent::StakeTransferred {
+            origin_coldkey: f.account(0)?,
+            destination_coldkey: f.account(1)?,
+            hotkey: f.account(2)?,
+            origin_netuid: f.u16(3)?,
+            destination_netuid: f.u16(4)?,
+            amount: f.rao(5)?,
+        },
+        "StakeSwapped" => SubtensorEvent::StakeSwapped {
+            coldkey: f.account(0)?,
+            hotkey: f.account(1)?,
+            origin_netuid: f.u16(2)?,
+            destination_netuid: f.u16(3)?,
+            amount: f.rao(4)?,
+        },
+        "WeightsSet" => SubtensorEvent::WeightsSet {
+            netuid_index: f.u16(0)?,
+            uid: f.u16(1)?,
+        },
+        "WeightsCommitted" => SubtensorEvent::WeightsCommitted {
+            who: f.account(0)?,
+            netuid_index: f.u16(1)?,
+            commit_hash: f.hash(2)?,
+        },
+        "CRV3WeightsCommitted" => SubtensorEvent::Crv3WeightsCommitted {
+            who: f.account(0)?,
+            netuid_index: f.u16(1)?,
+            commit_hash: f.hash(2)?,
+        },
+        "WeightsRevealed" => SubtensorEvent::WeightsRevealed {
+            who: f.account(0)?,
+            netuid_index: f.u16(1)?,
+            commit_hash: f.hash(2)?,
+        },
+        "NeuronRegistered" => SubtensorEvent::NeuronRegistered {
+            netuid: f.u16(0)?,
+            uid: f.u16(1)?,
+            hotkey: f.account(2)?,
+        },
+        "BulkNeuronsRegistered" => SubtensorEvent::BulkNeuronsRegistered {
+            netuid: f.u16(0)?,
+            count: f.u16(1)?,
+        },
+        "AxonServed" => SubtensorEvent::AxonServed {
+            netuid: f.u16(0)?,
+            hotkey: f.account(1)?,
+        },
+        "PrometheusServed" => SubtensorEvent::PrometheusServed {
+            netuid: f.u16(0)?,
+            hotkey: f.account(1)?,
+        },
+        "HotkeySwapped" => SubtensorEvent::HotkeySwapped {
+            coldkey: f.account(0)?,
+            old_hotkey: f.account(1)?,
+            new_hotkey: f.account(2)?,
+        },
+        "ColdkeySwapped" => SubtensorEvent::ColdkeySwapped {
+            old_coldkey: f.account(0)?,
+            new_coldkey: f.account(1)?,
+        },
+        "SubnetIdentitySet" => SubtensorEvent::SubnetIdentitySet { netuid: f.u16(0)? },
+        _ => return None,
+    };
+    Some(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(byte: u8) -> AccountId32 {
+        AccountId32::from([byte; 32])
+    }
+
+    fn account_value(byte: u8) -> Value {
+        Value::from_bytes([byte; 32])
+    }
+
+    /// Wrap a value in a single-field newtype, like `NetUid` or `TaoBalance`
+    fn newtype(value: Value) -> Value {
+        Value::unnamed_composite(vec![value])
+    }
+
+    #[test]
+    fn test_decode_neuron_registered() {
+        let fields = Value::unnamed_composite(vec![
+            newtype(Value::u128(7)),
+            Value::u128(42),
+            account_value(1),
+        ]);
+        let event = SubtensorEvent::decode(SUBTENSOR_MODULE, "NeuronRegistered", fields);
+        assert_eq!(
+            event,
+            SubtensorEvent::NeuronRegistered {
+                netuid: 7,
+                uid: 42,
+                hotkey: account(1),
+            }
+        );
+        assert_eq!(event.netuid(), Some(7));
+        assert_eq!(event.name(), (SUBTENSOR_MODULE, "NeuronRegistered"));
+    }
+
+    #[test]
+    fn test_decode_stake_added() {
+        let fields = Value::unnamed_composite(vec![
+            account_value(1),
+            account_value(2),
+            newtype(Value::u128(1_000_000_000)),
+            newtype(Value::u128(2_500_000_000)),
+            newtype(Value::u128(3)),
+            Value::u128(50_000),
+        ]);
+        assert_eq!(
+            SubtensorEvent::decode(SUBTENSOR_MODULE, "StakeAdded", fields),
+            SubtensorEvent::StakeAdded {
+                coldkey: account(1),
+                hotkey: account(2),
+                tao: Rao::from(1_000_000_000u64),
+                alpha: Rao::from(2_500_000_000u64),
+                netuid: 3,
+                fee: 50_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_named_fields() {
+        let fields = Value::named_composite(vec![
+            ("coldkey", account_value(1)),
+            ("old_hotkey", account_value(2)),
+            ("new_hotkey", account_value(3)),
+        ]);
+        assert_eq!(
+            SubtensorEvent::decode(SUBTENSOR_MODULE, "HotkeySwapped", fields),
+            SubtensorEvent::HotkeySwapped {
+                coldkey: account(1),
+                old_hotkey: account(2),
+                new_hotkey: account(3),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_weights_committed() {
+        let fields = Value::unnamed_composite(vec![
+            account_value(9),
+            newtype(Value::u128(4097)),
+            Value::from_bytes([0xab; 32]),
+        ]);
+        assert_eq!(
+            SubtensorEvent::decode(SUBTENSOR_MODULE, "CRV3WeightsCommitted", fields),
+            SubtensorEvent::Crv3WeightsCommitted {
+                who: account(9),
+                netuid_index: 4097,
+                commit_hash: [0xab; 32],
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_events_are_preserved() {
+        let fields = Value::unnamed_composite(vec![Value::u128(1)]);
+
+        let event = SubtensorEvent::decode("Balances", "Deposit", fields.clone());
+        assert_eq!(event.name(), ("Balances", "Deposit"));
+        assert!(matches!(event, SubtensorEvent::Other { fields: ref f, .. } if *f == fields));
+
+        let event = SubtensorEvent::decode(SUBTENSOR_MODULE, "SomeNewEvent", fields.clone());
+        assert!(matches!(event, SubtensorEvent::Other { .. }));
+
+        // Known variant with an unexpected layout
+        let event = SubtensorEvent::decode(SUBTENSOR_MODULE, "NeuronRegistered", fields);
+        assert!(
+            matches!(event, SubtensorEvent::Other { ref variant, .. } if variant == "NeuronRegistered")
+        );
+        assert_eq!(event.netuid(), None);
+    }
+}
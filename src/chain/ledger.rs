@@ -0,0 +1,492 @@
+//! Ledger hardware wallet signer
+//!
+//! Signs extrinsics on a Ledger device running the Polkadot app, so the
+//! coldkey never leaves the device. The account is derived on the device at
+//! the BIP44 path `m/44'/354'/<account>'/0'/<index>'` and uses ed25519.
+//!
+//! Enabled with the `ledger` feature.
+
+use crate::chain::signer::TransactionSigner;
+use crate::errors::ExtrinsicError;
+use crate::wallet::BITTENSOR_SS58_FORMAT;
+use futures::future::{self, BoxFuture};
+use ledger_apdu::APDUCommand;
+use ledger_transport_hid::{hidapi::HidApi, TransportNativeHID};
+use sp_core::crypto::{Ss58AddressFormat, Ss58Codec};
+use subxt::config::substrate::{AccountId32, MultiSignature};
+use thiserror::Error;
+use tracing::debug;
+
+/// Instruction class of the Polkadot app
+pub const POLKADOT_APP_CLA: u8 = 0x90;
+
+/// SLIP-44 coin type used by the Polkadot app
+pub const POLKADOT_COIN_TYPE: u32 = 354;
+
+/// Payloads longer than this are signed as their blake2-256 hash
+pub const MAX_UNHASHED_PAYLOAD: usize = 256;
+
+const INS_GET_ADDRESS: u8 = 0x01;
+const INS_SIGN: u8 = 0x02;
+
+const P1_SIGN_INIT: u8 = 0x00;
+const P1_SIGN_ADD: u8 = 0x01;
+const P1_SIGN_LAST: u8 = 0x02;
+
+/// Key type parameter selecting ed25519
+const P2_ED25519: u8 = 0x00;
+
+/// Maximum data bytes per APDU
+const CHUNK_SIZE: usize = 250;
+
+const HARDENED: u32 = 0x8000_0000;
+
+const SW_OK: u16 = 0x9000;
+const SW_DEVICE_LOCKED: u16 = 0x5515;
+const SW_SECURITY_STATUS: u16 = 0x6982;
+const SW_APP_NOT_OPEN: u16 = 0x6511;
+const SW_INS_NOT_SUPPORTED: u16 = 0x6D00;
+const SW_CLA_NOT_SUPPORTED: u16 = 0x6E00;
+const SW_CLA_NOT_SUPPORTED_ALT: u16 = 0x6E01;
+const SW_CONDITIONS_NOT_SATISFIED: u16 = 0x6985;
+const SW_TRANSACTION_REJECTED: u16 = 0x6986;
+
+/// Errors that can occur while talking to a Ledger device.
+#[derive(Debug, Error)]
+pub enum LedgerError {
+    #[error("No Ledger device found: {0}")]
+    DeviceNotFound(String),
+
+    #[error("Ledger device is locked, unlock it with your PIN and retry")]
+    Locked,
+
+    #[error("Polkadot app is not open on the Ledger device")]
+    AppNotOpen,
+
+    #[error("Request rejected on the Ledger device")]
+    Rejected,
+
+    #[error("Ledger returned status 0x{0:04x}")]
+    Status(u16),
+
+    #[error("Ledger transport error: {0}")]
+    Transport(String),
+
+    #[error("Invalid Ledger response: {0}")]
+    InvalidResponse(String),
+}
+
+/// APDU transport to a Ledger device.
+///
+/// Implemented for the USB HID transport; other transports (e.g. Speculos)
+/// can be plugged in with [`LedgerSigner::with_transport`].
+pub trait LedgerTransport: Send + Sync {
+    /// Send a command and return the status word and response data
+    fn exchange(
+        &self,
+        cla: u8,
+        ins: u8,
+        p1: u8,
+        p2: u8,
+        data: &[u8],
+    ) -> Result<(u16, Vec<u8>), LedgerError>;
+}
+
+impl LedgerTransport for TransportNativeHID {
+    fn exchange(
+        &self,
+        cla: u8,
+        ins: u8,
+        p1: u8,
+        p2: u8,
+        data: &[u8],
+    ) -> Result<(u16, Vec<u8>), LedgerError> {
+        let command = APDUCommand {
+            cla,
+            ins,
+            p1,
+            p2,
+            data,
+        };
+        let answer = TransportNativeHID::exchange(self, &command)
+            .map_err(|e| LedgerError::Transport(e.to_string()))?;
+        Ok((answer.retcode(), answer.data().to_vec()))
+    }
+}
+
+/// Build the BIP44 path `m/44'/354'/<account>'/0'/<address_index>'`
+pub fn bip44_path(account: u32, address_index: u32) -> [u32; 5] {
+    [
+        44 | HARDENED,
+        POLKADOT_COIN_TYPE | HARDENED,
+        account | HARDENED,
+        HARDENED,
+        address_index | HARDENED,
+    ]
+}
+
+/// Format a BIP44 path, e.g. `m/44'/354'/0'/0'/0'`
+pub fn format_bip44_path(path: &[u32; 5]) -> String {
+    path.iter().fold("m".to_string(), |acc, index| {
+        if index & HARDENED != 0 {
+            format!("{}/{}'", acc, index & !HARDENED)
+        } else {
+            format!("{}/{}", acc, index)
+        }
+    })
+}
+
+/// Get the bytes signed for an extrinsic payload.
+///
+/// Payloads longer than [`MAX_UNHASHED_PAYLOAD`] bytes are replaced by their
+/// blake2-256 hash, following the Substrate signing convention.
+pub fn signing_payload(payload: &[u8]) -> Vec<u8> {
+    if payload.len() > MAX_UNHASHED_PAYLOAD {
+        sp_core::hashing::blake2_256(payload).to_vec()
+    } else {
+        payload.to_vec()
+    }
+}
+
+/// Map an APDU status word to a result
+fn check_status(status: u16) -> Result<(), LedgerError> {
+    match status {
+        SW_OK => Ok(()),
+        SW_DEVICE_LOCKED | SW_SECURITY_STATUS => Err(LedgerError::Locked),
+        SW_APP_NOT_OPEN
+        | SW_INS_NOT_SUPPORTED
+        | SW_CLA_NOT_SUPPORTED
+        | SW_CLA_NOT_SUPPORTED_ALT => Err(LedgerError::AppNotOpen),
+        SW_CONDITIONS_NOT_SATISFIED | SW_TRANSACTION_REJECTED => Err(LedgerError::Rejected),
+        other => Err(LedgerError::Status(other)),
+    }
+}
+
+/// Signer backed by a Ledger device running the Polkadot app
+pub struct LedgerSigner {
+    transport: Box<dyn LedgerTransport>,
+    path: [u32; 5],
+    public_key: [u8; 32],
+    account_id: AccountId32,
+    ss58_address: String,
+}
+
+impl LedgerSigner {
+    /// Connect to the first Ledger device over USB HID
+    ///
+    /// # Arguments
+    /// * `account` - BIP44 account index
+    /// * `address_index` - BIP44 address index
+    pub fn connect(account: u32, address_index: u32) -> Result<Self, LedgerError> {
+        let api = HidApi::new().map_err(|e| LedgerError::Transport(e.to_string()))?;
+        let transport = TransportNativeHID::new(&api)
+            .map_err(|e| LedgerError::DeviceNotFound(e.to_string()))?;
+        Self::with_transport(Box::new(transport), bip44_path(account, address_index))
+    }
+
+    /// Create a signer over an existing transport
+    ///
+    /// Fetches the public key at `path` from the device.
+    pub fn with_transport(
+        transport: Box<dyn LedgerTransport>,
+        path: [u32; 5],
+    ) -> Result<Self, LedgerError> {
+        let (status, response) = transport.exchange(
+            POLKADOT_APP_CLA,
+            INS_GET_ADDRESS,
+            0,
+            P2_ED25519,
+            &encode_path(&path),
+        )?;
+        check_status(status)?;
+
+        let public_key: [u8; 32] = response
+            .get(..32)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| {
+                LedgerError::InvalidResponse(format!(
+                    "expected a 32-byte public key, got {} bytes",
+                    response.len()
+                ))
+            })?;
+        let ss58_address = sp_core::crypto::AccountId32::from(public_key)
+            .to_ss58check_with_version(Ss58AddressFormat::custom(BITTENSOR_SS58_FORMAT));
+        debug!(
+            "Ledger account {} at {}",
+            ss58_address,
+            format_bip44_path(&path)
+        );
+
+        Ok(Self {
+            transport,
+            path,
+            public_key,
+            account_id: AccountId32(public_key),
+            ss58_address,
+        })
+    }
+
+    /// Get the ed25519 public key
+    pub fn public_key(&self) -> &[u8; 32] {
+        &self.public_key
+    }
+
+    /// Get the account ID
+    pub fn account_id(&self) -> &AccountId32 {
+        &self.account_id
+    }
+
+    /// Get the SS58 address
+    pub fn ss58_address(&self) -> &str {
+        &self.ss58_address
+    }
+
+    /// Get the BIP44 derivation path
+    pub fn path(&self) -> &[u32; 5] {
+        &self.path
+    }
+
+    /// Sign an extrinsic payload on the device
+    ///
+    /// The user has to review and approve the transaction on the device.
+    /// Payloads longer than 256 bytes are signed as their blake2-256 hash.
+    ///
+    /// # Returns
+    /// The 64-byte ed25519 signature
+    pub fn sign_payload(&self, payload: &[u8]) -> Result<[u8; 64], LedgerError> {
+        let payload = signing_payload(payload);
+
+        let (status, _) = self.transport.exchange(
+            POLKADOT_APP_CLA,
+            INS_SIGN,
+            P1_SIGN_INIT,
+            P2_ED25519,
+            &encode_path(&self.path),
+        )?;
+        check_status(status)?;
+
+        let chunks: Vec<&[u8]> = payload.chunks(CHUNK_SIZE).collect();
+        let mut response = Vec::new();
+        for (i, chunk) in chunks.iter().enumerate() {
+            let p1 = if i + 1 == chunks.len() {
+                P1_SIGN_LAST
+            } else {
+                P1_SIGN_ADD
+            };
+            let (status, data) =
+                self.transport
+                    .exchange(POLKADOT_APP_CLA, INS_SIGN, p1, P2_ED25519, chunk)?;
+            check_status(status)?;
+            response = data;
+        }
+
+        // The signature may be prefixed with its type byte
+        let signature = match response.len() {
+            64 => &response[..],
+            65 => &response[1..],
+            len => {
+                return Err(LedgerError::InvalidResponse(format!(
+                    "expected a 64-byte signature, got {} bytes",
+                    len
+                )))
+            }
+        };
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(signature);
+        Ok(bytes)
+    }
+}
+
+impl std::fmt::Debug for LedgerSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LedgerSigner")
+            .field("path", &format_bip44_path(&self.path))
+            .field("ss58_address", &self.ss58_address)
+            .finish()
+    }
+}
+
+/// Device errors such as a locked device or a rejected transaction are
+/// returned as errors. There is deliberately no subxt `Signer` impl, which
+/// could only report them with an invalid signature.
+impl TransactionSigner for LedgerSigner {
+    fn account_id(&self) -> &AccountId32 {
+        &self.account_id
+    }
+
+    fn sign<'a>(
+        &'a self,
+        signer_payload: &'a [u8],
+    ) -> BoxFuture<'a, Result<MultiSignature, ExtrinsicError>> {
+        let signature = self
+            .sign_payload(signer_payload)
+            .map(MultiSignature::Ed25519)
+            .map_err(|e| ExtrinsicError::new(format!("Ledger signing failed: {}", e)));
+        Box::pin(future::ready(signature))
+    }
+}
+
+/// Encode a derivation path as little-endian u32s
+fn encode_path(path: &[u32; 5]) -> Vec<u8> {
+    path.iter().flat_map(|index| index.to_le_bytes()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+    use sp_core::{ed25519, Pair};
+    use std::sync::Mutex;
+
+    /// In-memory device running the Polkadot app
+    struct MockDevice {
+        pair: ed25519::Pair,
+        status: Option<u16>,
+        payload: Mutex<Vec<u8>>,
+        commands: Mutex<Vec<(u8, u8, usize)>>,
+    }
+
+    impl MockDevice {
+        fn new(status: Option<u16>) -> Self {
+            Self {
+                pair: ed25519::Pair::from_string("//Alice", None).unwrap(),
+                status,
+                payload: Mutex::new(Vec::new()),
+                commands: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl LedgerTransport for std::sync::Arc<MockDevice> {
+        fn exchange(
+            &self,
+            cla: u8,
+            ins: u8,
+            p1: u8,
+            _p2: u8,
+            data: &[u8],
+        ) -> Result<(u16, Vec<u8>), LedgerError> {
+            assert_eq!(cla, POLKADOT_APP_CLA);
+            assert!(data.len() <= CHUNK_SIZE);
+            self.commands.lock().unwrap().push((ins, p1, data.len()));
+
+            if let Some(status) = self.status {
+                if ins == INS_SIGN {
+                    return Ok((status, Vec::new()));
+                }
+            }
+
+            let mut payload = self.payload.lock().unwrap();
+            match (ins, p1) {
+                (INS_GET_ADDRESS, _) => Ok((SW_OK, self.pair.public().0.to_vec())),
+                (INS_SIGN, P1_SIGN_INIT) => {
+                    payload.clear();
+                    Ok((SW_OK, Vec::new()))
+                }
+                (INS_SIGN, P1_SIGN_ADD) => {
+                    payload.extend_from_slice(data);
+                    Ok((SW_OK, Vec::new()))
+                }
+                (INS_SIGN, P1_SIGN_LAST) => {
+                    payload.extend_from_slice(data);
+                    let mut response = vec![0x00];
+                    response.extend_from_slice(&self.pair.sign(&payload).0);
+                    Ok((SW_OK, response))
+                }
+                _ => Ok((SW_INS_NOT_SUPPORTED, Vec::new())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_bip44_path() {
+        let path = bip44_path(0, 0);
+        assert_eq!(format_bip44_path(&path), "m/44'/354'/0'/0'/0'");
+        assert_eq!(format_bip44_path(&bip44_path(2, 7)), "m/44'/354'/2'/0'/7'");
+        assert_eq!(
+            encode_path(&path)[..8],
+            [0x2c, 0x00, 0x00, 0x80, 0x62, 0x01, 0x00, 0x80]
+        );
+    }
+
+    #[test]
+    fn test_sign_payload() {
+        let device = std::sync::Arc::new(MockDevice::new(None));
+        let signer =
+            LedgerSigner::with_transport(Box::new(device.clone()), bip44_path(0, 0)).unwrap();
+        assert_eq!(signer.public_key(), &device.pair.public().0);
+        assert_eq!(
+            signer.ss58_address(),
+            "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu"
+        );
+
+        // Short payloads are signed as-is in a single chunk
+        let payload = b"extrinsic payload";
+        let signature = signer.sign_payload(payload).unwrap();
+        assert!(ed25519::Pair::verify(
+            &ed25519::Signature::from_raw(signature),
+            payload,
+            &device.pair.public()
+        ));
+
+        // Long payloads are hashed before signing
+        device.commands.lock().unwrap().clear();
+        let payload = vec![7u8; 600];
+        let signature = signer.sign_payload(&payload).unwrap();
+        assert!(ed25519::Pair::verify(
+            &ed25519::Signature::from_raw(signature),
+            sp_core::hashing::blake2_256(&payload),
+            &device.pair.public()
+        ));
+        assert_eq!(
+            *device.commands.lock().unwrap(),
+            vec![(INS_SIGN, P1_SIGN_INIT, 20), (INS_SIGN, P1_SIGN_LAST, 32)]
+        );
+    }
+
+    #[test]
+    fn test_payload_chunking() {
+        assert_eq!(signing_payload(&[1u8; 256]), vec![1u8; 256]);
+        assert_eq!(signing_payload(&[1u8; 257]).len(), 32);
+
+        let device = std::sync::Arc::new(MockDevice::new(None));
+        let signer =
+            LedgerSigner::with_transport(Box::new(device.clone()), bip44_path(0, 0)).unwrap();
+        device.commands.lock().unwrap().clear();
+        signer.sign_payload(&[1u8; 256]).unwrap();
+        assert_eq!(
+            *device.commands.lock().unwrap(),
+            vec![
+                (INS_SIGN, P1_SIGN_INIT, 20),
+                (INS_SIGN, P1_SIGN_ADD, 250),
+                (INS_SIGN, P1_SIGN_LAST, 6)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_device_errors() {
+        for (status, expected) in [
+            (SW_DEVICE_LOCKED, "locked"),
+            (SW_CLA_NOT_SUPPORTED, "app is not open"),
+            (SW_APP_NOT_OPEN, "app is not open"),
+            (SW_TRANSACTION_REJECTED, "rejected"),
+        ] {
+            let device = std::sync::Arc::new(MockDevice::new(Some(status)));
+            let signer = LedgerSigner::with_transport(Box::new(device), bip44_path(0, 0)).unwrap();
+            let err = signer.sign_payload(b"payload").unwrap_err();
+            assert!(err.to_string().contains(expected), "{}", err);
+
+            // Submission reports the failure instead of a blank signature
+            let err = TransactionSigner::sign(&signer, b"payload")
+                .now_or_never()
+                .unwrap()
+                .unwrap_err();
+            assert!(err.to_string().contains(expected), "{}", err);
+        }
+        assert!(matches!(
+            check_status(0x6a80),
+            Err(LedgerError::Status(0x6a80))
+        ));
+    }
+}
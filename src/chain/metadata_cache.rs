@@ -0,0 +1,349 @@
+//! On-disk cache of runtime metadata
+//!
+//! Downloading the runtime metadata (1-2 MB) dominates connection time on
+//! slow links. With a cache enabled, [`BittensorClientBuilder`] first fetches
+//! only the genesis hash and runtime version, and loads the metadata from
+//! disk if it was stored for the same (genesis hash, spec version,
+//! transaction version). Otherwise the metadata is downloaded and stored.
+//!
+//! Each file starts with a blake2-256 checksum of the metadata; a file that
+//! fails the check or does not decode is removed and fetched again.
+//!
+//! [`BittensorClientBuilder`]: super::BittensorClientBuilder
+
+use super::Error;
+use parity_scale_codec::{Decode, Encode};
+use sp_core::hashing::blake2_256;
+use std::io;
+use std::path::{Path, PathBuf};
+use subxt::backend::legacy::LegacyRpcMethods;
+use subxt::backend::rpc::RpcClient;
+use subxt::{Metadata, OnlineClient, PolkadotConfig};
+use tracing::debug;
+
+/// Marks a cache file, followed by the format version
+const MAGIC: &[u8; 4] = b"BTMD";
+const FORMAT_VERSION: u8 = 1;
+const HEADER_LEN: usize = MAGIC.len() + 1 + 32;
+
+/// Metadata versions requested from the runtime, newest first
+const METADATA_VERSIONS: [u32; 2] = [16, 15];
+
+/// Default cache directory (`~/.bittensor/metadata`)
+pub fn default_metadata_cache_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join(".bittensor")
+        .join("metadata")
+}
+
+/// Where a [`BittensorClientBuilder`](super::BittensorClientBuilder) caches metadata
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum MetadataCacheDir {
+    /// Always download the metadata
+    #[default]
+    Disabled,
+    /// [`default_metadata_cache_dir`]
+    Default,
+    /// A custom directory
+    Path(PathBuf),
+}
+
+impl MetadataCacheDir {
+    /// The directory to use, if caching is enabled
+    pub fn dir(&self) -> Option<PathBuf> {
+        match self {
+            MetadataCacheDir::Disabled => None,
+            MetadataCacheDir::Default => Some(default_metadata_cache_dir()),
+            MetadataCacheDir::Path(path) => Some(path.clone()),
+        }
+    }
+}
+
+impl From<bool> for MetadataCacheDir {
+    fn from(enabled: bool) -> Self {
+        if enabled {
+            MetadataCacheDir::Default
+        } else {
+            MetadataCacheDir::Disabled
+        }
+    }
+}
+
+impl From<PathBuf> for MetadataCacheDir {
+    fn from(path: PathBuf) -> Self {
+        MetadataCacheDir::Path(path)
+    }
+}
+
+impl From<&Path> for MetadataCacheDir {
+    fn from(path: &Path) -> Self {
+        MetadataCacheDir::Path(path.to_path_buf())
+    }
+}
+
+/// Identifies the runtime a metadata blob belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetadataKey {
+    pub genesis_hash: [u8; 32],
+    pub spec_version: u32,
+    pub transaction_version: u32,
+}
+
+impl MetadataKey {
+    fn file_name(&self) -> String {
+        format!(
+            "{}-{}-{}.scale",
+            hex::encode(self.genesis_hash),
+            self.spec_version,
+            self.transaction_version
+        )
+    }
+}
+
+/// Directory of SCALE encoded metadata blobs
+#[derive(Debug, Clone)]
+pub struct MetadataCache {
+    dir: PathBuf,
+}
+
+impl MetadataCache {
+    /// Cache metadata in `dir`, which is created on the first store
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Path of the cache file for `key`
+    pub fn path(&self, key: &MetadataKey) -> PathBuf {
+        self.dir.join(key.file_name())
+    }
+
+    /// The cached metadata for `key`, if present and intact
+    ///
+    /// A file that fails the checksum is removed.
+    pub fn load(&self, key: &MetadataKey) -> Option<Vec<u8>> {
+        let path = self.path(key);
+        let contents = match std::fs::read(&path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                if e.kind() != io::ErrorKind::NotFound {
+                    debug!("Failed to read metadata cache {}: {}", path.display(), e);
+                }
+                return None;
+            }
+        };
+        match verify(&contents) {
+            Some(metadata) => Some(metadata.to_vec()),
+            None => {
+                debug!("Discarding corrupted metadata cache {}", path.display());
+                self.remove(key);
+                None
+            }
+        }
+    }
+
+    /// Store `metadata` for `key`, replacing any existing file
+    ///
+    /// Writes to a temporary file first, so a concurrent load never sees a
+    /// partial file.
+    pub fn store(&self, key: &MetadataKey, metadata: &[u8]) -> io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.path(key);
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+
+        let mut contents = Vec::with_capacity(HEADER_LEN + metadata.len());
+        contents.extend_from_slice(MAGIC);
+        contents.push(FORMAT_VERSION);
+        contents.extend_from_slice(&blake2_256(metadata));
+        contents.extend_from_slice(metadata);
+
+        std::fs::write(&tmp, &contents)?;
+        std::fs::rename(&tmp, &path).inspect_err(|_| {
+            let _ = std::fs::remove_file(&tmp);
+        })
+    }
+
+    /// Remove the cache file for `key`, if any
+    pub fn remove(&self, key: &MetadataKey) {
+        let _ = std::fs::remove_file(self.path(key));
+    }
+}
+
+/// The metadata in a cache file, if the header and checksum are valid
+fn verify(contents: &[u8]) -> Option<&[u8]> {
+    if contents.len() < HEADER_LEN
+        || &contents[..MAGIC.len()] != MAGIC
+        || contents[MAGIC.len()] != FORMAT_VERSION
+    {
+        return None;
+    }
+    let checksum = &contents[MAGIC.len() + 1..HEADER_LEN];
+    let metadata = &contents[HEADER_LEN..];
+    (blake2_256(metadata) == checksum).then_some(metadata)
+}
+
+/// Create an [`OnlineClient`] with metadata from `cache` when it matches the
+/// runtime, downloading and caching it otherwise
+pub(crate) async fn online_client(
+    rpc: RpcClient,
+    cache: &MetadataCache,
+) -> Result<OnlineClient<PolkadotConfig>, Error> {
+    let methods = LegacyRpcMethods::<PolkadotConfig>::new(rpc.clone());
+    let genesis_hash = methods.genesis_hash().await?;
+    let version = methods.state_get_runtime_version(None).await?;
+    let key = MetadataKey {
+        genesis_hash: genesis_hash.0,
+        spec_version: version.spec_version,
+        transaction_version: version.transaction_version,
+    };
+
+    let cached = cache
+        .load(&key)
+        .and_then(|bytes| match decode_metadata(&bytes) {
+            Ok(metadata) => Some(metadata),
+            Err(e) => {
+                debug!("Discarding undecodable metadata cache: {}", e);
+                cache.remove(&key);
+                None
+            }
+        });
+    let metadata = match cached {
+        Some(metadata) => {
+            debug!(
+                "Loaded metadata for spec version {} from cache",
+                key.spec_version
+            );
+            metadata
+        }
+        None => {
+            let bytes = fetch_metadata(&methods).await?;
+            let metadata = decode_metadata(&bytes)?;
+            if let Err(e) = cache.store(&key, &bytes) {
+                debug!("Failed to write metadata cache: {}", e);
+            }
+            metadata
+        }
+    };
+
+    let runtime_version = subxt::client::RuntimeVersion {
+        spec_version: version.spec_version,
+        transaction_version: version.transaction_version,
+    };
+    Ok(OnlineClient::from_rpc_client_with(
+        genesis_hash,
+        runtime_version,
+        metadata,
+        rpc,
+    )?)
+}
+
+/// Download the newest supported metadata as a SCALE encoded blob
+pub(crate) async fn fetch_metadata(
+    methods: &LegacyRpcMethods<PolkadotConfig>,
+) -> Result<Vec<u8>, Error> {
+    for version in METADATA_VERSIONS {
+        let response = methods
+            .state_call(
+                "Metadata_metadata_at_version",
+                Some(&version.encode()),
+                None,
+            )
+            .await;
+        // `Option<OpaqueMetadata>`; runtimes without the call fail or return None
+        if let Ok(Ok(Some(bytes))) = response.map(|r| Option::<Vec<u8>>::decode(&mut &r[..])) {
+            return Ok(bytes);
+        }
+    }
+    Ok(methods.state_get_metadata(None).await?.into_raw())
+}
+
+pub(crate) fn decode_metadata(bytes: &[u8]) -> Result<Metadata, Error> {
+    Metadata::decode(&mut &bytes[..])
+        .map_err(|e| Error::Decoding(format!("Failed to decode runtime metadata: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn key(spec_version: u32) -> MetadataKey {
+        MetadataKey {
+            genesis_hash: [7; 32],
+            spec_version,
+            transaction_version: 1,
+        }
+    }
+
+    #[test]
+    fn test_cache_hit() {
+        let dir = tempdir().unwrap();
+        let cache = MetadataCache::new(dir.path().join("metadata"));
+        let metadata = b"metadata blob".to_vec();
+
+        cache.store(&key(300), &metadata).unwrap();
+        assert_eq!(cache.load(&key(300)), Some(metadata));
+        assert!(cache
+            .path(&key(300))
+            .to_string_lossy()
+            .ends_with(&format!("{}-300-1.scale", hex::encode([7; 32]))));
+
+        // Storing again replaces the file
+        cache.store(&key(300), b"updated").unwrap();
+        assert_eq!(cache.load(&key(300)), Some(b"updated".to_vec()));
+    }
+
+    #[test]
+    fn test_cache_miss() {
+        let dir = tempdir().unwrap();
+        let cache = MetadataCache::new(dir.path());
+        assert_eq!(cache.load(&key(300)), None);
+
+        // A runtime upgrade changes the key
+        cache.store(&key(300), b"metadata").unwrap();
+        assert_eq!(cache.load(&key(301)), None);
+        let other_chain = MetadataKey {
+            genesis_hash: [8; 32],
+            ..key(300)
+        };
+        assert_eq!(cache.load(&other_chain), None);
+    }
+
+    #[test]
+    fn test_corrupted_cache_is_removed() {
+        let dir = tempdir().unwrap();
+        let cache = MetadataCache::new(dir.path());
+        let path = cache.path(&key(300));
+
+        cache.store(&key(300), b"metadata blob").unwrap();
+        let mut contents = std::fs::read(&path).unwrap();
+        *contents.last_mut().unwrap() ^= 0xff;
+        std::fs::write(&path, &contents).unwrap();
+        assert_eq!(cache.load(&key(300)), None);
+        assert!(!path.exists());
+
+        // Truncated and foreign files are rejected too
+        std::fs::write(&path, b"BTMD").unwrap();
+        assert_eq!(cache.load(&key(300)), None);
+        std::fs::write(&path, vec![0u8; 64]).unwrap();
+        assert_eq!(cache.load(&key(300)), None);
+        assert!(!path.exists());
+
+        // The next fetch replaces it
+        cache.store(&key(300), b"metadata blob").unwrap();
+        assert_eq!(cache.load(&key(300)), Some(b"metadata blob".to_vec()));
+    }
+
+    #[test]
+    fn test_cache_dir_setting() {
+        assert_eq!(MetadataCacheDir::from(false).dir(), None);
+        assert_eq!(
+            MetadataCacheDir::from(true).dir(),
+            Some(default_metadata_cache_dir())
+        );
+        assert_eq!(
+            MetadataCacheDir::from(PathBuf::from("/tmp/md")).dir(),
+            Some(PathBuf::from("/tmp/md"))
+        );
+    }
+}
@@ -0,0 +1,427 @@
+//! In-memory chain for unit tests
+//!
+//! [`MockChain`] implements [`ChainBackend`] over a key-value store, so query
+//! and extrinsic helpers can be exercised without a node. Storage is seeded
+//! with [`MockChain::set_storage`], extrinsic outcomes are scripted with
+//! [`MockChain::push_extrinsic_result`] and every submission and storage
+//! read is recorded. Fee estimates are scripted with [`MockChain::set_fee`].
+//! Latency can be injected per call with [`MockChain::set_delay`], and calls
+//! are profiled like on a [`BittensorClient`](super::BittensorClient).
+//! [`MockChain::set_runtime_version`] simulates a runtime upgrade, which the
+//! next submission picks up like a client would.
+//!
+//! Storage keys are flattened to bytes for [`ChainBackend::storage_iter`]:
+//! byte arrays verbatim, integers as 16-byte little-endian `u128`. Hashers
+//! are not applied, so the last 32 bytes of an account-keyed entry are the
+//! account.
+
+use super::{
+    BittensorSigner, CallProfiler, ChainBackend, Error, ExtrinsicWait, ProfileReport,
+    RuntimeUpgrade, RuntimeUpgradeMonitor,
+};
+use sp_core::crypto::AccountId32;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+use subxt::dynamic::Value;
+use subxt::ext::scale_value::{Composite, Primitive, ValueDef};
+
+/// An extrinsic submitted to a [`MockChain`]
+#[derive(Debug, Clone)]
+pub struct SubmittedExtrinsic {
+    pub module: String,
+    pub function: String,
+    pub args: Vec<Value>,
+    pub signer: AccountId32,
+    /// Block number when the extrinsic was submitted
+    pub block: u64,
+}
+
+/// A storage read served by a [`MockChain`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StorageRead {
+    pub module: String,
+    pub entry: String,
+    pub keys: Vec<Value>,
+}
+
+#[derive(Default)]
+struct MockState {
+    storage: BTreeMap<(String, String, Vec<u8>), Value>,
+    runtime_apis: HashMap<(String, String), Vec<u8>>,
+    /// Fee per `Module.function`, in RAO
+    fees: HashMap<String, u128>,
+    outcomes: VecDeque<Result<String, Error>>,
+    submitted: Vec<SubmittedExtrinsic>,
+    reads: Vec<StorageRead>,
+    block_number: u64,
+    delays: HashMap<String, Duration>,
+    /// Spec and transaction version of the chain's runtime
+    runtime_version: (u32, u32),
+}
+
+/// In-memory [`ChainBackend`]
+#[derive(Default)]
+pub struct MockChain {
+    state: Mutex<MockState>,
+    profiler: CallProfiler,
+    /// Runtime version the client side is using
+    upgrades: RuntimeUpgradeMonitor,
+}
+
+impl MockChain {
+    /// An empty chain at block 0
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store `value` under `module::entry(keys)`
+    pub fn set_storage(&self, module: &str, entry: &str, keys: Vec<Value>, value: Value) {
+        let key = (module.to_string(), entry.to_string(), encode_keys(&keys));
+        self.state().storage.insert(key, value);
+    }
+
+    /// Remove `module::entry(keys)`
+    pub fn remove_storage(&self, module: &str, entry: &str, keys: Vec<Value>) {
+        let key = (module.to_string(), entry.to_string(), encode_keys(&keys));
+        self.state().storage.remove(&key);
+    }
+
+    /// Answer `runtime_api.method` with SCALE-encoded `response`
+    ///
+    /// Calls without a response fail with an RPC error.
+    pub fn set_runtime_api(&self, runtime_api: &str, method: &str, response: Vec<u8>) {
+        self.state()
+            .runtime_apis
+            .insert((runtime_api.to_string(), method.to_string()), response);
+    }
+
+    /// Estimate a fee of `fee` RAO for every `module::function` extrinsic
+    ///
+    /// Extrinsics without a fee are estimated at zero.
+    pub fn set_fee(&self, module: &str, function: &str, fee: u128) {
+        self.state()
+            .fees
+            .insert(format!("{}.{}", module, function), fee);
+    }
+
+    /// Queue the outcome of the next submitted extrinsic
+    ///
+    /// Outcomes are consumed in order; once the queue is empty submissions
+    /// succeed with a generated hash.
+    pub fn push_extrinsic_result(&self, result: Result<String, Error>) {
+        self.state().outcomes.push_back(result);
+    }
+
+    /// Current block number
+    pub fn block(&self) -> u64 {
+        self.state().block_number
+    }
+
+    /// Set the current block number
+    pub fn set_block(&self, block: u64) {
+        self.state().block_number = block;
+    }
+
+    /// Advance the chain by `blocks`, returning the new block number
+    pub fn advance_blocks(&self, blocks: u64) -> u64 {
+        let mut state = self.state();
+        state.block_number += blocks;
+        state.block_number
+    }
+
+    /// Extrinsics submitted so far, oldest first
+    pub fn submitted(&self) -> Vec<SubmittedExtrinsic> {
+        self.state().submitted.clone()
+    }
+
+    /// Single-entry storage reads served so far, oldest first
+    pub fn storage_reads(&self) -> Vec<StorageRead> {
+        self.state().reads.clone()
+    }
+
+    /// Forget the storage reads recorded so far
+    pub fn clear_storage_reads(&self) {
+        self.state().reads.clear();
+    }
+
+    /// Delay every `call` by `delay`
+    ///
+    /// Calls are named as in [`ProfileReport`]: `Module.Entry` for storage
+    /// and extrinsics, `RuntimeApi_method` for runtime APIs.
+    pub fn set_delay(&self, call: &str, delay: Duration) {
+        self.state().delays.insert(call.to_string(), delay);
+    }
+
+    /// Start or stop recording the timing of each call
+    pub fn enable_profiling(&self, enabled: bool) {
+        self.profiler.set_enabled(enabled);
+    }
+
+    /// Timings recorded since profiling was enabled, per call
+    pub fn profile_report(&self) -> ProfileReport {
+        self.profiler.report()
+    }
+
+    /// Upgrade the chain's runtime
+    ///
+    /// The client side keeps the old version until
+    /// [`refresh_runtime`](Self::refresh_runtime) or the next submission.
+    pub fn set_runtime_version(&self, spec_version: u32, transaction_version: u32) {
+        self.state().runtime_version = (spec_version, transaction_version);
+    }
+
+    /// Run `callback` when the client side picks up a runtime upgrade
+    pub fn on_runtime_upgrade(&self, callback: impl Fn(&RuntimeUpgrade) + Send + Sync + 'static) {
+        self.upgrades.on_upgrade(callback);
+    }
+
+    /// Switch the client side to the chain's runtime version
+    pub fn refresh_runtime(&self) -> Option<RuntimeUpgrade> {
+        let (spec_version, transaction_version) = self.state().runtime_version;
+        self.upgrades.observe(spec_version, transaction_version)
+    }
+
+    /// Runtime version the client side is using
+    pub fn client_runtime_version(&self) -> (u32, u32) {
+        self.upgrades.version()
+    }
+
+    /// Apply the delay of `call`, then time `respond`
+    async fn respond<T>(
+        &self,
+        call: String,
+        bytes: impl FnOnce(&T) -> usize,
+        respond: impl FnOnce(&mut MockState) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let started = self.profiler.start();
+        let delay = self.state().delays.get(&call).copied();
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+        let result = respond(&mut self.state());
+        let size = result.as_ref().map_or(0, bytes);
+        self.profiler.finish(started, || call, size, result.is_ok());
+        result
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MockState> {
+        // A panicking test thread must not hide the state from the others
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl ChainBackend for MockChain {
+    async fn storage_with_keys(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> Result<Option<Value>, Error> {
+        let key = (module.to_string(), entry.to_string(), encode_keys(&keys));
+        self.respond(
+            format!("{}.{}", module, entry),
+            |_| 0,
+            |state| {
+                state.reads.push(StorageRead {
+                    module: module.to_string(),
+                    entry: entry.to_string(),
+                    keys,
+                });
+                Ok(state.storage.get(&key).cloned())
+            },
+        )
+        .await
+    }
+
+    async fn storage_iter(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> Result<Vec<(Vec<u8>, Value)>, Error> {
+        let prefix = encode_keys(&keys);
+        self.respond(
+            format!("{}.{}", module, entry),
+            |_| 0,
+            |state| {
+                Ok(state
+                    .storage
+                    .iter()
+                    .filter(|((m, e, key), _)| {
+                        m == module && e == entry && key.starts_with(&prefix)
+                    })
+                    .map(|((_, _, key), value)| (key.clone(), value.clone()))
+                    .collect())
+            },
+        )
+        .await
+    }
+
+    async fn runtime_api_call(
+        &self,
+        runtime_api: &str,
+        method: &str,
+        _params: Option<Vec<u8>>,
+    ) -> Result<Vec<u8>, Error> {
+        self.respond(format!("{}_{}", runtime_api, method), Vec::len, |state| {
+            state
+                .runtime_apis
+                .get(&(runtime_api.to_string(), method.to_string()))
+                .cloned()
+                .ok_or_else(|| Error::Rpc(format!("{}_{} is not mocked", runtime_api, method)))
+        })
+        .await
+    }
+
+    async fn submit_extrinsic(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+        signer: &BittensorSigner,
+        _wait_for: ExtrinsicWait,
+    ) -> Result<String, Error> {
+        // A client resubmits after a stale runtime rejection; skip straight
+        // to the attempt for the refreshed runtime
+        self.refresh_runtime();
+        let signer = AccountId32::from(signer.account_id().0);
+        self.respond(
+            format!("{}.{}", module, function),
+            |_| 0,
+            |state| {
+                let block = state.block_number;
+                state.submitted.push(SubmittedExtrinsic {
+                    module: module.to_string(),
+                    function: function.to_string(),
+                    args,
+                    signer,
+                    block,
+                });
+                let index = state.submitted.len();
+                state
+                    .outcomes
+                    .pop_front()
+                    .unwrap_or_else(|| Ok(format!("0x{:064x}", index)))
+            },
+        )
+        .await
+    }
+
+    async fn estimate_fee(
+        &self,
+        module: &str,
+        function: &str,
+        _args: Vec<Value>,
+        _signer: &BittensorSigner,
+    ) -> Result<u128, Error> {
+        let call = format!("{}.{}", module, function);
+        self.respond(
+            "TransactionPaymentApi_query_info".to_string(),
+            |_| 0,
+            |state| Ok(state.fees.get(&call).copied().unwrap_or(0)),
+        )
+        .await
+    }
+
+    async fn block_number(&self) -> Result<u64, Error> {
+        Ok(self.block())
+    }
+}
+
+fn encode_keys(keys: &[Value]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for key in keys {
+        encode_key(key, &mut out);
+    }
+    out
+}
+
+fn encode_key(value: &Value, out: &mut Vec<u8>) {
+    match &value.value {
+        ValueDef::Primitive(Primitive::U128(n)) => out.extend_from_slice(&n.to_le_bytes()),
+        ValueDef::Primitive(Primitive::I128(n)) => out.extend_from_slice(&n.to_le_bytes()),
+        ValueDef::Primitive(Primitive::Bool(b)) => out.push(*b as u8),
+        ValueDef::Primitive(Primitive::String(s)) => out.extend_from_slice(s.as_bytes()),
+        ValueDef::Composite(Composite::Unnamed(values)) => {
+            // Byte arrays are composites of u8 values
+            let bytes: Option<Vec<u8>> = values
+                .iter()
+                .map(|v| match v.value {
+                    ValueDef::Primitive(Primitive::U128(n)) => u8::try_from(n).ok(),
+                    _ => None,
+                })
+                .collect();
+            match bytes {
+                Some(bytes) if !values.is_empty() => out.extend_from_slice(&bytes),
+                _ => values.iter().for_each(|v| encode_key(v, out)),
+            }
+        }
+        _ => out.extend_from_slice(value.to_string().as_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_storage_and_iteration() {
+        let chain = MockChain::new();
+        let hotkey = [7u8; 32];
+        chain.set_storage(
+            "Commitments",
+            "CommitmentOf",
+            vec![Value::from(3u16), Value::from_bytes(hotkey)],
+            Value::u128(42),
+        );
+        chain.set_storage(
+            "Commitments",
+            "CommitmentOf",
+            vec![Value::from(4u16), Value::from_bytes([8u8; 32])],
+            Value::u128(43),
+        );
+
+        // u16 and u128 keys are interchangeable
+        let value = chain
+            .storage_with_keys(
+                "Commitments",
+                "CommitmentOf",
+                vec![Value::u128(3), Value::from_bytes(hotkey)],
+            )
+            .await
+            .unwrap();
+        assert_eq!(value, Some(Value::u128(42)));
+
+        let entries = chain
+            .storage_iter("Commitments", "CommitmentOf", vec![Value::u128(3)])
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(&entries[0].0[entries[0].0.len() - 32..], &hotkey);
+    }
+
+    #[tokio::test]
+    async fn test_scripted_extrinsics() {
+        let chain = MockChain::new();
+        let signer = crate::chain::signer_from_seed("//Alice").unwrap();
+        chain.push_extrinsic_result(Err(Error::DispatchError("SubtensorModule.X".into())));
+        chain.advance_blocks(5);
+
+        let failed = chain
+            .submit_extrinsic("M", "a", vec![], &signer, ExtrinsicWait::Included)
+            .await;
+        assert!(matches!(failed, Err(Error::DispatchError(_))));
+        chain.advance_blocks(1);
+        let ok = chain
+            .submit_extrinsic("M", "b", vec![], &signer, ExtrinsicWait::Included)
+            .await;
+        assert!(ok.is_ok());
+
+        let submitted = chain.submitted();
+        assert_eq!(submitted.len(), 2);
+        assert_eq!((submitted[0].block, submitted[1].block), (5, 6));
+        assert_eq!(submitted[1].function, "b");
+        assert_eq!(chain.block_number().await.unwrap(), 6);
+    }
+}
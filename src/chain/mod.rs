@@ -1,6 +1,23 @@
+mod backend;
+pub mod block_ref;
+pub mod builder;
 pub mod connection;
+pub mod constants;
+pub mod events;
+#[cfg(feature = "ledger")]
+pub mod ledger;
+pub mod metadata_cache;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
+pub mod profiling;
+pub mod rate_limit;
+pub mod remote_signer;
+pub mod retry;
 pub mod runtime;
 pub mod signer;
+#[cfg(feature = "static-metadata")]
+pub mod static_metadata;
+pub mod upgrade;
 
 use anyhow::Result;
 use sp_core::crypto::AccountId32;
@@ -10,12 +27,29 @@ use subxt::{dynamic::Value, PolkadotConfig};
 use thiserror::Error;
 use tracing::{debug, info, warn};
 
+pub use backend::ChainBackend;
+pub use block_ref::BlockRef;
+pub use builder::BittensorClientBuilder;
 pub use connection::*;
+pub use constants::ChainConstants;
+pub use events::SubtensorEvent;
+#[cfg(feature = "ledger")]
+pub use ledger::{LedgerError, LedgerSigner};
+pub use metadata_cache::{MetadataCache, MetadataCacheDir, MetadataKey};
+#[cfg(any(test, feature = "test-utils"))]
+pub use mock::{MockChain, StorageRead, SubmittedExtrinsic};
+pub use profiling::{CallProfiler, CallSample, CallStats, ProfileReport};
+pub use rate_limit::{CategoryRateLimiter, ExtrinsicCategory, RateLimitedClient};
+pub use remote_signer::{RemoteSigner, DEFAULT_REMOTE_SIGNER_TIMEOUT};
+pub use retry::{is_transient_error_message, with_retry, RetryingClient, TransientError};
 pub use runtime::*;
 pub use signer::{
     create_signer, signer_from_seed, BittensorSigner, ManagedSigner, NonceManager,
-    SharedNonceManager,
+    SharedNonceManager, TransactionSigner,
 };
+#[cfg(feature = "static-metadata")]
+pub use static_metadata::{CallPayload, StaticInterface, StaticItem};
+pub use upgrade::{RuntimeUpgrade, RuntimeUpgradeCallback, RuntimeUpgradeMonitor};
 
 pub const DEFAULT_RPC_URL: &str = "wss://entrypoint-finney.opentensor.ai:443";
 
@@ -27,7 +61,7 @@ pub const FALLBACK_ENDPOINTS: &[&str] = &[
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Subxt error: {0}")]
-    Subxt(#[from] subxt::Error),
+    Subxt(Box<subxt::Error>),
     #[error("RPC error: {0}")]
     Rpc(String),
     #[error("Encoding error: {0}")]
@@ -44,6 +78,41 @@ pub enum Error {
     RateLimited(String),
     #[error("Dispatch error: {0}")]
     DispatchError(String),
+    #[error("Signer error: {0}")]
+    Signer(String),
+    #[error(transparent)]
+    BlockNotFound(#[from] crate::errors::BlockNotFound),
+}
+
+impl Error {
+    /// Short, stable name of the error variant (used as a metrics label)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::Subxt(_) => "subxt",
+            Error::Rpc(_) => "rpc",
+            Error::Encoding(_) => "encoding",
+            Error::Decoding(_) => "decoding",
+            Error::InvalidAccount(_) => "invalid_account",
+            Error::Transaction(_) => "transaction",
+            Error::Nonce(_) => "nonce",
+            Error::RateLimited(_) => "rate_limited",
+            Error::DispatchError(_) => "dispatch_error",
+            Error::Signer(_) => "signer",
+            Error::BlockNotFound(_) => "block_not_found",
+        }
+    }
+}
+
+impl From<subxt::Error> for Error {
+    fn from(e: subxt::Error) -> Self {
+        Error::Subxt(Box::new(e))
+    }
+}
+
+impl From<subxt::ext::subxt_rpcs::Error> for Error {
+    fn from(e: subxt::ext::subxt_rpcs::Error) -> Self {
+        Error::Subxt(Box::new(e.into()))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -180,7 +249,19 @@ fn is_nonce_error(err: &Error) -> bool {
 }
 
 fn is_retryable_rpc_error(err: &Error) -> bool {
-    let msg = err.to_string().to_lowercase();
+    is_connection_error_message(&err.to_string())
+}
+
+/// Whether an error message describes a dropped or unreachable connection
+/// rather than a failed query or dispatch
+///
+/// A connection closed over an oversized message does not count: the same
+/// request would fail again on any connection.
+pub(crate) fn is_connection_error_message(msg: &str) -> bool {
+    if is_message_too_large_error(msg) {
+        return false;
+    }
+    let msg = msg.to_lowercase();
     msg.contains("503")
         || msg.contains("connection reset")
         || msg.contains("connection refused")
@@ -193,6 +274,20 @@ fn is_retryable_rpc_error(err: &Error) -> bool {
         || msg.contains("restart")
 }
 
+/// Whether an error message says a request or response exceeded the
+/// WebSocket message size limit
+///
+/// See [`BittensorClientBuilder::max_message_size`]. Queries that hit this
+/// can be retried as several smaller requests on the same client.
+pub fn is_message_too_large_error(msg: &str) -> bool {
+    let msg = msg.to_lowercase();
+    msg.contains("message too large")
+        || msg.contains("message was too large")
+        || msg.contains("maximum message size")
+}
+
+// The closures return subxt's own, unboxed error
+#[allow(clippy::result_large_err)]
 fn parse_events_from_in_block(
     in_block: &subxt::tx::TxInBlock<PolkadotConfig, subxt::OnlineClient<PolkadotConfig>>,
     metadata: &subxt::Metadata,
@@ -332,6 +427,7 @@ fn resolve_module_error(
 pub struct BittensorClient {
     pub api: subxt::OnlineClient<PolkadotConfig>,
     pub rpc_url: String,
+    rpc: subxt::backend::rpc::RpcClient,
     nonce_manager: Arc<NonceManager>,
     rate_limiter: Option<
         Arc<
@@ -343,23 +439,23 @@ pub struct BittensorClient {
             >,
         >,
     >,
+    max_message_size: usize,
+    constants: ChainConstants,
+    profiler: Arc<CallProfiler>,
+    metadata_cache: Option<MetadataCache>,
+    upgrades: Arc<RuntimeUpgradeMonitor>,
+    _upgrade_watcher: upgrade::UpgradeWatcher,
+    #[cfg(feature = "static-metadata")]
+    static_interface: Arc<StaticInterface>,
 }
 
 impl BittensorClient {
+    /// Connect to `rpc_url` with default settings
+    ///
+    /// Use [`BittensorClient::builder`] to set a connection timeout,
+    /// handshake headers or the message size limit.
     pub async fn new(rpc_url: impl Into<String>) -> Result<Self, Error> {
-        let url = rpc_url.into();
-        let api = if url.starts_with("ws://") {
-            subxt::OnlineClient::<PolkadotConfig>::from_insecure_url(&url).await?
-        } else {
-            subxt::OnlineClient::<PolkadotConfig>::from_url(&url).await?
-        };
-
-        Ok(Self {
-            api,
-            rpc_url: url,
-            nonce_manager: Arc::new(NonceManager::new()),
-            rate_limiter: None,
-        })
+        BittensorClientBuilder::new(rpc_url).build().await
     }
 
     pub async fn with_default() -> Result<Self, Error> {
@@ -431,6 +527,16 @@ impl BittensorClient {
         &self.rpc_url
     }
 
+    /// Largest request or response the connection accepts, in bytes
+    pub fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
+    /// Legacy JSON-RPC methods (`chain_*`, `state_*`) of the connected node
+    pub fn legacy_rpc(&self) -> subxt::backend::legacy::LegacyRpcMethods<PolkadotConfig> {
+        subxt::backend::legacy::LegacyRpcMethods::new(self.rpc.clone())
+    }
+
     pub async fn fetch_nonce_from_chain(
         &self,
         account: &subxt::config::substrate::AccountId32,
@@ -439,8 +545,32 @@ impl BittensorClient {
         Ok(nonce)
     }
 
-    async fn ensure_nonce_initialized(&self, signer: &BittensorSigner) -> Result<(), Error> {
-        let account_id = <BittensorSigner as subxt::tx::Signer<PolkadotConfig>>::account_id(signer);
+    /// Sign a call, reporting signer failures such as a locked Ledger device
+    /// or an unreachable remote signer
+    async fn create_signed(
+        &self,
+        call: &impl subxt::tx::Payload,
+        signer: &dyn TransactionSigner,
+        account_id: &subxt::config::substrate::AccountId32,
+        tx_params: subxt::ext::subxt_core::config::ParamsFor<PolkadotConfig>,
+    ) -> Result<
+        subxt::tx::SubmittableTransaction<PolkadotConfig, subxt::OnlineClient<PolkadotConfig>>,
+        Error,
+    > {
+        let mut partial = self
+            .api
+            .tx()
+            .create_partial(call, account_id, tx_params)
+            .await?;
+        let signature = signer
+            .sign(&partial.signer_payload())
+            .await
+            .map_err(|e| Error::Signer(e.message))?;
+        Ok(partial.sign_with_account_and_signature(account_id, &signature))
+    }
+
+    async fn ensure_nonce_initialized(&self, signer: &dyn TransactionSigner) -> Result<(), Error> {
+        let account_id = signer.account_id().clone();
         if self.nonce_manager.needs_refresh(&account_id).await {
             let on_chain_nonce = self.fetch_nonce_from_chain(&account_id).await?;
             self.nonce_manager
@@ -461,20 +591,10 @@ impl BittensorClient {
         key: Option<Vec<Value>>,
     ) -> Result<Option<Value>, Error> {
         let keys = key.unwrap_or_default();
-        let storage_query = subxt::dynamic::storage(module, entry, keys);
-        let storage = self.api.storage().at_latest().await?;
-        let value = storage.fetch(&storage_query).await?;
-
-        match value {
-            Some(thunk) => match thunk.to_value() {
-                Ok(v) => Ok(Some(v.remove_context())),
-                Err(e) => Err(Error::Decoding(format!(
-                    "Failed to decode storage value: {}",
-                    e
-                ))),
-            },
-            None => Ok(None),
-        }
+        observe_rpc(
+            "storage",
+            self.fetch_storage(module, entry, keys, None).await,
+        )
     }
 
     pub async fn storage_with_keys(
@@ -483,20 +603,10 @@ impl BittensorClient {
         entry: &str,
         keys: Vec<Value>,
     ) -> Result<Option<Value>, Error> {
-        let storage_query = subxt::dynamic::storage(module, entry, keys);
-        let storage = self.api.storage().at_latest().await?;
-        let value = storage.fetch(&storage_query).await?;
-
-        match value {
-            Some(thunk) => match thunk.to_value() {
-                Ok(v) => Ok(Some(v.remove_context())),
-                Err(e) => Err(Error::Decoding(format!(
-                    "Failed to decode storage value: {}",
-                    e
-                ))),
-            },
-            None => Ok(None),
-        }
+        observe_rpc(
+            "storage",
+            self.fetch_storage(module, entry, keys, None).await,
+        )
     }
 
     pub async fn storage_at_block(
@@ -506,20 +616,133 @@ impl BittensorClient {
         keys: Vec<Value>,
         block_hash: sp_core::H256,
     ) -> Result<Option<Value>, Error> {
-        let storage_query = subxt::dynamic::storage(module, entry, keys);
-        let storage = self.api.storage().at(block_hash);
-        let value = storage.fetch(&storage_query).await?;
+        observe_rpc(
+            "storage_at_block",
+            self.fetch_storage(module, entry, keys, Some(block_hash))
+                .await,
+        )
+    }
 
-        match value {
-            Some(thunk) => match thunk.to_value() {
-                Ok(v) => Ok(Some(v.remove_context())),
-                Err(e) => Err(Error::Decoding(format!(
-                    "Failed to decode storage value: {}",
-                    e
-                ))),
-            },
-            None => Ok(None),
+    /// Iterate a storage map at the latest block
+    ///
+    /// `keys` is a (possibly empty) prefix of the map's keys. Returns the raw
+    /// storage key of each entry with its decoded value; for `*Concat` hashers
+    /// the trailing map key can be read from the end of the key bytes.
+    ///
+    /// subxt fetches the keys a page at a time (`state_getKeysPaged`) and the
+    /// values per page, so no single response holds the whole map.
+    pub async fn storage_iter(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> Result<Vec<(Vec<u8>, Value)>, Error> {
+        observe_rpc(
+            "storage_iter",
+            self.fetch_storage_iter(module, entry, keys, None).await,
+        )
+    }
+
+    /// Iterate a storage map at `block_hash`, see [`storage_iter`](Self::storage_iter)
+    pub async fn storage_iter_at_block(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+        block_hash: sp_core::H256,
+    ) -> Result<Vec<(Vec<u8>, Value)>, Error> {
+        observe_rpc(
+            "storage_iter_at_block",
+            self.fetch_storage_iter(module, entry, keys, Some(block_hash))
+                .await,
+        )
+    }
+
+    /// Iterate a storage map at `block_hash`, or the latest block
+    async fn fetch_storage_iter(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+        block_hash: Option<sp_core::H256>,
+    ) -> Result<Vec<(Vec<u8>, Value)>, Error> {
+        let started = self.profiler.start();
+        let mut bytes = 0;
+        let result = async {
+            let storage_query = subxt::dynamic::storage(module, entry, keys);
+            let storage = match block_hash {
+                Some(hash) => self.api.storage().at(hash),
+                None => self.api.storage().at_latest().await?,
+            };
+            let mut iter = storage.iter(storage_query).await?;
+
+            let mut entries = Vec::new();
+            while let Some(item) = iter.next().await {
+                let kv = item?;
+                bytes += kv.key_bytes.len() + kv.value.encoded().len();
+                let value = kv.value.to_value().map_err(|e| {
+                    Error::Decoding(format!("Failed to decode storage value: {}", e))
+                })?;
+                entries.push((kv.key_bytes, value.remove_context()));
+            }
+            Ok(entries)
         }
+        .await;
+
+        self.profiler.finish(
+            started,
+            || format!("{}.{}", module, entry),
+            bytes,
+            result.is_ok(),
+        );
+        result
+    }
+
+    /// Fetch and decode a storage value at `block_hash`, or the latest block
+    async fn fetch_storage(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+        block_hash: Option<sp_core::H256>,
+    ) -> Result<Option<Value>, Error> {
+        let started = self.profiler.start();
+        let result = async {
+            let storage = match block_hash {
+                Some(hash) => self.api.storage().at(hash),
+                None => self.api.storage().at_latest().await?,
+            };
+            #[cfg(feature = "static-metadata")]
+            if let Some(query) = static_metadata::StorageQuery::new(module, entry, &keys) {
+                if self.uses_static_metadata(query.item()) {
+                    return Ok(query.fetch(&storage).await?);
+                }
+            }
+
+            let storage_query = subxt::dynamic::storage(module, entry, keys);
+            let value = storage.fetch(&storage_query).await?;
+
+            match value {
+                Some(thunk) => match thunk.to_value() {
+                    Ok(v) => Ok((Some(v.remove_context()), thunk.encoded().len())),
+                    Err(e) => Err(Error::Decoding(format!(
+                        "Failed to decode storage value: {}",
+                        e
+                    ))),
+                },
+                None => Ok((None, 0)),
+            }
+        }
+        .await;
+
+        let bytes = result.as_ref().map_or(0, |(_, bytes)| *bytes);
+        self.profiler.finish(
+            started,
+            || format!("{}.{}", module, entry),
+            bytes,
+            result.is_ok(),
+        );
+        result.map(|(value, _)| value)
     }
 
     pub async fn runtime_api(
@@ -528,22 +751,35 @@ impl BittensorClient {
         method: &str,
         params: Vec<Value>,
     ) -> Result<Option<Value>, Error> {
+        let started = self.profiler.start();
         let api_call = subxt::dynamic::runtime_api_call(runtime_api, method, params);
-        let result = self
-            .api
-            .runtime_api()
-            .at_latest()
-            .await?
-            .call(api_call)
-            .await?;
-
-        match result.to_value() {
-            Ok(v) => Ok(Some(v.remove_context())),
-            Err(e) => Err(Error::Decoding(format!(
-                "Failed to decode runtime API result: {}",
-                e
-            ))),
+        let result = async {
+            let result = self
+                .api
+                .runtime_api()
+                .at_latest()
+                .await?
+                .call(api_call)
+                .await?;
+
+            match result.to_value() {
+                Ok(v) => Ok((Some(v.remove_context()), result.encoded().len())),
+                Err(e) => Err(Error::Decoding(format!(
+                    "Failed to decode runtime API result: {}",
+                    e
+                ))),
+            }
         }
+        .await;
+
+        let bytes = result.as_ref().map_or(0, |(_, bytes)| *bytes);
+        self.profiler.finish(
+            started,
+            || format!("{}_{}", runtime_api, method),
+            bytes,
+            result.is_ok(),
+        );
+        observe_rpc("runtime_api", result.map(|(value, _)| value))
     }
 
     pub async fn runtime_api_call(
@@ -552,16 +788,25 @@ impl BittensorClient {
         method: &str,
         params: Option<Vec<u8>>,
     ) -> Result<Vec<u8>, Error> {
+        let started = self.profiler.start();
         let params_ref = params.as_deref();
         let runtime_api_call = format!("{}_{}", runtime_api, method);
-        let payload = self
-            .api
-            .runtime_api()
-            .at_latest()
-            .await?
-            .call_raw(&runtime_api_call, params_ref)
-            .await?;
-        Ok(payload)
+        let payload = async {
+            Ok::<_, Error>(
+                self.api
+                    .runtime_api()
+                    .at_latest()
+                    .await?
+                    .call_raw(&runtime_api_call, params_ref)
+                    .await?,
+            )
+        }
+        .await;
+
+        let bytes = payload.as_ref().map_or(0, Vec::len);
+        self.profiler
+            .finish(started, || runtime_api_call, bytes, payload.is_ok());
+        observe_rpc("runtime_api_call", payload)
     }
 
     pub async fn account_balance(&self, account: &AccountId32) -> Result<u128, Error> {
@@ -570,40 +815,17 @@ impl BittensorClient {
         let account_bytes = account.encode();
         let account_value = Value::from_bytes(&account_bytes);
 
-        let storage_query = subxt::dynamic::storage("System", "Account", vec![account_value]);
-        let storage = self.api.storage().at_latest().await?;
-        let data = storage.fetch(&storage_query).await?;
-
-        match data {
-            Some(thunk) => {
-                let value = thunk
-                    .to_value()
-                    .map_err(|e| Error::Decoding(format!("Failed to decode account data: {}", e)))?
-                    .remove_context();
-
-                if let Ok(named) = crate::utils::decoders::decode_named_composite(&value) {
-                    if let Some(data_value) = named.get("data") {
-                        if let Ok(data_fields) =
-                            crate::utils::decoders::decode_named_composite(data_value)
-                        {
-                            if let Some(free_value) = data_fields.get("free") {
-                                if let Ok(balance) = crate::utils::decoders::decode_u128(free_value)
-                                {
-                                    return Ok(balance);
-                                }
-                            }
-                        }
-                    }
-                }
-
-                Err(Error::Decoding(
-                    "Failed to extract balance from account data".to_string(),
-                ))
-            }
-            None => Err(Error::Decoding(
+        let data = self
+            .fetch_storage("System", "Account", vec![account_value], None)
+            .await;
+        let balance = match data {
+            Ok(Some(value)) => decode_free_balance(&value),
+            Ok(None) => Err(Error::Decoding(
                 "Account balance not found in storage".to_string(),
             )),
-        }
+            Err(e) => Err(e),
+        };
+        observe_rpc("account_balance", balance)
     }
 
     pub async fn submit_extrinsic(
@@ -611,7 +833,7 @@ impl BittensorClient {
         module: &str,
         function: &str,
         args: Vec<Value>,
-        signer: &BittensorSigner,
+        signer: &dyn TransactionSigner,
         wait_for: ExtrinsicWait,
     ) -> Result<String, Error> {
         let result = self
@@ -632,12 +854,39 @@ impl BittensorClient {
         }
     }
 
+    /// Estimate the fee, in RAO, of an extrinsic from `signer`
+    ///
+    /// The extrinsic is built with a placeholder signature, so hardware and
+    /// remote signers are not asked to sign it.
+    pub async fn estimate_fee(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+        signer: &dyn TransactionSigner,
+    ) -> Result<u128, Error> {
+        let call = self.call_payload(module, function, args);
+        let account_id = signer.account_id().clone();
+        let tx_params = subxt::config::polkadot::PolkadotExtrinsicParamsBuilder::new().build();
+        let mut partial = self
+            .api
+            .tx()
+            .create_partial(&call, &account_id, tx_params)
+            .await?;
+        let signature = subxt::utils::MultiSignature::Sr25519([0u8; 64]);
+        let fee = partial
+            .sign_with_account_and_signature(&account_id, &signature)
+            .partial_fee_estimate()
+            .await?;
+        Ok(fee)
+    }
+
     pub async fn submit_extrinsic_with_result(
         &self,
         module: &str,
         function: &str,
         args: Vec<Value>,
-        signer: &BittensorSigner,
+        signer: &dyn TransactionSigner,
         wait_for: ExtrinsicWait,
     ) -> Result<ExtrinsicResult, Error> {
         let policy = RetryPolicy::standard();
@@ -650,7 +899,41 @@ impl BittensorClient {
         module: &str,
         function: &str,
         args: Vec<Value>,
-        signer: &BittensorSigner,
+        signer: &dyn TransactionSigner,
+        wait_for: ExtrinsicWait,
+        policy: &RetryPolicy,
+    ) -> Result<ExtrinsicResult, Error> {
+        #[cfg(feature = "metrics")]
+        let started = std::time::Instant::now();
+        let profiled = self.profiler.start();
+
+        let result = self
+            .submit_with_retries(module, function, args, signer, wait_for, policy)
+            .await;
+
+        self.profiler.finish(
+            profiled,
+            || format!("{}.{}", module, function),
+            0,
+            result.as_ref().is_ok_and(ExtrinsicResult::is_success),
+        );
+
+        #[cfg(feature = "metrics")]
+        crate::metrics::global().observe_extrinsic(
+            &format!("{}.{}", module, function),
+            wait_for,
+            &result,
+            started.elapsed(),
+        );
+        result
+    }
+
+    async fn submit_with_retries(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+        signer: &dyn TransactionSigner,
         wait_for: ExtrinsicWait,
         policy: &RetryPolicy,
     ) -> Result<ExtrinsicResult, Error> {
@@ -665,7 +948,7 @@ impl BittensorClient {
 
         self.ensure_nonce_initialized(signer).await?;
 
-        let account_id = <BittensorSigner as subxt::tx::Signer<PolkadotConfig>>::account_id(signer);
+        let account_id = signer.account_id().clone();
 
         let max_attempts = policy.max_retries + 1;
         let mut last_error: Option<Error> = None;
@@ -680,6 +963,9 @@ impl BittensorClient {
                     "Retry attempt {} for {}.{}, waiting {:?}",
                     attempt, module, function, delay
                 );
+                #[cfg(feature = "metrics")]
+                crate::metrics::global()
+                    .observe_extrinsic_retry(&format!("{}.{}", module, function));
                 tokio::time::sleep(delay).await;
             }
 
@@ -698,18 +984,19 @@ impl BittensorClient {
                 }
             };
 
-            let call = subxt::dynamic::tx(module, function, args.clone());
+            let call = self.call_payload(module, function, args.clone());
 
             let tx_params = subxt::config::polkadot::PolkadotExtrinsicParamsBuilder::new()
                 .nonce(nonce)
                 .build();
 
-            let submit_result = self.api.tx().create_signed(&call, signer, tx_params).await;
+            let submit_result = self
+                .create_signed(&call, signer, &account_id, tx_params)
+                .await;
 
             let signed_tx = match submit_result {
                 Ok(tx) => tx,
-                Err(e) => {
-                    let err = Error::Subxt(e);
+                Err(err) => {
                     if is_nonce_error(&err)
                         && policy.retry_nonce_errors
                         && attempt < max_attempts - 1
@@ -733,7 +1020,7 @@ impl BittensorClient {
             let mut tx_progress = match signed_tx.submit_and_watch().await {
                 Ok(progress) => progress,
                 Err(e) => {
-                    let err = Error::Subxt(e);
+                    let err = Error::from(e);
                     self.nonce_manager.fail_nonce(&account_id, nonce).await;
 
                     if is_nonce_error(&err)
@@ -747,6 +1034,17 @@ impl BittensorClient {
                         continue;
                     }
 
+                    if upgrade::is_stale_runtime_error(&err) && attempt < max_attempts - 1 {
+                        if let Ok(Some(upgrade)) = self.refresh_runtime().await {
+                            warn!(
+                                "Resubmitting {}.{} for spec version {}",
+                                module, function, upgrade.new_spec_version
+                            );
+                            last_error = Some(err);
+                            continue;
+                        }
+                    }
+
                     if is_retryable_rpc_error(&err)
                         && policy.retry_rpc_errors
                         && attempt < max_attempts - 1
@@ -828,7 +1126,7 @@ impl BittensorClient {
                     }
                     Err(e) => {
                         self.nonce_manager.fail_nonce(&account_id, nonce).await;
-                        let err = Error::Subxt(e);
+                        let err = Error::from(e);
                         if is_retryable_rpc_error(&err)
                             && policy.retry_rpc_errors
                             && attempt < max_attempts - 1
@@ -906,7 +1204,120 @@ impl BittensorClient {
         self.api.metadata()
     }
 
+    /// Whether the static fast path for `item` is in use
+    ///
+    /// True while `item` has the same shape in the connected runtime as in
+    /// the metadata the `static-metadata` feature was built with; see
+    /// [`static_metadata`].
+    #[cfg(feature = "static-metadata")]
+    pub fn uses_static_metadata(&self, item: StaticItem) -> bool {
+        self.static_interface.is_active(&self.api, item)
+    }
+
+    /// Call data for `module::function(args)`
+    #[cfg(feature = "static-metadata")]
+    fn call_payload(&self, module: &str, function: &str, args: Vec<Value>) -> CallPayload {
+        let use_static =
+            StaticItem::call(module, function).is_some_and(|item| self.uses_static_metadata(item));
+        CallPayload::new(use_static, module, function, args)
+    }
+
+    #[cfg(not(feature = "static-metadata"))]
+    fn call_payload(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+    ) -> subxt::tx::DynamicPayload {
+        subxt::dynamic::tx(module, function, args)
+    }
+
+    /// Constants of the runtime, read from metadata when the client connected
+    pub fn constants(&self) -> &ChainConstants {
+        &self.constants
+    }
+
+    /// Start or stop recording the timing of each chain call
+    ///
+    /// See [`profiling`] for what is recorded. Samples are kept when
+    /// profiling is turned off; use [`clear_profile`](Self::clear_profile)
+    /// to start over.
+    pub fn enable_profiling(&self, enabled: bool) {
+        self.profiler.set_enabled(enabled);
+    }
+
+    /// Timings recorded since profiling was enabled, per call
+    pub fn profile_report(&self) -> ProfileReport {
+        self.profiler.report()
+    }
+
+    /// Forget the recorded timings
+    pub fn clear_profile(&self) {
+        self.profiler.clear();
+    }
+
+    /// The profiler shared by this client
+    pub fn profiler(&self) -> &Arc<CallProfiler> {
+        &self.profiler
+    }
+
+    /// Run `callback` after each runtime upgrade, once the new metadata is
+    /// in use
+    ///
+    /// See [`upgrade`] for how upgrades are detected.
+    pub fn on_runtime_upgrade(&self, callback: impl Fn(&RuntimeUpgrade) + Send + Sync + 'static) {
+        self.upgrades.on_upgrade(callback);
+    }
+
+    /// Switch to the node's current runtime if it was upgraded
+    ///
+    /// Upgrades are normally applied in the background; this checks the
+    /// runtime version now. Returns the upgrade, or None if the client was
+    /// up to date.
+    pub async fn refresh_runtime(&self) -> Result<Option<RuntimeUpgrade>, Error> {
+        let methods = self.legacy_rpc();
+        let version = methods.state_get_runtime_version(None).await?;
+        let current = self.api.runtime_version();
+        if (version.spec_version, version.transaction_version)
+            == (current.spec_version, current.transaction_version)
+        {
+            return Ok(None);
+        }
+
+        let bytes = metadata_cache::fetch_metadata(&methods).await?;
+        self.api
+            .set_metadata(metadata_cache::decode_metadata(&bytes)?);
+        self.api.set_runtime_version(subxt::client::RuntimeVersion {
+            spec_version: version.spec_version,
+            transaction_version: version.transaction_version,
+        });
+        if let Some(cache) = &self.metadata_cache {
+            let key = MetadataKey {
+                genesis_hash: self.api.genesis_hash().0,
+                spec_version: version.spec_version,
+                transaction_version: version.transaction_version,
+            };
+            if let Err(e) = cache.store(&key, &bytes) {
+                debug!("Failed to write metadata cache: {}", e);
+            }
+        }
+        Ok(upgrade::applied(
+            &self.api,
+            &self.upgrades,
+            self.metadata_cache.as_ref(),
+        ))
+    }
+
     pub async fn block_number(&self) -> Result<u64, Error> {
+        Ok(self.finalized_head().await?.0)
+    }
+
+    /// Number and hash of the latest finalized block
+    ///
+    /// Pass the hash to [`storage_at_block`](Self::storage_at_block) and
+    /// [`storage_iter_at_block`](Self::storage_iter_at_block) to read several
+    /// entries from the same block.
+    pub async fn finalized_head(&self) -> Result<(u64, sp_core::H256), Error> {
         let finalized_head = self.api.backend().latest_finalized_block_ref().await?;
         let header = self
             .api
@@ -916,8 +1327,7 @@ impl BittensorClient {
             .map_err(|e| Error::Rpc(format!("Failed to get block header: {}", e)))?;
 
         if let Some(header) = header {
-            let number = header.number;
-            Ok(number as u64)
+            Ok((header.number as u64, finalized_head.hash()))
         } else {
             Err(Error::Rpc("Block header not found".to_string()))
         }
@@ -933,22 +1343,38 @@ impl BittensorClient {
         Ok(block_stream.map(|result| {
             result
                 .map(|block| block.number() as u64)
-                .map_err(Error::Subxt)
+                .map_err(Error::from)
+        }))
+    }
+
+    /// Get the hash of the block at `block_number`, or None if the node does
+    /// not know it (e.g. beyond the head)
+    /// Subscribe to finalized blocks, yielding each block number with its
+    /// decoded events
+    pub async fn subscribe_finalized_blocks_with_events(
+        &self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<(u64, Vec<SubtensorEvent>), Error>> + Send + '_,
+        Error,
+    > {
+        use futures::StreamExt;
+
+        let block_stream = self.api.blocks().subscribe_finalized().await?;
+
+        Ok(block_stream.then(|result| async move {
+            let block = result.map_err(Error::from)?;
+            let events = block.events().await.map_err(Error::from)?;
+            Ok((block.number() as u64, events::decode_events(&events)))
         }))
     }
 
     pub async fn block_hash(&self, block_number: u64) -> Result<Option<sp_core::H256>, Error> {
-        let backend = self.api.backend();
+        use subxt::backend::legacy::rpc_methods::BlockNumber;
 
-        match backend
-            .block_header(backend.latest_finalized_block_ref().await?.hash())
+        self.legacy_rpc()
+            .chain_get_block_hash(Some(BlockNumber::from(block_number)))
             .await
-        {
-            Ok(Some(header)) if header.number as u64 == block_number => {
-                Ok(Some(backend.latest_finalized_block_ref().await?.hash()))
-            }
-            _ => Ok(None),
-        }
+            .map_err(|e| Error::Rpc(format!("Failed to get block hash: {}", e)))
     }
 
     pub async fn query_constant(
@@ -1005,6 +1431,19 @@ impl BittensorClient {
     }
 }
 
+/// Extract the free balance from a decoded `System.Account` value
+fn decode_free_balance(value: &Value) -> Result<u128, Error> {
+    use crate::utils::decoders::{decode_named_composite, decode_u128};
+
+    decode_named_composite(value)
+        .ok()
+        .and_then(|named| {
+            let data = decode_named_composite(named.get("data")?).ok()?;
+            decode_u128(data.get("free")?).ok()
+        })
+        .ok_or_else(|| Error::Decoding("Failed to extract balance from account data".to_string()))
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum ExtrinsicWait {
     None,
@@ -1012,6 +1451,15 @@ pub enum ExtrinsicWait {
     Finalized,
 }
 
+/// Count an RPC call and its error kind when the `metrics` feature is on
+fn observe_rpc<T>(method: &'static str, result: Result<T, Error>) -> Result<T, Error> {
+    #[cfg(feature = "metrics")]
+    crate::metrics::global().observe_rpc(method, result.as_ref().err());
+    #[cfg(not(feature = "metrics"))]
+    let _ = method;
+    result
+}
+
 pub fn create_client_rate_limiter(
     ops_per_second: u32,
 ) -> Arc<
@@ -0,0 +1,321 @@
+//! Per-call timing of chain requests
+//!
+//! A [`CallProfiler`] records the duration, payload size and outcome of each
+//! storage read, runtime API call and extrinsic into a bounded ring buffer.
+//! [`CallProfiler::report`] aggregates the samples per call into a
+//! [`ProfileReport`], which prints as a table sorted by total time:
+//!
+//! ```ignore
+//! client.enable_profiling(true);
+//! let metagraph = sync_metagraph(&client, 1).await?;
+//! println!("{}", client.profile_report());
+//! ```
+//!
+//! Profiling is off by default. While disabled, a call costs one atomic load.
+//! Calls are named `Module.Entry` for storage and extrinsics and
+//! `RuntimeApi_method` for runtime APIs.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Samples kept before the oldest are dropped
+pub const DEFAULT_PROFILE_CAPACITY: usize = 10_000;
+
+/// One profiled call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallSample {
+    pub call: String,
+    pub duration: Duration,
+    /// Encoded size of the response, 0 when unknown
+    pub bytes: usize,
+    pub success: bool,
+}
+
+/// Records [`CallSample`]s while enabled
+#[derive(Debug)]
+pub struct CallProfiler {
+    enabled: AtomicBool,
+    samples: Mutex<VecDeque<CallSample>>,
+    capacity: usize,
+    dropped: AtomicU64,
+}
+
+impl Default for CallProfiler {
+    fn default() -> Self {
+        Self::new(DEFAULT_PROFILE_CAPACITY)
+    }
+}
+
+impl CallProfiler {
+    /// A disabled profiler keeping at most `capacity` samples
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            samples: Mutex::new(VecDeque::new()),
+            capacity: capacity.max(1),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Start or stop recording; samples already recorded are kept
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Start timing a call, or None while disabled
+    pub fn start(&self) -> Option<Instant> {
+        self.is_enabled().then(Instant::now)
+    }
+
+    /// Record a call timed with [`start`](Self::start)
+    ///
+    /// `call` is only evaluated when the call was timed.
+    pub fn finish(
+        &self,
+        started: Option<Instant>,
+        call: impl FnOnce() -> String,
+        bytes: usize,
+        success: bool,
+    ) {
+        if let Some(started) = started {
+            self.record(CallSample {
+                call: call(),
+                duration: started.elapsed(),
+                bytes,
+                success,
+            });
+        }
+    }
+
+    /// Add a sample, dropping the oldest one when full
+    pub fn record(&self, sample: CallSample) {
+        let mut samples = self.samples();
+        if samples.len() >= self.capacity {
+            samples.pop_front();
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        samples.push_back(sample);
+    }
+
+    /// Samples recorded so far, oldest first
+    pub fn samples_snapshot(&self) -> Vec<CallSample> {
+        self.samples().iter().cloned().collect()
+    }
+
+    /// Forget all samples
+    pub fn clear(&self) {
+        self.samples().clear();
+        self.dropped.store(0, Ordering::Relaxed);
+    }
+
+    /// Aggregate the recorded samples per call
+    pub fn report(&self) -> ProfileReport {
+        let mut report = ProfileReport::from_samples(self.samples().iter());
+        report.dropped = self.dropped.load(Ordering::Relaxed);
+        report
+    }
+
+    fn samples(&self) -> std::sync::MutexGuard<'_, VecDeque<CallSample>> {
+        self.samples.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Aggregated timings of one call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallStats {
+    pub call: String,
+    pub count: usize,
+    pub failures: usize,
+    pub total: Duration,
+    pub mean: Duration,
+    /// 95th percentile duration (nearest rank)
+    pub p95: Duration,
+    /// Total response size
+    pub bytes: usize,
+}
+
+/// Per-call timings, slowest in total first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfileReport {
+    pub calls: Vec<CallStats>,
+    /// Samples dropped because the buffer was full
+    pub dropped: u64,
+}
+
+impl ProfileReport {
+    /// Aggregate `samples` per call
+    pub fn from_samples<'a>(samples: impl IntoIterator<Item = &'a CallSample>) -> Self {
+        let mut grouped: HashMap<&str, Vec<&CallSample>> = HashMap::new();
+        for sample in samples {
+            grouped.entry(&sample.call).or_default().push(sample);
+        }
+
+        let mut calls: Vec<CallStats> = grouped
+            .into_iter()
+            .map(|(call, samples)| {
+                let mut durations: Vec<Duration> = samples.iter().map(|s| s.duration).collect();
+                durations.sort();
+                let count = durations.len();
+                let total: Duration = durations.iter().sum();
+                let rank = (count * 95).div_ceil(100).max(1);
+                CallStats {
+                    call: call.to_string(),
+                    count,
+                    failures: samples.iter().filter(|s| !s.success).count(),
+                    total,
+                    mean: total / count as u32,
+                    p95: durations[rank - 1],
+                    bytes: samples.iter().map(|s| s.bytes).sum(),
+                }
+            })
+            .collect();
+        calls.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.call.cmp(&b.call)));
+
+        Self { calls, dropped: 0 }
+    }
+
+    /// Stats of `call`, if it was recorded
+    pub fn get(&self, call: &str) -> Option<&CallStats> {
+        self.calls.iter().find(|stats| stats.call == call)
+    }
+
+    /// Total time across all calls
+    pub fn total(&self) -> Duration {
+        self.calls.iter().map(|stats| stats.total).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.1}ms", duration.as_secs_f64() * 1000.0)
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.calls.is_empty() {
+            return writeln!(f, "No calls profiled");
+        }
+
+        let width = self
+            .calls
+            .iter()
+            .map(|stats| stats.call.len())
+            .max()
+            .unwrap_or(0)
+            .max("call".len());
+        writeln!(
+            f,
+            "{:<width$}  {:>7}  {:>6}  {:>12}  {:>10}  {:>10}  {:>10}",
+            "call", "count", "failed", "total", "mean", "p95", "bytes"
+        )?;
+        for stats in &self.calls {
+            writeln!(
+                f,
+                "{:<width$}  {:>7}  {:>6}  {:>12}  {:>10}  {:>10}  {:>10}",
+                stats.call,
+                stats.count,
+                stats.failures,
+                millis(stats.total),
+                millis(stats.mean),
+                millis(stats.p95),
+                stats.bytes
+            )?;
+        }
+        if self.dropped > 0 {
+            writeln!(f, "({} older samples dropped)", self.dropped)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(call: &str, millis: u64, success: bool) -> CallSample {
+        CallSample {
+            call: call.to_string(),
+            duration: Duration::from_millis(millis),
+            bytes: 10,
+            success,
+        }
+    }
+
+    #[test]
+    fn test_disabled_profiler_records_nothing() {
+        let profiler = CallProfiler::default();
+        let started = profiler.start();
+        assert!(started.is_none());
+        profiler.finish(started, || unreachable!(), 0, true);
+        assert!(profiler.report().is_empty());
+
+        profiler.set_enabled(true);
+        let started = profiler.start();
+        profiler.finish(started, || "System.Account".to_string(), 4, true);
+        assert_eq!(profiler.report().get("System.Account").unwrap().count, 1);
+    }
+
+    #[test]
+    fn test_report_aggregates_per_call() {
+        let mut samples: Vec<CallSample> = (1..=20)
+            .map(|i| sample("SubtensorModule.Keys", i, true))
+            .collect();
+        samples.push(sample(
+            "SubtensorInfoRuntimeApi_get_neurons_lite",
+            500,
+            false,
+        ));
+
+        let report = ProfileReport::from_samples(&samples);
+        assert_eq!(
+            report.calls[0].call,
+            "SubtensorInfoRuntimeApi_get_neurons_lite"
+        );
+        assert_eq!(report.calls[0].failures, 1);
+
+        let keys = report.get("SubtensorModule.Keys").unwrap();
+        assert_eq!(keys.count, 20);
+        assert_eq!(keys.total, Duration::from_millis(210));
+        assert_eq!(keys.mean, Duration::from_micros(10_500));
+        assert_eq!(keys.p95, Duration::from_millis(19));
+        assert_eq!(keys.bytes, 200);
+        assert_eq!(report.total(), Duration::from_millis(710));
+
+        let table = report.to_string();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("SubtensorInfoRuntimeApi_get_neurons_lite"));
+        assert!(lines[2].contains("210.0ms"));
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        let profiler = CallProfiler::new(2);
+        for call in ["a", "b", "c"] {
+            profiler.record(sample(call, 1, true));
+        }
+        let calls: Vec<String> = profiler
+            .samples_snapshot()
+            .into_iter()
+            .map(|s| s.call)
+            .collect();
+        assert_eq!(calls, ["b", "c"]);
+
+        let report = profiler.report();
+        assert_eq!(report.dropped, 1);
+        assert!(report.to_string().contains("1 older samples dropped"));
+
+        profiler.clear();
+        assert_eq!(profiler.report(), ProfileReport::default());
+    }
+}
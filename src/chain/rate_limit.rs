@@ -0,0 +1,265 @@
+//! Per-block budgets for extrinsic submission
+//!
+//! Nodes drop transactions from accounts that flood the pool. A
+//! [`RateLimitedClient`] caps how many extrinsics of each
+//! [`ExtrinsicCategory`] are submitted per block and, once a budget is spent,
+//! waits for the next finalized block instead of failing.
+
+use super::connection::BlockRateLimiter;
+use super::{BittensorClient, Error as ChainError};
+use crate::config::RateLimitConfig;
+use futures::{Stream, StreamExt};
+use std::fmt;
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::debug;
+
+/// Kinds of extrinsics with separate per-block budgets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtrinsicCategory {
+    /// Setting, committing and revealing weights
+    Weights,
+    /// Adding, removing, moving and transferring stake
+    Staking,
+    /// Serving axon and prometheus endpoints
+    Serving,
+    /// Everything else
+    Other,
+}
+
+impl fmt::Display for ExtrinsicCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtrinsicCategory::Weights => write!(f, "weights"),
+            ExtrinsicCategory::Staking => write!(f, "staking"),
+            ExtrinsicCategory::Serving => write!(f, "serving"),
+            ExtrinsicCategory::Other => write!(f, "other"),
+        }
+    }
+}
+
+/// A [`BlockRateLimiter`] per [`ExtrinsicCategory`]
+#[derive(Debug)]
+pub struct CategoryRateLimiter {
+    weights: BlockRateLimiter,
+    staking: BlockRateLimiter,
+    serving: BlockRateLimiter,
+    other: BlockRateLimiter,
+    timeout: Option<Duration>,
+}
+
+impl CategoryRateLimiter {
+    /// Create limiters with the budgets from `config`
+    pub fn new(config: &RateLimitConfig) -> Self {
+        Self {
+            weights: BlockRateLimiter::with_limit(config.weights),
+            staking: BlockRateLimiter::with_limit(config.staking),
+            serving: BlockRateLimiter::with_limit(config.serving),
+            other: BlockRateLimiter::with_limit(config.other),
+            timeout: config.timeout_secs.map(Duration::from_secs),
+        }
+    }
+
+    /// Get the limiter of a category
+    pub fn limiter(&self, category: ExtrinsicCategory) -> &BlockRateLimiter {
+        match category {
+            ExtrinsicCategory::Weights => &self.weights,
+            ExtrinsicCategory::Staking => &self.staking,
+            ExtrinsicCategory::Serving => &self.serving,
+            ExtrinsicCategory::Other => &self.other,
+        }
+    }
+
+    /// How long `acquire` waits for budget before failing
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Take one slot of `category`'s budget for the current block
+    ///
+    /// While the budget is spent, waits for the next finalized block. Returns
+    /// the block the slot was taken in, or [`ChainError::RateLimited`] if the
+    /// configured timeout passes first.
+    pub async fn acquire(
+        &self,
+        client: &BittensorClient,
+        category: ExtrinsicCategory,
+    ) -> Result<u64, ChainError> {
+        let limiter = self.limiter(category);
+        let block = client.block_number().await?;
+        if limiter.check_and_record(block).await {
+            return Ok(block);
+        }
+
+        debug!(
+            "{} budget of {} per block spent in block {}, waiting for the next block",
+            category,
+            limiter.limit(),
+            block
+        );
+        let blocks = client.subscribe_finalized_blocks().await?;
+        self.wait_for_slot(category, blocks).await
+    }
+
+    /// Take a slot in the first block from `blocks` that has budget left
+    async fn wait_for_slot<S>(
+        &self,
+        category: ExtrinsicCategory,
+        blocks: S,
+    ) -> Result<u64, ChainError>
+    where
+        S: Stream<Item = Result<u64, ChainError>>,
+    {
+        let limiter = self.limiter(category);
+        let wait = async {
+            let mut blocks = std::pin::pin!(blocks);
+            while let Some(block) = blocks.next().await {
+                let block = block?;
+                if limiter.check_and_record(block).await {
+                    return Ok(block);
+                }
+            }
+            Err(ChainError::Rpc(
+                "Block subscription ended while waiting for rate limit".to_string(),
+            ))
+        };
+
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, wait).await.map_err(|_| {
+                ChainError::RateLimited(format!("No {} budget left within {:?}", category, timeout))
+            })?,
+            None => wait.await,
+        }
+    }
+}
+
+impl Default for CategoryRateLimiter {
+    fn default() -> Self {
+        Self::new(&RateLimitConfig::default())
+    }
+}
+
+/// Client that spends a per-block budget before each extrinsic
+///
+/// Dereferences to the wrapped [`BittensorClient`], so queries work as usual;
+/// only submissions made through [`RateLimitedClient::submit`] are limited.
+/// Clones share budgets.
+#[derive(Debug, Clone)]
+pub struct RateLimitedClient {
+    client: Arc<BittensorClient>,
+    limiter: Arc<CategoryRateLimiter>,
+}
+
+impl RateLimitedClient {
+    /// Wrap `client` with the budgets from `config`
+    pub fn new(client: Arc<BittensorClient>, config: &RateLimitConfig) -> Self {
+        Self::with_limiter(client, Arc::new(CategoryRateLimiter::new(config)))
+    }
+
+    /// Wrap `client` with budgets shared with other clients
+    pub fn with_limiter(client: Arc<BittensorClient>, limiter: Arc<CategoryRateLimiter>) -> Self {
+        Self { client, limiter }
+    }
+
+    /// Get the wrapped client
+    pub fn client(&self) -> &Arc<BittensorClient> {
+        &self.client
+    }
+
+    /// Get the budgets
+    pub fn limiter(&self) -> &Arc<CategoryRateLimiter> {
+        &self.limiter
+    }
+
+    /// Wait for budget in `category`, then run `submit`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let tx_hash = limited
+    ///     .submit(ExtrinsicCategory::Serving, |client| async move {
+    ///         serve_axon(&client, &signer, netuid, &axon_info, wait_for).await
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn submit<T, E, F, Fut>(&self, category: ExtrinsicCategory, submit: F) -> Result<T, E>
+    where
+        F: FnOnce(Arc<BittensorClient>) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: From<ChainError>,
+    {
+        self.limiter.acquire(&self.client, category).await?;
+        submit(self.client.clone()).await
+    }
+}
+
+impl Deref for RateLimitedClient {
+    type Target = BittensorClient;
+
+    fn deref(&self) -> &BittensorClient {
+        &self.client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(weights: u32, timeout_secs: Option<u64>) -> CategoryRateLimiter {
+        CategoryRateLimiter::new(&RateLimitConfig {
+            weights,
+            timeout_secs,
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_categories_have_separate_budgets() {
+        let limiter = limiter(1, None);
+        let weights = limiter.limiter(ExtrinsicCategory::Weights);
+        assert!(weights.check_and_record(10).await);
+        assert!(!weights.check_and_record(10).await);
+
+        let staking = limiter.limiter(ExtrinsicCategory::Staking);
+        assert_eq!(staking.limit(), RateLimitConfig::default().staking);
+        assert!(staking.check_and_record(10).await);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_slot_takes_next_block() {
+        let limiter = limiter(1, Some(5));
+        let weights = limiter.limiter(ExtrinsicCategory::Weights);
+        assert!(weights.check_and_record(10).await);
+
+        // The subscription repeats the spent block before the next one
+        let blocks = futures::stream::iter(vec![Ok(10), Ok(11)]);
+        let block = limiter
+            .wait_for_slot(ExtrinsicCategory::Weights, blocks)
+            .await
+            .unwrap();
+        assert_eq!(block, 11);
+        assert!(!weights.check_and_record(11).await);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_slot_times_out() {
+        let limiter = CategoryRateLimiter {
+            timeout: Some(Duration::from_millis(20)),
+            ..limiter(1, None)
+        };
+        assert!(
+            limiter
+                .limiter(ExtrinsicCategory::Weights)
+                .check_and_record(10)
+                .await
+        );
+
+        let blocks = futures::stream::iter(vec![Ok(10)]).chain(futures::stream::pending());
+        let result = limiter
+            .wait_for_slot(ExtrinsicCategory::Weights, blocks)
+            .await;
+        assert!(matches!(result, Err(ChainError::RateLimited(_))));
+    }
+}
@@ -0,0 +1,301 @@
+//! Signer backed by a remote signing service
+//!
+//! Keys held by an HSM or a separate signer process never reach the
+//! validator: [`RemoteSigner`] POSTs each signer payload to an HTTPS endpoint
+//! and submits the signature it returns. The exchange is JSON:
+//!
+//! ```text
+//! POST <url>
+//! Authorization: Bearer <token>
+//! {"account_id": "<ss58>", "payload": "0x<hex>"}
+//!
+//! 200 OK
+//! {"signature": "0x<hex>", "scheme": "sr25519"}
+//! ```
+//!
+//! `scheme` is one of `sr25519` (the default), `ed25519` or `ecdsa`.
+//! sr25519 and ed25519 signatures are verified against the account before
+//! they are used.
+
+use crate::chain::signer::TransactionSigner;
+use crate::errors::ExtrinsicError;
+use crate::wallet::BITTENSOR_SS58_FORMAT;
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::{Ss58AddressFormat, Ss58Codec};
+use sp_core::{ecdsa, ed25519, sr25519, Pair};
+use std::net::IpAddr;
+use std::time::Duration;
+use subxt::config::substrate::{AccountId32, MultiSignature};
+use tracing::debug;
+
+/// Default time to wait for a signature
+pub const DEFAULT_REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize)]
+struct SignRequest<'a> {
+    account_id: &'a str,
+    payload: String,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+    #[serde(default)]
+    scheme: Option<String>,
+}
+
+/// Signs extrinsics through a remote signing service
+#[derive(Clone)]
+pub struct RemoteSigner {
+    http: reqwest::Client,
+    url: reqwest::Url,
+    account_id: AccountId32,
+    ss58_address: String,
+    bearer_token: Option<String>,
+    timeout: Duration,
+}
+
+impl RemoteSigner {
+    /// Create a signer for `account_id` using the service at `url`
+    ///
+    /// The URL must use HTTPS, except for loopback addresses.
+    pub fn new(url: &str, account_id: AccountId32) -> Result<Self, ExtrinsicError> {
+        let url = reqwest::Url::parse(url)
+            .map_err(|e| ExtrinsicError::new(format!("Invalid remote signer URL: {}", e)))?;
+        if url.scheme() != "https" && !is_loopback(&url) {
+            return Err(ExtrinsicError::new(format!(
+                "Remote signer URL must use https: {}",
+                url
+            )));
+        }
+        let ss58_address = sp_core::crypto::AccountId32::from(account_id.0)
+            .to_ss58check_with_version(Ss58AddressFormat::custom(BITTENSOR_SS58_FORMAT));
+        Ok(Self {
+            http: reqwest::Client::new(),
+            url,
+            account_id,
+            ss58_address,
+            bearer_token: None,
+            timeout: DEFAULT_REMOTE_SIGNER_TIMEOUT,
+        })
+    }
+
+    /// Send `token` as a bearer token with every request
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
+    /// Fail requests that take longer than `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// SS58 address of the signing account
+    pub fn ss58_address(&self) -> &str {
+        &self.ss58_address
+    }
+
+    /// Endpoint signing requests are sent to
+    pub fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    async fn request_signature(&self, payload: &[u8]) -> Result<MultiSignature, ExtrinsicError> {
+        let request = SignRequest {
+            account_id: &self.ss58_address,
+            payload: format!("0x{}", hex::encode(payload)),
+        };
+        let mut builder = self
+            .http
+            .post(self.url.clone())
+            .timeout(self.timeout)
+            .json(&request);
+        if let Some(token) = &self.bearer_token {
+            builder = builder.bearer_auth(token);
+        }
+
+        debug!(
+            "Requesting signature for {} from {}",
+            self.ss58_address, self.url
+        );
+        let response = builder.send().await.map_err(|e| {
+            if e.is_timeout() {
+                ExtrinsicError::new(format!("Remote signer timed out after {:?}", self.timeout))
+            } else {
+                ExtrinsicError::new(format!("Remote signer request failed: {}", e))
+            }
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ExtrinsicError::new(format!(
+                "Remote signer returned {}: {}",
+                status,
+                body.trim()
+            )));
+        }
+        let response: SignResponse = response
+            .json()
+            .await
+            .map_err(|e| ExtrinsicError::new(format!("Invalid remote signer response: {}", e)))?;
+        self.decode_signature(&response, payload)
+    }
+
+    /// Decode a signature and check it is for this account
+    fn decode_signature(
+        &self,
+        response: &SignResponse,
+        payload: &[u8],
+    ) -> Result<MultiSignature, ExtrinsicError> {
+        let bytes = hex::decode(response.signature.trim_start_matches("0x"))
+            .map_err(|e| ExtrinsicError::new(format!("Invalid remote signer signature: {}", e)))?;
+        let invalid_length = || {
+            ExtrinsicError::new(format!(
+                "Remote signer returned a {} byte signature",
+                bytes.len()
+            ))
+        };
+        let scheme = response.scheme.as_deref().unwrap_or("sr25519");
+        let signature = match scheme {
+            "sr25519" => {
+                let signature =
+                    sr25519::Signature::try_from(bytes.as_slice()).map_err(|_| invalid_length())?;
+                let public = sr25519::Public::from_raw(self.account_id.0);
+                if !sr25519::Pair::verify(&signature, payload, &public) {
+                    return Err(self.wrong_signature());
+                }
+                MultiSignature::Sr25519(signature.0)
+            }
+            "ed25519" => {
+                let signature =
+                    ed25519::Signature::try_from(bytes.as_slice()).map_err(|_| invalid_length())?;
+                let public = ed25519::Public::from_raw(self.account_id.0);
+                if !ed25519::Pair::verify(&signature, payload, &public) {
+                    return Err(self.wrong_signature());
+                }
+                MultiSignature::Ed25519(signature.0)
+            }
+            // The account is a hash of the ecdsa key, so it cannot be checked here
+            "ecdsa" => MultiSignature::Ecdsa(
+                ecdsa::Signature::try_from(bytes.as_slice())
+                    .map_err(|_| invalid_length())?
+                    .0,
+            ),
+            other => {
+                return Err(ExtrinsicError::new(format!(
+                    "Unsupported remote signer scheme: {}",
+                    other
+                )))
+            }
+        };
+        Ok(signature)
+    }
+
+    fn wrong_signature(&self) -> ExtrinsicError {
+        ExtrinsicError::new(format!(
+            "Remote signer returned a signature that is not valid for {}",
+            self.ss58_address
+        ))
+    }
+}
+
+impl TransactionSigner for RemoteSigner {
+    fn account_id(&self) -> &AccountId32 {
+        &self.account_id
+    }
+
+    fn sign<'a>(
+        &'a self,
+        signer_payload: &'a [u8],
+    ) -> BoxFuture<'a, Result<MultiSignature, ExtrinsicError>> {
+        Box::pin(self.request_signature(signer_payload))
+    }
+}
+
+impl std::fmt::Debug for RemoteSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("url", &self.url.as_str())
+            .field("ss58_address", &self.ss58_address)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+fn is_loopback(url: &reqwest::Url) -> bool {
+    match url.host_str() {
+        Some("localhost") => true,
+        Some(host) => host
+            .trim_matches(['[', ']'])
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback()),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer(pair: &sr25519::Pair) -> RemoteSigner {
+        RemoteSigner::new("https://signer.example", AccountId32(pair.public().0)).unwrap()
+    }
+
+    fn response(signature: &[u8], scheme: Option<&str>) -> SignResponse {
+        SignResponse {
+            signature: format!("0x{}", hex::encode(signature)),
+            scheme: scheme.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_requires_https() {
+        let account = AccountId32([1; 32]);
+        assert!(RemoteSigner::new("https://signer.example/sign", account.clone()).is_ok());
+        assert!(RemoteSigner::new("http://127.0.0.1:8080/sign", account.clone()).is_ok());
+        assert!(RemoteSigner::new("http://localhost/sign", account.clone()).is_ok());
+        assert!(RemoteSigner::new("http://signer.example/sign", account.clone()).is_err());
+        assert!(RemoteSigner::new("not a url", account).is_err());
+    }
+
+    #[test]
+    fn test_decode_signature_checks_account() {
+        let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let signer = signer(&pair);
+        let payload = b"payload";
+
+        let signature = pair.sign(payload);
+        assert!(matches!(
+            signer.decode_signature(&response(&signature.0, None), payload),
+            Ok(MultiSignature::Sr25519(bytes)) if bytes == signature.0
+        ));
+
+        // Signed by another key or for another payload
+        let bob = sr25519::Pair::from_string("//Bob", None).unwrap();
+        let err = signer
+            .decode_signature(&response(&bob.sign(payload).0, None), payload)
+            .unwrap_err();
+        assert!(err.message.contains("not valid for"));
+        assert!(signer
+            .decode_signature(&response(&signature.0, Some("sr25519")), b"other")
+            .is_err());
+
+        assert!(signer
+            .decode_signature(&response(&[0; 10], None), payload)
+            .is_err());
+        assert!(signer
+            .decode_signature(&response(&signature.0, Some("bls")), payload)
+            .is_err());
+    }
+
+    #[test]
+    fn test_debug_hides_token() {
+        let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let signer = signer(&pair).with_bearer_token("secret");
+        assert!(!format!("{:?}", signer).contains("secret"));
+    }
+}
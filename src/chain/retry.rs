@@ -0,0 +1,385 @@
+//! Retries for individual reads
+//!
+//! [`with_retry`] reruns an idempotent operation while it fails with a
+//! transient error: a dropped or timed-out connection, or a node rejecting
+//! the request with HTTP 429. Decoding, dispatch and other errors that would
+//! fail again are returned at once. [`RetryingClient`] applies it to every
+//! read made through [`ChainBackend`].
+
+use super::{is_connection_error_message, BittensorClient, BittensorSigner, ChainBackend};
+use super::{ChainConstants, Error, ExtrinsicWait, RetryPolicy};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+use subxt::dynamic::Value;
+use subxt::error::RpcError;
+use tracing::debug;
+
+/// Errors that can tell whether retrying the same request may succeed
+pub trait TransientError: std::fmt::Display {
+    /// Whether the failure came from the connection or node load rather than
+    /// the request itself
+    fn is_transient(&self) -> bool;
+}
+
+/// Whether an error message describes a connection failure or a 429
+pub fn is_transient_error_message(msg: &str) -> bool {
+    if is_connection_error_message(msg) {
+        return true;
+    }
+    let msg = msg.to_lowercase();
+    msg.contains("429") || msg.contains("too many requests")
+}
+
+impl TransientError for subxt::Error {
+    fn is_transient(&self) -> bool {
+        match self {
+            subxt::Error::Io(_) => true,
+            subxt::Error::Rpc(RpcError::SubscriptionDropped) => true,
+            subxt::Error::Rpc(e) => is_transient_error_message(&e.to_string()),
+            subxt::Error::Codec(_)
+            | subxt::Error::Decode(_)
+            | subxt::Error::Encode(_)
+            | subxt::Error::Metadata(_)
+            | subxt::Error::Runtime(_)
+            | subxt::Error::Transaction(_) => false,
+            other => is_transient_error_message(&other.to_string()),
+        }
+    }
+}
+
+impl TransientError for Error {
+    fn is_transient(&self) -> bool {
+        match self {
+            Error::Subxt(e) => e.is_transient(),
+            Error::Rpc(msg) => is_transient_error_message(msg),
+            Error::Encoding(_)
+            | Error::Decoding(_)
+            | Error::InvalidAccount(_)
+            | Error::Transaction(_)
+            | Error::Nonce(_)
+            | Error::RateLimited(_)
+            | Error::DispatchError(_)
+            | Error::Signer(_)
+            | Error::BlockNotFound(_) => false,
+        }
+    }
+}
+
+impl TransientError for anyhow::Error {
+    /// Classifies a wrapped chain or subxt error by its type, anything else
+    /// by its message
+    fn is_transient(&self) -> bool {
+        if let Some(e) = self.downcast_ref::<Error>() {
+            return e.is_transient();
+        }
+        if let Some(e) = self.downcast_ref::<subxt::Error>() {
+            return e.is_transient();
+        }
+        is_transient_error_message(&format!("{:#}", self))
+    }
+}
+
+impl TransientError for crate::errors::BittensorError {
+    /// Connection errors are transient, and so are queries the node rejected
+    /// with a 429
+    fn is_transient(&self) -> bool {
+        use crate::errors::BittensorError;
+        match self {
+            BittensorError::ChainConnection(_) => true,
+            BittensorError::ChainQuery(e) => {
+                let msg = e.message.to_lowercase();
+                msg.contains("429") || msg.contains("too many requests")
+            }
+            BittensorError::Subxt(msg) | BittensorError::Unknown(msg) => {
+                is_transient_error_message(msg)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy for reads: up to 3 attempts, backing off from 200ms, within
+    /// 10s in total
+    pub fn conservative() -> Self {
+        Self {
+            max_retries: 2,
+            initial_delay: std::time::Duration::from_millis(200),
+            max_delay: std::time::Duration::from_secs(2),
+            max_elapsed_time: std::time::Duration::from_secs(10),
+            retry_nonce_errors: false,
+            retry_rpc_errors: true,
+        }
+    }
+}
+
+/// Run `operation`, rerunning it while it fails with a transient error
+///
+/// Waits `policy.initial_delay` before the first retry, doubling up to
+/// `policy.max_delay`, and gives up after `policy.max_retries` retries or
+/// once `policy.max_elapsed_time` has passed. Nothing is retried when
+/// `policy.retry_rpc_errors` is off. Only use this for idempotent reads.
+pub async fn with_retry<T, E, F, Fut>(policy: &RetryPolicy, operation: F) -> Result<T, E>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: TransientError,
+{
+    let started = Instant::now();
+    let mut delay = policy.initial_delay;
+    let mut attempt = 0;
+    loop {
+        match operation().await {
+            Err(e)
+                if policy.retry_rpc_errors
+                    && attempt < policy.max_retries
+                    && e.is_transient()
+                    && started.elapsed() + delay <= policy.max_elapsed_time =>
+            {
+                attempt += 1;
+                debug!(
+                    "Retry {} in {:?} after transient error: {}",
+                    attempt, delay, e
+                );
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(policy.max_delay);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// A [`BittensorClient`] whose reads are retried on transient errors
+///
+/// Storage reads, storage iteration, runtime API calls and the block number
+/// go through [`with_retry`]. Extrinsics are submitted once: a retry could
+/// send a transaction that is already in a block.
+#[derive(Debug, Clone)]
+pub struct RetryingClient {
+    client: Arc<BittensorClient>,
+    policy: RetryPolicy,
+}
+
+impl RetryingClient {
+    /// Wrap `client` with the [`RetryPolicy::conservative`] policy
+    pub fn new(client: Arc<BittensorClient>) -> Self {
+        Self::with_policy(client, RetryPolicy::conservative())
+    }
+
+    /// Wrap `client` with a custom policy
+    pub fn with_policy(client: Arc<BittensorClient>, policy: RetryPolicy) -> Self {
+        Self { client, policy }
+    }
+
+    /// Get the wrapped client
+    pub fn client(&self) -> &Arc<BittensorClient> {
+        &self.client
+    }
+
+    /// Get the retry policy
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+}
+
+impl ChainBackend for RetryingClient {
+    fn storage_with_keys(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> impl Future<Output = Result<Option<Value>, Error>> + Send {
+        with_retry(&self.policy, move || {
+            self.client.storage_with_keys(module, entry, keys.clone())
+        })
+    }
+
+    fn storage_iter(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> impl Future<Output = Result<Vec<(Vec<u8>, Value)>, Error>> + Send {
+        with_retry(&self.policy, move || {
+            self.client.storage_iter(module, entry, keys.clone())
+        })
+    }
+
+    fn runtime_api_call(
+        &self,
+        runtime_api: &str,
+        method: &str,
+        params: Option<Vec<u8>>,
+    ) -> impl Future<Output = Result<Vec<u8>, Error>> + Send {
+        with_retry(&self.policy, move || {
+            self.client
+                .runtime_api_call(runtime_api, method, params.clone())
+        })
+    }
+
+    fn submit_extrinsic(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+        signer: &BittensorSigner,
+        wait_for: ExtrinsicWait,
+    ) -> impl Future<Output = Result<String, Error>> + Send {
+        self.client
+            .submit_extrinsic(module, function, args, signer, wait_for)
+    }
+
+    fn estimate_fee(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+        signer: &BittensorSigner,
+    ) -> impl Future<Output = Result<u128, Error>> + Send {
+        with_retry(&self.policy, move || {
+            self.client
+                .estimate_fee(module, function, args.clone(), signer)
+        })
+    }
+
+    fn block_number(&self) -> impl Future<Output = Result<u64, Error>> + Send {
+        with_retry(&self.policy, move || self.client.block_number())
+    }
+
+    fn constants(&self) -> &ChainConstants {
+        self.client.constants()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    fn rpc_client_error(msg: &str) -> subxt::Error {
+        subxt::Error::Rpc(RpcError::ClientError(
+            subxt::ext::subxt_rpcs::Error::Client(Box::new(io::Error::other(msg.to_string()))),
+        ))
+    }
+
+    #[test]
+    fn test_subxt_errors_classified() {
+        let transient = [
+            subxt::Error::Io(io::Error::from(io::ErrorKind::ConnectionReset)),
+            subxt::Error::Rpc(RpcError::SubscriptionDropped),
+            rpc_client_error("Networking or low-level protocol error: Connection reset by peer"),
+            rpc_client_error("Request timeout"),
+            rpc_client_error("HTTP status client error (429 Too Many Requests)"),
+            rpc_client_error("The background task closed connection closed; restart required"),
+            subxt::Error::Other("websocket connection closed".into()),
+        ];
+        for e in &transient {
+            assert!(e.is_transient(), "{}", e);
+        }
+
+        let permanent = [
+            subxt::Error::Codec(parity_scale_codec::Error::from("Not enough data")),
+            rpc_client_error("Method not found"),
+            rpc_client_error("message too large: len >= 1048576"),
+            subxt::Error::Other("Storage entry not found".into()),
+        ];
+        for e in &permanent {
+            assert!(!e.is_transient(), "{}", e);
+        }
+    }
+
+    #[test]
+    fn test_chain_errors_classified() {
+        assert!(Error::from(rpc_client_error("connection refused")).is_transient());
+        assert!(Error::Rpc("429 Too Many Requests".into()).is_transient());
+        assert!(Error::Rpc("operation timed out".into()).is_transient());
+        assert!(!Error::Rpc("Invalid params".into()).is_transient());
+        // Error messages mentioning timeouts do not make these retryable
+        assert!(!Error::Decoding("timeout field missing".into()).is_transient());
+        assert!(
+            !Error::DispatchError("SubtensorModule.SettingWeightsTooFast".into()).is_transient()
+        );
+        assert!(!Error::Transaction("connection reset".into()).is_transient());
+        assert!(!Error::RateLimited("Client-side rate limit exceeded".into()).is_transient());
+    }
+
+    #[test]
+    fn test_anyhow_errors_classified() {
+        let wrapped = anyhow::Error::from(Error::Decoding("timeout".into())).context("get_tempo");
+        assert!(!wrapped.is_transient());
+        let wrapped = anyhow::Error::from(Error::Rpc("connection reset".into()));
+        assert!(wrapped.is_transient());
+        assert!(anyhow::anyhow!("Failed to query Tempo: request timed out").is_transient());
+        assert!(!anyhow::anyhow!("Failed to decode Tempo").is_transient());
+    }
+
+    #[test]
+    fn test_bittensor_errors_classified() {
+        use crate::errors::BittensorError;
+
+        assert!(BittensorError::from(Error::Rpc("connection reset".into())).is_transient());
+        assert!(BittensorError::from(Error::Rpc("429 Too Many Requests".into())).is_transient());
+        assert!(!BittensorError::from(Error::Decoding("timeout".into())).is_transient());
+        assert!(
+            !BittensorError::from(Error::Transaction("connection reset".into())).is_transient()
+        );
+        assert!(BittensorError::unknown("request timed out").is_transient());
+    }
+
+    fn fast_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(4),
+            ..RetryPolicy::conservative()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_recovers_from_transient_errors() {
+        let calls = AtomicU32::new(0);
+        let result = with_retry(&fast_policy(2), || async {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err(Error::Rpc("connection reset".into())),
+                n => Ok(n),
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_gives_up() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), _> = with_retry(&fast_policy(2), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::Rpc("connection reset".into()))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // Permanent errors and a policy without RPC retries fail at once
+        calls.store(0, Ordering::SeqCst);
+        let result: Result<(), _> = with_retry(&fast_policy(2), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::Decoding("bad u16".into()))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        calls.store(0, Ordering::SeqCst);
+        let result: Result<(), _> = with_retry(&RetryPolicy::no_retry(), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::Rpc("connection reset".into()))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,8 +1,12 @@
+#[cfg(feature = "ledger")]
+use crate::chain::ledger::LedgerSigner;
+use crate::chain::remote_signer::RemoteSigner;
+use crate::chain::Error;
+use crate::errors::ExtrinsicError;
+use crate::wallet::SchemePair;
+use futures::future::{self, BoxFuture};
 use sp_core::{sr25519, Pair};
-use sp_runtime::{
-    traits::{IdentifyAccount, Verify},
-    MultiSignature as SpMultiSignature,
-};
+use sp_runtime::{traits::IdentifyAccount, MultiSigner};
 use std::collections::HashMap;
 use std::sync::Arc;
 use subxt::{
@@ -13,28 +17,33 @@ use subxt::{
 use tokio::sync::Mutex;
 use tracing::{debug, warn};
 
-/// A concrete PairSigner implementation for sr25519::Pair compatible with subxt 0.44
+/// A concrete PairSigner implementation for sr25519, ed25519 and ecdsa pairs compatible
+/// with subxt 0.44
 /// This implements the Signer trait required by subxt
 #[derive(Clone)]
 pub struct PairSigner {
     account_id: <PolkadotConfig as Config>::AccountId,
-    signer: sr25519::Pair,
+    signer: SchemePair,
 }
 
 impl PairSigner {
-    /// Creates a new PairSigner from an sr25519::Pair
-    pub fn new(signer: sr25519::Pair) -> Self {
-        let account_id =
-            <SpMultiSignature as Verify>::Signer::from(Pair::public(&signer)).into_account();
+    /// Creates a new PairSigner from an sr25519, ed25519 or ecdsa pair
+    pub fn new(signer: impl Into<SchemePair>) -> Self {
+        let signer = signer.into();
+        let multi_signer = match &signer {
+            SchemePair::Sr25519(pair) => MultiSigner::from(pair.public()),
+            SchemePair::Ed25519(pair) => MultiSigner::from(pair.public()),
+            SchemePair::Ecdsa(pair) => MultiSigner::from(pair.public()),
+        };
         Self {
             // Convert sp_core::AccountId32 to subxt::config::substrate::AccountId32
-            account_id: AccountId32(account_id.into()),
+            account_id: AccountId32(multi_signer.into_account().into()),
             signer,
         }
     }
 
-    /// Returns the sr25519::Pair used for signing
-    pub fn signer(&self) -> &sr25519::Pair {
+    /// Returns the pair used for signing
+    pub fn signer(&self) -> &SchemePair {
         &self.signer
     }
 
@@ -50,17 +59,145 @@ impl Signer<PolkadotConfig> for PairSigner {
     }
 
     fn sign(&self, signer_payload: &[u8]) -> <PolkadotConfig as Config>::Signature {
-        let signature = Pair::sign(&self.signer, signer_payload);
-        MultiSignature::Sr25519(signature.0)
+        match &self.signer {
+            SchemePair::Sr25519(pair) => MultiSignature::Sr25519(pair.sign(signer_payload).0),
+            SchemePair::Ed25519(pair) => MultiSignature::Ed25519(pair.sign(signer_payload).0),
+            SchemePair::Ecdsa(pair) => MultiSignature::Ecdsa(pair.sign(signer_payload).0),
+        }
+    }
+}
+
+/// Signs extrinsic payloads for one account
+///
+/// Unlike subxt's `Signer`, signing is asynchronous and can fail, so keys
+/// can be held by an HSM or a signing service (see [`RemoteSigner`]).
+/// [`BittensorClient::submit_extrinsic`](crate::chain::BittensorClient::submit_extrinsic)
+/// accepts any implementation.
+pub trait TransactionSigner: Send + Sync {
+    /// The account extrinsics are signed for
+    fn account_id(&self) -> &AccountId32;
+
+    /// Sign a signer payload
+    ///
+    /// The payload is already hashed if it was longer than 256 bytes.
+    fn sign<'a>(
+        &'a self,
+        signer_payload: &'a [u8],
+    ) -> BoxFuture<'a, Result<MultiSignature, ExtrinsicError>>;
+}
+
+impl TransactionSigner for PairSigner {
+    fn account_id(&self) -> &AccountId32 {
+        &self.account_id
+    }
+
+    fn sign<'a>(
+        &'a self,
+        signer_payload: &'a [u8],
+    ) -> BoxFuture<'a, Result<MultiSignature, ExtrinsicError>> {
+        Box::pin(future::ready(Ok(Signer::<PolkadotConfig>::sign(
+            self,
+            signer_payload,
+        ))))
     }
 }
 
-/// Type alias for BittensorSigner
-pub type BittensorSigner = PairSigner;
+/// Signer used to submit extrinsics
+///
+/// An in-memory keypair, a [`TransactionSigner`] such as a
+/// [`RemoteSigner`] or, with the `ledger` feature, a Ledger hardware wallet.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+pub enum BittensorSigner {
+    /// In-memory sr25519, ed25519 or ecdsa pair
+    Pair(PairSigner),
+    /// Ledger device running the Polkadot app
+    #[cfg(feature = "ledger")]
+    Ledger(Arc<LedgerSigner>),
+    /// Signer outside this process, such as an HSM or signing service
+    Remote(Arc<dyn TransactionSigner>),
+}
+
+impl BittensorSigner {
+    /// Return the account ID
+    pub fn account_id(&self) -> &AccountId32 {
+        match self {
+            BittensorSigner::Pair(signer) => signer.account_id(),
+            #[cfg(feature = "ledger")]
+            BittensorSigner::Ledger(signer) => signer.account_id(),
+            BittensorSigner::Remote(signer) => signer.account_id(),
+        }
+    }
+
+    /// Sign an extrinsic payload, reporting signing failures
+    ///
+    /// Signing with a pair cannot fail; a Ledger device can be locked,
+    /// have its app closed or have the user reject the transaction. Remote
+    /// signers only sign asynchronously, through [`TransactionSigner`].
+    ///
+    /// `BittensorSigner` does not implement subxt's `Signer`, which cannot
+    /// report errors. Pass [`PairSigner`] to subxt's signing APIs directly.
+    pub fn try_sign(&self, signer_payload: &[u8]) -> Result<MultiSignature, Error> {
+        match self {
+            BittensorSigner::Pair(signer) => {
+                Ok(Signer::<PolkadotConfig>::sign(signer, signer_payload))
+            }
+            #[cfg(feature = "ledger")]
+            BittensorSigner::Ledger(signer) => signer
+                .sign_payload(signer_payload)
+                .map(MultiSignature::Ed25519)
+                .map_err(|e| Error::Signer(e.to_string())),
+            BittensorSigner::Remote(_) => Err(Error::Signer(
+                "Remote signers cannot sign synchronously".to_string(),
+            )),
+        }
+    }
+}
+
+impl TransactionSigner for BittensorSigner {
+    fn account_id(&self) -> &AccountId32 {
+        BittensorSigner::account_id(self)
+    }
+
+    fn sign<'a>(
+        &'a self,
+        signer_payload: &'a [u8],
+    ) -> BoxFuture<'a, Result<MultiSignature, ExtrinsicError>> {
+        match self {
+            BittensorSigner::Remote(signer) => signer.sign(signer_payload),
+            _ => Box::pin(future::ready(
+                self.try_sign(signer_payload)
+                    .map_err(|e| ExtrinsicError::new(e.to_string())),
+            )),
+        }
+    }
+}
+
+impl From<PairSigner> for BittensorSigner {
+    fn from(signer: PairSigner) -> Self {
+        BittensorSigner::Pair(signer)
+    }
+}
+
+#[cfg(feature = "ledger")]
+impl From<LedgerSigner> for BittensorSigner {
+    fn from(signer: LedgerSigner) -> Self {
+        BittensorSigner::Ledger(Arc::new(signer))
+    }
+}
+
+impl From<RemoteSigner> for BittensorSigner {
+    fn from(signer: RemoteSigner) -> Self {
+        BittensorSigner::Remote(Arc::new(signer))
+    }
+}
 
 /// Create a signer from a keypair
-pub fn create_signer(pair: sr25519::Pair) -> BittensorSigner {
-    PairSigner::new(pair)
+///
+/// Accepts an sr25519, ed25519 or ecdsa pair (or a wallet [`SchemePair`]);
+/// extrinsics are signed with the matching `MultiSignature` variant.
+pub fn create_signer(pair: impl Into<SchemePair>) -> BittensorSigner {
+    BittensorSigner::Pair(PairSigner::new(pair))
 }
 
 /// Create a signer from a seed phrase or key
@@ -309,12 +446,15 @@ pub struct ManagedSigner {
 
 impl ManagedSigner {
     /// Create a new managed signer from a pair
-    pub fn new(pair: sr25519::Pair) -> Self {
+    pub fn new(pair: impl Into<SchemePair>) -> Self {
         Self::from_pair_and_manager(pair, Arc::new(NonceManager::new()))
     }
 
     /// Create from a pair and existing nonce manager
-    pub fn from_pair_and_manager(pair: sr25519::Pair, nonce_manager: Arc<NonceManager>) -> Self {
+    pub fn from_pair_and_manager(
+        pair: impl Into<SchemePair>,
+        nonce_manager: Arc<NonceManager>,
+    ) -> Self {
         let signer = PairSigner::new(pair);
         Self {
             signer,
@@ -382,7 +522,7 @@ impl Signer<PolkadotConfig> for ManagedSigner {
     }
 
     fn sign(&self, signer_payload: &[u8]) -> <PolkadotConfig as Config>::Signature {
-        self.signer.sign(signer_payload)
+        Signer::<PolkadotConfig>::sign(&self.signer, signer_payload)
     }
 }
 
@@ -424,7 +564,7 @@ impl SharedNonceManager {
     }
 
     /// Create a managed signer from an existing pair
-    pub fn create_managed_signer_from_pair(&self, pair: sr25519::Pair) -> ManagedSigner {
+    pub fn create_managed_signer_from_pair(&self, pair: impl Into<SchemePair>) -> ManagedSigner {
         ManagedSigner::from_pair_and_manager(pair, self.inner.clone())
     }
 }
@@ -536,6 +676,28 @@ mod tests {
         assert_eq!(manager.allocate_nonce(&account).await, Some(10));
     }
 
+    #[test]
+    fn test_signer_schemes() {
+        use crate::wallet::{KeyScheme, Keypair};
+
+        let payload = b"extrinsic payload";
+        for scheme in [KeyScheme::Sr25519, KeyScheme::Ed25519, KeyScheme::Ecdsa] {
+            let keypair = Keypair::from_uri_with_scheme("//Alice", scheme).unwrap();
+            let signer = create_signer(keypair.pair().clone());
+
+            // ECDSA accounts are the blake2 hash of the compressed public key
+            assert_eq!(signer.account_id().0, *keypair.public_key());
+
+            let signature = match (scheme, signer.try_sign(payload).unwrap()) {
+                (KeyScheme::Sr25519, MultiSignature::Sr25519(sig)) => sig.to_vec(),
+                (KeyScheme::Ed25519, MultiSignature::Ed25519(sig)) => sig.to_vec(),
+                (KeyScheme::Ecdsa, MultiSignature::Ecdsa(sig)) => sig.to_vec(),
+                _ => panic!("Wrong signature variant for {}", scheme),
+            };
+            assert!(keypair.verify(payload, &signature));
+        }
+    }
+
     #[tokio::test]
     async fn test_account_not_initialized() {
         let manager = NonceManager::new();
@@ -0,0 +1,349 @@
+//! Static interface generated from the vendored Finney metadata
+//!
+//! Every call normally goes through `subxt::dynamic`, which looks up call and
+//! storage names in the node's metadata at runtime. With the
+//! `static-metadata` feature the [`finney`] module is generated at compile
+//! time from `metadata/finney.scale`, and [`BittensorClient`] uses its typed
+//! calls for the hottest operations:
+//!
+//! - `System.Account`, `SubtensorModule.Tempo` and `SubtensorModule.Uids`
+//!   storage reads
+//! - `SubtensorModule.set_weights` and `SubtensorModule.add_stake` extrinsics
+//!
+//! Each fast path is checked on its own: it is only taken while the call or
+//! storage entry has the same shape (its call or storage hash) in the
+//! connected runtime as in the vendored metadata. A runtime upgrade that
+//! changes one of them sends only that one through the dynamic path until
+//! the metadata file is regenerated; changes elsewhere in the runtime do not
+//! matter. Callers see the same `Value`s either way.
+//!
+//! To regenerate the metadata after a Finney runtime upgrade:
+//!
+//! ```sh
+//! cargo install subxt-cli --locked
+//! subxt metadata --url wss://entrypoint-finney.opentensor.ai:443 -f bytes > metadata/finney.scale
+//! cargo test --features static-metadata --test static_metadata_tests
+//! ```
+//!
+//! [`BittensorClient`]: super::BittensorClient
+
+use super::PolkadotConfig;
+use crate::utils::decoders::{decode_account_id32, decode_u16, decode_u64, decode_vec_u16};
+use std::sync::{Mutex, OnceLock};
+use subxt::dynamic::Value;
+use subxt::ext::subxt_core;
+use subxt::tx::{DefaultPayload, DynamicPayload, Payload};
+use subxt_core::tx::payload::ValidationDetails;
+
+#[subxt::subxt(runtime_metadata_path = "metadata/finney.scale")]
+pub mod finney {}
+
+const SUBTENSOR_MODULE: &str = "SubtensorModule";
+
+/// The metadata the [`finney`] module is generated from
+const FINNEY_METADATA: &[u8] = include_bytes!("../../metadata/finney.scale");
+
+/// A call or storage entry with a static fast path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StaticItem {
+    /// `SubtensorModule.set_weights` extrinsic
+    SetWeights,
+    /// `SubtensorModule.add_stake` extrinsic
+    AddStake,
+    /// `System.Account` storage
+    Account,
+    /// `SubtensorModule.Tempo` storage
+    Tempo,
+    /// `SubtensorModule.Uids` storage
+    Uids,
+}
+
+impl StaticItem {
+    /// Every item with a fast path
+    pub const ALL: [StaticItem; 5] = [
+        StaticItem::SetWeights,
+        StaticItem::AddStake,
+        StaticItem::Account,
+        StaticItem::Tempo,
+        StaticItem::Uids,
+    ];
+
+    /// The item for the extrinsic `module::function`, if it has a fast path
+    pub fn call(module: &str, function: &str) -> Option<Self> {
+        match (module, function) {
+            (SUBTENSOR_MODULE, "set_weights") => Some(Self::SetWeights),
+            (SUBTENSOR_MODULE, "add_stake") => Some(Self::AddStake),
+            _ => None,
+        }
+    }
+
+    /// Pallet and call or storage entry name
+    pub fn name(self) -> (&'static str, &'static str) {
+        match self {
+            Self::SetWeights => (SUBTENSOR_MODULE, "set_weights"),
+            Self::AddStake => (SUBTENSOR_MODULE, "add_stake"),
+            Self::Account => ("System", "Account"),
+            Self::Tempo => (SUBTENSOR_MODULE, "Tempo"),
+            Self::Uids => (SUBTENSOR_MODULE, "Uids"),
+        }
+    }
+
+    fn is_call(self) -> bool {
+        matches!(self, Self::SetWeights | Self::AddStake)
+    }
+
+    /// Hash of the item's shape in `metadata`, None if the runtime lacks it
+    fn hash(self, metadata: &subxt::Metadata) -> Option<[u8; 32]> {
+        let (pallet, name) = self.name();
+        let pallet = metadata.pallet_by_name(pallet)?;
+        if self.is_call() {
+            pallet.call_hash(name)
+        } else {
+            pallet.storage_hash(name)
+        }
+    }
+}
+
+/// The decoded vendored metadata
+fn vendored_metadata() -> &'static subxt::Metadata {
+    static METADATA: OnceLock<subxt::Metadata> = OnceLock::new();
+    METADATA.get_or_init(|| {
+        subxt_core::metadata::decode_from(FINNEY_METADATA)
+            .expect("metadata/finney.scale is checked by the subxt macro")
+    })
+}
+
+/// Whether the generated code for `item` can be used with `metadata`
+///
+/// Compares the call or storage hash of `item` in `metadata` with the one
+/// in the vendored metadata, so the rest of the runtime may differ.
+pub fn is_compatible(metadata: &subxt::Metadata, item: StaticItem) -> bool {
+    let hash = item.hash(metadata);
+    hash.is_some() && hash == item.hash(vendored_metadata())
+}
+
+/// Which fast paths the connected runtime supports
+///
+/// Hashing the metadata is costly, so the answer is kept until the runtime
+/// version changes.
+#[derive(Debug, Default)]
+pub struct StaticInterface {
+    /// (spec version, transaction version, compatible items)
+    checked: Mutex<Option<(u32, u32, Vec<StaticItem>)>>,
+}
+
+impl StaticInterface {
+    /// Whether the fast path for `item` can be used with the runtime `api`
+    /// is on
+    pub fn is_active(&self, api: &subxt::OnlineClient<PolkadotConfig>, item: StaticItem) -> bool {
+        let version = api.runtime_version();
+        let mut checked = self.checked.lock().unwrap_or_else(|e| e.into_inner());
+        match &*checked {
+            Some((spec_version, transaction_version, compatible))
+                if *spec_version == version.spec_version
+                    && *transaction_version == version.transaction_version =>
+            {
+                compatible.contains(&item)
+            }
+            _ => {
+                let metadata = api.metadata();
+                let (compatible, changed): (Vec<_>, Vec<_>) = StaticItem::ALL
+                    .into_iter()
+                    .partition(|&item| is_compatible(&metadata, item));
+                if !changed.is_empty() {
+                    crate::bt_warn!(
+                        "Runtime spec version {} changed {:?} since the static metadata; \
+                         using dynamic calls for them",
+                        version.spec_version,
+                        changed.iter().map(|item| item.name()).collect::<Vec<_>>()
+                    );
+                }
+                let active = compatible.contains(&item);
+                *checked = Some((
+                    version.spec_version,
+                    version.transaction_version,
+                    compatible,
+                ));
+                active
+            }
+        }
+    }
+}
+
+type SetWeights = finney::subtensor_module::calls::types::SetWeights;
+type AddStake = finney::subtensor_module::calls::types::AddStake;
+
+/// Call data of an extrinsic, typed when a static call is available
+pub enum CallPayload {
+    SetWeights(DefaultPayload<SetWeights>),
+    AddStake(DefaultPayload<AddStake>),
+    Dynamic(DynamicPayload),
+}
+
+impl CallPayload {
+    /// The payload for `module::function(args)`
+    ///
+    /// With `use_static`, known calls whose arguments decode into the
+    /// generated types are built statically; everything else is dynamic.
+    pub fn new(use_static: bool, module: &str, function: &str, args: Vec<Value>) -> Self {
+        let typed = match (use_static, module, function) {
+            (true, SUBTENSOR_MODULE, "set_weights") => set_weights(&args),
+            (true, SUBTENSOR_MODULE, "add_stake") => add_stake(&args),
+            _ => None,
+        };
+        typed.unwrap_or_else(|| Self::Dynamic(subxt::dynamic::tx(module, function, args)))
+    }
+
+    /// Whether the call is built from the generated types
+    pub fn is_static(&self) -> bool {
+        !matches!(self, Self::Dynamic(_))
+    }
+}
+
+impl Payload for CallPayload {
+    fn encode_call_data_to(
+        &self,
+        metadata: &subxt::Metadata,
+        out: &mut Vec<u8>,
+    ) -> Result<(), subxt_core::Error> {
+        match self {
+            Self::SetWeights(call) => call.encode_call_data_to(metadata, out),
+            Self::AddStake(call) => call.encode_call_data_to(metadata, out),
+            Self::Dynamic(call) => call.encode_call_data_to(metadata, out),
+        }
+    }
+
+    fn validation_details(&self) -> Option<ValidationDetails<'_>> {
+        match self {
+            Self::SetWeights(call) => call.validation_details(),
+            Self::AddStake(call) => call.validation_details(),
+            Self::Dynamic(call) => call.validation_details(),
+        }
+    }
+}
+
+fn set_weights(args: &[Value]) -> Option<CallPayload> {
+    let [netuid, dests, weights, version_key] = args else {
+        return None;
+    };
+    let call = finney::tx().subtensor_module().set_weights(
+        decode_u16(netuid).ok()?,
+        decode_vec_u16(dests).ok()?,
+        decode_vec_u16(weights).ok()?,
+        decode_u64(version_key).ok()?,
+    );
+    Some(CallPayload::SetWeights(call))
+}
+
+fn add_stake(args: &[Value]) -> Option<CallPayload> {
+    let [hotkey, netuid, amount] = args else {
+        return None;
+    };
+    let hotkey = decode_account_id32(hotkey).ok()?;
+    let call = finney::tx().subtensor_module().add_stake(
+        subxt::utils::AccountId32(hotkey.into()),
+        decode_u16(netuid).ok()?,
+        decode_u64(amount).ok()?,
+    );
+    Some(CallPayload::AddStake(call))
+}
+
+/// Storage read with a static fast path
+pub(crate) enum StorageQuery {
+    Account(subxt::utils::AccountId32),
+    Tempo(u16),
+    Uids(u16, subxt::utils::AccountId32),
+}
+
+impl StorageQuery {
+    /// The fast path for `module::entry(keys)`, if there is one
+    pub(crate) fn new(module: &str, entry: &str, keys: &[Value]) -> Option<Self> {
+        let account = |key: &Value| {
+            decode_account_id32(key)
+                .ok()
+                .map(|account| subxt::utils::AccountId32(account.into()))
+        };
+        match (module, entry, keys) {
+            ("System", "Account", [key]) => Some(Self::Account(account(key)?)),
+            (SUBTENSOR_MODULE, "Tempo", [netuid]) => Some(Self::Tempo(decode_u16(netuid).ok()?)),
+            (SUBTENSOR_MODULE, "Uids", [netuid, hotkey]) => {
+                Some(Self::Uids(decode_u16(netuid).ok()?, account(hotkey)?))
+            }
+            _ => None,
+        }
+    }
+
+    /// The item whose shape the fast path depends on
+    pub(crate) fn item(&self) -> StaticItem {
+        match self {
+            Self::Account(_) => StaticItem::Account,
+            Self::Tempo(_) => StaticItem::Tempo,
+            Self::Uids(..) => StaticItem::Uids,
+        }
+    }
+
+    /// Fetch the entry, as the `Value` the dynamic path would decode, with
+    /// its encoded size
+    pub(crate) async fn fetch(
+        self,
+        storage: &subxt::storage::Storage<PolkadotConfig, subxt::OnlineClient<PolkadotConfig>>,
+    ) -> Result<(Option<Value>, usize), subxt::Error> {
+        let storage_api = finney::storage();
+        let value = match self {
+            Self::Account(account) => {
+                let info = storage
+                    .fetch(&storage_api.system().account(account))
+                    .await?;
+                info.map(|info| {
+                    let data = &info.data;
+                    // Every field is a fixed-width integer, so SCALE encodes
+                    // each in its in-memory size
+                    let size = [
+                        size_of_val(&info.nonce),
+                        size_of_val(&info.consumers),
+                        size_of_val(&info.providers),
+                        size_of_val(&info.sufficients),
+                        size_of_val(&data.free),
+                        size_of_val(&data.reserved),
+                        size_of_val(&data.frozen),
+                        size_of_val(&data.flags.0),
+                    ]
+                    .iter()
+                    .sum();
+                    let value = Value::named_composite([
+                        ("nonce", Value::u128(u128::from(info.nonce))),
+                        ("consumers", Value::u128(u128::from(info.consumers))),
+                        ("providers", Value::u128(u128::from(info.providers))),
+                        ("sufficients", Value::u128(u128::from(info.sufficients))),
+                        (
+                            "data",
+                            Value::named_composite([
+                                ("free", Value::u128(u128::from(data.free))),
+                                ("reserved", Value::u128(u128::from(data.reserved))),
+                                ("frozen", Value::u128(u128::from(data.frozen))),
+                                (
+                                    "flags",
+                                    Value::unnamed_composite([Value::u128(data.flags.0)]),
+                                ),
+                            ]),
+                        ),
+                    ]);
+                    (value, size)
+                })
+            }
+            Self::Tempo(netuid) => {
+                let tempo = storage
+                    .fetch(&storage_api.subtensor_module().tempo(netuid))
+                    .await?;
+                tempo.map(|tempo| (Value::u128(tempo.into()), 2))
+            }
+            Self::Uids(netuid, hotkey) => {
+                let uid = storage
+                    .fetch(&storage_api.subtensor_module().uids(netuid, hotkey))
+                    .await?;
+                uid.map(|uid| (Value::u128(uid.into()), 2))
+            }
+        };
+        Ok(value.map_or((None, 0), |(value, bytes)| (Some(value), bytes)))
+    }
+}
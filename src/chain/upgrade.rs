@@ -0,0 +1,239 @@
+//! Runtime upgrades during a session
+//!
+//! After a runtime upgrade the client's metadata and transaction version are
+//! stale: storage stops decoding and extrinsics are rejected with a bad
+//! signature. [`BittensorClient`] follows the node's runtime version
+//! subscription and swaps in the new metadata as soon as an upgrade is
+//! enacted. Submissions rejected with a bad signature also check for an
+//! upgrade before being retried.
+//!
+//! [`RuntimeUpgradeMonitor`] tracks the version the client is using and runs
+//! the callbacks registered with [`BittensorClient::on_runtime_upgrade`], so
+//! applications can re-read values that depend on the runtime:
+//!
+//! ```ignore
+//! client.on_runtime_upgrade(|upgrade| {
+//!     println!("Runtime upgraded to {}", upgrade.new_spec_version);
+//! });
+//! ```
+//!
+//! [`BittensorClient`]: super::BittensorClient
+//! [`BittensorClient::on_runtime_upgrade`]: super::BittensorClient::on_runtime_upgrade
+
+use super::metadata_cache::{MetadataCache, MetadataKey};
+use super::Error;
+use std::sync::{Arc, Mutex};
+use subxt::{OnlineClient, PolkadotConfig};
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+/// A change of runtime version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuntimeUpgrade {
+    pub old_spec_version: u32,
+    pub new_spec_version: u32,
+    pub old_transaction_version: u32,
+    pub new_transaction_version: u32,
+}
+
+/// Callback run after a runtime upgrade was applied
+pub type RuntimeUpgradeCallback = Arc<dyn Fn(&RuntimeUpgrade) + Send + Sync>;
+
+/// The runtime version in use and the callbacks to run when it changes
+pub struct RuntimeUpgradeMonitor {
+    /// (spec version, transaction version)
+    version: Mutex<(u32, u32)>,
+    callbacks: Mutex<Vec<RuntimeUpgradeCallback>>,
+}
+
+impl std::fmt::Debug for RuntimeUpgradeMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (spec_version, transaction_version) = self.version();
+        f.debug_struct("RuntimeUpgradeMonitor")
+            .field("spec_version", &spec_version)
+            .field("transaction_version", &transaction_version)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for RuntimeUpgradeMonitor {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+impl RuntimeUpgradeMonitor {
+    pub fn new(spec_version: u32, transaction_version: u32) -> Self {
+        Self {
+            version: Mutex::new((spec_version, transaction_version)),
+            callbacks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Spec and transaction version in use
+    pub fn version(&self) -> (u32, u32) {
+        *self.version.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Run `callback` after each runtime upgrade
+    pub fn on_upgrade(&self, callback: impl Fn(&RuntimeUpgrade) + Send + Sync + 'static) {
+        self.callbacks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Arc::new(callback));
+    }
+
+    /// Record the version now in use, returning the upgrade if it changed
+    ///
+    /// Logs the upgrade and runs the callbacks, outside of any lock.
+    pub fn observe(&self, spec_version: u32, transaction_version: u32) -> Option<RuntimeUpgrade> {
+        let upgrade = {
+            let mut version = self.version.lock().unwrap_or_else(|e| e.into_inner());
+            let (old_spec_version, old_transaction_version) = *version;
+            if *version == (spec_version, transaction_version) {
+                return None;
+            }
+            *version = (spec_version, transaction_version);
+            RuntimeUpgrade {
+                old_spec_version,
+                new_spec_version: spec_version,
+                old_transaction_version,
+                new_transaction_version: transaction_version,
+            }
+        };
+
+        crate::bt_warn!(
+            "Runtime upgraded from spec version {} to {} (transaction version {} to {})",
+            upgrade.old_spec_version,
+            upgrade.new_spec_version,
+            upgrade.old_transaction_version,
+            upgrade.new_transaction_version
+        );
+        let callbacks = self
+            .callbacks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        for callback in callbacks {
+            callback(&upgrade);
+        }
+        Some(upgrade)
+    }
+}
+
+/// Whether a rejected extrinsic may have been built for an old runtime
+///
+/// Nodes report a signature over the wrong transaction version or genesis
+/// as a bad signature (`1010`).
+pub(crate) fn is_stale_runtime_error(err: &Error) -> bool {
+    let msg = err.to_string().to_lowercase();
+    msg.contains("bad signature") || msg.contains("bad proof")
+}
+
+/// Record the runtime version `api` switched to and drop the cached
+/// metadata of the previous one
+pub(crate) fn applied(
+    api: &OnlineClient<PolkadotConfig>,
+    monitor: &RuntimeUpgradeMonitor,
+    cache: Option<&MetadataCache>,
+) -> Option<RuntimeUpgrade> {
+    let version = api.runtime_version();
+    let upgrade = monitor.observe(version.spec_version, version.transaction_version)?;
+    if let Some(cache) = cache {
+        cache.remove(&MetadataKey {
+            genesis_hash: api.genesis_hash().0,
+            spec_version: upgrade.old_spec_version,
+            transaction_version: upgrade.old_transaction_version,
+        });
+    }
+    Some(upgrade)
+}
+
+/// Background task applying the runtime updates the node announces
+///
+/// The task is aborted when dropped.
+#[derive(Debug)]
+pub(crate) struct UpgradeWatcher {
+    task: JoinHandle<()>,
+}
+
+impl UpgradeWatcher {
+    pub(crate) fn spawn(
+        api: OnlineClient<PolkadotConfig>,
+        monitor: Arc<RuntimeUpgradeMonitor>,
+        cache: Option<MetadataCache>,
+    ) -> Self {
+        let task = tokio::spawn(async move {
+            let updater = api.updater();
+            let mut updates = match updater.runtime_updates().await {
+                Ok(updates) => updates,
+                Err(e) => {
+                    debug!("Runtime version subscription unavailable: {}", e);
+                    return;
+                }
+            };
+            while let Some(update) = updates.next().await {
+                let update = match update {
+                    Ok(update) => update,
+                    Err(e) => {
+                        debug!("Runtime version subscription error: {}", e);
+                        continue;
+                    }
+                };
+                // Versions already applied by a refresh are skipped
+                if updater.apply_update(update).is_ok() {
+                    applied(&api, &monitor, cache.as_ref());
+                }
+            }
+            debug!("Runtime version subscription ended");
+        });
+        Self { task }
+    }
+}
+
+impl Drop for UpgradeWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_monitor_reports_changes_once() {
+        let monitor = RuntimeUpgradeMonitor::new(300, 1);
+        let seen = Arc::new(AtomicU32::new(0));
+        let recorded = seen.clone();
+        monitor.on_upgrade(move |upgrade| {
+            recorded.store(upgrade.new_spec_version, Ordering::SeqCst);
+        });
+
+        assert_eq!(monitor.observe(300, 1), None);
+        let upgrade = monitor.observe(301, 2).unwrap();
+        assert_eq!(
+            upgrade,
+            RuntimeUpgrade {
+                old_spec_version: 300,
+                new_spec_version: 301,
+                old_transaction_version: 1,
+                new_transaction_version: 2,
+            }
+        );
+        assert_eq!(seen.load(Ordering::SeqCst), 301);
+        assert_eq!(monitor.version(), (301, 2));
+        assert_eq!(monitor.observe(301, 2), None);
+    }
+
+    #[test]
+    fn test_stale_runtime_errors() {
+        assert!(is_stale_runtime_error(&Error::Rpc(
+            "Invalid Transaction: Transaction has a bad signature".into()
+        )));
+        assert!(!is_stale_runtime_error(&Error::Rpc(
+            "Transaction is outdated".into()
+        )));
+    }
+}
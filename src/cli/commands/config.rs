@@ -0,0 +1,64 @@
+//! Config commands for viewing and editing the config file.
+
+use crate::cli::utils::{print_info, print_success};
+use crate::cli::Cli;
+use crate::config::{default_config_path, Config};
+use clap::{Args, Subcommand};
+
+/// Config command container
+#[derive(Args, Clone)]
+pub struct ConfigCommand {
+    #[command(subcommand)]
+    pub command: ConfigCommands,
+}
+
+/// Available config operations
+#[derive(Subcommand, Clone)]
+pub enum ConfigCommands {
+    /// Show the effective config after applying flags and environment
+    Show,
+    /// Set a value in the config file (e.g. `network test`, `axon.port 8091`)
+    Set {
+        /// Key to set: network, endpoint, or a dotted path such as axon.port
+        key: String,
+        /// New value
+        value: String,
+    },
+}
+
+/// Execute config commands
+pub fn execute(cmd: ConfigCommand, cli: &Cli) -> anyhow::Result<()> {
+    match cmd.command {
+        ConfigCommands::Show => show_config(cli),
+        ConfigCommands::Set { key, value } => set_config(&key, &value, cli),
+    }
+}
+
+/// Print the resolved config and where it was read from
+fn show_config(cli: &Cli) -> anyhow::Result<()> {
+    let path = cli.config.clone().unwrap_or_else(default_config_path);
+    if path.exists() {
+        print_info(&format!("Config file: {}", path.display()));
+    } else {
+        print_info(&format!("Config file: {} (not found)", path.display()));
+    }
+
+    let config = Config::resolve(&cli.config_overrides())?;
+    println!("{}", config.to_toml()?);
+    Ok(())
+}
+
+/// Update one key in the config file, leaving other settings untouched
+fn set_config(key: &str, value: &str, cli: &Cli) -> anyhow::Result<()> {
+    let path = cli.config.clone().unwrap_or_else(default_config_path);
+    let mut config = if path.exists() {
+        Config::load(Some(&path))?
+    } else {
+        Config::default()
+    };
+
+    config.set(key, value)?;
+    config.save(Some(&path))?;
+    print_success(&format!("Set {} = {} in {}", key, value, path.display()));
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! Liquidity commands for concentrated-liquidity positions in subnet pools.
+
+use crate::cli::utils::{
+    connect_client, create_table_with_headers, format_address, format_tao, print_error, print_info,
+    resolve_coldkey_password, resolve_endpoint, spinner,
+};
+use crate::cli::Cli;
+use crate::wallet::Wallet;
+use clap::{Args, Subcommand};
+
+/// Liquidity command container
+#[derive(Args, Clone)]
+pub struct LiquidityCommand {
+    #[command(subcommand)]
+    pub command: LiquidityCommands,
+}
+
+/// Available liquidity operations
+#[derive(Subcommand, Clone)]
+pub enum LiquidityCommands {
+    /// List liquidity positions for a wallet on a subnet
+    List {
+        /// Wallet name
+        #[arg(short, long)]
+        wallet: String,
+        /// Subnet ID
+        #[arg(short, long)]
+        netuid: u16,
+    },
+}
+
+/// Execute liquidity commands
+pub async fn execute(cmd: LiquidityCommand, cli: &Cli) -> anyhow::Result<()> {
+    match cmd.command {
+        LiquidityCommands::List { wallet, netuid } => list_positions(&wallet, netuid, cli).await,
+    }
+}
+
+/// List liquidity positions with their price range and fees owed
+async fn list_positions(wallet_name: &str, netuid: u16, cli: &Cli) -> anyhow::Result<()> {
+    use crate::core::constants::RAOPERTAO;
+    use crate::queries::liquidity::get_positions;
+    use sp_core::crypto::AccountId32;
+    use std::str::FromStr;
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let wallet = match Wallet::new(wallet_name, "default", None) {
+        Ok(w) => w,
+        Err(e) => {
+            print_error(&format!("Invalid wallet name '{}': {}", wallet_name, e));
+            return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+        }
+    };
+    if !wallet.coldkey_exists() {
+        print_error(&format!("Wallet '{}' not found", wallet_name));
+        return Err(anyhow::anyhow!("Wallet not found"));
+    }
+
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
+    let coldkey_addr = wallet
+        .coldkey_ss58(coldkey_password.as_deref())
+        .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
+    let coldkey_account = AccountId32::from_str(&coldkey_addr)
+        .map_err(|e| anyhow::anyhow!("Invalid coldkey address: {:?}", e))?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let sp = spinner("Fetching liquidity positions...");
+    let positions = get_positions(&client, &coldkey_account, netuid)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch positions: {}", e))?;
+    sp.finish_and_clear();
+
+    println!(
+        "\nLiquidity positions for wallet '{}' ({}) on subnet {}",
+        wallet_name,
+        format_address(&coldkey_addr),
+        netuid
+    );
+
+    if positions.is_empty() {
+        print_info("No liquidity positions found");
+        return Ok(());
+    }
+
+    let mut table = create_table_with_headers(&[
+        "ID",
+        "Price Low",
+        "Price High",
+        "Ticks",
+        "Liquidity",
+        "Fees (TAO)",
+        "Fees (Alpha)",
+    ]);
+
+    for position in &positions {
+        table.add_row(vec![
+            position.id.to_string(),
+            format!("{:.9}", position.price_low_rao as f64 / RAOPERTAO as f64),
+            format!("{:.9}", position.price_high_rao as f64 / RAOPERTAO as f64),
+            format!("{}..{}", position.tick_low, position.tick_high),
+            position.liquidity_rao.to_string(),
+            format_tao(position.fees_tao_rao),
+            format!("{:.9}", position.fees_alpha_rao as f64 / RAOPERTAO as f64),
+        ]);
+    }
+
+    println!("{table}");
+
+    Ok(())
+}
@@ -3,6 +3,8 @@
 //! Each module contains the command definitions and execution logic
 //! for a specific category of operations.
 
+pub mod config;
+pub mod liquidity;
 pub mod root;
 pub mod stake;
 pub mod subnet;
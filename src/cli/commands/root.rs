@@ -1,13 +1,15 @@
 //! Root network commands for managing the root subnet (netuid 0).
 
+use crate::cli::output::{emit, Render};
 use crate::cli::utils::{
-    confirm, create_table_with_headers, format_address, format_tao, keypair_to_signer,
-    parse_f64_list, parse_u16_list, print_error, print_info, print_success, print_warning,
-    prompt_password_optional, resolve_endpoint, spinner,
+    confirm, connect_client, create_table_with_headers, format_address, format_tao,
+    keypair_to_signer, parse_f64_list, parse_u16_list, print_error, print_info, print_success,
+    print_warning, resolve_coldkey_password, resolve_endpoint, resolve_hotkey_password, spinner,
 };
 use crate::cli::Cli;
 use crate::wallet::Wallet;
 use clap::{Args, Subcommand};
+use serde::Serialize;
 use std::str::FromStr;
 
 /// Root network command container
@@ -58,6 +60,79 @@ pub enum RootCommands {
 
     /// Show root network delegates
     Delegates,
+
+    /// Senate membership, proposals and voting
+    Senate(SenateCommand),
+
+    /// Alias of `root senate proposals`
+    #[command(hide = true)]
+    Proposals {
+        #[command(flatten)]
+        voter: VoterArgs,
+    },
+
+    /// Alias of `root senate vote`
+    #[command(hide = true)]
+    Vote(VoteArgs),
+}
+
+/// Senate command container
+#[derive(Args, Clone)]
+pub struct SenateCommand {
+    #[command(subcommand)]
+    pub command: SenateCommands,
+}
+
+/// Available senate operations
+#[derive(Subcommand, Clone)]
+pub enum SenateCommands {
+    /// List senate members with their identities
+    Members,
+
+    /// List active proposals with their decoded calls
+    Proposals {
+        #[command(flatten)]
+        voter: VoterArgs,
+    },
+
+    /// Vote on a proposal by its index
+    Vote(VoteArgs),
+}
+
+/// Hotkey whose votes are shown next to each proposal
+#[derive(Args, Clone, Debug)]
+pub struct VoterArgs {
+    /// Wallet name
+    #[arg(short, long)]
+    pub wallet: Option<String>,
+    /// Hotkey name (the senate member)
+    #[arg(short = 'k', long, default_value = "default", requires = "wallet")]
+    pub hotkey: String,
+}
+
+/// Arguments of `root senate vote`
+#[derive(Args, Clone, Debug)]
+pub struct VoteArgs {
+    /// Wallet name
+    #[arg(short, long)]
+    pub wallet: String,
+    /// Hotkey name (the senate member)
+    #[arg(short = 'k', long, default_value = "default")]
+    pub hotkey: String,
+    /// Proposal index, as shown by `root senate proposals`
+    #[arg(long)]
+    pub index: u32,
+    /// Vote in favor
+    #[arg(
+        long,
+        alias = "approve",
+        required_unless_present = "nay",
+        conflicts_with = "nay"
+    )]
+    pub aye: bool,
+    /// Vote against
+    #[arg(long, alias = "reject")]
+    pub nay: bool,
 }
 
 /// Execute root network commands
@@ -74,15 +149,22 @@ pub async fn execute(cmd: RootCommand, cli: &Cli) -> anyhow::Result<()> {
         RootCommands::GetWeights { hotkey } => get_weights(&hotkey, cli).await,
         RootCommands::Info => show_info(cli).await,
         RootCommands::Delegates => show_delegates(cli).await,
+        RootCommands::Senate(senate) => match senate.command {
+            SenateCommands::Members => show_senate_members(cli).await,
+            SenateCommands::Proposals { voter } => show_proposals(&voter, cli).await,
+            SenateCommands::Vote(args) => vote(&args, cli).await,
+        },
+        RootCommands::Proposals { voter } => show_proposals(&voter, cli).await,
+        RootCommands::Vote(args) => vote(&args, cli).await,
     }
 }
 
 /// Register on the root network
 async fn register(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::{BittensorClient, ExtrinsicWait};
+    use crate::chain::ExtrinsicWait;
     use crate::validator::root::root_register;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let wallet = match Wallet::new(wallet_name, "default", None) {
         Ok(w) => w,
@@ -96,13 +178,13 @@ async fn register(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
     let coldkey = wallet
         .coldkey_keypair(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
     let signer = keypair_to_signer(&coldkey);
 
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
     let hotkey = wallet
         .hotkey_keypair(hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
@@ -117,7 +199,7 @@ async fn register(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
     }
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -144,15 +226,14 @@ async fn register(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
 
 /// List all root network validators
 async fn list_root_validators(cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
     use crate::metagraph::sync_metagraph;
 
     const ROOT_NETUID: u16 = 0;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -205,10 +286,10 @@ async fn set_weights(
     weights_str: &str,
     cli: &Cli,
 ) -> anyhow::Result<()> {
-    use crate::chain::{BittensorClient, ExtrinsicWait};
+    use crate::chain::ExtrinsicWait;
     use crate::validator::root::root_set_weights;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     // Parse netuids
     let netuids = parse_u16_list(netuids_str)?;
@@ -246,13 +327,13 @@ async fn set_weights(
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
     let coldkey = wallet
         .coldkey_keypair(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
     let signer = keypair_to_signer(&coldkey);
 
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
     let _hotkey = wallet
         .hotkey_keypair(hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
@@ -268,7 +349,7 @@ async fn set_weights(
     }
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -300,15 +381,14 @@ async fn set_weights(
 
 /// Get root network weights for a validator
 async fn get_weights(hotkey_addr: &str, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
     use crate::metagraph::sync_metagraph;
 
     const ROOT_NETUID: u16 = 0;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -357,15 +437,14 @@ async fn get_weights(hotkey_addr: &str, cli: &Cli) -> anyhow::Result<()> {
 
 /// Show root network information
 async fn show_info(cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
     use crate::queries::subnets::{difficulty, immunity_period, subnet_info, tempo};
 
     const ROOT_NETUID: u16 = 0;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -417,15 +496,56 @@ async fn show_info(cli: &Cli) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// A delegate and its stake across all subnets
+#[derive(Debug, Serialize)]
+pub struct DelegateEntry {
+    pub hotkey: String,
+    pub owner: String,
+    /// Total stake across subnets, in RAO
+    pub total_stake_rao: u128,
+    /// Take as a fraction (0.18 = 18%)
+    pub take: f64,
+}
+
+/// Root network delegates
+#[derive(Debug, Serialize)]
+pub struct DelegateList {
+    pub delegates: Vec<DelegateEntry>,
+}
+
+impl Render for DelegateList {
+    fn render_table(&self) {
+        if self.delegates.is_empty() {
+            print_info("No delegates found");
+            return;
+        }
+
+        println!("\nRoot Network Delegates");
+        println!("═══════════════════════════════════════════════════════════════");
+
+        let mut table = create_table_with_headers(&["Hotkey", "Total Stake", "Take", "Owner"]);
+        for delegate in &self.delegates {
+            table.add_row(vec![
+                format_address(&delegate.hotkey),
+                format_tao(delegate.total_stake_rao),
+                format!("{:.2}%", delegate.take * 100.0),
+                format_address(&delegate.owner),
+            ]);
+        }
+
+        println!("{table}");
+        println!("\nTotal delegates: {}", self.delegates.len());
+    }
+}
+
 /// Show root network delegates
 async fn show_delegates(cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
     use crate::queries::delegates::get_delegates;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -436,30 +556,455 @@ async fn show_delegates(cli: &Cli) -> anyhow::Result<()> {
         .map_err(|e| anyhow::anyhow!("Failed to fetch delegates: {}", e))?;
     sp.finish_and_clear();
 
-    if delegates.is_empty() {
-        print_info("No delegates found");
+    let list = DelegateList {
+        delegates: delegates
+            .iter()
+            .map(|delegate| {
+                // Calculate total stake across all subnets
+                let total_stake: crate::utils::balance_newtypes::Rao =
+                    delegate.total_stake.values().copied().sum();
+                DelegateEntry {
+                    hotkey: delegate.base.hotkey_ss58.to_string(),
+                    owner: delegate.base.owner_ss58.to_string(),
+                    total_stake_rao: total_stake.as_u128(),
+                    take: delegate.base.take,
+                }
+            })
+            .collect(),
+    };
+    emit(cli, &list)
+}
+
+/// A senate member
+#[derive(Debug, Serialize)]
+pub struct SenateMemberEntry {
+    pub hotkey: String,
+    /// Coldkey owning the hotkey, if known
+    pub owner: Option<String>,
+    /// Identity name of the owner, if set
+    pub name: Option<String>,
+}
+
+/// Senate members
+#[derive(Debug, Serialize)]
+pub struct SenateMemberList {
+    pub members: Vec<SenateMemberEntry>,
+}
+
+impl Render for SenateMemberList {
+    fn render_table(&self) {
+        if self.members.is_empty() {
+            print_info("The senate has no members");
+            return;
+        }
+
+        println!("\nSenate Members");
+        println!("═══════════════════════════════════════════════════════════════");
+
+        let mut table = create_table_with_headers(&["Name", "Hotkey", "Owner"]);
+        for member in &self.members {
+            table.add_row(vec![
+                member.name.clone().unwrap_or_else(|| "~".to_string()),
+                format_address(&member.hotkey),
+                member
+                    .owner
+                    .as_deref()
+                    .map(format_address)
+                    .unwrap_or_else(|| "~".to_string()),
+            ]);
+        }
+
+        println!("{table}");
+        println!("\nTotal members: {}", self.members.len());
+    }
+}
+
+/// Show senate members with the identity names of their owners
+async fn show_senate_members(cli: &Cli) -> anyhow::Result<()> {
+    use crate::queries::identity::query_identity;
+    use crate::queries::neurons::get_hotkey_owner;
+    use crate::validator::senate::get_senate_members;
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let sp = spinner("Fetching senate members...");
+    let members = get_senate_members(&client)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch senate members: {}", e))?;
+
+    // Identities are optional; a failed lookup leaves the name blank
+    let client = &client;
+    let members = futures::future::join_all(members.into_iter().map(|hotkey| async move {
+        let owner = get_hotkey_owner(client, &hotkey).await.ok().flatten();
+        let name = match &owner {
+            Some(coldkey) => query_identity(client, coldkey)
+                .await
+                .ok()
+                .flatten()
+                .and_then(|identity| identity.name().cloned())
+                .filter(|name| !name.is_empty()),
+            None => None,
+        };
+        SenateMemberEntry {
+            hotkey: hotkey.to_string(),
+            owner: owner.map(|owner| owner.to_string()),
+            name,
+        }
+    }))
+    .await;
+    sp.finish_and_clear();
+
+    emit(cli, &SenateMemberList { members })
+}
+
+/// An active senate proposal
+#[derive(Debug, Serialize)]
+pub struct ProposalEntry {
+    pub index: u32,
+    /// Proposal hash as 0x-prefixed hex
+    pub hash: String,
+    /// Decoded call, or the call data as hex if it can't be decoded
+    pub call: String,
+    pub ayes: usize,
+    pub nays: usize,
+    pub threshold: u32,
+    /// Block at which voting ends
+    pub end: u64,
+    /// `aye` or `nay` if the selected hotkey voted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub my_vote: Option<&'static str>,
+}
+
+/// Active senate proposals
+#[derive(Debug, Serialize)]
+pub struct ProposalList {
+    /// Hotkey whose votes are shown, if one was selected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voter: Option<String>,
+    pub proposals: Vec<ProposalEntry>,
+}
+
+impl Render for ProposalList {
+    fn render_table(&self) {
+        if self.proposals.is_empty() {
+            print_info("No active proposals");
+            return;
+        }
+
+        println!("\nSenate Proposals");
+        println!("═══════════════════════════════════════════════════════════════");
+
+        let mut headers = vec!["Index", "Hash", "Call", "Ayes", "Nays", "Ends"];
+        if self.voter.is_some() {
+            headers.push("My Vote");
+        }
+        let mut table = create_table_with_headers(&headers);
+        for proposal in &self.proposals {
+            let mut row = vec![
+                proposal.index.to_string(),
+                format_address(&proposal.hash),
+                proposal.call.clone(),
+                format!("{}/{}", proposal.ayes, proposal.threshold),
+                proposal.nays.to_string(),
+                format!("block {}", proposal.end),
+            ];
+            if self.voter.is_some() {
+                row.push(proposal.my_vote.unwrap_or("-").to_string());
+            }
+            table.add_row(row);
+        }
+
+        println!("{table}");
+        println!("\nTotal proposals: {}", self.proposals.len());
+    }
+}
+
+/// `aye` or `nay` for a recorded vote
+fn vote_label(vote: Option<bool>) -> Option<&'static str> {
+    vote.map(|aye| if aye { "aye" } else { "nay" })
+}
+
+/// Load the hotkey of a wallet, unlocking it if needed
+fn load_hotkey(
+    wallet_name: &str,
+    hotkey_name: &str,
+    cli: &Cli,
+) -> anyhow::Result<crate::wallet::Keypair> {
+    let wallet = match Wallet::new(wallet_name, hotkey_name, None) {
+        Ok(w) => w,
+        Err(e) => {
+            print_error(&format!("Invalid wallet name '{}': {}", wallet_name, e));
+            return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+        }
+    };
+
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
+    wallet
+        .hotkey_keypair(hotkey_password.as_deref())
+        .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))
+}
+
+/// Show active senate proposals
+async fn show_proposals(voter: &VoterArgs, cli: &Cli) -> anyhow::Result<()> {
+    use crate::validator::senate::get_proposals;
+
+    let voter = match &voter.wallet {
+        Some(wallet) => {
+            let hotkey = load_hotkey(wallet, &voter.hotkey, cli)?;
+            Some(
+                sp_core::crypto::AccountId32::from_str(hotkey.ss58_address())
+                    .map_err(|e| anyhow::anyhow!("Invalid hotkey address: {:?}", e))?,
+            )
+        }
+        None => None,
+    };
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let sp = spinner("Fetching proposals...");
+    let proposals = get_proposals(&client)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch proposals: {}", e))?;
+    sp.finish_and_clear();
+
+    let metadata = client.metadata();
+    let list = ProposalList {
+        voter: voter.as_ref().map(|account| account.to_string()),
+        proposals: proposals
+            .iter()
+            .map(|proposal| ProposalEntry {
+                index: proposal.index,
+                hash: format!("0x{}", hex::encode(proposal.hash)),
+                call: proposal.describe_call(&metadata),
+                ayes: proposal.ayes.len(),
+                nays: proposal.nays.len(),
+                threshold: proposal.threshold,
+                end: proposal.end,
+                my_vote: voter.as_ref().and_then(|account| {
+                    if proposal.ayes.contains(account) {
+                        vote_label(Some(true))
+                    } else if proposal.nays.contains(account) {
+                        vote_label(Some(false))
+                    } else {
+                        None
+                    }
+                }),
+            })
+            .collect(),
+    };
+    emit(cli, &list)
+}
+
+/// Vote on a senate proposal
+async fn vote(args: &VoteArgs, cli: &Cli) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::validator::senate::{find_proposal_by_index, get_proposal, vote_by_index};
+
+    let approve = args.aye;
+    let proposal_index = args.index;
+    let endpoint = resolve_endpoint(cli)?;
+
+    let hotkey = load_hotkey(&args.wallet, &args.hotkey, cli)?;
+    let signer = keypair_to_signer(&hotkey);
+    let hotkey_account = sp_core::crypto::AccountId32::from_str(hotkey.ss58_address())
+        .map_err(|e| anyhow::anyhow!("Invalid hotkey address: {:?}", e))?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let (hash, vote_data) = find_proposal_by_index(&client, proposal_index)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch proposals: {}", e))?
+        .ok_or_else(|| anyhow::anyhow!("No active proposal with index {}", proposal_index))?;
+    let call = get_proposal(&client, &hash)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch proposal: {}", e))?
+        .map(|proposal| proposal.describe_call(&client.metadata()));
+
+    let choice = vote_label(Some(approve)).unwrap_or_default();
+    match vote_data.vote_of(&hotkey_account) {
+        Some(previous) if previous == approve => {
+            print_warning(&format!(
+                "Hotkey {} already voted {} on proposal {}",
+                hotkey.ss58_address(),
+                choice,
+                proposal_index
+            ));
+            return Ok(());
+        }
+        Some(previous) => print_warning(&format!(
+            "Hotkey {} already voted {} on proposal {}; this changes the vote",
+            hotkey.ss58_address(),
+            vote_label(Some(previous)).unwrap_or_default(),
+            proposal_index
+        )),
+        None => {}
+    }
+
+    print_info(&format!("Voting {} on proposal {}", choice, proposal_index));
+    if let Some(call) = &call {
+        print_info(&format!("Call: {}", call));
+    }
+    print_info(&format!("Hotkey: {}", hotkey.ss58_address()));
+    print_info(&format!(
+        "Votes: {} ayes, {} nays (threshold {}), ends at block {}",
+        vote_data.ayes.len(),
+        vote_data.nays.len(),
+        vote_data.threshold,
+        vote_data.end
+    ));
+
+    if !confirm("Submit vote?", cli.no_prompt) {
+        print_info("Vote cancelled");
         return Ok(());
     }
 
-    println!("\nRoot Network Delegates");
-    println!("═══════════════════════════════════════════════════════════════");
+    let sp = spinner("Submitting vote...");
+    let result = vote_by_index(
+        &client,
+        &signer,
+        proposal_index,
+        approve,
+        ExtrinsicWait::Finalized,
+    )
+    .await;
+    sp.finish_and_clear();
+
+    match result {
+        Ok(tx_hash) => {
+            print_success("Vote submitted!");
+            print_info(&format!("Transaction hash: {}", tx_hash));
+            Ok(())
+        }
+        Err(e) => {
+            print_error(&format!("Vote failed: {}", e));
+            Err(anyhow::anyhow!("Vote failed: {}", e))
+        }
+    }
+}
 
-    let mut table = create_table_with_headers(&["Hotkey", "Total Stake", "Take", "Owner"]);
-
-    for delegate in &delegates {
-        // Calculate total stake across all subnets
-        let total_stake: crate::utils::balance_newtypes::Rao =
-            delegate.total_stake.values().copied().sum();
-        table.add_row(vec![
-            format_address(&delegate.base.hotkey_ss58.to_string()),
-            format_tao(total_stake.as_u128()),
-            format!("{:.2}%", delegate.base.take * 100.0),
-            format_address(&delegate.base.owner_ss58.to_string()),
-        ]);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Commands;
+    use clap::Parser;
+
+    fn parse(args: &[&str]) -> Result<RootCommands, clap::Error> {
+        let cli = Cli::try_parse_from(["btcli", "root"].iter().chain(args))?;
+        match cli.command {
+            Commands::Root(root) => Ok(root.command),
+            _ => panic!("Expected a root command"),
+        }
     }
 
-    println!("{table}");
-    println!("\nTotal delegates: {}", delegates.len());
+    fn parse_vote(args: &[&str]) -> Result<VoteArgs, clap::Error> {
+        match parse(args)? {
+            RootCommands::Senate(SenateCommand {
+                command: SenateCommands::Vote(args),
+            })
+            | RootCommands::Vote(args) => Ok(args),
+            _ => panic!("Expected a vote command"),
+        }
+    }
 
-    Ok(())
+    #[test]
+    fn test_senate_vote_args() {
+        let args =
+            parse_vote(&["senate", "vote", "-w", "senator", "--index", "3", "--aye"]).unwrap();
+        assert_eq!(args.wallet, "senator");
+        assert_eq!(args.hotkey, "default");
+        assert_eq!(args.index, 3);
+        assert!(args.aye && !args.nay);
+
+        let args = parse_vote(&[
+            "senate", "vote", "-w", "s", "-k", "hk", "--index", "0", "--nay",
+        ])
+        .unwrap();
+        assert_eq!(args.hotkey, "hk");
+        assert!(!args.aye && args.nay);
+
+        // The old `root vote --approve/--reject` form still works
+        let args = parse_vote(&["vote", "-w", "s", "--index", "1", "--reject"]).unwrap();
+        assert!(!args.aye && args.nay);
+    }
+
+    #[test]
+    fn test_senate_vote_requires_one_choice() {
+        assert!(parse(&["senate", "vote", "-w", "s", "--index", "1"]).is_err());
+        assert!(parse(&["senate", "vote", "-w", "s", "--index", "1", "--aye", "--nay"]).is_err());
+        assert!(parse(&["senate", "vote", "-w", "s", "--aye"]).is_err());
+        assert!(parse(&["senate", "vote", "--index", "1", "--aye"]).is_err());
+    }
+
+    #[test]
+    fn test_senate_proposals_voter() {
+        let RootCommands::Senate(SenateCommand {
+            command: SenateCommands::Proposals { voter },
+        }) = parse(&["senate", "proposals"]).unwrap()
+        else {
+            panic!("Expected senate proposals");
+        };
+        assert!(voter.wallet.is_none());
+
+        assert!(matches!(
+            parse(&["senate", "proposals", "-w", "senator"]).unwrap(),
+            RootCommands::Senate(SenateCommand {
+                command: SenateCommands::Proposals { voter },
+            }) if voter.wallet.as_deref() == Some("senator")
+        ));
+        // A hotkey alone doesn't select a wallet
+        assert!(parse(&["senate", "proposals", "-k", "hk"]).is_err());
+        assert!(matches!(
+            parse(&["senate", "members"]).unwrap(),
+            RootCommands::Senate(SenateCommand {
+                command: SenateCommands::Members
+            })
+        ));
+    }
+
+    #[test]
+    fn test_proposal_list_json() {
+        let entry = |my_vote| ProposalEntry {
+            index: 2,
+            hash: "0x00".to_string(),
+            call: "AdminUtils.sudo_set_default_take(default_take: 18%)".to_string(),
+            ayes: 1,
+            nays: 0,
+            threshold: 3,
+            end: 100,
+            my_vote,
+        };
+
+        let list = ProposalList {
+            voter: None,
+            proposals: vec![entry(None)],
+        };
+        let json = serde_json::to_value(&list).unwrap();
+        assert!(json.get("voter").is_none());
+        assert!(json["proposals"][0].get("my_vote").is_none());
+
+        let list = ProposalList {
+            voter: Some("5Grw".to_string()),
+            proposals: vec![entry(vote_label(Some(false)))],
+        };
+        let json = serde_json::to_value(&list).unwrap();
+        assert_eq!(json["voter"], "5Grw");
+        assert_eq!(json["proposals"][0]["my_vote"], "nay");
+        assert_eq!(vote_label(None), None);
+    }
 }
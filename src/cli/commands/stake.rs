@@ -1,13 +1,22 @@
 //! Stake commands for managing TAO delegation.
 
+use crate::chain::BittensorSigner;
+use crate::cli::output::{emit, Render};
 use crate::cli::utils::{
-    confirm, create_table_with_headers, format_address, format_tao, keypair_to_signer, print_error,
-    print_info, print_success, print_warning, prompt_password_optional, resolve_endpoint, spinner,
-    tao_to_rao,
+    check_password_file, confirm, connect_client, create_table_with_headers, format_address,
+    format_tao, is_valid_ss58, keypair_to_signer, ledger_signer, parse_address_any_prefix,
+    print_error, print_info, print_success, print_warning, resolve_coldkey_password,
+    resolve_endpoint, resolve_hotkey_password, spinner, stake_totals_by_netuid, tao_to_rao,
+    LedgerArgs,
 };
+use crate::cli::utils::{compute_stakeable_amount, parse_amount_or_percent, AmountSpec};
 use crate::cli::Cli;
+use crate::queries::stakes::{StakeEmissionHistory, StakeInfo};
+use crate::utils::balance_newtypes::{Balance, Rao};
 use crate::wallet::Wallet;
 use clap::{Args, Subcommand};
+use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Stake command container
 #[derive(Args, Clone)]
@@ -22,33 +31,56 @@ pub enum StakeCommands {
     /// Add stake to a hotkey on a subnet
     Add {
         /// Wallet name
-        #[arg(short, long)]
-        wallet: String,
-        /// Hotkey name
+        #[arg(
+            short,
+            long,
+            required_unless_present = "ledger",
+            conflicts_with = "ledger"
+        )]
+        wallet: Option<String>,
+        /// Hotkey name (SS58 address with --ledger)
         #[arg(short = 'k', long)]
         hotkey: String,
         /// Subnet ID
         #[arg(short, long)]
         netuid: u16,
-        /// Amount in TAO to stake
-        #[arg(short, long)]
-        amount: f64,
+        /// Amount in TAO, or a percentage of the stakeable free balance (e.g. 50%)
+        #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+        amount: Option<String>,
+        /// Stake all free balance above the existential deposit, reserve and fee
+        #[arg(long)]
+        all: bool,
+        /// TAO to keep unstaked with --all
+        #[arg(long, default_value_t = 0.0, requires = "all")]
+        reserve: f64,
+        #[command(flatten)]
+        ledger: LedgerArgs,
     },
 
     /// Remove stake from a hotkey on a subnet
     Remove {
         /// Wallet name
-        #[arg(short, long)]
-        wallet: String,
-        /// Hotkey name
+        #[arg(
+            short,
+            long,
+            required_unless_present = "ledger",
+            conflicts_with = "ledger"
+        )]
+        wallet: Option<String>,
+        /// Hotkey name (SS58 address with --ledger)
         #[arg(short = 'k', long)]
         hotkey: String,
         /// Subnet ID
         #[arg(short, long)]
         netuid: u16,
-        /// Amount in TAO to unstake
-        #[arg(short, long)]
-        amount: f64,
+        /// Amount in TAO, or a percentage of current stake (e.g. 50%)
+        #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+        amount: Option<String>,
+        /// Unstake everything held on this hotkey and subnet
+        #[arg(long)]
+        all: bool,
+        #[command(flatten)]
+        ledger: LedgerArgs,
     },
 
     /// Show stake information
@@ -83,12 +115,82 @@ pub enum StakeCommands {
         amount: f64,
     },
 
+    /// Transfer stake to another coldkey, keeping the hotkey
+    Transfer {
+        /// Wallet name
+        #[arg(
+            short,
+            long,
+            required_unless_present = "ledger",
+            conflicts_with = "ledger"
+        )]
+        wallet: Option<String>,
+        /// Hotkey name (SS58 address with --ledger)
+        #[arg(short = 'k', long)]
+        hotkey: String,
+        /// Destination coldkey SS58 address
+        #[arg(long)]
+        dest_coldkey: String,
+        /// Source subnet ID
+        #[arg(long)]
+        origin_netuid: u16,
+        /// Destination subnet ID (defaults to the source subnet)
+        #[arg(long)]
+        dest_netuid: Option<u16>,
+        /// Amount of alpha to transfer
+        #[arg(short, long)]
+        amount: f64,
+        #[command(flatten)]
+        ledger: LedgerArgs,
+    },
+
     /// List all stake for a coldkey
     List {
         /// Wallet name
         #[arg(short, long)]
         wallet: String,
     },
+
+    /// Show emission earned by each stake over recent epochs
+    Earnings {
+        /// Wallet name
+        #[arg(long)]
+        name: String,
+        /// Only show stake on this subnet
+        #[arg(short, long)]
+        netuid: Option<u16>,
+        /// Number of epochs to look back
+        #[arg(long, default_value_t = 5)]
+        epochs: u64,
+    },
+
+    /// Manage child hotkeys
+    Children {
+        #[command(subcommand)]
+        command: ChildrenCommands,
+    },
+}
+
+/// Child hotkey operations
+#[derive(Subcommand, Clone)]
+pub enum ChildrenCommands {
+    /// Show or set the take a parent hotkey charges its children
+    Take {
+        /// Wallet name (needed to set the take, or to look up a hotkey by name)
+        #[arg(short, long, conflicts_with = "ledger")]
+        wallet: Option<String>,
+        /// Parent hotkey name, or SS58 address
+        #[arg(short = 'k', long)]
+        hotkey: String,
+        /// Subnet ID
+        #[arg(short, long)]
+        netuid: u16,
+        /// New take as a fraction (e.g. 0.09 for 9%); shows the current take if omitted
+        #[arg(short, long)]
+        take: Option<f64>,
+        #[command(flatten)]
+        ledger: LedgerArgs,
+    },
 }
 
 /// Execute stake commands
@@ -99,13 +201,34 @@ pub async fn execute(cmd: StakeCommand, cli: &Cli) -> anyhow::Result<()> {
             hotkey,
             netuid,
             amount,
-        } => add_stake(&wallet, &hotkey, netuid, amount, cli).await,
+            all,
+            reserve,
+            ledger,
+        } => {
+            let amount = stake_amount_arg(amount.as_deref(), all)?;
+            let reserve = tao_to_rao(reserve);
+            add_stake(
+                wallet.as_deref(),
+                &hotkey,
+                netuid,
+                amount,
+                reserve,
+                &ledger,
+                cli,
+            )
+            .await
+        }
         StakeCommands::Remove {
             wallet,
             hotkey,
             netuid,
             amount,
-        } => remove_stake(&wallet, &hotkey, netuid, amount, cli).await,
+            all,
+            ledger,
+        } => {
+            let amount = stake_amount_arg(amount.as_deref(), all)?;
+            remove_stake(wallet.as_deref(), &hotkey, netuid, amount, &ledger, cli).await
+        }
         StakeCommands::Show { wallet, all } => show_stake(wallet.as_deref(), all, cli).await,
         StakeCommands::Move {
             wallet,
@@ -126,31 +249,81 @@ pub async fn execute(cmd: StakeCommand, cli: &Cli) -> anyhow::Result<()> {
             )
             .await
         }
+        StakeCommands::Transfer {
+            wallet,
+            hotkey,
+            dest_coldkey,
+            origin_netuid,
+            dest_netuid,
+            amount,
+            ledger,
+        } => {
+            transfer_stake(
+                wallet.as_deref(),
+                &hotkey,
+                &dest_coldkey,
+                origin_netuid,
+                dest_netuid.unwrap_or(origin_netuid),
+                amount,
+                &ledger,
+                cli,
+            )
+            .await
+        }
         StakeCommands::List { wallet } => list_stake(&wallet, cli).await,
+        StakeCommands::Earnings {
+            name,
+            netuid,
+            epochs,
+        } => stake_earnings(&name, netuid, epochs, cli).await,
+        StakeCommands::Children { command } => match command {
+            ChildrenCommands::Take {
+                wallet,
+                hotkey,
+                netuid,
+                take: None,
+                ..
+            } => show_childkey_take(wallet.as_deref(), &hotkey, netuid, cli).await,
+            ChildrenCommands::Take {
+                wallet,
+                hotkey,
+                netuid,
+                take: Some(take),
+                ledger,
+            } => set_childkey_take(wallet.as_deref(), &hotkey, netuid, take, &ledger, cli).await,
+        },
     }
 }
 
-/// Add stake to a hotkey
-async fn add_stake(
-    wallet_name: &str,
-    hotkey_name: &str,
-    netuid: u16,
-    amount: f64,
-    cli: &Cli,
-) -> anyhow::Result<()> {
-    use crate::chain::{BittensorClient, ExtrinsicWait};
-    use crate::validator::staking::add_stake as stake_add;
-    use sp_core::crypto::AccountId32;
-    use std::str::FromStr;
-
-    if amount <= 0.0 {
-        print_error("Amount must be positive");
-        return Err(anyhow::anyhow!("Invalid amount"));
+/// `--amount` or `--all` for add/remove; `--all` is 100% of the base balance
+fn stake_amount_arg(amount: Option<&str>, all: bool) -> anyhow::Result<AmountSpec> {
+    match amount {
+        Some(amount) => parse_amount_or_percent(amount),
+        None if all => Ok(AmountSpec::Percent(100.0)),
+        None => Err(anyhow::anyhow!("Either --amount or --all is required")),
     }
+}
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+/// Unlock the coldkey signer and resolve the hotkey for add/remove.
+///
+/// With `--ledger` the coldkey lives on the device and the hotkey is given as
+/// an SS58 address. Returns the signer, coldkey address and hotkey address.
+fn unlock_stake_keys(
+    wallet_name: Option<&str>,
+    hotkey: &str,
+    ledger: &LedgerArgs,
+    cli: &Cli,
+) -> anyhow::Result<(BittensorSigner, String, String)> {
+    let Some(wallet_name) = wallet_name else {
+        if !is_valid_ss58(hotkey) {
+            print_error("With --ledger, --hotkey must be an SS58 address");
+            return Err(anyhow::anyhow!("Invalid hotkey address: {}", hotkey));
+        }
+        let (signer, coldkey_address) = ledger_signer(ledger)?;
+        return Ok((signer, coldkey_address, hotkey.to_string()));
+    };
 
-    let wallet = match Wallet::new(wallet_name, hotkey_name, None) {
+    let wallet = match Wallet::new(wallet_name, hotkey, None) {
         Ok(w) => w,
         Err(e) => {
             print_error(&format!("Invalid wallet name '{}': {}", wallet_name, e));
@@ -162,41 +335,125 @@ async fn add_stake(
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
     let coldkey = wallet
         .coldkey_keypair(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
-    let signer = keypair_to_signer(&coldkey);
 
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
     let hotkey = wallet
         .hotkey_keypair(hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
-    let hotkey_account = AccountId32::from_str(hotkey.ss58_address())
-        .map_err(|e| anyhow::anyhow!("Invalid hotkey address: {:?}", e))?;
 
-    let rao_amount = crate::utils::balance_newtypes::Rao::from(tao_to_rao(amount));
+    Ok((
+        keypair_to_signer(&coldkey),
+        coldkey.ss58_address().to_string(),
+        hotkey.ss58_address().to_string(),
+    ))
+}
+
+/// Add stake to a hotkey
+async fn add_stake(
+    wallet_name: Option<&str>,
+    hotkey_name: &str,
+    netuid: u16,
+    amount: AmountSpec,
+    reserve: u128,
+    ledger: &LedgerArgs,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::core::constants::RAOPERTAO;
+    use crate::queries::subnets::get_dynamic_info;
+    use crate::types::estimate_slippage;
+    use crate::validator::staking::add_stake as stake_add;
+    use sp_core::crypto::AccountId32;
+    use std::str::FromStr;
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let (signer, coldkey_address, hotkey_address) =
+        unlock_stake_keys(wallet_name, hotkey_name, ledger, cli)?;
+    let (hotkey_account, hotkey_address) = parse_address_any_prefix(&hotkey_address, "hotkey")?;
+    let coldkey_account = AccountId32::from_str(&coldkey_address)
+        .map_err(|e| anyhow::anyhow!("Invalid coldkey address: {:?}", e))?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let balance = compute_stakeable_amount(&client, &coldkey_account, reserve).await?;
+    let amount_rao = match amount {
+        // Percentages, including --all, leave the deposit, reserve and fee behind
+        AmountSpec::Percent(_) => amount.resolve(balance.stakeable()),
+        AmountSpec::Tao(_) => amount.resolve(0),
+    };
+    if amount_rao == 0 {
+        print_error(&format!(
+            "Nothing to stake: free balance {} leaves nothing above the existential \
+             deposit, reserve and fee",
+            format_tao(balance.free)
+        ));
+        return Err(anyhow::anyhow!("Invalid amount"));
+    }
+    if !balance.keeps_existential_deposit(amount_rao) {
+        print_error(&format!(
+            "Staking {} would leave less than the existential deposit ({}) after fees; \
+             at most {} can be staked",
+            format_tao(amount_rao),
+            format_tao(balance.existential_deposit),
+            format_tao(balance.stakeable())
+        ));
+        return Err(anyhow::anyhow!("Amount exceeds stakeable balance"));
+    }
+    let rao_amount = crate::utils::balance_newtypes::Rao::from(amount_rao);
 
     print_info(&format!(
-        "Adding stake: {} TAO ({} RAO)",
-        amount,
-        rao_amount.as_u128()
+        "Adding stake: {} ({} RAO)",
+        format_tao(amount_rao),
+        amount_rao
     ));
-    print_info(&format!("Coldkey: {}", coldkey.ss58_address()));
-    print_info(&format!("Hotkey: {}", hotkey.ss58_address()));
+    print_info(&format!("Coldkey: {}", coldkey_address));
+    print_info(&format!("Hotkey: {}", hotkey_address));
     print_info(&format!("Subnet: {}", netuid));
+    print_info(&format!(
+        "Free balance after staking: ~{}",
+        format_tao(
+            balance
+                .free
+                .saturating_sub(amount_rao)
+                .saturating_sub(balance.fee_estimate)
+        )
+    ));
+
+    if netuid > 0 {
+        match get_dynamic_info(&client, netuid).await {
+            Ok(dynamic) => {
+                let estimate = estimate_slippage(&dynamic, rao_amount);
+                print_info(&format!(
+                    "Expected alpha received: {:.9} {}",
+                    estimate.alpha_out as f64 / RAOPERTAO as f64,
+                    dynamic.symbol
+                ));
+                print_info(&format!("Slippage: {:.4}%", estimate.slippage_pct));
+                if estimate.slippage_pct > 5.0 {
+                    print_warning("High slippage: consider staking a smaller amount");
+                }
+            }
+            Err(e) => print_warning(&format!("Could not estimate slippage: {}", e)),
+        }
+    }
 
     if !confirm("Proceed with staking?", cli.no_prompt) {
         print_info("Staking cancelled");
         return Ok(());
     }
 
-    let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
-    sp.finish_and_clear();
-
+    if ledger.ledger {
+        print_info("Review and approve the transaction on your Ledger device");
+    }
     let sp = spinner("Submitting stake transaction...");
     let result = stake_add(
         &client,
@@ -225,58 +482,63 @@ async fn add_stake(
 
 /// Remove stake from a hotkey
 async fn remove_stake(
-    wallet_name: &str,
+    wallet_name: Option<&str>,
     hotkey_name: &str,
     netuid: u16,
-    amount: f64,
+    amount: AmountSpec,
+    ledger: &LedgerArgs,
     cli: &Cli,
 ) -> anyhow::Result<()> {
-    use crate::chain::{BittensorClient, ExtrinsicWait};
+    use crate::chain::ExtrinsicWait;
+    use crate::queries::stakes::get_stake;
     use crate::validator::staking::unstake;
     use sp_core::crypto::AccountId32;
     use std::str::FromStr;
 
-    if amount <= 0.0 {
-        print_error("Amount must be positive");
-        return Err(anyhow::anyhow!("Invalid amount"));
-    }
-
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
-
-    let wallet = match Wallet::new(wallet_name, hotkey_name, None) {
-        Ok(w) => w,
-        Err(e) => {
-            print_error(&format!("Invalid wallet name '{}': {}", wallet_name, e));
-            return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
-        }
-    };
-    if !wallet.coldkey_exists() {
-        print_error(&format!("Wallet '{}' not found", wallet_name));
-        return Err(anyhow::anyhow!("Wallet not found"));
-    }
+    let endpoint = resolve_endpoint(cli)?;
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
-    let coldkey = wallet
-        .coldkey_keypair(coldkey_password.as_deref())
-        .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
-    let signer = keypair_to_signer(&coldkey);
+    let (signer, coldkey_address, hotkey_address) =
+        unlock_stake_keys(wallet_name, hotkey_name, ledger, cli)?;
+    let (hotkey_account, hotkey_address) = parse_address_any_prefix(&hotkey_address, "hotkey")?;
+    let coldkey_account = AccountId32::from_str(&coldkey_address)
+        .map_err(|e| anyhow::anyhow!("Invalid coldkey address: {:?}", e))?;
 
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
-    let hotkey = wallet
-        .hotkey_keypair(hotkey_password.as_deref())
-        .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
-    let hotkey_account = AccountId32::from_str(hotkey.ss58_address())
-        .map_err(|e| anyhow::anyhow!("Invalid hotkey address: {:?}", e))?;
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
 
-    let rao_amount = crate::utils::balance_newtypes::Rao::from(tao_to_rao(amount));
+    // No Alpha entry means nothing is staked
+    let current = get_stake(&client, &coldkey_account, &hotkey_account, netuid)
+        .await
+        .map(|stake| stake.as_u128())
+        .unwrap_or(0);
+    let amount_rao = amount.resolve(current);
+    if amount_rao == 0 {
+        print_error(&format!(
+            "No stake to remove for this hotkey on subnet {}",
+            netuid
+        ));
+        return Err(anyhow::anyhow!("Invalid amount"));
+    }
+    if amount_rao > current {
+        print_error(&format!(
+            "Cannot unstake {}: only {} is staked",
+            format_tao(amount_rao),
+            format_tao(current)
+        ));
+        return Err(anyhow::anyhow!("Amount exceeds current stake"));
+    }
+    let rao_amount = crate::utils::balance_newtypes::Rao::from(amount_rao);
 
     print_info(&format!(
-        "Removing stake: {} TAO ({} RAO)",
-        amount,
-        rao_amount.as_u128()
+        "Removing stake: {} ({} RAO)",
+        format_tao(amount_rao),
+        amount_rao
     ));
-    print_info(&format!("Coldkey: {}", coldkey.ss58_address()));
-    print_info(&format!("Hotkey: {}", hotkey.ss58_address()));
+    print_info(&format!("Coldkey: {}", coldkey_address));
+    print_info(&format!("Hotkey: {}", hotkey_address));
     print_info(&format!("Subnet: {}", netuid));
 
     if !confirm("Proceed with unstaking?", cli.no_prompt) {
@@ -284,12 +546,9 @@ async fn remove_stake(
         return Ok(());
     }
 
-    let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
-    sp.finish_and_clear();
-
+    if ledger.ledger {
+        print_info("Review and approve the transaction on your Ledger device");
+    }
     let sp = spinner("Submitting unstake transaction...");
     let result = unstake(
         &client,
@@ -318,16 +577,15 @@ async fn remove_stake(
 
 /// Show stake information for wallets
 async fn show_stake(wallet_name: Option<&str>, all: bool, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
     use crate::queries::stakes::get_stake_info_for_coldkey;
     use crate::wallet::list_wallets;
     use sp_core::crypto::AccountId32;
     use std::str::FromStr;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -361,9 +619,16 @@ async fn show_stake(wallet_name: Option<&str>, all: bool, cli: &Cli) -> anyhow::
         return Ok(());
     }
 
+    check_password_file(cli, &wallets)?;
     for wallet in &wallets {
-        let coldkey_password =
-            prompt_password_optional(&format!("Password for '{}' (enter to skip)", &wallet.name));
+        let message = format!("Password for '{}'", &wallet.name);
+        let coldkey_password = match resolve_coldkey_password(cli, wallet, &message) {
+            Ok(password) => password,
+            Err(e) => {
+                print_warning(&format!("Could not unlock '{}': {}", &wallet.name, e));
+                continue;
+            }
+        };
 
         let coldkey_addr = match wallet.coldkey_ss58(coldkey_password.as_deref()) {
             Ok(addr) => addr,
@@ -401,17 +666,7 @@ async fn show_stake(wallet_name: Option<&str>, all: bool, cli: &Cli) -> anyhow::
                     continue;
                 }
 
-                let mut table = create_table_with_headers(&["Hotkey", "Subnet", "Stake (TAO)"]);
-
-                for stake_info in stakes {
-                    table.add_row(vec![
-                        format_address(&stake_info.hotkey.to_string()),
-                        stake_info.netuid.to_string(),
-                        format_tao(stake_info.stake.as_u128()),
-                    ]);
-                }
-
-                println!("{table}");
+                StakeSummary::fetch(&client, &stakes).await?.print_tables();
             }
             Err(e) => {
                 print_warning(&format!(
@@ -435,7 +690,7 @@ async fn move_stake(
     amount: f64,
     cli: &Cli,
 ) -> anyhow::Result<()> {
-    use crate::chain::{BittensorClient, ExtrinsicWait};
+    use crate::chain::ExtrinsicWait;
     use crate::validator::staking::move_stake as stake_move;
     use sp_core::crypto::AccountId32;
     use std::str::FromStr;
@@ -445,7 +700,7 @@ async fn move_stake(
         return Err(anyhow::anyhow!("Invalid amount"));
     }
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let from_wallet = match Wallet::new(wallet_name, from_hotkey, None) {
         Ok(w) => w,
@@ -467,14 +722,14 @@ async fn move_stake(
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &from_wallet, "Coldkey password")?;
     let coldkey = from_wallet
         .coldkey_keypair(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
     let signer = keypair_to_signer(&coldkey);
 
     let from_hotkey_password =
-        prompt_password_optional("Source hotkey password (enter if unencrypted)");
+        resolve_hotkey_password(cli, &from_wallet, "Source hotkey password")?;
     let from_hk = from_wallet
         .hotkey_keypair(from_hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock source hotkey: {}", e))?;
@@ -482,7 +737,7 @@ async fn move_stake(
         .map_err(|e| anyhow::anyhow!("Invalid source hotkey address: {:?}", e))?;
 
     let to_hotkey_password =
-        prompt_password_optional("Destination hotkey password (enter if unencrypted)");
+        resolve_hotkey_password(cli, &to_wallet, "Destination hotkey password")?;
     let to_hk = to_wallet
         .hotkey_keypair(to_hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock destination hotkey: {}", e))?;
@@ -513,7 +768,7 @@ async fn move_stake(
     }
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -546,14 +801,119 @@ async fn move_stake(
     Ok(())
 }
 
+/// Transfer stake to another coldkey
+#[allow(clippy::too_many_arguments)]
+async fn transfer_stake(
+    wallet_name: Option<&str>,
+    hotkey_name: &str,
+    dest_coldkey: &str,
+    origin_netuid: u16,
+    dest_netuid: u16,
+    amount: f64,
+    ledger: &LedgerArgs,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::queries::subnets::get_transfer_toggle;
+    use crate::validator::transfer::transfer_stake as stake_transfer;
+    use sp_core::crypto::AccountId32;
+    use std::str::FromStr;
+
+    if amount <= 0.0 {
+        print_error("Amount must be positive");
+        return Err(anyhow::anyhow!("Invalid amount"));
+    }
+    let (dest_account, dest_coldkey) =
+        parse_address_any_prefix(dest_coldkey, "destination coldkey").inspect_err(|e| {
+            print_error(&e.to_string());
+        })?;
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let (signer, coldkey_address, hotkey_address) =
+        unlock_stake_keys(wallet_name, hotkey_name, ledger, cli)?;
+    let hotkey_account = AccountId32::from_str(&hotkey_address)
+        .map_err(|e| anyhow::anyhow!("Invalid hotkey address: {:?}", e))?;
+    let rao_amount = Rao::from(tao_to_rao(amount));
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let (origin_enabled, dest_enabled) = tokio::try_join!(
+        get_transfer_toggle(&client, origin_netuid),
+        get_transfer_toggle(&client, dest_netuid),
+    )?;
+    let toggle = |enabled: bool| if enabled { "enabled" } else { "disabled" };
+
+    print_info(&format!(
+        "Transferring stake: {} ({} RAO)",
+        format_tao(rao_amount.as_u128()),
+        rao_amount.as_u128()
+    ));
+    print_info(&format!("Hotkey: {}", hotkey_address));
+    print_info(&format!(
+        "From: {} (subnet {}, transfers {})",
+        coldkey_address,
+        origin_netuid,
+        toggle(origin_enabled)
+    ));
+    print_info(&format!(
+        "To: {} (subnet {}, transfers {})",
+        dest_coldkey,
+        dest_netuid,
+        toggle(dest_enabled)
+    ));
+    if !origin_enabled || !dest_enabled {
+        print_warning("Stake transfers are disabled on this subnet; the transfer would fail");
+        return Err(anyhow::anyhow!("Stake transfer disabled"));
+    }
+
+    if !confirm("Proceed with stake transfer?", cli.no_prompt) {
+        print_info("Transfer cancelled");
+        return Ok(());
+    }
+
+    if ledger.ledger {
+        print_info("Review and approve the transaction on your Ledger device");
+    }
+    let sp = spinner("Submitting transfer stake transaction...");
+    let result = stake_transfer(
+        &client,
+        &signer,
+        &dest_account,
+        &hotkey_account,
+        origin_netuid,
+        dest_netuid,
+        rao_amount,
+        ExtrinsicWait::Finalized,
+    )
+    .await;
+    sp.finish_and_clear();
+
+    match result {
+        Ok(tx_hash) => {
+            print_success("Stake transferred successfully!");
+            print_info(&format!("Transaction hash: {}", tx_hash));
+        }
+        Err(e) => {
+            print_error(&format!("Failed to transfer stake: {}", e));
+            return Err(anyhow::anyhow!("Transfer stake failed: {}", e));
+        }
+    }
+
+    Ok(())
+}
+
 /// List all stakes for a coldkey
 async fn list_stake(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
     use crate::queries::stakes::get_stake_info_for_coldkey;
     use sp_core::crypto::AccountId32;
     use std::str::FromStr;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let wallet = match Wallet::new(wallet_name, "default", None) {
         Ok(w) => w,
@@ -567,7 +927,7 @@ async fn list_stake(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
     let coldkey_addr = wallet
         .coldkey_ss58(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
@@ -575,7 +935,7 @@ async fn list_stake(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
         .map_err(|e| anyhow::anyhow!("Invalid coldkey address: {:?}", e))?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -586,31 +946,600 @@ async fn list_stake(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
         .map_err(|e| anyhow::anyhow!("Failed to fetch stakes: {}", e))?;
     sp.finish_and_clear();
 
-    println!(
-        "\nStake for wallet '{}' ({})",
-        wallet_name,
-        format_address(&coldkey_addr)
+    let report = StakeReport {
+        wallet: wallet_name.to_string(),
+        summary: StakeSummary::fetch(&client, &stakes).await?,
+        coldkey: coldkey_addr,
+    };
+    emit(cli, &report)
+}
+
+/// Show the emission each stake of a wallet earned over the last `epochs` epochs
+async fn stake_earnings(
+    wallet_name: &str,
+    netuid: Option<u16>,
+    epochs: u64,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::queries::stakes::{get_stake_emission_history, get_stake_info_for_coldkey};
+    use crate::queries::subnets::get_dynamic_info;
+    use sp_core::crypto::AccountId32;
+    use std::str::FromStr;
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let wallet = match Wallet::new(wallet_name, "default", None) {
+        Ok(w) => w,
+        Err(e) => {
+            print_error(&format!("Invalid wallet name '{}': {}", wallet_name, e));
+            return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+        }
+    };
+    if !wallet.coldkey_exists() {
+        print_error(&format!("Wallet '{}' not found", wallet_name));
+        return Err(anyhow::anyhow!("Wallet not found"));
+    }
+
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
+    let coldkey_addr = wallet
+        .coldkey_ss58(coldkey_password.as_deref())
+        .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
+    let coldkey_account = AccountId32::from_str(&coldkey_addr)
+        .map_err(|e| anyhow::anyhow!("Invalid coldkey address: {:?}", e))?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let sp = spinner("Fetching stake information...");
+    let stakes: Vec<StakeInfo> = get_stake_info_for_coldkey(&client, &coldkey_account)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch stakes: {}", e))?
+        .into_iter()
+        .filter(|stake| netuid.is_none_or(|netuid| stake.netuid == netuid))
+        .collect();
+    sp.finish_and_clear();
+
+    let sp = spinner(&format!(
+        "Fetching emission for the last {} epochs...",
+        epochs
+    ));
+    let mut histories = Vec::new();
+    for stake in &stakes {
+        let history = get_stake_emission_history(
+            &client,
+            &coldkey_account,
+            &stake.hotkey,
+            stake.netuid,
+            epochs,
+        )
+        .await
+        .map_err(|e| {
+            anyhow::anyhow!("Failed to fetch emission on subnet {}: {}", stake.netuid, e)
+        })?;
+        histories.push(history);
+    }
+
+    let mut prices = BTreeMap::new();
+    for stake in &stakes {
+        if let std::collections::btree_map::Entry::Vacant(entry) = prices.entry(stake.netuid) {
+            let info = get_dynamic_info(&client, stake.netuid).await.map_err(|e| {
+                anyhow::anyhow!("Failed to fetch price for subnet {}: {}", stake.netuid, e)
+            })?;
+            entry.insert(Rao::from(info.price));
+        }
+    }
+    sp.finish_and_clear();
+
+    let report = EarningsReport::new(
+        wallet_name.to_string(),
+        coldkey_addr,
+        epochs,
+        &histories,
+        &prices,
     );
+    emit(cli, &report)
+}
+
+/// Show the childkey take of a parent hotkey
+async fn show_childkey_take(
+    wallet_name: Option<&str>,
+    hotkey: &str,
+    netuid: u16,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::validator::children::{get_childkey_take, ChildkeyTakeBounds};
+    use sp_core::crypto::AccountId32;
+    use std::str::FromStr;
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let hotkey_address = if is_valid_ss58(hotkey) {
+        hotkey.to_string()
+    } else {
+        let wallet_name = wallet_name.unwrap_or("default");
+        let wallet = Wallet::new(wallet_name, hotkey, None)
+            .map_err(|e| anyhow::anyhow!("Invalid wallet name '{}': {}", wallet_name, e))?;
+        let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
+        wallet
+            .hotkey_ss58(hotkey_password.as_deref())
+            .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?
+    };
+    let hotkey_account = AccountId32::from_str(&hotkey_address)
+        .map_err(|e| anyhow::anyhow!("Invalid hotkey address: {:?}", e))?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let sp = spinner("Fetching childkey take...");
+    let (take, bounds) = tokio::try_join!(
+        get_childkey_take(&client, &hotkey_account, netuid),
+        ChildkeyTakeBounds::fetch(&client),
+    )?;
+    sp.finish_and_clear();
+
+    let report = ChildkeyTakeReport {
+        hotkey: hotkey_address,
+        netuid,
+        take,
+        min_take: bounds.min,
+        max_take: bounds.max,
+    };
+    emit(cli, &report)
+}
+
+/// Set the childkey take of a parent hotkey
+async fn set_childkey_take(
+    wallet_name: Option<&str>,
+    hotkey_name: &str,
+    netuid: u16,
+    take: f64,
+    ledger: &LedgerArgs,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::utils::weights::{float_to_u16, u16_normalized_float};
+    use crate::validator::children::set_childkey_take as submit_childkey_take;
+    use sp_core::crypto::AccountId32;
+    use std::str::FromStr;
+
+    if !(0.0..=1.0).contains(&take) {
+        print_error("Take must be a fraction between 0 and 1 (e.g. 0.09 for 9%)");
+        return Err(anyhow::anyhow!("Invalid take: {}", take));
+    }
+    if wallet_name.is_none() && !ledger.ledger {
+        print_error("Setting the take needs --wallet or --ledger");
+        return Err(anyhow::anyhow!("No signer given"));
+    }
+    let take_u16 = float_to_u16(take);
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let (signer, coldkey_address, hotkey_address) =
+        unlock_stake_keys(wallet_name, hotkey_name, ledger, cli)?;
+    let hotkey_account = AccountId32::from_str(&hotkey_address)
+        .map_err(|e| anyhow::anyhow!("Invalid hotkey address: {:?}", e))?;
 
-    if stakes.is_empty() {
-        print_info("No stake found");
+    print_info(&format!(
+        "Setting childkey take: {:.2}% ({})",
+        u16_normalized_float(take_u16) * 100.0,
+        take_u16
+    ));
+    print_info(&format!("Coldkey: {}", coldkey_address));
+    print_info(&format!("Hotkey: {}", hotkey_address));
+    print_info(&format!("Subnet: {}", netuid));
+
+    if !confirm("Proceed with setting the childkey take?", cli.no_prompt) {
+        print_info("Cancelled");
         return Ok(());
     }
 
-    let mut table = create_table_with_headers(&["Hotkey", "Subnet", "Stake (TAO)"]);
-    let mut total_stake = crate::utils::balance_newtypes::Rao::ZERO;
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    if ledger.ledger {
+        print_info("Review and approve the transaction on your Ledger device");
+    }
+    let sp = spinner("Submitting childkey take transaction...");
+    let result = submit_childkey_take(
+        &client,
+        &signer,
+        &hotkey_account,
+        netuid,
+        take_u16,
+        ExtrinsicWait::Finalized,
+    )
+    .await;
+    sp.finish_and_clear();
+
+    match result {
+        Ok(tx_hash) => {
+            print_success("Childkey take set successfully!");
+            print_info(&format!("Transaction hash: {}", tx_hash));
+        }
+        Err(e) => {
+            print_error(&format!("Failed to set childkey take: {}", e));
+            return Err(anyhow::anyhow!("Setting childkey take failed: {}", e));
+        }
+    }
+
+    Ok(())
+}
+
+/// Childkey take of a parent hotkey, as u16 proportions
+#[derive(Debug, Serialize)]
+pub struct ChildkeyTakeReport {
+    pub hotkey: String,
+    pub netuid: u16,
+    pub take: u16,
+    pub min_take: u16,
+    pub max_take: u16,
+}
+
+impl Render for ChildkeyTakeReport {
+    fn render_table(&self) {
+        use crate::utils::weights::u16_normalized_float;
+
+        let percent = |take: u16| format!("{:.2}%", u16_normalized_float(take) * 100.0);
+        println!(
+            "\nChildkey take for {} on subnet {}: {}",
+            format_address(&self.hotkey),
+            self.netuid,
+            percent(self.take)
+        );
+        println!(
+            "Allowed range: {} to {}",
+            percent(self.min_take),
+            percent(self.max_take)
+        );
+    }
+}
+
+/// One stake position
+#[derive(Debug, Serialize)]
+pub struct StakeEntry {
+    pub hotkey: String,
+    pub netuid: u16,
+    /// Stake in the subnet's unit (alpha, or TAO on root), in RAO
+    pub stake_rao: u128,
+}
+
+/// Stake on one subnet valued at the subnet's price
+#[derive(Debug, Serialize)]
+pub struct SubnetStakeValue {
+    pub netuid: u16,
+    pub stake_rao: u128,
+    /// TAO per unit of the subnet's token, in RAO
+    pub price_rao: u128,
+    pub value_rao: u128,
+}
+
+/// Stake positions with per-subnet totals valued in TAO
+///
+/// Alpha from different subnets is not fungible, so entries are totalled per
+/// subnet and each total is converted at that subnet's price before summing.
+#[derive(Debug, Serialize)]
+pub struct StakeSummary {
+    pub stakes: Vec<StakeEntry>,
+    pub subnets: Vec<SubnetStakeValue>,
+    pub total_value_rao: u128,
+}
+
+impl StakeSummary {
+    /// Summarize stake entries, valuing each subnet at `prices` (RAO per unit)
+    fn new(stakes: &[StakeInfo], prices: &BTreeMap<u16, Rao>) -> Self {
+        let entries = stakes
+            .iter()
+            .map(|stake| StakeEntry {
+                hotkey: stake.hotkey.to_string(),
+                netuid: stake.netuid,
+                stake_rao: stake.stake.as_u128(),
+            })
+            .collect();
+
+        let mut subnets = Vec::new();
+        let mut total_value = Balance::ZERO_TAO;
+        for (netuid, stake) in stake_totals_by_netuid(stakes) {
+            let price = prices.get(&netuid).copied().unwrap_or(Rao::ZERO);
+            let value = stake.to_tao_value(price.as_tao());
+            total_value = total_value + value;
+            subnets.push(SubnetStakeValue {
+                netuid,
+                stake_rao: stake.as_rao(),
+                price_rao: price.as_u128(),
+                value_rao: value.as_rao(),
+            });
+        }
+
+        Self {
+            stakes: entries,
+            subnets,
+            total_value_rao: total_value.as_rao(),
+        }
+    }
+
+    /// Fetch the price of every staked subnet and summarize
+    async fn fetch(
+        client: &crate::chain::BittensorClient,
+        stakes: &[StakeInfo],
+    ) -> anyhow::Result<Self> {
+        use crate::queries::subnets::get_subnet_price;
+
+        let sp = spinner("Fetching subnet prices...");
+        let mut prices = BTreeMap::new();
+        for netuid in stake_totals_by_netuid(stakes).into_keys() {
+            let price = get_subnet_price(client, netuid).await.map_err(|e| {
+                anyhow::anyhow!("Failed to fetch price for subnet {}: {}", netuid, e)
+            })?;
+            prices.insert(netuid, price);
+        }
+        sp.finish_and_clear();
+
+        Ok(Self::new(stakes, &prices))
+    }
+
+    /// Print the per-entry and per-subnet tables
+    fn print_tables(&self) {
+        if self.stakes.is_empty() {
+            print_info("No stake found");
+            return;
+        }
+
+        let mut table = create_table_with_headers(&["Hotkey", "Subnet", "Stake"]);
+        for entry in &self.stakes {
+            let stake = Balance::from_rao_with_netuid(entry.stake_rao, entry.netuid);
+            table.add_row(vec![
+                format_address(&entry.hotkey),
+                entry.netuid.to_string(),
+                stake.to_string(),
+            ]);
+        }
+        println!("{table}");
+
+        let mut totals = create_table_with_headers(&["Subnet", "Stake", "Price", "Value"]);
+        for subnet in &self.subnets {
+            let stake = Balance::from_rao_with_netuid(subnet.stake_rao, subnet.netuid);
+            totals.add_row(vec![
+                subnet.netuid.to_string(),
+                stake.to_string(),
+                format_tao(subnet.price_rao),
+                format_tao(subnet.value_rao),
+            ]);
+        }
+        println!("{totals}");
+        println!("\nTotal value: {}", format_tao(self.total_value_rao));
+    }
+}
+
+/// Stake held by one wallet's coldkey
+#[derive(Debug, Serialize)]
+pub struct StakeReport {
+    pub wallet: String,
+    pub coldkey: String,
+    #[serde(flatten)]
+    pub summary: StakeSummary,
+}
 
-    for stake_info in &stakes {
+impl Render for StakeReport {
+    fn render_table(&self) {
+        println!(
+            "\nStake for wallet '{}' ({})",
+            self.wallet,
+            format_address(&self.coldkey)
+        );
+        self.summary.print_tables();
+    }
+}
+
+/// Emission earned by one stake, valued at the subnet's price
+#[derive(Debug, Serialize)]
+pub struct EarningsEntry {
+    pub hotkey: String,
+    pub netuid: u16,
+    /// Current stake in the subnet's unit, in RAO
+    pub stake_rao: u128,
+    /// Emission earned in the subnet's unit, in RAO
+    pub earned_rao: u128,
+    /// TAO per unit of the subnet's token, in RAO
+    pub price_rao: u128,
+    pub value_rao: u128,
+    /// Some epochs were estimated from the latest state
+    pub estimated: bool,
+}
+
+/// Emission earned by one wallet's stake over recent epochs
+#[derive(Debug, Serialize)]
+pub struct EarningsReport {
+    pub wallet: String,
+    pub coldkey: String,
+    pub epochs: u64,
+    pub entries: Vec<EarningsEntry>,
+    pub total_value_rao: u128,
+}
+
+impl EarningsReport {
+    /// Value each history at its subnet's price in `prices` (RAO per unit)
+    fn new(
+        wallet: String,
+        coldkey: String,
+        epochs: u64,
+        histories: &[StakeEmissionHistory],
+        prices: &BTreeMap<u16, Rao>,
+    ) -> Self {
+        let mut total_value = Balance::ZERO_TAO;
+        let entries = histories
+            .iter()
+            .map(|history| {
+                let price = prices.get(&history.netuid).copied().unwrap_or(Rao::ZERO);
+                let earned = history.total_alpha();
+                let value = Balance::from_rao_with_netuid(earned.as_u128(), history.netuid)
+                    .to_tao_value(price.as_tao());
+                total_value = total_value + value;
+                EarningsEntry {
+                    hotkey: history.hotkey.to_string(),
+                    netuid: history.netuid,
+                    stake_rao: history.stake.as_u128(),
+                    earned_rao: earned.as_u128(),
+                    price_rao: price.as_u128(),
+                    value_rao: value.as_rao(),
+                    estimated: history.is_estimated(),
+                }
+            })
+            .collect();
+
+        Self {
+            wallet,
+            coldkey,
+            epochs,
+            entries,
+            total_value_rao: total_value.as_rao(),
+        }
+    }
+}
+
+impl Render for EarningsReport {
+    fn render_table(&self) {
+        println!(
+            "\nEarnings over the last {} epochs for wallet '{}' ({})",
+            self.epochs,
+            self.wallet,
+            format_address(&self.coldkey)
+        );
+        if self.entries.is_empty() {
+            print_info("No stake found");
+            return;
+        }
+
+        let mark = |estimated: bool| if estimated { "*" } else { "" };
+        let mut table =
+            create_table_with_headers(&["Hotkey", "Subnet", "Stake", "Earned", "Value"]);
+        for entry in &self.entries {
+            let stake = Balance::from_rao_with_netuid(entry.stake_rao, entry.netuid);
+            let earned = Balance::from_rao_with_netuid(entry.earned_rao, entry.netuid);
+            table.add_row(vec![
+                format_address(&entry.hotkey),
+                entry.netuid.to_string(),
+                stake.to_string(),
+                format!("{}{}", earned, mark(entry.estimated)),
+                format!("{}{}", format_tao(entry.value_rao), mark(entry.estimated)),
+            ]);
+        }
+        let estimated = self.entries.iter().any(|entry| entry.estimated);
         table.add_row(vec![
-            format_address(&stake_info.hotkey.to_string()),
-            stake_info.netuid.to_string(),
-            format_tao(stake_info.stake.as_u128()),
+            "Total".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            format!("{}{}", format_tao(self.total_value_rao), mark(estimated)),
         ]);
-        total_stake = total_stake.saturating_add(stake_info.stake);
+        println!("{table}");
+
+        println!("\nEarnings are attributed from each hotkey's emission by stake share.");
+        if estimated {
+            print_warning(
+                "* Includes epochs whose state the node has pruned; \
+                 the latest epoch's values were used for them",
+            );
+        }
     }
+}
 
-    println!("{table}");
-    println!("\nTotal stake: {}", format_tao(total_stake.as_u128()));
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::crypto::AccountId32;
 
-    Ok(())
+    fn stake(netuid: u16, rao: u128) -> StakeInfo {
+        StakeInfo {
+            hotkey: AccountId32::new([1; 32]),
+            coldkey: AccountId32::new([2; 32]),
+            netuid,
+            stake: Rao::from(rao),
+        }
+    }
+
+    #[test]
+    fn test_stake_summary_values_each_subnet_at_its_price() {
+        let stakes = [
+            stake(0, 2_000_000_000),
+            stake(3, 1_000_000_000),
+            stake(3, 3_000_000_000),
+        ];
+        let prices = BTreeMap::from([
+            (0, Rao::from(1_000_000_000u128)),
+            (3, Rao::from(250_000_000u128)),
+        ]);
+
+        let summary = StakeSummary::new(&stakes, &prices);
+        assert_eq!(summary.stakes.len(), 3);
+        let subnets: Vec<(u16, u128, u128)> = summary
+            .subnets
+            .iter()
+            .map(|s| (s.netuid, s.stake_rao, s.value_rao))
+            .collect();
+        assert_eq!(
+            subnets,
+            vec![
+                (0, 2_000_000_000, 2_000_000_000),
+                (3, 4_000_000_000, 1_000_000_000)
+            ]
+        );
+        assert_eq!(summary.total_value_rao, 3_000_000_000);
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["subnets"][1]["price_rao"], 250_000_000);
+    }
+
+    #[test]
+    fn test_earnings_report_values_alpha_at_subnet_price() {
+        use crate::queries::stakes::EpochEmission;
+
+        let history = |netuid: u16, alpha: [u128; 2], estimated: bool| StakeEmissionHistory {
+            hotkey: AccountId32::new([1; 32]),
+            coldkey: AccountId32::new([2; 32]),
+            netuid,
+            stake: Rao::from(10_000_000_000u128),
+            epochs: alpha
+                .iter()
+                .enumerate()
+                .map(|(i, &alpha)| EpochEmission {
+                    epoch: 10 - i as u64,
+                    block: 1_000 - i as u64 * 361,
+                    alpha: Rao::from(alpha),
+                    estimated: estimated && i == 1,
+                })
+                .collect(),
+        };
+        let histories = [
+            history(0, [1_000_000_000, 1_000_000_000], false),
+            history(3, [2_000_000_000, 2_000_000_000], true),
+        ];
+        let prices = BTreeMap::from([
+            (0, Rao::from(1_000_000_000u128)),
+            (3, Rao::from(250_000_000u128)),
+        ]);
+
+        let report = EarningsReport::new("w".into(), "c".into(), 2, &histories, &prices);
+        let entries: Vec<(u128, u128, bool)> = report
+            .entries
+            .iter()
+            .map(|e| (e.earned_rao, e.value_rao, e.estimated))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (2_000_000_000, 2_000_000_000, false),
+                (4_000_000_000, 1_000_000_000, true)
+            ]
+        );
+        assert_eq!(report.total_value_rao, 3_000_000_000);
+    }
 }
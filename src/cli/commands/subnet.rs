@@ -1,12 +1,21 @@
 //! Subnet commands for viewing subnet information and registration.
 
+use crate::cli::output::{emit, OutputFormat, Render};
 use crate::cli::utils::{
-    confirm, create_table_with_headers, format_address, format_tao, keypair_to_signer, print_error,
-    print_info, print_success, print_warning, prompt_password_optional, resolve_endpoint, spinner,
+    confirm, connect_client, create_table_with_headers, format_address, format_tao,
+    keypair_to_signer, print_error, print_info, print_success, print_warning, progress_bar,
+    prompt_input, resolve_coldkey_password, resolve_endpoint, resolve_hotkey_password, spinner,
+    tao_to_rao,
 };
 use crate::cli::Cli;
+use crate::errors::BittensorError;
+use crate::export::{export_subnet_snapshot, ExportFormat};
+use crate::subtensor::pruning::{get_pruning_risk, PruningRisk, PruningThresholds, RiskLevel};
+use crate::types::SubnetInfo;
 use crate::wallet::Wallet;
 use clap::{Args, Subcommand};
+use serde::Serialize;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 /// Subnet command container
@@ -27,8 +36,24 @@ pub enum SubnetCommands {
         /// Subnet ID
         #[arg(short, long)]
         netuid: u16,
+        /// Also show how recently this UID set weights and its immunity
+        #[arg(short, long)]
+        uid: Option<u16>,
+    },
+
+    /// Show hyperparameters, pool state and recent registrations of a subnet
+    Info {
+        /// Subnet ID
+        #[arg(short, long)]
+        netuid: u16,
+        /// Print the result as JSON
+        #[arg(long)]
+        json: bool,
     },
 
+    /// Show network-wide totals: issuance, stake, subnets and emission
+    Stats,
+
     /// Show subnet metagraph
     Metagraph {
         /// Subnet ID
@@ -36,6 +61,39 @@ pub enum SubnetCommands {
         netuid: u16,
     },
 
+    /// Watch a subnet's metagraph and print changes every tempo
+    Watch {
+        /// Subnet ID
+        #[arg(short, long)]
+        netuid: u16,
+        /// Smallest stake change to report, in TAO
+        #[arg(long, default_value_t = 1.0)]
+        stake_threshold: f64,
+        /// Print each diff as a JSON line
+        #[arg(long)]
+        json: bool,
+        /// Also report the pruning risk of this UID
+        #[arg(short, long)]
+        uid: Option<u16>,
+    },
+
+    /// Export per-UID subnet state for offline analysis
+    Export {
+        /// Subnet ID
+        #[arg(short, long)]
+        netuid: u16,
+        /// Block to export (default: latest finalized; older blocks need an
+        /// archive node)
+        #[arg(short, long)]
+        block: Option<u64>,
+        /// Output file (default: stdout)
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// csv or jsonl (default: from the --out extension, else csv)
+        #[arg(short, long)]
+        format: Option<ExportFormat>,
+    },
+
     /// Register on a subnet
     Register {
         /// Wallet name
@@ -59,20 +117,160 @@ pub enum SubnetCommands {
         netuid: u16,
     },
 
+    /// Set an owner-settable subnet hyperparameter
+    SetHyperparam {
+        /// Wallet name (subnet owner coldkey)
+        #[arg(short, long)]
+        wallet: String,
+        /// Subnet ID
+        #[arg(short, long)]
+        netuid: u16,
+        /// Hyperparameter name (e.g. serving_rate_limit, min_burn)
+        #[arg(long)]
+        name: String,
+        /// New value (RAO for burn amounts, true/false for flags)
+        #[arg(long)]
+        value: String,
+    },
+
+    /// Transfer subnet ownership to another coldkey
+    TransferOwnership {
+        /// Wallet name (current subnet owner coldkey)
+        #[arg(short, long)]
+        wallet: String,
+        /// Subnet ID
+        #[arg(short, long)]
+        netuid: u16,
+        /// SS58 address of the new owner coldkey
+        #[arg(long)]
+        new_owner: String,
+        /// Subnet ID typed back to confirm the transfer (required with
+        /// --no-prompt)
+        #[arg(long)]
+        confirm_netuid: Option<u16>,
+    },
+
+    /// Publish a commitment (on-chain metadata) for a hotkey
+    Commit {
+        /// Wallet name
+        #[arg(short, long)]
+        wallet: String,
+        /// Hotkey name
+        #[arg(short = 'k', long, default_value = "default")]
+        hotkey: String,
+        /// Subnet ID
+        #[arg(short, long)]
+        netuid: u16,
+        /// Commitment text (UTF-8, up to 384 bytes)
+        #[arg(long)]
+        data: String,
+    },
+
+    /// Show the commitments published on a subnet
+    GetCommitments {
+        /// Subnet ID
+        #[arg(short, long)]
+        netuid: u16,
+    },
+
     /// Create a new subnet
     Create {
         /// Wallet name
         #[arg(short, long)]
         wallet: String,
+        /// Hotkey name
+        #[arg(short = 'k', long, default_value = "default")]
+        hotkey: String,
+        #[command(flatten)]
+        identity: SubnetIdentityArgs,
     },
 }
 
+/// Optional subnet identity set at subnet creation
+#[derive(Args, Clone, Default)]
+pub struct SubnetIdentityArgs {
+    /// Subnet name
+    #[arg(long)]
+    pub subnet_name: Option<String>,
+    /// GitHub repository URL
+    #[arg(long)]
+    pub github_repo: Option<String>,
+    /// Subnet contact information
+    #[arg(long)]
+    pub subnet_contact: Option<String>,
+    /// Subnet URL
+    #[arg(long)]
+    pub subnet_url: Option<String>,
+    /// Logo URL
+    #[arg(long)]
+    pub logo_url: Option<String>,
+    /// Discord server
+    #[arg(long)]
+    pub discord: Option<String>,
+    /// Description
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Additional information
+    #[arg(long)]
+    pub additional: Option<String>,
+}
+
+impl SubnetIdentityArgs {
+    /// Build a subnet identity if any identity field was given
+    fn to_identity(&self) -> Option<crate::types::SubnetIdentity> {
+        let fields = [
+            &self.subnet_name,
+            &self.github_repo,
+            &self.subnet_contact,
+            &self.subnet_url,
+            &self.logo_url,
+            &self.discord,
+            &self.description,
+            &self.additional,
+        ];
+        if fields.iter().all(|f| f.is_none()) {
+            return None;
+        }
+        let field = |f: &Option<String>| f.clone().unwrap_or_default();
+        Some(crate::types::SubnetIdentity {
+            subnet_name: field(&self.subnet_name),
+            github_repo: field(&self.github_repo),
+            subnet_contact: field(&self.subnet_contact),
+            subnet_url: field(&self.subnet_url),
+            logo_url: field(&self.logo_url),
+            discord: field(&self.discord),
+            description: field(&self.description),
+            additional: field(&self.additional),
+        })
+    }
+}
+
 /// Execute subnet commands
 pub async fn execute(cmd: SubnetCommand, cli: &Cli) -> anyhow::Result<()> {
     match cmd.command {
         SubnetCommands::List => list_subnets(cli).await,
-        SubnetCommands::Show { netuid } => show_subnet(netuid, cli).await,
+        SubnetCommands::Show { netuid, uid } => show_subnet(netuid, uid, cli).await,
+        SubnetCommands::Info { netuid, json } => {
+            let json = json || cli.output == OutputFormat::Json;
+            subnet_info(netuid, json, cli).await
+        }
+        SubnetCommands::Stats => network_stats(cli).await,
         SubnetCommands::Metagraph { netuid } => show_metagraph(netuid, cli).await,
+        SubnetCommands::Watch {
+            netuid,
+            stake_threshold,
+            json,
+            uid,
+        } => {
+            let json = json || cli.output == OutputFormat::Json;
+            watch_subnet(netuid, stake_threshold, json, uid, cli).await
+        }
+        SubnetCommands::Export {
+            netuid,
+            block,
+            out,
+            format,
+        } => export_subnet(netuid, block, out, format, cli).await,
         SubnetCommands::Register {
             wallet,
             hotkey,
@@ -80,19 +278,138 @@ pub async fn execute(cmd: SubnetCommand, cli: &Cli) -> anyhow::Result<()> {
             burned,
         } => register(&wallet, &hotkey, netuid, burned, cli).await,
         SubnetCommands::Hyperparams { netuid } => show_hyperparams(netuid, cli).await,
-        SubnetCommands::Create { wallet } => create_subnet(&wallet, cli).await,
+        SubnetCommands::SetHyperparam {
+            wallet,
+            netuid,
+            name,
+            value,
+        } => set_hyperparam(&wallet, netuid, &name, &value, cli).await,
+        SubnetCommands::TransferOwnership {
+            wallet,
+            netuid,
+            new_owner,
+            confirm_netuid,
+        } => transfer_ownership(&wallet, netuid, &new_owner, confirm_netuid, cli).await,
+        SubnetCommands::Commit {
+            wallet,
+            hotkey,
+            netuid,
+            data,
+        } => commit(&wallet, &hotkey, netuid, &data, cli).await,
+        SubnetCommands::GetCommitments { netuid } => get_commitments(netuid, cli).await,
+        SubnetCommands::Create {
+            wallet,
+            hotkey,
+            identity,
+        } => create_subnet(&wallet, &hotkey, &identity, cli).await,
+    }
+}
+
+/// All subnets on the network
+#[derive(Debug, Serialize)]
+pub struct SubnetList {
+    pub subnets: Vec<SubnetInfo>,
+}
+
+impl Render for SubnetList {
+    fn render_table(&self) {
+        if self.subnets.is_empty() {
+            print_info("No subnets found");
+            return;
+        }
+
+        let mut table = create_table_with_headers(&["NetUID", "Name", "Neurons", "Emission"]);
+        for info in &self.subnets {
+            table.add_row(vec![
+                info.netuid.to_string(),
+                info.name.clone().unwrap_or_else(|| "N/A".to_string()),
+                info.neuron_count.to_string(),
+                format_tao(info.emission.as_u128()),
+            ]);
+        }
+
+        println!("\n{table}");
+        println!("\nTotal subnets: {}", self.subnets.len());
+    }
+}
+
+/// One subnet's info and key parameters
+#[derive(Debug, Serialize)]
+pub struct SubnetDetails {
+    #[serde(flatten)]
+    pub info: SubnetInfo,
+    /// Blocks per epoch
+    pub tempo: u64,
+    pub difficulty: u64,
+    /// Blocks a new registration is protected from pruning
+    pub immunity_period: u64,
+    /// Weight-setting activity of the UID passed with `--uid`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub neuron_activity: Option<NeuronActivity>,
+}
+
+/// How recently a UID set weights, against the subnet's activity cutoff
+#[derive(Debug, Serialize)]
+pub struct NeuronActivity {
+    pub uid: u16,
+    pub last_update: u64,
+    pub blocks_since_last_update: u64,
+    pub activity_cutoff: u64,
+    pub block_at_registration: u64,
+    /// Blocks of immunity left, None once expired
+    pub immunity_remaining: Option<u64>,
+}
+
+impl Render for SubnetDetails {
+    fn render_table(&self) {
+        let info = &self.info;
+        println!("\nSubnet {}", info.netuid);
+        println!("═════════════════════════════════════════");
+        println!(
+            "Name:             {}",
+            info.name.as_deref().unwrap_or("N/A")
+        );
+        println!("Neurons:          {}", info.neuron_count);
+        println!("Emission:         {}", format_tao(info.emission.as_u128()));
+        println!(
+            "Total Stake:      {}",
+            format_tao(info.total_stake.as_u128())
+        );
+        println!("Tempo:            {} blocks", self.tempo);
+        println!("Difficulty:       {}", self.difficulty);
+        println!("Immunity Period:  {} blocks", self.immunity_period);
+        if let Some(activity) = &self.neuron_activity {
+            let line = format!(
+                "{:<18}last update {} blocks ago (cutoff {})",
+                format!("UID {}:", activity.uid),
+                activity.blocks_since_last_update,
+                activity.activity_cutoff
+            );
+            if activity.blocks_since_last_update >= activity.activity_cutoff {
+                print_warning(&line);
+            } else {
+                println!("{}", line);
+            }
+            let immunity = match activity.immunity_remaining {
+                Some(blocks) => format!("{} blocks left", blocks),
+                None => "expired".to_string(),
+            };
+            println!(
+                "{:<18}registered at block {}, immunity {}",
+                "", activity.block_at_registration, immunity
+            );
+        }
     }
 }
 
 /// List all subnets
 async fn list_subnets(cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
     use crate::queries::subnets::all_subnets;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -103,37 +420,17 @@ async fn list_subnets(cli: &Cli) -> anyhow::Result<()> {
         .map_err(|e| anyhow::anyhow!("Failed to fetch subnets: {}", e))?;
     sp.finish_and_clear();
 
-    if subnets.is_empty() {
-        print_info("No subnets found");
-        return Ok(());
-    }
-
-    let mut table = create_table_with_headers(&["NetUID", "Name", "Neurons", "Emission"]);
-
-    for info in &subnets {
-        table.add_row(vec![
-            info.netuid.to_string(),
-            info.name.clone().unwrap_or_else(|| "N/A".to_string()),
-            info.neuron_count.to_string(),
-            format_tao(info.emission.as_u128()),
-        ]);
-    }
-
-    println!("\n{table}");
-    println!("\nTotal subnets: {}", subnets.len());
-
-    Ok(())
+    emit(cli, &SubnetList { subnets })
 }
 
 /// Show detailed subnet information
-async fn show_subnet(netuid: u16, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
+async fn show_subnet(netuid: u16, uid: Option<u16>, cli: &Cli) -> anyhow::Result<()> {
     use crate::queries::subnets::{difficulty, immunity_period, subnet_info, tempo};
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -153,54 +450,321 @@ async fn show_subnet(netuid: u16, cli: &Cli) -> anyhow::Result<()> {
         .await
         .unwrap_or(Some(0))
         .unwrap_or(0);
+    let neuron_activity = match uid {
+        Some(uid) => Some(fetch_neuron_activity(&client, netuid, uid).await?),
+        None => None,
+    };
     sp.finish_and_clear();
 
-    match info {
-        Some(info) => {
-            println!("\nSubnet {}", netuid);
-            println!("═════════════════════════════════════════");
-            println!(
-                "Name:             {}",
-                info.name.unwrap_or_else(|| "N/A".to_string())
-            );
-            println!("Neurons:          {}", info.neuron_count);
-            println!("Emission:         {}", format_tao(info.emission.as_u128()));
-            println!(
-                "Total Stake:      {}",
-                format_tao(info.total_stake.as_u128())
-            );
-            println!("Tempo:            {} blocks", tempo_val);
-            println!("Difficulty:       {}", diff_val);
-            println!("Immunity Period:  {} blocks", immunity_val);
+    let Some(info) = info else {
+        print_error(&format!("Subnet {} not found", netuid));
+        return Err(anyhow::anyhow!("Subnet {} not found", netuid));
+    };
+
+    emit(
+        cli,
+        &SubnetDetails {
+            info,
+            tempo: tempo_val,
+            difficulty: diff_val,
+            immunity_period: immunity_val,
+            neuron_activity,
+        },
+    )
+}
+
+/// Last weight update of a UID and the subnet's activity cutoff
+async fn fetch_neuron_activity(
+    client: &crate::chain::BittensorClient,
+    netuid: u16,
+    uid: u16,
+) -> anyhow::Result<NeuronActivity> {
+    use crate::queries::metagraph_queries::get_last_update;
+    use crate::queries::{
+        get_activity_cutoff, get_block_at_registration, get_immunity_period,
+        immunity_blocks_remaining,
+    };
+
+    let (current_block, last_update, activity_cutoff, immunity_period) = tokio::try_join!(
+        async { client.block_number().await.map_err(BittensorError::from) },
+        get_last_update(client, netuid, uid),
+        async { Ok::<_, BittensorError>(get_activity_cutoff(client, netuid).await? as u64) },
+        async { Ok::<_, BittensorError>(get_immunity_period(client, netuid).await? as u64) },
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to fetch activity for UID {}: {}", uid, e))?;
+    let block_at_registration = get_block_at_registration(client, netuid, uid)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch registration of UID {}: {}", uid, e))?;
+
+    Ok(NeuronActivity {
+        uid,
+        last_update,
+        blocks_since_last_update: current_block.saturating_sub(last_update),
+        activity_cutoff,
+        block_at_registration,
+        immunity_remaining: immunity_blocks_remaining(
+            block_at_registration,
+            immunity_period,
+            current_block,
+        ),
+    })
+}
+
+/// Number of registrations listed by `subnet info`
+const RECENT_REGISTRATIONS: usize = 5;
+
+/// A recent registration on a subnet
+#[derive(Debug, Serialize)]
+pub struct RecentRegistration {
+    pub uid: u16,
+    pub hotkey: Option<String>,
+    pub block: u64,
+}
+
+/// Hyperparameters, pool state and registration activity of a subnet
+#[derive(Debug, Serialize)]
+pub struct SubnetOverview {
+    pub netuid: u16,
+    /// Identity name of the owner coldkey, if set
+    pub owner_identity: Option<String>,
+    /// TAO locked by the owner at registration, in RAO
+    pub locked: u128,
+    pub hyperparameters: crate::queries::SubnetHyperparameters,
+    pub dynamic_info: crate::types::DynamicInfo,
+    /// Current registration burn, in RAO
+    pub burn: u128,
+    pub neurons: u64,
+    pub max_neurons: u64,
+    /// Newest first
+    pub recent_registrations: Vec<RecentRegistration>,
+}
+
+impl Render for SubnetOverview {
+    fn render_table(&self) {
+        let hp = &self.hyperparameters;
+        let dynamic = &self.dynamic_info;
+        let alpha = |rao: u128| format!("{:.9} {}", rao as f64 / 1e9, dynamic.symbol);
+        let yes_no = |flag: bool| if flag { "yes" } else { "no" }.to_string();
+
+        println!("\nSubnet {}", self.netuid);
+        println!("═══════════════════════════════════════════════");
+
+        let mut table = create_table_with_headers(&["Field", "Value"]);
+        let rows = [
+            (
+                "Owner",
+                match &self.owner_identity {
+                    Some(name) => format!("{} ({})", name, dynamic.owner_coldkey),
+                    None => dynamic.owner_coldkey.clone(),
+                },
+            ),
+            ("Locked", format_tao(self.locked)),
+            (
+                "Neurons",
+                format!("{} / {}", self.neurons, self.max_neurons),
+            ),
+            ("Price", format!("{:.9} τ", dynamic.alpha_price_tao())),
+            ("TAO In", format_tao(dynamic.tao_in)),
+            ("Alpha In", alpha(dynamic.alpha_in)),
+            ("Alpha Out", alpha(dynamic.alpha_out)),
+            ("Emission", format_tao(dynamic.emission_value)),
+            ("Burn", format_tao(self.burn)),
+            ("Min Burn", format_tao(hp.min_burn as u128)),
+            ("Max Burn", format_tao(hp.max_burn as u128)),
+            ("Registration Allowed", yes_no(hp.registration_allowed)),
+            (
+                "PoW Registration Allowed",
+                yes_no(hp.pow_registration_allowed),
+            ),
+            ("Max Registrations/Block", hp.max_regs_per_block.to_string()),
+            (
+                "Target Registrations/Interval",
+                hp.target_regs_per_interval.to_string(),
+            ),
+            ("Tempo", format!("{} blocks", hp.tempo)),
+            ("Immunity Period", format!("{} blocks", hp.immunity_period)),
+            ("Activity Cutoff", format!("{} blocks", hp.activity_cutoff)),
+            ("Difficulty", hp.difficulty.to_string()),
+            ("Rho", hp.rho.to_string()),
+            ("Kappa", hp.kappa.to_string()),
+            ("Max Validators", hp.max_validators.to_string()),
+            ("Min Allowed Weights", hp.min_allowed_weights.to_string()),
+            (
+                "Max Weight Limit",
+                format!(
+                    "{:.4}",
+                    crate::utils::weights::u16_normalized_float(hp.max_weights_limit)
+                ),
+            ),
+            ("Weights Rate Limit", hp.weights_rate_limit.to_string()),
+            ("Weights Version", hp.weights_version.to_string()),
+            ("Serving Rate Limit", hp.serving_rate_limit.to_string()),
+            ("Bonds Moving Average", hp.bonds_moving_avg.to_string()),
+            ("Commit Reveal", yes_no(hp.commit_reveal_weights_enabled)),
+            (
+                "Commit Reveal Interval",
+                hp.commit_reveal_weights_interval.to_string(),
+            ),
+            ("Liquid Alpha", yes_no(hp.liquid_alpha_enabled)),
+        ];
+        for (field, value) in rows {
+            table.add_row(vec![field.to_string(), value]);
         }
-        None => {
-            print_error(&format!("Subnet {} not found", netuid));
-            return Err(anyhow::anyhow!("Subnet not found"));
+        println!("{table}");
+
+        if self.recent_registrations.is_empty() {
+            print_info("No registrations");
+            return;
         }
+        println!("\nRecent registrations");
+        let mut table = create_table_with_headers(&["UID", "Hotkey", "Block"]);
+        for registration in &self.recent_registrations {
+            table.add_row(vec![
+                registration.uid.to_string(),
+                registration
+                    .hotkey
+                    .as_deref()
+                    .map_or_else(|| "-".to_string(), format_address),
+                registration.block.to_string(),
+            ]);
+        }
+        println!("{table}");
+    }
+}
+
+/// Show hyperparameters, pool state and recent registrations of a subnet
+async fn subnet_info(netuid: u16, json: bool, cli: &Cli) -> anyhow::Result<()> {
+    use crate::queries::get_subnet_hyperparameters;
+    use crate::queries::identity::query_identity;
+    use crate::queries::neurons::{get_hotkey_for_uid, get_recent_registrations};
+    use crate::queries::subnets::{
+        get_dynamic_info, get_subnet_locked, get_subnet_owner, recycle, subnet_exists,
+    };
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    if !subnet_exists(&client, netuid).await? {
+        print_error(&format!("Subnet {} not found", netuid));
+        return Err(anyhow::anyhow!("Subnet {} not found", netuid));
     }
 
+    let sp = spinner(&format!("Fetching subnet {} info...", netuid));
+    let (hyperparameters, dynamic_info, burn, registrations, owner, locked) = tokio::try_join!(
+        get_subnet_hyperparameters(&client, netuid),
+        get_dynamic_info(&client, netuid),
+        recycle(&client, netuid),
+        get_recent_registrations(&client, netuid, RECENT_REGISTRATIONS),
+        get_subnet_owner(&client, netuid),
+        get_subnet_locked(&client, netuid),
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to fetch subnet info: {}", e))?;
+
+    // Identities are optional; a failed lookup leaves the name blank
+    let owner_identity = query_identity(&client, &owner)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|identity| identity.name().cloned())
+        .filter(|name| !name.is_empty());
+
+    let mut recent_registrations = Vec::with_capacity(registrations.len());
+    for (uid, block) in registrations {
+        let hotkey = get_hotkey_for_uid(&client, netuid, uid).await?;
+        recent_registrations.push(RecentRegistration {
+            uid,
+            hotkey: hotkey.map(|h| h.to_string()),
+            block,
+        });
+    }
+    sp.finish_and_clear();
+
+    let max_neurons = match hyperparameters.max_allowed_uids {
+        0 => dynamic_info.max_n,
+        max => max as u64,
+    };
+    let overview = SubnetOverview {
+        netuid,
+        owner_identity,
+        locked: locked.as_u128(),
+        neurons: dynamic_info.subnet_n,
+        max_neurons,
+        burn: burn.unwrap_or(dynamic_info.burn),
+        hyperparameters,
+        dynamic_info,
+        recent_registrations,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&overview)?);
+    } else {
+        overview.render_table();
+    }
     Ok(())
 }
 
-/// Show subnet metagraph
-async fn show_metagraph(netuid: u16, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
-    use crate::metagraph::sync_metagraph;
+impl Render for crate::queries::NetworkStats {
+    fn render_table(&self) {
+        let mut table = create_table_with_headers(&["Statistic", "Value"]);
+        table.add_row(vec!["Block".to_string(), self.block.to_string()]);
+        table.add_row(vec![
+            "Total Issuance".to_string(),
+            format_tao(self.total_issuance.as_u128()),
+        ]);
+        table.add_row(vec![
+            "Total Stake".to_string(),
+            format_tao(self.total_stake.as_u128()),
+        ]);
+        table.add_row(vec!["Subnets".to_string(), self.total_networks.to_string()]);
+        table.add_row(vec![
+            "Block Emission".to_string(),
+            format_tao(self.block_emission.as_u128()),
+        ]);
+        table.add_row(vec![
+            "TAO in Subnets".to_string(),
+            format_tao(self.subnet_tao.as_u128()),
+        ]);
+        println!("\nNetwork at block {:?}", self.block_hash);
+        println!("{table}");
+    }
+}
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+/// Show network-wide totals
+async fn network_stats(cli: &Cli) -> anyhow::Result<()> {
+    use crate::queries::get_network_stats;
+
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
 
-    let sp = spinner(&format!("Syncing metagraph for subnet {}...", netuid));
-    let metagraph = sync_metagraph(&client, netuid)
+    let sp = spinner("Fetching network stats...");
+    let stats = get_network_stats(&client).await;
+    sp.finish_and_clear();
+
+    emit(cli, &stats?)
+}
+
+/// Show subnet metagraph
+async fn show_metagraph(netuid: u16, cli: &Cli) -> anyhow::Result<()> {
+    let endpoint = resolve_endpoint(cli)?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to sync metagraph: {}", e))?;
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
 
+    let metagraph = sync_metagraph_with_progress(&client, netuid, false).await?;
+
     println!("\nMetagraph for Subnet {}", netuid);
     println!("═════════════════════════════════════════════════════════════════");
 
@@ -244,6 +808,285 @@ async fn show_metagraph(netuid: u16, cli: &Cli) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Sync a subnet's metagraph, showing a progress bar per sync stage, and read
+/// axons if `axons` is set
+async fn sync_metagraph_with_progress(
+    client: &crate::chain::BittensorClient,
+    netuid: u16,
+    axons: bool,
+) -> anyhow::Result<crate::metagraph::Metagraph> {
+    use crate::metagraph::{sync_metagraph_with_options, SyncOptions};
+
+    let pb = progress_bar(0, &format!("Syncing metagraph for subnet {}", netuid));
+    let bar = pb.clone();
+    let options =
+        SyncOptions::new()
+            .with_axons(axons)
+            .with_progress(move |stage, completed, total| {
+                bar.set_message(format!("Syncing {} for subnet {}", stage, netuid));
+                bar.set_length(total);
+                bar.set_position(completed);
+            });
+    let result = sync_metagraph_with_options(client, netuid, None, &options).await;
+    pb.finish_and_clear();
+    result.map_err(|e| anyhow::anyhow!("Failed to sync metagraph: {}", e))
+}
+
+/// Sync the metagraph every tempo and print what changed, with the pruning
+/// risk of `uid` if given
+async fn watch_subnet(
+    netuid: u16,
+    stake_threshold: f64,
+    json: bool,
+    uid: Option<u16>,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::utils::balance_newtypes::Rao;
+
+    let threshold = Rao::from(tao_to_rao(stake_threshold));
+    let endpoint = resolve_endpoint(cli)?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let mut previous = sync_metagraph_with_progress(&client, netuid, true).await?;
+
+    if !json {
+        print_info(&format!(
+            "Watching subnet {} from block {} ({} neurons), press Ctrl+C to stop",
+            netuid, previous.block, previous.n
+        ));
+        if let Some(risk) = watched_pruning_risk(&client, netuid, uid).await? {
+            print_pruning_risk(&risk);
+        }
+    }
+
+    // Errors are reported and retried on the next poll rather than ending the watch
+    loop {
+        tokio::time::sleep(std::time::Duration::from_secs(12)).await;
+        let current = match next_tempo_sync(&client, netuid, &previous).await {
+            Ok(Some(current)) => current,
+            Ok(None) => continue,
+            Err(e) => {
+                print_warning(&format!("{}; retrying", e));
+                continue;
+            }
+        };
+        let diff = previous.diff_with_stake_threshold(&current, threshold);
+        let risk = watched_pruning_risk(&client, netuid, uid)
+            .await
+            .unwrap_or_else(|e| {
+                print_warning(&e.to_string());
+                None
+            });
+
+        if json {
+            let mut line = serde_json::to_value(&diff)?;
+            if let Some(risk) = &risk {
+                line["pruning_risk"] = serde_json::to_value(risk)?;
+            }
+            println!("{}", line);
+        } else {
+            print_metagraph_diff(&diff);
+            if let Some(risk) = &risk {
+                print_pruning_risk(risk);
+            }
+        }
+        previous = current;
+    }
+}
+
+/// Sync the metagraph once the tempo after `previous` has passed
+///
+/// Runs the staged sync with axons, so endpoint changes show up in the
+/// diff. Returns None while the tempo is still running.
+async fn next_tempo_sync(
+    client: &crate::chain::BittensorClient,
+    netuid: u16,
+    previous: &crate::metagraph::Metagraph,
+) -> anyhow::Result<Option<crate::metagraph::Metagraph>> {
+    use crate::metagraph::{sync_metagraph_with_options, SyncOptions};
+    use crate::queries::subnets::tempo;
+
+    let tempo = tempo(client, netuid)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch tempo: {}", e))?
+        .unwrap_or(360);
+    let block = client
+        .block_number()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch block number: {}", e))?;
+    if block < previous.block + tempo + 1 {
+        return Ok(None);
+    }
+
+    let options = SyncOptions::new()
+        .with_max_retries_per_stage(2)
+        .with_axons(true);
+    sync_metagraph_with_options(client, netuid, Some(previous), &options)
+        .await
+        .map(Some)
+        .map_err(|e| anyhow::anyhow!("Failed to sync metagraph: {}", e))
+}
+
+/// Write a subnet snapshot to a file or stdout
+async fn export_subnet(
+    netuid: u16,
+    block: Option<u64>,
+    out: Option<PathBuf>,
+    format: Option<ExportFormat>,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    let format = format
+        .or_else(|| out.as_deref().map(ExportFormat::from_path))
+        .unwrap_or_default();
+    let endpoint = resolve_endpoint(cli)?;
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+
+    let Some(path) = out else {
+        let stdout = std::io::BufWriter::new(std::io::stdout());
+        export_subnet_snapshot(&client, netuid, block, format, stdout).await?;
+        return Ok(());
+    };
+
+    let file = std::fs::File::create(&path)
+        .map_err(|e| anyhow::anyhow!("Failed to create {}: {}", path.display(), e))?;
+    let sp = spinner(&format!("Exporting subnet {}...", netuid));
+    let result = export_subnet_snapshot(
+        &client,
+        netuid,
+        block,
+        format,
+        std::io::BufWriter::new(file),
+    )
+    .await;
+    sp.finish_and_clear();
+    let summary = result?;
+    print_success(&format!(
+        "Exported {} UIDs of subnet {} at block {} to {} ({})",
+        summary.rows,
+        netuid,
+        summary.block,
+        path.display(),
+        format
+    ));
+    Ok(())
+}
+
+/// Pruning risk of the watched UID, if any, with the default thresholds
+async fn watched_pruning_risk(
+    client: &crate::chain::BittensorClient,
+    netuid: u16,
+    uid: Option<u16>,
+) -> anyhow::Result<Option<PruningRisk>> {
+    let Some(uid) = uid else {
+        return Ok(None);
+    };
+    get_pruning_risk(client, netuid, uid, &PruningThresholds::default())
+        .await
+        .map(Some)
+        .map_err(|e| anyhow::anyhow!("Failed to evaluate pruning risk: {}", e))
+}
+
+/// Print where a watched UID stands in the pruning order
+fn print_pruning_risk(risk: &PruningRisk) {
+    let position = match risk.rank {
+        Some(rank) => format!("{} of {} to be pruned", rank + 1, risk.candidates),
+        None => format!("immune for {} blocks", risk.immunity_remaining.unwrap_or(0)),
+    };
+    let message = format!(
+        "UID {} pruning risk: {} (score {}, {}, {}/{} UIDs used)",
+        risk.uid, risk.risk, risk.pruning_score, position, risk.num_uids, risk.max_uids
+    );
+    if risk.risk == RiskLevel::High {
+        print_warning(&message);
+    } else {
+        print_info(&message);
+    }
+}
+
+/// Print a metagraph diff as tables
+fn print_metagraph_diff(diff: &crate::metagraph::MetagraphDiff) {
+    let endpoint = |addr: &Option<std::net::SocketAddr>| {
+        addr.map_or_else(|| "-".to_string(), |a| a.to_string())
+    };
+
+    println!(
+        "\nSubnet {}: blocks {} -> {}",
+        diff.netuid, diff.from_block, diff.to_block
+    );
+    if diff.is_empty() {
+        print_info("No changes");
+        return;
+    }
+
+    if !diff.deregistered.is_empty() || !diff.registered.is_empty() {
+        let mut table = create_table_with_headers(&["UID", "Change", "Hotkey", "Registered At"]);
+        for (change, entries) in [
+            ("deregistered", &diff.deregistered),
+            ("registered", &diff.registered),
+        ] {
+            for entry in entries {
+                table.add_row(vec![
+                    entry.uid.to_string(),
+                    change.to_string(),
+                    format_address(&entry.hotkey.to_string()),
+                    entry.block_at_registration.to_string(),
+                ]);
+            }
+        }
+        println!("{table}");
+    }
+
+    if !diff.axon_changes.is_empty() {
+        let mut table = create_table_with_headers(&["UID", "Hotkey", "Old Axon", "New Axon"]);
+        for change in &diff.axon_changes {
+            table.add_row(vec![
+                change.uid.to_string(),
+                format_address(&change.hotkey.to_string()),
+                endpoint(&change.old),
+                endpoint(&change.new),
+            ]);
+        }
+        println!("{table}");
+    }
+
+    if !diff.permit_changes.is_empty() {
+        let mut table = create_table_with_headers(&["UID", "Hotkey", "Validator Permit"]);
+        for change in &diff.permit_changes {
+            table.add_row(vec![
+                change.uid.to_string(),
+                format_address(&change.hotkey.to_string()),
+                if change.validator_permit {
+                    "gained"
+                } else {
+                    "lost"
+                }
+                .to_string(),
+            ]);
+        }
+        println!("{table}");
+    }
+
+    if !diff.stake_deltas.is_empty() {
+        let mut table = create_table_with_headers(&["UID", "Hotkey", "Old Stake", "New Stake"]);
+        for delta in &diff.stake_deltas {
+            table.add_row(vec![
+                delta.uid.to_string(),
+                format_address(&delta.hotkey.to_string()),
+                format_tao(delta.old.as_u128()),
+                format_tao(delta.new.as_u128()),
+            ]);
+        }
+        println!("{table}");
+    }
+}
+
 /// Register on a subnet
 async fn register(
     wallet_name: &str,
@@ -252,10 +1095,10 @@ async fn register(
     burned: bool,
     cli: &Cli,
 ) -> anyhow::Result<()> {
-    use crate::chain::{BittensorClient, ExtrinsicWait};
+    use crate::chain::ExtrinsicWait;
     use crate::validator::registration::{burned_register, register as pow_register};
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let wallet = match Wallet::new(wallet_name, hotkey_name, None) {
         Ok(w) => w,
@@ -269,13 +1112,13 @@ async fn register(
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
     let coldkey = wallet
         .coldkey_keypair(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
     let signer = keypair_to_signer(&coldkey);
 
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
     let hotkey = wallet
         .hotkey_keypair(hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
@@ -294,7 +1137,7 @@ async fn register(
     }
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -343,18 +1186,159 @@ async fn register(
     Ok(())
 }
 
+/// Publish a commitment from a hotkey
+async fn commit(
+    wallet_name: &str,
+    hotkey_name: &str,
+    netuid: u16,
+    data: &str,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::validator::commitments::{set_commitment, CommitmentData};
+
+    let data = match CommitmentData::try_from(data) {
+        Ok(data) => data,
+        Err(e) => {
+            print_error(&format!("{}", e));
+            return Err(e.into());
+        }
+    };
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let wallet = match Wallet::new(wallet_name, hotkey_name, None) {
+        Ok(w) => w,
+        Err(e) => {
+            print_error(&format!("Invalid wallet name '{}': {}", wallet_name, e));
+            return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+        }
+    };
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
+    let hotkey = wallet
+        .hotkey_keypair(hotkey_password.as_deref())
+        .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
+    let signer = keypair_to_signer(&hotkey);
+
+    print_info(&format!("Committing on subnet {}", netuid));
+    print_info(&format!("Hotkey: {}", hotkey.ss58_address()));
+    print_info(&format!(
+        "Data: {} bytes in {} field(s)",
+        data.to_bytes().len(),
+        data.fields().len()
+    ));
+
+    if !confirm("Publish commitment?", cli.no_prompt) {
+        print_info("Commitment cancelled");
+        return Ok(());
+    }
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let sp = spinner("Submitting commitment...");
+    let result = set_commitment(&client, &signer, netuid, data, ExtrinsicWait::Finalized).await;
+    sp.finish_and_clear();
+
+    match result {
+        Ok(tx_hash) => {
+            print_success("Commitment published!");
+            print_info(&format!("Transaction hash: {}", tx_hash));
+        }
+        Err(e) => {
+            print_error(&format!("Commitment failed: {}", e));
+            return Err(anyhow::anyhow!("Commitment failed: {}", e));
+        }
+    }
+
+    Ok(())
+}
+
+/// One hotkey's commitment
+#[derive(Debug, Serialize)]
+pub struct CommitmentEntry {
+    pub hotkey: String,
+    pub block: u64,
+    /// The payload as text, with invalid UTF-8 replaced
+    pub data: String,
+    pub data_hex: String,
+}
+
+/// Commitments published on a subnet
+#[derive(Debug, Serialize)]
+pub struct SubnetCommitments {
+    pub netuid: u16,
+    pub commitments: Vec<CommitmentEntry>,
+}
+
+impl Render for SubnetCommitments {
+    fn render_table(&self) {
+        if self.commitments.is_empty() {
+            print_info(&format!("No commitments on subnet {}", self.netuid));
+            return;
+        }
+
+        let mut table = create_table_with_headers(&["Hotkey", "Block", "Data"]);
+        for entry in &self.commitments {
+            table.add_row(vec![
+                format_address(&entry.hotkey),
+                entry.block.to_string(),
+                entry.data.clone(),
+            ]);
+        }
+        println!("\n{table}");
+    }
+}
+
+/// Show the commitments published on a subnet
+async fn get_commitments(netuid: u16, cli: &Cli) -> anyhow::Result<()> {
+    use crate::queries::commitments::get_all_commitments;
+
+    let endpoint = resolve_endpoint(cli)?;
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let sp = spinner("Fetching commitments...");
+    let commitments = get_all_commitments(&client, netuid).await;
+    sp.finish_and_clear();
+
+    let mut commitments: Vec<CommitmentEntry> = commitments?
+        .into_iter()
+        .map(|(hotkey, (block, data))| CommitmentEntry {
+            hotkey: hotkey.to_string(),
+            block,
+            data: String::from_utf8_lossy(&data).into_owned(),
+            data_hex: hex::encode(&data),
+        })
+        .collect();
+    commitments.sort_by_key(|c| std::cmp::Reverse(c.block));
+
+    emit(
+        cli,
+        &SubnetCommitments {
+            netuid,
+            commitments,
+        },
+    )
+}
+
 /// Show subnet hyperparameters
 async fn show_hyperparams(netuid: u16, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
     use crate::queries::subnets::{
         difficulty, immunity_period, max_weight_limit, min_allowed_weights, tempo,
         weights_rate_limit,
     };
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -414,11 +1398,21 @@ async fn show_hyperparams(netuid: u16, cli: &Cli) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Create a new subnet
-async fn create_subnet(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
+/// Set a subnet hyperparameter as the subnet owner
+async fn set_hyperparam(
+    wallet_name: &str,
+    netuid: u16,
+    name: &str,
+    value: &str,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::validator::subnet_admin::{ensure_subnet_owner, OwnerHyperparameter};
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let param = OwnerHyperparameter::from_str(name)?;
+    let new_value = param.parse_value(value)?;
+
+    let endpoint = resolve_endpoint(cli)?;
 
     let wallet = match Wallet::new(wallet_name, "default", None) {
         Ok(w) => w,
@@ -432,30 +1426,269 @@ async fn create_subnet(wallet_name: &str, cli: &Cli) -> anyhow::Result<()> {
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
     let coldkey = wallet
         .coldkey_keypair(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
+    let signer = keypair_to_signer(&coldkey);
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    if let Err(e) = ensure_subnet_owner(&client, &signer, netuid).await {
+        print_error(&e.to_string());
+        return Err(e.into());
+    }
+
+    let old_value = param.current(&client, netuid).await?;
+
+    print_info(&format!("Subnet: {}", netuid));
+    print_info(&format!("Hyperparameter: {}", param));
+    print_info(&format!("Current value: {}", old_value));
+    print_info(&format!("New value: {}", new_value));
+
+    if !confirm("Proceed with hyperparameter update?", cli.no_prompt) {
+        print_info("Update cancelled");
+        return Ok(());
+    }
+
+    let sp = spinner("Submitting hyperparameter update...");
+    let result = param
+        .set(
+            &client,
+            &signer,
+            netuid,
+            new_value,
+            ExtrinsicWait::Finalized,
+        )
+        .await;
+    sp.finish_and_clear();
+
+    match result {
+        Ok(tx_hash) => {
+            let updated = param.current(&client, netuid).await?;
+            print_success(&format!("{} updated: {} -> {}", param, old_value, updated));
+            print_info(&format!("Transaction hash: {}", tx_hash));
+        }
+        Err(e) => {
+            print_error(&format!("Hyperparameter update failed: {}", e));
+            return Err(anyhow::anyhow!("Hyperparameter update failed: {}", e));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the user typed back `netuid`, from `--confirm-netuid` or a prompt
+fn netuid_confirmed(netuid: u16, typed: Option<u16>, cli: &Cli) -> anyhow::Result<bool> {
+    match typed {
+        Some(typed) => Ok(typed == netuid),
+        None if cli.no_prompt => Err(anyhow::anyhow!(
+            "Confirmation required; pass --confirm-netuid {}",
+            netuid
+        )),
+        None => {
+            let typed = prompt_input(&format!("Type the subnet ID ({}) to confirm", netuid));
+            Ok(typed.trim() == netuid.to_string())
+        }
+    }
+}
+
+/// Transfer subnet ownership to another coldkey
+async fn transfer_ownership(
+    wallet_name: &str,
+    netuid: u16,
+    new_owner: &str,
+    confirm_netuid: Option<u16>,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::queries::subnets::{get_subnet_locked, get_subnet_owner};
+    use crate::validator::subnet_admin::{ensure_subnet_owner, swap_subnet_owner};
+    use sp_core::crypto::AccountId32;
+
+    let new_owner_account = AccountId32::from_str(new_owner)
+        .map_err(|_| anyhow::anyhow!("Invalid new owner address: {}", new_owner))?;
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let wallet = match Wallet::new(wallet_name, "default", None) {
+        Ok(w) => w,
+        Err(e) => {
+            print_error(&format!("Invalid wallet name '{}': {}", wallet_name, e));
+            return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+        }
+    };
+    if !wallet.coldkey_exists() {
+        print_error(&format!("Wallet '{}' not found", wallet_name));
+        return Err(anyhow::anyhow!("Wallet not found"));
+    }
+
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
+    let coldkey = wallet
+        .coldkey_keypair(coldkey_password.as_deref())
+        .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
+    let signer = keypair_to_signer(&coldkey);
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    if let Err(e) = ensure_subnet_owner(&client, &signer, netuid).await {
+        print_error(&e.to_string());
+        return Err(e.into());
+    }
+    let locked = get_subnet_locked(&client, netuid).await?;
+
+    print_warning(&format!(
+        "Subnet {} and its {} lock will be owned by {}",
+        netuid,
+        format_tao(locked.as_u128()),
+        new_owner
+    ));
+    print_warning("This cannot be undone from this wallet");
+    if !netuid_confirmed(netuid, confirm_netuid, cli)? {
+        print_error("Subnet ID did not match; transfer cancelled");
+        return Err(anyhow::anyhow!("Ownership transfer not confirmed"));
+    }
+
+    let sp = spinner("Submitting ownership transfer...");
+    let result = swap_subnet_owner(
+        &client,
+        &signer,
+        netuid,
+        &new_owner_account,
+        ExtrinsicWait::Finalized,
+    )
+    .await;
+    sp.finish_and_clear();
+
+    match result {
+        Ok(tx_hash) => {
+            let owner = get_subnet_owner(&client, netuid).await?;
+            if owner == new_owner_account {
+                print_success(&format!("Subnet {} is now owned by {}", netuid, owner));
+            } else {
+                print_warning(&format!(
+                    "Transfer submitted; subnet {} is still owned by {}",
+                    netuid, owner
+                ));
+            }
+            print_info(&format!("Transaction hash: {}", tx_hash));
+        }
+        Err(e) => {
+            print_error(&format!("Ownership transfer failed: {}", e));
+            return Err(anyhow::anyhow!("Ownership transfer failed: {}", e));
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a new subnet
+async fn create_subnet(
+    wallet_name: &str,
+    hotkey_name: &str,
+    identity_args: &SubnetIdentityArgs,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::validator::subnet_admin::{get_network_registration_cost, register_network};
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let wallet = match Wallet::new(wallet_name, hotkey_name, None) {
+        Ok(w) => w,
+        Err(e) => {
+            print_error(&format!("Invalid wallet name '{}': {}", wallet_name, e));
+            return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+        }
+    };
+    if !wallet.coldkey_exists() {
+        print_error(&format!("Wallet '{}' not found", wallet_name));
+        return Err(anyhow::anyhow!("Wallet not found"));
+    }
+
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
+    let coldkey = wallet
+        .coldkey_keypair(coldkey_password.as_deref())
+        .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
+    let signer = keypair_to_signer(&coldkey);
+
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
+    let hotkey = wallet
+        .hotkey_keypair(hotkey_password.as_deref())
+        .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
+    let hotkey_account = sp_core::crypto::AccountId32::from_str(hotkey.ss58_address())
+        .map_err(|e| anyhow::anyhow!("Invalid hotkey address: {:?}", e))?;
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let lock_cost = get_network_registration_cost(&client).await?;
+    let identity = identity_args.to_identity();
 
     print_info("Creating new subnet");
     print_info(&format!("Coldkey: {}", coldkey.ss58_address()));
-    print_warning("This will cost TAO to register a new subnet");
+    print_info(&format!("Hotkey: {}", hotkey.ss58_address()));
+    if let Some(identity) = &identity {
+        print_info(&format!("Subnet name: {}", identity.subnet_name));
+    }
+    print_warning(&format!(
+        "This will lock {} to register a new subnet",
+        format_tao(lock_cost.as_u128())
+    ));
 
     if !confirm("Proceed with subnet creation?", cli.no_prompt) {
         print_info("Subnet creation cancelled");
         return Ok(());
     }
 
-    let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let _client = BittensorClient::new(&endpoint)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    let sp = spinner("Registering subnet...");
+    let result = register_network(
+        &client,
+        &signer,
+        &hotkey_account,
+        identity.as_ref(),
+        ExtrinsicWait::Finalized,
+    )
+    .await;
     sp.finish_and_clear();
 
-    // Note: register_network is not currently available in the validator module
-    // This would require adding the extrinsic call for RegisterNetwork
-    print_warning("Subnet registration requires a specialized extrinsic call.");
-    print_info("Please use the Python btcli or submit the RegisterNetwork extrinsic directly.");
+    match result {
+        Ok(registration) => {
+            print_success(&format!("Subnet {} created!", registration.netuid));
+            print_info(&format!("Transaction hash: {}", registration.tx_hash));
+        }
+        Err(e) => {
+            print_error(&format!("Subnet creation failed: {}", e));
+            return Err(anyhow::anyhow!("Subnet creation failed: {}", e));
+        }
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn test_transfer_ownership_confirmation() {
+        let cli = Cli::parse_from(["btcli", "--no-prompt", "subnet", "list"]);
+        assert!(netuid_confirmed(3, Some(3), &cli).unwrap());
+        assert!(!netuid_confirmed(3, Some(4), &cli).unwrap());
+        // Never prompts under --no-prompt
+        let err = netuid_confirmed(3, None, &cli).unwrap_err();
+        assert!(err.to_string().contains("--confirm-netuid 3"));
+    }
+}
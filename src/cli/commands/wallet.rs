@@ -1,13 +1,18 @@
 //! Wallet commands for managing coldkeys and hotkeys.
 
+use crate::cli::output::{emit, Render};
 use crate::cli::utils::{
-    confirm, create_table_with_headers, format_address, format_tao, keypair_to_signer, print_error,
-    print_info, print_success, print_warning, prompt_password, prompt_password_optional,
-    resolve_endpoint, spinner, tao_to_rao,
+    check_password_file, confirm, connect_client, create_table_with_headers, format_address,
+    format_duration, format_tao, keypair_to_signer, ledger_signer, parse_address_any_prefix,
+    print_error, print_info, print_success, print_warning, prompt_input, prompt_password,
+    prompt_password_optional, resolve_coldkey_password, resolve_endpoint, resolve_hotkey_password,
+    spinner, stake_tao_value, tao_to_rao, LedgerArgs,
 };
 use crate::cli::Cli;
+use crate::validator::transfer::TransferMode;
 use crate::wallet::{Mnemonic, Wallet};
 use clap::{Args, Subcommand};
+use serde::Serialize;
 
 /// Wallet command container
 #[derive(Args, Clone)]
@@ -33,6 +38,9 @@ pub enum WalletCommands {
         /// Skip password for coldkey encryption
         #[arg(long)]
         no_password: bool,
+        /// Overwrite an existing keyfile without keeping a backup
+        #[arg(long)]
+        no_backup: bool,
     },
 
     /// Regenerate wallet from mnemonic phrase
@@ -46,6 +54,9 @@ pub enum WalletCommands {
         /// Skip password for encryption
         #[arg(long)]
         no_password: bool,
+        /// Overwrite an existing keyfile without keeping a backup
+        #[arg(long)]
+        no_backup: bool,
     },
 
     /// List all wallets
@@ -78,14 +89,29 @@ pub enum WalletCommands {
     /// Transfer TAO to another address
     Transfer {
         /// Source wallet name
-        #[arg(short, long)]
-        name: String,
+        #[arg(
+            short,
+            long,
+            required_unless_present = "ledger",
+            conflicts_with = "ledger"
+        )]
+        name: Option<String>,
         /// Destination address (SS58 format)
         #[arg(short, long)]
         dest: String,
         /// Amount in TAO
-        #[arg(short, long)]
-        amount: f64,
+        #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+        amount: Option<f64>,
+        /// Transfer the whole free balance (keeps the existential deposit
+        /// unless --allow-death is given)
+        #[arg(long)]
+        all: bool,
+        /// Allow the source account to drop below the existential deposit and
+        /// be reaped
+        #[arg(long)]
+        allow_death: bool,
+        #[command(flatten)]
+        ledger: LedgerArgs,
     },
 
     /// Create a new hotkey
@@ -102,6 +128,9 @@ pub enum WalletCommands {
         /// Skip password for hotkey encryption
         #[arg(long)]
         no_password: bool,
+        /// Overwrite an existing keyfile without keeping a backup
+        #[arg(long)]
+        no_backup: bool,
     },
 
     /// Create a new coldkey
@@ -115,6 +144,9 @@ pub enum WalletCommands {
         /// Skip password for encryption
         #[arg(long)]
         no_password: bool,
+        /// Overwrite an existing keyfile without keeping a backup
+        #[arg(long)]
+        no_backup: bool,
     },
 
     /// Regenerate coldkey from mnemonic
@@ -128,6 +160,9 @@ pub enum WalletCommands {
         /// Skip password for encryption
         #[arg(long)]
         no_password: bool,
+        /// Overwrite an existing keyfile without keeping a backup
+        #[arg(long)]
+        no_backup: bool,
     },
 
     /// Regenerate hotkey from mnemonic
@@ -138,12 +173,53 @@ pub enum WalletCommands {
         /// Hotkey name
         #[arg(short = 'k', long)]
         hotkey: String,
-        /// Mnemonic phrase
+        /// Mnemonic phrase (the coldkey mnemonic when --derivation-path is given)
         #[arg(long)]
         mnemonic: String,
+        /// Derivation path recorded in a derived hotkey keyfile (e.g. //default//0)
+        #[arg(long)]
+        derivation_path: Option<String>,
         /// Skip password for encryption
         #[arg(long)]
         no_password: bool,
+        /// Overwrite an existing keyfile without keeping a backup
+        #[arg(long)]
+        no_backup: bool,
+    },
+
+    /// Change or remove the password of a coldkey or hotkey
+    ChangePassword {
+        /// Wallet name
+        #[arg(short, long, default_value = "default")]
+        name: String,
+        /// Change the password of this hotkey instead of the coldkey
+        #[arg(short = 'k', long)]
+        hotkey: Option<String>,
+    },
+
+    /// Schedule a swap of a compromised coldkey to a new coldkey
+    SwapColdkey {
+        /// Wallet name (omit when signing with --ledger)
+        #[arg(short, long, required_unless_present = "ledger")]
+        name: Option<String>,
+        /// New coldkey address (SS58 format); prompted for if omitted
+        #[arg(long)]
+        new_coldkey: Option<String>,
+        /// New coldkey address again, required with --no-prompt
+        #[arg(long)]
+        confirm_new_coldkey: Option<String>,
+        #[command(flatten)]
+        ledger: LedgerArgs,
+    },
+
+    /// Show the coldkey swap scheduled for a wallet or address
+    SwapStatus {
+        /// Wallet name
+        #[arg(short, long, default_value = "default")]
+        name: String,
+        /// Coldkey address to check instead of a wallet
+        #[arg(long)]
+        address: Option<String>,
     },
 
     /// Show wallet addresses
@@ -165,41 +241,94 @@ pub async fn execute(cmd: WalletCommand, cli: &Cli) -> anyhow::Result<()> {
             hotkey,
             words,
             no_password,
-        } => create_wallet(&name, &hotkey, words, no_password, cli).await,
+            no_backup,
+        } => create_wallet(&name, &hotkey, words, no_password, no_backup, cli).await,
         WalletCommands::Regen {
             name,
             mnemonic,
             no_password,
-        } => regen_wallet(&name, &mnemonic, no_password, cli).await,
-        WalletCommands::List { path } => list_wallets(path.as_deref()).await,
+            no_backup,
+        } => regen_wallet(&name, &mnemonic, no_password, no_backup, cli).await,
+        WalletCommands::List { path } => list_wallets(path.as_deref(), cli).await,
         WalletCommands::Overview { name, all } => overview(name.as_deref(), all, cli).await,
         WalletCommands::Balance { name, all } => balance(name.as_deref(), all, cli).await,
-        WalletCommands::Transfer { name, dest, amount } => {
-            transfer(&name, &dest, amount, cli).await
+        WalletCommands::Transfer {
+            name,
+            dest,
+            amount,
+            all,
+            allow_death,
+            ledger,
+        } => {
+            let mode = match (all, allow_death) {
+                (true, allow_death) => TransferMode::All {
+                    keep_alive: !allow_death,
+                },
+                (false, true) => TransferMode::AllowDeath,
+                (false, false) => TransferMode::KeepAlive,
+            };
+            transfer(name.as_deref(), &dest, amount, mode, &ledger, cli).await
         }
         WalletCommands::NewHotkey {
             name,
             hotkey,
             words,
             no_password,
-        } => new_hotkey(&name, &hotkey, words, no_password).await,
+            no_backup,
+        } => new_hotkey(&name, &hotkey, words, no_password, no_backup, cli).await,
         WalletCommands::NewColdkey {
             name,
             words,
             no_password,
-        } => new_coldkey(&name, words, no_password).await,
+            no_backup,
+        } => new_coldkey(&name, words, no_password, no_backup, cli).await,
         WalletCommands::RegenColdkey {
             name,
             mnemonic,
             no_password,
-        } => regen_coldkey(&name, &mnemonic, no_password).await,
+            no_backup,
+        } => regen_coldkey(&name, &mnemonic, no_password, no_backup, cli).await,
         WalletCommands::RegenHotkey {
             name,
             hotkey,
             mnemonic,
+            derivation_path,
             no_password,
-        } => regen_hotkey(&name, &hotkey, &mnemonic, no_password).await,
-        WalletCommands::Address { name, hotkey } => show_address(&name, &hotkey).await,
+            no_backup,
+        } => {
+            regen_hotkey(
+                &name,
+                &hotkey,
+                &mnemonic,
+                derivation_path.as_deref(),
+                no_password,
+                no_backup,
+                cli,
+            )
+            .await
+        }
+        WalletCommands::ChangePassword { name, hotkey } => {
+            change_password(&name, hotkey.as_deref(), cli).await
+        }
+        WalletCommands::SwapColdkey {
+            name,
+            new_coldkey,
+            confirm_new_coldkey,
+            ledger,
+        } => {
+            swap_coldkey(
+                name.as_deref(),
+                new_coldkey,
+                confirm_new_coldkey,
+                &ledger,
+                cli,
+            )
+            .await
+        }
+        WalletCommands::SwapStatus { name, address } => {
+            swap_status(&name, address.as_deref(), cli).await
+        }
+        WalletCommands::Address { name, hotkey } => show_address(&name, &hotkey, cli).await,
     }
 }
 
@@ -209,6 +338,7 @@ async fn create_wallet(
     hotkey_name: &str,
     words: usize,
     no_password: bool,
+    no_backup: bool,
     cli: &Cli,
 ) -> anyhow::Result<()> {
     // Validate word count
@@ -219,19 +349,22 @@ async fn create_wallet(
 
     // Check if wallet already exists
     let mut wallet = match Wallet::new(name, hotkey_name, None) {
-        Ok(w) => w,
+        Ok(w) => w.with_backup_existing(!no_backup),
         Err(e) => {
             print_error(&format!("Invalid wallet name '{}': {}", name, e));
             return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
         }
     };
-    if wallet.coldkey_exists() {
-        print_warning(&format!("Wallet '{}' already exists", name));
-        if !confirm("Overwrite existing wallet?", cli.no_prompt) {
-            print_info("Aborted");
-            return Ok(());
-        }
-    }
+    let exists = wallet.coldkey_exists() || wallet.hotkey_exists();
+    let Some(overwrite) = confirm_overwrite(
+        exists,
+        &format!("Wallet '{}' already exists", name),
+        no_backup,
+        cli,
+    ) else {
+        print_info("Aborted");
+        return Ok(());
+    };
 
     // Generate mnemonics
     let coldkey_mnemonic = Mnemonic::generate_with_words(words)
@@ -244,8 +377,8 @@ async fn create_wallet(
     let coldkey_password = if no_password {
         None
     } else {
-        let pwd = prompt_password("Enter password for coldkey encryption");
-        let confirm = prompt_password("Confirm password");
+        let pwd = prompt_password("Enter password for coldkey encryption", cli.no_prompt)?;
+        let confirm = prompt_password("Confirm password", cli.no_prompt)?;
         if pwd != confirm {
             print_error("Passwords do not match");
             return Err(anyhow::anyhow!("Password mismatch"));
@@ -258,8 +391,8 @@ async fn create_wallet(
     wallet
         .create_coldkey(
             coldkey_password.as_deref(),
-            Some(coldkey_mnemonic.phrase()),
-            false,
+            Some(coldkey_mnemonic.phrase().expose_secret().as_str()),
+            overwrite,
         )
         .map_err(|e| anyhow::anyhow!("Failed to create coldkey: {}", e))?;
     sp.finish_and_clear();
@@ -267,9 +400,14 @@ async fn create_wallet(
     // Create hotkey (typically no password)
     let sp = spinner("Creating hotkey...");
     wallet
-        .create_hotkey(None, Some(hotkey_mnemonic.phrase()), false)
+        .create_hotkey(
+            None,
+            Some(hotkey_mnemonic.phrase().expose_secret().as_str()),
+            overwrite,
+        )
         .map_err(|e| anyhow::anyhow!("Failed to create hotkey: {}", e))?;
     sp.finish_and_clear();
+    report_backups(&wallet);
 
     // Display results
     print_success(&format!("Wallet '{}' created successfully!", name));
@@ -286,19 +424,52 @@ async fn create_wallet(
         .map_err(|e| anyhow::anyhow!("Failed to get hotkey address: {}", e))?;
 
     println!("Coldkey address: {}", coldkey_addr);
-    println!("Coldkey mnemonic: {}", coldkey_mnemonic.phrase());
+    println!(
+        "Coldkey mnemonic: {}",
+        coldkey_mnemonic.phrase().expose_secret()
+    );
     println!();
     println!("Hotkey address: {}", hotkey_addr);
-    println!("Hotkey mnemonic: {}", hotkey_mnemonic.phrase());
+    println!(
+        "Hotkey mnemonic: {}",
+        hotkey_mnemonic.phrase().expose_secret()
+    );
 
     Ok(())
 }
 
+/// Ask before replacing existing keys
+///
+/// Returns the `overwrite` flag to pass on, or `None` if the user declined.
+fn confirm_overwrite(exists: bool, message: &str, no_backup: bool, cli: &Cli) -> Option<bool> {
+    if !exists {
+        return Some(false);
+    }
+    print_warning(message);
+    let prompt = if no_backup {
+        "Overwrite without a backup? The existing keys will be lost"
+    } else {
+        "Overwrite? The existing keyfile will be backed up"
+    };
+    confirm(prompt, cli.no_prompt).then_some(true)
+}
+
+/// Print where replaced keyfiles were backed up
+fn report_backups(wallet: &Wallet) {
+    for backup in wallet.backups() {
+        print_info(&format!(
+            "Previous keyfile backed up to {}",
+            backup.display()
+        ));
+    }
+}
+
 /// Regenerate wallet from mnemonic
 async fn regen_wallet(
     name: &str,
     mnemonic: &str,
     no_password: bool,
+    no_backup: bool,
     cli: &Cli,
 ) -> anyhow::Result<()> {
     // Validate mnemonic
@@ -308,26 +479,28 @@ async fn regen_wallet(
     }
 
     let mut wallet = match Wallet::new(name, "default", None) {
-        Ok(w) => w,
+        Ok(w) => w.with_backup_existing(!no_backup),
         Err(e) => {
             print_error(&format!("Invalid wallet name '{}': {}", name, e));
             return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
         }
     };
 
-    if wallet.coldkey_exists() {
-        print_warning(&format!("Wallet '{}' already exists", name));
-        if !confirm("Overwrite existing wallet?", cli.no_prompt) {
-            print_info("Aborted");
-            return Ok(());
-        }
-    }
+    let Some(overwrite) = confirm_overwrite(
+        wallet.coldkey_exists(),
+        &format!("Wallet '{}' already exists", name),
+        no_backup,
+        cli,
+    ) else {
+        print_info("Aborted");
+        return Ok(());
+    };
 
     let password = if no_password {
         None
     } else {
-        let pwd = prompt_password("Enter password for encryption");
-        let confirm = prompt_password("Confirm password");
+        let pwd = prompt_password("Enter password for encryption", cli.no_prompt)?;
+        let confirm = prompt_password("Confirm password", cli.no_prompt)?;
         if pwd != confirm {
             print_error("Passwords do not match");
             return Err(anyhow::anyhow!("Password mismatch"));
@@ -337,9 +510,10 @@ async fn regen_wallet(
 
     let sp = spinner("Regenerating wallet from mnemonic...");
     wallet
-        .create_coldkey(password.as_deref(), Some(mnemonic), false)
+        .create_coldkey(password.as_deref(), Some(mnemonic), overwrite)
         .map_err(|e| anyhow::anyhow!("Failed to regenerate coldkey: {}", e))?;
     sp.finish_and_clear();
+    report_backups(&wallet);
 
     let addr = wallet
         .coldkey_ss58(password.as_deref())
@@ -351,8 +525,84 @@ async fn regen_wallet(
     Ok(())
 }
 
+/// Wallets found on disk
+#[derive(Debug, Serialize)]
+pub struct WalletListing {
+    pub wallets: Vec<WalletEntry>,
+}
+
+/// A wallet name and its directory
+#[derive(Debug, Serialize)]
+pub struct WalletEntry {
+    pub name: String,
+    pub path: String,
+}
+
+impl Render for WalletListing {
+    fn render_table(&self) {
+        if self.wallets.is_empty() {
+            print_info("No wallets found");
+            return;
+        }
+
+        let mut table = create_table_with_headers(&["Wallet", "Coldkey Path"]);
+        for wallet in &self.wallets {
+            table.add_row(vec![wallet.name.clone(), wallet.path.clone()]);
+        }
+        println!("{table}");
+    }
+}
+
+/// Free balance and stake value of one wallet's coldkey
+#[derive(Debug, Serialize)]
+pub struct WalletBalance {
+    pub wallet: String,
+    pub coldkey: String,
+    pub free_rao: u128,
+    /// Stake valued in TAO at each subnet's current price
+    pub staked_rao: u128,
+    pub total_rao: u128,
+}
+
+/// Balances for `wallet balance` and `wallet overview`
+#[derive(Debug, Serialize)]
+pub struct WalletBalances {
+    pub wallets: Vec<WalletBalance>,
+    /// Whether the table shows a total column (`wallet balance`)
+    #[serde(skip)]
+    with_total: bool,
+}
+
+impl Render for WalletBalances {
+    fn render_table(&self) {
+        if self.wallets.is_empty() {
+            print_info("No wallets found");
+            return;
+        }
+
+        let mut headers = vec!["Wallet", "Coldkey", "Free Balance", "Staked"];
+        if self.with_total {
+            headers.push("Total");
+        }
+        let mut table = create_table_with_headers(&headers);
+        for balance in &self.wallets {
+            let mut row = vec![
+                balance.wallet.clone(),
+                format_address(&balance.coldkey),
+                format_tao(balance.free_rao),
+                format_tao(balance.staked_rao),
+            ];
+            if self.with_total {
+                row.push(format_tao(balance.total_rao));
+            }
+            table.add_row(row);
+        }
+        println!("\n{table}");
+    }
+}
+
 /// List all wallets
-async fn list_wallets(path: Option<&str>) -> anyhow::Result<()> {
+async fn list_wallets(path: Option<&str>, cli: &Cli) -> anyhow::Result<()> {
     use crate::wallet::{list_wallets as get_wallet_names, wallet_path};
     use std::path::Path;
 
@@ -363,40 +613,21 @@ async fn list_wallets(path: Option<&str>) -> anyhow::Result<()> {
         get_wallet_names().map_err(|e| anyhow::anyhow!("Failed to list wallets: {}", e))?
     };
 
-    if wallet_names.is_empty() {
-        print_info("No wallets found");
-        return Ok(());
-    }
-
-    let mut table = create_table_with_headers(&["Wallet", "Coldkey Path"]);
-
-    for wallet_name in &wallet_names {
-        table.add_row(vec![
-            wallet_name.clone(),
-            wallet_path(wallet_name).display().to_string(),
-        ]);
-    }
-
-    println!("{table}");
-    Ok(())
+    let listing = WalletListing {
+        wallets: wallet_names
+            .into_iter()
+            .map(|name| WalletEntry {
+                path: wallet_path(&name).display().to_string(),
+                name,
+            })
+            .collect(),
+    };
+    emit(cli, &listing)
 }
 
 /// Show wallet overview
 async fn overview(name: Option<&str>, _all: bool, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
-    use crate::queries::balances::get_balance;
-    use crate::queries::stakes::get_stake_info_for_coldkey;
     use crate::wallet::list_wallets as get_wallet_names;
-    use sp_core::crypto::AccountId32;
-    use std::str::FromStr;
-
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
-
-    let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
-    sp.finish_and_clear();
 
     let wallets: Vec<Wallet> = if let Some(wallet_name) = name {
         match Wallet::new(wallet_name, "default", None) {
@@ -415,71 +646,16 @@ async fn overview(name: Option<&str>, _all: bool, cli: &Cli) -> anyhow::Result<(
             .collect()
     };
 
-    if wallets.is_empty() {
-        print_info("No wallets found");
-        return Ok(());
-    }
-
-    let mut table = create_table_with_headers(&["Wallet", "Coldkey", "Free Balance", "Staked"]);
-
-    for wallet in &wallets {
-        let password =
-            prompt_password_optional(&format!("Password for '{}' (enter to skip)", &wallet.name));
-
-        let coldkey_addr = match wallet.coldkey_ss58(password.as_deref()) {
-            Ok(addr) => addr,
-            Err(e) => {
-                print_warning(&format!("Could not unlock '{}': {}", &wallet.name, e));
-                continue;
-            }
-        };
-
-        let sp = spinner(&format!(
-            "Fetching balance for {}...",
-            format_address(&coldkey_addr)
-        ));
-
-        // Parse SS58 to AccountId32
-        let account = AccountId32::from_str(&coldkey_addr)
-            .map_err(|e| anyhow::anyhow!("Invalid SS58 address: {}", e))?;
-
-        let balance_result = get_balance(&client, &account).await;
-        let stake_result = get_stake_info_for_coldkey(&client, &account).await;
-        sp.finish_and_clear();
-
-        let free = balance_result.unwrap_or(crate::utils::balance_newtypes::Rao::ZERO);
-        let staked: crate::utils::balance_newtypes::Rao = stake_result
-            .map(|stakes| stakes.iter().map(|s| s.stake).sum())
-            .unwrap_or(crate::utils::balance_newtypes::Rao::ZERO);
-
-        table.add_row(vec![
-            wallet.name.to_string(),
-            format_address(&coldkey_addr),
-            format_tao(free.as_u128()),
-            format_tao(staked.as_u128()),
-        ]);
-    }
-
-    println!("\n{table}");
-    Ok(())
+    let balances = WalletBalances {
+        wallets: fetch_wallet_balances(&wallets, cli).await?,
+        with_total: false,
+    };
+    emit(cli, &balances)
 }
 
 /// Show wallet balance
 async fn balance(name: Option<&str>, all: bool, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
-    use crate::queries::balances::get_balance;
-    use crate::queries::stakes::get_stake_info_for_coldkey;
     use crate::wallet::list_wallets as get_wallet_names;
-    use sp_core::crypto::AccountId32;
-    use std::str::FromStr;
-
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
-
-    let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
-    sp.finish_and_clear();
 
     let wallets: Vec<Wallet> = if let Some(wallet_name) = name {
         match Wallet::new(wallet_name, "default", None) {
@@ -506,17 +682,48 @@ async fn balance(name: Option<&str>, all: bool, cli: &Cli) -> anyhow::Result<()>
         }
     };
 
+    let balances = WalletBalances {
+        wallets: fetch_wallet_balances(&wallets, cli).await?,
+        with_total: true,
+    };
+    emit(cli, &balances)
+}
+
+/// Fetch free balance and stake value for each wallet's coldkey
+///
+/// Wallets that cannot be unlocked are skipped with a warning.
+async fn fetch_wallet_balances(
+    wallets: &[Wallet],
+    cli: &Cli,
+) -> anyhow::Result<Vec<WalletBalance>> {
+    use crate::queries::balances::get_balance;
+    use crate::queries::stakes::get_stake_info_for_coldkey;
+    use sp_core::crypto::AccountId32;
+    use std::str::FromStr;
+
     if wallets.is_empty() {
-        print_info("No wallets found");
-        return Ok(());
+        return Ok(Vec::new());
     }
 
-    let mut table =
-        create_table_with_headers(&["Wallet", "Coldkey", "Free Balance", "Staked", "Total"]);
+    let endpoint = resolve_endpoint(cli)?;
 
-    for wallet in &wallets {
-        let password =
-            prompt_password_optional(&format!("Password for '{}' (enter to skip)", &wallet.name));
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    check_password_file(cli, wallets)?;
+    let mut balances = Vec::with_capacity(wallets.len());
+    for wallet in wallets {
+        let message = format!("Password for '{}'", &wallet.name);
+        let password = match resolve_coldkey_password(cli, wallet, &message) {
+            Ok(password) => password,
+            Err(e) => {
+                print_warning(&format!("Could not unlock '{}': {}", &wallet.name, e));
+                continue;
+            }
+        };
 
         let coldkey_addr = match wallet.coldkey_ss58(password.as_deref()) {
             Ok(addr) => addr,
@@ -539,95 +746,145 @@ async fn balance(name: Option<&str>, all: bool, cli: &Cli) -> anyhow::Result<()>
         let stake_result = get_stake_info_for_coldkey(&client, &account).await;
         sp.finish_and_clear();
 
-        let free = balance_result.unwrap_or(crate::utils::balance_newtypes::Rao::ZERO);
-        let staked: crate::utils::balance_newtypes::Rao = stake_result
-            .map(|stakes| stakes.iter().map(|s| s.stake).sum())
-            .unwrap_or(crate::utils::balance_newtypes::Rao::ZERO);
-        let total = free.as_u128() + staked.as_u128();
+        let free = balance_result
+            .unwrap_or(crate::utils::balance_newtypes::Rao::ZERO)
+            .as_u128();
+        // Stake is alpha per subnet; value each subnet at its own price
+        let staked = match stake_result {
+            Ok(stakes) => stake_tao_value(&client, &stakes).await.unwrap_or_else(|e| {
+                print_warning(&format!(
+                    "Could not value stake for '{}': {}",
+                    &wallet.name, e
+                ));
+                0
+            }),
+            Err(_) => 0,
+        };
 
-        table.add_row(vec![
-            wallet.name.to_string(),
-            format_address(&coldkey_addr),
-            format_tao(free.as_u128()),
-            format_tao(staked.as_u128()),
-            format_tao(total),
-        ]);
+        balances.push(WalletBalance {
+            wallet: wallet.name.to_string(),
+            coldkey: coldkey_addr,
+            free_rao: free,
+            staked_rao: staked,
+            total_rao: free.saturating_add(staked),
+        });
     }
-
-    println!("\n{table}");
-    Ok(())
+    Ok(balances)
 }
 
 /// Transfer TAO to another address
-async fn transfer(name: &str, dest: &str, amount: f64, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::{BittensorClient, ExtrinsicWait};
-    use crate::validator::transfer::transfer as do_transfer;
+async fn transfer(
+    name: Option<&str>,
+    dest: &str,
+    amount: Option<f64>,
+    mode: TransferMode,
+    ledger: &LedgerArgs,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::validator::transfer::{get_transfer_plan, transfer_with_mode};
     use sp_core::crypto::AccountId32;
     use std::str::FromStr;
 
-    if amount <= 0.0 {
+    if amount.is_some_and(|amount| amount <= 0.0) {
         print_error("Amount must be positive");
         return Err(anyhow::anyhow!("Invalid amount"));
     }
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
+
+    let (signer, source_address) = match name {
+        Some(name) => {
+            let wallet = match Wallet::new(name, "default", None) {
+                Ok(w) => w,
+                Err(e) => {
+                    print_error(&format!("Invalid wallet name '{}': {}", name, e));
+                    return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+                }
+            };
+            if !wallet.coldkey_exists() {
+                print_error(&format!("Wallet '{}' not found", name));
+                return Err(anyhow::anyhow!("Wallet not found"));
+            }
 
-    let wallet = match Wallet::new(name, "default", None) {
-        Ok(w) => w,
-        Err(e) => {
-            print_error(&format!("Invalid wallet name '{}': {}", name, e));
-            return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+            let password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
+            let coldkey = wallet
+                .coldkey_keypair(password.as_deref())
+                .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
+            (
+                keypair_to_signer(&coldkey),
+                coldkey.ss58_address().to_string(),
+            )
         }
+        None => ledger_signer(ledger)?,
     };
-    if !wallet.coldkey_exists() {
-        print_error(&format!("Wallet '{}' not found", name));
-        return Err(anyhow::anyhow!("Wallet not found"));
-    }
 
-    let password = prompt_password_optional("Coldkey password (enter if unencrypted)");
-    let coldkey = wallet
-        .coldkey_keypair(password.as_deref())
-        .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
-    let signer = keypair_to_signer(&coldkey);
+    let (dest_account, dest) = parse_address_any_prefix(dest, "destination")?;
+    let source_account = AccountId32::from_str(&source_address)
+        .map_err(|e| anyhow::anyhow!("Invalid source address: {:?}", e))?;
 
-    let dest_account = AccountId32::from_str(dest)
-        .map_err(|e| anyhow::anyhow!("Invalid destination address: {:?}", e))?;
+    let rao_amount =
+        amount.map(|amount| crate::utils::balance_newtypes::Rao::from(tao_to_rao(amount)));
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
 
-    let rao_amount = crate::utils::balance_newtypes::Rao::from(tao_to_rao(amount));
+    let plan = match get_transfer_plan(&client, &source_account, mode, rao_amount).await {
+        Ok(plan) => plan,
+        Err(e) => {
+            print_error(&format!("Cannot transfer: {}", e));
+            return Err(anyhow::anyhow!("Transfer rejected: {}", e));
+        }
+    };
 
     print_info(&format!(
-        "Transfer {} TAO ({} RAO)",
-        amount,
-        rao_amount.as_u128()
+        "Transfer {} ({} RAO) via {}",
+        plan.amount.format_tao(),
+        plan.amount.as_u128(),
+        mode.call_name()
     ));
-    print_info(&format!("From: {}", coldkey.ss58_address()));
+    print_info(&format!("From: {}", source_address));
     print_info(&format!("To: {}", dest));
+    print_info(&format!(
+        "Remaining free balance (before fees): {}",
+        plan.remaining.format_tao()
+    ));
+    if plan.reaps_sender {
+        println!();
+        print_warning(&format!(
+            "WARNING: THIS TRANSFER WILL REAP ACCOUNT {}. Its remaining balance ({}) is \
+             below the existential deposit and will be LOST, along with the account's nonce.",
+            source_address,
+            plan.remaining.format_tao()
+        ));
+        println!();
+    }
 
     if !confirm("Proceed with transfer?", cli.no_prompt) {
         print_info("Transfer cancelled");
         return Ok(());
     }
 
-    let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
-    sp.finish_and_clear();
-
+    if ledger.ledger {
+        print_info("Review and approve the transfer on your Ledger device");
+    }
     let sp = spinner("Submitting transfer...");
-    let result = do_transfer(
+    let result = transfer_with_mode(
         &client,
         &signer,
         &dest_account,
+        mode,
         rao_amount,
-        true,
         ExtrinsicWait::Finalized,
     )
     .await;
     sp.finish_and_clear();
 
     match result {
-        Ok(tx_hash) => {
+        Ok((tx_hash, _)) => {
             print_success("Transfer successful!");
             print_info(&format!("Transaction hash: {}", tx_hash));
         }
@@ -646,6 +903,8 @@ async fn new_hotkey(
     hotkey_name: &str,
     words: usize,
     no_password: bool,
+    no_backup: bool,
+    cli: &Cli,
 ) -> anyhow::Result<()> {
     if ![12, 15, 18, 21, 24].contains(&words) {
         print_error("Word count must be 12, 15, 18, 21, or 24");
@@ -653,7 +912,7 @@ async fn new_hotkey(
     }
 
     let mut wallet = match Wallet::new(name, hotkey_name, None) {
-        Ok(w) => w,
+        Ok(w) => w.with_backup_existing(!no_backup),
         Err(e) => {
             print_error(&format!("Invalid wallet name '{}': {}", name, e));
             return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
@@ -665,25 +924,38 @@ async fn new_hotkey(
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
+    let Some(overwrite) = confirm_overwrite(
+        wallet.hotkey_exists(),
+        &format!("Hotkey '{}' already exists", hotkey_name),
+        no_backup,
+        cli,
+    ) else {
+        print_info("Aborted");
+        return Ok(());
+    };
+
     let mnemonic = Mnemonic::generate_with_words(words)
         .map_err(|e| anyhow::anyhow!("Failed to generate mnemonic: {}", e))?;
 
     let password = if no_password {
         None
     } else {
-        let pwd = prompt_password("Enter password for hotkey encryption (enter for none)");
-        if pwd.is_empty() {
-            None
-        } else {
-            Some(pwd)
-        }
+        prompt_password_optional(
+            "Enter password for hotkey encryption (enter for none)",
+            cli.no_prompt,
+        )?
     };
 
     let sp = spinner("Creating hotkey...");
     wallet
-        .create_hotkey(password.as_deref(), Some(mnemonic.phrase()), false)
+        .create_hotkey(
+            password.as_deref(),
+            Some(mnemonic.phrase().expose_secret().as_str()),
+            overwrite,
+        )
         .map_err(|e| anyhow::anyhow!("Failed to create hotkey: {}", e))?;
     sp.finish_and_clear();
+    report_backups(&wallet);
 
     let addr = wallet
         .hotkey_ss58(password.as_deref())
@@ -696,34 +968,50 @@ async fn new_hotkey(
     println!();
     print_warning("Save this mnemonic phrase securely!");
     println!("Hotkey address: {}", addr);
-    println!("Hotkey mnemonic: {}", mnemonic.phrase());
+    println!("Hotkey mnemonic: {}", mnemonic.phrase().expose_secret());
 
     Ok(())
 }
 
 /// Create a new coldkey
-async fn new_coldkey(name: &str, words: usize, no_password: bool) -> anyhow::Result<()> {
+async fn new_coldkey(
+    name: &str,
+    words: usize,
+    no_password: bool,
+    no_backup: bool,
+    cli: &Cli,
+) -> anyhow::Result<()> {
     if ![12, 15, 18, 21, 24].contains(&words) {
         print_error("Word count must be 12, 15, 18, 21, or 24");
         return Err(anyhow::anyhow!("Invalid word count"));
     }
 
     let mut wallet = match Wallet::new(name, "default", None) {
-        Ok(w) => w,
+        Ok(w) => w.with_backup_existing(!no_backup),
         Err(e) => {
             print_error(&format!("Invalid wallet name '{}': {}", name, e));
             return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
         }
     };
 
+    let Some(overwrite) = confirm_overwrite(
+        wallet.coldkey_exists(),
+        &format!("Coldkey for wallet '{}' already exists", name),
+        no_backup,
+        cli,
+    ) else {
+        print_info("Aborted");
+        return Ok(());
+    };
+
     let mnemonic = Mnemonic::generate_with_words(words)
         .map_err(|e| anyhow::anyhow!("Failed to generate mnemonic: {}", e))?;
 
     let password = if no_password {
         None
     } else {
-        let pwd = prompt_password("Enter password for encryption");
-        let confirm = prompt_password("Confirm password");
+        let pwd = prompt_password("Enter password for encryption", cli.no_prompt)?;
+        let confirm = prompt_password("Confirm password", cli.no_prompt)?;
         if pwd != confirm {
             print_error("Passwords do not match");
             return Err(anyhow::anyhow!("Password mismatch"));
@@ -733,9 +1021,14 @@ async fn new_coldkey(name: &str, words: usize, no_password: bool) -> anyhow::Res
 
     let sp = spinner("Creating coldkey...");
     wallet
-        .create_coldkey(password.as_deref(), Some(mnemonic.phrase()), false)
+        .create_coldkey(
+            password.as_deref(),
+            Some(mnemonic.phrase().expose_secret().as_str()),
+            overwrite,
+        )
         .map_err(|e| anyhow::anyhow!("Failed to create coldkey: {}", e))?;
     sp.finish_and_clear();
+    report_backups(&wallet);
 
     let addr = wallet
         .coldkey_ss58(password.as_deref())
@@ -745,31 +1038,47 @@ async fn new_coldkey(name: &str, words: usize, no_password: bool) -> anyhow::Res
     println!();
     print_warning("IMPORTANT: Save this mnemonic phrase securely!");
     println!("Coldkey address: {}", addr);
-    println!("Coldkey mnemonic: {}", mnemonic.phrase());
+    println!("Coldkey mnemonic: {}", mnemonic.phrase().expose_secret());
 
     Ok(())
 }
 
 /// Regenerate coldkey from mnemonic
-async fn regen_coldkey(name: &str, mnemonic: &str, no_password: bool) -> anyhow::Result<()> {
+async fn regen_coldkey(
+    name: &str,
+    mnemonic: &str,
+    no_password: bool,
+    no_backup: bool,
+    cli: &Cli,
+) -> anyhow::Result<()> {
     if !Mnemonic::validate(mnemonic) {
         print_error("Invalid mnemonic phrase");
         return Err(anyhow::anyhow!("Invalid mnemonic"));
     }
 
     let mut wallet = match Wallet::new(name, "default", None) {
-        Ok(w) => w,
+        Ok(w) => w.with_backup_existing(!no_backup),
         Err(e) => {
             print_error(&format!("Invalid wallet name '{}': {}", name, e));
             return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
         }
     };
 
+    let Some(overwrite) = confirm_overwrite(
+        wallet.coldkey_exists(),
+        &format!("Coldkey for wallet '{}' already exists", name),
+        no_backup,
+        cli,
+    ) else {
+        print_info("Aborted");
+        return Ok(());
+    };
+
     let password = if no_password {
         None
     } else {
-        let pwd = prompt_password("Enter password for encryption");
-        let confirm = prompt_password("Confirm password");
+        let pwd = prompt_password("Enter password for encryption", cli.no_prompt)?;
+        let confirm = prompt_password("Confirm password", cli.no_prompt)?;
         if pwd != confirm {
             print_error("Passwords do not match");
             return Err(anyhow::anyhow!("Password mismatch"));
@@ -779,9 +1088,10 @@ async fn regen_coldkey(name: &str, mnemonic: &str, no_password: bool) -> anyhow:
 
     let sp = spinner("Regenerating coldkey...");
     wallet
-        .create_coldkey(password.as_deref(), Some(mnemonic), false)
+        .create_coldkey(password.as_deref(), Some(mnemonic), overwrite)
         .map_err(|e| anyhow::anyhow!("Failed to regenerate coldkey: {}", e))?;
     sp.finish_and_clear();
+    report_backups(&wallet);
 
     let addr = wallet
         .coldkey_ss58(password.as_deref())
@@ -793,12 +1103,15 @@ async fn regen_coldkey(name: &str, mnemonic: &str, no_password: bool) -> anyhow:
     Ok(())
 }
 
-/// Regenerate hotkey from mnemonic
+/// Regenerate hotkey from mnemonic, optionally derived with a path
 async fn regen_hotkey(
     name: &str,
     hotkey_name: &str,
     mnemonic: &str,
+    derivation_path: Option<&str>,
     no_password: bool,
+    no_backup: bool,
+    cli: &Cli,
 ) -> anyhow::Result<()> {
     if !Mnemonic::validate(mnemonic) {
         print_error("Invalid mnemonic phrase");
@@ -806,7 +1119,7 @@ async fn regen_hotkey(
     }
 
     let mut wallet = match Wallet::new(name, hotkey_name, None) {
-        Ok(w) => w,
+        Ok(w) => w.with_backup_existing(!no_backup),
         Err(e) => {
             print_error(&format!("Invalid wallet name '{}': {}", name, e));
             return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
@@ -818,22 +1131,37 @@ async fn regen_hotkey(
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
+    let Some(overwrite) = confirm_overwrite(
+        wallet.hotkey_exists(),
+        &format!("Hotkey '{}' already exists", hotkey_name),
+        no_backup,
+        cli,
+    ) else {
+        print_info("Aborted");
+        return Ok(());
+    };
+
     let password = if no_password {
         None
     } else {
-        let pwd = prompt_password("Enter password for encryption (enter for none)");
-        if pwd.is_empty() {
-            None
-        } else {
-            Some(pwd)
-        }
+        prompt_password_optional(
+            "Enter password for encryption (enter for none)",
+            cli.no_prompt,
+        )?
     };
 
     let sp = spinner("Regenerating hotkey...");
-    wallet
-        .create_hotkey(password.as_deref(), Some(mnemonic), false)
-        .map_err(|e| anyhow::anyhow!("Failed to regenerate hotkey: {}", e))?;
+    let result = match derivation_path {
+        Some(path) => {
+            wallet.create_hotkey_from_path(mnemonic, path, password.as_deref(), overwrite)
+        }
+        None => wallet
+            .create_hotkey(password.as_deref(), Some(mnemonic), overwrite)
+            .map(|_| ()),
+    };
+    result.map_err(|e| anyhow::anyhow!("Failed to regenerate hotkey: {}", e))?;
     sp.finish_and_clear();
+    report_backups(&wallet);
 
     let addr = wallet
         .hotkey_ss58(password.as_deref())
@@ -848,8 +1176,278 @@ async fn regen_hotkey(
     Ok(())
 }
 
+/// Change or remove the password of a coldkey or hotkey
+async fn change_password(name: &str, hotkey_name: Option<&str>, cli: &Cli) -> anyhow::Result<()> {
+    let mut wallet = match Wallet::new(name, hotkey_name.unwrap_or("default"), None) {
+        Ok(w) => w,
+        Err(e) => {
+            print_error(&format!("Invalid wallet name '{}': {}", name, e));
+            return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+        }
+    };
+
+    let (label, keyfile) = match hotkey_name {
+        Some(hotkey) => (format!("Hotkey '{}'", hotkey), wallet.hotkey()),
+        None => ("Coldkey".to_string(), wallet.coldkey()),
+    };
+    if !keyfile.exists() {
+        print_error(&format!("{} not found in wallet '{}'", label, name));
+        return Err(anyhow::anyhow!("Keyfile not found"));
+    }
+
+    // Check the current password before asking for a new one
+    let old_password = if keyfile.is_encrypted() {
+        let pwd = prompt_password(
+            &format!("Current password for {}", label.to_lowercase()),
+            cli.no_prompt,
+        )?;
+        if let Err(e) = keyfile.get_keypair(Some(&pwd)) {
+            print_error(&format!(
+                "Failed to decrypt {}: {}",
+                label.to_lowercase(),
+                e
+            ));
+            return Err(anyhow::anyhow!("Decryption failed"));
+        }
+        Some(pwd)
+    } else {
+        None
+    };
+
+    let new_password =
+        prompt_password_optional("New password (enter to remove encryption)", cli.no_prompt)?;
+    if let Some(ref pwd) = new_password {
+        if prompt_password("Confirm new password", cli.no_prompt)? != *pwd {
+            print_error("Passwords do not match");
+            return Err(anyhow::anyhow!("Passwords do not match"));
+        }
+    } else {
+        print_warning("The key will be stored unencrypted on disk");
+        if !confirm("Remove encryption?", cli.no_prompt) {
+            print_info("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let sp = spinner("Re-encrypting keyfile...");
+    let result = match hotkey_name {
+        Some(_) => wallet.change_hotkey_password(old_password.as_deref(), new_password.as_deref()),
+        None => wallet.change_coldkey_password(old_password.as_deref(), new_password.as_deref()),
+    };
+    sp.finish_and_clear();
+    result.map_err(|e| anyhow::anyhow!("Failed to change password: {}", e))?;
+
+    match new_password {
+        Some(_) => print_success(&format!("{} password changed", label)),
+        None => print_success(&format!("{} password removed", label)),
+    }
+
+    Ok(())
+}
+
+/// Schedule a coldkey swap, asking for the new address twice
+async fn swap_coldkey(
+    name: Option<&str>,
+    new_coldkey: Option<String>,
+    confirm_new_coldkey: Option<String>,
+    ledger: &LedgerArgs,
+    cli: &Cli,
+) -> anyhow::Result<()> {
+    use crate::chain::ExtrinsicWait;
+    use crate::validator::security::{
+        get_coldkey_swap_schedule_duration, get_scheduled_coldkey_swap, schedule_swap_coldkey,
+        validate_swap_destination,
+    };
+    use sp_core::crypto::AccountId32;
+    use std::str::FromStr;
+
+    // The address is entered twice so a typo cannot send everything to an
+    // account nobody controls
+    let address = |given: Option<String>, message: &str, flag: &str| match given {
+        Some(address) => Ok(address),
+        None if cli.no_prompt => Err(anyhow::anyhow!("{} is required with --no-prompt", flag)),
+        None => Ok(prompt_input(message)),
+    };
+    let first = address(new_coldkey, "New coldkey address", "--new-coldkey")?;
+    let second = address(
+        confirm_new_coldkey,
+        "Re-type the new coldkey address",
+        "--confirm-new-coldkey",
+    )?;
+    if first.trim() != second.trim() {
+        print_error("The two addresses do not match");
+        return Err(anyhow::anyhow!("New coldkey addresses do not match"));
+    }
+
+    let endpoint = resolve_endpoint(cli)?;
+
+    let (signer, source_address) = match name {
+        Some(name) => {
+            let wallet = match Wallet::new(name, "default", None) {
+                Ok(w) => w,
+                Err(e) => {
+                    print_error(&format!("Invalid wallet name '{}': {}", name, e));
+                    return Err(anyhow::anyhow!("Invalid wallet name: {}", e));
+                }
+            };
+            if !wallet.coldkey_exists() {
+                print_error(&format!("Wallet '{}' not found", name));
+                return Err(anyhow::anyhow!("Wallet not found"));
+            }
+
+            let password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
+            let coldkey = wallet
+                .coldkey_keypair(password.as_deref())
+                .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
+            (
+                keypair_to_signer(&coldkey),
+                coldkey.ss58_address().to_string(),
+            )
+        }
+        None => ledger_signer(ledger)?,
+    };
+
+    let current = AccountId32::from_str(&source_address)
+        .map_err(|e| anyhow::anyhow!("Invalid source address: {:?}", e))?;
+    let destination = match validate_swap_destination(&current, &first) {
+        Ok(destination) => destination,
+        Err(e) => {
+            print_error(&format!("{:#}", e));
+            return Err(e.into());
+        }
+    };
+
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    if let Some(existing) = get_scheduled_coldkey_swap(&client, &current).await? {
+        print_error(&format!(
+            "A swap to {} is already scheduled for block {}",
+            existing.new_coldkey, existing.execution_block
+        ));
+        return Err(anyhow::anyhow!("Coldkey swap already scheduled"));
+    }
+    let duration = get_coldkey_swap_schedule_duration(&client).await?;
+
+    print_info(&format!("Current coldkey: {}", source_address));
+    print_info(&format!("New coldkey:     {}", destination));
+    print_info(&format!(
+        "The swap executes {} blocks (about {}) after it is scheduled",
+        duration,
+        format_duration(duration * 12)
+    ));
+    println!();
+    print_warning(
+        "WARNING: ALL BALANCE, STAKE AND HOTKEYS OF THE CURRENT COLDKEY WILL MOVE TO THE NEW \
+         COLDKEY. Make sure you control the new coldkey; the swap cannot be undone.",
+    );
+    println!();
+
+    if !confirm("Schedule coldkey swap?", cli.no_prompt) {
+        print_info("Coldkey swap cancelled");
+        return Ok(());
+    }
+
+    if ledger.ledger {
+        print_info("Review and approve the swap on your Ledger device");
+    }
+    let sp = spinner("Scheduling coldkey swap...");
+    let result = schedule_swap_coldkey(
+        &client,
+        &signer,
+        &destination.to_string(),
+        ExtrinsicWait::Finalized,
+    )
+    .await;
+    sp.finish_and_clear();
+
+    match result {
+        Ok(tx_hash) => {
+            print_success("Coldkey swap scheduled");
+            print_info(&format!("Transaction hash: {}", tx_hash));
+            if let Some(swap) = get_scheduled_coldkey_swap(&client, &current).await? {
+                print_info(&format!("Executes at block {}", swap.execution_block));
+            }
+        }
+        Err(e) => {
+            print_error(&format!("Failed to schedule coldkey swap: {}", e));
+            return Err(anyhow::anyhow!("Coldkey swap failed: {}", e));
+        }
+    }
+
+    Ok(())
+}
+
+/// Coldkey swap scheduled for one coldkey
+#[derive(Debug, Serialize)]
+pub struct ColdkeySwapStatus {
+    pub coldkey: String,
+    pub current_block: u64,
+    pub scheduled: Option<crate::validator::security::SwapInfo>,
+}
+
+impl Render for ColdkeySwapStatus {
+    fn render_table(&self) {
+        let Some(swap) = &self.scheduled else {
+            print_info(&format!("No coldkey swap scheduled for {}", self.coldkey));
+            return;
+        };
+
+        let remaining = swap.execution_block.saturating_sub(self.current_block);
+        let mut table = create_table_with_headers(&["Coldkey", "New Coldkey", "Block", "ETA"]);
+        table.add_row(vec![
+            self.coldkey.clone(),
+            swap.new_coldkey.to_string(),
+            swap.execution_block.to_string(),
+            format_duration(remaining * 12),
+        ]);
+        println!("{table}");
+    }
+}
+
+/// Show the coldkey swap scheduled for a wallet or address
+async fn swap_status(name: &str, address: Option<&str>, cli: &Cli) -> anyhow::Result<()> {
+    use crate::validator::security::get_scheduled_coldkey_swap;
+    use sp_core::crypto::AccountId32;
+    use std::str::FromStr;
+
+    let coldkey = match address {
+        Some(address) => address.to_string(),
+        None => {
+            let wallet = Wallet::new(name, "default", None)
+                .map_err(|e| anyhow::anyhow!("Invalid wallet name: {}", e))?;
+            wallet
+                .coldkey_ss58(None)
+                .map_err(|e| anyhow::anyhow!("Failed to read coldkey of '{}': {}", name, e))?
+        }
+    };
+    let account =
+        AccountId32::from_str(&coldkey).map_err(|e| anyhow::anyhow!("Invalid address: {:?}", e))?;
+
+    let endpoint = resolve_endpoint(cli)?;
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let scheduled = get_scheduled_coldkey_swap(&client, &account).await?;
+    let current_block = client.block_number().await?;
+    emit(
+        cli,
+        &ColdkeySwapStatus {
+            coldkey,
+            current_block,
+            scheduled,
+        },
+    )
+}
+
 /// Show wallet addresses
-async fn show_address(name: &str, hotkey_name: &str) -> anyhow::Result<()> {
+async fn show_address(name: &str, hotkey_name: &str, cli: &Cli) -> anyhow::Result<()> {
     let wallet = match Wallet::new(name, hotkey_name, None) {
         Ok(w) => w,
         Err(e) => {
@@ -863,8 +1461,8 @@ async fn show_address(name: &str, hotkey_name: &str) -> anyhow::Result<()> {
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
 
     let coldkey_addr = wallet.coldkey_ss58(coldkey_password.as_deref());
     let hotkey_addr = wallet.hotkey_ss58(hotkey_password.as_deref());
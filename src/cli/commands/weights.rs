@@ -1,13 +1,18 @@
 //! Weight commands for commit-reveal and direct weight setting.
 
+use crate::cli::output::{emit, Render};
 use crate::cli::utils::{
-    confirm, create_table_with_headers, format_address, keypair_to_signer, parse_f64_list,
-    parse_u16_list, print_error, print_info, print_success, print_warning,
-    prompt_password_optional, resolve_endpoint, spinner,
+    confirm, connect_client, create_table_with_headers, format_address, keypair_to_signer,
+    parse_f64_list, parse_u16_list, print_error, print_info, print_success, print_warning,
+    resolve_coldkey_password, resolve_endpoint, resolve_hotkey_password, spinner,
 };
 use crate::cli::Cli;
+use crate::crv4::{Crv4CommitData, Crv4PersistedState};
 use crate::wallet::Wallet;
 use clap::{Args, Subcommand};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 /// Weights command container
 #[derive(Args, Clone)]
@@ -36,6 +41,9 @@ pub enum WeightsCommands {
         /// Weights (comma-separated, e.g., "0.3,0.5,0.2")
         #[arg(long)]
         weights: String,
+        /// Drop duplicate and out-of-range UIDs instead of failing
+        #[arg(long)]
+        lenient: bool,
     },
 
     /// Reveal committed weights
@@ -51,7 +59,7 @@ pub enum WeightsCommands {
         netuid: u16,
     },
 
-    /// Set weights directly (no commit-reveal)
+    /// Set weights, from the command line or a JSON/CSV file
     Set {
         /// Wallet name
         #[arg(short, long)]
@@ -63,11 +71,26 @@ pub enum WeightsCommands {
         #[arg(short, long)]
         netuid: u16,
         /// Target UIDs (comma-separated, e.g., "1,2,3")
-        #[arg(long)]
-        uids: String,
+        #[arg(long, required_unless_present = "file", requires = "weights")]
+        uids: Option<String>,
         /// Weights (comma-separated, e.g., "0.3,0.5,0.2")
+        #[arg(long, required_unless_present = "file", requires = "uids")]
+        weights: Option<String>,
+        /// Read `uid,weight` pairs from a JSON or CSV file
+        #[arg(long, conflicts_with_all = ["uids", "weights"])]
+        file: Option<PathBuf>,
+        /// Treat weights as raw u16 values (detected automatically otherwise)
         #[arg(long)]
-        weights: String,
+        raw: bool,
+        /// Drop duplicate and out-of-range UIDs instead of failing
+        #[arg(long)]
+        lenient: bool,
+        /// Commit through CRv4 if the subnet has commit-reveal enabled
+        #[arg(long)]
+        commit_reveal: bool,
+        /// Directory for crv4_commits.json when committing
+        #[arg(long, default_value = ".")]
+        data_dir: PathBuf,
     },
 
     /// Check current weight information
@@ -88,6 +111,9 @@ pub enum WeightsCommands {
         /// Hotkey name
         #[arg(short = 'k', long)]
         hotkey: String,
+        /// Directory holding the validator's crv4_commits.json
+        #[arg(long, default_value = ".")]
+        data_dir: PathBuf,
     },
 }
 
@@ -100,7 +126,8 @@ pub async fn execute(cmd: WeightsCommand, cli: &Cli) -> anyhow::Result<()> {
             netuid,
             uids,
             weights,
-        } => commit_weights(&wallet, &hotkey, netuid, &uids, &weights, cli).await,
+            lenient,
+        } => commit_weights(&wallet, &hotkey, netuid, &uids, &weights, lenient, cli).await,
         WeightsCommands::Reveal {
             wallet,
             hotkey,
@@ -112,11 +139,55 @@ pub async fn execute(cmd: WeightsCommand, cli: &Cli) -> anyhow::Result<()> {
             netuid,
             uids,
             weights,
-        } => set_weights(&wallet, &hotkey, netuid, &uids, &weights, cli).await,
+            file,
+            raw,
+            lenient,
+            commit_reveal,
+            data_dir,
+        } => {
+            let entries = match (&file, uids, weights) {
+                (Some(path), _, _) => read_weights_file(path)?,
+                (None, Some(uids), Some(weights)) => {
+                    let uids = parse_u16_list(&uids)?;
+                    let weights = parse_f64_list(&weights)?;
+                    if uids.len() != weights.len() {
+                        print_error("Number of UIDs must match number of weights");
+                        return Err(anyhow::anyhow!("Mismatched UIDs and weights"));
+                    }
+                    uids.into_iter().zip(weights).collect()
+                }
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "Either --file or --uids and --weights is required"
+                    ))
+                }
+            };
+            let raw = raw || looks_raw(&entries);
+            let (uids, values) = weights_to_u16(&entries, raw)?;
+            let mode = if commit_reveal {
+                SetMode::CommitReveal(data_dir)
+            } else {
+                SetMode::Direct
+            };
+            set_weights(&wallet, &hotkey, netuid, &uids, &values, lenient, mode, cli).await
+        }
         WeightsCommands::Info { netuid, hotkey } => {
             weight_info(netuid, hotkey.as_deref(), cli).await
         }
-        WeightsCommands::Pending { wallet, hotkey } => pending_commits(&wallet, &hotkey, cli).await,
+        WeightsCommands::Pending {
+            wallet,
+            hotkey,
+            data_dir,
+        } => pending_commits(&wallet, &hotkey, &data_dir, cli).await,
+    }
+}
+
+/// Validation mode for the `--lenient` flag
+fn weight_validation(lenient: bool) -> crate::utils::weights::WeightValidation {
+    if lenient {
+        crate::utils::weights::WeightValidation::Lenient
+    } else {
+        crate::utils::weights::WeightValidation::Strict
     }
 }
 
@@ -127,13 +198,14 @@ async fn commit_weights(
     netuid: u16,
     uids_str: &str,
     weights_str: &str,
+    lenient: bool,
     cli: &Cli,
 ) -> anyhow::Result<()> {
-    use crate::chain::{BittensorClient, ExtrinsicWait};
+    use crate::chain::ExtrinsicWait;
     use crate::utils::crypto::generate_subtensor_commit_hash;
-    use crate::validator::weights::commit_weights as raw_commit_weights;
+    use crate::validator::weights::{check_weight_inputs, commit_weights as raw_commit_weights};
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     // Parse UIDs and weights
     let uids = parse_u16_list(uids_str)?;
@@ -168,13 +240,13 @@ async fn commit_weights(
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
     let coldkey = wallet
         .coldkey_keypair(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
     let signer = keypair_to_signer(&coldkey);
 
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
     let hotkey = wallet
         .hotkey_keypair(hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
@@ -190,15 +262,27 @@ async fn commit_weights(
     }
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
 
+    let sp = spinner("Checking weights against subnet bounds...");
+    let checked = check_weight_inputs(
+        &client,
+        &keypair_to_signer(&hotkey),
+        netuid,
+        &uids,
+        &normalized,
+        weight_validation(lenient),
+    )
+    .await;
+    sp.finish_and_clear();
+    let (uids, normalized) = checked?;
+
     // Generate commit hash
     // Get the hotkey's public key bytes
-    use sp_core::Pair;
-    let hotkey_pubkey: [u8; 32] = hotkey.pair().public().0;
+    let hotkey_pubkey: [u8; 32] = *hotkey.public_key();
 
     // Generate random salt
     let salt: Vec<u16> = (0..8).map(|_| rand::random::<u16>()).collect();
@@ -247,9 +331,7 @@ async fn reveal_weights(
     netuid: u16,
     cli: &Cli,
 ) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
-
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let wallet = match Wallet::new(wallet_name, hotkey_name, None) {
         Ok(w) => w,
@@ -263,12 +345,12 @@ async fn reveal_weights(
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
     let _coldkey = wallet
         .coldkey_keypair(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
 
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
     let hotkey = wallet
         .hotkey_keypair(hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
@@ -282,7 +364,7 @@ async fn reveal_weights(
     }
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let _client = BittensorClient::new(&endpoint)
+    let _client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -298,41 +380,192 @@ async fn reveal_weights(
     Ok(())
 }
 
-/// Set weights directly (no commit-reveal)
+/// How `weights set` submits to the chain
+enum SetMode {
+    /// Always `set_weights`
+    Direct,
+    /// CRv4 commit when the subnet has commit-reveal enabled, persisting the
+    /// commit in the given data directory
+    CommitReveal(PathBuf),
+}
+
+/// Number of weights shown in the preview table
+const PREVIEW_WEIGHTS: usize = 20;
+
+/// Read `uid,weight` pairs from a JSON or CSV file
+///
+/// JSON is either an array of `{"uid": 0, "weight": 0.5}` objects or an
+/// object mapping UIDs to weights. CSV has `uid,weight` columns with an
+/// optional header row. Files without a `.json` or `.csv` extension are
+/// tried as JSON first.
+fn read_weights_file(path: &std::path::Path) -> anyhow::Result<Vec<(u16, f64)>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+    let entries = match extension.as_deref() {
+        Some("json") => parse_weights_json(&content),
+        Some("csv") => parse_weights_csv(&content),
+        _ => parse_weights_json(&content).or_else(|_| parse_weights_csv(&content)),
+    }
+    .map_err(|e| anyhow::anyhow!("Invalid weights file {}: {}", path.display(), e))?;
+
+    if entries.is_empty() {
+        return Err(anyhow::anyhow!("No weights in {}", path.display()));
+    }
+    Ok(entries)
+}
+
+/// One entry of a JSON weights array
+#[derive(Deserialize)]
+struct WeightEntry {
+    uid: u16,
+    weight: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum WeightsJson {
+    Entries(Vec<WeightEntry>),
+    Map(BTreeMap<String, f64>),
+}
+
+fn parse_weights_json(content: &str) -> anyhow::Result<Vec<(u16, f64)>> {
+    match serde_json::from_str::<WeightsJson>(content)? {
+        WeightsJson::Entries(entries) => {
+            Ok(entries.into_iter().map(|e| (e.uid, e.weight)).collect())
+        }
+        WeightsJson::Map(map) => map
+            .into_iter()
+            .map(|(uid, weight)| {
+                let uid = uid
+                    .trim()
+                    .parse::<u16>()
+                    .map_err(|e| anyhow::anyhow!("Invalid UID '{}': {}", uid, e))?;
+                Ok((uid, weight))
+            })
+            .collect(),
+    }
+}
+
+fn parse_weights_csv(content: &str) -> anyhow::Result<Vec<(u16, f64)>> {
+    let mut entries = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut columns = line.split(',').map(str::trim);
+        let (Some(uid), Some(weight), None) = (columns.next(), columns.next(), columns.next())
+        else {
+            return Err(anyhow::anyhow!(
+                "line {}: expected 2 columns (uid,weight)",
+                idx + 1
+            ));
+        };
+        if entries.is_empty() && uid.eq_ignore_ascii_case("uid") {
+            continue;
+        }
+        let uid = uid
+            .parse::<u16>()
+            .map_err(|e| anyhow::anyhow!("line {}: invalid UID '{}': {}", idx + 1, uid, e))?;
+        let weight = weight
+            .parse::<f64>()
+            .map_err(|e| anyhow::anyhow!("line {}: invalid weight '{}': {}", idx + 1, weight, e))?;
+        entries.push((uid, weight));
+    }
+    Ok(entries)
+}
+
+/// Whether weights look like raw u16 values rather than normalized floats:
+/// all whole numbers and at least one above 1
+fn looks_raw(entries: &[(u16, f64)]) -> bool {
+    entries.iter().all(|(_, w)| w.fract() == 0.0) && entries.iter().any(|(_, w)| *w > 1.0)
+}
+
+/// Convert weights to the u16 values submitted on chain
+///
+/// Raw values are used as given. Floats are scaled so the largest weight
+/// becomes `u16::MAX`, and entries that round to zero are dropped.
+fn weights_to_u16(entries: &[(u16, f64)], raw: bool) -> anyhow::Result<(Vec<u16>, Vec<u16>)> {
+    use crate::utils::weights::max_upscale_to_u16;
+
+    if let Some((uid, weight)) = entries.iter().find(|(_, w)| !w.is_finite() || *w < 0.0) {
+        return Err(anyhow::anyhow!("Invalid weight {} for UID {}", weight, uid));
+    }
+
+    let uids: Vec<u16> = entries.iter().map(|(uid, _)| *uid).collect();
+    if raw {
+        let values = entries
+            .iter()
+            .map(|(uid, w)| {
+                if w.fract() != 0.0 || *w > u16::MAX as f64 {
+                    return Err(anyhow::anyhow!(
+                        "Raw weight {} for UID {} is not a u16 value",
+                        w,
+                        uid
+                    ));
+                }
+                Ok(*w as u16)
+            })
+            .collect::<anyhow::Result<Vec<u16>>>()?;
+        return Ok((uids, values));
+    }
+
+    let weights: Vec<f64> = entries.iter().map(|(_, w)| *w).collect();
+    let (uids, values) = max_upscale_to_u16(&uids, &weights)?;
+    if values.is_empty() {
+        return Err(anyhow::anyhow!("Weights must sum to a positive value"));
+    }
+    Ok((uids, values))
+}
+
+/// Print the largest weights and the totals before confirmation
+fn preview_weights(uids: &[u16], values: &[u16]) {
+    let sum: u64 = values.iter().map(|v| *v as u64).sum();
+    let mut sorted: Vec<(u16, u16)> = uids.iter().copied().zip(values.iter().copied()).collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let mut table = create_table_with_headers(&["UID", "Weight (u16)", "Share"]);
+    for (uid, value) in sorted.iter().take(PREVIEW_WEIGHTS) {
+        let share = if sum == 0 {
+            0.0
+        } else {
+            *value as f64 / sum as f64 * 100.0
+        };
+        table.add_row(vec![
+            uid.to_string(),
+            value.to_string(),
+            format!("{:.2}%", share),
+        ]);
+    }
+    println!("{table}");
+    if sorted.len() > PREVIEW_WEIGHTS {
+        print_info(&format!("... and {} more", sorted.len() - PREVIEW_WEIGHTS));
+    }
+    print_info(&format!("{} weights, sum {} (u16)", sorted.len(), sum));
+}
+
+/// Set weights, committing through CRv4 when requested and enabled
+#[allow(clippy::too_many_arguments)]
 async fn set_weights(
     wallet_name: &str,
     hotkey_name: &str,
     netuid: u16,
-    uids_str: &str,
-    weights_str: &str,
+    uids: &[u16],
+    values: &[u16],
+    lenient: bool,
+    mode: SetMode,
     cli: &Cli,
 ) -> anyhow::Result<()> {
-    use crate::chain::{BittensorClient, ExtrinsicWait};
+    use crate::chain::ExtrinsicWait;
+    use crate::crv4::{prepare_and_commit_crv4_weights, Crv4StateManager};
+    use crate::queries::subnets::commit_reveal_enabled;
     use crate::validator::weights::set_weights as raw_set_weights;
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
-
-    // Parse UIDs and weights
-    let uids = parse_u16_list(uids_str)?;
-    let weight_values = parse_f64_list(weights_str)?;
-
-    if uids.len() != weight_values.len() {
-        print_error("Number of UIDs must match number of weights");
-        return Err(anyhow::anyhow!("Mismatched UIDs and weights"));
-    }
-
-    // Normalize and convert weights to u16 for the API
-    let sum: f64 = weight_values.iter().sum();
-    if sum <= 0.0 {
-        print_error("Weights must sum to a positive value");
-        return Err(anyhow::anyhow!("Invalid weights"));
-    }
-
-    let normalized_f32: Vec<f32> = weight_values.iter().map(|w| (*w / sum) as f32).collect();
-    let normalized_weights: Vec<u16> = normalized_f32
-        .iter()
-        .map(|w| crate::utils::weights::float_to_u16(*w as f64))
-        .collect();
+    let endpoint = resolve_endpoint(cli)?;
 
     let wallet = match Wallet::new(wallet_name, hotkey_name, None) {
         Ok(w) => w,
@@ -346,41 +579,101 @@ async fn set_weights(
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let coldkey_password = prompt_password_optional("Coldkey password (enter if unencrypted)");
+    let coldkey_password = resolve_coldkey_password(cli, &wallet, "Coldkey password")?;
     let _coldkey = wallet
         .coldkey_keypair(coldkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock coldkey: {}", e))?;
 
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
     let hotkey = wallet
         .hotkey_keypair(hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
     let signer = keypair_to_signer(&hotkey);
 
+    let sp = spinner(&format!("Connecting to {}...", endpoint));
+    let client = connect_client(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    sp.finish_and_clear();
+
+    let cr_enabled = commit_reveal_enabled(&client, netuid)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to check commit-reveal: {}", e))?;
+    let data_dir = match mode {
+        SetMode::CommitReveal(data_dir) if cr_enabled => Some(data_dir),
+        SetMode::CommitReveal(_) => {
+            print_info("Commit-reveal is disabled on this subnet; setting weights directly");
+            None
+        }
+        SetMode::Direct if cr_enabled => {
+            print_error(&format!(
+                "Subnet {} has commit-reveal enabled; pass --commit-reveal",
+                netuid
+            ));
+            return Err(anyhow::anyhow!("Commit-reveal required"));
+        }
+        SetMode::Direct => None,
+    };
+
     print_info(&format!("Setting weights for subnet {}", netuid));
     print_info(&format!("Hotkey: {}", hotkey.ss58_address()));
-    print_info(&format!("UIDs: {:?}", uids));
-    print_info(&format!("Weights (normalized): {:?}", normalized_weights));
+    preview_weights(uids, values);
 
-    if !confirm("Proceed with setting weights?", cli.no_prompt) {
+    let prompt = if data_dir.is_some() {
+        "Proceed with CRv4 weight commit?"
+    } else {
+        "Proceed with setting weights?"
+    };
+    if !confirm(prompt, cli.no_prompt) {
         print_info("Weight setting cancelled");
         return Ok(());
     }
 
-    let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
-    sp.finish_and_clear();
+    if let Some(data_dir) = data_dir {
+        let sp = spinner("Submitting CRv4 weight commit...");
+        let result = prepare_and_commit_crv4_weights(
+            &client,
+            &signer,
+            netuid,
+            uids,
+            values,
+            None,
+            weight_validation(lenient),
+            ExtrinsicWait::Finalized,
+        )
+        .await;
+        sp.finish_and_clear();
+
+        let commit = match result {
+            Ok(commit) => commit,
+            Err(e) => {
+                print_error(&format!("Failed to commit weights: {}", e));
+                return Err(anyhow::anyhow!("Weight commit failed: {}", e));
+            }
+        };
+        print_success("Weights committed successfully!");
+        print_info(&format!("Transaction hash: {}", commit.tx_hash));
+        print_info(&format!(
+            "Reveal round: {} (revealed automatically by the chain)",
+            commit.reveal_round
+        ));
+
+        let mut state = Crv4StateManager::new(Some(data_dir.clone()));
+        state.add_and_save(commit).map_err(|e| {
+            anyhow::anyhow!("Failed to save commit to {}: {}", data_dir.display(), e)
+        })?;
+        return Ok(());
+    }
 
     let sp = spinner("Submitting weights...");
     let result = raw_set_weights(
         &client,
         &signer,
         netuid,
-        &uids,
-        &normalized_weights,
+        uids,
+        values,
         0,
+        weight_validation(lenient),
         ExtrinsicWait::Finalized,
     )
     .await;
@@ -402,13 +695,12 @@ async fn set_weights(
 
 /// Show weight-related information for a subnet
 async fn weight_info(netuid: u16, _hotkey: Option<&str>, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
     use crate::queries::subnets::{commit_reveal_enabled, tempo, weights_rate_limit};
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+    let endpoint = resolve_endpoint(cli)?;
 
     let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let client = BittensorClient::new(&endpoint)
+    let client = connect_client(&endpoint)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
     sp.finish_and_clear();
@@ -444,12 +736,59 @@ async fn weight_info(netuid: u16, _hotkey: Option<&str>, cli: &Cli) -> anyhow::R
     Ok(())
 }
 
-/// Show pending weight commits
-async fn pending_commits(wallet_name: &str, hotkey_name: &str, cli: &Cli) -> anyhow::Result<()> {
-    use crate::chain::BittensorClient;
+/// CRv4 commits a validator has persisted but not yet seen revealed
+#[derive(Debug, Serialize)]
+pub struct PendingCommits {
+    pub hotkey: String,
+    pub commits: Vec<Crv4CommitData>,
+}
 
-    let endpoint = resolve_endpoint(&cli.network, cli.endpoint.as_deref());
+impl Render for PendingCommits {
+    fn render_table(&self) {
+        println!("\nPending Commits for {}", format_address(&self.hotkey));
+        println!("═══════════════════════════════════════════════");
+
+        if self.commits.is_empty() {
+            print_info("No pending commits");
+            return;
+        }
+
+        let mut table = create_table_with_headers(&[
+            "NetUID",
+            "Mechanism",
+            "Epoch",
+            "Reveal Round",
+            "UIDs",
+            "Committed At",
+            "Tx Hash",
+        ]);
+        for commit in &self.commits {
+            table.add_row(vec![
+                commit.netuid.to_string(),
+                commit
+                    .mechanism_id
+                    .map_or_else(|| "main".to_string(), |id| id.to_string()),
+                commit.epoch.to_string(),
+                commit.reveal_round.to_string(),
+                commit.uids.len().to_string(),
+                commit.committed_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                format_address(&commit.tx_hash),
+            ]);
+        }
+        println!("{table}");
+    }
+}
 
+/// Show pending weight commits
+///
+/// The chain only stores commit hashes, so this reads the CRv4 state a
+/// validator persists in `<data_dir>/crv4_commits.json`.
+async fn pending_commits(
+    wallet_name: &str,
+    hotkey_name: &str,
+    data_dir: &std::path::Path,
+    cli: &Cli,
+) -> anyhow::Result<()> {
     let wallet = match Wallet::new(wallet_name, hotkey_name, None) {
         Ok(w) => w,
         Err(e) => {
@@ -462,27 +801,72 @@ async fn pending_commits(wallet_name: &str, hotkey_name: &str, cli: &Cli) -> any
         return Err(anyhow::anyhow!("Wallet not found"));
     }
 
-    let hotkey_password = prompt_password_optional("Hotkey password (enter if unencrypted)");
+    let hotkey_password = resolve_hotkey_password(cli, &wallet, "Hotkey password")?;
     let hotkey = wallet
         .hotkey_keypair(hotkey_password.as_deref())
         .map_err(|e| anyhow::anyhow!("Failed to unlock hotkey: {}", e))?;
 
-    let sp = spinner(&format!("Connecting to {}...", endpoint));
-    let _client = BittensorClient::new(&endpoint)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
-    sp.finish_and_clear();
+    let state = Crv4PersistedState::load(&data_dir.join("crv4_commits.json"));
+    let mut commits: Vec<Crv4CommitData> = state
+        .pending_commits
+        .into_values()
+        .filter(|commit| commit.hotkey.as_slice() == hotkey.public_key().as_slice())
+        .collect();
+    commits.sort_by_key(|commit| (commit.netuid, commit.mechanism_id));
+
+    emit(
+        cli,
+        &PendingCommits {
+            hotkey: hotkey.ss58_address().to_string(),
+            commits,
+        },
+    )
+}
 
-    println!(
-        "\nPending Commits for {}",
-        format_address(hotkey.ss58_address())
-    );
-    println!("═══════════════════════════════════════════════");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Note: Pending commits are typically stored locally by the application
-    // since the chain only stores the hash. Display any local state if available.
-    print_info("Pending commit tracking requires local state management.");
-    print_info("Use the Subtensor high-level API for automatic commit tracking.");
+    #[test]
+    fn test_parse_weights_json() {
+        let entries =
+            parse_weights_json(r#"[{"uid": 3, "weight": 0.25}, {"uid": 1, "weight": 0.75}]"#)
+                .unwrap();
+        assert_eq!(entries, vec![(3, 0.25), (1, 0.75)]);
 
-    Ok(())
+        let entries = parse_weights_json(r#"{"1": 0.75, "3": 0.25}"#).unwrap();
+        assert_eq!(entries, vec![(1, 0.75), (3, 0.25)]);
+
+        assert!(parse_weights_json(r#"{"70000": 1.0}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_weights_csv() {
+        let entries = parse_weights_csv("uid,weight\n0, 100\n\n# comment\n5,200\n").unwrap();
+        assert_eq!(entries, vec![(0, 100.0), (5, 200.0)]);
+
+        let err = parse_weights_csv("0,1\n1,x\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+        assert!(parse_weights_csv("0,1,2\n").is_err());
+    }
+
+    #[test]
+    fn test_weights_to_u16() {
+        let floats = [(0, 0.5), (1, 0.25), (2, 0.0)];
+        assert!(!looks_raw(&floats));
+        assert_eq!(
+            weights_to_u16(&floats, false).unwrap(),
+            (vec![0, 1], vec![65535, 32768])
+        );
+
+        let raw = [(0, 65535.0), (1, 100.0)];
+        assert!(looks_raw(&raw));
+        assert_eq!(
+            weights_to_u16(&raw, true).unwrap(),
+            (vec![0, 1], vec![65535, 100])
+        );
+        assert!(weights_to_u16(&[(0, 70000.0)], true).is_err());
+        assert!(weights_to_u16(&[(0, -1.0)], false).is_err());
+        assert!(weights_to_u16(&[(0, 0.0)], false).is_err());
+    }
 }
@@ -10,12 +10,18 @@
 //! - `subnet` - Subnet information and registration
 //! - `root` - Root network operations
 //! - `weights` - Weight commit, reveal, and set operations
+//! - `liquidity` - Liquidity position listing
+//! - `config` - Show and edit the config file
 
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 pub mod commands;
+pub mod output;
 pub mod utils;
 
+use output::OutputFormat;
+
 /// Bittensor CLI - Rust implementation
 #[derive(Parser)]
 #[command(name = "btcli")]
@@ -28,16 +34,44 @@ pub struct Cli {
     pub command: Commands,
 
     /// Network to connect to (finney, test, local, or custom URL)
-    #[arg(short, long, default_value = "finney", global = true)]
-    pub network: String,
+    ///
+    /// Defaults to the config file's network, or finney.
+    #[arg(short, long, global = true)]
+    pub network: Option<String>,
 
     /// Custom RPC endpoint (overrides --network)
     #[arg(long, global = true)]
     pub endpoint: Option<String>,
 
-    /// Don't prompt for confirmations (auto-approve)
+    /// Never prompt: auto-approve confirmations and fail where a password prompt
+    /// would be needed
     #[arg(long, global = true)]
     pub no_prompt: bool,
+
+    /// Read the key password from this file (must not be accessible by other
+    /// users), or from the file named after the key's BT_PW_* variable when
+    /// this is a directory
+    #[arg(long, global = true)]
+    pub password_file: Option<PathBuf>,
+
+    /// Config file to use instead of ~/.bittensor/config.toml
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Output format for read commands
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table, global = true)]
+    pub output: OutputFormat,
+}
+
+impl Cli {
+    /// Settings from the command line, for [`Config::resolve`](crate::config::Config::resolve)
+    pub fn config_overrides(&self) -> crate::config::ConfigOverrides {
+        crate::config::ConfigOverrides {
+            config_path: self.config.clone(),
+            network: self.network.clone(),
+            endpoint: self.endpoint.clone(),
+        }
+    }
 }
 
 /// Available CLI commands
@@ -62,17 +96,37 @@ pub enum Commands {
     /// Weight operations (commit, reveal, set)
     #[command(alias = "wt")]
     Weights(commands::weights::WeightsCommand),
+
+    /// Liquidity position operations
+    #[command(alias = "lq")]
+    Liquidity(commands::liquidity::LiquidityCommand),
+
+    /// Config file operations (show, set)
+    #[command(alias = "c")]
+    Config(commands::config::ConfigCommand),
 }
 
 /// Run the CLI application
+///
+/// With `--output json`, a failed command prints `{"error": ...}` to stderr
+/// and exits with status 1.
 pub async fn run() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    utils::set_output_format(cli.output);
 
-    match &cli.command {
+    let result = match &cli.command {
         Commands::Wallet(cmd) => commands::wallet::execute(cmd.clone(), &cli).await,
         Commands::Stake(cmd) => commands::stake::execute(cmd.clone(), &cli).await,
         Commands::Subnet(cmd) => commands::subnet::execute(cmd.clone(), &cli).await,
         Commands::Root(cmd) => commands::root::execute(cmd.clone(), &cli).await,
         Commands::Weights(cmd) => commands::weights::execute(cmd.clone(), &cli).await,
+        Commands::Liquidity(cmd) => commands::liquidity::execute(cmd.clone(), &cli).await,
+        Commands::Config(cmd) => commands::config::execute(cmd.clone(), &cli),
+    };
+
+    if let (Err(e), OutputFormat::Json) = (&result, cli.output) {
+        eprintln!("{}", output::error_json(e));
+        std::process::exit(1);
     }
+    result
 }
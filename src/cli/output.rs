@@ -0,0 +1,53 @@
+//! Output formats for read commands.
+//!
+//! Read commands build a typed result and hand it to [`emit`], which prints
+//! it as tables or, with `--output json`, as JSON on stdout. Errors in JSON
+//! mode are written to stderr as `{"error": "..."}`.
+
+use crate::cli::Cli;
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How command results are printed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable tables
+    #[default]
+    Table,
+    /// JSON on stdout, for scripts
+    Json,
+}
+
+/// A command result that can be shown as tables or serialized as JSON
+pub trait Render: Serialize {
+    /// Print the result for a terminal
+    fn render_table(&self);
+}
+
+/// Print a command result in the format selected with `--output`
+pub fn emit<T: Render>(cli: &Cli, result: &T) -> anyhow::Result<()> {
+    match cli.output {
+        OutputFormat::Table => result.render_table(),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(result)?),
+    }
+    Ok(())
+}
+
+/// JSON body reported on stderr for a failed command
+pub fn error_json(error: &anyhow::Error) -> serde_json::Value {
+    serde_json::json!({ "error": format!("{:#}", error) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_json_includes_context() {
+        let error = anyhow::anyhow!("connection refused").context("Failed to connect");
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({ "error": "Failed to connect: connection refused" })
+        );
+    }
+}
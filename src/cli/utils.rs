@@ -1,12 +1,41 @@
 //! CLI utility functions for terminal interaction and formatting.
 
+use crate::cli::output::OutputFormat;
+use crate::cli::Cli;
+use crate::config::Config;
 use crate::core::constants::RAOPERTAO;
+use crate::wallet::{PasswordSource, Wallet};
 use comfy_table::{presets::UTF8_FULL, ContentArrangement, Table};
 use console::{style, Term};
 use dialoguer::{Confirm, Input, Password};
 use indicatif::{ProgressBar, ProgressStyle};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
+/// Set when `--output json` is active, so stdout carries only the result
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Select the output format for messages and spinners.
+///
+/// In JSON mode spinners are hidden, status messages move to stderr and
+/// error messages are left to the final `{"error": ...}` report.
+pub fn set_output_format(format: OutputFormat) {
+    JSON_OUTPUT.store(format == OutputFormat::Json, Ordering::Relaxed);
+}
+
+fn json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Terminal for status messages: stdout, or stderr in JSON mode.
+fn message_term() -> Term {
+    if json_output() {
+        Term::stderr()
+    } else {
+        Term::stdout()
+    }
+}
+
 /// Prompt for confirmation with default behavior based on `no_prompt` flag.
 /// If `no_prompt` is true, returns true without prompting.
 pub fn confirm(message: &str, no_prompt: bool) -> bool {
@@ -21,27 +50,115 @@ pub fn confirm(message: &str, no_prompt: bool) -> bool {
         .unwrap_or(false)
 }
 
+/// Error returned when a password is needed but `--no-prompt` is set
+fn prompts_disabled(hint: &str) -> anyhow::Error {
+    anyhow::anyhow!("Password required but prompts are disabled; {}", hint)
+}
+
 /// Prompt for password input (hidden characters).
-pub fn prompt_password(message: &str) -> String {
+///
+/// Fails without reading stdin when `no_prompt` is set, so unattended runs
+/// never block on a terminal that is not there.
+pub fn prompt_password(message: &str, no_prompt: bool) -> anyhow::Result<String> {
+    if no_prompt {
+        return Err(prompts_disabled("pass --no-password or run interactively"));
+    }
+
     Password::new()
         .with_prompt(message)
         .interact()
-        .unwrap_or_default()
+        .map_err(|e| anyhow::anyhow!("Password prompt failed: {}", e))
 }
 
 /// Prompt for optional password input. Returns None if empty.
-pub fn prompt_password_optional(message: &str) -> Option<String> {
+///
+/// Fails without reading stdin when `no_prompt` is set.
+pub fn prompt_password_optional(message: &str, no_prompt: bool) -> anyhow::Result<Option<String>> {
+    if no_prompt {
+        return Err(prompts_disabled("pass --no-password or run interactively"));
+    }
+
     let password = Password::new()
         .with_prompt(message)
         .allow_empty_password(true)
         .interact()
-        .unwrap_or_default();
+        .map_err(|e| anyhow::anyhow!("Password prompt failed: {}", e))?;
 
-    if password.is_empty() {
-        None
-    } else {
-        Some(password)
+    Ok(Some(password).filter(|p| !p.is_empty()))
+}
+
+/// Read a password from `source`, refusing to prompt when `no_prompt` is set.
+fn resolve_password(
+    source: PasswordSource,
+    env_var: &str,
+    no_prompt: bool,
+    message: &str,
+) -> anyhow::Result<Option<String>> {
+    if no_prompt && source == PasswordSource::Prompt {
+        return Err(prompts_disabled(&format!(
+            "use --password-file or set {}",
+            env_var
+        )));
+    }
+    Ok(source.resolve(message)?)
+}
+
+/// Get a wallet's coldkey password from `--password-file`, the
+/// `BT_PW_<WALLET>` environment variable or a prompt, in that order.
+/// Returns None without prompting if the coldkey is not encrypted, and an
+/// error instead of prompting under `--no-prompt`.
+pub fn resolve_coldkey_password(
+    cli: &Cli,
+    wallet: &Wallet,
+    message: &str,
+) -> anyhow::Result<Option<String>> {
+    if !wallet.coldkey().is_encrypted() {
+        return Ok(None);
+    }
+    let source = wallet.coldkey_password_source(cli.password_file.as_deref());
+    let env_var = PasswordSource::coldkey_env_var(&wallet.name);
+    resolve_password(source, &env_var, cli.no_prompt, message)
+}
+
+/// Get a wallet's hotkey password from `--password-file`, the
+/// `BT_PW_<WALLET>_HOTKEY_<HOTKEY>` environment variable or a prompt, in that
+/// order. Returns None without prompting if the hotkey is not encrypted, and
+/// an error instead of prompting under `--no-prompt`.
+pub fn resolve_hotkey_password(
+    cli: &Cli,
+    wallet: &Wallet,
+    message: &str,
+) -> anyhow::Result<Option<String>> {
+    if !wallet.hotkey().is_encrypted() {
+        return Ok(None);
+    }
+    let source = wallet.hotkey_password_source(cli.password_file.as_deref());
+    let env_var = PasswordSource::hotkey_env_var(&wallet.name, &wallet.hotkey_name);
+    resolve_password(source, &env_var, cli.no_prompt, message)
+}
+
+/// Refuse a single `--password-file` for several encrypted coldkeys up front
+///
+/// A password file holds one password, so a batch over `wallets` would fail
+/// on every wallet with a different password. A directory holding one file
+/// per key (see [`PasswordSource::select`]) is accepted.
+pub fn check_password_file(cli: &Cli, wallets: &[Wallet]) -> anyhow::Result<()> {
+    let Some(path) = cli.password_file.as_deref() else {
+        return Ok(());
+    };
+    let encrypted = wallets
+        .iter()
+        .filter(|wallet| wallet.coldkey().is_encrypted())
+        .count();
+    if encrypted > 1 && !path.is_dir() {
+        return Err(anyhow::anyhow!(
+            "--password-file holds one password but {} wallets are encrypted; \
+             pass a directory with one file per wallet named after its {} variable",
+            encrypted,
+            PasswordSource::coldkey_env_var("<WALLET>")
+        ));
     }
+    Ok(())
 }
 
 /// Prompt for text input with a default value.
@@ -63,6 +180,9 @@ pub fn prompt_input_with_default(message: &str, default: &str) -> String {
 
 /// Create a spinner progress bar with message.
 pub fn spinner(message: &str) -> ProgressBar {
+    if json_output() {
+        return ProgressBar::hidden();
+    }
     let pb = ProgressBar::new_spinner();
     pb.set_style(
         ProgressStyle::default_spinner()
@@ -75,27 +195,46 @@ pub fn spinner(message: &str) -> ProgressBar {
     pb
 }
 
+/// Create a progress bar with a length and message.
+pub fn progress_bar(len: u64, message: &str) -> ProgressBar {
+    if json_output() {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new(len);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("{msg} [{bar:30.blue}] {pos}/{len}")
+            .expect("valid template")
+            .progress_chars("=> "),
+    );
+    pb.set_message(message.to_string());
+    pb
+}
+
 /// Print success message in green.
 pub fn print_success(message: &str) {
-    let term = Term::stdout();
+    let term = message_term();
     let _ = term.write_line(&format!("{} {}", style("✓").green().bold(), message));
 }
 
 /// Print error message in red.
 pub fn print_error(message: &str) {
+    if json_output() {
+        return;
+    }
     let term = Term::stderr();
     let _ = term.write_line(&format!("{} {}", style("✗").red().bold(), message));
 }
 
 /// Print info message in blue.
 pub fn print_info(message: &str) {
-    let term = Term::stdout();
+    let term = message_term();
     let _ = term.write_line(&format!("{} {}", style("ℹ").blue().bold(), message));
 }
 
 /// Print warning message in yellow.
 pub fn print_warning(message: &str) {
-    let term = Term::stdout();
+    let term = message_term();
     let _ = term.write_line(&format!("{} {}", style("⚠").yellow().bold(), message));
 }
 
@@ -165,23 +304,29 @@ pub fn parse_f64_list(input: &str) -> anyhow::Result<Vec<f64>> {
         .collect()
 }
 
-/// Get network endpoint from network name or custom endpoint.
-pub fn resolve_endpoint(network: &str, custom_endpoint: Option<&str>) -> String {
-    if let Some(endpoint) = custom_endpoint {
-        return endpoint.to_string();
-    }
-
-    if let Ok(endpoint) = std::env::var("BITTENSOR_RPC") {
-        return endpoint;
+/// Get the chain endpoint for a command.
+///
+/// Resolved with [`Config::resolve`]: `--endpoint`/`--network` flags, then
+/// `BITTENSOR_RPC`/`BITTENSOR_NETWORK`, then the config file (`--config` or
+/// `~/.bittensor/config.toml`), then the finney default. An explicit
+/// `--endpoint` is used without reading the config file.
+pub fn resolve_endpoint(cli: &Cli) -> anyhow::Result<String> {
+    if let Some(endpoint) = &cli.endpoint {
+        return Ok(endpoint.clone());
     }
+    Ok(Config::resolve(&cli.config_overrides())?
+        .subtensor
+        .chain_endpoint)
+}
 
-    match network.to_lowercase().as_str() {
-        "finney" => "wss://entrypoint-finney.opentensor.ai:443".to_string(),
-        "test" | "testnet" => "wss://test.finney.opentensor.ai:443".to_string(),
-        "local" | "localhost" => "ws://127.0.0.1:9944".to_string(),
-        "archive" => "wss://archive.chain.opentensor.ai:443".to_string(),
-        _ => network.to_string(),
-    }
+/// Connect to `endpoint`, caching the runtime metadata in `~/.bittensor/metadata`
+pub async fn connect_client(
+    endpoint: &str,
+) -> Result<crate::chain::BittensorClient, crate::chain::Error> {
+    crate::chain::BittensorClient::builder(endpoint)
+        .metadata_cache(true)
+        .build()
+        .await
 }
 
 /// Format duration for display.
@@ -207,11 +352,200 @@ pub fn is_valid_ss58(address: &str) -> bool {
     address.chars().all(|c| c.is_alphanumeric())
 }
 
+/// Parse an SS58 address of any network prefix into a Bittensor account
+///
+/// Addresses pasted from other networks (e.g. Polkadot, prefix 0) refer to
+/// the same key; they are accepted with a warning showing the Bittensor
+/// address that will be used, which is returned alongside the account.
+pub fn parse_address_any_prefix(
+    address: &str,
+    label: &str,
+) -> anyhow::Result<(sp_core::crypto::AccountId32, String)> {
+    use crate::utils::ss58::{encode_ss58, ss58_decode, SS58_FORMAT};
+
+    let (account, prefix) = ss58_decode(address)
+        .map_err(|e| anyhow::anyhow!("Invalid {} address {}: {}", label, address, e))?;
+    let bittensor_address = encode_ss58(&account);
+    if prefix != SS58_FORMAT {
+        print_warning(&format!(
+            "{} address {} uses SS58 prefix {}; using its Bittensor address {}",
+            label, address, prefix, bittensor_address
+        ));
+    }
+    Ok((account, bittensor_address))
+}
+
 /// Create a BittensorSigner from a wallet Keypair
 pub fn keypair_to_signer(keypair: &crate::wallet::Keypair) -> crate::chain::BittensorSigner {
     crate::chain::create_signer(keypair.pair().clone())
 }
 
+/// Ledger options for commands signing with the coldkey
+#[derive(clap::Args, Clone, Debug, Default)]
+pub struct LedgerArgs {
+    /// Sign with a Ledger device instead of a wallet coldkey
+    #[arg(long)]
+    pub ledger: bool,
+    /// BIP44 account index of the Ledger account
+    #[arg(long, default_value_t = 0, requires = "ledger")]
+    pub ledger_account: u32,
+}
+
+/// Connect to a Ledger device. Returns the signer and its SS58 address.
+pub fn ledger_signer(args: &LedgerArgs) -> anyhow::Result<(crate::chain::BittensorSigner, String)> {
+    #[cfg(feature = "ledger")]
+    {
+        let sp = spinner("Connecting to Ledger...");
+        let signer = crate::chain::LedgerSigner::connect(args.ledger_account, 0);
+        sp.finish_and_clear();
+        let signer = signer.map_err(|e| anyhow::anyhow!("{}", e))?;
+        let address = signer.ss58_address().to_string();
+        Ok((signer.into(), address))
+    }
+    #[cfg(not(feature = "ledger"))]
+    {
+        let _ = args;
+        Err(anyhow::anyhow!(
+            "Ledger support is not enabled, rebuild with the `ledger` feature"
+        ))
+    }
+}
+
+/// Per-subnet stake totals for a set of stake entries, in each subnet's unit
+pub fn stake_totals_by_netuid(
+    stakes: &[crate::queries::stakes::StakeInfo],
+) -> std::collections::BTreeMap<u16, crate::utils::balance_newtypes::Balance> {
+    use crate::utils::balance_newtypes::Balance;
+    Balance::sum_by_netuid(
+        stakes
+            .iter()
+            .map(|s| Balance::from_rao_with_netuid(s.stake.as_u128(), s.netuid)),
+    )
+}
+
+/// TAO value of stake entries, converting each subnet's total at its current price
+pub async fn stake_tao_value(
+    client: &crate::chain::BittensorClient,
+    stakes: &[crate::queries::stakes::StakeInfo],
+) -> anyhow::Result<u128> {
+    use crate::queries::subnets::get_subnet_price;
+
+    let mut total: u128 = 0;
+    for (netuid, alpha) in stake_totals_by_netuid(stakes) {
+        let price = get_subnet_price(client, netuid).await?;
+        total = total.saturating_add(alpha.to_tao_value(price.as_tao()).as_rao());
+    }
+    Ok(total)
+}
+
+/// Fee assumed for a staking extrinsic when sizing `--all` (0.001 TAO).
+///
+/// Staking calls pay a small weight-based fee; this is a deliberate
+/// overestimate so a full-balance stake is not rejected for lack of fees.
+pub const STAKE_FEE_ESTIMATE_RAO: u128 = 1_000_000;
+
+/// An amount given on the command line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AmountSpec {
+    /// Absolute amount in TAO
+    Tao(f64),
+    /// Percentage (0-100] of a balance chosen by the command
+    Percent(f64),
+}
+
+impl AmountSpec {
+    /// Amount in RAO, taking a percentage of `base_rao` where applicable.
+    pub fn resolve(self, base_rao: u128) -> u128 {
+        match self {
+            AmountSpec::Tao(tao) => tao_to_rao(tao),
+            AmountSpec::Percent(percent) => {
+                // Parts per billion keeps the arithmetic in integers
+                let ppb = (percent * 10_000_000.0).round() as u128;
+                base_rao.saturating_mul(ppb) / 1_000_000_000
+            }
+        }
+    }
+}
+
+/// Parse an amount as TAO (`1.5`) or a percentage (`50%`).
+pub fn parse_amount_or_percent(input: &str) -> anyhow::Result<AmountSpec> {
+    let input = input.trim();
+    if let Some(percent) = input.strip_suffix('%') {
+        let percent: f64 = percent
+            .trim()
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid percentage '{}': {}", input, e))?;
+        if !(percent > 0.0 && percent <= 100.0) {
+            return Err(anyhow::anyhow!(
+                "Percentage must be greater than 0 and at most 100, got {}",
+                input
+            ));
+        }
+        return Ok(AmountSpec::Percent(percent));
+    }
+
+    let tao: f64 = input
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid amount '{}': {}", input, e))?;
+    if !tao.is_finite() || tao <= 0.0 {
+        return Err(anyhow::anyhow!("Amount must be positive, got {}", input));
+    }
+    Ok(AmountSpec::Tao(tao))
+}
+
+/// A coldkey's free balance and what must stay behind when staking (RAO).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StakeableAmount {
+    pub free: u128,
+    pub existential_deposit: u128,
+    /// Extra balance the user asked to keep (`--reserve`)
+    pub reserve: u128,
+    pub fee_estimate: u128,
+}
+
+impl StakeableAmount {
+    /// Largest amount that can be staked while keeping the existential
+    /// deposit, the reserve and the fee.
+    pub fn stakeable(&self) -> u128 {
+        self.free
+            .saturating_sub(self.existential_deposit)
+            .saturating_sub(self.reserve)
+            .saturating_sub(self.fee_estimate)
+    }
+
+    /// Whether staking `amount` and paying the fee leaves at least the
+    /// existential deposit, so the account is not reaped.
+    pub fn keeps_existential_deposit(&self, amount: u128) -> bool {
+        amount
+            .checked_add(self.fee_estimate)
+            .and_then(|spent| spent.checked_add(self.existential_deposit))
+            .is_some_and(|needed| needed <= self.free)
+    }
+}
+
+/// Fetch a coldkey's free balance and the existential deposit for staking.
+pub async fn compute_stakeable_amount(
+    client: &crate::chain::BittensorClient,
+    coldkey: &sp_core::crypto::AccountId32,
+    reserve: u128,
+) -> anyhow::Result<StakeableAmount> {
+    use crate::queries::balances::{get_balance, get_existential_deposit};
+
+    let free = get_balance(client, coldkey)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch balance: {}", e))?;
+    let existential_deposit = get_existential_deposit(client)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch existential deposit: {}", e))?;
+
+    Ok(StakeableAmount {
+        free: free.as_u128(),
+        existential_deposit: existential_deposit.as_u128(),
+        reserve,
+        fee_estimate: STAKE_FEE_ESTIMATE_RAO,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,21 +576,143 @@ mod tests {
 
     #[test]
     fn test_resolve_endpoint() {
-        let previous = std::env::var("BITTENSOR_RPC").ok();
-        std::env::remove_var("BITTENSOR_RPC");
+        use clap::Parser;
+
+        let missing = std::env::temp_dir().join("bittensor-cli-no-config.toml");
+        let config = missing.to_str().unwrap();
+        let cli = Cli::parse_from([
+            "btcli",
+            "--config",
+            config,
+            "--endpoint",
+            "ws://custom:9944",
+            "subnet",
+            "list",
+        ]);
+        assert_eq!(resolve_endpoint(&cli).unwrap(), "ws://custom:9944");
+
+        // An explicit config path that does not exist is an error
+        let cli = Cli::parse_from(["btcli", "--config", config, "subnet", "list"]);
+        assert!(resolve_endpoint(&cli).is_err());
+    }
+
+    /// Run `f` on a thread, failing if it blocks (e.g. on stdin)
+    fn without_blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || tx.send(f()).unwrap());
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("prompt blocked instead of failing")
+    }
+
+    #[test]
+    fn test_password_prompts_disabled() {
+        let err = without_blocking(|| prompt_password("Password", true)).unwrap_err();
+        assert!(err.to_string().contains("prompts are disabled"));
+        let err = without_blocking(|| prompt_password_optional("Password", true)).unwrap_err();
+        assert!(err.to_string().contains("prompts are disabled"));
+    }
+
+    #[test]
+    fn test_resolve_password_no_prompt() {
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let base_path = dir.path().to_str().unwrap().to_string();
+        let wallet =
+            Wallet::create_at_path("no_prompt_wallet", "default", &base_path, Some("secret"))
+                .unwrap();
+        let env_var = PasswordSource::coldkey_env_var("no_prompt_wallet");
+        std::env::remove_var(&env_var);
+
+        let cli = Cli::parse_from(["btcli", "--no-prompt", "subnet", "list"]);
+        let err = without_blocking(move || {
+            resolve_coldkey_password(&cli, &wallet, "Password").map(|_| ())
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("prompts are disabled"));
+        assert!(err.to_string().contains(&env_var));
+
+        // A password from the environment still works unattended
+        std::env::set_var(&env_var, "secret");
+        let cli = Cli::parse_from(["btcli", "--no-prompt", "subnet", "list"]);
+        let wallet = Wallet::new("no_prompt_wallet", "default", Some(&base_path)).unwrap();
+        assert_eq!(
+            resolve_coldkey_password(&cli, &wallet, "Password").unwrap(),
+            Some("secret".to_string())
+        );
+        std::env::remove_var(&env_var);
+    }
+
+    #[test]
+    fn test_check_password_file() {
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let base_path = dir.path().to_str().unwrap();
+        let wallets: Vec<Wallet> = ["batch_a", "batch_b"]
+            .iter()
+            .map(|name| Wallet::create_at_path(name, "default", base_path, Some("secret")).unwrap())
+            .collect();
+        let password_file = dir.path().join("password");
+        std::fs::write(&password_file, "secret").unwrap();
+        let password_file = password_file.to_str().unwrap();
+
+        let cli = Cli::parse_from(["btcli", "--password-file", password_file, "subnet", "list"]);
+        assert!(check_password_file(&cli, &wallets[..1]).is_ok());
+        let err = check_password_file(&cli, &wallets).unwrap_err();
+        assert!(err.to_string().contains("2 wallets are encrypted"));
+
+        let cli = Cli::parse_from(["btcli", "--password-file", base_path, "subnet", "list"]);
+        assert!(check_password_file(&cli, &wallets).is_ok());
+        let cli = Cli::parse_from(["btcli", "subnet", "list"]);
+        assert!(check_password_file(&cli, &wallets).is_ok());
+    }
+
+    #[test]
+    fn test_parse_amount_or_percent() {
         assert_eq!(
-            resolve_endpoint("finney", None),
-            "wss://entrypoint-finney.opentensor.ai:443"
+            parse_amount_or_percent("1.5").unwrap(),
+            AmountSpec::Tao(1.5)
         );
-        assert_eq!(resolve_endpoint("local", None), "ws://127.0.0.1:9944");
         assert_eq!(
-            resolve_endpoint("finney", Some("ws://custom:9944")),
-            "ws://custom:9944"
+            parse_amount_or_percent(" 50% ").unwrap(),
+            AmountSpec::Percent(50.0)
         );
-        if let Some(value) = previous {
-            std::env::set_var("BITTENSOR_RPC", value);
-        } else {
-            std::env::remove_var("BITTENSOR_RPC");
-        }
+        assert!(parse_amount_or_percent("0").is_err());
+        assert!(parse_amount_or_percent("-1").is_err());
+        assert!(parse_amount_or_percent("0%").is_err());
+        assert!(parse_amount_or_percent("150%").is_err());
+        assert!(parse_amount_or_percent("abc%").is_err());
+    }
+
+    #[test]
+    fn test_amount_spec_resolve() {
+        assert_eq!(AmountSpec::Tao(1.5).resolve(0), 1_500_000_000);
+        assert_eq!(
+            AmountSpec::Percent(50.0).resolve(3_000_000_001),
+            1_500_000_000
+        );
+        assert_eq!(AmountSpec::Percent(100.0).resolve(7), 7);
+        assert_eq!(AmountSpec::Percent(12.5).resolve(1_000), 125);
+    }
+
+    #[test]
+    fn test_stakeable_amount() {
+        let balance = StakeableAmount {
+            free: 10_000_000_000,
+            existential_deposit: 500,
+            reserve: 100_000_000,
+            fee_estimate: STAKE_FEE_ESTIMATE_RAO,
+        };
+        let stakeable = balance.stakeable();
+        assert_eq!(stakeable, 10_000_000_000 - 500 - 100_000_000 - 1_000_000);
+        assert!(balance.keeps_existential_deposit(stakeable));
+        assert!(!balance.keeps_existential_deposit(10_000_000_000));
+
+        let empty = StakeableAmount {
+            free: 100,
+            ..balance
+        };
+        assert_eq!(empty.stakeable(), 0);
     }
 }
@@ -0,0 +1,325 @@
+//! Deprecated `anyhow` forms of the [`crv4`](crate::crv4) functions
+//!
+//! Each function keeps its signature from before the switch to
+//! `BittensorResult` and calls the current one. They are removed together
+//! with this module.
+
+use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::crv4::{Crv4BatchCommit, Crv4CommitData, DrandClient, MechanismWeights};
+use crate::utils::WeightValidation;
+use crate::validator::utility::BatchMode;
+
+/// [`calculate_reveal_round`](crate::crv4::calculate_reveal_round) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::calculate_reveal_round`, which returns `BittensorResult`")]
+pub fn calculate_reveal_round(
+    tempo: u16,
+    current_block: u64,
+    netuid: u16,
+    subnet_reveal_period_epochs: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+) -> anyhow::Result<u64> {
+    Ok(crate::crv4::calculate_reveal_round(
+        tempo,
+        current_block,
+        netuid,
+        subnet_reveal_period_epochs,
+        block_time,
+        chain_last_drand_round,
+    )?)
+}
+
+/// [`calculate_reveal_round_checked`](crate::crv4::calculate_reveal_round_checked) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::calculate_reveal_round_checked`, which returns `BittensorResult`")]
+pub fn calculate_reveal_round_checked(
+    tempo: u16,
+    current_block: u64,
+    netuid: u16,
+    subnet_reveal_period_epochs: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+    live_drand_round: u64,
+) -> anyhow::Result<u64> {
+    Ok(crate::crv4::calculate_reveal_round_checked(
+        tempo,
+        current_block,
+        netuid,
+        subnet_reveal_period_epochs,
+        block_time,
+        chain_last_drand_round,
+        live_drand_round,
+    )?)
+}
+
+/// [`calculate_reveal_round_for_epoch`](crate::crv4::calculate_reveal_round_for_epoch) returning `anyhow::Result`
+#[deprecated(
+    note = "use `crv4::calculate_reveal_round_for_epoch`, which returns `BittensorResult`"
+)]
+pub fn calculate_reveal_round_for_epoch(
+    reveal_epoch: u64,
+    tempo: u16,
+    netuid: u16,
+    current_block: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+) -> anyhow::Result<u64> {
+    Ok(crate::crv4::calculate_reveal_round_for_epoch(
+        reveal_epoch,
+        tempo,
+        netuid,
+        current_block,
+        block_time,
+        chain_last_drand_round,
+    )?)
+}
+
+/// [`calculate_reveal_round_for_blocks`](crate::crv4::calculate_reveal_round_for_blocks) returning `anyhow::Result`
+#[deprecated(
+    note = "use `crv4::calculate_reveal_round_for_blocks`, which returns `BittensorResult`"
+)]
+pub fn calculate_reveal_round_for_blocks(
+    blocks_until_reveal: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+) -> anyhow::Result<u64> {
+    Ok(crate::crv4::calculate_reveal_round_for_blocks(
+        blocks_until_reveal,
+        block_time,
+        chain_last_drand_round,
+    )?)
+}
+
+/// [`prepare_crv4_commit`](crate::crv4::prepare_crv4_commit) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::prepare_crv4_commit`, which returns `BittensorResult`")]
+pub fn prepare_crv4_commit(
+    hotkey: &[u8],
+    uids: &[u16],
+    weights: &[u16],
+    version_key: u64,
+    reveal_round: u64,
+) -> anyhow::Result<Vec<u8>> {
+    Ok(crate::crv4::prepare_crv4_commit(
+        hotkey,
+        uids,
+        weights,
+        version_key,
+        reveal_round,
+    )?)
+}
+
+/// [`encrypt_for_round`](crate::crv4::encrypt_for_round) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::encrypt_for_round`, which returns `BittensorResult`")]
+pub fn encrypt_for_round(data: &[u8], reveal_round: u64) -> anyhow::Result<Vec<u8>> {
+    Ok(crate::crv4::encrypt_for_round(data, reveal_round)?)
+}
+
+/// [`encrypt_for_blocks`](crate::crv4::encrypt_for_blocks) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::encrypt_for_blocks`, which returns `BittensorResult`")]
+pub fn encrypt_for_blocks(
+    data: &[u8],
+    blocks_until_reveal: u64,
+    block_time: f64,
+) -> anyhow::Result<(Vec<u8>, u64)> {
+    Ok(crate::crv4::encrypt_for_blocks(
+        data,
+        blocks_until_reveal,
+        block_time,
+    )?)
+}
+
+/// [`encrypt_for_chain_blocks`](crate::crv4::encrypt_for_chain_blocks) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::encrypt_for_chain_blocks`, which returns `BittensorResult`")]
+pub fn encrypt_for_chain_blocks(
+    data: &[u8],
+    blocks_until_reveal: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+) -> anyhow::Result<(Vec<u8>, u64)> {
+    Ok(crate::crv4::encrypt_for_chain_blocks(
+        data,
+        blocks_until_reveal,
+        block_time,
+        chain_last_drand_round,
+    )?)
+}
+
+/// [`commit_timelocked_weights`](crate::crv4::commit_timelocked_weights) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::commit_timelocked_weights`, which returns `BittensorResult`")]
+pub async fn commit_timelocked_weights(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    commit: &[u8],
+    reveal_round: u64,
+    commit_reveal_version: u16,
+    wait_for: ExtrinsicWait,
+) -> anyhow::Result<String> {
+    Ok(crate::crv4::commit_timelocked_weights(
+        client,
+        signer,
+        netuid,
+        commit,
+        reveal_round,
+        commit_reveal_version,
+        wait_for,
+    )
+    .await?)
+}
+
+/// [`commit_timelocked_mechanism_weights`](crate::crv4::commit_timelocked_mechanism_weights) returning `anyhow::Result`
+#[deprecated(
+    note = "use `crv4::commit_timelocked_mechanism_weights`, which returns `BittensorResult`"
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn commit_timelocked_mechanism_weights(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    mechanism_id: u8,
+    commit: &[u8],
+    reveal_round: u64,
+    commit_reveal_version: u16,
+    wait_for: ExtrinsicWait,
+) -> anyhow::Result<String> {
+    Ok(crate::crv4::commit_timelocked_mechanism_weights(
+        client,
+        signer,
+        netuid,
+        mechanism_id,
+        commit,
+        reveal_round,
+        commit_reveal_version,
+        wait_for,
+    )
+    .await?)
+}
+
+/// [`get_last_drand_round`](crate::crv4::get_last_drand_round) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::get_last_drand_round`, which returns `BittensorResult`")]
+pub async fn get_last_drand_round(client: &impl ChainBackend) -> anyhow::Result<u64> {
+    Ok(crate::crv4::get_last_drand_round(client).await?)
+}
+
+/// [`checked_reveal_round`](crate::crv4::checked_reveal_round) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::checked_reveal_round`, which returns `BittensorResult`")]
+#[allow(clippy::too_many_arguments)]
+pub async fn checked_reveal_round(
+    drand: &DrandClient,
+    tempo: u16,
+    current_block: u64,
+    storage_index: u16,
+    reveal_period: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+) -> anyhow::Result<u64> {
+    Ok(crate::crv4::checked_reveal_round(
+        drand,
+        tempo,
+        current_block,
+        storage_index,
+        reveal_period,
+        block_time,
+        chain_last_drand_round,
+    )
+    .await?)
+}
+
+/// [`prepare_and_commit_crv4_weights`](crate::crv4::prepare_and_commit_crv4_weights) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::prepare_and_commit_crv4_weights`, which returns `BittensorResult`")]
+#[allow(clippy::too_many_arguments)]
+pub async fn prepare_and_commit_crv4_weights(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    uids: &[u16],
+    weights: &[u16],
+    version_key: Option<u64>,
+    validation: WeightValidation,
+    wait_for: ExtrinsicWait,
+) -> anyhow::Result<Crv4CommitData> {
+    Ok(crate::crv4::prepare_and_commit_crv4_weights(
+        client,
+        signer,
+        netuid,
+        uids,
+        weights,
+        version_key,
+        validation,
+        wait_for,
+    )
+    .await?)
+}
+
+/// [`prepare_and_commit_crv4_mechanism_weights`](crate::crv4::prepare_and_commit_crv4_mechanism_weights) returning `anyhow::Result`
+#[deprecated(
+    note = "use `crv4::prepare_and_commit_crv4_mechanism_weights`, which returns `BittensorResult`"
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn prepare_and_commit_crv4_mechanism_weights(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    mechanism_id: u8,
+    uids: &[u16],
+    weights: &[u16],
+    version_key: Option<u64>,
+    validation: WeightValidation,
+    wait_for: ExtrinsicWait,
+) -> anyhow::Result<Crv4CommitData> {
+    Ok(crate::crv4::prepare_and_commit_crv4_mechanism_weights(
+        client,
+        signer,
+        netuid,
+        mechanism_id,
+        uids,
+        weights,
+        version_key,
+        validation,
+        wait_for,
+    )
+    .await?)
+}
+
+/// [`prepare_and_commit_crv4_multi`](crate::crv4::prepare_and_commit_crv4_multi) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::prepare_and_commit_crv4_multi`, which returns `BittensorResult`")]
+#[allow(clippy::too_many_arguments)]
+pub async fn prepare_and_commit_crv4_multi(
+    client: &BittensorClient,
+    signer: &BittensorSigner,
+    netuid: u16,
+    commits: Vec<MechanismWeights>,
+    version_key: Option<u64>,
+    validation: WeightValidation,
+    mode: BatchMode,
+    wait_for: ExtrinsicWait,
+) -> anyhow::Result<Crv4BatchCommit> {
+    Ok(crate::crv4::prepare_and_commit_crv4_multi(
+        client,
+        signer,
+        netuid,
+        commits,
+        version_key,
+        validation,
+        mode,
+        wait_for,
+    )
+    .await?)
+}
+
+/// [`get_commit_reveal_version`](crate::crv4::get_commit_reveal_version) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::get_commit_reveal_version`, which returns `BittensorResult`")]
+pub async fn get_commit_reveal_version(client: &impl ChainBackend) -> anyhow::Result<u16> {
+    Ok(crate::crv4::get_commit_reveal_version(client).await?)
+}
+
+/// [`get_tempo`](crate::crv4::get_tempo) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::get_tempo`, which returns `BittensorResult`")]
+pub async fn get_tempo(client: &impl ChainBackend, netuid: u16) -> anyhow::Result<u16> {
+    Ok(crate::crv4::get_tempo(client, netuid).await?)
+}
+
+/// [`get_reveal_period`](crate::crv4::get_reveal_period) returning `anyhow::Result`
+#[deprecated(note = "use `crv4::get_reveal_period`, which returns `BittensorResult`")]
+pub async fn get_reveal_period(client: &impl ChainBackend, netuid: u16) -> anyhow::Result<u64> {
+    Ok(crate::crv4::get_reveal_period(client, netuid).await?)
+}
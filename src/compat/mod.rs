@@ -0,0 +1,91 @@
+//! Compatibility with the `anyhow` based API
+//!
+//! The `crv4`, `queries`, `validator` functions and [`Subtensor`] used to
+//! return `anyhow::Result`. They now return
+//! [`BittensorResult`](crate::errors::BittensorResult), so errors can be
+//! matched on without downcasting; see [`errors`](crate::errors) for how chain
+//! errors map to [`BittensorError`] variants.
+//!
+//! Code that keeps `anyhow` mostly needs no change, as `?` converts a
+//! [`BittensorError`] into `anyhow::Error`. This module covers the remaining
+//! cases for one release and will then be removed:
+//!
+//! - [`crv4`], [`queries`] and [`validator`] keep every changed function
+//!   under the same path with its old signature, e.g.
+//!   `compat::queries::stakes::get_stake` for `queries::stakes::get_stake`
+//! - [`Result`] for signatures that named the old return type
+//! - [`IntoAnyhow::into_anyhow`] where the result is returned without `?`
+//!
+//! [`Subtensor`] methods have no old forms; their errors convert with `?`.
+//!
+//! Downcasting an `anyhow::Error` built this way yields the
+//! [`BittensorError`], not the typed error struct it wraps:
+//!
+//! ```ignore
+//! // Before
+//! err.downcast_ref::<WeightVersionMismatch>()
+//! // After
+//! match err.downcast_ref::<BittensorError>() {
+//!     Some(BittensorError::WeightVersionMismatch(mismatch)) => ...,
+//! }
+//! ```
+//!
+//! [`Subtensor`]: crate::subtensor::Subtensor
+
+pub mod crv4;
+pub mod queries;
+pub mod validator;
+
+use crate::errors::BittensorResult;
+
+/// The previous return type of the `crv4`, `queries` and `validator`
+/// functions
+#[deprecated(note = "use `bittensor_rs::errors::BittensorResult`")]
+pub type Result<T> = anyhow::Result<T>;
+
+/// Convert a [`BittensorResult`] back into an `anyhow::Result`
+#[deprecated(note = "return `BittensorResult` or convert with `?`")]
+pub trait IntoAnyhow<T> {
+    /// The result with its error boxed into `anyhow::Error`
+    fn into_anyhow(self) -> anyhow::Result<T>;
+}
+
+#[allow(deprecated)]
+impl<T> IntoAnyhow<T> for BittensorResult<T> {
+    fn into_anyhow(self) -> anyhow::Result<T> {
+        self.map_err(anyhow::Error::from)
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+    use crate::errors::{BittensorError, ChainQueryError};
+
+    #[test]
+    fn test_into_anyhow_keeps_error() {
+        let result: BittensorResult<u64> = Err(ChainQueryError::new("missing").into());
+        let err = result.into_anyhow().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<BittensorError>(),
+            Some(BittensorError::ChainQuery(_))
+        ));
+
+        let ok: Result<u64> = BittensorResult::Ok(7).into_anyhow();
+        assert_eq!(ok.unwrap(), 7);
+    }
+
+    #[test]
+    fn test_old_signatures_call_through() {
+        assert_eq!(
+            crv4::calculate_reveal_round_for_blocks(100, 12.0, 1_000).unwrap(),
+            1_401
+        );
+        let err = crv4::calculate_reveal_round_for_blocks(100, 0.0, 1_000).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<BittensorError>(),
+            Some(BittensorError::Weights(_))
+        ));
+    }
+}
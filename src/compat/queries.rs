@@ -0,0 +1,1651 @@
+//! Deprecated `anyhow` forms of the [`queries`](crate::queries) functions
+//!
+//! Each function keeps its signature from before the switch to
+//! `BittensorResult` and calls the current one. They are removed together
+//! with this module.
+
+use std::future::Future;
+use std::time::Duration;
+
+/// [`with_retry`](crate::queries::with_retry) returning `anyhow::Result`
+#[deprecated(note = "use `queries::with_retry`, which returns `BittensorResult`")]
+pub async fn with_retry<F, Fut, T>(f: F, max_retries: u32) -> anyhow::Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = anyhow::Result<T>>,
+{
+    let mut last_err = None;
+    for attempt in 0..=max_retries {
+        match f().await {
+            Ok(val) => return Ok(val),
+            Err(e) => {
+                last_err = Some(e);
+                if attempt < max_retries {
+                    let delay = Duration::from_millis(100 * 2u64.pow(attempt));
+                    tokio::time::sleep(delay).await;
+                    tracing::warn!(
+                        attempt = attempt + 1,
+                        max_retries,
+                        "Retry attempt after failure"
+                    );
+                }
+            }
+        }
+    }
+    Err(last_err.unwrap_or_else(|| anyhow::anyhow!("All retry attempts failed")))
+}
+
+/// Deprecated forms of the [`queries::balances`](crate::queries::balances) functions
+pub mod balances {
+    use crate::chain::{BittensorClient, ChainBackend};
+    use crate::utils::balance_newtypes::Rao;
+    use sp_core::crypto::AccountId32;
+
+    /// [`get_balance`](crate::queries::balances::get_balance) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::balances::get_balance`, which returns `BittensorResult`")]
+    pub async fn get_balance(
+        client: &BittensorClient,
+        account: &AccountId32,
+    ) -> anyhow::Result<Rao> {
+        Ok(crate::queries::balances::get_balance(client, account).await?)
+    }
+
+    /// [`get_balances`](crate::queries::balances::get_balances) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::balances::get_balances`, which returns `BittensorResult`")]
+    pub async fn get_balances(
+        client: &BittensorClient,
+        accounts: &[AccountId32],
+    ) -> anyhow::Result<Vec<Rao>> {
+        Ok(crate::queries::balances::get_balances(client, accounts).await?)
+    }
+
+    /// [`get_existential_deposit`](crate::queries::balances::get_existential_deposit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::balances::get_existential_deposit`, which returns `BittensorResult`"
+    )]
+    pub async fn get_existential_deposit(client: &impl ChainBackend) -> anyhow::Result<Rao> {
+        Ok(crate::queries::balances::get_existential_deposit(client).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::bonds`](crate::queries::bonds) functions
+pub mod bonds {
+    use crate::chain::ChainBackend;
+
+    /// [`get_neuron_bonds`](crate::queries::bonds::get_neuron_bonds) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::bonds::get_neuron_bonds`, which returns `BittensorResult`")]
+    pub async fn get_neuron_bonds(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u64,
+        mechid: u16,
+    ) -> anyhow::Result<Vec<(u16, u64)>> {
+        Ok(crate::queries::bonds::get_neuron_bonds(client, netuid, uid, mechid).await?)
+    }
+
+    /// [`get_all_bonds`](crate::queries::bonds::get_all_bonds) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::bonds::get_all_bonds`, which returns `BittensorResult`")]
+    pub async fn get_all_bonds(
+        client: &impl ChainBackend,
+        netuid: u16,
+        mechid: u16,
+    ) -> anyhow::Result<Vec<(u64, Vec<(u16, u64)>)>> {
+        Ok(crate::queries::bonds::get_all_bonds(client, netuid, mechid).await?)
+    }
+
+    /// [`get_neuron_weights`](crate::queries::bonds::get_neuron_weights) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::bonds::get_neuron_weights`, which returns `BittensorResult`"
+    )]
+    pub async fn get_neuron_weights(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u64,
+        mechid: u16,
+    ) -> anyhow::Result<Vec<(u16, u16)>> {
+        Ok(crate::queries::bonds::get_neuron_weights(client, netuid, uid, mechid).await?)
+    }
+
+    /// [`get_uid_weights`](crate::queries::bonds::get_uid_weights) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::bonds::get_uid_weights`, which returns `BittensorResult`")]
+    pub async fn get_uid_weights(
+        client: &impl ChainBackend,
+        netuid: u16,
+        mechanism_id: u8,
+        uid: u16,
+    ) -> anyhow::Result<Vec<(u16, u16)>> {
+        Ok(crate::queries::bonds::get_uid_weights(client, netuid, mechanism_id, uid).await?)
+    }
+
+    /// [`get_all_weights`](crate::queries::bonds::get_all_weights) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::bonds::get_all_weights`, which returns `BittensorResult`")]
+    pub async fn get_all_weights(
+        client: &impl ChainBackend,
+        netuid: u16,
+        mechid: u16,
+    ) -> anyhow::Result<Vec<(u64, Vec<(u16, u16)>)>> {
+        Ok(crate::queries::bonds::get_all_weights(client, netuid, mechid).await?)
+    }
+
+    /// [`subnet_n`](crate::queries::bonds::subnet_n) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::bonds::subnet_n`, which returns `BittensorResult`")]
+    #[allow(dead_code)]
+    pub async fn subnet_n(client: &impl ChainBackend, netuid: u16) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::bonds::subnet_n(client, netuid).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::chain_info`](crate::queries::chain_info) functions
+pub mod chain_info {
+    use crate::chain::{BittensorClient, ChainBackend};
+    use crate::queries::chain_info::NetworkStats;
+
+    /// [`get_timestamp`](crate::queries::chain_info::get_timestamp) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::get_timestamp`, which returns `BittensorResult`"
+    )]
+    pub async fn get_timestamp(client: &impl ChainBackend) -> anyhow::Result<u64> {
+        Ok(crate::queries::chain_info::get_timestamp(client).await?)
+    }
+
+    /// [`last_drand_round`](crate::queries::chain_info::last_drand_round) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::last_drand_round`, which returns `BittensorResult`"
+    )]
+    pub async fn last_drand_round(client: &impl ChainBackend) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::chain_info::last_drand_round(client).await?)
+    }
+
+    /// [`tx_rate_limit`](crate::queries::chain_info::tx_rate_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::tx_rate_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn tx_rate_limit(client: &impl ChainBackend) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::chain_info::tx_rate_limit(client).await?)
+    }
+
+    /// [`get_admin_freeze_window`](crate::queries::chain_info::get_admin_freeze_window) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::get_admin_freeze_window`, which returns `BittensorResult`"
+    )]
+    pub async fn get_admin_freeze_window(client: &impl ChainBackend) -> anyhow::Result<u64> {
+        Ok(crate::queries::chain_info::get_admin_freeze_window(client).await?)
+    }
+
+    /// [`is_in_admin_freeze_window`](crate::queries::chain_info::is_in_admin_freeze_window) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::is_in_admin_freeze_window`, which returns `BittensorResult`"
+    )]
+    pub async fn is_in_admin_freeze_window(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<bool> {
+        Ok(crate::queries::chain_info::is_in_admin_freeze_window(client, netuid).await?)
+    }
+
+    /// [`is_fast_blocks`](crate::queries::chain_info::is_fast_blocks) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::is_fast_blocks`, which returns `BittensorResult`"
+    )]
+    pub async fn is_fast_blocks(client: &impl ChainBackend) -> anyhow::Result<bool> {
+        Ok(crate::queries::chain_info::is_fast_blocks(client).await?)
+    }
+
+    /// [`get_total_issuance`](crate::queries::chain_info::get_total_issuance) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::get_total_issuance`, which returns `BittensorResult`"
+    )]
+    pub async fn get_total_issuance(client: &impl ChainBackend) -> anyhow::Result<u128> {
+        Ok(crate::queries::chain_info::get_total_issuance(client).await?)
+    }
+
+    /// [`get_total_stake`](crate::queries::chain_info::get_total_stake) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::get_total_stake`, which returns `BittensorResult`"
+    )]
+    pub async fn get_total_stake(client: &impl ChainBackend) -> anyhow::Result<u128> {
+        Ok(crate::queries::chain_info::get_total_stake(client).await?)
+    }
+
+    /// [`get_block_hash`](crate::queries::chain_info::get_block_hash) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::get_block_hash`, which returns `BittensorResult`"
+    )]
+    pub async fn get_block_hash(
+        client: &BittensorClient,
+        block_number: u64,
+    ) -> anyhow::Result<[u8; 32]> {
+        Ok(crate::queries::chain_info::get_block_hash(client, block_number).await?)
+    }
+
+    /// [`get_current_block_with_retry`](crate::queries::chain_info::get_current_block_with_retry) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::get_current_block_with_retry`, which returns `BittensorResult`"
+    )]
+    pub async fn get_current_block_with_retry(
+        client: &impl ChainBackend,
+        max_retries: u32,
+    ) -> anyhow::Result<u64> {
+        Ok(crate::queries::chain_info::get_current_block_with_retry(client, max_retries).await?)
+    }
+
+    /// [`get_total_subnets`](crate::queries::chain_info::get_total_subnets) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::get_total_subnets`, which returns `BittensorResult`"
+    )]
+    pub async fn get_total_subnets(client: &impl ChainBackend) -> anyhow::Result<u16> {
+        Ok(crate::queries::chain_info::get_total_subnets(client).await?)
+    }
+
+    /// [`get_network_stats`](crate::queries::chain_info::get_network_stats) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::chain_info::get_network_stats`, which returns `BittensorResult`"
+    )]
+    pub async fn get_network_stats(client: &BittensorClient) -> anyhow::Result<NetworkStats> {
+        Ok(crate::queries::chain_info::get_network_stats(client).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::commitments`](crate::queries::commitments) functions
+pub mod commitments {
+    use crate::chain::ChainBackend;
+    use crate::queries::commitments::WeightCommitInfo;
+    use sp_core::crypto::AccountId32;
+
+    /// [`get_commitment`](crate::queries::commitments::get_commitment) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_commitment`, which returns `BittensorResult`"
+    )]
+    pub async fn get_commitment(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<(u64, Vec<u8>)>> {
+        Ok(crate::queries::commitments::get_commitment(client, netuid, hotkey).await?)
+    }
+
+    /// [`get_revealed_commitments`](crate::queries::commitments::get_revealed_commitments) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_revealed_commitments`, which returns `BittensorResult`"
+    )]
+    pub async fn get_revealed_commitments(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>)>> {
+        Ok(crate::queries::commitments::get_revealed_commitments(client, netuid, hotkey).await?)
+    }
+
+    /// [`get_revealed_commitment_by_hotkey`](crate::queries::commitments::get_revealed_commitment_by_hotkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_revealed_commitment_by_hotkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_revealed_commitment_by_hotkey(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Vec<(u64, String)>> {
+        Ok(
+            crate::queries::commitments::get_revealed_commitment_by_hotkey(client, netuid, hotkey)
+                .await?,
+        )
+    }
+
+    /// [`get_revealed_commitment`](crate::queries::commitments::get_revealed_commitment) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_revealed_commitment`, which returns `BittensorResult`"
+    )]
+    pub async fn get_revealed_commitment(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<(u64, String)>> {
+        Ok(crate::queries::commitments::get_revealed_commitment(client, netuid, hotkey).await?)
+    }
+
+    /// [`get_current_weight_commit_info`](crate::queries::commitments::get_current_weight_commit_info) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_current_weight_commit_info`, which returns `BittensorResult`"
+    )]
+    pub async fn get_current_weight_commit_info(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        Ok(crate::queries::commitments::get_current_weight_commit_info(client, netuid).await?)
+    }
+
+    /// [`get_timelocked_weight_commits`](crate::queries::commitments::get_timelocked_weight_commits) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_timelocked_weight_commits`, which returns `BittensorResult`"
+    )]
+    pub async fn get_timelocked_weight_commits(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        Ok(crate::queries::commitments::get_timelocked_weight_commits(client, netuid).await?)
+    }
+
+    /// [`get_all_commitments`](crate::queries::commitments::get_all_commitments) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_all_commitments`, which returns `BittensorResult`"
+    )]
+    pub async fn get_all_commitments(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<std::collections::HashMap<AccountId32, (u64, Vec<u8>)>> {
+        Ok(crate::queries::commitments::get_all_commitments(client, netuid).await?)
+    }
+
+    /// [`get_last_commitment_block`](crate::queries::commitments::get_last_commitment_block) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_last_commitment_block`, which returns `BittensorResult`"
+    )]
+    pub async fn get_last_commitment_block(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::commitments::get_last_commitment_block(client, netuid, hotkey).await?)
+    }
+
+    /// [`get_commitment_rate_limit`](crate::queries::commitments::get_commitment_rate_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_commitment_rate_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn get_commitment_rate_limit(client: &impl ChainBackend) -> anyhow::Result<u64> {
+        Ok(crate::queries::commitments::get_commitment_rate_limit(client).await?)
+    }
+
+    /// [`get_all_revealed_commitments`](crate::queries::commitments::get_all_revealed_commitments) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_all_revealed_commitments`, which returns `BittensorResult`"
+    )]
+    pub async fn get_all_revealed_commitments(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<std::collections::HashMap<AccountId32, Vec<(u64, String)>>> {
+        Ok(crate::queries::commitments::get_all_revealed_commitments(client, netuid).await?)
+    }
+
+    /// [`get_last_commitment_bonds_reset_block`](crate::queries::commitments::get_last_commitment_bonds_reset_block) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_last_commitment_bonds_reset_block`, which returns `BittensorResult`"
+    )]
+    pub async fn get_last_commitment_bonds_reset_block(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(
+            crate::queries::commitments::get_last_commitment_bonds_reset_block(
+                client, netuid, hotkey,
+            )
+            .await?,
+        )
+    }
+
+    /// [`get_current_weight_commit_info_v2`](crate::queries::commitments::get_current_weight_commit_info_v2) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_current_weight_commit_info_v2`, which returns `BittensorResult`"
+    )]
+    pub async fn get_current_weight_commit_info_v2(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Vec<(AccountId32, u64, String, u64)>> {
+        Ok(crate::queries::commitments::get_current_weight_commit_info_v2(client, netuid).await?)
+    }
+
+    /// [`get_timelocked_weight_commits_v4`](crate::queries::commitments::get_timelocked_weight_commits_v4) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::commitments::get_timelocked_weight_commits_v4`, which returns `BittensorResult`"
+    )]
+    pub async fn get_timelocked_weight_commits_v4(
+        client: &impl ChainBackend,
+        netuid: u16,
+        mechanism_id: u8,
+    ) -> anyhow::Result<Vec<(AccountId32, WeightCommitInfo)>> {
+        Ok(
+            crate::queries::commitments::get_timelocked_weight_commits_v4(
+                client,
+                netuid,
+                mechanism_id,
+            )
+            .await?,
+        )
+    }
+}
+
+/// Deprecated forms of the [`queries::delegates`](crate::queries::delegates) functions
+pub mod delegates {
+    use crate::chain::ChainBackend;
+    use crate::types::{DelegateInfo, DelegatedInfo};
+    use sp_core::crypto::AccountId32;
+
+    /// [`get_delegate_by_hotkey`](crate::queries::delegates::get_delegate_by_hotkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::delegates::get_delegate_by_hotkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_delegate_by_hotkey(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<DelegateInfo>> {
+        Ok(crate::queries::delegates::get_delegate_by_hotkey(client, hotkey).await?)
+    }
+
+    /// [`get_delegate_identities`](crate::queries::delegates::get_delegate_identities) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::delegates::get_delegate_identities`, which returns `BittensorResult`"
+    )]
+    pub async fn get_delegate_identities(
+        client: &impl ChainBackend,
+    ) -> anyhow::Result<Vec<AccountId32>> {
+        Ok(crate::queries::delegates::get_delegate_identities(client).await?)
+    }
+
+    /// [`get_delegated`](crate::queries::delegates::get_delegated) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::delegates::get_delegated`, which returns `BittensorResult`")]
+    pub async fn get_delegated(
+        client: &impl ChainBackend,
+        coldkey: &AccountId32,
+    ) -> anyhow::Result<Vec<DelegatedInfo>> {
+        Ok(crate::queries::delegates::get_delegated(client, coldkey).await?)
+    }
+
+    /// [`get_delegates`](crate::queries::delegates::get_delegates) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::delegates::get_delegates`, which returns `BittensorResult`")]
+    pub async fn get_delegates(client: &impl ChainBackend) -> anyhow::Result<Vec<DelegateInfo>> {
+        Ok(crate::queries::delegates::get_delegates(client).await?)
+    }
+
+    /// [`get_delegates_from_storage`](crate::queries::delegates::get_delegates_from_storage) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::delegates::get_delegates_from_storage`, which returns `BittensorResult`"
+    )]
+    pub async fn get_delegates_from_storage(
+        client: &impl ChainBackend,
+    ) -> anyhow::Result<Vec<DelegateInfo>> {
+        Ok(crate::queries::delegates::get_delegates_from_storage(client).await?)
+    }
+
+    /// [`get_delegate_take`](crate::queries::delegates::get_delegate_take) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::delegates::get_delegate_take`, which returns `BittensorResult`"
+    )]
+    pub async fn get_delegate_take(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<f64> {
+        Ok(crate::queries::delegates::get_delegate_take(client, hotkey).await?)
+    }
+
+    /// [`get_delegate_take_raw`](crate::queries::delegates::get_delegate_take_raw) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::delegates::get_delegate_take_raw`, which returns `BittensorResult`"
+    )]
+    pub async fn get_delegate_take_raw(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<u16> {
+        Ok(crate::queries::delegates::get_delegate_take_raw(client, hotkey).await?)
+    }
+
+    /// [`get_total_hotkey_stake`](crate::queries::delegates::get_total_hotkey_stake) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::delegates::get_total_hotkey_stake`, which returns `BittensorResult`"
+    )]
+    pub async fn get_total_hotkey_stake(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<u128> {
+        Ok(crate::queries::delegates::get_total_hotkey_stake(client, hotkey).await?)
+    }
+
+    /// [`get_delegate_info_optimized`](crate::queries::delegates::get_delegate_info_optimized) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::delegates::get_delegate_info_optimized`, which returns `BittensorResult`"
+    )]
+    pub async fn get_delegate_info_optimized(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<DelegateInfo>> {
+        Ok(crate::queries::delegates::get_delegate_info_optimized(client, hotkey).await?)
+    }
+
+    /// [`is_hotkey_delegate`](crate::queries::delegates::is_hotkey_delegate) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::delegates::is_hotkey_delegate`, which returns `BittensorResult`"
+    )]
+    pub async fn is_hotkey_delegate(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<bool> {
+        Ok(crate::queries::delegates::is_hotkey_delegate(client, hotkey).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::identity`](crate::queries::identity) functions
+pub mod identity {
+    use crate::chain::ChainBackend;
+    use crate::types::ChainIdentity;
+
+    /// [`query_identity`](crate::queries::identity::query_identity) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::identity::query_identity`, which returns `BittensorResult`")]
+    pub async fn query_identity(
+        client: &impl ChainBackend,
+        coldkey: &sp_core::crypto::AccountId32,
+    ) -> anyhow::Result<Option<ChainIdentity>> {
+        Ok(crate::queries::identity::query_identity(client, coldkey).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::liquidity`](crate::queries::liquidity) functions
+pub mod liquidity {
+    use crate::chain::ChainBackend;
+    use crate::types::LiquidityPosition;
+    use sp_core::crypto::AccountId32;
+
+    /// [`get_liquidity_list`](crate::queries::liquidity::get_liquidity_list) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::liquidity::get_liquidity_list`, which returns `BittensorResult`"
+    )]
+    pub async fn get_liquidity_list(
+        client: &impl ChainBackend,
+        netuid: u16,
+        coldkey: &AccountId32,
+        _block: Option<u64>,
+    ) -> anyhow::Result<Vec<LiquidityPosition>> {
+        Ok(crate::queries::liquidity::get_liquidity_list(client, netuid, coldkey, _block).await?)
+    }
+
+    /// [`get_positions`](crate::queries::liquidity::get_positions) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::liquidity::get_positions`, which returns `BittensorResult`")]
+    pub async fn get_positions(
+        client: &impl ChainBackend,
+        coldkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<Vec<LiquidityPosition>> {
+        Ok(crate::queries::liquidity::get_positions(client, coldkey, netuid).await?)
+    }
+
+    /// [`get_current_subnet_price`](crate::queries::liquidity::get_current_subnet_price) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::liquidity::get_current_subnet_price`, which returns `BittensorResult`"
+    )]
+    pub async fn get_current_subnet_price(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<f64> {
+        Ok(crate::queries::liquidity::get_current_subnet_price(client, netuid).await?)
+    }
+
+    /// [`get_current_subnet_price_rao`](crate::queries::liquidity::get_current_subnet_price_rao) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::liquidity::get_current_subnet_price_rao`, which returns `BittensorResult`"
+    )]
+    pub async fn get_current_subnet_price_rao(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<u128> {
+        Ok(crate::queries::liquidity::get_current_subnet_price_rao(client, netuid).await?)
+    }
+
+    /// Deprecated forms of the [`queries::liquidity::ticks`](crate::queries::liquidity::ticks) functions
+    pub mod ticks {
+        /// [`price_range_to_ticks`](crate::queries::liquidity::ticks::price_range_to_ticks) returning `anyhow::Result`
+        #[deprecated(
+            note = "use `queries::liquidity::ticks::price_range_to_ticks`, which returns `BittensorResult`"
+        )]
+        pub fn price_range_to_ticks(price_low: f64, price_high: f64) -> anyhow::Result<(i32, i32)> {
+            Ok(crate::queries::liquidity::ticks::price_range_to_ticks(
+                price_low, price_high,
+            )?)
+        }
+
+        /// [`liquidity_for_tao`](crate::queries::liquidity::ticks::liquidity_for_tao) returning `anyhow::Result`
+        #[deprecated(
+            note = "use `queries::liquidity::ticks::liquidity_for_tao`, which returns `BittensorResult`"
+        )]
+        pub fn liquidity_for_tao(
+            tao_rao: u128,
+            current_price: f64,
+            tick_low: i32,
+            tick_high: i32,
+        ) -> anyhow::Result<u64> {
+            Ok(crate::queries::liquidity::ticks::liquidity_for_tao(
+                tao_rao,
+                current_price,
+                tick_low,
+                tick_high,
+            )?)
+        }
+    }
+}
+
+/// Deprecated forms of the [`queries::metagraph_queries`](crate::queries::metagraph_queries) functions
+pub mod metagraph_queries {
+    use crate::chain::{BittensorClient, ChainBackend};
+    use crate::metagraph::Metagraph;
+    use crate::types::MetagraphInfo;
+
+    /// [`get_metagraph_info`](crate::queries::metagraph_queries::get_metagraph_info) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::metagraph_queries::get_metagraph_info`, which returns `BittensorResult`"
+    )]
+    pub async fn get_metagraph_info(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Metagraph> {
+        Ok(crate::queries::metagraph_queries::get_metagraph_info(client, netuid).await?)
+    }
+
+    /// [`metagraph_at_block`](crate::queries::metagraph_queries::metagraph_at_block) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::metagraph_queries::metagraph_at_block`, which returns `BittensorResult`"
+    )]
+    pub async fn metagraph_at_block(
+        client: &BittensorClient,
+        netuid: u16,
+        height: u64,
+    ) -> anyhow::Result<Metagraph> {
+        Ok(crate::queries::metagraph_queries::metagraph_at_block(client, netuid, height).await?)
+    }
+
+    /// [`get_last_update`](crate::queries::metagraph_queries::get_last_update) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::metagraph_queries::get_last_update`, which returns `BittensorResult`"
+    )]
+    pub async fn get_last_update(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u16,
+    ) -> anyhow::Result<u64> {
+        Ok(crate::queries::metagraph_queries::get_last_update(client, netuid, uid).await?)
+    }
+
+    /// [`blocks_since_last_update`](crate::queries::metagraph_queries::blocks_since_last_update) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::metagraph_queries::blocks_since_last_update`, which returns `BittensorResult`"
+    )]
+    pub async fn blocks_since_last_update(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u16,
+    ) -> anyhow::Result<u64> {
+        Ok(
+            crate::queries::metagraph_queries::blocks_since_last_update(client, netuid, uid)
+                .await?,
+        )
+    }
+
+    /// [`get_validator_permits`](crate::queries::metagraph_queries::get_validator_permits) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::metagraph_queries::get_validator_permits`, which returns `BittensorResult`"
+    )]
+    pub async fn get_validator_permits(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Vec<bool>> {
+        Ok(crate::queries::metagraph_queries::get_validator_permits(client, netuid).await?)
+    }
+
+    /// [`get_all_metagraphs_info`](crate::queries::metagraph_queries::get_all_metagraphs_info) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::metagraph_queries::get_all_metagraphs_info`, which returns `BittensorResult`"
+    )]
+    pub async fn get_all_metagraphs_info(
+        client: &impl ChainBackend,
+    ) -> anyhow::Result<Vec<Metagraph>> {
+        Ok(crate::queries::metagraph_queries::get_all_metagraphs_info(client).await?)
+    }
+
+    /// [`get_metagraph_info_full`](crate::queries::metagraph_queries::get_metagraph_info_full) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::metagraph_queries::get_metagraph_info_full`, which returns `BittensorResult`"
+    )]
+    pub async fn get_metagraph_info_full(
+        client: &BittensorClient,
+        netuid: u16,
+    ) -> anyhow::Result<MetagraphInfo> {
+        Ok(crate::queries::metagraph_queries::get_metagraph_info_full(client, netuid).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::neurons`](crate::queries::neurons) functions
+pub mod neurons {
+    use crate::chain::ChainBackend;
+    use crate::queries::neurons::Certificate;
+    use crate::types::NeuronInfo;
+    use sp_core::crypto::AccountId32;
+    use std::collections::HashMap;
+
+    /// [`neurons`](crate::queries::neurons::neurons) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::neurons::neurons`, which returns `BittensorResult`")]
+    pub async fn neurons(
+        client: &impl ChainBackend,
+        netuid: u16,
+        _block: Option<u64>,
+    ) -> anyhow::Result<Vec<NeuronInfo>> {
+        Ok(crate::queries::neurons::neurons(client, netuid, _block).await?)
+    }
+
+    /// [`neuron`](crate::queries::neurons::neuron) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::neurons::neuron`, which returns `BittensorResult`")]
+    pub async fn neuron(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u64,
+        block: Option<u64>,
+    ) -> anyhow::Result<Option<NeuronInfo>> {
+        Ok(crate::queries::neurons::neuron(client, netuid, uid, block).await?)
+    }
+
+    /// [`query_neuron_from_storage`](crate::queries::neurons::query_neuron_from_storage) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::query_neuron_from_storage`, which returns `BittensorResult`"
+    )]
+    pub async fn query_neuron_from_storage(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u64,
+        _block: Option<u64>,
+    ) -> anyhow::Result<Option<NeuronInfo>> {
+        Ok(crate::queries::neurons::query_neuron_from_storage(client, netuid, uid, _block).await?)
+    }
+
+    /// [`get_neuron_certificate`](crate::queries::neurons::get_neuron_certificate) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_neuron_certificate`, which returns `BittensorResult`"
+    )]
+    pub async fn get_neuron_certificate(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<Option<Certificate>> {
+        Ok(crate::queries::neurons::get_neuron_certificate(client, hotkey, netuid).await?)
+    }
+
+    /// [`get_all_neuron_certificates`](crate::queries::neurons::get_all_neuron_certificates) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_all_neuron_certificates`, which returns `BittensorResult`"
+    )]
+    pub async fn get_all_neuron_certificates(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<HashMap<AccountId32, Certificate>> {
+        Ok(crate::queries::neurons::get_all_neuron_certificates(client, netuid).await?)
+    }
+
+    /// [`get_neuron_for_pubkey_and_subnet`](crate::queries::neurons::get_neuron_for_pubkey_and_subnet) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_neuron_for_pubkey_and_subnet`, which returns `BittensorResult`"
+    )]
+    pub async fn get_neuron_for_pubkey_and_subnet(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<Option<NeuronInfo>> {
+        Ok(
+            crate::queries::neurons::get_neuron_for_pubkey_and_subnet(client, hotkey, netuid)
+                .await?,
+        )
+    }
+
+    /// [`get_children`](crate::queries::neurons::get_children) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::neurons::get_children`, which returns `BittensorResult`")]
+    pub async fn get_children(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<Vec<(f64, AccountId32)>> {
+        Ok(crate::queries::neurons::get_children(client, hotkey, netuid).await?)
+    }
+
+    /// [`get_children_pending`](crate::queries::neurons::get_children_pending) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_children_pending`, which returns `BittensorResult`"
+    )]
+    pub async fn get_children_pending(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<(Vec<(f64, AccountId32)>, u64)> {
+        Ok(crate::queries::neurons::get_children_pending(client, hotkey, netuid).await?)
+    }
+
+    /// [`get_parents`](crate::queries::neurons::get_parents) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::neurons::get_parents`, which returns `BittensorResult`")]
+    pub async fn get_parents(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<Vec<(f64, AccountId32)>> {
+        Ok(crate::queries::neurons::get_parents(client, hotkey, netuid).await?)
+    }
+
+    /// [`neurons_lite`](crate::queries::neurons::neurons_lite) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::neurons::neurons_lite`, which returns `BittensorResult`")]
+    pub async fn neurons_lite(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Vec<crate::types::NeuronInfoLite>> {
+        Ok(crate::queries::neurons::neurons_lite(client, netuid).await?)
+    }
+
+    /// [`get_uid_for_hotkey`](crate::queries::neurons::get_uid_for_hotkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_uid_for_hotkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_uid_for_hotkey(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<u16>> {
+        Ok(crate::queries::neurons::get_uid_for_hotkey(client, netuid, hotkey).await?)
+    }
+
+    /// [`is_hotkey_registered_any`](crate::queries::neurons::is_hotkey_registered_any) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::is_hotkey_registered_any`, which returns `BittensorResult`"
+    )]
+    pub async fn is_hotkey_registered_any(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Vec<u16>> {
+        Ok(crate::queries::neurons::is_hotkey_registered_any(client, hotkey).await?)
+    }
+
+    /// [`get_hotkey_owner`](crate::queries::neurons::get_hotkey_owner) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_hotkey_owner`, which returns `BittensorResult`"
+    )]
+    pub async fn get_hotkey_owner(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<AccountId32>> {
+        Ok(crate::queries::neurons::get_hotkey_owner(client, hotkey).await?)
+    }
+
+    /// [`get_hotkey_for_uid`](crate::queries::neurons::get_hotkey_for_uid) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_hotkey_for_uid`, which returns `BittensorResult`"
+    )]
+    pub async fn get_hotkey_for_uid(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u16,
+    ) -> anyhow::Result<Option<AccountId32>> {
+        Ok(crate::queries::neurons::get_hotkey_for_uid(client, netuid, uid).await?)
+    }
+
+    /// [`get_block_at_registration`](crate::queries::neurons::get_block_at_registration) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_block_at_registration`, which returns `BittensorResult`"
+    )]
+    pub async fn get_block_at_registration(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u16,
+    ) -> anyhow::Result<u64> {
+        Ok(crate::queries::neurons::get_block_at_registration(client, netuid, uid).await?)
+    }
+
+    /// [`immunity_remaining`](crate::queries::neurons::immunity_remaining) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::immunity_remaining`, which returns `BittensorResult`"
+    )]
+    pub async fn immunity_remaining(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::neurons::immunity_remaining(client, netuid, uid).await?)
+    }
+
+    /// [`get_blocks_at_registration`](crate::queries::neurons::get_blocks_at_registration) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_blocks_at_registration`, which returns `BittensorResult`"
+    )]
+    pub async fn get_blocks_at_registration(
+        client: &impl ChainBackend,
+        netuid: u16,
+        n: u64,
+    ) -> anyhow::Result<Vec<u64>> {
+        Ok(crate::queries::neurons::get_blocks_at_registration(client, netuid, n).await?)
+    }
+
+    /// [`get_recent_registrations`](crate::queries::neurons::get_recent_registrations) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_recent_registrations`, which returns `BittensorResult`"
+    )]
+    pub async fn get_recent_registrations(
+        client: &impl ChainBackend,
+        netuid: u16,
+        count: usize,
+    ) -> anyhow::Result<Vec<(u16, u64)>> {
+        Ok(crate::queries::neurons::get_recent_registrations(client, netuid, count).await?)
+    }
+
+    /// [`get_stake_weights_for_subnet`](crate::queries::neurons::get_stake_weights_for_subnet) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::neurons::get_stake_weights_for_subnet`, which returns `BittensorResult`"
+    )]
+    pub async fn get_stake_weights_for_subnet(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<(Vec<u128>, Vec<u128>, Vec<u128>)> {
+        Ok(crate::queries::neurons::get_stake_weights_for_subnet(client, netuid).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::runtime`](crate::queries::runtime) functions
+pub mod runtime {
+    use crate::chain::ChainBackend;
+
+    /// [`get_weights_version_key`](crate::queries::runtime::get_weights_version_key) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::runtime::get_weights_version_key`, which returns `BittensorResult`"
+    )]
+    pub async fn get_weights_version_key(client: &impl ChainBackend) -> anyhow::Result<u64> {
+        Ok(crate::queries::runtime::get_weights_version_key(client).await?)
+    }
+
+    /// [`commit_reveal_enabled`](crate::queries::runtime::commit_reveal_enabled) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::runtime::commit_reveal_enabled`, which returns `BittensorResult`"
+    )]
+    pub async fn commit_reveal_enabled(client: &impl ChainBackend) -> anyhow::Result<bool> {
+        Ok(crate::queries::runtime::commit_reveal_enabled(client).await?)
+    }
+
+    /// [`get_tempo`](crate::queries::runtime::get_tempo) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::runtime::get_tempo`, which returns `BittensorResult`")]
+    pub async fn get_tempo(client: &impl ChainBackend, netuid: u16) -> anyhow::Result<u16> {
+        Ok(crate::queries::runtime::get_tempo(client, netuid).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::stakes`](crate::queries::stakes) functions
+pub mod stakes {
+    use crate::chain::{BittensorClient, ChainBackend};
+    use crate::queries::stakes::{StakeEmissionHistory, StakeInfo};
+    use crate::utils::balance_newtypes::Rao;
+    use sp_core::crypto::AccountId32;
+
+    /// [`get_stake`](crate::queries::stakes::get_stake) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::stakes::get_stake`, which returns `BittensorResult`")]
+    pub async fn get_stake(
+        client: &impl ChainBackend,
+        coldkey: &AccountId32,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<Rao> {
+        Ok(crate::queries::stakes::get_stake(client, coldkey, hotkey, netuid).await?)
+    }
+
+    /// [`get_stake_for_coldkey`](crate::queries::stakes::get_stake_for_coldkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_stake_for_coldkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_stake_for_coldkey(
+        client: &impl ChainBackend,
+        coldkey: &AccountId32,
+    ) -> anyhow::Result<Vec<(u16, Rao)>> {
+        Ok(crate::queries::stakes::get_stake_for_coldkey(client, coldkey).await?)
+    }
+
+    /// [`get_stake_for_hotkey`](crate::queries::stakes::get_stake_for_hotkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_stake_for_hotkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_stake_for_hotkey(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<Rao> {
+        Ok(crate::queries::stakes::get_stake_for_hotkey(client, hotkey, netuid).await?)
+    }
+
+    /// [`get_stake_for_coldkey_and_hotkey`](crate::queries::stakes::get_stake_for_coldkey_and_hotkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_stake_for_coldkey_and_hotkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_stake_for_coldkey_and_hotkey(
+        client: &impl ChainBackend,
+        coldkey: &AccountId32,
+        hotkey: &AccountId32,
+        netuids: Option<Vec<u16>>,
+    ) -> anyhow::Result<std::collections::HashMap<u16, Rao>> {
+        Ok(crate::queries::stakes::get_stake_for_coldkey_and_hotkey(
+            client, coldkey, hotkey, netuids,
+        )
+        .await?)
+    }
+
+    /// [`get_auto_stakes`](crate::queries::stakes::get_auto_stakes) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::stakes::get_auto_stakes`, which returns `BittensorResult`")]
+    pub async fn get_auto_stakes(
+        client: &impl ChainBackend,
+        coldkey: &AccountId32,
+    ) -> anyhow::Result<std::collections::HashMap<u16, AccountId32>> {
+        Ok(crate::queries::stakes::get_auto_stakes(client, coldkey).await?)
+    }
+
+    /// [`get_stake_weight`](crate::queries::stakes::get_stake_weight) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::stakes::get_stake_weight`, which returns `BittensorResult`")]
+    pub async fn get_stake_weight(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<f64> {
+        Ok(crate::queries::stakes::get_stake_weight(client, netuid, hotkey).await?)
+    }
+
+    /// [`get_minimum_required_stake`](crate::queries::stakes::get_minimum_required_stake) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_minimum_required_stake`, which returns `BittensorResult`"
+    )]
+    pub async fn get_minimum_required_stake(client: &impl ChainBackend) -> anyhow::Result<Rao> {
+        Ok(crate::queries::stakes::get_minimum_required_stake(client).await?)
+    }
+
+    /// [`get_hotkey_stake`](crate::queries::stakes::get_hotkey_stake) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::stakes::get_hotkey_stake`, which returns `BittensorResult`")]
+    pub async fn get_hotkey_stake(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<Rao> {
+        Ok(crate::queries::stakes::get_hotkey_stake(client, hotkey, netuid).await?)
+    }
+
+    /// [`get_stake_add_fee`](crate::queries::stakes::get_stake_add_fee) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_stake_add_fee`, which returns `BittensorResult`"
+    )]
+    pub async fn get_stake_add_fee(
+        client: &impl ChainBackend,
+        amount: u128,
+        netuid: u16,
+    ) -> anyhow::Result<u128> {
+        Ok(crate::queries::stakes::get_stake_add_fee(client, amount, netuid).await?)
+    }
+
+    /// [`get_unstake_fee`](crate::queries::stakes::get_unstake_fee) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::stakes::get_unstake_fee`, which returns `BittensorResult`")]
+    pub async fn get_unstake_fee(
+        client: &impl ChainBackend,
+        amount: u128,
+        netuid: u16,
+    ) -> anyhow::Result<u128> {
+        Ok(crate::queries::stakes::get_unstake_fee(client, amount, netuid).await?)
+    }
+
+    /// [`get_stake_movement_fee`](crate::queries::stakes::get_stake_movement_fee) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_stake_movement_fee`, which returns `BittensorResult`"
+    )]
+    pub async fn get_stake_movement_fee(
+        client: &impl ChainBackend,
+        amount: u128,
+        netuid: u16,
+    ) -> anyhow::Result<u128> {
+        Ok(crate::queries::stakes::get_stake_movement_fee(client, amount, netuid).await?)
+    }
+
+    /// [`get_stake_operations_fee`](crate::queries::stakes::get_stake_operations_fee) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_stake_operations_fee`, which returns `BittensorResult`"
+    )]
+    pub async fn get_stake_operations_fee(
+        client: &impl ChainBackend,
+        amount: u128,
+        netuid: u16,
+    ) -> anyhow::Result<u128> {
+        Ok(crate::queries::stakes::get_stake_operations_fee(client, amount, netuid).await?)
+    }
+
+    /// [`get_stake_info_for_coldkey`](crate::queries::stakes::get_stake_info_for_coldkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_stake_info_for_coldkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_stake_info_for_coldkey(
+        client: &impl ChainBackend,
+        coldkey: &AccountId32,
+    ) -> anyhow::Result<Vec<StakeInfo>> {
+        Ok(crate::queries::stakes::get_stake_info_for_coldkey(client, coldkey).await?)
+    }
+
+    /// [`get_total_stake_for_hotkey`](crate::queries::stakes::get_total_stake_for_hotkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_total_stake_for_hotkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_total_stake_for_hotkey(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<u128> {
+        Ok(crate::queries::stakes::get_total_stake_for_hotkey(client, hotkey).await?)
+    }
+
+    /// [`get_total_stake_for_coldkey`](crate::queries::stakes::get_total_stake_for_coldkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_total_stake_for_coldkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_total_stake_for_coldkey(
+        client: &impl ChainBackend,
+        coldkey: &AccountId32,
+    ) -> anyhow::Result<u128> {
+        Ok(crate::queries::stakes::get_total_stake_for_coldkey(client, coldkey).await?)
+    }
+
+    /// [`get_stake_emission_history`](crate::queries::stakes::get_stake_emission_history) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::stakes::get_stake_emission_history`, which returns `BittensorResult`"
+    )]
+    pub async fn get_stake_emission_history(
+        client: &BittensorClient,
+        coldkey: &AccountId32,
+        hotkey: &AccountId32,
+        netuid: u16,
+        last_n_epochs: u64,
+    ) -> anyhow::Result<StakeEmissionHistory> {
+        Ok(crate::queries::stakes::get_stake_emission_history(
+            client,
+            coldkey,
+            hotkey,
+            netuid,
+            last_n_epochs,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::subnets`](crate::queries::subnets) functions
+pub mod subnets {
+    use crate::chain::{BittensorClient, ChainBackend};
+    use crate::types::{DynamicInfo, SubnetInfo, SubnetStateInfo};
+    use crate::utils::balance_newtypes::Rao;
+
+    /// [`commit_reveal_enabled`](crate::queries::subnets::commit_reveal_enabled) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::commit_reveal_enabled`, which returns `BittensorResult`"
+    )]
+    pub async fn commit_reveal_enabled(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<bool> {
+        Ok(crate::queries::subnets::commit_reveal_enabled(client, netuid).await?)
+    }
+
+    /// [`get_mechanism_count`](crate::queries::subnets::get_mechanism_count) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_mechanism_count`, which returns `BittensorResult`"
+    )]
+    pub async fn get_mechanism_count(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<u8> {
+        Ok(crate::queries::subnets::get_mechanism_count(client, netuid).await?)
+    }
+
+    /// [`recycle`](crate::queries::subnets::recycle) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::recycle`, which returns `BittensorResult`")]
+    pub async fn recycle(client: &impl ChainBackend, netuid: u16) -> anyhow::Result<Option<u128>> {
+        Ok(crate::queries::subnets::recycle(client, netuid).await?)
+    }
+
+    /// [`get_subnet_reveal_period_epochs`](crate::queries::subnets::get_subnet_reveal_period_epochs) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_subnet_reveal_period_epochs`, which returns `BittensorResult`"
+    )]
+    pub async fn get_subnet_reveal_period_epochs(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::get_subnet_reveal_period_epochs(client, netuid).await?)
+    }
+
+    /// [`is_subnet_active`](crate::queries::subnets::is_subnet_active) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::is_subnet_active`, which returns `BittensorResult`"
+    )]
+    pub async fn is_subnet_active(client: &impl ChainBackend, netuid: u16) -> anyhow::Result<bool> {
+        Ok(crate::queries::subnets::is_subnet_active(client, netuid).await?)
+    }
+
+    /// [`all_subnets`](crate::queries::subnets::all_subnets) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::all_subnets`, which returns `BittensorResult`")]
+    pub async fn all_subnets(client: &impl ChainBackend) -> anyhow::Result<Vec<SubnetInfo>> {
+        Ok(crate::queries::subnets::all_subnets(client).await?)
+    }
+
+    /// [`subnet_info`](crate::queries::subnets::subnet_info) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::subnet_info`, which returns `BittensorResult`")]
+    pub async fn subnet_info(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<SubnetInfo>> {
+        Ok(crate::queries::subnets::subnet_info(client, netuid).await?)
+    }
+
+    /// [`all_subnets_info`](crate::queries::subnets::all_subnets_info) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::all_subnets_info`, which returns `BittensorResult`"
+    )]
+    pub async fn all_subnets_info(client: &impl ChainBackend) -> anyhow::Result<Vec<SubnetInfo>> {
+        Ok(crate::queries::subnets::all_subnets_info(client).await?)
+    }
+
+    /// [`subnet_n`](crate::queries::subnets::subnet_n) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::subnet_n`, which returns `BittensorResult`")]
+    pub async fn subnet_n(client: &impl ChainBackend, netuid: u16) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::subnet_n(client, netuid).await?)
+    }
+
+    /// [`subnet_exists`](crate::queries::subnets::subnet_exists) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::subnet_exists`, which returns `BittensorResult`")]
+    pub async fn subnet_exists(client: &impl ChainBackend, netuid: u16) -> anyhow::Result<bool> {
+        Ok(crate::queries::subnets::subnet_exists(client, netuid).await?)
+    }
+
+    /// [`get_transfer_toggle`](crate::queries::subnets::get_transfer_toggle) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_transfer_toggle`, which returns `BittensorResult`"
+    )]
+    pub async fn get_transfer_toggle(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<bool> {
+        Ok(crate::queries::subnets::get_transfer_toggle(client, netuid).await?)
+    }
+
+    /// [`total_subnets`](crate::queries::subnets::total_subnets) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::total_subnets`, which returns `BittensorResult`")]
+    pub async fn total_subnets(client: &impl ChainBackend) -> anyhow::Result<u16> {
+        Ok(crate::queries::subnets::total_subnets(client).await?)
+    }
+
+    /// [`difficulty`](crate::queries::subnets::difficulty) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::difficulty`, which returns `BittensorResult`")]
+    pub async fn difficulty(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::difficulty(client, netuid).await?)
+    }
+
+    /// [`tempo`](crate::queries::subnets::tempo) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::tempo`, which returns `BittensorResult`")]
+    pub async fn tempo(client: &impl ChainBackend, netuid: u16) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::tempo(client, netuid).await?)
+    }
+
+    /// [`min_allowed_weights`](crate::queries::subnets::min_allowed_weights) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::min_allowed_weights`, which returns `BittensorResult`"
+    )]
+    pub async fn min_allowed_weights(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::min_allowed_weights(client, netuid).await?)
+    }
+
+    /// [`max_weight_limit`](crate::queries::subnets::max_weight_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::max_weight_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn max_weight_limit(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u16>> {
+        Ok(crate::queries::subnets::max_weight_limit(client, netuid).await?)
+    }
+
+    /// [`immunity_period`](crate::queries::subnets::immunity_period) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::immunity_period`, which returns `BittensorResult`")]
+    pub async fn immunity_period(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::immunity_period(client, netuid).await?)
+    }
+
+    /// [`weights_rate_limit`](crate::queries::subnets::weights_rate_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::weights_rate_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn weights_rate_limit(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::weights_rate_limit(client, netuid).await?)
+    }
+
+    /// [`blocks_since_last_step`](crate::queries::subnets::blocks_since_last_step) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::blocks_since_last_step`, which returns `BittensorResult`"
+    )]
+    pub async fn blocks_since_last_step(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::blocks_since_last_step(client, netuid).await?)
+    }
+
+    /// [`blocks_since_last_update`](crate::queries::subnets::blocks_since_last_update) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::blocks_since_last_update`, which returns `BittensorResult`"
+    )]
+    pub async fn blocks_since_last_update(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::blocks_since_last_update(client, netuid).await?)
+    }
+
+    /// [`subnet_owner_hotkey`](crate::queries::subnets::subnet_owner_hotkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::subnet_owner_hotkey`, which returns `BittensorResult`"
+    )]
+    pub async fn subnet_owner_hotkey(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<sp_core::crypto::AccountId32>> {
+        Ok(crate::queries::subnets::subnet_owner_hotkey(client, netuid).await?)
+    }
+
+    /// [`subnet_validator_permits`](crate::queries::subnets::subnet_validator_permits) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::subnet_validator_permits`, which returns `BittensorResult`"
+    )]
+    pub async fn subnet_validator_permits(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Vec<bool>> {
+        Ok(crate::queries::subnets::subnet_validator_permits(client, netuid).await?)
+    }
+
+    /// [`mechanism_count`](crate::queries::subnets::mechanism_count) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::mechanism_count`, which returns `BittensorResult`")]
+    pub async fn mechanism_count(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::mechanism_count(client, netuid).await?)
+    }
+
+    /// [`mechanism_emission_split`](crate::queries::subnets::mechanism_emission_split) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::mechanism_emission_split`, which returns `BittensorResult`"
+    )]
+    pub async fn mechanism_emission_split(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::mechanism_emission_split(client, netuid).await?)
+    }
+
+    /// [`subnet_burn_cost`](crate::queries::subnets::subnet_burn_cost) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::subnet_burn_cost`, which returns `BittensorResult`"
+    )]
+    pub async fn subnet_burn_cost(client: &BittensorClient, _netuid: u16) -> anyhow::Result<Rao> {
+        Ok(crate::queries::subnets::subnet_burn_cost(client, _netuid).await?)
+    }
+
+    /// [`get_subnet_price`](crate::queries::subnets::get_subnet_price) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_subnet_price`, which returns `BittensorResult`"
+    )]
+    pub async fn get_subnet_price(client: &BittensorClient, netuid: u16) -> anyhow::Result<Rao> {
+        Ok(crate::queries::subnets::get_subnet_price(client, netuid).await?)
+    }
+
+    /// [`get_subnet_prices`](crate::queries::subnets::get_subnet_prices) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_subnet_prices`, which returns `BittensorResult`"
+    )]
+    pub async fn get_subnet_prices(
+        client: &BittensorClient,
+    ) -> anyhow::Result<std::collections::HashMap<u16, Rao>> {
+        Ok(crate::queries::subnets::get_subnet_prices(client).await?)
+    }
+
+    /// [`get_next_epoch_start_block`](crate::queries::subnets::get_next_epoch_start_block) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_next_epoch_start_block`, which returns `BittensorResult`"
+    )]
+    pub async fn get_next_epoch_start_block(
+        client: &impl ChainBackend,
+        netuid: u16,
+        block: Option<u64>,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::get_next_epoch_start_block(client, netuid, block).await?)
+    }
+
+    /// [`subnet_tao_in_emission`](crate::queries::subnets::subnet_tao_in_emission) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::subnet_tao_in_emission`, which returns `BittensorResult`"
+    )]
+    pub async fn subnet_tao_in_emission(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::subnet_tao_in_emission(client, netuid).await?)
+    }
+
+    /// [`block_emission`](crate::queries::subnets::block_emission) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::subnets::block_emission`, which returns `BittensorResult`")]
+    pub async fn block_emission(client: &impl ChainBackend) -> anyhow::Result<Option<u64>> {
+        Ok(crate::queries::subnets::block_emission(client).await?)
+    }
+
+    /// [`subnet_emission_percent`](crate::queries::subnets::subnet_emission_percent) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::subnet_emission_percent`, which returns `BittensorResult`"
+    )]
+    pub async fn subnet_emission_percent(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<f64>> {
+        Ok(crate::queries::subnets::subnet_emission_percent(client, netuid).await?)
+    }
+
+    /// The owner of a subnet, or None, as [`subnet_owner`](crate::queries::subnets::subnet_owner)
+    /// returns it
+    #[deprecated(note = "use `queries::subnets::subnet_owner`, which returns `BittensorResult`")]
+    pub async fn get_subnet_owner(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<sp_core::crypto::AccountId32>> {
+        Ok(crate::queries::subnets::subnet_owner(client, netuid).await?)
+    }
+
+    /// [`get_subnet_burn_cost`](crate::queries::subnets::get_subnet_burn_cost) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_subnet_burn_cost`, which returns `BittensorResult`"
+    )]
+    pub async fn get_subnet_burn_cost(client: &BittensorClient) -> anyhow::Result<u128> {
+        Ok(crate::queries::subnets::get_subnet_burn_cost(client).await?)
+    }
+
+    /// [`get_subnet_emission_value`](crate::queries::subnets::get_subnet_emission_value) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_subnet_emission_value`, which returns `BittensorResult`"
+    )]
+    pub async fn get_subnet_emission_value(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<u128> {
+        Ok(crate::queries::subnets::get_subnet_emission_value(client, netuid).await?)
+    }
+
+    /// [`get_all_subnets_info`](crate::queries::subnets::get_all_subnets_info) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_all_subnets_info`, which returns `BittensorResult`"
+    )]
+    pub async fn get_all_subnets_info(
+        client: &impl ChainBackend,
+    ) -> anyhow::Result<Vec<SubnetInfo>> {
+        Ok(crate::queries::subnets::get_all_subnets_info(client).await?)
+    }
+
+    /// [`get_dynamic_info`](crate::queries::subnets::get_dynamic_info) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_dynamic_info`, which returns `BittensorResult`"
+    )]
+    pub async fn get_dynamic_info(
+        client: &BittensorClient,
+        netuid: u16,
+    ) -> anyhow::Result<DynamicInfo> {
+        Ok(crate::queries::subnets::get_dynamic_info(client, netuid).await?)
+    }
+
+    /// [`get_all_dynamic_info`](crate::queries::subnets::get_all_dynamic_info) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_all_dynamic_info`, which returns `BittensorResult`"
+    )]
+    pub async fn get_all_dynamic_info(
+        client: &BittensorClient,
+    ) -> anyhow::Result<Vec<DynamicInfo>> {
+        Ok(crate::queries::subnets::get_all_dynamic_info(client).await?)
+    }
+
+    /// [`decode_subnet_state`](crate::queries::subnets::decode_subnet_state) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::decode_subnet_state`, which returns `BittensorResult`"
+    )]
+    pub fn decode_subnet_state(bytes: &[u8]) -> anyhow::Result<Option<SubnetStateInfo>> {
+        Ok(crate::queries::subnets::decode_subnet_state(bytes)?)
+    }
+
+    /// [`try_get_subnet_state`](crate::queries::subnets::try_get_subnet_state) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::try_get_subnet_state`, which returns `BittensorResult`"
+    )]
+    pub async fn try_get_subnet_state(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<Option<SubnetStateInfo>> {
+        Ok(crate::queries::subnets::try_get_subnet_state(client, netuid).await?)
+    }
+
+    /// [`get_subnet_state`](crate::queries::subnets::get_subnet_state) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::subnets::get_subnet_state`, which returns `BittensorResult`"
+    )]
+    pub async fn get_subnet_state(
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> anyhow::Result<SubnetStateInfo> {
+        Ok(crate::queries::subnets::get_subnet_state(client, netuid).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::voting`](crate::queries::voting) functions
+pub mod voting {
+    use crate::chain::ChainBackend;
+    use crate::types::ProposalVoteData;
+    use sp_core::H256;
+
+    /// [`get_vote_data`](crate::queries::voting::get_vote_data) returning `anyhow::Result`
+    #[deprecated(note = "use `queries::voting::get_vote_data`, which returns `BittensorResult`")]
+    pub async fn get_vote_data(
+        client: &impl ChainBackend,
+        proposal_hash: H256,
+    ) -> anyhow::Result<Option<ProposalVoteData>> {
+        Ok(crate::queries::voting::get_vote_data(client, proposal_hash).await?)
+    }
+}
+
+/// Deprecated forms of the [`queries::wallets`](crate::queries::wallets) functions
+pub mod wallets {
+    use crate::chain::ChainBackend;
+    use sp_core::crypto::AccountId32;
+
+    /// [`does_hotkey_exist`](crate::queries::wallets::does_hotkey_exist) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::wallets::does_hotkey_exist`, which returns `BittensorResult`"
+    )]
+    pub async fn does_hotkey_exist(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<bool> {
+        Ok(crate::queries::wallets::does_hotkey_exist(client, hotkey).await?)
+    }
+
+    /// [`is_hotkey_registered`](crate::queries::wallets::is_hotkey_registered) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::wallets::is_hotkey_registered`, which returns `BittensorResult`"
+    )]
+    pub async fn is_hotkey_registered(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<bool> {
+        Ok(crate::queries::wallets::is_hotkey_registered(client, hotkey, netuid).await?)
+    }
+
+    /// [`is_hotkey_registered_any`](crate::queries::wallets::is_hotkey_registered_any) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::wallets::is_hotkey_registered_any`, which returns `BittensorResult`"
+    )]
+    pub async fn is_hotkey_registered_any(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<bool> {
+        Ok(crate::queries::wallets::is_hotkey_registered_any(client, hotkey).await?)
+    }
+
+    /// [`get_netuids_for_hotkey`](crate::queries::wallets::get_netuids_for_hotkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::wallets::get_netuids_for_hotkey`, which returns `BittensorResult`"
+    )]
+    pub async fn get_netuids_for_hotkey(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Vec<u16>> {
+        Ok(crate::queries::wallets::get_netuids_for_hotkey(client, hotkey).await?)
+    }
+
+    /// [`get_owned_hotkeys`](crate::queries::wallets::get_owned_hotkeys) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::wallets::get_owned_hotkeys`, which returns `BittensorResult`"
+    )]
+    pub async fn get_owned_hotkeys(
+        client: &impl ChainBackend,
+        coldkey: &AccountId32,
+    ) -> anyhow::Result<Vec<AccountId32>> {
+        Ok(crate::queries::wallets::get_owned_hotkeys(client, coldkey).await?)
+    }
+
+    /// [`get_hotkey_owner`](crate::queries::wallets::get_hotkey_owner) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::wallets::get_hotkey_owner`, which returns `BittensorResult`"
+    )]
+    pub async fn get_hotkey_owner(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<AccountId32>> {
+        Ok(crate::queries::wallets::get_hotkey_owner(client, hotkey).await?)
+    }
+
+    /// [`filter_netuids_by_registered_hotkeys`](crate::queries::wallets::filter_netuids_by_registered_hotkeys) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::wallets::filter_netuids_by_registered_hotkeys`, which returns `BittensorResult`"
+    )]
+    pub async fn filter_netuids_by_registered_hotkeys(
+        client: &impl ChainBackend,
+        hotkeys: &[AccountId32],
+    ) -> anyhow::Result<Vec<u16>> {
+        Ok(crate::queries::wallets::filter_netuids_by_registered_hotkeys(client, hotkeys).await?)
+    }
+
+    /// [`get_transfer_fee`](crate::queries::wallets::get_transfer_fee) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `queries::wallets::get_transfer_fee`, which returns `BittensorResult`"
+    )]
+    pub async fn get_transfer_fee(client: &impl ChainBackend) -> anyhow::Result<u128> {
+        Ok(crate::queries::wallets::get_transfer_fee(client).await?)
+    }
+}
@@ -0,0 +1,1835 @@
+//! Deprecated `anyhow` forms of the [`validator`](crate::validator) functions
+//!
+//! Each function keeps its signature from before the switch to
+//! `BittensorResult` and calls the current one. They are removed together
+//! with this module.
+
+/// Deprecated forms of the [`validator::children`](crate::validator::children) functions
+pub mod children {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use sp_core::crypto::AccountId32;
+
+    /// [`set_children`](crate::validator::children::set_children) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::children::set_children`, which returns `BittensorResult`")]
+    pub async fn set_children(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        hotkey: &AccountId32,
+        children: Vec<(u64, AccountId32)>,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::children::set_children(
+            client, signer, netuid, hotkey, children, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`get_parents`](crate::validator::children::get_parents) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::children::get_parents`, which returns `BittensorResult`")]
+    pub async fn get_parents(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Vec<AccountId32>> {
+        Ok(crate::validator::children::get_parents(client, netuid, hotkey).await?)
+    }
+
+    /// [`get_children`](crate::validator::children::get_children) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::children::get_children`, which returns `BittensorResult`")]
+    pub async fn get_children(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Vec<(AccountId32, u64)>> {
+        Ok(crate::validator::children::get_children(client, netuid, hotkey).await?)
+    }
+
+    /// [`get_children_pending`](crate::validator::children::get_children_pending) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::children::get_children_pending`, which returns `BittensorResult`"
+    )]
+    pub async fn get_children_pending(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Vec<AccountId32>> {
+        Ok(crate::validator::children::get_children_pending(client, netuid, hotkey).await?)
+    }
+
+    /// [`get_childkey_take`](crate::validator::children::get_childkey_take) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::children::get_childkey_take`, which returns `BittensorResult`"
+    )]
+    pub async fn get_childkey_take(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+        netuid: u16,
+    ) -> anyhow::Result<u16> {
+        Ok(crate::validator::children::get_childkey_take(client, hotkey, netuid).await?)
+    }
+
+    /// [`get_last_childkey_take_block`](crate::validator::children::get_last_childkey_take_block) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::children::get_last_childkey_take_block`, which returns `BittensorResult`"
+    )]
+    pub async fn get_last_childkey_take_block(
+        client: &impl ChainBackend,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<Option<u64>> {
+        Ok(crate::validator::children::get_last_childkey_take_block(client, hotkey).await?)
+    }
+
+    /// [`get_childkey_take_rate_limit`](crate::validator::children::get_childkey_take_rate_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::children::get_childkey_take_rate_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn get_childkey_take_rate_limit(client: &impl ChainBackend) -> anyhow::Result<u64> {
+        Ok(crate::validator::children::get_childkey_take_rate_limit(client).await?)
+    }
+
+    /// [`set_childkey_take`](crate::validator::children::set_childkey_take) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::children::set_childkey_take`, which returns `BittensorResult`"
+    )]
+    pub async fn set_childkey_take(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        netuid: u16,
+        take: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::children::set_childkey_take(
+            client, signer, hotkey, netuid, take, wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::commitments`](crate::validator::commitments) functions
+pub mod commitments {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::validator::commitments::CommitmentData;
+
+    /// [`set_commitment`](crate::validator::commitments::set_commitment) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::commitments::set_commitment`, which returns `BittensorResult`"
+    )]
+    pub async fn set_commitment(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        data: CommitmentData,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::commitments::set_commitment(client, signer, netuid, data, wait_for)
+                .await?,
+        )
+    }
+
+    /// [`set_timelocked_commitment`](crate::validator::commitments::set_timelocked_commitment) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::commitments::set_timelocked_commitment`, which returns `BittensorResult`"
+    )]
+    pub async fn set_timelocked_commitment(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        data: &[u8],
+        reveal_blocks_ahead: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<(String, u64)> {
+        Ok(crate::validator::commitments::set_timelocked_commitment(
+            client,
+            signer,
+            netuid,
+            data,
+            reveal_blocks_ahead,
+            wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::evm`](crate::validator::evm) functions
+pub mod evm {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+
+    /// [`recover_evm_address`](crate::validator::evm::recover_evm_address) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::evm::recover_evm_address`, which returns `BittensorResult`"
+    )]
+    pub fn recover_evm_address(message: &[u8], signature: &[u8; 65]) -> anyhow::Result<[u8; 20]> {
+        Ok(crate::validator::evm::recover_evm_address(
+            message, signature,
+        )?)
+    }
+
+    /// [`associate_evm_key`](crate::validator::evm::associate_evm_key) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::evm::associate_evm_key`, which returns `BittensorResult`")]
+    pub async fn associate_evm_key(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        evm_address: [u8; 20],
+        block_number: u64,
+        evm_signature: [u8; 65],
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::evm::associate_evm_key(
+            client,
+            signer,
+            netuid,
+            evm_address,
+            block_number,
+            evm_signature,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`get_associated_evm_address`](crate::validator::evm::get_associated_evm_address) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::evm::get_associated_evm_address`, which returns `BittensorResult`"
+    )]
+    pub async fn get_associated_evm_address(
+        client: &impl ChainBackend,
+        netuid: u16,
+        uid: u16,
+    ) -> anyhow::Result<Option<([u8; 20], u64)>> {
+        Ok(crate::validator::evm::get_associated_evm_address(client, netuid, uid).await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::hyperparameters`](crate::validator::hyperparameters) functions
+pub mod hyperparameters {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+
+    /// [`set_hyperparameter`](crate::validator::hyperparameters::set_hyperparameter) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::set_hyperparameter`, which returns `BittensorResult`"
+    )]
+    pub async fn set_hyperparameter(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        param_name: &str,
+        value: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::hyperparameters::set_hyperparameter(
+            client, signer, netuid, param_name, value, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`sudo_set_tempo`](crate::validator::hyperparameters::sudo_set_tempo) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_tempo`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_tempo(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        tempo: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::hyperparameters::sudo_set_tempo(
+            client, signer, netuid, tempo, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`sudo_set_weights_rate_limit`](crate::validator::hyperparameters::sudo_set_weights_rate_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_weights_rate_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_weights_rate_limit(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        rate_limit: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_weights_rate_limit(
+                client, signer, netuid, rate_limit, wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`sudo_set_max_allowed_validators`](crate::validator::hyperparameters::sudo_set_max_allowed_validators) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_max_allowed_validators`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_max_allowed_validators(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        max_validators: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_max_allowed_validators(
+                client,
+                signer,
+                netuid,
+                max_validators,
+                wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`sudo_set_min_allowed_weights`](crate::validator::hyperparameters::sudo_set_min_allowed_weights) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_min_allowed_weights`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_min_allowed_weights(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        min_weights: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_min_allowed_weights(
+                client,
+                signer,
+                netuid,
+                min_weights,
+                wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`sudo_set_max_weights_limit`](crate::validator::hyperparameters::sudo_set_max_weights_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_max_weights_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_max_weights_limit(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        max_weights: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_max_weights_limit(
+                client,
+                signer,
+                netuid,
+                max_weights,
+                wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`sudo_set_immunity_period`](crate::validator::hyperparameters::sudo_set_immunity_period) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_immunity_period`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_immunity_period(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        immunity_period: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::hyperparameters::sudo_set_immunity_period(
+            client,
+            signer,
+            netuid,
+            immunity_period,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`sudo_set_activity_cutoff`](crate::validator::hyperparameters::sudo_set_activity_cutoff) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_activity_cutoff`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_activity_cutoff(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        activity_cutoff: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::hyperparameters::sudo_set_activity_cutoff(
+            client,
+            signer,
+            netuid,
+            activity_cutoff,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`sudo_set_registration_allowed`](crate::validator::hyperparameters::sudo_set_registration_allowed) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_registration_allowed`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_registration_allowed(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        allowed: bool,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_registration_allowed(
+                client, signer, netuid, allowed, wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`sudo_set_target_registrations_per_interval`](crate::validator::hyperparameters::sudo_set_target_registrations_per_interval) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_target_registrations_per_interval`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_target_registrations_per_interval(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        target: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_target_registrations_per_interval(
+                client, signer, netuid, target, wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`sudo_set_difficulty`](crate::validator::hyperparameters::sudo_set_difficulty) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_difficulty`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_difficulty(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        difficulty: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::hyperparameters::sudo_set_difficulty(
+            client, signer, netuid, difficulty, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`sudo_set_max_registrations_per_block`](crate::validator::hyperparameters::sudo_set_max_registrations_per_block) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_max_registrations_per_block`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_max_registrations_per_block(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        max_registrations: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_max_registrations_per_block(
+                client,
+                signer,
+                netuid,
+                max_registrations,
+                wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`sudo_set_serving_rate_limit`](crate::validator::hyperparameters::sudo_set_serving_rate_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_serving_rate_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_serving_rate_limit(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        rate_limit: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_serving_rate_limit(
+                client, signer, netuid, rate_limit, wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`sudo_set_commit_reveal_weights_enabled`](crate::validator::hyperparameters::sudo_set_commit_reveal_weights_enabled) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_commit_reveal_weights_enabled`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_commit_reveal_weights_enabled(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        enabled: bool,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_commit_reveal_weights_enabled(
+                client, signer, netuid, enabled, wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`sudo_set_liquid_alpha_enabled`](crate::validator::hyperparameters::sudo_set_liquid_alpha_enabled) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::hyperparameters::sudo_set_liquid_alpha_enabled`, which returns `BittensorResult`"
+    )]
+    pub async fn sudo_set_liquid_alpha_enabled(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        enabled: bool,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::hyperparameters::sudo_set_liquid_alpha_enabled(
+                client, signer, netuid, enabled, wait_for,
+            )
+            .await?,
+        )
+    }
+}
+
+/// Deprecated forms of the [`validator::identity`](crate::validator::identity) functions
+pub mod identity {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+
+    /// [`set_identity`](crate::validator::identity::set_identity) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::identity::set_identity`, which returns `BittensorResult`")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_identity(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        name: &str,
+        url: &str,
+        description: &str,
+        image: &str,
+        discord: &str,
+        additional: &str,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::identity::set_identity(
+            client,
+            signer,
+            name,
+            url,
+            description,
+            image,
+            discord,
+            additional,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`set_subnet_identity`](crate::validator::identity::set_subnet_identity) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::identity::set_subnet_identity`, which returns `BittensorResult`"
+    )]
+    pub async fn set_subnet_identity(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        subnet_name: &str,
+        github_repo: &str,
+        subnet_contact: &str,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::identity::set_subnet_identity(
+            client,
+            signer,
+            netuid,
+            subnet_name,
+            github_repo,
+            subnet_contact,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`clear_identity`](crate::validator::identity::clear_identity) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::identity::clear_identity`, which returns `BittensorResult`"
+    )]
+    pub async fn clear_identity(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::identity::clear_identity(client, signer, wait_for).await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::key_swap`](crate::validator::key_swap) functions
+pub mod key_swap {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use sp_core::crypto::AccountId32;
+
+    /// [`schedule_coldkey_swap`](crate::validator::key_swap::schedule_coldkey_swap) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::key_swap::schedule_coldkey_swap`, which returns `BittensorResult`"
+    )]
+    pub async fn schedule_coldkey_swap(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        new_coldkey: &AccountId32,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::key_swap::schedule_coldkey_swap(
+                client,
+                signer,
+                new_coldkey,
+                wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`schedule_hotkey_swap`](crate::validator::key_swap::schedule_hotkey_swap) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::key_swap::schedule_hotkey_swap`, which returns `BittensorResult`"
+    )]
+    pub async fn schedule_hotkey_swap(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        old_hotkey: &AccountId32,
+        new_hotkey: &AccountId32,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::key_swap::schedule_hotkey_swap(
+            client, signer, old_hotkey, new_hotkey, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`schedule_dissolve_network`](crate::validator::key_swap::schedule_dissolve_network) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::key_swap::schedule_dissolve_network`, which returns `BittensorResult`"
+    )]
+    pub async fn schedule_dissolve_network(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::key_swap::schedule_dissolve_network(client, signer, netuid, wait_for)
+                .await?,
+        )
+    }
+}
+
+/// Deprecated forms of the [`validator::liquidity`](crate::validator::liquidity) functions
+pub mod liquidity {
+    use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::utils::balance_newtypes::Rao;
+    use sp_core::crypto::AccountId32;
+
+    /// [`add_liquidity`](crate::validator::liquidity::add_liquidity) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::liquidity::add_liquidity`, which returns `BittensorResult`"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn add_liquidity(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        netuid: u16,
+        hotkey: &AccountId32,
+        tao_amount: Rao,
+        price_low: f64,
+        price_high: f64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::liquidity::add_liquidity(
+            client, signer, netuid, hotkey, tao_amount, price_low, price_high, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`remove_liquidity`](crate::validator::liquidity::remove_liquidity) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::liquidity::remove_liquidity`, which returns `BittensorResult`"
+    )]
+    pub async fn remove_liquidity(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        hotkey: &AccountId32,
+        position_id: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::liquidity::remove_liquidity(
+            client,
+            signer,
+            netuid,
+            hotkey,
+            position_id,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`modify_liquidity`](crate::validator::liquidity::modify_liquidity) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::liquidity::modify_liquidity`, which returns `BittensorResult`"
+    )]
+    pub async fn modify_liquidity(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        hotkey: &AccountId32,
+        position_id: u64,
+        liquidity_delta: i64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::liquidity::modify_liquidity(
+            client,
+            signer,
+            netuid,
+            hotkey,
+            position_id,
+            liquidity_delta,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`toggle_user_liquidity`](crate::validator::liquidity::toggle_user_liquidity) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::liquidity::toggle_user_liquidity`, which returns `BittensorResult`"
+    )]
+    pub async fn toggle_user_liquidity(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        enabled: bool,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::liquidity::toggle_user_liquidity(
+            client, signer, netuid, enabled, wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::mechanism`](crate::validator::mechanism) functions
+pub mod mechanism {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::validator::mechanism::WeightSettingStatus;
+    use sp_core::crypto::AccountId32;
+
+    /// [`can_set_weights`](crate::validator::mechanism::can_set_weights) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::mechanism::can_set_weights`, which returns `BittensorResult`"
+    )]
+    pub async fn can_set_weights(
+        client: &impl ChainBackend,
+        netuid: u16,
+        mechanism_id: u8,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<WeightSettingStatus> {
+        Ok(
+            crate::validator::mechanism::can_set_weights(client, netuid, mechanism_id, hotkey)
+                .await?,
+        )
+    }
+
+    /// [`commit_mechanism_weights`](crate::validator::mechanism::commit_mechanism_weights) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::mechanism::commit_mechanism_weights`, which returns `BittensorResult`"
+    )]
+    pub async fn commit_mechanism_weights(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        mechanism_id: u8,
+        commit_hash: &str,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::mechanism::commit_mechanism_weights(
+            client,
+            signer,
+            netuid,
+            mechanism_id,
+            commit_hash,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`reveal_mechanism_weights`](crate::validator::mechanism::reveal_mechanism_weights) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::mechanism::reveal_mechanism_weights`, which returns `BittensorResult`"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reveal_mechanism_weights(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        mechanism_id: u8,
+        uids: &[u16],
+        weights: &[u16],
+        _salt: &[u16],
+        version_key: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::mechanism::reveal_mechanism_weights(
+            client,
+            signer,
+            netuid,
+            mechanism_id,
+            uids,
+            weights,
+            _salt,
+            version_key,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`set_mechanism_weights`](crate::validator::mechanism::set_mechanism_weights) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::mechanism::set_mechanism_weights`, which returns `BittensorResult`"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_mechanism_weights(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        mechanism_id: u8,
+        uids: &[u16],
+        weights: &[u16],
+        version_key: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::mechanism::set_mechanism_weights(
+            client,
+            signer,
+            netuid,
+            mechanism_id,
+            uids,
+            weights,
+            version_key,
+            wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::registration`](crate::validator::registration) functions
+pub mod registration {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use sp_core::crypto::AccountId32;
+
+    /// [`register`](crate::validator::registration::register) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::registration::register`, which returns `BittensorResult`")]
+    pub async fn register(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        hotkey: &AccountId32,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::registration::register(client, signer, netuid, hotkey, wait_for)
+                .await?,
+        )
+    }
+
+    /// [`burned_register`](crate::validator::registration::burned_register) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::registration::burned_register`, which returns `BittensorResult`"
+    )]
+    pub async fn burned_register(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        hotkey: &AccountId32,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::registration::burned_register(
+            client, signer, netuid, hotkey, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`swap_hotkey`](crate::validator::registration::swap_hotkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::registration::swap_hotkey`, which returns `BittensorResult`"
+    )]
+    pub async fn swap_hotkey(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        old_hotkey: &AccountId32,
+        new_hotkey: &AccountId32,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::registration::swap_hotkey(
+            client, signer, old_hotkey, new_hotkey, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`is_registered`](crate::validator::registration::is_registered) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::registration::is_registered`, which returns `BittensorResult`"
+    )]
+    pub async fn is_registered(
+        client: &impl ChainBackend,
+        netuid: u16,
+        hotkey: &AccountId32,
+    ) -> anyhow::Result<bool> {
+        Ok(crate::validator::registration::is_registered(client, netuid, hotkey).await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::root`](crate::validator::root) functions
+pub mod root {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use sp_core::crypto::AccountId32;
+
+    /// [`root_register`](crate::validator::root::root_register) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::root::root_register`, which returns `BittensorResult`")]
+    pub async fn root_register(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::root::root_register(client, signer, hotkey, wait_for).await?)
+    }
+
+    /// [`root_set_weights`](crate::validator::root::root_set_weights) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::root::root_set_weights`, which returns `BittensorResult`")]
+    pub async fn root_set_weights(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        uids: &[u16],
+        weights: &[u16],
+        version_key: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::root::root_set_weights(
+            client,
+            signer,
+            uids,
+            weights,
+            version_key,
+            wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::security`](crate::validator::security) functions
+pub mod security {
+    use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::validator::security::{ScheduledCall, SwapInfo};
+    use sp_core::crypto::AccountId32;
+
+    /// [`validate_swap_destination`](crate::validator::security::validate_swap_destination) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::security::validate_swap_destination`, which returns `BittensorResult`"
+    )]
+    pub fn validate_swap_destination(
+        current: &AccountId32,
+        new_coldkey: &str,
+    ) -> anyhow::Result<AccountId32> {
+        Ok(crate::validator::security::validate_swap_destination(
+            current,
+            new_coldkey,
+        )?)
+    }
+
+    /// [`schedule_swap_coldkey`](crate::validator::security::schedule_swap_coldkey) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::security::schedule_swap_coldkey`, which returns `BittensorResult`"
+    )]
+    pub async fn schedule_swap_coldkey(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        new_coldkey: &str,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::security::schedule_swap_coldkey(
+                client,
+                signer,
+                new_coldkey,
+                wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`get_scheduled_coldkey_swap`](crate::validator::security::get_scheduled_coldkey_swap) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::security::get_scheduled_coldkey_swap`, which returns `BittensorResult`"
+    )]
+    pub async fn get_scheduled_coldkey_swap(
+        client: &impl ChainBackend,
+        old_coldkey: &AccountId32,
+    ) -> anyhow::Result<Option<SwapInfo>> {
+        Ok(crate::validator::security::get_scheduled_coldkey_swap(client, old_coldkey).await?)
+    }
+
+    /// [`get_coldkey_swap_schedule_duration`](crate::validator::security::get_coldkey_swap_schedule_duration) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::security::get_coldkey_swap_schedule_duration`, which returns `BittensorResult`"
+    )]
+    pub async fn get_coldkey_swap_schedule_duration(
+        client: &impl ChainBackend,
+    ) -> anyhow::Result<u64> {
+        Ok(crate::validator::security::get_coldkey_swap_schedule_duration(client).await?)
+    }
+
+    /// [`get_dissolve_network_schedule_duration`](crate::validator::security::get_dissolve_network_schedule_duration) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::security::get_dissolve_network_schedule_duration`, which returns `BittensorResult`"
+    )]
+    pub async fn get_dissolve_network_schedule_duration(
+        client: &impl ChainBackend,
+    ) -> anyhow::Result<u64> {
+        Ok(crate::validator::security::get_dissolve_network_schedule_duration(client).await?)
+    }
+
+    /// [`get_scheduled_calls`](crate::validator::security::get_scheduled_calls) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::security::get_scheduled_calls`, which returns `BittensorResult`"
+    )]
+    pub async fn get_scheduled_calls(
+        client: &BittensorClient,
+        block: u64,
+    ) -> anyhow::Result<Vec<ScheduledCall>> {
+        Ok(crate::validator::security::get_scheduled_calls(client, block).await?)
+    }
+
+    /// [`is_dissolve_network_scheduled`](crate::validator::security::is_dissolve_network_scheduled) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::security::is_dissolve_network_scheduled`, which returns `BittensorResult`"
+    )]
+    pub async fn is_dissolve_network_scheduled(
+        client: &BittensorClient,
+        netuid: u16,
+        block: u64,
+    ) -> anyhow::Result<bool> {
+        Ok(
+            crate::validator::security::is_dissolve_network_scheduled(client, netuid, block)
+                .await?,
+        )
+    }
+}
+
+/// Deprecated forms of the [`validator::serving`](crate::validator::serving) functions
+pub mod serving {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::queries::neurons::Certificate;
+    use crate::validator::serving::Served;
+    use std::net::IpAddr;
+
+    /// [`serve_axon`](crate::validator::serving::serve_axon) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::serving::serve_axon`, which returns `BittensorResult`")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn serve_axon(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        version: u32,
+        ip: IpAddr,
+        port: u16,
+        protocol: u8,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::serving::serve_axon(
+            client, signer, netuid, version, ip, port, protocol, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`serve_axon_tls`](crate::validator::serving::serve_axon_tls) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::serving::serve_axon_tls`, which returns `BittensorResult`"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn serve_axon_tls(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        version: u32,
+        ip: IpAddr,
+        port: u16,
+        protocol: u8,
+        certificate: &Certificate,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::serving::serve_axon_tls(
+            client,
+            signer,
+            netuid,
+            version,
+            ip,
+            port,
+            protocol,
+            certificate,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`serve_axon_auto`](crate::validator::serving::serve_axon_auto) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::serving::serve_axon_auto`, which returns `BittensorResult`"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn serve_axon_auto(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        version: u32,
+        port: u16,
+        protocol: u8,
+        external_ip: Option<IpAddr>,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<Served> {
+        Ok(crate::validator::serving::serve_axon_auto(
+            client,
+            signer,
+            netuid,
+            version,
+            port,
+            protocol,
+            external_ip,
+            wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::staking`](crate::validator::staking) functions
+pub mod staking {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::utils::balance_newtypes::Rao;
+    use sp_core::crypto::AccountId32;
+
+    /// [`add_stake`](crate::validator::staking::add_stake) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::staking::add_stake`, which returns `BittensorResult`")]
+    pub async fn add_stake(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        netuid: u16,
+        amount: Rao,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::staking::add_stake(client, signer, hotkey, netuid, amount, wait_for)
+                .await?,
+        )
+    }
+
+    /// [`unstake`](crate::validator::staking::unstake) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::staking::unstake`, which returns `BittensorResult`")]
+    pub async fn unstake(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        netuid: u16,
+        amount: Rao,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::staking::unstake(client, signer, hotkey, netuid, amount, wait_for)
+                .await?,
+        )
+    }
+
+    /// [`unstake_all`](crate::validator::staking::unstake_all) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::staking::unstake_all`, which returns `BittensorResult`")]
+    pub async fn unstake_all(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::staking::unstake_all(client, signer, hotkey, wait_for).await?)
+    }
+
+    /// [`add_stake_multiple`](crate::validator::staking::add_stake_multiple) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::staking::add_stake_multiple`, which returns `BittensorResult`"
+    )]
+    pub async fn add_stake_multiple(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkeys: &[AccountId32],
+        amounts: &[Rao],
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::staking::add_stake_multiple(
+            client, signer, hotkeys, amounts, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`unstake_multiple`](crate::validator::staking::unstake_multiple) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::staking::unstake_multiple`, which returns `BittensorResult`"
+    )]
+    pub async fn unstake_multiple(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkeys: &[AccountId32],
+        amounts: &[Rao],
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::staking::unstake_multiple(client, signer, hotkeys, amounts, wait_for)
+                .await?,
+        )
+    }
+
+    /// [`set_auto_stake`](crate::validator::staking::set_auto_stake) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::staking::set_auto_stake`, which returns `BittensorResult`"
+    )]
+    pub async fn set_auto_stake(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        auto_stake: bool,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::staking::set_auto_stake(client, signer, hotkey, auto_stake, wait_for)
+                .await?,
+        )
+    }
+
+    /// [`move_stake`](crate::validator::staking::move_stake) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::staking::move_stake`, which returns `BittensorResult`")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn move_stake(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        from_hotkey: &AccountId32,
+        to_hotkey: &AccountId32,
+        origin_netuid: u16,
+        destination_netuid: u16,
+        amount: Rao,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::staking::move_stake(
+            client,
+            signer,
+            from_hotkey,
+            to_hotkey,
+            origin_netuid,
+            destination_netuid,
+            amount,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`swap_stake`](crate::validator::staking::swap_stake) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::staking::swap_stake`, which returns `BittensorResult`")]
+    pub async fn swap_stake(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        origin_netuid: u16,
+        destination_netuid: u16,
+        amount: Rao,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::staking::swap_stake(
+            client,
+            signer,
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+            amount,
+            wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::subnet_admin`](crate::validator::subnet_admin) functions
+pub mod subnet_admin {
+    use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::types::SubnetIdentity;
+    use crate::utils::balance_newtypes::Rao;
+    use crate::validator::subnet_admin::NetworkRegistration;
+    use sp_core::crypto::AccountId32;
+
+    /// [`get_network_registration_cost`](crate::validator::subnet_admin::get_network_registration_cost) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_admin::get_network_registration_cost`, which returns `BittensorResult`"
+    )]
+    pub async fn get_network_registration_cost(client: &impl ChainBackend) -> anyhow::Result<Rao> {
+        Ok(crate::validator::subnet_admin::get_network_registration_cost(client).await?)
+    }
+
+    /// [`register_network`](crate::validator::subnet_admin::register_network) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_admin::register_network`, which returns `BittensorResult`"
+    )]
+    pub async fn register_network(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        identity: Option<&SubnetIdentity>,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<NetworkRegistration> {
+        Ok(crate::validator::subnet_admin::register_network(
+            client, signer, hotkey, identity, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`ensure_subnet_owner`](crate::validator::subnet_admin::ensure_subnet_owner) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_admin::ensure_subnet_owner`, which returns `BittensorResult`"
+    )]
+    pub async fn ensure_subnet_owner(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+    ) -> anyhow::Result<()> {
+        Ok(crate::validator::subnet_admin::ensure_subnet_owner(client, signer, netuid).await?)
+    }
+
+    /// [`set_serving_rate_limit`](crate::validator::subnet_admin::set_serving_rate_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_admin::set_serving_rate_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn set_serving_rate_limit(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        serving_rate_limit: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::subnet_admin::set_serving_rate_limit(
+            client,
+            signer,
+            netuid,
+            serving_rate_limit,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`set_weights_rate_limit`](crate::validator::subnet_admin::set_weights_rate_limit) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_admin::set_weights_rate_limit`, which returns `BittensorResult`"
+    )]
+    pub async fn set_weights_rate_limit(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        weights_rate_limit: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::subnet_admin::set_weights_rate_limit(
+            client,
+            signer,
+            netuid,
+            weights_rate_limit,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`set_commit_reveal_enabled`](crate::validator::subnet_admin::set_commit_reveal_enabled) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_admin::set_commit_reveal_enabled`, which returns `BittensorResult`"
+    )]
+    pub async fn set_commit_reveal_enabled(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        enabled: bool,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::subnet_admin::set_commit_reveal_enabled(
+            client, signer, netuid, enabled, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`set_min_burn`](crate::validator::subnet_admin::set_min_burn) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_admin::set_min_burn`, which returns `BittensorResult`"
+    )]
+    pub async fn set_min_burn(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        min_burn: Rao,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::subnet_admin::set_min_burn(
+                client, signer, netuid, min_burn, wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`set_max_burn`](crate::validator::subnet_admin::set_max_burn) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_admin::set_max_burn`, which returns `BittensorResult`"
+    )]
+    pub async fn set_max_burn(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        max_burn: Rao,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::subnet_admin::set_max_burn(
+                client, signer, netuid, max_burn, wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`set_bonds_moving_average`](crate::validator::subnet_admin::set_bonds_moving_average) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_admin::set_bonds_moving_average`, which returns `BittensorResult`"
+    )]
+    pub async fn set_bonds_moving_average(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        bonds_moving_average: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::subnet_admin::set_bonds_moving_average(
+            client,
+            signer,
+            netuid,
+            bonds_moving_average,
+            wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::subnet_management`](crate::validator::subnet_management) functions
+pub mod subnet_management {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+
+    /// [`dissolve_network`](crate::validator::subnet_management::dissolve_network) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_management::dissolve_network`, which returns `BittensorResult`"
+    )]
+    pub async fn dissolve_network(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::subnet_management::dissolve_network(client, signer, netuid, wait_for)
+                .await?,
+        )
+    }
+
+    /// [`set_subnet_metadata`](crate::validator::subnet_management::set_subnet_metadata) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::subnet_management::set_subnet_metadata`, which returns `BittensorResult`"
+    )]
+    pub async fn set_subnet_metadata(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        metadata: &[u8],
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::subnet_management::set_subnet_metadata(
+            client, signer, netuid, metadata, wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::sudo`](crate::validator::sudo) functions
+pub mod sudo {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::utils::balance_newtypes::Rao;
+    use sp_core::crypto::AccountId32;
+
+    /// [`force_set_balance`](crate::validator::sudo::force_set_balance) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::sudo::force_set_balance`, which returns `BittensorResult`"
+    )]
+    pub async fn force_set_balance(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        who: &AccountId32,
+        amount: Rao,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::sudo::force_set_balance(client, signer, who, amount, wait_for)
+                .await?,
+        )
+    }
+
+    /// [`sudo`](crate::validator::sudo::sudo) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::sudo::sudo`, which returns `BittensorResult`")]
+    pub async fn sudo(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        call: Vec<u8>,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::sudo::sudo(client, signer, call, wait_for).await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::take`](crate::validator::take) functions
+pub mod take {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use sp_core::crypto::AccountId32;
+
+    /// [`increase_take`](crate::validator::take::increase_take) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::take::increase_take`, which returns `BittensorResult`")]
+    pub async fn increase_take(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        take: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::take::increase_take(client, signer, hotkey, take, wait_for).await?)
+    }
+
+    /// [`decrease_take`](crate::validator::take::decrease_take) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::take::decrease_take`, which returns `BittensorResult`")]
+    pub async fn decrease_take(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        take: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::take::decrease_take(client, signer, hotkey, take, wait_for).await?)
+    }
+
+    /// [`become_delegate`](crate::validator::take::become_delegate) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::take::become_delegate`, which returns `BittensorResult`")]
+    pub async fn become_delegate(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        take: u16,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::take::become_delegate(client, signer, hotkey, take, wait_for).await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::transfer`](crate::validator::transfer) functions
+pub mod transfer {
+    use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::utils::balance_newtypes::Rao;
+    use crate::validator::transfer::{TransferMode, TransferPlan};
+    use sp_core::crypto::AccountId32;
+
+    /// [`plan_transfer`](crate::validator::transfer::plan_transfer) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::transfer::plan_transfer`, which returns `BittensorResult`"
+    )]
+    pub fn plan_transfer(
+        mode: TransferMode,
+        amount: Option<Rao>,
+        free: Rao,
+        existential_deposit: Rao,
+    ) -> anyhow::Result<TransferPlan> {
+        Ok(crate::validator::transfer::plan_transfer(
+            mode,
+            amount,
+            free,
+            existential_deposit,
+        )?)
+    }
+
+    /// [`get_transfer_plan`](crate::validator::transfer::get_transfer_plan) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::transfer::get_transfer_plan`, which returns `BittensorResult`"
+    )]
+    pub async fn get_transfer_plan(
+        client: &BittensorClient,
+        account: &AccountId32,
+        mode: TransferMode,
+        amount: Option<Rao>,
+    ) -> anyhow::Result<TransferPlan> {
+        Ok(crate::validator::transfer::get_transfer_plan(client, account, mode, amount).await?)
+    }
+
+    /// [`transfer`](crate::validator::transfer::transfer) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::transfer::transfer`, which returns `BittensorResult`")]
+    pub async fn transfer(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        dest: &AccountId32,
+        amount: Rao,
+        keep_alive: bool,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::transfer::transfer(
+                client, signer, dest, amount, keep_alive, wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`transfer_with_mode`](crate::validator::transfer::transfer_with_mode) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::transfer::transfer_with_mode`, which returns `BittensorResult`"
+    )]
+    pub async fn transfer_with_mode(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        dest: &AccountId32,
+        mode: TransferMode,
+        amount: Option<Rao>,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<(String, TransferPlan)> {
+        Ok(crate::validator::transfer::transfer_with_mode(
+            client, signer, dest, mode, amount, wait_for,
+        )
+        .await?)
+    }
+
+    /// [`transfer_stake`](crate::validator::transfer::transfer_stake) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::transfer::transfer_stake`, which returns `BittensorResult`"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn transfer_stake(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        destination_coldkey: &AccountId32,
+        hotkey: &AccountId32,
+        origin_netuid: u16,
+        destination_netuid: u16,
+        amount: Rao,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::transfer::transfer_stake(
+            client,
+            signer,
+            destination_coldkey,
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+            amount,
+            wait_for,
+        )
+        .await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::utility`](crate::validator::utility) functions
+pub mod utility {
+    use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::validator::utility::{BatchCall, BatchMode, BatchOutcome};
+
+    /// [`batch_all`](crate::validator::utility::batch_all) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::utility::batch_all`, which returns `BittensorResult`")]
+    pub async fn batch_all(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        calls: Vec<BatchCall>,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::utility::batch_all(client, signer, calls, wait_for).await?)
+    }
+
+    /// [`batch`](crate::validator::utility::batch) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::utility::batch`, which returns `BittensorResult`")]
+    pub async fn batch(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        calls: Vec<BatchCall>,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::utility::batch(client, signer, calls, wait_for).await?)
+    }
+
+    /// [`force_batch`](crate::validator::utility::force_batch) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::utility::force_batch`, which returns `BittensorResult`")]
+    pub async fn force_batch(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        calls: Vec<BatchCall>,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::utility::force_batch(client, signer, calls, wait_for).await?)
+    }
+
+    /// [`submit_batch`](crate::validator::utility::submit_batch) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::utility::submit_batch`, which returns `BittensorResult`")]
+    pub async fn submit_batch(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        calls: Vec<BatchCall>,
+        mode: BatchMode,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<BatchOutcome> {
+        Ok(crate::validator::utility::submit_batch(client, signer, calls, mode, wait_for).await?)
+    }
+}
+
+/// Deprecated forms of the [`validator::weights`](crate::validator::weights) functions
+pub mod weights {
+    use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+    use crate::utils::WeightValidation;
+
+    /// [`set_weights`](crate::validator::weights::set_weights) returning `anyhow::Result`
+    #[deprecated(note = "use `validator::weights::set_weights`, which returns `BittensorResult`")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_weights(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        uids: &[u16],
+        weights: &[u16],
+        version_key: u64,
+        validation: WeightValidation,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::weights::set_weights(
+            client,
+            signer,
+            netuid,
+            uids,
+            weights,
+            version_key,
+            validation,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`check_weight_inputs`](crate::validator::weights::check_weight_inputs) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::weights::check_weight_inputs`, which returns `BittensorResult`"
+    )]
+    pub async fn check_weight_inputs(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        uids: &[u16],
+        weights: &[u16],
+        validation: WeightValidation,
+    ) -> anyhow::Result<(Vec<u16>, Vec<u16>)> {
+        Ok(crate::validator::weights::check_weight_inputs(
+            client, signer, netuid, uids, weights, validation,
+        )
+        .await?)
+    }
+
+    /// [`resolve_version_key`](crate::validator::weights::resolve_version_key) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::weights::resolve_version_key`, which returns `BittensorResult`"
+    )]
+    pub async fn resolve_version_key(
+        client: &impl ChainBackend,
+        netuid: u16,
+        version_key: Option<u64>,
+    ) -> anyhow::Result<u64> {
+        Ok(crate::validator::weights::resolve_version_key(client, netuid, version_key).await?)
+    }
+
+    /// [`commit_weights`](crate::validator::weights::commit_weights) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::weights::commit_weights`, which returns `BittensorResult`"
+    )]
+    pub async fn commit_weights(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        commit_hash: &str,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(
+            crate::validator::weights::commit_weights(
+                client,
+                signer,
+                netuid,
+                commit_hash,
+                wait_for,
+            )
+            .await?,
+        )
+    }
+
+    /// [`reveal_weights`](crate::validator::weights::reveal_weights) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::weights::reveal_weights`, which returns `BittensorResult`"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reveal_weights(
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        uids: &[u16],
+        weights: &[u16],
+        salt: &[u16],
+        version_key: u64,
+        wait_for: ExtrinsicWait,
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::weights::reveal_weights(
+            client,
+            signer,
+            netuid,
+            uids,
+            weights,
+            salt,
+            version_key,
+            wait_for,
+        )
+        .await?)
+    }
+
+    /// [`generate_commit_hash`](crate::validator::weights::generate_commit_hash) returning `anyhow::Result`
+    #[deprecated(
+        note = "use `validator::weights::generate_commit_hash`, which returns `BittensorResult`"
+    )]
+    pub fn generate_commit_hash(
+        uids: &[u64],
+        weights: &[u16],
+        salt: &[u8],
+    ) -> anyhow::Result<String> {
+        Ok(crate::validator::weights::generate_commit_hash(
+            uids, weights, salt,
+        )?)
+    }
+}
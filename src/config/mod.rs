@@ -1,9 +1,23 @@
 //! Configuration module for Bittensor SDK
 //! Provides configuration management similar to Python's bittensor.core.config
+//!
+//! # Precedence
+//!
+//! [`Config::resolve`] layers settings, highest first:
+//!
+//! 1. CLI flags ([`ConfigOverrides`])
+//! 2. Environment variables (`BITTENSOR_NETWORK`, `BITTENSOR_RPC`, ...)
+//! 3. The config file (`~/.bittensor/config.toml` by default)
+//! 4. Built-in defaults
+//!
+//! A network set at a higher layer also replaces the endpoint from the
+//! layers below it, unless that same layer sets the endpoint too.
 
 use crate::core::constants;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
 
 /// Default network configuration
 pub const DEFAULT_NETWORK: &str = "finney";
@@ -15,13 +29,64 @@ pub fn get_network_endpoint(network: &str) -> &'static str {
         "finney" => constants::FINNEY_ENDPOINT,
         "test" | "testnet" => constants::FINNEY_TEST_ENDPOINT,
         "archive" => constants::ARCHIVE_ENDPOINT,
-        "local" => constants::LOCAL_ENDPOINT,
+        "local" | "localhost" => constants::LOCAL_ENDPOINT,
         _ => DEFAULT_CHAIN_ENDPOINT,
     }
 }
 
+/// Endpoint for a network name, or the value itself if it is already a URL
+fn network_endpoint(network: &str) -> String {
+    if network.contains("://") {
+        network.to_string()
+    } else {
+        get_network_endpoint(&network.to_lowercase()).to_string()
+    }
+}
+
+/// Path of the config file used when none is given (`~/.bittensor/config.toml`)
+pub fn default_config_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join(".bittensor")
+        .join("config.toml")
+}
+
+/// Errors reading or writing a config file
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Failed to read config {path}: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to write config {path}: {source}")]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Invalid config {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("Failed to serialize config: {0}")]
+    Serialize(#[from] toml::ser::Error),
+    #[error("Invalid value for '{key}': {message}")]
+    InvalidValue { key: String, message: String },
+}
+
+/// Settings given on the command line, applied last by [`Config::resolve`]
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    /// Config file to read instead of the default path
+    pub config_path: Option<PathBuf>,
+    pub network: Option<String>,
+    pub endpoint: Option<String>,
+}
+
 /// Axon configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AxonConfig {
     pub port: u16,
     pub ip: String,
@@ -44,10 +109,15 @@ impl Default for AxonConfig {
 
 /// Subtensor configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SubtensorConfig {
     pub network: String,
+    /// Empty in a config file means "the endpoint of `network`"
+    #[serde(default)]
     pub chain_endpoint: String,
     pub retry_forever: bool,
+    /// Per-block extrinsic budgets
+    pub rate_limits: RateLimitConfig,
 }
 
 impl Default for SubtensorConfig {
@@ -56,12 +126,39 @@ impl Default for SubtensorConfig {
             network: DEFAULT_NETWORK.to_string(),
             chain_endpoint: DEFAULT_CHAIN_ENDPOINT.to_string(),
             retry_forever: false,
+            rate_limits: RateLimitConfig::default(),
+        }
+    }
+}
+
+/// Extrinsics allowed per block for each
+/// [`ExtrinsicCategory`](crate::chain::ExtrinsicCategory)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    pub weights: u32,
+    pub staking: u32,
+    pub serving: u32,
+    pub other: u32,
+    /// Seconds to wait for budget before failing; unset waits indefinitely
+    pub timeout_secs: Option<u64>,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            weights: 8,
+            staking: 16,
+            serving: 4,
+            other: 16,
+            timeout_secs: None,
         }
     }
 }
 
 /// Logging configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct LoggingConfig {
     pub debug: bool,
     pub trace: bool,
@@ -82,11 +179,13 @@ impl Default for LoggingConfig {
 
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct Config {
     pub axon: AxonConfig,
     pub subtensor: SubtensorConfig,
     pub logging: LoggingConfig,
-    /// Additional custom configuration
+    /// Additional custom configuration; unknown top-level keys in a config
+    /// file land here and are written back on save
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
 }
@@ -104,7 +203,7 @@ impl Config {
             subtensor: SubtensorConfig {
                 network: network.to_string(),
                 chain_endpoint: endpoint.to_string(),
-                retry_forever: false,
+                ..Default::default()
             },
             ..Default::default()
         }
@@ -138,27 +237,145 @@ impl Config {
     /// Load config from environment variables
     pub fn from_env() -> Self {
         let mut config = Self::default();
+        config.apply_env();
+        config
+    }
 
+    /// Override settings from environment variables
+    fn apply_env(&mut self) {
         if let Ok(network) = std::env::var("BITTENSOR_NETWORK") {
-            config.subtensor.network = network.clone();
-            config.subtensor.chain_endpoint = get_network_endpoint(&network).to_string();
+            self.subtensor.chain_endpoint = network_endpoint(&network);
+            self.subtensor.network = network;
         }
 
         if let Ok(endpoint) = std::env::var("BITTENSOR_RPC") {
-            config.subtensor.chain_endpoint = endpoint;
+            self.subtensor.chain_endpoint = endpoint;
         }
 
         if let Ok(port) = std::env::var("BITTENSOR_AXON_PORT") {
             if let Ok(p) = port.parse() {
-                config.axon.port = p;
+                self.axon.port = p;
             }
         }
 
         if std::env::var("BITTENSOR_DEBUG").is_ok() {
-            config.logging.debug = true;
+            self.logging.debug = true;
         }
+    }
 
-        config
+    /// Load a TOML config file, `~/.bittensor/config.toml` if `path` is None
+    ///
+    /// A missing default file yields the defaults; a missing explicit path
+    /// is an error.
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => {
+                let path = default_config_path();
+                if !path.exists() {
+                    return Ok(Self::default());
+                }
+                path
+            }
+        };
+
+        let contents = std::fs::read_to_string(&path).map_err(|source| ConfigError::Read {
+            path: path.clone(),
+            source,
+        })?;
+        Self::from_toml(&contents).map_err(|source| ConfigError::Parse { path, source })
+    }
+
+    /// Parse a TOML config, deriving the endpoint from the network if unset
+    pub fn from_toml(contents: &str) -> Result<Self, toml::de::Error> {
+        let mut config: Self = toml::from_str(contents)?;
+        if config.subtensor.chain_endpoint.is_empty() {
+            config.subtensor.chain_endpoint = network_endpoint(&config.subtensor.network);
+        }
+        Ok(config)
+    }
+
+    /// Render the config as TOML
+    pub fn to_toml(&self) -> Result<String, ConfigError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    /// Write the config as TOML, to `~/.bittensor/config.toml` if `path` is None
+    pub fn save(&self, path: Option<&Path>) -> Result<(), ConfigError> {
+        let path = path.map_or_else(default_config_path, Path::to_path_buf);
+        let contents = self.to_toml()?;
+        let write_error = |source| ConfigError::Write {
+            path: path.clone(),
+            source,
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(write_error)?;
+        }
+        std::fs::write(&path, contents).map_err(write_error)
+    }
+
+    /// Resolve the effective config: CLI flags > env vars > config file > defaults
+    pub fn resolve(cli_overrides: &ConfigOverrides) -> Result<Self, ConfigError> {
+        let mut config = Self::load(cli_overrides.config_path.as_deref())?;
+        config.apply_env();
+
+        if let Some(network) = &cli_overrides.network {
+            config.subtensor.network = network.clone();
+            config.subtensor.chain_endpoint = network_endpoint(network);
+        }
+        if let Some(endpoint) = &cli_overrides.endpoint {
+            config.subtensor.chain_endpoint = endpoint.clone();
+        }
+        Ok(config)
+    }
+
+    /// Set a value by dotted key, e.g. `axon.port` or `subtensor.network`
+    ///
+    /// `network` and `endpoint` are shorthands for the subtensor settings;
+    /// setting the network also updates the endpoint. Values are parsed as
+    /// TOML scalars where possible and kept as strings otherwise. Unknown
+    /// top-level keys are stored in `extra`.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ConfigError> {
+        let key = match key {
+            "network" => "subtensor.network",
+            "endpoint" | "chain_endpoint" => "subtensor.chain_endpoint",
+            other => other,
+        };
+        let invalid = |message: String| ConfigError::InvalidValue {
+            key: key.to_string(),
+            message,
+        };
+
+        let mut document = toml::Value::try_from(&*self).map_err(|e| invalid(e.to_string()))?;
+        let parsed = toml::from_str::<toml::Table>(&format!("v = {}", value))
+            .ok()
+            .and_then(|mut table| table.remove("v"))
+            .unwrap_or_else(|| toml::Value::String(value.to_string()));
+
+        let mut parts = key.split('.').peekable();
+        let mut table = document
+            .as_table_mut()
+            .ok_or_else(|| invalid("config is not a table".to_string()))?;
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                table.insert(part.to_string(), parsed.clone());
+                break;
+            }
+            table = table
+                .entry(part)
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+                .as_table_mut()
+                .ok_or_else(|| invalid(format!("'{}' is not a section", part)))?;
+        }
+
+        let mut updated: Self = document
+            .try_into()
+            .map_err(|e: toml::de::Error| invalid(e.message().to_string()))?;
+        if key == "subtensor.network" {
+            updated.subtensor.chain_endpoint = network_endpoint(&updated.subtensor.network);
+        }
+        *self = updated;
+        Ok(())
     }
 
     /// Merge with another config (other takes precedence)
@@ -206,4 +423,118 @@ mod tests {
         assert_eq!(config.axon.port, 9000);
         assert!(config.logging.debug);
     }
+
+    #[test]
+    fn test_partial_toml_derives_endpoint() {
+        let config = Config::from_toml("[subtensor]\nnetwork = \"test\"\n").unwrap();
+        assert_eq!(
+            config.subtensor.chain_endpoint,
+            constants::FINNEY_TEST_ENDPOINT
+        );
+        assert_eq!(config.axon.port, 8091);
+    }
+
+    #[test]
+    fn test_toml_round_trip_keeps_unknown_keys() {
+        let contents = "wallet_name = \"miner\"\n\n[subtensor]\nnetwork = \"local\"\n\n[plugins]\nenabled = true\n";
+        let config = Config::from_toml(contents).unwrap();
+        assert_eq!(config.extra["wallet_name"], "miner");
+
+        let reloaded = Config::from_toml(&config.to_toml().unwrap()).unwrap();
+        assert_eq!(reloaded.extra["wallet_name"], "miner");
+        assert_eq!(reloaded.extra["plugins"]["enabled"], true);
+        assert_eq!(reloaded.subtensor.chain_endpoint, constants::LOCAL_ENDPOINT);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!(
+            "bittensor-config-test-{}/config.toml",
+            std::process::id()
+        ));
+        let config = Config::new().with_network("test").with_axon_port(9000);
+        config.save(Some(&path)).unwrap();
+
+        let loaded = Config::load(Some(&path)).unwrap();
+        assert_eq!(loaded.subtensor.network, "test");
+        assert_eq!(loaded.axon.port, 9000);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert!(matches!(
+            Config::load(Some(&path)),
+            Err(ConfigError::Read { .. })
+        ));
+    }
+
+    #[test]
+    fn test_set_values() {
+        let mut config = Config::new();
+        config.set("network", "test").unwrap();
+        assert_eq!(config.subtensor.network, "test");
+        assert_eq!(
+            config.subtensor.chain_endpoint,
+            constants::FINNEY_TEST_ENDPOINT
+        );
+
+        config.set("endpoint", "ws://10.0.0.1:9944").unwrap();
+        assert_eq!(config.subtensor.chain_endpoint, "ws://10.0.0.1:9944");
+
+        config.set("axon.port", "9100").unwrap();
+        assert_eq!(config.axon.port, 9100);
+
+        config.set("wallet_name", "miner").unwrap();
+        assert_eq!(config.extra["wallet_name"], "miner");
+
+        assert!(config.set("axon.port", "not-a-port").is_err());
+        assert_eq!(config.axon.port, 9100);
+    }
+
+    #[test]
+    fn test_rate_limits_from_toml() {
+        let config = Config::from_toml("[subtensor.rate_limits]\nweights = 2\n").unwrap();
+        assert_eq!(config.subtensor.rate_limits.weights, 2);
+        assert_eq!(
+            config.subtensor.rate_limits.staking,
+            RateLimitConfig::default().staking
+        );
+
+        let mut config = Config::new();
+        config
+            .set("subtensor.rate_limits.timeout_secs", "30")
+            .unwrap();
+        assert_eq!(config.subtensor.rate_limits.timeout_secs, Some(30));
+    }
+
+    #[test]
+    fn test_resolve_cli_overrides_file() {
+        let path = std::env::temp_dir().join(format!(
+            "bittensor-config-resolve-{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "[subtensor]\nnetwork = \"local\"\n").unwrap();
+
+        let mut overrides = ConfigOverrides {
+            config_path: Some(path.clone()),
+            ..Default::default()
+        };
+        let from_file = Config::resolve(&overrides).unwrap();
+        overrides.network = Some("test".to_string());
+        let from_cli = Config::resolve(&overrides).unwrap();
+        overrides.endpoint = Some("ws://custom:9944".to_string());
+        let from_endpoint = Config::resolve(&overrides).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // Skip the file layer assertion if the environment overrides it
+        if std::env::var("BITTENSOR_NETWORK").is_err() && std::env::var("BITTENSOR_RPC").is_err() {
+            assert_eq!(
+                from_file.subtensor.chain_endpoint,
+                constants::LOCAL_ENDPOINT
+            );
+        }
+        assert_eq!(
+            from_cli.subtensor.chain_endpoint,
+            constants::FINNEY_TEST_ENDPOINT
+        );
+        assert_eq!(from_endpoint.subtensor.chain_endpoint, "ws://custom:9944");
+    }
 }
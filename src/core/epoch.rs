@@ -0,0 +1,94 @@
+//! Subnet epoch arithmetic
+//!
+//! Pure functions mirroring subtensor's epoch schedule
+//! (`pallets/subtensor/src/coinbase/run_coinbase.rs` and
+//! `pallets/subtensor/src/subnets/weights.rs`). Subnets are offset by their
+//! netuid so they do not all run their epoch in the same block:
+//!
+//! - `epoch_index = (block + netuid + 1) / (tempo + 1)`
+//! - `epoch_start_block = epoch_index * (tempo + 1) - (netuid + 1)`
+//! - `blocks_until_next_epoch = tempo - (block + netuid + 1) % (tempo + 1)`
+//!
+//! The chain runs a subnet's epoch in the block where
+//! [`blocks_until_next_epoch`] is 0, which is the last block of its epoch
+//! index; the index increments in the block after.
+
+/// Blocks until the subnet's next epoch runs
+///
+/// Returns 0 in the block the epoch runs, and `u64::MAX` for tempo 0, which
+/// never runs an epoch.
+pub fn blocks_until_next_epoch(netuid: u16, tempo: u64, block: u64) -> u64 {
+    if tempo == 0 {
+        return u64::MAX;
+    }
+    let netuid_plus_one = (netuid as u64).saturating_add(1);
+    let tempo_plus_one = tempo.saturating_add(1);
+    let adjusted_block = block.wrapping_add(netuid_plus_one);
+    let remainder = adjusted_block.checked_rem(tempo_plus_one).unwrap_or(0);
+    tempo.saturating_sub(remainder)
+}
+
+/// Epoch index of `block`: `(block + netuid + 1) / (tempo + 1)`
+///
+/// This is the index commit-reveal uses to match reveals to commits.
+pub fn epoch_index(netuid: u16, tempo: u64, block: u64) -> u64 {
+    let tempo_plus_one = tempo.saturating_add(1);
+    let netuid_plus_one = (netuid as u64).saturating_add(1);
+    block
+        .saturating_add(netuid_plus_one)
+        .checked_div(tempo_plus_one)
+        .unwrap_or(0)
+}
+
+/// First block of `epoch_index`: `epoch_index * (tempo + 1) - (netuid + 1)`
+///
+/// Saturates at 0 for the first epoch of a subnet.
+pub fn epoch_start_block(netuid: u16, tempo: u64, epoch_index: u64) -> u64 {
+    let tempo_plus_one = tempo.saturating_add(1);
+    let netuid_plus_one = (netuid as u64).saturating_add(1);
+    epoch_index
+        .saturating_mul(tempo_plus_one)
+        .saturating_sub(netuid_plus_one)
+}
+
+/// First block of the epoch after the one containing `block`
+///
+/// Always greater than `block`. Equals
+/// `block + blocks_until_next_epoch(netuid, tempo, block) + 1` for non-zero
+/// tempo.
+pub fn next_epoch_start_block(netuid: u16, tempo: u64, block: u64) -> u64 {
+    let next = epoch_index(netuid, tempo, block).saturating_add(1);
+    epoch_start_block(netuid, tempo, next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_subtensor() {
+        // netuid 1, tempo 360: epochs start where (block + 2) % 361 == 0
+        assert_eq!(epoch_index(1, 360, 0), 0);
+        assert_eq!(epoch_index(1, 360, 358), 0);
+        assert_eq!(epoch_index(1, 360, 359), 1);
+        assert_eq!(epoch_index(1, 360, 720), 2);
+        assert_eq!(epoch_start_block(1, 360, 0), 0);
+        assert_eq!(epoch_start_block(1, 360, 1), 359);
+        assert_eq!(epoch_start_block(1, 360, 3), 1081);
+
+        // The epoch runs in the last block of the index
+        assert_eq!(blocks_until_next_epoch(1, 360, 358), 0);
+        assert_eq!(blocks_until_next_epoch(1, 360, 359), 360);
+        assert_eq!(blocks_until_next_epoch(1, 360, 1000), 80);
+        assert_eq!(next_epoch_start_block(1, 360, 1000), 1081);
+        assert_eq!(next_epoch_start_block(1, 360, 1081), 1442);
+    }
+
+    #[test]
+    fn test_zero_tempo() {
+        assert_eq!(blocks_until_next_epoch(1, 0, 100), u64::MAX);
+        // Every block is its own index, as on chain
+        assert_eq!(epoch_index(1, 0, 100), 102);
+        assert_eq!(epoch_start_block(1, 0, 102), 100);
+    }
+}
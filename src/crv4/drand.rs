@@ -5,9 +5,13 @@
 //!
 //! IMPORTANT: The chain's DRAND pallet may be behind real-world time.
 //! Always use `calculate_reveal_round` with the chain's `LastStoredRound`
-//! to ensure reveal_rounds are within the chain's DRAND range.
+//! to ensure reveal_rounds are within the chain's DRAND range, and cross-check
+//! the result against the live beacon with `calculate_reveal_round_checked`.
 
+use crate::core::epoch;
+use crate::errors::{BittensorResult, ChainConnectionError, ChainQueryError, WeightsError};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// DRAND Quicknet public key (hex encoded, 96 bytes compressed G2 point)
 pub const DRAND_QUICKNET_PK_HEX: &str = "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a";
@@ -19,6 +23,24 @@ pub const DRAND_QUICKNET_GENESIS: u64 = 1688385600;
 /// DRAND round interval in seconds (Quicknet = 3 seconds)
 pub const DRAND_ROUND_INTERVAL_SECS: u64 = 3;
 
+/// DRAND Quicknet chain hash
+pub const DRAND_QUICKNET_CHAIN_HASH: &str =
+    "52db9ba70e0cc0f6eaf7803dd07447a1f5477735fd3f661792ba94600c84e971";
+
+/// Public DRAND HTTP API
+pub const DRAND_API_URL: &str = "https://api.drand.sh";
+
+/// Default number of rounds the chain's `LastStoredRound` may lag the live
+/// beacon before a warning is logged (20 rounds = 60 seconds)
+pub const DEFAULT_DRAND_DIVERGENCE_THRESHOLD: u64 = 20;
+
+/// Maximum distance (in rounds) between a beacon reported by the API and the
+/// round expected from system time before the beacon is rejected
+pub const DRAND_MAX_CLOCK_SKEW_ROUNDS: u64 = 10;
+
+/// Timeout for DRAND HTTP requests
+const DRAND_HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Information about DRAND beacon
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DrandInfo {
@@ -70,8 +92,153 @@ impl DrandInfo {
             .as_secs();
         self.round_at_time(now)
     }
+
+    /// Verify that a beacon round reported by an external source is plausible
+    /// for the given Unix timestamp.
+    ///
+    /// Rejects rounds that are ahead of the schedule implied by genesis and
+    /// period (they cannot exist yet), or more than `DRAND_MAX_CLOCK_SKEW_ROUNDS`
+    /// behind it (stale cache or wrong beacon chain).
+    pub fn verify_round_at_time(&self, round: u64, timestamp: u64) -> BittensorResult<()> {
+        let expected = self.round_at_time(timestamp);
+        if round > expected.saturating_add(1) {
+            return Err(WeightsError::new(format!(
+                "DRAND round {} is ahead of the expected round {} for timestamp {}",
+                round, expected, timestamp
+            ))
+            .into());
+        }
+        if round.saturating_add(DRAND_MAX_CLOCK_SKEW_ROUNDS) < expected {
+            return Err(WeightsError::new(format!(
+                "DRAND round {} is stale: expected round {} for timestamp {}",
+                round, expected, timestamp
+            ))
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// A beacon returned by the DRAND HTTP API
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DrandBeacon {
+    /// Round number
+    pub round: u64,
+    /// Hex-encoded randomness
+    #[serde(default)]
+    pub randomness: String,
+    /// Hex-encoded BLS signature
+    #[serde(default)]
+    pub signature: String,
 }
 
+/// HTTP client for the public DRAND Quicknet API
+///
+/// Used to cross-check the chain's `Drand.LastStoredRound` against the live
+/// beacon so commits are never targeted at rounds that have already passed.
+#[derive(Clone, Debug)]
+pub struct DrandClient {
+    http: reqwest::Client,
+    base_url: String,
+    info: DrandInfo,
+    divergence_threshold: u64,
+}
+
+impl DrandClient {
+    /// Create a client for Quicknet served from `base_url`
+    pub fn new(base_url: impl Into<String>) -> BittensorResult<Self> {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        let http = reqwest::Client::builder()
+            .timeout(DRAND_HTTP_TIMEOUT)
+            .build()
+            .map_err(|e| {
+                ChainConnectionError::with_url(
+                    format!("Failed to build DRAND HTTP client: {}", e),
+                    &base_url,
+                )
+            })?;
+        Ok(Self {
+            http,
+            base_url,
+            info: DrandInfo::quicknet(),
+            divergence_threshold: DEFAULT_DRAND_DIVERGENCE_THRESHOLD,
+        })
+    }
+
+    /// Create a client configured from environment variables
+    ///
+    /// - `BITTENSOR_DRAND_URL`: API base URL (default `DRAND_API_URL`)
+    /// - `BITTENSOR_DRAND_DIVERGENCE_ROUNDS`: divergence warning threshold
+    pub fn from_env() -> BittensorResult<Self> {
+        let base_url =
+            std::env::var("BITTENSOR_DRAND_URL").unwrap_or_else(|_| DRAND_API_URL.into());
+        let mut client = Self::new(base_url)?;
+        if let Ok(threshold) = std::env::var("BITTENSOR_DRAND_DIVERGENCE_ROUNDS") {
+            if let Ok(t) = threshold.parse() {
+                client.divergence_threshold = t;
+            }
+        }
+        Ok(client)
+    }
+
+    /// Set the number of rounds the chain may lag the live beacon before warning
+    pub fn with_divergence_threshold(mut self, rounds: u64) -> Self {
+        self.divergence_threshold = rounds;
+        self
+    }
+
+    /// Divergence warning threshold in rounds
+    pub fn divergence_threshold(&self) -> u64 {
+        self.divergence_threshold
+    }
+
+    /// Beacon parameters used for verification
+    pub fn info(&self) -> &DrandInfo {
+        &self.info
+    }
+
+    /// Fetch the latest Quicknet beacon and verify its round against
+    /// `DRAND_QUICKNET_GENESIS` / `DRAND_ROUND_INTERVAL_SECS`
+    pub async fn latest(&self) -> BittensorResult<DrandBeacon> {
+        let url = format!(
+            "{}/{}/public/latest",
+            self.base_url, DRAND_QUICKNET_CHAIN_HASH
+        );
+        let beacon: DrandBeacon = self
+            .http
+            .get(&url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| {
+                ChainConnectionError::with_url(
+                    format!("Failed to fetch latest DRAND beacon: {}", e),
+                    &self.base_url,
+                )
+            })?
+            .json()
+            .await
+            .map_err(|e| ChainQueryError::new(format!("Failed to decode DRAND beacon: {}", e)))?;
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.info.verify_round_at_time(beacon.round, now)?;
+
+        Ok(beacon)
+    }
+
+    /// Fetch the latest verified Quicknet round
+    pub async fn latest_round(&self) -> BittensorResult<u64> {
+        Ok(self.latest().await?.round)
+    }
+}
+
+/// Rounds added past the computed reveal time, so the target round is never
+/// published before the reveal block
+pub const REVEAL_ROUND_SAFETY_MARGIN: u64 = 1;
+
 /// Calculate the reveal round for CRv4 commits
 ///
 /// IMPORTANT: This function calculates reveal_round relative to the chain's
@@ -79,6 +246,13 @@ impl DrandInfo {
 /// significantly behind real time (e.g., 51 days), so using system time would
 /// produce reveal_rounds that don't exist on chain yet.
 ///
+/// Uses subtensor's epoch formula: the epoch of `block` is
+/// `(block + netuid + 1) / (tempo + 1)`, and the reveal is due at the first
+/// block of the epoch `subnet_reveal_period_epochs` after the current one.
+/// The wait is converted to DRAND rounds rounding up, plus
+/// [`REVEAL_ROUND_SAFETY_MARGIN`]. All arithmetic is integer; the block time is
+/// read once as whole milliseconds.
+///
 /// # Arguments
 /// * `tempo` - Number of blocks in one epoch
 /// * `current_block` - Current block number
@@ -88,7 +262,9 @@ impl DrandInfo {
 /// * `chain_last_drand_round` - The chain's Drand.LastStoredRound value
 ///
 /// # Returns
-/// The DRAND round number when the reveal should occur
+/// The DRAND round number when the reveal should occur. Fails if `tempo` or
+/// the reveal period is 0 (the chain never reveals such commits), if the
+/// block time is not a positive number, or if the round would overflow.
 pub fn calculate_reveal_round(
     tempo: u16,
     current_block: u64,
@@ -96,64 +272,99 @@ pub fn calculate_reveal_round(
     subnet_reveal_period_epochs: u64,
     block_time: f64,
     chain_last_drand_round: u64,
-) -> u64 {
-    let tempo = tempo as u64;
-    let netuid = netuid as u64;
-
-    // Calculate current epoch (same formula as subtensor)
-    // epoch = (current_block + netuid + 1) / (tempo + 1)
-    let tempo_plus_one = tempo.saturating_add(1);
-    let netuid_plus_one = netuid.saturating_add(1);
-    let current_epoch = current_block.saturating_add(netuid_plus_one) / tempo_plus_one;
-
-    // Reveal epoch = current_epoch + reveal_period
+) -> BittensorResult<u64> {
+    if tempo == 0 {
+        return Err(WeightsError::new(format!(
+            "Cannot calculate reveal round for netuid {}: tempo is 0, so epochs never end",
+            netuid
+        ))
+        .into());
+    }
     if subnet_reveal_period_epochs == 0 {
-        return chain_last_drand_round.saturating_add(1);
+        return Err(WeightsError::new(format!(
+            "Cannot calculate reveal round for netuid {}: reveal period must be at least 1 epoch",
+            netuid
+        ))
+        .into());
     }
 
-    let reveal_epoch = current_epoch.saturating_add(subnet_reveal_period_epochs);
-
-    // First block of reveal epoch
-    // first_block = reveal_epoch * (tempo + 1) - (netuid + 1)
-    let first_reveal_block = reveal_epoch
-        .saturating_mul(tempo_plus_one)
-        .saturating_sub(netuid_plus_one);
-
-    // Calculate time until reveal in seconds
-    let blocks_until_reveal = first_reveal_block.saturating_sub(current_block);
-    let secs_until_reveal = (blocks_until_reveal as f64 * block_time) as u64;
-
-    // Calculate how many DRAND rounds until reveal
-    // DRAND rounds occur every 3 seconds
-    let drand_rounds_until_reveal = secs_until_reveal / DRAND_ROUND_INTERVAL_SECS;
-
-    // Calculate reveal_round relative to chain's last stored round
-    // Add +1 buffer for safety
-    let reveal_round = chain_last_drand_round
-        .saturating_add(drand_rounds_until_reveal)
-        .saturating_add(1);
+    let tempo_plus_one = u64::from(tempo) + 1;
+    // The epoch runs in the last block of the index; reveals open in the next
+    let blocks_left_in_epoch =
+        epoch::blocks_until_next_epoch(netuid, u64::from(tempo), current_block) + 1;
+
+    let blocks_until_reveal = (subnet_reveal_period_epochs - 1)
+        .checked_mul(tempo_plus_one)
+        .and_then(|blocks| blocks.checked_add(blocks_left_in_epoch))
+        .ok_or_else(|| {
+            WeightsError::new(format!(
+                "Reveal period of {} epochs at tempo {} overflows the block count",
+                subnet_reveal_period_epochs, tempo
+            ))
+        })?;
+    let reveal_round =
+        calculate_reveal_round_for_blocks(blocks_until_reveal, block_time, chain_last_drand_round)?;
 
     tracing::info!(
         "CRv4 reveal round calculation: tempo={}, current_block={}, netuid={}, \
-         reveal_period={}, current_epoch={}, reveal_epoch={}, \
-         blocks_until_reveal={}, chain_last_drand_round={}, reveal_round={}",
+         reveal_period={}, blocks_until_reveal={}, chain_last_drand_round={}, reveal_round={}",
         tempo,
         current_block,
         netuid,
         subnet_reveal_period_epochs,
-        current_epoch,
-        reveal_epoch,
         blocks_until_reveal,
         chain_last_drand_round,
         reveal_round
     );
 
-    reveal_round
+    Ok(reveal_round)
+}
+
+/// Calculate the reveal round and check it against the live DRAND round
+///
+/// Computes the same round as `calculate_reveal_round`, then fails if the
+/// target is not strictly ahead of `live_drand_round`. A round that has
+/// already been published would let anyone decrypt the commit, and a chain
+/// whose drand pallet lags far enough behind produces exactly such targets.
+///
+/// # Arguments
+/// Same as `calculate_reveal_round`, plus:
+/// * `live_drand_round` - Latest round from the DRAND network (see `DrandClient`)
+pub fn calculate_reveal_round_checked(
+    tempo: u16,
+    current_block: u64,
+    netuid: u16,
+    subnet_reveal_period_epochs: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+    live_drand_round: u64,
+) -> BittensorResult<u64> {
+    let reveal_round = calculate_reveal_round(
+        tempo,
+        current_block,
+        netuid,
+        subnet_reveal_period_epochs,
+        block_time,
+        chain_last_drand_round,
+    )?;
+
+    if reveal_round <= live_drand_round {
+        return Err(WeightsError::new(format!(
+            "Reveal round {} is already in the past: live DRAND round is {}, chain LastStoredRound is {} ({} rounds behind)",
+            reveal_round,
+            live_drand_round,
+            chain_last_drand_round,
+            live_drand_round.saturating_sub(chain_last_drand_round)
+        )).into());
+    }
+
+    Ok(reveal_round)
 }
 
 /// Calculate reveal round with explicit epoch information
 ///
-/// This is useful when you already know the epoch boundaries.
+/// This is useful when you already know the epoch boundaries. A reveal epoch
+/// that has already begun targets the next round.
 ///
 /// # Arguments
 /// * `reveal_epoch` - The epoch when weights should be revealed
@@ -169,29 +380,68 @@ pub fn calculate_reveal_round_for_epoch(
     current_block: u64,
     block_time: f64,
     chain_last_drand_round: u64,
-) -> u64 {
-    let tempo = tempo as u64;
-    let netuid = netuid as u64;
-
-    let tempo_plus_one = tempo.saturating_add(1);
-    let netuid_plus_one = netuid.saturating_add(1);
+) -> BittensorResult<u64> {
+    let tempo_plus_one = u64::from(tempo) + 1;
+    let netuid_plus_one = u64::from(netuid) + 1;
 
     // First block of reveal epoch
     let first_reveal_block = reveal_epoch
-        .saturating_mul(tempo_plus_one)
+        .checked_mul(tempo_plus_one)
+        .ok_or_else(|| {
+            WeightsError::new(format!(
+                "Reveal epoch {} at tempo {} overflows the block number",
+                reveal_epoch, tempo
+            ))
+        })?
         .saturating_sub(netuid_plus_one);
 
-    // Calculate blocks until reveal
     let blocks_until_reveal = first_reveal_block.saturating_sub(current_block);
-    let secs_until_reveal = (blocks_until_reveal as f64 * block_time) as u64;
+    calculate_reveal_round_for_blocks(blocks_until_reveal, block_time, chain_last_drand_round)
+}
 
-    // Calculate DRAND rounds until reveal
-    let drand_rounds_until_reveal = secs_until_reveal / DRAND_ROUND_INTERVAL_SECS;
+/// Calculate the DRAND round reached `blocks_until_reveal` blocks from now
+///
+/// The wait is rounded up to whole rounds, plus [`REVEAL_ROUND_SAFETY_MARGIN`].
+///
+/// # Arguments
+/// * `blocks_until_reveal` - Blocks from the current block until the reveal
+/// * `block_time` - Block time in seconds
+/// * `chain_last_drand_round` - The chain's Drand.LastStoredRound value
+pub fn calculate_reveal_round_for_blocks(
+    blocks_until_reveal: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+) -> BittensorResult<u64> {
+    let block_time_ms = block_time_millis(block_time)?;
+    let overflow = || {
+        WeightsError::new(format!(
+            "Reveal round overflows: {} blocks of {}s after DRAND round {}",
+            blocks_until_reveal, block_time, chain_last_drand_round
+        ))
+    };
+
+    let ms_until_reveal = blocks_until_reveal
+        .checked_mul(block_time_ms)
+        .ok_or_else(overflow)?;
+    let rounds_until_reveal = ms_until_reveal.div_ceil(DRAND_ROUND_INTERVAL_SECS * 1000);
+
+    Ok(chain_last_drand_round
+        .checked_add(rounds_until_reveal)
+        .and_then(|round| round.checked_add(REVEAL_ROUND_SAFETY_MARGIN))
+        .ok_or_else(overflow)?)
+}
 
-    // Return reveal_round relative to chain's last stored round
-    chain_last_drand_round
-        .saturating_add(drand_rounds_until_reveal)
-        .saturating_add(1)
+/// Block time in whole milliseconds, rejecting values that are not positive
+fn block_time_millis(block_time: f64) -> BittensorResult<u64> {
+    let millis = (block_time * 1000.0).round();
+    if !millis.is_finite() || millis < 1.0 || millis > u32::MAX as f64 {
+        return Err(WeightsError::new(format!(
+            "Invalid block time {}s: must be a positive number of seconds",
+            block_time
+        ))
+        .into());
+    }
+    Ok(millis as u64)
 }
 
 #[cfg(test)]
@@ -243,7 +493,8 @@ mod tests {
             reveal_period,
             block_time,
             chain_last_drand_round,
-        );
+        )
+        .unwrap();
 
         // Should be greater than chain's last stored round
         assert!(reveal_round > chain_last_drand_round);
@@ -275,7 +526,8 @@ mod tests {
             reveal_period,
             block_time,
             chain_round_1,
-        );
+        )
+        .unwrap();
         let reveal_2 = calculate_reveal_round(
             tempo,
             current_block,
@@ -283,9 +535,161 @@ mod tests {
             reveal_period,
             block_time,
             chain_round_2,
-        );
+        )
+        .unwrap();
 
         // The difference should be exactly 1_000_000 (the difference in chain state)
         assert_eq!(reveal_2 - reveal_1, chain_round_2 - chain_round_1);
     }
+
+    #[test]
+    fn test_reveal_round_for_blocks() {
+        // 100 blocks of 12s = 1200s = 400 rounds, plus the +1 buffer
+        assert_eq!(
+            calculate_reveal_round_for_blocks(100, 12.0, 1_000).unwrap(),
+            1_401
+        );
+        assert_eq!(
+            calculate_reveal_round_for_blocks(0, 12.0, 1_000).unwrap(),
+            1_001
+        );
+        // Partial rounds are rounded up: 1 block of 12.5s = 4.17 rounds
+        assert_eq!(
+            calculate_reveal_round_for_blocks(1, 12.5, 1_000).unwrap(),
+            1_006
+        );
+    }
+
+    #[test]
+    fn test_reveal_round_for_blocks_rejects_bad_input() {
+        for block_time in [0.0, -12.0, f64::NAN, f64::INFINITY] {
+            assert!(calculate_reveal_round_for_blocks(10, block_time, 1_000).is_err());
+        }
+        assert!(calculate_reveal_round_for_blocks(u64::MAX, 12.0, 1_000).is_err());
+        assert!(calculate_reveal_round_for_blocks(1, 12.0, u64::MAX - 2).is_err());
+    }
+
+    /// Blocks until the reveal, from subtensor's epoch formula
+    fn reference_blocks_until_reveal(tempo: u64, block: u64, netuid: u64, period: u64) -> u64 {
+        let epoch = (block + netuid + 1) / (tempo + 1);
+        let first_reveal_block = (epoch + period) * (tempo + 1) - (netuid + 1);
+        first_reveal_block - block
+    }
+
+    #[test]
+    fn test_reveal_round_every_block_of_an_epoch() {
+        let chain_last = 24_000_000u64;
+        let netuid = 7u16;
+        for tempo in [99u16, 360, 1000] {
+            let tempo_plus_one = tempo as u64 + 1;
+            // Start of the 50th epoch for this netuid
+            let epoch_start = 50 * tempo_plus_one - (netuid as u64 + 1);
+            for offset in 0..tempo_plus_one {
+                let block = epoch_start + offset;
+                for period in 1..=4u64 {
+                    let round =
+                        calculate_reveal_round(tempo, block, netuid, period, 12.0, chain_last)
+                            .unwrap();
+                    assert!(round > chain_last);
+
+                    // The round is not published before the reveal block
+                    let blocks =
+                        reference_blocks_until_reveal(tempo as u64, block, netuid as u64, period);
+                    assert!((1..=period * tempo_plus_one).contains(&blocks));
+                    let rounds = round - chain_last - REVEAL_ROUND_SAFETY_MARGIN;
+                    assert_eq!(rounds, blocks * 12 / 3, "tempo={} block={}", tempo, block);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reveal_round_epoch_boundary() {
+        let chain_last = 1_000u64;
+        // netuid 1, tempo 360: epochs start at blocks where (block + 2) % 361 == 0
+        let last_block = 361 * 10 - 3;
+        let first_block = last_block + 1;
+
+        // One block before the boundary, the reveal is one block away
+        assert_eq!(
+            calculate_reveal_round(360, last_block, 1, 1, 12.0, chain_last).unwrap(),
+            chain_last + 4 + REVEAL_ROUND_SAFETY_MARGIN
+        );
+        // On the boundary, a whole epoch
+        assert_eq!(
+            calculate_reveal_round(360, first_block, 1, 1, 12.0, chain_last).unwrap(),
+            chain_last + 361 * 4 + REVEAL_ROUND_SAFETY_MARGIN
+        );
+    }
+
+    #[test]
+    fn test_reveal_round_rejects_degenerate_subnets() {
+        let err = calculate_reveal_round(0, 5000, 1, 1, 12.0, 1_000).unwrap_err();
+        assert!(err.to_string().contains("tempo is 0"));
+        let err = calculate_reveal_round(360, 5000, 1, 0, 12.0, 1_000).unwrap_err();
+        assert!(err.to_string().contains("reveal period"));
+    }
+
+    #[test]
+    fn test_reveal_round_large_values() {
+        // Near-max blocks do not overflow the epoch offset
+        let round = calculate_reveal_round(u16::MAX, u64::MAX, u16::MAX, 1, 12.0, 0).unwrap();
+        assert!(round > 0);
+        // Huge reveal periods and chain rounds fail instead of wrapping
+        assert!(calculate_reveal_round(360, 5000, 1, u64::MAX, 12.0, 0).is_err());
+        assert!(calculate_reveal_round(360, 5000, 1, 1, 12.0, u64::MAX - 100).is_err());
+    }
+
+    #[test]
+    fn test_verify_round_at_time() {
+        let info = DrandInfo::quicknet();
+        let now = info.genesis_time + 3_000_000;
+        let expected = info.round_at_time(now);
+
+        assert!(info.verify_round_at_time(expected, now).is_ok());
+        assert!(info.verify_round_at_time(expected + 1, now).is_ok());
+        assert!(info
+            .verify_round_at_time(expected - DRAND_MAX_CLOCK_SKEW_ROUNDS, now)
+            .is_ok());
+
+        // Rounds from the future or far in the past are rejected
+        assert!(info.verify_round_at_time(expected + 2, now).is_err());
+        assert!(info
+            .verify_round_at_time(expected - DRAND_MAX_CLOCK_SKEW_ROUNDS - 1, now)
+            .is_err());
+    }
+
+    #[test]
+    fn test_reveal_round_checked_in_sync() {
+        let chain_round = 24_000_000u64;
+        let checked =
+            calculate_reveal_round_checked(360, 5000, 1, 1, 12.0, chain_round, chain_round)
+                .expect("chain in sync with live beacon");
+        let unchecked = calculate_reveal_round(360, 5000, 1, 1, 12.0, chain_round).unwrap();
+        assert_eq!(checked, unchecked);
+    }
+
+    #[test]
+    fn test_reveal_round_checked_rejects_past_round() {
+        let chain_round = 24_000_000u64;
+        let target = calculate_reveal_round(360, 5000, 1, 1, 12.0, chain_round).unwrap();
+
+        // Live beacon already at the target round
+        let err =
+            calculate_reveal_round_checked(360, 5000, 1, 1, 12.0, chain_round, target).unwrap_err();
+        assert!(err.to_string().contains("already in the past"));
+
+        // One round before the target is still acceptable
+        assert!(
+            calculate_reveal_round_checked(360, 5000, 1, 1, 12.0, chain_round, target - 1).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_drand_beacon_deserialize() {
+        let json = r#"{"round":1234,"randomness":"ab","signature":"cd"}"#;
+        let beacon: DrandBeacon = serde_json::from_str(json).unwrap();
+        assert_eq!(beacon.round, 1234);
+        assert_eq!(beacon.randomness, "ab");
+    }
 }
@@ -3,8 +3,10 @@
 //! Implements the TLE (Timelock Encryption) using DRAND Quicknet beacon.
 //! The encryption is based on BLS12-381 curve (TinyBLS381).
 
-use crate::crv4::{DrandInfo, WeightsTlockPayload, DRAND_QUICKNET_PK_HEX};
-use anyhow::Result;
+use crate::crv4::{
+    calculate_reveal_round_for_blocks, DrandInfo, WeightsTlockPayload, DRAND_QUICKNET_PK_HEX,
+};
+use crate::errors::{BittensorResult, SerializationError};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use parity_scale_codec::Encode;
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
@@ -33,7 +35,7 @@ pub fn prepare_crv4_commit(
     weights: &[u16],
     version_key: u64,
     reveal_round: u64,
-) -> Result<Vec<u8>> {
+) -> BittensorResult<Vec<u8>> {
     // Create payload
     let payload = WeightsTlockPayload {
         hotkey: hotkey.to_vec(),
@@ -55,13 +57,16 @@ pub fn prepare_crv4_commit(
 /// - BLS12-381 curve (TinyBLS381)
 /// - AES-GCM stream cipher
 /// - DRAND Quicknet public key
-pub fn encrypt_for_round(data: &[u8], reveal_round: u64) -> Result<Vec<u8>> {
+pub fn encrypt_for_round(data: &[u8], reveal_round: u64) -> BittensorResult<Vec<u8>> {
     // Get DRAND public key
-    let pk_bytes = hex::decode(DRAND_QUICKNET_PK_HEX)
-        .map_err(|e| anyhow::anyhow!("Failed to decode DRAND public key: {}", e))?;
+    let pk_bytes = hex::decode(DRAND_QUICKNET_PK_HEX).map_err(|e| {
+        SerializationError::new(format!("Failed to decode DRAND public key: {}", e))
+    })?;
 
     let pub_key = <TinyBLS381 as EngineBLS>::PublicKeyGroup::deserialize_compressed(&*pk_bytes)
-        .map_err(|e| anyhow::anyhow!("Failed to deserialize DRAND public key: {:?}", e))?;
+        .map_err(|e| {
+            SerializationError::new(format!("Failed to deserialize DRAND public key: {:?}", e))
+        })?;
 
     // Create identity from round number
     // Identity = SHA256(round.to_be_bytes())
@@ -80,7 +85,7 @@ pub fn encrypt_for_round(data: &[u8], reveal_round: u64) -> Result<Vec<u8>> {
     let ciphertext = tle::<TinyBLS381, AESGCMStreamCipherProvider, ChaCha20Rng>(
         pub_key, esk, data, identity, rng,
     )
-    .map_err(|e| anyhow::anyhow!("TLE encryption failed: {:?}", e))?;
+    .map_err(|e| SerializationError::new(format!("TLE encryption failed: {:?}", e)))?;
 
     // SECURITY: Zeroize ephemeral secret key after use to prevent leakage
     esk.zeroize();
@@ -89,7 +94,7 @@ pub fn encrypt_for_round(data: &[u8], reveal_round: u64) -> Result<Vec<u8>> {
     let mut commit_bytes = Vec::new();
     ciphertext
         .serialize_compressed(&mut commit_bytes)
-        .map_err(|e| anyhow::anyhow!("Failed to serialize ciphertext: {:?}", e))?;
+        .map_err(|e| SerializationError::new(format!("Failed to serialize ciphertext: {:?}", e)))?;
 
     Ok(commit_bytes)
 }
@@ -107,7 +112,7 @@ pub fn encrypt_for_blocks(
     data: &[u8],
     blocks_until_reveal: u64,
     block_time: f64,
-) -> Result<(Vec<u8>, u64)> {
+) -> BittensorResult<(Vec<u8>, u64)> {
     let drand_info = DrandInfo::quicknet();
 
     let now = std::time::SystemTime::now()
@@ -126,6 +131,26 @@ pub fn encrypt_for_blocks(
     Ok((encrypted, reveal_round))
 }
 
+/// Encrypt data to be revealed `blocks_until_reveal` blocks from now
+///
+/// Unlike [`encrypt_for_blocks`], the reveal round is counted from the
+/// chain's `Drand.LastStoredRound` (see [`calculate_reveal_round_for_blocks`]),
+/// which is what the chain uses to decide when to decrypt.
+///
+/// # Returns
+/// (encrypted_data, reveal_round)
+pub fn encrypt_for_chain_blocks(
+    data: &[u8],
+    blocks_until_reveal: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+) -> BittensorResult<(Vec<u8>, u64)> {
+    let reveal_round =
+        calculate_reveal_round_for_blocks(blocks_until_reveal, block_time, chain_last_drand_round)?;
+    let encrypted = encrypt_for_round(data, reveal_round)?;
+    Ok((encrypted, reveal_round))
+}
+
 /// Verify that encrypted data is valid
 ///
 /// This doesn't decrypt, just checks the structure is valid.
@@ -185,4 +210,12 @@ mod tests {
         assert!(reveal_round > 0);
         assert!(verify_encrypted_data(&encrypted));
     }
+
+    #[test]
+    fn test_encrypt_for_chain_blocks() {
+        let (encrypted, reveal_round) =
+            encrypt_for_chain_blocks(b"reveal later", 100, 12.0, 24_000_000).unwrap();
+        assert_eq!(reveal_round, 24_000_401);
+        assert!(verify_encrypted_data(&encrypted));
+    }
 }
@@ -29,6 +29,9 @@
 //! // Submit to chain
 //! commit_timelocked_mechanism_weights(client, signer, netuid, mecid, &commit_data).await?;
 //! ```
+//!
+//! Weights for several mechanisms can be committed in one `Utility` batch with
+//! [`prepare_and_commit_crv4_multi`].
 
 mod drand;
 mod encryption;
@@ -40,8 +43,12 @@ pub use encryption::*;
 pub use payload::*;
 pub use persistence::*;
 
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
+use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult, ChainQueryError, WeightsError};
+use crate::utils::decoders::{decode_stored_or, decode_u16, decode_u64};
+use crate::utils::WeightValidation;
+use crate::validator::utility::{submit_batch, BatchCall, BatchMode};
+use crate::validator::weights::{check_weight_inputs, resolve_version_key};
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
@@ -52,19 +59,22 @@ const COMMIT_TIMELOCKED_MECHANISM_WEIGHTS: &str = "commit_timelocked_mechanism_w
 pub const DEFAULT_COMMIT_REVEAL_VERSION: u16 = 4;
 
 /// Submit a timelocked weight commitment (CRv4) for main mechanism
+#[tracing::instrument(
+    level = "error",
+    skip_all,
+    fields(netuid = netuid, hotkey = %signer.account_id())
+)]
 pub async fn commit_timelocked_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     commit: &[u8],
     reveal_round: u64,
     commit_reveal_version: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if commit.is_empty() {
-        return Err(anyhow::anyhow!(
-            "Encrypted commit payload must not be empty"
-        ));
+        return Err(WeightsError::new("Encrypted commit payload must not be empty").into());
     }
 
     let args = vec![
@@ -74,7 +84,7 @@ pub async fn commit_timelocked_weights(
         Value::from(commit_reveal_version),
     ];
 
-    let tx_hash = client
+    let result = client
         .submit_extrinsic(
             SUBTENSOR_MODULE,
             COMMIT_TIMELOCKED_WEIGHTS,
@@ -82,16 +92,23 @@ pub async fn commit_timelocked_weights(
             signer,
             wait_for,
         )
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to commit timelocked weights: {}", e))?;
+        .await;
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::global().observe_crv4_commit(result.is_ok());
 
-    Ok(tx_hash)
+    result.map_err(|e| BittensorError::from_chain("Failed to commit timelocked weights", e))
 }
 
 /// Submit a timelocked mechanism weight commitment (CRv4)
 #[allow(clippy::too_many_arguments)]
+#[tracing::instrument(
+    level = "error",
+    skip_all,
+    fields(netuid = netuid, hotkey = %signer.account_id())
+)]
 pub async fn commit_timelocked_mechanism_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     mechanism_id: u8,
@@ -99,11 +116,9 @@ pub async fn commit_timelocked_mechanism_weights(
     reveal_round: u64,
     commit_reveal_version: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if commit.is_empty() {
-        return Err(anyhow::anyhow!(
-            "Encrypted commit payload must not be empty"
-        ));
+        return Err(WeightsError::new("Encrypted commit payload must not be empty").into());
     }
 
     let args = vec![
@@ -114,7 +129,7 @@ pub async fn commit_timelocked_mechanism_weights(
         Value::from(commit_reveal_version),
     ];
 
-    let tx_hash = client
+    let result = client
         .submit_extrinsic(
             SUBTENSOR_MODULE,
             COMMIT_TIMELOCKED_MECHANISM_WEIGHTS,
@@ -122,61 +137,136 @@ pub async fn commit_timelocked_mechanism_weights(
             signer,
             wait_for,
         )
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to commit timelocked mechanism weights: {}", e))?;
+        .await;
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::global().observe_crv4_commit(result.is_ok());
 
-    Ok(tx_hash)
+    result
+        .map_err(|e| BittensorError::from_chain("Failed to commit timelocked mechanism weights", e))
 }
 
 /// Get the chain's last stored DRAND round
-pub async fn get_last_drand_round(client: &BittensorClient) -> Result<u64> {
+pub async fn get_last_drand_round(client: &impl ChainBackend) -> BittensorResult<u64> {
     if let Some(val) = client.storage("Drand", "LastStoredRound", None).await? {
-        if let Ok(round) = crate::utils::decoders::decode_u64(&val) {
+        if let Ok(round) = decode_u64(&val) {
             return Ok(round);
         }
     }
-    Err(anyhow::anyhow!(
-        "Failed to get Drand.LastStoredRound from chain"
-    ))
+    Err(ChainQueryError::new("Failed to get Drand.LastStoredRound from chain").into())
+}
+
+/// Calculate the reveal round, cross-checking the chain's DRAND state against
+/// the live beacon
+///
+/// Logs a warning with both rounds when the chain lags the live beacon by more
+/// than the client's divergence threshold. If the live beacon cannot be
+/// reached, falls back to the unchecked calculation.
+#[allow(clippy::too_many_arguments)]
+pub async fn checked_reveal_round(
+    drand: &DrandClient,
+    tempo: u16,
+    current_block: u64,
+    storage_index: u16,
+    reveal_period: u64,
+    block_time: f64,
+    chain_last_drand_round: u64,
+) -> BittensorResult<u64> {
+    let live_round = match drand.latest_round().await {
+        Ok(round) => round,
+        Err(e) => {
+            tracing::warn!(
+                "Could not verify chain DRAND round {} against live beacon: {}",
+                chain_last_drand_round,
+                e
+            );
+            return calculate_reveal_round(
+                tempo,
+                current_block,
+                storage_index,
+                reveal_period,
+                block_time,
+                chain_last_drand_round,
+            );
+        }
+    };
+
+    if live_round.abs_diff(chain_last_drand_round) > drand.divergence_threshold() {
+        tracing::warn!(
+            "Chain DRAND round diverges from live beacon: chain_last_drand={}, live_drand={}, threshold={}",
+            chain_last_drand_round,
+            live_round,
+            drand.divergence_threshold()
+        );
+    }
+
+    calculate_reveal_round_checked(
+        tempo,
+        current_block,
+        storage_index,
+        reveal_period,
+        block_time,
+        chain_last_drand_round,
+        live_round,
+    )
 }
 
 /// High-level function: Prepare and submit CRv4 weights
 ///
 /// This handles the entire flow:
-/// 1. Calculate reveal round based on tempo/epoch and chain's DRAND state
+/// 1. Calculate reveal round based on tempo/epoch and chain's DRAND state,
+///    verified against the live DRAND beacon
 /// 2. Encrypt payload with TLE
 /// 3. Submit to chain
 /// 4. Return commit data for persistence
+///
+/// UIDs and weights are checked against the subnet's bounds before
+/// encryption, as the chain only rejects them at reveal time. A `version_key`
+/// of None uses the subnet's `WeightsVersionKey`; see
+/// [`resolve_version_key`].
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(
+    level = "error",
+    skip_all,
+    fields(netuid = netuid, hotkey = %signer.account_id())
+)]
 pub async fn prepare_and_commit_crv4_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     uids: &[u16],
     weights: &[u16],
-    version_key: u64,
+    version_key: Option<u64>,
+    validation: WeightValidation,
     wait_for: ExtrinsicWait,
-) -> Result<Crv4CommitData> {
+) -> BittensorResult<Crv4CommitData> {
+    let version_key = resolve_version_key(client, netuid, version_key).await?;
     let hotkey_bytes = signer.account_id().0.to_vec();
+    let (uids, weights) =
+        check_weight_inputs(client, signer, netuid, uids, weights, validation).await?;
+    let (uids, weights) = (uids.as_slice(), weights.as_slice());
 
     // Get chain parameters
     let current_block = client.block_number().await?;
     let tempo = get_tempo(client, netuid).await.unwrap_or(360);
     let reveal_period = get_reveal_period(client, netuid).await.unwrap_or(1);
-    let block_time = 12.0; // Standard Bittensor block time
+    let block_time = client.constants().block_time_secs();
 
     // Get chain's last DRAND round (CRITICAL: must use chain state, not system time)
     let chain_last_drand_round = get_last_drand_round(client).await?;
 
-    // Calculate reveal round relative to chain's DRAND state
+    // Calculate reveal round relative to chain's DRAND state, checked against the live beacon
     let storage_index = get_mechid_storage_index(netuid, 0); // Main mechanism
-    let reveal_round = calculate_reveal_round(
+    let reveal_round = checked_reveal_round(
+        &DrandClient::from_env()?,
         tempo,
         current_block,
         storage_index,
         reveal_period,
         block_time,
         chain_last_drand_round,
-    );
+    )
+    .await?;
 
     // Prepare and encrypt payload
     let encrypted = prepare_crv4_commit(&hotkey_bytes, uids, weights, version_key, reveal_round)?;
@@ -225,35 +315,47 @@ pub async fn prepare_and_commit_crv4_weights(
 
 /// High-level function: Prepare and submit CRv4 mechanism weights
 #[allow(clippy::too_many_arguments)]
+#[tracing::instrument(
+    level = "error",
+    skip_all,
+    fields(netuid = netuid, hotkey = %signer.account_id())
+)]
 pub async fn prepare_and_commit_crv4_mechanism_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     mechanism_id: u8,
     uids: &[u16],
     weights: &[u16],
-    version_key: u64,
+    version_key: Option<u64>,
+    validation: WeightValidation,
     wait_for: ExtrinsicWait,
-) -> Result<Crv4CommitData> {
+) -> BittensorResult<Crv4CommitData> {
+    let version_key = resolve_version_key(client, netuid, version_key).await?;
     let hotkey_bytes = signer.account_id().0.to_vec();
+    let (uids, weights) =
+        check_weight_inputs(client, signer, netuid, uids, weights, validation).await?;
+    let (uids, weights) = (uids.as_slice(), weights.as_slice());
 
     let current_block = client.block_number().await?;
     let tempo = get_tempo(client, netuid).await.unwrap_or(360);
     let reveal_period = get_reveal_period(client, netuid).await.unwrap_or(1);
-    let block_time = 12.0;
+    let block_time = client.constants().block_time_secs();
 
     // Get chain's last DRAND round (CRITICAL: must use chain state, not system time)
     let chain_last_drand_round = get_last_drand_round(client).await?;
 
     let storage_index = get_mechid_storage_index(netuid, mechanism_id);
-    let reveal_round = calculate_reveal_round(
+    let reveal_round = checked_reveal_round(
+        &DrandClient::from_env()?,
         tempo,
         current_block,
         storage_index,
         reveal_period,
         block_time,
         chain_last_drand_round,
-    );
+    )
+    .await?;
 
     let encrypted = prepare_crv4_commit(&hotkey_bytes, uids, weights, version_key, reveal_round)?;
 
@@ -298,13 +400,180 @@ pub async fn prepare_and_commit_crv4_mechanism_weights(
     })
 }
 
+/// Weights for one mechanism of a subnet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MechanismWeights {
+    pub mecid: u8,
+    pub uids: Vec<u16>,
+    pub weights: Vec<u16>,
+}
+
+impl MechanismWeights {
+    pub fn new(mecid: u8, uids: Vec<u16>, weights: Vec<u16>) -> Self {
+        Self {
+            mecid,
+            uids,
+            weights,
+        }
+    }
+}
+
+/// Commits for several mechanisms submitted in one batch extrinsic
+#[derive(Debug, Clone)]
+pub struct Crv4BatchCommit {
+    pub tx_hash: String,
+    /// One commit per mechanism, in the order given
+    pub commits: Vec<Crv4CommitData>,
+    /// Whether each commit was applied, matching `commits`
+    pub succeeded: Vec<bool>,
+}
+
+impl Crv4BatchCommit {
+    /// The commits that were applied, e.g. to persist
+    pub fn successful(&self) -> impl Iterator<Item = &Crv4CommitData> {
+        self.commits
+            .iter()
+            .zip(&self.succeeded)
+            .filter(|(_, &ok)| ok)
+            .map(|(commit, _)| commit)
+    }
+}
+
+/// High-level function: Prepare and submit CRv4 weights for several
+/// mechanisms in one extrinsic
+///
+/// Each mechanism gets its own reveal round, as its storage index shifts its
+/// epoch boundaries, and its own encrypted payload. All
+/// `commit_timelocked_mechanism_weights` calls go into a single `Utility`
+/// batch, so they land in the same block and cannot straddle the commit
+/// window. `mode` selects what happens when one commit fails:
+/// [`BatchMode::All`] reverts all of them, [`BatchMode::StopOnError`] keeps
+/// the commits before the failing one and [`BatchMode::Force`] keeps every
+/// commit that succeeds. The outcome of each commit is read from the batch's
+/// events.
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(
+    level = "error",
+    skip_all,
+    fields(netuid = netuid, hotkey = %signer.account_id())
+)]
+pub async fn prepare_and_commit_crv4_multi(
+    client: &BittensorClient,
+    signer: &BittensorSigner,
+    netuid: u16,
+    commits: Vec<MechanismWeights>,
+    version_key: Option<u64>,
+    validation: WeightValidation,
+    mode: BatchMode,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<Crv4BatchCommit> {
+    if commits.is_empty() {
+        return Err(WeightsError::new("No mechanism weights to commit").into());
+    }
+    let mut mecids = std::collections::HashSet::new();
+    if let Some(duplicate) = commits.iter().find(|c| !mecids.insert(c.mecid)) {
+        return Err(WeightsError::new(format!(
+            "Mechanism {} appears more than once in the batch",
+            duplicate.mecid
+        ))
+        .into());
+    }
+
+    let version_key = resolve_version_key(client, netuid, version_key).await?;
+    let hotkey_bytes = signer.account_id().0.to_vec();
+    let current_block = client.block_number().await?;
+    let tempo = get_tempo(client, netuid).await.unwrap_or(360);
+    let reveal_period = get_reveal_period(client, netuid).await.unwrap_or(1);
+    let block_time = client.constants().block_time_secs();
+    let chain_last_drand_round = get_last_drand_round(client).await?;
+    let crv_version = get_commit_reveal_version(client)
+        .await
+        .unwrap_or(DEFAULT_COMMIT_REVEAL_VERSION);
+    let drand = DrandClient::from_env()?;
+
+    let mut prepared = Vec::with_capacity(commits.len());
+    let mut calls = Vec::with_capacity(commits.len());
+    for mechanism in commits {
+        let (uids, weights) = check_weight_inputs(
+            client,
+            signer,
+            netuid,
+            &mechanism.uids,
+            &mechanism.weights,
+            validation,
+        )
+        .await?;
+
+        let storage_index = get_mechid_storage_index(netuid, mechanism.mecid);
+        let reveal_round = checked_reveal_round(
+            &drand,
+            tempo,
+            current_block,
+            storage_index,
+            reveal_period,
+            block_time,
+            chain_last_drand_round,
+        )
+        .await?;
+        let encrypted =
+            prepare_crv4_commit(&hotkey_bytes, &uids, &weights, version_key, reveal_round)?;
+
+        calls.push(BatchCall::commit_timelocked_mechanism_weights(
+            netuid,
+            mechanism.mecid,
+            &encrypted,
+            reveal_round,
+            crv_version,
+        ));
+        prepared.push(Crv4CommitData {
+            netuid,
+            mechanism_id: Some(mechanism.mecid),
+            hotkey: hotkey_bytes.clone(),
+            uids,
+            weights,
+            version_key,
+            reveal_round,
+            commit_reveal_version: crv_version,
+            encrypted_payload: encrypted,
+            tx_hash: String::new(),
+            committed_at: chrono::Utc::now(),
+            epoch: current_block / (tempo as u64 + 1),
+        });
+    }
+
+    let outcome = submit_batch(client, signer, calls, mode, wait_for).await?;
+
+    #[cfg(feature = "metrics")]
+    for &ok in &outcome.succeeded {
+        crate::metrics::global().observe_crv4_commit(ok);
+    }
+
+    tracing::info!(
+        "CRv4 batch commit submitted: tx={}, netuid={}, mecids={:?}, succeeded={:?}, mode={:?}",
+        outcome.tx_hash,
+        netuid,
+        prepared.iter().map(|c| c.mechanism_id).collect::<Vec<_>>(),
+        outcome.succeeded,
+        mode
+    );
+
+    for commit in &mut prepared {
+        commit.tx_hash = outcome.tx_hash.clone();
+    }
+    Ok(Crv4BatchCommit {
+        tx_hash: outcome.tx_hash,
+        commits: prepared,
+        succeeded: outcome.succeeded,
+    })
+}
+
 /// Get commit-reveal version from chain
-pub async fn get_commit_reveal_version(client: &BittensorClient) -> Result<u16> {
+pub async fn get_commit_reveal_version(client: &impl ChainBackend) -> BittensorResult<u16> {
     if let Some(val) = client
         .storage(SUBTENSOR_MODULE, "CommitRevealWeightsVersion", None)
         .await?
     {
-        if let Ok(version) = crate::utils::decoders::decode_u16(&val) {
+        if let Ok(version) = decode_u16(&val) {
             return Ok(version);
         }
     }
@@ -312,26 +581,21 @@ pub async fn get_commit_reveal_version(client: &BittensorClient) -> Result<u16>
 }
 
 /// Get tempo for a subnet
-pub async fn get_tempo(client: &BittensorClient, netuid: u16) -> Result<u16> {
+pub async fn get_tempo(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
     let key = vec![Value::from(netuid)];
-    if let Some(val) = client.storage(SUBTENSOR_MODULE, "Tempo", Some(key)).await? {
-        return crate::utils::decoders::decode_u16(&val)
-            .map_err(|e| anyhow::anyhow!("Failed to decode tempo: {}", e));
-    }
-    Ok(360)
+    let value = client.storage(SUBTENSOR_MODULE, "Tempo", Some(key)).await?;
+    decode_stored_or(value, 360, decode_u16)
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode tempo: {}", e)).into())
 }
 
 /// Get reveal period in epochs
-pub async fn get_reveal_period(client: &BittensorClient, netuid: u16) -> Result<u64> {
+pub async fn get_reveal_period(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u64> {
     let key = vec![Value::from(netuid)];
-    if let Some(val) = client
+    let value = client
         .storage(SUBTENSOR_MODULE, "RevealPeriodEpochs", Some(key))
-        .await?
-    {
-        return crate::utils::decoders::decode_u64(&val)
-            .map_err(|e| anyhow::anyhow!("Failed to decode reveal period: {}", e));
-    }
-    Ok(1)
+        .await?;
+    decode_stored_or(value, 1, decode_u64)
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode reveal period: {}", e)).into())
 }
 
 /// Calculate mechanism storage index (same as subtensor)
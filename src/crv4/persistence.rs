@@ -5,6 +5,7 @@
 //! on restart, the validator knows it has already committed for
 //! an epoch and doesn't need to commit again.
 
+use crate::errors::BittensorResult;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -80,7 +81,7 @@ impl Crv4PersistedState {
     }
 
     /// Save state to file
-    pub fn save(&self, path: &PathBuf) -> anyhow::Result<()> {
+    pub fn save(&self, path: &PathBuf) -> BittensorResult<()> {
         let content = serde_json::to_string_pretty(self)?;
         std::fs::write(path, content)?;
         tracing::debug!("Saved CRv4 state to {:?}", path);
@@ -120,7 +121,12 @@ impl Crv4PersistedState {
             Some(mecid) => format!("{}_{}", netuid, mecid),
             None => format!("{}_main", netuid),
         };
-        self.pending_commits.remove(&key);
+        let _removed = self.pending_commits.remove(&key);
+
+        #[cfg(feature = "metrics")]
+        if _removed.is_some() {
+            crate::metrics::global().observe_crv4_reveal_verified();
+        }
     }
 
     /// Get pending commit for netuid/mechanism
@@ -227,12 +233,12 @@ impl Crv4StateManager {
     }
 
     /// Save state to disk
-    pub fn save(&self) -> anyhow::Result<()> {
+    pub fn save(&self) -> BittensorResult<()> {
         self.state.save(&self.path)
     }
 
     /// Add commit and save
-    pub fn add_and_save(&mut self, commit: Crv4CommitData) -> anyhow::Result<()> {
+    pub fn add_and_save(&mut self, commit: Crv4CommitData) -> BittensorResult<()> {
         self.state.add_commit(commit);
         self.save()
     }
@@ -242,13 +248,13 @@ impl Crv4StateManager {
         &mut self,
         netuid: u16,
         mechanism_id: Option<u8>,
-    ) -> anyhow::Result<()> {
+    ) -> BittensorResult<()> {
         self.state.mark_revealed(netuid, mechanism_id);
         self.save()
     }
 
     /// Update epoch and save
-    pub fn update_epoch_and_save(&mut self, new_epoch: u64) -> anyhow::Result<()> {
+    pub fn update_epoch_and_save(&mut self, new_epoch: u64) -> BittensorResult<()> {
         self.state.update_epoch(new_epoch);
         self.save()
     }
@@ -2,19 +2,42 @@
 //!
 //! The Dendrite client is responsible for making HTTP requests to Axon servers.
 //! It handles request signing, connection pooling, timeouts, and response parsing.
-
-use crate::dendrite::request::{DendriteRequest, RequestError};
+//!
+//! A single pooled HTTP client is built when the Dendrite is created and
+//! shared by every call (and every clone of the Dendrite), so repeated
+//! requests to the same axon reuse an idle keep-alive connection. Calls made
+//! with [`Dendrite::call_tls`] use a separate pooled client per pinned
+//! certificate.
+
+use crate::dendrite::config::DendriteConfig;
+use crate::dendrite::request::{header_names, DendriteRequest, RequestError};
 use crate::dendrite::response::{
-    build_error_synapse, status_codes, DendriteResponse, ResponseError,
+    build_error_synapse, status_codes, DendriteResponse, DendriteResult, ResponseError,
 };
-use crate::dendrite::streaming::{StreamError, StreamingResponse, StreamingSynapse};
-use crate::types::{AxonInfo, Synapse, TerminalInfo};
+use crate::dendrite::retry::{RetryPolicy, RetryReason};
+use crate::dendrite::streaming::{
+    read_stream_frames, terminal_from_trailers, StreamError, StreamEvent, StreamSummary,
+    StreamingResponse, StreamingSynapse,
+};
+use crate::dendrite::tls::pinned_client_config;
+use crate::errors::{self, AxonNotServing, AxonUnreachable, InvalidResponse, SynapseTimeout};
+use crate::queries::neurons::Certificate;
+use crate::types::synapse::python_float_repr;
+use crate::types::{AxonInfo, Synapse, SynapseType, TerminalInfo};
 use crate::utils::ss58::AccountId32ToSS58;
+use bytes::Bytes;
 use futures::Stream;
 use reqwest::Client;
+use sha2::{Digest, Sha256};
 use sp_core::{sr25519, Pair};
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use thiserror::Error;
+use tokio::sync::Semaphore;
+use tracing::debug;
 use uuid::Uuid;
 
 /// Default timeout for Dendrite requests (12 seconds, matching Python SDK)
@@ -42,6 +65,10 @@ pub enum DendriteError {
     Signing(String),
     #[error("Stream error: {0}")]
     Stream(#[from] StreamError),
+    #[error("TLS error: {0}")]
+    Tls(String),
+    #[error(transparent)]
+    InvalidResponse(#[from] InvalidResponse),
 }
 
 /// Dendrite HTTP client for making requests to Axon servers
@@ -71,6 +98,10 @@ pub enum DendriteError {
 pub struct Dendrite {
     /// The HTTP client (with connection pooling)
     client: Client,
+    /// Pooled HTTPS clients keyed by the pinned DER certificate
+    tls_clients: Arc<Mutex<HashMap<Vec<u8>, Client>>>,
+    /// Client configuration
+    config: DendriteConfig,
     /// Optional keypair for signing requests
     keypair: Option<sr25519::Pair>,
     /// Default timeout for requests
@@ -81,6 +112,8 @@ pub struct Dendrite {
     ip: Option<String>,
     /// Dendrite port (optional, for headers)
     port: Option<u16>,
+    /// Last nonce issued, shared by clones so nonces never repeat
+    last_nonce: Arc<AtomicU64>,
 }
 
 impl Dendrite {
@@ -94,22 +127,41 @@ impl Dendrite {
     ///
     /// A new Dendrite instance with default settings
     pub fn new(keypair: Option<sr25519::Pair>) -> Self {
-        let client = Client::builder()
-            .pool_max_idle_per_host(10)
-            .pool_idle_timeout(Duration::from_secs(90))
-            .connect_timeout(Duration::from_secs(5))
-            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
-            .build()
-            .expect("Failed to build HTTP client");
+        Self::with_config(keypair, DendriteConfig::default()).expect("Failed to build HTTP client")
+    }
 
-        Self {
+    /// Create a new Dendrite client with a custom configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `keypair` - Optional SR25519 keypair for signing requests
+    /// * `config` - Timeout and connection pool settings
+    ///
+    /// # Returns
+    ///
+    /// A new Dendrite instance, or an error if the HTTP client cannot be built
+    pub fn with_config(
+        keypair: Option<sr25519::Pair>,
+        config: DendriteConfig,
+    ) -> Result<Self, DendriteError> {
+        let client = config.build_client()?;
+
+        Ok(Self {
             client,
+            tls_clients: Arc::new(Mutex::new(HashMap::new())),
+            timeout: config.timeout,
+            config,
             keypair,
-            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
             version: DEFAULT_DENDRITE_VERSION,
             ip: None,
             port: None,
-        }
+            last_nonce: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Get the client configuration
+    pub fn config(&self) -> &DendriteConfig {
+        &self.config
     }
 
     /// Set the default timeout for requests
@@ -173,12 +225,27 @@ impl Dendrite {
         self.keypair.as_ref().map(|kp| kp.public().to_ss58())
     }
 
-    /// Build the dendrite terminal info for request headers
-    fn build_dendrite_info(&self) -> TerminalInfo {
-        let nonce = std::time::SystemTime::now()
+    /// Next request nonce: the current time in nanoseconds since the Unix
+    /// epoch, as the Python SDK sends, bumped if needed so it always increases
+    ///
+    /// Axons read the nonce as the request timestamp for replay protection.
+    fn next_nonce(&self) -> u64 {
+        let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .map(|d| d.as_nanos() as u64)
             .unwrap_or(0);
+        let previous = self
+            .last_nonce
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| {
+                Some(now.max(last + 1))
+            })
+            .unwrap_or_default();
+        now.max(previous + 1)
+    }
+
+    /// Build the dendrite terminal info for request headers
+    fn build_dendrite_info(&self) -> TerminalInfo {
+        let nonce = self.next_nonce();
 
         TerminalInfo {
             ip: self.ip.clone(),
@@ -207,6 +274,9 @@ impl Dendrite {
 
     /// Send a synapse to a single axon with a specific timeout
     ///
+    /// Failed attempts are retried according to the configured
+    /// [`RetryPolicy`], within `timeout` overall.
+    ///
     /// # Arguments
     ///
     /// * `axon` - The target Axon server
@@ -222,6 +292,269 @@ impl Dendrite {
         synapse: Synapse,
         timeout: Duration,
     ) -> Result<Synapse, DendriteError> {
+        self.call_with_policy(axon, synapse, timeout, &self.config.retry)
+            .await
+    }
+
+    /// Send a synapse to a single axon with a specific retry policy
+    ///
+    /// Every attempt is signed with a fresh nonce, and all attempts together
+    /// stay within the dendrite's timeout: a retry whose backoff would run
+    /// past it is not made. The number of attempts is reported in the
+    /// response's `dendrite.attempts`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axon` - The target Axon server
+    /// * `synapse` - The Synapse to send
+    /// * `policy` - How failed attempts are retried
+    ///
+    /// # Returns
+    ///
+    /// The response Synapse from the last attempt
+    pub async fn call_with_retry(
+        &self,
+        axon: &AxonInfo,
+        synapse: Synapse,
+        policy: &RetryPolicy,
+    ) -> Result<Synapse, DendriteError> {
+        self.call_with_policy(axon, synapse, self.timeout, policy)
+            .await
+    }
+
+    async fn call_with_policy(
+        &self,
+        axon: &AxonInfo,
+        synapse: Synapse,
+        timeout: Duration,
+        policy: &RetryPolicy,
+    ) -> Result<Synapse, DendriteError> {
+        let start_time = Instant::now();
+        let deadline = start_time + timeout;
+        let mut attempt = 1;
+
+        loop {
+            // The first attempt advertises the full timeout, as the Python SDK does
+            let remaining = if attempt == 1 {
+                timeout
+            } else {
+                deadline.saturating_duration_since(Instant::now())
+            };
+            let http_request = self.build_http_request(axon, &synapse, remaining)?;
+            let (mut response, failure) =
+                Self::execute_attempt(http_request, &synapse, Instant::now()).await?;
+
+            let delay = policy.backoff.delay(attempt);
+            let retry = failure.is_some_and(|reason| policy.should_retry(attempt, reason))
+                && Instant::now() + delay < deadline;
+            if !retry {
+                let dendrite = response.dendrite.get_or_insert_with(TerminalInfo::default);
+                dendrite.attempts = Some(attempt);
+                dendrite.process_time = Some(start_time.elapsed().as_secs_f64());
+                return Ok(response);
+            }
+
+            debug!(
+                "Attempt {} to {} failed ({:?}), retrying in {:?}",
+                attempt,
+                axon.to_endpoint(),
+                failure,
+                delay
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send a typed synapse to a single axon
+    ///
+    /// The synapse is converted to the dynamic representation for sending
+    /// and the response is converted back to `T`, so the axon may serve it
+    /// with either a typed or a dynamic handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `axon` - The target Axon server
+    /// * `synapse` - The typed synapse to send
+    ///
+    /// # Returns
+    ///
+    /// The typed response, or an error if the call failed or the response
+    /// cannot be converted to `T`
+    pub async fn call_typed<T: SynapseType>(
+        &self,
+        axon: &AxonInfo,
+        synapse: T,
+    ) -> Result<T, DendriteError> {
+        let request = synapse
+            .to_synapse()
+            .map_err(|e| RequestError::Serialization(e.to_string()))?;
+        let response = self.call(axon, request).await?;
+
+        if response.is_timeout() {
+            return Err(DendriteError::Timeout(self.timeout));
+        }
+        if response.is_failure() {
+            let status = response
+                .dendrite
+                .as_ref()
+                .and_then(|d| d.status_code)
+                .unwrap_or_default();
+            return Err(ResponseError::HttpError {
+                status: status as u16,
+            }
+            .into());
+        }
+
+        T::from_synapse(&response).map_err(|e| ResponseError::Deserialization(e.to_string()).into())
+    }
+
+    /// Send a synapse to a TLS axon, pinning its certificate
+    ///
+    /// The request is sent over HTTPS and the handshake only succeeds if the
+    /// axon presents a certificate for the public key in `certificate`; any
+    /// other certificate, including ones signed by a trusted CA, is rejected. Connections are pooled per
+    /// certificate, so repeated calls reuse the TLS session.
+    ///
+    /// # Arguments
+    ///
+    /// * `axon` - The target Axon server
+    /// * `synapse` - The Synapse to send
+    /// * `certificate` - The axon's certificate, e.g. from `get_neuron_certificate`
+    ///
+    /// # Returns
+    ///
+    /// The response Synapse with updated terminal info and response data
+    pub async fn call_tls(
+        &self,
+        axon: &AxonInfo,
+        synapse: Synapse,
+        certificate: &Certificate,
+    ) -> Result<Synapse, DendriteError> {
+        let start_time = Instant::now();
+        let client = self.tls_client(certificate)?;
+        let http_request = self.build_request_with(&client, true, axon, &synapse, self.timeout)?;
+        Self::execute(http_request, &synapse, start_time).await
+    }
+
+    /// Get or build the pooled HTTPS client pinning `certificate`
+    fn tls_client(&self, certificate: &Certificate) -> Result<Client, DendriteError> {
+        let der = certificate.as_der();
+        let mut clients = self
+            .tls_clients
+            .lock()
+            .map_err(|_| DendriteError::Tls("TLS client cache poisoned".to_string()))?;
+        if let Some(client) = clients.get(der) {
+            return Ok(client.clone());
+        }
+
+        let tls_config =
+            pinned_client_config(der).map_err(|e| DendriteError::Tls(e.to_string()))?;
+        let client = self
+            .config
+            .client_builder()
+            .use_preconfigured_tls(tls_config)
+            .build()?;
+        clients.insert(der.to_vec(), client.clone());
+        Ok(client)
+    }
+
+    /// Send a built request and convert the response into a synapse
+    ///
+    /// Timeouts and connection failures are reported as error synapses
+    /// rather than errors.
+    async fn execute(
+        http_request: reqwest::RequestBuilder,
+        synapse: &Synapse,
+        start_time: Instant,
+    ) -> Result<Synapse, DendriteError> {
+        Self::execute_attempt(http_request, synapse, start_time)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    /// Like [`Dendrite::execute`], also classifying a failed attempt for
+    /// retrying
+    async fn execute_attempt(
+        http_request: reqwest::RequestBuilder,
+        synapse: &Synapse,
+        start_time: Instant,
+    ) -> Result<(Synapse, Option<RetryReason>), DendriteError> {
+        // Execute the request
+        let result = http_request.send().await;
+        let process_time = start_time.elapsed().as_secs_f64();
+
+        #[cfg(feature = "metrics")]
+        crate::metrics::global().observe_dendrite_request(
+            start_time.elapsed(),
+            matches!(&result, Err(e) if e.is_timeout()),
+        );
+
+        match result {
+            Ok(response) => {
+                let status = response.status().as_u16();
+                let response_headers = response.headers().clone();
+                let body = response.bytes().await?.to_vec();
+
+                let dendrite_response =
+                    DendriteResponse::new(status, response_headers, body, process_time);
+                Ok((
+                    dendrite_response.into_synapse()?,
+                    RetryReason::from_status(status as i32),
+                ))
+            }
+            Err(e) => {
+                if e.is_timeout() {
+                    Ok((
+                        build_error_synapse(
+                            synapse,
+                            status_codes::TIMEOUT,
+                            "Request timeout",
+                            process_time,
+                        ),
+                        Some(RetryReason::Timeout),
+                    ))
+                } else if e.is_connect() {
+                    Ok((
+                        build_error_synapse(
+                            synapse,
+                            status_codes::SERVICE_UNAVAILABLE,
+                            &format!("Connection failed: {}", e),
+                            process_time,
+                        ),
+                        Some(RetryReason::Connect),
+                    ))
+                } else {
+                    Err(DendriteError::Http(e))
+                }
+            }
+        }
+    }
+
+    /// Build a signed HTTP request for a synapse
+    ///
+    /// Each call uses a fresh nonce and UUID, so the request must not be
+    /// reused across axons.
+    fn build_http_request(
+        &self,
+        axon: &AxonInfo,
+        synapse: &Synapse,
+        timeout: Duration,
+    ) -> Result<reqwest::RequestBuilder, DendriteError> {
+        self.build_request_with(&self.client, false, axon, synapse, timeout)
+    }
+
+    /// Build a signed request for a synapse on the given client
+    ///
+    /// If `https` is set the request is sent to the axon's HTTPS endpoint.
+    fn build_request_with(
+        &self,
+        client: &Client,
+        https: bool,
+        axon: &AxonInfo,
+        synapse: &Synapse,
+        timeout: Duration,
+    ) -> Result<reqwest::RequestBuilder, DendriteError> {
         // Validate axon is serving
         if !axon.is_serving() {
             return Err(DendriteError::InvalidAxon(
@@ -229,11 +562,9 @@ impl Dendrite {
             ));
         }
 
-        let start_time = Instant::now();
-
         // Build the request
         let dendrite_info = self.build_dendrite_info();
-        let mut request = DendriteRequest::new(axon, &synapse, &dendrite_info, timeout)?;
+        let mut request = DendriteRequest::new(axon, synapse, &dendrite_info, timeout)?;
 
         // Sign the request if we have a keypair
         // For signing, prefer the axon's hotkey if known, otherwise fall back to IP:port
@@ -243,7 +574,7 @@ impl Dendrite {
         }
 
         // Convert to HTTP headers
-        let headers = crate::dendrite::request::synapse_to_headers(&Synapse {
+        let mut headers = crate::dendrite::request::synapse_to_headers(&Synapse {
             name: synapse.name.clone(),
             timeout: Some(timeout.as_secs_f64()),
             dendrite: Some(TerminalInfo {
@@ -268,69 +599,186 @@ impl Dendrite {
                 signature: request.headers.dendrite_signature.clone(),
                 ..Default::default()
             }),
+            axon: Some(TerminalInfo {
+                ip: Some(axon.ip.to_string()),
+                port: Some(axon.port),
+                hotkey: axon.hotkey.clone(),
+                ..Default::default()
+            }),
             computed_body_hash: request.headers.computed_body_hash.clone(),
+            extra: synapse.extra.clone(),
             ..Default::default()
         });
+        let url = if https {
+            request.url.replacen("http://", "https://", 1)
+        } else {
+            request.url
+        };
+
+        // Compress after signing: the body hash covers the uncompressed body
+        let body = match self.config.request_compression {
+            Some(encoding) => {
+                let compressed = encoding
+                    .encode(&request.body)
+                    .map_err(|e| RequestError::Compression(e.to_string()))?;
+                headers.insert(
+                    reqwest::header::CONTENT_ENCODING,
+                    reqwest::header::HeaderValue::from_static(encoding.as_str()),
+                );
+                compressed
+            }
+            None => request.body,
+        };
+
         // Build the HTTP request
-        let http_request = self
-            .client
-            .post(&request.url)
+        let http_request = client
+            .post(&url)
             .headers(headers)
-            .body(request.body)
+            .body(body)
             .timeout(timeout);
 
-        // Execute the request
-        let result = http_request.send().await;
-        let process_time = start_time.elapsed().as_secs_f64();
-
-        match result {
-            Ok(response) => {
-                let status = response.status().as_u16();
-                let response_headers = response.headers().clone();
-                let body = response.bytes().await?.to_vec();
-
-                let dendrite_response =
-                    DendriteResponse::new(status, response_headers, body, process_time);
-                Ok(dendrite_response.into_synapse()?)
-            }
-            Err(e) => {
-                if e.is_timeout() {
-                    Ok(build_error_synapse(
-                        &synapse,
-                        status_codes::TIMEOUT,
-                        "Request timeout",
-                        process_time,
-                    ))
-                } else if e.is_connect() {
-                    Ok(build_error_synapse(
-                        &synapse,
-                        status_codes::SERVICE_UNAVAILABLE,
-                        &format!("Connection failed: {}", e),
-                        process_time,
-                    ))
-                } else {
-                    Err(DendriteError::Http(e))
-                }
-            }
-        }
+        Ok(http_request)
     }
 
     /// Send a synapse to multiple axons concurrently
     ///
+    /// The synapse is cloned and signed separately for each axon. At most
+    /// `concurrency` requests are in flight at once, and each one is bounded
+    /// by `timeout`. The measured latency is written to each response
+    /// synapse's `dendrite.process_time`.
+    ///
+    /// Axons that are not serving are skipped unless
+    /// [`DendriteConfig::skip_non_serving`] is off, in which case each gets a
+    /// failed `AxonNotServing` result.
+    ///
     /// # Arguments
     ///
     /// * `axons` - List of target Axon servers
     /// * `synapse` - The Synapse to send (cloned for each request)
+    /// * `timeout` - Per-call timeout
+    /// * `concurrency` - Maximum number of concurrent requests
     ///
     /// # Returns
     ///
-    /// A vector of results, one for each axon in the same order
+    /// A vector of results, one for each axon called, in the order of `axons`
     pub async fn call_many(
         &self,
         axons: &[AxonInfo],
         synapse: Synapse,
-    ) -> Vec<Result<Synapse, DendriteError>> {
-        self.forward(axons, synapse, None).await
+        timeout: Duration,
+        concurrency: usize,
+    ) -> Vec<DendriteResult> {
+        let semaphore = Semaphore::new(concurrency.max(1));
+
+        let skip_non_serving = self.config.skip_non_serving;
+        let futures = axons
+            .iter()
+            .filter(|axon| !skip_non_serving || axon.is_serving())
+            .map(|axon| {
+                let synapse = synapse.clone();
+                let semaphore = &semaphore;
+                async move {
+                    // The semaphore is never closed, so acquiring cannot fail
+                    let _permit = semaphore.acquire().await.ok();
+                    self.call_one(axon, synapse, timeout).await
+                }
+            });
+
+        futures::future::join_all(futures).await
+    }
+
+    /// Send a synapse to a single axon as part of `call_many`
+    async fn call_one(
+        &self,
+        axon: &AxonInfo,
+        synapse: Synapse,
+        timeout: Duration,
+    ) -> DendriteResult {
+        let start_time = Instant::now();
+        let endpoint = axon.to_endpoint();
+
+        let http_request = match self.build_http_request(axon, &synapse, timeout) {
+            Ok(request) => request,
+            Err(DendriteError::InvalidAxon(message)) => {
+                let error = AxonNotServing::with_ip_port(message, axon.ip_str(), axon.port);
+                return DendriteResult::failed(
+                    axon,
+                    status_codes::SERVICE_UNAVAILABLE,
+                    start_time.elapsed(),
+                    error.into(),
+                );
+            }
+            Err(e) => {
+                return DendriteResult::failed(
+                    axon,
+                    status_codes::INTERNAL_ERROR,
+                    start_time.elapsed(),
+                    errors::DendriteError::new(e.to_string()).into(),
+                );
+            }
+        };
+
+        let outcome = tokio::time::timeout(timeout, async {
+            let response = http_request.send().await?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+            Ok::<_, reqwest::Error>((status, headers, body))
+        })
+        .await;
+        let latency = start_time.elapsed();
+
+        #[cfg(feature = "metrics")]
+        crate::metrics::global().observe_dendrite_request(
+            latency,
+            match &outcome {
+                Err(_) => true,
+                Ok(Err(e)) => e.is_timeout(),
+                Ok(Ok(_)) => false,
+            },
+        );
+
+        let timed_out = || {
+            let error = SynapseTimeout::with_details(
+                format!("Request timed out after {:.3}s", timeout.as_secs_f64()),
+                timeout.as_secs_f64(),
+                endpoint.clone(),
+            );
+            DendriteResult::failed(axon, status_codes::TIMEOUT, latency, error.into())
+        };
+
+        match outcome {
+            Err(_) => timed_out(),
+            Ok(Err(e)) if e.is_timeout() => timed_out(),
+            Ok(Err(e)) if e.is_connect() => {
+                let error =
+                    AxonUnreachable::with_endpoint(format!("Connection failed: {}", e), &endpoint);
+                DendriteResult::failed(
+                    axon,
+                    status_codes::SERVICE_UNAVAILABLE,
+                    latency,
+                    error.into(),
+                )
+            }
+            Ok(Err(e)) => DendriteResult::failed(
+                axon,
+                status_codes::INTERNAL_ERROR,
+                latency,
+                errors::DendriteError::new(e.to_string()).into(),
+            ),
+            Ok(Ok((status, headers, body))) => {
+                let response = DendriteResponse::new(status, headers, body, latency.as_secs_f64());
+                match response.into_synapse() {
+                    Ok(synapse) => DendriteResult::succeeded(axon, latency, synapse),
+                    Err(e) => DendriteResult::failed(
+                        axon,
+                        status as i32,
+                        latency,
+                        InvalidResponse::new(e.to_string()).into(),
+                    ),
+                }
+            }
+        }
     }
 
     /// Forward a synapse to multiple axons (like Python dendrite.forward)
@@ -390,6 +838,109 @@ impl Dendrite {
             .await
     }
 
+    /// Send a synapse to a streaming handler, passing each chunk to
+    /// `on_chunk` as it arrives
+    ///
+    /// The response is read on a separate task, at most
+    /// [`DendriteConfig::stream_buffer`] chunks ahead of `on_chunk`; while the
+    /// buffer is full nothing is read and the socket pushes back on the axon.
+    /// Returning [`ControlFlow::Break`] drops the response, which closes the
+    /// connection and cancels the axon's handler. Unlike
+    /// [`call_stream`](Self::call_stream) the request is signed and the
+    /// chunks are raw bytes.
+    ///
+    /// A complete stream ends with the axon's status trailers. A stream that
+    /// ends without them, or whose body does not match the hash they carry,
+    /// fails with [`InvalidResponse`]. The whole call is bounded by the
+    /// dendrite's timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `axon` - The target Axon server
+    /// * `synapse` - The Synapse to send
+    /// * `on_chunk` - Called with each chunk; `Break` stops the stream
+    ///
+    /// # Returns
+    ///
+    /// Byte and chunk counts, the elapsed time and the axon's final status
+    pub async fn call_stream_chunks<F>(
+        &self,
+        axon: &AxonInfo,
+        synapse: Synapse,
+        mut on_chunk: F,
+    ) -> Result<StreamSummary, DendriteError>
+    where
+        F: FnMut(Bytes) -> ControlFlow<()>,
+    {
+        let start_time = Instant::now();
+        let http_request = self
+            .build_http_request(axon, &synapse, self.timeout)?
+            // Accept the status trailers sent after the final chunk
+            .header(http::header::TE, "trailers");
+        let response = http_request.send().await.map_err(|e| {
+            if e.is_timeout() {
+                DendriteError::Timeout(self.timeout)
+            } else {
+                e.into()
+            }
+        })?;
+        if !response.status().is_success() {
+            return Err(DendriteError::Response(ResponseError::HttpError {
+                status: response.status().as_u16(),
+            }));
+        }
+
+        let (tx, mut rx) = tokio::sync::mpsc::channel(self.config.stream_buffer.max(1));
+        let reader = tokio::spawn(read_stream_frames(reqwest::Body::from(response), tx));
+
+        let mut summary = StreamSummary::default();
+        let mut hasher = Sha256::new();
+        let trailers = loop {
+            match rx.recv().await {
+                Some(StreamEvent::Chunk(chunk)) => {
+                    summary.bytes += chunk.len() as u64;
+                    summary.chunks += 1;
+                    hasher.update(&chunk);
+                    if on_chunk(chunk).is_break() {
+                        reader.abort();
+                        summary.cancelled = true;
+                        summary.elapsed = start_time.elapsed();
+                        return Ok(summary);
+                    }
+                }
+                Some(StreamEvent::Trailers(trailers)) => break trailers,
+                Some(StreamEvent::Failed(e)) if e.is_timeout() => {
+                    return Err(DendriteError::Timeout(self.timeout))
+                }
+                Some(StreamEvent::Failed(e)) => {
+                    return Err(StreamError::Network(e.to_string()).into())
+                }
+                None => {
+                    return Err(InvalidResponse::new(format!(
+                        "Stream from {} ended after {} bytes without status trailers",
+                        axon.to_endpoint(),
+                        summary.bytes
+                    ))
+                    .into())
+                }
+            }
+        };
+        summary.elapsed = start_time.elapsed();
+
+        let body_hash = hex::encode(hasher.finalize());
+        if let Some(expected) = trailers.get(header_names::BODY_HASH) {
+            if expected.as_bytes() != body_hash.as_bytes() {
+                return Err(InvalidResponse::with_raw_response(
+                    "Streamed body does not match the trailer body hash",
+                    String::from_utf8_lossy(expected.as_bytes()),
+                )
+                .into());
+            }
+        }
+        summary.axon = Some(terminal_from_trailers(&trailers));
+        Ok(summary)
+    }
+
     /// Send a streaming synapse to a single axon with a specific timeout
     ///
     /// # Arguments
@@ -462,10 +1013,13 @@ impl Dendrite {
         }
 
         // Add timeout header
-        if let Ok(hv) = http::HeaderValue::from_str(&timeout.as_secs_f64().to_string()) {
-            headers.insert("bt_header_timeout", hv);
+        if let Ok(hv) = http::HeaderValue::from_str(&python_float_repr(timeout.as_secs_f64())) {
+            headers.insert(header_names::TIMEOUT, hv);
         }
 
+        // Accept the status trailers sent after the final chunk
+        headers.insert(http::header::TE, http::HeaderValue::from_static("trailers"));
+
         // Build the HTTP request
         let http_request = self.client.post(&url).headers(headers).timeout(timeout);
 
@@ -490,11 +1044,14 @@ impl Clone for Dendrite {
     fn clone(&self) -> Self {
         Self {
             client: self.client.clone(),
+            tls_clients: self.tls_clients.clone(),
+            config: self.config.clone(),
             keypair: self.keypair.clone(),
             timeout: self.timeout,
             version: self.version,
             ip: self.ip.clone(),
             port: self.port,
+            last_nonce: self.last_nonce.clone(),
         }
     }
 }
@@ -508,6 +1065,7 @@ impl Default for Dendrite {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::BittensorError;
     use std::net::IpAddr;
 
     fn create_test_axon() -> AxonInfo {
@@ -568,6 +1126,24 @@ mod tests {
         assert_eq!(dendrite.version, 200);
     }
 
+    #[test]
+    fn test_nonces_increase_across_clones() {
+        let dendrite = Dendrite::new(None);
+        let clone = dendrite.clone();
+        let nonces: Vec<u64> = (0..100)
+            .map(|i| {
+                if i % 2 == 0 {
+                    dendrite.next_nonce()
+                } else {
+                    clone.next_nonce()
+                }
+            })
+            .collect();
+        assert!(nonces.windows(2).all(|w| w[0] < w[1]));
+        // Nanosecond timestamps, as the Python SDK sends
+        assert!(nonces[0] > 1_600_000_000 * 1_000_000_000);
+    }
+
     #[test]
     fn test_dendrite_with_ip_and_port() {
         let dendrite = Dendrite::new(None).with_ip("192.168.1.1").with_port(8080);
@@ -637,11 +1213,48 @@ mod tests {
         let axons: Vec<AxonInfo> = vec![];
         let synapse = Synapse::new().with_name("Test");
 
-        let results = dendrite.call_many(&axons, synapse).await;
+        let results = dendrite
+            .call_many(&axons, synapse, Duration::from_secs(1), 8)
+            .await;
 
         assert!(results.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_call_many_skips_non_serving() {
+        let dendrite = Dendrite::new(None);
+        let axons = vec![create_non_serving_axon(), create_test_axon()];
+        let synapse = Synapse::new().with_name("Test");
+
+        let results = dendrite
+            .call_many(&axons, synapse, Duration::from_millis(200), 1)
+            .await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].endpoint, axons[1].to_endpoint());
+    }
+
+    #[tokio::test]
+    async fn test_call_many_preserves_order_and_reports_errors() {
+        let config = DendriteConfig::default().with_skip_non_serving(false);
+        let dendrite = Dendrite::with_config(None, config).unwrap();
+        let axons = vec![create_non_serving_axon(), create_test_axon()];
+        let synapse = Synapse::new().with_name("Test");
+
+        let results = dendrite
+            .call_many(&axons, synapse, Duration::from_millis(200), 1)
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            results[0].response,
+            Err(BittensorError::AxonNotServing(_))
+        ));
+        assert_eq!(results[1].endpoint, axons[1].to_endpoint());
+        assert!(!results[1].is_success());
+        assert!(results[1].latency <= Duration::from_secs(1));
+    }
+
     #[tokio::test]
     async fn test_forward_with_timeout() {
         let dendrite = Dendrite::new(None);
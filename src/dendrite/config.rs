@@ -0,0 +1,199 @@
+//! Dendrite client configuration
+//!
+//! Controls request timeouts and how connections to axons are pooled. A
+//! Dendrite builds a single HTTP client from its configuration and reuses it
+//! for every call, so repeated requests to the same axon share an idle
+//! keep-alive connection instead of reconnecting.
+
+use crate::axon::compression::ContentEncoding;
+use crate::dendrite::retry::RetryPolicy;
+use reqwest::Client;
+use std::time::Duration;
+
+/// Default timeout for establishing a connection
+pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 5;
+
+/// Default maximum number of idle connections kept per axon
+pub const DEFAULT_POOL_SIZE: usize = 10;
+
+/// Default time an idle pooled connection is kept alive
+pub const DEFAULT_KEEPALIVE_SECS: u64 = 90;
+
+/// Default number of chunks buffered between a stream and its consumer
+pub const DEFAULT_STREAM_BUFFER: usize = 16;
+
+/// Dendrite client configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct DendriteConfig {
+    /// Default timeout for requests
+    pub timeout: Duration,
+    /// Timeout for establishing a connection
+    pub connect_timeout: Duration,
+    /// Maximum number of idle connections kept per axon (0 disables reuse)
+    pub pool_size: usize,
+    /// How long idle connections are kept alive (None keeps them indefinitely)
+    pub keepalive: Option<Duration>,
+    /// Whether to verify axon TLS certificates against the system roots
+    ///
+    /// This only affects `https` axons called without a pinned certificate;
+    /// `Dendrite::call_tls` always verifies against the given certificate.
+    pub verify_certs: bool,
+    /// Encoding used to compress request bodies (None sends them as-is)
+    ///
+    /// Signatures and body hashes always cover the uncompressed body.
+    pub request_compression: Option<ContentEncoding>,
+    /// How failed calls are retried (a single attempt by default)
+    pub retry: RetryPolicy,
+    /// Whether `Dendrite::call_many` skips axons that are not serving
+    ///
+    /// When off, each non-serving axon gets a failed result instead.
+    pub skip_non_serving: bool,
+    /// Chunks `Dendrite::call_stream_chunks` reads ahead of its callback
+    ///
+    /// Once this many are waiting, reading stops and the socket pushes back
+    /// on the axon.
+    pub stream_buffer: usize,
+}
+
+impl Default for DendriteConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(crate::dendrite::client::DEFAULT_TIMEOUT_SECS),
+            connect_timeout: Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
+            pool_size: DEFAULT_POOL_SIZE,
+            keepalive: Some(Duration::from_secs(DEFAULT_KEEPALIVE_SECS)),
+            verify_certs: true,
+            request_compression: None,
+            retry: RetryPolicy::none(),
+            skip_non_serving: true,
+            stream_buffer: DEFAULT_STREAM_BUFFER,
+        }
+    }
+}
+
+impl DendriteConfig {
+    /// Create a new DendriteConfig with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the default request timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the connection timeout
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Set the maximum number of idle connections kept per axon
+    pub fn with_pool_size(mut self, pool_size: usize) -> Self {
+        self.pool_size = pool_size;
+        self
+    }
+
+    /// Set how long idle connections are kept alive
+    pub fn with_keepalive(mut self, keepalive: Option<Duration>) -> Self {
+        self.keepalive = keepalive;
+        self
+    }
+
+    /// Enable or disable certificate verification for `https` axons
+    pub fn with_verify_certs(mut self, verify: bool) -> Self {
+        self.verify_certs = verify;
+        self
+    }
+
+    /// Compress request bodies with `encoding`
+    ///
+    /// Only use against axons that decode compressed requests.
+    pub fn with_request_compression(mut self, encoding: Option<ContentEncoding>) -> Self {
+        self.request_compression = encoding;
+        self
+    }
+
+    /// Set how failed calls are retried
+    ///
+    /// Applies to `Dendrite::call` and the calls built on it; use
+    /// `Dendrite::call_with_retry` to override it for a single call.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Set whether `Dendrite::call_many` skips axons that are not serving
+    pub fn with_skip_non_serving(mut self, skip: bool) -> Self {
+        self.skip_non_serving = skip;
+        self
+    }
+
+    /// Set how many chunks a stream reads ahead of its consumer (at least 1)
+    pub fn with_stream_buffer(mut self, chunks: usize) -> Self {
+        self.stream_buffer = chunks.max(1);
+        self
+    }
+
+    /// Create a client builder with the pooling settings applied
+    ///
+    /// Request timeouts are set per request, so they are not configured here.
+    /// Responses compressed with gzip or zstd are decoded transparently.
+    pub(crate) fn client_builder(&self) -> reqwest::ClientBuilder {
+        Client::builder()
+            .gzip(true)
+            .zstd(true)
+            .pool_max_idle_per_host(self.pool_size)
+            .pool_idle_timeout(self.keepalive)
+            .tcp_keepalive(self.keepalive)
+            .connect_timeout(self.connect_timeout)
+    }
+
+    /// Build the shared HTTP client for this configuration
+    pub fn build_client(&self) -> Result<Client, reqwest::Error> {
+        self.client_builder()
+            .danger_accept_invalid_certs(!self.verify_certs)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dendrite_config_builder() {
+        let config = DendriteConfig::new()
+            .with_timeout(Duration::from_secs(30))
+            .with_pool_size(2)
+            .with_keepalive(None)
+            .with_verify_certs(false)
+            .with_request_compression(Some(ContentEncoding::Zstd))
+            .with_skip_non_serving(false)
+            .with_stream_buffer(0);
+
+        assert_eq!(config.timeout, Duration::from_secs(30));
+        assert_eq!(config.pool_size, 2);
+        assert_eq!(config.keepalive, None);
+        assert!(!config.verify_certs);
+        assert_eq!(config.request_compression, Some(ContentEncoding::Zstd));
+        assert!(!config.skip_non_serving);
+        assert_eq!(config.stream_buffer, 1);
+        assert!(config.build_client().is_ok());
+    }
+
+    #[test]
+    fn test_dendrite_config_defaults() {
+        let config = DendriteConfig::default();
+        assert_eq!(config.pool_size, DEFAULT_POOL_SIZE);
+        assert_eq!(
+            config.keepalive,
+            Some(Duration::from_secs(DEFAULT_KEEPALIVE_SECS))
+        );
+        assert!(config.verify_certs);
+        assert_eq!(config.retry, RetryPolicy::none());
+        assert!(config.skip_non_serving);
+        assert_eq!(config.stream_buffer, DEFAULT_STREAM_BUFFER);
+    }
+}
@@ -18,11 +18,16 @@
 //! ```
 
 pub mod client;
+pub mod config;
 pub mod request;
 pub mod response;
+pub mod retry;
 pub mod streaming;
+pub mod tls;
 
 pub use client::Dendrite;
+pub use config::DendriteConfig;
 pub use request::{headers_to_synapse, synapse_to_headers, DendriteRequest};
-pub use response::DendriteResponse;
-pub use streaming::{StreamingResponse, StreamingSynapse};
+pub use response::{DendriteResponse, DendriteResult};
+pub use retry::{Backoff, RetryOn, RetryPolicy, RetryReason};
+pub use streaming::{StreamSummary, StreamingResponse, StreamingSynapse};
@@ -3,10 +3,13 @@
 //! This module handles the construction of HTTP requests to Axon servers,
 //! including header generation, body hashing, and cryptographic signing.
 
+use crate::types::synapse::{listed_body_hash, python_float_repr};
 use crate::types::{AxonInfo, Synapse, SynapseHeaders, TerminalInfo};
+use base64::Engine;
 use http::header::HeaderMap;
 use sha2::{Digest, Sha256};
 use sp_core::{sr25519, Pair};
+use std::collections::HashMap;
 use std::time::Duration;
 use thiserror::Error;
 
@@ -21,6 +24,8 @@ pub enum RequestError {
     Signing(String),
     #[error("Invalid header value: {0}")]
     InvalidHeader(String),
+    #[error("Compression error: {0}")]
+    Compression(String),
 }
 
 /// A prepared Dendrite request ready for transmission
@@ -75,7 +80,7 @@ impl DendriteRequest {
         headers.dendrite_hotkey = dendrite_info.hotkey.clone();
 
         // Set timeout in headers
-        headers.timeout = Some(timeout.as_secs_f64().to_string());
+        headers.timeout = Some(python_float_repr(timeout.as_secs_f64()));
 
         Ok(Self {
             url,
@@ -100,7 +105,12 @@ impl DendriteRequest {
     /// Sign the request with the given keypair
     ///
     /// The signature format matches the Python SDK:
-    /// `sign(message = "{nonce}.{dendrite_hotkey}.{axon_hotkey}.{body_hash}")`
+    /// `sign(message = "{nonce}.{dendrite_hotkey}.{axon_hotkey}.{uuid}.{body_hash}")`,
+    /// sent as `0x`-prefixed hex.
+    ///
+    /// The body hash is the synapse's `computed_body_hash` if it has one, as
+    /// typed synapses do, or else Python's body hash over the
+    /// `required_hash_fields` the body lists.
     ///
     /// # Arguments
     ///
@@ -111,8 +121,11 @@ impl DendriteRequest {
     ///
     /// Ok(()) if signing succeeds, otherwise an error
     pub fn sign(&mut self, keypair: &sr25519::Pair, axon_hotkey: &str) -> Result<(), RequestError> {
-        // Compute body hash
-        let body_hash = self.compute_body_hash();
+        // Use the synapse's body hash, or hash the fields the body lists
+        let body_hash = match self.headers.computed_body_hash.clone() {
+            Some(hash) if !hash.is_empty() => hash,
+            _ => listed_body_hash(&serde_json::from_slice(&self.body).unwrap_or_default()),
+        };
         self.headers.computed_body_hash = Some(body_hash.clone());
 
         // Get nonce from headers
@@ -121,6 +134,9 @@ impl DendriteRequest {
             .dendrite_nonce
             .as_ref()
             .ok_or_else(|| RequestError::Signing("Missing nonce".to_string()))?;
+        let nonce: u64 = nonce
+            .parse()
+            .map_err(|_| RequestError::Signing(format!("Invalid nonce format: {}", nonce)))?;
 
         // Get dendrite hotkey from headers
         let dendrite_hotkey = self
@@ -129,15 +145,12 @@ impl DendriteRequest {
             .as_ref()
             .ok_or_else(|| RequestError::Signing("Missing dendrite hotkey".to_string()))?;
 
-        // Create message to sign: "{nonce}.{dendrite_hotkey}.{axon_hotkey}.{body_hash}"
-        let message = format!(
-            "{}.{}.{}.{}",
-            nonce, dendrite_hotkey, axon_hotkey, body_hash
-        );
+        // A missing uuid is signed as empty, as the axon reads it
+        let uuid = self.headers.dendrite_uuid.as_deref().unwrap_or_default();
 
-        // Sign the message
-        let signature = keypair.sign(message.as_bytes());
-        self.headers.dendrite_signature = Some(hex::encode(signature.0));
+        let message =
+            create_signature_message(nonce, dendrite_hotkey, axon_hotkey, uuid, &body_hash);
+        self.headers.dendrite_signature = Some(sign_message(keypair, message.as_bytes()));
 
         Ok(())
     }
@@ -145,6 +158,9 @@ impl DendriteRequest {
 
 /// Header name constants matching Python SDK
 pub mod header_names {
+    pub const DENDRITE_STATUS_CODE: &str = "bt_header_dendrite_status_code";
+    pub const DENDRITE_STATUS_MESSAGE: &str = "bt_header_dendrite_status_message";
+    pub const DENDRITE_PROCESS_TIME: &str = "bt_header_dendrite_process_time";
     pub const DENDRITE_IP: &str = "bt_header_dendrite_ip";
     pub const DENDRITE_PORT: &str = "bt_header_dendrite_port";
     pub const DENDRITE_VERSION: &str = "bt_header_dendrite_version";
@@ -164,15 +180,92 @@ pub mod header_names {
     pub const AXON_PROCESS_TIME: &str = "bt_header_axon_process_time";
     pub const INPUT_OBJ: &str = "bt_header_input_obj";
     pub const OUTPUT_OBJ: &str = "bt_header_output_obj";
-    pub const TIMEOUT: &str = "bt_header_timeout";
+    pub const TIMEOUT: &str = "timeout";
+    /// Timeout header sent by earlier versions of this crate, still accepted
+    pub const LEGACY_TIMEOUT: &str = "bt_header_timeout";
     pub const BODY_HASH: &str = "computed_body_hash";
     pub const NAME: &str = "name";
     pub const TOTAL_SIZE: &str = "total_size";
     pub const HEADER_SIZE: &str = "header_size";
+
+    /// Header carrying the placeholder for input field `field`
+    pub fn input_obj(field: &str) -> String {
+        format!("{}_{}", INPUT_OBJ, field)
+    }
+}
+
+/// Base `Synapse` fields the Python SDK includes in request and response
+/// bodies (`synapse.model_dump()`). They travel in headers, so they are not
+/// extra fields.
+pub const PYTHON_BASE_FIELDS: &[&str] = &[
+    "name",
+    "timeout",
+    "total_size",
+    "header_size",
+    "dendrite",
+    "axon",
+    "computed_body_hash",
+    "required_hash_fields",
+];
+
+/// Parse a request or response body into extra synapse fields
+///
+/// Bodies from the Python SDK also carry the base synapse fields; those are
+/// dropped in favour of the headers.
+///
+/// # Arguments
+///
+/// * `body` - The JSON body bytes (empty for no fields)
+///
+/// # Returns
+///
+/// The extra fields, or an error if the body is not a JSON object
+pub fn body_to_extra(body: &[u8]) -> Result<HashMap<String, serde_json::Value>, serde_json::Error> {
+    if body.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let mut extra: HashMap<String, serde_json::Value> = serde_json::from_slice(body)?;
+    extra.retain(|field, _| !PYTHON_BASE_FIELDS.contains(&field.as_str()));
+    Ok(extra)
+}
+
+/// Read the synapse timeout from request headers
+///
+/// Accepts the Python SDK's `timeout` header and the legacy
+/// `bt_header_timeout` header.
+pub fn timeout_from_headers(headers: &HeaderMap) -> Option<f64> {
+    [header_names::TIMEOUT, header_names::LEGACY_TIMEOUT]
+        .into_iter()
+        .find_map(|name| headers.get(name)?.to_str().ok()?.parse().ok())
+}
+
+/// Base64 placeholder for an input field, as the Python SDK sends it
+///
+/// Python's `Synapse.to_headers` sends `json.dumps(type(value)())`, an empty
+/// value of the field's type, so the receiving axon can validate the synapse
+/// from headers alone. The real value is only ever in the body.
+fn input_obj_placeholder(value: &serde_json::Value) -> String {
+    let placeholder = match value {
+        serde_json::Value::Bool(_) => "false",
+        serde_json::Value::Number(n) if n.is_f64() => "0.0",
+        serde_json::Value::Number(_) => "0",
+        serde_json::Value::String(_) => "\"\"",
+        serde_json::Value::Array(_) => "[]",
+        serde_json::Value::Object(_) => "{}",
+        serde_json::Value::Null => "null",
+    };
+    base64::engine::general_purpose::STANDARD.encode(placeholder)
 }
 
 /// Convert a Synapse to HTTP headers for transmission
 ///
+/// Header names and values match the Python SDK's `Synapse.to_headers`:
+/// numbers are rendered with Python's `str()` (so a 12 second timeout is
+/// `12.0`), unset fields are omitted, and every non-null extra field gets a
+/// `bt_header_input_obj_<field>` header holding a base64 JSON placeholder.
+/// Python only sends placeholders for required fields; they never carry
+/// data and are not signed, so sending one for every field is harmless.
+///
 /// # Arguments
 ///
 /// * `synapse` - The Synapse to convert
@@ -203,6 +296,18 @@ pub fn synapse_to_headers(synapse: &Synapse) -> HeaderMap {
     add_header!(header_names::BODY_HASH, synapse_headers.computed_body_hash);
 
     // Dendrite terminal info
+    add_header!(
+        header_names::DENDRITE_STATUS_CODE,
+        synapse_headers.dendrite_status_code
+    );
+    add_header!(
+        header_names::DENDRITE_STATUS_MESSAGE,
+        synapse_headers.dendrite_status_message
+    );
+    add_header!(
+        header_names::DENDRITE_PROCESS_TIME,
+        synapse_headers.dendrite_process_time
+    );
     add_header!(header_names::DENDRITE_IP, synapse_headers.dendrite_ip);
     add_header!(header_names::DENDRITE_PORT, synapse_headers.dendrite_port);
     add_header!(
@@ -221,6 +326,18 @@ pub fn synapse_to_headers(synapse: &Synapse) -> HeaderMap {
     );
 
     // Axon terminal info
+    add_header!(
+        header_names::AXON_STATUS_CODE,
+        synapse_headers.axon_status_code
+    );
+    add_header!(
+        header_names::AXON_STATUS_MESSAGE,
+        synapse_headers.axon_status_message
+    );
+    add_header!(
+        header_names::AXON_PROCESS_TIME,
+        synapse_headers.axon_process_time
+    );
     add_header!(header_names::AXON_IP, synapse_headers.axon_ip);
     add_header!(header_names::AXON_PORT, synapse_headers.axon_port);
     add_header!(header_names::AXON_VERSION, synapse_headers.axon_version);
@@ -229,11 +346,29 @@ pub fn synapse_to_headers(synapse: &Synapse) -> HeaderMap {
     add_header!(header_names::AXON_HOTKEY, synapse_headers.axon_hotkey);
     add_header!(header_names::AXON_SIGNATURE, synapse_headers.axon_signature);
 
+    // Input field placeholders
+    for (field, value) in &synapse.extra {
+        if value.is_null() {
+            continue;
+        }
+        let name = http::header::HeaderName::from_bytes(header_names::input_obj(field).as_bytes());
+        let value = http::header::HeaderValue::from_str(&input_obj_placeholder(value));
+        if let (Ok(name), Ok(value)) = (name, value) {
+            headers.insert(name, value);
+        }
+    }
+
     headers
 }
 
 /// Parse HTTP headers into a Synapse
 ///
+/// The inverse of [`synapse_to_headers`], accepting headers from the Python
+/// SDK: the timeout is read from `timeout` (or the legacy
+/// `bt_header_timeout`), the body hash is lowercased, and base fields in a
+/// Python body are ignored. Input placeholders are ignored too; field
+/// values come from the body only.
+///
 /// # Arguments
 ///
 /// * `headers` - The HTTP response headers
@@ -270,9 +405,9 @@ pub fn headers_to_synapse(headers: &HeaderMap, body: &[u8]) -> Result<Synapse, R
 
     // Build dendrite terminal info from headers
     let dendrite = TerminalInfo {
-        status_code: get_header_i32(headers, "bt_header_dendrite_status_code"),
-        status_message: get_header(headers, "bt_header_dendrite_status_message"),
-        process_time: get_header_f64(headers, "bt_header_dendrite_process_time"),
+        status_code: get_header_i32(headers, header_names::DENDRITE_STATUS_CODE),
+        status_message: get_header(headers, header_names::DENDRITE_STATUS_MESSAGE),
+        process_time: get_header_f64(headers, header_names::DENDRITE_PROCESS_TIME),
         ip: get_header(headers, header_names::DENDRITE_IP),
         port: get_header_u16(headers, header_names::DENDRITE_PORT),
         version: get_header_u64(headers, header_names::DENDRITE_VERSION),
@@ -280,6 +415,7 @@ pub fn headers_to_synapse(headers: &HeaderMap, body: &[u8]) -> Result<Synapse, R
         uuid: get_header(headers, header_names::DENDRITE_UUID),
         hotkey: get_header(headers, header_names::DENDRITE_HOTKEY),
         signature: get_header(headers, header_names::DENDRITE_SIGNATURE),
+        ..Default::default()
     };
 
     // Build axon terminal info from headers
@@ -294,23 +430,21 @@ pub fn headers_to_synapse(headers: &HeaderMap, body: &[u8]) -> Result<Synapse, R
         uuid: get_header(headers, header_names::AXON_UUID),
         hotkey: get_header(headers, header_names::AXON_HOTKEY),
         signature: get_header(headers, header_names::AXON_SIGNATURE),
+        ..Default::default()
     };
 
     // Parse body as JSON extra fields
-    let extra = if body.is_empty() {
-        std::collections::HashMap::new()
-    } else {
-        serde_json::from_slice(body).map_err(|e| RequestError::Serialization(e.to_string()))?
-    };
+    let extra = body_to_extra(body).map_err(|e| RequestError::Serialization(e.to_string()))?;
 
     Ok(Synapse {
         name: get_header(headers, header_names::NAME),
-        timeout: get_header_f64(headers, header_names::TIMEOUT),
+        timeout: timeout_from_headers(headers),
         total_size: get_header_u64(headers, header_names::TOTAL_SIZE),
         header_size: get_header_u64(headers, header_names::HEADER_SIZE),
         dendrite: Some(dendrite),
         axon: Some(axon),
-        computed_body_hash: get_header(headers, header_names::BODY_HASH),
+        computed_body_hash: get_header(headers, header_names::BODY_HASH)
+            .map(|hash| hash.to_ascii_lowercase()),
         extra,
     })
 }
@@ -318,14 +452,15 @@ pub fn headers_to_synapse(headers: &HeaderMap, body: &[u8]) -> Result<Synapse, R
 /// Create a signature message for request authentication
 ///
 /// The signature format matches the Python SDK:
-/// `"{nonce}.{dendrite_hotkey}.{axon_hotkey}.{body_hash}"`
+/// `"{nonce}.{dendrite_hotkey}.{axon_hotkey}.{uuid}.{body_hash}"`
 ///
 /// # Arguments
 ///
 /// * `nonce` - Request nonce
 /// * `dendrite_hotkey` - The sender's hotkey SS58 address
 /// * `axon_hotkey` - The target axon's hotkey SS58 address
-/// * `body_hash` - SHA-256 hash of the request body
+/// * `uuid` - The sender's dendrite UUID
+/// * `body_hash` - The request's `computed_body_hash`
 ///
 /// # Returns
 ///
@@ -334,15 +469,17 @@ pub fn create_signature_message(
     nonce: u64,
     dendrite_hotkey: &str,
     axon_hotkey: &str,
+    uuid: &str,
     body_hash: &str,
 ) -> String {
     format!(
-        "{}.{}.{}.{}",
-        nonce, dendrite_hotkey, axon_hotkey, body_hash
+        "{}.{}.{}.{}.{}",
+        nonce, dendrite_hotkey, axon_hotkey, uuid, body_hash
     )
 }
 
-/// Sign a message with the given keypair and return hex-encoded signature
+/// Sign a message with the given keypair and return the signature as the
+/// Python SDK sends it, `0x`-prefixed hex
 ///
 /// # Arguments
 ///
@@ -351,10 +488,10 @@ pub fn create_signature_message(
 ///
 /// # Returns
 ///
-/// Hex-encoded signature string
+/// `0x`-prefixed hex signature string
 pub fn sign_message(keypair: &sr25519::Pair, message: &[u8]) -> String {
     let signature = keypair.sign(message);
-    hex::encode(signature.0)
+    format!("0x{}", hex::encode(signature.0))
 }
 
 #[cfg(test)]
@@ -425,18 +562,52 @@ mod tests {
         assert_eq!(hash, request.compute_body_hash());
     }
 
+    #[test]
+    fn test_sign_uses_python_body_hash() {
+        let axon = create_test_axon();
+        let dendrite_info = create_test_dendrite_info();
+        let keypair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let axon_hotkey = axon.hotkey.clone().unwrap();
+
+        // No hash fields listed: Python's sha3_256(b"")
+        let mut request = DendriteRequest::new(
+            &axon,
+            &create_test_synapse(),
+            &dendrite_info,
+            Duration::from_secs(12),
+        )
+        .unwrap();
+        request.sign(&keypair, &axon_hotkey).unwrap();
+        assert_eq!(
+            request.headers.computed_body_hash.as_deref(),
+            Some("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a")
+        );
+
+        // A synapse's own hash is signed as-is
+        let mut synapse = create_test_synapse();
+        synapse.computed_body_hash = Some("abc123".to_string());
+        let mut request =
+            DendriteRequest::new(&axon, &synapse, &dendrite_info, Duration::from_secs(12)).unwrap();
+        request.sign(&keypair, &axon_hotkey).unwrap();
+        assert_eq!(
+            request.headers.computed_body_hash.as_deref(),
+            Some("abc123")
+        );
+    }
+
     #[test]
     fn test_create_signature_message() {
         let message = create_signature_message(
             12345,
             "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
             "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty",
+            "test-uuid-1234",
             "abc123def456",
         );
 
         assert_eq!(
             message,
-            "12345.5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY.5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty.abc123def456"
+            "12345.5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY.5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty.test-uuid-1234.abc123def456"
         );
     }
 
@@ -481,7 +652,8 @@ mod tests {
         let message = b"test message";
         let signature = sign_message(&keypair, message);
 
-        // Signature should be 128 hex characters (64 bytes)
-        assert_eq!(signature.len(), 128);
+        // 0x and 128 hex characters (64 bytes)
+        assert!(signature.starts_with("0x"));
+        assert_eq!(signature.len(), 130);
     }
 }
@@ -4,8 +4,10 @@
 //! from Axon servers, including status interpretation and synapse reconstruction.
 
 use crate::dendrite::request::{headers_to_synapse, RequestError};
-use crate::types::{Synapse, TerminalInfo};
+use crate::errors::BittensorError;
+use crate::types::{AxonInfo, Synapse, TerminalInfo};
 use http::header::HeaderMap;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors that can occur during response processing
@@ -57,13 +59,20 @@ impl DendriteResponse {
     /// Convert the response into a Synapse
     ///
     /// Parses headers and body to reconstruct the full synapse with
-    /// terminal information and response data.
+    /// terminal information and response data. Error responses whose body is
+    /// not JSON, such as the axon's plain text rejections, keep only the
+    /// header data.
     ///
     /// # Returns
     ///
     /// The reconstructed Synapse or an error
     pub fn into_synapse(self) -> Result<Synapse, ResponseError> {
-        let mut synapse = headers_to_synapse(&self.headers, &self.body)?;
+        let mut synapse = match headers_to_synapse(&self.headers, &self.body) {
+            Err(RequestError::Serialization(_)) if !self.is_success() => {
+                headers_to_synapse(&self.headers, &[])?
+            }
+            result => result?,
+        };
 
         // Update the dendrite terminal info with response status
         if let Some(ref mut dendrite) = synapse.dendrite {
@@ -136,6 +145,66 @@ impl DendriteResponse {
     }
 }
 
+/// Result of a single call made by `Dendrite::call_many`
+#[derive(Debug)]
+pub struct DendriteResult {
+    /// Endpoint of the target axon
+    pub endpoint: String,
+    /// Hotkey of the target axon, if known
+    pub hotkey: Option<String>,
+    /// Status code of the call (HTTP status, or the matching code for a
+    /// transport failure)
+    pub status_code: i32,
+    /// Time from sending the request to receiving the full response
+    pub latency: Duration,
+    /// The response synapse, or the reason the call failed
+    pub response: Result<Synapse, BittensorError>,
+}
+
+impl DendriteResult {
+    /// Create a result for a call that received a response
+    pub fn succeeded(axon: &AxonInfo, latency: Duration, synapse: Synapse) -> Self {
+        let status_code = synapse
+            .dendrite
+            .as_ref()
+            .and_then(|d| d.status_code)
+            .unwrap_or(status_codes::SUCCESS);
+        Self {
+            endpoint: axon.to_endpoint(),
+            hotkey: axon.hotkey.clone(),
+            status_code,
+            latency,
+            response: Ok(synapse),
+        }
+    }
+
+    /// Create a result for a call that failed
+    pub fn failed(
+        axon: &AxonInfo,
+        status_code: i32,
+        latency: Duration,
+        error: BittensorError,
+    ) -> Self {
+        Self {
+            endpoint: axon.to_endpoint(),
+            hotkey: axon.hotkey.clone(),
+            status_code,
+            latency,
+            response: Err(error),
+        }
+    }
+
+    /// Check if the axon responded with a success status
+    pub fn is_success(&self) -> bool {
+        self.response.is_ok() && (200..300).contains(&self.status_code)
+    }
+
+    /// Get the response synapse, if any
+    pub fn synapse(&self) -> Option<&Synapse> {
+        self.response.as_ref().ok()
+    }
+}
+
 /// Build a failed response synapse for error conditions
 ///
 /// This is used when the request fails before reaching the axon,
@@ -207,6 +276,54 @@ mod tests {
         assert!(!response.is_server_error());
     }
 
+    #[test]
+    fn test_error_response_with_text_body() {
+        let mut headers = HeaderMap::new();
+        headers.insert("bt_header_axon_status_code", "413".parse().unwrap());
+        let synapse = DendriteResponse::new(413, headers, b"Request body too large".to_vec(), 0.1)
+            .into_synapse()
+            .unwrap();
+        assert_eq!(
+            synapse.dendrite.as_ref().and_then(|d| d.status_code),
+            Some(413)
+        );
+        assert_eq!(synapse.axon.as_ref().and_then(|a| a.status_code), Some(413));
+
+        // A success response must still carry a synapse body
+        assert!(
+            DendriteResponse::new(200, HeaderMap::new(), b"ok".to_vec(), 0.1)
+                .into_synapse()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_dendrite_result_status() {
+        let axon = AxonInfo::from_chain_data(0, 0, "127.0.0.1".parse().unwrap(), 8091, 4, 0, 0, 0);
+
+        let synapse = DendriteResponse::new(200, HeaderMap::new(), vec![], 0.25)
+            .into_synapse()
+            .unwrap();
+        let ok = DendriteResult::succeeded(&axon, Duration::from_millis(250), synapse);
+        assert!(ok.is_success());
+        assert_eq!(ok.status_code, 200);
+        assert_eq!(
+            ok.synapse().and_then(|s| s.dendrite.as_ref()?.process_time),
+            Some(0.25)
+        );
+
+        let error = crate::errors::SynapseTimeout::new("timed out");
+        let failed = DendriteResult::failed(
+            &axon,
+            status_codes::TIMEOUT,
+            Duration::from_secs(1),
+            error.into(),
+        );
+        assert!(!failed.is_success());
+        assert!(failed.synapse().is_none());
+        assert_eq!(failed.endpoint, "http://127.0.0.1:8091");
+    }
+
     #[test]
     fn test_dendrite_response_is_timeout() {
         let response_408 = DendriteResponse::new(408, HeaderMap::new(), vec![], 12.0);
@@ -0,0 +1,215 @@
+//! Retry policy for dendrite calls
+//!
+//! A [`RetryPolicy`] resends a call that failed transiently: the axon could
+//! not be reached, timed out, or answered with a 5xx status. Retries wait a
+//! jittered, exponentially growing delay and share the call's timeout, so a
+//! call with retries never takes longer than one without. Every attempt is
+//! signed afresh with a new nonce and UUID.
+//!
+//! The default policy makes a single attempt. Synapses whose handlers are not
+//! safe to run twice should keep it, or opt out explicitly with
+//! [`RetryPolicy::none`].
+
+use rand::Rng;
+use std::time::Duration;
+
+/// Default delay before the first retry
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Default upper bound on the delay between retries
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Why an attempt failed, as far as retrying is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryReason {
+    /// The connection could not be established
+    Connect,
+    /// The request timed out, or the axon answered 408 or 504
+    Timeout,
+    /// The axon answered with another 5xx status
+    ServerError,
+}
+
+impl RetryReason {
+    /// Classify a response status code (None for statuses never retried)
+    pub fn from_status(status: i32) -> Option<Self> {
+        match status {
+            408 | 504 => Some(RetryReason::Timeout),
+            500..=599 => Some(RetryReason::ServerError),
+            _ => None,
+        }
+    }
+}
+
+/// Failures that are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryOn {
+    /// Retry connection failures
+    pub connect: bool,
+    /// Retry 5xx responses other than 504
+    pub server_error: bool,
+    /// Retry timeouts
+    pub timeout: bool,
+}
+
+impl RetryOn {
+    /// Retry every transient failure
+    pub fn all() -> Self {
+        Self {
+            connect: true,
+            server_error: true,
+            timeout: true,
+        }
+    }
+
+    /// Whether a failure for `reason` is retried
+    pub fn matches(&self, reason: RetryReason) -> bool {
+        match reason {
+            RetryReason::Connect => self.connect,
+            RetryReason::Timeout => self.timeout,
+            RetryReason::ServerError => self.server_error,
+        }
+    }
+}
+
+impl Default for RetryOn {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// Exponential backoff between retries
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    /// Delay before the first retry
+    pub initial: Duration,
+    /// Upper bound on the delay
+    pub max: Duration,
+}
+
+impl Backoff {
+    /// Backoff starting at `initial` and doubling up to `max`
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self { initial, max }
+    }
+
+    /// Delay before retry number `retry` (1 for the first retry), without jitter
+    pub fn base_delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+
+    /// Delay before retry number `retry`, randomized to between half and
+    /// all of [`Backoff::base_delay`] so callers retrying together spread out
+    pub fn delay(&self, retry: u32) -> Duration {
+        let base = self.base_delay(retry);
+        let half = base / 2;
+        half + half.mul_f64(rand::rng().random::<f64>())
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::new(DEFAULT_INITIAL_BACKOFF, DEFAULT_MAX_BACKOFF)
+    }
+}
+
+/// How a dendrite call is retried
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay between attempts
+    pub backoff: Backoff,
+    /// Failures that are retried
+    pub retry_on: RetryOn,
+}
+
+impl RetryPolicy {
+    /// Retry transient failures, making at most `max_attempts` attempts
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff: Backoff::default(),
+            retry_on: RetryOn::all(),
+        }
+    }
+
+    /// Make a single attempt
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Set the backoff between attempts
+    pub fn with_backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Set which failures are retried
+    pub fn with_retry_on(mut self, retry_on: RetryOn) -> Self {
+        self.retry_on = retry_on;
+        self
+    }
+
+    /// Whether attempt number `attempt` (1-based), which failed for `reason`,
+    /// may be followed by another
+    pub fn should_retry(&self, attempt: u32, reason: RetryReason) -> bool {
+        attempt < self.max_attempts && self.retry_on.matches(reason)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let backoff = Backoff::new(Duration::from_millis(100), Duration::from_millis(500));
+        let delays: Vec<u128> = (1..=5)
+            .map(|retry| backoff.base_delay(retry).as_millis())
+            .collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500]);
+        assert_eq!(
+            backoff.base_delay(u32::MAX),
+            Duration::from_millis(500),
+            "no overflow"
+        );
+
+        for _ in 0..100 {
+            let delay = backoff.delay(2);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy::new(3).with_retry_on(RetryOn {
+            server_error: false,
+            ..RetryOn::all()
+        });
+        assert!(policy.should_retry(1, RetryReason::Connect));
+        assert!(policy.should_retry(2, RetryReason::Timeout));
+        assert!(!policy.should_retry(3, RetryReason::Timeout));
+        assert!(!policy.should_retry(1, RetryReason::ServerError));
+        assert!(!RetryPolicy::none().should_retry(1, RetryReason::Connect));
+    }
+
+    #[test]
+    fn test_reason_from_status() {
+        assert_eq!(
+            RetryReason::from_status(502),
+            Some(RetryReason::ServerError)
+        );
+        assert_eq!(RetryReason::from_status(504), Some(RetryReason::Timeout));
+        assert_eq!(RetryReason::from_status(408), Some(RetryReason::Timeout));
+        assert_eq!(RetryReason::from_status(429), None);
+        assert_eq!(RetryReason::from_status(200), None);
+    }
+}
@@ -4,9 +4,15 @@
 //! from Axon servers, allowing for incremental processing of large
 //! or continuous data streams.
 
+use crate::dendrite::request::header_names;
+use crate::types::TerminalInfo;
+use bytes::Bytes;
 use futures::Stream;
+use http::HeaderMap;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
 
 /// Trait for synapses that support streaming responses
 ///
@@ -27,6 +33,19 @@ pub trait StreamingSynapse: Send {
     /// `Some(chunk)` if a complete chunk was parsed, `None` if more data is needed
     fn process_chunk(&mut self, chunk: &[u8]) -> Option<Self::Chunk>;
 
+    /// Length of the first complete frame in the buffered data
+    ///
+    /// Used to consume exactly one frame from the buffer after it has been
+    /// processed, so that data received in the same read is not lost.
+    /// Returns `None` if no complete frame is buffered or the framing is
+    /// unknown; in that case the whole buffer is consumed once a chunk is
+    /// parsed.
+    ///
+    /// Default implementation returns `None`
+    fn frame_len(&self, _buffer: &[u8]) -> Option<usize> {
+        None
+    }
+
     /// Check if the stream is complete
     ///
     /// Returns `true` when no more chunks are expected
@@ -56,6 +75,8 @@ where
     byte_stream: B,
     /// Buffer for incomplete chunks
     buffer: Vec<u8>,
+    /// Whether the underlying byte stream has ended
+    exhausted: bool,
     /// Whether the stream has completed
     completed: bool,
 }
@@ -76,6 +97,7 @@ where
             synapse,
             byte_stream,
             buffer: Vec::with_capacity(4096),
+            exhausted: false,
             completed: false,
         }
     }
@@ -94,6 +116,39 @@ where
     pub fn is_completed(&self) -> bool {
         self.completed
     }
+
+    /// Parse the next chunk out of the buffer, consuming the bytes it used
+    fn next_buffered(&mut self) -> Option<S::Chunk> {
+        while !self.buffer.is_empty() {
+            let frame_len = self
+                .synapse
+                .frame_len(&self.buffer)
+                .map(|len| len.min(self.buffer.len()));
+
+            match (self.synapse.process_chunk(&self.buffer), frame_len) {
+                (Some(chunk), Some(len)) => {
+                    self.buffer.drain(..len);
+                    return Some(chunk);
+                }
+                (Some(chunk), None) => {
+                    self.buffer.clear();
+                    return Some(chunk);
+                }
+                // A complete frame that doesn't parse is skipped
+                (None, Some(len)) if len > 0 => {
+                    self.buffer.drain(..len);
+                }
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// Mark the stream as completed and notify the synapse
+    fn finish(&mut self) {
+        self.completed = true;
+        self.synapse.on_stream_end();
+    }
 }
 
 impl<S, B> Stream for StreamingResponse<S, B>
@@ -107,59 +162,116 @@ where
         let this = &mut *self;
 
         if this.completed || this.synapse.is_complete() {
-            this.synapse.on_stream_end();
+            if !this.completed {
+                this.finish();
+            }
             return Poll::Ready(None);
         }
 
-        // Try to process any buffered data first
-        if !this.buffer.is_empty() {
-            if let Some(chunk) = this.synapse.process_chunk(&this.buffer) {
-                this.buffer.clear();
+        loop {
+            // Drain any buffered frames first
+            if let Some(chunk) = this.next_buffered() {
                 return Poll::Ready(Some(Ok(chunk)));
             }
-        }
 
-        // Poll the underlying stream for more data
-        match Pin::new(&mut this.byte_stream).poll_next(cx) {
-            Poll::Ready(Some(Ok(bytes))) => {
-                // Append to buffer
-                this.buffer.extend_from_slice(&bytes);
-
-                // Try to process the chunk
-                if let Some(chunk) = this.synapse.process_chunk(&this.buffer) {
-                    this.buffer.clear();
-                    Poll::Ready(Some(Ok(chunk)))
-                } else {
-                    // Need more data, continue polling
-                    cx.waker().wake_by_ref();
-                    Poll::Pending
-                }
+            if this.exhausted {
+                this.finish();
+                return Poll::Ready(None);
             }
-            Poll::Ready(Some(Err(e))) => {
-                this.completed = true;
-                this.synapse.on_stream_end();
-                Poll::Ready(Some(Err(StreamError::Network(e.to_string()))))
-            }
-            Poll::Ready(None) => {
-                // Stream ended
-                this.completed = true;
-                this.synapse.on_stream_end();
-
-                // Process any remaining buffered data
-                if !this.buffer.is_empty() {
-                    if let Some(chunk) = this.synapse.process_chunk(&this.buffer) {
-                        this.buffer.clear();
-                        return Poll::Ready(Some(Ok(chunk)));
-                    }
-                }
 
-                Poll::Ready(None)
+            // Poll the underlying stream for more data
+            match Pin::new(&mut this.byte_stream).poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    this.buffer.extend_from_slice(&bytes);
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    this.finish();
+                    return Poll::Ready(Some(Err(StreamError::Network(e.to_string()))));
+                }
+                Poll::Ready(None) => {
+                    this.exhausted = true;
+                }
+                Poll::Pending => return Poll::Pending,
             }
-            Poll::Pending => Poll::Pending,
         }
     }
 }
 
+/// Outcome of a stream consumed with `Dendrite::call_stream_chunks`
+#[derive(Debug, Clone, Default)]
+pub struct StreamSummary {
+    /// Bytes passed to the callback
+    pub bytes: u64,
+    /// Chunks passed to the callback, as they arrived from the socket
+    ///
+    /// These need not line up with the chunks the axon's handler produced.
+    pub chunks: u64,
+    /// Time from sending the request to the end of the stream
+    pub elapsed: Duration,
+    /// Whether the callback stopped the stream early
+    pub cancelled: bool,
+    /// The axon's final status and process time from the trailers (None if
+    /// cancelled)
+    pub axon: Option<TerminalInfo>,
+}
+
+impl StreamSummary {
+    /// Whether the stream ran to completion and the axon reported success
+    pub fn is_success(&self) -> bool {
+        !self.cancelled && self.axon.as_ref().and_then(|a| a.status_code) == Some(200)
+    }
+}
+
+/// An item read from a streaming response body
+pub(crate) enum StreamEvent {
+    /// A data frame
+    Chunk(Bytes),
+    /// The trailers that end a complete stream
+    Trailers(HeaderMap),
+    /// Reading the body failed
+    Failed(reqwest::Error),
+}
+
+/// Read `body` frame by frame into `tx`
+///
+/// Waits for room in the channel before reading the next frame, so a full
+/// channel stops reading from the socket. Returns once the body ends, fails
+/// or the receiver is dropped; the body is dropped with it, closing the
+/// connection if the stream was not finished.
+pub(crate) async fn read_stream_frames(mut body: reqwest::Body, tx: mpsc::Sender<StreamEvent>) {
+    use http_body::Body as _;
+
+    loop {
+        let event = match futures::future::poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await {
+            Some(Ok(frame)) => match frame.into_data() {
+                Ok(chunk) if chunk.is_empty() => continue,
+                Ok(chunk) => StreamEvent::Chunk(chunk),
+                Err(frame) => match frame.into_trailers() {
+                    Ok(trailers) => StreamEvent::Trailers(trailers),
+                    Err(_) => continue,
+                },
+            },
+            Some(Err(e)) => StreamEvent::Failed(e),
+            None => return,
+        };
+        let last = !matches!(event, StreamEvent::Chunk(_));
+        if tx.send(event).await.is_err() || last {
+            return;
+        }
+    }
+}
+
+/// The axon's final status from the trailers of a streaming response
+pub(crate) fn terminal_from_trailers(trailers: &HeaderMap) -> TerminalInfo {
+    let get = |name: &str| trailers.get(name).and_then(|v| v.to_str().ok());
+    TerminalInfo {
+        status_code: get(header_names::AXON_STATUS_CODE).and_then(|v| v.parse().ok()),
+        status_message: get(header_names::AXON_STATUS_MESSAGE).map(str::to_string),
+        process_time: get(header_names::AXON_PROCESS_TIME).and_then(|v| v.parse().ok()),
+        ..Default::default()
+    }
+}
+
 /// Errors that can occur during streaming
 #[derive(Debug, thiserror::Error)]
 pub enum StreamError {
@@ -213,6 +325,13 @@ impl StreamingSynapse for TextStreamingSynapse {
         }
     }
 
+    fn frame_len(&self, buffer: &[u8]) -> Option<usize> {
+        buffer
+            .iter()
+            .position(|&b| b == self.delimiter)
+            .map(|pos| pos + 1)
+    }
+
     fn is_complete(&self) -> bool {
         self.complete
     }
@@ -270,6 +389,10 @@ where
         }
     }
 
+    fn frame_len(&self, buffer: &[u8]) -> Option<usize> {
+        buffer.iter().position(|&b| b == b'\n').map(|pos| pos + 1)
+    }
+
     fn is_complete(&self) -> bool {
         self.complete
     }
@@ -347,6 +470,13 @@ impl StreamingSynapse for SseStreamingSynapse {
         None
     }
 
+    fn frame_len(&self, buffer: &[u8]) -> Option<usize> {
+        buffer
+            .windows(2)
+            .position(|w| w == b"\n\n")
+            .map(|pos| pos + 2)
+    }
+
     fn is_complete(&self) -> bool {
         self.complete
     }
@@ -446,6 +576,29 @@ mod tests {
         assert_eq!(synapse.process_chunk(b"hello|"), Some("hello".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_streaming_response_keeps_frames_from_one_read() {
+        use futures::StreamExt;
+
+        let reads: Vec<Result<bytes::Bytes, reqwest::Error>> = vec![
+            Ok(bytes::Bytes::from_static(b"one\ntwo\nthr")),
+            Ok(bytes::Bytes::from_static(b"ee\n")),
+        ];
+        let synapse = TextStreamingSynapse::new("test");
+        let response = StreamingResponse::new(synapse, futures::stream::iter(reads));
+
+        let chunks: Vec<String> = response.map(|c| c.unwrap()).collect().await;
+        assert_eq!(chunks, vec!["one", "two", "three"]);
+    }
+
+    #[test]
+    fn test_sse_frame_len() {
+        let synapse = SseStreamingSynapse::new("test");
+
+        assert_eq!(synapse.frame_len(b"data: a\n\ndata: b\n\n"), Some(9));
+        assert_eq!(synapse.frame_len(b"data: a\n"), None);
+    }
+
     #[test]
     fn test_streaming_synapse_completion() {
         let mut synapse = TextStreamingSynapse::new("test");
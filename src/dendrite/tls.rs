@@ -0,0 +1,242 @@
+//! Certificate pinning for TLS axons
+//!
+//! Miners serving over TLS typically use self-signed certificates, which do
+//! not chain to any system root and usually have no hostname matching the
+//! axon's IP. Instead of CA validation, the dendrite pins the certificate the
+//! neuron published on chain: the handshake succeeds only if the server
+//! presents a certificate for the pinned key and proves possession of it.
+//!
+//! The chain stores at most 64 bytes per neuron, so neurons publish the DER
+//! `SubjectPublicKeyInfo` of their key. A pin may also be a full DER
+//! certificate, which must then match exactly.
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{CertificateError, DigitallySignedStruct, SignatureScheme};
+use std::sync::Arc;
+
+/// Server certificate verifier that accepts a single pinned certificate
+#[derive(Debug)]
+pub struct PinnedCertVerifier {
+    /// The DER encoded public key or certificate the server must present
+    certificate: CertificateDer<'static>,
+    /// Crypto provider used to verify handshake signatures
+    provider: Arc<CryptoProvider>,
+}
+
+impl PinnedCertVerifier {
+    /// Create a verifier pinning `der`
+    ///
+    /// # Arguments
+    ///
+    /// * `der` - The DER encoded public key or certificate to trust
+    /// * `provider` - Crypto provider used to verify handshake signatures
+    pub fn new(der: &[u8], provider: Arc<CryptoProvider>) -> Self {
+        Self {
+            certificate: CertificateDer::from(der.to_vec()),
+            provider,
+        }
+    }
+}
+
+impl ServerCertVerifier for PinnedCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let pinned = self.certificate.as_ref();
+        if end_entity.as_ref() == pinned
+            || subject_public_key_info(end_entity.as_ref()) == Some(pinned)
+        {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::UnknownIssuer,
+            ))
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+/// The installed rustls crypto provider, or ring if none is installed
+pub(crate) fn crypto_provider() -> Arc<CryptoProvider> {
+    CryptoProvider::get_default()
+        .cloned()
+        .unwrap_or_else(|| Arc::new(rustls::crypto::ring::default_provider()))
+}
+
+/// A DER element's tag, contents, whole encoding and the bytes after it
+type DerElement<'a> = (u8, &'a [u8], &'a [u8], &'a [u8]);
+
+/// Split one DER element off `input`
+///
+/// Returns the tag, the contents, the whole element and the remaining bytes.
+fn der_element(input: &[u8]) -> Option<DerElement<'_>> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first, rest) = rest.split_first()?;
+    let (len, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else {
+        let n = (first & 0x7f) as usize;
+        if n == 0 || n > 4 || rest.len() < n {
+            return None;
+        }
+        let len = rest[..n]
+            .iter()
+            .fold(0usize, |len, &b| (len << 8) | b as usize);
+        (len, &rest[n..])
+    };
+    if rest.len() < len {
+        return None;
+    }
+    let header_len = input.len() - rest.len();
+    Some((tag, &rest[..len], &input[..header_len + len], &rest[len..]))
+}
+
+/// Extract the DER `SubjectPublicKeyInfo` from a DER X.509 certificate
+///
+/// # Returns
+///
+/// The encoded `SubjectPublicKeyInfo`, or None if `der` is not a certificate
+pub fn subject_public_key_info(der: &[u8]) -> Option<&[u8]> {
+    const SEQUENCE: u8 = 0x30;
+    const VERSION: u8 = 0xa0;
+
+    let (SEQUENCE, certificate, _, _) = der_element(der)? else {
+        return None;
+    };
+    let (SEQUENCE, tbs, _, _) = der_element(certificate)? else {
+        return None;
+    };
+    let mut rest = tbs;
+    if rest.first() == Some(&VERSION) {
+        rest = der_element(rest)?.3;
+    }
+    // serialNumber, signature, issuer, validity, subject
+    for _ in 0..5 {
+        rest = der_element(rest)?.3;
+    }
+    match der_element(rest)? {
+        (SEQUENCE, _, spki, _) => Some(spki),
+        _ => None,
+    }
+}
+
+/// Build a rustls client configuration that trusts only `der`
+///
+/// # Arguments
+///
+/// * `der` - The DER encoded public key or certificate to trust
+///
+/// # Returns
+///
+/// A client configuration with the pinned verifier installed
+pub fn pinned_client_config(der: &[u8]) -> Result<rustls::ClientConfig, rustls::Error> {
+    let provider = crypto_provider();
+    let verifier = PinnedCertVerifier::new(der, provider.clone());
+
+    Ok(rustls::ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier))
+        .with_no_client_auth())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verify(verifier: &PinnedCertVerifier, der: &[u8]) -> Result<(), rustls::Error> {
+        let server_name = ServerName::try_from("127.0.0.1").unwrap();
+        verifier
+            .verify_server_cert(
+                &CertificateDer::from(der),
+                &[],
+                &server_name,
+                &[],
+                UnixTime::now(),
+            )
+            .map(|_| ())
+    }
+
+    #[test]
+    fn test_pinned_verifier_accepts_only_pinned_certificate() {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let verifier = PinnedCertVerifier::new(b"pinned-cert", provider);
+
+        assert!(verify(&verifier, b"pinned-cert").is_ok());
+        assert!(matches!(
+            verify(&verifier, b"other-cert"),
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::UnknownIssuer
+            ))
+        ));
+        assert!(!verifier.supported_verify_schemes().is_empty());
+    }
+
+    #[test]
+    fn test_subject_public_key_info() {
+        let key = rcgen::KeyPair::generate_for(&rcgen::PKCS_ED25519).unwrap();
+        let cert = rcgen::CertificateParams::default()
+            .self_signed(&key)
+            .unwrap();
+        assert_eq!(
+            subject_public_key_info(cert.der()),
+            Some(key.public_key_der().as_slice())
+        );
+        assert_eq!(subject_public_key_info(b"pinned-cert"), None);
+        assert_eq!(subject_public_key_info(&cert.der()[..40]), None);
+
+        // A pinned public key accepts the certificate carrying it
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let verifier = PinnedCertVerifier::new(&key.public_key_der(), provider.clone());
+        assert!(verify(&verifier, cert.der()).is_ok());
+
+        let other = rcgen::KeyPair::generate_for(&rcgen::PKCS_ED25519).unwrap();
+        let verifier = PinnedCertVerifier::new(&other.public_key_der(), provider);
+        assert!(verify(&verifier, cert.der()).is_err());
+    }
+
+    #[test]
+    fn test_pinned_client_config() {
+        assert!(pinned_client_config(b"pinned-cert").is_ok());
+    }
+}
@@ -2,6 +2,37 @@
 //!
 //! This module provides error types that match the Python SDK exception hierarchy
 //! for compatibility and ease of use when porting code between implementations.
+//!
+//! [`BittensorError`] is the error returned by `crv4`, `queries`, `validator`
+//! and [`Subtensor`](crate::subtensor::Subtensor). Errors from the chain layer
+//! convert into it as follows:
+//!
+//! | [`chain::Error`](crate::chain::Error) | [`BittensorError`] |
+//! |---|---|
+//! | `Subxt` | `Subxt` |
+//! | `Rpc` (dropped or unreachable connection) | `ChainConnection` |
+//! | `Rpc` (anything else) | `ChainQuery` |
+//! | `Decoding` | `ChainQuery` |
+//! | `Encoding` | `Serialization` |
+//! | `Transaction` | `TransactionFailed` |
+//! | `DispatchError` | `TransactionFailed`, with `dispatch_error` set |
+//! | `Nonce` | `NonceError` |
+//! | `RateLimited` | `RateLimited` |
+//! | `InvalidAccount`, `Signer` | `Wallet` |
+//! | `BlockNotFound` | `BlockNotFound` |
+//!
+//! Failures to decode a storage value or runtime API response
+//! ([`ValueDecodeError`](crate::utils::decoders::ValueDecodeError),
+//! [`ScaleDecodeError`](crate::utils::decoders::ScaleDecodeError) and
+//! `parity_scale_codec::Error`) become `ChainQuery` as well.
+//!
+//! `TransactionFailed::dispatch_error` holds the `Pallet.Error` name when the
+//! message contains one. An [`anyhow::Error`] converts to the
+//! [`BittensorError`], specific error or chain error it wraps (looking through
+//! context), and to `Unknown` otherwise.
+//!
+//! Code written against the previous `anyhow::Result` signatures can use
+//! [`compat`](crate::compat) for one release.
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -726,6 +757,26 @@ impl StakeFailed {
     }
 }
 
+/// Error when stake transfers between coldkeys are turned off for a subnet
+#[derive(Debug, Error, Clone, Serialize, Deserialize)]
+#[error("Stake transfer disabled: {message}")]
+pub struct StakeTransferDisabled {
+    /// Detailed error message
+    pub message: String,
+    /// The subnet with `TransferToggle` off
+    pub netuid: u16,
+}
+
+impl StakeTransferDisabled {
+    /// Create a new stake transfer disabled error for a subnet
+    pub fn new(netuid: u16) -> Self {
+        Self {
+            message: format!("stake transfers are disabled on subnet {}", netuid),
+            netuid,
+        }
+    }
+}
+
 // =============================================================================
 // Weights Errors
 // =============================================================================
@@ -1362,6 +1413,29 @@ impl ProposalNotFound {
     }
 }
 
+/// Error when a proposal's voting period has ended
+#[derive(Debug, Error, Clone, Serialize, Deserialize)]
+#[error("Proposal {proposal_index} expired at block {end_block} (current block {current_block})")]
+pub struct ProposalExpired {
+    /// The proposal index
+    pub proposal_index: u32,
+    /// The block at which voting ended
+    pub end_block: u64,
+    /// The block at which the vote was attempted
+    pub current_block: u64,
+}
+
+impl ProposalExpired {
+    /// Create a new proposal expired error
+    pub fn new(proposal_index: u32, end_block: u64, current_block: u64) -> Self {
+        Self {
+            proposal_index,
+            end_block,
+            current_block,
+        }
+    }
+}
+
 // =============================================================================
 // Extrinsic Dispatch Errors (decoded from chain events/metadata)
 // =============================================================================
@@ -1521,6 +1595,8 @@ pub enum BittensorError {
     InsufficientStake(#[from] InsufficientStake),
     #[error(transparent)]
     StakeFailed(#[from] StakeFailed),
+    #[error(transparent)]
+    StakeTransferDisabled(#[from] StakeTransferDisabled),
 
     // Weights Errors
     #[error(transparent)]
@@ -1569,6 +1645,8 @@ pub enum BittensorError {
     VoteFailed(#[from] VoteFailed),
     #[error(transparent)]
     ProposalNotFound(#[from] ProposalNotFound),
+    #[error(transparent)]
+    ProposalExpired(#[from] ProposalExpired),
 
     // Extrinsic dispatch error (decoded from chain events)
     #[error(transparent)]
@@ -1623,6 +1701,149 @@ impl From<hex::FromHexError> for BittensorError {
     }
 }
 
+impl From<crate::utils::decoders::ValueDecodeError> for BittensorError {
+    fn from(err: crate::utils::decoders::ValueDecodeError) -> Self {
+        BittensorError::ChainQuery(ChainQueryError::new(err.to_string()))
+    }
+}
+
+impl From<crate::utils::decoders::ScaleDecodeError> for BittensorError {
+    fn from(err: crate::utils::decoders::ScaleDecodeError) -> Self {
+        BittensorError::ChainQuery(ChainQueryError::new(err.to_string()))
+    }
+}
+
+impl From<parity_scale_codec::Error> for BittensorError {
+    fn from(err: parity_scale_codec::Error) -> Self {
+        BittensorError::ChainQuery(ChainQueryError::new(err.to_string()))
+    }
+}
+
+impl From<crate::chain::Error> for BittensorError {
+    fn from(err: crate::chain::Error) -> Self {
+        use crate::chain::Error as ChainError;
+        let message = match &err {
+            ChainError::Subxt(e) => e.to_string(),
+            ChainError::BlockNotFound(e) => return BittensorError::BlockNotFound(e.clone()),
+            ChainError::Rpc(msg)
+            | ChainError::Encoding(msg)
+            | ChainError::Decoding(msg)
+            | ChainError::InvalidAccount(msg)
+            | ChainError::Transaction(msg)
+            | ChainError::Nonce(msg)
+            | ChainError::RateLimited(msg)
+            | ChainError::DispatchError(msg)
+            | ChainError::Signer(msg) => msg.clone(),
+        };
+        BittensorError::from_chain_error(&err, message)
+    }
+}
+
+/// Return the first specific error type that `$err` wraps
+macro_rules! downcast_specific {
+    ($err:expr, $($ty:ty),+ $(,)?) => {
+        $(
+            if let Some(e) = $err.downcast_ref::<$ty>() {
+                return e.clone().into();
+            }
+        )+
+    };
+}
+
+impl From<anyhow::Error> for BittensorError {
+    fn from(err: anyhow::Error) -> Self {
+        let err = match err.downcast::<BittensorError>() {
+            Ok(e) => return e,
+            Err(err) => err,
+        };
+        downcast_specific!(
+            err,
+            ChainConnectionError,
+            ChainQueryError,
+            ExtrinsicError,
+            TransactionFailed,
+            BlockNotFound,
+            MetadataError,
+            WalletError,
+            KeyfileNotFound,
+            KeyfileDecryptionError,
+            InvalidMnemonic,
+            InvalidKeyfile,
+            KeyfilePermissionError,
+            KeyExists,
+            NotRegistered,
+            AlreadyRegistered,
+            RegistrationFailed,
+            PowFailed,
+            InsufficientBalance,
+            InsufficientStake,
+            StakeFailed,
+            StakeTransferDisabled,
+            WeightsError,
+            InvalidWeights,
+            WeightVersionMismatch,
+            TooManyWeights,
+            SynapseError,
+            SynapseTimeout,
+            SynapseUnauthorized,
+            SynapseBlacklisted,
+            SerializationError,
+            DendriteError,
+            AxonUnreachable,
+            InvalidResponse,
+            AxonError,
+            AxonNotServing,
+            AxonConfigError,
+            NotSenateMember,
+            AlreadySenateMember,
+            VoteFailed,
+            ProposalNotFound,
+            ProposalExpired,
+            ExtrinsicDispatchError,
+            NonceError,
+            RateLimitedError,
+        );
+        // Keep any context added around the chain error in the message
+        let message = format!("{:#}", err);
+        if let Some(e) = err.downcast_ref::<crate::chain::Error>() {
+            return BittensorError::from_chain_error(e, message);
+        }
+        if err.downcast_ref::<subxt::Error>().is_some() {
+            return BittensorError::Subxt(message);
+        }
+        if err
+            .downcast_ref::<crate::utils::decoders::ValueDecodeError>()
+            .is_some()
+            || err
+                .downcast_ref::<crate::utils::decoders::ScaleDecodeError>()
+                .is_some()
+        {
+            return BittensorError::ChainQuery(ChainQueryError::new(message));
+        }
+        BittensorError::Unknown(message)
+    }
+}
+
+/// The first `Pallet.Error` name in a transaction error message
+///
+/// Dispatch errors are reported as `"SubtensorModule.SettingWeightsTooFast:
+/// description"`.
+fn dispatch_error_name(message: &str) -> Option<String> {
+    message
+        .split(|c: char| c.is_whitespace() || matches!(c, ':' | '(' | ')' | ',' | '"'))
+        .find(|token| {
+            let mut parts = token.split('.');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(pallet), Some(error), None) => [pallet, error].iter().all(|part| {
+                    part.starts_with(|c: char| c.is_ascii_uppercase())
+                        && part.chars().all(|c| c.is_ascii_alphanumeric())
+                }),
+                _ => false,
+            }
+        })
+        .map(str::to_string)
+}
+
 // =============================================================================
 // Convenience type alias
 // =============================================================================
@@ -1640,6 +1861,45 @@ impl BittensorError {
         BittensorError::Unknown(err.to_string())
     }
 
+    /// Map a chain error per the table in the module docs, prefixing its
+    /// message with `context`
+    pub(crate) fn from_chain(context: impl std::fmt::Display, err: crate::chain::Error) -> Self {
+        let message = format!("{}: {}", context, err);
+        BittensorError::from_chain_error(&err, message)
+    }
+
+    /// Map a chain error, described by `message`, per the table in the
+    /// module docs
+    fn from_chain_error(err: &crate::chain::Error, message: String) -> Self {
+        use crate::chain::Error as ChainError;
+        match err {
+            ChainError::Subxt(_) => BittensorError::Subxt(message),
+            ChainError::Rpc(msg) if crate::chain::is_connection_error_message(msg) => {
+                BittensorError::ChainConnection(ChainConnectionError::new(message))
+            }
+            ChainError::Rpc(_) | ChainError::Decoding(_) => {
+                BittensorError::ChainQuery(ChainQueryError::new(message))
+            }
+            ChainError::Encoding(_) => {
+                BittensorError::Serialization(SerializationError::new(message))
+            }
+            ChainError::Transaction(msg) | ChainError::DispatchError(msg) => {
+                BittensorError::TransactionFailed(match dispatch_error_name(msg) {
+                    Some(name) => TransactionFailed::with_dispatch_error(message, name),
+                    None => TransactionFailed::new(message),
+                })
+            }
+            ChainError::Nonce(_) => BittensorError::NonceError(NonceError::new(message)),
+            ChainError::RateLimited(_) => {
+                BittensorError::RateLimited(RateLimitedError::new(message))
+            }
+            ChainError::InvalidAccount(_) | ChainError::Signer(_) => {
+                BittensorError::Wallet(WalletError::new(message))
+            }
+            ChainError::BlockNotFound(e) => BittensorError::BlockNotFound(e.clone()),
+        }
+    }
+
     /// Check if this is a chain connection error
     pub fn is_connection_error(&self) -> bool {
         matches!(self, BittensorError::ChainConnection(_))
@@ -1754,6 +2014,91 @@ mod tests {
         assert!(unauth_err.is_unauthorized());
     }
 
+    #[test]
+    fn test_bittensor_error_from_chain_error() {
+        use crate::chain::Error as ChainError;
+
+        let err: BittensorError = ChainError::Rpc("connection reset by peer".into()).into();
+        assert!(err.is_connection_error());
+        let err: BittensorError = ChainError::Rpc("Method not found".into()).into();
+        assert!(matches!(err, BittensorError::ChainQuery(_)));
+        let err: BittensorError = ChainError::Decoding("bad u16".into()).into();
+        assert!(matches!(err, BittensorError::ChainQuery(_)));
+        let err: BittensorError = ChainError::Nonce("stale".into()).into();
+        assert!(err.is_nonce_error());
+        let err: BittensorError = ChainError::Signer("no key".into()).into();
+        assert!(matches!(err, BittensorError::Wallet(_)));
+
+        let err: BittensorError = ChainError::DispatchError(
+            "SubtensorModule.SettingWeightsTooFast: Rate limit exceeded".into(),
+        )
+        .into();
+        match err {
+            BittensorError::TransactionFailed(e) => {
+                assert_eq!(
+                    e.dispatch_error.as_deref(),
+                    Some("SubtensorModule.SettingWeightsTooFast")
+                );
+                assert!(e.message.contains("Rate limit exceeded"));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        let err: BittensorError = ChainError::Transaction("Transaction dropped: x".into()).into();
+        match err {
+            BittensorError::TransactionFailed(e) => assert_eq!(e.dispatch_error, None),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bittensor_error_from_anyhow() {
+        use anyhow::Context;
+
+        let err: BittensorError =
+            anyhow::Error::from(InsufficientBalance::with_amounts("low", 2, 1)).into();
+        assert!(err.is_insufficient_balance());
+
+        let err: BittensorError =
+            anyhow::Error::from(BittensorError::NotRegistered(NotRegistered::new("x"))).into();
+        assert!(err.is_not_registered());
+
+        // Context is kept in the message of a mapped chain error
+        let result: Result<(), _> = Err(crate::chain::Error::DispatchError(
+            "SubtensorModule.NotEnoughStake: low".into(),
+        ));
+        let err: BittensorError = result.context("Failed to add stake").unwrap_err().into();
+        match err {
+            BittensorError::TransactionFailed(e) => {
+                assert!(e.message.starts_with("Failed to add stake: "));
+                assert_eq!(
+                    e.dispatch_error.as_deref(),
+                    Some("SubtensorModule.NotEnoughStake")
+                );
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let decoded = crate::utils::decoders::decode_vec(
+            &subxt::dynamic::Value::u128(1),
+            crate::utils::decoders::decode_u64,
+        );
+        let err: BittensorError = decoded.unwrap_err().into();
+        assert!(matches!(err, BittensorError::ChainQuery(_)));
+
+        let err: BittensorError = anyhow::anyhow!("something else").into();
+        assert!(matches!(err, BittensorError::Unknown(m) if m == "something else"));
+    }
+
+    #[test]
+    fn test_dispatch_error_name() {
+        assert_eq!(
+            dispatch_error_name("Dispatch error: Balances.InsufficientBalance: too low").as_deref(),
+            Some("Balances.InsufficientBalance")
+        );
+        assert_eq!(dispatch_error_name("Invalid transaction: 1.5 TAO"), None);
+        assert_eq!(dispatch_error_name("stream ended."), None);
+    }
+
     #[test]
     fn test_error_serialization() {
         let err = ChainQueryError::with_storage("Query failed", "SubtensorModule", "TotalStake");
@@ -0,0 +1,374 @@
+//! Subnet snapshots for offline analysis
+//!
+//! [`export_subnet_snapshot`] syncs a subnet's metagraph pinned to one block
+//! and writes one row per UID as CSV or JSON lines. Rows are written as they
+//! are built, so beyond the metagraph itself nothing is buffered.
+//!
+//! Every row carries the snapshot block and its timestamp (RFC 3339, UTC),
+//! and each RAO amount appears twice: as an integer (`*_rao`) and as a
+//! decimal TAO string with nine places (`*_tao`).
+
+use crate::chain::{BittensorClient, ChainBackend};
+use crate::core::constants::RAOPERTAO;
+use crate::errors::{BittensorResult, ChainQueryError};
+use crate::metagraph::{sync_metagraph, Metagraph};
+use crate::types::AxonInfo;
+use crate::utils::balance_newtypes::Rao;
+use crate::utils::decoders::decode_u64;
+use chrono::{DateTime, SecondsFormat};
+use serde::Serialize;
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Snapshot columns, in CSV order
+pub const SNAPSHOT_COLUMNS: [&str; 27] = [
+    "netuid",
+    "block",
+    "timestamp",
+    "uid",
+    "hotkey",
+    "coldkey",
+    "active",
+    "validator_permit",
+    "stake_rao",
+    "stake_tao",
+    "total_stake_rao",
+    "total_stake_tao",
+    "emission_rao",
+    "emission_tao",
+    "rank",
+    "trust",
+    "consensus",
+    "validator_trust",
+    "incentive",
+    "dividends",
+    "pruning_score",
+    "last_update",
+    "block_at_registration",
+    "axon_ip",
+    "axon_port",
+    "axon_version",
+    "axon_protocol",
+];
+
+/// Output format of a snapshot
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma separated values with a header row
+    #[default]
+    Csv,
+    /// One JSON object per line
+    JsonLines,
+}
+
+impl ExportFormat {
+    /// Format implied by a file extension: `.jsonl` and `.ndjson` are JSON
+    /// lines, anything else CSV
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("jsonl" | "ndjson") => ExportFormat::JsonLines,
+            _ => ExportFormat::Csv,
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "jsonl" | "jsonlines" | "ndjson" => Ok(ExportFormat::JsonLines),
+            other => Err(format!(
+                "Unknown export format '{}' (expected csv or jsonl)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::JsonLines => "jsonl",
+        })
+    }
+}
+
+/// One UID of a snapshot; fields follow [`SNAPSHOT_COLUMNS`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SnapshotRow {
+    pub netuid: u16,
+    pub block: u64,
+    pub timestamp: Option<String>,
+    pub uid: u64,
+    pub hotkey: String,
+    pub coldkey: String,
+    pub active: bool,
+    pub validator_permit: bool,
+    pub stake_rao: u128,
+    pub stake_tao: String,
+    pub total_stake_rao: u128,
+    pub total_stake_tao: String,
+    pub emission_rao: u128,
+    pub emission_tao: String,
+    pub rank: f64,
+    pub trust: f64,
+    pub consensus: f64,
+    pub validator_trust: f64,
+    pub incentive: f64,
+    pub dividends: f64,
+    pub pruning_score: u64,
+    pub last_update: u64,
+    pub block_at_registration: Option<u64>,
+    pub axon_ip: Option<String>,
+    pub axon_port: Option<u16>,
+    pub axon_version: Option<u32>,
+    pub axon_protocol: Option<u8>,
+}
+
+impl SnapshotRow {
+    /// CSV fields, in [`SNAPSHOT_COLUMNS`] order; `None` is written empty
+    fn csv_fields(&self) -> [String; SNAPSHOT_COLUMNS.len()] {
+        fn opt<T: ToString>(value: &Option<T>) -> String {
+            value.as_ref().map(T::to_string).unwrap_or_default()
+        }
+        [
+            self.netuid.to_string(),
+            self.block.to_string(),
+            opt(&self.timestamp),
+            self.uid.to_string(),
+            self.hotkey.clone(),
+            self.coldkey.clone(),
+            self.active.to_string(),
+            self.validator_permit.to_string(),
+            self.stake_rao.to_string(),
+            self.stake_tao.clone(),
+            self.total_stake_rao.to_string(),
+            self.total_stake_tao.clone(),
+            self.emission_rao.to_string(),
+            self.emission_tao.clone(),
+            self.rank.to_string(),
+            self.trust.to_string(),
+            self.consensus.to_string(),
+            self.validator_trust.to_string(),
+            self.incentive.to_string(),
+            self.dividends.to_string(),
+            self.pruning_score.to_string(),
+            self.last_update.to_string(),
+            opt(&self.block_at_registration),
+            opt(&self.axon_ip),
+            opt(&self.axon_port),
+            opt(&self.axon_version),
+            opt(&self.axon_protocol),
+        ]
+    }
+}
+
+/// What an export wrote
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SnapshotSummary {
+    pub netuid: u16,
+    pub block: u64,
+    pub timestamp: Option<String>,
+    pub rows: usize,
+}
+
+/// Export the state of `netuid` at `block`, or the latest finalized block
+///
+/// All reads are pinned to the block's hash, so the rows are consistent with
+/// each other. Older blocks need a node that still has their state.
+pub async fn export_subnet_snapshot<W: Write>(
+    client: &BittensorClient,
+    netuid: u16,
+    block: Option<u64>,
+    format: ExportFormat,
+    writer: W,
+) -> BittensorResult<SnapshotSummary> {
+    let height = match block {
+        Some(height) => height,
+        None => client.block_number().await?,
+    };
+    let pinned = client.at_height(height).await?;
+    export_snapshot_from(&pinned, netuid, format, writer).await
+}
+
+/// Export the state of `netuid` as read through `chain`
+///
+/// Use a [`BlockRef`](crate::chain::BlockRef) or another backend that reads
+/// a single block for a consistent snapshot.
+pub async fn export_snapshot_from<C: ChainBackend, W: Write>(
+    chain: &C,
+    netuid: u16,
+    format: ExportFormat,
+    writer: W,
+) -> BittensorResult<SnapshotSummary> {
+    let metagraph = sync_metagraph(chain, netuid).await?;
+    let timestamp = match chain.storage("Timestamp", "Now", None).await? {
+        Some(value) => Some(decode_u64(&value).map_err(|e| {
+            ChainQueryError::with_storage(
+                format!("Failed to decode timestamp: {}", e),
+                "Timestamp",
+                "Now",
+            )
+        })?),
+        None => None,
+    };
+    write_snapshot(&metagraph, timestamp, format, writer)
+}
+
+/// Write the rows of `metagraph`, sorted by UID
+///
+/// `timestamp_ms` is the block timestamp in milliseconds since the Unix
+/// epoch (`Timestamp::Now`).
+pub fn write_snapshot<W: Write>(
+    metagraph: &Metagraph,
+    timestamp_ms: Option<u64>,
+    format: ExportFormat,
+    mut writer: W,
+) -> BittensorResult<SnapshotSummary> {
+    let timestamp = timestamp_ms.and_then(format_timestamp);
+    if format == ExportFormat::Csv {
+        write_csv_record(&mut writer, SNAPSHOT_COLUMNS)?;
+    }
+
+    let mut uids: Vec<u64> = metagraph.neurons.keys().copied().collect();
+    uids.sort_unstable();
+    for &uid in &uids {
+        let row = snapshot_row(metagraph, uid, timestamp.clone());
+        match format {
+            ExportFormat::Csv => write_csv_record(&mut writer, row.csv_fields())?,
+            ExportFormat::JsonLines => {
+                serde_json::to_writer(&mut writer, &row)?;
+                writer.write_all(b"\n")?;
+            }
+        }
+    }
+    writer.flush()?;
+
+    Ok(SnapshotSummary {
+        netuid: metagraph.netuid,
+        block: metagraph.block,
+        timestamp,
+        rows: uids.len(),
+    })
+}
+
+/// Flatten the neuron at `uid`
+fn snapshot_row(metagraph: &Metagraph, uid: u64, timestamp: Option<String>) -> SnapshotRow {
+    let neuron = &metagraph.neurons[&uid];
+    let axon: Option<&AxonInfo> = metagraph
+        .axons
+        .get(&uid)
+        .or(neuron.axon_info.as_ref())
+        .filter(|axon| axon.is_serving());
+    SnapshotRow {
+        netuid: metagraph.netuid,
+        block: metagraph.block,
+        timestamp,
+        uid,
+        hotkey: neuron.hotkey.to_string(),
+        coldkey: neuron.coldkey.to_string(),
+        active: neuron.active,
+        validator_permit: neuron.validator_permit,
+        stake_rao: neuron.stake.as_u128(),
+        stake_tao: tao_string(neuron.stake),
+        total_stake_rao: neuron.total_stake.as_u128(),
+        total_stake_tao: tao_string(neuron.total_stake),
+        emission_rao: neuron.emission.as_u128(),
+        emission_tao: tao_string(neuron.emission),
+        rank: neuron.rank,
+        trust: neuron.trust,
+        consensus: neuron.consensus,
+        validator_trust: neuron.validator_trust,
+        incentive: neuron.incentive,
+        dividends: neuron.dividends,
+        pruning_score: neuron.pruning_score,
+        last_update: neuron.last_update,
+        block_at_registration: metagraph.block_at_registration.get(uid as usize).copied(),
+        axon_ip: axon.map(|axon| axon.ip.to_string()),
+        axon_port: axon.map(|axon| axon.port),
+        axon_version: axon.map(|axon| axon.version),
+        axon_protocol: axon.map(|axon| axon.protocol),
+    }
+}
+
+/// Exact decimal TAO amount, e.g. `1.500000000`
+fn tao_string(amount: Rao) -> String {
+    let rao = amount.as_u128();
+    format!("{}.{:09}", rao / RAOPERTAO, rao % RAOPERTAO)
+}
+
+fn format_timestamp(millis: u64) -> Option<String> {
+    DateTime::from_timestamp_millis(i64::try_from(millis).ok()?)
+        .map(|time| time.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Write one CSV record, quoting fields that need it (RFC 4180)
+fn write_csv_record<W, I, S>(writer: &mut W, fields: I) -> std::io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    for (i, field) in fields.into_iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        let field = field.as_ref();
+        if field.contains([',', '"', '\n', '\r']) {
+            write!(writer, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            writer.write_all(field.as_bytes())?;
+        }
+    }
+    writer.write_all(b"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tao_string() {
+        assert_eq!(tao_string(Rao::ZERO), "0.000000000");
+        assert_eq!(tao_string(Rao::from(1_500_000_000u64)), "1.500000000");
+        assert_eq!(tao_string(Rao::from(7u64)), "0.000000007");
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(
+            format_timestamp(1_700_000_000_123).as_deref(),
+            Some("2023-11-14T22:13:20.123Z")
+        );
+    }
+
+    #[test]
+    fn test_csv_quoting() {
+        let mut out = Vec::new();
+        write_csv_record(&mut out, ["plain", "a,b", "say \"hi\"", ""]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "plain,\"a,b\",\"say \"\"hi\"\"\",\n"
+        );
+    }
+
+    #[test]
+    fn test_format_parsing() {
+        assert_eq!("CSV".parse::<ExportFormat>(), Ok(ExportFormat::Csv));
+        assert_eq!("jsonl".parse::<ExportFormat>(), Ok(ExportFormat::JsonLines));
+        assert!("parquet".parse::<ExportFormat>().is_err());
+        assert_eq!(
+            ExportFormat::from_path(Path::new("out/sn1.ndjson")),
+            ExportFormat::JsonLines
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("sn1.csv")),
+            ExportFormat::Csv
+        );
+    }
+}
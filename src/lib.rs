@@ -2,13 +2,17 @@ pub mod axon;
 pub mod blocks;
 pub mod chain;
 pub mod cli;
+pub mod compat;
 pub mod config;
 pub mod core;
 pub mod crv4;
 pub mod dendrite;
 pub mod errors;
+pub mod export;
 pub mod logging;
 pub mod metagraph;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod queries;
 pub mod subtensor;
 pub mod types;
@@ -17,18 +21,26 @@ pub mod validator;
 pub mod wallet;
 
 pub use chain::ExtrinsicWait;
+#[cfg(feature = "test-utils")]
+pub use chain::MockChain;
 pub use chain::{
-    create_client_rate_limiter, BittensorClient, ChainEvent, DispatchResult, Error as ChainError,
-    ExtrinsicResult, RetryPolicy, FALLBACK_ENDPOINTS,
+    create_client_rate_limiter, BittensorClient, BittensorClientBuilder, ChainBackend, ChainEvent,
+    DispatchResult, Error as ChainError, ExtrinsicResult, RetryPolicy, FALLBACK_ENDPOINTS,
+};
+pub use chain::{ManagedSigner, NonceManager, RemoteSigner, SharedNonceManager, TransactionSigner};
+pub use config::{
+    AxonConfig, Config, LoggingConfig as ConfigLoggingConfig, RateLimitConfig, SubtensorConfig,
+};
+pub use metagraph::{
+    sync_metagraph, sync_metagraph_with, sync_metagraph_with_options, Metagraph, MetagraphDiff,
+    MetagraphSyncer, NeuronView, SyncOptions,
 };
-pub use chain::{ManagedSigner, NonceManager, SharedNonceManager};
-pub use config::{AxonConfig, Config, LoggingConfig as ConfigLoggingConfig, SubtensorConfig};
-pub use metagraph::{sync_metagraph, Metagraph};
 
 // Re-export logging module
 pub use logging::{
-    init_default_logging, init_logging, is_initialized, BittensorFormatter, CompactFormatter,
-    JsonFormatter, LogFormat, LoggingConfig,
+    get_current_filter, hotkey_span, init_default_logging, init_logging, is_initialized,
+    set_filter, set_level, subnet_span, BittensorFormatter, CompactFormatter, FilterHandle,
+    JsonFormatter, LogFormat, LogRotation, LoggingConfig, LoggingError,
 };
 
 // Re-export types first (includes liquidity types)
@@ -39,11 +51,15 @@ pub use queries::{
     balances::*, chain_info::*, delegates::*, identity::*, metagraph_queries::*, stakes::*,
     subnets::*,
 };
+// Both subnets and metagraph_queries define this; keep the subnet-wide one at
+// the crate root
+pub use queries::subnets::blocks_since_last_update;
 
 // Re-export neurons module (use prefix for children/parents to avoid conflict with validator)
 pub use queries::neurons::{
     fetch_axon_info, fetch_prometheus_info, get_all_neuron_certificates, get_neuron_certificate,
-    get_neuron_for_pubkey_and_subnet, neuron, neurons, query_neuron_from_storage, Certificate,
+    get_neuron_for_pubkey_and_subnet, neuron, neurons, neurons_filtered, query_neuron_from_storage,
+    Certificate, NeuronFilter, CERTIFICATE_ALGORITHM_ED25519,
 };
 
 // Children/parents queries accessible via module path
@@ -55,8 +71,8 @@ pub use queries::liquidity as liquidity_queries;
 // Re-export validator functions (except weights to avoid conflict)
 pub use validator::{
     commitments::*, hyperparameters::*, identity::*, key_swap::*, liquidity::*, mechanism::*,
-    registration::*, root::*, serving::*, staking::*, subnet_management::*, sudo::*, take::*,
-    transfer::*, utility::*,
+    registration::*, root::*, serving::*, staking::*, subnet_admin::*, subnet_management::*,
+    sudo::*, take::*, transfer::*, utility::*,
 };
 
 // Children module accessible via module path to avoid conflict with query children
@@ -90,19 +106,21 @@ pub use utils::crypto::{
 
 // Re-export CRv4 (Commit-Reveal v4 with timelock encryption)
 pub use crv4::{
-    calculate_reveal_round, commit_timelocked_mechanism_weights, commit_timelocked_weights,
-    encrypt_for_round, get_commit_reveal_version, get_last_drand_round, get_mechid_storage_index,
-    get_reveal_period, get_tempo, prepare_and_commit_crv4_mechanism_weights,
-    prepare_and_commit_crv4_weights, prepare_crv4_commit, verify_encrypted_data, Crv4CommitData,
-    Crv4PersistedState, Crv4StateManager, DrandInfo, WeightsTlockPayload,
+    calculate_reveal_round, calculate_reveal_round_checked, calculate_reveal_round_for_blocks,
+    checked_reveal_round, commit_timelocked_mechanism_weights, commit_timelocked_weights,
+    encrypt_for_chain_blocks, encrypt_for_round, get_commit_reveal_version, get_last_drand_round,
+    get_mechid_storage_index, get_reveal_period, get_tempo,
+    prepare_and_commit_crv4_mechanism_weights, prepare_and_commit_crv4_weights,
+    prepare_crv4_commit, verify_encrypted_data, Crv4CommitData, Crv4PersistedState,
+    Crv4StateManager, DrandBeacon, DrandClient, DrandInfo, WeightsTlockPayload,
     DEFAULT_COMMIT_REVEAL_VERSION, DRAND_QUICKNET_GENESIS, DRAND_QUICKNET_PK_HEX,
     DRAND_ROUND_INTERVAL_SECS,
 };
 
 // Re-export high-level Subtensor API (like Python SDK)
 pub use subtensor::{
-    PendingCommit, Salt, Subtensor, SubtensorBuilder, SubtensorState, WeightResponse,
-    WeightResponseData,
+    HotkeyWeightResponse, PendingCommit, PruningRisk, PruningThresholds, RiskLevel, Salt,
+    Subtensor, SubtensorBuilder, SubtensorState, WeightResponse, WeightResponseData,
 };
 
 // Re-export mechanism functions from validator
@@ -117,7 +135,7 @@ pub use dendrite::{
 
 // Re-export Axon HTTP server
 pub use axon::{
-    Axon, AxonConfig as AxonServerConfig, AxonState, HandlerContext, RequestPriority,
+    Axon, AxonConfig as AxonServerConfig, AxonHandle, AxonState, HandlerContext, RequestPriority,
     VerifiedRequest, AXON_VERSION,
 };
 
@@ -169,12 +187,14 @@ pub use errors::{
     NotRegistered,
     NotSenateMember,
     PowFailed,
+    ProposalExpired,
     ProposalNotFound,
     RateLimitedError,
     RegistrationFailed,
     // Synapse/Communication Errors
     SerializationError,
     StakeFailed,
+    StakeTransferDisabled,
     SynapseBlacklisted,
     SynapseError,
     SynapseTimeout,
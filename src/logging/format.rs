@@ -1,21 +1,27 @@
 //! Custom log formatters for Bittensor SDK
 //!
-//! Provides log formatting that matches Python SDK output style.
+//! Provides log formatting that matches Python SDK output style. Fields of
+//! the spans around an event (see [`subnet_span`](super::subnet_span) and
+//! [`hotkey_span`](super::hotkey_span)) are flattened into JSON output, and
+//! text output is prefixed with `[netuid=12 hotkey=5F...]` when present.
 
 use std::fmt;
 use tracing::{Event, Level, Subscriber};
 use tracing_subscriber::fmt::format::{self, FormatEvent, FormatFields};
-use tracing_subscriber::fmt::FmtContext;
+use tracing_subscriber::fmt::{FmtContext, FormattedFields};
 use tracing_subscriber::registry::LookupSpan;
 
+/// Span fields shown as a prefix by the text formatter, in order
+const CONTEXT_FIELDS: &[&str] = &["netuid", "hotkey"];
+
 /// Bittensor-style log formatter matching Python SDK output format.
 ///
-/// Output format: `YYYY-MM-DD HH:MM:SS | LEVEL | target | message`
+/// Output format: `YYYY-MM-DD HH:MM:SS | LEVEL | target | [context] message`
 ///
 /// # Example Output
 /// ```text
 /// 2024-01-15 10:30:45 | INFO  | bittensor::subtensor | Connected to network
-/// 2024-01-15 10:30:46 | DEBUG | bittensor::metagraph | Syncing metagraph for netuid 1
+/// 2024-01-15 10:30:46 | DEBUG | bittensor::metagraph | [netuid=1] Syncing metagraph
 /// ```
 pub struct BittensorFormatter;
 
@@ -46,11 +52,68 @@ where
             target
         )?;
 
+        let context: Vec<String> = span_fields(ctx)
+            .into_iter()
+            .filter(|(key, _)| CONTEXT_FIELDS.contains(&key.as_str()))
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        if !context.is_empty() {
+            write!(writer, "[{}] ", context.join(" "))?;
+        }
+
         ctx.field_format().format_fields(writer.by_ref(), event)?;
         writeln!(writer)
     }
 }
 
+/// Fields of the spans around an event, outermost first; a field set again
+/// by an inner span takes its value from the inner span
+fn span_fields<S, N>(ctx: &FmtContext<'_, S, N>) -> Vec<(String, String)>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    let mut fields: Vec<(String, String)> = Vec::new();
+    let Some(scope) = ctx.event_scope() else {
+        return fields;
+    };
+    for span in scope.from_root() {
+        let extensions = span.extensions();
+        let Some(formatted) = extensions.get::<FormattedFields<N>>() else {
+            continue;
+        };
+        for (key, value) in parse_formatted_fields(&formatted.fields) {
+            fields.retain(|(existing, _)| *existing != key);
+            fields.push((key, value));
+        }
+    }
+    fields
+}
+
+/// Parse span fields as formatted by `JsonFields` (`{"netuid":1}`) or
+/// `DefaultFields` (`netuid=1 hotkey=5F...`)
+fn parse_formatted_fields(formatted: &str) -> Vec<(String, String)> {
+    if formatted.starts_with('{') {
+        if let Ok(serde_json::Value::Object(map)) = serde_json::from_str(formatted) {
+            return map
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        serde_json::Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    (key, value)
+                })
+                .collect();
+        }
+    }
+    formatted
+        .split_whitespace()
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.trim_matches('"').to_string()))
+        .collect()
+}
+
 /// Format log level with fixed width for alignment
 fn format_level(level: Level) -> &'static str {
     match level {
@@ -65,10 +128,12 @@ fn format_level(level: Level) -> &'static str {
 /// JSON log formatter for structured logging.
 ///
 /// Produces newline-delimited JSON (NDJSON) suitable for log aggregation systems.
+/// Fields of enclosing spans are added to the object after the event's own
+/// fields, which win on conflicts.
 ///
 /// # Example Output
 /// ```json
-/// {"timestamp":"2024-01-15T10:30:45.123456Z","level":"INFO","target":"bittensor::subtensor","message":"Connected to network"}
+/// {"timestamp":"2024-01-15T10:30:45.123456Z","level":"INFO","target":"bittensor::subtensor","message":"Connected to network","netuid":"1"}
 /// ```
 pub struct JsonFormatter;
 
@@ -79,7 +144,7 @@ where
 {
     fn format_event(
         &self,
-        _ctx: &FmtContext<'_, S, N>,
+        ctx: &FmtContext<'_, S, N>,
         mut writer: format::Writer<'_>,
         event: &Event<'_>,
     ) -> fmt::Result {
@@ -102,17 +167,21 @@ where
         write!(writer, "{}", escape_json_string(&field_visitor.message))?;
         write!(writer, "\"")?;
 
-        // Add additional fields if present
-        if !field_visitor.fields.is_empty() {
-            for (key, value) in &field_visitor.fields {
-                write!(
-                    writer,
-                    ",\"{}\":\"{}\"",
-                    escape_json_string(key),
-                    escape_json_string(value)
-                )?;
+        // Add additional fields if present, then those of enclosing spans
+        let mut fields = field_visitor.fields;
+        for (key, value) in span_fields(ctx) {
+            if key != "message" && !fields.iter().any(|(existing, _)| *existing == key) {
+                fields.push((key, value));
             }
         }
+        for (key, value) in &fields {
+            write!(
+                writer,
+                ",\"{}\":\"{}\"",
+                escape_json_string(key),
+                escape_json_string(value)
+            )?;
+        }
 
         writeln!(writer, "}}")
     }
@@ -252,4 +321,90 @@ mod tests {
         assert!(visitor.message.is_empty());
         assert!(visitor.fields.is_empty());
     }
+
+    #[test]
+    fn test_parse_formatted_fields() {
+        let expected = vec![
+            ("netuid".to_string(), "12".to_string()),
+            ("hotkey".to_string(), "5Hot".to_string()),
+        ];
+        assert_eq!(parse_formatted_fields("netuid=12 hotkey=5Hot"), expected);
+        assert_eq!(
+            parse_formatted_fields(r#"{"netuid":12,"hotkey":"5Hot"}"#),
+            expected
+        );
+        assert!(parse_formatted_fields("").is_empty());
+    }
+
+    /// Writer collecting formatted output for assertions
+    #[derive(Clone, Default)]
+    struct Captured(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl Captured {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl std::io::Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for Captured {
+        type Writer = Captured;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn emit_in_context() {
+        let _subnet = crate::logging::subnet_span(12);
+        let _hotkey = crate::logging::hotkey_span("5Hot");
+        tracing::info!(uid = 3, "weights committed");
+    }
+
+    #[test]
+    fn test_text_formatter_prefixes_context() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let out = Captured::default();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .event_format(BittensorFormatter)
+                .with_writer(out.clone())
+                .with_ansi(false),
+        );
+        tracing::subscriber::with_default(subscriber, emit_in_context);
+
+        let line = out.contents();
+        assert!(line.contains("| [netuid=12 hotkey=5Hot] weights committed"));
+    }
+
+    #[test]
+    fn test_json_formatter_flattens_span_fields() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let out = Captured::default();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .fmt_fields(tracing_subscriber::fmt::format::JsonFields::new())
+                .event_format(JsonFormatter)
+                .with_writer(out.clone()),
+        );
+        tracing::subscriber::with_default(subscriber, emit_in_context);
+
+        let line: serde_json::Value = serde_json::from_str(out.contents().trim()).unwrap();
+        assert_eq!(line["message"], "weights committed");
+        assert_eq!(line["uid"], "3");
+        assert_eq!(line["netuid"], "12");
+        assert_eq!(line["hotkey"], "5Hot");
+    }
 }
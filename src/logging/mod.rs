@@ -30,22 +30,67 @@
 //! bt_warn!("Connection unstable");
 //! bt_error!(error = %e, "Failed to submit extrinsic");
 //! ```
+//!
+//! # Subnet and Hotkey Context
+//!
+//! Events inside a [`subnet_span`] or [`hotkey_span`] carry `netuid` and
+//! `hotkey` fields: flattened into JSON output, and as a `[netuid=12]`
+//! prefix in text output. The SDK's weight setting, CRv4 commits and
+//! metagraph sync open these spans themselves.
+//!
+//! ```rust,no_run
+//! let _subnet = bittensor_rs::logging::subnet_span(12);
+//! tracing::info!("Scoring miners"); // ... | [netuid=12] Scoring miners
+//! ```
+//!
+//! # Redacting Secrets
+//!
+//! With file logging on, output is passed through [`redact`], which replaces
+//! 64 and 128 character hex strings (the shape of seeds and private keys)
+//! with `[REDACTED]`. Use [`LoggingConfig::with_redact_secrets`] to turn it
+//! on for stdout-only logging or off entirely.
+//!
+//! # Runtime Filters
+//!
+//! The level filter can be changed after initialization, e.g. to debug a
+//! live validator without restarting it:
+//!
+//! ```rust,no_run
+//! use bittensor_rs::logging::{get_current_filter, set_filter, set_level};
+//! use tracing::Level;
+//!
+//! set_level(Level::DEBUG).unwrap();
+//! set_filter("info,bittensor_rs::axon=trace").unwrap();
+//! println!("{}", get_current_filter().unwrap());
+//! ```
 
 pub mod format;
+pub mod redact;
+pub mod rotation;
 
 use std::io;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Once;
+use std::sync::{Once, OnceLock};
 
+use thiserror::Error;
+use tracing::span::EnteredSpan;
 use tracing::Level;
 use tracing_appender::non_blocking::WorkerGuard;
 
+use tracing_subscriber::fmt::format::JsonFields;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
-use tracing_subscriber::{fmt, EnvFilter};
+use tracing_subscriber::{fmt, reload, EnvFilter, Registry};
 
 pub use format::{BittensorFormatter, CompactFormatter, JsonFormatter};
+pub use redact::{redact, RedactingMakeWriter};
+pub use rotation::{LogRotation, SizeRotatingFile};
+
+/// Name of the log file in `logging_dir`
+const LOG_FILE_NAME: &str = "bittensor.log";
 
 /// Static initialization guard to ensure logging is only initialized once
 static INIT: Once = Once::new();
@@ -54,7 +99,60 @@ static INIT: Once = Once::new();
 static INITIALIZED: AtomicBool = AtomicBool::new(false);
 
 /// Guard for non-blocking file writer (must be kept alive for duration of program)
-static mut FILE_GUARD: Option<WorkerGuard> = None;
+static FILE_GUARD: OnceLock<WorkerGuard> = OnceLock::new();
+
+/// Handle to the installed subscriber's filter
+static FILTER_HANDLE: OnceLock<FilterHandle> = OnceLock::new();
+
+/// Directives added to every level filter to quiet noisy HTTP dependencies
+const DEPENDENCY_DIRECTIVES: &str = "hyper=warn,reqwest=warn,h2=warn";
+
+/// Errors from changing the log filter at runtime
+#[derive(Debug, Error)]
+pub enum LoggingError {
+    #[error("Logging has not been initialized")]
+    NotInitialized,
+
+    #[error("Invalid log filter '{0}': {1}")]
+    InvalidFilter(String, String),
+
+    #[error("Failed to reload log filter: {0}")]
+    Reload(String),
+}
+
+/// Handle for replacing a subscriber's [`EnvFilter`] after it is installed
+#[derive(Clone)]
+pub struct FilterHandle(reload::Handle<EnvFilter, Registry>);
+
+impl FilterHandle {
+    /// Wrap `filter` in a reloadable layer, returning the layer to install
+    /// on a [`Registry`] and the handle that controls it
+    pub fn new(filter: EnvFilter) -> (reload::Layer<EnvFilter, Registry>, Self) {
+        let (layer, handle) = reload::Layer::new(filter);
+        (layer, Self(handle))
+    }
+
+    /// Show events at `level` and above, keeping dependencies at warn
+    pub fn set_level(&self, level: Level) -> Result<(), LoggingError> {
+        self.set_filter(&level_directives(level))
+    }
+
+    /// Replace the filter with `directives` in [`EnvFilter`] syntax
+    pub fn set_filter(&self, directives: &str) -> Result<(), LoggingError> {
+        let filter = EnvFilter::try_new(directives)
+            .map_err(|e| LoggingError::InvalidFilter(directives.to_string(), e.to_string()))?;
+        self.0
+            .reload(filter)
+            .map_err(|e| LoggingError::Reload(e.to_string()))
+    }
+
+    /// The active filter directives
+    pub fn current_filter(&self) -> Result<String, LoggingError> {
+        self.0
+            .with_current(|filter| filter.to_string())
+            .map_err(|e| LoggingError::Reload(e.to_string()))
+    }
+}
 
 /// Log output format
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -111,6 +209,14 @@ pub struct LoggingConfig {
     pub logging_dir: String,
     /// Output format for log messages
     pub format: LogFormat,
+    /// When the log file is rolled over
+    pub rotation: LogRotation,
+    /// Size in MB at which the log file rolls over with `LogRotation::Size`
+    pub max_file_size_mb: u64,
+    /// Number of rolled-over log files to keep; older files are deleted
+    pub max_files: usize,
+    /// Whether to scrub hex secrets from output (None: only with file logging)
+    pub redact_secrets: Option<bool>,
 }
 
 impl Default for LoggingConfig {
@@ -121,6 +227,10 @@ impl Default for LoggingConfig {
             record_log: false,
             logging_dir: "~/.bittensor/logs".to_string(),
             format: LogFormat::Text,
+            rotation: LogRotation::Daily,
+            max_file_size_mb: 100,
+            max_files: 10,
+            redact_secrets: None,
         }
     }
 }
@@ -161,6 +271,37 @@ impl LoggingConfig {
         self
     }
 
+    /// Set when the log file rolls over
+    pub fn with_rotation(mut self, rotation: LogRotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Set the size at which the log file rolls over with `LogRotation::Size`
+    pub fn with_max_file_size_mb(mut self, size_mb: u64) -> Self {
+        self.max_file_size_mb = size_mb;
+        self
+    }
+
+    /// Set how many rolled-over log files are kept
+    pub fn with_max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    /// Scrub hex secrets from all log output, or never
+    ///
+    /// By default output is only scrubbed when file logging is on.
+    pub fn with_redact_secrets(mut self, redact: bool) -> Self {
+        self.redact_secrets = Some(redact);
+        self
+    }
+
+    /// Whether log output is passed through [`redact`]
+    pub fn redacts_secrets(&self) -> bool {
+        self.redact_secrets.unwrap_or(self.record_log)
+    }
+
     /// Load configuration from environment variables
     ///
     /// Supported environment variables:
@@ -168,6 +309,12 @@ impl LoggingConfig {
     /// - `BITTENSOR_LOG_FORMAT`: Set format (text, json, compact)
     /// - `BITTENSOR_LOG_DIR`: Set logging directory
     /// - `BITTENSOR_DEBUG`: Enable debug mode (any value)
+    /// - `BITTENSOR_LOG_ROTATION`: Set rotation (daily, hourly, size)
+    /// - `BITTENSOR_LOG_MAX_SIZE_MB`: Set the rotation size; implies size
+    ///   rotation unless `BITTENSOR_LOG_ROTATION` is set
+    /// - `BITTENSOR_LOG_MAX_FILES`: Set how many rolled-over files are kept
+    /// - `BITTENSOR_LOG_REDACT`: Scrub hex secrets from output (`0` or
+    ///   `false` turns it off, even with file logging)
     /// - `RUST_LOG`: Standard tracing filter (takes precedence if set)
     pub fn from_env() -> Self {
         let mut config = Self::default();
@@ -191,6 +338,31 @@ impl LoggingConfig {
             config.record_log = true;
         }
 
+        if let Some(size_mb) = std::env::var("BITTENSOR_LOG_MAX_SIZE_MB")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            config.max_file_size_mb = size_mb;
+            config.rotation = LogRotation::Size;
+        }
+
+        if let Ok(rotation) = std::env::var("BITTENSOR_LOG_ROTATION") {
+            if let Ok(r) = rotation.parse() {
+                config.rotation = r;
+            }
+        }
+
+        if let Some(max_files) = std::env::var("BITTENSOR_LOG_MAX_FILES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            config.max_files = max_files;
+        }
+
+        if let Ok(redact) = std::env::var("BITTENSOR_LOG_REDACT") {
+            config.redact_secrets = Some(!matches!(redact.to_lowercase().as_str(), "0" | "false"));
+        }
+
         config
     }
 
@@ -205,6 +377,33 @@ impl LoggingConfig {
         }
     }
 
+    /// Non-blocking writer for the log file, rolled over per `rotation`
+    fn file_writer(
+        &self,
+        log_dir: &std::path::Path,
+    ) -> io::Result<(tracing_appender::non_blocking::NonBlocking, WorkerGuard)> {
+        use tracing_appender::rolling::{RollingFileAppender, Rotation};
+
+        let rotation = match self.rotation {
+            LogRotation::Daily => Rotation::DAILY,
+            LogRotation::Hourly => Rotation::HOURLY,
+            LogRotation::Size => {
+                let max_size = self.max_file_size_mb.saturating_mul(1024 * 1024);
+                let file = SizeRotatingFile::new(log_dir, LOG_FILE_NAME, max_size, self.max_files)?;
+                return Ok(tracing_appender::non_blocking(file));
+            }
+        };
+
+        // The appender counts the current file towards its limit
+        let appender = RollingFileAppender::builder()
+            .rotation(rotation)
+            .filename_prefix(LOG_FILE_NAME)
+            .max_log_files(self.max_files.saturating_add(1))
+            .build(log_dir)
+            .map_err(io::Error::other)?;
+        Ok(tracing_appender::non_blocking(appender))
+    }
+
     /// Expand ~ to home directory in paths
     fn expand_path(&self) -> PathBuf {
         let path = &self.logging_dir;
@@ -267,6 +466,63 @@ pub fn is_initialized() -> bool {
     INITIALIZED.load(Ordering::SeqCst)
 }
 
+/// Change the minimum level of the installed subscriber.
+///
+/// Replaces any per-module directives; use [`set_filter`] to keep them.
+pub fn set_level(level: Level) -> Result<(), LoggingError> {
+    filter_handle()?.set_level(level)
+}
+
+/// Replace the installed subscriber's filter, e.g.
+/// `"info,bittensor_rs::axon=debug"` (full [`EnvFilter`] syntax).
+pub fn set_filter(directives: &str) -> Result<(), LoggingError> {
+    filter_handle()?.set_filter(directives)
+}
+
+/// The installed subscriber's filter directives
+pub fn get_current_filter() -> Result<String, LoggingError> {
+    filter_handle()?.current_filter()
+}
+
+/// Handle to the filter installed by [`init_logging`]
+pub fn filter_handle() -> Result<&'static FilterHandle, LoggingError> {
+    FILTER_HANDLE.get().ok_or(LoggingError::NotInitialized)
+}
+
+/// Enter a span tagging every event inside it with `netuid`.
+///
+/// The span is created at ERROR level so the context is kept under any
+/// level filter. It is not `Send`; in async code instrument the future with
+/// `tracing::error_span!("subnet", netuid)` instead of holding it across
+/// `.await`.
+pub fn subnet_span(netuid: u16) -> EnteredSpan {
+    tracing::error_span!("subnet", netuid).entered()
+}
+
+/// Enter a span tagging every event inside it with `hotkey` (SS58).
+///
+/// See [`subnet_span`] for the level and async caveats.
+pub fn hotkey_span(ss58: &str) -> EnteredSpan {
+    tracing::error_span!("hotkey", hotkey = %ss58).entered()
+}
+
+/// `writer`, passed through [`redact`] if `redact_secrets` is set
+fn redacting_writer<M>(writer: M, redact_secrets: bool) -> BoxMakeWriter
+where
+    M: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    if redact_secrets {
+        BoxMakeWriter::new(RedactingMakeWriter::new(writer))
+    } else {
+        BoxMakeWriter::new(writer)
+    }
+}
+
+/// Filter directives for `level` with dependencies kept at warn
+fn level_directives(level: Level) -> String {
+    format!("{},{}", level, DEPENDENCY_DIRECTIVES)
+}
+
 /// Internal initialization logic
 fn init_logging_internal(config: &LoggingConfig) {
     // Build environment filter
@@ -274,33 +530,32 @@ fn init_logging_internal(config: &LoggingConfig) {
     let env_filter = if std::env::var("RUST_LOG").is_ok() {
         EnvFilter::from_default_env()
     } else {
-        let level = config.get_level();
-        EnvFilter::new(format!("{},hyper=warn,reqwest=warn,h2=warn", level))
+        EnvFilter::new(level_directives(config.get_level()))
     };
+    let (env_filter, handle) = FilterHandle::new(env_filter);
+    let _ = FILTER_HANDLE.set(handle);
 
     // Setup file appender if configured
     let file_appender = if config.record_log {
         let log_dir = config.expand_path();
-        if let Err(e) = std::fs::create_dir_all(&log_dir) {
-            eprintln!(
-                "Warning: Failed to create log directory {:?}: {}",
-                log_dir, e
-            );
-            None
-        } else {
-            let file_appender = tracing_appender::rolling::daily(&log_dir, "bittensor.log");
-            let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
-            // Store the guard to keep the writer alive
-            // SAFETY: This is only called once via Once::call_once, so there's no race condition
-            unsafe {
-                FILE_GUARD = Some(guard);
+        let writer = std::fs::create_dir_all(&log_dir).and_then(|_| config.file_writer(&log_dir));
+        match writer {
+            Ok((non_blocking, guard)) => {
+                // Store the guard to keep the writer alive
+                let _ = FILE_GUARD.set(guard);
+                Some(non_blocking)
+            }
+            Err(e) => {
+                eprintln!("Warning: Failed to open log file in {:?}: {}", log_dir, e);
+                None
             }
-            Some(non_blocking)
         }
     } else {
         None
     };
 
+    let redact = config.redacts_secrets();
+
     // Build and initialize the subscriber based on format
     match config.format {
         LogFormat::Text => {
@@ -310,12 +565,12 @@ fn init_logging_internal(config: &LoggingConfig) {
                     .with(
                         fmt::layer()
                             .event_format(BittensorFormatter)
-                            .with_writer(io::stdout),
+                            .with_writer(redacting_writer(io::stdout, redact)),
                     )
                     .with(
                         fmt::layer()
                             .event_format(BittensorFormatter)
-                            .with_writer(file_writer)
+                            .with_writer(redacting_writer(file_writer, redact))
                             .with_ansi(false),
                     );
                 subscriber.init();
@@ -323,7 +578,7 @@ fn init_logging_internal(config: &LoggingConfig) {
                 let subscriber = tracing_subscriber::registry().with(env_filter).with(
                     fmt::layer()
                         .event_format(BittensorFormatter)
-                        .with_writer(io::stdout),
+                        .with_writer(redacting_writer(io::stdout, redact)),
                 );
                 subscriber.init();
             }
@@ -332,18 +587,27 @@ fn init_logging_internal(config: &LoggingConfig) {
             if let Some(file_writer) = file_appender {
                 let subscriber = tracing_subscriber::registry()
                     .with(env_filter)
-                    .with(fmt::layer().json().with_writer(io::stdout))
                     .with(
                         fmt::layer()
-                            .json()
-                            .with_writer(file_writer)
+                            .fmt_fields(JsonFields::new())
+                            .event_format(JsonFormatter)
+                            .with_writer(redacting_writer(io::stdout, redact)),
+                    )
+                    .with(
+                        fmt::layer()
+                            .fmt_fields(JsonFields::new())
+                            .event_format(JsonFormatter)
+                            .with_writer(redacting_writer(file_writer, redact))
                             .with_ansi(false),
                     );
                 subscriber.init();
             } else {
-                let subscriber = tracing_subscriber::registry()
-                    .with(env_filter)
-                    .with(fmt::layer().json().with_writer(io::stdout));
+                let subscriber = tracing_subscriber::registry().with(env_filter).with(
+                    fmt::layer()
+                        .fmt_fields(JsonFields::new())
+                        .event_format(JsonFormatter)
+                        .with_writer(redacting_writer(io::stdout, redact)),
+                );
                 subscriber.init();
             }
         }
@@ -354,12 +618,12 @@ fn init_logging_internal(config: &LoggingConfig) {
                     .with(
                         fmt::layer()
                             .event_format(CompactFormatter)
-                            .with_writer(io::stdout),
+                            .with_writer(redacting_writer(io::stdout, redact)),
                     )
                     .with(
                         fmt::layer()
                             .event_format(CompactFormatter)
-                            .with_writer(file_writer)
+                            .with_writer(redacting_writer(file_writer, redact))
                             .with_ansi(false),
                     );
                 subscriber.init();
@@ -367,7 +631,7 @@ fn init_logging_internal(config: &LoggingConfig) {
                 let subscriber = tracing_subscriber::registry().with(env_filter).with(
                     fmt::layer()
                         .event_format(CompactFormatter)
-                        .with_writer(io::stdout),
+                        .with_writer(redacting_writer(io::stdout, redact)),
                 );
                 subscriber.init();
             }
@@ -473,6 +737,21 @@ mod tests {
         assert!(!config.record_log);
         assert_eq!(config.logging_dir, "~/.bittensor/logs");
         assert_eq!(config.format, LogFormat::Text);
+        assert!(!config.redacts_secrets());
+    }
+
+    #[test]
+    fn test_redact_secrets_follows_file_logging() {
+        assert!(LoggingConfig::new()
+            .with_file_logging(true)
+            .redacts_secrets());
+        assert!(!LoggingConfig::new()
+            .with_file_logging(true)
+            .with_redact_secrets(false)
+            .redacts_secrets());
+        assert!(LoggingConfig::new()
+            .with_redact_secrets(true)
+            .redacts_secrets());
     }
 
     #[test]
@@ -487,6 +766,22 @@ mod tests {
         assert_eq!(config.logging_dir, "/tmp/logs");
     }
 
+    #[test]
+    fn test_logging_config_rotation() {
+        let config = LoggingConfig::default();
+        assert_eq!(config.rotation, LogRotation::Daily);
+        assert_eq!(config.max_file_size_mb, 100);
+        assert_eq!(config.max_files, 10);
+
+        let config = LoggingConfig::new()
+            .with_rotation(LogRotation::Size)
+            .with_max_file_size_mb(5)
+            .with_max_files(2);
+        assert_eq!(config.rotation, LogRotation::Size);
+        assert_eq!(config.max_file_size_mb, 5);
+        assert_eq!(config.max_files, 2);
+    }
+
     #[test]
     fn test_log_format_display() {
         assert_eq!(format!("{}", LogFormat::Text), "text");
@@ -527,6 +822,94 @@ mod tests {
         assert!(!path.to_string_lossy().starts_with('~'));
     }
 
+    /// Counts the events that pass the filter in front of it
+    struct EventCounter(std::sync::Arc<std::sync::atomic::AtomicUsize>);
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for EventCounter {
+        fn on_event(
+            &self,
+            _event: &tracing::Event<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Dispatcher whose filter is controlled by the returned handle, and
+    /// the count of events it lets through
+    fn counting_dispatch(
+        filter: &str,
+    ) -> (
+        tracing::Dispatch,
+        FilterHandle,
+        std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    ) {
+        let count = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let (layer, handle) = FilterHandle::new(EnvFilter::new(filter));
+        let subscriber = tracing_subscriber::registry()
+            .with(layer)
+            .with(EventCounter(count.clone()));
+        (tracing::Dispatch::new(subscriber), handle, count)
+    }
+
+    fn emit_each_level() {
+        tracing::debug!("debug");
+        tracing::info!("info");
+        tracing::warn!("warn");
+    }
+
+    #[test]
+    fn test_set_level_at_runtime() {
+        let (dispatch, handle, count) = counting_dispatch("info");
+
+        // Reload while the dispatch is the default: with a single registered
+        // dispatch, tracing rebuilds callsite interest against the default
+        tracing::dispatcher::with_default(&dispatch, || {
+            emit_each_level();
+            assert_eq!(count.swap(0, Ordering::SeqCst), 2);
+
+            handle.set_level(Level::DEBUG).unwrap();
+            emit_each_level();
+            assert_eq!(count.swap(0, Ordering::SeqCst), 3);
+
+            handle.set_level(Level::WARN).unwrap();
+            emit_each_level();
+            assert_eq!(count.swap(0, Ordering::SeqCst), 1);
+        });
+
+        let current = handle.current_filter().unwrap();
+        assert!(current.contains("hyper=warn"));
+        assert!(!current.contains("debug"));
+    }
+
+    #[test]
+    fn test_set_filter_per_module() {
+        let (dispatch, handle, count) = counting_dispatch("warn");
+
+        handle
+            .set_filter("warn,bittensor_rs::logging=debug")
+            .unwrap();
+        tracing::dispatcher::with_default(&dispatch, || {
+            tracing::debug!("module debug");
+            tracing::debug!(target: "other_crate", "other debug");
+        });
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert!(handle
+            .current_filter()
+            .unwrap()
+            .contains("bittensor_rs::logging=debug"));
+
+        // An invalid filter leaves the current one in place
+        assert!(matches!(
+            handle.set_filter("bittensor_rs=loud"),
+            Err(LoggingError::InvalidFilter(_, _))
+        ));
+        assert!(handle
+            .current_filter()
+            .unwrap()
+            .contains("bittensor_rs::logging=debug"));
+    }
+
     #[test]
     fn test_expand_path_absolute() {
         let config = LoggingConfig::default().with_logging_dir("/var/log/bittensor");
@@ -0,0 +1,137 @@
+//! Scrubbing of key material from log output
+//!
+//! Seeds and private keys are 32 or 64 bytes, so in hex they are runs of 64
+//! or 128 characters. [`redact`] replaces any such run with `[REDACTED]`, and
+//! [`RedactingMakeWriter`] applies it to every event a formatting layer
+//! writes. Block and extrinsic hashes and public keys have the same shape and
+//! are scrubbed as well.
+
+use regex::Regex;
+use std::borrow::Cow;
+use std::io;
+use std::sync::OnceLock;
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Text written in place of a redacted value
+pub const REDACTED: &str = "[REDACTED]";
+
+fn secret_pattern() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\b(?:0x)?(?:[0-9a-fA-F]{128}|[0-9a-fA-F]{64})\b")
+            .expect("Secret pattern is valid")
+    })
+}
+
+/// Replace every 64 or 128 character hex string in `text` with `[REDACTED]`
+///
+/// An optional `0x` prefix is redacted with the string. Longer or shorter hex
+/// runs are left alone.
+pub fn redact(text: &str) -> Cow<'_, str> {
+    secret_pattern().replace_all(text, REDACTED)
+}
+
+/// [`MakeWriter`] whose writers pass output through [`redact`]
+///
+/// Formatting layers write each event with a single call, so a secret is
+/// never split across writes.
+#[derive(Debug, Clone)]
+pub struct RedactingMakeWriter<M> {
+    inner: M,
+}
+
+impl<M> RedactingMakeWriter<M> {
+    /// Redact everything written through `inner`
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for RedactingMakeWriter<M> {
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter(self.inner.make_writer())
+    }
+
+    fn make_writer_for(&'a self, meta: &tracing::Metadata<'_>) -> Self::Writer {
+        RedactingWriter(self.inner.make_writer_for(meta))
+    }
+}
+
+/// Writer created by [`RedactingMakeWriter`]
+#[derive(Debug)]
+pub struct RedactingWriter<W>(W);
+
+impl<W: io::Write> io::Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match std::str::from_utf8(buf) {
+            Ok(text) => self.0.write_all(redact(text).as_bytes())?,
+            Err(_) => self.0.write_all(buf)?,
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    const SEED: &str = "4ed8d4b17698ddeaa1f1559f152f87b5d472f725ca86d341bd0276f1b61197e2";
+
+    #[test]
+    fn test_redact_hex_secrets() {
+        assert_eq!(
+            redact(&format!("seed 0x{} loaded", SEED)),
+            "seed [REDACTED] loaded"
+        );
+        let private_key = format!("{}{}", SEED, SEED);
+        assert_eq!(redact(&format!("key={}", private_key)), "key=[REDACTED]");
+        assert_eq!(redact(&SEED.to_uppercase()), REDACTED);
+
+        // Other lengths and plain text are untouched
+        assert_eq!(redact(&SEED[..63]), &SEED[..63]);
+        assert_eq!(redact(&format!("{}0", SEED)), format!("{}0", SEED));
+        assert!(matches!(redact("netuid=1 uid=5"), Cow::Borrowed(_)));
+    }
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_layer_scrubs_events() {
+        let captured = Captured::default();
+        let output = captured.clone();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(RedactingMakeWriter::new(move || captured.clone())),
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(seed = %format!("0x{}", SEED), "Decrypted keyfile");
+        });
+
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("Decrypted keyfile"));
+        assert!(output.contains(REDACTED));
+        assert!(!output.contains(SEED));
+    }
+}
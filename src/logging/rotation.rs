@@ -0,0 +1,236 @@
+//! Log file rotation
+//!
+//! `tracing-appender` only rolls files by time. [`SizeRotatingFile`] rolls
+//! `bittensor.log` once it reaches a size limit: the current file becomes
+//! `bittensor.log.1`, older files shift to `.2`, `.3`, ... and files beyond
+//! the retention count are deleted.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use tracing_subscriber::fmt::MakeWriter;
+
+/// When log files are rolled over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogRotation {
+    /// A new file each day, suffixed with the date
+    #[default]
+    Daily,
+    /// A new file each hour, suffixed with the date and hour
+    Hourly,
+    /// A new file once the current one reaches `max_file_size_mb`
+    Size,
+}
+
+impl std::fmt::Display for LogRotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogRotation::Daily => write!(f, "daily"),
+            LogRotation::Hourly => write!(f, "hourly"),
+            LogRotation::Size => write!(f, "size"),
+        }
+    }
+}
+
+impl std::str::FromStr for LogRotation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "daily" => Ok(LogRotation::Daily),
+            "hourly" => Ok(LogRotation::Hourly),
+            "size" => Ok(LogRotation::Size),
+            _ => Err(format!(
+                "Invalid log rotation '{}'. Valid options: daily, hourly, size",
+                s
+            )),
+        }
+    }
+}
+
+/// Log file that rolls over when it would exceed a size limit
+///
+/// Clones share the same file, so it can be handed to
+/// `tracing_appender::non_blocking` or used directly as a [`MakeWriter`].
+#[derive(Clone)]
+pub struct SizeRotatingFile {
+    inner: Arc<Mutex<RotatingState>>,
+}
+
+struct RotatingState {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_size: u64,
+    max_files: usize,
+}
+
+impl SizeRotatingFile {
+    /// Open (or append to) `dir/file_name`
+    ///
+    /// # Arguments
+    /// * `max_size` - Size in bytes at which the file is rolled over
+    /// * `max_files` - Number of rolled files (`.1` to `.N`) to keep
+    pub fn new(dir: &Path, file_name: &str, max_size: u64, max_files: usize) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(file_name);
+        let file = open_append(&path)?;
+        let size = file.metadata()?.len();
+
+        Ok(Self {
+            inner: Arc::new(Mutex::new(RotatingState {
+                path,
+                file,
+                size,
+                max_size: max_size.max(1),
+                max_files,
+            })),
+        })
+    }
+
+    /// Path of the file currently written to
+    pub fn path(&self) -> PathBuf {
+        self.lock().path.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, RotatingState> {
+        // A panic while writing leaves the state usable
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl RotatingState {
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{}", index));
+        PathBuf::from(name)
+    }
+
+    /// Shift `.1..N-1` up by one, move the current file to `.1` and reopen
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        if self.max_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let oldest = self.rotated_path(self.max_files);
+            if oldest.exists() {
+                fs::remove_file(&oldest)?;
+            }
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(&from, self.rotated_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.rotated_path(1))?;
+        }
+
+        self.file = open_append(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for SizeRotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut state = self.lock();
+        // Roll before a write that would cross the limit, so each event
+        // stays whole in one file
+        if state.size > 0 && state.size + buf.len() as u64 > state.max_size {
+            state.rotate()?;
+        }
+        let written = state.file.write(buf)?;
+        state.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.lock().file.flush()
+    }
+}
+
+impl<'a> MakeWriter<'a> for SizeRotatingFile {
+    type Writer = SizeRotatingFile;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_log_rotation_from_str() {
+        assert_eq!("daily".parse::<LogRotation>().unwrap(), LogRotation::Daily);
+        assert_eq!(
+            "HOURLY".parse::<LogRotation>().unwrap(),
+            LogRotation::Hourly
+        );
+        assert_eq!("size".parse::<LogRotation>().unwrap(), LogRotation::Size);
+        assert!("weekly".parse::<LogRotation>().is_err());
+        assert_eq!(LogRotation::Size.to_string(), "size");
+    }
+
+    #[test]
+    fn test_size_rotation_and_retention() {
+        let dir = tempdir().unwrap();
+        let mut writer = SizeRotatingFile::new(dir.path(), "bittensor.log", 100, 3).unwrap();
+
+        // 40-byte lines: two fit per file, so 20 lines roll 9 times
+        let line = format!("{}\n", "x".repeat(39));
+        for _ in 0..20 {
+            writer.write_all(line.as_bytes()).unwrap();
+        }
+        writer.flush().unwrap();
+
+        let log = dir.path().join("bittensor.log");
+        assert_eq!(fs::metadata(&log).unwrap().len(), 80);
+        for index in 1..=3 {
+            let rotated = dir.path().join(format!("bittensor.log.{}", index));
+            assert_eq!(fs::metadata(&rotated).unwrap().len(), 80);
+        }
+        assert!(!dir.path().join("bittensor.log.4").exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 4);
+    }
+
+    #[test]
+    fn test_size_rotation_appends_to_existing_file() {
+        let dir = tempdir().unwrap();
+        let log = dir.path().join("bittensor.log");
+        fs::write(&log, "a".repeat(90)).unwrap();
+
+        let mut writer = SizeRotatingFile::new(dir.path(), "bittensor.log", 100, 1).unwrap();
+        writer.write_all(b"short\n").unwrap();
+        assert_eq!(fs::metadata(&log).unwrap().len(), 96);
+
+        writer.write_all(b"this line crosses the limit\n").unwrap();
+        assert_eq!(
+            fs::metadata(dir.path().join("bittensor.log.1"))
+                .unwrap()
+                .len(),
+            96
+        );
+        assert_eq!(fs::metadata(&log).unwrap().len(), 28);
+    }
+
+    #[test]
+    fn test_size_rotation_without_retention() {
+        let dir = tempdir().unwrap();
+        let mut writer = SizeRotatingFile::new(dir.path(), "bittensor.log", 10, 0).unwrap();
+        writer.write_all(b"0123456789").unwrap();
+        writer.write_all(b"abc").unwrap();
+
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+        assert_eq!(fs::read_to_string(writer.path()).unwrap(), "abc");
+    }
+}
@@ -0,0 +1,314 @@
+//! Changes between two metagraph syncs
+//!
+//! [`Metagraph::diff`] compares two snapshots of the same subnet UID by UID:
+//! a UID whose hotkey (or registration block) changed was deregistered and
+//! re-registered in between. Everything else is only reported for UIDs that
+//! kept their hotkey.
+
+use crate::metagraph::Metagraph;
+use crate::utils::balance_newtypes::Rao;
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::AccountId32;
+use std::net::SocketAddr;
+
+/// A hotkey that appeared on or disappeared from a UID
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UidRegistration {
+    pub uid: u64,
+    #[serde(with = "crate::utils::ss58::serde_account")]
+    pub hotkey: AccountId32,
+    /// Block the hotkey registered at (0 when unknown)
+    pub block_at_registration: u64,
+}
+
+/// Axon endpoint change for a UID that kept its hotkey
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AxonChange {
+    pub uid: u64,
+    #[serde(with = "crate::utils::ss58::serde_account")]
+    pub hotkey: AccountId32,
+    /// Previous endpoint, `None` if the axon was not served
+    pub old: Option<SocketAddr>,
+    /// New endpoint, `None` if the axon is no longer served
+    pub new: Option<SocketAddr>,
+}
+
+/// Validator permit gained or lost by a UID that kept its hotkey
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermitChange {
+    pub uid: u64,
+    #[serde(with = "crate::utils::ss58::serde_account")]
+    pub hotkey: AccountId32,
+    /// Permit state in the newer metagraph
+    pub validator_permit: bool,
+}
+
+/// Stake change for a UID that kept its hotkey
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StakeDelta {
+    pub uid: u64,
+    #[serde(with = "crate::utils::ss58::serde_account")]
+    pub hotkey: AccountId32,
+    pub old: Rao,
+    pub new: Rao,
+}
+
+impl StakeDelta {
+    /// Signed change in RAO
+    pub fn delta(&self) -> i128 {
+        self.new.as_u128() as i128 - self.old.as_u128() as i128
+    }
+}
+
+/// Differences between two metagraphs of the same subnet
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetagraphDiff {
+    pub netuid: u16,
+    /// Block of the older metagraph
+    pub from_block: u64,
+    /// Block of the newer metagraph
+    pub to_block: u64,
+    /// Hotkeys registered on a new or replaced UID
+    pub registered: Vec<UidRegistration>,
+    /// Hotkeys whose UID was removed or replaced
+    pub deregistered: Vec<UidRegistration>,
+    pub axon_changes: Vec<AxonChange>,
+    pub permit_changes: Vec<PermitChange>,
+    /// Stake changes at or above the diff threshold
+    pub stake_deltas: Vec<StakeDelta>,
+}
+
+impl MetagraphDiff {
+    /// True when nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.registered.is_empty()
+            && self.deregistered.is_empty()
+            && self.axon_changes.is_empty()
+            && self.permit_changes.is_empty()
+            && self.stake_deltas.is_empty()
+    }
+}
+
+impl Metagraph {
+    /// Changes from `self` (older) to `other` (newer), reporting every stake change
+    pub fn diff(&self, other: &Metagraph) -> MetagraphDiff {
+        self.diff_with_stake_threshold(other, Rao::ONE)
+    }
+
+    /// Changes from `self` (older) to `other` (newer)
+    ///
+    /// Only stake changes of at least `stake_threshold` (either direction)
+    /// are reported. Stake is the neuron's `stake` on this subnet.
+    pub fn diff_with_stake_threshold(
+        &self,
+        other: &Metagraph,
+        stake_threshold: Rao,
+    ) -> MetagraphDiff {
+        let mut diff = MetagraphDiff {
+            netuid: other.netuid,
+            from_block: self.block,
+            to_block: other.block,
+            ..Default::default()
+        };
+
+        let n = self.hotkeys.len().max(other.hotkeys.len());
+        for idx in 0..n {
+            let uid = idx as u64;
+            let old = self.hotkeys.get(idx);
+            let new = other.hotkeys.get(idx);
+            let old_block = self.registered_at(idx);
+            let new_block = other.registered_at(idx);
+
+            let replaced = match (old, new) {
+                (Some(old), Some(new)) => {
+                    old != new || (old_block != 0 && new_block != 0 && old_block != new_block)
+                }
+                _ => true,
+            };
+            if replaced {
+                if let Some(hotkey) = old {
+                    diff.deregistered.push(UidRegistration {
+                        uid,
+                        hotkey: hotkey.clone(),
+                        block_at_registration: old_block,
+                    });
+                }
+                if let Some(hotkey) = new {
+                    diff.registered.push(UidRegistration {
+                        uid,
+                        hotkey: hotkey.clone(),
+                        block_at_registration: new_block,
+                    });
+                }
+                continue;
+            }
+            let Some(hotkey) = new else { continue };
+
+            let old_axon = self.axon_endpoint(uid);
+            let new_axon = other.axon_endpoint(uid);
+            if old_axon != new_axon {
+                diff.axon_changes.push(AxonChange {
+                    uid,
+                    hotkey: hotkey.clone(),
+                    old: old_axon,
+                    new: new_axon,
+                });
+            }
+
+            let old_permit = self.validator_permit.get(idx).copied().unwrap_or(false);
+            let new_permit = other.validator_permit.get(idx).copied().unwrap_or(false);
+            if old_permit != new_permit {
+                diff.permit_changes.push(PermitChange {
+                    uid,
+                    hotkey: hotkey.clone(),
+                    validator_permit: new_permit,
+                });
+            }
+
+            let old_stake = self.neurons.get(&uid).map_or(Rao::ZERO, |n| n.stake);
+            let new_stake = other.neurons.get(&uid).map_or(Rao::ZERO, |n| n.stake);
+            let change = old_stake
+                .max(new_stake)
+                .saturating_sub(old_stake.min(new_stake));
+            if change > Rao::ZERO && change >= stake_threshold {
+                diff.stake_deltas.push(StakeDelta {
+                    uid,
+                    hotkey: hotkey.clone(),
+                    old: old_stake,
+                    new: new_stake,
+                });
+            }
+        }
+
+        diff
+    }
+
+    pub(super) fn registered_at(&self, idx: usize) -> u64 {
+        self.block_at_registration.get(idx).copied().unwrap_or(0)
+    }
+
+    /// Served axon endpoint; unserved axons report `0.0.0.0:0`
+    fn axon_endpoint(&self, uid: u64) -> Option<SocketAddr> {
+        self.axons
+            .get(&uid)
+            .filter(|axon| axon.port != 0 && !axon.ip.is_unspecified())
+            .map(|axon| SocketAddr::new(axon.ip, axon.port))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metagraph::test_neuron;
+    use crate::types::AxonInfo;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    fn account(seed: u8) -> AccountId32 {
+        AccountId32::new([seed; 32])
+    }
+
+    fn axon(ip: [u8; 4], port: u16) -> AxonInfo {
+        AxonInfo {
+            hotkey: None,
+            block: 0,
+            version: 0,
+            ip: IpAddr::V4(Ipv4Addr::from(ip)),
+            port,
+            ip_type: 4,
+            protocol: 4,
+            placeholder1: 0,
+            placeholder2: 0,
+        }
+    }
+
+    /// Metagraph with one neuron per `(hotkey seed, stake, registration block)`
+    fn metagraph(block: u64, neurons: &[(u8, u128, u64)]) -> Metagraph {
+        let mut metagraph = Metagraph::new(1);
+        metagraph.block = block;
+        metagraph.n = neurons.len() as u64;
+        for (uid, &(seed, stake, registered)) in neurons.iter().enumerate() {
+            let hotkey = account(seed);
+            metagraph.neurons.insert(
+                uid as u64,
+                test_neuron(uid as u64, &hotkey, Rao::from(stake)),
+            );
+            metagraph.hotkeys.push(hotkey.clone());
+            metagraph.coldkeys.push(hotkey);
+            metagraph.validator_permit.push(false);
+            metagraph.active.push(true);
+            metagraph.block_at_registration.push(registered);
+        }
+        metagraph
+    }
+
+    #[test]
+    fn test_diff_identical_is_empty() {
+        let a = metagraph(100, &[(1, 10, 5), (2, 20, 6)]);
+        let diff = a.diff(&a.clone());
+        assert!(diff.is_empty());
+        assert_eq!((diff.from_block, diff.to_block), (100, 100));
+    }
+
+    #[test]
+    fn test_diff_registrations() {
+        let old = metagraph(100, &[(1, 10, 5), (2, 20, 6)]);
+        let new = metagraph(200, &[(1, 10, 5), (3, 0, 150), (4, 0, 160)]);
+        let diff = old.diff(&new);
+
+        assert_eq!(
+            diff.deregistered,
+            vec![UidRegistration {
+                uid: 1,
+                hotkey: account(2),
+                block_at_registration: 6,
+            }]
+        );
+        let registered: Vec<(u64, u64)> = diff
+            .registered
+            .iter()
+            .map(|r| (r.uid, r.block_at_registration))
+            .collect();
+        assert_eq!(registered, vec![(1, 150), (2, 160)]);
+        // The replaced UID's stake drop is not a stake delta
+        assert!(diff.stake_deltas.is_empty());
+    }
+
+    #[test]
+    fn test_diff_same_hotkey_reregistered() {
+        let old = metagraph(100, &[(1, 10, 5)]);
+        let new = metagraph(200, &[(1, 0, 150)]);
+        let diff = old.diff(&new);
+        assert_eq!(diff.deregistered.len(), 1);
+        assert_eq!(diff.registered.len(), 1);
+    }
+
+    #[test]
+    fn test_diff_axon_permit_and_stake() {
+        let mut old = metagraph(100, &[(1, 1_000, 5), (2, 1_000, 6)]);
+        let mut new = metagraph(200, &[(1, 1_500, 5), (2, 1_010, 6)]);
+        old.axons.insert(0, axon([1, 2, 3, 4], 8091));
+        new.axons.insert(0, axon([1, 2, 3, 4], 8092));
+        new.axons.insert(1, axon([0, 0, 0, 0], 0));
+        new.validator_permit[1] = true;
+
+        let diff = old.diff_with_stake_threshold(&new, Rao::from(100u128));
+        assert_eq!(diff.axon_changes.len(), 1);
+        assert_eq!(diff.axon_changes[0].new.unwrap().port(), 8092);
+        assert_eq!(diff.permit_changes.len(), 1);
+        assert!(diff.permit_changes[0].validator_permit);
+        assert_eq!(diff.stake_deltas.len(), 1);
+        assert_eq!(diff.stake_deltas[0].uid, 0);
+        assert_eq!(diff.stake_deltas[0].delta(), 500);
+
+        assert_eq!(old.diff(&new).stake_deltas.len(), 2);
+    }
+
+    #[test]
+    fn test_diff_serializes_to_json() {
+        let old = metagraph(100, &[(1, 10, 5)]);
+        let new = metagraph(200, &[(2, 10, 150)]);
+        let json = serde_json::to_value(old.diff(&new)).unwrap();
+        assert_eq!(json["registered"][0]["block_at_registration"], 150);
+        assert_eq!(json["to_block"], 200);
+    }
+}
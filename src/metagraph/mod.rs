@@ -1,5 +1,30 @@
+pub mod diff;
 pub mod sync;
+pub mod syncer;
 pub mod types;
+pub mod view;
 
-pub use sync::sync_metagraph;
-pub use types::Metagraph;
+pub use diff::{AxonChange, MetagraphDiff, PermitChange, StakeDelta, UidRegistration};
+pub use sync::{
+    sync_metagraph, sync_metagraph_with, sync_metagraph_with_options, SyncOptions, SyncProgressFn,
+    SyncStage,
+};
+pub use syncer::MetagraphSyncer;
+pub use types::{Metagraph, SyncWarning};
+pub use view::NeuronView;
+
+/// An active neuron on subnet 1 whose coldkey is its hotkey, for tests
+#[cfg(test)]
+pub(crate) fn test_neuron(
+    uid: u64,
+    hotkey: &sp_core::crypto::AccountId32,
+    stake: crate::utils::balance_newtypes::Rao,
+) -> crate::types::NeuronInfo {
+    crate::types::NeuronInfo {
+        coldkey: hotkey.clone(),
+        stake,
+        total_stake: stake,
+        active: true,
+        ..sync::empty_neuron(uid, 1, hotkey.clone())
+    }
+}
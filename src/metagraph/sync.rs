@@ -1,12 +1,158 @@
-use crate::chain::BittensorClient;
-use crate::metagraph::Metagraph;
-use crate::queries::neurons;
-use crate::types::{AxonInfo, PrometheusInfo};
+use crate::chain::{is_message_too_large_error, BittensorClient, ChainBackend};
+use crate::crv4::get_mechid_storage_index;
+use crate::metagraph::{Metagraph, SyncWarning};
+use crate::queries::{neurons, subnets};
+use crate::types::{AxonInfo, NeuronInfo, PrometheusInfo, SubnetStateInfo};
+use crate::utils::balance_newtypes::Rao;
+use crate::utils::decoders::{
+    decode_account_id32, decode_axon_info, decode_bool, decode_u128, decode_u16, decode_u64,
+    decode_vec, decode_vec_u16_u16_pairs,
+};
+use crate::utils::ss58::encode_ss58;
 use anyhow::{Context, Result};
+use futures::stream::{FuturesUnordered, StreamExt};
+use parity_scale_codec::Encode;
+use sp_core::crypto::AccountId32;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 use subxt::dynamic::Value;
 
+const SUBTENSOR_MODULE: &str = "SubtensorModule";
+
+/// Pause before a stage retries its failed fetches, multiplied by the attempt
+const STAGE_RETRY_DELAY: Duration = Duration::from_millis(250);
+
+/// Stage of a metagraph sync, reported to the progress callback
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyncStage {
+    /// Hotkey of each UID
+    Hotkeys,
+    /// Per-subnet vectors such as rank, trust and incentive
+    Subnet,
+    /// Owning coldkey of each hotkey
+    Coldkeys,
+    /// Subnet and root stake of each hotkey
+    Stake,
+    /// Axon of each hotkey, with [`SyncOptions::with_axons`]
+    Axons,
+    /// Weights set by each UID, with [`SyncOptions::with_weights`]
+    Weights,
+    /// Registration block of each UID
+    Registration,
+}
+
+impl SyncStage {
+    /// All stages, in the order they run
+    pub const ALL: [SyncStage; 7] = [
+        SyncStage::Hotkeys,
+        SyncStage::Subnet,
+        SyncStage::Coldkeys,
+        SyncStage::Stake,
+        SyncStage::Axons,
+        SyncStage::Weights,
+        SyncStage::Registration,
+    ];
+
+    /// Lowercase name of the stage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyncStage::Hotkeys => "hotkeys",
+            SyncStage::Subnet => "subnet",
+            SyncStage::Coldkeys => "coldkeys",
+            SyncStage::Stake => "stake",
+            SyncStage::Axons => "axons",
+            SyncStage::Weights => "weights",
+            SyncStage::Registration => "registration",
+        }
+    }
+}
+
+impl fmt::Display for SyncStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Progress callback taking the stage and its completed and total fetches
+pub type SyncProgressFn = Arc<dyn Fn(SyncStage, u64, u64) + Send + Sync>;
+
+/// Options for [`sync_metagraph_with_options`]
+#[derive(Clone, Default)]
+pub struct SyncOptions {
+    /// Record failed fetches in [`Metagraph::sync_warnings`] instead of
+    /// failing the sync
+    pub allow_partial: bool,
+    /// How many times a stage retries the fetches that failed
+    pub max_retries_per_stage: u32,
+    /// Read each neuron's axon
+    pub axons: bool,
+    /// Read the weights set by each UID
+    pub weights: bool,
+    /// Called when a stage starts and as each of its fetches completes
+    pub on_progress: Option<SyncProgressFn>,
+}
+
+impl fmt::Debug for SyncOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyncOptions")
+            .field("allow_partial", &self.allow_partial)
+            .field("max_retries_per_stage", &self.max_retries_per_stage)
+            .field("axons", &self.axons)
+            .field("weights", &self.weights)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+impl SyncOptions {
+    /// Strict sync of everything but axons and weights, without retries or
+    /// progress reporting
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep syncing when individual fetches fail
+    pub fn with_allow_partial(mut self, allow_partial: bool) -> Self {
+        self.allow_partial = allow_partial;
+        self
+    }
+
+    /// Set how many times each stage retries its failed fetches
+    pub fn with_max_retries_per_stage(mut self, retries: u32) -> Self {
+        self.max_retries_per_stage = retries;
+        self
+    }
+
+    /// Read each neuron's axon in the [`SyncStage::Axons`] stage
+    pub fn with_axons(mut self, axons: bool) -> Self {
+        self.axons = axons;
+        self
+    }
+
+    /// Read the weights set by each UID in the [`SyncStage::Weights`] stage
+    pub fn with_weights(mut self, weights: bool) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Report progress as `on_progress(stage, completed, total)`
+    pub fn with_progress<F>(mut self, on_progress: F) -> Self
+    where
+        F: Fn(SyncStage, u64, u64) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+}
+
 /// Synchronize metagraph data from the chain
-pub async fn sync_metagraph(client: &BittensorClient, netuid: u16) -> Result<Metagraph> {
+///
+/// See [`sync_metagraph_with_options`] for retries, partial results and
+/// progress reporting.
+#[tracing::instrument(level = "error", skip_all, fields(netuid = netuid))]
+pub async fn sync_metagraph(client: &impl ChainBackend, netuid: u16) -> Result<Metagraph> {
     let mut metagraph = Metagraph::new(netuid);
 
     // Get current block
@@ -25,6 +171,10 @@ pub async fn sync_metagraph(client: &BittensorClient, netuid: u16) -> Result<Met
     metagraph.coldkeys = Vec::with_capacity(neurons_list.len());
     metagraph.validator_permit = Vec::with_capacity(neurons_list.len());
     metagraph.active = Vec::with_capacity(neurons_list.len());
+    metagraph.block_at_registration =
+        neurons::get_blocks_at_registration(client, netuid, metagraph.n)
+            .await
+            .context("Failed to query registration blocks")?;
 
     for neuron in neurons_list {
         metagraph.hotkeys.push(neuron.hotkey.clone());
@@ -39,16 +189,655 @@ pub async fn sync_metagraph(client: &BittensorClient, netuid: u16) -> Result<Met
             metagraph.axons.insert(neuron.uid, axon.clone());
         }
     }
+    metagraph.rebuild_hotkey_index();
+
+    Ok(metagraph)
+}
+
+/// Synchronize metagraph data from storage, stage by stage
+///
+/// Each stage reads one field for every UID (see [`SyncStage`]) and retries
+/// the reads that failed up to `options.max_retries_per_stage` times. Reads
+/// still failing then fail the sync, or with `options.allow_partial` are
+/// recorded in [`Metagraph::sync_warnings`] and leave the field as it was in
+/// `previous`, or at its default. A UID whose hotkey cannot be read keeps its
+/// previous neuron, or is left out when there is none. The subnet size is
+/// always required. Axons and weights are only read when enabled in
+/// `options`; otherwise neurons kept from `previous` keep theirs.
+///
+/// # Arguments
+///
+/// * `client` - The chain client
+/// * `netuid` - The subnet to sync
+/// * `previous` - An earlier sync of the subnet, whose values are kept for
+///   failed reads
+/// * `options` - Retry, partial result and progress options
+#[tracing::instrument(level = "error", skip_all, fields(netuid = netuid))]
+pub async fn sync_metagraph_with_options(
+    client: &impl ChainBackend,
+    netuid: u16,
+    previous: Option<&Metagraph>,
+    options: &SyncOptions,
+) -> Result<Metagraph> {
+    let previous = previous.filter(|p| p.netuid == netuid);
+    let block = client.block_number().await?;
+
+    let netuid_key = vec![Value::u128(netuid as u128)];
+    let n_value = client
+        .storage_with_keys(SUBTENSOR_MODULE, "SubnetworkN", netuid_key.clone())
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Subnet {} not found", netuid))?;
+    let n = decode_u64(&n_value).context("Failed to decode SubnetworkN")?;
+
+    let mut stages = StageRunner::new(client, options);
+    let uid_key = |uid: u64| vec![Value::u128(netuid as u128), Value::u128(uid as u128)];
+
+    // Hotkeys decide which previous neurons can be kept
+    let fetches = (0..n)
+        .map(|uid| Fetch::new(Some(uid), "hotkey", "Keys", uid_key(uid)))
+        .collect::<Vec<_>>();
+    let results = stages.run(SyncStage::Hotkeys, &fetches).await?;
+    let mut synced = BTreeMap::new();
+    for (fetch, result) in fetches.iter().zip(results) {
+        let uid = fetch.uid.unwrap_or_default();
+        let previous = previous.and_then(|p| p.neurons.get(&uid));
+        let hotkey = match result {
+            Fetched::Value(value) => stages.decode(fetch, &value, decode_account_id32)?,
+            // Not registered
+            Fetched::Empty => continue,
+            Fetched::Failed => None,
+        };
+        let neuron = match (hotkey, previous) {
+            (Some(hotkey), Some(prev)) if prev.hotkey == hotkey => prev.clone(),
+            (Some(hotkey), _) => empty_neuron(uid, netuid, hotkey),
+            // Unreadable hotkey: nothing to identify a new neuron by
+            (None, Some(prev)) => prev.clone(),
+            (None, None) => continue,
+        };
+        synced.insert(uid, neuron);
+    }
+
+    // Per-subnet vectors, indexed by UID
+    let fetches = SUBNET_VECTORS
+        .iter()
+        .map(|(field, entry)| Fetch::new(None, field, entry, netuid_key.clone()))
+        .collect::<Vec<_>>();
+    let results = stages.run(SyncStage::Subnet, &fetches).await?;
+    let norm = |v: u16| v as f64 / u16::MAX as f64;
+    for (fetch, result) in fetches.iter().zip(results) {
+        match fetch.field {
+            "rank" => stages.apply_vector(fetch, result, &mut synced, decode_u16, |n, v| {
+                n.rank = norm(v)
+            })?,
+            "trust" => stages.apply_vector(fetch, result, &mut synced, decode_u16, |n, v| {
+                n.trust = norm(v)
+            })?,
+            "consensus" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_u16, |n, v| {
+                    n.consensus = norm(v)
+                })?
+            }
+            "validator_trust" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_u16, |n, v| {
+                    n.validator_trust = norm(v)
+                })?
+            }
+            "incentive" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_u16, |n, v| {
+                    n.incentive = norm(v)
+                })?
+            }
+            "dividends" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_u16, |n, v| {
+                    n.dividends = norm(v)
+                })?
+            }
+            "active" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_bool, |n, v| n.active = v)?
+            }
+            "last_update" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_u64, |n, v| {
+                    n.last_update = v
+                })?
+            }
+            "emission" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_u128, |n, v| {
+                    n.emission = Rao::from(v)
+                })?
+            }
+            "validator_permit" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_bool, |n, v| {
+                    n.validator_permit = v
+                })?
+            }
+            "pruning_score" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_u16, |n, v| {
+                    n.pruning_score = v as u64
+                })?
+            }
+            "stake_weight" => {
+                stages.apply_vector(fetch, result, &mut synced, decode_u16, |n, v| {
+                    n.stake_weight = v
+                })?
+            }
+            field => unreachable!("Unknown subnet vector {}", field),
+        }
+    }
+
+    let hotkey_key = |neuron: &NeuronInfo| Value::from_bytes(neuron.hotkey.encode());
+
+    let fetches = hotkey_fetches(&synced, "coldkey", "Owner", |neuron| {
+        vec![hotkey_key(neuron)]
+    });
+    let results = stages.run(SyncStage::Coldkeys, &fetches).await?;
+    for (fetch, result) in fetches.iter().zip(results) {
+        stages.apply(
+            fetch,
+            result,
+            &mut synced,
+            decode_account_id32,
+            |n, coldkey| n.coldkey = coldkey.unwrap_or_else(|| AccountId32::new([0u8; 32])),
+        )?;
+    }
+
+    let mut fetches = hotkey_fetches(&synced, "stake", "TotalHotkeyAlpha", |neuron| {
+        vec![hotkey_key(neuron), Value::u128(netuid as u128)]
+    });
+    fetches.extend(hotkey_fetches(
+        &synced,
+        "root_stake",
+        "TotalHotkeyAlpha",
+        |neuron| vec![hotkey_key(neuron), Value::u128(0)],
+    ));
+    let results = stages.run(SyncStage::Stake, &fetches).await?;
+    for (fetch, result) in fetches.iter().zip(results) {
+        if fetch.field == "stake" {
+            stages.apply(fetch, result, &mut synced, decode_u128, |n, stake| {
+                n.stake = Rao::from(stake.unwrap_or(0));
+                n.total_stake = n.stake;
+            })?;
+        } else {
+            stages.apply(fetch, result, &mut synced, decode_u128, |n, stake| {
+                n.root_stake = Rao::from(stake.unwrap_or(0))
+            })?;
+        }
+    }
+    // Consensus stakes include parent inheritance; storage values remain if
+    // the runtime API is unavailable
+    if let Ok((alpha_stakes, _tao_stakes, total_stakes)) =
+        neurons::get_stake_weights_for_subnet(client, netuid).await
+    {
+        for neuron in synced.values_mut() {
+            let idx = neuron.uid as usize;
+            if let Some(&alpha) = alpha_stakes.get(idx) {
+                neuron.stake = Rao::from(alpha);
+                neuron.total_stake = Rao::from(alpha);
+            }
+            if let Some(&total) = total_stakes.get(idx) {
+                neuron.total_stake = Rao::from(total);
+            }
+        }
+    }
+
+    if options.axons {
+        let fetches = hotkey_fetches(&synced, "axon_info", "Axons", |neuron| {
+            vec![Value::u128(netuid as u128), hotkey_key(neuron)]
+        });
+        let results = stages.run(SyncStage::Axons, &fetches).await?;
+        for (fetch, result) in fetches.iter().zip(results) {
+            stages.apply(fetch, result, &mut synced, decode_axon_info, |n, axon| {
+                n.axon_info = axon.map(|mut axon| {
+                    axon.hotkey = Some(encode_ss58(&n.hotkey));
+                    axon
+                })
+            })?;
+        }
+    }
+
+    if options.weights {
+        let storage_index = get_mechid_storage_index(netuid, 0);
+        let fetches = synced
+            .keys()
+            .map(|&uid| {
+                let keys = vec![Value::u128(storage_index as u128), Value::u128(uid as u128)];
+                Fetch::new(Some(uid), "weights", "Weights", keys)
+            })
+            .collect::<Vec<_>>();
+        let results = stages.run(SyncStage::Weights, &fetches).await?;
+        for (fetch, result) in fetches.iter().zip(results) {
+            stages.apply(
+                fetch,
+                result,
+                &mut synced,
+                decode_vec_u16_u16_pairs,
+                |n, w| {
+                    n.weights = w
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|(uid, weight)| (uid as u64, weight as u64))
+                        .collect()
+                },
+            )?;
+        }
+    }
+
+    let fetches = (0..n)
+        .map(|uid| {
+            Fetch::new(
+                Some(uid),
+                "block_at_registration",
+                "BlockAtRegistration",
+                uid_key(uid),
+            )
+        })
+        .collect::<Vec<_>>();
+    let results = stages.run(SyncStage::Registration, &fetches).await?;
+    let mut block_at_registration = Vec::with_capacity(n as usize);
+    for (fetch, result) in fetches.iter().zip(results) {
+        let uid = fetch.uid.unwrap_or_default();
+        let kept = previous
+            .and_then(|p| p.block_at_registration.get(uid as usize).copied())
+            .unwrap_or(0);
+        let registered_at = match result {
+            Fetched::Value(value) => stages.decode(fetch, &value, decode_u64)?.unwrap_or(kept),
+            Fetched::Empty => 0,
+            Fetched::Failed => kept,
+        };
+        block_at_registration.push(registered_at);
+    }
+
+    let mut metagraph = Metagraph::new(netuid);
+    metagraph.block = block;
+    metagraph.n = synced.len() as u64;
+    metagraph.block_at_registration = block_at_registration;
+    for (uid, neuron) in synced {
+        metagraph.hotkeys.push(neuron.hotkey.clone());
+        metagraph.coldkeys.push(neuron.coldkey.clone());
+        metagraph.validator_permit.push(neuron.validator_permit);
+        metagraph.active.push(neuron.active);
+        if let Some(ref axon) = neuron.axon_info {
+            metagraph.axons.insert(uid, axon.clone());
+        }
+        metagraph.neurons.insert(uid, neuron);
+    }
+    metagraph.sync_warnings = stages.warnings;
+    metagraph.rebuild_hotkey_index();
 
     Ok(metagraph)
 }
 
+/// Per-subnet vectors read by the subnet stage, as (field, storage entry)
+const SUBNET_VECTORS: [(&str, &str); 12] = [
+    ("rank", "Rank"),
+    ("trust", "Trust"),
+    ("consensus", "Consensus"),
+    ("validator_trust", "ValidatorTrust"),
+    ("incentive", "Incentive"),
+    ("dividends", "Dividends"),
+    ("active", "Active"),
+    ("last_update", "LastUpdate"),
+    ("emission", "Emission"),
+    ("validator_permit", "ValidatorPermit"),
+    ("pruning_score", "PruningScores"),
+    ("stake_weight", "StakeWeight"),
+];
+
+/// Per-hotkey reads for every neuron whose hotkey is known
+fn hotkey_fetches(
+    neurons: &BTreeMap<u64, NeuronInfo>,
+    field: &'static str,
+    entry: &'static str,
+    keys: impl Fn(&NeuronInfo) -> Vec<Value>,
+) -> Vec<Fetch> {
+    neurons
+        .values()
+        .filter(|neuron| !neuron.is_null)
+        .map(|neuron| Fetch::new(Some(neuron.uid), field, entry, keys(neuron)))
+        .collect()
+}
+
+/// A storage read made by a sync stage
+struct Fetch {
+    uid: Option<u64>,
+    field: &'static str,
+    entry: &'static str,
+    keys: Vec<Value>,
+}
+
+impl Fetch {
+    fn new(uid: Option<u64>, field: &'static str, entry: &'static str, keys: Vec<Value>) -> Self {
+        Self {
+            uid,
+            field,
+            entry,
+            keys,
+        }
+    }
+}
+
+/// Outcome of a [`Fetch`]
+#[derive(Clone)]
+enum Fetched {
+    Value(Value),
+    /// The storage entry is not set
+    Empty,
+    /// The read failed and was recorded as a warning
+    Failed,
+}
+
+/// Runs the reads of each stage and collects warnings
+struct StageRunner<'a, C> {
+    client: &'a C,
+    options: &'a SyncOptions,
+    warnings: Vec<SyncWarning>,
+}
+
+impl<'a, C: ChainBackend> StageRunner<'a, C> {
+    fn new(client: &'a C, options: &'a SyncOptions) -> Self {
+        Self {
+            client,
+            options,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn progress(&self, stage: SyncStage, completed: u64, total: u64) {
+        if let Some(on_progress) = &self.options.on_progress {
+            on_progress(stage, completed, total);
+        }
+    }
+
+    /// Read every fetch, retrying failures, in the order given
+    async fn run(&mut self, stage: SyncStage, fetches: &[Fetch]) -> Result<Vec<Fetched>> {
+        let total = fetches.len() as u64;
+        let mut completed = 0;
+        let mut results = vec![Fetched::Failed; fetches.len()];
+        let mut pending: Vec<usize> = (0..fetches.len()).collect();
+        let mut attempt = 0;
+        self.progress(stage, 0, total);
+
+        while !pending.is_empty() {
+            if attempt > 0 {
+                tokio::time::sleep(STAGE_RETRY_DELAY * attempt).await;
+            }
+            let client = self.client;
+            let mut reads = pending
+                .iter()
+                .map(|&i| {
+                    let fetch = &fetches[i];
+                    async move {
+                        let result = client
+                            .storage_with_keys(SUBTENSOR_MODULE, fetch.entry, fetch.keys.clone())
+                            .await;
+                        (i, result)
+                    }
+                })
+                .collect::<FuturesUnordered<_>>();
+
+            let mut failed = Vec::new();
+            while let Some((i, result)) = reads.next().await {
+                match result {
+                    Ok(value) => {
+                        results[i] = value.map_or(Fetched::Empty, Fetched::Value);
+                        completed += 1;
+                        self.progress(stage, completed, total);
+                    }
+                    Err(e) => failed.push((i, e)),
+                }
+            }
+            drop(reads);
+
+            if attempt >= self.options.max_retries_per_stage {
+                for (i, e) in failed {
+                    self.fail(&fetches[i], e.to_string())?;
+                    completed += 1;
+                    self.progress(stage, completed, total);
+                }
+                break;
+            }
+            attempt += 1;
+            pending = failed.into_iter().map(|(i, _)| i).collect();
+        }
+
+        Ok(results)
+    }
+
+    /// Record a failed fetch, or fail the sync unless partial results are
+    /// allowed
+    fn fail(&mut self, fetch: &Fetch, error: String) -> Result<()> {
+        let target = match fetch.uid {
+            Some(uid) => format!("{} of UID {}", fetch.field, uid),
+            None => fetch.field.to_string(),
+        };
+        if !self.options.allow_partial {
+            anyhow::bail!("Failed to sync {}: {}", target, error);
+        }
+        tracing::warn!(
+            "Failed to sync {}, keeping previous value: {}",
+            target,
+            error
+        );
+        self.warnings.push(SyncWarning {
+            uid: fetch.uid,
+            field: fetch.field,
+            error,
+        });
+        Ok(())
+    }
+
+    /// Decode a fetched value, treating a decode error like a failed read
+    fn decode<T>(
+        &mut self,
+        fetch: &Fetch,
+        value: &Value,
+        decoder: impl Fn(&Value) -> Result<T>,
+    ) -> Result<Option<T>> {
+        match decoder(value) {
+            Ok(decoded) => Ok(Some(decoded)),
+            Err(e) => self.fail(fetch, format!("{:#}", e)).map(|_| None),
+        }
+    }
+
+    /// Set a per-UID field from its fetch; unset storage passes None
+    fn apply<T>(
+        &mut self,
+        fetch: &Fetch,
+        result: Fetched,
+        neurons: &mut BTreeMap<u64, NeuronInfo>,
+        decoder: fn(&Value) -> Result<T>,
+        set: impl FnOnce(&mut NeuronInfo, Option<T>),
+    ) -> Result<()> {
+        let value = match result {
+            Fetched::Value(value) => match self.decode(fetch, &value, decoder)? {
+                Some(decoded) => Some(decoded),
+                None => return Ok(()),
+            },
+            Fetched::Empty => None,
+            Fetched::Failed => return Ok(()),
+        };
+        if let Some(neuron) = fetch.uid.and_then(|uid| neurons.get_mut(&uid)) {
+            set(neuron, value);
+        }
+        Ok(())
+    }
+
+    /// Set a field of every neuron from a per-subnet vector
+    fn apply_vector<T: Copy + Default>(
+        &mut self,
+        fetch: &Fetch,
+        result: Fetched,
+        neurons: &mut BTreeMap<u64, NeuronInfo>,
+        decoder: fn(&Value) -> Result<T>,
+        set: impl Fn(&mut NeuronInfo, T),
+    ) -> Result<()> {
+        let values = match result {
+            Fetched::Value(value) => {
+                match self.decode(fetch, &value, |v| decode_vec(v, decoder))? {
+                    Some(values) => values,
+                    None => return Ok(()),
+                }
+            }
+            Fetched::Empty => Vec::new(),
+            Fetched::Failed => return Ok(()),
+        };
+        for (uid, neuron) in neurons.iter_mut() {
+            set(
+                neuron,
+                values.get(*uid as usize).copied().unwrap_or_default(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// A neuron with only its identity set
+pub(crate) fn empty_neuron(uid: u64, netuid: u16, hotkey: AccountId32) -> NeuronInfo {
+    NeuronInfo {
+        uid,
+        netuid,
+        hotkey,
+        coldkey: AccountId32::new([0u8; 32]),
+        stake: Rao::ZERO,
+        stake_dict: HashMap::new(),
+        total_stake: Rao::ZERO,
+        root_stake: Rao::ZERO,
+        stake_weight: 0,
+        rank: 0.0,
+        trust: 0.0,
+        consensus: 0.0,
+        validator_trust: 0.0,
+        incentive: 0.0,
+        emission: Rao::ZERO,
+        dividends: 0.0,
+        active: false,
+        last_update: 0,
+        validator_permit: false,
+        version: 0,
+        weights: Vec::new(),
+        bonds: Vec::new(),
+        pruning_score: 0,
+        prometheus_info: None,
+        axon_info: None,
+        is_null: false,
+    }
+}
+
+/// Synchronize metagraph data, optionally in lite mode
+///
+/// Lite mode reads all per-UID data with a single `get_subnet_state` runtime
+/// call when the runtime supports it (see
+/// [`subnets::SUBNET_STATE_MIN_SPEC_VERSION`]). Lite metagraphs have no axon,
+/// prometheus, weight or bond data. Older runtimes, or a failed runtime call,
+/// fall back to the storage-based [`sync_metagraph`]. This includes a
+/// `get_subnet_state` response over the client's message size limit: the
+/// per-field storage queries are each far smaller.
+#[tracing::instrument(level = "error", skip_all, fields(netuid = netuid))]
+pub async fn sync_metagraph_with(
+    client: &BittensorClient,
+    netuid: u16,
+    lite: bool,
+) -> Result<Metagraph> {
+    if lite && subnets::supports_subnet_state(client) {
+        let block = client.block_number().await?;
+        match subnets::try_get_subnet_state(client, netuid).await {
+            Ok(Some(state)) => return Ok(metagraph_from_subnet_state(&state, block)),
+            Ok(None) => return Err(anyhow::anyhow!("Subnet {} does not exist", netuid)),
+            Err(e) if is_message_too_large_error(&e.to_string()) => {
+                tracing::info!(
+                    "get_subnet_state response exceeds the {} byte message limit, reading per-field storage",
+                    client.max_message_size()
+                );
+            }
+            Err(e) => {
+                tracing::warn!("get_subnet_state failed, falling back to storage: {}", e);
+            }
+        }
+    }
+    sync_metagraph(client, netuid).await
+}
+
+impl Metagraph {
+    /// Re-sync this metagraph in place; see [`sync_metagraph_with`]
+    pub async fn sync(&mut self, client: &BittensorClient, lite: bool) -> Result<()> {
+        *self = sync_metagraph_with(client, self.netuid, lite).await?;
+        Ok(())
+    }
+
+    /// Re-sync this metagraph in place, keeping current values for failed
+    /// reads; see [`sync_metagraph_with_options`]
+    pub async fn sync_with_options(
+        &mut self,
+        client: &impl ChainBackend,
+        options: &SyncOptions,
+    ) -> Result<()> {
+        *self = sync_metagraph_with_options(client, self.netuid, Some(self), options).await?;
+        Ok(())
+    }
+}
+
+/// Build a lite metagraph from a runtime `SubnetStateInfo`
+pub(crate) fn metagraph_from_subnet_state(state: &SubnetStateInfo, block: u64) -> Metagraph {
+    fn norm(values: &[u16], idx: usize) -> f64 {
+        values.get(idx).copied().unwrap_or(0) as f64 / u16::MAX as f64
+    }
+    fn rao(values: &[Rao], idx: usize) -> Rao {
+        values.get(idx).copied().unwrap_or(Rao::ZERO)
+    }
+
+    let mut metagraph = Metagraph::new(state.netuid);
+    metagraph.block = block;
+    metagraph.n = state.n() as u64;
+    metagraph.hotkeys = state.hotkeys.clone();
+    metagraph.coldkeys = state.coldkeys.clone();
+    metagraph.active = state.active.clone();
+    metagraph.validator_permit = state.validator_permit.clone();
+    metagraph.block_at_registration = state.block_at_registration.clone();
+
+    for (idx, (hotkey, coldkey)) in state.hotkeys.iter().zip(&state.coldkeys).enumerate() {
+        let uid = idx as u64;
+        metagraph.neurons.insert(
+            uid,
+            NeuronInfo {
+                uid,
+                netuid: state.netuid,
+                hotkey: hotkey.clone(),
+                coldkey: coldkey.clone(),
+                stake: rao(&state.alpha_stake, idx),
+                stake_dict: HashMap::new(),
+                total_stake: rao(&state.total_stake, idx),
+                root_stake: rao(&state.tao_stake, idx),
+                stake_weight: 0,
+                rank: norm(&state.rank, idx),
+                trust: norm(&state.trust, idx),
+                consensus: norm(&state.consensus, idx),
+                validator_trust: 0.0,
+                incentive: norm(&state.incentives, idx),
+                emission: rao(&state.emission, idx),
+                dividends: norm(&state.dividends, idx),
+                active: state.active.get(idx).copied().unwrap_or(false),
+                last_update: state.last_update.get(idx).copied().unwrap_or(0),
+                validator_permit: state.validator_permit.get(idx).copied().unwrap_or(false),
+                version: 0,
+                weights: Vec::new(),
+                bonds: Vec::new(),
+                pruning_score: state.pruning_score.get(idx).copied().unwrap_or(0) as u64,
+                prometheus_info: None,
+                axon_info: None,
+                is_null: false,
+            },
+        );
+    }
+    metagraph.rebuild_hotkey_index();
+
+    metagraph
+}
+
 // Helper functions use implementations from neurons module
 
 /// Fetch axon information from storage
 #[allow(dead_code)]
 async fn fetch_axon_info(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     module: &str,
     entry: &str,
     keys: Vec<Value>,
@@ -61,7 +850,7 @@ async fn fetch_axon_info(
 /// Fetch prometheus information from storage  
 #[allow(dead_code)]
 async fn fetch_prometheus_info(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     module: &str,
     entry: &str,
     keys: Vec<Value>,
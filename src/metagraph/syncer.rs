@@ -0,0 +1,334 @@
+//! Background metagraph syncing
+//!
+//! [`MetagraphSyncer`] keeps a shared [`Metagraph`] up to date for one subnet.
+//! It follows finalized blocks and re-syncs every `interval_blocks`, or as
+//! soon as a neuron registers on the subnet, then publishes the block of the
+//! new metagraph on a watch channel:
+//!
+//! ```ignore
+//! let (metagraph, mut synced, syncer) = MetagraphSyncer::spawn(client, 1, 100, true);
+//! while synced.changed().await.is_ok() {
+//!     let metagraph = metagraph.read().await;
+//!     // score against the fresh metagraph
+//! }
+//! ```
+//!
+//! Failed syncs are retried with backoff, up to one minute apart, and the
+//! latest failure is kept for [`MetagraphSyncer::last_error`]. The task never
+//! stops on chain errors; only [`MetagraphSyncer::shutdown`] ends it.
+
+use crate::chain::{BittensorClient, SubtensorEvent};
+use crate::metagraph::sync::sync_metagraph_with;
+use crate::metagraph::types::Metagraph;
+use futures::StreamExt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{watch, RwLock};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Keeps a shared metagraph in sync in the background
+pub struct MetagraphSyncer {
+    metagraph: Arc<RwLock<Metagraph>>,
+    synced: watch::Receiver<u64>,
+    last_error: Arc<Mutex<Option<String>>>,
+    cancel: CancellationToken,
+    task: JoinHandle<()>,
+}
+
+impl MetagraphSyncer {
+    /// Start syncing the metagraph of `netuid` every `interval_blocks`
+    ///
+    /// Returns the shared metagraph, a receiver for the block of each sync
+    /// (0 until the first sync completes) and the handle to stop the task.
+    /// `lite` is passed on to [`sync_metagraph_with`].
+    pub fn spawn(
+        client: Arc<BittensorClient>,
+        netuid: u16,
+        interval_blocks: u64,
+        lite: bool,
+    ) -> (Arc<RwLock<Metagraph>>, watch::Receiver<u64>, Self) {
+        let metagraph = Arc::new(RwLock::new(Metagraph::new(netuid)));
+        let (sender, synced) = watch::channel(0);
+        let last_error = Arc::new(Mutex::new(None));
+        let cancel = CancellationToken::new();
+
+        let worker = Worker {
+            client,
+            netuid,
+            lite,
+            schedule: Schedule::new(interval_blocks),
+            metagraph: metagraph.clone(),
+            sender,
+            last_error: last_error.clone(),
+        };
+        let task = tokio::spawn(worker.run(cancel.clone()));
+
+        let syncer = Self {
+            metagraph: metagraph.clone(),
+            synced: synced.clone(),
+            last_error,
+            cancel,
+            task,
+        };
+        (metagraph, synced, syncer)
+    }
+
+    /// The shared metagraph
+    pub fn metagraph(&self) -> Arc<RwLock<Metagraph>> {
+        self.metagraph.clone()
+    }
+
+    /// Another receiver for the block of each sync
+    pub fn subscribe(&self) -> watch::Receiver<u64> {
+        self.synced.clone()
+    }
+
+    /// Error of the latest sync attempt, or None if it succeeded
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Whether the task has stopped
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Stop syncing and wait for the task to finish
+    ///
+    /// A sync in progress is abandoned; the shared metagraph keeps its last
+    /// synced value.
+    pub async fn shutdown(self) {
+        self.cancel.cancel();
+        if let Err(e) = self.task.await {
+            warn!("Metagraph syncer task failed: {}", e);
+        }
+    }
+}
+
+/// Whether `event` adds neurons to `netuid`
+fn registers_on(event: &SubtensorEvent, netuid: u16) -> bool {
+    matches!(
+        event,
+        SubtensorEvent::NeuronRegistered { .. } | SubtensorEvent::BulkNeuronsRegistered { .. }
+    ) && event.netuid() == Some(netuid)
+}
+
+/// Decides on which blocks to sync
+#[derive(Debug)]
+struct Schedule {
+    interval_blocks: u64,
+    /// Block of the last successful sync
+    last_synced: Option<u64>,
+    /// A registration was seen since the last successful sync
+    registration_pending: bool,
+    /// No attempt before this after a failure
+    retry_at: Option<Instant>,
+    retry_delay: Duration,
+}
+
+impl Schedule {
+    fn new(interval_blocks: u64) -> Self {
+        Self {
+            interval_blocks,
+            last_synced: None,
+            registration_pending: false,
+            retry_at: None,
+            retry_delay: INITIAL_RETRY_DELAY,
+        }
+    }
+
+    /// Whether to sync at `block`, noting a registration in it
+    fn due(&mut self, block: u64, registered: bool, now: Instant) -> bool {
+        self.registration_pending |= registered;
+        if self.retry_at.is_some_and(|at| now < at) {
+            return false;
+        }
+        match self.last_synced {
+            None => true,
+            Some(last) => {
+                self.registration_pending || block >= last.saturating_add(self.interval_blocks)
+            }
+        }
+    }
+
+    fn succeeded(&mut self, block: u64) {
+        self.last_synced = Some(block);
+        self.registration_pending = false;
+        self.retry_at = None;
+        self.retry_delay = INITIAL_RETRY_DELAY;
+    }
+
+    fn failed(&mut self, now: Instant) {
+        self.retry_at = Some(now + self.retry_delay);
+        self.retry_delay = (self.retry_delay * 2).min(MAX_RETRY_DELAY);
+    }
+}
+
+struct Worker {
+    client: Arc<BittensorClient>,
+    netuid: u16,
+    lite: bool,
+    schedule: Schedule,
+    metagraph: Arc<RwLock<Metagraph>>,
+    sender: watch::Sender<u64>,
+    last_error: Arc<Mutex<Option<String>>>,
+}
+
+impl Worker {
+    async fn run(mut self, cancel: CancellationToken) {
+        let client = self.client.clone();
+        let mut reconnect_delay = INITIAL_RETRY_DELAY;
+
+        'connection: loop {
+            // Catch up on the head before waiting for the next block
+            match client.block_number().await {
+                Ok(head) => {
+                    tokio::select! {
+                        _ = cancel.cancelled() => break,
+                        _ = self.on_block(head, false) => {}
+                    }
+                }
+                Err(e) => warn!("Failed to read head block: {}", e),
+            }
+
+            match client.subscribe_finalized_blocks_with_events().await {
+                Ok(stream) => {
+                    let mut stream = std::pin::pin!(stream);
+                    loop {
+                        let next = tokio::select! {
+                            _ = cancel.cancelled() => break 'connection,
+                            next = stream.next() => next,
+                        };
+                        match next {
+                            Some(Ok((block, events))) => {
+                                reconnect_delay = INITIAL_RETRY_DELAY;
+                                let registered =
+                                    events.iter().any(|event| registers_on(event, self.netuid));
+                                tokio::select! {
+                                    _ = cancel.cancelled() => break 'connection,
+                                    _ = self.on_block(block, registered) => {}
+                                }
+                            }
+                            Some(Err(e)) => {
+                                warn!("Block subscription error: {}", e);
+                                break;
+                            }
+                            None => {
+                                warn!("Block subscription ended");
+                                break;
+                            }
+                        }
+                    }
+                }
+                Err(e) => warn!("Failed to subscribe to finalized blocks: {}", e),
+            }
+
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = tokio::time::sleep(reconnect_delay) => {}
+            }
+            reconnect_delay = (reconnect_delay * 2).min(MAX_RETRY_DELAY);
+        }
+    }
+
+    async fn on_block(&mut self, block: u64, registered: bool) {
+        if !self.schedule.due(block, registered, Instant::now()) {
+            return;
+        }
+
+        debug!(
+            "Syncing metagraph for subnet {} at block {}",
+            self.netuid, block
+        );
+        match sync_metagraph_with(&self.client, self.netuid, self.lite).await {
+            Ok(fresh) => {
+                let synced_block = fresh.block.max(block);
+                *self.metagraph.write().await = fresh;
+                self.schedule.succeeded(block);
+                *self.last_error.lock().unwrap_or_else(|e| e.into_inner()) = None;
+                self.sender.send_replace(synced_block);
+            }
+            Err(e) => {
+                warn!("Failed to sync metagraph for subnet {}: {}", self.netuid, e);
+                self.schedule.failed(Instant::now());
+                *self.last_error.lock().unwrap_or_else(|e| e.into_inner()) = Some(e.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::crypto::AccountId32;
+
+    #[test]
+    fn test_schedule_interval_and_registrations() {
+        let now = Instant::now();
+        let mut schedule = Schedule::new(100);
+
+        assert!(schedule.due(1_000, false, now));
+        schedule.succeeded(1_000);
+        assert!(!schedule.due(1_001, false, now));
+        assert!(!schedule.due(1_099, false, now));
+        assert!(schedule.due(1_100, false, now));
+        schedule.succeeded(1_100);
+
+        assert!(schedule.due(1_101, true, now));
+        schedule.succeeded(1_101);
+        assert!(!schedule.due(1_102, false, now));
+    }
+
+    #[test]
+    fn test_schedule_backs_off_after_failures() {
+        let now = Instant::now();
+        let mut schedule = Schedule::new(100);
+
+        assert!(schedule.due(1_000, false, now));
+        schedule.failed(now);
+        assert!(!schedule.due(1_001, false, now));
+        // Registrations seen while backing off are not forgotten
+        assert!(!schedule.due(1_002, true, now + Duration::from_millis(500)));
+        assert!(schedule.due(1_003, false, now + INITIAL_RETRY_DELAY));
+
+        // Each consecutive failure doubles the wait, up to the maximum
+        for _ in 0..10 {
+            schedule.failed(now);
+        }
+        assert_eq!(schedule.retry_at, Some(now + MAX_RETRY_DELAY));
+
+        schedule.succeeded(1_003);
+        assert_eq!(schedule.retry_delay, INITIAL_RETRY_DELAY);
+        assert!(!schedule.due(1_004, false, now));
+    }
+
+    #[test]
+    fn test_registration_events() {
+        let registered = SubtensorEvent::NeuronRegistered {
+            netuid: 1,
+            uid: 7,
+            hotkey: AccountId32::new([1; 32]),
+        };
+        assert!(registers_on(&registered, 1));
+        assert!(!registers_on(&registered, 2));
+        assert!(registers_on(
+            &SubtensorEvent::BulkNeuronsRegistered {
+                netuid: 2,
+                count: 3
+            },
+            2
+        ));
+        assert!(!registers_on(
+            &SubtensorEvent::NetworkRemoved { netuid: 1 },
+            1
+        ));
+    }
+}
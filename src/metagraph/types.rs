@@ -26,6 +26,26 @@ pub struct Metagraph {
     pub validator_permit: Vec<bool>,
     /// Active list indexed by UID
     pub active: Vec<bool>,
+    /// Registration block indexed by UID
+    pub block_at_registration: Vec<u64>,
+    /// Fetches that failed during the last partial sync
+    pub sync_warnings: Vec<SyncWarning>,
+    /// UID of each hotkey, rebuilt on every sync
+    pub(crate) hotkey_index: HashMap<AccountId32, u64>,
+}
+
+/// A fetch that failed during a partial sync
+///
+/// The affected field keeps its previous value, or its default if the UID
+/// was not in the previous metagraph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncWarning {
+    /// The UID whose field failed, or None for a subnet-wide vector
+    pub uid: Option<u64>,
+    /// The neuron field that was not updated, e.g. `"coldkey"`
+    pub field: &'static str,
+    /// The error of the last attempt
+    pub error: String,
 }
 
 impl Metagraph {
@@ -41,6 +61,9 @@ impl Metagraph {
             coldkeys: Vec::new(),
             validator_permit: Vec::new(),
             active: Vec::new(),
+            block_at_registration: Vec::new(),
+            sync_warnings: Vec::new(),
+            hotkey_index: HashMap::new(),
         }
     }
 
@@ -59,19 +82,23 @@ impl Metagraph {
         self.neurons.values().filter(|n| n.active).collect()
     }
 
-    /// Get all validators (neurons with validator_permit)
-    pub fn validators(&self) -> Vec<&NeuronInfo> {
-        self.neurons
-            .values()
-            .filter(|n| n.validator_permit)
-            .collect()
-    }
-
     /// Get neuron by hotkey
     pub fn get_neuron_by_hotkey(&self, hotkey: &AccountId32) -> Option<&NeuronInfo> {
         self.neurons.values().find(|n| &n.hotkey == hotkey)
     }
 
+    /// Rebuild the hotkey lookup used by `neuron_by_hotkey`
+    ///
+    /// Syncing does this automatically; call it after editing `neurons` by
+    /// hand.
+    pub fn rebuild_hotkey_index(&mut self) {
+        self.hotkey_index = self
+            .neurons
+            .values()
+            .map(|neuron| (neuron.hotkey.clone(), neuron.uid))
+            .collect();
+    }
+
     /// Get total stake in the subnet
     pub fn total_stake(&self) -> Rao {
         self.neurons.values().map(|n| n.stake).sum()
@@ -0,0 +1,256 @@
+//! Per-UID views over a metagraph
+//!
+//! A [`NeuronView`] gathers everything the metagraph holds for one UID, so
+//! callers do not have to index the per-UID vectors and maps in step.
+
+use crate::metagraph::Metagraph;
+use crate::queries::neurons::immunity_blocks_remaining;
+use crate::types::{AxonInfo, NeuronInfo};
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use std::ops::Deref;
+
+/// One UID of a metagraph, borrowed from it
+///
+/// Dereferences to the neuron's [`NeuronInfo`], so `view.hotkey`,
+/// `view.stake`, `view.incentive` and so on read the neuron directly.
+#[derive(Debug, Clone, Copy)]
+pub struct NeuronView<'a> {
+    /// The neuron's chain data
+    pub neuron: &'a NeuronInfo,
+    /// The neuron's axon (None for lite metagraphs)
+    pub axon: Option<&'a AxonInfo>,
+    /// Block the UID was registered at (0 if unknown)
+    pub block_at_registration: u64,
+    /// Block the metagraph was synced at
+    pub block: u64,
+}
+
+impl Deref for NeuronView<'_> {
+    type Target = NeuronInfo;
+
+    fn deref(&self) -> &NeuronInfo {
+        self.neuron
+    }
+}
+
+impl NeuronView<'_> {
+    /// Whether the neuron is active and its axon is serving
+    pub fn is_serving(&self) -> bool {
+        self.neuron.active && self.axon.is_some_and(AxonInfo::is_serving)
+    }
+
+    /// Blocks of immunity left at the sync block, or None if it has expired
+    ///
+    /// `immunity_period` is the subnet's `ImmunityPeriod` hyperparameter,
+    /// which the metagraph does not sync.
+    pub fn immunity_remaining(&self, immunity_period: u64) -> Option<u64> {
+        immunity_blocks_remaining(self.block_at_registration, immunity_period, self.block)
+    }
+}
+
+impl Metagraph {
+    /// View of one UID
+    pub fn neuron(&self, uid: u64) -> Option<NeuronView<'_>> {
+        let neuron = self.neurons.get(&uid)?;
+        Some(NeuronView {
+            neuron,
+            axon: self.axons.get(&uid),
+            block_at_registration: self.registered_at(uid as usize),
+            block: self.block,
+        })
+    }
+
+    /// View of the UID registered to an SS58 hotkey
+    ///
+    /// Uses the hotkey index built at sync time, see
+    /// [`rebuild_hotkey_index`](Metagraph::rebuild_hotkey_index).
+    pub fn neuron_by_hotkey(&self, hotkey: &str) -> Option<NeuronView<'_>> {
+        let account = AccountId32::from_ss58check(hotkey).ok()?;
+        let uid = *self.hotkey_index.get(&account)?;
+        self.neuron(uid)
+    }
+
+    /// Views of every UID, in UID order
+    pub fn neurons(&self) -> impl Iterator<Item = NeuronView<'_>> {
+        let mut uids: Vec<u64> = self.neurons.keys().copied().collect();
+        uids.sort_unstable();
+        uids.into_iter().filter_map(move |uid| self.neuron(uid))
+    }
+
+    /// Views of the UIDs holding a validator permit, in UID order
+    pub fn validators(&self) -> impl Iterator<Item = NeuronView<'_>> {
+        self.neurons().filter(|view| view.validator_permit)
+    }
+
+    /// The `k` UIDs with the most stake, largest first
+    ///
+    /// Equal stakes are ordered by UID.
+    pub fn top_k_by_stake(&self, k: usize) -> Vec<NeuronView<'_>> {
+        let mut views: Vec<NeuronView<'_>> = self.neurons().collect();
+        views.sort_by(|a, b| b.stake.cmp(&a.stake).then(a.uid.cmp(&b.uid)));
+        views.truncate(k);
+        views
+    }
+
+    /// Axons of active neurons that are serving, with their UIDs, in UID order
+    ///
+    /// The UIDs line up with the results of `Dendrite::call_many` over the
+    /// returned axons.
+    pub fn active_axons(&self) -> Vec<(u64, &AxonInfo)> {
+        self.active_axons_with(false)
+    }
+
+    /// Axons of active neurons, with their UIDs, in UID order
+    ///
+    /// Axons that are not serving, such as the placeholder entry of a neuron
+    /// that never served, are only included if `include_non_serving` is set.
+    /// Pass these to a Dendrite with `skip_non_serving` off to keep one
+    /// result per UID.
+    pub fn active_axons_with(&self, include_non_serving: bool) -> Vec<(u64, &AxonInfo)> {
+        self.neurons()
+            .filter(|view| view.active)
+            .filter_map(|view| Some((view.uid, view.axon?)))
+            .filter(|(_, axon)| include_non_serving || axon.is_serving())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metagraph::test_neuron;
+    use crate::utils::balance_newtypes::Rao;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    fn account(seed: u8) -> AccountId32 {
+        AccountId32::new([seed; 32])
+    }
+
+    fn neuron(uid: u64, hotkey: &AccountId32, stake: u128, permit: bool) -> NeuronInfo {
+        NeuronInfo {
+            incentive: uid as f64 / 10.0,
+            validator_permit: permit,
+            ..test_neuron(uid, hotkey, Rao::from(stake))
+        }
+    }
+
+    fn axon(ip: [u8; 4]) -> AxonInfo {
+        AxonInfo {
+            hotkey: None,
+            block: 0,
+            version: 0,
+            ip: IpAddr::V4(Ipv4Addr::from(ip)),
+            port: 8091,
+            ip_type: 4,
+            protocol: 4,
+            placeholder1: 0,
+            placeholder2: 0,
+        }
+    }
+
+    /// UIDs 0-3 with stakes 30, 10, 30, 20; UIDs 0 and 2 are validators.
+    /// UID 1 serves, UID 2 is unreachable and UID 3 is inactive.
+    fn metagraph() -> Metagraph {
+        let mut metagraph = Metagraph::new(1);
+        for (uid, (stake, permit)) in [(30, true), (10, false), (30, true), (20, false)]
+            .into_iter()
+            .enumerate()
+        {
+            let uid = uid as u64;
+            let hotkey = account(uid as u8 + 1);
+            let mut neuron = neuron(uid, &hotkey, stake, permit);
+            neuron.active = uid != 3;
+            metagraph.neurons.insert(uid, neuron);
+            metagraph.hotkeys.push(hotkey);
+            metagraph.block_at_registration.push(100 + uid);
+        }
+        metagraph.axons.insert(1, axon([10, 0, 0, 1]));
+        metagraph.axons.insert(2, axon([0, 0, 0, 0]));
+        metagraph.axons.insert(3, axon([10, 0, 0, 3]));
+        metagraph.n = 4;
+        metagraph.rebuild_hotkey_index();
+        metagraph
+    }
+
+    #[test]
+    fn test_neuron_view_borrows_per_uid_fields() {
+        let metagraph = metagraph();
+        let view = metagraph.neuron(1).unwrap();
+
+        assert!(std::ptr::eq(view.neuron, &metagraph.neurons[&1]));
+        assert_eq!(view.hotkey, account(2));
+        assert_eq!(view.stake, Rao::from(10u128));
+        assert_eq!(view.incentive, 0.1);
+        assert_eq!(view.block_at_registration, 101);
+        assert_eq!(view.block, 0);
+        assert_eq!(view.axon.unwrap().port, 8091);
+        assert!(metagraph.neuron(4).is_none());
+    }
+
+    #[test]
+    fn test_neuron_by_hotkey() {
+        let mut metagraph = metagraph();
+        let ss58 = account(3).to_ss58check();
+        assert_eq!(metagraph.neuron_by_hotkey(&ss58).unwrap().uid, 2);
+        assert!(metagraph.neuron_by_hotkey("not an address").is_none());
+
+        // A replaced hotkey is found only once the index is rebuilt
+        metagraph
+            .neurons
+            .insert(2, neuron(2, &account(9), 30, true));
+        metagraph.rebuild_hotkey_index();
+        assert!(metagraph.neuron_by_hotkey(&ss58).is_none());
+        let replaced = metagraph
+            .neuron_by_hotkey(&account(9).to_ss58check())
+            .unwrap();
+        assert_eq!(replaced.uid, 2);
+    }
+
+    #[test]
+    fn test_neuron_iterators() {
+        let metagraph = metagraph();
+        let uids: Vec<u64> = metagraph.neurons().map(|v| v.uid).collect();
+        assert_eq!(uids, [0, 1, 2, 3]);
+        let validators: Vec<u64> = metagraph.validators().map(|v| v.uid).collect();
+        assert_eq!(validators, [0, 2]);
+    }
+
+    #[test]
+    fn test_top_k_by_stake() {
+        let metagraph = metagraph();
+        let top: Vec<u64> = metagraph.top_k_by_stake(3).iter().map(|v| v.uid).collect();
+        assert_eq!(top, [0, 2, 3]);
+        assert_eq!(metagraph.top_k_by_stake(10).len(), 4);
+        assert!(metagraph.top_k_by_stake(0).is_empty());
+    }
+
+    #[test]
+    fn test_active_axons() {
+        let metagraph = metagraph();
+        let axons = metagraph.active_axons();
+        assert_eq!(axons.len(), 1);
+        assert_eq!(axons[0].0, 1);
+        assert!(std::ptr::eq(axons[0].1, &metagraph.axons[&1]));
+
+        // The placeholder axon of UID 2 is kept on request, inactive UID 3 is not
+        let uids: Vec<u64> = metagraph
+            .active_axons_with(true)
+            .iter()
+            .map(|(uid, _)| *uid)
+            .collect();
+        assert_eq!(uids, [1, 2]);
+    }
+
+    #[test]
+    fn test_immunity_remaining() {
+        let mut metagraph = metagraph();
+        metagraph.block = 150;
+        // Registered at 101 and 103
+        assert_eq!(
+            metagraph.neuron(1).unwrap().immunity_remaining(100),
+            Some(51)
+        );
+        assert_eq!(metagraph.neuron(3).unwrap().immunity_remaining(40), None);
+        assert_eq!(metagraph.neuron(3).unwrap().immunity_remaining(48), Some(1));
+    }
+}
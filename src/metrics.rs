@@ -0,0 +1,330 @@
+//! Prometheus metrics for validator and axon runtime health
+//!
+//! Enabled with the `metrics` feature. The chain client, extrinsic
+//! submission, axon middleware, dendrite and CRv4 commit path record into
+//! the process-wide [`global`] registry. Expose it either on an axon with
+//! [`Axon::serve_metrics`](crate::axon::Axon::serve_metrics) or on a
+//! standalone port with [`serve`].
+//!
+//! ## Metrics
+//!
+//! Names and labels are stable; dashboards may depend on them.
+//!
+//! | Name | Type | Labels |
+//! |------|------|--------|
+//! | `bittensor_rpc_requests_total` | counter | `method` |
+//! | `bittensor_rpc_errors_total` | counter | `method`, `kind` |
+//! | `bittensor_extrinsics_total` | counter | `call`, `outcome` |
+//! | `bittensor_extrinsic_retries_total` | counter | `call` |
+//! | `bittensor_extrinsic_wait_seconds` | histogram | `call`, `wait` |
+//! | `bittensor_axon_requests_total` | counter | `synapse`, `status` |
+//! | `bittensor_axon_request_duration_seconds` | histogram | `synapse` |
+//! | `bittensor_dendrite_request_duration_seconds` | histogram | |
+//! | `bittensor_dendrite_timeouts_total` | counter | |
+//! | `bittensor_crv4_commits_total` | counter | `outcome` |
+//! | `bittensor_crv4_reveals_verified_total` | counter | |
+//!
+//! - `method` is the client call (`storage`, `runtime_api`, ...); `kind` is
+//!   [`chain::Error::kind`](crate::chain::Error::kind).
+//! - `call` is `Pallet.call`; `outcome` is `success`, `dispatch_error` or
+//!   an error kind; `wait` is `none`, `included` or `finalized`.
+//! - `synapse` is the route name, or `unknown` for unrouted requests.
+//! - Dendrite metrics have no per-axon label: a validator queries every
+//!   axon of a subnet, and one series per endpoint would grow without bound.
+//! - CRv4 `outcome` is `success` or `failure`.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! // Standalone exporter on :9100
+//! tokio::spawn(bittensor_rs::metrics::serve("0.0.0.0:9100".parse()?));
+//!
+//! // Or mounted on the axon at GET /metrics
+//! axon.serve_metrics();
+//! ```
+
+use crate::chain::{Error as ChainError, ExtrinsicResult, ExtrinsicWait};
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, TextEncoder,
+};
+use std::net::SocketAddr;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Latency buckets for HTTP round trips (seconds)
+const REQUEST_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 12.0, 30.0,
+];
+
+/// Buckets for extrinsic submission, which may wait for finalization (seconds)
+const EXTRINSIC_BUCKETS: &[f64] = &[0.1, 0.5, 1.0, 3.0, 6.0, 12.0, 24.0, 48.0, 96.0, 192.0];
+
+/// Metrics registry with the SDK's instruments
+pub struct Registry {
+    registry: prometheus::Registry,
+    rpc_requests: IntCounterVec,
+    rpc_errors: IntCounterVec,
+    extrinsics: IntCounterVec,
+    extrinsic_retries: IntCounterVec,
+    extrinsic_wait: HistogramVec,
+    axon_requests: IntCounterVec,
+    axon_duration: HistogramVec,
+    dendrite_duration: Histogram,
+    dendrite_timeouts: IntCounter,
+    crv4_commits: IntCounterVec,
+    crv4_reveals_verified: IntCounter,
+}
+
+impl Registry {
+    /// Create a registry with all SDK metrics registered
+    pub fn new() -> Self {
+        let registry = prometheus::Registry::new();
+
+        let counter = |name: &str, help: &str, labels: &[&str]| {
+            let metric = IntCounterVec::new(Opts::new(name, help), labels)
+                .expect("metric definition is valid");
+            registry
+                .register(Box::new(metric.clone()))
+                .expect("metric name is unique");
+            metric
+        };
+        let histogram = |name: &str, help: &str, labels: &[&str], buckets: &[f64]| {
+            let opts = HistogramOpts::new(name, help).buckets(buckets.to_vec());
+            let metric = HistogramVec::new(opts, labels).expect("metric definition is valid");
+            registry
+                .register(Box::new(metric.clone()))
+                .expect("metric name is unique");
+            metric
+        };
+
+        let rpc_requests = counter(
+            "bittensor_rpc_requests_total",
+            "Chain RPC calls made by the client",
+            &["method"],
+        );
+        let rpc_errors = counter(
+            "bittensor_rpc_errors_total",
+            "Chain RPC calls that failed, by error kind",
+            &["method", "kind"],
+        );
+        let extrinsics = counter(
+            "bittensor_extrinsics_total",
+            "Submitted extrinsics by outcome",
+            &["call", "outcome"],
+        );
+        let extrinsic_retries = counter(
+            "bittensor_extrinsic_retries_total",
+            "Extrinsic submission retries",
+            &["call"],
+        );
+        let extrinsic_wait = histogram(
+            "bittensor_extrinsic_wait_seconds",
+            "Time from submission until the requested inclusion stage",
+            &["call", "wait"],
+            EXTRINSIC_BUCKETS,
+        );
+        let axon_requests = counter(
+            "bittensor_axon_requests_total",
+            "Requests served by the axon",
+            &["synapse", "status"],
+        );
+        let axon_duration = histogram(
+            "bittensor_axon_request_duration_seconds",
+            "Axon request handling time",
+            &["synapse"],
+            REQUEST_BUCKETS,
+        );
+        let dendrite_duration = Histogram::with_opts(
+            HistogramOpts::new(
+                "bittensor_dendrite_request_duration_seconds",
+                "Dendrite round trip time",
+            )
+            .buckets(REQUEST_BUCKETS.to_vec()),
+        )
+        .expect("metric definition is valid");
+        registry
+            .register(Box::new(dendrite_duration.clone()))
+            .expect("metric name is unique");
+        let dendrite_timeouts = IntCounter::new(
+            "bittensor_dendrite_timeouts_total",
+            "Dendrite requests that timed out",
+        )
+        .expect("metric definition is valid");
+        registry
+            .register(Box::new(dendrite_timeouts.clone()))
+            .expect("metric name is unique");
+        let crv4_commits = counter(
+            "bittensor_crv4_commits_total",
+            "CRv4 timelocked weight commits submitted",
+            &["outcome"],
+        );
+        let crv4_reveals_verified = IntCounter::new(
+            "bittensor_crv4_reveals_verified_total",
+            "Pending CRv4 commits confirmed as revealed",
+        )
+        .expect("metric definition is valid");
+        registry
+            .register(Box::new(crv4_reveals_verified.clone()))
+            .expect("metric name is unique");
+
+        Self {
+            registry,
+            rpc_requests,
+            rpc_errors,
+            extrinsics,
+            extrinsic_retries,
+            extrinsic_wait,
+            axon_requests,
+            axon_duration,
+            dendrite_duration,
+            dendrite_timeouts,
+            crv4_commits,
+            crv4_reveals_verified,
+        }
+    }
+
+    /// Underlying Prometheus registry, for registering application metrics
+    pub fn prometheus(&self) -> &prometheus::Registry {
+        &self.registry
+    }
+
+    /// Render all metrics in the Prometheus text exposition format
+    pub fn encode(&self) -> String {
+        let mut buffer = Vec::new();
+        if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            tracing::error!("Failed to encode metrics: {}", e);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+
+    /// Record a chain RPC call and its error, if any
+    pub fn observe_rpc(&self, method: &str, error: Option<&ChainError>) {
+        self.rpc_requests.with_label_values(&[method]).inc();
+        if let Some(error) = error {
+            self.rpc_errors
+                .with_label_values(&[method, error.kind()])
+                .inc();
+        }
+    }
+
+    /// Record the final outcome of an extrinsic submission
+    pub fn observe_extrinsic(
+        &self,
+        call: &str,
+        wait_for: ExtrinsicWait,
+        result: &Result<ExtrinsicResult, ChainError>,
+        elapsed: Duration,
+    ) {
+        let outcome = match result {
+            Ok(result) if result.is_success() => "success",
+            Ok(_) => "dispatch_error",
+            Err(e) => e.kind(),
+        };
+        let wait = match wait_for {
+            ExtrinsicWait::None => "none",
+            ExtrinsicWait::Included => "included",
+            ExtrinsicWait::Finalized => "finalized",
+        };
+        self.extrinsics.with_label_values(&[call, outcome]).inc();
+        self.extrinsic_wait
+            .with_label_values(&[call, wait])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Record a retried extrinsic submission attempt
+    pub fn observe_extrinsic_retry(&self, call: &str) {
+        self.extrinsic_retries.with_label_values(&[call]).inc();
+    }
+
+    /// Record a request served by the axon
+    pub fn observe_axon_request(&self, synapse: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        self.axon_requests
+            .with_label_values(&[synapse, status.as_str()])
+            .inc();
+        self.axon_duration
+            .with_label_values(&[synapse])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Record a dendrite request
+    pub fn observe_dendrite_request(&self, elapsed: Duration, timed_out: bool) {
+        self.dendrite_duration.observe(elapsed.as_secs_f64());
+        if timed_out {
+            self.dendrite_timeouts.inc();
+        }
+    }
+
+    /// Record a CRv4 commit submission
+    pub fn observe_crv4_commit(&self, success: bool) {
+        let outcome = if success { "success" } else { "failure" };
+        self.crv4_commits.with_label_values(&[outcome]).inc();
+    }
+
+    /// Record a pending CRv4 commit confirmed as revealed
+    pub fn observe_crv4_reveal_verified(&self) {
+        self.crv4_reveals_verified.inc();
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Process-wide registry the SDK records into
+pub fn global() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Registry::new)
+}
+
+/// Router serving the [`global`] registry at `GET /metrics`
+pub fn router() -> Router {
+    Router::new().route("/metrics", get(metrics_handler))
+}
+
+/// Serve `GET /metrics` on a standalone address until the task is dropped
+pub async fn serve(addr: SocketAddr) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    tracing::info!("Serving metrics on http://{}/metrics", addr);
+    axum::serve(listener, router()).await
+}
+
+async fn metrics_handler() -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)],
+        global().encode(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_uses_stable_names() {
+        let registry = Registry::new();
+        registry.observe_rpc("storage", None);
+        registry.observe_rpc("storage", Some(&ChainError::Rpc("boom".to_string())));
+        registry.observe_axon_request("Query", 200, Duration::from_millis(5));
+        registry.observe_dendrite_request(Duration::from_secs(12), true);
+        registry.observe_dendrite_request(Duration::from_millis(20), false);
+        registry.observe_crv4_commit(true);
+        registry.observe_crv4_reveal_verified();
+
+        let text = registry.encode();
+        assert!(text.contains("bittensor_rpc_requests_total{method=\"storage\"} 2"));
+        assert!(text.contains("bittensor_rpc_errors_total{kind=\"rpc\",method=\"storage\"} 1"));
+        assert!(text.contains("bittensor_axon_requests_total{status=\"200\",synapse=\"Query\"} 1"));
+        assert!(text.contains("bittensor_dendrite_timeouts_total 1"));
+        assert!(text.contains("bittensor_dendrite_request_duration_seconds_count 2"));
+        assert!(text.contains("bittensor_crv4_commits_total{outcome=\"success\"} 1"));
+        assert!(text.contains("bittensor_crv4_reveals_verified_total 1"));
+    }
+}
@@ -3,11 +3,11 @@
 //! This module provides functions to query IP addresses associated with hotkeys
 //! on the Bittensor network.
 
-use crate::chain::BittensorClient;
+use crate::chain::ChainBackend;
 use crate::errors::{BittensorError, BittensorResult, ChainQueryError};
 use crate::utils::decoders::primitive;
 use crate::utils::decoders::utils::parse_ip_addr;
-use crate::utils::decoders::vec::decode_vec;
+use crate::utils::decoders::vec::decode_vec_lossy;
 use anyhow::anyhow;
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
@@ -93,7 +93,7 @@ fn decode_ip_info(value: &Value) -> Option<IpInfo> {
 /// # Returns
 /// A vector of IpInfo structures containing the associated IP addresses
 pub async fn get_associated_ips(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
 ) -> BittensorResult<Vec<IpInfo>> {
     let keys = vec![Value::from_bytes(hotkey.encode())];
@@ -117,7 +117,7 @@ pub async fn get_associated_ips(
 
 /// Decode a vector of IpInfo from a Value
 fn decode_ip_info_vec(value: &Value) -> Vec<IpInfo> {
-    decode_vec(value, |entry| {
+    decode_vec_lossy(value, |entry| {
         decode_ip_info(entry).ok_or_else(|| anyhow!("invalid ip info"))
     })
     .unwrap_or_default()
@@ -132,7 +132,7 @@ fn decode_ip_info_vec(value: &Value) -> Vec<IpInfo> {
 /// # Returns
 /// The count of associated IP addresses
 pub async fn get_associated_ip_count(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
 ) -> BittensorResult<usize> {
     let ips = get_associated_ips(client, hotkey).await?;
@@ -148,7 +148,7 @@ pub async fn get_associated_ip_count(
 /// # Returns
 /// true if the hotkey has at least one associated IP
 pub async fn has_associated_ips(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
 ) -> BittensorResult<bool> {
     let ips = get_associated_ips(client, hotkey).await?;
@@ -1,22 +1,43 @@
-use crate::chain::BittensorClient;
+use crate::chain::{BittensorClient, ChainBackend};
+use crate::errors::{BittensorError, BittensorResult, ChainQueryError};
 use crate::utils::balance_newtypes::Rao;
-use anyhow::Result;
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
 use subxt::ext::scale_value::{Composite, ValueDef};
 
 /// Get balance for an account
-pub async fn get_balance(client: &BittensorClient, account: &AccountId32) -> Result<Rao> {
+pub async fn get_balance(client: &BittensorClient, account: &AccountId32) -> BittensorResult<Rao> {
     client
         .account_balance(account)
         .await
         .map(Rao::from)
-        .map_err(|e| anyhow::anyhow!("{}", e))
+        .map_err(BittensorError::from)
+}
+
+/// Free balance of an account from `System.Account`, zero if it does not
+/// exist
+pub async fn get_free_balance(
+    client: &impl ChainBackend,
+    account: &AccountId32,
+) -> BittensorResult<Rao> {
+    let value = client
+        .storage_with_keys(
+            "System",
+            "Account",
+            vec![Value::from_bytes(account.encode())],
+        )
+        .await?;
+    Ok(Rao::from(
+        value.as_ref().and_then(extract_free_balance).unwrap_or(0),
+    ))
 }
 
 /// Get balances for multiple accounts (batch)
-pub async fn get_balances(client: &BittensorClient, accounts: &[AccountId32]) -> Result<Vec<Rao>> {
+pub async fn get_balances(
+    client: &BittensorClient,
+    accounts: &[AccountId32],
+) -> BittensorResult<Vec<Rao>> {
     let storage = client.api().storage().at_latest().await?;
     let _keys: Vec<_> = accounts
         .iter()
@@ -33,7 +54,7 @@ pub async fn get_balances(client: &BittensorClient, accounts: &[AccountId32]) ->
         if let Some(thunk) = res {
             let value = thunk
                 .to_value()
-                .map_err(|e| anyhow::anyhow!("decode: {}", e))?
+                .map_err(|e| ChainQueryError::new(format!("decode: {}", e)))?
                 .remove_context();
             let free = extract_free_balance(&value).unwrap_or(0);
             out.push(Rao::from(free));
@@ -45,15 +66,11 @@ pub async fn get_balances(client: &BittensorClient, accounts: &[AccountId32]) ->
 }
 
 /// Get existential deposit
-pub async fn get_existential_deposit(client: &BittensorClient) -> Result<Rao> {
-    let value = client
-        .query_constant("Balances", "ExistentialDeposit")
-        .await?
-        .ok_or_else(|| anyhow::anyhow!("Unable to retrieve existential deposit amount."))?;
-
-    crate::utils::decoders::decode_u128(&value)
-        .map(Rao::from)
-        .map_err(|e| anyhow::anyhow!("Failed to decode existential deposit: {}", e))
+///
+/// Read from the runtime metadata when the client connected, see
+/// [`BittensorClient::constants`].
+pub async fn get_existential_deposit(client: &impl ChainBackend) -> BittensorResult<Rao> {
+    Ok(client.constants().existential_deposit)
 }
 
 fn extract_free_balance(value: &Value) -> Option<u128> {
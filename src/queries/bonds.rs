@@ -1,9 +1,11 @@
 //! Bonds query functions
 //! Read-only queries for neuron bonds
 
-use crate::chain::BittensorClient;
-use crate::utils::decoders::vec::decode_vec;
-use crate::utils::decoders::{decode_u16, decode_u64};
+use crate::chain::ChainBackend;
+use crate::crv4::get_mechid_storage_index;
+use crate::errors::BittensorResult;
+use crate::utils::decoders::vec::{decode_vec_lossy, decode_vec_u16_u16_pairs};
+use crate::utils::decoders::{decode_stored_or, decode_u16, decode_u64};
 use anyhow::Result;
 use subxt::dynamic::Value;
 use subxt::ext::scale_value::{Composite, ValueDef};
@@ -13,11 +15,11 @@ const SUBTENSOR_MODULE: &str = "SubtensorModule";
 /// Get bonds for a specific neuron
 /// Returns Vec<(uid, bond_value)>
 pub async fn get_neuron_bonds(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     uid: u64,
     mechid: u16,
-) -> Result<Vec<(u16, u64)>> {
+) -> BittensorResult<Vec<(u16, u64)>> {
     // Calculate storage index: (netuid << 16) | mechid
     let storage_index = ((netuid as u32) << 16) | (mechid as u32);
 
@@ -30,7 +32,7 @@ pub async fn get_neuron_bonds(
         .await?;
 
     if let Some(value) = result {
-        parse_bonds_from_value(&value)
+        Ok(parse_bonds_from_value(&value)?)
     } else {
         Ok(vec![])
     }
@@ -39,10 +41,10 @@ pub async fn get_neuron_bonds(
 /// Get all bonds for a subnet
 /// Returns Vec<(uid, Vec<(target_uid, bond_value)>)>
 pub async fn get_all_bonds(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     mechid: u16,
-) -> Result<Vec<(u64, Vec<(u16, u64)>)>> {
+) -> BittensorResult<Vec<(u64, Vec<(u16, u64)>)>> {
     // Get neuron count
     let n = crate::queries::subnets::subnet_n(client, netuid)
         .await?
@@ -74,11 +76,11 @@ pub async fn get_all_bonds(
 /// Get weights for a specific neuron
 /// Returns Vec<(uid, weight_value)>
 pub async fn get_neuron_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     uid: u64,
     mechid: u16,
-) -> Result<Vec<(u16, u16)>> {
+) -> BittensorResult<Vec<(u16, u16)>> {
     let storage_index = ((netuid as u32) << 16) | (mechid as u32);
 
     let result = client
@@ -90,18 +92,43 @@ pub async fn get_neuron_weights(
         .await?;
 
     if let Some(value) = result {
-        parse_weights_from_value(&value)
+        Ok(parse_weights_from_value(&value)?)
     } else {
         Ok(vec![])
     }
 }
 
+/// Get the weights a UID has set on a subnet mechanism, as the chain stored them
+///
+/// Values are after the chain's max-upscaling. Returns an empty vec if the
+/// UID has not set weights.
+pub async fn get_uid_weights(
+    client: &impl ChainBackend,
+    netuid: u16,
+    mechanism_id: u8,
+    uid: u16,
+) -> BittensorResult<Vec<(u16, u16)>> {
+    let storage_index = get_mechid_storage_index(netuid, mechanism_id);
+    let value = client
+        .storage_with_keys(
+            SUBTENSOR_MODULE,
+            "Weights",
+            vec![Value::u128(storage_index as u128), Value::u128(uid as u128)],
+        )
+        .await?;
+    Ok(decode_stored_or(
+        value,
+        Vec::new(),
+        decode_vec_u16_u16_pairs,
+    )?)
+}
+
 /// Get all weights for a subnet
 pub async fn get_all_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     mechid: u16,
-) -> Result<Vec<(u64, Vec<(u16, u16)>)>> {
+) -> BittensorResult<Vec<(u64, Vec<(u16, u16)>)>> {
     let n = crate::queries::subnets::subnet_n(client, netuid)
         .await?
         .unwrap_or(0);
@@ -131,7 +158,7 @@ pub async fn get_all_weights(
 
 /// Parse bonds from storage value using debug string parsing
 fn parse_bonds_from_value(value: &Value) -> Result<Vec<(u16, u64)>> {
-    decode_vec(value, |entry| {
+    decode_vec_lossy(value, |entry| {
         if let Some((uid_val, bond_val)) = extract_pair(entry) {
             let uid = decode_u16(uid_val)?;
             let bond = decode_u64(bond_val)?;
@@ -143,7 +170,7 @@ fn parse_bonds_from_value(value: &Value) -> Result<Vec<(u16, u64)>> {
 
 /// Parse weights from storage value using debug string parsing
 fn parse_weights_from_value(value: &Value) -> Result<Vec<(u16, u16)>> {
-    decode_vec(value, |entry| {
+    decode_vec_lossy(value, |entry| {
         if let Some((uid_val, weight_val)) = extract_pair(entry) {
             let uid = decode_u16(uid_val)?;
             let weight = decode_u16(weight_val)?;
@@ -190,7 +217,7 @@ fn extract_pair(value: &Value) -> Option<(&Value, &Value)> {
 
 /// Get subnet N (number of neurons)
 #[allow(dead_code)]
-pub async fn subnet_n(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn subnet_n(client: &impl ChainBackend, netuid: u16) -> BittensorResult<Option<u64>> {
     let result = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
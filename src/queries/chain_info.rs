@@ -1,20 +1,24 @@
-use crate::chain::BittensorClient;
-use anyhow::Result;
+use crate::chain::{BittensorClient, ChainBackend};
+use crate::errors::{BittensorError, BittensorResult, BlockNotFound, ChainQueryError};
+use crate::utils::balance_newtypes::Rao;
+use anyhow::Context;
+use serde::Serialize;
+use std::fmt;
 use std::time::Duration;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
 /// Get on-chain timestamp (ms since epoch)
-pub async fn get_timestamp(client: &BittensorClient) -> Result<u64> {
+pub async fn get_timestamp(client: &impl ChainBackend) -> BittensorResult<u64> {
     let val = client
         .storage("Timestamp", "Now", None)
         .await?
-        .ok_or_else(|| anyhow::anyhow!("Timestamp.Now not found"))?;
-    crate::utils::decoders::decode_u64(&val).map_err(|e| anyhow::anyhow!("{}", e))
+        .ok_or_else(|| ChainQueryError::new("Timestamp.Now not found"))?;
+    crate::utils::decoders::decode_u64(&val).map_err(|e| ChainQueryError::new(e.to_string()).into())
 }
 
 /// Get last drand round from Drand pallet
-pub async fn last_drand_round(client: &BittensorClient) -> Result<Option<u64>> {
+pub async fn last_drand_round(client: &impl ChainBackend) -> BittensorResult<Option<u64>> {
     if let Some(val) = client.storage("Drand", "LastStoredRound", None).await? {
         return Ok(crate::utils::decoders::decode_u64(&val).ok());
     }
@@ -22,7 +26,7 @@ pub async fn last_drand_round(client: &BittensorClient) -> Result<Option<u64>> {
 }
 
 /// Get tx rate limit from Subtensor module
-pub async fn tx_rate_limit(client: &BittensorClient) -> Result<Option<u64>> {
+pub async fn tx_rate_limit(client: &impl ChainBackend) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage(SUBTENSOR_MODULE, "TxRateLimit", None)
         .await?
@@ -33,16 +37,19 @@ pub async fn tx_rate_limit(client: &BittensorClient) -> Result<Option<u64>> {
 }
 
 /// Get the admin freeze window (number of blocks where dependent txs are frozen)
-pub async fn get_admin_freeze_window(client: &BittensorClient) -> Result<u64> {
+pub async fn get_admin_freeze_window(client: &impl ChainBackend) -> BittensorResult<u64> {
     let val = client
         .storage(SUBTENSOR_MODULE, "AdminFreezeWindow", None)
         .await?
-        .ok_or_else(|| anyhow::anyhow!("AdminFreezeWindow not found"))?;
-    crate::utils::decoders::decode_u64(&val).map_err(|e| anyhow::anyhow!("{}", e))
+        .ok_or_else(|| ChainQueryError::new("AdminFreezeWindow not found"))?;
+    crate::utils::decoders::decode_u64(&val).map_err(|e| ChainQueryError::new(e.to_string()).into())
 }
 
 /// Check if current block is within admin freeze window for a subnet
-pub async fn is_in_admin_freeze_window(client: &BittensorClient, netuid: u16) -> Result<bool> {
+pub async fn is_in_admin_freeze_window(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<bool> {
     // SN0 doesn't have admin freeze window
     if netuid == 0 {
         return Ok(false);
@@ -52,10 +59,7 @@ pub async fn is_in_admin_freeze_window(client: &BittensorClient, netuid: u16) ->
         .await?
         .unwrap_or(0);
     let window = get_admin_freeze_window(client).await.unwrap_or(0);
-    let current_block = client
-        .block_number()
-        .await
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let current_block = client.block_number().await?;
 
     if next_epoch > 0 {
         let remaining = next_epoch.saturating_sub(current_block);
@@ -65,7 +69,7 @@ pub async fn is_in_admin_freeze_window(client: &BittensorClient, netuid: u16) ->
 }
 
 /// Check if the node is running with fast blocks
-pub async fn is_fast_blocks(client: &BittensorClient) -> Result<bool> {
+pub async fn is_fast_blocks(client: &impl ChainBackend) -> BittensorResult<bool> {
     if let Some(val) = client
         .storage(SUBTENSOR_MODULE, "DurationOfStartCall", None)
         .await?
@@ -79,29 +83,32 @@ pub async fn is_fast_blocks(client: &BittensorClient) -> Result<bool> {
 
 /// Get total issuance of the native token (RAO)
 /// Reads Balances::TotalIssuance storage
-pub async fn get_total_issuance(client: &BittensorClient) -> Result<u128> {
+pub async fn get_total_issuance(client: &impl ChainBackend) -> BittensorResult<u128> {
     let val = client
         .storage("Balances", "TotalIssuance", None)
         .await?
-        .ok_or_else(|| anyhow::anyhow!("Balances::TotalIssuance not found"))?;
+        .ok_or_else(|| ChainQueryError::new("Balances::TotalIssuance not found"))?;
     crate::utils::decoders::decode_u128(&val)
-        .map_err(|e| anyhow::anyhow!("Failed to decode TotalIssuance: {}", e))
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode TotalIssuance: {}", e)).into())
 }
 
 /// Get total stake across all subnets (RAO)
 /// Reads SubtensorModule::TotalStake storage
-pub async fn get_total_stake(client: &BittensorClient) -> Result<u128> {
+pub async fn get_total_stake(client: &impl ChainBackend) -> BittensorResult<u128> {
     let val = client
         .storage(SUBTENSOR_MODULE, "TotalStake", None)
         .await?
-        .ok_or_else(|| anyhow::anyhow!("SubtensorModule::TotalStake not found"))?;
+        .ok_or_else(|| ChainQueryError::new("SubtensorModule::TotalStake not found"))?;
     crate::utils::decoders::decode_u128(&val)
-        .map_err(|e| anyhow::anyhow!("Failed to decode TotalStake: {}", e))
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode TotalStake: {}", e)).into())
 }
 
 /// Get block hash for a specific block number
 /// Uses the chain RPC to retrieve the hash
-pub async fn get_block_hash(client: &BittensorClient, block_number: u64) -> Result<[u8; 32]> {
+pub async fn get_block_hash(
+    client: &BittensorClient,
+    block_number: u64,
+) -> BittensorResult<[u8; 32]> {
     let hash = client
         .api()
         .backend()
@@ -136,10 +143,11 @@ pub async fn get_block_hash(client: &BittensorClient, block_number: u64) -> Resu
         }
         _ => {
             let _ = params;
-            Err(anyhow::anyhow!(
+            Err(BlockNotFound::new(format!(
                 "Failed to get block hash for block {}",
                 block_number
             ))
+            .into())
         }
     }
 }
@@ -147,15 +155,15 @@ pub async fn get_block_hash(client: &BittensorClient, block_number: u64) -> Resu
 /// Get current block number with retry logic
 /// Retries up to max_retries times with exponential backoff
 pub async fn get_current_block_with_retry(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     max_retries: u32,
-) -> Result<u64> {
+) -> BittensorResult<u64> {
     let mut last_err = None;
     for attempt in 0..=max_retries {
         match client.block_number().await {
             Ok(block) => return Ok(block),
             Err(e) => {
-                last_err = Some(anyhow::anyhow!("{}", e));
+                last_err = Some(BittensorError::from(e));
                 if attempt < max_retries {
                     let delay = Duration::from_millis(100 * 2u64.pow(attempt));
                     tokio::time::sleep(delay).await;
@@ -163,11 +171,131 @@ pub async fn get_current_block_with_retry(
             }
         }
     }
-    Err(last_err.unwrap_or_else(|| anyhow::anyhow!("Failed to get current block")))
+    Err(last_err.unwrap_or_else(|| BittensorError::unknown("Failed to get current block")))
 }
 
 /// Get total number of subnets
 /// Reads SubtensorModule::TotalNetworks storage
-pub async fn get_total_subnets(client: &BittensorClient) -> Result<u16> {
+pub async fn get_total_subnets(client: &impl ChainBackend) -> BittensorResult<u16> {
     crate::queries::subnets::total_subnets(client).await
 }
+
+/// Network-wide totals read at a single block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct NetworkStats {
+    /// Block the totals were read at
+    pub block: u64,
+    /// Hash of that block
+    pub block_hash: sp_core::H256,
+    /// SubtensorModule::TotalIssuance
+    pub total_issuance: Rao,
+    /// SubtensorModule::TotalStake
+    pub total_stake: Rao,
+    /// SubtensorModule::TotalNetworks
+    pub total_networks: u16,
+    /// SubtensorModule::BlockEmission, per block
+    pub block_emission: Rao,
+    /// SubtensorModule::SubnetTAO summed over every subnet
+    pub subnet_tao: Rao,
+}
+
+impl fmt::Display for NetworkStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Block:           {} ({:?})", self.block, self.block_hash)?;
+        writeln!(f, "Total issuance:  {}", self.total_issuance.to_tao())?;
+        writeln!(f, "Total stake:     {}", self.total_stake.to_tao())?;
+        writeln!(f, "Subnets:         {}", self.total_networks)?;
+        writeln!(f, "Block emission:  {}", self.block_emission.to_tao())?;
+        write!(f, "TAO in subnets:  {}", self.subnet_tao.to_tao())
+    }
+}
+
+/// Get network-wide totals, all read at the latest finalized block
+///
+/// Reads `TotalIssuance`, `TotalStake`, `TotalNetworks` and `BlockEmission`
+/// from SubtensorModule and sums `SubnetTAO` over every subnet by storage
+/// iteration. Pinning one block hash keeps the totals consistent with each
+/// other; unset entries read as zero.
+pub async fn get_network_stats(client: &BittensorClient) -> BittensorResult<NetworkStats> {
+    use crate::utils::decoders::{decode_stored_or, decode_u128, decode_u16};
+
+    let (block, block_hash) = client.finalized_head().await?;
+    let read = |entry: &'static str| async move {
+        client
+            .storage_at_block(SUBTENSOR_MODULE, entry, vec![], block_hash)
+            .await
+            .with_context(|| format!("Failed to read {}", entry))
+    };
+
+    let (total_issuance, total_stake, total_networks, block_emission, subnet_tao) = tokio::try_join!(
+        read("TotalIssuance"),
+        read("TotalStake"),
+        read("TotalNetworks"),
+        read("BlockEmission"),
+        async {
+            client
+                .storage_iter_at_block(SUBTENSOR_MODULE, "SubnetTAO", vec![], block_hash)
+                .await
+                .context("Failed to iterate SubnetTAO")
+        },
+    )?;
+
+    let mut tao_in_subnets = 0u128;
+    for (_, value) in subnet_tao {
+        tao_in_subnets = tao_in_subnets.saturating_add(
+            decode_u128(&value)
+                .map_err(|e| ChainQueryError::new(format!("Failed to decode SubnetTAO: {}", e)))?,
+        );
+    }
+    let rao = |value, entry: &str| {
+        decode_stored_or(value, 0, decode_u128)
+            .map(Rao::from)
+            .map_err(|e| ChainQueryError::new(format!("Failed to decode {}: {}", entry, e)))
+    };
+
+    Ok(NetworkStats {
+        block,
+        block_hash,
+        total_issuance: rao(total_issuance, "TotalIssuance")?,
+        total_stake: rao(total_stake, "TotalStake")?,
+        total_networks: decode_stored_or(total_networks, 0, decode_u16)
+            .map_err(|e| ChainQueryError::new(format!("Failed to decode TotalNetworks: {}", e)))?,
+        block_emission: rao(block_emission, "BlockEmission")?,
+        subnet_tao: Rao::from(tao_in_subnets),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_stats_display() {
+        let stats = NetworkStats {
+            block: 4_200,
+            block_hash: sp_core::H256::repeat_byte(0xab),
+            total_issuance: Rao::from(7_000_000_000_000_000u128),
+            total_stake: Rao::from(5_500_000_000_000_000u128),
+            total_networks: 64,
+            block_emission: Rao::from(1_000_000_000u128),
+            subnet_tao: Rao::from(1_250_000_000u128),
+        };
+        let shown = stats.to_string();
+        assert!(
+            shown.starts_with("Block:           4200 (0xabab"),
+            "{}",
+            shown
+        );
+        assert!(
+            shown.contains("Total issuance:  7000000.000000000 τ"),
+            "{}",
+            shown
+        );
+        assert!(shown.contains("Subnets:         64"), "{}", shown);
+        assert!(
+            shown.ends_with("TAO in subnets:  1.250000000 τ"),
+            "{}",
+            shown
+        );
+    }
+}
@@ -1,7 +1,9 @@
-use crate::chain::BittensorClient;
+use crate::chain::ChainBackend;
 use crate::errors::{BittensorError, BittensorResult, ChainQueryError};
-use crate::utils::decoders::vec::decode_vec;
-use crate::utils::decoders::{decode_bytes, decode_u64};
+use crate::utils::decoders::vec::decode_vec_lossy;
+use crate::utils::decoders::{
+    decode_bytes, decode_bytes_from_composite, decode_named_composite, decode_u64,
+};
 use anyhow::{anyhow, Result};
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
@@ -38,29 +40,51 @@ impl WeightCommitInfo {
     }
 }
 
-/// Get commitment: SubtensorModule.Commits[(netuid, block, uid)] -> bytes
+/// Get a hotkey's commitment on a subnet: Commitments.CommitmentOf[(netuid, hotkey)]
+///
+/// Returns the block the commitment was made at and its raw fields joined.
 pub async fn get_commitment(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-    block: u64,
-    uid: u64,
-) -> Result<Option<String>> {
-    let keys = vec![Value::from(netuid), Value::from(block), Value::from(uid)];
-    if let Some(val) = client
-        .storage_with_keys(SUBTENSOR_MODULE, "Commits", keys)
+    hotkey: &AccountId32,
+) -> BittensorResult<Option<(u64, Vec<u8>)>> {
+    let keys = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
+    match client
+        .storage_with_keys(COMMITMENTS_PALLET, "CommitmentOf", keys)
         .await?
     {
-        return Ok(Some(decode_bytes_as_utf8(&val)));
+        Some(val) => Ok(Some(decode_registration(&val)?)),
+        None => Ok(None),
+    }
+}
+
+/// Get a hotkey's revealed timelocked commitments:
+/// Commitments.RevealedCommitments[(netuid, hotkey)]
+///
+/// Returns `(reveal block, data)` pairs, oldest first.
+pub async fn get_revealed_commitments(
+    client: &impl ChainBackend,
+    netuid: u16,
+    hotkey: &AccountId32,
+) -> BittensorResult<Vec<(u64, Vec<u8>)>> {
+    let keys = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
+    match client
+        .storage_with_keys(COMMITMENTS_PALLET, "RevealedCommitments", keys)
+        .await?
+    {
+        Some(val) => Ok(decode_vec_lossy(&val, |entry| {
+            decode_revealed_entry(entry).ok_or_else(|| anyhow!("invalid"))
+        })?),
+        None => Ok(Vec::new()),
     }
-    Ok(None)
 }
 
 /// Get revealed commitments for a hotkey on a specific netuid
 pub async fn get_revealed_commitment_by_hotkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
-) -> Result<Vec<(u64, String)>> {
+) -> BittensorResult<Vec<(u64, String)>> {
     let keys = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
     if let Some(val) = client
         .storage_with_keys(COMMITMENTS_PALLET, "RevealedCommitments", keys)
@@ -73,10 +97,10 @@ pub async fn get_revealed_commitment_by_hotkey(
 
 /// Get revealed commitment (latest) for (netuid, hotkey)
 pub async fn get_revealed_commitment(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
-) -> Result<Option<(u64, String)>> {
+) -> BittensorResult<Option<(u64, String)>> {
     let keys = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
     if let Some(val) = client
         .storage_with_keys(COMMITMENTS_PALLET, "RevealedCommitments", keys)
@@ -92,9 +116,9 @@ pub async fn get_revealed_commitment(
 
 /// Get current weight commit info: SubtensorModule.CRV3WeightCommitsV2[(netuid)] -> Vec<Vec<u8>>
 pub async fn get_current_weight_commit_info(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<Vec<Vec<u8>>> {
+) -> BittensorResult<Vec<Vec<u8>>> {
     let key = vec![Value::from(netuid)];
     if let Some(val) = client
         .storage_with_keys(SUBTENSOR_MODULE, "CRV3WeightCommitsV2", key)
@@ -107,9 +131,9 @@ pub async fn get_current_weight_commit_info(
 
 /// Get timelocked weight commits
 pub async fn get_timelocked_weight_commits(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<Vec<Vec<u8>>> {
+) -> BittensorResult<Vec<Vec<u8>>> {
     let storage_index = crate::crv4::get_mechid_storage_index(netuid, 0);
     let key = vec![Value::from(storage_index)];
     if let Some(val) = client
@@ -121,52 +145,65 @@ pub async fn get_timelocked_weight_commits(
     Ok(Vec::new())
 }
 
-/// Get all commitments for a subnet from Commitments.CommitmentOf
+/// Get all commitments for a subnet by iterating Commitments.CommitmentOf[(netuid, *)]
 pub async fn get_all_commitments(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<std::collections::HashMap<AccountId32, String>> {
-    let n_val = client
-        .storage_with_keys("SubtensorModule", "SubnetworkN", vec![Value::from(netuid)])
+) -> BittensorResult<std::collections::HashMap<AccountId32, (u64, Vec<u8>)>> {
+    let entries = client
+        .storage_iter(
+            COMMITMENTS_PALLET,
+            "CommitmentOf",
+            vec![Value::from(netuid)],
+        )
         .await?;
-    let n = n_val
-        .and_then(|v| crate::utils::decoders::decode_u64(&v).ok())
-        .unwrap_or(0);
     let mut map = std::collections::HashMap::new();
-    for uid in 0..n {
-        if let Some(hk_val) = client
-            .storage_with_keys(
-                "SubtensorModule",
-                "Keys",
-                vec![Value::from(netuid), Value::from(uid)],
-            )
-            .await?
-        {
-            if let Ok(hk) = crate::utils::decoders::decode_account_id32(&hk_val) {
-                if let Some(val) = client
-                    .storage_with_keys(
-                        COMMITMENTS_PALLET,
-                        "CommitmentOf",
-                        vec![Value::from(netuid), Value::from_bytes(hk.encode())],
-                    )
-                    .await?
-                {
-                    let msg = decode_metadata_like(&val);
-                    if !msg.is_empty() {
-                        map.insert(hk, msg);
-                    }
-                }
-            }
-        }
+    for (key, val) in entries {
+        // Twox64Concat(hotkey) ends the key, so the hotkey is the last 32 bytes
+        let Some(hotkey) = key
+            .len()
+            .checked_sub(32)
+            .and_then(|start| <[u8; 32]>::try_from(&key[start..]).ok())
+        else {
+            continue;
+        };
+        map.insert(AccountId32::from(hotkey), decode_registration(&val)?);
     }
     Ok(map)
 }
 
+/// Get the block of a hotkey's last commitment: Commitments.LastCommitment[(netuid, hotkey)]
+pub async fn get_last_commitment_block(
+    client: &impl ChainBackend,
+    netuid: u16,
+    hotkey: &AccountId32,
+) -> BittensorResult<Option<u64>> {
+    let keys = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
+    match client
+        .storage_with_keys(COMMITMENTS_PALLET, "LastCommitment", keys)
+        .await?
+    {
+        Some(val) => Ok(Some(decode_u64(&val)?)),
+        None => Ok(None),
+    }
+}
+
+/// Blocks a hotkey must wait between commitments: Commitments.RateLimit
+pub async fn get_commitment_rate_limit(client: &impl ChainBackend) -> BittensorResult<u64> {
+    match client
+        .storage(COMMITMENTS_PALLET, "RateLimit", None)
+        .await?
+    {
+        Some(val) => Ok(decode_u64(&val)?),
+        None => Ok(0),
+    }
+}
+
 /// Get all revealed commitments for a subnet: Commitments.RevealedCommitments[(netuid, hotkey)]
 pub async fn get_all_revealed_commitments(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<std::collections::HashMap<AccountId32, Vec<(u64, String)>>> {
+) -> BittensorResult<std::collections::HashMap<AccountId32, Vec<(u64, String)>>> {
     let n_val = client
         .storage_with_keys("SubtensorModule", "SubnetworkN", vec![Value::from(netuid)])
         .await?;
@@ -205,10 +242,10 @@ pub async fn get_all_revealed_commitments(
 
 /// Get last commitment bonds reset block: Commitments.LastBondsReset[(netuid, hotkey)] -> bytes containing block
 pub async fn get_last_commitment_bonds_reset_block(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
-) -> Result<Option<u64>> {
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             COMMITMENTS_PALLET,
@@ -224,9 +261,9 @@ pub async fn get_last_commitment_bonds_reset_block(
 
 /// Get CRV3 weight commit info v2, decoded to (hotkey, block, message, reveal_round)
 pub async fn get_current_weight_commit_info_v2(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<Vec<(AccountId32, u64, String, u64)>> {
+) -> BittensorResult<Vec<(AccountId32, u64, String, u64)>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -240,8 +277,49 @@ pub async fn get_current_weight_commit_info_v2(
     Ok(Vec::new())
 }
 
-fn decode_metadata_like(value: &Value) -> String {
-    decode_bytes_as_utf8(value)
+/// Decode `Registration { deposit, block, info: CommitmentInfo { fields } }`,
+/// joining the bytes of the `RawN` fields
+fn decode_registration(value: &Value) -> Result<(u64, Vec<u8>)> {
+    let registration = decode_named_composite(value)?;
+    let block = registration
+        .get("block")
+        .ok_or_else(|| anyhow!("Commitment has no block"))
+        .and_then(decode_u64)?;
+    let info = registration
+        .get("info")
+        .ok_or_else(|| anyhow!("Commitment has no info"))
+        .and_then(decode_named_composite)?;
+    let fields = info
+        .get("fields")
+        .ok_or_else(|| anyhow!("Commitment has no fields"))?;
+
+    let mut data = Vec::new();
+    collect_raw_fields(fields, &mut data)?;
+    Ok((block, data))
+}
+
+/// Append the bytes of every non-empty `RawN` variant under `value`; the
+/// `BoundedVec` of fields may be wrapped in a single-field composite
+fn collect_raw_fields(value: &Value, data: &mut Vec<u8>) -> Result<()> {
+    match &value.value {
+        ValueDef::Variant(variant) if variant.name.starts_with("Raw") && variant.name != "Raw0" => {
+            data.extend(decode_bytes_from_composite(value)?);
+        }
+        ValueDef::Variant(variant) if variant.name.starts_with("Raw") => {}
+        ValueDef::Composite(Composite::Unnamed(values)) => {
+            for value in values {
+                collect_raw_fields(value, data)?;
+            }
+        }
+        ValueDef::Composite(Composite::Named(fields)) => {
+            for (_, value) in fields {
+                collect_raw_fields(value, data)?;
+            }
+        }
+        // Hashed and timelocked fields carry no raw payload
+        _ => {}
+    }
+    Ok(())
 }
 
 fn decode_bytes_as_utf8(value: &Value) -> String {
@@ -249,13 +327,16 @@ fn decode_bytes_as_utf8(value: &Value) -> String {
 }
 
 fn decode_revealed_vec(value: &Value) -> Vec<(u64, String)> {
-    decode_vec(value, |entry| {
-        decode_revealed_entry(entry).ok_or_else(|| anyhow!("invalid"))
+    decode_vec_lossy(value, |entry| {
+        decode_revealed_entry(entry)
+            .map(|(block, data)| (block, String::from_utf8_lossy(&data).into_owned()))
+            .ok_or_else(|| anyhow!("invalid"))
     })
     .unwrap_or_default()
 }
 
-fn decode_revealed_entry(value: &Value) -> Option<(u64, String)> {
+/// Decode a revealed `(data, block)` entry into `(block, data)`
+fn decode_revealed_entry(value: &Value) -> Option<(u64, Vec<u8>)> {
     let fields = match &value.value {
         ValueDef::Composite(Composite::Named(fields)) => fields.iter().map(|(_, v)| v).collect(),
         ValueDef::Composite(Composite::Unnamed(values)) => values.iter().collect(),
@@ -268,13 +349,13 @@ fn decode_revealed_entry(value: &Value) -> Option<(u64, String)> {
     if fields.len() < 2 {
         return None;
     }
-    let block = decode_u64(fields[0]).ok()?;
-    let message = decode_bytes_as_utf8(fields[1]);
-    Some((block, message))
+    let data = decode_bytes_from_composite(fields[0]).unwrap_or_default();
+    let block = decode_u64(fields[1]).ok()?;
+    Some((block, data))
 }
 
 fn decode_commit_info_v2(value: &Value) -> Vec<(AccountId32, u64, String, u64)> {
-    decode_vec(value, |entry| {
+    decode_vec_lossy(value, |entry| {
         decode_commit_info_v2_entry(entry).ok_or_else(|| anyhow!("invalid"))
     })
     .unwrap_or_default()
@@ -301,7 +382,7 @@ fn decode_commit_info_v2_entry(value: &Value) -> Option<(AccountId32, u64, Strin
 }
 
 fn decode_timelocked_weight_commit_info(value: &Value) -> Vec<(AccountId32, WeightCommitInfo)> {
-    decode_vec(value, |entry| {
+    decode_vec_lossy(value, |entry| {
         decode_timelocked_commit_entry(entry).ok_or_else(|| anyhow!("invalid"))
     })
     .unwrap_or_default()
@@ -330,7 +411,7 @@ fn decode_timelocked_commit_entry(value: &Value) -> Option<(AccountId32, WeightC
 }
 
 fn decode_vec_of_bytes(value: &Value) -> Vec<Vec<u8>> {
-    decode_vec(value, |entry| {
+    decode_vec_lossy(value, |entry| {
         decode_bytes(entry).map_err(|e| anyhow!("{e}"))
     })
     .unwrap_or_default()
@@ -338,7 +419,7 @@ fn decode_vec_of_bytes(value: &Value) -> Vec<Vec<u8>> {
 
 /// Get weight commitment for a hotkey on a subnet
 pub async fn get_weight_commitment(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
 ) -> BittensorResult<Option<WeightCommitInfo>> {
@@ -383,7 +464,7 @@ fn decode_weight_commit_info(value: &Value) -> Option<WeightCommitInfo> {
 
 /// Get all weight commitments for a subnet
 pub async fn get_all_weight_commitments(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<Vec<(AccountId32, WeightCommitInfo)>> {
     let n_val = client
@@ -434,7 +515,7 @@ pub async fn get_all_weight_commitments(
 
 /// Get pending weight commits for a subnet
 pub async fn get_pending_weight_commits(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<Vec<(AccountId32, WeightCommitInfo)>> {
     let commits_v2 = get_current_weight_commit_info_v2(client, netuid)
@@ -461,10 +542,10 @@ pub async fn get_pending_weight_commits(
 
 /// Get CRv4 timelocked commits for a subnet mechanism.
 pub async fn get_timelocked_weight_commits_v4(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     mechanism_id: u8,
-) -> Result<Vec<(AccountId32, WeightCommitInfo)>> {
+) -> BittensorResult<Vec<(AccountId32, WeightCommitInfo)>> {
     let netuid_index = crate::crv4::get_mechid_storage_index(netuid, mechanism_id);
     let key = vec![Value::from(netuid_index)];
     if let Some(val) = client
@@ -479,7 +560,7 @@ pub async fn get_timelocked_weight_commits_v4(
 
 /// Check if a hotkey has a pending weight commitment on a subnet
 pub async fn has_pending_commitment(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
 ) -> BittensorResult<bool> {
@@ -489,7 +570,7 @@ pub async fn has_pending_commitment(
 
 /// Get the last commit block for a hotkey on a subnet
 pub async fn get_last_commit_block(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
 ) -> BittensorResult<Option<u64>> {
@@ -534,4 +615,45 @@ mod tests {
         assert!(debug_str.contains("100"));
         assert!(debug_str.contains("5"));
     }
+
+    #[test]
+    fn test_decode_registration() {
+        let raw = |bytes: &[u8]| {
+            Value::unnamed_variant(format!("Raw{}", bytes.len()), [Value::from_bytes(bytes)])
+        };
+        let registration = Value::named_composite([
+            ("deposit", Value::u128(0)),
+            ("block", Value::u128(4_200)),
+            (
+                "info",
+                Value::named_composite([(
+                    "fields",
+                    Value::unnamed_composite(vec![Value::unnamed_composite(vec![
+                        raw(b"ipfs://"),
+                        Value::unnamed_variant("Sha256", [Value::from_bytes([1u8; 32])]),
+                        raw(b"Qm123"),
+                    ])]),
+                )]),
+            ),
+        ]);
+
+        let (block, data) = decode_registration(&registration).unwrap();
+        assert_eq!(block, 4_200);
+        assert_eq!(data, b"ipfs://Qm123");
+    }
+
+    #[test]
+    fn test_decode_revealed_entry() {
+        let entry = Value::unnamed_composite(vec![Value::from_bytes(b"revealed"), Value::u128(99)]);
+        assert_eq!(
+            decode_revealed_entry(&entry),
+            Some((99, b"revealed".to_vec()))
+        );
+
+        let entries = Value::unnamed_composite(vec![entry]);
+        assert_eq!(
+            decode_revealed_vec(&entries),
+            vec![(99, "revealed".to_string())]
+        );
+    }
 }
@@ -1,38 +1,22 @@
-use crate::chain::BittensorClient;
+use crate::chain::ChainBackend;
+use crate::errors::BittensorResult;
 use crate::types::delegate::DelegateInfoBase;
 use crate::types::{DelegateInfo, DelegatedInfo};
 use crate::utils::balance_newtypes::Rao;
+use crate::utils::decoders::runtime::decode_delegates;
 use crate::utils::decoders::{decode_account_id32, decode_u16};
-use anyhow::Result;
-use parity_scale_codec::{Compact, Decode, Encode};
+use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use std::collections::{HashMap, HashSet};
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
-// Type alias to simplify complex nominator stakes type
-type NominatorStakes = Vec<(Compact<u16>, Compact<u64>)>;
-
-/// DelegateInfo structure matching the on-chain SCALE encoding
-/// This is the exact structure used in subtensor runtime
-#[derive(Decode, Clone, Debug)]
-struct DelegateInfoRaw {
-    delegate_ss58: AccountId32,
-    take: Compact<u16>,
-    nominators: Vec<(AccountId32, NominatorStakes)>,
-    owner_ss58: AccountId32,
-    registrations: Vec<Compact<u16>>,
-    validator_permits: Vec<Compact<u16>>,
-    return_per_1000: Compact<u64>,
-    total_daily_return: Compact<u64>,
-}
-
 /// Get delegate by hotkey - built from storage
 pub async fn get_delegate_by_hotkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
-) -> Result<Option<DelegateInfo>> {
+) -> BittensorResult<Option<DelegateInfo>> {
     // Owner[hotkey] -> coldkey
     let owner_val = client
         .storage_with_keys(
@@ -167,7 +151,9 @@ pub async fn get_delegate_by_hotkey(
 }
 
 /// Get all delegate identities (delegate hotkeys) by scanning subnets
-pub async fn get_delegate_identities(client: &BittensorClient) -> Result<Vec<AccountId32>> {
+pub async fn get_delegate_identities(
+    client: &impl ChainBackend,
+) -> BittensorResult<Vec<AccountId32>> {
     // Collect unique hotkeys across all subnets, then filter those with a non-zero delegate take
     let total_networks_val = client
         .storage(SUBTENSOR_MODULE, "TotalNetworks", None)
@@ -217,9 +203,9 @@ pub async fn get_delegate_identities(client: &BittensorClient) -> Result<Vec<Acc
 
 /// Get delegated stake information (per coldkey)
 pub async fn get_delegated(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     coldkey: &AccountId32,
-) -> Result<Vec<DelegatedInfo>> {
+) -> BittensorResult<Vec<DelegatedInfo>> {
     // For each delegate hotkey, check if this coldkey appears in their nominators and collect per-netuid stakes
     let hotkeys = get_delegate_identities(client).await?;
     let mut out: Vec<DelegatedInfo> = Vec::new();
@@ -240,64 +226,20 @@ pub async fn get_delegated(
 
 /// Get all delegates using runtime API (single RPC call like Python SDK)
 /// Optimized version using direct SCALE decoding for maximum performance
-pub async fn get_delegates(client: &BittensorClient) -> Result<Vec<DelegateInfo>> {
+pub async fn get_delegates(client: &impl ChainBackend) -> BittensorResult<Vec<DelegateInfo>> {
     // Use runtime_api_call which returns raw bytes for direct SCALE decoding
     // This is much faster than going through Value parsing
     let raw_bytes = client
         .runtime_api_call("DelegateInfoRuntimeApi", "get_delegates", None)
         .await?;
 
-    if raw_bytes.is_empty() {
-        return Ok(Vec::new());
-    }
-
-    // Decode Vec<DelegateInfoRaw> directly from SCALE bytes
-    let raw_delegates: Vec<DelegateInfoRaw> =
-        Vec::<DelegateInfoRaw>::decode(&mut &raw_bytes[..])
-            .map_err(|e| anyhow::anyhow!("Failed to decode delegates from runtime API: {}", e))?;
-
-    // Convert to our DelegateInfo type
-    let delegates: Vec<DelegateInfo> = raw_delegates
-        .into_iter()
-        .map(|raw| {
-            // Convert nominators: Vec<(AccountId32, Vec<(netuid, stake)>)> to HashMap
-            let mut nominators: HashMap<AccountId32, HashMap<u16, u128>> = HashMap::new();
-            let mut total_stake: HashMap<u16, Rao> = HashMap::new();
-
-            for (nominator, stakes) in raw.nominators {
-                let mut stake_map: HashMap<u16, u128> = HashMap::new();
-                for (netuid, stake) in stakes {
-                    let netuid_val = netuid.0;
-                    let stake_val = stake.0 as u128;
-                    stake_map.insert(netuid_val, stake_val);
-
-                    let entry = total_stake.entry(netuid_val).or_insert(Rao::ZERO);
-                    *entry = entry.saturating_add(Rao::from(stake_val));
-                }
-                nominators.insert(nominator, stake_map);
-            }
-
-            DelegateInfo {
-                base: DelegateInfoBase {
-                    hotkey_ss58: raw.delegate_ss58,
-                    owner_ss58: raw.owner_ss58,
-                    take: raw.take.0 as f64 / u16::MAX as f64,
-                    validator_permits: raw.validator_permits.iter().map(|c| c.0).collect(),
-                    registrations: raw.registrations.iter().map(|c| c.0).collect(),
-                    return_per_1000: Rao::from(raw.return_per_1000.0 as u128),
-                    total_daily_return: Rao::from(raw.total_daily_return.0 as u128),
-                },
-                total_stake,
-                nominators,
-            }
-        })
-        .collect();
-
-    Ok(delegates)
+    Ok(decode_delegates(&raw_bytes)?)
 }
 
 /// Get all delegates by building from storage (fallback method, slower but complete)
-pub async fn get_delegates_from_storage(client: &BittensorClient) -> Result<Vec<DelegateInfo>> {
+pub async fn get_delegates_from_storage(
+    client: &impl ChainBackend,
+) -> BittensorResult<Vec<DelegateInfo>> {
     let ids = get_delegate_identities(client).await?;
     let mut delegates = Vec::new();
     for hk in ids.iter() {
@@ -309,7 +251,10 @@ pub async fn get_delegates_from_storage(client: &BittensorClient) -> Result<Vec<
 }
 
 /// Get delegate take (commission)
-pub async fn get_delegate_take(client: &BittensorClient, hotkey: &AccountId32) -> Result<f64> {
+pub async fn get_delegate_take(
+    client: &impl ChainBackend,
+    hotkey: &AccountId32,
+) -> BittensorResult<f64> {
     let keys = vec![Value::from_bytes(hotkey.encode())];
 
     if let Some(take_val) = client
@@ -328,7 +273,10 @@ pub async fn get_delegate_take(client: &BittensorClient, hotkey: &AccountId32) -
 
 /// Get delegate take as raw u16 value (0-65535)
 /// Direct storage read without normalization
-pub async fn get_delegate_take_raw(client: &BittensorClient, hotkey: &AccountId32) -> Result<u16> {
+pub async fn get_delegate_take_raw(
+    client: &impl ChainBackend,
+    hotkey: &AccountId32,
+) -> BittensorResult<u16> {
     let keys = vec![Value::from_bytes(hotkey.encode())];
 
     if let Some(take_val) = client
@@ -345,9 +293,9 @@ pub async fn get_delegate_take_raw(client: &BittensorClient, hotkey: &AccountId3
 /// Get total hotkey stake across all subnets
 /// Direct storage read from SubtensorModule::TotalHotkeyStake
 pub async fn get_total_hotkey_stake(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
-) -> Result<u128> {
+) -> BittensorResult<u128> {
     let keys = vec![Value::from_bytes(hotkey.encode())];
 
     if let Some(val) = client
@@ -364,9 +312,9 @@ pub async fn get_total_hotkey_stake(
 /// Optimized: Get delegate info using direct storage reads (O(D) instead of O(N*M))
 /// Reads Delegates map for take, TotalHotkeyStake for total stake
 pub async fn get_delegate_info_optimized(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
-) -> Result<Option<DelegateInfo>> {
+) -> BittensorResult<Option<DelegateInfo>> {
     let take_raw = get_delegate_take_raw(client, hotkey).await?;
     if take_raw == 0 {
         return Ok(None);
@@ -456,6 +404,9 @@ pub async fn get_delegate_info_optimized(
 }
 
 /// Check if hotkey is a delegate
-pub async fn is_hotkey_delegate(client: &BittensorClient, hotkey: &AccountId32) -> Result<bool> {
+pub async fn is_hotkey_delegate(
+    client: &impl ChainBackend,
+    hotkey: &AccountId32,
+) -> BittensorResult<bool> {
     Ok(get_delegate_take(client, hotkey).await.unwrap_or(0.0) > 0.0)
 }
@@ -3,15 +3,16 @@
 //! This module provides functions to query individual subnet hyperparameters
 //! from the Bittensor chain, matching the Python SDK SubnetHyperparameters structure.
 
-use crate::chain::BittensorClient;
+use crate::chain::ChainBackend;
 use crate::errors::{BittensorError, BittensorResult, ChainQueryError};
 use crate::utils::decoders::{decode_bool, decode_u16, decode_u64};
+use serde::Serialize;
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
 /// Complete subnet hyperparameters (matches Python SDK SubnetHyperparameters)
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct SubnetHyperparameters {
     pub rho: u16,
     pub kappa: u16,
@@ -26,6 +27,7 @@ pub struct SubnetHyperparameters {
     pub adjustment_interval: u16,
     pub activity_cutoff: u16,
     pub registration_allowed: bool,
+    pub pow_registration_allowed: bool,
     pub target_regs_per_interval: u16,
     pub min_burn: u64,
     pub max_burn: u64,
@@ -47,7 +49,7 @@ pub struct SubnetHyperparameters {
 
 /// Helper to fetch a u16 storage value for a subnet
 async fn fetch_u16_param(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     entry: &str,
     netuid: u16,
 ) -> BittensorResult<u16> {
@@ -74,7 +76,7 @@ async fn fetch_u16_param(
 
 /// Helper to fetch a u64 storage value for a subnet
 async fn fetch_u64_param(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     entry: &str,
     netuid: u16,
 ) -> BittensorResult<u64> {
@@ -101,7 +103,7 @@ async fn fetch_u64_param(
 
 /// Helper to fetch a bool storage value for a subnet
 async fn fetch_bool_param(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     entry: &str,
     netuid: u16,
 ) -> BittensorResult<bool> {
@@ -128,7 +130,7 @@ async fn fetch_bool_param(
 
 /// Get all hyperparameters for a subnet
 pub async fn get_subnet_hyperparameters(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<SubnetHyperparameters> {
     // Fetch all hyperparameters in parallel for efficiency
@@ -146,6 +148,7 @@ pub async fn get_subnet_hyperparameters(
         adjustment_interval,
         activity_cutoff,
         registration_allowed,
+        pow_registration_allowed,
         target_regs_per_interval,
         min_burn,
         max_burn,
@@ -160,6 +163,7 @@ pub async fn get_subnet_hyperparameters(
         alpha_high,
         alpha_low,
         liquid_alpha_enabled,
+        max_allowed_uids,
     ) = tokio::join!(
         get_rho(client, netuid),
         get_kappa(client, netuid),
@@ -174,6 +178,7 @@ pub async fn get_subnet_hyperparameters(
         get_adjustment_interval(client, netuid),
         get_activity_cutoff(client, netuid),
         get_registration_allowed(client, netuid),
+        get_pow_registration_allowed(client, netuid),
         get_target_regs_per_interval(client, netuid),
         get_min_burn(client, netuid),
         get_max_burn(client, netuid),
@@ -188,6 +193,7 @@ pub async fn get_subnet_hyperparameters(
         get_alpha_high(client, netuid),
         get_alpha_low(client, netuid),
         get_liquid_alpha_enabled(client, netuid),
+        get_max_allowed_uids(client, netuid),
     );
 
     let max_weights_limit_value = max_weights_limit.unwrap_or(0);
@@ -205,6 +211,7 @@ pub async fn get_subnet_hyperparameters(
         adjustment_interval: adjustment_interval.unwrap_or(0),
         activity_cutoff: activity_cutoff.unwrap_or(0),
         registration_allowed: registration_allowed.unwrap_or(false),
+        pow_registration_allowed: pow_registration_allowed.unwrap_or(false),
         target_regs_per_interval: target_regs_per_interval.unwrap_or(0),
         min_burn: min_burn.unwrap_or(0),
         max_burn: max_burn.unwrap_or(0),
@@ -221,32 +228,32 @@ pub async fn get_subnet_hyperparameters(
         liquid_alpha_enabled: liquid_alpha_enabled.unwrap_or(false),
         min_stake: 0,
         max_weight_limit: max_weights_limit_value,
-        max_allowed_uids: 0,
+        max_allowed_uids: max_allowed_uids.unwrap_or(0),
     })
 }
 
 /// Get Rho parameter for a subnet
 /// Rho is the ratio for calculating the weights to set
-pub async fn get_rho(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_rho(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
     fetch_u16_param(client, "Rho", netuid).await
 }
 
 /// Get Kappa parameter for a subnet
 /// Kappa is used in the Yuma Consensus algorithm
-pub async fn get_kappa(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_kappa(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
     fetch_u16_param(client, "Kappa", netuid).await
 }
 
 /// Get immunity period for a subnet
 /// Number of blocks a neuron is protected from deregistration after registration
-pub async fn get_immunity_period(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_immunity_period(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
     fetch_u16_param(client, "ImmunityPeriod", netuid).await
 }
 
 /// Get minimum allowed weights for a subnet
 /// Minimum number of weights each validator must set
 pub async fn get_min_allowed_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<u16> {
     fetch_u16_param(client, "MinAllowedWeights", netuid).await
@@ -254,38 +261,41 @@ pub async fn get_min_allowed_weights(
 
 /// Get maximum weights limit for a subnet
 /// Maximum weight value that can be assigned (normalized to u16 range)
-pub async fn get_max_weights_limit(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_max_weights_limit(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<u16> {
     fetch_u16_param(client, "MaxWeightsLimit", netuid).await
 }
 
 /// Get tempo for a subnet
 /// Number of blocks between weight setting epochs
-pub async fn get_tempo(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_tempo(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
     fetch_u16_param(client, "Tempo", netuid).await
 }
 
 /// Get minimum difficulty for a subnet
 /// Minimum PoW difficulty for registration
-pub async fn get_min_difficulty(client: &BittensorClient, netuid: u16) -> BittensorResult<u64> {
+pub async fn get_min_difficulty(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u64> {
     fetch_u64_param(client, "MinDifficulty", netuid).await
 }
 
 /// Get maximum difficulty for a subnet
 /// Maximum PoW difficulty for registration
-pub async fn get_max_difficulty(client: &BittensorClient, netuid: u16) -> BittensorResult<u64> {
+pub async fn get_max_difficulty(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u64> {
     fetch_u64_param(client, "MaxDifficulty", netuid).await
 }
 
 /// Get current difficulty for a subnet
 /// Current PoW difficulty for registration
-pub async fn get_difficulty(client: &BittensorClient, netuid: u16) -> BittensorResult<u64> {
+pub async fn get_difficulty(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u64> {
     fetch_u64_param(client, "Difficulty", netuid).await
 }
 
 /// Get weights version key for a subnet
-/// Version number for weight format compatibility
+/// Minimum `version_key` the chain accepts weights with; 0 accepts any
 pub async fn get_weights_version_key(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<u64> {
     fetch_u64_param(client, "WeightsVersionKey", netuid).await
@@ -293,14 +303,17 @@ pub async fn get_weights_version_key(
 
 /// Get weights rate limit for a subnet
 /// Minimum blocks between weight setting transactions
-pub async fn get_weights_rate_limit(client: &BittensorClient, netuid: u16) -> BittensorResult<u64> {
+pub async fn get_weights_rate_limit(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<u64> {
     fetch_u64_param(client, "WeightsSetRateLimit", netuid).await
 }
 
 /// Get adjustment interval for a subnet
 /// Number of blocks between difficulty adjustments
 pub async fn get_adjustment_interval(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<u16> {
     fetch_u16_param(client, "AdjustmentInterval", netuid).await
@@ -308,23 +321,32 @@ pub async fn get_adjustment_interval(
 
 /// Get activity cutoff for a subnet
 /// Number of blocks of inactivity before a neuron becomes inactive
-pub async fn get_activity_cutoff(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_activity_cutoff(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
     fetch_u16_param(client, "ActivityCutoff", netuid).await
 }
 
 /// Check if registration is allowed for a subnet
 /// Whether new neurons can register on this subnet
 pub async fn get_registration_allowed(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<bool> {
     fetch_bool_param(client, "NetworkRegistrationAllowed", netuid).await
 }
 
+/// Check if PoW registration is allowed for a subnet
+/// Whether neurons can register by solving a proof of work instead of burning
+pub async fn get_pow_registration_allowed(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<bool> {
+    fetch_bool_param(client, "NetworkPowRegistrationAllowed", netuid).await
+}
+
 /// Get target registrations per interval for a subnet
 /// Target number of registrations per adjustment interval
 pub async fn get_target_regs_per_interval(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<u16> {
     fetch_u16_param(client, "TargetRegistrationsPerInterval", netuid).await
@@ -332,20 +354,20 @@ pub async fn get_target_regs_per_interval(
 
 /// Get minimum burn amount for a subnet (in RAO)
 /// Minimum amount of TAO to burn for registration
-pub async fn get_min_burn(client: &BittensorClient, netuid: u16) -> BittensorResult<u64> {
+pub async fn get_min_burn(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u64> {
     fetch_u64_param(client, "MinBurn", netuid).await
 }
 
 /// Get maximum burn amount for a subnet (in RAO)
 /// Maximum amount of TAO to burn for registration
-pub async fn get_max_burn(client: &BittensorClient, netuid: u16) -> BittensorResult<u64> {
+pub async fn get_max_burn(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u64> {
     fetch_u64_param(client, "MaxBurn", netuid).await
 }
 
 /// Get bonds moving average for a subnet
 /// Rate at which bonds update (higher = faster updates)
 pub async fn get_bonds_moving_average(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<u64> {
     fetch_u64_param(client, "BondsMovingAverage", netuid).await
@@ -353,32 +375,43 @@ pub async fn get_bonds_moving_average(
 
 /// Get maximum registrations per block for a subnet
 /// Maximum number of neurons that can register in a single block
-pub async fn get_max_regs_per_block(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_max_regs_per_block(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<u16> {
     fetch_u16_param(client, "MaxRegistrationsPerBlock", netuid).await
 }
 
 /// Get serving rate limit for a subnet
 /// Minimum blocks between axon serving info updates
-pub async fn get_serving_rate_limit(client: &BittensorClient, netuid: u16) -> BittensorResult<u64> {
+pub async fn get_serving_rate_limit(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<u64> {
     fetch_u64_param(client, "ServingRateLimit", netuid).await
 }
 
 /// Get maximum validators for a subnet
 /// Maximum number of validators allowed on the subnet
-pub async fn get_max_validators(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_max_validators(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
     fetch_u16_param(client, "MaxAllowedValidators", netuid).await
 }
 
+/// Get maximum number of UIDs for a subnet
+pub async fn get_max_allowed_uids(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
+    fetch_u16_param(client, "MaxAllowedUids", netuid).await
+}
+
 /// Get adjustment alpha for a subnet
 /// Alpha parameter for difficulty adjustment algorithm
-pub async fn get_adjustment_alpha(client: &BittensorClient, netuid: u16) -> BittensorResult<u64> {
+pub async fn get_adjustment_alpha(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u64> {
     fetch_u64_param(client, "AdjustmentAlpha", netuid).await
 }
 
 /// Get commit reveal weights interval for a subnet
 /// Number of blocks for commit-reveal weight setting cycle
 pub async fn get_commit_reveal_weights_interval(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<u64> {
     fetch_u64_param(client, "CommitRevealWeightsInterval", netuid).await
@@ -386,7 +419,7 @@ pub async fn get_commit_reveal_weights_interval(
 
 /// Check if commit-reveal weights mechanism is enabled for a subnet
 pub async fn get_commit_reveal_weights_enabled(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<bool> {
     fetch_bool_param(client, "CommitRevealWeightsEnabled", netuid).await
@@ -394,19 +427,19 @@ pub async fn get_commit_reveal_weights_enabled(
 
 /// Get alpha high parameter for liquid alpha
 /// Upper bound for alpha in liquid alpha mechanism
-pub async fn get_alpha_high(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_alpha_high(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
     fetch_u16_param(client, "AlphaHigh", netuid).await
 }
 
 /// Get alpha low parameter for liquid alpha
 /// Lower bound for alpha in liquid alpha mechanism
-pub async fn get_alpha_low(client: &BittensorClient, netuid: u16) -> BittensorResult<u16> {
+pub async fn get_alpha_low(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u16> {
     fetch_u16_param(client, "AlphaLow", netuid).await
 }
 
 /// Check if liquid alpha mechanism is enabled for a subnet
 pub async fn get_liquid_alpha_enabled(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
 ) -> BittensorResult<bool> {
     fetch_bool_param(client, "LiquidAlphaOn", netuid).await
@@ -1,15 +1,15 @@
-use crate::chain::BittensorClient;
+use crate::chain::ChainBackend;
+use crate::errors::BittensorResult;
 use crate::types::ChainIdentity;
-use anyhow::Result;
 use parity_scale_codec::Encode;
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
 pub async fn query_identity(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     coldkey: &sp_core::crypto::AccountId32,
-) -> Result<Option<ChainIdentity>> {
+) -> BittensorResult<Option<ChainIdentity>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -1,18 +1,23 @@
-use crate::chain::BittensorClient;
-use crate::core::constants::{MAX_TICK, MIN_TICK, RAOPERTAO, TICK_STEP};
+pub mod ticks;
+
+use crate::chain::ChainBackend;
+use crate::core::constants::RAOPERTAO;
+use crate::errors::{BittensorResult, ChainQueryError};
 use crate::types::LiquidityPosition;
 use crate::utils::decoders::{
-    decode_fixed_u64f64, decode_i32, decode_named_composite, decode_u128, decode_u64, decode_vec,
+    decode_fixed_u64f64, decode_i32, decode_named_composite, decode_u128, decode_u64,
+    decode_vec_lossy,
 };
 use anyhow::Result;
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
+use ticks::{price_to_tick, tick_to_price};
 
 const SWAP_PALLET: &str = "Swap";
 
 async fn fetch_positions_value(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     coldkey: &AccountId32,
 ) -> Result<Option<Value>> {
@@ -65,11 +70,11 @@ async fn fetch_positions_value(
 }
 
 pub async fn get_liquidity_list(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     coldkey: &AccountId32,
     _block: Option<u64>,
-) -> Result<Vec<LiquidityPosition>> {
+) -> BittensorResult<Vec<LiquidityPosition>> {
     // Fetch global fees and sqrt price
     let fee_global_tao = read_fixed_u64f64(
         client,
@@ -104,7 +109,7 @@ pub async fn get_liquidity_list(
     let mut out: Vec<LiquidityPosition> = Vec::new();
 
     // Try to decode as a vector of positions
-    if let Ok(positions) = decode_vec(&positions_val, |v| Ok(v.clone())) {
+    if let Ok(positions) = decode_vec_lossy(&positions_val, |v| Ok(v.clone())) {
         for position_val in positions {
             let (id, tick_low, tick_high, liquidity, pos_netuid) =
                 parse_position_fields(&position_val);
@@ -175,6 +180,8 @@ pub async fn get_liquidity_list(
 
             out.push(LiquidityPosition {
                 id: id.unwrap_or(0),
+                tick_low,
+                tick_high,
                 price_low_rao: price_low.max(0.0) as u128,
                 price_high_rao: price_high.max(0.0) as u128,
                 liquidity_rao: liquidity,
@@ -191,7 +198,34 @@ pub async fn get_liquidity_list(
     Ok(out)
 }
 
-pub async fn get_current_subnet_price_rao(client: &BittensorClient, netuid: u16) -> Result<u128> {
+/// Get a coldkey's liquidity positions on a subnet, with fees owed
+pub async fn get_positions(
+    client: &impl ChainBackend,
+    coldkey: &AccountId32,
+    netuid: u16,
+) -> BittensorResult<Vec<LiquidityPosition>> {
+    get_liquidity_list(client, netuid, coldkey, None).await
+}
+
+/// Get the current alpha price of a subnet's pool in TAO
+pub async fn get_current_subnet_price(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<f64> {
+    let sqrt_price = read_fixed_u64f64(
+        client,
+        SWAP_PALLET,
+        "AlphaSqrtPrice",
+        vec![Value::u128(netuid as u128)],
+    )
+    .await?;
+    Ok(sqrt_price * sqrt_price)
+}
+
+pub async fn get_current_subnet_price_rao(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<u128> {
     let sqrt_price = read_fixed_u64f64(
         client,
         SWAP_PALLET,
@@ -229,7 +263,7 @@ fn parse_position_fields(value: &Value) -> (Option<u64>, i32, i32, u128, Option<
 }
 
 async fn read_fixed_u64f64(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     pallet: &str,
     entry: &str,
     keys: Vec<Value>,
@@ -237,13 +271,13 @@ async fn read_fixed_u64f64(
     let val = client
         .storage_with_keys(pallet, entry, keys)
         .await?
-        .ok_or_else(|| anyhow::anyhow!(format!("{}.{} not found", pallet, entry)))?;
+        .ok_or_else(|| ChainQueryError::new(format!("{}.{} not found", pallet, entry)))?;
     Ok(decode_fixed_u64f64(&val).unwrap_or(0.0))
 }
 
 // Try multiple key shapes for Swap.Ticks storage
 async fn fetch_tick_value(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     tick: i32,
     netuid: u16,
 ) -> Result<Option<Value>> {
@@ -345,18 +379,3 @@ fn extract_fixed_field(value: &Value, key: &str) -> Option<f64> {
     }
     None
 }
-
-fn price_to_tick(price: f64) -> i32 {
-    if price <= 0.0 {
-        return 0;
-    }
-    let tick = (price.ln() / TICK_STEP.ln()) as i32;
-    tick.clamp(MIN_TICK, MAX_TICK)
-}
-
-fn tick_to_price(tick: i32) -> f64 {
-    if !(MIN_TICK..=MAX_TICK).contains(&tick) {
-        return 0.0;
-    }
-    TICK_STEP.powi(tick)
-}
@@ -0,0 +1,152 @@
+//! Tick and price math for the swap pallet's concentrated-liquidity pools
+//!
+//! Prices are TAO per alpha. Tick `i` corresponds to price `1.0001^i`, the
+//! same spacing the subtensor swap pallet uses, bounded by `MIN_TICK` and
+//! `MAX_TICK`.
+
+use crate::core::constants::{MAX_TICK, MIN_TICK, TICK_STEP};
+use crate::errors::{BittensorResult, StakeFailed};
+
+/// Convert a price to its tick index
+///
+/// Truncates toward zero like the Python SDK and clamps to the valid tick
+/// range. Non-positive prices map to tick 0.
+pub fn price_to_tick(price: f64) -> i32 {
+    if price <= 0.0 {
+        return 0;
+    }
+    let tick = (price.ln() / TICK_STEP.ln()) as i32;
+    tick.clamp(MIN_TICK, MAX_TICK)
+}
+
+/// Convert a tick index to its price; out-of-range ticks map to 0
+pub fn tick_to_price(tick: i32) -> f64 {
+    if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+        return 0.0;
+    }
+    TICK_STEP.powi(tick)
+}
+
+/// Convert a price range to `(tick_low, tick_high)`, rejecting empty ranges
+pub fn price_range_to_ticks(price_low: f64, price_high: f64) -> BittensorResult<(i32, i32)> {
+    if !(price_low.is_finite() && price_high.is_finite()) || price_low <= 0.0 {
+        return Err(StakeFailed::new("Prices must be positive and finite").into());
+    }
+    if price_low >= price_high {
+        return Err(StakeFailed::new(format!(
+            "price_low ({}) must be below price_high ({})",
+            price_low, price_high
+        ))
+        .into());
+    }
+    let tick_low = price_to_tick(price_low);
+    let tick_high = price_to_tick(price_high);
+    if tick_low >= tick_high {
+        return Err(StakeFailed::new(format!(
+            "Price range {}..{} is narrower than one tick",
+            price_low, price_high
+        ))
+        .into());
+    }
+    Ok((tick_low, tick_high))
+}
+
+/// Liquidity provided by depositing `tao_rao` into `[tick_low, tick_high]`
+///
+/// `current_price` is the pool's spot price. A range entirely below the spot
+/// price is all TAO; a range straddling it also needs alpha (see
+/// [`token_amounts`]). A range entirely above the spot price holds only alpha,
+/// so it cannot be funded with TAO and is rejected.
+pub fn liquidity_for_tao(
+    tao_rao: u128,
+    current_price: f64,
+    tick_low: i32,
+    tick_high: i32,
+) -> BittensorResult<u64> {
+    let sqrt_low = tick_to_price(tick_low).sqrt();
+    let sqrt_high = tick_to_price(tick_high).sqrt();
+    let sqrt_current = current_price.max(0.0).sqrt().min(sqrt_high);
+
+    if sqrt_current <= sqrt_low {
+        return Err(StakeFailed::with_amount(
+            format!(
+                "Range starts above the current price {}; it can only hold alpha",
+                current_price
+            ),
+            tao_rao,
+        )
+        .into());
+    }
+
+    let liquidity = tao_rao as f64 / (sqrt_current - sqrt_low);
+    if !liquidity.is_finite() || liquidity < 1.0 || liquidity > u64::MAX as f64 {
+        return Err(StakeFailed::with_amount(
+            format!("Liquidity {} out of range for {} RAO", liquidity, tao_rao),
+            tao_rao,
+        )
+        .into());
+    }
+    Ok(liquidity as u64)
+}
+
+/// Token amounts `(alpha_rao, tao_rao)` backing `liquidity` in a tick range
+pub fn token_amounts(
+    liquidity: u64,
+    current_price: f64,
+    tick_low: i32,
+    tick_high: i32,
+) -> (u128, u128) {
+    let sqrt_low = tick_to_price(tick_low).sqrt();
+    let sqrt_high = tick_to_price(tick_high).sqrt();
+    let sqrt_current = current_price.max(0.0).sqrt().clamp(sqrt_low, sqrt_high);
+    let liquidity = liquidity as f64;
+
+    let alpha = liquidity * (1.0 / sqrt_current - 1.0 / sqrt_high);
+    let tao = liquidity * (sqrt_current - sqrt_low);
+    (alpha.max(0.0) as u128, tao.max(0.0) as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_tick_round_trip() {
+        assert_eq!(price_to_tick(1.0), 0);
+        assert_eq!(tick_to_price(0), 1.0);
+        for tick in [-50_000, -100, -1, 1, 100, 50_000] {
+            let price = tick_to_price(tick);
+            // Truncation toward zero can land one tick inward
+            assert!((price_to_tick(price) - tick).abs() <= 1, "tick {}", tick);
+        }
+        assert_eq!(price_to_tick(0.0), 0);
+        assert_eq!(price_to_tick(f64::MAX), MAX_TICK);
+        assert_eq!(tick_to_price(MAX_TICK + 1), 0.0);
+    }
+
+    #[test]
+    fn test_price_range_to_ticks() {
+        let (low, high) = price_range_to_ticks(0.01, 0.02).unwrap();
+        assert!(low < high);
+        assert!(price_range_to_ticks(0.02, 0.01).is_err());
+        assert!(price_range_to_ticks(0.0, 0.01).is_err());
+        assert!(price_range_to_ticks(1.0, 1.000_01).is_err());
+    }
+
+    #[test]
+    fn test_liquidity_for_tao_round_trip() {
+        let (low, high) = price_range_to_ticks(0.005, 0.02).unwrap();
+        let tao = 10_000_000_000u128;
+        let liquidity = liquidity_for_tao(tao, 0.01, low, high).unwrap();
+        let (alpha, tao_back) = token_amounts(liquidity, 0.01, low, high);
+        assert!(tao_back.abs_diff(tao) <= 1_000);
+        assert!(alpha > 0);
+
+        // Range below the spot price is all TAO
+        let (alpha, _) = token_amounts(liquidity, 0.05, low, high);
+        assert_eq!(alpha, 0);
+
+        // Range above the spot price cannot be funded with TAO
+        assert!(liquidity_for_tao(tao, 0.001, low, high).is_err());
+    }
+}
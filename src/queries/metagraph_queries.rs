@@ -1,18 +1,123 @@
-use crate::chain::BittensorClient;
+use crate::chain::{BittensorClient, ChainBackend};
+use crate::errors::{BittensorError, BittensorResult, ChainQueryError, NotRegistered};
 use crate::metagraph::Metagraph;
 use crate::types::MetagraphInfo;
-use anyhow::Result;
+use crate::utils::decoders::{decode_vec_bool, decode_vec_u64};
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
 /// Get metagraph information for a subnet
-pub async fn get_metagraph_info(client: &BittensorClient, netuid: u16) -> Result<Metagraph> {
-    crate::metagraph::sync::sync_metagraph(client, netuid).await
+pub async fn get_metagraph_info(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Metagraph> {
+    Ok(crate::metagraph::sync::sync_metagraph(client, netuid).await?)
+}
+
+/// Reconstruct the metagraph of a subnet at a historical block height
+///
+/// Reads the per-UID subnet state pinned to `height`, so the node must still
+/// have the state of that block (use an archive node for old heights). The
+/// result is a lite metagraph without axon, weight or bond data, and needs a
+/// runtime with `get_subnet_state` (spec version
+/// [`SUBNET_STATE_MIN_SPEC_VERSION`](crate::queries::subnets::SUBNET_STATE_MIN_SPEC_VERSION)).
+pub async fn metagraph_at_block(
+    client: &BittensorClient,
+    netuid: u16,
+    height: u64,
+) -> BittensorResult<Metagraph> {
+    use crate::queries::subnets::{decode_subnet_state, SUBNET_STATE_MIN_SPEC_VERSION};
+    use parity_scale_codec::Encode;
+
+    let block = client.at_height(height).await?;
+    let spec_version = block.spec_version().await?;
+    if spec_version < SUBNET_STATE_MIN_SPEC_VERSION {
+        return Err(ChainQueryError::new(format!(
+            "Runtime at block {} (spec version {}) predates get_subnet_state (spec version {})",
+            height, spec_version, SUBNET_STATE_MIN_SPEC_VERSION
+        ))
+        .into());
+    }
+
+    let bytes = block
+        .runtime_api_call(
+            "SubnetInfoRuntimeApi",
+            "get_subnet_state",
+            Some(netuid.encode()),
+        )
+        .await
+        .map_err(|e| BittensorError::from_chain("Failed to call get_subnet_state", e))?;
+    let state = decode_subnet_state(&bytes)?.ok_or_else(|| {
+        ChainQueryError::new(format!(
+            "Subnet {} did not exist at block {}",
+            netuid, height
+        ))
+    })?;
+
+    Ok(crate::metagraph::sync::metagraph_from_subnet_state(
+        &state, height,
+    ))
+}
+
+/// Get the block at which a neuron last set weights
+///
+/// Reads the subnet's `LastUpdate` vector (main mechanism) and indexes it by
+/// `uid`; errors if the UID is not on the subnet.
+pub async fn get_last_update(
+    client: &impl ChainBackend,
+    netuid: u16,
+    uid: u16,
+) -> BittensorResult<u64> {
+    let last_updates = client
+        .storage_with_keys(
+            SUBTENSOR_MODULE,
+            "LastUpdate",
+            vec![Value::u128(netuid as u128)],
+        )
+        .await?
+        .map(|v| decode_vec_u64(&v))
+        .transpose()?
+        .unwrap_or_default();
+    last_updates.get(uid as usize).copied().ok_or_else(|| {
+        NotRegistered::new(format!("UID {} not found on subnet {}", uid, netuid)).into()
+    })
+}
+
+/// Get the number of blocks since a neuron last set weights
+pub async fn blocks_since_last_update(
+    client: &impl ChainBackend,
+    netuid: u16,
+    uid: u16,
+) -> BittensorResult<u64> {
+    let (current_block, last_update) = tokio::try_join!(
+        async { client.block_number().await.map_err(BittensorError::from) },
+        get_last_update(client, netuid, uid),
+    )?;
+    Ok(current_block.saturating_sub(last_update))
+}
+
+/// Get the validator permit of every UID on a subnet
+pub async fn get_validator_permits(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Vec<bool>> {
+    Ok(client
+        .storage_with_keys(
+            SUBTENSOR_MODULE,
+            "ValidatorPermit",
+            vec![Value::u128(netuid as u128)],
+        )
+        .await?
+        .map(|v| decode_vec_bool(&v))
+        .transpose()?
+        .unwrap_or_default())
 }
 
 /// Get all metagraphs information
-pub async fn get_all_metagraphs_info(client: &BittensorClient) -> Result<Vec<Metagraph>> {
+pub async fn get_all_metagraphs_info(
+    client: &impl ChainBackend,
+) -> BittensorResult<Vec<Metagraph>> {
     use crate::queries::subnets::all_subnets;
 
     let netuids = all_subnets(client).await?;
@@ -32,13 +137,10 @@ pub async fn get_all_metagraphs_info(client: &BittensorClient) -> Result<Vec<Met
 pub async fn get_metagraph_info_full(
     client: &BittensorClient,
     netuid: u16,
-) -> Result<MetagraphInfo> {
+) -> BittensorResult<MetagraphInfo> {
     let mut info = MetagraphInfo::new(netuid);
 
-    info.block = client
-        .block_number()
-        .await
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    info.block = client.block_number().await?;
 
     let n_key = vec![Value::u128(netuid as u128)];
     let n_value = client
@@ -69,7 +171,7 @@ pub async fn get_metagraph_info_full(
         .unwrap_or(0);
     info.last_step = info.block.saturating_sub(info.blocks_since_last_step);
 
-    if let Some(owner_ck) = crate::queries::subnets::get_subnet_owner(client, netuid).await? {
+    if let Some(owner_ck) = crate::queries::subnets::subnet_owner(client, netuid).await? {
         info.owner_coldkey = crate::utils::ss58::encode_ss58(&owner_ck);
     }
     if let Some(owner_hk) = crate::queries::subnets::subnet_owner_hotkey(client, netuid).await? {
@@ -15,15 +15,16 @@ pub mod subnets;
 pub mod voting;
 pub mod wallets;
 
+use crate::errors::{BittensorError, BittensorResult};
 use std::future::Future;
 use std::time::Duration;
 
 /// Generic retry wrapper with exponential backoff
 /// Retries the given async closure up to max_retries times
-pub async fn with_retry<F, Fut, T>(f: F, max_retries: u32) -> anyhow::Result<T>
+pub async fn with_retry<F, Fut, T>(f: F, max_retries: u32) -> BittensorResult<T>
 where
     F: Fn() -> Fut,
-    Fut: Future<Output = anyhow::Result<T>>,
+    Fut: Future<Output = BittensorResult<T>>,
 {
     let mut last_err = None;
     for attempt in 0..=max_retries {
@@ -43,31 +44,37 @@ where
             }
         }
     }
-    Err(last_err.unwrap_or_else(|| anyhow::anyhow!("All retry attempts failed")))
+    Err(last_err.unwrap_or_else(|| BittensorError::unknown("All retry attempts failed")))
 }
 
 // Re-export commonly used functions
-pub use bonds::{get_all_bonds, get_all_weights, get_neuron_bonds, get_neuron_weights};
+pub use bonds::{
+    get_all_bonds, get_all_weights, get_neuron_bonds, get_neuron_weights, get_uid_weights,
+};
 pub use chain_info::{
-    get_admin_freeze_window, get_block_hash, get_current_block_with_retry, get_timestamp,
-    get_total_issuance, get_total_stake, get_total_subnets, is_fast_blocks,
-    is_in_admin_freeze_window, last_drand_round, tx_rate_limit,
+    get_admin_freeze_window, get_block_hash, get_current_block_with_retry, get_network_stats,
+    get_timestamp, get_total_issuance, get_total_stake, get_total_subnets, is_fast_blocks,
+    is_in_admin_freeze_window, last_drand_round, tx_rate_limit, NetworkStats,
 };
 pub use neurons::{
-    get_all_neuron_certificates, get_children, get_children_pending, get_hotkey_for_uid,
-    get_neuron_certificate, get_neuron_for_pubkey_and_subnet, get_parents, get_uid_for_hotkey,
-    neurons, neurons_lite, Certificate,
+    get_all_neuron_certificates, get_block_at_registration, get_children, get_children_pending,
+    get_hotkey_for_uid, get_hotkey_owner, get_neuron_certificate, get_neuron_for_pubkey_and_subnet,
+    get_parents, get_recent_registrations, get_uid_for_hotkey, immunity_blocks_remaining,
+    immunity_remaining, is_hotkey_registered_any, neurons, neurons_filtered, neurons_lite,
+    Certificate, NeuronFilter, CERTIFICATE_ALGORITHM_ED25519, MAX_CERTIFICATE_PUBLIC_KEY_LEN,
 };
 pub use stakes::{
-    get_hotkey_stake, get_stake, get_stake_add_fee, get_stake_for_coldkey,
-    get_stake_for_coldkey_and_hotkey, get_stake_for_hotkey, get_stake_info_for_coldkey,
-    get_stake_movement_fee, get_stake_operations_fee, get_total_stake_for_coldkey,
-    get_total_stake_for_hotkey, get_unstake_fee, StakeInfo,
+    get_hotkey_stake, get_stake, get_stake_add_fee, get_stake_emission_history,
+    get_stake_for_coldkey, get_stake_for_coldkey_and_hotkey, get_stake_for_hotkey,
+    get_stake_info_for_coldkey, get_stake_movement_fee, get_stake_operations_fee,
+    get_total_stake_for_coldkey, get_total_stake_for_hotkey, get_unstake_fee, EpochEmission,
+    StakeEmissionHistory, StakeInfo,
 };
 pub use subnets::{
     commit_reveal_enabled, get_all_dynamic_info, get_all_subnets_info, get_dynamic_info,
-    get_mechanism_count, get_subnet_burn_cost, get_subnet_emission_value, get_subnet_owner,
-    get_subnet_reveal_period_epochs, is_subnet_active, recycle,
+    get_mechanism_count, get_subnet_burn_cost, get_subnet_emission_value, get_subnet_locked,
+    get_subnet_owner, get_subnet_reveal_period_epochs, get_subnet_state, get_transfer_toggle,
+    is_subnet_active, recycle,
 };
 
 // Re-export hyperparameters
@@ -75,25 +82,26 @@ pub use hyperparameters::{
     get_activity_cutoff, get_adjustment_alpha, get_adjustment_interval, get_alpha_high,
     get_alpha_low, get_bonds_moving_average, get_commit_reveal_weights_enabled,
     get_commit_reveal_weights_interval, get_difficulty, get_immunity_period, get_kappa,
-    get_liquid_alpha_enabled, get_max_burn, get_max_difficulty, get_max_regs_per_block,
-    get_max_validators, get_max_weights_limit, get_min_allowed_weights, get_min_burn,
-    get_min_difficulty, get_registration_allowed, get_rho, get_serving_rate_limit,
-    get_subnet_hyperparameters, get_target_regs_per_interval, get_tempo as get_subnet_tempo,
-    get_weights_rate_limit, get_weights_version_key as get_subnet_weights_version_key,
-    SubnetHyperparameters,
+    get_liquid_alpha_enabled, get_max_allowed_uids, get_max_burn, get_max_difficulty,
+    get_max_regs_per_block, get_max_validators, get_max_weights_limit, get_min_allowed_weights,
+    get_min_burn, get_min_difficulty, get_pow_registration_allowed, get_registration_allowed,
+    get_rho, get_serving_rate_limit, get_subnet_hyperparameters, get_target_regs_per_interval,
+    get_tempo as get_subnet_tempo, get_weights_rate_limit,
+    get_weights_version_key as get_subnet_weights_version_key, SubnetHyperparameters,
 };
 
 // Re-export commitment types and functions
 pub use commitments::{
-    get_all_weight_commitments, get_last_commit_block, get_pending_weight_commits,
-    get_weight_commitment, has_pending_commitment, WeightCommitInfo,
+    get_all_commitments, get_all_weight_commitments, get_commitment, get_commitment_rate_limit,
+    get_last_commit_block, get_last_commitment_block, get_pending_weight_commits,
+    get_revealed_commitments, get_weight_commitment, has_pending_commitment, WeightCommitInfo,
 };
 
 // Re-export associated IPs
 pub use associated_ips::{get_associated_ip_count, get_associated_ips, has_associated_ips, IpInfo};
 
 // Re-export runtime queries
-pub use runtime::{get_hotkey_owner, get_tempo, get_weights_version_key};
+pub use runtime::{get_tempo, get_weights_version_key};
 
 // Re-export delegate optimized queries
 pub use delegates::{get_delegate_info_optimized, get_delegate_take_raw, get_total_hotkey_stake};
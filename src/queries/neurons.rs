@@ -1,259 +1,382 @@
 /// Neuron queries for fetching neuron information from the Bittensor network
-use crate::chain::BittensorClient;
+use crate::chain::{ChainBackend, Error as ChainError};
+use crate::errors::{BittensorError, BittensorResult, ChainQueryError};
 use crate::types::{AxonInfo, NeuronInfo, PrometheusInfo};
 use crate::utils::balance_newtypes::Rao;
 use crate::utils::decoders::*;
 use anyhow::{Context, Result};
 use futures::stream::{FuturesUnordered, StreamExt};
-use parity_scale_codec::{Compact, Decode, Encode};
+use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use std::collections::HashMap;
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
-/// SubnetState structure matching the on-chain SCALE encoding from subtensor
-/// Used to decode the response from SubnetInfoRuntimeApi.get_subnet_state
-#[derive(Decode, Clone, Debug)]
-#[allow(dead_code)]
-struct SubnetStateRaw {
-    netuid: Compact<u16>,
-    hotkeys: Vec<AccountId32>,
-    coldkeys: Vec<AccountId32>,
-    active: Vec<bool>,
-    validator_permit: Vec<bool>,
-    pruning_score: Vec<Compact<u16>>,
-    last_update: Vec<Compact<u64>>,
-    emission: Vec<Compact<u64>>,
-    dividends: Vec<Compact<u16>>,
-    incentives: Vec<Compact<u16>>,
-    consensus: Vec<Compact<u16>>,
-    trust: Vec<Compact<u16>>,
-    rank: Vec<Compact<u16>>,
-    block_at_registration: Vec<Compact<u64>>,
-    alpha_stake: Vec<Compact<u64>>,
-    tao_stake: Vec<Compact<u64>>,
-    total_stake: Vec<Compact<u64>>,
-    emission_history: Vec<Vec<Compact<u64>>>,
+/// Which neurons [`neurons_filtered`] returns
+///
+/// UIDs are pre-selected from the subnet-wide vectors (`uids`,
+/// `only_validators`, `active_only`), then narrowed by `min_stake` and paged
+/// with `offset` and `limit` in UID order, or by descending stake with
+/// `order_by_stake`. Per-neuron storage is only read for the selected UIDs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NeuronFilter {
+    /// Only these UIDs
+    pub uids: Option<Vec<u16>>,
+    /// Only neurons with a validator permit
+    pub only_validators: bool,
+    /// Only neurons with at least this total stake
+    pub min_stake: Option<Rao>,
+    /// Only neurons marked active
+    pub active_only: bool,
+    /// Page by descending total stake instead of UID
+    pub order_by_stake: bool,
+    /// Neurons to skip
+    pub offset: usize,
+    /// Most neurons to return
+    pub limit: Option<usize>,
+    /// Also read each selected neuron's axon info
+    pub with_axons: bool,
 }
 
-/// Get all neurons for a subnet with bulk storage queries
-pub async fn neurons(
-    client: &BittensorClient,
-    netuid: u16,
-    _block: Option<u64>,
-) -> Result<Vec<NeuronInfo>> {
-    // First get the count
-    let n_key = vec![Value::u128(netuid as u128)];
-    let n_value = client
-        .storage_with_keys(SUBTENSOR_MODULE, "SubnetworkN", n_key.clone())
-        .await?
-        .ok_or_else(|| anyhow::anyhow!("Subnet {} not found", netuid))?;
-    let n = decode_u64(&n_value).context("Failed to decode SubnetworkN")?;
+impl NeuronFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    if n == 0 {
-        return Ok(vec![]);
+    pub fn with_uids(mut self, uids: impl IntoIterator<Item = u16>) -> Self {
+        self.uids = Some(uids.into_iter().collect());
+        self
     }
 
-    // Step 1: Fetch all vector storages for the subnet in parallel
-    let (
-        rank_vec,
-        trust_vec,
-        consensus_vec,
-        validator_trust_vec,
-        incentive_vec,
-        dividends_vec,
-        active_vec,
-        last_update_vec,
-        emission_vec,
-        validator_permit_vec,
-        pruning_scores_vec,
-        stake_weight_vec,
-    ) = tokio::try_join!(
-        fetch_vec_u16(client, "Rank", &n_key),
-        fetch_vec_u16(client, "Trust", &n_key),
-        fetch_vec_u16(client, "Consensus", &n_key),
-        fetch_vec_u16(client, "ValidatorTrust", &n_key),
-        fetch_vec_u16(client, "Incentive", &n_key),
-        fetch_vec_u16(client, "Dividends", &n_key),
-        fetch_vec_bool(client, "Active", &n_key),
-        fetch_vec_u64(client, "LastUpdate", &n_key),
-        fetch_vec_u128(client, "Emission", &n_key),
-        fetch_vec_bool(client, "ValidatorPermit", &n_key),
-        fetch_vec_u16(client, "PruningScores", &n_key),
-        fetch_vec_u16(client, "StakeWeight", &n_key),
-    )?;
+    pub fn with_only_validators(mut self, only_validators: bool) -> Self {
+        self.only_validators = only_validators;
+        self
+    }
 
-    // Step 2: Batch fetch all hotkeys
-    let mut hotkeys = Vec::with_capacity(n as usize);
-    let mut futures = FuturesUnordered::new();
+    pub fn with_min_stake(mut self, min_stake: Rao) -> Self {
+        self.min_stake = Some(min_stake);
+        self
+    }
 
-    for uid in 0..n {
-        let uid_key = vec![Value::u128(netuid as u128), Value::u128(uid as u128)];
-        let client_ref = client;
-        futures.push(async move {
-            let hotkey_val = client_ref
-                .storage_with_keys(SUBTENSOR_MODULE, "Keys", uid_key)
-                .await
-                .ok()
-                .flatten();
-            (uid, hotkey_val)
+    pub fn with_active_only(mut self, active_only: bool) -> Self {
+        self.active_only = active_only;
+        self
+    }
+
+    pub fn with_order_by_stake(mut self, order_by_stake: bool) -> Self {
+        self.order_by_stake = order_by_stake;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_axons(mut self, with_axons: bool) -> Self {
+        self.with_axons = with_axons;
+        self
+    }
+
+    fn needs_stake(&self) -> bool {
+        self.min_stake.is_some() || self.order_by_stake
+    }
+
+    /// UIDs below `n` passing the UID, validator and activity filters
+    fn preselect(&self, n: u64, vectors: &SubnetVectors) -> Vec<u64> {
+        let mut uids: Vec<u64> = match &self.uids {
+            Some(uids) => uids
+                .iter()
+                .map(|&uid| uid as u64)
+                .filter(|&uid| uid < n)
+                .collect(),
+            None => (0..n).collect(),
+        };
+        uids.sort_unstable();
+        uids.dedup();
+        uids.retain(|&uid| {
+            let idx = uid as usize;
+            (!self.only_validators || vectors.validator_permit.get(idx) == Some(&true))
+                && (!self.active_only || vectors.active.get(idx) == Some(&true))
         });
+        uids
     }
 
-    while let Some((uid, hotkey_val)) = futures.next().await {
-        if let Some(val) = hotkey_val {
-            if let Ok(hotkey) = decode_account_id32(&val) {
-                hotkeys.push((uid, hotkey));
-            }
+    /// Apply `min_stake`, the stake order and paging to `items` in UID order
+    fn select<T>(&self, mut items: Vec<T>, stake: impl Fn(&T) -> Rao) -> Vec<T> {
+        if let Some(min_stake) = self.min_stake {
+            items.retain(|item| stake(item) >= min_stake);
         }
+        if self.order_by_stake {
+            // Stable, so equal stakes stay in UID order
+            items.sort_by_key(|item| std::cmp::Reverse(stake(item)));
+        }
+        items
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
     }
+}
 
-    // Step 3: Batch fetch all coldkeys (owners)
-    let mut coldkeys = HashMap::new();
-    let mut futures = FuturesUnordered::new();
+/// Per-UID vectors of a subnet, one storage read each
+struct SubnetVectors {
+    rank: Vec<u16>,
+    trust: Vec<u16>,
+    consensus: Vec<u16>,
+    validator_trust: Vec<u16>,
+    incentive: Vec<u16>,
+    dividends: Vec<u16>,
+    active: Vec<bool>,
+    last_update: Vec<u64>,
+    emission: Vec<u128>,
+    validator_permit: Vec<bool>,
+    pruning_scores: Vec<u16>,
+    stake_weight: Vec<u16>,
+}
 
-    for (uid, hotkey) in &hotkeys {
-        let owner_key = vec![Value::from_bytes(hotkey.encode())];
-        let client_ref = client;
-        let hk = hotkey.clone();
-        let u = *uid;
-        futures.push(async move {
-            let coldkey_val = client_ref
-                .storage_with_keys(SUBTENSOR_MODULE, "Owner", owner_key)
-                .await
-                .ok()
-                .flatten();
-            (u, hk, coldkey_val)
-        });
+impl SubnetVectors {
+    async fn fetch(client: &impl ChainBackend, netuid: u16) -> Result<Self> {
+        let n_key = vec![Value::u128(netuid as u128)];
+        let (
+            rank,
+            trust,
+            consensus,
+            validator_trust,
+            incentive,
+            dividends,
+            active,
+            last_update,
+            emission,
+            validator_permit,
+            pruning_scores,
+            stake_weight,
+        ) = tokio::try_join!(
+            fetch_vec(client, "Rank", &n_key, decode_u16),
+            fetch_vec(client, "Trust", &n_key, decode_u16),
+            fetch_vec(client, "Consensus", &n_key, decode_u16),
+            fetch_vec(client, "ValidatorTrust", &n_key, decode_u16),
+            fetch_vec(client, "Incentive", &n_key, decode_u16),
+            fetch_vec(client, "Dividends", &n_key, decode_u16),
+            fetch_vec(client, "Active", &n_key, decode_bool),
+            fetch_vec(client, "LastUpdate", &n_key, decode_u64),
+            fetch_vec(client, "Emission", &n_key, decode_u128),
+            fetch_vec(client, "ValidatorPermit", &n_key, decode_bool),
+            fetch_vec(client, "PruningScores", &n_key, decode_u16),
+            fetch_vec(client, "StakeWeight", &n_key, decode_u16),
+        )?;
+        Ok(Self {
+            rank,
+            trust,
+            consensus,
+            validator_trust,
+            incentive,
+            dividends,
+            active,
+            last_update,
+            emission,
+            validator_permit,
+            pruning_scores,
+            stake_weight,
+        })
     }
 
-    while let Some((uid, hotkey, coldkey_val)) = futures.next().await {
-        if let Some(val) = coldkey_val {
-            if let Ok(coldkey) = decode_account_id32(&val) {
-                coldkeys.insert(uid, (hotkey, coldkey));
-            }
+    fn neuron(&self, netuid: u16, keys: NeuronKeys) -> NeuronInfo {
+        let idx = keys.uid as usize;
+        let score = |vec: &[u16]| vec.get(idx).copied().unwrap_or(0) as f64 / 65535.0;
+        let total_stake = Rao::from(keys.stake);
+
+        NeuronInfo {
+            uid: keys.uid,
+            netuid,
+            hotkey: keys.hotkey,
+            coldkey: keys.coldkey,
+            stake: total_stake,
+            stake_dict: HashMap::new(),
+            total_stake,
+            root_stake: Rao::from(keys.root_stake),
+            stake_weight: self.stake_weight.get(idx).copied().unwrap_or(0),
+            rank: score(&self.rank),
+            trust: score(&self.trust),
+            consensus: score(&self.consensus),
+            validator_trust: score(&self.validator_trust),
+            incentive: score(&self.incentive),
+            emission: Rao::from(self.emission.get(idx).copied().unwrap_or(0)),
+            dividends: score(&self.dividends),
+            active: self.active.get(idx).copied().unwrap_or(false),
+            last_update: self.last_update.get(idx).copied().unwrap_or(0),
+            validator_permit: self.validator_permit.get(idx).copied().unwrap_or(false),
+            version: 0,
+            weights: Vec::new(),
+            bonds: Vec::new(),
+            pruning_score: self.pruning_scores.get(idx).copied().unwrap_or(0) as u64,
+            prometheus_info: None,
+            axon_info: None,
+            is_null: false,
         }
     }
+}
 
-    // Step 4: Batch fetch all stakes
-    let mut stakes = HashMap::new();
+/// Keys and storage stakes of one neuron
+struct NeuronKeys {
+    uid: u64,
+    hotkey: AccountId32,
+    coldkey: AccountId32,
+    stake: u128,
+    root_stake: u128,
+}
+
+/// Read the keys and stakes of `uids`, skipping UIDs without a hotkey or
+/// coldkey
+async fn fetch_neuron_keys(
+    client: &impl ChainBackend,
+    netuid: u16,
+    uids: &[u64],
+) -> Vec<NeuronKeys> {
+    // Step 1: Batch fetch the hotkeys
+    let mut hotkeys = Vec::with_capacity(uids.len());
     let mut futures = FuturesUnordered::new();
 
-    for (uid, (hotkey, _)) in &coldkeys {
-        let stake_key = vec![
-            Value::from_bytes(hotkey.encode()),
-            Value::u128(netuid as u128),
-        ];
+    for &uid in uids {
+        let uid_key = vec![Value::u128(netuid as u128), Value::u128(uid as u128)];
         let client_ref = client;
-        let u = *uid;
         futures.push(async move {
-            let stake_val = client_ref
-                .storage_with_keys(SUBTENSOR_MODULE, "TotalHotkeyAlpha", stake_key)
+            let hotkey_val = client_ref
+                .storage_with_keys(SUBTENSOR_MODULE, "Keys", uid_key)
                 .await
                 .ok()
                 .flatten();
-            (u, stake_val)
+            (uid, hotkey_val)
         });
     }
 
-    while let Some((uid, stake_val)) = futures.next().await {
-        if let Some(val) = stake_val {
-            if let Ok(stake) = decode_u128(&val) {
-                stakes.insert(uid, stake);
+    while let Some((uid, hotkey_val)) = futures.next().await {
+        if let Some(val) = hotkey_val {
+            if let Ok(hotkey) = decode_account_id32(&val) {
+                hotkeys.push((uid, hotkey));
             }
         }
     }
 
-    // Step 4.5: Batch fetch all root_stakes (TAO stake on root subnet, netuid 0)
-    let mut root_stakes = HashMap::new();
+    // Step 2: Batch fetch the coldkeys (owners) and stakes of each hotkey
     let mut futures = FuturesUnordered::new();
 
-    for (uid, (hotkey, _)) in &coldkeys {
-        let root_stake_key = vec![
-            Value::from_bytes(hotkey.encode()),
-            Value::u128(0u128), // NetUid::ROOT
-        ];
+    for (uid, hotkey) in hotkeys {
         let client_ref = client;
-        let u = *uid;
         futures.push(async move {
-            let root_stake_val = client_ref
-                .storage_with_keys(SUBTENSOR_MODULE, "TotalHotkeyAlpha", root_stake_key)
-                .await
-                .ok()
-                .flatten();
-            (u, root_stake_val)
+            let owner_key = vec![Value::from_bytes(hotkey.encode())];
+            let stake_key = vec![
+                Value::from_bytes(hotkey.encode()),
+                Value::u128(netuid as u128),
+            ];
+            let root_stake_key = vec![
+                Value::from_bytes(hotkey.encode()),
+                Value::u128(0u128), // NetUid::ROOT
+            ];
+            let (coldkey_val, stake_val, root_stake_val) = tokio::join!(
+                client_ref.storage_with_keys(SUBTENSOR_MODULE, "Owner", owner_key),
+                client_ref.storage_with_keys(SUBTENSOR_MODULE, "TotalHotkeyAlpha", stake_key),
+                client_ref.storage_with_keys(SUBTENSOR_MODULE, "TotalHotkeyAlpha", root_stake_key),
+            );
+            let coldkey = coldkey_val.ok().flatten()?;
+            let coldkey = decode_account_id32(&coldkey).ok()?;
+            let stake = |val: Result<Option<Value>, ChainError>| {
+                val.ok()
+                    .flatten()
+                    .and_then(|v| decode_u128(&v).ok())
+                    .unwrap_or(0)
+            };
+            Some(NeuronKeys {
+                uid,
+                hotkey,
+                coldkey,
+                stake: stake(stake_val),
+                root_stake: stake(root_stake_val),
+            })
         });
     }
 
-    while let Some((uid, root_stake_val)) = futures.next().await {
-        if let Some(val) = root_stake_val {
-            if let Ok(root_stake) = decode_u128(&val) {
-                root_stakes.insert(uid, root_stake);
-            }
-        }
+    let mut keys = Vec::with_capacity(futures.len());
+    while let Some(neuron_keys) = futures.next().await {
+        keys.extend(neuron_keys);
     }
+    keys
+}
 
-    // Step 5: Build all neurons from the collected data
-    let mut neurons = Vec::new();
+/// Get all neurons for a subnet with bulk storage queries
+pub async fn neurons(
+    client: &impl ChainBackend,
+    netuid: u16,
+    _block: Option<u64>,
+) -> BittensorResult<Vec<NeuronInfo>> {
+    neurons_filtered(client, netuid, NeuronFilter::default()).await
+}
 
-    for (uid, (hotkey, coldkey)) in coldkeys {
-        let idx = uid as usize;
+/// Get the neurons of a subnet selected by `filter`
+///
+/// Reads the subnet-wide vectors and consensus stakes first and only reads
+/// per-neuron storage (keys, stakes and, with
+/// [`NeuronFilter::with_axons`], axon info) for the selected UIDs, so the
+/// number of storage calls scales with the result. UIDs whose hotkey or
+/// coldkey cannot be read are left out, so a page may be short.
+///
+/// If the stake runtime API is unavailable, `min_stake` and
+/// `order_by_stake` fall back to storage stakes, which are read for every
+/// pre-selected UID before paging.
+pub async fn neurons_filtered(
+    client: &impl ChainBackend,
+    netuid: u16,
+    filter: NeuronFilter,
+) -> BittensorResult<Vec<NeuronInfo>> {
+    // First get the count
+    let n_key = vec![Value::u128(netuid as u128)];
+    let n_value = client
+        .storage_with_keys(SUBTENSOR_MODULE, "SubnetworkN", n_key)
+        .await?
+        .ok_or_else(|| ChainQueryError::new(format!("Subnet {} not found", netuid)))?;
+    let n = decode_u64(&n_value).context("Failed to decode SubnetworkN")?;
 
-        // Get values from vectors
-        let rank = rank_vec.get(idx).copied().unwrap_or(0) as f64 / 65535.0;
-        let trust = trust_vec.get(idx).copied().unwrap_or(0) as f64 / 65535.0;
-        let consensus = consensus_vec.get(idx).copied().unwrap_or(0) as f64 / 65535.0;
-        let validator_trust = validator_trust_vec.get(idx).copied().unwrap_or(0) as f64 / 65535.0;
-        let incentive = incentive_vec.get(idx).copied().unwrap_or(0) as f64 / 65535.0;
-        let dividends = dividends_vec.get(idx).copied().unwrap_or(0) as f64 / 65535.0;
-        let active = active_vec.get(idx).copied().unwrap_or(false);
-        let validator_permit = validator_permit_vec.get(idx).copied().unwrap_or(false);
-        let last_update = last_update_vec.get(idx).copied().unwrap_or(0);
-        let emission = emission_vec.get(idx).copied().unwrap_or(0);
-        let pruning_score = pruning_scores_vec.get(idx).copied().unwrap_or(0) as u64;
+    if n == 0 {
+        return Ok(vec![]);
+    }
 
-        let total_stake = Rao::from(stakes.get(&uid).copied().unwrap_or(0));
-        let root_stake = Rao::from(root_stakes.get(&uid).copied().unwrap_or(0));
-        let stake_weight = stake_weight_vec.get(idx).copied().unwrap_or(0);
+    let vectors = SubnetVectors::fetch(client, netuid).await?;
 
-        neurons.push(NeuronInfo {
-            uid,
-            netuid,
-            hotkey,
-            coldkey,
-            stake: total_stake,
-            stake_dict: HashMap::new(),
-            total_stake,
-            root_stake,
-            stake_weight,
-            rank,
-            trust,
-            consensus,
-            validator_trust,
-            incentive,
-            emission: Rao::from(emission),
-            dividends,
-            active,
-            last_update,
-            validator_permit,
-            version: 0,
-            weights: Vec::new(),
-            bonds: Vec::new(),
-            pruning_score,
-            prometheus_info: None,
-            axon_info: None,
-            is_null: false,
+    // Consensus stakes include parent inheritance and overwrite the storage
+    // stakes; they are unavailable on nodes without the runtime API
+    let consensus_stakes = get_stake_weights_for_subnet(client, netuid)
+        .await
+        .ok()
+        .filter(|(_, _, total_stakes)| !total_stakes.is_empty());
+
+    // Page the UIDs before any per-neuron read unless storage stakes are
+    // needed to select them
+    let mut uids = filter.preselect(n, &vectors);
+    let paged = consensus_stakes.is_some() || !filter.needs_stake();
+    if paged {
+        let total_stakes = consensus_stakes
+            .as_ref()
+            .map(|(_, _, total_stakes)| total_stakes.as_slice())
+            .unwrap_or_default();
+        uids = filter.select(uids, |&uid| {
+            Rao::from(total_stakes.get(uid as usize).copied().unwrap_or(0))
         });
     }
 
+    let mut neurons: Vec<NeuronInfo> = fetch_neuron_keys(client, netuid, &uids)
+        .await
+        .into_iter()
+        .map(|keys| vectors.neuron(netuid, keys))
+        .collect();
     neurons.sort_by_key(|n| n.uid);
 
-    // Step 6: Fetch correct stake values from runtime API (includes parent inheritance)
-    // This overwrites the storage-based stakes with the actual consensus values
-    if let Ok((alpha_stakes, _tao_stakes, total_stakes)) =
-        get_stake_weights_for_subnet(client, netuid).await
-    {
+    if let Some((alpha_stakes, _tao_stakes, total_stakes)) = &consensus_stakes {
         for neuron in &mut neurons {
             let idx = neuron.uid as usize;
             if let Some(&alpha) = alpha_stakes.get(idx) {
@@ -266,75 +389,90 @@ pub async fn neurons(
         }
     }
 
+    if paged {
+        if filter.order_by_stake {
+            neurons.sort_by_key(|n| std::cmp::Reverse(n.total_stake));
+        }
+    } else {
+        neurons = filter.select(neurons, |n| n.total_stake);
+    }
+
+    if filter.with_axons {
+        fetch_axons(client, netuid, &mut neurons).await;
+    }
+
     Ok(neurons)
 }
 
-// Helper functions - return empty vec if storage not found (graceful degradation)
-async fn fetch_vec_u16(
-    client: &BittensorClient,
-    storage: &str,
-    keys: &[Value],
-) -> Result<Vec<u16>> {
-    Ok(client
-        .storage_with_keys(SUBTENSOR_MODULE, storage, keys.to_vec())
-        .await?
-        .and_then(|v| decode_vec_u16(&v).ok())
-        .unwrap_or_default())
-}
+/// Fill in the axon info of `neurons`
+async fn fetch_axons(client: &impl ChainBackend, netuid: u16, neurons: &mut [NeuronInfo]) {
+    let mut futures = FuturesUnordered::new();
 
-async fn fetch_vec_u64(
-    client: &BittensorClient,
-    storage: &str,
-    keys: &[Value],
-) -> Result<Vec<u64>> {
-    Ok(client
-        .storage_with_keys(SUBTENSOR_MODULE, storage, keys.to_vec())
-        .await?
-        .and_then(|v| decode_vec_u64(&v).ok())
-        .unwrap_or_default())
+    for (i, neuron) in neurons.iter().enumerate() {
+        let keys = vec![
+            Value::u128(netuid as u128),
+            Value::from_bytes(neuron.hotkey.encode()),
+        ];
+        futures.push(async move {
+            (
+                i,
+                fetch_axon_info(client, SUBTENSOR_MODULE, "Axons", keys).await,
+            )
+        });
+    }
+
+    while let Some((i, axon_info)) = futures.next().await {
+        neurons[i].axon_info = axon_info;
+    }
 }
 
-async fn fetch_vec_u128(
-    client: &BittensorClient,
+// Helper functions - return empty vec if storage not found (graceful degradation)
+async fn fetch_vec<T>(
+    client: &impl ChainBackend,
     storage: &str,
     keys: &[Value],
-) -> Result<Vec<u128>> {
+    decoder: fn(&Value) -> Result<T>,
+) -> Result<Vec<T>> {
     Ok(client
         .storage_with_keys(SUBTENSOR_MODULE, storage, keys.to_vec())
         .await?
-        .and_then(|v| decode_vec_u128(&v).ok())
+        .and_then(|v| decode_vec(&v, decoder).ok())
         .unwrap_or_default())
 }
 
-async fn fetch_vec_bool(
-    client: &BittensorClient,
-    storage: &str,
-    keys: &[Value],
-) -> Result<Vec<bool>> {
-    Ok(client
-        .storage_with_keys(SUBTENSOR_MODULE, storage, keys.to_vec())
-        .await?
-        .and_then(|v| decode_vec_bool(&v).ok())
-        .unwrap_or_default())
+/// Per-UID pair lists degrade to empty when missing or unreadable
+async fn fetch_pairs_or_empty<T>(
+    client: &impl ChainBackend,
+    entry: &str,
+    keys: Vec<Value>,
+    decoder: fn(&Value) -> Result<Vec<T>>,
+) -> Vec<T> {
+    match client
+        .storage_with_keys(SUBTENSOR_MODULE, entry, keys)
+        .await
+    {
+        Ok(Some(value)) => decoder(&value).unwrap_or_default(),
+        _ => Vec::new(),
+    }
 }
 
 /// Get a specific neuron by subnet and UID
 pub async fn neuron(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     uid: u64,
     block: Option<u64>,
-) -> Result<Option<NeuronInfo>> {
+) -> BittensorResult<Option<NeuronInfo>> {
     query_neuron_from_storage(client, netuid, uid, block).await
 }
 
 /// Query neuron information from storage
 pub async fn query_neuron_from_storage(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     uid: u64,
     _block: Option<u64>,
-) -> Result<Option<NeuronInfo>> {
+) -> BittensorResult<Option<NeuronInfo>> {
     let uid_key = vec![Value::u128(netuid as u128), Value::u128(uid as u128)];
 
     let hotkey_val = client
@@ -351,7 +489,10 @@ pub async fn query_neuron_from_storage(
         .storage_with_keys(SUBTENSOR_MODULE, "Owner", owner_key)
         .await?
         .ok_or_else(|| {
-            anyhow::anyhow!("Coldkey (Owner) not found for hotkey on subnet {}", netuid)
+            ChainQueryError::new(format!(
+                "Coldkey (Owner) not found for hotkey on subnet {}",
+                netuid
+            ))
         })?;
     let coldkey = decode_account_id32(&coldkey_val)?;
 
@@ -373,33 +514,18 @@ pub async fn query_neuron_from_storage(
         pruning_scores_vec,
         stake_weight_vec,
     ) = tokio::try_join!(
-        fetch_vec_u16_storage(client, SUBTENSOR_MODULE, "Rank", netuid_key.clone()),
-        fetch_vec_u16_storage(client, SUBTENSOR_MODULE, "Trust", netuid_key.clone()),
-        fetch_vec_u16_storage(client, SUBTENSOR_MODULE, "Consensus", netuid_key.clone()),
-        fetch_vec_u16_storage(
-            client,
-            SUBTENSOR_MODULE,
-            "ValidatorTrust",
-            netuid_key.clone()
-        ),
-        fetch_vec_u16_storage(client, SUBTENSOR_MODULE, "Incentive", netuid_key.clone()),
-        fetch_vec_u16_storage(client, SUBTENSOR_MODULE, "Dividends", netuid_key.clone()),
-        fetch_vec_bool_storage(client, SUBTENSOR_MODULE, "Active", netuid_key.clone()),
-        fetch_vec_u64_storage(client, SUBTENSOR_MODULE, "LastUpdate", netuid_key.clone()),
-        fetch_vec_u128_storage(client, SUBTENSOR_MODULE, "Emission", netuid_key.clone()),
-        fetch_vec_bool_storage(
-            client,
-            SUBTENSOR_MODULE,
-            "ValidatorPermit",
-            netuid_key.clone()
-        ),
-        fetch_vec_u16_storage(
-            client,
-            SUBTENSOR_MODULE,
-            "PruningScores",
-            netuid_key.clone()
-        ),
-        fetch_vec_u16_storage(client, SUBTENSOR_MODULE, "StakeWeight", netuid_key.clone()),
+        fetch_vec_storage(client, "Rank", netuid_key.clone(), decode_u16),
+        fetch_vec_storage(client, "Trust", netuid_key.clone(), decode_u16),
+        fetch_vec_storage(client, "Consensus", netuid_key.clone(), decode_u16),
+        fetch_vec_storage(client, "ValidatorTrust", netuid_key.clone(), decode_u16),
+        fetch_vec_storage(client, "Incentive", netuid_key.clone(), decode_u16),
+        fetch_vec_storage(client, "Dividends", netuid_key.clone(), decode_u16),
+        fetch_vec_storage(client, "Active", netuid_key.clone(), decode_bool),
+        fetch_vec_storage(client, "LastUpdate", netuid_key.clone(), decode_u64),
+        fetch_vec_storage(client, "Emission", netuid_key.clone(), decode_u128),
+        fetch_vec_storage(client, "ValidatorPermit", netuid_key.clone(), decode_bool),
+        fetch_vec_storage(client, "PruningScores", netuid_key.clone(), decode_u16),
+        fetch_vec_storage(client, "StakeWeight", netuid_key.clone(), decode_u16),
     )?;
 
     let rank = rank_vec.get(idx).copied().unwrap_or(0) as f64 / 65535.0;
@@ -427,10 +553,7 @@ pub async fn query_neuron_from_storage(
         .storage_with_keys(SUBTENSOR_MODULE, "TotalHotkeyAlpha", stake_key.clone())
         .await?
     {
-        Rao::from(
-            decode_u128(&total_stake_val)
-                .map_err(|e| anyhow::anyhow!("Failed to decode TotalHotkeyAlpha: {}", e))?,
-        )
+        Rao::from(decode_u128(&total_stake_val).context("Failed to decode TotalHotkeyAlpha")?)
     } else {
         Rao::ZERO
     };
@@ -440,24 +563,18 @@ pub async fn query_neuron_from_storage(
         .storage_with_keys(SUBTENSOR_MODULE, "TotalHotkeyAlpha", root_stake_key)
         .await?
     {
-        Rao::from(
-            decode_u128(&root_stake_val)
-                .map_err(|e| anyhow::anyhow!("Failed to decode TotalHotkeyAlpha (root): {}", e))
-                .unwrap_or(0u128),
-        )
+        Rao::from(decode_u128(&root_stake_val).unwrap_or(0u128))
     } else {
         Rao::ZERO
     };
 
-    let stake_entries = match client
-        .storage_with_keys(SUBTENSOR_MODULE, "Stake", uid_key.clone())
-        .await
-    {
-        Ok(Some(stake_val)) => {
-            decode_vec_account_u128_pairs(&stake_val).unwrap_or_else(|_| Vec::new())
-        }
-        _ => Vec::new(),
-    };
+    let stake_entries = fetch_pairs_or_empty(
+        client,
+        "Stake",
+        uid_key.clone(),
+        decode_vec_account_u128_pairs,
+    )
+    .await;
     let mut stake_dict: HashMap<AccountId32, u128> = HashMap::new();
     for (ck, amt) in stake_entries {
         stake_dict.insert(ck, amt);
@@ -470,26 +587,16 @@ pub async fn query_neuron_from_storage(
 
     let storage_index: u64 = (netuid as u64) << 16;
     let weights_keys = vec![Value::u128(storage_index as u128), Value::u128(uid as u128)];
-    let weights_pairs = match client
-        .storage_with_keys(SUBTENSOR_MODULE, "Weights", weights_keys)
-        .await
-    {
-        Ok(Some(w_val)) => decode_vec_u16_u16_pairs(&w_val).unwrap_or_else(|_| Vec::new()),
-        _ => Vec::new(),
-    };
+    let weights_pairs =
+        fetch_pairs_or_empty(client, "Weights", weights_keys, decode_vec_u16_u16_pairs).await;
     let weights: Vec<(u64, u64)> = weights_pairs
         .into_iter()
         .map(|(a, b)| (a as u64, b as u64))
         .collect();
 
     let bonds_keys = vec![Value::u128(storage_index as u128), Value::u128(uid as u128)];
-    let bonds_pairs: Vec<(u16, u16)> = match client
-        .storage_with_keys(SUBTENSOR_MODULE, "Bonds", bonds_keys)
-        .await
-    {
-        Ok(Some(b_val)) => decode_vec_u16_u16_pairs(&b_val).unwrap_or_else(|_| Vec::new()),
-        _ => Vec::new(),
-    };
+    let bonds_pairs =
+        fetch_pairs_or_empty(client, "Bonds", bonds_keys, decode_vec_u16_u16_pairs).await;
     let bonds: Vec<Vec<u64>> = bonds_pairs
         .into_iter()
         .map(|(a, b)| vec![a as u64, b as u64])
@@ -530,7 +637,7 @@ pub async fn query_neuron_from_storage(
 
 /// Fetch AxonInfo from storage
 pub async fn fetch_axon_info(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     module: &str,
     entry: &str,
     keys: Vec<Value>,
@@ -551,7 +658,7 @@ pub async fn fetch_axon_info(
 
 /// Fetch PrometheusInfo from storage
 pub async fn fetch_prometheus_info(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     module: &str,
     entry: &str,
     keys: Vec<Value>,
@@ -564,83 +671,110 @@ pub async fn fetch_prometheus_info(
 }
 
 // Helper functions for storage queries
-async fn fetch_vec_u16_storage(
-    client: &BittensorClient,
-    module: &str,
+async fn fetch_vec_storage<T>(
+    client: &impl ChainBackend,
     entry: &str,
     keys: Vec<Value>,
-) -> Result<Vec<u16>> {
-    if let Some(value) = client.storage_with_keys(module, entry, keys).await? {
-        decode_vec_u16(&value).context(format!("Failed to decode {} vector", entry))
-    } else {
-        Ok(Vec::new())
-    }
+    decoder: fn(&Value) -> Result<T>,
+) -> Result<Vec<T>> {
+    let value = client
+        .storage_with_keys(SUBTENSOR_MODULE, entry, keys)
+        .await?;
+    decode_stored_or(value, Vec::new(), |v| decode_vec(v, decoder))
+        .context(format!("Failed to decode {} vector", entry))
 }
 
-async fn fetch_vec_u64_storage(
-    client: &BittensorClient,
+async fn fetch_u64_storage_opt(
+    client: &impl ChainBackend,
     module: &str,
     entry: &str,
     keys: Vec<Value>,
-) -> Result<Vec<u64>> {
-    if let Some(value) = client.storage_with_keys(module, entry, keys).await? {
-        decode_vec_u64(&value).context(format!("Failed to decode {} vector", entry))
-    } else {
-        Ok(Vec::new())
-    }
+) -> Result<Option<u64>> {
+    let value = client.storage_with_keys(module, entry, keys).await?;
+    value
+        .as_ref()
+        .map(decode_u64)
+        .transpose()
+        .context("Failed to decode u64")
 }
 
-async fn fetch_vec_u128_storage(
-    client: &BittensorClient,
-    module: &str,
-    entry: &str,
-    keys: Vec<Value>,
-) -> Result<Vec<u128>> {
-    if let Some(value) = client.storage_with_keys(module, entry, keys).await? {
-        decode_vec_u128(&value).context(format!("Failed to decode {} vector", entry))
-    } else {
-        Ok(Vec::new())
-    }
+/// Algorithm byte for an ed25519 [`Certificate`]
+///
+/// The chain stores this byte without interpreting it.
+pub const CERTIFICATE_ALGORITHM_ED25519: u8 = 1;
+
+/// Largest public key the chain accepts in a `NeuronCertificate`
+pub const MAX_CERTIFICATE_PUBLIC_KEY_LEN: usize = 64;
+
+/// Neuron certificate information
+///
+/// The certificate a neuron published with `serve_axon_tls`, used by
+/// `Dendrite::call_tls` to pin the axon's TLS certificate. The chain keeps at
+/// most [`MAX_CERTIFICATE_PUBLIC_KEY_LEN`] bytes, so neurons publish the DER
+/// `SubjectPublicKeyInfo` of their certificate's key rather than the whole
+/// certificate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Certificate {
+    /// Certificate algorithm identifier
+    pub algorithm: u8,
+    /// DER encoded public key, or a full DER certificate
+    pub certificate: Vec<u8>,
 }
 
-async fn fetch_vec_bool_storage(
-    client: &BittensorClient,
-    module: &str,
-    entry: &str,
-    keys: Vec<Value>,
-) -> Result<Vec<bool>> {
-    if let Some(value) = client.storage_with_keys(module, entry, keys).await? {
-        decode_vec_bool(&value).context(format!("Failed to decode {} vector", entry))
-    } else {
-        Ok(Vec::new())
+impl Certificate {
+    /// Create a certificate from DER bytes
+    pub fn from_der(algorithm: u8, der: impl Into<Vec<u8>>) -> Self {
+        Self {
+            algorithm,
+            certificate: der.into(),
+        }
     }
-}
 
-async fn fetch_u64_storage_opt(
-    client: &BittensorClient,
-    module: &str,
-    entry: &str,
-    keys: Vec<Value>,
-) -> Result<Option<u64>> {
-    if let Some(value) = client.storage_with_keys(module, entry, keys).await? {
-        Ok(Some(decode_u64(&value).context("Failed to decode u64")?))
-    } else {
-        Ok(None)
+    /// Get the DER encoded certificate bytes
+    pub fn as_der(&self) -> &[u8] {
+        &self.certificate
+    }
+
+    /// Encode as the `certificate` argument of `serve_axon_tls`
+    ///
+    /// The chain expects the algorithm byte followed by the public key.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(1 + self.certificate.len());
+        encoded.push(self.algorithm);
+        encoded.extend_from_slice(&self.certificate);
+        encoded
+    }
+
+    /// Decode the `certificate` argument of `serve_axon_tls`
+    pub fn decode(encoded: &[u8]) -> Option<Self> {
+        let (algorithm, public_key) = encoded.split_first()?;
+        Some(Self::from_der(*algorithm, public_key))
     }
 }
 
-/// Neuron certificate information
-#[derive(Debug, Clone)]
-pub struct Certificate {
-    pub certificate: String,
+/// Decode a `NeuronCertificate { public_key, algorithm }` storage value
+fn decode_certificate(value: &Value) -> Result<Certificate> {
+    let fields = decode_named_composite(value)?;
+    let certificate = fields
+        .get("public_key")
+        .context("Missing certificate public_key")
+        .and_then(decode_bytes)?;
+    let algorithm = fields
+        .get("algorithm")
+        .context("Missing certificate algorithm")
+        .and_then(decode_u8)?;
+    Ok(Certificate {
+        algorithm,
+        certificate,
+    })
 }
 
 /// Get neuron certificate for a hotkey on a subnet
 pub async fn get_neuron_certificate(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
     netuid: u16,
-) -> Result<Option<Certificate>> {
+) -> BittensorResult<Option<Certificate>> {
     let keys = vec![
         Value::u128(netuid as u128),
         Value::from_bytes(hotkey.encode()),
@@ -650,19 +784,18 @@ pub async fn get_neuron_certificate(
         .storage_with_keys(SUBTENSOR_MODULE, "NeuronCertificates", keys)
         .await?
     {
-        let cert_str = format!("{:?}", val);
-        return Ok(Some(Certificate {
-            certificate: cert_str,
-        }));
+        let certificate =
+            decode_certificate(&val).context("Failed to decode neuron certificate")?;
+        return Ok(Some(certificate));
     }
     Ok(None)
 }
 
 /// Get all neuron certificates for a subnet
 pub async fn get_all_neuron_certificates(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<HashMap<AccountId32, Certificate>> {
+) -> BittensorResult<HashMap<AccountId32, Certificate>> {
     let mut certs = HashMap::new();
 
     let n_val = client
@@ -693,10 +826,10 @@ pub async fn get_all_neuron_certificates(
 
 /// Get neuron for a pubkey on a subnet
 pub async fn get_neuron_for_pubkey_and_subnet(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
     netuid: u16,
-) -> Result<Option<NeuronInfo>> {
+) -> BittensorResult<Option<NeuronInfo>> {
     let uid_keys = vec![
         Value::u128(netuid as u128),
         Value::from_bytes(hotkey.encode()),
@@ -716,128 +849,75 @@ pub async fn get_neuron_for_pubkey_and_subnet(
 /// Get children hotkeys for a parent hotkey on a subnet
 /// Returns list of (proportion, child_hotkey)
 pub async fn get_children(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
     netuid: u16,
-) -> Result<Vec<(f64, AccountId32)>> {
+) -> BittensorResult<Vec<(f64, AccountId32)>> {
     let keys = vec![
         Value::from_bytes(hotkey.encode()),
         Value::u128(netuid as u128),
     ];
 
-    if let Some(val) = client
+    let value = client
         .storage_with_keys(SUBTENSOR_MODULE, "ChildKeys", keys)
-        .await?
-    {
-        return parse_children_or_parents_from_value(&val);
-    }
-    Ok(Vec::new())
+        .await?;
+    Ok(decode_stored_or(value, Vec::new(), decode_proportions)
+        .context("Failed to decode ChildKeys")?)
 }
 
 /// Get pending children for a hotkey on a subnet
 /// Returns (list of (proportion, child_hotkey), cooldown_block)
 pub async fn get_children_pending(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
     netuid: u16,
-) -> Result<(Vec<(f64, AccountId32)>, u64)> {
+) -> BittensorResult<(Vec<(f64, AccountId32)>, u64)> {
     let keys = vec![
         Value::u128(netuid as u128),
         Value::from_bytes(hotkey.encode()),
     ];
 
-    if let Some(val) = client
+    let value = client
         .storage_with_keys(SUBTENSOR_MODULE, "PendingChildKeys", keys)
-        .await?
-    {
-        let s = format!("{:?}", val);
-        let children = parse_children_or_parents_from_value(&val).unwrap_or_default();
-        let cooldown = extract_last_u64_from_str(&s).unwrap_or(0);
-        return Ok((children, cooldown));
-    }
-    Ok((Vec::new(), 0))
+        .await?;
+    Ok(decode_stored_or(value, (Vec::new(), 0), |v| {
+        decode_tuple2(v, decode_proportions, decode_u64)
+    })
+    .context("Failed to decode PendingChildKeys")?)
 }
 
 /// Get parent hotkeys for a child hotkey on a subnet
 /// Returns list of (proportion, parent_hotkey)
 pub async fn get_parents(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
     netuid: u16,
-) -> Result<Vec<(f64, AccountId32)>> {
+) -> BittensorResult<Vec<(f64, AccountId32)>> {
     let keys = vec![
         Value::from_bytes(hotkey.encode()),
         Value::u128(netuid as u128),
     ];
 
-    if let Some(val) = client
+    let value = client
         .storage_with_keys(SUBTENSOR_MODULE, "ParentKeys", keys)
-        .await?
-    {
-        return parse_children_or_parents_from_value(&val);
-    }
-    Ok(Vec::new())
-}
-
-/// Helper to parse children/parents from storage value
-fn parse_children_or_parents_from_value<T: std::fmt::Debug>(
-    val: &T,
-) -> Result<Vec<(f64, AccountId32)>> {
-    let mut result = Vec::new();
-    let s = format!("{:?}", val);
-
-    // Parse tuples of (u64, AccountId32) from the string representation
-    // Try multiple patterns for different formats
-    let patterns = [
-        r"(\d+),\s*\(?AccountId32\((\[[^\]]+\])",
-        r"(\d+),\s*\[([^\]]+)\]",
-    ];
-
-    for pattern in patterns {
-        if let Ok(re) = regex::Regex::new(pattern) {
-            for cap in re.captures_iter(&s) {
-                if let (Some(prop_match), Some(bytes_match)) = (cap.get(1), cap.get(2)) {
-                    if let Ok(proportion) = prop_match.as_str().parse::<u64>() {
-                        let normalized = proportion as f64 / u64::MAX as f64;
-                        let bytes_str = bytes_match.as_str();
-                        let bytes: Vec<u8> = bytes_str
-                            .trim_matches(|c| c == '[' || c == ']')
-                            .split(',')
-                            .filter_map(|s| s.trim().parse::<u8>().ok())
-                            .collect();
-                        if bytes.len() == 32 {
-                            let mut arr = [0u8; 32];
-                            arr.copy_from_slice(&bytes);
-                            result.push((normalized, AccountId32::from(arr)));
-                        }
-                    }
-                }
-            }
-            if !result.is_empty() {
-                break;
-            }
-        }
-    }
-
-    Ok(result)
+        .await?;
+    Ok(decode_stored_or(value, Vec::new(), decode_proportions)
+        .context("Failed to decode ParentKeys")?)
 }
 
-/// Extract last u64 from a string (for cooldown parsing)
-fn extract_last_u64_from_str(s: &str) -> Option<u64> {
-    if let Ok(re) = regex::Regex::new(r"(\d+)") {
-        re.find_iter(s)
-            .last()
-            .and_then(|m| m.as_str().parse::<u64>().ok())
-    } else {
-        None
-    }
+/// Decode a `Vec<(u64, AccountId32)>` of proportions, normalized to 0.0-1.0
+fn decode_proportions(value: &Value) -> Result<Vec<(f64, AccountId32)>> {
+    decode_vec(value, |entry| {
+        let (proportion, hotkey) = decode_tuple2(entry, decode_u64, decode_account_id32)?;
+        Ok((proportion as f64 / u64::MAX as f64, hotkey))
+    })
 }
 
 /// Get lightweight neuron info for a subnet (no weights or bonds)
 pub async fn neurons_lite(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<Vec<crate::types::NeuronInfoLite>> {
+) -> BittensorResult<Vec<crate::types::NeuronInfoLite>> {
     use crate::types::NeuronInfoLite;
 
     let n_key = vec![Value::u128(netuid as u128)];
@@ -865,16 +945,16 @@ pub async fn neurons_lite(
         validator_permit_vec,
         pruning_scores_vec,
     ) = tokio::try_join!(
-        fetch_vec_u16(client, "Rank", &n_key),
-        fetch_vec_u16(client, "Trust", &n_key),
-        fetch_vec_u16(client, "Consensus", &n_key),
-        fetch_vec_u16(client, "Incentive", &n_key),
-        fetch_vec_u16(client, "Dividends", &n_key),
-        fetch_vec_bool(client, "Active", &n_key),
-        fetch_vec_u64(client, "LastUpdate", &n_key),
-        fetch_vec_u128(client, "Emission", &n_key),
-        fetch_vec_bool(client, "ValidatorPermit", &n_key),
-        fetch_vec_u16(client, "PruningScores", &n_key),
+        fetch_vec(client, "Rank", &n_key, decode_u16),
+        fetch_vec(client, "Trust", &n_key, decode_u16),
+        fetch_vec(client, "Consensus", &n_key, decode_u16),
+        fetch_vec(client, "Incentive", &n_key, decode_u16),
+        fetch_vec(client, "Dividends", &n_key, decode_u16),
+        fetch_vec(client, "Active", &n_key, decode_bool),
+        fetch_vec(client, "LastUpdate", &n_key, decode_u64),
+        fetch_vec(client, "Emission", &n_key, decode_u128),
+        fetch_vec(client, "ValidatorPermit", &n_key, decode_bool),
+        fetch_vec(client, "PruningScores", &n_key, decode_u16),
     )?;
 
     let mut hotkeys_map = Vec::with_capacity(n as usize);
@@ -1005,10 +1085,10 @@ pub async fn neurons_lite(
 /// Get the UID for a hotkey on a specific subnet
 /// Reads SubtensorModule::Uids storage
 pub async fn get_uid_for_hotkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
-) -> Result<Option<u16>> {
+) -> BittensorResult<Option<u16>> {
     let keys = vec![
         Value::u128(netuid as u128),
         Value::from_bytes(hotkey.encode()),
@@ -1025,13 +1105,74 @@ pub async fn get_uid_for_hotkey(
     Ok(None)
 }
 
+/// Get the netuids a hotkey is registered on
+/// Reads SubtensorModule::IsNetworkMember for every subnet concurrently
+pub async fn is_hotkey_registered_any(
+    client: &impl ChainBackend,
+    hotkey: &AccountId32,
+) -> BittensorResult<Vec<u16>> {
+    let total = crate::queries::subnets::total_subnets(client).await?;
+    let hotkey_key = hotkey.encode();
+
+    let mut lookups: FuturesUnordered<_> = (0..total)
+        .map(|netuid| {
+            let keys = vec![Value::from_bytes(&hotkey_key), Value::u128(netuid as u128)];
+            async move {
+                let member = client
+                    .storage_with_keys(SUBTENSOR_MODULE, "IsNetworkMember", keys)
+                    .await?;
+                let is_member = match member {
+                    Some(val) => decode_bool(&val).with_context(|| {
+                        format!("Failed to decode IsNetworkMember for subnet {}", netuid)
+                    })?,
+                    None => false,
+                };
+                Ok::<_, anyhow::Error>((netuid, is_member))
+            }
+        })
+        .collect();
+
+    let mut netuids = Vec::new();
+    while let Some(result) = lookups.next().await {
+        let (netuid, is_member) = result?;
+        if is_member {
+            netuids.push(netuid);
+        }
+    }
+    netuids.sort_unstable();
+    Ok(netuids)
+}
+
+/// Get the coldkey that owns a hotkey
+/// Reads SubtensorModule::Owner storage; unknown hotkeys have no owner
+pub async fn get_hotkey_owner(
+    client: &impl ChainBackend,
+    hotkey: &AccountId32,
+) -> BittensorResult<Option<AccountId32>> {
+    let value = client
+        .storage_with_keys(
+            SUBTENSOR_MODULE,
+            "Owner",
+            vec![Value::from_bytes(hotkey.encode())],
+        )
+        .await?;
+    match value {
+        Some(value) => {
+            let owner = decode_account_id32(&value).context("Failed to decode owner")?;
+            // Owner is a ValueQuery, so unset entries read as the zero account
+            Ok((owner != AccountId32::new([0u8; 32])).then_some(owner))
+        }
+        None => Ok(None),
+    }
+}
+
 /// Get the hotkey for a specific UID on a subnet
 /// Reads SubtensorModule::Keys storage
 pub async fn get_hotkey_for_uid(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     uid: u16,
-) -> Result<Option<AccountId32>> {
+) -> BittensorResult<Option<AccountId32>> {
     let keys = vec![Value::u128(netuid as u128), Value::u128(uid as u128)];
 
     if let Some(val) = client
@@ -1043,38 +1184,131 @@ pub async fn get_hotkey_for_uid(
     Ok(None)
 }
 
-/// Get stake weights for all neurons in a subnet using runtime API
-/// Returns (alpha_stake, tao_stake, total_stake) vectors indexed by UID
-/// These values include parent inheritance and are the actual values used in consensus
-pub async fn get_stake_weights_for_subnet(
-    client: &BittensorClient,
+/// Get the block a UID was registered at
+/// Reads SubtensorModule::BlockAtRegistration storage; a missing entry is 0
+pub async fn get_block_at_registration(
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<(Vec<u128>, Vec<u128>, Vec<u128>)> {
-    // Encode netuid as SCALE bytes for the runtime API call
-    let params = netuid.encode();
+    uid: u16,
+) -> BittensorResult<u64> {
+    let keys = vec![Value::u128(netuid as u128), Value::u128(uid as u128)];
+    match client
+        .storage_with_keys(SUBTENSOR_MODULE, "BlockAtRegistration", keys)
+        .await?
+    {
+        Some(val) => Ok(decode_u64(&val).context("Failed to decode BlockAtRegistration")?),
+        None => Ok(0),
+    }
+}
 
-    // Call SubnetInfoRuntimeApi.get_subnet_state which returns SCALE-encoded Option<SubnetState>
-    let raw_bytes = client
-        .runtime_api_call("SubnetInfoRuntimeApi", "get_subnet_state", Some(params))
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to call get_subnet_state: {}", e))?;
+/// Blocks of immunity a UID registered at `block_at_registration` has left
+///
+/// Mirrors subtensor's check, which counts a neuron as immune while
+/// `current_block - block_at_registration < immunity_period`. Returns None
+/// once immunity has expired. A registration block newer than
+/// `current_block`, as seen when the UID is recycled between reads, counts
+/// as a fresh registration with the full period left.
+pub fn immunity_blocks_remaining(
+    block_at_registration: u64,
+    immunity_period: u64,
+    current_block: u64,
+) -> Option<u64> {
+    let elapsed = current_block.saturating_sub(block_at_registration);
+    immunity_period
+        .checked_sub(elapsed)
+        .filter(|&remaining| remaining > 0)
+}
 
-    if raw_bytes.is_empty() {
-        return Ok((Vec::new(), Vec::new(), Vec::new()));
+/// Blocks of immunity a UID has left, or None if it has expired
+///
+/// See [`immunity_blocks_remaining`].
+pub async fn immunity_remaining(
+    client: &impl ChainBackend,
+    netuid: u16,
+    uid: u16,
+) -> BittensorResult<Option<u64>> {
+    let (current_block, immunity_period) = tokio::try_join!(
+        async { client.block_number().await.map_err(BittensorError::from) },
+        crate::queries::get_immunity_period(client, netuid),
+    )?;
+    // Read the registration last, so a UID recycled in the meantime shows
+    // up as newer than `current_block` rather than as an old registration
+    let block_at_registration = get_block_at_registration(client, netuid, uid).await?;
+    Ok(immunity_blocks_remaining(
+        block_at_registration,
+        immunity_period as u64,
+        current_block,
+    ))
+}
+
+/// Get the registration block of UIDs `0..n` on a subnet
+/// Reads SubtensorModule::BlockAtRegistration storage; missing entries are 0
+pub async fn get_blocks_at_registration(
+    client: &impl ChainBackend,
+    netuid: u16,
+    n: u64,
+) -> BittensorResult<Vec<u64>> {
+    let mut blocks = vec![0u64; n as usize];
+    let mut futures = FuturesUnordered::new();
+
+    for uid in 0..n {
+        let keys = vec![Value::u128(netuid as u128), Value::u128(uid as u128)];
+        futures.push(async move {
+            let val = client
+                .storage_with_keys(SUBTENSOR_MODULE, "BlockAtRegistration", keys)
+                .await?;
+            Ok::<_, anyhow::Error>((uid, val))
+        });
     }
 
-    // Decode Option<SubnetState> from SCALE bytes
-    let subnet_state: Option<SubnetStateRaw> =
-        Option::<SubnetStateRaw>::decode(&mut &raw_bytes[..])
-            .map_err(|e| anyhow::anyhow!("Failed to decode SubnetState: {}", e))?;
+    while let Some(result) = futures.next().await {
+        let (uid, val) = result?;
+        if let Some(val) = val {
+            blocks[uid as usize] = decode_u64(&val).unwrap_or(0);
+        }
+    }
+    Ok(blocks)
+}
 
-    match subnet_state {
+/// The `count` most recent registrations on a subnet, newest first
+///
+/// Reads `SubnetworkN` and one `BlockAtRegistration` entry per UID, without
+/// fetching full neuron data. Returns `(uid, block)` pairs; registrations in
+/// the same block are ordered by UID, highest first.
+pub async fn get_recent_registrations(
+    client: &impl ChainBackend,
+    netuid: u16,
+    count: usize,
+) -> BittensorResult<Vec<(u16, u64)>> {
+    let n = crate::queries::subnets::subnet_n(client, netuid)
+        .await?
+        .unwrap_or(0);
+    let blocks = get_blocks_at_registration(client, netuid, n).await?;
+    let mut registrations: Vec<(u16, u64)> = blocks
+        .into_iter()
+        .enumerate()
+        .map(|(uid, block)| (uid as u16, block))
+        .collect();
+    registrations.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+    registrations.truncate(count);
+    Ok(registrations)
+}
+
+/// Get stake weights for all neurons in a subnet using runtime API
+/// Returns (alpha_stake, tao_stake, total_stake) vectors indexed by UID
+/// These values include parent inheritance and are the actual values used in consensus
+pub async fn get_stake_weights_for_subnet(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<(Vec<u128>, Vec<u128>, Vec<u128>)> {
+    match super::subnets::try_get_subnet_state(client, netuid).await? {
         Some(state) => {
-            // Convert Compact<u64> to u128
-            let alpha_stake: Vec<u128> = state.alpha_stake.iter().map(|c| c.0 as u128).collect();
-            let tao_stake: Vec<u128> = state.tao_stake.iter().map(|c| c.0 as u128).collect();
-            let total_stake: Vec<u128> = state.total_stake.iter().map(|c| c.0 as u128).collect();
-            Ok((alpha_stake, tao_stake, total_stake))
+            let to_u128 = |v: Vec<Rao>| v.into_iter().map(u128::from).collect::<Vec<_>>();
+            Ok((
+                to_u128(state.alpha_stake),
+                to_u128(state.tao_stake),
+                to_u128(state.total_stake),
+            ))
         }
         None => Ok((Vec::new(), Vec::new(), Vec::new())),
     }
@@ -1,6 +1,11 @@
-use crate::chain::BittensorClient;
+use crate::chain::{BittensorClient, ChainBackend};
+use crate::core::epoch;
+use crate::errors::{BittensorResult, ChainQueryError};
 use crate::utils::balance_newtypes::Rao;
-use crate::utils::decoders::{decode_u128, decode_u64, decode_vec_account_id32};
+use crate::utils::decoders::{
+    decode_account_id32, decode_stored_or, decode_u128, decode_u16, decode_u64, decode_vec,
+    decode_vec_account_id32,
+};
 use anyhow::Result;
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
@@ -11,11 +16,11 @@ const SUBTENSOR_MODULE: &str = "SubtensorModule";
 /// Get stake amount for a coldkey-hotkey pair on a specific subnet
 /// Same signature as Bittensor Python
 pub async fn get_stake(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     coldkey: &AccountId32,
     hotkey: &AccountId32,
     netuid: u16,
-) -> Result<Rao> {
+) -> BittensorResult<Rao> {
     // Use query_module for Alpha storage (same as Bittensor Python: query_module("SubtensorModule", "Alpha", ...))
     // Alpha storage: (hotkey, coldkey, netuid) -> stake amount
     let keys = vec![
@@ -28,19 +33,22 @@ pub async fn get_stake(
         .storage_with_keys(SUBTENSOR_MODULE, "Alpha", keys)
         .await?
         .ok_or_else(|| {
-            anyhow::anyhow!("Alpha not found for hotkey, coldkey, and netuid {}", netuid)
+            ChainQueryError::new(format!(
+                "Alpha not found for hotkey, coldkey, and netuid {}",
+                netuid
+            ))
         })?;
 
     decode_u128(&alpha_val)
         .map(Rao::from)
-        .map_err(|e| anyhow::anyhow!("Failed to decode Alpha stake: {}", e))
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode Alpha stake: {}", e)).into())
 }
 
 /// Get total stake for a coldkey across all hotkeys
 pub async fn get_stake_for_coldkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     coldkey: &AccountId32,
-) -> Result<Vec<(u16, Rao)>> {
+) -> BittensorResult<Vec<(u16, Rao)>> {
     // Strategy without relying on runtime API:
     // 1) Get list of owned hotkeys for this coldkey
     // 2) For each netuid, sum Alpha[(hotkey, coldkey, netuid)] across all owned hotkeys
@@ -52,10 +60,8 @@ pub async fn get_stake_for_coldkey(
             vec![Value::from_bytes(coldkey.encode())],
         )
         .await?;
-    let owned_hotkeys: Vec<AccountId32> = match owned_hotkeys_val {
-        Some(v) => decode_vec_account_id32(&v).unwrap_or_default(),
-        None => Vec::new(),
-    };
+    let owned_hotkeys = decode_stored_or(owned_hotkeys_val, Vec::new(), decode_vec_account_id32)
+        .unwrap_or_default();
 
     // Get total networks
     let total_networks_val = client
@@ -94,10 +100,10 @@ pub async fn get_stake_for_coldkey(
 
 /// Get total stake for a hotkey from all coldkeys
 pub async fn get_stake_for_hotkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
     netuid: u16,
-) -> Result<Rao> {
+) -> BittensorResult<Rao> {
     let keys = vec![
         Value::from_bytes(hotkey.encode()),
         Value::u128(netuid as u128),
@@ -107,25 +113,25 @@ pub async fn get_stake_for_hotkey(
         .storage_with_keys(SUBTENSOR_MODULE, "TotalHotkeyAlpha", keys)
         .await?
         .ok_or_else(|| {
-            anyhow::anyhow!(
+            ChainQueryError::new(format!(
                 "TotalHotkeyAlpha not found for hotkey and netuid {}",
                 netuid
-            )
+            ))
         })?;
 
-    decode_u128(&alpha_val)
-        .map(Rao::from)
-        .map_err(|e| anyhow::anyhow!("Failed to decode TotalHotkeyAlpha: {}", e))
+    decode_u128(&alpha_val).map(Rao::from).map_err(|e| {
+        ChainQueryError::new(format!("Failed to decode TotalHotkeyAlpha: {}", e)).into()
+    })
 }
 
 /// Get stake for specific coldkey-hotkey pair across multiple subnets
 /// Returns HashMap<netuid, StakeInfo> (same as Bittensor Python)
 pub async fn get_stake_for_coldkey_and_hotkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     coldkey: &AccountId32,
     hotkey: &AccountId32,
     netuids: Option<Vec<u16>>,
-) -> Result<std::collections::HashMap<u16, Rao>> {
+) -> BittensorResult<std::collections::HashMap<u16, Rao>> {
     let all_netuids = if let Some(nets) = netuids {
         nets
     } else {
@@ -133,7 +139,7 @@ pub async fn get_stake_for_coldkey_and_hotkey(
         let total_val = client
             .storage(SUBTENSOR_MODULE, "TotalNetworks", None)
             .await?
-            .ok_or_else(|| anyhow::anyhow!("TotalNetworks not found"))?;
+            .ok_or_else(|| ChainQueryError::new("TotalNetworks not found"))?;
         let total = u16::try_from(decode_u64(&total_val).unwrap_or(0)).unwrap_or(0);
         (0..total).collect()
     };
@@ -156,9 +162,9 @@ pub async fn get_stake_for_coldkey_and_hotkey(
 /// Get auto-stake settings for a coldkey
 /// Returns HashMap<netuid, hotkey> (same as Bittensor Python)
 pub async fn get_auto_stakes(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     coldkey: &AccountId32,
-) -> Result<std::collections::HashMap<u16, AccountId32>> {
+) -> BittensorResult<std::collections::HashMap<u16, AccountId32>> {
     let mut map = std::collections::HashMap::new();
     // Iterate across all subnets and query (coldkey, netuid) -> destination
     let total_networks_val = client
@@ -178,7 +184,7 @@ pub async fn get_auto_stakes(
             .storage_with_keys(SUBTENSOR_MODULE, "AutoStakeDestination", keys)
             .await?
         {
-            if let Ok(hotkey) = crate::utils::decoders::decode_account_id32(&dest_val) {
+            if let Ok(hotkey) = decode_account_id32(&dest_val) {
                 map.insert(netuid, hotkey);
             }
         }
@@ -188,10 +194,10 @@ pub async fn get_auto_stakes(
 
 /// Get stake weight (normalized)
 pub async fn get_stake_weight(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
-) -> Result<f64> {
+) -> BittensorResult<f64> {
     // Query stake weights from storage
     let keys = vec![
         Value::u128(netuid as u128),
@@ -201,65 +207,74 @@ pub async fn get_stake_weight(
     let weight_val = client
         .storage_with_keys(SUBTENSOR_MODULE, "StakeWeight", keys)
         .await?
-        .ok_or_else(|| anyhow::anyhow!("StakeWeight not found for hotkey and netuid {}", netuid))?;
+        .ok_or_else(|| {
+            ChainQueryError::new(format!(
+                "StakeWeight not found for hotkey and netuid {}",
+                netuid
+            ))
+        })?;
 
     let weight = decode_u64(&weight_val)
-        .map_err(|e| anyhow::anyhow!("Failed to decode StakeWeight: {}", e))?;
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode StakeWeight: {}", e)))?;
 
     // Normalize from u64 to 0.0-1.0 range
     Ok(weight as f64 / u64::MAX as f64)
 }
 
 /// Get minimum required stake
-pub async fn get_minimum_required_stake(client: &BittensorClient) -> Result<Rao> {
+pub async fn get_minimum_required_stake(client: &impl ChainBackend) -> BittensorResult<Rao> {
     let min_stake_val = client
         .storage(SUBTENSOR_MODULE, "NominatorMinRequiredStake", None)
         .await?
-        .ok_or_else(|| anyhow::anyhow!("NominatorMinRequiredStake storage entry not found"))?;
+        .ok_or_else(|| ChainQueryError::new("NominatorMinRequiredStake storage entry not found"))?;
 
-    decode_u128(&min_stake_val)
-        .map(Rao::from)
-        .map_err(|e| anyhow::anyhow!("Failed to decode NominatorMinRequiredStake: {}", e))
+    decode_u128(&min_stake_val).map(Rao::from).map_err(|e| {
+        ChainQueryError::new(format!("Failed to decode NominatorMinRequiredStake: {}", e)).into()
+    })
 }
 
 /// Get hotkey stake (alias for get_stake_for_hotkey)
 pub async fn get_hotkey_stake(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
     netuid: u16,
-) -> Result<Rao> {
+) -> BittensorResult<Rao> {
     get_stake_for_hotkey(client, hotkey, netuid).await
 }
 
 /// Get stake add fee for a given amount
 pub async fn get_stake_add_fee(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     amount: u128,
     netuid: u16,
-) -> Result<u128> {
+) -> BittensorResult<u128> {
     get_stake_operations_fee(client, amount, netuid).await
 }
 
 /// Get unstake fee for a given amount
-pub async fn get_unstake_fee(client: &BittensorClient, amount: u128, netuid: u16) -> Result<u128> {
+pub async fn get_unstake_fee(
+    client: &impl ChainBackend,
+    amount: u128,
+    netuid: u16,
+) -> BittensorResult<u128> {
     get_stake_operations_fee(client, amount, netuid).await
 }
 
 /// Get stake movement fee for a given amount
 pub async fn get_stake_movement_fee(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     amount: u128,
     netuid: u16,
-) -> Result<u128> {
+) -> BittensorResult<u128> {
     get_stake_operations_fee(client, amount, netuid).await
 }
 
 /// Get stake operations fee (base calculation for add/unstake/movement)
 pub async fn get_stake_operations_fee(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     amount: u128,
     netuid: u16,
-) -> Result<u128> {
+) -> BittensorResult<u128> {
     let keys = vec![Value::u128(netuid as u128)];
     if let Some(val) = client.storage_with_keys("Swap", "FeeRate", keys).await? {
         if let Ok(fee_rate) = decode_u64(&val) {
@@ -273,9 +288,9 @@ pub async fn get_stake_operations_fee(
 
 /// Get stake info for a coldkey (detailed stake information)
 pub async fn get_stake_info_for_coldkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     coldkey: &AccountId32,
-) -> Result<Vec<StakeInfo>> {
+) -> BittensorResult<Vec<StakeInfo>> {
     let stakes = get_stake_for_coldkey(client, coldkey).await?;
     let owned_hotkeys = crate::queries::wallets::get_owned_hotkeys(client, coldkey).await?;
 
@@ -310,35 +325,331 @@ pub struct StakeInfo {
 /// Get total stake for a hotkey across all subnets
 /// Reads SubtensorModule::TotalHotkeyStake storage
 pub async fn get_total_stake_for_hotkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
-) -> Result<u128> {
+) -> BittensorResult<u128> {
     let keys = vec![Value::from_bytes(hotkey.encode())];
 
-    if let Some(val) = client
+    let value = client
         .storage_with_keys(SUBTENSOR_MODULE, "TotalHotkeyStake", keys)
-        .await?
-    {
-        return decode_u128(&val)
-            .map_err(|e| anyhow::anyhow!("Failed to decode TotalHotkeyStake: {}", e));
-    }
-    Ok(0)
+        .await?;
+    decode_stored_or(value, 0, decode_u128).map_err(|e| {
+        ChainQueryError::new(format!("Failed to decode TotalHotkeyStake: {}", e)).into()
+    })
 }
 
 /// Get total stake for a coldkey across all subnets
 /// Reads SubtensorModule::TotalColdkeyStake storage
 pub async fn get_total_stake_for_coldkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     coldkey: &AccountId32,
-) -> Result<u128> {
+) -> BittensorResult<u128> {
     let keys = vec![Value::from_bytes(coldkey.encode())];
 
-    if let Some(val) = client
+    let value = client
         .storage_with_keys(SUBTENSOR_MODULE, "TotalColdkeyStake", keys)
+        .await?;
+    decode_stored_or(value, 0, decode_u128).map_err(|e| {
+        ChainQueryError::new(format!("Failed to decode TotalColdkeyStake: {}", e)).into()
+    })
+}
+
+/// Emission earned by one stake in one epoch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpochEmission {
+    /// Index of the epoch, see [`crate::core::epoch::epoch_index`]
+    pub epoch: u64,
+    /// Block the epoch ran in
+    pub block: u64,
+    /// Alpha earned by the stake
+    pub alpha: Rao,
+    /// The node no longer had the state of `block`, so the latest state was
+    /// used instead
+    pub estimated: bool,
+}
+
+/// Recent emission earned by a coldkey's stake on a hotkey
+#[derive(Debug, Clone)]
+pub struct StakeEmissionHistory {
+    pub hotkey: AccountId32,
+    pub coldkey: AccountId32,
+    pub netuid: u16,
+    /// Current stake
+    pub stake: Rao,
+    /// Most recent epoch first
+    pub epochs: Vec<EpochEmission>,
+}
+
+impl StakeEmissionHistory {
+    /// Alpha earned over all epochs
+    pub fn total_alpha(&self) -> Rao {
+        self.epochs
+            .iter()
+            .fold(Rao::ZERO, |total, epoch| total.saturating_add(epoch.alpha))
+    }
+
+    /// Whether any epoch was estimated from the latest state
+    pub fn is_estimated(&self) -> bool {
+        self.epochs.iter().any(|epoch| epoch.estimated)
+    }
+}
+
+/// Estimate the emission a stake earned in each of the last `last_n_epochs` epochs
+///
+/// Storage does not record emission per staker, so it is attributed the way
+/// the chain distributes a hotkey's epoch emission:
+///
+/// 1. The hotkey's `Emission` is split into miner incentive and validator
+///    dividends in proportion to its `Incentive` and `Dividends` values.
+/// 2. The delegate take (`Delegates`) of the dividends goes to the hotkey
+///    owner; the rest is shared by stakers in proportion to their `Alpha` out
+///    of `TotalHotkeyAlpha`.
+/// 3. If `coldkey` owns the hotkey, it also earns the take and the incentive.
+///
+/// Parent and child hotkey splits are not accounted for. Each epoch is read
+/// at the block it ran in, which needs an archive node for all but the most
+/// recent blocks; when that state has been pruned, the latest state is used
+/// and the epoch is marked [`estimated`](EpochEmission::estimated).
+pub async fn get_stake_emission_history(
+    client: &BittensorClient,
+    coldkey: &AccountId32,
+    hotkey: &AccountId32,
+    netuid: u16,
+    last_n_epochs: u64,
+) -> BittensorResult<StakeEmissionHistory> {
+    let stake = get_stake(client, coldkey, hotkey, netuid)
+        .await
+        .unwrap_or(Rao::ZERO);
+    let tempo = crate::queries::subnets::tempo(client, netuid)
         .await?
-    {
-        return decode_u128(&val)
-            .map_err(|e| anyhow::anyhow!("Failed to decode TotalColdkeyStake: {}", e));
+        .unwrap_or(0);
+    if tempo == 0 {
+        return Err(ChainQueryError::new(format!(
+            "Subnet {} has tempo 0; it does not run epochs",
+            netuid
+        ))
+        .into());
+    }
+
+    let current_block = client.block_number().await?;
+    let latest = read_epoch_snapshot(client, coldkey, hotkey, netuid, None).await?;
+
+    // The chain runs an epoch in the block before its index increments
+    let mut index = epoch::epoch_index(netuid, tempo, current_block);
+    let mut epochs = Vec::new();
+    while (epochs.len() as u64) < last_n_epochs {
+        let start = epoch::epoch_start_block(netuid, tempo, index);
+        if start == 0 {
+            break;
+        }
+        let block = start - 1;
+        index -= 1;
+
+        let snapshot = match client.block_hash(block).await {
+            Ok(Some(hash)) => read_epoch_snapshot(client, coldkey, hotkey, netuid, Some(hash))
+                .await
+                .inspect_err(|e| tracing::debug!("State of block {} unavailable: {}", block, e))
+                .ok(),
+            _ => None,
+        };
+        let estimated = snapshot.is_none();
+        let snapshot = snapshot.unwrap_or(latest);
+        epochs.push(EpochEmission {
+            epoch: index,
+            block,
+            alpha: Rao::from(snapshot.attributed_emission()),
+            estimated,
+        });
+    }
+
+    Ok(StakeEmissionHistory {
+        hotkey: hotkey.clone(),
+        coldkey: coldkey.clone(),
+        netuid,
+        stake,
+        epochs,
+    })
+}
+
+/// Storage values emission is attributed from
+#[derive(Debug, Clone, Copy, Default)]
+struct EpochSnapshot {
+    /// `Emission` of the hotkey's UID
+    emission: u128,
+    incentive: u16,
+    dividends: u16,
+    take: u16,
+    stake: u128,
+    total_hotkey_alpha: u128,
+    is_owner: bool,
+}
+
+impl EpochSnapshot {
+    /// Share of the hotkey's emission earned by the stake
+    fn attributed_emission(&self) -> u128 {
+        let split = self.incentive as u128 + self.dividends as u128;
+        let dividends = if split == 0 {
+            0
+        } else {
+            mul_div(self.emission, self.dividends as u128, split)
+        };
+        let incentive = self.emission.saturating_sub(dividends);
+        let take = mul_div(dividends, self.take as u128, u16::MAX as u128);
+        let nominators = dividends.saturating_sub(take);
+
+        let mut earned = if self.total_hotkey_alpha == 0 {
+            0
+        } else {
+            mul_div(nominators, self.stake, self.total_hotkey_alpha)
+        };
+        if self.is_owner {
+            earned = earned.saturating_add(take).saturating_add(incentive);
+        }
+        earned
+    }
+}
+
+/// `a * b / c` without overflowing the product, saturating the result
+fn mul_div(a: u128, b: u128, c: u128) -> u128 {
+    let result = sp_core::U256::from(a) * sp_core::U256::from(b) / sp_core::U256::from(c);
+    result.try_into().unwrap_or(u128::MAX)
+}
+
+/// Read the attribution inputs at `at`, or the latest block
+async fn read_epoch_snapshot(
+    client: &BittensorClient,
+    coldkey: &AccountId32,
+    hotkey: &AccountId32,
+    netuid: u16,
+    at: Option<sp_core::H256>,
+) -> Result<EpochSnapshot> {
+    let read = |entry: &'static str, keys: Vec<Value>| async move {
+        match at {
+            Some(hash) => {
+                client
+                    .storage_at_block(SUBTENSOR_MODULE, entry, keys, hash)
+                    .await
+            }
+            None => {
+                client
+                    .storage_with_keys(SUBTENSOR_MODULE, entry, keys)
+                    .await
+            }
+        }
+    };
+    let netuid_key = || vec![Value::u128(netuid as u128)];
+    let hotkey_key = || Value::from_bytes(hotkey.encode());
+
+    let mut snapshot = EpochSnapshot::default();
+
+    let uid = read("Uids", vec![Value::u128(netuid as u128), hotkey_key()])
+        .await?
+        .and_then(|v| decode_u64(&v).ok());
+    if let Some(uid) = uid.map(|uid| uid as usize) {
+        let emission = decode_stored_or(read("Emission", netuid_key()).await?, Vec::new(), |v| {
+            decode_vec(v, decode_u128)
+        })?;
+        let incentive =
+            decode_stored_or(read("Incentive", netuid_key()).await?, Vec::new(), |v| {
+                decode_vec(v, decode_u16)
+            })?;
+        let dividends =
+            decode_stored_or(read("Dividends", netuid_key()).await?, Vec::new(), |v| {
+                decode_vec(v, decode_u16)
+            })?;
+        snapshot.emission = emission.get(uid).copied().unwrap_or(0);
+        snapshot.incentive = incentive.get(uid).copied().unwrap_or(0);
+        snapshot.dividends = dividends.get(uid).copied().unwrap_or(0);
+    }
+
+    snapshot.take = decode_stored_or(read("Delegates", vec![hotkey_key()]).await?, 0, decode_u16)?;
+    snapshot.stake = decode_stored_or(
+        read(
+            "Alpha",
+            vec![
+                hotkey_key(),
+                Value::from_bytes(coldkey.encode()),
+                Value::u128(netuid as u128),
+            ],
+        )
+        .await?,
+        0,
+        decode_u128,
+    )?;
+    snapshot.total_hotkey_alpha = decode_stored_or(
+        read(
+            "TotalHotkeyAlpha",
+            vec![hotkey_key(), Value::u128(netuid as u128)],
+        )
+        .await?,
+        0,
+        decode_u128,
+    )?;
+    snapshot.is_owner = read("Owner", vec![hotkey_key()])
+        .await?
+        .and_then(|v| decode_account_id32(&v).ok())
+        .is_some_and(|owner| &owner == coldkey);
+
+    Ok(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> EpochSnapshot {
+        EpochSnapshot {
+            emission: 1_000,
+            incentive: 0,
+            dividends: u16::MAX,
+            take: 0,
+            stake: 250,
+            total_hotkey_alpha: 1_000,
+            is_owner: false,
+        }
+    }
+
+    #[test]
+    fn test_nominator_gets_stake_share_of_dividends() {
+        assert_eq!(snapshot().attributed_emission(), 250);
+
+        // An 18% take comes out of the dividends before they are shared
+        let taxed = EpochSnapshot {
+            take: 11_796,
+            ..snapshot()
+        };
+        assert_eq!(taxed.attributed_emission(), 205);
+
+        let unstaked = EpochSnapshot {
+            total_hotkey_alpha: 0,
+            ..snapshot()
+        };
+        assert_eq!(unstaked.attributed_emission(), 0);
+    }
+
+    #[test]
+    fn test_owner_also_gets_take_and_incentive() {
+        // Half incentive, half dividends
+        let owner = EpochSnapshot {
+            incentive: 32_768,
+            dividends: 32_768,
+            take: 11_796,
+            is_owner: true,
+            ..snapshot()
+        };
+        // 500 incentive + 89 take + a quarter of the remaining 411
+        assert_eq!(owner.attributed_emission(), 500 + 89 + 102);
+
+        let not_owner = EpochSnapshot {
+            is_owner: false,
+            ..owner
+        };
+        assert_eq!(not_owner.attributed_emission(), 102);
+    }
+
+    #[test]
+    fn test_mul_div_does_not_overflow() {
+        assert_eq!(mul_div(u128::MAX, 4, 8), u128::MAX / 2);
+        assert_eq!(mul_div(u128::MAX, 2, 1), u128::MAX);
     }
-    Ok(0)
 }
@@ -1,18 +1,26 @@
-use crate::chain::BittensorClient;
+use crate::chain::{BittensorClient, ChainBackend};
 use crate::core::constants::RAOPERTAO;
-use crate::types::SubnetInfo;
+use crate::errors::{BittensorError, BittensorResult, ChainQueryError};
+use crate::types::{DynamicInfo, SubnetInfo, SubnetStateInfo};
 use crate::utils::balance_newtypes::Rao;
+use crate::utils::decoders::runtime::decode_all_dynamic_info;
 use crate::utils::decoders::{
-    decode_account_id32, decode_bool, decode_u128, decode_u16, decode_u64,
+    decode_account_id32, decode_bool, decode_stored_or, decode_u128, decode_u16, decode_u64,
 };
 use anyhow::Result;
-use parity_scale_codec::Encode;
+use parity_scale_codec::{Compact, Decode, Encode};
+use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
 
+pub use crate::utils::decoders::runtime::decode_dynamic_info;
+
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
 /// Check if commit-reveal mechanism is enabled for a subnet
-pub async fn commit_reveal_enabled(client: &BittensorClient, netuid: u16) -> Result<bool> {
+pub async fn commit_reveal_enabled(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<bool> {
     match client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -53,7 +61,7 @@ pub async fn commit_reveal_enabled(client: &BittensorClient, netuid: u16) -> Res
 /// Get the number of mechanisms for a subnet
 /// Returns the count of mechanisms (0 to count-1 are valid mechanism IDs)
 /// Default is 1 (only mechanism 0 exists)
-pub async fn get_mechanism_count(client: &BittensorClient, netuid: u16) -> Result<u8> {
+pub async fn get_mechanism_count(client: &impl ChainBackend, netuid: u16) -> BittensorResult<u8> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -72,7 +80,7 @@ pub async fn get_mechanism_count(client: &BittensorClient, netuid: u16) -> Resul
 }
 
 /// Get the recycle/burn amount for a subnet
-pub async fn recycle(client: &BittensorClient, netuid: u16) -> Result<Option<u128>> {
+pub async fn recycle(client: &impl ChainBackend, netuid: u16) -> BittensorResult<Option<u128>> {
     if let Some(val) = client
         .storage_with_keys(SUBTENSOR_MODULE, "Burn", vec![Value::u128(netuid as u128)])
         .await?
@@ -84,9 +92,9 @@ pub async fn recycle(client: &BittensorClient, netuid: u16) -> Result<Option<u12
 
 /// Get the reveal period epochs for a subnet
 pub async fn get_subnet_reveal_period_epochs(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<Option<u64>> {
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -101,7 +109,7 @@ pub async fn get_subnet_reveal_period_epochs(
 }
 
 /// Check if a subnet is active (FirstEmissionBlockNumber > 0)
-pub async fn is_subnet_active(client: &BittensorClient, netuid: u16) -> Result<bool> {
+pub async fn is_subnet_active(client: &impl ChainBackend, netuid: u16) -> BittensorResult<bool> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -118,7 +126,7 @@ pub async fn is_subnet_active(client: &BittensorClient, netuid: u16) -> Result<b
 }
 
 /// Get all subnet infos using storage
-pub async fn all_subnets(client: &BittensorClient) -> Result<Vec<SubnetInfo>> {
+pub async fn all_subnets(client: &impl ChainBackend) -> BittensorResult<Vec<SubnetInfo>> {
     let total = total_subnets(client).await.unwrap_or(0);
     let mut res = Vec::with_capacity(total as usize);
     for netuid in 0u16..total {
@@ -130,7 +138,10 @@ pub async fn all_subnets(client: &BittensorClient) -> Result<Vec<SubnetInfo>> {
 }
 
 /// Get subnet information using targeted storage reads
-pub async fn subnet_info(client: &BittensorClient, netuid: u16) -> Result<Option<SubnetInfo>> {
+pub async fn subnet_info(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<SubnetInfo>> {
     // If subnet does not exist, return None
     if !subnet_exists(client, netuid).await.unwrap_or(false) {
         return Ok(None);
@@ -200,12 +211,12 @@ pub async fn subnet_info(client: &BittensorClient, netuid: u16) -> Result<Option
 }
 
 /// Get all subnets information
-pub async fn all_subnets_info(client: &BittensorClient) -> Result<Vec<SubnetInfo>> {
+pub async fn all_subnets_info(client: &impl ChainBackend) -> BittensorResult<Vec<SubnetInfo>> {
     all_subnets(client).await
 }
 
 /// Get neuron count for a subnet
-pub async fn subnet_n(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn subnet_n(client: &impl ChainBackend, netuid: u16) -> BittensorResult<Option<u64>> {
     let result = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -222,35 +233,51 @@ pub async fn subnet_n(client: &BittensorClient, netuid: u16) -> Result<Option<u6
 }
 
 /// Extract if subnet exists
-pub async fn subnet_exists(client: &BittensorClient, netuid: u16) -> Result<bool> {
+pub async fn subnet_exists(client: &impl ChainBackend, netuid: u16) -> BittensorResult<bool> {
     let keys = vec![Value::u128(netuid as u128)];
     let result = client
         .storage_with_keys(SUBTENSOR_MODULE, "NetworksAdded", keys)
         .await?;
     if let Some(value) = result {
         decode_bool(&value).map_err(|e| {
-            anyhow::anyhow!(
+            ChainQueryError::new(format!(
                 "Failed to decode NetworksAdded for subnet {}: {}",
-                netuid,
-                e
-            )
+                netuid, e
+            ))
+            .into()
         })
     } else {
         Ok(false)
     }
 }
 
+/// Whether stake can be transferred between coldkeys on a subnet
+///
+/// Reads `TransferToggle`, which defaults to enabled.
+pub async fn get_transfer_toggle(client: &impl ChainBackend, netuid: u16) -> BittensorResult<bool> {
+    let value = client
+        .storage_with_keys(
+            SUBTENSOR_MODULE,
+            "TransferToggle",
+            vec![Value::u128(netuid as u128)],
+        )
+        .await?;
+    decode_stored_or(value, true, decode_bool)
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode TransferToggle: {}", e)).into())
+}
+
 /// Get total number of subnets
-pub async fn total_subnets(client: &BittensorClient) -> Result<u16> {
+pub async fn total_subnets(client: &impl ChainBackend) -> BittensorResult<u16> {
     let total_val = client
         .storage(SUBTENSOR_MODULE, "TotalNetworks", None)
         .await?
-        .ok_or_else(|| anyhow::anyhow!("TotalNetworks storage entry not found"))?;
-    decode_u16(&total_val).map_err(|e| anyhow::anyhow!("Failed to decode TotalNetworks: {}", e))
+        .ok_or_else(|| ChainQueryError::new("TotalNetworks storage entry not found"))?;
+    decode_u16(&total_val)
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode TotalNetworks: {}", e)).into())
 }
 
 /// Hyperparameters
-pub async fn difficulty(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn difficulty(client: &impl ChainBackend, netuid: u16) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -263,7 +290,7 @@ pub async fn difficulty(client: &BittensorClient, netuid: u16) -> Result<Option<
     }
     Ok(None)
 }
-pub async fn tempo(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn tempo(client: &impl ChainBackend, netuid: u16) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(SUBTENSOR_MODULE, "Tempo", vec![Value::u128(netuid as u128)])
         .await?
@@ -272,7 +299,10 @@ pub async fn tempo(client: &BittensorClient, netuid: u16) -> Result<Option<u64>>
     }
     Ok(None)
 }
-pub async fn min_allowed_weights(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn min_allowed_weights(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -285,7 +315,10 @@ pub async fn min_allowed_weights(client: &BittensorClient, netuid: u16) -> Resul
     }
     Ok(None)
 }
-pub async fn max_weight_limit(client: &BittensorClient, netuid: u16) -> Result<Option<u16>> {
+pub async fn max_weight_limit(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<u16>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -300,7 +333,10 @@ pub async fn max_weight_limit(client: &BittensorClient, netuid: u16) -> Result<O
     }
     Ok(None)
 }
-pub async fn immunity_period(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn immunity_period(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -313,7 +349,10 @@ pub async fn immunity_period(client: &BittensorClient, netuid: u16) -> Result<Op
     }
     Ok(None)
 }
-pub async fn weights_rate_limit(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn weights_rate_limit(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -326,7 +365,10 @@ pub async fn weights_rate_limit(client: &BittensorClient, netuid: u16) -> Result
     }
     Ok(None)
 }
-pub async fn blocks_since_last_step(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn blocks_since_last_step(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -340,9 +382,9 @@ pub async fn blocks_since_last_step(client: &BittensorClient, netuid: u16) -> Re
     Ok(None)
 }
 pub async fn blocks_since_last_update(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<Option<u64>> {
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -358,9 +400,9 @@ pub async fn blocks_since_last_update(
 
 /// Subnet owner hotkey
 pub async fn subnet_owner_hotkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<Option<sp_core::crypto::AccountId32>> {
+) -> BittensorResult<Option<sp_core::crypto::AccountId32>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -375,7 +417,10 @@ pub async fn subnet_owner_hotkey(
 }
 
 /// Subnet validator permits
-pub async fn subnet_validator_permits(client: &BittensorClient, netuid: u16) -> Result<Vec<bool>> {
+pub async fn subnet_validator_permits(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Vec<bool>> {
     let n_val = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -403,7 +448,10 @@ pub async fn subnet_validator_permits(client: &BittensorClient, netuid: u16) ->
 }
 
 /// Mechanism info
-pub async fn mechanism_count(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn mechanism_count(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -418,9 +466,9 @@ pub async fn mechanism_count(client: &BittensorClient, netuid: u16) -> Result<Op
 }
 
 pub async fn mechanism_emission_split(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<Option<u64>> {
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -435,7 +483,7 @@ pub async fn mechanism_emission_split(
 }
 
 /// Get subnet burn cost (runtime API)
-pub async fn subnet_burn_cost(client: &BittensorClient, _netuid: u16) -> Result<Rao> {
+pub async fn subnet_burn_cost(client: &BittensorClient, _netuid: u16) -> BittensorResult<Rao> {
     let cost_val = client
         .runtime_api(
             "SubnetRegistrationRuntimeApi",
@@ -444,19 +492,19 @@ pub async fn subnet_burn_cost(client: &BittensorClient, _netuid: u16) -> Result<
         )
         .await?
         .ok_or_else(|| {
-            anyhow::anyhow!("Failed to retrieve network registration cost from runtime API")
+            ChainQueryError::new("Failed to retrieve network registration cost from runtime API")
         })?;
     let cost_u64 = decode_u64(&cost_val).map_err(|e| {
-        anyhow::anyhow!(
+        ChainQueryError::new(format!(
             "Failed to decode network registration cost (TaoCurrency): {}",
             e
-        )
+        ))
     })?;
     Ok(Rao::from(cost_u64 as u128))
 }
 
 /// Get subnet Alpha price in RAO via runtime API (SN0 fixed to 1 TAO)
-pub async fn get_subnet_price(client: &BittensorClient, netuid: u16) -> Result<Rao> {
+pub async fn get_subnet_price(client: &BittensorClient, netuid: u16) -> BittensorResult<Rao> {
     if netuid == 0 {
         return Ok(Rao::from(RAOPERTAO));
     }
@@ -470,7 +518,7 @@ pub async fn get_subnet_price(client: &BittensorClient, netuid: u16) -> Result<R
     {
         return crate::utils::decoders::decode_u128(&val)
             .map(Rao::from)
-            .map_err(|e| anyhow::anyhow!("Failed to decode price: {}", e));
+            .map_err(|e| ChainQueryError::new(format!("Failed to decode price: {}", e)).into());
     }
     let sqrt_price = super::liquidity::get_current_subnet_price_rao(client, netuid).await?;
     Ok(Rao::from(sqrt_price))
@@ -479,7 +527,7 @@ pub async fn get_subnet_price(client: &BittensorClient, netuid: u16) -> Result<R
 /// Get prices for all subnets
 pub async fn get_subnet_prices(
     client: &BittensorClient,
-) -> Result<std::collections::HashMap<u16, Rao>> {
+) -> BittensorResult<std::collections::HashMap<u16, Rao>> {
     let total = total_subnets(client).await.unwrap_or(0);
     let mut map = std::collections::HashMap::new();
     for netuid in 0u16..total {
@@ -493,17 +541,14 @@ pub async fn get_subnet_prices(
 
 /// Calculate next epoch start block
 pub async fn get_next_epoch_start_block(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     block: Option<u64>,
-) -> Result<Option<u64>> {
+) -> BittensorResult<Option<u64>> {
     let current_block = if let Some(b) = block {
         b
     } else {
-        client
-            .block_number()
-            .await
-            .map_err(|e| anyhow::anyhow!("{}", e))?
+        client.block_number().await?
     };
     let blocks_since = super::subnets::blocks_since_last_step(client, netuid)
         .await?
@@ -521,7 +566,10 @@ pub async fn get_next_epoch_start_block(
     }
 }
 
-pub async fn subnet_tao_in_emission(client: &BittensorClient, netuid: u16) -> Result<Option<u64>> {
+pub async fn subnet_tao_in_emission(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -535,7 +583,7 @@ pub async fn subnet_tao_in_emission(client: &BittensorClient, netuid: u16) -> Re
     Ok(None)
 }
 
-pub async fn block_emission(client: &BittensorClient) -> Result<Option<u64>> {
+pub async fn block_emission(client: &impl ChainBackend) -> BittensorResult<Option<u64>> {
     if let Some(val) = client
         .storage_with_keys(SUBTENSOR_MODULE, "BlockEmission", vec![])
         .await?
@@ -545,7 +593,10 @@ pub async fn block_emission(client: &BittensorClient) -> Result<Option<u64>> {
     Ok(None)
 }
 
-pub async fn subnet_emission_percent(client: &BittensorClient, netuid: u16) -> Result<Option<f64>> {
+pub async fn subnet_emission_percent(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<f64>> {
     let sub = subnet_tao_in_emission(client, netuid).await?.unwrap_or(0);
     let total = block_emission(client).await?.unwrap_or(0);
     if total == 0 {
@@ -554,12 +605,12 @@ pub async fn subnet_emission_percent(client: &BittensorClient, netuid: u16) -> R
     Ok(Some((sub as f64) / (total as f64)))
 }
 
-/// Get the owner (coldkey) of a subnet
+/// Subnet owner coldkey
 /// Reads SubtensorModule::SubnetOwner storage
-pub async fn get_subnet_owner(
-    client: &BittensorClient,
+pub async fn subnet_owner(
+    client: &impl ChainBackend,
     netuid: u16,
-) -> Result<Option<sp_core::crypto::AccountId32>> {
+) -> BittensorResult<Option<AccountId32>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -573,15 +624,56 @@ pub async fn get_subnet_owner(
     Ok(None)
 }
 
+/// Get the owner (coldkey) of a subnet, failing if the subnet has none
+pub async fn get_subnet_owner(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<AccountId32> {
+    subnet_owner(client, netuid).await?.ok_or_else(|| {
+        ChainQueryError::with_storage(
+            format!("Subnet {} has no owner", netuid),
+            SUBTENSOR_MODULE,
+            "SubnetOwner",
+        )
+        .into()
+    })
+}
+
+/// Get the TAO the owner locked when registering a subnet
+/// Reads SubtensorModule::SubnetLocked storage
+pub async fn get_subnet_locked(client: &impl ChainBackend, netuid: u16) -> BittensorResult<Rao> {
+    match client
+        .storage_with_keys(
+            SUBTENSOR_MODULE,
+            "SubnetLocked",
+            vec![Value::u128(netuid as u128)],
+        )
+        .await?
+    {
+        Some(val) => decode_u64(&val).map(Rao::from).map_err(|e| {
+            ChainQueryError::with_storage(
+                format!("Failed to decode SubnetLocked: {}", e),
+                SUBTENSOR_MODULE,
+                "SubnetLocked",
+            )
+            .into()
+        }),
+        None => Ok(Rao::ZERO),
+    }
+}
+
 /// Get the network lock cost (burn cost to register a new subnet)
 /// Reads SubtensorModule::NetworkLockReductionInterval and NetworkMinLockCost
-pub async fn get_subnet_burn_cost(client: &BittensorClient) -> Result<u128> {
+pub async fn get_subnet_burn_cost(client: &BittensorClient) -> BittensorResult<u128> {
     Ok(subnet_burn_cost(client, 0).await?.as_u128())
 }
 
 /// Get emission value for a specific subnet (RAO per block)
 /// Reads SubtensorModule::EmissionValues storage
-pub async fn get_subnet_emission_value(client: &BittensorClient, netuid: u16) -> Result<u128> {
+pub async fn get_subnet_emission_value(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<u128> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -590,28 +682,61 @@ pub async fn get_subnet_emission_value(client: &BittensorClient, netuid: u16) ->
         )
         .await?
     {
-        return decode_u128(&val)
-            .map_err(|e| anyhow::anyhow!("Failed to decode EmissionValues: {}", e));
+        return decode_u128(&val).map_err(|e| {
+            ChainQueryError::new(format!("Failed to decode EmissionValues: {}", e)).into()
+        });
     }
     Ok(0)
 }
 
 /// Get all subnets info (alias for all_subnets_info with richer data)
-pub async fn get_all_subnets_info(client: &BittensorClient) -> Result<Vec<SubnetInfo>> {
+pub async fn get_all_subnets_info(client: &impl ChainBackend) -> BittensorResult<Vec<SubnetInfo>> {
     all_subnets_info(client).await
 }
 
 /// Get DynamicInfo for a specific subnet
+///
+/// Uses `SubnetInfoRuntimeApi_get_dynamic_info` and falls back to individual
+/// storage reads on runtimes without it.
 pub async fn get_dynamic_info(
     client: &BittensorClient,
     netuid: u16,
-) -> Result<crate::types::DynamicInfo> {
-    use crate::types::DynamicInfo;
+) -> BittensorResult<DynamicInfo> {
+    let bytes = client
+        .runtime_api_call(
+            "SubnetInfoRuntimeApi",
+            "get_dynamic_info",
+            Some(netuid.encode()),
+        )
+        .await;
+    match bytes {
+        Ok(bytes) => {
+            if let Some(mut info) = decode_dynamic_info(&bytes)? {
+                // Activation (first emission block) is not part of the runtime struct
+                info.is_active = is_subnet_active(client, netuid).await.unwrap_or(false);
+                return Ok(info);
+            }
+            Err(ChainQueryError::new(format!("Subnet {} does not exist", netuid)).into())
+        }
+        Err(e) => {
+            tracing::debug!(
+                "get_dynamic_info runtime API unavailable, using storage: {}",
+                e
+            );
+            Ok(get_dynamic_info_from_storage(client, netuid).await?)
+        }
+    }
+}
 
+/// Build DynamicInfo from individual storage entries
+async fn get_dynamic_info_from_storage(
+    client: &BittensorClient,
+    netuid: u16,
+) -> Result<DynamicInfo> {
     let mut info = DynamicInfo::new(netuid);
 
     if !subnet_exists(client, netuid).await.unwrap_or(false) {
-        return Err(anyhow::anyhow!("Subnet {} does not exist", netuid));
+        return Err(ChainQueryError::new(format!("Subnet {} does not exist", netuid)).into());
     }
 
     info.tempo = tempo(client, netuid).await?.unwrap_or(0);
@@ -643,7 +768,7 @@ pub async fn get_dynamic_info(
         info.burn = burn;
     }
 
-    if let Some(owner_ck) = get_subnet_owner(client, netuid).await? {
+    if let Some(owner_ck) = subnet_owner(client, netuid).await? {
         info.owner_coldkey = crate::utils::ss58::encode_ss58(&owner_ck);
     }
 
@@ -739,9 +864,18 @@ pub async fn get_dynamic_info(
 }
 
 /// Get DynamicInfo for all subnets
-pub async fn get_all_dynamic_info(
-    client: &BittensorClient,
-) -> Result<Vec<crate::types::DynamicInfo>> {
+pub async fn get_all_dynamic_info(client: &BittensorClient) -> BittensorResult<Vec<DynamicInfo>> {
+    if let Ok(bytes) = client
+        .runtime_api_call("SubnetInfoRuntimeApi", "get_all_dynamic_info", None)
+        .await
+    {
+        let mut infos = decode_all_dynamic_info(&bytes)?;
+        for info in &mut infos {
+            info.is_active = is_subnet_active(client, info.netuid).await.unwrap_or(false);
+        }
+        return Ok(infos);
+    }
+
     let total = total_subnets(client).await.unwrap_or(0);
     let mut results = Vec::with_capacity(total as usize);
     for netuid in 0u16..total {
@@ -754,3 +888,119 @@ pub async fn get_all_dynamic_info(
     }
     Ok(results)
 }
+
+/// Runtime `spec_version` that introduced `get_subnet_state`
+///
+/// `SubnetInfoRuntimeApi_get_subnet_state` shipped with dynamic TAO in finney
+/// spec version 233, with the field layout decoded here. Older runtimes take
+/// the storage path instead.
+pub const SUBNET_STATE_MIN_SPEC_VERSION: u32 = 233;
+
+/// SCALE layout of `SubnetStateInfo` as returned by the runtime API
+#[derive(Decode, Clone, Debug)]
+struct SubnetStateRaw {
+    netuid: Compact<u16>,
+    hotkeys: Vec<AccountId32>,
+    coldkeys: Vec<AccountId32>,
+    active: Vec<bool>,
+    validator_permit: Vec<bool>,
+    pruning_score: Vec<Compact<u16>>,
+    last_update: Vec<Compact<u64>>,
+    emission: Vec<Compact<u64>>,
+    dividends: Vec<Compact<u16>>,
+    incentives: Vec<Compact<u16>>,
+    consensus: Vec<Compact<u16>>,
+    trust: Vec<Compact<u16>>,
+    rank: Vec<Compact<u16>>,
+    block_at_registration: Vec<Compact<u64>>,
+    alpha_stake: Vec<Compact<u64>>,
+    tao_stake: Vec<Compact<u64>>,
+    total_stake: Vec<Compact<u64>>,
+    emission_history: Vec<Vec<Compact<u64>>>,
+}
+
+impl From<SubnetStateRaw> for SubnetStateInfo {
+    fn from(raw: SubnetStateRaw) -> Self {
+        fn u16s(v: Vec<Compact<u16>>) -> Vec<u16> {
+            v.into_iter().map(|c| c.0).collect()
+        }
+        fn u64s(v: Vec<Compact<u64>>) -> Vec<u64> {
+            v.into_iter().map(|c| c.0).collect()
+        }
+        fn raos(v: Vec<Compact<u64>>) -> Vec<Rao> {
+            v.into_iter().map(|c| Rao::from(c.0)).collect()
+        }
+
+        Self {
+            netuid: raw.netuid.0,
+            hotkeys: raw.hotkeys,
+            coldkeys: raw.coldkeys,
+            active: raw.active,
+            validator_permit: raw.validator_permit,
+            pruning_score: u16s(raw.pruning_score),
+            last_update: u64s(raw.last_update),
+            emission: raos(raw.emission),
+            dividends: u16s(raw.dividends),
+            incentives: u16s(raw.incentives),
+            consensus: u16s(raw.consensus),
+            trust: u16s(raw.trust),
+            rank: u16s(raw.rank),
+            block_at_registration: u64s(raw.block_at_registration),
+            alpha_stake: raos(raw.alpha_stake),
+            tao_stake: raos(raw.tao_stake),
+            total_stake: raos(raw.total_stake),
+            emission_history: raw.emission_history.into_iter().map(u64s).collect(),
+        }
+    }
+}
+
+/// Decode the SCALE-encoded `Option<SubnetStateInfo>` returned by the runtime API
+pub fn decode_subnet_state(bytes: &[u8]) -> BittensorResult<Option<SubnetStateInfo>> {
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    let raw = Option::<SubnetStateRaw>::decode(&mut &bytes[..])
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode SubnetState: {}", e)))?;
+    Ok(raw.map(SubnetStateInfo::from))
+}
+
+/// Whether the connected runtime is new enough for `get_subnet_state`
+///
+/// Also checks that the runtime metadata declares the call, so a node that
+/// gates the API differently falls back to storage.
+pub fn supports_subnet_state(client: &BittensorClient) -> bool {
+    client.api.runtime_version().spec_version >= SUBNET_STATE_MIN_SPEC_VERSION
+        && client
+            .api
+            .metadata()
+            .runtime_api_trait_by_name("SubnetInfoRuntimeApi")
+            .is_some_and(|api| api.method_by_name("get_subnet_state").is_some())
+}
+
+/// Fetch the per-UID subnet state in one runtime API call
+///
+/// Returns `None` if the subnet does not exist.
+pub async fn try_get_subnet_state(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<Option<SubnetStateInfo>> {
+    let bytes = client
+        .runtime_api_call(
+            "SubnetInfoRuntimeApi",
+            "get_subnet_state",
+            Some(netuid.encode()),
+        )
+        .await
+        .map_err(|e| BittensorError::from_chain("Failed to call get_subnet_state", e))?;
+    decode_subnet_state(&bytes)
+}
+
+/// Fetch the per-UID subnet state (hotkeys, scores, emission, stakes) in one call
+pub async fn get_subnet_state(
+    client: &impl ChainBackend,
+    netuid: u16,
+) -> BittensorResult<SubnetStateInfo> {
+    try_get_subnet_state(client, netuid)
+        .await?
+        .ok_or_else(|| ChainQueryError::new(format!("Subnet {} does not exist", netuid)).into())
+}
@@ -1,16 +1,16 @@
-use crate::chain::BittensorClient;
+use crate::chain::ChainBackend;
+use crate::errors::BittensorResult;
 use crate::types::ProposalVoteData;
 use crate::utils::decoders::{decode_named_composite, decode_u64, decode_vec_account_id32};
-use anyhow::Result;
 use sp_core::H256;
 use subxt::dynamic::Value;
 
 const TRI_PALLET: &str = "Triumvirate";
 
 pub async fn get_vote_data(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     proposal_hash: H256,
-) -> Result<Option<ProposalVoteData>> {
+) -> BittensorResult<Option<ProposalVoteData>> {
     if let Some(val) = client
         .storage_with_keys(
             TRI_PALLET,
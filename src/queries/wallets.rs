@@ -1,5 +1,5 @@
-use crate::chain::BittensorClient;
-use anyhow::Result;
+use crate::chain::ChainBackend;
+use crate::errors::BittensorResult;
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
@@ -7,7 +7,10 @@ use subxt::dynamic::Value;
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
 /// Returns true if the hotkey is known by the chain (Owner[hotkey] not zero)
-pub async fn does_hotkey_exist(client: &BittensorClient, hotkey: &AccountId32) -> Result<bool> {
+pub async fn does_hotkey_exist(
+    client: &impl ChainBackend,
+    hotkey: &AccountId32,
+) -> BittensorResult<bool> {
     if let Some(owner_val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -35,10 +38,10 @@ pub async fn does_hotkey_exist(client: &BittensorClient, hotkey: &AccountId32) -
 
 /// Check if hotkey is registered on a given subnet (Uids[(netuid, hotkey)] exists)
 pub async fn is_hotkey_registered(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
     netuid: u16,
-) -> Result<bool> {
+) -> BittensorResult<bool> {
     let val = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -54,39 +57,26 @@ pub async fn is_hotkey_registered(
 
 /// Check if hotkey is registered on any subnet
 pub async fn is_hotkey_registered_any(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
-) -> Result<bool> {
+) -> BittensorResult<bool> {
     let netuids = get_netuids_for_hotkey(client, hotkey).await?;
     Ok(!netuids.is_empty())
 }
 
 /// Get all netuids where the hotkey is registered
 pub async fn get_netuids_for_hotkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
-) -> Result<Vec<u16>> {
-    let total_val = client
-        .storage(SUBTENSOR_MODULE, "TotalNetworks", None)
-        .await?;
-    let total: u16 = total_val
-        .and_then(|v| crate::utils::decoders::decode_u64(&v).ok())
-        .and_then(|n| u16::try_from(n).ok())
-        .unwrap_or(0);
-    let mut nets = Vec::new();
-    for netuid in 0u16..total {
-        if is_hotkey_registered(client, hotkey, netuid).await? {
-            nets.push(netuid);
-        }
-    }
-    Ok(nets)
+) -> BittensorResult<Vec<u16>> {
+    crate::queries::neurons::is_hotkey_registered_any(client, hotkey).await
 }
 
 /// Get hotkeys owned by a coldkey (OwnedHotkeys[coldkey])
 pub async fn get_owned_hotkeys(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     coldkey: &AccountId32,
-) -> Result<Vec<AccountId32>> {
+) -> BittensorResult<Vec<AccountId32>> {
     if let Some(val) = client
         .storage_with_keys(
             SUBTENSOR_MODULE,
@@ -102,27 +92,17 @@ pub async fn get_owned_hotkeys(
 
 /// Get owner (coldkey) of hotkey (Owner[hotkey])
 pub async fn get_hotkey_owner(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
-) -> Result<Option<AccountId32>> {
-    if let Some(val) = client
-        .storage_with_keys(
-            SUBTENSOR_MODULE,
-            "Owner",
-            vec![Value::from_bytes(hotkey.encode())],
-        )
-        .await?
-    {
-        return Ok(crate::utils::decoders::decode_account_id32(&val).ok());
-    }
-    Ok(None)
+) -> BittensorResult<Option<AccountId32>> {
+    crate::queries::neurons::get_hotkey_owner(client, hotkey).await
 }
 
 /// Filter subnets by hotkeys that are registered on them
 pub async fn filter_netuids_by_registered_hotkeys(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkeys: &[AccountId32],
-) -> Result<Vec<u16>> {
+) -> BittensorResult<Vec<u16>> {
     let total_val = client
         .storage(SUBTENSOR_MODULE, "TotalNetworks", None)
         .await?;
@@ -143,9 +123,9 @@ pub async fn filter_netuids_by_registered_hotkeys(
 }
 
 /// Estimate transfer fee by reading fee-related storage (FeeRate). Returns raw fee rate (u128)
-pub async fn get_transfer_fee(client: &BittensorClient) -> Result<u128> {
+pub async fn get_transfer_fee(client: &impl ChainBackend) -> BittensorResult<u128> {
     if let Some(val) = client.storage(SUBTENSOR_MODULE, "FeeRate", None).await? {
-        return crate::utils::decoders::decode_u128(&val).map_err(|e| anyhow::anyhow!("{}", e));
+        return Ok(crate::utils::decoders::decode_u128(&val)?);
     }
     Ok(0)
 }
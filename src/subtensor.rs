@@ -11,33 +11,79 @@
 //!
 //! // Automatically uses commit-reveal if enabled on subnet
 //! let response = subtensor.set_weights(
-//!     &wallet,
+//!     &signer,
 //!     netuid,
 //!     &uids,
 //!     &weights,
-//!     version_key,
+//!     None, // use the subnet's WeightsVersionKey
+//!     ExtrinsicWait::Finalized,
+//!     true, // read the stored weights back
 //! ).await?;
+//! if let Some(verification) = &response.verification {
+//!     assert!(verification.matches, "{:?}", verification.differences);
+//! }
 //! ```
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
+//!
+//! Hot read-only queries (block number, tempo, rate limits) can be cached per
+//! block with [`Subtensor::cached`]; see [`cache`].
+//!
+//! [`Subtensor::with_connection_manager`] runs reads over a pool with retry
+//! and circuit breaking instead of a single connection.
+//! [`Subtensor::from_backend`] runs queries and extrinsics on any
+//! [`ChainBackend`] instead, such as an in-memory mock in tests.
+//!
+//! [`Subtensor::pruning_risk`] reports how close a UID is to deregistration,
+//! and [`Subtensor::watch_pruning`] follows it as blocks are finalized; see
+//! [`pruning`].
+
+pub mod cache;
+pub mod pruning;
+
+pub use cache::{CacheCategory, CacheKey, CacheStats, CacheTtl, QueryCache};
+pub use pruning::{PruningRisk, PruningThresholds, RiskLevel};
+
+use crate::blocks::{BlockEvent, BlockListener};
+use crate::chain::connection::{
+    create_backoff_config, CircuitBreakerState, ConnectionManager, ManagedConnection, PoolStats,
+    CIRCUIT_BREAKER_FAILURE_THRESHOLD, INITIAL_RETRY_DELAY_MS, MAX_RETRY_DELAY_MS,
+};
+use crate::chain::{
+    is_connection_error_message, with_retry, BittensorClient, BittensorSigner, CategoryRateLimiter,
+    ChainBackend, Error as ChainError, ExtrinsicCategory, ExtrinsicWait, RetryPolicy,
+};
+use crate::config::{RateLimitConfig, SubtensorConfig};
+use crate::core::epoch;
 use crate::crv4::{
-    calculate_reveal_round, commit_timelocked_mechanism_weights, commit_timelocked_weights,
-    get_mechid_storage_index, prepare_crv4_commit, DEFAULT_COMMIT_REVEAL_VERSION,
+    checked_reveal_round, commit_timelocked_mechanism_weights, commit_timelocked_weights,
+    get_mechid_storage_index, prepare_crv4_commit, DrandClient, DEFAULT_COMMIT_REVEAL_VERSION,
 };
+use crate::errors::{
+    BittensorResult, ChainConnectionError, ChainQueryError, NotRegistered, WeightsError,
+};
+use crate::queries::hyperparameters::get_weights_version_key;
 use crate::queries::subnets::{commit_reveal_enabled, tempo, weights_rate_limit};
-use crate::utils::weights::normalize_weights;
+use crate::utils::weights::{
+    normalize_weights, resolve_hotkey_weights, ResolvedWeight, WeightBounds, WeightValidation,
+    WeightVerification,
+};
+use crate::validator::mechanism::{WeightSettingLimit, WeightSettingStatus};
+use crate::validator::transfer::{TransferAmount, TransferPreview};
 use crate::validator::weights::{
-    commit_weights as raw_commit_weights, reveal_weights as raw_reveal_weights,
-    set_weights as raw_set_weights,
+    check_version_key, commit_weights as raw_commit_weights, reveal_weights as raw_reveal_weights,
+    submit_set_weights,
 };
-use anyhow::Result;
+use backoff::Error as BackoffError;
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::future::Future;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
-use tracing::{error, info};
-
-const SUBTENSOR_MODULE: &str = "SubtensorModule";
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, warn};
 
 /// Response from weight submission operations
 #[derive(Clone, Debug)]
@@ -50,6 +96,10 @@ pub struct WeightResponse {
     pub message: String,
     /// Additional data (e.g., reveal round for CRv4)
     pub data: Option<WeightResponseData>,
+    /// Weights row read back from the chain, when verification was requested
+    pub stored: Option<Vec<(u16, u16)>>,
+    /// Submitted weights compared with `stored`
+    pub verification: Option<WeightVerification>,
 }
 
 impl WeightResponse {
@@ -59,6 +109,8 @@ impl WeightResponse {
             tx_hash: Some(tx_hash),
             message: message.to_string(),
             data: None,
+            stored: None,
+            verification: None,
         }
     }
 
@@ -68,6 +120,8 @@ impl WeightResponse {
             tx_hash: None,
             message: message.to_string(),
             data: None,
+            stored: None,
+            verification: None,
         }
     }
 
@@ -75,6 +129,27 @@ impl WeightResponse {
         self.data = Some(data);
         self
     }
+
+    pub fn with_stored(
+        mut self,
+        stored: Vec<(u16, u16)>,
+        verification: WeightVerification,
+    ) -> Self {
+        self.stored = Some(stored);
+        self.verification = Some(verification);
+        self
+    }
+}
+
+/// Response from [`Subtensor::set_weights_by_hotkey`]
+#[derive(Clone, Debug)]
+pub struct HotkeyWeightResponse {
+    /// Outcome of the submission
+    pub response: WeightResponse,
+    /// Hotkeys that were submitted, with their UIDs and u16 weights
+    pub resolved: Vec<ResolvedWeight>,
+    /// Hotkeys that were not registered on the subnet and were dropped
+    pub skipped: Vec<String>,
 }
 
 /// Additional data from weight operations
@@ -123,7 +198,7 @@ impl SubtensorState {
         }
     }
 
-    pub fn save(&self, path: &PathBuf) -> Result<()> {
+    pub fn save(&self, path: &PathBuf) -> BittensorResult<()> {
         let content = serde_json::to_string_pretty(self)?;
         std::fs::write(path, content)?;
         Ok(())
@@ -137,8 +212,21 @@ impl SubtensorState {
 /// 2. Uses CRv4 (timelock encryption) if version >= 4
 /// 3. Falls back to legacy commit-reveal if needed
 /// 4. Uses direct set_weights if commit-reveal is disabled
-pub struct Subtensor {
-    client: Arc<BittensorClient>,
+///
+/// Queries and extrinsics run on any [`ChainBackend`] `B`. Endpoint failover,
+/// connection pooling, rate limits and the calls that need runtime metadata
+/// or block subscriptions are only available on a live [`BittensorClient`].
+pub struct Subtensor<B = BittensorClient> {
+    /// Active client; swapped out when rotating endpoints
+    client: std::sync::RwLock<Arc<B>>,
+    /// Endpoints in priority order
+    endpoints: Vec<String>,
+    /// Index into `endpoints` of the active connection
+    endpoint_index: AtomicUsize,
+    /// Consecutive connection failures on the active endpoint
+    connection_failures: AtomicU32,
+    /// Keep retrying (with backoff) when every endpoint is down
+    retry_forever: bool,
     /// Cached commit-reveal version
     crv_version: RwLock<Option<u16>>,
     /// Persisted state for pending commits
@@ -147,101 +235,613 @@ pub struct Subtensor {
     state_path: Option<PathBuf>,
     /// Default block time (seconds)
     block_time: f64,
+    /// Cache for hot read-only queries
+    cache: Arc<QueryCache>,
+    /// How weight inputs violating subnet bounds are handled
+    weight_validation: WeightValidation,
+    /// Where `pruning_risk` levels start
+    pruning_thresholds: PruningThresholds,
+    /// Connection pool for reads, when created with a connection manager
+    connections: Option<Arc<ConnectionManager>>,
+    /// Pooled connection that extrinsics are submitted on
+    pinned: std::sync::Mutex<Option<Arc<ManagedConnection>>>,
+    /// Per-block extrinsic budgets when not using a connection manager
+    rate_limits: Option<Arc<CategoryRateLimiter>>,
+    /// Retries for reads when not using a connection manager
+    read_retry: RetryPolicy,
+    /// Set when `B` is a [`BittensorClient`]
+    live: Option<LiveClient<B>>,
+}
+
+/// Conversions between a backend and the [`BittensorClient`] that endpoint
+/// rotation, the connection pool and rate limits work with
+struct LiveClient<B> {
+    wrap: fn(Arc<BittensorClient>) -> Arc<B>,
+    unwrap: fn(&B) -> &BittensorClient,
+}
+
+impl LiveClient<BittensorClient> {
+    const IDENTITY: Self = Self {
+        wrap: |client| client,
+        unwrap: |client| client,
+    };
 }
 
 impl Subtensor {
     /// Create a new Subtensor connection
-    pub async fn new(endpoint: &str) -> Result<Self> {
-        let client = BittensorClient::new(endpoint)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
-        Ok(Self {
-            client: Arc::new(client),
-            crv_version: RwLock::new(None),
-            state: RwLock::new(SubtensorState::default()),
-            state_path: None,
-            block_time: 12.0,
-        })
+    pub async fn new(endpoint: &str) -> BittensorResult<Self> {
+        let client = BittensorClient::new(endpoint).await.map_err(|e| {
+            ChainConnectionError::with_url(format!("Failed to connect: {}", e), endpoint)
+        })?;
+        Ok(Self::from_parts(
+            Arc::new(client),
+            SubtensorState::default(),
+            None,
+        ))
     }
 
     /// Create a new Subtensor connection using the default endpoint
-    pub async fn with_default() -> Result<Self> {
+    pub async fn with_default() -> BittensorResult<Self> {
         let client = BittensorClient::with_default()
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
-        Ok(Self {
-            client: Arc::new(client),
-            crv_version: RwLock::new(None),
-            state: RwLock::new(SubtensorState::default()),
-            state_path: None,
-            block_time: 12.0,
-        })
+            .map_err(|e| ChainConnectionError::new(format!("Failed to connect: {}", e)))?;
+        Ok(Self::from_parts(
+            Arc::new(client),
+            SubtensorState::default(),
+            None,
+        ))
     }
 
     /// Create with persistence for pending commits
-    pub async fn with_persistence(endpoint: &str, state_path: PathBuf) -> Result<Self> {
-        let client = BittensorClient::new(endpoint)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    pub async fn with_persistence(endpoint: &str, state_path: PathBuf) -> BittensorResult<Self> {
+        let client = BittensorClient::new(endpoint).await.map_err(|e| {
+            ChainConnectionError::with_url(format!("Failed to connect: {}", e), endpoint)
+        })?;
         let state = SubtensorState::load(&state_path);
-        Ok(Self {
-            client: Arc::new(client),
-            crv_version: RwLock::new(None),
-            state: RwLock::new(state),
-            state_path: Some(state_path),
-            block_time: 12.0,
-        })
+        Ok(Self::from_parts(Arc::new(client), state, Some(state_path)))
     }
 
     /// Create with persistence using the default endpoint
-    pub async fn with_default_persistence(state_path: PathBuf) -> Result<Self> {
+    pub async fn with_default_persistence(state_path: PathBuf) -> BittensorResult<Self> {
         let client = BittensorClient::with_default()
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+            .map_err(|e| ChainConnectionError::new(format!("Failed to connect: {}", e)))?;
         let state = SubtensorState::load(&state_path);
-        Ok(Self {
-            client: Arc::new(client),
-            crv_version: RwLock::new(None),
-            state: RwLock::new(state),
-            state_path: Some(state_path),
-            block_time: 12.0,
-        })
+        Ok(Self::from_parts(Arc::new(client), state, Some(state_path)))
     }
 
     /// Create from existing client
     pub fn from_client(client: Arc<BittensorClient>) -> Self {
+        Self::from_parts(client, SubtensorState::default(), None)
+    }
+
+    /// Create on top of a connection pool
+    ///
+    /// Reads run through [`ConnectionManager::execute_with_retry`], so a
+    /// dropped connection is retried on a healthy one. Extrinsics are submitted
+    /// on one pinned connection and never resubmitted; a connection-level
+    /// error moves later extrinsics to a healthy connection. Starts the
+    /// manager's background health checks.
+    pub async fn with_connection_manager(manager: Arc<ConnectionManager>) -> BittensorResult<Self> {
+        manager.start_health_checks();
+        let conn = manager
+            .get_connection()
+            .await
+            .map_err(|e| ChainConnectionError::new(format!("Failed to connect: {}", e)))?;
+
+        let mut subtensor = Self::from_parts(conn.shared_client(), SubtensorState::default(), None);
+        subtensor.pinned = std::sync::Mutex::new(Some(conn));
+        subtensor.connections = Some(manager);
+        Ok(subtensor)
+    }
+
+    fn from_parts(
+        client: Arc<BittensorClient>,
+        state: SubtensorState,
+        state_path: Option<PathBuf>,
+    ) -> Self {
+        let endpoints = vec![client.rpc_url().to_string()];
+        let mut subtensor = Self::from_shared(client, state, state_path);
+        subtensor.endpoints = endpoints;
+        subtensor.live = Some(LiveClient::IDENTITY);
+        subtensor
+    }
+
+    /// Get current RPC endpoint
+    ///
+    /// Returns an owned `String` (previously `&str`) since the active
+    /// connection can change after a failover.
+    pub fn endpoint(&self) -> String {
+        self.current_endpoint()
+    }
+
+    /// Endpoint of the active connection
+    ///
+    /// Changes after a failover to the next configured endpoint.
+    pub fn current_endpoint(&self) -> String {
+        self.client().rpc_url().to_string()
+    }
+
+    /// Wait until the finalized head reaches `target`
+    ///
+    /// Follows the finalized block subscription instead of polling. If the
+    /// subscription ends or errors, it is re-established with backoff and the
+    /// current head is re-read so blocks finalized while disconnected are not
+    /// missed. Returns the first finalized block number `>= target`, or an
+    /// error if `cancel` fires first.
+    pub async fn wait_for_block(
+        &self,
+        target: u64,
+        cancel: &CancellationToken,
+    ) -> BittensorResult<u64> {
+        let mut delay = Duration::from_millis(INITIAL_RETRY_DELAY_MS);
+
+        loop {
+            if cancel.is_cancelled() {
+                return Err(
+                    ChainQueryError::new(format!("Wait for block {} cancelled", target)).into(),
+                );
+            }
+
+            // Reconcile with the current head before (re)subscribing
+            match self.fetch_current_block().await {
+                Ok(head) if head >= target => return Ok(head),
+                Ok(_) => {}
+                Err(e) => warn!(
+                    "Failed to read head while waiting for block {}: {}",
+                    target, e
+                ),
+            }
+
+            let client = self.client();
+            match client.subscribe_finalized_blocks().await {
+                Ok(stream) => {
+                    let mut stream = std::pin::pin!(stream);
+                    loop {
+                        let next = tokio::select! {
+                            _ = cancel.cancelled() => {
+                                return Err(ChainQueryError::new(format!("Wait for block {} cancelled", target)).into());
+                            }
+                            next = stream.next() => next,
+                        };
+                        match next {
+                            Some(Ok(number)) => {
+                                delay = Duration::from_millis(INITIAL_RETRY_DELAY_MS);
+                                self.cache.on_block(number);
+                                if number >= target {
+                                    return Ok(number);
+                                }
+                            }
+                            Some(Err(e)) => {
+                                self.record_connection_failure().await;
+                                warn!(
+                                    "Block subscription error while waiting for {}: {}",
+                                    target, e
+                                );
+                                break;
+                            }
+                            None => {
+                                warn!("Block subscription ended while waiting for {}", target);
+                                break;
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.record_connection_failure().await;
+                    warn!("Failed to subscribe to finalized blocks: {}", e);
+                }
+            }
+
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    return Err(ChainQueryError::new(format!("Wait for block {} cancelled", target)).into());
+                }
+                _ = tokio::time::sleep(delay) => {}
+            }
+            delay = (delay * 2).min(Duration::from_millis(MAX_RETRY_DELAY_MS));
+        }
+    }
+
+    /// Wait until `offset_blocks` relative to the start of the next epoch
+    ///
+    /// A negative offset waits for a block before the boundary, e.g. `-100`
+    /// for "100 blocks before the next tempo". If that block has already
+    /// passed in the current epoch, the following epoch's boundary is used.
+    /// Epochs are per-subnet, so all mechanisms of `netuid` share the same
+    /// boundaries. Returns the block number reached.
+    pub async fn wait_for_next_epoch(
+        &self,
+        netuid: u16,
+        offset_blocks: i64,
+        cancel: &CancellationToken,
+    ) -> BittensorResult<u64> {
+        let tempo = self.tempo(netuid).await? as u64;
+        if tempo == 0 {
+            return Err(ChainQueryError::new(format!(
+                "Subnet {} has tempo 0; epochs never advance",
+                netuid
+            ))
+            .into());
+        }
+        let current_block = self.get_current_block().await?;
+        let target = next_epoch_target_block(current_block, netuid, tempo, offset_blocks);
+        debug!(
+            "Waiting for block {} (netuid {}, tempo {}, offset {})",
+            target, netuid, tempo, offset_blocks
+        );
+        self.wait_for_block(target, cancel).await
+    }
+
+    /// Re-evaluate the pruning risk of `uid` every `interval_blocks`
+    /// finalized blocks until `cancel` fires
+    ///
+    /// `on_change` is called with the first evaluation and then whenever the
+    /// risk level changes. Blocks are followed with [`Self::wait_for_block`].
+    pub async fn watch_pruning<F>(
+        &self,
+        netuid: u16,
+        uid: u16,
+        interval_blocks: u64,
+        cancel: &CancellationToken,
+        mut on_change: F,
+    ) -> BittensorResult<()>
+    where
+        F: FnMut(&PruningRisk),
+    {
+        let mut level = None;
+        while !cancel.is_cancelled() {
+            let risk = self.pruning_risk(netuid, uid).await?;
+            if level != Some(risk.risk) {
+                debug!(
+                    "Pruning risk of UID {} on subnet {} is {}",
+                    uid, netuid, risk.risk
+                );
+                level = Some(risk.risk);
+                on_change(&risk);
+            }
+            // Only fails once cancelled
+            if self
+                .wait_for_block(risk.block + interval_blocks.max(1), cancel)
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<B: ChainBackend> Subtensor<B> {
+    /// Create on top of any chain backend, e.g. an in-memory `MockChain`
+    ///
+    /// There are no endpoints to fail over to, and no connection pool or
+    /// rate limits.
+    pub fn from_backend(backend: B) -> Self {
+        Self::from_shared(Arc::new(backend), SubtensorState::default(), None)
+    }
+
+    fn from_shared(client: Arc<B>, state: SubtensorState, state_path: Option<PathBuf>) -> Self {
         Self {
-            client,
+            client: std::sync::RwLock::new(client),
+            endpoints: Vec::new(),
+            endpoint_index: AtomicUsize::new(0),
+            connection_failures: AtomicU32::new(0),
+            retry_forever: false,
             crv_version: RwLock::new(None),
-            state: RwLock::new(SubtensorState::default()),
-            state_path: None,
+            state: RwLock::new(state),
+            state_path,
             block_time: 12.0,
+            cache: Arc::new(QueryCache::default()),
+            weight_validation: WeightValidation::default(),
+            pruning_thresholds: PruningThresholds::default(),
+            connections: None,
+            pinned: std::sync::Mutex::new(None),
+            rate_limits: None,
+            read_retry: RetryPolicy::conservative(),
+            live: None,
         }
     }
-    /// Get current RPC endpoint
-    pub fn endpoint(&self) -> &str {
-        self.client.rpc_url()
-    }
+
     /// Get the underlying client
-    pub fn client(&self) -> &BittensorClient {
-        &self.client
+    ///
+    /// Returns a shared handle (previously `&BittensorClient`) since the
+    /// client is replaced after a failover; a handle taken before that keeps
+    /// using the old connection.
+    pub fn client(&self) -> Arc<B> {
+        self.client
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
     }
 
-    /// Get current block number
-    pub async fn get_current_block(&self) -> Result<u64> {
-        self.client
-            .block_number()
+    /// Reconnect to the next configured endpoint
+    ///
+    /// Endpoints are tried in order starting after the active one, wrapping
+    /// around. With `retry_forever` the full list is retried with backoff until
+    /// one connects; otherwise the last error is returned after one pass.
+    pub async fn rotate_endpoint(&self) -> BittensorResult<()> {
+        let Some(live) = &self.live else {
+            return Err(ChainConnectionError::new("No endpoints configured").into());
+        };
+        let start = self.endpoint_index.load(Ordering::SeqCst) + 1;
+        let (index, client) = connect_endpoints(&self.endpoints, start, self.retry_forever).await?;
+        *self
+            .client
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = (live.wrap)(Arc::new(client));
+        self.endpoint_index.store(index, Ordering::SeqCst);
+        self.connection_failures.store(0, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Connection health
+    ///
+    /// With a connection manager these are the pool's stats; otherwise the
+    /// single direct connection is reported, degraded after a failure.
+    pub async fn health(&self) -> PoolStats {
+        if let Some(manager) = &self.connections {
+            return manager.pool_stats().await;
+        }
+        let failing = self.connection_failures.load(Ordering::SeqCst) > 0;
+        PoolStats {
+            total_connections: 1,
+            healthy_count: usize::from(!failing),
+            degraded_count: usize::from(failing),
+            unhealthy_count: 0,
+            circuit_breaker_state: CircuitBreakerState::Closed,
+            max_size: 1,
+        }
+    }
+
+    /// Run a read-only query, retried across pooled connections when a
+    /// connection manager is configured and on the active connection
+    /// otherwise (see [`SubtensorBuilder::read_retry`])
+    async fn read<T, F, Fut>(&self, query: F) -> BittensorResult<T>
+    where
+        F: Fn(Arc<B>) -> Fut,
+        Fut: Future<Output = BittensorResult<T>>,
+    {
+        match self.connections.as_ref().zip(self.live.as_ref()) {
+            Some((manager, live)) => {
+                let wrap = live.wrap;
+                manager
+                    .execute_with_retry(|client| query(wrap(client)))
+                    .await
+            }
+            None => {
+                let result = with_retry(&self.read_retry, || query(self.client())).await;
+                self.observe_connection(&result).await;
+                result
+            }
+        }
+    }
+
+    /// Per-block extrinsic budgets, from the connection manager or builder
+    fn rate_limits(&self) -> Option<&CategoryRateLimiter> {
+        match &self.connections {
+            Some(manager) => Some(manager.rate_limits()),
+            None => self.rate_limits.as_deref(),
+        }
+    }
+
+    /// Submit an extrinsic on the pinned connection
+    ///
+    /// Waits for `category`'s per-block budget when rate limits are
+    /// configured. With a connection manager, a pinned connection that the
+    /// health checks no longer consider healthy is replaced before anything
+    /// is sent. The extrinsic itself is sent at most once (see
+    /// [`submit_once`]).
+    async fn write<T, F, Fut>(&self, category: ExtrinsicCategory, submit: F) -> BittensorResult<T>
+    where
+        F: FnOnce(Arc<B>) -> Fut,
+        Fut: Future<Output = BittensorResult<T>>,
+    {
+        let Some(live) = &self.live else {
+            let result = submit(self.client()).await;
+            self.observe_connection(&result).await;
+            return result;
+        };
+        let Some(manager) = &self.connections else {
+            let client = self.client();
+            if let Some(limits) = self.rate_limits() {
+                limits.acquire((live.unwrap)(&client), category).await?;
+            }
+            let result = submit(client).await;
+            self.observe_connection(&result).await;
+            return result;
+        };
+
+        let pinned = self
+            .pinned
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        if let Some(conn) = pinned {
+            if !conn.is_healthy().await {
+                self.repin(manager, live).await?;
+            }
+        }
+        let client = self.client();
+        manager
+            .rate_limits()
+            .acquire((live.unwrap)(&client), category)
+            .await?;
+        submit_once(client, submit, || async {
+            if let Err(e) = self.repin(manager, live).await {
+                warn!("Failed to replace the dropped connection: {}", e);
+            }
+        })
+        .await
+    }
+
+    /// Replace the pinned connection with a healthy one from the pool
+    async fn repin(
+        &self,
+        manager: &ConnectionManager,
+        live: &LiveClient<B>,
+    ) -> BittensorResult<()> {
+        let dropped = self
+            .pinned
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(conn) = dropped {
+            conn.record_failure().await;
+            manager.pool().remove_connection(conn.id()).await;
+        }
+
+        let conn = manager
+            .get_connection()
+            .await
+            .map_err(|e| ChainConnectionError::new(format!("Failed to reconnect: {}", e)))?;
+        *self
+            .client
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = (live.wrap)(conn.shared_client());
+        *self
+            .pinned
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(conn);
+        Ok(())
+    }
+
+    /// Track the health of the direct connection from a call's outcome
+    ///
+    /// Successes reset the failure count; connection-level errors count
+    /// towards rotating to the next endpoint.
+    async fn observe_connection<T>(&self, result: &BittensorResult<T>) {
+        match result {
+            Ok(_) => self.connection_failures.store(0, Ordering::SeqCst),
+            Err(e) if is_connection_error_message(&e.to_string()) => {
+                self.record_connection_failure().await
+            }
+            Err(_) => {}
+        }
+    }
+
+    /// Record a connection-level failure, rotating endpoints once failures
+    /// reach the circuit breaker threshold
+    ///
+    /// Pooled connections are recovered by the connection manager instead,
+    /// and a backend without endpoints has nowhere to rotate to.
+    async fn record_connection_failure(&self) {
+        if self.connections.is_some() || self.endpoints.is_empty() {
+            return;
+        }
+        let failures = self.connection_failures.fetch_add(1, Ordering::SeqCst) + 1;
+        if failures < CIRCUIT_BREAKER_FAILURE_THRESHOLD {
+            return;
+        }
+        warn!(
+            "{} consecutive failures on {}, rotating endpoint",
+            failures,
+            self.endpoints[self.endpoint_index.load(Ordering::SeqCst)]
+        );
+        if let Err(e) = self.rotate_endpoint().await {
+            warn!("Endpoint rotation failed: {}", e);
+        }
+    }
+
+    /// Enable or disable caching of hot read-only queries
+    ///
+    /// Cached values expire after a per-category number of blocks (see
+    /// [`CacheTtl`]). Values used to build extrinsics always bypass the cache.
+    pub fn cached(&self, enabled: bool) -> &Self {
+        self.cache.set_enabled(enabled);
+        self
+    }
+
+    /// Get the query cache, e.g. to cache metagraph or identity queries
+    pub fn cache(&self) -> &Arc<QueryCache> {
+        &self.cache
+    }
+
+    /// Get cache hit/miss counters
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache.stats()
+    }
+
+    /// Drop every cached value of a subnet
+    pub fn invalidate(&self, netuid: u16) {
+        self.cache.invalidate(netuid);
+    }
+
+    /// Expire cached values as the block listener reports new finalized blocks
+    ///
+    /// The returned task ends when the listener stops.
+    pub fn follow_blocks(&self, listener: &BlockListener) -> tokio::task::JoinHandle<()> {
+        let cache = self.cache.clone();
+        let mut events = listener.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(BlockEvent::NewBlock { block_number, .. }) => cache.on_block(block_number),
+                    Ok(BlockEvent::Stopped) => break,
+                    Ok(_) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                        debug!("Cache block follower lagged by {} events", skipped);
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    /// Hyperparameter TTL in blocks: one subnet tempo unless configured
+    async fn hyperparameter_ttl(&self, netuid: u16) -> u64 {
+        if let Some(ttl) = self.cache.ttl().hyperparameters {
+            return ttl;
+        }
+        let tempo = self.tempo(netuid).await.ok().map(u64::from);
+        self.cache
+            .ttl()
+            .blocks(CacheCategory::Hyperparameters, tempo)
+    }
+
+    /// Get current block number (cached for one block when caching is enabled)
+    pub async fn get_current_block(&self) -> BittensorResult<u64> {
+        let key = CacheKey::global(CacheCategory::BlockNumber, "block_number");
+        let ttl = self.cache.ttl().blocks(CacheCategory::BlockNumber, None);
+        self.cache
+            .get_or_fetch(key, ttl, self.fetch_current_block())
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to get block number: {}", e))
+    }
+
+    /// Get current block number from the chain, bypassing the cache
+    pub async fn fetch_current_block(&self) -> BittensorResult<u64> {
+        let block = self
+            .read(|client| async move { Ok(client.block_number().await?) })
+            .await;
+        match block {
+            Ok(block) => {
+                self.cache.on_block(block);
+                Ok(block)
+            }
+            Err(e) => {
+                Err(ChainConnectionError::new(format!("Failed to get block number: {}", e)).into())
+            }
+        }
     }
 
     /// Check if commit-reveal is enabled for a subnet
-    pub async fn commit_reveal_enabled(&self, netuid: u16) -> Result<bool> {
-        commit_reveal_enabled(&self.client, netuid).await
+    pub async fn commit_reveal_enabled(&self, netuid: u16) -> BittensorResult<bool> {
+        let fetch = self.read(|client| async move { commit_reveal_enabled(&client, netuid).await });
+        if !self.cache.is_enabled() {
+            return fetch.await;
+        }
+        let key = CacheKey::subnet(
+            CacheCategory::Hyperparameters,
+            netuid,
+            "commit_reveal_enabled",
+        );
+        let ttl = self.hyperparameter_ttl(netuid).await;
+        self.cache.get_or_fetch(key, ttl, fetch).await
     }
 
     /// Get commit-reveal version from chain (cached)
-    pub async fn get_commit_reveal_version(&self) -> Result<u16> {
+    pub async fn get_commit_reveal_version(&self) -> BittensorResult<u16> {
         {
             let cached = self.crv_version.read().await;
             if let Some(v) = *cached {
@@ -249,7 +849,8 @@ impl Subtensor {
             }
         }
 
-        let version = crate::crv4::get_commit_reveal_version(&self.client)
+        let version = self
+            .read(|client| async move { crate::crv4::get_commit_reveal_version(&client).await })
             .await
             .unwrap_or(DEFAULT_COMMIT_REVEAL_VERSION);
 
@@ -266,87 +867,312 @@ impl Subtensor {
     }
 
     /// Get tempo for a subnet
-    pub async fn tempo(&self, netuid: u16) -> Result<u16> {
-        let t = tempo(&self.client, netuid).await?.unwrap_or(360);
+    pub async fn tempo(&self, netuid: u16) -> BittensorResult<u16> {
+        let key = CacheKey::subnet(CacheCategory::Hyperparameters, netuid, "tempo");
+        if let Some(t) = self.cache.get::<u16>(&key) {
+            return Ok(t);
+        }
+        let t = self.fetch_tempo(netuid).await?;
+        let ttl = self
+            .cache
+            .ttl()
+            .blocks(CacheCategory::Hyperparameters, Some(t as u64));
+        self.cache.insert(key, t, ttl);
+        Ok(t)
+    }
+
+    /// Get tempo for a subnet from the chain, bypassing the cache
+    async fn fetch_tempo(&self, netuid: u16) -> BittensorResult<u16> {
+        let t = self
+            .read(|client| async move { tempo(&client, netuid).await })
+            .await?
+            .unwrap_or(360);
         Ok(t as u16)
     }
 
     /// Get weights rate limit for a subnet
-    pub async fn weights_rate_limit(&self, netuid: u16) -> Result<u64> {
-        weights_rate_limit(&self.client, netuid)
+    pub async fn weights_rate_limit(&self, netuid: u16) -> BittensorResult<u64> {
+        let fetch = async {
+            self.read(|client| async move { weights_rate_limit(&client, netuid).await })
+                .await
+                .map(|v| v.unwrap_or(0))
+        };
+        if !self.cache.is_enabled() {
+            return fetch.await;
+        }
+        let key = CacheKey::subnet(CacheCategory::Hyperparameters, netuid, "weights_rate_limit");
+        let ttl = self.hyperparameter_ttl(netuid).await;
+        self.cache.get_or_fetch(key, ttl, fetch).await
+    }
+
+    /// Get activity cutoff for a subnet
+    ///
+    /// A neuron that has not set weights for this many blocks is marked
+    /// inactive and loses its dividends.
+    pub async fn activity_cutoff(&self, netuid: u16) -> BittensorResult<u64> {
+        let fetch = async {
+            self.read(|client| async move {
+                Ok(crate::queries::get_activity_cutoff(&client, netuid).await? as u64)
+            })
             .await
-            .map(|v| v.unwrap_or(0))
+        };
+        if !self.cache.is_enabled() {
+            return fetch.await;
+        }
+        let key = CacheKey::subnet(CacheCategory::Hyperparameters, netuid, "activity_cutoff");
+        let ttl = self.hyperparameter_ttl(netuid).await;
+        self.cache.get_or_fetch(key, ttl, fetch).await
     }
 
     /// Get blocks since last update for a neuron
-    pub async fn blocks_since_last_update(&self, netuid: u16, uid: u16) -> Result<u64> {
-        use crate::utils::decoders::decode_u64;
-        use subxt::dynamic::Value;
+    pub async fn blocks_since_last_update(&self, netuid: u16, uid: u16) -> BittensorResult<u64> {
+        let last_update = self
+            .read(|client| async move {
+                crate::queries::metagraph_queries::get_last_update(&client, netuid, uid).await
+            })
+            .await?;
+        let current_block = self.get_current_block().await?;
+        Ok(current_block.saturating_sub(last_update))
+    }
 
-        let storage_index = get_mechid_storage_index(netuid, 0);
-        if let Some(val) = self
-            .client
-            .storage_with_keys(
-                SUBTENSOR_MODULE,
-                "LastUpdate",
-                vec![Value::u128(storage_index as u128), Value::u128(uid as u128)],
-            )
-            .await?
-        {
-            let last_update = decode_u64(&val).unwrap_or(0);
-            let current_block = self.get_current_block().await?;
-            return Ok(current_block.saturating_sub(last_update));
-        }
-        Ok(0)
+    /// Blocks left before a neuron passes the subnet's activity cutoff
+    ///
+    /// Returns 0 once `blocks_since_last_update` has reached `activity_cutoff`;
+    /// the neuron then counts as inactive until it sets weights again.
+    pub async fn blocks_until_deregistration_risk(
+        &self,
+        netuid: u16,
+        uid: u16,
+    ) -> BittensorResult<u64> {
+        let (since, cutoff) = tokio::try_join!(
+            self.blocks_since_last_update(netuid, uid),
+            self.activity_cutoff(netuid),
+        )?;
+        Ok(cutoff.saturating_sub(since))
+    }
+
+    /// How close `uid` is to being replaced by a new registration
+    ///
+    /// Levels use the thresholds set with
+    /// [`SubtensorBuilder::pruning_thresholds`].
+    pub async fn pruning_risk(&self, netuid: u16, uid: u16) -> BittensorResult<PruningRisk> {
+        let thresholds = self.pruning_thresholds;
+        self.read(|client| async move {
+            pruning::get_pruning_risk(&client, netuid, uid, &thresholds).await
+        })
+        .await
     }
 
     /// Check if rate limit allows setting weights
-    pub async fn can_set_weights(&self, netuid: u16, uid: u16) -> Result<bool> {
+    pub async fn can_set_weights(&self, netuid: u16, uid: u16) -> BittensorResult<bool> {
         let bslu = self.blocks_since_last_update(netuid, uid).await?;
         let wrl = self.weights_rate_limit(netuid).await?;
         Ok(bslu > wrl)
     }
 
+    /// Check whether a hotkey can set weights on a subnet mechanism right now
+    ///
+    /// Unlike `can_set_weights`, this is mechanism-aware and reports which
+    /// chain constraint (rate limit, permit, weight bounds) blocks the hotkey.
+    pub async fn weight_setting_status(
+        &self,
+        netuid: u16,
+        mechanism_id: u8,
+        hotkey: &sp_core::crypto::AccountId32,
+    ) -> BittensorResult<WeightSettingStatus> {
+        self.read(|client| async move {
+            crate::validator::mechanism::can_set_weights(&client, netuid, mechanism_id, hotkey)
+                .await
+        })
+        .await
+    }
+
     /// Get reveal period epochs
-    pub async fn get_reveal_period(&self, netuid: u16) -> Result<u64> {
-        crate::crv4::get_reveal_period(&self.client, netuid).await
+    pub async fn get_reveal_period(&self, netuid: u16) -> BittensorResult<u64> {
+        if !self.cache.is_enabled() {
+            return self.fetch_reveal_period(netuid).await;
+        }
+        let key = CacheKey::subnet(CacheCategory::Hyperparameters, netuid, "reveal_period");
+        let ttl = self.hyperparameter_ttl(netuid).await;
+        self.cache
+            .get_or_fetch(key, ttl, self.fetch_reveal_period(netuid))
+            .await
+    }
+
+    /// Get reveal period epochs from the chain, bypassing the cache
+    async fn fetch_reveal_period(&self, netuid: u16) -> BittensorResult<u64> {
+        self.read(|client| async move { crate::crv4::get_reveal_period(&client, netuid).await })
+            .await
     }
 
     /// Get UID for hotkey on subnet
-    pub async fn get_uid_for_hotkey(&self, netuid: u16, hotkey: &[u8; 32]) -> Result<Option<u16>> {
-        use crate::utils::decoders::decode_u16;
-        use subxt::dynamic::Value;
+    pub async fn get_uid_for_hotkey(
+        &self,
+        netuid: u16,
+        hotkey: &[u8; 32],
+    ) -> BittensorResult<Option<u16>> {
+        let hotkey = sp_core::crypto::AccountId32::from(*hotkey);
+        self.read(|client| {
+            let hotkey = hotkey.clone();
+            async move {
+                crate::queries::neurons::get_uid_for_hotkey(&client, netuid, &hotkey).await
+            }
+        })
+        .await
+    }
 
-        if let Some(val) = self
-            .client
-            .storage_with_keys(
-                SUBTENSOR_MODULE,
-                "Uids",
-                vec![
-                    Value::u128(netuid as u128),
-                    Value::from_bytes(hotkey.as_slice()),
-                ],
+    /// Get the netuids a hotkey is registered on
+    pub async fn is_hotkey_registered_any(&self, hotkey: &[u8; 32]) -> BittensorResult<Vec<u16>> {
+        let hotkey = sp_core::crypto::AccountId32::from(*hotkey);
+        self.read(|client| {
+            let hotkey = hotkey.clone();
+            async move { crate::queries::neurons::is_hotkey_registered_any(&client, &hotkey).await }
+        })
+        .await
+    }
+
+    /// Get the coldkey that owns a hotkey, if the hotkey is known
+    pub async fn get_hotkey_owner(
+        &self,
+        hotkey: &[u8; 32],
+    ) -> BittensorResult<Option<sp_core::crypto::AccountId32>> {
+        let hotkey = sp_core::crypto::AccountId32::from(*hotkey);
+        self.read(|client| {
+            let hotkey = hotkey.clone();
+            async move { crate::queries::neurons::get_hotkey_owner(&client, &hotkey).await }
+        })
+        .await
+    }
+
+    /// Get the take a hotkey charges its children on a subnet (u16 proportion)
+    pub async fn get_childkey_take(&self, hotkey: &[u8; 32], netuid: u16) -> BittensorResult<u16> {
+        let hotkey = sp_core::crypto::AccountId32::from(*hotkey);
+        self.read(|client| {
+            let hotkey = hotkey.clone();
+            async move {
+                crate::validator::children::get_childkey_take(&client, &hotkey, netuid).await
+            }
+        })
+        .await
+    }
+
+    /// Set the take a hotkey charges its children on a subnet
+    ///
+    /// See [`set_childkey_take`](crate::validator::children::set_childkey_take)
+    /// for the bounds and rate limit checked before submitting.
+    pub async fn set_childkey_take(
+        &self,
+        signer: &BittensorSigner,
+        hotkey: &[u8; 32],
+        netuid: u16,
+        take: u16,
+        wait_for: ExtrinsicWait,
+    ) -> BittensorResult<String> {
+        let hotkey = sp_core::crypto::AccountId32::from(*hotkey);
+        let hotkey = &hotkey;
+        self.write(ExtrinsicCategory::Other, |client| async move {
+            crate::validator::children::set_childkey_take(
+                &client, signer, hotkey, netuid, take, wait_for,
             )
-            .await?
-        {
-            return Ok(decode_u16(&val).ok());
-        }
-        Ok(None)
+            .await
+        })
+        .await
+    }
+
+    /// Preview a [`transfer_safe`](Self::transfer_safe): the amount sent,
+    /// the estimated fee and the free balance left behind
+    ///
+    /// Fails with `InsufficientBalance` when the transfer would be refused.
+    pub async fn preview_transfer(
+        &self,
+        signer: &BittensorSigner,
+        dest: &[u8; 32],
+        amount: TransferAmount,
+        keep_alive: bool,
+    ) -> BittensorResult<TransferPreview> {
+        let dest = sp_core::crypto::AccountId32::from(*dest);
+        let dest = &dest;
+        self.read(|client| async move {
+            let existential_deposit = client.constants().existential_deposit;
+            crate::validator::transfer::preview_transfer(
+                &client,
+                signer,
+                dest,
+                amount,
+                keep_alive,
+                existential_deposit,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Transfer TAO once the free balance covers the amount, the estimated
+    /// fee and, with `keep_alive`, the existential deposit
+    ///
+    /// `TransferAmount::Max` sends everything left after those. See
+    /// [`transfer_safe`](crate::validator::transfer::transfer_safe).
+    pub async fn transfer_safe(
+        &self,
+        signer: &BittensorSigner,
+        dest: &[u8; 32],
+        amount: TransferAmount,
+        keep_alive: bool,
+        wait_for: ExtrinsicWait,
+    ) -> BittensorResult<(String, TransferPreview)> {
+        let dest = sp_core::crypto::AccountId32::from(*dest);
+        let dest = &dest;
+        self.write(ExtrinsicCategory::Other, |client| async move {
+            let existential_deposit = client.constants().existential_deposit;
+            crate::validator::transfer::transfer_safe(
+                &client,
+                signer,
+                dest,
+                amount,
+                keep_alive,
+                existential_deposit,
+                wait_for,
+            )
+            .await
+        })
+        .await
     }
 
     /// Get current epoch number for a subnet
     /// Uses subtensor formula: epoch = (block + netuid + 1) / (tempo + 1)
-    pub async fn get_current_epoch(&self, netuid: u16) -> Result<u64> {
+    pub async fn get_current_epoch(&self, netuid: u16) -> BittensorResult<u64> {
         let block = self.get_current_block().await?;
         let tempo = self.tempo(netuid).await? as u64;
-        let tempo_plus_one = tempo.saturating_add(1);
-        let netuid_plus_one = (netuid as u64).saturating_add(1);
-        Ok(block.saturating_add(netuid_plus_one) / tempo_plus_one)
+        Ok(epoch::epoch_index(netuid, tempo, block))
+    }
+
+    /// First block of the subnet's next epoch
+    ///
+    /// The chain runs the epoch in the block before. Fails for tempo 0, as
+    /// such a subnet never advances.
+    pub async fn next_epoch_block(&self, netuid: u16) -> BittensorResult<u64> {
+        let tempo = self.tempo(netuid).await? as u64;
+        if tempo == 0 {
+            return Err(ChainQueryError::new(format!(
+                "Subnet {} has tempo 0; epochs never advance",
+                netuid
+            ))
+            .into());
+        }
+        let block = self.get_current_block().await?;
+        Ok(epoch::next_epoch_start_block(netuid, tempo, block))
     }
 
     /// Get mechanism count for a subnet
-    pub async fn get_mechanism_count(&self, netuid: u16) -> Result<u8> {
-        crate::get_mechanism_count(&self.client, netuid).await
+    pub async fn get_mechanism_count(&self, netuid: u16) -> BittensorResult<u8> {
+        let fetch =
+            self.read(|client| async move { crate::get_mechanism_count(&client, netuid).await });
+        if !self.cache.is_enabled() {
+            return fetch.await;
+        }
+        let key = CacheKey::subnet(CacheCategory::Hyperparameters, netuid, "mechanism_count");
+        let ttl = self.hyperparameter_ttl(netuid).await;
+        self.cache.get_or_fetch(key, ttl, fetch).await
     }
 
     /// Resolve mechanism storage index
@@ -359,8 +1185,9 @@ impl Subtensor {
     /// Subtensor uses epoch-granular commit-reveal:
     /// - Commits are accepted during the ENTIRE epoch
     /// - Reveals are accepted during the ENTIRE next epoch (epoch + reveal_period)
+    ///
     /// Returns: "commit" (always - entire epoch is a commit window)
-    pub async fn get_current_phase(&self, _netuid: u16) -> Result<String> {
+    pub async fn get_current_phase(&self, _netuid: u16) -> BittensorResult<String> {
         // Subtensor allows commits at any block during an epoch.
         // The "reveal" phase is the next epoch, not a sub-epoch window.
         Ok("commit".to_string())
@@ -370,13 +1197,13 @@ impl Subtensor {
     /// Note: In subtensor, reveal phase = the entire next epoch after commit.
     /// This always returns false for the current epoch since you commit in epoch N
     /// and reveal in epoch N + reveal_period.
-    pub async fn is_in_reveal_phase(&self, _netuid: u16) -> Result<bool> {
+    pub async fn is_in_reveal_phase(&self, _netuid: u16) -> BittensorResult<bool> {
         Ok(false)
     }
 
     /// Check if currently in commit phase
     /// In subtensor, the entire epoch is a commit window.
-    pub async fn is_in_commit_phase(&self, _netuid: u16) -> Result<bool> {
+    pub async fn is_in_commit_phase(&self, _netuid: u16) -> BittensorResult<bool> {
         Ok(true)
     }
 
@@ -412,7 +1239,16 @@ impl Subtensor {
     /// * `netuid` - Subnet ID
     /// * `uids` - Neuron UIDs to set weights for
     /// * `weights` - Weight values (f32 0.0-1.0 or raw u16 0-65535)
-    /// * `version_key` - Network version key
+    /// * `version_key` - Network version key, or None to read the subnet's
+    ///   `WeightsVersionKey` at submit time. An explicit key below it fails
+    ///   with [`WeightVersionMismatch`](crate::errors::WeightVersionMismatch)
+    ///   before anything is submitted.
+    /// * `wait_for` - How long to wait for the extrinsic
+    /// * `verify` - Read the stored weights back and compare them with the
+    ///   submission (see [`WeightResponse::verification`]). Only direct
+    ///   `set_weights` is verified: committed weights are not stored until
+    ///   they are revealed. Use `InBlock` or `Finalized` so the row is updated
+    ///   by the time it is read.
     #[allow(clippy::too_many_arguments)]
     pub async fn set_weights(
         &self,
@@ -420,15 +1256,91 @@ impl Subtensor {
         netuid: u16,
         uids: &[u16],
         weights: &[u16],
-        version_key: u64,
+        version_key: Option<u64>,
         wait_for: ExtrinsicWait,
-    ) -> Result<WeightResponse> {
-        self.set_mechanism_weights(signer, netuid, 0, uids, weights, version_key, wait_for)
-            .await
+        verify: bool,
+    ) -> BittensorResult<WeightResponse> {
+        self.set_mechanism_weights(
+            signer,
+            netuid,
+            0,
+            uids,
+            weights,
+            version_key,
+            wait_for,
+            verify,
+        )
+        .await
+    }
+
+    /// Set weights keyed by SS58 hotkey instead of UID
+    ///
+    /// Resolves each hotkey to the UID it is registered at when called, so
+    /// weights follow their hotkey across deregistrations. Hotkeys that are no
+    /// longer registered are dropped and listed in
+    /// [`HotkeyWeightResponse::skipped`]. The rest are max-upscaled to u16 and
+    /// submitted through [`set_weights`](Self::set_weights), so commit-reveal
+    /// is used when the subnet requires it.
+    pub async fn set_weights_by_hotkey(
+        &self,
+        signer: &BittensorSigner,
+        netuid: u16,
+        weights: &HashMap<String, f64>,
+        version_key: Option<u64>,
+        wait_for: ExtrinsicWait,
+    ) -> BittensorResult<HotkeyWeightResponse> {
+        let resolved = self
+            .read(|client| async move {
+                Ok(resolve_hotkey_weights(client.as_ref(), netuid, weights).await?)
+            })
+            .await?;
+        if !resolved.skipped.is_empty() {
+            warn!(
+                "Dropping weights for {} hotkeys not registered on netuid {}: {:?}",
+                resolved.skipped.len(),
+                netuid,
+                resolved.skipped
+            );
+        }
+        if resolved.resolved.is_empty() {
+            return Err(WeightsError::with_netuid(
+                format!(
+                    "No registered hotkeys with non-zero weight on netuid {}",
+                    netuid
+                ),
+                netuid,
+            )
+            .into());
+        }
+
+        let (uids, weights) = resolved.uids_and_weights();
+        let response = self
+            .set_weights(
+                signer,
+                netuid,
+                &uids,
+                &weights,
+                version_key,
+                wait_for,
+                false,
+            )
+            .await?;
+        Ok(HotkeyWeightResponse {
+            response,
+            resolved: resolved.resolved,
+            skipped: resolved.skipped,
+        })
     }
 
     /// Set mechanism weights with full control
+    ///
+    /// `version_key` is resolved as in [`set_weights`](Self::set_weights).
     #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(
+        level = "error",
+        skip_all,
+        fields(netuid = netuid, hotkey = %signer.account_id())
+    )]
     pub async fn set_mechanism_weights(
         &self,
         signer: &BittensorSigner,
@@ -436,9 +1348,67 @@ impl Subtensor {
         mechanism_id: u8,
         uids: &[u16],
         weights: &[u16],
-        version_key: u64,
+        version_key: Option<u64>,
         wait_for: ExtrinsicWait,
-    ) -> Result<WeightResponse> {
+        verify: bool,
+    ) -> BittensorResult<WeightResponse> {
+        let required = self
+            .read(|client| async move { get_weights_version_key(client.as_ref(), netuid).await })
+            .await?;
+        let version_key = check_version_key(netuid, required, version_key)?;
+
+        // A pending legacy commit is revealed rather than re-submitted, so the
+        // pre-flight checks only apply to new submissions
+        let (uids, weights) = if self
+            .pending_commit(netuid, Some(mechanism_id))
+            .await
+            .is_none()
+        {
+            let hotkey = sp_core::crypto::AccountId32::from(signer.account_id().0);
+            let status = self
+                .weight_setting_status(netuid, mechanism_id, &hotkey)
+                .await?;
+            let subnet_n =
+                self.read(|client| async move {
+                    crate::queries::subnets::subnet_n(&client, netuid).await
+                })
+                .await?
+                .unwrap_or(0);
+            let bounds = WeightBounds {
+                subnet_n: subnet_n.min(u16::MAX as u64) as u16,
+                min_allowed: status.min_allowed_weights.min(u16::MAX as u64) as u16,
+                max_limit: status.max_weights_limit,
+            };
+            let (uids, weights) = bounds
+                .check(uids, weights, status.uid, self.weight_validation)
+                .map_err(|e| {
+                    WeightsError::with_netuid(
+                        format!("Invalid weights for netuid {}: {}", netuid, e),
+                        netuid,
+                    )
+                })?;
+
+            let blocking = match status.blocking_limit.clone() {
+                Some(WeightSettingLimit::NoValidatorPermit) if status.is_self_weight(&uids) => None,
+                Some(limit) => Some(limit),
+                None => status.check_weights(&uids, &weights).err(),
+            };
+            if let Some(limit) = blocking {
+                return Err(WeightsError::with_netuid(
+                    format!(
+                        "Cannot set weights on netuid {} mechanism {}: {}",
+                        netuid, mechanism_id, limit
+                    ),
+                    netuid,
+                )
+                .into());
+            }
+            (uids, weights)
+        } else {
+            (uids.to_vec(), weights.to_vec())
+        };
+        let (uids, weights) = (uids.as_slice(), weights.as_slice());
+
         // Check if commit-reveal is enabled
         let cr_enabled = self.commit_reveal_enabled(netuid).await?;
 
@@ -484,28 +1454,94 @@ impl Subtensor {
                 "Using direct set_weights for netuid={}, mechanism={}",
                 netuid, mechanism_id
             );
-            self.set_weights_direct(
-                signer,
-                netuid,
-                mechanism_id,
-                uids,
-                weights,
-                version_key,
-                wait_for,
-            )
-            .await
+            let response = self
+                .set_weights_direct(
+                    signer,
+                    netuid,
+                    mechanism_id,
+                    uids,
+                    weights,
+                    version_key,
+                    wait_for,
+                )
+                .await?;
+            if !verify {
+                return Ok(response);
+            }
+
+            let stored = self
+                .fetch_stored_weights(signer, netuid, mechanism_id)
+                .await?;
+            let verification = WeightVerification::compare(uids, weights, &stored)?;
+            if !verification.matches {
+                warn!(
+                    "Stored weights on netuid {} mechanism {} differ from the submission at {} UIDs",
+                    netuid,
+                    mechanism_id,
+                    verification.differences.len()
+                );
+            }
+            Ok(response.with_stored(stored, verification))
         }
     }
 
+    /// Get the weights the signer's hotkey has stored on a subnet
+    ///
+    /// Reads the `Weights` row for the hotkey's UID, as the chain stored it
+    /// after normalization. Fails if the hotkey is not registered.
+    pub async fn get_my_weights(
+        &self,
+        signer: &BittensorSigner,
+        netuid: u16,
+    ) -> BittensorResult<Vec<(u16, u16)>> {
+        self.fetch_stored_weights(signer, netuid, 0).await
+    }
+
+    /// Read the signer's `Weights` row for a subnet mechanism
+    async fn fetch_stored_weights(
+        &self,
+        signer: &BittensorSigner,
+        netuid: u16,
+        mechanism_id: u8,
+    ) -> BittensorResult<Vec<(u16, u16)>> {
+        let uid = self
+            .get_uid_for_hotkey(netuid, &signer.account_id().0)
+            .await?
+            .ok_or_else(|| {
+                NotRegistered::with_details(
+                    format!(
+                        "Hotkey {} is not registered on netuid {}",
+                        signer.account_id(),
+                        netuid
+                    ),
+                    signer.account_id().to_string(),
+                    netuid,
+                )
+            })?;
+        self.read(|client| async move {
+            crate::queries::bonds::get_uid_weights(client.as_ref(), netuid, mechanism_id, uid).await
+        })
+        .await
+    }
+
     // ==========================================================================
     // CRv4 (Timelock Encryption)
     // ==========================================================================
 
     /// Get the chain's last stored DRAND round
-    pub async fn get_last_drand_round(&self) -> Result<u64> {
-        crate::queries::chain_info::last_drand_round(&self.client)
-            .await?
-            .ok_or_else(|| anyhow::anyhow!("Failed to get Drand.LastStoredRound from chain"))
+    pub async fn get_last_drand_round(&self) -> BittensorResult<u64> {
+        self.read(
+            |client| async move { crate::queries::chain_info::last_drand_round(&client).await },
+        )
+        .await?
+        .ok_or_else(|| {
+            ChainQueryError::with_storage(
+                "Failed to get Drand.LastStoredRound from chain",
+                "Drand",
+                "LastStoredRound",
+            )
+            .into()
+        })
     }
 
     /// Set weights using CRv4 timelock encryption
@@ -520,13 +1556,14 @@ impl Subtensor {
         weights: &[u16],
         version_key: u64,
         wait_for: ExtrinsicWait,
-    ) -> Result<WeightResponse> {
+    ) -> BittensorResult<WeightResponse> {
         let hotkey_bytes = signer.account_id().0.to_vec();
 
-        // Get chain parameters
-        let current_block = self.get_current_block().await?;
-        let tempo = self.tempo(netuid).await?;
-        let reveal_period = self.get_reveal_period(netuid).await?;
+        // Get chain parameters; the reveal round is part of the extrinsic, so
+        // bypass the query cache
+        let current_block = self.fetch_current_block().await?;
+        let tempo = self.fetch_tempo(netuid).await?;
+        let reveal_period = self.fetch_reveal_period(netuid).await?;
         let crv_version = self.get_commit_reveal_version().await?;
 
         // Get chain's last DRAND round (CRITICAL: must use chain state, not system time)
@@ -534,14 +1571,16 @@ impl Subtensor {
 
         // Calculate reveal round relative to chain's DRAND state
         let storage_index = get_mechid_storage_index(netuid, mechanism_id);
-        let reveal_round = calculate_reveal_round(
+        let reveal_round = checked_reveal_round(
+            &DrandClient::from_env()?,
             tempo,
             current_block,
             storage_index,
             reveal_period,
             self.block_time,
             chain_last_drand_round,
-        );
+        )
+        .await?;
 
         // Encrypt payload
         let encrypted =
@@ -557,30 +1596,35 @@ impl Subtensor {
         );
 
         // Submit to chain
-        let tx_hash = if mechanism_id == 0 {
-            commit_timelocked_weights(
-                &self.client,
-                signer,
-                netuid,
-                &encrypted,
-                reveal_round,
-                crv_version,
-                wait_for,
-            )
-            .await?
-        } else {
-            commit_timelocked_mechanism_weights(
-                &self.client,
-                signer,
-                netuid,
-                mechanism_id,
-                &encrypted,
-                reveal_round,
-                crv_version,
-                wait_for,
-            )
-            .await?
-        };
+        let encrypted_ref = encrypted.as_slice();
+        let tx_hash = self
+            .write(ExtrinsicCategory::Weights, |client| async move {
+                if mechanism_id == 0 {
+                    commit_timelocked_weights(
+                        client.as_ref(),
+                        signer,
+                        netuid,
+                        encrypted_ref,
+                        reveal_round,
+                        crv_version,
+                        wait_for,
+                    )
+                    .await
+                } else {
+                    commit_timelocked_mechanism_weights(
+                        client.as_ref(),
+                        signer,
+                        netuid,
+                        mechanism_id,
+                        encrypted_ref,
+                        reveal_round,
+                        crv_version,
+                        wait_for,
+                    )
+                    .await
+                }
+            })
+            .await?;
 
         info!(
             "CRv4 commit submitted: tx={}, reveal_round={}, chain_last_drand={} (no manual reveal needed)",
@@ -613,7 +1657,7 @@ impl Subtensor {
         weights: &[u16],
         version_key: u64,
         wait_for: ExtrinsicWait,
-    ) -> Result<WeightResponse> {
+    ) -> BittensorResult<WeightResponse> {
         let key = (netuid, Some(mechanism_id).filter(|&m| m != 0));
 
         // Check if we have a pending commit to reveal
@@ -657,33 +1701,29 @@ impl Subtensor {
         );
 
         // Submit commit
-        let tx_hash = if mechanism_id == 0 {
-            raw_commit_weights(
-                &self.client,
-                signer,
-                netuid,
-                &commit_data.commit_hash,
-                wait_for,
-            )
-            .await?
-        } else {
-            crate::commit_mechanism_weights(
-                &self.client,
-                signer,
-                netuid,
-                mechanism_id,
-                &commit_data.commit_hash,
-                wait_for,
-            )
-            .await?
-        };
+        let commit_hash = commit_data.commit_hash.as_str();
+        let tx_hash = self
+            .write(ExtrinsicCategory::Weights, |client| async move {
+                if mechanism_id == 0 {
+                    raw_commit_weights(&client, signer, netuid, commit_hash, wait_for).await
+                } else {
+                    crate::commit_mechanism_weights(
+                        &client,
+                        signer,
+                        netuid,
+                        mechanism_id,
+                        commit_hash,
+                        wait_for,
+                    )
+                    .await
+                }
+            })
+            .await?;
 
         // Store pending commit
         let current_block = self.get_current_block().await?;
         let tempo = self.tempo(netuid).await? as u64;
-        let tempo_plus_one = tempo.saturating_add(1);
-        let netuid_plus_one = (netuid as u64).saturating_add(1);
-        let epoch = current_block.saturating_add(netuid_plus_one) / tempo_plus_one;
+        let epoch = epoch::epoch_index(netuid, tempo, current_block);
 
         let pending = PendingCommit {
             netuid,
@@ -723,12 +1763,17 @@ impl Subtensor {
     }
 
     /// Reveal a pending commit
+    #[tracing::instrument(
+        level = "error",
+        skip_all,
+        fields(netuid = pending.netuid, hotkey = %signer.account_id())
+    )]
     async fn reveal_pending_commit(
         &self,
         signer: &BittensorSigner,
         pending: PendingCommit,
         wait_for: ExtrinsicWait,
-    ) -> Result<WeightResponse> {
+    ) -> BittensorResult<WeightResponse> {
         info!(
             "Revealing weights for commit: {} (netuid={}, mechanism={:?})",
             &pending.commit_hash[..16],
@@ -736,35 +1781,40 @@ impl Subtensor {
             pending.mechanism_id
         );
 
-        let tx_hash = match pending.mechanism_id {
-            None | Some(0) => {
-                raw_reveal_weights(
-                    &self.client,
-                    signer,
-                    pending.netuid,
-                    &pending.uids,
-                    &pending.weights,
-                    &pending.salt,
-                    pending.version_key,
-                    wait_for,
-                )
-                .await?
-            }
-            Some(mechanism_id) => {
-                crate::reveal_mechanism_weights(
-                    &self.client,
-                    signer,
-                    pending.netuid,
-                    mechanism_id,
-                    &pending.uids,
-                    &pending.weights,
-                    &pending.salt,
-                    pending.version_key,
-                    wait_for,
-                )
-                .await?
-            }
-        };
+        let commit = &pending;
+        let tx_hash = self
+            .write(ExtrinsicCategory::Weights, |client| async move {
+                match commit.mechanism_id {
+                    None | Some(0) => {
+                        raw_reveal_weights(
+                            &client,
+                            signer,
+                            commit.netuid,
+                            &commit.uids,
+                            &commit.weights,
+                            &commit.salt,
+                            commit.version_key,
+                            wait_for,
+                        )
+                        .await
+                    }
+                    Some(mechanism_id) => {
+                        crate::reveal_mechanism_weights(
+                            &client,
+                            signer,
+                            commit.netuid,
+                            mechanism_id,
+                            &commit.uids,
+                            &commit.weights,
+                            &commit.salt,
+                            commit.version_key,
+                            wait_for,
+                        )
+                        .await
+                    }
+                }
+            })
+            .await?;
 
         // Remove pending commit
         let key = (pending.netuid, pending.mechanism_id);
@@ -802,31 +1852,35 @@ impl Subtensor {
         weights: &[u16],
         version_key: u64,
         wait_for: ExtrinsicWait,
-    ) -> Result<WeightResponse> {
-        let tx_hash = if mechanism_id == 0 {
-            raw_set_weights(
-                &self.client,
-                signer,
-                netuid,
-                uids,
-                weights,
-                version_key,
-                wait_for,
-            )
-            .await?
-        } else {
-            crate::set_mechanism_weights(
-                &self.client,
-                signer,
-                netuid,
-                mechanism_id,
-                uids,
-                weights,
-                version_key,
-                wait_for,
-            )
-            .await?
-        };
+    ) -> BittensorResult<WeightResponse> {
+        let tx_hash = self
+            .write(ExtrinsicCategory::Weights, |client| async move {
+                if mechanism_id == 0 {
+                    submit_set_weights(
+                        &client,
+                        signer,
+                        netuid,
+                        uids,
+                        weights,
+                        version_key,
+                        wait_for,
+                    )
+                    .await
+                } else {
+                    crate::set_mechanism_weights(
+                        &client,
+                        signer,
+                        netuid,
+                        mechanism_id,
+                        uids,
+                        weights,
+                        version_key,
+                        wait_for,
+                    )
+                    .await
+                }
+            })
+            .await?;
 
         info!("Weights set directly: {}", tx_hash);
 
@@ -860,11 +1914,12 @@ impl Subtensor {
     }
 
     /// Force reveal all pending commits
+    #[tracing::instrument(level = "error", skip_all, fields(hotkey = %signer.account_id()))]
     pub async fn reveal_all_pending(
         &self,
         signer: &BittensorSigner,
         wait_for: ExtrinsicWait,
-    ) -> Result<Vec<WeightResponse>> {
+    ) -> BittensorResult<Vec<WeightResponse>> {
         let pending: Vec<PendingCommit> = {
             let state = self.state.read().await;
             state.pending_commits.values().cloned().collect()
@@ -902,12 +1957,15 @@ impl Subtensor {
     }
 
     /// Normalize weights from f32 to u16
-    pub fn normalize_weights_f32(uids: &[u64], weights: &[f32]) -> Result<(Vec<u16>, Vec<u16>)> {
-        normalize_weights(uids, weights)
+    pub fn normalize_weights_f32(
+        uids: &[u64],
+        weights: &[f32],
+    ) -> BittensorResult<(Vec<u16>, Vec<u16>)> {
+        Ok(normalize_weights(uids, weights)?)
     }
 
     /// Persist current state to disk
-    pub async fn persist_state(&self) -> Result<()> {
+    pub async fn persist_state(&self) -> BittensorResult<()> {
         if let Some(ref path) = self.state_path {
             let state = self.state.read().await;
             state.save(path)?;
@@ -918,20 +1976,54 @@ impl Subtensor {
 
 /// Builder for Subtensor with options
 pub struct SubtensorBuilder {
-    endpoint: String,
+    endpoints: Vec<String>,
+    retry_forever: bool,
     state_path: Option<PathBuf>,
     block_time: f64,
+    cached: bool,
+    cache_ttl: CacheTtl,
+    weight_validation: WeightValidation,
+    pruning_thresholds: PruningThresholds,
+    rate_limits: Option<RateLimitConfig>,
+    read_retry: RetryPolicy,
 }
 
 impl SubtensorBuilder {
     pub fn new(endpoint: &str) -> Self {
         Self {
-            endpoint: endpoint.to_string(),
+            endpoints: vec![endpoint.to_string()],
+            retry_forever: false,
             state_path: None,
             block_time: 12.0,
+            cached: false,
+            cache_ttl: CacheTtl::default(),
+            weight_validation: WeightValidation::default(),
+            pruning_thresholds: PruningThresholds::default(),
+            rate_limits: None,
+            read_retry: RetryPolicy::conservative(),
         }
     }
 
+    /// Build from a `SubtensorConfig`, honoring its `retry_forever` flag and
+    /// rate limits
+    pub fn from_config(config: &SubtensorConfig) -> Self {
+        Self::new(&config.chain_endpoint)
+            .retry_forever(config.retry_forever)
+            .rate_limits(config.rate_limits)
+    }
+
+    /// Replace the endpoint list; endpoints are tried in order
+    pub fn endpoints<S: Into<String>>(mut self, endpoints: Vec<S>) -> Self {
+        self.endpoints = endpoints.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Keep retrying with backoff instead of failing when every endpoint is down
+    pub fn retry_forever(mut self, retry_forever: bool) -> Self {
+        self.retry_forever = retry_forever;
+        self
+    }
+
     pub fn with_persistence(mut self, path: PathBuf) -> Self {
         self.state_path = Some(path);
         self
@@ -942,26 +2034,177 @@ impl SubtensorBuilder {
         self
     }
 
-    pub async fn build(self) -> Result<Subtensor> {
-        let client = BittensorClient::new(&self.endpoint)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+    /// Enable caching of hot read-only queries
+    pub fn cached(mut self, cached: bool) -> Self {
+        self.cached = cached;
+        self
+    }
+
+    /// Set the per-category cache TTLs
+    pub fn with_cache_ttl(mut self, ttl: CacheTtl) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Set how `set_weights` handles UIDs outside the subnet's bounds
+    pub fn weight_validation(mut self, mode: WeightValidation) -> Self {
+        self.weight_validation = mode;
+        self
+    }
+
+    /// Set where `Subtensor::pruning_risk` classifies a UID as medium or
+    /// high risk
+    pub fn pruning_thresholds(mut self, thresholds: PruningThresholds) -> Self {
+        self.pruning_thresholds = thresholds;
+        self
+    }
+
+    /// Limit extrinsics per block; submissions wait for the next block once
+    /// a category's budget is spent
+    pub fn rate_limits(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limits = Some(config);
+        self
+    }
+
+    /// Set how reads are retried on transient RPC errors
+    ///
+    /// Defaults to [`RetryPolicy::conservative`]; use
+    /// [`RetryPolicy::no_retry`] to fail on the first error. Extrinsics are
+    /// never retried by this policy.
+    pub fn read_retry(mut self, policy: RetryPolicy) -> Self {
+        self.read_retry = policy;
+        self
+    }
+
+    pub async fn build(self) -> BittensorResult<Subtensor> {
+        let (index, client) = connect_endpoints(&self.endpoints, 0, self.retry_forever).await?;
         let state = self
             .state_path
             .as_ref()
             .map(SubtensorState::load)
             .unwrap_or_default();
+        let block_time =
+            Duration::try_from_secs_f64(self.block_time).unwrap_or(Duration::from_secs(12));
+        let cache = QueryCache::new(self.cache_ttl, block_time);
+        cache.set_enabled(self.cached);
 
         Ok(Subtensor {
-            client: Arc::new(client),
+            client: std::sync::RwLock::new(Arc::new(client)),
+            endpoints: self.endpoints,
+            endpoint_index: AtomicUsize::new(index),
+            connection_failures: AtomicU32::new(0),
+            retry_forever: self.retry_forever,
             crv_version: RwLock::new(None),
             state: RwLock::new(state),
             state_path: self.state_path,
             block_time: self.block_time,
+            cache: Arc::new(cache),
+            weight_validation: self.weight_validation,
+            pruning_thresholds: self.pruning_thresholds,
+            connections: None,
+            pinned: std::sync::Mutex::new(None),
+            rate_limits: self
+                .rate_limits
+                .map(|config| Arc::new(CategoryRateLimiter::new(&config))),
+            read_retry: self.read_retry,
+            live: Some(LiveClient::IDENTITY),
         })
     }
 }
 
+/// Send an extrinsic at most once
+///
+/// A connection-level failure runs `on_dropped` so later calls move to another
+/// connection, but the extrinsic is not sent again: after a timeout or a
+/// closed socket the node may already have it, and a second submission could
+/// be included too.
+async fn submit_once<C, T, F, Fut, D, DFut>(
+    client: C,
+    submit: F,
+    on_dropped: D,
+) -> BittensorResult<T>
+where
+    F: FnOnce(C) -> Fut,
+    Fut: Future<Output = BittensorResult<T>>,
+    D: FnOnce() -> DFut,
+    DFut: Future<Output = ()>,
+{
+    let result = submit(client).await;
+    if let Err(e) = &result {
+        if is_connection_error_message(&e.to_string()) {
+            warn!(
+                "Extrinsic submission lost its connection, not resubmitting: {}",
+                e
+            );
+            on_dropped().await;
+        }
+    }
+    result
+}
+
+/// Connect to the first reachable endpoint, starting at `start` and wrapping
+///
+/// Each pass tries every endpoint once. Failed passes are retried with the
+/// backoff from `chain::connection`; `retry_forever` removes its elapsed-time
+/// cap. Returns the index of the endpoint that connected.
+async fn connect_endpoints(
+    endpoints: &[String],
+    start: usize,
+    retry_forever: bool,
+) -> BittensorResult<(usize, BittensorClient)> {
+    if endpoints.is_empty() {
+        return Err(ChainConnectionError::new("No endpoints configured").into());
+    }
+
+    let mut backoff = create_backoff_config();
+    if retry_forever {
+        backoff.max_elapsed_time = None;
+    }
+
+    let attempt = || async {
+        let mut last_error = None;
+        for offset in 0..endpoints.len() {
+            let index = (start + offset) % endpoints.len();
+            let endpoint = &endpoints[index];
+            info!("Attempting connection to {}", endpoint);
+            match BittensorClient::new(endpoint.as_str()).await {
+                Ok(client) => {
+                    info!("Successfully connected to {}", endpoint);
+                    return Ok((index, client));
+                }
+                Err(e) => {
+                    warn!("Failed to connect to {}: {}", endpoint, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        let e = last_error.unwrap_or_else(|| ChainError::Rpc("No endpoints available".into()));
+        if retry_forever {
+            Err(BackoffError::transient(e))
+        } else {
+            Err(BackoffError::permanent(e))
+        }
+    };
+
+    backoff::future::retry(backoff, attempt)
+        .await
+        .map_err(|e| ChainConnectionError::new(format!("Failed to connect: {}", e)).into())
+}
+
+/// Block at `offset_blocks` from the next epoch boundary that is still ahead
+/// of `current_block`
+fn next_epoch_target_block(current_block: u64, netuid: u16, tempo: u64, offset_blocks: i64) -> u64 {
+    let tempo_plus_one = tempo.saturating_add(1);
+    let target = epoch::next_epoch_start_block(netuid, tempo, current_block)
+        .saturating_add_signed(offset_blocks);
+    if target > current_block {
+        return target;
+    }
+    // Already passed this epoch's target; skip ahead whole epochs
+    let epochs_behind = (current_block - target) / tempo_plus_one + 1;
+    target.saturating_add(epochs_behind.saturating_mul(tempo_plus_one))
+}
+
 impl Default for SubtensorBuilder {
     fn default() -> Self {
         Self::new(crate::chain::DEFAULT_RPC_URL)
@@ -972,6 +2215,18 @@ impl Default for SubtensorBuilder {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_next_epoch_target_block() {
+        // netuid 1, tempo 360: block 1000 is in epoch 2, epoch 3 starts at 1081
+        assert_eq!(next_epoch_target_block(1000, 1, 360, 0), 1081);
+        assert_eq!(next_epoch_target_block(1000, 1, 360, 5), 1086);
+        assert_eq!(next_epoch_target_block(1000, 1, 360, -50), 1031);
+        // 100 blocks before epoch 3 already passed, so target epoch 4
+        assert_eq!(next_epoch_target_block(1000, 1, 360, -100), 1342);
+        // The boundary block itself waits for the following epoch
+        assert_eq!(next_epoch_target_block(1081, 1, 360, 0), 1442);
+    }
+
     #[test]
     fn test_weight_response() {
         let response = WeightResponse::success("0x123".to_string(), "Test");
@@ -999,4 +2254,80 @@ mod tests {
         assert_eq!(commit.commit_hash, decoded.commit_hash);
         assert_eq!(commit.uids, decoded.uids);
     }
+
+    /// Fail with a dropped connection while `failures` counts down to zero
+    fn drop_connection(failures: &AtomicU32) -> Result<(), ChainError> {
+        match failures.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1)) {
+            Ok(_) => Err(ChainError::Rpc("connection reset by peer".into())),
+            Err(_) => Ok(()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reads_retry_a_dropped_connection() {
+        use crate::chain::{ChainBackend, MockChain};
+        use crate::errors::BittensorError;
+
+        let chain = MockChain::new();
+        chain.set_block(42);
+        let failures = AtomicU32::new(2);
+        let policy = RetryPolicy {
+            initial_delay: Duration::from_millis(1),
+            ..RetryPolicy::conservative()
+        };
+
+        // The same retry as `Subtensor::read` without a connection manager
+        let read = || async {
+            drop_connection(&failures)?;
+            Ok::<_, BittensorError>(chain.block_number().await?)
+        };
+        assert_eq!(with_retry(&policy, read).await.unwrap(), 42);
+
+        failures.store(3, Ordering::SeqCst);
+        assert!(with_retry(&policy, read).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_writes_are_never_resubmitted() {
+        use crate::chain::{signer_from_seed, ChainBackend, MockChain};
+        use crate::errors::BittensorError;
+
+        let chain = Arc::new(MockChain::new());
+        let signer = signer_from_seed("//Alice").unwrap();
+        let signer = &signer;
+        let dropped = AtomicU32::new(0);
+        let submit = |chain: Arc<MockChain>| async move {
+            let hash = chain
+                .submit_extrinsic(
+                    "SubtensorModule",
+                    "add_stake",
+                    Vec::new(),
+                    signer,
+                    ExtrinsicWait::Included,
+                )
+                .await?;
+            Ok::<_, BittensorError>(hash)
+        };
+        let on_dropped = || async {
+            dropped.fetch_add(1, Ordering::SeqCst);
+        };
+
+        // The node may have received the extrinsic before the socket closed
+        chain.push_extrinsic_result(Err(ChainError::Rpc("request timed out".into())));
+        let result: BittensorResult<String> = submit_once(chain.clone(), submit, on_dropped).await;
+        assert!(result.is_err());
+        assert_eq!(chain.submitted().len(), 1);
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+
+        // Dispatch errors leave the connection alone
+        chain.push_extrinsic_result(Err(ChainError::Transaction("Insufficient balance".into())));
+        let result: BittensorResult<String> = submit_once(chain.clone(), submit, on_dropped).await;
+        assert!(result.is_err());
+        assert_eq!(chain.submitted().len(), 2);
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+
+        let result: BittensorResult<String> = submit_once(chain.clone(), submit, on_dropped).await;
+        assert!(result.is_ok());
+        assert_eq!(chain.submitted().len(), 3);
+    }
 }
@@ -0,0 +1,449 @@
+//! Block-based query cache for [`Subtensor`](super::Subtensor)
+//!
+//! Validator loops read the same chain values (block number, tempo,
+//! hyperparameters, metagraph info) many times per epoch. [`QueryCache`]
+//! keeps those values for a number of blocks that depends on their
+//! [`CacheCategory`]. Entries expire when the chain head moves past their
+//! expiry block (see [`QueryCache::on_block`]) or, when no new blocks are
+//! observed, after the equivalent wall-clock time.
+//!
+//! Values used to build extrinsics (nonces, burn cost, DRAND rounds) must
+//! never go through the cache.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Tempo assumed for hyperparameter TTLs when the subnet tempo is unknown
+const DEFAULT_TEMPO: u64 = 360;
+
+/// Kind of cached value, which determines its TTL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheCategory {
+    /// Current block number
+    BlockNumber,
+    /// Subnet hyperparameters (tempo, rate limits, commit-reveal settings)
+    Hyperparameters,
+    /// Metagraph and neuron info
+    Metagraph,
+    /// On-chain identities
+    Identity,
+}
+
+/// Per-category cache TTLs, in blocks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheTtl {
+    /// TTL of the block number
+    pub block_number: u64,
+    /// TTL of hyperparameters; `None` caches them for one subnet tempo
+    pub hyperparameters: Option<u64>,
+    /// TTL of metagraph info
+    pub metagraph: u64,
+    /// TTL of identities
+    pub identity: u64,
+}
+
+impl Default for CacheTtl {
+    fn default() -> Self {
+        Self {
+            block_number: 1,
+            hyperparameters: None,
+            metagraph: 10,
+            identity: 100,
+        }
+    }
+}
+
+impl CacheTtl {
+    /// Get the TTL of a category, given the subnet tempo if known
+    pub fn blocks(&self, category: CacheCategory, tempo: Option<u64>) -> u64 {
+        match category {
+            CacheCategory::BlockNumber => self.block_number,
+            CacheCategory::Hyperparameters => self
+                .hyperparameters
+                .unwrap_or_else(|| tempo.unwrap_or(DEFAULT_TEMPO)),
+            CacheCategory::Metagraph => self.metagraph,
+            CacheCategory::Identity => self.identity,
+        }
+    }
+}
+
+/// Key of a cached value
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    /// Category of the value
+    pub category: CacheCategory,
+    /// Subnet the value belongs to, if any
+    pub netuid: Option<u16>,
+    /// Name of the query, including any extra parameters
+    pub name: String,
+}
+
+impl CacheKey {
+    /// Create a key for a chain-wide value
+    pub fn global(category: CacheCategory, name: impl Into<String>) -> Self {
+        Self {
+            category,
+            netuid: None,
+            name: name.into(),
+        }
+    }
+
+    /// Create a key for a per-subnet value
+    pub fn subnet(category: CacheCategory, netuid: u16, name: impl Into<String>) -> Self {
+        Self {
+            category,
+            netuid: Some(netuid),
+            name: name.into(),
+        }
+    }
+}
+
+/// Cache hit/miss counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that went to the chain
+    pub misses: u64,
+    /// Entries currently cached
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// Fraction of lookups answered from the cache
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+struct CacheEntry {
+    value: Arc<dyn Any + Send + Sync>,
+    /// First block at which the entry is stale
+    expires_at_block: u64,
+    /// Wall-clock fallback when no new blocks are observed
+    expires_at: Instant,
+}
+
+impl CacheEntry {
+    fn is_expired(&self, head: u64, now: Instant) -> bool {
+        head >= self.expires_at_block || now >= self.expires_at
+    }
+}
+
+/// Keyed query cache with TTLs measured in blocks
+///
+/// Disabled by default; a disabled cache stores nothing and counts nothing.
+pub struct QueryCache {
+    enabled: AtomicBool,
+    ttl: CacheTtl,
+    block_time: Duration,
+    /// Latest block seen, 0 if unknown
+    head: AtomicU64,
+    entries: Mutex<HashMap<CacheKey, CacheEntry>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl QueryCache {
+    /// Create a disabled cache
+    ///
+    /// # Arguments
+    /// * `ttl` - Per-category TTLs in blocks
+    /// * `block_time` - Expected block time, used for wall-clock expiry
+    pub fn new(ttl: CacheTtl, block_time: Duration) -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            ttl,
+            block_time,
+            head: AtomicU64::new(0),
+            entries: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Enable or disable the cache; disabling drops all entries
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+        if !enabled {
+            self.clear();
+        }
+    }
+
+    /// Check whether the cache is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst)
+    }
+
+    /// Get the configured TTLs
+    pub fn ttl(&self) -> &CacheTtl {
+        &self.ttl
+    }
+
+    /// Look up a value, counting a hit or miss
+    ///
+    /// Returns None if the cache is disabled, the entry is missing or
+    /// expired, or it holds a value of another type.
+    pub fn get<T: Clone + 'static>(&self, key: &CacheKey) -> Option<T> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let head = self.head.load(Ordering::SeqCst);
+        let mut entries = self.lock_entries();
+        let value = match entries.get(key) {
+            Some(entry) if entry.is_expired(head, Instant::now()) => {
+                entries.remove(key);
+                None
+            }
+            Some(entry) => entry.value.downcast_ref::<T>().cloned(),
+            None => None,
+        };
+        drop(entries);
+
+        let counter = if value.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    /// Store a value for `ttl_blocks` blocks
+    pub fn insert<T: Send + Sync + 'static>(&self, key: CacheKey, value: T, ttl_blocks: u64) {
+        if !self.is_enabled() || ttl_blocks == 0 {
+            return;
+        }
+
+        let head = self.head.load(Ordering::SeqCst);
+        let expires_at_block = if head == 0 {
+            u64::MAX
+        } else {
+            head.saturating_add(ttl_blocks)
+        };
+        let lifetime = self
+            .block_time
+            .saturating_mul(u32::try_from(ttl_blocks).unwrap_or(u32::MAX));
+        let entry = CacheEntry {
+            value: Arc::new(value),
+            expires_at_block,
+            expires_at: Instant::now() + lifetime,
+        };
+        self.lock_entries().insert(key, entry);
+    }
+
+    /// Return the cached value for `key`, or fetch and cache it
+    ///
+    /// # Arguments
+    /// * `key` - Cache key of the value
+    /// * `ttl_blocks` - TTL used if the value is fetched
+    /// * `fetch` - Query to run on a miss
+    pub async fn get_or_fetch<T, E, F>(
+        &self,
+        key: CacheKey,
+        ttl_blocks: u64,
+        fetch: F,
+    ) -> Result<T, E>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: Future<Output = Result<T, E>>,
+    {
+        if let Some(value) = self.get::<T>(&key) {
+            return Ok(value);
+        }
+        let value = fetch.await?;
+        self.insert(key, value.clone(), ttl_blocks);
+        Ok(value)
+    }
+
+    /// Record a new chain head, dropping entries that expired at it
+    pub fn on_block(&self, block_number: u64) {
+        let previous = self.head.fetch_max(block_number, Ordering::SeqCst);
+        if block_number <= previous {
+            return;
+        }
+        let now = Instant::now();
+        self.lock_entries()
+            .retain(|_, entry| !entry.is_expired(block_number, now));
+    }
+
+    /// Latest block seen by the cache, if any
+    pub fn head(&self) -> Option<u64> {
+        Some(self.head.load(Ordering::SeqCst)).filter(|&head| head != 0)
+    }
+
+    /// Drop every entry of a subnet
+    pub fn invalidate(&self, netuid: u16) {
+        self.lock_entries()
+            .retain(|key, _| key.netuid != Some(netuid));
+    }
+
+    /// Drop every entry
+    pub fn clear(&self) {
+        self.lock_entries().clear();
+    }
+
+    /// Get the hit/miss counters
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.lock_entries().len(),
+        }
+    }
+
+    fn lock_entries(&self) -> std::sync::MutexGuard<'_, HashMap<CacheKey, CacheEntry>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self::new(CacheTtl::default(), Duration::from_secs(12))
+    }
+}
+
+impl std::fmt::Debug for QueryCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryCache")
+            .field("enabled", &self.is_enabled())
+            .field("ttl", &self.ttl)
+            .field("head", &self.head())
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled_cache() -> QueryCache {
+        let cache = QueryCache::default();
+        cache.set_enabled(true);
+        cache
+    }
+
+    fn tempo_key(netuid: u16) -> CacheKey {
+        CacheKey::subnet(CacheCategory::Hyperparameters, netuid, "tempo")
+    }
+
+    #[test]
+    fn test_disabled_cache_stores_nothing() {
+        let cache = QueryCache::default();
+        cache.insert(tempo_key(1), 360u16, 10);
+        assert_eq!(cache.get::<u16>(&tempo_key(1)), None);
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn test_hits_and_misses() {
+        let cache = enabled_cache();
+        assert_eq!(cache.get::<u16>(&tempo_key(1)), None);
+        cache.insert(tempo_key(1), 360u16, 10);
+        assert_eq!(cache.get::<u16>(&tempo_key(1)), Some(360));
+        assert_eq!(cache.get::<u16>(&tempo_key(1)), Some(360));
+        // Wrong type is a miss
+        assert_eq!(cache.get::<u64>(&tempo_key(1)), None);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (2, 2, 1));
+        assert_eq!(stats.hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_block_expiry() {
+        let cache = enabled_cache();
+        cache.on_block(100);
+        cache.insert(tempo_key(1), 360u16, 5);
+        let block_key = CacheKey::global(CacheCategory::BlockNumber, "block_number");
+        cache.insert(block_key.clone(), 100u64, 1);
+
+        cache.on_block(101);
+        assert_eq!(cache.get::<u64>(&block_key), None);
+        assert_eq!(cache.get::<u16>(&tempo_key(1)), Some(360));
+
+        // Old heads are ignored
+        cache.on_block(99);
+        assert_eq!(cache.head(), Some(101));
+
+        cache.on_block(105);
+        assert_eq!(cache.get::<u16>(&tempo_key(1)), None);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    fn test_wall_clock_expiry() {
+        let cache = QueryCache::new(CacheTtl::default(), Duration::ZERO);
+        cache.set_enabled(true);
+        cache.insert(tempo_key(1), 360u16, 5);
+        assert_eq!(cache.get::<u16>(&tempo_key(1)), None);
+    }
+
+    #[test]
+    fn test_invalidate_subnet() {
+        let cache = enabled_cache();
+        cache.insert(tempo_key(1), 360u16, 10);
+        cache.insert(tempo_key(2), 99u16, 10);
+        cache.insert(
+            CacheKey::global(CacheCategory::Identity, "5F3sa2TJ"),
+            "alice".to_string(),
+            10,
+        );
+
+        cache.invalidate(1);
+        assert_eq!(cache.get::<u16>(&tempo_key(1)), None);
+        assert_eq!(cache.get::<u16>(&tempo_key(2)), Some(99));
+        assert_eq!(cache.stats().entries, 2);
+
+        cache.set_enabled(false);
+        cache.set_enabled(true);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    fn test_ttl_blocks() {
+        let ttl = CacheTtl::default();
+        assert_eq!(ttl.blocks(CacheCategory::BlockNumber, None), 1);
+        assert_eq!(ttl.blocks(CacheCategory::Hyperparameters, Some(99)), 99);
+        assert_eq!(ttl.blocks(CacheCategory::Hyperparameters, None), 360);
+        assert_eq!(ttl.blocks(CacheCategory::Identity, None), 100);
+
+        let fixed = CacheTtl {
+            hyperparameters: Some(20),
+            ..CacheTtl::default()
+        };
+        assert_eq!(fixed.blocks(CacheCategory::Hyperparameters, Some(99)), 20);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_fetch() {
+        let cache = enabled_cache();
+        let key = tempo_key(3);
+        let value: Result<u16, String> =
+            cache.get_or_fetch(key.clone(), 10, async { Ok(42) }).await;
+        assert_eq!(value, Ok(42));
+        let value: Result<u16, String> = cache
+            .get_or_fetch(key, 10, async { Err("should not fetch".to_string()) })
+            .await;
+        assert_eq!(value, Ok(42));
+
+        let err: Result<u16, String> = cache
+            .get_or_fetch(tempo_key(4), 10, async { Err("rpc".to_string()) })
+            .await;
+        assert_eq!(err, Err("rpc".to_string()));
+        assert_eq!(cache.get::<u16>(&tempo_key(4)), None);
+    }
+}
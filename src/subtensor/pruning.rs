@@ -0,0 +1,321 @@
+//! Deregistration risk of a UID
+//!
+//! When a subnet is full, each new registration replaces the non-immune
+//! neuron with the lowest pruning score. [`PruningRisk`] reports where a UID
+//! stands in that order and classifies it as a [`RiskLevel`] using
+//! configurable [`PruningThresholds`].
+//!
+//! Neurons with equal scores are ordered by registration block, then UID, so
+//! the oldest registration is pruned first.
+
+use crate::chain::ChainBackend;
+use crate::errors::{BittensorError, BittensorResult, ChainQueryError};
+use crate::queries::hyperparameters::{get_immunity_period, get_max_allowed_uids};
+use crate::queries::neurons::immunity_blocks_remaining;
+use crate::queries::subnets::get_subnet_state;
+use crate::types::SubnetStateInfo;
+use serde::Serialize;
+use std::fmt;
+
+/// How close a UID is to being pruned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+        })
+    }
+}
+
+/// Where the risk levels start, as fractions of the prunable neurons
+///
+/// A UID ranked in the lowest `high_fraction` of non-immune neurons is at
+/// high risk, in the lowest `medium_fraction` at medium risk. The next UID
+/// to be pruned is always at high risk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PruningThresholds {
+    /// Fraction of prunable neurons at high risk
+    pub high_fraction: f64,
+    /// Fraction of prunable neurons at medium or high risk
+    pub medium_fraction: f64,
+    /// Immune UIDs with at most this many blocks of immunity left are at
+    /// medium risk
+    pub immunity_warning_blocks: u64,
+}
+
+impl Default for PruningThresholds {
+    fn default() -> Self {
+        Self {
+            high_fraction: 0.05,
+            medium_fraction: 0.2,
+            immunity_warning_blocks: 360,
+        }
+    }
+}
+
+impl PruningThresholds {
+    /// Risk of the UID at `rank` (0 = pruned next) among `candidates`
+    fn classify(&self, rank: usize, candidates: usize) -> RiskLevel {
+        let position = rank as f64 / candidates.max(1) as f64;
+        if rank == 0 || position < self.high_fraction {
+            RiskLevel::High
+        } else if position < self.medium_fraction {
+            RiskLevel::Medium
+        } else {
+            RiskLevel::Low
+        }
+    }
+}
+
+/// Deregistration risk of one UID
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PruningRisk {
+    pub netuid: u16,
+    pub uid: u16,
+    /// Block the risk was evaluated at
+    pub block: u64,
+    pub pruning_score: u16,
+    /// Position in the pruning order among non-immune neurons (0 = pruned
+    /// next), or None while the UID is immune
+    pub rank: Option<usize>,
+    /// Number of non-immune neurons
+    pub candidates: usize,
+    pub num_uids: usize,
+    pub max_uids: u16,
+    /// Whether new registrations replace an existing neuron
+    pub subnet_full: bool,
+    /// Blocks of immunity left, or None if it has expired
+    pub immunity_remaining: Option<u64>,
+    pub risk: RiskLevel,
+}
+
+/// Inputs of a pruning risk evaluation
+#[derive(Debug, Clone, Default)]
+pub struct SubnetPruningState {
+    /// Pruning score per UID
+    pub pruning_score: Vec<u16>,
+    /// Registration block per UID
+    pub block_at_registration: Vec<u64>,
+    pub immunity_period: u64,
+    pub max_uids: u16,
+    /// Block to evaluate immunity at
+    pub block: u64,
+}
+
+impl SubnetPruningState {
+    /// Take the scores and registration blocks from a [`SubnetStateInfo`]
+    pub fn from_subnet_state(
+        state: &SubnetStateInfo,
+        immunity_period: u64,
+        max_uids: u16,
+        block: u64,
+    ) -> Self {
+        Self {
+            pruning_score: state.pruning_score.clone(),
+            block_at_registration: state.block_at_registration.clone(),
+            immunity_period,
+            max_uids,
+            block,
+        }
+    }
+
+    fn immunity_remaining(&self, uid: usize) -> Option<u64> {
+        immunity_blocks_remaining(
+            self.block_at_registration[uid],
+            self.immunity_period,
+            self.block,
+        )
+    }
+
+    /// Evaluate the risk of `uid`, or None if it does not exist
+    pub fn risk(
+        &self,
+        netuid: u16,
+        uid: u16,
+        thresholds: &PruningThresholds,
+    ) -> Option<PruningRisk> {
+        let num_uids = self
+            .pruning_score
+            .len()
+            .min(self.block_at_registration.len());
+        let index = uid as usize;
+        if index >= num_uids {
+            return None;
+        }
+
+        let order = |i: usize| (self.pruning_score[i], self.block_at_registration[i], i);
+        let candidates: Vec<usize> = (0..num_uids)
+            .filter(|&i| self.immunity_remaining(i).is_none())
+            .collect();
+        let immunity_remaining = self.immunity_remaining(index);
+        let rank = immunity_remaining.is_none().then(|| {
+            candidates
+                .iter()
+                .filter(|&&i| order(i) < order(index))
+                .count()
+        });
+        let subnet_full = num_uids >= self.max_uids as usize;
+
+        let risk = match (subnet_full, rank, immunity_remaining) {
+            (false, _, _) => RiskLevel::Low,
+            (true, Some(rank), _) => thresholds.classify(rank, candidates.len()),
+            (true, None, Some(remaining)) if remaining <= thresholds.immunity_warning_blocks => {
+                RiskLevel::Medium
+            }
+            (true, None, _) => RiskLevel::Low,
+        };
+
+        Some(PruningRisk {
+            netuid,
+            uid,
+            block: self.block,
+            pruning_score: self.pruning_score[index],
+            rank,
+            candidates: candidates.len(),
+            num_uids,
+            max_uids: self.max_uids,
+            subnet_full,
+            immunity_remaining,
+            risk,
+        })
+    }
+}
+
+/// Evaluate the deregistration risk of `uid` at the current block
+pub async fn get_pruning_risk(
+    client: &impl ChainBackend,
+    netuid: u16,
+    uid: u16,
+    thresholds: &PruningThresholds,
+) -> BittensorResult<PruningRisk> {
+    let (block, immunity_period, max_uids) = tokio::try_join!(
+        async { client.block_number().await.map_err(BittensorError::from) },
+        get_immunity_period(client, netuid),
+        get_max_allowed_uids(client, netuid),
+    )?;
+    // Read the state last, so registrations after `block` look immune
+    // rather than old
+    let state = get_subnet_state(client, netuid).await?;
+    SubnetPruningState::from_subnet_state(&state, immunity_period as u64, max_uids, block)
+        .risk(netuid, uid, thresholds)
+        .ok_or_else(|| {
+            ChainQueryError::new(format!("UID {} does not exist on subnet {}", uid, netuid)).into()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A subnet at block 10_000 with an immunity period of 100 blocks
+    fn state(scores: &[u16], registered_at: &[u64], max_uids: u16) -> SubnetPruningState {
+        SubnetPruningState {
+            pruning_score: scores.to_vec(),
+            block_at_registration: registered_at.to_vec(),
+            immunity_period: 100,
+            max_uids,
+            block: 10_000,
+        }
+    }
+
+    fn risk(state: &SubnetPruningState, uid: u16) -> PruningRisk {
+        state
+            .risk(1, uid, &PruningThresholds::default())
+            .expect("UID exists")
+    }
+
+    #[test]
+    fn test_rank_among_non_immune() {
+        // UID 1 has the lowest score but is still immune
+        let state = state(&[500, 10, 300, 200, 400], &[0, 9_950, 0, 0, 0], 5);
+
+        let immune = risk(&state, 1);
+        assert_eq!(immune.rank, None);
+        assert_eq!(immune.immunity_remaining, Some(50));
+        assert_eq!(immune.candidates, 4);
+        assert_eq!(immune.risk, RiskLevel::Medium);
+
+        assert_eq!(risk(&state, 3).rank, Some(0));
+        assert_eq!(risk(&state, 3).risk, RiskLevel::High);
+        assert_eq!(risk(&state, 2).rank, Some(1));
+        assert_eq!(risk(&state, 0).rank, Some(3));
+        assert_eq!(risk(&state, 0).risk, RiskLevel::Low);
+        assert!(state.risk(1, 5, &PruningThresholds::default()).is_none());
+    }
+
+    #[test]
+    fn test_ties_prune_oldest_first() {
+        let state = state(&[100, 100, 100], &[300, 100, 100], 3);
+        assert_eq!(risk(&state, 1).rank, Some(0));
+        assert_eq!(risk(&state, 2).rank, Some(1));
+        assert_eq!(risk(&state, 0).rank, Some(2));
+    }
+
+    #[test]
+    fn test_subnet_with_free_slots_is_low_risk() {
+        let state = state(&[0, 500], &[0, 0], 256);
+        let lowest = risk(&state, 0);
+        assert!(!lowest.subnet_full);
+        assert_eq!(lowest.rank, Some(0));
+        assert_eq!(lowest.risk, RiskLevel::Low);
+    }
+
+    #[test]
+    fn test_thresholds() {
+        // 100 prunable neurons with scores equal to their UID
+        let scores: Vec<u16> = (0..100).collect();
+        let state = state(&scores, &[0; 100], 100);
+
+        let defaults = PruningThresholds::default();
+        let level =
+            |uid: u16, thresholds: &PruningThresholds| state.risk(1, uid, thresholds).unwrap().risk;
+        assert_eq!(level(4, &defaults), RiskLevel::High);
+        assert_eq!(level(5, &defaults), RiskLevel::Medium);
+        assert_eq!(level(19, &defaults), RiskLevel::Medium);
+        assert_eq!(level(20, &defaults), RiskLevel::Low);
+
+        let strict = PruningThresholds {
+            high_fraction: 0.0,
+            medium_fraction: 0.5,
+            ..defaults
+        };
+        assert_eq!(level(0, &strict), RiskLevel::High);
+        assert_eq!(level(1, &strict), RiskLevel::Medium);
+        assert_eq!(level(49, &strict), RiskLevel::Medium);
+        assert_eq!(level(50, &strict), RiskLevel::Low);
+    }
+
+    #[test]
+    fn test_immunity_warning() {
+        // UID 0 scores lowest and has 50 blocks of immunity left
+        let state = state(&[0, 100], &[9_950, 0], 2);
+        assert_eq!(risk(&state, 0).immunity_remaining, Some(50));
+        assert_eq!(risk(&state, 0).risk, RiskLevel::Medium);
+
+        let level = |state: &SubnetPruningState, warning: u64| {
+            let thresholds = PruningThresholds {
+                immunity_warning_blocks: warning,
+                ..PruningThresholds::default()
+            };
+            state.risk(1, 0, &thresholds).unwrap().risk
+        };
+        assert_eq!(level(&state, 50), RiskLevel::Medium);
+        assert_eq!(level(&state, 49), RiskLevel::Low);
+
+        let expired = SubnetPruningState {
+            block: 10_050,
+            ..state
+        };
+        assert_eq!(risk(&expired, 0).rank, Some(0));
+        assert_eq!(level(&expired, 0), RiskLevel::High);
+    }
+}
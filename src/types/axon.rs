@@ -1,5 +1,12 @@
+use crate::utils::networking::ip_version;
 use serde::{Deserialize, Serialize};
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr};
+
+/// Protocol of an axon served over plain HTTP (the default)
+pub const AXON_PROTOCOL_HTTP: u8 = 4;
+
+/// Protocol of an axon served over HTTPS
+pub const AXON_PROTOCOL_HTTPS: u8 = 5;
 
 /// Complete axon information stored on-chain for a neuron endpoint
 /// Includes optional hotkey metadata when available.
@@ -17,7 +24,7 @@ pub struct AxonInfo {
     pub port: u16,
     /// IP type: 4 for IPv4, 6 for IPv6
     pub ip_type: u8,
-    /// Protocol: TCP, UDP, other
+    /// Protocol: [`AXON_PROTOCOL_HTTP`] or [`AXON_PROTOCOL_HTTPS`]
     pub protocol: u8,
     /// Reserved field for future use
     pub placeholder1: u8,
@@ -51,20 +58,71 @@ impl AxonInfo {
         }
     }
 
-    /// Check if the axon is serving (not 0.0.0.0)
-    pub fn is_serving(&self) -> bool {
-        match self.ip {
-            IpAddr::V4(ipv4) => ipv4.octets() != [0, 0, 0, 0],
-            IpAddr::V6(ipv6) => ipv6.segments() != [0, 0, 0, 0, 0, 0, 0, 0],
+    /// Create AxonInfo for a socket address
+    ///
+    /// IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`), as reported by
+    /// dual-stack sockets, are stored as IPv4.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - The address the axon is reachable at
+    /// * `protocol` - The axon protocol
+    pub fn from_socket_addr(addr: SocketAddr, protocol: u8) -> Self {
+        let ip = addr.ip().to_canonical();
+        Self {
+            hotkey: None,
+            block: 0,
+            version: 0,
+            ip,
+            port: addr.port(),
+            ip_type: ip_version(&ip),
+            protocol,
+            placeholder1: 0,
+            placeholder2: 0,
         }
     }
 
+    /// Get the socket address, or `None` if the axon is not serving
+    pub fn socket_addr(&self) -> Option<SocketAddr> {
+        self.is_serving()
+            .then(|| SocketAddr::new(self.ip, self.port))
+    }
+
+    /// Check if the axon is serving
+    ///
+    /// Neurons that never served an axon hold a placeholder entry with a zero
+    /// IP and port. An entry is only serving with a non-zero IP and port and
+    /// an `ip_type` matching the IP.
+    pub fn is_serving(&self) -> bool {
+        !self.ip.is_unspecified() && self.port != 0 && self.ip_type == ip_version(&self.ip)
+    }
+
+    /// Get the URL the axon is reachable at, or `None` if it is not serving
+    ///
+    /// Uses `https` for [`AXON_PROTOCOL_HTTPS`] and `http` otherwise. IPv6
+    /// addresses are bracketed, e.g. `https://[::1]:8091`.
+    pub fn url(&self) -> Option<String> {
+        let scheme = match self.protocol {
+            AXON_PROTOCOL_HTTPS => "https",
+            _ => "http",
+        };
+        Some(format!("{}://{}", scheme, self.socket_addr()?))
+    }
+
+    /// Number of blocks since the axon was last served
+    ///
+    /// Zero if `current_block` is before the axon's block.
+    pub fn age_blocks(&self, current_block: u64) -> u64 {
+        current_block.saturating_sub(self.block)
+    }
+
     /// Get endpoint string
+    ///
+    /// Always `http`, whether or not the axon is serving; see
+    /// [`url`](AxonInfo::url). IPv6 addresses are bracketed, e.g.
+    /// `http://[::1]:8091`.
     pub fn to_endpoint(&self) -> String {
-        match self.ip {
-            IpAddr::V4(ip) => format!("http://{}:{}", ip, self.port),
-            IpAddr::V6(ip) => format!("http://[{}]:{}", ip, self.port),
-        }
+        format!("http://{}", SocketAddr::new(self.ip, self.port))
     }
 
     /// Get IP as string
@@ -72,3 +130,99 @@ impl AxonInfo {
         format!("{}:{}", self.ip, self.port)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn test_from_socket_addr() {
+        let axon = AxonInfo::from_socket_addr("1.2.3.4:8091".parse().unwrap(), 4);
+        assert_eq!(axon.ip, IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)));
+        assert_eq!(axon.ip_type, 4);
+        assert_eq!(axon.port, 8091);
+        assert_eq!(axon.protocol, 4);
+
+        let axon = AxonInfo::from_socket_addr("[2001:db8::1]:9000".parse().unwrap(), 4);
+        assert_eq!(axon.ip_type, 6);
+        assert_eq!(
+            axon.socket_addr(),
+            Some("[2001:db8::1]:9000".parse().unwrap())
+        );
+
+        // Mapped addresses are stored as IPv4
+        let axon = AxonInfo::from_socket_addr("[::ffff:10.0.0.1]:8091".parse().unwrap(), 4);
+        assert_eq!(axon.ip, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+        assert_eq!(axon.ip_type, 4);
+    }
+
+    #[test]
+    fn test_socket_addr_not_serving() {
+        let axon = AxonInfo::from_socket_addr("0.0.0.0:8091".parse().unwrap(), 4);
+        assert!(!axon.is_serving());
+        assert_eq!(axon.socket_addr(), None);
+
+        let axon = AxonInfo::from_socket_addr(SocketAddr::new(Ipv6Addr::UNSPECIFIED.into(), 1), 4);
+        assert_eq!(axon.socket_addr(), None);
+    }
+
+    #[test]
+    fn test_to_endpoint() {
+        let v4 = AxonInfo::from_socket_addr("127.0.0.1:8091".parse().unwrap(), 4);
+        assert_eq!(v4.to_endpoint(), "http://127.0.0.1:8091");
+
+        let v6 = AxonInfo::from_socket_addr("[::1]:8091".parse().unwrap(), 4);
+        assert_eq!(v6.to_endpoint(), "http://[::1]:8091");
+    }
+
+    #[test]
+    fn test_ipv4_url() {
+        let axon = AxonInfo::from_socket_addr("10.0.0.1:8091".parse().unwrap(), AXON_PROTOCOL_HTTP);
+        assert!(axon.is_serving());
+        assert_eq!(axon.url().as_deref(), Some("http://10.0.0.1:8091"));
+
+        let tls = AxonInfo::from_socket_addr("10.0.0.1:443".parse().unwrap(), AXON_PROTOCOL_HTTPS);
+        assert_eq!(tls.url().as_deref(), Some("https://10.0.0.1:443"));
+    }
+
+    #[test]
+    fn test_ipv6_url() {
+        let axon =
+            AxonInfo::from_socket_addr("[2001:db8::1]:8091".parse().unwrap(), AXON_PROTOCOL_HTTP);
+        assert!(axon.is_serving());
+        assert_eq!(axon.url().as_deref(), Some("http://[2001:db8::1]:8091"));
+
+        let tls = AxonInfo::from_socket_addr("[::1]:443".parse().unwrap(), AXON_PROTOCOL_HTTPS);
+        assert_eq!(tls.url().as_deref(), Some("https://[::1]:443"));
+    }
+
+    #[test]
+    fn test_placeholder_not_serving() {
+        // The entry stored for a neuron that never served
+        let placeholder =
+            AxonInfo::from_chain_data(0, 0, Ipv4Addr::UNSPECIFIED.into(), 0, 0, 0, 0, 0);
+        assert!(!placeholder.is_serving());
+        assert_eq!(placeholder.url(), None);
+
+        let mut axon = AxonInfo::from_socket_addr("10.0.0.1:8091".parse().unwrap(), 4);
+        axon.port = 0;
+        assert!(!axon.is_serving());
+
+        let mut axon = AxonInfo::from_socket_addr("10.0.0.1:8091".parse().unwrap(), 4);
+        axon.ip_type = 0;
+        assert!(!axon.is_serving());
+        axon.ip_type = 6;
+        assert!(!axon.is_serving());
+        assert_eq!(axon.url(), None);
+    }
+
+    #[test]
+    fn test_age_blocks() {
+        let mut axon = AxonInfo::from_socket_addr("10.0.0.1:8091".parse().unwrap(), 4);
+        axon.block = 1_000;
+        assert_eq!(axon.age_blocks(1_360), 360);
+        assert_eq!(axon.age_blocks(1_000), 0);
+        assert_eq!(axon.age_blocks(900), 0);
+    }
+}
@@ -2,6 +2,7 @@
 //! Matches Python's bittensor.core.chain_data.DynamicInfo
 
 use crate::core::constants::RAOPERTAO;
+use crate::utils::balance_newtypes::Rao;
 use serde::{Deserialize, Serialize};
 
 /// Dynamic information about a subnet
@@ -52,7 +53,7 @@ pub struct DynamicInfo {
     pub network_connect: Vec<(u16, u16)>,
     /// Subnet volume
     pub subnet_volume: u128,
-    /// Moving price average
+    /// Moving price average (RAO per alpha)
     pub moving_price: u128,
     /// Current alpha price
     pub price: u128,
@@ -93,7 +94,76 @@ impl DynamicInfo {
     }
 }
 
+/// Expected outcome of staking TAO into a subnet pool
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlippageEstimate {
+    /// TAO being staked
+    pub tao_in: Rao,
+    /// Alpha received at the current spot price, ignoring pool depth
+    pub ideal_alpha_out: u128,
+    /// Alpha actually received after the swap moves the pool
+    pub alpha_out: u128,
+    /// Shortfall versus the spot price, in percent
+    pub slippage_pct: f64,
+    /// Increase in alpha price caused by the swap, in percent
+    pub price_impact_pct: f64,
+}
+
+/// Estimate alpha received for staking `tao_in` into a subnet
+///
+/// Uses constant-product pool math: with `k = tao_in * alpha_in`, adding
+/// `tao` leaves `k / (tao_in + tao)` alpha in the pool and the difference is
+/// paid out. Root (netuid 0) is a 1:1 stable pool with no slippage.
+pub fn estimate_slippage(dynamic: &DynamicInfo, tao_in: Rao) -> SlippageEstimate {
+    let tao = tao_in.as_u128();
+
+    if dynamic.netuid == 0 {
+        return SlippageEstimate {
+            tao_in,
+            ideal_alpha_out: tao,
+            alpha_out: tao,
+            slippage_pct: 0.0,
+            price_impact_pct: 0.0,
+        };
+    }
+
+    if !dynamic.has_liquidity() || tao == 0 {
+        return SlippageEstimate {
+            tao_in,
+            ideal_alpha_out: 0,
+            alpha_out: 0,
+            slippage_pct: 0.0,
+            price_impact_pct: 0.0,
+        };
+    }
+
+    let pool_tao = dynamic.tao_in as f64;
+    let pool_alpha = dynamic.alpha_in as f64;
+    let tao_f = tao as f64;
+
+    let ideal = tao_f * pool_alpha / pool_tao;
+    let new_pool_tao = pool_tao + tao_f;
+    let new_pool_alpha = pool_tao * pool_alpha / new_pool_tao;
+    let alpha_out = (pool_alpha - new_pool_alpha).max(0.0);
+
+    let old_price = pool_tao / pool_alpha;
+    let new_price = new_pool_tao / new_pool_alpha;
+
+    SlippageEstimate {
+        tao_in,
+        ideal_alpha_out: ideal as u128,
+        alpha_out: alpha_out as u128,
+        slippage_pct: (ideal - alpha_out) / ideal * 100.0,
+        price_impact_pct: (new_price / old_price - 1.0) * 100.0,
+    }
+}
+
 /// Extended subnet state information
+///
+/// Never filled from the chain. Per-UID state from `get_subnet_state` is
+/// [`SubnetStateInfo`](crate::types::SubnetStateInfo); subnet parameters are
+/// in [`SubnetHyperparameters`](crate::queries::hyperparameters::SubnetHyperparameters).
+#[deprecated(note = "use `SubnetStateInfo` or `SubnetHyperparameters`")]
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SubnetState {
     /// Subnet unique identifier
@@ -144,6 +214,7 @@ pub struct SubnetState {
     pub rho: u64,
 }
 
+#[allow(deprecated)]
 impl SubnetState {
     pub fn new(netuid: u16) -> Self {
         Self {
@@ -178,4 +249,32 @@ mod tests {
         info.price = 1_500_000_000; // 1.5 TAO
         assert!((info.alpha_price_tao() - 1.5).abs() < 0.0001);
     }
+
+    #[test]
+    fn test_estimate_slippage_constant_product() {
+        let mut info = DynamicInfo::new(1);
+        info.tao_in = 1_000 * RAOPERTAO;
+        info.alpha_in = 2_000 * RAOPERTAO;
+
+        // 10 TAO into a 1000 TAO / 2000 alpha pool
+        let est = estimate_slippage(&info, Rao::from(10 * RAOPERTAO));
+        assert_eq!(est.ideal_alpha_out, 20 * RAOPERTAO);
+        // k / (1000 + 10) = 1980.198..., so ~19.80 alpha out
+        let alpha = est.alpha_out as f64 / RAOPERTAO as f64;
+        assert!((alpha - 19.80198).abs() < 1e-4);
+        assert!((est.slippage_pct - 0.990099).abs() < 1e-4);
+        assert!((est.price_impact_pct - 2.01).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_estimate_slippage_root_and_empty_pool() {
+        let root = DynamicInfo::new(0);
+        let est = estimate_slippage(&root, Rao::from(5 * RAOPERTAO));
+        assert_eq!(est.alpha_out, 5 * RAOPERTAO);
+        assert_eq!(est.slippage_pct, 0.0);
+
+        let empty = DynamicInfo::new(3);
+        let est = estimate_slippage(&empty, Rao::from(5 * RAOPERTAO));
+        assert_eq!(est.alpha_out, 0);
+    }
 }
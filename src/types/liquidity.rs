@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+/// A concentrated-liquidity position from the swap pallet's `Positions` storage
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiquidityPosition {
     pub id: u64,
+    pub tick_low: i32,
+    pub tick_high: i32,
     pub price_low_rao: u128,
     pub price_high_rao: u128,
     pub liquidity_rao: u128,
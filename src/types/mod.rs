@@ -11,10 +11,12 @@ pub mod proposal_vote;
 pub mod subnet;
 pub mod synapse;
 
-pub use axon::AxonInfo;
+pub use axon::{AxonInfo, AXON_PROTOCOL_HTTP, AXON_PROTOCOL_HTTPS};
 pub use commitment::WeightCommitInfo;
 pub use delegate::{DelegateInfo, DelegatedInfo};
-pub use dynamic_info::{DynamicInfo, SubnetState};
+#[allow(deprecated)]
+pub use dynamic_info::SubnetState;
+pub use dynamic_info::{estimate_slippage, DynamicInfo, SlippageEstimate};
 pub use liquidity::LiquidityPosition;
 pub use metagraph_info::{
     ChainIdentity as MetagraphChainIdentity, MetagraphInfo, MetagraphInfoEmissions,
@@ -24,7 +26,7 @@ pub use neuron::NeuronInfo;
 pub use neuron_lite::NeuronInfoLite;
 pub use prometheus::PrometheusInfo;
 pub use proposal_vote::ProposalVoteData;
-pub use subnet::{SubnetIdentity, SubnetInfo};
+pub use subnet::{SubnetIdentity, SubnetInfo, SubnetStateInfo};
 pub use synapse::{
     headers, Message, StreamingSynapse, StreamingTextPromptSynapse, Synapse, SynapseHeaders,
     SynapseType, TerminalInfo, TextPromptSynapse,
@@ -1,5 +1,6 @@
 use crate::utils::balance_newtypes::Rao;
 use serde::{Deserialize, Serialize};
+use sp_core::crypto::AccountId32;
 
 /// Information about a subnet in the Bittensor network
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,3 +68,70 @@ pub struct SubnetIdentity {
     /// Additional information
     pub additional: String,
 }
+
+/// Per-UID subnet state returned by `SubnetInfoRuntimeApi_get_subnet_state`
+///
+/// All vectors are indexed by UID. Scores (`dividends` through `rank`) and
+/// `pruning_score` are raw u16 fixed-point values; divide by `u16::MAX` for
+/// the normalized float.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubnetStateInfo {
+    /// Subnet unique identifier
+    pub netuid: u16,
+    /// Hotkeys indexed by UID
+    #[serde(with = "crate::utils::ss58::serde_account_vec")]
+    pub hotkeys: Vec<AccountId32>,
+    /// Coldkeys indexed by UID
+    #[serde(with = "crate::utils::ss58::serde_account_vec")]
+    pub coldkeys: Vec<AccountId32>,
+    /// Active flags
+    pub active: Vec<bool>,
+    /// Validator permits
+    pub validator_permit: Vec<bool>,
+    /// Pruning scores
+    pub pruning_score: Vec<u16>,
+    /// Block of each UID's last weight update
+    pub last_update: Vec<u64>,
+    /// Per-UID emission (RAO)
+    pub emission: Vec<Rao>,
+    /// Dividends
+    pub dividends: Vec<u16>,
+    /// Incentives
+    pub incentives: Vec<u16>,
+    /// Consensus
+    pub consensus: Vec<u16>,
+    /// Trust
+    pub trust: Vec<u16>,
+    /// Rank
+    pub rank: Vec<u16>,
+    /// Registration block
+    pub block_at_registration: Vec<u64>,
+    /// Alpha stake, including parent inheritance (RAO)
+    pub alpha_stake: Vec<Rao>,
+    /// TAO stake (RAO)
+    pub tao_stake: Vec<Rao>,
+    /// Total stake weight used in consensus (RAO)
+    pub total_stake: Vec<Rao>,
+    /// Recent emission per UID, one entry per past epoch
+    pub emission_history: Vec<Vec<u64>>,
+}
+
+impl SubnetStateInfo {
+    /// Number of UIDs in the subnet
+    pub fn n(&self) -> usize {
+        self.hotkeys.len()
+    }
+
+    /// UID registered to `hotkey`, if any
+    pub fn uid_for_hotkey(&self, hotkey: &AccountId32) -> Option<u16> {
+        self.hotkeys
+            .iter()
+            .position(|h| h == hotkey)
+            .map(|uid| uid as u16)
+    }
+
+    /// Total emission across all UIDs (RAO)
+    pub fn total_emission(&self) -> Rao {
+        self.emission.iter().sum()
+    }
+}
@@ -6,7 +6,7 @@
 //! # Features
 //!
 //! - `Synapse` - Base synapse structure with body hash computation
-//! - `SynapseType` - Trait for custom synapse implementations
+//! - `SynapseType` - Trait for typed synapses, convertible to and from `Synapse`
 //! - `StreamingSynapse` - Trait for streaming synapse implementations
 //! - `TextPromptSynapse` - Built-in text prompt synapse
 //! - `headers` module - Header constants matching Python SDK
@@ -28,6 +28,7 @@
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use sha3::Sha3_256;
 use std::collections::HashMap;
 use std::time::Duration;
 
@@ -136,7 +137,14 @@ pub mod headers {
 /// Trait for custom synapse types
 ///
 /// Types implementing this trait can be used with the Dendrite client
-/// for type-safe communication with Axon servers.
+/// (`Dendrite::call_typed`) and the Axon (`Axon::attach_typed`) for
+/// type-safe communication. They convert to and from the dynamic [`Synapse`]
+/// through serde: every field other than the header fields travels in the
+/// request body, so typed and dynamic peers interoperate.
+///
+/// To carry the header fields (timeout, body hash, dendrite and axon
+/// terminal info), embed a `Synapse` with `#[serde(flatten)]`; types
+/// without one get the defaults when converted.
 ///
 /// # Example
 ///
@@ -211,6 +219,70 @@ pub trait SynapseType: Serialize + DeserializeOwned + Send + Sync + 'static {
             ))
         })
     }
+
+    /// Convert to the dynamic synapse representation
+    ///
+    /// The synapse name is set to [`SynapseType::name`] if the type does not
+    /// carry one, and the computed body hash is set to
+    /// [`SynapseType::body_hash`].
+    ///
+    /// # Returns
+    ///
+    /// The dynamic synapse or an error
+    fn to_synapse(&self) -> Result<Synapse, BittensorError> {
+        let value = serde_json::to_value(self).map_err(|e| {
+            BittensorError::Serialization(SerializationError::with_type(
+                format!("Failed to serialize {}: {}", Self::name(), e),
+                Self::name(),
+            ))
+        })?;
+        let mut synapse: Synapse = serde_json::from_value(value).map_err(|e| {
+            BittensorError::Serialization(SerializationError::with_type(
+                format!("{} is not a JSON object: {}", Self::name(), e),
+                Self::name(),
+            ))
+        })?;
+
+        if synapse.name.is_none() {
+            synapse.name = Some(Self::name().to_string());
+        }
+        synapse.computed_body_hash = Some(self.body_hash());
+        Ok(synapse)
+    }
+
+    /// Convert from the dynamic synapse representation
+    ///
+    /// # Arguments
+    ///
+    /// * `synapse` - The dynamic synapse, e.g. as received by an axon
+    ///
+    /// # Returns
+    ///
+    /// The typed synapse, or an error if required fields are missing or
+    /// have the wrong type
+    fn from_synapse(synapse: &Synapse) -> Result<Self, BittensorError> {
+        serde_json::to_value(synapse)
+            .and_then(serde_json::from_value)
+            .map_err(|e| {
+                BittensorError::Serialization(SerializationError::with_type(
+                    format!("Failed to convert synapse to {}: {}", Self::name(), e),
+                    Self::name(),
+                ))
+            })
+    }
+
+    /// Compute the body hash the same way as the Python SDK
+    ///
+    /// Matches Python's `Synapse.body_hash`: each field in
+    /// [`SynapseType::required_hash_fields`] is rendered with Python's `str()`
+    /// and hashed with SHA3-256, and the concatenated hex digests are hashed
+    /// again. With no required fields this is the hash of the empty string.
+    ///
+    /// Nested objects are rendered in the key order of the serialized JSON.
+    fn body_hash(&self) -> String {
+        let value = serde_json::to_value(self).unwrap_or_default();
+        python_body_hash(&value, &Self::required_hash_fields())
+    }
 }
 
 // =============================================================================
@@ -317,6 +389,9 @@ pub struct TerminalInfo {
     pub hotkey: Option<String>,
     /// Signature
     pub signature: Option<String>,
+    /// Number of attempts the dendrite made (set on responses only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<u32>,
 }
 
 impl TerminalInfo {
@@ -594,8 +669,127 @@ impl Synapse {
     }
 }
 
+/// Python's `Synapse.body_hash` over `fields` of a serialized synapse
+///
+/// Fields missing from `value` are skipped.
+pub(crate) fn python_body_hash(value: &serde_json::Value, fields: &[&str]) -> String {
+    let hashes: String = fields
+        .iter()
+        .filter_map(|field| value.get(field))
+        .map(|field| sha3_256_hex(python_str(field).as_bytes()))
+        .collect();
+    sha3_256_hex(hashes.as_bytes())
+}
+
+/// [`python_body_hash`] over the `required_hash_fields` a serialized synapse
+/// lists, as Python dendrites include them in the request body
+pub(crate) fn listed_body_hash(value: &serde_json::Value) -> String {
+    let fields: Vec<&str> = value
+        .get("required_hash_fields")
+        .and_then(|fields| fields.as_array())
+        .map(|fields| fields.iter().filter_map(|f| f.as_str()).collect())
+        .unwrap_or_default();
+    python_body_hash(value, &fields)
+}
+
+/// Hex-encoded SHA3-256 hash, as Python's `hashlib.sha3_256(data).hexdigest()`
+fn sha3_256_hex(data: &[u8]) -> String {
+    hex::encode(Sha3_256::digest(data))
+}
+
+/// Render a JSON value the way Python's `str()` renders it once decoded
+///
+/// Top-level strings are rendered as-is; everything else uses `repr()`.
+fn python_str(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => python_repr(other),
+    }
+}
+
+/// Render a JSON value the way Python's `repr()` renders it once decoded
+fn python_repr(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "None".to_string(),
+        serde_json::Value::Bool(true) => "True".to_string(),
+        serde_json::Value::Bool(false) => "False".to_string(),
+        serde_json::Value::Number(n) => match n.as_f64() {
+            Some(f) if !n.is_i64() && !n.is_u64() => python_float_repr(f),
+            _ => n.to_string(),
+        },
+        serde_json::Value::String(s) => python_str_repr(s),
+        serde_json::Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(python_repr).collect();
+            format!("[{}]", items.join(", "))
+        }
+        serde_json::Value::Object(map) => {
+            let entries: Vec<String> = map
+                .iter()
+                .map(|(k, v)| format!("{}: {}", python_str_repr(k), python_repr(v)))
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        }
+    }
+}
+
+/// Python's `repr()` of a float: shortest round-trip digits, in scientific
+/// notation for exponents below -4 or from 16 up
+pub(crate) fn python_float_repr(f: f64) -> String {
+    if f.is_nan() {
+        return "nan".to_string();
+    }
+    if f.is_infinite() {
+        return if f > 0.0 { "inf" } else { "-inf" }.to_string();
+    }
+
+    let scientific = format!("{:e}", f);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+    if (-4..16).contains(&exponent) {
+        let plain = f.to_string();
+        if plain.contains('.') {
+            plain
+        } else {
+            format!("{}.0", plain)
+        }
+    } else {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", mantissa, sign, exponent.abs())
+    }
+}
+
+/// Python's `repr()` of a string
+fn python_str_repr(s: &str) -> String {
+    let quote = if s.contains('\'') && !s.contains('"') {
+        '"'
+    } else {
+        '\''
+    };
+
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push(quote);
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c == quote => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if (c as u32) < 0x20 || c as u32 == 0x7f => {
+                out.push_str(&format!("\\x{:02x}", c as u32));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push(quote);
+    out
+}
+
 /// Constant-time string comparison to prevent timing attacks
-fn constant_time_compare(a: &[u8], b: &[u8]) -> bool {
+pub(crate) fn constant_time_compare(a: &[u8], b: &[u8]) -> bool {
     if a.len() != b.len() {
         return false;
     }
@@ -918,7 +1112,7 @@ impl Synapse {
     pub fn to_headers(&self) -> SynapseHeaders {
         let mut headers: SynapseHeaders = SynapseHeaders {
             name: self.name.clone(),
-            timeout: self.timeout.map(|t| t.to_string()),
+            timeout: self.timeout.map(python_float_repr),
             total_size: self.total_size.map(|s| s.to_string()),
             header_size: self.header_size.map(|s| s.to_string()),
             computed_body_hash: self.computed_body_hash.clone(),
@@ -928,7 +1122,7 @@ impl Synapse {
         if let Some(ref d) = self.dendrite {
             headers.dendrite_status_code = d.status_code.map(|c| c.to_string());
             headers.dendrite_status_message = d.status_message.clone();
-            headers.dendrite_process_time = d.process_time.map(|t| t.to_string());
+            headers.dendrite_process_time = d.process_time.map(python_float_repr);
             headers.dendrite_ip = d.ip.clone();
             headers.dendrite_port = d.port.map(|p| p.to_string());
             headers.dendrite_version = d.version.map(|v| v.to_string());
@@ -941,7 +1135,7 @@ impl Synapse {
         if let Some(ref a) = self.axon {
             headers.axon_status_code = a.status_code.map(|c| c.to_string());
             headers.axon_status_message = a.status_message.clone();
-            headers.axon_process_time = a.process_time.map(|t| t.to_string());
+            headers.axon_process_time = a.process_time.map(python_float_repr);
             headers.axon_ip = a.ip.clone();
             headers.axon_port = a.port.map(|p| p.to_string());
             headers.axon_version = a.version.map(|v| v.to_string());
@@ -1390,6 +1584,119 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // =========================================================================
+    // Typed Synapse Conversion Tests
+    // =========================================================================
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct ScoreSynapse {
+        #[serde(flatten)]
+        base: Synapse,
+        query: String,
+        scores: Vec<serde_json::Value>,
+        tags: Vec<serde_json::Value>,
+        result: Option<String>,
+    }
+
+    impl SynapseType for ScoreSynapse {
+        fn name() -> &'static str {
+            "ScoreSynapse"
+        }
+
+        fn required_hash_fields() -> Vec<&'static str> {
+            vec!["query", "scores", "tags"]
+        }
+    }
+
+    fn score_synapse() -> ScoreSynapse {
+        ScoreSynapse {
+            base: Synapse::new().with_timeout(5.0),
+            query: "What's up?".to_string(),
+            scores: vec![
+                serde_json::json!(0.5),
+                serde_json::json!(1.0),
+                serde_json::json!(1e-5),
+                serde_json::json!(3),
+            ],
+            tags: vec![
+                serde_json::json!("it's"),
+                serde_json::json!("b\n"),
+                serde_json::Value::Null,
+                serde_json::json!(true),
+            ],
+            result: None,
+        }
+    }
+
+    #[test]
+    fn test_typed_synapse_to_synapse() {
+        let synapse = score_synapse().to_synapse().expect("Conversion failed");
+
+        assert_eq!(synapse.name.as_deref(), Some("ScoreSynapse"));
+        assert_eq!(synapse.timeout, Some(5.0));
+        assert_eq!(
+            synapse.get_field("query"),
+            Some(&serde_json::json!("What's up?"))
+        );
+        assert_eq!(synapse.get_field("result"), Some(&serde_json::Value::Null));
+        assert_eq!(
+            synapse.computed_body_hash.as_deref(),
+            Some(score_synapse().body_hash().as_str())
+        );
+    }
+
+    #[test]
+    fn test_typed_synapse_from_synapse() {
+        let mut synapse = Synapse::new().with_name("ScoreSynapse").with_timeout(7.0);
+        synapse.set_field("query", serde_json::json!("hello"));
+        synapse.set_field("scores", serde_json::json!([1, 2]));
+        synapse.set_field("tags", serde_json::json!([]));
+        synapse.set_field("result", serde_json::json!("done"));
+
+        let typed = ScoreSynapse::from_synapse(&synapse).expect("Conversion failed");
+        assert_eq!(typed.query, "hello");
+        assert_eq!(typed.result.as_deref(), Some("done"));
+        assert_eq!(typed.base.timeout, Some(7.0));
+        assert!(typed.base.extra.is_empty());
+
+        // Missing required fields are reported
+        let incomplete = Synapse::new().with_name("ScoreSynapse");
+        assert!(ScoreSynapse::from_synapse(&incomplete).is_err());
+    }
+
+    #[test]
+    fn test_body_hash_matches_python() {
+        // Values computed with the Python SDK's `Synapse.body_hash` for the
+        // same fields and `required_hash_fields = ["query", "scores", "tags"]`
+        assert_eq!(
+            score_synapse().body_hash(),
+            "88375e5d0297ea9f38e8fcd183765166720859bd55532600dc59cac2e7595f2c"
+        );
+
+        // No required fields hashes the empty string
+        assert_eq!(
+            Synapse::new().body_hash(),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+    }
+
+    #[test]
+    fn test_python_str() {
+        assert_eq!(python_str(&serde_json::json!("it's")), "it's");
+        assert_eq!(
+            python_str(&serde_json::json!(["it's", "a\"b'"])),
+            r#"["it's", 'a"b\'']"#
+        );
+        assert_eq!(
+            python_str(&serde_json::json!([1.0, 1e16, 1e-5, 0.0001])),
+            "[1.0, 1e+16, 1e-05, 0.0001]"
+        );
+        assert_eq!(
+            python_str(&serde_json::json!({"a": [null, false]})),
+            "{'a': [None, False]}"
+        );
+    }
+
     // =========================================================================
     // Constant Time Compare Tests
     // =========================================================================
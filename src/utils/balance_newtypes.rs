@@ -24,9 +24,11 @@
 
 use crate::core::constants::RAOPERTAO;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::iter::Sum;
 use std::ops::{Add, Div, Mul, Sub};
+use thiserror::Error;
 
 /// Maximum exact integer value in f64 (2^53)
 const F64_MAX_EXACT_INT: u128 = 9_007_199_254_740_992; // 2^53
@@ -138,11 +140,7 @@ impl Rao {
 
     /// Safe division (returns `Rao::ZERO` on divide-by-zero).
     pub fn safe_div(self, divisor: u128) -> Self {
-        if divisor == 0 {
-            Self::ZERO
-        } else {
-            Self(self.0 / divisor)
-        }
+        self.0.checked_div(divisor).map_or(Self::ZERO, Self)
     }
 
     /// Validate that this amount is suitable for a transfer or stake extrinsic.
@@ -624,37 +622,113 @@ impl Balance {
         self.netuid > 0
     }
 
-    /// Saturating addition (requires same netuid or one is TAO)
+    /// Add two balances of the same unit, saturating on overflow
+    ///
+    /// # Errors
+    /// [`BalanceUnitError`] if the operands belong to different subnets.
+    pub fn checked_add_same_unit(self, other: Self) -> Result<Self, BalanceUnitError> {
+        self.ensure_same_unit(&other)?;
+        let amount = self.amount.saturating_add(other.amount);
+        Ok(Self::from_rao_with_netuid(amount.as_u128(), self.netuid))
+    }
+
+    /// Subtract two balances of the same unit, saturating at zero
+    ///
+    /// # Errors
+    /// [`BalanceUnitError`] if the operands belong to different subnets.
+    pub fn checked_sub_same_unit(self, other: Self) -> Result<Self, BalanceUnitError> {
+        self.ensure_same_unit(&other)?;
+        let amount = self.amount.saturating_sub(other.amount);
+        Ok(Self::from_rao_with_netuid(amount.as_u128(), self.netuid))
+    }
+
+    /// Saturating addition of two balances of the same unit
+    ///
+    /// Mixing units is a programming error: it panics in debug builds and
+    /// logs an error and returns `self` unchanged in release builds. Use
+    /// [`Balance::checked_add_same_unit`] to handle the mismatch instead.
     pub fn saturating_add(self, other: Self) -> Self {
-        let result_netuid = if self.netuid == 0 {
-            other.netuid
+        self.checked_add_same_unit(other)
+            .unwrap_or_else(|e| self.unit_mismatch("add", e))
+    }
+
+    /// Saturating subtraction of two balances of the same unit
+    ///
+    /// Mixing units panics in debug builds and logs an error and returns
+    /// `self` unchanged in release builds, like [`Balance::saturating_add`].
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.checked_sub_same_unit(other)
+            .unwrap_or_else(|e| self.unit_mismatch("subtract", e))
+    }
+
+    /// Convert to a TAO balance at `price` TAO per unit
+    ///
+    /// TAO balances are returned unchanged. Negative or non-finite prices
+    /// yield zero.
+    pub fn to_tao_value(&self, price: f64) -> Balance {
+        if self.is_tao() {
+            return *self;
+        }
+        if !price.is_finite() || price <= 0.0 {
+            return Self::ZERO_TAO;
+        }
+        let rao = self.as_rao() as f64 * price;
+        if rao >= u128::MAX as f64 {
+            Self::from_rao(u128::MAX)
         } else {
-            self.netuid
-        };
-        let amount = self.amount.saturating_add(other.amount);
-        Self {
-            amount,
-            netuid: result_netuid,
-            rao: amount.as_u128(),
+            Self::from_rao(rao as u128)
         }
     }
 
-    /// Saturating subtraction (requires same netuid or one is TAO)
-    pub fn saturating_sub(self, other: Self) -> Self {
-        let result_netuid = if self.netuid == 0 {
-            other.netuid
+    /// Sum balances per unit, keyed by netuid
+    pub fn sum_by_netuid<I>(balances: I) -> BTreeMap<u16, Balance>
+    where
+        I: IntoIterator<Item = Balance>,
+    {
+        let mut totals: BTreeMap<u16, Balance> = BTreeMap::new();
+        for balance in balances {
+            let total = totals
+                .entry(balance.netuid)
+                .or_insert_with(|| Self::from_rao_with_netuid(0, balance.netuid));
+            *total = total.saturating_add(balance);
+        }
+        totals
+    }
+
+    fn ensure_same_unit(&self, other: &Self) -> Result<(), BalanceUnitError> {
+        if self.netuid == other.netuid {
+            Ok(())
         } else {
-            self.netuid
-        };
-        let amount = self.amount.saturating_sub(other.amount);
-        Self {
-            amount,
-            netuid: result_netuid,
-            rao: amount.as_u128(),
+            Err(BalanceUnitError {
+                lhs: self.netuid,
+                rhs: other.netuid,
+            })
+        }
+    }
+
+    fn unit_mismatch(self, op: &str, err: BalanceUnitError) -> Self {
+        if cfg!(debug_assertions) {
+            panic!("cannot {} balances: {}", op, err);
         }
+        tracing::error!("cannot {} balances: {}; keeping left operand", op, err);
+        self
     }
 }
 
+/// Arithmetic attempted on balances of different subnets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error(
+    "unit mismatch: {} (netuid {lhs}) vs {} (netuid {rhs})",
+    get_unit_symbol(*.lhs),
+    get_unit_symbol(*.rhs)
+)]
+pub struct BalanceUnitError {
+    /// Netuid of the left operand
+    pub lhs: u16,
+    /// Netuid of the right operand
+    pub rhs: u16,
+}
+
 impl Default for Balance {
     fn default() -> Self {
         Self::ZERO_TAO
@@ -672,6 +746,7 @@ impl fmt::Display for Balance {
     }
 }
 
+/// Same-unit addition; see [`Balance::saturating_add`] for mixed units
 impl Add for Balance {
     type Output = Self;
     fn add(self, other: Self) -> Self {
@@ -679,6 +754,7 @@ impl Add for Balance {
     }
 }
 
+/// Same-unit subtraction; see [`Balance::saturating_sub`] for mixed units
 impl Sub for Balance {
     type Output = Self;
     fn sub(self, other: Self) -> Self {
@@ -1096,4 +1172,66 @@ mod tests {
                                           // This might or might not be lossless depending on exact value
         let _ = large_tao.is_lossless_roundtrip();
     }
+
+    #[test]
+    fn test_balance_same_unit_arithmetic() {
+        let a = Balance::from_rao_with_netuid(300, 3);
+        let b = Balance::from_rao_with_netuid(100, 3);
+        assert_eq!(a + b, Balance::from_rao_with_netuid(400, 3));
+        assert_eq!(b - a, Balance::from_rao_with_netuid(0, 3));
+        assert_eq!(
+            a.checked_sub_same_unit(b),
+            Ok(Balance::from_rao_with_netuid(200, 3))
+        );
+    }
+
+    #[test]
+    fn test_balance_mixed_unit_checked_errors() {
+        let alpha3 = Balance::from_rao_with_netuid(300, 3);
+        let alpha7 = Balance::from_rao_with_netuid(700, 7);
+        let err = BalanceUnitError { lhs: 3, rhs: 7 };
+        assert_eq!(alpha3.checked_add_same_unit(alpha7), Err(err));
+        assert_eq!(alpha3.checked_sub_same_unit(alpha7), Err(err));
+        assert_eq!(
+            Balance::from_rao(1).checked_add_same_unit(alpha3),
+            Err(BalanceUnitError { lhs: 0, rhs: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "unit mismatch")]
+    fn test_balance_mixed_unit_add_panics_in_debug() {
+        let _ = Balance::from_rao_with_netuid(300, 3) + Balance::from_rao_with_netuid(700, 7);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "unit mismatch")]
+    fn test_balance_mixed_unit_sub_panics_in_debug() {
+        let _ = Balance::from_rao(300) - Balance::from_rao_with_netuid(100, 1);
+    }
+
+    #[test]
+    fn test_balance_to_tao_value() {
+        let alpha = Balance::from_tao_with_netuid(10.0, 5);
+        assert_eq!(alpha.to_tao_value(0.5), Balance::from_tao(5.0));
+        assert_eq!(alpha.to_tao_value(-1.0), Balance::ZERO_TAO);
+        assert_eq!(alpha.to_tao_value(f64::NAN), Balance::ZERO_TAO);
+
+        let tao = Balance::from_tao(2.0);
+        assert_eq!(tao.to_tao_value(0.5), tao);
+    }
+
+    #[test]
+    fn test_balance_sum_by_netuid() {
+        let totals = Balance::sum_by_netuid([
+            Balance::from_rao_with_netuid(100, 3),
+            Balance::from_rao_with_netuid(700, 7),
+            Balance::from_rao_with_netuid(50, 3),
+        ]);
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[&3], Balance::from_rao_with_netuid(150, 3));
+        assert_eq!(totals[&7], Balance::from_rao_with_netuid(700, 7));
+    }
 }
@@ -4,6 +4,7 @@
 //! - Commit-reveal mechanism for weights
 //! - Salt generation for cryptographic operations
 //! - Blake2b hashing (used by Subtensor)
+//! - Signature verification against SS58 addresses
 //!
 //! # SR25519 Signature Compatibility
 //!
@@ -15,10 +16,17 @@
 //! This format is compatible with the Python Bittensor SDK's use of substrate-interface
 //! and the sp_core::sr25519 implementation in Substrate.
 
+use crate::utils::ss58::decode_ss58;
 use anyhow::Result;
 use hex;
 use parity_scale_codec::Encode;
-use sp_core::blake2_256;
+use sp_core::{blake2_256, ecdsa, ed25519, sr25519, Pair};
+
+/// Prefix polkadot-js `signRaw` adds to raw messages before signing
+pub const BYTES_WRAP_PREFIX: &[u8] = b"<Bytes>";
+
+/// Suffix polkadot-js `signRaw` adds to raw messages before signing
+pub const BYTES_WRAP_SUFFIX: &[u8] = b"</Bytes>";
 
 /// Generate a commit hash for weights using Blake2b (legacy format)
 /// Uses u16 format for weights to match Subtensor's expected format
@@ -219,6 +227,109 @@ pub fn signature_format_info(signature: &[u8]) -> String {
     )
 }
 
+/// Check whether a message is wrapped in `<Bytes>...</Bytes>`
+pub fn is_wrapped_bytes(message: &[u8]) -> bool {
+    message.len() >= BYTES_WRAP_PREFIX.len() + BYTES_WRAP_SUFFIX.len()
+        && message.starts_with(BYTES_WRAP_PREFIX)
+        && message.ends_with(BYTES_WRAP_SUFFIX)
+}
+
+/// Wrap a message in `<Bytes>...</Bytes>` as polkadot-js `signRaw` does.
+///
+/// Messages that are already wrapped are returned unchanged.
+pub fn wrap_bytes(message: &[u8]) -> Vec<u8> {
+    if is_wrapped_bytes(message) {
+        return message.to_vec();
+    }
+    [BYTES_WRAP_PREFIX, message, BYTES_WRAP_SUFFIX].concat()
+}
+
+/// Strip a `<Bytes>...</Bytes>` wrapper from a message, if present.
+pub fn unwrap_bytes(message: &[u8]) -> &[u8] {
+    if is_wrapped_bytes(message) {
+        &message[BYTES_WRAP_PREFIX.len()..message.len() - BYTES_WRAP_SUFFIX.len()]
+    } else {
+        message
+    }
+}
+
+/// Verify a signature made by the account at an SS58 address.
+///
+/// Accepts raw 64-byte SR25519 or ED25519 signatures, 65-byte ECDSA
+/// signatures, and signatures prefixed with their `MultiSignature` scheme
+/// byte (0 = ED25519, 1 = SR25519, 2 = ECDSA). The message is checked both
+/// as given and with the `<Bytes>...</Bytes>` wrapping polkadot-js `signRaw`
+/// applies, so signatures from browser wallets verify.
+///
+/// # Arguments
+/// * `ss58_address` - Address of the signer
+/// * `message` - The signed message
+/// * `signature` - The signature bytes
+///
+/// # Returns
+/// Whether the signature is valid, or an error if the address or signature
+/// format cannot be decoded.
+///
+/// # Example
+/// ```
+/// use bittensor_rs::utils::crypto::verify_signature;
+/// use bittensor_rs::wallet::Keypair;
+///
+/// let keypair = Keypair::from_uri("//Alice").unwrap();
+/// let signature = keypair.sign_message(b"handshake", true);
+/// assert!(verify_signature(keypair.ss58_address(), b"handshake", &signature).unwrap());
+/// ```
+pub fn verify_signature(ss58_address: &str, message: &[u8], signature: &[u8]) -> Result<bool> {
+    let account: [u8; 32] = decode_ss58(ss58_address)?.into();
+
+    let unwrapped = unwrap_bytes(message);
+    let wrapped = wrap_bytes(message);
+    let candidates = [unwrapped, wrapped.as_slice()];
+
+    let verify_sr25519 = |sig: &[u8]| -> bool {
+        let Ok(sig) = sr25519::Signature::try_from(sig) else {
+            return false;
+        };
+        let public = sr25519::Public::from_raw(account);
+        candidates
+            .iter()
+            .any(|msg| sr25519::Pair::verify(&sig, msg, &public))
+    };
+    let verify_ed25519 = |sig: &[u8]| -> bool {
+        let Ok(sig) = ed25519::Signature::try_from(sig) else {
+            return false;
+        };
+        let public = ed25519::Public::from_raw(account);
+        candidates
+            .iter()
+            .any(|msg| ed25519::Pair::verify(&sig, msg, &public))
+    };
+    // ECDSA accounts are the blake2 hash of the compressed public key, so the
+    // key is recovered from the signature
+    let verify_ecdsa = |sig: &[u8]| -> bool {
+        let Ok(sig) = ecdsa::Signature::try_from(sig) else {
+            return false;
+        };
+        candidates.iter().any(|msg| {
+            sig.recover(msg)
+                .is_some_and(|public| blake2_256(&public.0) == account)
+        })
+    };
+
+    // A 65-byte signature is either scheme-prefixed or a raw ECDSA signature
+    match (signature.len(), signature.first()) {
+        (64, _) => Ok(verify_sr25519(signature) || verify_ed25519(signature)),
+        (65, Some(0x00)) => Ok(verify_ed25519(&signature[1..]) || verify_ecdsa(signature)),
+        (65, Some(0x01)) => Ok(verify_sr25519(&signature[1..]) || verify_ecdsa(signature)),
+        (65, _) => Ok(verify_ecdsa(signature)),
+        (66, Some(0x02)) => Ok(verify_ecdsa(&signature[1..])),
+        _ => Err(anyhow::anyhow!(
+            "Unsupported signature length: {} bytes",
+            signature.len()
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -388,4 +499,64 @@ mod tests {
         let decoded32 = hex_to_commit_hash_32(&hex_str32).unwrap();
         assert_eq!(hash32, decoded32);
     }
+
+    #[test]
+    fn test_bytes_wrapping() {
+        assert_eq!(wrap_bytes(b"hello"), b"<Bytes>hello</Bytes>".to_vec());
+        assert_eq!(
+            wrap_bytes(b"<Bytes>hello</Bytes>"),
+            b"<Bytes>hello</Bytes>".to_vec()
+        );
+        assert_eq!(unwrap_bytes(b"<Bytes>hello</Bytes>"), b"hello");
+        assert_eq!(unwrap_bytes(b"hello"), b"hello");
+        assert_eq!(unwrap_bytes(b"<Bytes></Bytes>"), b"");
+        assert!(!is_wrapped_bytes(b"<Bytes>"));
+    }
+
+    #[test]
+    fn test_verify_signature_schemes() {
+        use crate::wallet::{KeyScheme, Keypair};
+
+        let message = b"axon handshake";
+        for scheme in [KeyScheme::Sr25519, KeyScheme::Ed25519, KeyScheme::Ecdsa] {
+            let keypair = Keypair::from_uri_with_scheme("//Alice", scheme).unwrap();
+            let address = keypair.ss58_address();
+
+            // Plain and polkadot-js wrapped signatures
+            for wrap in [false, true] {
+                let signature = keypair.sign_message(message, wrap);
+                assert!(verify_signature(address, message, &signature).unwrap());
+                assert!(verify_signature(address, &wrap_bytes(message), &signature).unwrap());
+                assert!(!verify_signature(address, b"other message", &signature).unwrap());
+
+                // MultiSignature encoding with a scheme byte
+                let scheme_byte = match scheme {
+                    KeyScheme::Ed25519 => 0u8,
+                    KeyScheme::Sr25519 => 1,
+                    KeyScheme::Ecdsa => 2,
+                };
+                let prefixed = [&[scheme_byte][..], &signature].concat();
+                assert!(verify_signature(address, message, &prefixed).unwrap());
+            }
+
+            // Signatures from another account do not verify
+            let bob = Keypair::from_uri_with_scheme("//Bob", scheme).unwrap();
+            let signature = bob.sign_message(message, true);
+            assert!(!verify_signature(address, message, &signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify_signature_errors() {
+        let keypair = crate::wallet::Keypair::from_uri("//Alice").unwrap();
+        let signature = keypair.sign(b"message");
+
+        assert!(verify_signature("not an address", b"message", &signature).is_err());
+        assert!(verify_signature(keypair.ss58_address(), b"message", &signature[..32]).is_err());
+        assert!(verify_signature(keypair.ss58_address(), b"message", &[]).is_err());
+
+        // A wrong scheme byte does not verify
+        let prefixed = [&[0u8][..], &signature].concat();
+        assert!(!verify_signature(keypair.ss58_address(), b"message", &prefixed).unwrap());
+    }
 }
@@ -0,0 +1,355 @@
+//! Generic decoders for `Option<T>`, `Vec<T>`, tuples and maps
+//!
+//! Each combinator takes the decoder for the inner type, so a new storage
+//! shape is assembled rather than hand-matched, e.g. a
+//! `BoundedVec<(u64, AccountId32), _>` is
+//! `decode_vec(v, |e| decode_tuple2(e, decode_u64, decode_account_id32))`.
+//! Failures are [`ValueDecodeError`]s naming the path through the value
+//! tree, e.g. `value[3].1: Failed to decode u64 from value`.
+
+use anyhow::Result;
+use subxt::dynamic::Value;
+use subxt::ext::scale_value::{Composite, ValueDef};
+use thiserror::Error;
+
+/// A dynamic storage value failed to decode
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("Failed to decode value{path}: {reason}")]
+pub struct ValueDecodeError {
+    /// Path below the root value, e.g. `[3].1` or `.Some[0]`
+    pub path: String,
+    /// Error from the innermost decoder
+    pub reason: String,
+}
+
+impl ValueDecodeError {
+    fn new(reason: impl Into<String>) -> Self {
+        Self {
+            path: String::new(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Prefix `segment` to the path of an error from a nested decoder
+fn nested<T>(segment: &str, result: Result<T>) -> Result<T> {
+    result.map_err(|e| {
+        let mut error = e
+            .downcast::<ValueDecodeError>()
+            .unwrap_or_else(|e| ValueDecodeError::new(format!("{:#}", e)));
+        error.path.insert_str(0, segment);
+        error.into()
+    })
+}
+
+fn kind(value: &Value) -> String {
+    match &value.value {
+        ValueDef::Composite(_) => "composite".to_string(),
+        ValueDef::Variant(variant) => format!("variant {}", variant.name),
+        ValueDef::BitSequence(_) => "bit sequence".to_string(),
+        ValueDef::Primitive(_) => "primitive".to_string(),
+    }
+}
+
+fn composite_values(composite: &Composite<()>) -> Vec<&Value> {
+    match composite {
+        Composite::Named(fields) => fields.iter().map(|(_, v)| v).collect(),
+        Composite::Unnamed(values) => values.iter().collect(),
+    }
+}
+
+/// Fields of a struct, tuple or enum variant
+fn fields(value: &Value) -> Result<Vec<&Value>> {
+    match &value.value {
+        ValueDef::Composite(composite) => Ok(composite_values(composite)),
+        ValueDef::Variant(variant) => Ok(composite_values(&variant.values)),
+        _ => Err(ValueDecodeError::new(format!("expected fields, found {}", kind(value))).into()),
+    }
+}
+
+/// Decode an `Option<T>`
+///
+/// `None`/`Some` variants are unwrapped; any other value is an
+/// already-unwrapped `Some`, as storage returns for `OptionQuery` entries.
+pub fn decode_option<T, F>(value: &Value, decoder: F) -> Result<Option<T>>
+where
+    F: FnOnce(&Value) -> Result<T>,
+{
+    if let ValueDef::Variant(variant) = &value.value {
+        match variant.name.as_str() {
+            "None" => return Ok(None),
+            "Some" => {
+                let inner = composite_values(&variant.values);
+                let [inner] = inner.as_slice() else {
+                    return Err(ValueDecodeError {
+                        path: ".Some".to_string(),
+                        reason: format!("expected 1 field, found {}", inner.len()),
+                    }
+                    .into());
+                };
+                return nested(".Some", decoder(inner)).map(Some);
+            }
+            _ => {}
+        }
+    }
+    decoder(value).map(Some)
+}
+
+/// Decode a `Vec<T>` or `BoundedVec<T, N>`
+///
+/// Fails on the first element that does not decode. A `BoundedVec` is a
+/// composite wrapping the sequence; a single composite element is read as
+/// that wrapper when its contents decode, otherwise as a one-element vec.
+pub fn decode_vec<T, F>(value: &Value, decoder: F) -> Result<Vec<T>>
+where
+    F: Fn(&Value) -> Result<T>,
+{
+    let ValueDef::Composite(composite) = &value.value else {
+        return Err(
+            ValueDecodeError::new(format!("expected a sequence, found {}", kind(value))).into(),
+        );
+    };
+    let items = composite_values(composite);
+    if let [wrapped] = items.as_slice() {
+        if let ValueDef::Composite(inner) = &wrapped.value {
+            if let Ok(decoded) = decode_items(composite_values(inner), &decoder) {
+                return Ok(decoded);
+            }
+        }
+    }
+    decode_items(items, &decoder)
+}
+
+fn decode_items<T, F>(items: Vec<&Value>, decoder: &F) -> Result<Vec<T>>
+where
+    F: Fn(&Value) -> Result<T>,
+{
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| nested(&format!("[{}]", index), decoder(item)))
+        .collect()
+}
+
+fn tuple_fields<const N: usize>(value: &Value) -> Result<[&Value; N]> {
+    let values = fields(value)?;
+    let found = values.len();
+    values.try_into().map_err(|_| {
+        ValueDecodeError::new(format!("expected {} fields, found {}", N, found)).into()
+    })
+}
+
+/// Decode a 2-tuple (or 2-field struct)
+pub fn decode_tuple2<A, B, FA, FB>(value: &Value, decode_a: FA, decode_b: FB) -> Result<(A, B)>
+where
+    FA: FnOnce(&Value) -> Result<A>,
+    FB: FnOnce(&Value) -> Result<B>,
+{
+    let [a, b] = tuple_fields(value)?;
+    Ok((nested(".0", decode_a(a))?, nested(".1", decode_b(b))?))
+}
+
+/// Decode a 3-tuple (or 3-field struct)
+pub fn decode_tuple3<A, B, C, FA, FB, FC>(
+    value: &Value,
+    decode_a: FA,
+    decode_b: FB,
+    decode_c: FC,
+) -> Result<(A, B, C)>
+where
+    FA: FnOnce(&Value) -> Result<A>,
+    FB: FnOnce(&Value) -> Result<B>,
+    FC: FnOnce(&Value) -> Result<C>,
+{
+    let [a, b, c] = tuple_fields(value)?;
+    Ok((
+        nested(".0", decode_a(a))?,
+        nested(".1", decode_b(b))?,
+        nested(".2", decode_c(c))?,
+    ))
+}
+
+/// Decode the `(key, value)` entries of a `BTreeMap<K, V>`
+///
+/// Maps are encoded as a sequence of pairs; entries keep the chain's order.
+pub fn decode_map_entries<K, V, FK, FV>(
+    value: &Value,
+    decode_key: FK,
+    decode_value: FV,
+) -> Result<Vec<(K, V)>>
+where
+    FK: Fn(&Value) -> Result<K>,
+    FV: Fn(&Value) -> Result<V>,
+{
+    decode_vec(value, |entry| {
+        decode_tuple2(entry, &decode_key, &decode_value)
+    })
+}
+
+/// Decode a storage read, falling back to `default` when the entry is absent
+///
+/// Covers `ValueQuery` entries that were never written, which read as
+/// `None` but hold the runtime default.
+pub fn decode_stored_or<T, F>(value: Option<Value>, default: T, decoder: F) -> Result<T>
+where
+    F: FnOnce(&Value) -> Result<T>,
+{
+    match value {
+        Some(value) => decoder(&value),
+        None => Ok(default),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::decoders::primitive::{decode_account_id32, decode_u16, decode_u64};
+    use sp_core::crypto::AccountId32;
+
+    fn some(inner: Value) -> Value {
+        Value::unnamed_variant("Some", [inner])
+    }
+
+    fn none() -> Value {
+        Value::unnamed_variant("None", [])
+    }
+
+    fn error_of<T: std::fmt::Debug>(result: Result<T>) -> ValueDecodeError {
+        result
+            .unwrap_err()
+            .downcast::<ValueDecodeError>()
+            .expect("path-aware error")
+    }
+
+    #[test]
+    fn test_decode_option() {
+        assert_eq!(decode_option(&none(), decode_u16).unwrap(), None);
+        assert_eq!(
+            decode_option(&some(Value::u128(7)), decode_u16).unwrap(),
+            Some(7)
+        );
+        // Storage hands back OptionQuery values already unwrapped
+        assert_eq!(decode_option(&Value::u128(7), decode_u16).unwrap(), Some(7));
+    }
+
+    #[test]
+    fn test_decode_option_error_path() {
+        let value = some(Value::unnamed_composite([
+            Value::u128(1),
+            Value::bool(true),
+        ]));
+        let error = error_of(decode_option(&value, |v| {
+            decode_tuple2(v, decode_u16, decode_u16)
+        }));
+        assert_eq!(error.path, ".Some.1");
+    }
+
+    #[test]
+    fn test_decode_vec() {
+        let empty = Value::unnamed_composite([]);
+        assert!(decode_vec(&empty, decode_u16).unwrap().is_empty());
+
+        let values = Value::unnamed_composite([Value::u128(1), Value::u128(2), Value::u128(3)]);
+        assert_eq!(decode_vec(&values, decode_u16).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_decode_bounded_vec() {
+        let bounded =
+            Value::unnamed_composite([Value::unnamed_composite([Value::u128(4), Value::u128(5)])]);
+        assert_eq!(decode_vec(&bounded, decode_u16).unwrap(), vec![4, 5]);
+
+        let empty = Value::unnamed_composite([Value::unnamed_composite([])]);
+        assert!(decode_vec(&empty, decode_u16).unwrap().is_empty());
+
+        // A single pair is a one-element vec, not a wrapper around two items
+        let pairs =
+            Value::unnamed_composite([Value::unnamed_composite([Value::u128(4), Value::u128(5)])]);
+        assert_eq!(
+            decode_vec(&pairs, |e| decode_tuple2(e, decode_u16, decode_u16)).unwrap(),
+            vec![(4, 5)]
+        );
+    }
+
+    #[test]
+    fn test_decode_vec_error_path() {
+        let values = Value::unnamed_composite([
+            Value::unnamed_composite([Value::u128(1), Value::u128(2)]),
+            Value::unnamed_composite([Value::u128(3)]),
+        ]);
+        let error = error_of(decode_vec(&values, |e| {
+            decode_tuple2(e, decode_u16, decode_u16)
+        }));
+        assert_eq!(error.path, "[1]");
+        assert_eq!(error.reason, "expected 2 fields, found 1");
+
+        let error = error_of(decode_vec(&Value::u128(1), decode_u16));
+        assert_eq!(
+            error.to_string(),
+            "Failed to decode value: expected a sequence, found primitive"
+        );
+    }
+
+    #[test]
+    fn test_decode_nested_tuples() {
+        let account = AccountId32::from([9u8; 32]);
+        let value = Value::unnamed_composite([
+            Value::unnamed_composite([Value::unnamed_composite([
+                Value::u128(u64::MAX as u128),
+                Value::from_bytes(account.clone()),
+            ])]),
+            Value::u128(1200),
+        ]);
+        let (children, cooldown) = decode_tuple2(
+            &value,
+            |v| decode_vec(v, |e| decode_tuple2(e, decode_u64, decode_account_id32)),
+            decode_u64,
+        )
+        .unwrap();
+        assert_eq!(children, vec![(u64::MAX, account)]);
+        assert_eq!(cooldown, 1200);
+
+        let triple = Value::unnamed_composite([
+            Value::u128(1),
+            Value::unnamed_composite([Value::u128(2), Value::u128(3)]),
+            none(),
+        ]);
+        let (a, (b, c), d) = decode_tuple3(
+            &triple,
+            decode_u16,
+            |v| decode_tuple2(v, decode_u16, decode_u16),
+            |v| decode_option(v, decode_u16),
+        )
+        .unwrap();
+        assert_eq!((a, b, c, d), (1, 2, 3, None));
+    }
+
+    #[test]
+    fn test_decode_map_entries() {
+        let map = Value::unnamed_composite([
+            Value::unnamed_composite([Value::u128(1), some(Value::u128(10))]),
+            Value::unnamed_composite([Value::u128(2), none()]),
+        ]);
+        let entries =
+            decode_map_entries(&map, decode_u16, |v| decode_option(v, decode_u64)).unwrap();
+        assert_eq!(entries, vec![(1, Some(10)), (2, None)]);
+
+        let bad = Value::unnamed_composite([
+            Value::unnamed_composite([Value::u128(1), some(Value::u128(10))]),
+            Value::unnamed_composite([Value::u128(2), some(Value::bool(true))]),
+        ]);
+        let error = error_of(decode_map_entries(&bad, decode_u16, |v| {
+            decode_option(v, decode_u64)
+        }));
+        assert_eq!(error.path, "[1].1.Some");
+    }
+
+    #[test]
+    fn test_decode_stored_or() {
+        assert_eq!(decode_stored_or(None, 360, decode_u16).unwrap(), 360);
+        assert_eq!(
+            decode_stored_or(Some(Value::u128(99)), 360, decode_u16).unwrap(),
+            99
+        );
+    }
+}
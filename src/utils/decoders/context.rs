@@ -0,0 +1,220 @@
+//! Field-aware SCALE decoding for runtime API responses
+//!
+//! Runtime API structs are decoded one field at a time through a
+//! [`DecodeContext`], which tracks the field path and byte offset so a
+//! failure reads e.g. `NeuronInfoLite[3].axon_info.port at byte 132`.
+//!
+//! [`decode_runtime`] ignores bytes left over after the value, so fields a
+//! newer runtime appends to a single struct (`Option<T>` responses) are
+//! skipped. Appended fields inside a `Vec<T>` response shift every following
+//! element and still fail, naming the first field that no longer fits.
+
+use parity_scale_codec::{Compact, Decode};
+use thiserror::Error;
+
+/// A runtime value failed to decode
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("Failed to decode {path} at byte {offset}: {reason}")]
+pub struct ScaleDecodeError {
+    /// Field path, e.g. `NeuronInfoLite.axon_info.port`
+    pub path: String,
+    /// Offset of the field within the response
+    pub offset: usize,
+    /// Underlying codec error
+    pub reason: String,
+}
+
+/// Cursor over SCALE bytes that remembers where it is
+pub struct DecodeContext<'a> {
+    input: &'a [u8],
+    len: usize,
+    path: Vec<String>,
+}
+
+impl<'a> DecodeContext<'a> {
+    /// Start decoding `bytes` as the type called `root`
+    pub fn new(root: &str, bytes: &'a [u8]) -> Self {
+        Self {
+            input: bytes,
+            len: bytes.len(),
+            path: vec![root.to_string()],
+        }
+    }
+
+    /// Bytes consumed so far
+    pub fn offset(&self) -> usize {
+        self.len - self.input.len()
+    }
+
+    /// Bytes not yet consumed
+    pub fn remaining(&self) -> usize {
+        self.input.len()
+    }
+
+    /// Decode a plain SCALE field
+    pub fn field<T: Decode>(&mut self, name: &str) -> Result<T, ScaleDecodeError> {
+        self.path.push(name.to_string());
+        let result = self.raw();
+        self.path.pop();
+        result
+    }
+
+    /// Decode a runtime struct field, extending the path into it
+    pub fn nested<T: ScaleDecode>(&mut self, name: &str) -> Result<T, ScaleDecodeError> {
+        self.path.push(name.to_string());
+        let result = T::decode_with(self);
+        self.path.pop();
+        result
+    }
+
+    fn raw<T: Decode>(&mut self) -> Result<T, ScaleDecodeError> {
+        let offset = self.offset();
+        T::decode(&mut self.input).map_err(|e| self.error(offset, e.to_string()))
+    }
+
+    fn error(&self, offset: usize, reason: String) -> ScaleDecodeError {
+        let mut path = String::new();
+        for segment in &self.path {
+            if !path.is_empty() && !segment.starts_with('[') {
+                path.push('.');
+            }
+            path.push_str(segment);
+        }
+        ScaleDecodeError {
+            path,
+            offset,
+            reason,
+        }
+    }
+}
+
+/// A runtime type decoded field by field through a [`DecodeContext`]
+pub trait ScaleDecode: Sized {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError>;
+}
+
+impl<T: ScaleDecode> ScaleDecode for Option<T> {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        let offset = ctx.offset();
+        match ctx.raw::<u8>()? {
+            0 => Ok(None),
+            1 => T::decode_with(ctx).map(Some),
+            tag => Err(ctx.error(offset, format!("invalid Option tag {}", tag))),
+        }
+    }
+}
+
+impl<T: ScaleDecode> ScaleDecode for Vec<T> {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        let Compact(len) = ctx.raw::<Compact<u32>>()?;
+        // Every element takes at least one byte
+        if len as usize > ctx.remaining() {
+            let offset = ctx.offset();
+            return Err(ctx.error(
+                offset,
+                format!("{} elements do not fit in {} bytes", len, ctx.remaining()),
+            ));
+        }
+        let mut items = Vec::with_capacity(len as usize);
+        for index in 0..len {
+            ctx.path.push(format!("[{}]", index));
+            let item = T::decode_with(ctx);
+            ctx.path.pop();
+            items.push(item?);
+        }
+        Ok(items)
+    }
+}
+
+/// Decode a runtime API response, ignoring trailing bytes
+///
+/// `type_name` roots the error path, e.g. `NeuronInfoLite`.
+pub fn decode_runtime<T: ScaleDecode>(
+    type_name: &str,
+    bytes: &[u8],
+) -> Result<T, ScaleDecodeError> {
+    let mut ctx = DecodeContext::new(type_name, bytes);
+    let value = T::decode_with(&mut ctx)?;
+    if ctx.remaining() > 0 {
+        tracing::debug!(
+            "Ignoring {} trailing bytes after {} (newer runtime?)",
+            ctx.remaining(),
+            type_name
+        );
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::Encode;
+
+    #[derive(Debug, PartialEq)]
+    struct Inner {
+        port: u16,
+    }
+
+    impl ScaleDecode for Inner {
+        fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+            Ok(Self {
+                port: ctx.field("port")?,
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Outer {
+        uid: Compact<u16>,
+        inner: Inner,
+    }
+
+    impl ScaleDecode for Outer {
+        fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+            Ok(Self {
+                uid: ctx.field("uid")?,
+                inner: ctx.nested("inner")?,
+            })
+        }
+    }
+
+    fn outer(uid: u16, port: u16) -> Vec<u8> {
+        (Compact(uid), port).encode()
+    }
+
+    #[test]
+    fn test_error_names_field_and_offset() {
+        // uid decodes (1 byte), port is cut short
+        let err = decode_runtime::<Outer>("Outer", &[0x04, 0x01]).unwrap_err();
+        assert_eq!(err.path, "Outer.inner.port");
+        assert_eq!(err.offset, 1);
+        assert!(err
+            .to_string()
+            .starts_with("Failed to decode Outer.inner.port at byte 1"));
+
+        let mut two = vec![0x08];
+        two.extend(outer(1, 8080));
+        two.extend([0x04]);
+        let err = decode_runtime::<Vec<Outer>>("Outer", &two).unwrap_err();
+        assert_eq!(err.path, "Outer[1].inner.port");
+        assert_eq!(err.offset, 5);
+    }
+
+    #[test]
+    fn test_trailing_bytes_ignored() {
+        let mut bytes = Some(()).encode();
+        bytes.extend(outer(7, 8091));
+        bytes.extend([0xde, 0xad]);
+        let value = decode_runtime::<Option<Outer>>("Outer", &bytes).unwrap();
+        assert_eq!(
+            value,
+            Some(Outer {
+                uid: Compact(7),
+                inner: Inner { port: 8091 },
+            })
+        );
+
+        let err = decode_runtime::<Option<Outer>>("Outer", &[2]).unwrap_err();
+        assert_eq!((err.path.as_str(), err.offset), ("Outer", 0));
+    }
+}
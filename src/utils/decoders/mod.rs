@@ -1,15 +1,20 @@
 /// Utilities for decoding Value from subxt storage results
+pub mod combinators;
 pub mod composite;
+pub mod context;
 pub mod fixed;
 pub mod primitive;
+pub mod runtime;
 pub mod utils;
 pub mod vec;
 
+pub use combinators::*;
 pub use composite::*;
+pub use context::{decode_runtime, DecodeContext, ScaleDecode, ScaleDecodeError};
 pub use fixed::*;
 pub use primitive::{
-    decode_account_id32, decode_bool, decode_bytes, decode_i32, decode_option, decode_string,
-    decode_u128, decode_u16, decode_u64, decode_u8,
+    decode_account_id32, decode_bool, decode_bytes, decode_i32, decode_string, decode_u128,
+    decode_u16, decode_u64, decode_u8,
 };
 pub use utils::*;
 pub use vec::*;
@@ -163,35 +163,6 @@ pub fn decode_bytes(value: &Value) -> Result<Vec<u8>> {
     utils::decode_bytes_from_composite(value).map_err(|_| anyhow!("Cannot decode bytes from value"))
 }
 
-/// Decode an Option<T> from a Value
-/// Returns None if value represents None, otherwise attempts to decode as Some(T)
-pub fn decode_option<T, F>(value: &Value, decoder: F) -> Result<Option<T>>
-where
-    F: FnOnce(&Value) -> Result<T>,
-{
-    if let ValueDef::Variant(variant) = &value.value {
-        if variant.name == "None" {
-            return Ok(None);
-        }
-        if variant.name == "Some" {
-            match &variant.values {
-                Composite::Unnamed(values) => {
-                    if let Some(inner) = values.first() {
-                        return decoder(inner).map(Some);
-                    }
-                }
-                Composite::Named(fields) => {
-                    if let Some((_, inner)) = fields.first() {
-                        return decoder(inner).map(Some);
-                    }
-                }
-            }
-        }
-    }
-
-    decoder(value).map(Some)
-}
-
 pub fn decode_account_id32(value: &Value) -> Result<AccountId32> {
     if let Some(bytes) = utils::extract_bytes_from_composite_sequence(value) {
         return Ok(AccountId32::from(bytes));
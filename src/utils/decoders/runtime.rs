@@ -0,0 +1,545 @@
+//! Decoders for runtime API structs
+//!
+//! Layouts match the Finney runtime captured in `metadata/finney.scale`;
+//! golden responses live in `tests/fixtures/runtime`. See
+//! [`context`](super::context) for error paths and trailing-byte handling.
+
+use super::context::{decode_runtime, DecodeContext, ScaleDecode, ScaleDecodeError};
+use super::utils::parse_ip_addr;
+use crate::core::constants::RAOPERTAO;
+use crate::queries::stakes::StakeInfo;
+use crate::types::delegate::DelegateInfoBase;
+use crate::types::{
+    AxonInfo, DelegateInfo, DynamicInfo, NeuronInfoLite, PrometheusInfo, SubnetInfo,
+};
+use crate::utils::balance_newtypes::Rao;
+use crate::utils::ss58::encode_ss58;
+use parity_scale_codec::Compact;
+use sp_core::crypto::AccountId32;
+use std::collections::HashMap;
+
+type NominatorStakes = Vec<(Compact<u16>, Compact<u64>)>;
+
+/// Normalize a u16 score to `[0, 1]`
+fn norm(value: Compact<u16>) -> f64 {
+    value.0 as f64 / u16::MAX as f64
+}
+
+/// `pallet_subtensor::AxonInfo`
+struct AxonInfoRaw {
+    block: u64,
+    version: u32,
+    ip: u128,
+    port: u16,
+    ip_type: u8,
+    protocol: u8,
+    placeholder1: u8,
+    placeholder2: u8,
+}
+
+impl ScaleDecode for AxonInfoRaw {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        Ok(Self {
+            block: ctx.field("block")?,
+            version: ctx.field("version")?,
+            ip: ctx.field("ip")?,
+            port: ctx.field("port")?,
+            ip_type: ctx.field("ip_type")?,
+            protocol: ctx.field("protocol")?,
+            placeholder1: ctx.field("placeholder1")?,
+            placeholder2: ctx.field("placeholder2")?,
+        })
+    }
+}
+
+impl From<AxonInfoRaw> for AxonInfo {
+    fn from(raw: AxonInfoRaw) -> Self {
+        AxonInfo::from_chain_data(
+            raw.block,
+            raw.version,
+            parse_ip_addr(raw.ip, raw.ip_type),
+            raw.port,
+            raw.ip_type,
+            raw.protocol,
+            raw.placeholder1,
+            raw.placeholder2,
+        )
+    }
+}
+
+/// `pallet_subtensor::PrometheusInfo`
+struct PrometheusInfoRaw {
+    block: u64,
+    version: u32,
+    ip: u128,
+    port: u16,
+    ip_type: u8,
+}
+
+impl ScaleDecode for PrometheusInfoRaw {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        Ok(Self {
+            block: ctx.field("block")?,
+            version: ctx.field("version")?,
+            ip: ctx.field("ip")?,
+            port: ctx.field("port")?,
+            ip_type: ctx.field("ip_type")?,
+        })
+    }
+}
+
+impl From<PrometheusInfoRaw> for PrometheusInfo {
+    fn from(raw: PrometheusInfoRaw) -> Self {
+        PrometheusInfo::from_chain_data(
+            raw.block,
+            raw.version,
+            parse_ip_addr(raw.ip, raw.ip_type).to_string(),
+            raw.port,
+            raw.ip_type,
+        )
+    }
+}
+
+/// `NeuronInfoLite<AccountId32>`
+struct NeuronInfoLiteRaw {
+    hotkey: AccountId32,
+    coldkey: AccountId32,
+    uid: Compact<u16>,
+    netuid: Compact<u16>,
+    active: bool,
+    axon_info: AxonInfoRaw,
+    prometheus_info: PrometheusInfoRaw,
+    stake: Vec<(AccountId32, Compact<u64>)>,
+    rank: Compact<u16>,
+    emission: Compact<u64>,
+    incentive: Compact<u16>,
+    consensus: Compact<u16>,
+    trust: Compact<u16>,
+    validator_trust: Compact<u16>,
+    dividends: Compact<u16>,
+    last_update: Compact<u64>,
+    validator_permit: bool,
+    pruning_score: Compact<u16>,
+}
+
+impl ScaleDecode for NeuronInfoLiteRaw {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        Ok(Self {
+            hotkey: ctx.field("hotkey")?,
+            coldkey: ctx.field("coldkey")?,
+            uid: ctx.field("uid")?,
+            netuid: ctx.field("netuid")?,
+            active: ctx.field("active")?,
+            axon_info: ctx.nested("axon_info")?,
+            prometheus_info: ctx.nested("prometheus_info")?,
+            stake: ctx.field("stake")?,
+            rank: ctx.field("rank")?,
+            emission: ctx.field("emission")?,
+            incentive: ctx.field("incentive")?,
+            consensus: ctx.field("consensus")?,
+            trust: ctx.field("trust")?,
+            validator_trust: ctx.field("validator_trust")?,
+            dividends: ctx.field("dividends")?,
+            last_update: ctx.field("last_update")?,
+            validator_permit: ctx.field("validator_permit")?,
+            pruning_score: ctx.field("pruning_score")?,
+        })
+    }
+}
+
+impl From<NeuronInfoLiteRaw> for NeuronInfoLite {
+    fn from(raw: NeuronInfoLiteRaw) -> Self {
+        let stake_dict: HashMap<AccountId32, u128> = raw
+            .stake
+            .into_iter()
+            .map(|(coldkey, stake)| (coldkey, stake.0 as u128))
+            .collect();
+        let stake = Rao::from(stake_dict.values().sum::<u128>());
+        let mut axon_info = AxonInfo::from(raw.axon_info);
+        axon_info.hotkey = Some(encode_ss58(&raw.hotkey));
+
+        Self {
+            stake,
+            stake_dict,
+            total_stake: stake,
+            hotkey: raw.hotkey,
+            coldkey: raw.coldkey,
+            rank: norm(raw.rank),
+            trust: norm(raw.trust),
+            consensus: norm(raw.consensus),
+            validator_trust: norm(raw.validator_trust),
+            incentive: norm(raw.incentive),
+            emission: Rao::from(raw.emission.0),
+            dividends: norm(raw.dividends),
+            active: raw.active,
+            last_update: raw.last_update.0,
+            validator_permit: raw.validator_permit,
+            pruning_score: raw.pruning_score.0 as u64,
+            prometheus_info: Some(raw.prometheus_info.into()),
+            axon_info: Some(axon_info),
+            ..NeuronInfoLite::new(raw.uid.0 as u64, raw.netuid.0)
+        }
+    }
+}
+
+/// `SubnetInfo<AccountId32>`
+#[allow(dead_code)]
+struct SubnetInfoRaw {
+    netuid: Compact<u16>,
+    rho: Compact<u16>,
+    kappa: Compact<u16>,
+    difficulty: Compact<u64>,
+    immunity_period: Compact<u16>,
+    max_allowed_validators: Compact<u16>,
+    min_allowed_weights: Compact<u16>,
+    max_weights_limit: Compact<u16>,
+    scaling_law_power: Compact<u16>,
+    subnetwork_n: Compact<u16>,
+    max_allowed_uids: Compact<u16>,
+    blocks_since_last_step: Compact<u64>,
+    tempo: Compact<u16>,
+    network_modality: Compact<u16>,
+    network_connect: Vec<[u16; 2]>,
+    emission_values: Compact<u64>,
+    burn: Compact<u64>,
+    owner: AccountId32,
+}
+
+impl ScaleDecode for SubnetInfoRaw {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        Ok(Self {
+            netuid: ctx.field("netuid")?,
+            rho: ctx.field("rho")?,
+            kappa: ctx.field("kappa")?,
+            difficulty: ctx.field("difficulty")?,
+            immunity_period: ctx.field("immunity_period")?,
+            max_allowed_validators: ctx.field("max_allowed_validators")?,
+            min_allowed_weights: ctx.field("min_allowed_weights")?,
+            max_weights_limit: ctx.field("max_weights_limit")?,
+            scaling_law_power: ctx.field("scaling_law_power")?,
+            subnetwork_n: ctx.field("subnetwork_n")?,
+            max_allowed_uids: ctx.field("max_allowed_uids")?,
+            blocks_since_last_step: ctx.field("blocks_since_last_step")?,
+            tempo: ctx.field("tempo")?,
+            network_modality: ctx.field("network_modality")?,
+            network_connect: ctx.field("network_connect")?,
+            emission_values: ctx.field("emission_values")?,
+            burn: ctx.field("burn")?,
+            owner: ctx.field("owner")?,
+        })
+    }
+}
+
+impl From<SubnetInfoRaw> for SubnetInfo {
+    fn from(raw: SubnetInfoRaw) -> Self {
+        Self {
+            neuron_count: raw.subnetwork_n.0 as u64,
+            emission: Rao::from(raw.emission_values.0),
+            ..SubnetInfo::new(raw.netuid.0)
+        }
+    }
+}
+
+/// `StakeInfo<AccountId32>`
+#[allow(dead_code)]
+struct StakeInfoRaw {
+    hotkey: AccountId32,
+    coldkey: AccountId32,
+    netuid: Compact<u16>,
+    stake: Compact<u64>,
+    locked: Compact<u64>,
+    emission: Compact<u64>,
+    tao_emission: Compact<u64>,
+    drain: Compact<u64>,
+    is_registered: bool,
+}
+
+impl ScaleDecode for StakeInfoRaw {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        Ok(Self {
+            hotkey: ctx.field("hotkey")?,
+            coldkey: ctx.field("coldkey")?,
+            netuid: ctx.field("netuid")?,
+            stake: ctx.field("stake")?,
+            locked: ctx.field("locked")?,
+            emission: ctx.field("emission")?,
+            tao_emission: ctx.field("tao_emission")?,
+            drain: ctx.field("drain")?,
+            is_registered: ctx.field("is_registered")?,
+        })
+    }
+}
+
+impl From<StakeInfoRaw> for StakeInfo {
+    fn from(raw: StakeInfoRaw) -> Self {
+        Self {
+            hotkey: raw.hotkey,
+            coldkey: raw.coldkey,
+            netuid: raw.netuid.0,
+            stake: Rao::from(raw.stake.0),
+        }
+    }
+}
+
+/// `DelegateInfo<AccountId32>`
+struct DelegateInfoRaw {
+    delegate_ss58: AccountId32,
+    take: Compact<u16>,
+    nominators: Vec<(AccountId32, NominatorStakes)>,
+    owner_ss58: AccountId32,
+    registrations: Vec<Compact<u16>>,
+    validator_permits: Vec<Compact<u16>>,
+    return_per_1000: Compact<u64>,
+    total_daily_return: Compact<u64>,
+}
+
+impl ScaleDecode for DelegateInfoRaw {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        Ok(Self {
+            delegate_ss58: ctx.field("delegate_ss58")?,
+            take: ctx.field("take")?,
+            nominators: ctx.field("nominators")?,
+            owner_ss58: ctx.field("owner_ss58")?,
+            registrations: ctx.field("registrations")?,
+            validator_permits: ctx.field("validator_permits")?,
+            return_per_1000: ctx.field("return_per_1000")?,
+            total_daily_return: ctx.field("total_daily_return")?,
+        })
+    }
+}
+
+impl From<DelegateInfoRaw> for DelegateInfo {
+    fn from(raw: DelegateInfoRaw) -> Self {
+        let mut nominators: HashMap<AccountId32, HashMap<u16, u128>> = HashMap::new();
+        let mut total_stake: HashMap<u16, Rao> = HashMap::new();
+
+        for (nominator, stakes) in raw.nominators {
+            let mut stake_map: HashMap<u16, u128> = HashMap::new();
+            for (netuid, stake) in stakes {
+                let stake = stake.0 as u128;
+                stake_map.insert(netuid.0, stake);
+
+                let entry = total_stake.entry(netuid.0).or_insert(Rao::ZERO);
+                *entry = entry.saturating_add(Rao::from(stake));
+            }
+            nominators.insert(nominator, stake_map);
+        }
+
+        Self {
+            base: DelegateInfoBase {
+                hotkey_ss58: raw.delegate_ss58,
+                owner_ss58: raw.owner_ss58,
+                take: norm(raw.take),
+                validator_permits: raw.validator_permits.iter().map(|c| c.0).collect(),
+                registrations: raw.registrations.iter().map(|c| c.0).collect(),
+                return_per_1000: Rao::from(raw.return_per_1000.0),
+                total_daily_return: Rao::from(raw.total_daily_return.0),
+            },
+            total_stake,
+            nominators,
+        }
+    }
+}
+
+/// `SubnetIdentityV3`, embedded in `DynamicInfo`
+#[allow(dead_code)]
+struct SubnetIdentityRaw {
+    subnet_name: Vec<u8>,
+    github_repo: Vec<u8>,
+    subnet_contact: Vec<u8>,
+    subnet_url: Vec<u8>,
+    discord: Vec<u8>,
+    description: Vec<u8>,
+    logo_url: Vec<u8>,
+    additional: Vec<u8>,
+}
+
+impl ScaleDecode for SubnetIdentityRaw {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        Ok(Self {
+            subnet_name: ctx.field("subnet_name")?,
+            github_repo: ctx.field("github_repo")?,
+            subnet_contact: ctx.field("subnet_contact")?,
+            subnet_url: ctx.field("subnet_url")?,
+            discord: ctx.field("discord")?,
+            description: ctx.field("description")?,
+            logo_url: ctx.field("logo_url")?,
+            additional: ctx.field("additional")?,
+        })
+    }
+}
+
+/// `DynamicInfo<AccountId32>`
+#[allow(dead_code)]
+struct DynamicInfoRaw {
+    netuid: Compact<u16>,
+    owner_hotkey: AccountId32,
+    owner_coldkey: AccountId32,
+    subnet_name: Vec<Compact<u8>>,
+    token_symbol: Vec<Compact<u8>>,
+    tempo: Compact<u16>,
+    last_step: Compact<u64>,
+    blocks_since_last_step: Compact<u64>,
+    emission: Compact<u64>,
+    alpha_in: Compact<u64>,
+    alpha_out: Compact<u64>,
+    tao_in: Compact<u64>,
+    alpha_out_emission: Compact<u64>,
+    alpha_in_emission: Compact<u64>,
+    tao_in_emission: Compact<u64>,
+    pending_alpha_emission: Compact<u64>,
+    pending_root_emission: Compact<u64>,
+    subnet_volume: Compact<u128>,
+    network_registered_at: Compact<u64>,
+    subnet_identity: Option<SubnetIdentityRaw>,
+    /// I96F32 fixed-point bits
+    moving_price: i128,
+}
+
+impl ScaleDecode for DynamicInfoRaw {
+    fn decode_with(ctx: &mut DecodeContext<'_>) -> Result<Self, ScaleDecodeError> {
+        Ok(Self {
+            netuid: ctx.field("netuid")?,
+            owner_hotkey: ctx.field("owner_hotkey")?,
+            owner_coldkey: ctx.field("owner_coldkey")?,
+            subnet_name: ctx.field("subnet_name")?,
+            token_symbol: ctx.field("token_symbol")?,
+            tempo: ctx.field("tempo")?,
+            last_step: ctx.field("last_step")?,
+            blocks_since_last_step: ctx.field("blocks_since_last_step")?,
+            emission: ctx.field("emission")?,
+            alpha_in: ctx.field("alpha_in")?,
+            alpha_out: ctx.field("alpha_out")?,
+            tao_in: ctx.field("tao_in")?,
+            alpha_out_emission: ctx.field("alpha_out_emission")?,
+            alpha_in_emission: ctx.field("alpha_in_emission")?,
+            tao_in_emission: ctx.field("tao_in_emission")?,
+            pending_alpha_emission: ctx.field("pending_alpha_emission")?,
+            pending_root_emission: ctx.field("pending_root_emission")?,
+            subnet_volume: ctx.field("subnet_volume")?,
+            network_registered_at: ctx.field("network_registered_at")?,
+            subnet_identity: ctx.nested("subnet_identity")?,
+            moving_price: ctx.field("moving_price")?,
+        })
+    }
+}
+
+impl From<DynamicInfoRaw> for DynamicInfo {
+    fn from(raw: DynamicInfoRaw) -> Self {
+        let netuid = raw.netuid.0;
+        let alpha_in = raw.alpha_in.0 as u128;
+        let tao_in = raw.tao_in.0 as u128;
+        // Root is priced 1:1; dynamic subnets use the pool ratio
+        let price = if netuid == 0 {
+            RAOPERTAO
+        } else {
+            tao_in
+                .saturating_mul(RAOPERTAO)
+                .checked_div(alpha_in)
+                .unwrap_or(0)
+        };
+        let symbol_bytes: Vec<u8> = raw.token_symbol.into_iter().map(|c| c.0).collect();
+        let moving_price_bits = raw.moving_price.max(0) as u128;
+
+        Self {
+            netuid,
+            owner_coldkey: encode_ss58(&raw.owner_coldkey),
+            owner_hotkey: encode_ss58(&raw.owner_hotkey),
+            symbol: String::from_utf8_lossy(&symbol_bytes).into_owned(),
+            tempo: raw.tempo.0 as u64,
+            last_step: raw.last_step.0,
+            blocks_since_last_step: raw.blocks_since_last_step.0,
+            network_registered_at: raw.network_registered_at.0,
+            emission_value: raw.emission.0 as u128,
+            pending_emission: raw.pending_alpha_emission.0 as u128,
+            alpha_in,
+            alpha_out: raw.alpha_out.0 as u128,
+            tao_in,
+            alpha_out_emission: raw.alpha_out_emission.0 as u128,
+            tao_in_emission: raw.tao_in_emission.0 as u128,
+            pending_root_emission: raw.pending_root_emission.0 as u128,
+            subnet_volume: raw.subnet_volume.0,
+            moving_price: moving_price_bits.saturating_mul(RAOPERTAO) >> 32,
+            price,
+            ..DynamicInfo::new(netuid)
+        }
+    }
+}
+
+/// Decode an `Option<T>` response; an empty response reads as `None`
+fn decode_option<Raw: ScaleDecode, T: From<Raw>>(
+    type_name: &str,
+    bytes: &[u8],
+) -> Result<Option<T>, ScaleDecodeError> {
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    Ok(decode_runtime::<Option<Raw>>(type_name, bytes)?.map(T::from))
+}
+
+fn decode_list<Raw: ScaleDecode, T: From<Raw>>(
+    type_name: &str,
+    bytes: &[u8],
+) -> Result<Vec<T>, ScaleDecodeError> {
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(decode_runtime::<Vec<Raw>>(type_name, bytes)?
+        .into_iter()
+        .map(T::from)
+        .collect())
+}
+
+/// `NeuronInfoRuntimeApi_get_neuron_lite`
+pub fn decode_neuron_lite(bytes: &[u8]) -> Result<Option<NeuronInfoLite>, ScaleDecodeError> {
+    decode_option::<NeuronInfoLiteRaw, _>("NeuronInfoLite", bytes)
+}
+
+/// `NeuronInfoRuntimeApi_get_neurons_lite`
+pub fn decode_neurons_lite(bytes: &[u8]) -> Result<Vec<NeuronInfoLite>, ScaleDecodeError> {
+    decode_list::<NeuronInfoLiteRaw, _>("NeuronInfoLite", bytes)
+}
+
+/// `SubnetInfoRuntimeApi_get_subnet_info`
+pub fn decode_subnet_info(bytes: &[u8]) -> Result<Option<SubnetInfo>, ScaleDecodeError> {
+    decode_option::<SubnetInfoRaw, _>("SubnetInfo", bytes)
+}
+
+/// `StakeInfoRuntimeApi_get_stake_info_for_hotkey_coldkey_netuid`
+pub fn decode_stake_info(bytes: &[u8]) -> Result<Option<StakeInfo>, ScaleDecodeError> {
+    decode_option::<StakeInfoRaw, _>("StakeInfo", bytes)
+}
+
+/// `StakeInfoRuntimeApi_get_stake_info_for_coldkey`
+pub fn decode_stake_info_list(bytes: &[u8]) -> Result<Vec<StakeInfo>, ScaleDecodeError> {
+    decode_list::<StakeInfoRaw, _>("StakeInfo", bytes)
+}
+
+/// `DelegateInfoRuntimeApi_get_delegate`
+pub fn decode_delegate(bytes: &[u8]) -> Result<Option<DelegateInfo>, ScaleDecodeError> {
+    decode_option::<DelegateInfoRaw, _>("DelegateInfo", bytes)
+}
+
+/// `DelegateInfoRuntimeApi_get_delegates`
+pub fn decode_delegates(bytes: &[u8]) -> Result<Vec<DelegateInfo>, ScaleDecodeError> {
+    decode_list::<DelegateInfoRaw, _>("DelegateInfo", bytes)
+}
+
+/// `SubnetInfoRuntimeApi_get_dynamic_info`
+pub fn decode_dynamic_info(bytes: &[u8]) -> Result<Option<DynamicInfo>, ScaleDecodeError> {
+    decode_option::<DynamicInfoRaw, _>("DynamicInfo", bytes)
+}
+
+/// `SubnetInfoRuntimeApi_get_all_dynamic_info`; missing subnets are skipped
+pub fn decode_all_dynamic_info(bytes: &[u8]) -> Result<Vec<DynamicInfo>, ScaleDecodeError> {
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(
+        decode_runtime::<Vec<Option<DynamicInfoRaw>>>("DynamicInfo", bytes)?
+            .into_iter()
+            .flatten()
+            .map(DynamicInfo::from)
+            .collect(),
+    )
+}
@@ -2,6 +2,7 @@ pub mod balance_newtypes;
 pub mod crypto;
 pub mod decoders;
 pub mod encode;
+pub mod networking;
 pub mod scale;
 pub mod ss58;
 pub mod weights;
@@ -0,0 +1,161 @@
+//! Networking helpers for serving axons
+//!
+//! Converts IP addresses to and from the chain's `u128` representation and
+//! determines the external IP address of this machine using public HTTP echo
+//! services.
+
+use anyhow::{anyhow, Result};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+use tracing::debug;
+
+/// HTTP echo services returning the caller's IP address as plain text,
+/// tried in order
+pub const EXTERNAL_IP_SERVICES: &[&str] = &[
+    "https://checkip.amazonaws.com",
+    "https://api.ipify.org",
+    "https://ifconfig.me/ip",
+    "https://ipinfo.io/ip",
+];
+
+/// Timeout for each echo service request
+const EXTERNAL_IP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Get the chain's IP type for an address (4 or 6)
+pub fn ip_version(ip: &IpAddr) -> u8 {
+    match ip {
+        IpAddr::V4(_) => 4,
+        IpAddr::V6(_) => 6,
+    }
+}
+
+/// Convert an IP address to the chain's `u128` representation
+///
+/// IPv4 addresses are stored as their 32-bit value, IPv6 addresses as their
+/// full 128-bit value.
+pub fn ip_to_int(ip: &IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(ipv4) => u32::from_be_bytes(ipv4.octets()) as u128,
+        IpAddr::V6(ipv6) => u128::from_be_bytes(ipv6.octets()),
+    }
+}
+
+/// Convert the chain's `u128` representation back to an IP address
+///
+/// # Arguments
+///
+/// * `ip` - The stored IP value
+/// * `ip_type` - The stored IP type (4 or 6)
+///
+/// # Returns
+///
+/// The address, or `None` for an unknown IP type or an IPv4 value that
+/// does not fit in 32 bits
+pub fn int_to_ip(ip: u128, ip_type: u8) -> Option<IpAddr> {
+    match ip_type {
+        4 => u32::try_from(ip)
+            .ok()
+            .map(|v4| IpAddr::V4(Ipv4Addr::from(v4))),
+        6 => Some(IpAddr::V6(Ipv6Addr::from(ip))),
+        _ => None,
+    }
+}
+
+/// Determine the external IP address of this machine
+///
+/// Queries each of [`EXTERNAL_IP_SERVICES`] in turn and returns the first
+/// valid address.
+pub async fn get_external_ip() -> Result<IpAddr> {
+    get_external_ip_from(EXTERNAL_IP_SERVICES).await
+}
+
+/// Determine the external IP address using the given echo services
+///
+/// # Arguments
+///
+/// * `services` - URLs returning the caller's IP address as plain text
+///
+/// # Returns
+///
+/// The first valid address returned by a service
+pub async fn get_external_ip_from(services: &[&str]) -> Result<IpAddr> {
+    let client = reqwest::Client::builder()
+        .timeout(EXTERNAL_IP_TIMEOUT)
+        .build()?;
+
+    for service in services {
+        let body = match client.get(*service).send().await {
+            Ok(response) => response.text().await,
+            Err(e) => Err(e),
+        };
+        match body.map(|text| text.trim().parse::<IpAddr>()) {
+            Ok(Ok(ip)) if !ip.is_unspecified() => return Ok(ip),
+            Ok(Ok(ip)) => debug!("{} returned an unspecified address {}", service, ip),
+            Ok(Err(e)) => debug!("{} returned an invalid address: {}", service, e),
+            Err(e) => debug!("Failed to query {}: {}", service, e),
+        }
+    }
+
+    Err(anyhow!(
+        "Unable to determine external IP address from {} services",
+        services.len()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ip_to_int_roundtrip() {
+        let v4 = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10));
+        assert_eq!(ip_to_int(&v4), 0xC0A8010A);
+        assert_eq!(ip_version(&v4), 4);
+        assert_eq!(int_to_ip(ip_to_int(&v4), 4), Some(v4));
+
+        let v6: IpAddr = "2001:db8::ff00:42:8329".parse().unwrap();
+        assert_eq!(ip_version(&v6), 6);
+        assert_eq!(int_to_ip(ip_to_int(&v6), 6), Some(v6));
+    }
+
+    #[test]
+    fn test_ip_encoding_edge_cases() {
+        let loopback = IpAddr::V6(Ipv6Addr::LOCALHOST);
+        assert_eq!(ip_to_int(&loopback), 1);
+        assert_eq!(int_to_ip(1, 6), Some(loopback));
+
+        let unspecified = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+        assert_eq!(ip_to_int(&unspecified), 0);
+        assert_eq!(int_to_ip(0, 4), Some(unspecified));
+
+        let broadcast = IpAddr::V4(Ipv4Addr::BROADCAST);
+        assert_eq!(int_to_ip(ip_to_int(&broadcast), 4), Some(broadcast));
+
+        // Mapped addresses keep their IPv6 encoding
+        let mapped: IpAddr = "::ffff:1.2.3.4".parse().unwrap();
+        assert_eq!(ip_version(&mapped), 6);
+        assert_eq!(ip_to_int(&mapped), 0xffff_0102_0304);
+        assert_eq!(int_to_ip(0xffff_0102_0304, 6), Some(mapped));
+
+        // Invalid encodings
+        assert_eq!(int_to_ip(u32::MAX as u128 + 1, 4), None);
+        assert_eq!(int_to_ip(1, 5), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_external_ip_falls_back() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = axum::Router::new()
+            .route("/bad", axum::routing::get(|| async { "not an ip" }))
+            .route("/ip", axum::routing::get(|| async { "203.0.113.7\n" }));
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let bad = format!("http://{}/bad", addr);
+        let good = format!("http://{}/ip", addr);
+        let ip = get_external_ip_from(&[&bad, &good]).await.unwrap();
+        assert_eq!(ip, IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7)));
+
+        assert!(get_external_ip_from(&[&bad]).await.is_err());
+    }
+}
@@ -1,11 +1,92 @@
+//! SS58 address encoding
+//!
+//! Bittensor addresses use prefix 42, but the same key pasted from
+//! polkadot-js may carry another network's prefix, e.g. 0 for Polkadot or 2
+//! for Kusama. [`ss58_decode`] accepts any prefix and reports it, and
+//! [`convert_address_prefix`] re-encodes an address for another network.
+
 use anyhow::Result;
-use sp_core::crypto::{AccountId32, Ss58AddressFormat, Ss58Codec};
+use sp_core::crypto::{AccountId32, PublicError, Ss58AddressFormat, Ss58Codec};
 use sp_core::sr25519;
 use std::str::FromStr;
+use thiserror::Error;
 
 /// SS58 format constant for Bittensor (42 = "bt")
 pub const SS58_FORMAT: u16 = 42;
 
+/// Largest prefix an SS58 address can carry
+pub const MAX_SS58_PREFIX: u16 = 16_383;
+
+/// Why an SS58 address is invalid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum Ss58Error {
+    /// Not valid base58
+    #[error("not valid base58")]
+    BadBase58,
+    /// Decodes to the wrong number of bytes for a 32-byte account
+    #[error("wrong length for a 32-byte account")]
+    BadLength,
+    /// The checksum does not match, e.g. after a typo
+    #[error("bad checksum")]
+    BadChecksum,
+    /// The prefix bytes are malformed or out of range
+    #[error("invalid prefix")]
+    InvalidPrefix,
+    /// Valid, but for another network
+    #[error("prefix {found} does not match expected prefix {expected}")]
+    WrongPrefix { expected: u16, found: u16 },
+}
+
+impl From<PublicError> for Ss58Error {
+    fn from(err: PublicError) -> Self {
+        match err {
+            PublicError::BadBase58 => Ss58Error::BadBase58,
+            PublicError::BadLength => Ss58Error::BadLength,
+            PublicError::InvalidChecksum => Ss58Error::BadChecksum,
+            _ => Ss58Error::InvalidPrefix,
+        }
+    }
+}
+
+/// Decode an SS58 address with any prefix, returning the account and prefix
+pub fn ss58_decode(address: &str) -> std::result::Result<(AccountId32, u16), Ss58Error> {
+    let (account, format) = AccountId32::from_ss58check_with_version(address)?;
+    Ok((account, u16::from(format)))
+}
+
+/// Encode a public key as an SS58 address with `prefix`
+pub fn ss58_encode_with_prefix(
+    pubkey: &[u8; 32],
+    prefix: u16,
+) -> std::result::Result<String, Ss58Error> {
+    if prefix > MAX_SS58_PREFIX {
+        return Err(Ss58Error::InvalidPrefix);
+    }
+    Ok(encode_ss58_with_format(&AccountId32::new(*pubkey), prefix))
+}
+
+/// Re-encode an SS58 address, of any prefix, with `target_prefix`
+pub fn convert_address_prefix(
+    address: &str,
+    target_prefix: u16,
+) -> std::result::Result<String, Ss58Error> {
+    let (account, _) = ss58_decode(address)?;
+    ss58_encode_with_prefix(&account.into(), target_prefix)
+}
+
+/// Check that `address` is a valid SS58 address with `prefix`
+///
+/// Unlike [`is_valid_ss58`], the error says what is wrong with it.
+pub fn is_valid_ss58_address(address: &str, prefix: u16) -> std::result::Result<(), Ss58Error> {
+    match ss58_decode(address)? {
+        (_, found) if found == prefix => Ok(()),
+        (_, found) => Err(Ss58Error::WrongPrefix {
+            expected: prefix,
+            found,
+        }),
+    }
+}
+
 /// Trait for converting types to SS58 address format
 pub trait AccountId32ToSS58 {
     /// Convert to SS58 address string
@@ -25,8 +106,16 @@ impl AccountId32ToSS58 for sr25519::Public {
 }
 
 /// Encode AccountId32 to SS58 string
+///
+/// Uses the Bittensor format; see [`encode_ss58_with_format`] for the prefix
+/// read from the chain (`ChainConstants::ss58_prefix`).
 pub fn encode_ss58(account: &AccountId32) -> String {
-    account.to_ss58check_with_version(Ss58AddressFormat::custom(SS58_FORMAT))
+    encode_ss58_with_format(account, SS58_FORMAT)
+}
+
+/// Encode AccountId32 to SS58 string with the given address format
+pub fn encode_ss58_with_format(account: &AccountId32, format: u16) -> String {
+    account.to_ss58check_with_version(Ss58AddressFormat::custom(format))
 }
 
 /// Decode SS58 string to AccountId32
@@ -126,3 +215,77 @@ pub mod serde_account_map {
         Ok(map)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `//Alice` under the Polkadot, Kusama and Bittensor prefixes
+    const ALICE_PUBKEY: &str = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+    const ALICE: [(u16, &str); 3] = [
+        (0, "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"),
+        (2, "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F"),
+        (42, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+    ];
+
+    fn alice_pubkey() -> [u8; 32] {
+        hex::decode(ALICE_PUBKEY).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_prefix_vectors() {
+        let pubkey = alice_pubkey();
+        for (prefix, address) in ALICE {
+            let (account, found) = ss58_decode(address).unwrap();
+            assert_eq!(found, prefix);
+            assert_eq!(<[u8; 32]>::from(account), pubkey);
+            assert_eq!(ss58_encode_with_prefix(&pubkey, prefix).unwrap(), address);
+            assert_eq!(decode_ss58(address).unwrap(), AccountId32::new(pubkey));
+        }
+        assert_eq!(
+            ss58_encode_with_prefix(&pubkey, MAX_SS58_PREFIX + 1),
+            Err(Ss58Error::InvalidPrefix)
+        );
+    }
+
+    #[test]
+    fn test_convert_address_prefix() {
+        for (_, from) in ALICE {
+            for (prefix, to) in ALICE {
+                assert_eq!(convert_address_prefix(from, prefix).unwrap(), to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let (_, polkadot) = ALICE[0];
+        let (_, bittensor) = ALICE[2];
+        assert_eq!(is_valid_ss58_address(bittensor, SS58_FORMAT), Ok(()));
+        assert_eq!(
+            is_valid_ss58_address(polkadot, SS58_FORMAT),
+            Err(Ss58Error::WrongPrefix {
+                expected: 42,
+                found: 0
+            })
+        );
+
+        // Last character changed
+        let typo = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ";
+        assert_eq!(
+            is_valid_ss58_address(typo, SS58_FORMAT),
+            Err(Ss58Error::BadChecksum)
+        );
+        let truncated = &bittensor[..45];
+        assert_eq!(
+            is_valid_ss58_address(truncated, SS58_FORMAT),
+            Err(Ss58Error::BadLength)
+        );
+        // '0' is not in the base58 alphabet
+        let not_base58 = "0GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+        assert_eq!(
+            is_valid_ss58_address(not_base58, SS58_FORMAT),
+            Err(Ss58Error::BadBase58)
+        );
+    }
+}
@@ -2,7 +2,10 @@
 //! Provides weight normalization, processing, and conversion functions
 //! matching Python's bittensor.utils.weight_utils
 
+use crate::errors::{BittensorError, InvalidWeights, TooManyWeights};
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Maximum value for u16 weights
 pub const U16_MAX: u16 = 65535;
@@ -82,7 +85,9 @@ pub fn float_to_u16(value: f64) -> u16 {
 }
 
 /// Normalize max weight to ensure no single weight exceeds limit
-/// Similar to Python's normalize_max_weight function
+///
+/// Port of the Python SDK's `weight_utils.normalize_max_weight`; outputs
+/// match it to within float32 rounding.
 pub fn normalize_max_weight(weights: &[f32], limit: f32) -> Vec<f32> {
     if weights.is_empty() {
         return vec![];
@@ -119,9 +124,9 @@ pub fn normalize_max_weight(weights: &[f32], limit: f32) -> Vec<f32> {
     // Determine cutoff index
     let mut n_values = 0;
     for i in 0..estimation.len() {
-        let estimation_sum: f32 = (0..estimation.len())
-            .map(|j| if j > i { estimation[j] } else { 0.0 })
-            .sum();
+        // Total if every value from i onward were clipped to estimation[i],
+        // as `(len(values) - i - 1) * estimation[i]` in the SDK
+        let estimation_sum = (estimation.len() - i - 1) as f32 * estimation[i];
         if estimation[i] / (estimation_sum + cumsum[i] + epsilon) < limit {
             n_values += 1;
         }
@@ -147,6 +152,480 @@ pub fn normalize_max_weight(weights: &[f32], limit: f32) -> Vec<f32> {
     }
 }
 
+/// Apply the chain's weight constraints to float weights
+///
+/// Fails on duplicate UIDs. Drops non-finite and non-positive entries and
+/// the excluded UID (usually the caller's own UID), fails if fewer than
+/// `min_allowed_weights` remain, then normalizes so no weight exceeds
+/// `max_weight_limit` (a fraction of the total, e.g. `MaxWeightsLimit / 65535`).
+/// Output is sorted by UID.
+pub fn clip_and_filter(
+    uids: &[u16],
+    weights: &[f64],
+    exclude_uid: Option<u16>,
+    min_allowed_weights: usize,
+    max_weight_limit: f64,
+) -> Result<(Vec<u16>, Vec<f64>)> {
+    if uids.len() != weights.len() {
+        return Err(anyhow::anyhow!(
+            "UIDS and weights must have the same length"
+        ));
+    }
+
+    let mut sorted_uids = uids.to_vec();
+    sorted_uids.sort_unstable();
+    if let Some(pair) = sorted_uids.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(anyhow::anyhow!("UID {} is given more than once", pair[0]));
+    }
+
+    let mut kept: Vec<(u16, f64)> = uids
+        .iter()
+        .zip(weights.iter())
+        .filter(|(uid, w)| w.is_finite() && **w > 0.0 && Some(**uid) != exclude_uid)
+        .map(|(u, w)| (*u, *w))
+        .collect();
+    kept.sort_by_key(|(uid, _)| *uid);
+
+    if kept.is_empty() || kept.len() < min_allowed_weights {
+        return Err(anyhow::anyhow!(
+            "Only {} non-zero weights remain, subnet requires at least {}",
+            kept.len(),
+            min_allowed_weights.max(1)
+        ));
+    }
+
+    let raw: Vec<f32> = kept.iter().map(|(_, w)| *w as f32).collect();
+    let clipped = normalize_max_weight(&raw, max_weight_limit as f32);
+
+    Ok((
+        kept.iter().map(|(uid, _)| *uid).collect(),
+        clipped.iter().map(|w| *w as f64).collect(),
+    ))
+}
+
+/// Convert normalized float weights to u16 the way subtensor stores them
+///
+/// Scales so the largest weight becomes `U16_MAX` and drops entries that
+/// round to zero.
+pub fn max_upscale_to_u16(uids: &[u16], weights: &[f64]) -> Result<(Vec<u16>, Vec<u16>)> {
+    if uids.len() != weights.len() {
+        return Err(anyhow::anyhow!(
+            "UIDS and weights must have the same length"
+        ));
+    }
+
+    let max = weights.iter().cloned().fold(0.0f64, f64::max);
+    if max <= 0.0 {
+        return Ok((vec![], vec![]));
+    }
+
+    let mut out_uids = Vec::with_capacity(uids.len());
+    let mut out_vals = Vec::with_capacity(uids.len());
+    for (uid, w) in uids.iter().zip(weights.iter()) {
+        let val = ((w / max) * U16_MAX as f64).round() as u16;
+        if val > 0 {
+            out_uids.push(*uid);
+            out_vals.push(val);
+        }
+    }
+
+    Ok((out_uids, out_vals))
+}
+
+/// A UID whose stored weight differs from the submitted one
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeightDiff {
+    /// Destination UID
+    pub uid: u16,
+    /// Submitted weight after the chain's max-upscaling (None if not submitted)
+    pub submitted: Option<u16>,
+    /// Weight the chain stored (None if missing or zero)
+    pub stored: Option<u16>,
+}
+
+/// Submitted weights compared with the row the chain stored
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeightVerification {
+    /// Whether every stored weight matches the submission
+    pub matches: bool,
+    /// UIDs that were dropped, added or changed, by UID
+    pub differences: Vec<WeightDiff>,
+}
+
+impl WeightVerification {
+    /// Compare submitted u16 weights with a stored `Weights` row
+    ///
+    /// The submission is max-upscaled first, as the chain does, and values
+    /// within 1 of each other are treated as equal to allow for rounding.
+    /// Zero weights count as absent on both sides.
+    pub fn compare(uids: &[u16], weights: &[u16], stored: &[(u16, u16)]) -> Result<Self> {
+        let as_f64: Vec<f64> = weights.iter().map(|&w| w as f64).collect();
+        let (uids, upscaled) = max_upscale_to_u16(uids, &as_f64)?;
+        let submitted: BTreeMap<u16, u16> = uids.into_iter().zip(upscaled).collect();
+        let stored: BTreeMap<u16, u16> = stored
+            .iter()
+            .filter(|(_, weight)| *weight > 0)
+            .copied()
+            .collect();
+
+        let all_uids: BTreeSet<u16> = submitted.keys().chain(stored.keys()).copied().collect();
+        let differences: Vec<WeightDiff> = all_uids
+            .into_iter()
+            .filter_map(|uid| {
+                let submitted = submitted.get(&uid).copied();
+                let stored = stored.get(&uid).copied();
+                let equal = match (submitted, stored) {
+                    (Some(a), Some(b)) => a.abs_diff(b) <= 1,
+                    _ => false,
+                };
+                (!equal).then_some(WeightDiff {
+                    uid,
+                    submitted,
+                    stored,
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            matches: differences.is_empty(),
+            differences,
+        })
+    }
+}
+
+/// Process float weights into exactly what the chain will store for a subnet
+///
+/// Fetches `MinAllowedWeights`, `MaxWeightsLimit` and `SubnetworkN`, rejects
+/// UIDs outside the subnet, excludes `self_uid` when given, clips with
+/// `clip_and_filter` and converts with `max_upscale_to_u16`.
+pub async fn process_weights_for_netuid(
+    client: &impl crate::chain::ChainBackend,
+    netuid: u16,
+    uids: &[u16],
+    weights: &[f64],
+    self_uid: Option<u16>,
+) -> Result<(Vec<u16>, Vec<u16>)> {
+    use crate::queries::subnets::{max_weight_limit, min_allowed_weights, subnet_n};
+
+    let (min_allowed, max_limit, n) = tokio::try_join!(
+        min_allowed_weights(client, netuid),
+        max_weight_limit(client, netuid),
+        subnet_n(client, netuid),
+    )?;
+    let n = n.unwrap_or(0);
+
+    if let Some(uid) = uids.iter().find(|&&uid| uid as u64 >= n) {
+        return Err(anyhow::anyhow!(
+            "UID {} is out of range for netuid {} (subnet has {} neurons)",
+            uid,
+            netuid,
+            n
+        ));
+    }
+
+    let max_limit = max_limit.unwrap_or(U16_MAX) as f64 / U16_MAX as f64;
+    let min_allowed = (min_allowed.unwrap_or(0) as usize).min(n as usize);
+
+    let (uids, clipped) = clip_and_filter(uids, weights, self_uid, min_allowed, max_limit)?;
+    max_upscale_to_u16(&uids, &clipped)
+}
+
+/// A hotkey's weight resolved to the UID it is registered at
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolvedWeight {
+    /// SS58 hotkey, as given
+    pub hotkey: String,
+    /// UID the hotkey was registered at when resolved
+    pub uid: u16,
+    /// Weight after max-upscaling to u16
+    pub weight: u16,
+}
+
+/// Hotkey-keyed weights resolved against a subnet's current registrations
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotkeyWeights {
+    /// Registered hotkeys with a non-zero weight, by UID
+    pub resolved: Vec<ResolvedWeight>,
+    /// Hotkeys not registered on the subnet, sorted
+    pub skipped: Vec<String>,
+}
+
+impl HotkeyWeights {
+    /// UIDs and u16 weights to submit
+    pub fn uids_and_weights(&self) -> (Vec<u16>, Vec<u16>) {
+        self.resolved.iter().map(|w| (w.uid, w.weight)).unzip()
+    }
+}
+
+/// Resolve SS58 hotkey weights to the UIDs currently registered on a subnet
+///
+/// Looks every hotkey up in `Uids` at call time, so a deregistration that
+/// reshuffled UIDs is picked up. Unregistered hotkeys are reported in
+/// [`HotkeyWeights::skipped`]; the remaining weights are converted with
+/// `max_upscale_to_u16`, which drops zeros. Fails on an invalid SS58 address.
+pub async fn resolve_hotkey_weights(
+    client: &impl crate::chain::ChainBackend,
+    netuid: u16,
+    weights: &std::collections::HashMap<String, f64>,
+) -> Result<HotkeyWeights> {
+    use sp_core::crypto::{AccountId32, Ss58Codec};
+
+    let accounts = weights
+        .keys()
+        .map(|hotkey| {
+            AccountId32::from_ss58check(hotkey)
+                .map(|account| (hotkey, account))
+                .map_err(|e| anyhow::anyhow!("Invalid hotkey {}: {:?}", hotkey, e))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let uids =
+        futures::future::try_join_all(accounts.iter().map(|(_, account)| {
+            crate::queries::neurons::get_uid_for_hotkey(client, netuid, account)
+        }))
+        .await?;
+
+    let mut registered = BTreeMap::new();
+    let mut skipped = Vec::new();
+    for ((hotkey, _), uid) in accounts.into_iter().zip(uids) {
+        match uid {
+            Some(uid) => {
+                registered.insert(uid, hotkey);
+            }
+            None => skipped.push(hotkey.clone()),
+        }
+    }
+    skipped.sort();
+
+    let uids: Vec<u16> = registered.keys().copied().collect();
+    let floats: Vec<f64> = registered.values().map(|hotkey| weights[*hotkey]).collect();
+    let (uids, upscaled) = max_upscale_to_u16(&uids, &floats)?;
+    let resolved = uids
+        .into_iter()
+        .zip(upscaled)
+        .map(|(uid, weight)| ResolvedWeight {
+            hotkey: registered[&uid].clone(),
+            uid,
+            weight,
+        })
+        .collect();
+
+    Ok(HotkeyWeights { resolved, skipped })
+}
+
+/// How weight inputs that violate subnet bounds are handled before submission
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WeightValidation {
+    /// Reject the inputs with an error
+    #[default]
+    Strict,
+    /// Drop out-of-range and duplicate UIDs with a warning, sort by UID, then
+    /// apply the strict checks to what remains
+    Lenient,
+}
+
+/// Check integer weight inputs against a subnet's bounds before submitting
+///
+/// Rejects mismatched lengths, more entries than `subnet_n`, UIDs
+/// `>= subnet_n`, duplicate UIDs, weights that sum to zero, fewer than
+/// `min_allowed` entries (capped at `subnet_n`) and, when `max_limit` is below
+/// `U16_MAX`, a normalized weight above `max_limit`. Errors name the offending
+/// indices. Self-weights are not special-cased; see [`WeightBounds::check`].
+pub fn validate_weight_inputs(
+    uids: &[u16],
+    weights: &[u16],
+    subnet_n: u16,
+    min_allowed: u16,
+    max_limit: u16,
+) -> std::result::Result<(), BittensorError> {
+    if uids.len() != weights.len() {
+        return Err(InvalidWeights::new(format!(
+            "{} uids but {} weights",
+            uids.len(),
+            weights.len()
+        ))
+        .into());
+    }
+
+    if uids.len() > subnet_n as usize {
+        return Err(TooManyWeights::with_counts(
+            format!(
+                "{} weights for a subnet of {} neurons",
+                uids.len(),
+                subnet_n
+            ),
+            uids.len(),
+            subnet_n as usize,
+        )
+        .into());
+    }
+
+    let out_of_range: Vec<String> = uids
+        .iter()
+        .enumerate()
+        .filter(|(_, &uid)| uid >= subnet_n)
+        .map(|(i, uid)| format!("{}: uid {}", i, uid))
+        .collect();
+    if !out_of_range.is_empty() {
+        return Err(InvalidWeights::new(format!(
+            "uids out of range for a subnet of {} neurons at indices [{}]",
+            subnet_n,
+            out_of_range.join(", ")
+        ))
+        .into());
+    }
+
+    let mut seen = std::collections::HashMap::new();
+    let duplicates: Vec<String> = uids
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &uid)| {
+            seen.insert(uid, i)
+                .map(|first| format!("{}: uid {} (first at {})", i, uid, first))
+        })
+        .collect();
+    if !duplicates.is_empty() {
+        return Err(InvalidWeights::new(format!(
+            "duplicate uids at indices [{}]",
+            duplicates.join(", ")
+        ))
+        .into());
+    }
+
+    let sum: u64 = weights.iter().map(|&w| w as u64).sum();
+    if sum == 0 {
+        return Err(InvalidWeights::with_sums("weights sum to zero", 0.0, U16_MAX as f64).into());
+    }
+
+    let min_required = min_allowed.min(subnet_n) as usize;
+    if uids.len() < min_required {
+        return Err(InvalidWeights::new(format!(
+            "{} weights provided, subnet requires at least {}",
+            uids.len(),
+            min_required
+        ))
+        .into());
+    }
+
+    if max_limit < U16_MAX {
+        let over: Vec<String> = weights
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &w)| {
+                let normalized = (w as u64 * U16_MAX as u64 / sum) as u16;
+                (normalized > max_limit).then(|| format!("{}: uid {} ({})", i, uids[i], normalized))
+            })
+            .collect();
+        if !over.is_empty() {
+            return Err(InvalidWeights::new(format!(
+                "normalized weights exceed max_weights_limit {} at indices [{}]",
+                max_limit,
+                over.join(", ")
+            ))
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Drop entries that can never be accepted on a subnet of `subnet_n` neurons
+///
+/// Extra entries of the longer slice, UIDs `>= subnet_n` and repeated UIDs
+/// (keeping the first occurrence) are dropped with a warning. The result is
+/// sorted by UID.
+pub fn sanitize_weight_inputs(
+    uids: &[u16],
+    weights: &[u16],
+    subnet_n: u16,
+) -> (Vec<u16>, Vec<u16>) {
+    if uids.len() != weights.len() {
+        tracing::warn!(
+            "{} uids but {} weights, ignoring unmatched entries",
+            uids.len(),
+            weights.len()
+        );
+    }
+
+    let mut kept: Vec<(u16, u16)> = Vec::with_capacity(uids.len());
+    let mut dropped_range = Vec::new();
+    let mut dropped_dup = Vec::new();
+    for (i, (&uid, &weight)) in uids.iter().zip(weights.iter()).enumerate() {
+        if uid >= subnet_n {
+            dropped_range.push(i);
+        } else if kept.iter().any(|(u, _)| *u == uid) {
+            dropped_dup.push(i);
+        } else {
+            kept.push((uid, weight));
+        }
+    }
+    if !dropped_range.is_empty() {
+        tracing::warn!(
+            "Dropping uids out of range for a subnet of {} neurons at indices {:?}",
+            subnet_n,
+            dropped_range
+        );
+    }
+    if !dropped_dup.is_empty() {
+        tracing::warn!("Dropping duplicate uids at indices {:?}", dropped_dup);
+    }
+
+    kept.sort_by_key(|(uid, _)| *uid);
+    kept.into_iter().unzip()
+}
+
+/// Subnet parameters that integer weight inputs are checked against
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeightBounds {
+    /// `SubnetworkN`
+    pub subnet_n: u16,
+    /// `MinAllowedWeights`
+    pub min_allowed: u16,
+    /// `MaxWeightsLimit` (u16 fraction of the normalized total)
+    pub max_limit: u16,
+}
+
+impl WeightBounds {
+    /// Read the bounds for `netuid` from chain
+    pub async fn fetch(client: &impl crate::chain::ChainBackend, netuid: u16) -> Result<Self> {
+        use crate::queries::subnets::{max_weight_limit, min_allowed_weights, subnet_n};
+
+        let (min_allowed, max_limit, n) = tokio::try_join!(
+            min_allowed_weights(client, netuid),
+            max_weight_limit(client, netuid),
+            subnet_n(client, netuid),
+        )?;
+        Ok(Self {
+            subnet_n: n.unwrap_or(0).min(U16_MAX as u64) as u16,
+            min_allowed: min_allowed.unwrap_or(0).min(U16_MAX as u64) as u16,
+            max_limit: max_limit.unwrap_or(U16_MAX),
+        })
+    }
+
+    /// Validate inputs according to `mode`, returning the UIDs and weights to submit
+    ///
+    /// A single weight on `self_uid` skips the count and max weight checks, as
+    /// subtensor always accepts self-weights.
+    pub fn check(
+        &self,
+        uids: &[u16],
+        weights: &[u16],
+        self_uid: Option<u16>,
+        mode: WeightValidation,
+    ) -> std::result::Result<(Vec<u16>, Vec<u16>), BittensorError> {
+        let (uids, weights) = match mode {
+            WeightValidation::Strict => (uids.to_vec(), weights.to_vec()),
+            WeightValidation::Lenient => sanitize_weight_inputs(uids, weights, self.subnet_n),
+        };
+        let (min_allowed, max_limit) = if uids.len() == 1 && self_uid == Some(uids[0]) {
+            (0, U16_MAX)
+        } else {
+            (self.min_allowed, self.max_limit)
+        };
+        validate_weight_inputs(&uids, &weights, self.subnet_n, min_allowed, max_limit)?;
+        Ok((uids, weights))
+    }
+}
+
 /// Convert weight UIDs and values to dense tensor representation
 pub fn convert_weight_uids_and_vals_to_tensor(n: usize, uids: &[u16], weights: &[u16]) -> Vec<f32> {
     let mut row_weights = vec![0.0f32; n];
@@ -223,7 +702,7 @@ pub fn process_weights(
     }
 
     // Apply quantile exclusion
-    let max_exclude = (non_zero.len() - min_allowed).max(0) as f64 / non_zero.len() as f64;
+    let max_exclude = non_zero.len().saturating_sub(min_allowed) as f64 / non_zero.len() as f64;
     let effective_quantile = quantile.min(max_exclude);
 
     let mut sorted_weights: Vec<f32> = non_zero.iter().map(|(_, w)| *w).collect();
@@ -358,6 +837,121 @@ mod tests {
         assert!((sum - 1.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_normalize_max_weight_sdk_parity() {
+        // Outputs of the Python SDK's `normalize_max_weight(np.array(x), limit)`
+        let cases: &[(&[f32], f32, &[f32])] = &[
+            (
+                &[0.1, 0.2, 0.3, 0.4],
+                0.3,
+                &[0.1333334, 0.2666668, 0.2999999, 0.2999999],
+            ),
+            (
+                &[1.0, 1.0, 1.0, 10.0],
+                0.3,
+                &[0.2333334, 0.2333334, 0.2333334, 0.2999997],
+            ),
+            (
+                &[5.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+                0.25,
+                &[0.2499998, 0.15, 0.15, 0.15, 0.15, 0.15],
+            ),
+            (
+                &[1.0, 2.0, 3.0, 4.0, 100.0],
+                0.3,
+                &[0.0700001, 0.1400002, 0.2100002, 0.2800003, 0.2999992],
+            ),
+            // Already under the limit: plain normalization
+            (&[3.0, 2.0, 1.0], 0.5, &[0.5, 0.3333333, 0.1666667]),
+            // Limit unreachable for four weights: uniform
+            (&[0.0, 0.0, 0.0, 1.0], 0.2, &[0.25, 0.25, 0.25, 0.25]),
+        ];
+
+        for (weights, limit, expected) in cases {
+            let normalized = normalize_max_weight(weights, *limit);
+            assert_eq!(normalized.len(), expected.len());
+            for (got, want) in normalized.iter().zip(expected.iter()) {
+                assert!(
+                    (got - want).abs() < 1e-5,
+                    "{:?} at {}: got {:?}, want {:?}",
+                    weights,
+                    limit,
+                    normalized,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_clip_and_filter_drops_invalid_and_self() {
+        let uids = vec![3, 1, 2, 0];
+        let weights = vec![1.0, 2.0, 0.0, f64::NAN];
+
+        let (out_uids, out_weights) = clip_and_filter(&uids, &weights, Some(3), 1, 1.0).unwrap();
+
+        // uid 3 excluded, uid 2 zero, uid 0 NaN
+        assert_eq!(out_uids, vec![1]);
+        assert!((out_weights[0] - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_clip_and_filter_rejects_duplicate_uids() {
+        let err = clip_and_filter(&[1, 2, 1], &[2.0, 1.0, 5.0], None, 1, 1.0).unwrap_err();
+        assert!(err.to_string().contains("UID 1"));
+    }
+
+    #[test]
+    fn test_clip_and_filter_min_allowed() {
+        let err = clip_and_filter(&[0, 1], &[1.0, 1.0], None, 3, 1.0).unwrap_err();
+        assert!(err.to_string().contains("at least 3"));
+    }
+
+    #[test]
+    fn test_max_upscale_to_u16() {
+        let (uids, vals) = max_upscale_to_u16(&[0, 1, 2], &[0.5, 0.25, 0.0]).unwrap();
+        assert_eq!(uids, vec![0, 1]);
+        assert_eq!(vals, vec![U16_MAX, U16_MAX / 2 + 1]);
+    }
+
+    #[test]
+    fn test_clip_and_filter_invariants() {
+        use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..500 {
+            let n = rng.random_range(1..64usize);
+            let mut uids: Vec<u16> = (0..256).collect();
+            uids.shuffle(&mut rng);
+            uids.truncate(n);
+            let weights: Vec<f64> = (0..n).map(|_| rng.random_range(0.0..10.0)).collect();
+            let limit = rng.random_range(0.05..1.0);
+
+            let Ok((out_uids, out_weights)) = clip_and_filter(&uids, &weights, None, 1, limit)
+            else {
+                continue;
+            };
+
+            // UIDs are strictly increasing (sorted and unique)
+            assert!(out_uids.windows(2).all(|w| w[0] < w[1]));
+            assert_eq!(out_uids.len(), out_weights.len());
+
+            // Normalized weights sum to 1
+            let sum: f64 = out_weights.iter().sum();
+            assert!((sum - 1.0).abs() < 1e-3, "sum {}", sum);
+
+            // No weight exceeds the limit unless the limit is unreachable
+            if limit * out_weights.len() as f64 > 1.0 {
+                assert!(out_weights.iter().all(|w| *w <= limit + 1e-3));
+            }
+
+            // u16 conversion keeps the order and puts the max at U16_MAX
+            let (emit_uids, emit_vals) = max_upscale_to_u16(&out_uids, &out_weights).unwrap();
+            assert!(emit_uids.windows(2).all(|w| w[0] < w[1]));
+            assert_eq!(emit_vals.iter().max(), Some(&U16_MAX));
+        }
+    }
+
     #[test]
     fn test_convert_weight_uids_and_vals_to_tensor() {
         let uids = vec![0, 2];
@@ -371,4 +965,79 @@ mod tests {
         assert!(tensor[2] > 0.0);
         assert_eq!(tensor[3], 0.0);
     }
+
+    #[test]
+    fn test_validate_weight_inputs_accepts_valid() {
+        assert!(validate_weight_inputs(&[0, 2, 1], &[100, 200, 300], 4, 2, U16_MAX).is_ok());
+    }
+
+    #[test]
+    fn test_validate_weight_inputs_rejects_bad_inputs() {
+        let err = |uids: &[u16], weights: &[u16], n, min, max| {
+            validate_weight_inputs(uids, weights, n, min, max)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(err(&[0, 1], &[1], 4, 0, U16_MAX).contains("2 uids but 1 weights"));
+        assert!(err(&[0, 1, 2], &[1, 1, 1], 2, 0, U16_MAX).starts_with("Too many weights"));
+        assert!(err(&[0, 7, 1, 9], &[1; 4], 5, 0, U16_MAX).contains("[1: uid 7, 3: uid 9]"));
+        assert!(err(&[3, 1, 3], &[1; 3], 5, 0, U16_MAX).contains("[2: uid 3 (first at 0)]"));
+        assert!(err(&[0, 1], &[0, 0], 5, 0, U16_MAX).contains("sum to zero"));
+        assert!(err(&[0], &[1], 5, 3, U16_MAX).contains("at least 3"));
+        assert!(err(&[0, 1], &[1, 3], 5, 0, U16_MAX / 2).contains("[1: uid 1"));
+    }
+
+    #[test]
+    fn test_validate_weight_inputs_caps_min_allowed_at_subnet_n() {
+        assert!(validate_weight_inputs(&[0, 1], &[1, 1], 2, 8, U16_MAX).is_ok());
+    }
+
+    #[test]
+    fn test_sanitize_weight_inputs() {
+        let (uids, weights) = sanitize_weight_inputs(&[4, 9, 1, 4, 2], &[40, 90, 10, 41, 20], 5);
+        assert_eq!(uids, vec![1, 2, 4]);
+        assert_eq!(weights, vec![10, 20, 40]);
+    }
+
+    #[test]
+    fn test_weight_bounds_check_modes() {
+        let bounds = WeightBounds {
+            subnet_n: 5,
+            min_allowed: 2,
+            max_limit: U16_MAX,
+        };
+        let uids = [3, 3, 8, 1];
+        let weights = [30, 31, 80, 10];
+
+        assert!(bounds
+            .check(&uids, &weights, None, WeightValidation::Strict)
+            .is_err());
+        assert_eq!(
+            bounds
+                .check(&uids, &weights, None, WeightValidation::Lenient)
+                .unwrap(),
+            (vec![1, 3], vec![10, 30])
+        );
+
+        // Lenient mode still enforces the minimum count
+        assert!(bounds
+            .check(&[1, 9], &[1, 1], None, WeightValidation::Lenient)
+            .is_err());
+    }
+
+    #[test]
+    fn test_weight_bounds_check_allows_self_weight() {
+        let bounds = WeightBounds {
+            subnet_n: 5,
+            min_allowed: 3,
+            max_limit: U16_MAX / 4,
+        };
+        assert!(bounds
+            .check(&[2], &[U16_MAX], Some(2), WeightValidation::Strict)
+            .is_ok());
+        assert!(bounds
+            .check(&[2], &[U16_MAX], Some(1), WeightValidation::Strict)
+            .is_err());
+    }
 }
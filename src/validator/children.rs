@@ -1,12 +1,19 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use crate::utils::decoders::{decode_vec_account_id32, decode_vec_tuple_u64_account};
-use anyhow::Result;
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult, ExtrinsicError, RateLimitedError};
+use crate::utils::decoders::{
+    decode_u16, decode_u64, decode_vec_account_id32, decode_vec_tuple_u64_account,
+};
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
+/// Runtime defaults for childkey take storage that has never been written
+const DEFAULT_MIN_CHILDKEY_TAKE: u16 = 0;
+const DEFAULT_MAX_CHILDKEY_TAKE: u16 = 11_796; // 18%
+const DEFAULT_CHILDKEY_TAKE_RATE_LIMIT: u64 = 216_000; // ~30 days
+
 /// Set children hotkeys with proportions.
 ///
 /// Associates child hotkeys with a parent hotkey on a given subnet, each
@@ -21,13 +28,13 @@ const SUBTENSOR_MODULE: &str = "SubtensorModule";
 ///   are u64 values (not RAO or TAO).
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn set_children(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     hotkey: &AccountId32,
     children: Vec<(u64, AccountId32)>, // (proportion, child_hotkey)
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     // Build children list as composite values
     let children_values: Vec<Value> = children
         .iter()
@@ -48,7 +55,7 @@ pub async fn set_children(
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "set_children", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set children: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set children", e))
 }
 
 /// Get parent hotkeys for a neuron.
@@ -56,10 +63,10 @@ pub async fn set_children(
 /// Returns the list of parent hotkeys that have delegated stake weight
 /// to the given hotkey on the specified subnet.
 pub async fn get_parents(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
-) -> Result<Vec<AccountId32>> {
+) -> BittensorResult<Vec<AccountId32>> {
     let keys = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
 
     if let Some(parents_val) = client
@@ -67,7 +74,7 @@ pub async fn get_parents(
         .await?
     {
         // Decode the list of parent AccountId32s
-        return decode_vec_account_id32(&parents_val);
+        return Ok(decode_vec_account_id32(&parents_val)?);
     }
 
     Ok(vec![])
@@ -78,10 +85,10 @@ pub async fn get_parents(
 /// Returns a list of `(child_hotkey, proportion)` tuples for the given
 /// parent hotkey on the specified subnet.
 pub async fn get_children(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
-) -> Result<Vec<(AccountId32, u64)>> {
+) -> BittensorResult<Vec<(AccountId32, u64)>> {
     let keys = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
 
     if let Some(children_val) = client
@@ -105,10 +112,10 @@ pub async fn get_children(
 
 /// Get pending children hotkeys that have not yet been confirmed.
 pub async fn get_children_pending(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
-) -> Result<Vec<AccountId32>> {
+) -> BittensorResult<Vec<AccountId32>> {
     let keys = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
 
     if let Some(pending_val) = client
@@ -116,8 +123,146 @@ pub async fn get_children_pending(
         .await?
     {
         // Decode the list of pending AccountId32s
-        return decode_vec_account_id32(&pending_val);
+        return Ok(decode_vec_account_id32(&pending_val)?);
     }
 
     Ok(vec![])
 }
+
+/// Get the take a hotkey charges its children on a subnet.
+///
+/// Reads `ChildkeyTake[(hotkey, netuid)]`, a u16 proportion (0–65535 maps to
+/// 0–100%). This is separate from the delegate take; unset means 0.
+pub async fn get_childkey_take(
+    client: &impl ChainBackend,
+    hotkey: &AccountId32,
+    netuid: u16,
+) -> BittensorResult<u16> {
+    let keys = vec![Value::from_bytes(hotkey.encode()), Value::from(netuid)];
+    match client
+        .storage_with_keys(SUBTENSOR_MODULE, "ChildkeyTake", keys)
+        .await?
+    {
+        Some(val) => Ok(decode_u16(&val)?),
+        None => Ok(0),
+    }
+}
+
+/// Childkey take range accepted by the chain, as u16 proportions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChildkeyTakeBounds {
+    pub min: u16,
+    pub max: u16,
+}
+
+impl ChildkeyTakeBounds {
+    /// Read `MinChildkeyTake` and `MaxChildkeyTake`, using the runtime
+    /// defaults for values never set
+    pub async fn fetch(client: &impl ChainBackend) -> BittensorResult<Self> {
+        let (min, max) = tokio::try_join!(
+            client.storage(SUBTENSOR_MODULE, "MinChildkeyTake", None),
+            client.storage(SUBTENSOR_MODULE, "MaxChildkeyTake", None),
+        )?;
+        Ok(Self {
+            min: min.map_or(Ok(DEFAULT_MIN_CHILDKEY_TAKE), |v| decode_u16(&v))?,
+            max: max.map_or(Ok(DEFAULT_MAX_CHILDKEY_TAKE), |v| decode_u16(&v))?,
+        })
+    }
+
+    /// Fail unless `take` is within the bounds
+    pub fn check(&self, take: u16) -> BittensorResult<()> {
+        if take < self.min || take > self.max {
+            return Err(ExtrinsicError::new(format!(
+                "Childkey take {} is outside the allowed range {}..={}",
+                take, self.min, self.max
+            ))
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Get the block of a hotkey's last childkey take change, if any.
+///
+/// Reads `LastTxBlockChildKeyTake[hotkey]`; 0 is treated as never.
+pub async fn get_last_childkey_take_block(
+    client: &impl ChainBackend,
+    hotkey: &AccountId32,
+) -> BittensorResult<Option<u64>> {
+    let keys = vec![Value::from_bytes(hotkey.encode())];
+    match client
+        .storage_with_keys(SUBTENSOR_MODULE, "LastTxBlockChildKeyTake", keys)
+        .await?
+    {
+        Some(val) => Ok(Some(decode_u64(&val)?).filter(|block| *block > 0)),
+        None => Ok(None),
+    }
+}
+
+/// Blocks a hotkey must wait between childkey take changes: `TxChildkeyTakeRateLimit`
+pub async fn get_childkey_take_rate_limit(client: &impl ChainBackend) -> BittensorResult<u64> {
+    match client
+        .storage(SUBTENSOR_MODULE, "TxChildkeyTakeRateLimit", None)
+        .await?
+    {
+        Some(val) => Ok(decode_u64(&val)?),
+        None => Ok(DEFAULT_CHILDKEY_TAKE_RATE_LIMIT),
+    }
+}
+
+/// Set the take a hotkey charges its children on a subnet.
+///
+/// Subtensor extrinsic argument order: `(hotkey, netuid, take: u16)`.
+/// `take` is checked against the chain's [`ChildkeyTakeBounds`], and the
+/// hotkey's last change against `TxChildkeyTakeRateLimit`; a change too soon
+/// fails with a [`RateLimitedError`] naming the block to retry at.
+///
+/// # Arguments
+/// * `client` — The Bittensor RPC client.
+/// * `signer` — The signing keypair (coldkey that owns the hotkey).
+/// * `hotkey` — The parent hotkey.
+/// * `netuid` — The subnet ID.
+/// * `take` — New take value as u16 proportion.
+/// * `wait_for` — How long to wait for on-chain inclusion.
+pub async fn set_childkey_take(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    hotkey: &AccountId32,
+    netuid: u16,
+    take: u16,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    ChildkeyTakeBounds::fetch(client).await?.check(take)?;
+
+    if let Some(last_block) = get_last_childkey_take_block(client, hotkey).await? {
+        let rate_limit = get_childkey_take_rate_limit(client).await?;
+        let current_block = client.block_number().await?;
+        let next_allowed = last_block.saturating_add(rate_limit);
+        if current_block < next_allowed {
+            return Err(RateLimitedError::with_retry_after(
+                format!(
+                    "Childkey take last changed at block {}; retry at block {}",
+                    last_block, next_allowed
+                ),
+                next_allowed - current_block,
+            )
+            .into());
+        }
+    }
+
+    let args = vec![
+        Value::from_bytes(hotkey.encode()),
+        Value::from(netuid),
+        Value::from(take),
+    ];
+    client
+        .submit_extrinsic(
+            SUBTENSOR_MODULE,
+            "set_childkey_take",
+            args,
+            signer,
+            wait_for,
+        )
+        .await
+        .map_err(|e| BittensorError::from_chain("Failed to set childkey take", e))
+}
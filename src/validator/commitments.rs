@@ -1,42 +1,290 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::crv4::{encrypt_for_chain_blocks, get_last_drand_round, DrandClient};
+use crate::errors::{BittensorError, BittensorResult, ExtrinsicError, RateLimitedError};
+use crate::queries::commitments::{get_commitment_rate_limit, get_last_commitment_block};
+use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
 
 const COMMITMENTS_MODULE: &str = "Commitments";
 
+/// Commitment payload, stored on chain as `Raw0`..`Raw128` fields
+///
+/// Payloads longer than [`MAX_FIELD_LEN`](Self::MAX_FIELD_LEN) are split over
+/// several fields, up to [`MAX_FIELDS`](Self::MAX_FIELDS). Each field uses the
+/// `RawN` variant matching its length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitmentData {
+    fields: Vec<Vec<u8>>,
+}
+
+impl CommitmentData {
+    /// Largest `Data::RawN` variant
+    pub const MAX_FIELD_LEN: usize = 128;
+    /// Fields per commitment allowed by the runtime (`MaxFields`)
+    pub const MAX_FIELDS: usize = 3;
+
+    /// Split `data` into raw fields, failing if it does not fit
+    pub fn new(data: impl Into<Vec<u8>>) -> BittensorResult<Self> {
+        let data = data.into();
+        let max_len = Self::MAX_FIELD_LEN * Self::MAX_FIELDS;
+        if data.len() > max_len {
+            return Err(ExtrinsicError::new(format!(
+                "Commitment is {} bytes; at most {} bytes fit in {} fields",
+                data.len(),
+                max_len,
+                Self::MAX_FIELDS
+            ))
+            .into());
+        }
+
+        let fields = if data.is_empty() {
+            vec![Vec::new()]
+        } else {
+            data.chunks(Self::MAX_FIELD_LEN)
+                .map(|chunk| chunk.to_vec())
+                .collect()
+        };
+        Ok(Self { fields })
+    }
+
+    /// Raw fields in order
+    pub fn fields(&self) -> &[Vec<u8>] {
+        &self.fields
+    }
+
+    /// The payload with all fields joined
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.fields.concat()
+    }
+
+    /// `CommitmentInfo { fields }` as a dynamic value
+    fn to_value(&self) -> Value {
+        let fields: Vec<Value> = self
+            .fields
+            .iter()
+            .map(|field| {
+                Value::unnamed_variant(format!("Raw{}", field.len()), [Value::from_bytes(field)])
+            })
+            .collect();
+        Value::named_composite([("fields", Value::unnamed_composite(fields))])
+    }
+}
+
+impl TryFrom<&str> for CommitmentData {
+    type Error = BittensorError;
+
+    fn try_from(data: &str) -> BittensorResult<Self> {
+        Self::new(data.as_bytes())
+    }
+}
+
+impl TryFrom<Vec<u8>> for CommitmentData {
+    type Error = BittensorError;
+
+    fn try_from(data: Vec<u8>) -> BittensorResult<Self> {
+        Self::new(data)
+    }
+}
+
 /// Set a commitment on-chain for a given subnet.
 ///
-/// Commitments pallet dispatch: `set_commitment(netuid, data)`
+/// Commitments pallet dispatch: `set_commitment(netuid, info)`
+///
+/// The pallet's `RateLimit` is checked against the signer's last commitment
+/// block first; if a commitment would be rejected a [`RateLimitedError`]
+/// naming the block to retry at is returned.
 ///
 /// # Arguments
 /// * `client` — The Bittensor RPC client.
-/// * `signer` — The signing keypair.
+/// * `signer` — The signing keypair (hotkey registered on `netuid`).
 /// * `netuid` — The subnet ID.
-/// * `data` — The commitment data bytes.
+/// * `data` — The commitment payload.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn set_commitment(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    data: CommitmentData,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    let hotkey = AccountId32::from(signer.account_id().0);
+    if let Some(last_block) = get_last_commitment_block(client, netuid, &hotkey).await? {
+        let rate_limit = get_commitment_rate_limit(client).await?;
+        let current_block = client.block_number().await?;
+        let next_allowed = last_block.saturating_add(rate_limit);
+        if current_block < next_allowed {
+            return Err(RateLimitedError::with_retry_after(
+                format!(
+                    "Last commitment on subnet {} was at block {}; retry at block {}",
+                    netuid, last_block, next_allowed
+                ),
+                next_allowed - current_block,
+            )
+            .into());
+        }
+    }
+
+    submit_commitment(client, signer, netuid, data.to_value(), wait_for).await
+}
+
+/// Set a commitment that stays encrypted until a future DRAND round.
+///
+/// Commitments pallet dispatch: `set_commitment(netuid, info)` with a single
+/// `TimelockEncrypted { encrypted, reveal_round }` field. The reveal round is
+/// counted `reveal_blocks_ahead` blocks from the chain's last stored DRAND
+/// round and must be ahead of the live beacon; once it is reached the chain
+/// decrypts the data into `RevealedCommitments`.
+///
+/// # Arguments
+/// * `client` — The Bittensor RPC client.
+/// * `signer` — The signing keypair (hotkey registered on `netuid`).
+/// * `netuid` — The subnet ID.
+/// * `data` — The payload to reveal later.
+/// * `reveal_blocks_ahead` — Blocks from now until the reveal.
+/// * `wait_for` — How long to wait for on-chain inclusion.
+///
+/// # Returns
+/// (transaction hash, reveal round)
+pub async fn set_timelocked_commitment(
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     data: &[u8],
+    reveal_blocks_ahead: u64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
-    let fields_value = Value::named_composite([("info", build_commitment_info(data))]);
+) -> BittensorResult<(String, u64)> {
+    let chain_round = get_last_drand_round(client).await?;
+    let (encrypted, reveal_round) = encrypt_for_chain_blocks(
+        data,
+        reveal_blocks_ahead,
+        client.constants().block_time_secs(),
+        chain_round,
+    )?;
 
-    let args = vec![Value::from(netuid), fields_value];
+    // A round that is already public would reveal the data immediately
+    let live_round = match DrandClient::from_env() {
+        Ok(drand) => drand.latest_round().await,
+        Err(e) => Err(e),
+    };
+    match live_round {
+        Ok(live_round) if reveal_round <= live_round => {
+            return Err(ExtrinsicError::new(format!(
+                "Reveal round {} is already in the past: live DRAND round is {}",
+                reveal_round, live_round
+            ))
+            .into());
+        }
+        Ok(_) => {}
+        Err(e) => tracing::warn!(
+            "Could not verify reveal round {} against live beacon: {}",
+            reveal_round,
+            e
+        ),
+    }
+
+    let info = timelocked_info(encrypted, reveal_round);
+    let tx_hash = submit_commitment(client, signer, netuid, info, wait_for).await?;
+    Ok((tx_hash, reveal_round))
+}
+
+/// `CommitmentInfo` holding one `TimelockEncrypted` field
+fn timelocked_info(encrypted: Vec<u8>, reveal_round: u64) -> Value {
+    let field = Value::named_variant(
+        "TimelockEncrypted",
+        [
+            ("encrypted", Value::from_bytes(encrypted)),
+            ("reveal_round", Value::u128(reveal_round as u128)),
+        ],
+    );
+    Value::named_composite([("fields", Value::unnamed_composite(vec![field]))])
+}
+
+async fn submit_commitment(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    info: Value,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    let args = vec![Value::from(netuid), info];
 
     client
         .submit_extrinsic(COMMITMENTS_MODULE, "set_commitment", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set commitment: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set commitment", e))
 }
 
-fn build_commitment_info(data: &[u8]) -> Value {
-    Value::named_composite([(
-        "fields",
-        Value::unnamed_composite(vec![Value::named_variant(
-            "Raw0",
-            [("0", Value::from_bytes(data))],
-        )]),
-    )])
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use subxt::ext::scale_value::{Composite, ValueDef};
+
+    fn variant_names(data: &CommitmentData) -> Vec<String> {
+        let value = data.to_value();
+        let ValueDef::Composite(Composite::Named(info)) = &value.value else {
+            panic!("expected CommitmentInfo");
+        };
+        let ValueDef::Composite(Composite::Unnamed(fields)) = &info[0].1.value else {
+            panic!("expected fields");
+        };
+        fields
+            .iter()
+            .map(|field| match &field.value {
+                ValueDef::Variant(variant) => variant.name.clone(),
+                _ => panic!("expected Data variant"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_commitment_variant_by_length() {
+        let data = CommitmentData::try_from("ipfs://model").unwrap();
+        assert_eq!(variant_names(&data), ["Raw12"]);
+        assert_eq!(data.to_bytes(), b"ipfs://model");
+
+        let empty = CommitmentData::new(Vec::new()).unwrap();
+        assert_eq!(variant_names(&empty), ["Raw0"]);
+
+        let full = CommitmentData::new(vec![7u8; 128]).unwrap();
+        assert_eq!(variant_names(&full), ["Raw128"]);
+    }
+
+    #[test]
+    fn test_commitment_split_across_fields() {
+        let payload: Vec<u8> = (0..300u16).map(|i| i as u8).collect();
+        let data = CommitmentData::new(payload.clone()).unwrap();
+        assert_eq!(variant_names(&data), ["Raw128", "Raw128", "Raw44"]);
+        assert_eq!(data.to_bytes(), payload);
+
+        assert!(CommitmentData::new(vec![0u8; 385]).is_err());
+    }
+
+    #[test]
+    fn test_timelocked_commitment_round_trip() {
+        use crate::crv4::verify_encrypted_data;
+        use crate::utils::decoders::decode_bytes_from_composite;
+
+        let (encrypted, reveal_round) =
+            encrypt_for_chain_blocks(b"model-hash", 50, 12.0, 24_000_000).unwrap();
+        assert_eq!(reveal_round, 24_000_201);
+
+        let info = timelocked_info(encrypted.clone(), reveal_round);
+        let ValueDef::Composite(Composite::Named(info)) = &info.value else {
+            panic!("expected CommitmentInfo");
+        };
+        let ValueDef::Composite(Composite::Unnamed(fields)) = &info[0].1.value else {
+            panic!("expected fields");
+        };
+        let ValueDef::Variant(variant) = &fields[0].value else {
+            panic!("expected Data variant");
+        };
+        assert_eq!(variant.name, "TimelockEncrypted");
+        let Composite::Named(values) = &variant.values else {
+            panic!("expected named fields");
+        };
+
+        let ciphertext = decode_bytes_from_composite(&values[0].1).unwrap();
+        assert_eq!(ciphertext, encrypted);
+        assert!(verify_encrypted_data(&ciphertext));
+    }
 }
@@ -0,0 +1,252 @@
+//! EVM key association
+//!
+//! A hotkey registered on a subnet can be linked to an EVM address with
+//! `associate_evm_key`. The EVM key proves ownership by signing, EIP-191
+//! style (`personal_sign`), the message
+//! `hotkey (32 bytes) ++ keccak256(SCALE(block_number))`.
+//! [`association_message`] builds that message so it can be signed with any
+//! Ethereum wallet.
+
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult, ChainQueryError, ExtrinsicError};
+use crate::utils::decoders::{decode_bytes_from_composite, decode_u64};
+use parity_scale_codec::Encode;
+use sp_core::crypto::AccountId32;
+use sp_core::hashing::keccak_256;
+use subxt::dynamic::Value;
+use subxt::ext::scale_value::{Composite, ValueDef};
+
+const SUBTENSOR_MODULE: &str = "SubtensorModule";
+
+/// Prefix of EIP-191 `personal_sign` messages
+pub const EIP191_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+
+/// Message the EVM key signs to associate with `hotkey`
+///
+/// Pass it to `personal_sign` / `signMessage`; the wallet adds the EIP-191
+/// prefix itself.
+pub fn association_message(hotkey: &AccountId32, block_number: u64) -> Vec<u8> {
+    let block_hash = keccak_256(&block_number.encode());
+    [hotkey.as_ref(), &block_hash[..]].concat()
+}
+
+/// EIP-191 digest of `message`, for signers that sign raw 32-byte hashes
+pub fn eip191_hash(message: &[u8]) -> [u8; 32] {
+    let prefixed = [
+        EIP191_PREFIX.as_bytes(),
+        message.len().to_string().as_bytes(),
+        message,
+    ]
+    .concat();
+    keccak_256(&prefixed)
+}
+
+/// Recover the EVM address that produced `signature` over `message`
+///
+/// Accepts recovery ids `0`/`1` and Ethereum's `27`/`28`. Fails on a
+/// malformed signature.
+pub fn recover_evm_address(message: &[u8], signature: &[u8; 65]) -> BittensorResult<[u8; 20]> {
+    let recovery_id = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        v => {
+            return Err(ExtrinsicError::new(format!("Invalid signature recovery id {}", v)).into())
+        }
+    };
+    let recovery_id = libsecp256k1::RecoveryId::parse(recovery_id)
+        .map_err(|e| ExtrinsicError::new(format!("Invalid signature recovery id: {:?}", e)))?;
+    let rs = libsecp256k1::Signature::parse_standard_slice(&signature[..64])
+        .map_err(|e| ExtrinsicError::new(format!("Malformed signature: {:?}", e)))?;
+    let digest = libsecp256k1::Message::parse(&eip191_hash(message));
+
+    let public = libsecp256k1::recover(&digest, &rs, &recovery_id)
+        .map_err(|e| ExtrinsicError::new(format!("Failed to recover signer: {:?}", e)))?;
+    // Address is the last 20 bytes of keccak256(uncompressed key without 0x04)
+    let hash = keccak_256(&public.serialize()[1..]);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    Ok(address)
+}
+
+/// Associate an EVM address with the signer's hotkey on a subnet.
+///
+/// Subtensor pallet dispatch:
+/// `associate_evm_key(netuid, evm_key, block_number, signature)`.
+/// The signature is checked locally against `evm_address` before submitting.
+///
+/// # Arguments
+/// * `client` — The Bittensor RPC client.
+/// * `signer` — The signing keypair (hotkey registered on `netuid`).
+/// * `netuid` — The subnet ID.
+/// * `evm_address` — The EVM address to associate.
+/// * `block_number` — Block number included in the signed message.
+/// * `evm_signature` — 65-byte `r ++ s ++ v` signature over [`association_message`].
+/// * `wait_for` — How long to wait for on-chain inclusion.
+pub async fn associate_evm_key(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    evm_address: [u8; 20],
+    block_number: u64,
+    evm_signature: [u8; 65],
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    let hotkey = AccountId32::from(signer.account_id().0);
+    let message = association_message(&hotkey, block_number);
+    let recovered = recover_evm_address(&message, &evm_signature)?;
+    if recovered != evm_address {
+        return Err(ExtrinsicError::new(format!(
+            "Signature was made by 0x{}, not 0x{}",
+            hex::encode(recovered),
+            hex::encode(evm_address)
+        ))
+        .into());
+    }
+
+    // The runtime expects a 0/1 recovery id
+    let mut signature = evm_signature;
+    if signature[64] >= 27 {
+        signature[64] -= 27;
+    }
+
+    let args = vec![
+        Value::u128(netuid as u128),
+        Value::from_bytes(evm_address),
+        Value::u128(block_number as u128),
+        Value::from_bytes(signature),
+    ];
+
+    client
+        .submit_extrinsic(
+            SUBTENSOR_MODULE,
+            "associate_evm_key",
+            args,
+            signer,
+            wait_for,
+        )
+        .await
+        .map_err(|e| BittensorError::from_chain("Failed to associate EVM key", e))
+}
+
+/// Get the EVM address associated with a neuron and the block it was set at
+///
+/// Reads `SubtensorModule::AssociatedEvmAddress`.
+pub async fn get_associated_evm_address(
+    client: &impl ChainBackend,
+    netuid: u16,
+    uid: u16,
+) -> BittensorResult<Option<([u8; 20], u64)>> {
+    let value = client
+        .storage_with_keys(
+            SUBTENSOR_MODULE,
+            "AssociatedEvmAddress",
+            vec![Value::u128(netuid as u128), Value::u128(uid as u128)],
+        )
+        .await?;
+    value.map(|value| decode_association(&value)).transpose()
+}
+
+/// Decode `(H160, u64)`
+fn decode_association(value: &Value) -> BittensorResult<([u8; 20], u64)> {
+    let fields: Vec<&Value> = match &value.value {
+        ValueDef::Composite(Composite::Unnamed(values)) => values.iter().collect(),
+        ValueDef::Composite(Composite::Named(fields)) => fields.iter().map(|(_, v)| v).collect(),
+        _ => Vec::new(),
+    };
+    let [address, block] = fields.as_slice() else {
+        return Err(ExtrinsicError::new("Unexpected AssociatedEvmAddress layout").into());
+    };
+    let address: [u8; 20] = decode_bytes_from_composite(address)?
+        .try_into()
+        .map_err(|_| ExtrinsicError::new("EVM address is not 20 bytes"))?;
+    let block = decode_u64(block)
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode association block: {}", e)))?;
+    Ok((address, block))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // //Alice hotkey, secp256k1 private key 0x00..01
+    const HOTKEY: &str = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+    const EVM_ADDRESS: &str = "7e5f4552091a69125d5dfcb7b8c2659029395bdf";
+    const SIGNATURE: &str = "92a0b8959f644b4065465e70e3db5677caa76b6df3587af544ff750ada3b3118\
+                             0ebca9e29b56df34f1fa205bab01bc777d6c2637458c5bd11a5b77d33df5afab01";
+
+    fn hotkey() -> AccountId32 {
+        AccountId32::new(hex::decode(HOTKEY).unwrap().try_into().unwrap())
+    }
+
+    fn signature() -> [u8; 65] {
+        hex::decode(SIGNATURE).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_association_payload() {
+        let message = association_message(&hotkey(), 1000);
+        assert_eq!(
+            hex::encode(&message),
+            format!(
+                "{}{}",
+                HOTKEY, "21c9650f3e9e5e2b94468fe9f1a4351613d2f305993af006c012c30ea53117e9"
+            )
+        );
+        assert_eq!(
+            hex::encode(eip191_hash(&message)),
+            "980e2b1cbe36401e119de55aa339b359f8ef1c0f00b4249eb7fec8745252202e"
+        );
+    }
+
+    #[test]
+    fn test_sign_and_recover() {
+        let mut key = [0u8; 32];
+        key[31] = 1;
+        let key = libsecp256k1::SecretKey::parse(&key).unwrap();
+        let message = association_message(&hotkey(), 1000);
+        let (rs, recovery_id) =
+            libsecp256k1::sign(&libsecp256k1::Message::parse(&eip191_hash(&message)), &key);
+
+        let mut signature = [0u8; 65];
+        signature[..64].copy_from_slice(&rs.serialize());
+        signature[64] = recovery_id.serialize();
+        assert_eq!(signature, self::signature());
+
+        let address = recover_evm_address(&message, &signature).unwrap();
+        assert_eq!(hex::encode(address), EVM_ADDRESS);
+
+        // Ethereum-style v
+        signature[64] += 27;
+        assert_eq!(recover_evm_address(&message, &signature).unwrap(), address);
+    }
+
+    #[test]
+    fn test_rejects_malformed_signatures() {
+        let message = association_message(&hotkey(), 1000);
+
+        let mut bad_v = signature();
+        bad_v[64] = 5;
+        assert!(recover_evm_address(&message, &bad_v).is_err());
+
+        // s = 0 is not a valid signature
+        let mut zero_s = signature();
+        zero_s[32..64].fill(0);
+        assert!(recover_evm_address(&message, &zero_s).is_err());
+
+        // A different block recovers a different address
+        let other = association_message(&hotkey(), 1001);
+        assert_ne!(
+            recover_evm_address(&other, &signature()).ok(),
+            Some(hex::decode(EVM_ADDRESS).unwrap().try_into().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_decode_association() {
+        let address = [0x7eu8; 20];
+        let value =
+            Value::unnamed_composite(vec![Value::from_bytes(address), Value::u128(4_000_000)]);
+        assert_eq!(decode_association(&value).unwrap(), (address, 4_000_000));
+        assert!(decode_association(&Value::u128(1)).is_err());
+    }
+}
@@ -1,5 +1,5 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult};
 use subxt::dynamic::Value;
 
 const ADMIN_UTILS: &str = "AdminUtils";
@@ -14,46 +14,48 @@ const ADMIN_UTILS: &str = "AdminUtils";
 /// * `value` — The value to set (as u64, cast appropriately on-chain).
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn set_hyperparameter(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     param_name: &str,
     value: u64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let function = format!("sudo_set_{}", param_name);
     let args = vec![Value::from(netuid), Value::from(value)];
 
     client
         .submit_extrinsic(ADMIN_UTILS, &function, args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set hyperparameter '{}': {}", param_name, e))
+        .map_err(|e| {
+            BittensorError::from_chain(format!("Failed to set hyperparameter '{}'", param_name), e)
+        })
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_tempo(netuid, tempo)`
 pub async fn sudo_set_tempo(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     tempo: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(tempo)];
 
     client
         .submit_extrinsic(ADMIN_UTILS, "sudo_set_tempo", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set tempo: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set tempo", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_weights_rate_limit(netuid, rate_limit)`
 pub async fn sudo_set_weights_rate_limit(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     rate_limit: u64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(rate_limit)];
 
     client
@@ -65,17 +67,17 @@ pub async fn sudo_set_weights_rate_limit(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set weights rate limit: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set weights rate limit", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_max_allowed_validators(netuid, max_validators)`
 pub async fn sudo_set_max_allowed_validators(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     max_validators: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(max_validators)];
 
     client
@@ -87,17 +89,17 @@ pub async fn sudo_set_max_allowed_validators(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set max allowed validators: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set max allowed validators", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_min_allowed_weights(netuid, min_weights)`
 pub async fn sudo_set_min_allowed_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     min_weights: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(min_weights)];
 
     client
@@ -109,17 +111,17 @@ pub async fn sudo_set_min_allowed_weights(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set min allowed weights: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set min allowed weights", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_max_weights_limit(netuid, max_weights)`
 pub async fn sudo_set_max_weights_limit(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     max_weights: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(max_weights)];
 
     client
@@ -131,17 +133,17 @@ pub async fn sudo_set_max_weights_limit(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set max weights limit: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set max weights limit", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_immunity_period(netuid, immunity_period)`
 pub async fn sudo_set_immunity_period(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     immunity_period: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(immunity_period)];
 
     client
@@ -153,17 +155,17 @@ pub async fn sudo_set_immunity_period(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set immunity period: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set immunity period", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_activity_cutoff(netuid, activity_cutoff)`
 pub async fn sudo_set_activity_cutoff(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     activity_cutoff: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(activity_cutoff)];
 
     client
@@ -175,17 +177,17 @@ pub async fn sudo_set_activity_cutoff(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set activity cutoff: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set activity cutoff", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_registration_allowed(netuid, allowed)`
 pub async fn sudo_set_registration_allowed(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     allowed: bool,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::bool(allowed)];
 
     client
@@ -197,17 +199,17 @@ pub async fn sudo_set_registration_allowed(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set registration allowed: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set registration allowed", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_target_registrations_per_interval(netuid, target)`
 pub async fn sudo_set_target_registrations_per_interval(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     target: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(target)];
 
     client
@@ -219,33 +221,35 @@ pub async fn sudo_set_target_registrations_per_interval(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set target registrations per interval: {}", e))
+        .map_err(|e| {
+            BittensorError::from_chain("Failed to set target registrations per interval", e)
+        })
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_difficulty(netuid, difficulty)`
 pub async fn sudo_set_difficulty(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     difficulty: u64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(difficulty)];
 
     client
         .submit_extrinsic(ADMIN_UTILS, "sudo_set_difficulty", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set difficulty: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set difficulty", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_max_registrations_per_block(netuid, max_registrations)`
 pub async fn sudo_set_max_registrations_per_block(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     max_registrations: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(max_registrations)];
 
     client
@@ -257,17 +261,17 @@ pub async fn sudo_set_max_registrations_per_block(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set max registrations per block: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set max registrations per block", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_serving_rate_limit(netuid, rate_limit)`
 pub async fn sudo_set_serving_rate_limit(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     rate_limit: u64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from(rate_limit)];
 
     client
@@ -279,17 +283,17 @@ pub async fn sudo_set_serving_rate_limit(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set serving rate limit: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set serving rate limit", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_commit_reveal_weights_enabled(netuid, enabled)`
 pub async fn sudo_set_commit_reveal_weights_enabled(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     enabled: bool,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::bool(enabled)];
 
     client
@@ -301,17 +305,17 @@ pub async fn sudo_set_commit_reveal_weights_enabled(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set commit reveal weights enabled: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set commit reveal weights enabled", e))
 }
 
 /// AdminUtils pallet dispatch: `sudo_set_liquid_alpha_enabled(netuid, enabled)`
 pub async fn sudo_set_liquid_alpha_enabled(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     enabled: bool,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::bool(enabled)];
 
     client
@@ -323,5 +327,5 @@ pub async fn sudo_set_liquid_alpha_enabled(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set liquid alpha enabled: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set liquid alpha enabled", e))
 }
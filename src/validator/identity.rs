@@ -1,5 +1,5 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult};
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
@@ -20,7 +20,7 @@ const SUBTENSOR_MODULE: &str = "SubtensorModule";
 /// * `wait_for` — How long to wait for on-chain inclusion.
 #[allow(clippy::too_many_arguments)]
 pub async fn set_identity(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     name: &str,
     url: &str,
@@ -29,7 +29,7 @@ pub async fn set_identity(
     discord: &str,
     additional: &str,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![
         Value::from_bytes(name.as_bytes()),
         Value::from_bytes(url.as_bytes()),
@@ -42,7 +42,7 @@ pub async fn set_identity(
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "set_identity", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set identity: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set identity", e))
 }
 
 /// Set identity for a subnet.
@@ -58,14 +58,14 @@ pub async fn set_identity(
 /// * `subnet_contact` — Contact information.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn set_subnet_identity(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     subnet_name: &str,
     github_repo: &str,
     subnet_contact: &str,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![
         Value::from(netuid),
         Value::from_bytes(subnet_name.as_bytes()),
@@ -82,7 +82,7 @@ pub async fn set_subnet_identity(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set subnet identity: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set subnet identity", e))
 }
 
 /// Clear the on-chain identity for the signer's account.
@@ -94,14 +94,14 @@ pub async fn set_subnet_identity(
 /// * `signer` — The signing keypair.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn clear_identity(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args: Vec<Value> = vec![];
 
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "clear_identity", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to clear identity: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to clear identity", e))
 }
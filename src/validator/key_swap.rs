@@ -1,5 +1,6 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult};
+use crate::utils::ss58::encode_ss58;
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
@@ -8,8 +9,9 @@ const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
 /// Schedule a coldkey swap to a new coldkey.
 ///
-/// Subtensor pallet dispatch: `schedule_coldkey_swap(new_coldkey)`
-/// Matches Python SDK's `subtensor.schedule_coldkey_swap()`.
+/// Subtensor pallet dispatch: `schedule_swap_coldkey(new_coldkey)`
+/// Matches Python SDK's `subtensor.schedule_coldkey_swap()`. Checked the same
+/// way as [`schedule_swap_coldkey`](super::security::schedule_swap_coldkey).
 ///
 /// # Arguments
 /// * `client` — The Bittensor RPC client.
@@ -17,23 +19,13 @@ const SUBTENSOR_MODULE: &str = "SubtensorModule";
 /// * `new_coldkey` — The new coldkey to swap to.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn schedule_coldkey_swap(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     new_coldkey: &AccountId32,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
-    let args = vec![Value::from_bytes(new_coldkey.encode())];
-
-    client
-        .submit_extrinsic(
-            SUBTENSOR_MODULE,
-            "schedule_coldkey_swap",
-            args,
-            signer,
-            wait_for,
-        )
+) -> BittensorResult<String> {
+    super::security::schedule_swap_coldkey(client, signer, &encode_ss58(new_coldkey), wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to schedule coldkey swap: {}", e))
 }
 
 /// Schedule a hotkey swap from an old hotkey to a new hotkey.
@@ -47,12 +39,12 @@ pub async fn schedule_coldkey_swap(
 /// * `new_hotkey` — The new hotkey to swap to.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn schedule_hotkey_swap(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     old_hotkey: &AccountId32,
     new_hotkey: &AccountId32,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![
         Value::from_bytes(old_hotkey.encode()),
         Value::from_bytes(new_hotkey.encode()),
@@ -67,7 +59,7 @@ pub async fn schedule_hotkey_swap(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to schedule hotkey swap: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to schedule hotkey swap", e))
 }
 
 /// Schedule the dissolution of a network.
@@ -80,11 +72,11 @@ pub async fn schedule_hotkey_swap(
 /// * `netuid` — The subnet ID to schedule for dissolution.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn schedule_dissolve_network(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid)];
 
     client
@@ -96,5 +88,5 @@ pub async fn schedule_dissolve_network(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to schedule dissolve network: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to schedule dissolve network", e))
 }
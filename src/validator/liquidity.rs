@@ -1,96 +1,165 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
+use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult, StakeFailed};
+use crate::queries::balances::get_balance;
+use crate::queries::liquidity::{get_current_subnet_price, ticks};
+use crate::queries::stakes::get_stake;
 use crate::utils::balance_newtypes::Rao;
-use anyhow::Result;
+use parity_scale_codec::Encode;
+use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
 
-const SUBTENSOR_MODULE: &str = "SubtensorModule";
+const SWAP_PALLET: &str = "Swap";
 
-/// Add liquidity to a subnet pool.
+/// Add a concentrated-liquidity position funded with `tao_amount`.
+///
+/// Prices (TAO per alpha) are converted to ticks with
+/// [`ticks::price_range_to_ticks`]. If the range straddles the current
+/// price, the matching alpha is taken from the hotkey's stake as well. The
+/// coldkey's balance and stake are checked before submitting.
+///
+/// Swap pallet dispatch: `add_liquidity(hotkey, netuid, tick_low, tick_high, liquidity)`.
 ///
 /// # Arguments
 /// * `client` — The Bittensor RPC client.
-/// * `signer` — The signing keypair.
+/// * `signer` — The signing keypair (coldkey).
 /// * `netuid` — The subnet ID.
-/// * `amount_a` — First token amount **in RAO** (1 TAO = 1e9 RAO).
-/// * `amount_b` — Second token amount **in RAO** (1 TAO = 1e9 RAO).
+/// * `hotkey` — Hotkey whose stake backs the alpha side.
+/// * `tao_amount` — TAO to deposit **in RAO** (1 TAO = 1e9 RAO).
+/// * `price_low` — Lower bound of the price range.
+/// * `price_high` — Upper bound of the price range.
 /// * `wait_for` — How long to wait for on-chain inclusion.
+#[allow(clippy::too_many_arguments)]
 pub async fn add_liquidity(
     client: &BittensorClient,
     signer: &BittensorSigner,
     netuid: u16,
-    amount_a: Rao,
-    amount_b: Rao,
+    hotkey: &AccountId32,
+    tao_amount: Rao,
+    price_low: f64,
+    price_high: f64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
+    if tao_amount.as_u128() == 0 {
+        return Err(StakeFailed::new("Liquidity amount must be greater than zero").into());
+    }
+
+    let (tick_low, tick_high) = ticks::price_range_to_ticks(price_low, price_high)?;
+    let current_price = get_current_subnet_price(client, netuid).await?;
+    let liquidity =
+        ticks::liquidity_for_tao(tao_amount.as_u128(), current_price, tick_low, tick_high)?;
+    let (alpha_needed, tao_needed) =
+        ticks::token_amounts(liquidity, current_price, tick_low, tick_high);
+
+    let coldkey = AccountId32::from(signer.account_id().0);
+    let balance = get_balance(client, &coldkey).await?;
+    if balance.as_u128() < tao_needed {
+        return Err(StakeFailed::new(format!(
+            "Insufficient balance: have {} RAO, need {} RAO",
+            balance.as_u128(),
+            tao_needed
+        ))
+        .into());
+    }
+    if alpha_needed > 0 {
+        let stake = get_stake(client, &coldkey, hotkey, netuid)
+            .await
+            .unwrap_or(Rao::ZERO);
+        if stake.as_u128() < alpha_needed {
+            return Err(StakeFailed::new(format!(
+                "Insufficient alpha stake on netuid {}: have {}, need {}",
+                netuid,
+                stake.as_u128(),
+                alpha_needed
+            ))
+            .into());
+        }
+    }
+
     let args = vec![
+        Value::from_bytes(hotkey.encode()),
         Value::from(netuid),
-        Value::u128(amount_a.as_u128()),
-        Value::u128(amount_b.as_u128()),
+        Value::i128(tick_low as i128),
+        Value::i128(tick_high as i128),
+        Value::u128(liquidity as u128),
     ];
 
     client
-        .submit_extrinsic(SUBTENSOR_MODULE, "add_liquidity", args, signer, wait_for)
+        .submit_extrinsic(SWAP_PALLET, "add_liquidity", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to add liquidity: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to add liquidity", e))
 }
 
-/// Remove liquidity from a subnet pool.
+/// Remove a liquidity position, returning its tokens and fees.
+///
+/// Swap pallet dispatch: `remove_liquidity(hotkey, netuid, position_id)`.
 ///
 /// # Arguments
 /// * `client` — The Bittensor RPC client.
-/// * `signer` — The signing keypair.
+/// * `signer` — The signing keypair (coldkey that owns the position).
 /// * `netuid` — The subnet ID.
-/// * `liquidity_amount` — Amount of liquidity tokens to remove **in RAO** (1 TAO = 1e9 RAO).
+/// * `hotkey` — Hotkey the position was opened with.
+/// * `position_id` — Position ID from [`get_positions`](crate::queries::liquidity::get_positions).
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn remove_liquidity(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
-    liquidity_amount: Rao,
+    hotkey: &AccountId32,
+    position_id: u64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
-    let args = vec![Value::from(netuid), Value::u128(liquidity_amount.as_u128())];
+) -> BittensorResult<String> {
+    let args = vec![
+        Value::from_bytes(hotkey.encode()),
+        Value::from(netuid),
+        Value::u128(position_id as u128),
+    ];
 
     client
-        .submit_extrinsic(SUBTENSOR_MODULE, "remove_liquidity", args, signer, wait_for)
+        .submit_extrinsic(SWAP_PALLET, "remove_liquidity", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to remove liquidity: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to remove liquidity", e))
 }
 
-/// Modify a concentrated-liquidity position.
+/// Modify the liquidity of an existing position.
+///
+/// The `liquidity_delta` is a signed value representing the change in
+/// liquidity — positive to add, negative to remove. This is NOT an amount in
+/// RAO; it is the raw liquidity-unit delta used by the AMM.
 ///
-/// The `liquidity_delta` is a signed value (`i128`) representing the change
-/// in liquidity — positive to add, negative to remove.  This is NOT an
-/// amount in RAO; it is the raw liquidity-unit delta used by the AMM.
+/// Swap pallet dispatch: `modify_position(hotkey, netuid, position_id, liquidity_delta)`.
 ///
 /// # Arguments
 /// * `client` — The Bittensor RPC client.
-/// * `signer` — The signing keypair.
+/// * `signer` — The signing keypair (coldkey that owns the position).
 /// * `netuid` — The subnet ID.
-/// * `tick_lower` — Lower tick boundary.
-/// * `tick_upper` — Upper tick boundary.
+/// * `hotkey` — Hotkey the position was opened with.
+/// * `position_id` — Position ID to modify.
 /// * `liquidity_delta` — Signed change in liquidity units.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn modify_liquidity(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
-    tick_lower: i32,
-    tick_upper: i32,
-    liquidity_delta: i128,
+    hotkey: &AccountId32,
+    position_id: u64,
+    liquidity_delta: i64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
+    if liquidity_delta == 0 {
+        return Err(StakeFailed::new("Liquidity delta must be non-zero").into());
+    }
+
     let args = vec![
+        Value::from_bytes(hotkey.encode()),
         Value::from(netuid),
-        Value::i128(tick_lower as i128),
-        Value::i128(tick_upper as i128),
-        Value::i128(liquidity_delta),
+        Value::u128(position_id as u128),
+        Value::i128(liquidity_delta as i128),
     ];
 
     client
-        .submit_extrinsic(SUBTENSOR_MODULE, "modify_liquidity", args, signer, wait_for)
+        .submit_extrinsic(SWAP_PALLET, "modify_position", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to modify liquidity: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to modify liquidity", e))
 }
 
 /// Toggle user liquidity permission for a subnet.
@@ -102,22 +171,16 @@ pub async fn modify_liquidity(
 /// * `enabled` — `true` to allow user liquidity operations, `false` to disable.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn toggle_user_liquidity(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     enabled: bool,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::bool(enabled)];
 
     client
-        .submit_extrinsic(
-            SUBTENSOR_MODULE,
-            "toggle_user_liquidity",
-            args,
-            signer,
-            wait_for,
-        )
+        .submit_extrinsic(SWAP_PALLET, "toggle_user_liquidity", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to toggle user liquidity: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to toggle user liquidity", e))
 }
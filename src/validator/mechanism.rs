@@ -1,10 +1,236 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::crv4::get_mechid_storage_index;
+use crate::errors::{BittensorError, BittensorResult, InvalidWeights, WeightsError};
+use crate::queries::neurons::get_uid_for_hotkey;
+use crate::utils::decoders::{decode_u64, decode_vec_bool, decode_vec_u64};
 use crate::utils::{commit_hash_to_hex, generate_mechanism_commit_hash, generate_salt};
-use anyhow::Result;
+use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
+/// The specific chain constraint that prevents a hotkey from setting weights
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WeightSettingLimit {
+    /// Hotkey has no UID on the subnet
+    NotRegistered,
+    /// `WeightsSetRateLimit` has not elapsed since the mechanism's `LastUpdate`
+    RateLimited {
+        rate_limit: u64,
+        blocks_since_last_update: u64,
+    },
+    /// Hotkey has no validator permit (only self-weights are accepted)
+    NoValidatorPermit,
+    /// Fewer weights than `MinAllowedWeights`
+    TooFewWeights { count: usize, min_allowed: u64 },
+    /// Largest normalized weight exceeds `MaxWeightsLimit`
+    MaxWeightExceeded { max_weight: u16, limit: u16 },
+    /// `uids` and `weights` differ in length
+    LengthMismatch { uids: usize, weights: usize },
+}
+
+impl std::fmt::Display for WeightSettingLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotRegistered => write!(f, "hotkey is not registered on the subnet"),
+            Self::RateLimited {
+                rate_limit,
+                blocks_since_last_update,
+            } => write!(
+                f,
+                "weights rate limit not elapsed: {} blocks since last update, limit is {}",
+                blocks_since_last_update, rate_limit
+            ),
+            Self::NoValidatorPermit => write!(f, "hotkey has no validator permit"),
+            Self::TooFewWeights { count, min_allowed } => write!(
+                f,
+                "too few weights: {} provided, min_allowed_weights is {}",
+                count, min_allowed
+            ),
+            Self::MaxWeightExceeded { max_weight, limit } => write!(
+                f,
+                "normalized max weight {} exceeds max_weights_limit {}",
+                max_weight, limit
+            ),
+            Self::LengthMismatch { uids, weights } => write!(
+                f,
+                "uids and weights length mismatch: {} uids, {} weights",
+                uids, weights
+            ),
+        }
+    }
+}
+
+/// Whether a hotkey can set weights on a subnet mechanism right now
+#[derive(Clone, Debug)]
+pub struct WeightSettingStatus {
+    /// True when no chain constraint blocks the hotkey
+    pub allowed: bool,
+    /// Blocks to wait before the rate limit allows another update (0 when allowed)
+    pub blocks_until_allowed: u64,
+    /// The constraint that blocks the hotkey, if any
+    pub blocking_limit: Option<WeightSettingLimit>,
+    /// Hotkey UID on the subnet
+    pub uid: Option<u16>,
+    /// Subnet `WeightsSetRateLimit`
+    pub weights_rate_limit: u64,
+    /// Blocks since the mechanism's `LastUpdate` for this UID
+    pub blocks_since_last_update: u64,
+    /// Whether the UID holds a validator permit
+    pub has_validator_permit: bool,
+    /// Subnet `MinAllowedWeights`
+    pub min_allowed_weights: u64,
+    /// Subnet `MaxWeightsLimit` (u16 fraction of the normalized total)
+    pub max_weights_limit: u16,
+}
+
+impl WeightSettingStatus {
+    /// True when `uids` only contains the hotkey's own UID
+    ///
+    /// Subtensor accepts self-weights without a validator permit and without
+    /// checking weight bounds.
+    pub fn is_self_weight(&self, uids: &[u16]) -> bool {
+        uids.len() == 1 && self.uid == Some(uids[0])
+    }
+
+    /// Check a concrete weight vector against the subnet's min/max weight constraints
+    ///
+    /// Mirrors subtensor's `check_length` and `max_weight_limited`: a vector that
+    /// only sets the hotkey's own weight is always accepted.
+    pub fn check_weights(&self, uids: &[u16], weights: &[u16]) -> Result<(), WeightSettingLimit> {
+        if uids.len() != weights.len() {
+            return Err(WeightSettingLimit::LengthMismatch {
+                uids: uids.len(),
+                weights: weights.len(),
+            });
+        }
+
+        if self.is_self_weight(uids) {
+            return Ok(());
+        }
+
+        if (uids.len() as u64) < self.min_allowed_weights {
+            return Err(WeightSettingLimit::TooFewWeights {
+                count: uids.len(),
+                min_allowed: self.min_allowed_weights,
+            });
+        }
+
+        if self.max_weights_limit < u16::MAX {
+            let sum: u64 = weights.iter().map(|&w| w as u64).sum();
+            let max = weights.iter().copied().max().unwrap_or(0) as u64;
+            if let Some(max_weight) = (max * u16::MAX as u64).checked_div(sum) {
+                let max_weight = max_weight as u16;
+                if max_weight > self.max_weights_limit {
+                    return Err(WeightSettingLimit::MaxWeightExceeded {
+                        max_weight,
+                        limit: self.max_weights_limit,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check whether `hotkey` can set weights on `netuid`/`mechanism_id` right now
+///
+/// Reads `WeightsSetRateLimit`, the mechanism's `LastUpdate` (indexed with
+/// `get_mechid_storage_index`), `ValidatorPermit`, `MinAllowedWeights` and
+/// `MaxWeightsLimit`. Use `WeightSettingStatus::check_weights` to validate a
+/// concrete weight vector against the returned limits.
+pub async fn can_set_weights(
+    client: &impl ChainBackend,
+    netuid: u16,
+    mechanism_id: u8,
+    hotkey: &AccountId32,
+) -> BittensorResult<WeightSettingStatus> {
+    let netuid_key = vec![Value::u128(netuid as u128)];
+    let storage_index = get_mechid_storage_index(netuid, mechanism_id);
+
+    let (uid, current_block, rate_limit, last_updates, permits, min_allowed, max_limit) = tokio::try_join!(
+        get_uid_for_hotkey(client, netuid, hotkey),
+        async { client.block_number().await.map_err(BittensorError::from) },
+        fetch_u64(client, "WeightsSetRateLimit", netuid_key.clone()),
+        async {
+            Ok::<_, BittensorError>(
+                client
+                    .storage_with_keys(
+                        SUBTENSOR_MODULE,
+                        "LastUpdate",
+                        vec![Value::u128(storage_index as u128)],
+                    )
+                    .await?
+                    .and_then(|v| decode_vec_u64(&v).ok())
+                    .unwrap_or_default(),
+            )
+        },
+        async {
+            Ok::<_, BittensorError>(
+                client
+                    .storage_with_keys(SUBTENSOR_MODULE, "ValidatorPermit", netuid_key.clone())
+                    .await?
+                    .and_then(|v| decode_vec_bool(&v).ok())
+                    .unwrap_or_default(),
+            )
+        },
+        fetch_u64(client, "MinAllowedWeights", netuid_key.clone()),
+        fetch_u64(client, "MaxWeightsLimit", netuid_key.clone()),
+    )?;
+
+    let mut status = WeightSettingStatus {
+        allowed: false,
+        blocks_until_allowed: 0,
+        blocking_limit: None,
+        uid,
+        weights_rate_limit: rate_limit,
+        blocks_since_last_update: 0,
+        has_validator_permit: false,
+        min_allowed_weights: min_allowed,
+        max_weights_limit: max_limit.min(u16::MAX as u64) as u16,
+    };
+
+    let Some(uid) = uid else {
+        status.blocking_limit = Some(WeightSettingLimit::NotRegistered);
+        return Ok(status);
+    };
+
+    let last_update = last_updates.get(uid as usize).copied().unwrap_or(0);
+    status.blocks_since_last_update = current_block.saturating_sub(last_update);
+    status.has_validator_permit = permits.get(uid as usize).copied().unwrap_or(false);
+
+    // Subtensor: a UID that never set weights is not rate limited
+    if last_update != 0 && status.blocks_since_last_update < rate_limit {
+        status.blocks_until_allowed = rate_limit - status.blocks_since_last_update;
+        status.blocking_limit = Some(WeightSettingLimit::RateLimited {
+            rate_limit,
+            blocks_since_last_update: status.blocks_since_last_update,
+        });
+        return Ok(status);
+    }
+
+    if !status.has_validator_permit {
+        status.blocking_limit = Some(WeightSettingLimit::NoValidatorPermit);
+        return Ok(status);
+    }
+
+    status.allowed = true;
+    Ok(status)
+}
+
+async fn fetch_u64(
+    client: &impl ChainBackend,
+    entry: &str,
+    keys: Vec<Value>,
+) -> BittensorResult<u64> {
+    Ok(client
+        .storage_with_keys(SUBTENSOR_MODULE, entry, keys)
+        .await?
+        .and_then(|v| decode_u64(&v).ok())
+        .unwrap_or(0))
+}
+
 /// Helper struct for mechanism commit-reveal data
 #[derive(Clone, Debug)]
 pub struct MechanismCommitRevealData {
@@ -52,21 +278,22 @@ pub fn prepare_mechanism_commit(
 /// Commit mechanism weights
 /// Subtensor expects: (netuid, mecid: u8, commit_hash: H256)
 pub async fn commit_mechanism_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     mechanism_id: u8,
     commit_hash: &str,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
-    let hash_bytes =
-        hex::decode(commit_hash).map_err(|e| anyhow::anyhow!("Invalid commit hash: {}", e))?;
+) -> BittensorResult<String> {
+    let hash_bytes = hex::decode(commit_hash)
+        .map_err(|e| WeightsError::new(format!("Invalid commit hash: {}", e)))?;
 
     if hash_bytes.len() != 32 {
-        return Err(anyhow::anyhow!(
+        return Err(WeightsError::new(format!(
             "Commit hash must be 32 bytes, got {}",
             hash_bytes.len()
-        ));
+        ))
+        .into());
     }
 
     let args = vec![
@@ -84,14 +311,14 @@ pub async fn commit_mechanism_weights(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to commit mechanism weights: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to commit mechanism weights", e))
 }
 
 /// Reveal mechanism weights
 /// Subtensor expects: (netuid, mecid: u8, uids: Vec<u16>, values: Vec<u16>, salt: Vec<u16>, version_key: u64)
 #[allow(clippy::too_many_arguments)]
 pub async fn reveal_mechanism_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     mechanism_id: u8,
@@ -100,11 +327,9 @@ pub async fn reveal_mechanism_weights(
     _salt: &[u16],
     version_key: u64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if uids.len() != weights.len() {
-        return Err(anyhow::anyhow!(
-            "UIDS and weights must have the same length"
-        ));
+        return Err(InvalidWeights::new("UIDS and weights must have the same length").into());
     }
 
     let uid_values: Vec<Value> = uids.iter().map(|uid| Value::from(*uid)).collect();
@@ -128,14 +353,14 @@ pub async fn reveal_mechanism_weights(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to reveal mechanism weights: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to reveal mechanism weights", e))
 }
 
 /// Set mechanism weights directly
 /// Subtensor expects: (netuid, mecid: u8, dests: Vec<u16>, weights: Vec<u16>, version_key: u64)
 #[allow(clippy::too_many_arguments)]
 pub async fn set_mechanism_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     mechanism_id: u8,
@@ -143,11 +368,9 @@ pub async fn set_mechanism_weights(
     weights: &[u16],
     version_key: u64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if uids.len() != weights.len() {
-        return Err(anyhow::anyhow!(
-            "UIDS and weights must have the same length"
-        ));
+        return Err(InvalidWeights::new("UIDS and weights must have the same length").into());
     }
 
     let uid_values: Vec<Value> = uids.iter().map(|uid| Value::from(*uid)).collect();
@@ -169,5 +392,79 @@ pub async fn set_mechanism_weights(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set mechanism weights: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set mechanism weights", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(uid: u16, min_allowed: u64, max_limit: u16) -> WeightSettingStatus {
+        WeightSettingStatus {
+            allowed: true,
+            blocks_until_allowed: 0,
+            blocking_limit: None,
+            uid: Some(uid),
+            weights_rate_limit: 100,
+            blocks_since_last_update: 200,
+            has_validator_permit: true,
+            min_allowed_weights: min_allowed,
+            max_weights_limit: max_limit,
+        }
+    }
+
+    #[test]
+    fn test_check_weights_min_allowed() {
+        let s = status(0, 3, u16::MAX);
+        assert_eq!(
+            s.check_weights(&[1, 2], &[100, 100]),
+            Err(WeightSettingLimit::TooFewWeights {
+                count: 2,
+                min_allowed: 3
+            })
+        );
+        assert!(s.check_weights(&[1, 2, 3], &[100, 100, 100]).is_ok());
+    }
+
+    #[test]
+    fn test_check_weights_self_weight_bypasses_limits() {
+        let s = status(5, 8, 1000);
+        assert!(s.check_weights(&[5], &[u16::MAX]).is_ok());
+        assert!(s.check_weights(&[6], &[u16::MAX]).is_err());
+    }
+
+    #[test]
+    fn test_check_weights_max_weight_limit() {
+        // Limit of 50% of the normalized total
+        let s = status(0, 1, u16::MAX / 2);
+        assert!(s.check_weights(&[1, 2], &[100, 100]).is_ok());
+        assert!(matches!(
+            s.check_weights(&[1, 2], &[300, 100]),
+            Err(WeightSettingLimit::MaxWeightExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_weights_length_mismatch() {
+        let s = status(0, 1, u16::MAX);
+        assert_eq!(
+            s.check_weights(&[1, 2], &[100]),
+            Err(WeightSettingLimit::LengthMismatch {
+                uids: 2,
+                weights: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_limit_display_is_precise() {
+        let limit = WeightSettingLimit::RateLimited {
+            rate_limit: 100,
+            blocks_since_last_update: 40,
+        };
+        assert_eq!(
+            limit.to_string(),
+            "weights rate limit not elapsed: 40 blocks since last update, limit is 100"
+        );
+    }
 }
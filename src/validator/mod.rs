@@ -1,5 +1,6 @@
 pub mod children;
 pub mod commitments;
+pub mod evm;
 pub mod hyperparameters;
 pub mod identity;
 pub mod key_swap;
@@ -8,9 +9,11 @@ pub mod mechanism;
 pub mod proxy;
 pub mod registration;
 pub mod root;
+pub mod security;
 pub mod senate;
 pub mod serving;
 pub mod staking;
+pub mod subnet_admin;
 pub mod subnet_management;
 pub mod sudo;
 pub mod take;
@@ -19,11 +22,14 @@ pub mod utility;
 pub mod weights;
 
 pub use staking::{add_stake, unstake};
-pub use weights::{commit_weights, reveal_weights, set_weights};
+pub use weights::{
+    check_version_key, commit_weights, resolve_version_key, reveal_weights, set_weights,
+};
 // get_stake is in queries::stakes with netuid parameter
 pub use crate::queries::stakes::get_stake;
 pub use children::*;
 pub use commitments::*;
+pub use evm::*;
 pub use hyperparameters::*;
 pub use identity::*;
 pub use key_swap::*;
@@ -32,10 +38,16 @@ pub use mechanism::*;
 pub use proxy::*;
 pub use registration::{is_registered, register, swap_hotkey};
 pub use root::*;
+pub use security::*;
 pub use senate::*;
-pub use serving::{serve_axon, serve_axon_tls};
+pub use serving::{serve_axon, serve_axon_auto, serve_axon_tls, Served};
+pub use subnet_admin::*;
 pub use subnet_management::*;
 pub use sudo::*;
 pub use take::*;
-pub use transfer::{transfer, transfer_stake};
+pub use transfer::{
+    get_transfer_plan, plan_safe_transfer, plan_transfer, preview_transfer, transfer,
+    transfer_safe, transfer_stake, transfer_with_mode, TransferAmount, TransferMode, TransferPlan,
+    TransferPreview,
+};
 pub use utility::*;
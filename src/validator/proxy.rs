@@ -1,9 +1,9 @@
 //! Proxy account operations for Bittensor
 //! Allows delegating permissions to other accounts
 
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
 use crate::errors::{BittensorError, BittensorResult, ChainQueryError, ExtrinsicError};
-use crate::utils::decoders::vec::decode_vec;
+use crate::utils::decoders::vec::decode_vec_lossy;
 use crate::utils::decoders::{decode_account_id32, decode_u128};
 use parity_scale_codec::{Decode, Encode};
 use sp_core::crypto::AccountId32;
@@ -114,7 +114,7 @@ pub struct ProxyInfo {
 /// # Returns
 /// The transaction hash on success
 pub async fn add_proxy(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     delegate: &AccountId32,
     proxy_type: ProxyType,
@@ -154,7 +154,7 @@ pub async fn add_proxy(
 /// # Returns
 /// The transaction hash on success
 pub async fn remove_proxy(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     delegate: &AccountId32,
     proxy_type: ProxyType,
@@ -191,7 +191,7 @@ pub async fn remove_proxy(
 /// # Returns
 /// The transaction hash on success
 pub async fn remove_proxies(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     wait_for: ExtrinsicWait,
 ) -> BittensorResult<String> {
@@ -222,7 +222,7 @@ pub async fn remove_proxies(
 /// # Returns
 /// The transaction hash on success
 pub async fn proxy(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     real: &AccountId32,
     force_proxy_type: Option<ProxyType>,
@@ -268,7 +268,7 @@ pub async fn proxy(
 /// # Returns
 /// The transaction hash on success
 pub async fn create_pure(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     proxy_type: ProxyType,
     delay: u32,
@@ -312,7 +312,7 @@ pub async fn create_pure(
 /// The transaction hash on success
 #[allow(clippy::too_many_arguments)]
 pub async fn kill_pure(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     spawner: &AccountId32,
     proxy_type: ProxyType,
@@ -356,7 +356,7 @@ pub async fn kill_pure(
 /// # Returns
 /// A list of ProxyInfo containing delegate, proxy type, and delay
 pub async fn get_proxies(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     account: &AccountId32,
 ) -> BittensorResult<Vec<ProxyInfo>> {
     let result = client
@@ -391,7 +391,7 @@ pub async fn get_proxies(
 /// # Returns
 /// True if the delegate is a proxy for the real account
 pub async fn is_proxy(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     real: &AccountId32,
     delegate: &AccountId32,
     proxy_type: Option<ProxyType>,
@@ -432,7 +432,7 @@ fn parse_proxies_storage(value: &Value) -> BittensorResult<Vec<ProxyInfo>> {
         return Ok(Vec::new());
     };
 
-    decode_vec(list_value, |entry| {
+    decode_vec_lossy(list_value, |entry| {
         decode_proxy_info(entry).ok_or_else(|| {
             anyhow::anyhow!(
                 "{}",
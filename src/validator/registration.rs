@@ -1,5 +1,6 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult};
+use crate::queries::neurons::get_uid_for_hotkey;
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
@@ -10,30 +11,30 @@ const BURNED_REGISTER_FUNCTION: &str = "burned_register";
 
 /// Register a neuron on a subnet
 pub async fn register(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     hotkey: &AccountId32,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
 
     let tx_hash = client
         .submit_extrinsic(SUBTENSOR_MODULE, REGISTER_FUNCTION, args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to register: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to register", e))?;
 
     Ok(tx_hash)
 }
 
 /// Register using burned TAO (registration cost)
 pub async fn burned_register(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     hotkey: &AccountId32,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
 
     let tx_hash = client
@@ -45,7 +46,7 @@ pub async fn burned_register(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to burned register: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to burned register", e))?;
 
     Ok(tx_hash)
 }
@@ -61,12 +62,12 @@ pub async fn burned_register(
 /// * `new_hotkey` — The new hotkey to swap to.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn swap_hotkey(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     old_hotkey: &AccountId32,
     new_hotkey: &AccountId32,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![
         Value::from_bytes(old_hotkey.encode()),
         Value::from_bytes(new_hotkey.encode()),
@@ -75,32 +76,15 @@ pub async fn swap_hotkey(
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "swap_hotkey", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to swap hotkey: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to swap hotkey", e))
 }
 
 /// Check if a hotkey is registered on a subnet
 pub async fn is_registered(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     netuid: u16,
     hotkey: &AccountId32,
-) -> Result<bool> {
-    // Query the Uids storage to check if hotkey is registered
-    let keys = vec![Value::from(netuid), Value::from_bytes(hotkey.encode())];
-
-    let uid_data = client
-        .storage_with_keys(SUBTENSOR_MODULE, "Uids", keys)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to query registration: {}", e))?;
-
-    match uid_data {
-        Some(data) => {
-            // Decode UID from Value - if we get a UID back, the hotkey is registered
-            use crate::utils::decoders::decode_u64;
-            match decode_u64(&data) {
-                Ok(_uid) => Ok(true),
-                Err(_) => Ok(false),
-            }
-        }
-        None => Ok(false),
-    }
+) -> BittensorResult<bool> {
+    let uid = get_uid_for_hotkey(client, netuid, hotkey).await?;
+    Ok(uid.is_some())
 }
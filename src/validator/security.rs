@@ -0,0 +1,303 @@
+//! Coldkey swap scheduling and scheduler queries
+//!
+//! A compromised coldkey is rescued by scheduling a swap to a fresh coldkey.
+//! The swap executes `ColdkeySwapScheduleDuration` blocks later through the
+//! Scheduler pallet, which also holds scheduled `dissolve_network` calls.
+
+use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{
+    BittensorError, BittensorResult, ChainQueryError, ExtrinsicError, WalletError,
+};
+use crate::utils::decoders::{decode_account_id32, decode_bytes_from_composite, decode_u64};
+use crate::utils::ss58::decode_ss58;
+use parity_scale_codec::Encode;
+use serde::Serialize;
+use sp_core::crypto::AccountId32;
+use subxt::dynamic::Value;
+use subxt::ext::scale_value::{Composite, ValueDef};
+
+const SUBTENSOR_MODULE: &str = "SubtensorModule";
+const SCHEDULER_MODULE: &str = "Scheduler";
+
+/// A coldkey swap waiting in the scheduler
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SwapInfo {
+    /// Block at which the swap executes
+    pub execution_block: u64,
+    /// Coldkey that receives the balance, stake and hotkeys
+    #[serde(with = "crate::utils::ss58::serde_account")]
+    pub new_coldkey: AccountId32,
+}
+
+/// A call queued in `Scheduler::Agenda`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledCall {
+    /// Block at which the call dispatches
+    pub block: u64,
+    /// Pallet name, e.g. `SubtensorModule`
+    pub pallet: String,
+    /// Call name, e.g. `dissolve_network`
+    pub call: String,
+    /// SCALE-encoded call arguments
+    pub args: Vec<u8>,
+}
+
+/// Check that `new_coldkey` is a usable swap destination for `current`
+///
+/// The address must be valid SS58, must not be the zero account and must
+/// differ from the current coldkey.
+pub fn validate_swap_destination(
+    current: &AccountId32,
+    new_coldkey: &str,
+) -> BittensorResult<AccountId32> {
+    let destination = decode_ss58(new_coldkey.trim()).map_err(|e| {
+        WalletError::new(format!(
+            "Invalid destination coldkey '{}': {}",
+            new_coldkey, e
+        ))
+    })?;
+    if destination == AccountId32::new([0u8; 32]) {
+        return Err(ExtrinsicError::new("Destination coldkey is the zero account").into());
+    }
+    if &destination == current {
+        return Err(ExtrinsicError::new("Destination coldkey is the current coldkey").into());
+    }
+    Ok(destination)
+}
+
+/// Schedule a swap of the signer's coldkey to `new_coldkey`
+///
+/// Subtensor pallet dispatch: `schedule_swap_coldkey(new_coldkey)`.
+/// The destination is validated with [`validate_swap_destination`] and the
+/// call is rejected if a swap is already scheduled for this coldkey.
+///
+/// # Arguments
+/// * `client` — The Bittensor RPC client.
+/// * `signer` — The signing keypair (current coldkey).
+/// * `new_coldkey` — SS58 address of the new coldkey.
+/// * `wait_for` — How long to wait for on-chain inclusion.
+pub async fn schedule_swap_coldkey(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    new_coldkey: &str,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    let current = AccountId32::from(signer.account_id().0);
+    let destination = validate_swap_destination(&current, new_coldkey)?;
+
+    if let Some(existing) = get_scheduled_coldkey_swap(client, &current).await? {
+        return Err(ExtrinsicError::new(format!(
+            "A swap to {} is already scheduled for block {}",
+            existing.new_coldkey, existing.execution_block
+        ))
+        .into());
+    }
+
+    client
+        .submit_extrinsic(
+            SUBTENSOR_MODULE,
+            "schedule_swap_coldkey",
+            vec![Value::from_bytes(destination.encode())],
+            signer,
+            wait_for,
+        )
+        .await
+        .map_err(|e| BittensorError::from_chain("Failed to schedule coldkey swap", e))
+}
+
+/// Get the swap scheduled for `old_coldkey`, if any
+///
+/// Reads `SubtensorModule::ColdkeySwapScheduled`.
+pub async fn get_scheduled_coldkey_swap(
+    client: &impl ChainBackend,
+    old_coldkey: &AccountId32,
+) -> BittensorResult<Option<SwapInfo>> {
+    let value = client
+        .storage_with_keys(
+            SUBTENSOR_MODULE,
+            "ColdkeySwapScheduled",
+            vec![Value::from_bytes(old_coldkey.encode())],
+        )
+        .await?;
+    match value {
+        Some(value) => decode_swap_info(&value),
+        None => Ok(None),
+    }
+}
+
+/// Number of blocks between scheduling a coldkey swap and its execution
+pub async fn get_coldkey_swap_schedule_duration(
+    client: &impl ChainBackend,
+) -> BittensorResult<u64> {
+    read_u64(client, "ColdkeySwapScheduleDuration").await
+}
+
+/// Number of blocks between scheduling a network dissolution and its execution
+pub async fn get_dissolve_network_schedule_duration(
+    client: &impl ChainBackend,
+) -> BittensorResult<u64> {
+    read_u64(client, "DissolveNetworkScheduleDuration").await
+}
+
+/// Calls queued in the scheduler for `block`
+///
+/// Reads `Scheduler::Agenda`. Calls stored by hash (preimages) are skipped
+/// since their arguments are not held in the agenda.
+pub async fn get_scheduled_calls(
+    client: &BittensorClient,
+    block: u64,
+) -> BittensorResult<Vec<ScheduledCall>> {
+    let value = client
+        .storage_with_keys(SCHEDULER_MODULE, "Agenda", vec![Value::u128(block as u128)])
+        .await?;
+    let Some(value) = value else {
+        return Ok(Vec::new());
+    };
+
+    let metadata = client.metadata();
+    let mut calls = Vec::new();
+    for task in values(&value) {
+        // Option<Scheduled>: cancelled tasks leave a None in the agenda
+        let Some(scheduled) = variant_field(task, "Some", 0) else {
+            continue;
+        };
+        let Some(bytes) =
+            field(scheduled, "call").and_then(|call| variant_field(call, "Inline", 0))
+        else {
+            continue;
+        };
+        let bytes = decode_bytes_from_composite(bytes)?;
+        if let Some((pallet, call, args)) = decode_call(&metadata, &bytes) {
+            calls.push(ScheduledCall {
+                block,
+                pallet,
+                call,
+                args,
+            });
+        }
+    }
+    Ok(calls)
+}
+
+/// Whether dissolving `netuid` is scheduled for `block`
+///
+/// `block` is the execution block reported when the dissolution was
+/// scheduled (the `DissolveNetworkScheduled` event).
+pub async fn is_dissolve_network_scheduled(
+    client: &BittensorClient,
+    netuid: u16,
+    block: u64,
+) -> BittensorResult<bool> {
+    let calls = get_scheduled_calls(client, block).await?;
+    Ok(calls.iter().any(|call| {
+        call.pallet == SUBTENSOR_MODULE
+            && call.call == "dissolve_network"
+            // dissolve_network(coldkey: AccountId32, netuid: u16)
+            && call.args.get(32..34) == Some(&netuid.to_le_bytes()[..])
+    }))
+}
+
+async fn read_u64(client: &impl ChainBackend, entry: &str) -> BittensorResult<u64> {
+    let value = client
+        .storage(SUBTENSOR_MODULE, entry, None)
+        .await?
+        .ok_or_else(|| ChainQueryError::new(format!("{} not found in storage", entry)))?;
+    Ok(decode_u64(&value)?)
+}
+
+/// Decode `(BlockNumber, AccountId)`; the unset default reads as `(0, 0x00..)`
+fn decode_swap_info(value: &Value) -> BittensorResult<Option<SwapInfo>> {
+    let fields = values(value);
+    let [block, account] = fields.as_slice() else {
+        return Err(ExtrinsicError::new("Unexpected ColdkeySwapScheduled layout").into());
+    };
+    let execution_block = decode_u64(block)?;
+    let new_coldkey = decode_account_id32(account)?;
+    if execution_block == 0 && new_coldkey == AccountId32::new([0u8; 32]) {
+        return Ok(None);
+    }
+    Ok(Some(SwapInfo {
+        execution_block,
+        new_coldkey,
+    }))
+}
+
+/// Split an encoded `RuntimeCall` into pallet name, call name and arguments
+fn decode_call(metadata: &subxt::Metadata, bytes: &[u8]) -> Option<(String, String, Vec<u8>)> {
+    let [pallet_index, call_index, args @ ..] = bytes else {
+        return None;
+    };
+    let pallet = metadata.pallet_by_index(*pallet_index)?;
+    let call = pallet.call_variant_by_index(*call_index)?;
+    Some((pallet.name().to_string(), call.name.clone(), args.to_vec()))
+}
+
+fn values(value: &Value) -> Vec<&Value> {
+    match &value.value {
+        ValueDef::Composite(Composite::Unnamed(values)) => values.iter().collect(),
+        ValueDef::Composite(Composite::Named(fields)) => fields.iter().map(|(_, v)| v).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn field<'a>(value: &'a Value, name: &str) -> Option<&'a Value> {
+    match &value.value {
+        ValueDef::Composite(Composite::Named(fields)) => {
+            fields.iter().find(|(n, _)| n == name).map(|(_, v)| v)
+        }
+        _ => None,
+    }
+}
+
+fn variant_field<'a>(value: &'a Value, variant: &str, index: usize) -> Option<&'a Value> {
+    match &value.value {
+        ValueDef::Variant(v) if v.name == variant => match &v.values {
+            Composite::Unnamed(values) => values.get(index),
+            Composite::Named(fields) => fields.get(index).map(|(_, v)| v),
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ss58::encode_ss58;
+
+    #[test]
+    fn test_validate_swap_destination() {
+        let current = AccountId32::new([1u8; 32]);
+        let new = AccountId32::new([2u8; 32]);
+
+        assert_eq!(
+            validate_swap_destination(&current, &encode_ss58(&new)).unwrap(),
+            new
+        );
+        assert!(validate_swap_destination(&current, &encode_ss58(&current)).is_err());
+        assert!(
+            validate_swap_destination(&current, &encode_ss58(&AccountId32::new([0u8; 32])))
+                .is_err()
+        );
+        assert!(validate_swap_destination(&current, "not-an-address").is_err());
+    }
+
+    #[test]
+    fn test_decode_swap_info() {
+        let new = AccountId32::new([2u8; 32]);
+        let scheduled = Value::unnamed_composite(vec![
+            Value::u128(7_200),
+            Value::from_bytes(<[u8; 32]>::from(new.clone())),
+        ]);
+        assert_eq!(
+            decode_swap_info(&scheduled).unwrap(),
+            Some(SwapInfo {
+                execution_block: 7_200,
+                new_coldkey: new,
+            })
+        );
+
+        let unset = Value::unnamed_composite(vec![Value::u128(0), Value::from_bytes([0u8; 32])]);
+        assert_eq!(decode_swap_info(&unset).unwrap(), None);
+        assert!(decode_swap_info(&Value::u128(1)).is_err());
+    }
+}
@@ -1,15 +1,19 @@
 //! Senate and governance operations for Bittensor
 //! Implements senate registration, voting, and membership management
 
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use crate::errors::{BittensorError, BittensorResult, ChainQueryError, ExtrinsicError};
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{
+    BittensorError, BittensorResult, ChainQueryError, ExtrinsicError, MetadataError,
+    ProposalExpired, ProposalNotFound,
+};
 use crate::utils::decoders::{
-    decode_account_id32, decode_bytes, decode_u64, decode_vec, decode_vec_account_id32,
+    decode_account_id32, decode_bytes, decode_u64, decode_vec_account_id32, decode_vec_lossy,
 };
 use anyhow::anyhow;
-use sp_core::crypto::AccountId32;
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use std::fmt;
 use subxt::dynamic::Value;
-use subxt::ext::scale_value::{Composite, ValueDef};
+use subxt::ext::scale_value::{self, Composite, Primitive, ValueDef};
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 const SENATE_MODULE: &str = "SenateMembers";
@@ -40,6 +44,53 @@ pub struct Proposal {
     pub end: u64,
 }
 
+impl Proposal {
+    /// Decode `call_data` into pallet, call and named arguments
+    ///
+    /// The first two bytes are the pallet and call indices; the arguments are
+    /// decoded against the call's field types in `metadata`.
+    pub fn decode_call(&self, metadata: &subxt::Metadata) -> BittensorResult<DecodedCall> {
+        decode_call_data(&self.call_data, metadata)
+    }
+
+    /// Human-readable call, falling back to a hex dump if it can't be decoded
+    pub fn describe_call(&self, metadata: &subxt::Metadata) -> String {
+        match self.decode_call(metadata) {
+            Ok(call) => call.to_string(),
+            Err(e) => {
+                tracing::debug!("Failed to decode proposal {}: {}", self.index, e);
+                format!("0x{}", hex::encode(&self.call_data))
+            }
+        }
+    }
+}
+
+/// A proposal call decoded against chain metadata
+///
+/// Displays as `AdminUtils.sudo_set_default_take(default_take: 18%)`.
+#[derive(Debug, Clone)]
+pub struct DecodedCall {
+    /// Pallet name, e.g. `AdminUtils`
+    pub pallet: String,
+    /// Call name, e.g. `sudo_set_default_take`
+    pub call: String,
+    /// Arguments in declaration order; unnamed fields are named by position
+    pub args: Vec<(String, Value)>,
+}
+
+impl fmt::Display for DecodedCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}(", self.pallet, self.call)?;
+        for (i, (name, value)) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", name, format_call_arg(name, value))?;
+        }
+        write!(f, ")")
+    }
+}
+
 /// Vote data for a specific proposal
 #[derive(Debug, Clone)]
 pub struct VoteData {
@@ -55,6 +106,19 @@ pub struct VoteData {
     pub end: u64,
 }
 
+impl VoteData {
+    /// `Some(true)` if `account` voted aye, `Some(false)` if nay, else `None`
+    pub fn vote_of(&self, account: &AccountId32) -> Option<bool> {
+        if self.ayes.contains(account) {
+            Some(true)
+        } else if self.nays.contains(account) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
 // =============================================================================
 // Senate Registration
 // =============================================================================
@@ -62,7 +126,7 @@ pub struct VoteData {
 /// Register as a senate member
 /// Requires being a delegate with sufficient stake
 pub async fn register_senate(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     wait_for: ExtrinsicWait,
 ) -> BittensorResult<String> {
@@ -89,7 +153,7 @@ pub async fn register_senate(
 /// * `call_data` - Encoded call data for the proposal
 /// * `wait_for` - How long to wait for the transaction
 pub async fn propose(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     call_data: Vec<u8>,
     wait_for: ExtrinsicWait,
@@ -120,7 +184,7 @@ pub async fn propose(
 /// * `weight_bound` - Weight bound for the proposal
 /// * `wait_for` - How long to wait for the transaction
 pub async fn close_proposal(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     proposal_hash: &[u8; 32],
     proposal_index: u32,
@@ -149,7 +213,7 @@ pub async fn close_proposal(
 
 /// Leave the senate
 pub async fn leave_senate(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     wait_for: ExtrinsicWait,
 ) -> BittensorResult<String> {
@@ -183,7 +247,7 @@ pub async fn leave_senate(
 /// * `approve` - Whether to vote in favor (true) or against (false)
 /// * `wait_for` - How long to wait for the transaction
 pub async fn vote(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     proposal_hash: &[u8; 32],
     proposal_index: u32,
@@ -210,13 +274,96 @@ pub async fn vote(
     Ok(tx_hash)
 }
 
+/// Vote on a proposal identified only by its index
+///
+/// Looks up the proposal hash for `proposal_index` among the active
+/// proposals, so the (hash, index) pair sent to the chain always matches.
+///
+/// # Errors
+/// * `ProposalNotFound` - No active proposal has this index
+/// * `ProposalExpired` - The proposal's voting period has ended
+pub async fn vote_by_index(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    proposal_index: u32,
+    approve: bool,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    let (proposal_hash, vote_data) = find_proposal_by_index(client, proposal_index)
+        .await?
+        .ok_or_else(|| {
+            ProposalNotFound::with_index(
+                format!("No active proposal with index {}", proposal_index),
+                proposal_index,
+            )
+        })?;
+
+    let current_block = client.block_number().await.map_err(|e| {
+        BittensorError::ChainQuery(ChainQueryError::new(format!(
+            "Failed to query current block: {}",
+            e
+        )))
+    })?;
+    check_voting_open(&vote_data, current_block)?;
+
+    vote(
+        client,
+        signer,
+        &proposal_hash,
+        proposal_index,
+        approve,
+        wait_for,
+    )
+    .await
+}
+
+/// Hash and vote data of the active proposal with `proposal_index`
+pub async fn find_proposal_by_index(
+    client: &impl ChainBackend,
+    proposal_index: u32,
+) -> BittensorResult<Option<([u8; 32], VoteData)>> {
+    for hash in get_proposal_hashes(client).await? {
+        if let Some(vote_data) = get_vote_data(client, &hash).await? {
+            if vote_data.index == proposal_index {
+                return Ok(Some((hash, vote_data)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Check whether `hotkey` has voted on a proposal
+///
+/// Returns `Some(true)` for an aye, `Some(false)` for a nay and `None` if the
+/// hotkey hasn't voted.
+pub async fn has_voted(
+    client: &impl ChainBackend,
+    proposal_hash: &[u8; 32],
+    hotkey: &AccountId32,
+) -> BittensorResult<Option<bool>> {
+    let vote_data = get_vote_data(client, proposal_hash).await?.ok_or_else(|| {
+        ProposalNotFound::with_hash(
+            "No active proposal with this hash",
+            format!("0x{}", hex::encode(proposal_hash)),
+        )
+    })?;
+    Ok(vote_data.vote_of(hotkey))
+}
+
+fn check_voting_open(vote_data: &VoteData, current_block: u64) -> BittensorResult<()> {
+    if current_block >= vote_data.end {
+        return Err(ProposalExpired::new(vote_data.index, vote_data.end, current_block).into());
+    }
+    Ok(())
+}
+
 // =============================================================================
 // Senate Queries
 // =============================================================================
 
 /// Check if an account is a senate member
 pub async fn is_senate_member(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     hotkey: &AccountId32,
 ) -> BittensorResult<bool> {
     // Query SenateMembers.Members storage
@@ -246,7 +393,7 @@ pub async fn is_senate_member(
 }
 
 /// Get all senate members
-pub async fn get_senate_members(client: &BittensorClient) -> BittensorResult<Vec<AccountId32>> {
+pub async fn get_senate_members(client: &impl ChainBackend) -> BittensorResult<Vec<AccountId32>> {
     // Query SenateMembers.Members storage
     let members_val = client
         .storage(SENATE_MODULE, "Members", None)
@@ -275,7 +422,7 @@ pub async fn get_senate_members(client: &BittensorClient) -> BittensorResult<Vec
 
 /// Get proposal data for a specific proposal hash
 pub async fn get_proposal(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     proposal_hash: &[u8; 32],
 ) -> BittensorResult<Option<Proposal>> {
     // Get vote data first
@@ -335,23 +482,8 @@ pub async fn get_proposal(
 }
 
 /// Get all active proposals
-pub async fn get_proposals(client: &BittensorClient) -> BittensorResult<Vec<Proposal>> {
-    // Query Triumvirate.Proposals to get list of proposal hashes
-    let proposals_val = client
-        .storage(TRIUMVIRATE_MODULE, "Proposals", None)
-        .await
-        .map_err(|e| {
-            BittensorError::ChainQuery(ChainQueryError::with_storage(
-                format!("Failed to query proposals list: {}", e),
-                TRIUMVIRATE_MODULE,
-                "Proposals",
-            ))
-        })?;
-
-    let proposal_hashes = match proposals_val {
-        Some(val) => extract_proposal_hashes(&val),
-        None => return Ok(Vec::new()),
-    };
+pub async fn get_proposals(client: &impl ChainBackend) -> BittensorResult<Vec<Proposal>> {
+    let proposal_hashes = get_proposal_hashes(client).await?;
 
     let mut proposals = Vec::with_capacity(proposal_hashes.len());
 
@@ -366,7 +498,7 @@ pub async fn get_proposals(client: &BittensorClient) -> BittensorResult<Vec<Prop
 
 /// Get vote data for a proposal
 pub async fn get_vote_data(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     proposal_hash: &[u8; 32],
 ) -> BittensorResult<Option<VoteData>> {
     // Query Triumvirate.Voting storage
@@ -392,7 +524,7 @@ pub async fn get_vote_data(
 }
 
 /// Get the number of proposals
-pub async fn get_proposal_count(client: &BittensorClient) -> BittensorResult<u32> {
+pub async fn get_proposal_count(client: &impl ChainBackend) -> BittensorResult<u32> {
     // Query Triumvirate.ProposalCount storage
     let count_val = client
         .storage(TRIUMVIRATE_MODULE, "ProposalCount", None)
@@ -415,8 +547,26 @@ pub async fn get_proposal_count(client: &BittensorClient) -> BittensorResult<u32
 // Helper Functions
 // =============================================================================
 
+/// Hashes of the active proposals from Triumvirate.Proposals
+async fn get_proposal_hashes(client: &impl ChainBackend) -> BittensorResult<Vec<[u8; 32]>> {
+    let proposals_val = client
+        .storage(TRIUMVIRATE_MODULE, "Proposals", None)
+        .await
+        .map_err(|e| {
+            BittensorError::ChainQuery(ChainQueryError::with_storage(
+                format!("Failed to query proposals list: {}", e),
+                TRIUMVIRATE_MODULE,
+                "Proposals",
+            ))
+        })?;
+
+    Ok(proposals_val
+        .map(|val| extract_proposal_hashes(&val))
+        .unwrap_or_default())
+}
+
 /// Get the Triumvirate Prime (lead proposer)
-async fn get_triumvirate_prime(client: &BittensorClient) -> BittensorResult<AccountId32> {
+async fn get_triumvirate_prime(client: &impl ChainBackend) -> BittensorResult<AccountId32> {
     let prime_val = client
         .storage(TRIUMVIRATE_MODULE, "Prime", None)
         .await
@@ -508,7 +658,7 @@ fn decode_vote_data(value: &Value) -> Option<VoteData> {
 
 /// Extract proposal hashes from the Proposals storage value
 fn extract_proposal_hashes(val: &Value) -> Vec<[u8; 32]> {
-    decode_vec(val, |entry| {
+    decode_vec_lossy(val, |entry| {
         let bytes = decode_bytes(entry)?;
         if bytes.len() == 32 {
             let mut arr = [0u8; 32];
@@ -626,6 +776,98 @@ fn extract_call_data(val: &Value) -> Vec<u8> {
     decode_bytes(val).unwrap_or_default()
 }
 
+fn decode_call_data(call_data: &[u8], metadata: &subxt::Metadata) -> BittensorResult<DecodedCall> {
+    let [pallet_index, call_index, args_data @ ..] = call_data else {
+        return Err(MetadataError::new("Call data is shorter than the call index").into());
+    };
+    let mut args_data = args_data;
+
+    let pallet = metadata
+        .pallet_by_index(*pallet_index)
+        .ok_or_else(|| MetadataError::new(format!("Unknown pallet index {}", pallet_index)))?;
+    let variant = pallet.call_variant_by_index(*call_index).ok_or_else(|| {
+        MetadataError::new(format!(
+            "Unknown call index {} in pallet {}",
+            call_index,
+            pallet.name()
+        ))
+    })?;
+
+    let mut args = Vec::with_capacity(variant.fields.len());
+    for (position, field) in variant.fields.iter().enumerate() {
+        let name = field.name.clone().unwrap_or_else(|| position.to_string());
+        let value =
+            scale_value::scale::decode_as_type(&mut args_data, field.ty.id, metadata.types())
+                .map_err(|e| {
+                    MetadataError::new(format!(
+                        "Failed to decode argument '{}' of {}.{}: {}",
+                        name,
+                        pallet.name(),
+                        variant.name,
+                        e
+                    ))
+                })?;
+        args.push((name, value.remove_context()));
+    }
+
+    if !args_data.is_empty() {
+        return Err(MetadataError::new(format!(
+            "{} trailing bytes after {}.{} arguments",
+            args_data.len(),
+            pallet.name(),
+            variant.name
+        ))
+        .into());
+    }
+
+    Ok(DecodedCall {
+        pallet: pallet.name().to_string(),
+        call: variant.name.clone(),
+        args,
+    })
+}
+
+/// Format a call argument for display
+///
+/// Takes (`u16` fractions of `u16::MAX`) are shown as percentages and 32-byte
+/// accounts as SS58; everything else uses the value's own formatting.
+fn format_call_arg(name: &str, value: &Value) -> String {
+    if name.ends_with("take") {
+        if let ValueDef::Primitive(Primitive::U128(raw)) = &value.value {
+            if *raw <= u16::MAX as u128 {
+                let percent = *raw as f64 / u16::MAX as f64 * 100.0;
+                let formatted = format!("{:.2}", percent);
+                return format!("{}%", formatted.trim_end_matches('0').trim_end_matches('.'));
+            }
+        }
+    }
+    if let Some(bytes) = account_bytes(value) {
+        return AccountId32::from(bytes).to_ss58check();
+    }
+    value.to_string()
+}
+
+/// The bytes of an `AccountId32`-shaped value: 32 `u8`s, possibly newtype-wrapped
+fn account_bytes(value: &Value) -> Option<[u8; 32]> {
+    let ValueDef::Composite(Composite::Unnamed(values)) = &value.value else {
+        return None;
+    };
+    if values.len() == 1 {
+        return account_bytes(&values[0]);
+    }
+    if values.len() != 32 {
+        return None;
+    }
+    let mut bytes = [0u8; 32];
+    for (byte, value) in bytes.iter_mut().zip(values) {
+        match &value.value {
+            ValueDef::Primitive(Primitive::U128(b)) => *byte = u8::try_from(*b).ok()?,
+            _ => return None,
+        }
+    }
+    Some(bytes)
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -650,6 +892,44 @@ mod tests {
         assert_eq!(vote_data.end, 1000);
     }
 
+    #[test]
+    fn test_vote_of() {
+        let aye = AccountId32::from([1u8; 32]);
+        let nay = AccountId32::from([2u8; 32]);
+        let vote_data = VoteData {
+            index: 3,
+            threshold: 2,
+            ayes: vec![aye.clone()],
+            nays: vec![nay.clone()],
+            end: 1000,
+        };
+        assert_eq!(vote_data.vote_of(&aye), Some(true));
+        assert_eq!(vote_data.vote_of(&nay), Some(false));
+        assert_eq!(vote_data.vote_of(&AccountId32::from([3u8; 32])), None);
+    }
+
+    #[test]
+    fn test_check_voting_open() {
+        let vote_data = VoteData {
+            index: 3,
+            threshold: 2,
+            ayes: vec![],
+            nays: vec![],
+            end: 1000,
+        };
+        assert!(check_voting_open(&vote_data, 999).is_ok());
+
+        let err = check_voting_open(&vote_data, 1000).unwrap_err();
+        match err {
+            BittensorError::ProposalExpired(e) => {
+                assert_eq!(e.proposal_index, 3);
+                assert_eq!(e.end_block, 1000);
+                assert_eq!(e.current_block, 1000);
+            }
+            other => panic!("expected ProposalExpired, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_proposal_creation() {
         let hash = [1u8; 32];
@@ -675,6 +955,48 @@ mod tests {
         assert!(proposal.proposer.is_some());
     }
 
+    #[test]
+    fn test_decoded_call_display() {
+        let account = AccountId32::from([7u8; 32]);
+        let call = DecodedCall {
+            pallet: "AdminUtils".to_string(),
+            call: "sudo_set_default_take".to_string(),
+            args: vec![("default_take".to_string(), Value::u128(11796))],
+        };
+        assert_eq!(
+            call.to_string(),
+            "AdminUtils.sudo_set_default_take(default_take: 18%)"
+        );
+
+        let call = DecodedCall {
+            pallet: "SubtensorModule".to_string(),
+            call: "sudo_set_tempo".to_string(),
+            args: vec![
+                ("hotkey".to_string(), Value::from_bytes([7u8; 32])),
+                ("netuid".to_string(), Value::u128(3)),
+                ("max_take".to_string(), Value::u128(u16::MAX as u128)),
+            ],
+        };
+        assert_eq!(
+            call.to_string(),
+            format!(
+                "SubtensorModule.sudo_set_tempo(hotkey: {}, netuid: 3, max_take: 100%)",
+                account.to_ss58check()
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_call_arg_leaves_other_values() {
+        assert_eq!(format_call_arg("netuid", &Value::u128(12)), "12");
+        assert_eq!(format_call_arg("take", &Value::u128(70000)), "70000");
+        assert_eq!(account_bytes(&Value::from_bytes([1u8; 31])), None);
+        assert_eq!(
+            account_bytes(&Value::unnamed_composite([Value::from_bytes([1u8; 32])])),
+            Some([1u8; 32])
+        );
+    }
+
     #[test]
     fn test_proposal_with_unknown_proposer() {
         let hash = [1u8; 32];
@@ -1,5 +1,10 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{AxonConfigError, BittensorError, BittensorResult, RateLimitedError};
+use crate::queries::hyperparameters::get_serving_rate_limit;
+use crate::queries::neurons::{fetch_axon_info, Certificate, MAX_CERTIFICATE_PUBLIC_KEY_LEN};
+use crate::utils::networking::{get_external_ip, ip_to_int, ip_version};
+use parity_scale_codec::Encode;
+use sp_core::crypto::AccountId32;
 use std::net::IpAddr;
 use subxt::dynamic::Value;
 
@@ -9,7 +14,7 @@ const SUBTENSOR_MODULE: &str = "SubtensorModule";
 /// Subtensor expects: (netuid, version: u32, ip: u128, port: u16, ip_type: u8, protocol: u8, placeholder1: u8, placeholder2: u8)
 #[allow(clippy::too_many_arguments)]
 pub async fn serve_axon(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     version: u32,
@@ -17,28 +22,9 @@ pub async fn serve_axon(
     port: u16,
     protocol: u8,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
-    let (ip_type, ip_u128) = match ip {
-        IpAddr::V4(ipv4) => {
-            // IPv4: convert 4 bytes to u128 (stored as u32 in u128)
-            let bytes = ipv4.octets();
-            let ip_val = u32::from_be_bytes(bytes) as u128;
-            (4u8, ip_val)
-        }
-        IpAddr::V6(ipv6) => {
-            // IPv6: convert 16 bytes to u128 (direct encoding)
-            let segments = ipv6.segments();
-            let ip_val = ((segments[0] as u128) << 112)
-                | ((segments[1] as u128) << 96)
-                | ((segments[2] as u128) << 80)
-                | ((segments[3] as u128) << 64)
-                | ((segments[4] as u128) << 48)
-                | ((segments[5] as u128) << 32)
-                | ((segments[6] as u128) << 16)
-                | (segments[7] as u128);
-            (6u8, ip_val)
-        }
-    };
+) -> BittensorResult<String> {
+    let ip_type = ip_version(&ip);
+    let ip_u128 = ip_to_int(&ip);
 
     let args = vec![
         Value::from(netuid),
@@ -54,46 +40,43 @@ pub async fn serve_axon(
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "serve_axon", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to serve axon: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to serve axon", e))
 }
 
 /// Serve axon with TLS certificate
 /// Subtensor expects: (netuid, version: u32, ip: u128, port: u16, ip_type: u8, protocol: u8, placeholder1: u8, placeholder2: u8, certificate: Vec<u8>)
+///
+/// The certificate is encoded as its algorithm byte followed by the public
+/// key, which the chain stores as the hotkey's `NeuronCertificates` entry.
+/// Use [`AxonTlsConfig::chain_certificate`] for an axon served with
+/// `Axon::serve_https`.
+///
+/// [`AxonTlsConfig::chain_certificate`]: crate::axon::AxonTlsConfig::chain_certificate
 #[allow(clippy::too_many_arguments)]
 pub async fn serve_axon_tls(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     version: u32,
     ip: IpAddr,
     port: u16,
     protocol: u8,
-    certificate: &[u8],
+    certificate: &Certificate,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
-    let (ip_type, ip_u128) = match ip {
-        IpAddr::V4(ipv4) => {
-            // IPv4: convert 4 bytes to u128 (stored as u32 in u128)
-            let bytes = ipv4.octets();
-            let ip_val = u32::from_be_bytes(bytes) as u128;
-            (4u8, ip_val)
-        }
-        IpAddr::V6(ipv6) => {
-            // IPv6: convert 16 bytes to u128 (direct encoding)
-            let segments = ipv6.segments();
-            let ip_val = ((segments[0] as u128) << 112)
-                | ((segments[1] as u128) << 96)
-                | ((segments[2] as u128) << 80)
-                | ((segments[3] as u128) << 64)
-                | ((segments[4] as u128) << 48)
-                | ((segments[5] as u128) << 32)
-                | ((segments[6] as u128) << 16)
-                | (segments[7] as u128);
-            (6u8, ip_val)
-        }
-    };
+) -> BittensorResult<String> {
+    if certificate.as_der().len() > MAX_CERTIFICATE_PUBLIC_KEY_LEN {
+        return Err(AxonConfigError::new(format!(
+            "Certificate public key is {} bytes; the chain accepts at most {}",
+            certificate.as_der().len(),
+            MAX_CERTIFICATE_PUBLIC_KEY_LEN
+        ))
+        .into());
+    }
+
+    let ip_type = ip_version(&ip);
+    let ip_u128 = ip_to_int(&ip);
 
-    let cert_value = Value::from_bytes(certificate);
+    let cert_value = Value::from_bytes(certificate.encode());
 
     let args = vec![
         Value::from(netuid),
@@ -110,5 +93,84 @@ pub async fn serve_axon_tls(
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "serve_axon_tls", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to serve axon with TLS: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to serve axon with TLS", e))
+}
+
+/// Outcome of [`serve_axon_auto`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Served {
+    /// The on-chain axon already matches, so nothing was submitted
+    AlreadyServing,
+    /// The axon was served; contains the transaction hash
+    Updated(String),
+}
+
+/// Serve axon endpoint, detecting the external IP and skipping no-op updates
+///
+/// The external IP is taken from `external_ip` if given, otherwise detected
+/// with [`get_external_ip`]. If the hotkey's current `Axons` entry already
+/// has the same ip, port and protocol nothing is submitted. Otherwise the
+/// subnet's `ServingRateLimit` is checked against the block the axon was
+/// last served at, and a [`RateLimitedError`] carrying the blocks remaining
+/// is returned if the update would be rejected.
+///
+/// # Arguments
+///
+/// * `client` - The chain client
+/// * `signer` - The hotkey signer
+/// * `netuid` - The subnet to serve on
+/// * `version` - The axon version
+/// * `port` - The external port
+/// * `protocol` - The axon protocol
+/// * `external_ip` - Override for the external IP address
+/// * `wait_for` - How long to wait for the extrinsic
+#[allow(clippy::too_many_arguments)]
+pub async fn serve_axon_auto(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    version: u32,
+    port: u16,
+    protocol: u8,
+    external_ip: Option<IpAddr>,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<Served> {
+    let ip = match external_ip {
+        Some(ip) => ip,
+        None => get_external_ip().await?,
+    };
+
+    let hotkey = AccountId32::from(signer.account_id().0);
+    let keys = vec![
+        Value::u128(netuid as u128),
+        Value::from_bytes(hotkey.encode()),
+    ];
+    if let Some(current) = fetch_axon_info(client, SUBTENSOR_MODULE, "Axons", keys).await {
+        if current.ip == ip && current.port == port && current.protocol == protocol {
+            return Ok(Served::AlreadyServing);
+        }
+
+        if current.block > 0 {
+            let rate_limit = get_serving_rate_limit(client, netuid).await?;
+            let current_block = client.block_number().await?;
+            let next_allowed = current.block.saturating_add(rate_limit);
+            if current_block < next_allowed {
+                let remaining = next_allowed - current_block;
+                return Err(RateLimitedError::with_retry_after(
+                    format!(
+                        "Axon on subnet {} was served at block {}; serving is rate limited for {} more blocks",
+                        netuid, current.block, remaining
+                    ),
+                    remaining,
+                )
+                .into());
+            }
+        }
+    }
+
+    serve_axon(
+        client, signer, netuid, version, ip, port, protocol, wait_for,
+    )
+    .await
+    .map(Served::Updated)
 }
@@ -1,6 +1,6 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult, StakeFailed};
 use crate::utils::balance_newtypes::Rao;
-use anyhow::Result;
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
@@ -24,15 +24,15 @@ const UNSTAKE_FUNCTION: &str = "remove_stake";
 /// # Errors
 /// Returns an error if the amount is zero or the extrinsic submission fails.
 pub async fn add_stake(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkey: &AccountId32,
     netuid: u16,
     amount: Rao,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if amount.as_u128() == 0 {
-        return Err(anyhow::anyhow!("Stake amount must be greater than zero"));
+        return Err(StakeFailed::new("Stake amount must be greater than zero").into());
     }
 
     let args = vec![
@@ -44,7 +44,7 @@ pub async fn add_stake(
     let tx_hash = client
         .submit_extrinsic(SUBTENSOR_MODULE, ADD_STAKE_FUNCTION, args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to add stake: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to add stake", e))?;
 
     Ok(tx_hash)
 }
@@ -64,15 +64,15 @@ pub async fn add_stake(
 /// # Errors
 /// Returns an error if the amount is zero or the extrinsic submission fails.
 pub async fn unstake(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkey: &AccountId32,
     netuid: u16,
     amount: Rao,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if amount.as_u128() == 0 {
-        return Err(anyhow::anyhow!("Unstake amount must be greater than zero"));
+        return Err(StakeFailed::new("Unstake amount must be greater than zero").into());
     }
 
     let args = vec![
@@ -84,7 +84,7 @@ pub async fn unstake(
     let tx_hash = client
         .submit_extrinsic(SUBTENSOR_MODULE, UNSTAKE_FUNCTION, args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to unstake: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to unstake", e))?;
 
     Ok(tx_hash)
 }
@@ -102,17 +102,17 @@ pub async fn unstake(
 /// # Errors
 /// Returns an error if the extrinsic submission fails.
 pub async fn unstake_all(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkey: &AccountId32,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from_bytes(hotkey.encode())];
 
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "remove_stake_all", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to unstake all: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to unstake all", e))
 }
 
 /// Add stake to multiple hotkeys in a single extrinsic.
@@ -128,20 +128,18 @@ pub async fn unstake_all(
 /// Returns an error if the slices differ in length, any amount is zero, or the
 /// extrinsic submission fails.
 pub async fn add_stake_multiple(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkeys: &[AccountId32],
     amounts: &[Rao],
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if hotkeys.len() != amounts.len() {
-        return Err(anyhow::anyhow!(
-            "Hotkeys and amounts must have the same length"
-        ));
+        return Err(StakeFailed::new("Hotkeys and amounts must have the same length").into());
     }
 
     if amounts.iter().any(|amount| amount.as_u128() == 0) {
-        return Err(anyhow::anyhow!("Stake amounts must be greater than zero"));
+        return Err(StakeFailed::new("Stake amounts must be greater than zero").into());
     }
 
     let hotkey_values: Vec<Value> = hotkeys
@@ -168,7 +166,7 @@ pub async fn add_stake_multiple(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to add stake multiple: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to add stake multiple", e))
 }
 
 /// Unstake from multiple hotkeys in a single extrinsic.
@@ -184,20 +182,18 @@ pub async fn add_stake_multiple(
 /// Returns an error if the slices differ in length, any amount is zero, or the
 /// extrinsic submission fails.
 pub async fn unstake_multiple(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkeys: &[AccountId32],
     amounts: &[Rao],
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if hotkeys.len() != amounts.len() {
-        return Err(anyhow::anyhow!(
-            "Hotkeys and amounts must have the same length"
-        ));
+        return Err(StakeFailed::new("Hotkeys and amounts must have the same length").into());
     }
 
     if amounts.iter().any(|amount| amount.as_u128() == 0) {
-        return Err(anyhow::anyhow!("Unstake amounts must be greater than zero"));
+        return Err(StakeFailed::new("Unstake amounts must be greater than zero").into());
     }
 
     let hotkey_values: Vec<Value> = hotkeys
@@ -224,7 +220,7 @@ pub async fn unstake_multiple(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to unstake multiple: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to unstake multiple", e))
 }
 
 /// Set auto-stake for a hotkey.
@@ -239,18 +235,18 @@ pub async fn unstake_multiple(
 /// * `auto_stake` — `true` to enable auto-staking, `false` to disable.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn set_auto_stake(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkey: &AccountId32,
     auto_stake: bool,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from_bytes(hotkey.encode()), Value::bool(auto_stake)];
 
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "set_auto_stake", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set auto stake: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set auto stake", e))
 }
 
 /// Move stake from one hotkey to another, optionally across subnets.
@@ -269,7 +265,7 @@ pub async fn set_auto_stake(
 /// * `wait_for` — How long to wait for on-chain inclusion.
 #[allow(clippy::too_many_arguments)]
 pub async fn move_stake(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     from_hotkey: &AccountId32,
     to_hotkey: &AccountId32,
@@ -277,11 +273,9 @@ pub async fn move_stake(
     destination_netuid: u16,
     amount: Rao,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if amount.as_u128() == 0 {
-        return Err(anyhow::anyhow!(
-            "Stake move amount must be greater than zero"
-        ));
+        return Err(StakeFailed::new("Stake move amount must be greater than zero").into());
     }
 
     let args = vec![
@@ -295,7 +289,7 @@ pub async fn move_stake(
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "move_stake", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to move stake: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to move stake", e))
 }
 
 /// Swap stake from one subnet to another for a hotkey.
@@ -312,18 +306,16 @@ pub async fn move_stake(
 /// * `amount` — Amount to swap **in RAO** (1 TAO = 1e9 RAO).
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn swap_stake(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkey: &AccountId32,
     origin_netuid: u16,
     destination_netuid: u16,
     amount: Rao,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if amount.as_u128() == 0 {
-        return Err(anyhow::anyhow!(
-            "Stake swap amount must be greater than zero"
-        ));
+        return Err(StakeFailed::new("Stake swap amount must be greater than zero").into());
     }
 
     let args = vec![
@@ -336,5 +328,5 @@ pub async fn swap_stake(
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "swap_stake", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to swap stake: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to swap stake", e))
 }
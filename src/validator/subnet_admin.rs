@@ -0,0 +1,689 @@
+//! Subnet creation and typed hyperparameter setters for subnet owners
+//!
+//! [`register_network`] creates a new subnet, locking the current network
+//! registration cost (see [`get_network_registration_cost`]).
+//!
+//! Subnet owners can tune part of their subnet's hyperparameters through the
+//! `AdminUtils.sudo_set_*` extrinsics. Every setter first checks that the
+//! signer's coldkey owns the subnet (`SubnetOwner` storage) so a wrong key
+//! fails fast with a clear error instead of a dispatch error. The same check
+//! guards [`swap_subnet_owner`], which hands the subnet to another coldkey.
+
+use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ChainEvent, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult, ChainQueryError, ExtrinsicError};
+use crate::queries::hyperparameters::{
+    get_bonds_moving_average, get_commit_reveal_weights_enabled, get_max_burn, get_min_burn,
+    get_serving_rate_limit, get_weights_rate_limit,
+};
+use crate::queries::subnets::subnet_owner;
+use crate::types::SubnetIdentity;
+use crate::utils::balance_newtypes::Rao;
+use crate::utils::decoders::decode_u64;
+use crate::utils::ss58::encode_ss58;
+use parity_scale_codec::Encode;
+use sp_core::crypto::AccountId32;
+use std::fmt;
+use std::str::FromStr;
+use subxt::dynamic::Value;
+
+const ADMIN_UTILS: &str = "AdminUtils";
+const SUBTENSOR_MODULE: &str = "SubtensorModule";
+
+/// Result of a successful subnet registration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkRegistration {
+    /// Netuid assigned to the new subnet
+    pub netuid: u16,
+    /// Transaction hash of the registration extrinsic
+    pub tx_hash: String,
+}
+
+/// Get the amount of TAO locked when registering a new subnet.
+///
+/// Mirrors `get_network_lock_cost` in the subtensor pallet: the last lock cost
+/// doubles on registration and then decays linearly over
+/// `NetworkLockReductionInterval` blocks, never going below
+/// `NetworkMinLockCost`.
+pub async fn get_network_registration_cost(client: &impl ChainBackend) -> BittensorResult<Rao> {
+    let last_lock = read_u64(client, "NetworkLastLockCost").await?;
+    let min_lock = read_u64(client, "NetworkMinLockCost").await?;
+    let last_lock_block = read_u64(client, "NetworkLastRegistered").await?;
+    let reduction_interval = read_u64(client, "NetworkLockReductionInterval").await?;
+    let current_block = client.block_number().await?;
+
+    Ok(Rao::from(network_lock_cost(
+        last_lock,
+        min_lock,
+        last_lock_block,
+        current_block,
+        reduction_interval,
+    )))
+}
+
+/// Read a plain u64 `SubtensorModule` storage value, defaulting to zero
+async fn read_u64(client: &impl ChainBackend, entry: &str) -> BittensorResult<u64> {
+    match client.storage(SUBTENSOR_MODULE, entry, None).await? {
+        Some(val) => decode_u64(&val)
+            .map_err(|e| ChainQueryError::new(format!("Failed to decode {}: {}", entry, e)).into()),
+        None => Ok(0),
+    }
+}
+
+/// Compute the network lock cost from the lock-cost storage values
+fn network_lock_cost(
+    last_lock: u64,
+    min_lock: u64,
+    last_lock_block: u64,
+    current_block: u64,
+    reduction_interval: u64,
+) -> u64 {
+    let mult = if last_lock_block == 0 { 1 } else { 2 };
+    let reduction_per_block = last_lock.checked_div(reduction_interval).unwrap_or(0);
+    let lock_cost = last_lock.saturating_mul(mult).saturating_sub(
+        reduction_per_block.saturating_mul(current_block.saturating_sub(last_lock_block)),
+    );
+    lock_cost.max(min_lock)
+}
+
+/// Register a new subnet (network) on the Bittensor chain.
+///
+/// Subtensor pallet dispatch: `register_network(hotkey)`, or
+/// `register_network_with_identity(hotkey, identity)` when an identity is
+/// given. The network registration cost is locked from the signer's coldkey,
+/// which becomes the subnet owner.
+///
+/// # Arguments
+/// * `client` — The Bittensor RPC client.
+/// * `signer` — The signing keypair (coldkey that will own the subnet).
+/// * `hotkey` — The hotkey to associate with the new subnet.
+/// * `identity` — Optional subnet identity to set at registration.
+/// * `wait_for` — How long to wait for on-chain inclusion; must not be
+///   `ExtrinsicWait::None` since the netuid is read from the events.
+///
+/// # Returns
+/// The netuid assigned by the `NetworkAdded` event and the transaction hash.
+pub async fn register_network(
+    client: &BittensorClient,
+    signer: &BittensorSigner,
+    hotkey: &AccountId32,
+    identity: Option<&SubnetIdentity>,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<NetworkRegistration> {
+    if matches!(wait_for, ExtrinsicWait::None) {
+        return Err(ExtrinsicError::new(
+            "register_network must wait for inclusion to read the assigned netuid",
+        )
+        .into());
+    }
+
+    let hotkey_value = Value::from_bytes(hotkey.encode());
+    let (function, args) = match identity {
+        Some(identity) => (
+            "register_network_with_identity",
+            vec![
+                hotkey_value,
+                Value::unnamed_variant("Some", vec![subnet_identity_value(identity)]),
+            ],
+        ),
+        None => ("register_network", vec![hotkey_value]),
+    };
+
+    let result = client
+        .submit_extrinsic_with_result(SUBTENSOR_MODULE, function, args, signer, wait_for)
+        .await
+        .map_err(|e| BittensorError::from_chain("Failed to register network", e))?;
+
+    if !result.is_success() {
+        return Err(ExtrinsicError::new(format!("Failed to register network: {}", result)).into());
+    }
+
+    let event = result
+        .find_events(SUBTENSOR_MODULE, "NetworkAdded")
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            ExtrinsicError::new(format!("NetworkAdded event not found in {}", result))
+        })?;
+
+    Ok(NetworkRegistration {
+        netuid: decode_network_added(event)?,
+        tx_hash: result.tx_hash,
+    })
+}
+
+/// Build the `SubnetIdentityOfV3` value for `register_network_with_identity`
+fn subnet_identity_value(identity: &SubnetIdentity) -> Value {
+    Value::named_composite(vec![
+        (
+            "subnet_name",
+            Value::from_bytes(identity.subnet_name.as_bytes()),
+        ),
+        (
+            "github_repo",
+            Value::from_bytes(identity.github_repo.as_bytes()),
+        ),
+        (
+            "subnet_contact",
+            Value::from_bytes(identity.subnet_contact.as_bytes()),
+        ),
+        (
+            "subnet_url",
+            Value::from_bytes(identity.subnet_url.as_bytes()),
+        ),
+        ("discord", Value::from_bytes(identity.discord.as_bytes())),
+        (
+            "description",
+            Value::from_bytes(identity.description.as_bytes()),
+        ),
+        ("logo_url", Value::from_bytes(identity.logo_url.as_bytes())),
+        (
+            "additional",
+            Value::from_bytes(identity.additional.as_bytes()),
+        ),
+    ])
+}
+
+/// Decode the netuid from a `NetworkAdded(netuid, mechid)` event
+fn decode_network_added(event: &ChainEvent) -> BittensorResult<u16> {
+    match event.field_bytes.get(..2) {
+        Some(bytes) => Ok(u16::from_le_bytes([bytes[0], bytes[1]])),
+        None => Err(ExtrinsicError::new(format!(
+            "NetworkAdded event too short: {} bytes",
+            event.field_bytes.len()
+        ))
+        .into()),
+    }
+}
+
+/// Check that the signer's coldkey owns a subnet.
+///
+/// # Arguments
+/// * `client` — The Bittensor RPC client.
+/// * `signer` — The signing keypair (coldkey).
+/// * `netuid` — The subnet ID.
+pub async fn ensure_subnet_owner(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+) -> BittensorResult<()> {
+    let coldkey = AccountId32::from(signer.account_id().0);
+    let owner = subnet_owner(client, netuid)
+        .await?
+        .ok_or_else(|| ChainQueryError::new(format!("Subnet {} does not exist", netuid)))?;
+
+    if owner != coldkey {
+        return Err(ExtrinsicError::new(format!(
+            "Coldkey {} does not own subnet {} (owner is {})",
+            encode_ss58(&coldkey),
+            netuid,
+            encode_ss58(&owner)
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Transfer ownership of a subnet to another coldkey.
+///
+/// Dispatches `SubtensorModule::swap_subnet_owner(netuid, new_owner)` when the
+/// runtime exposes it, and `AdminUtils::sudo_set_subnet_owner(netuid,
+/// new_owner)` otherwise. Either way the signer must own the subnet; this is
+/// checked before submitting.
+///
+/// # Arguments
+/// * `client` — The Bittensor RPC client.
+/// * `signer` — The signing keypair (current owner coldkey).
+/// * `netuid` — The subnet ID.
+/// * `new_owner` — The coldkey that will own the subnet.
+/// * `wait_for` — How long to wait for on-chain inclusion.
+pub async fn swap_subnet_owner(
+    client: &BittensorClient,
+    signer: &BittensorSigner,
+    netuid: u16,
+    new_owner: &AccountId32,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    ensure_subnet_owner(client, signer, netuid).await?;
+    if AccountId32::from(signer.account_id().0) == *new_owner {
+        return Err(ExtrinsicError::new(format!(
+            "Coldkey {} already owns subnet {}",
+            encode_ss58(new_owner),
+            netuid
+        ))
+        .into());
+    }
+
+    let (module, function) = owner_swap_call(&client.metadata()).ok_or_else(|| {
+        ExtrinsicError::new("The runtime does not support transferring subnet ownership")
+    })?;
+    let args = vec![Value::from(netuid), Value::from_bytes(new_owner.encode())];
+    client
+        .submit_extrinsic(module, function, args, signer, wait_for)
+        .await
+        .map_err(|e| BittensorError::from_chain(format!("Failed to call {}", function), e))
+}
+
+/// The call that transfers subnet ownership in this runtime, if any
+fn owner_swap_call(metadata: &subxt::Metadata) -> Option<(&'static str, &'static str)> {
+    [
+        (SUBTENSOR_MODULE, "swap_subnet_owner"),
+        (ADMIN_UTILS, "sudo_set_subnet_owner"),
+    ]
+    .into_iter()
+    .find(|(module, function)| {
+        metadata
+            .pallet_by_name(module)
+            .and_then(|pallet| pallet.call_variant_by_name(function))
+            .is_some()
+    })
+}
+
+/// Submit an owner-only `AdminUtils` call after checking subnet ownership
+async fn submit_owner_call(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    function: &str,
+    value: Value,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    ensure_subnet_owner(client, signer, netuid).await?;
+
+    let args = vec![Value::from(netuid), value];
+    client
+        .submit_extrinsic(ADMIN_UTILS, function, args, signer, wait_for)
+        .await
+        .map_err(|e| BittensorError::from_chain(format!("Failed to call {}", function), e))
+}
+
+/// AdminUtils pallet dispatch: `sudo_set_serving_rate_limit(netuid, serving_rate_limit)`
+pub async fn set_serving_rate_limit(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    serving_rate_limit: u64,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    submit_owner_call(
+        client,
+        signer,
+        netuid,
+        "sudo_set_serving_rate_limit",
+        Value::from(serving_rate_limit),
+        wait_for,
+    )
+    .await
+}
+
+/// AdminUtils pallet dispatch: `sudo_set_weights_set_rate_limit(netuid, weights_set_rate_limit)`
+pub async fn set_weights_rate_limit(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    weights_rate_limit: u64,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    submit_owner_call(
+        client,
+        signer,
+        netuid,
+        "sudo_set_weights_set_rate_limit",
+        Value::from(weights_rate_limit),
+        wait_for,
+    )
+    .await
+}
+
+/// AdminUtils pallet dispatch: `sudo_set_commit_reveal_weights_enabled(netuid, enabled)`
+pub async fn set_commit_reveal_enabled(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    enabled: bool,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    submit_owner_call(
+        client,
+        signer,
+        netuid,
+        "sudo_set_commit_reveal_weights_enabled",
+        Value::bool(enabled),
+        wait_for,
+    )
+    .await
+}
+
+/// AdminUtils pallet dispatch: `sudo_set_min_burn(netuid, min_burn)`
+pub async fn set_min_burn(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    min_burn: Rao,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    submit_owner_call(
+        client,
+        signer,
+        netuid,
+        "sudo_set_min_burn",
+        Value::u128(min_burn.as_u128()),
+        wait_for,
+    )
+    .await
+}
+
+/// AdminUtils pallet dispatch: `sudo_set_max_burn(netuid, max_burn)`
+pub async fn set_max_burn(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    max_burn: Rao,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    submit_owner_call(
+        client,
+        signer,
+        netuid,
+        "sudo_set_max_burn",
+        Value::u128(max_burn.as_u128()),
+        wait_for,
+    )
+    .await
+}
+
+/// AdminUtils pallet dispatch: `sudo_set_bonds_moving_average(netuid, bonds_moving_average)`
+pub async fn set_bonds_moving_average(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    bonds_moving_average: u64,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    submit_owner_call(
+        client,
+        signer,
+        netuid,
+        "sudo_set_bonds_moving_average",
+        Value::from(bonds_moving_average),
+        wait_for,
+    )
+    .await
+}
+
+/// Hyperparameters a subnet owner can set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OwnerHyperparameter {
+    ServingRateLimit,
+    WeightsRateLimit,
+    CommitRevealEnabled,
+    MinBurn,
+    MaxBurn,
+    BondsMovingAverage,
+}
+
+impl OwnerHyperparameter {
+    /// All owner-settable hyperparameters
+    pub const ALL: [OwnerHyperparameter; 6] = [
+        OwnerHyperparameter::ServingRateLimit,
+        OwnerHyperparameter::WeightsRateLimit,
+        OwnerHyperparameter::CommitRevealEnabled,
+        OwnerHyperparameter::MinBurn,
+        OwnerHyperparameter::MaxBurn,
+        OwnerHyperparameter::BondsMovingAverage,
+    ];
+
+    /// Get the name of the hyperparameter (as used by btcli)
+    pub fn name(&self) -> &'static str {
+        match self {
+            OwnerHyperparameter::ServingRateLimit => "serving_rate_limit",
+            OwnerHyperparameter::WeightsRateLimit => "weights_rate_limit",
+            OwnerHyperparameter::CommitRevealEnabled => "commit_reveal_weights_enabled",
+            OwnerHyperparameter::MinBurn => "min_burn",
+            OwnerHyperparameter::MaxBurn => "max_burn",
+            OwnerHyperparameter::BondsMovingAverage => "bonds_moving_avg",
+        }
+    }
+
+    /// Parse a value for this hyperparameter
+    ///
+    /// Booleans accept `true`/`false`, `1`/`0` and `yes`/`no`; burn amounts
+    /// are in RAO.
+    pub fn parse_value(&self, value: &str) -> BittensorResult<HyperparameterValue> {
+        let value = value.trim();
+        match self {
+            OwnerHyperparameter::CommitRevealEnabled => match value.to_lowercase().as_str() {
+                "true" | "1" | "yes" => Ok(HyperparameterValue::Bool(true)),
+                "false" | "0" | "no" => Ok(HyperparameterValue::Bool(false)),
+                _ => Err(ExtrinsicError::new(format!(
+                    "Invalid value '{}' for {}: expected true or false",
+                    value,
+                    self.name()
+                ))
+                .into()),
+            },
+            _ => value.parse().map(HyperparameterValue::U64).map_err(|_| {
+                ExtrinsicError::new(format!(
+                    "Invalid value '{}' for {}: expected an unsigned integer",
+                    value,
+                    self.name()
+                ))
+                .into()
+            }),
+        }
+    }
+
+    /// Query the current value on chain
+    pub async fn current(
+        &self,
+        client: &impl ChainBackend,
+        netuid: u16,
+    ) -> BittensorResult<HyperparameterValue> {
+        let value = match self {
+            OwnerHyperparameter::ServingRateLimit => {
+                HyperparameterValue::U64(get_serving_rate_limit(client, netuid).await?)
+            }
+            OwnerHyperparameter::WeightsRateLimit => {
+                HyperparameterValue::U64(get_weights_rate_limit(client, netuid).await?)
+            }
+            OwnerHyperparameter::CommitRevealEnabled => {
+                HyperparameterValue::Bool(get_commit_reveal_weights_enabled(client, netuid).await?)
+            }
+            OwnerHyperparameter::MinBurn => {
+                HyperparameterValue::U64(get_min_burn(client, netuid).await?)
+            }
+            OwnerHyperparameter::MaxBurn => {
+                HyperparameterValue::U64(get_max_burn(client, netuid).await?)
+            }
+            OwnerHyperparameter::BondsMovingAverage => {
+                HyperparameterValue::U64(get_bonds_moving_average(client, netuid).await?)
+            }
+        };
+        Ok(value)
+    }
+
+    /// Set the hyperparameter with its typed setter
+    pub async fn set(
+        &self,
+        client: &impl ChainBackend,
+        signer: &BittensorSigner,
+        netuid: u16,
+        value: HyperparameterValue,
+        wait_for: ExtrinsicWait,
+    ) -> BittensorResult<String> {
+        match (self, value) {
+            (OwnerHyperparameter::ServingRateLimit, HyperparameterValue::U64(v)) => {
+                set_serving_rate_limit(client, signer, netuid, v, wait_for).await
+            }
+            (OwnerHyperparameter::WeightsRateLimit, HyperparameterValue::U64(v)) => {
+                set_weights_rate_limit(client, signer, netuid, v, wait_for).await
+            }
+            (OwnerHyperparameter::CommitRevealEnabled, HyperparameterValue::Bool(v)) => {
+                set_commit_reveal_enabled(client, signer, netuid, v, wait_for).await
+            }
+            (OwnerHyperparameter::MinBurn, HyperparameterValue::U64(v)) => {
+                set_min_burn(client, signer, netuid, Rao::from(v), wait_for).await
+            }
+            (OwnerHyperparameter::MaxBurn, HyperparameterValue::U64(v)) => {
+                set_max_burn(client, signer, netuid, Rao::from(v), wait_for).await
+            }
+            (OwnerHyperparameter::BondsMovingAverage, HyperparameterValue::U64(v)) => {
+                set_bonds_moving_average(client, signer, netuid, v, wait_for).await
+            }
+            (param, value) => {
+                Err(
+                    ExtrinsicError::new(format!("Invalid value {} for {}", value, param.name()))
+                        .into(),
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Display for OwnerHyperparameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for OwnerHyperparameter {
+    type Err = BittensorError;
+
+    /// Parse a hyperparameter name; `-` and `_` are interchangeable
+    fn from_str(s: &str) -> BittensorResult<Self> {
+        match s.trim().to_lowercase().replace('-', "_").as_str() {
+            "serving_rate_limit" => Ok(OwnerHyperparameter::ServingRateLimit),
+            "weights_rate_limit" | "weights_set_rate_limit" => {
+                Ok(OwnerHyperparameter::WeightsRateLimit)
+            }
+            "commit_reveal_weights_enabled" | "commit_reveal_enabled" => {
+                Ok(OwnerHyperparameter::CommitRevealEnabled)
+            }
+            "min_burn" => Ok(OwnerHyperparameter::MinBurn),
+            "max_burn" => Ok(OwnerHyperparameter::MaxBurn),
+            "bonds_moving_avg" | "bonds_moving_average" => {
+                Ok(OwnerHyperparameter::BondsMovingAverage)
+            }
+            _ => Err(ExtrinsicError::new(format!(
+                "Unknown or non-owner hyperparameter '{}', expected one of: {}",
+                s,
+                OwnerHyperparameter::ALL.map(|p| p.name()).join(", ")
+            ))
+            .into()),
+        }
+    }
+}
+
+/// Value of a subnet hyperparameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HyperparameterValue {
+    Bool(bool),
+    U64(u64),
+}
+
+impl fmt::Display for HyperparameterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HyperparameterValue::Bool(v) => write!(f, "{}", v),
+            HyperparameterValue::U64(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_lock_cost() {
+        let tao = 1_000_000_000;
+        // Never registered: last lock cost without doubling
+        assert_eq!(
+            network_lock_cost(100 * tao, 10 * tao, 0, 0, 1000),
+            100 * tao
+        );
+        // Doubles right after a registration
+        assert_eq!(
+            network_lock_cost(100 * tao, 10 * tao, 1000, 1000, 1000),
+            200 * tao
+        );
+        // Decays linearly by last_lock / interval per block
+        assert_eq!(
+            network_lock_cost(100 * tao, 10 * tao, 1000, 1500, 1000),
+            150 * tao
+        );
+        // Floors at the minimum lock cost
+        assert_eq!(
+            network_lock_cost(100 * tao, 10 * tao, 1000, 10_000, 1000),
+            10 * tao
+        );
+        // Zero interval disables the decay
+        assert_eq!(
+            network_lock_cost(100 * tao, 10 * tao, 1000, 5000, 0),
+            200 * tao
+        );
+    }
+
+    #[test]
+    fn test_decode_network_added() {
+        let event = ChainEvent {
+            pallet_name: SUBTENSOR_MODULE.to_string(),
+            variant_name: "NetworkAdded".to_string(),
+            pallet_index: 7,
+            variant_index: 0,
+            field_bytes: vec![0x2a, 0x01, 0x00, 0x00],
+        };
+        assert_eq!(decode_network_added(&event).unwrap(), 298);
+
+        let short = ChainEvent {
+            field_bytes: vec![0x2a],
+            ..event
+        };
+        assert!(decode_network_added(&short).is_err());
+    }
+
+    #[test]
+    fn test_hyperparameter_names() {
+        for param in OwnerHyperparameter::ALL {
+            assert_eq!(param.name().parse::<OwnerHyperparameter>().unwrap(), param);
+        }
+        assert_eq!(
+            "commit-reveal-enabled"
+                .parse::<OwnerHyperparameter>()
+                .unwrap(),
+            OwnerHyperparameter::CommitRevealEnabled
+        );
+        assert_eq!(
+            "Bonds_Moving_Average"
+                .parse::<OwnerHyperparameter>()
+                .unwrap(),
+            OwnerHyperparameter::BondsMovingAverage
+        );
+
+        // Tempo is root-only
+        let err = "tempo".parse::<OwnerHyperparameter>().unwrap_err();
+        assert!(err.to_string().contains("serving_rate_limit"));
+    }
+
+    #[test]
+    fn test_parse_value() {
+        let commit_reveal = OwnerHyperparameter::CommitRevealEnabled;
+        assert_eq!(
+            commit_reveal.parse_value("true").unwrap(),
+            HyperparameterValue::Bool(true)
+        );
+        assert_eq!(
+            commit_reveal.parse_value("0").unwrap(),
+            HyperparameterValue::Bool(false)
+        );
+        assert!(commit_reveal.parse_value("maybe").is_err());
+
+        let min_burn = OwnerHyperparameter::MinBurn;
+        assert_eq!(
+            min_burn.parse_value("500000000").unwrap(),
+            HyperparameterValue::U64(500_000_000)
+        );
+        assert!(min_burn.parse_value("-1").is_err());
+        assert!(min_burn.parse_value("true").is_err());
+        assert_eq!(HyperparameterValue::U64(7).to_string(), "7");
+    }
+}
@@ -1,37 +1,9 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
-use parity_scale_codec::Encode;
-use sp_core::crypto::AccountId32;
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult};
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
-/// Register a new subnet (network) on the Bittensor chain.
-///
-/// Subtensor pallet dispatch: `register_network(hotkey, mechid)`
-/// Source: pallets/subtensor/src/subnets/registration.rs
-///
-/// # Arguments
-/// * `client` — The Bittensor RPC client.
-/// * `signer` — The signing keypair (coldkey that will own the subnet).
-/// * `hotkey` — The hotkey to associate with the new subnet.
-/// * `mechid` — The mechanism ID for the subnet (e.g., 0 for default).
-/// * `wait_for` — How long to wait for on-chain inclusion.
-pub async fn register_network(
-    client: &BittensorClient,
-    signer: &BittensorSigner,
-    hotkey: &AccountId32,
-    mechid: u16,
-    wait_for: ExtrinsicWait,
-) -> Result<String> {
-    let args = vec![Value::from_bytes(hotkey.encode()), Value::from(mechid)];
-
-    client
-        .submit_extrinsic(SUBTENSOR_MODULE, "register_network", args, signer, wait_for)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to register network: {}", e))
-}
-
 /// Dissolve (remove) a subnet from the chain.
 ///
 /// Subtensor pallet dispatch: `dissolve_network(netuid)`
@@ -43,17 +15,17 @@ pub async fn register_network(
 /// * `netuid` — The subnet ID to dissolve.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn dissolve_network(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid)];
 
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "dissolve_network", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to dissolve network: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to dissolve network", e))
 }
 
 /// Set metadata for a subnet.
@@ -67,12 +39,12 @@ pub async fn dissolve_network(
 /// * `metadata` — Metadata bytes to set for the subnet.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn set_subnet_metadata(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     metadata: &[u8],
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from(netuid), Value::from_bytes(metadata)];
 
     client
@@ -84,5 +56,5 @@ pub async fn set_subnet_metadata(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set subnet metadata: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to set subnet metadata", e))
 }
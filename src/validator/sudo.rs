@@ -1,6 +1,6 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult};
 use crate::utils::balance_newtypes::Rao;
-use anyhow::Result;
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
@@ -20,12 +20,12 @@ const ADMIN_UTILS: &str = "AdminUtils";
 /// * `amount` — The balance to set **in RAO** (1 TAO = 1e9 RAO).
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn force_set_balance(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     who: &AccountId32,
     amount: Rao,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![
         Value::from_bytes(who.encode()),
         Value::u128(amount.as_u128()),
@@ -34,7 +34,7 @@ pub async fn force_set_balance(
     client
         .submit_extrinsic(ADMIN_UTILS, "sudo_set_balance", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to force set balance: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to force set balance", e))
 }
 
 /// Execute an arbitrary call via sudo.
@@ -48,15 +48,15 @@ pub async fn force_set_balance(
 /// * `call` — SCALE-encoded call data to execute with sudo privileges.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn sudo(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     call: Vec<u8>,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from_bytes(&call)];
 
     client
         .submit_extrinsic(SUDO_MODULE, "sudo", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to execute sudo call: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to execute sudo call", e))
 }
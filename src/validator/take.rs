@@ -1,5 +1,5 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{BittensorError, BittensorResult};
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
@@ -18,12 +18,12 @@ const SUBTENSOR_MODULE: &str = "SubtensorModule";
 /// * `take` — New take value as u16 proportion.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn increase_take(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkey: &AccountId32,
     take: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from_bytes(hotkey.encode()), Value::from(take)];
 
     client
@@ -35,7 +35,7 @@ pub async fn increase_take(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to increase take: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to increase take", e))
 }
 
 /// Decrease delegate take (commission rate).
@@ -50,12 +50,12 @@ pub async fn increase_take(
 /// * `take` — New take value as u16 proportion.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn decrease_take(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkey: &AccountId32,
     take: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![Value::from_bytes(hotkey.encode()), Value::from(take)];
 
     client
@@ -67,7 +67,7 @@ pub async fn decrease_take(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to decrease take: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to decrease take", e))
 }
 
 /// Become a delegate (enable delegate take > 0).
@@ -82,12 +82,12 @@ pub async fn decrease_take(
 /// * `take` — Initial take value as u16 proportion.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 pub async fn become_delegate(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     hotkey: &AccountId32,
     take: u16,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     let args = vec![
         Value::from_bytes(hotkey.encode()),
         Value::u128(take as u128),
@@ -96,5 +96,5 @@ pub async fn become_delegate(
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "become_delegate", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to become delegate: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to become delegate", e))
 }
@@ -1,7 +1,12 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use crate::core::constants::EXISTENTIAL_DEPOSIT_RAO;
-use crate::utils::balance_newtypes::Rao;
-use anyhow::Result;
+use crate::chain::{BittensorClient, BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{
+    BittensorError, BittensorResult, ChainQueryError, ExtrinsicError, InsufficientBalance,
+    InsufficientStake, StakeFailed, StakeTransferDisabled,
+};
+use crate::queries::balances::{get_balance, get_existential_deposit, get_free_balance};
+use crate::queries::subnets::get_transfer_toggle;
+use crate::utils::balance_newtypes::{Rao, Tao};
+use crate::utils::decoders::{decode_stored_or, decode_u128};
 use parity_scale_codec::Encode;
 use sp_core::crypto::AccountId32;
 use subxt::dynamic::Value;
@@ -9,6 +14,328 @@ use subxt::dynamic::Value;
 const BALANCES_MODULE: &str = "Balances";
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
 
+/// How a transfer treats the sender's account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferMode {
+    /// `Balances.transfer_keep_alive`: fails rather than leave the sender
+    /// below the existential deposit
+    KeepAlive,
+    /// `Balances.transfer_allow_death`: the sender is reaped if left below the
+    /// existential deposit
+    AllowDeath,
+    /// `Balances.transfer_all`: sweep the whole free balance. With
+    /// `keep_alive` the existential deposit stays behind.
+    All { keep_alive: bool },
+}
+
+impl TransferMode {
+    /// Name of the `Balances` call for this mode
+    pub fn call_name(&self) -> &'static str {
+        match self {
+            TransferMode::KeepAlive => "transfer_keep_alive",
+            TransferMode::AllowDeath => "transfer_allow_death",
+            TransferMode::All { .. } => "transfer_all",
+        }
+    }
+}
+
+/// What a transfer will do to the sender's free balance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferPlan {
+    /// Amount leaving the account, before fees
+    pub amount: Rao,
+    /// Free balance left afterwards, before fees
+    pub remaining: Rao,
+    /// Whether the sender's account will be reaped
+    pub reaps_sender: bool,
+}
+
+/// Validate a transfer against the sender's free balance.
+///
+/// `amount` is required for `KeepAlive` and `AllowDeath` and ignored for
+/// `All`, whose amount is computed from `free`. Fees are not included, so a
+/// sweep moves slightly less than planned.
+///
+/// # Errors
+/// Returns an error if the amount is missing, zero or above the safety limit,
+/// or `InsufficientBalance` if the free balance can't cover it in this mode.
+pub fn plan_transfer(
+    mode: TransferMode,
+    amount: Option<Rao>,
+    free: Rao,
+    existential_deposit: Rao,
+) -> BittensorResult<TransferPlan> {
+    if let TransferMode::All { keep_alive } = mode {
+        let retained = if keep_alive {
+            existential_deposit
+        } else {
+            Rao::ZERO
+        };
+        let amount = free.saturating_sub(retained);
+        if amount == Rao::ZERO {
+            return Err(InsufficientBalance::with_amounts(
+                "Nothing to transfer",
+                retained.as_u128().saturating_add(1),
+                free.as_u128(),
+            )
+            .into());
+        }
+        return Ok(TransferPlan {
+            amount,
+            remaining: retained,
+            reaps_sender: !keep_alive,
+        });
+    }
+
+    let amount = amount.ok_or_else(|| ExtrinsicError::new("Transfer amount is required"))?;
+    validate_amount(amount)?;
+
+    let required = match mode {
+        TransferMode::KeepAlive => amount.saturating_add(existential_deposit),
+        _ => amount,
+    };
+    if free < required {
+        let message = if mode == TransferMode::KeepAlive && free >= amount {
+            "Transfer would leave less than the existential deposit; use AllowDeath to \
+             empty the account"
+        } else {
+            "Free balance is lower than the transfer amount"
+        };
+        return Err(
+            InsufficientBalance::with_amounts(message, required.as_u128(), free.as_u128()).into(),
+        );
+    }
+
+    let remaining = free.saturating_sub(amount);
+    Ok(TransferPlan {
+        amount,
+        remaining,
+        reaps_sender: remaining < existential_deposit,
+    })
+}
+
+/// Plan a transfer from `account` using its current free balance.
+///
+/// See [`plan_transfer`].
+pub async fn get_transfer_plan(
+    client: &BittensorClient,
+    account: &AccountId32,
+    mode: TransferMode,
+    amount: Option<Rao>,
+) -> BittensorResult<TransferPlan> {
+    let free = get_balance(client, account).await?;
+    let existential_deposit = get_existential_deposit(client).await?;
+    plan_transfer(mode, amount, free, existential_deposit)
+}
+
+/// Amount of a [`transfer_safe`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransferAmount {
+    /// Exactly this amount
+    Exact(Tao),
+    /// The whole free balance less the fee, and less the existential deposit
+    /// with `keep_alive`
+    Max,
+}
+
+impl From<Tao> for TransferAmount {
+    fn from(amount: Tao) -> Self {
+        TransferAmount::Exact(amount)
+    }
+}
+
+/// What a [`transfer_safe`] will send and leave behind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferPreview {
+    /// Amount the destination receives
+    pub amount: Rao,
+    /// Estimated fee paid by the sender
+    pub fee: Rao,
+    /// Free balance left after the amount and fee
+    pub remaining: Rao,
+    /// Whether the sender's account will be reaped
+    pub reaps_sender: bool,
+}
+
+/// Validate a transfer against the sender's free balance and the fee.
+///
+/// With `keep_alive`, `free - amount - fee` must stay at or above the
+/// existential deposit. [`TransferAmount::Max`] sends whatever is left after
+/// the fee and, with `keep_alive`, the existential deposit.
+///
+/// # Errors
+/// Returns an error if the amount is invalid, zero or above the safety
+/// limit, or `InsufficientBalance` whose required amount exceeds the free
+/// balance by the exact shortfall.
+pub fn plan_safe_transfer(
+    amount: TransferAmount,
+    keep_alive: bool,
+    free: Rao,
+    fee: Rao,
+    existential_deposit: Rao,
+) -> BittensorResult<TransferPreview> {
+    let retained = if keep_alive {
+        existential_deposit
+    } else {
+        Rao::ZERO
+    };
+    let reserved = fee.saturating_add(retained);
+    let kept = if keep_alive {
+        " and the existential deposit"
+    } else {
+        ""
+    };
+
+    let amount = match amount {
+        TransferAmount::Exact(tao) => {
+            let amount = exact_amount(tao)?;
+            let required = amount.saturating_add(reserved);
+            if free < required {
+                return Err(InsufficientBalance::with_amounts(
+                    format!(
+                        "Free balance {} cannot cover {} plus the {} fee{}; {} short",
+                        free.format_tao(),
+                        amount.format_tao(),
+                        fee.format_tao(),
+                        kept,
+                        required.saturating_sub(free).format_tao()
+                    ),
+                    required.as_u128(),
+                    free.as_u128(),
+                )
+                .into());
+            }
+            amount
+        }
+        TransferAmount::Max => {
+            let amount = free.saturating_sub(reserved);
+            if amount == Rao::ZERO {
+                let required = reserved.saturating_add(Rao::new(1));
+                return Err(InsufficientBalance::with_amounts(
+                    format!(
+                        "Nothing to transfer: free balance {} only covers the {} fee{}; {} short",
+                        free.format_tao(),
+                        fee.format_tao(),
+                        kept,
+                        required.saturating_sub(free).format_tao()
+                    ),
+                    required.as_u128(),
+                    free.as_u128(),
+                )
+                .into());
+            }
+            validate_amount(amount)?;
+            amount
+        }
+    };
+
+    let remaining = free.saturating_sub(amount).saturating_sub(fee);
+    Ok(TransferPreview {
+        amount,
+        fee,
+        remaining,
+        reaps_sender: remaining < existential_deposit,
+    })
+}
+
+/// Preview a [`transfer_safe`] from `signer` with its current free balance
+/// and an estimated fee.
+///
+/// See [`plan_safe_transfer`].
+pub async fn preview_transfer(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    dest: &AccountId32,
+    amount: TransferAmount,
+    keep_alive: bool,
+    existential_deposit: Rao,
+) -> BittensorResult<TransferPreview> {
+    let source = AccountId32::from(signer.account_id().0);
+    let free = get_free_balance(client, &source).await?;
+
+    // A sweep is estimated for the whole balance; its fee is no lower than
+    // the fee of the smaller amount finally sent
+    let estimated = match amount {
+        TransferAmount::Exact(tao) => exact_amount(tao)?,
+        TransferAmount::Max => free,
+    };
+    let mode = keep_alive_mode(keep_alive);
+    let fee = client
+        .estimate_fee(
+            BALANCES_MODULE,
+            mode.call_name(),
+            transfer_args(dest, mode, estimated),
+            signer,
+        )
+        .await
+        .map_err(|e| BittensorError::from_chain("Failed to estimate the transfer fee", e))?;
+
+    plan_safe_transfer(
+        amount,
+        keep_alive,
+        free,
+        Rao::from(fee),
+        existential_deposit,
+    )
+}
+
+/// Transfer TAO after checking it against the free balance and fee.
+///
+/// Unlike [`transfer`], the fee is estimated first: with `keep_alive` the
+/// transfer is refused unless the existential deposit stays behind after the
+/// fee, and [`TransferAmount::Max`] is reduced by the fee so it cannot fail
+/// for lack of funds.
+///
+/// # Arguments
+/// * `client` — The Bittensor RPC client.
+/// * `signer` — The signing keypair (source of funds).
+/// * `dest` — Destination account.
+/// * `amount` — Amount to transfer, or `Max`.
+/// * `keep_alive` — Keep the sender above the existential deposit, using
+///   `transfer_keep_alive`; otherwise `transfer_allow_death`.
+/// * `existential_deposit` — The chain's existential deposit, see
+///   [`ChainConstants`](crate::chain::ChainConstants).
+/// * `wait_for` — How long to wait for on-chain inclusion.
+///
+/// # Returns
+/// The transaction hash and the preview that was submitted.
+///
+/// # Errors
+/// See [`plan_safe_transfer`]; also fails if the fee cannot be estimated or
+/// the extrinsic submission fails.
+#[allow(clippy::too_many_arguments)]
+pub async fn transfer_safe(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    dest: &AccountId32,
+    amount: TransferAmount,
+    keep_alive: bool,
+    existential_deposit: Rao,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<(String, TransferPreview)> {
+    let preview = preview_transfer(
+        client,
+        signer,
+        dest,
+        amount,
+        keep_alive,
+        existential_deposit,
+    )
+    .await?;
+    if preview.reaps_sender {
+        tracing::warn!(
+            "Transfer of {} leaves {} behind; account {} will be reaped",
+            preview.amount.format_tao(),
+            preview.remaining.format_tao(),
+            AccountId32::from(signer.account_id().0)
+        );
+    }
+
+    let mode = keep_alive_mode(keep_alive);
+    let tx_hash = submit_transfer(client, signer, dest, mode, preview.amount, wait_for).await?;
+    Ok((tx_hash, preview))
+}
+
 /// Transfer TAO to another account.
 ///
 /// # Arguments
@@ -18,61 +345,135 @@ const SUBTENSOR_MODULE: &str = "SubtensorModule";
 /// * `amount` — Amount to transfer **in RAO** (1 TAO = 1e9 RAO).
 /// * `keep_alive` — When `true`, uses `transfer_keep_alive` which ensures the
 ///   sender's account is not reaped (balance stays above the existential
-///   deposit).
+///   deposit); otherwise `transfer_allow_death`.
 /// * `wait_for` — How long to wait for on-chain inclusion.
 ///
 /// # Errors
 /// Returns an error if the amount is zero, exceeds the safety limit, or the
 /// extrinsic submission fails.
 pub async fn transfer(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     dest: &AccountId32,
     amount: Rao,
     keep_alive: bool,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
+    validate_amount(amount)?;
+
+    let existential_deposit = client.constants().existential_deposit;
+    if keep_alive && amount < existential_deposit {
+        tracing::warn!(
+            "Transfer amount ({} RAO) is below the existential deposit ({} RAO). \
+             The transaction may fail on-chain.",
+            amount.as_u128(),
+            existential_deposit.as_u128(),
+        );
+    }
+
+    let mode = keep_alive_mode(keep_alive);
+    submit_transfer(client, signer, dest, mode, amount, wait_for).await
+}
+
+/// Transfer TAO with an explicit [`TransferMode`].
+///
+/// The transfer is checked against the signer's current free balance with
+/// [`plan_transfer`] before submission.
+///
+/// # Arguments
+/// * `client` — The Bittensor RPC client.
+/// * `signer` — The signing keypair (source of funds).
+/// * `dest` — Destination account.
+/// * `mode` — Which `Balances` call to use.
+/// * `amount` — Amount **in RAO**; required unless `mode` is `All`.
+/// * `wait_for` — How long to wait for on-chain inclusion.
+///
+/// # Returns
+/// The transaction hash and the plan that was submitted.
+pub async fn transfer_with_mode(
+    client: &BittensorClient,
+    signer: &BittensorSigner,
+    dest: &AccountId32,
+    mode: TransferMode,
+    amount: Option<Rao>,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<(String, TransferPlan)> {
+    let source = AccountId32::from(signer.account_id().0);
+    let plan = get_transfer_plan(client, &source, mode, amount).await?;
+    if plan.reaps_sender {
+        tracing::warn!(
+            "Transfer of {} leaves {} behind; account {} will be reaped",
+            plan.amount.format_tao(),
+            plan.remaining.format_tao(),
+            source
+        );
+    }
+
+    let tx_hash = submit_transfer(client, signer, dest, mode, plan.amount, wait_for).await?;
+    Ok((tx_hash, plan))
+}
+
+fn validate_amount(amount: Rao) -> BittensorResult<()> {
     if amount.as_u128() == 0 {
-        return Err(anyhow::anyhow!("Transfer amount must be greater than zero"));
+        return Err(ExtrinsicError::new("Transfer amount must be greater than zero").into());
     }
 
     if !amount.is_valid_transfer_amount() {
-        return Err(anyhow::anyhow!(
+        return Err(ExtrinsicError::new(format!(
             "Transfer amount {} RAO exceeds the safe maximum (u64::MAX). \
              This is almost certainly an error — did you pass TAO instead of RAO?",
             amount.as_u128()
-        ));
-    }
-
-    if keep_alive && amount.as_u128() < EXISTENTIAL_DEPOSIT_RAO {
-        tracing::warn!(
-            "Transfer amount ({} RAO) is below the existential deposit ({} RAO). \
-             The transaction may fail on-chain.",
-            amount.as_u128(),
-            EXISTENTIAL_DEPOSIT_RAO,
-        );
+        ))
+        .into());
     }
+    Ok(())
+}
 
-    let dest_bytes = dest.encode();
-    let dest_value = Value::from_bytes(&dest_bytes);
+/// Convert an exact TAO amount, validating it like [`validate_amount`]
+fn exact_amount(amount: Tao) -> BittensorResult<Rao> {
+    let amount = amount
+        .to_rao_checked()
+        .ok_or_else(|| ExtrinsicError::new(format!("Invalid transfer amount {}", amount)))?;
+    validate_amount(amount)?;
+    Ok(amount)
+}
 
-    let function = if keep_alive {
-        "transfer_keep_alive"
+fn keep_alive_mode(keep_alive: bool) -> TransferMode {
+    if keep_alive {
+        TransferMode::KeepAlive
     } else {
-        "transfer"
-    };
+        TransferMode::AllowDeath
+    }
+}
 
-    let args = vec![dest_value, Value::u128(amount.as_u128())];
+fn transfer_args(dest: &AccountId32, mode: TransferMode, amount: Rao) -> Vec<Value> {
+    let dest_value = Value::from_bytes(dest.encode());
+    match mode {
+        TransferMode::All { keep_alive } => vec![dest_value, Value::bool(keep_alive)],
+        _ => vec![dest_value, Value::u128(amount.as_u128())],
+    }
+}
 
+async fn submit_transfer(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    dest: &AccountId32,
+    mode: TransferMode,
+    amount: Rao,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
+    let args = transfer_args(dest, mode, amount);
     client
-        .submit_extrinsic(BALANCES_MODULE, function, args, signer, wait_for)
+        .submit_extrinsic(BALANCES_MODULE, mode.call_name(), args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to transfer: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to transfer", e))
 }
 
 /// Transfer stake from one coldkey to another, optionally across subnets.
 ///
 /// Subtensor expects: `(destination_coldkey, hotkey, origin_netuid, destination_netuid, alpha_amount)`.
+/// Before submitting, `TransferToggle` is checked on both subnets and the
+/// signer's alpha on the origin subnet must cover `amount`.
 ///
 /// # Arguments
 /// * `client` — The Bittensor RPC client.
@@ -81,15 +482,17 @@ pub async fn transfer(
 /// * `hotkey` — The hotkey whose stake is being transferred.
 /// * `origin_netuid` — Source subnet ID.
 /// * `destination_netuid` — Target subnet ID.
-/// * `amount` — Amount to transfer **in RAO** (1 TAO = 1e9 RAO).
+/// * `amount` — Alpha to transfer **in RAO** (1 TAO = 1e9 RAO).
 /// * `wait_for` — How long to wait for on-chain inclusion.
 ///
 /// # Errors
-/// Returns an error if the amount is zero, exceeds the safety limit, or the
-/// extrinsic submission fails.
+/// Returns an error if the amount is zero or exceeds the safety limit, if the
+/// destination is the origin, `StakeTransferDisabled` if either subnet has
+/// transfers turned off, `InsufficientStake` if the origin alpha is too low,
+/// or if the extrinsic submission fails.
 #[allow(clippy::too_many_arguments)]
 pub async fn transfer_stake(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     destination_coldkey: &AccountId32,
     hotkey: &AccountId32,
@@ -97,19 +500,48 @@ pub async fn transfer_stake(
     destination_netuid: u16,
     amount: Rao,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if amount.as_u128() == 0 {
-        return Err(anyhow::anyhow!(
-            "Stake transfer amount must be greater than zero"
-        ));
+        return Err(StakeFailed::new("Stake transfer amount must be greater than zero").into());
     }
 
     if !amount.is_valid_transfer_amount() {
-        return Err(anyhow::anyhow!(
+        return Err(StakeFailed::new(format!(
             "Stake transfer amount {} RAO exceeds the safe maximum (u64::MAX). \
              This is almost certainly an error — did you pass TAO instead of RAO?",
             amount.as_u128()
-        ));
+        ))
+        .into());
+    }
+
+    let origin_coldkey = AccountId32::from(signer.account_id().0);
+    if *destination_coldkey == origin_coldkey && origin_netuid == destination_netuid {
+        return Err(StakeFailed::new(
+            "Destination coldkey and subnet are the same as the origin; nothing to transfer",
+        )
+        .into());
+    }
+
+    for netuid in [origin_netuid, destination_netuid] {
+        if !get_transfer_toggle(client, netuid).await? {
+            return Err(StakeTransferDisabled::new(netuid).into());
+        }
+    }
+
+    let alpha = get_origin_alpha(client, &origin_coldkey, hotkey, origin_netuid).await?;
+    if alpha < amount {
+        return Err(InsufficientStake::with_amounts(
+            format!(
+                "{} staked to {} on subnet {}, cannot transfer {}",
+                alpha.format_tao(),
+                hotkey,
+                origin_netuid,
+                amount.format_tao()
+            ),
+            amount.as_u128(),
+            alpha.as_u128(),
+        )
+        .into());
     }
 
     let args = vec![
@@ -123,5 +555,176 @@ pub async fn transfer_stake(
     client
         .submit_extrinsic(SUBTENSOR_MODULE, "transfer_stake", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to transfer stake: {}", e))
+        .map_err(|e| BittensorError::from_chain("Failed to transfer stake", e))
+}
+
+/// Alpha held by `coldkey` on `hotkey` in a subnet; zero if never staked
+async fn get_origin_alpha(
+    client: &impl ChainBackend,
+    coldkey: &AccountId32,
+    hotkey: &AccountId32,
+    netuid: u16,
+) -> BittensorResult<Rao> {
+    let keys = vec![
+        Value::from_bytes(hotkey.encode()),
+        Value::from_bytes(coldkey.encode()),
+        Value::u128(netuid as u128),
+    ];
+    let value = client
+        .storage_with_keys(SUBTENSOR_MODULE, "Alpha", keys)
+        .await?;
+    decode_stored_or(value, 0, decode_u128)
+        .map(Rao::from)
+        .map_err(|e| ChainQueryError::new(format!("Failed to decode Alpha stake: {}", e)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ED: Rao = Rao::new(500);
+    const TAO: u128 = 1_000_000_000;
+
+    #[test]
+    fn test_plan_keep_alive() {
+        let plan = plan_transfer(
+            TransferMode::KeepAlive,
+            Some(Rao::new(400)),
+            Rao::new(1000),
+            ED,
+        )
+        .unwrap();
+        assert_eq!(plan.remaining, Rao::new(600));
+        assert!(!plan.reaps_sender);
+
+        // Would drop the sender below the deposit
+        let err = plan_transfer(
+            TransferMode::KeepAlive,
+            Some(Rao::new(600)),
+            Rao::new(1000),
+            ED,
+        )
+        .unwrap_err();
+        assert!(matches!(err, BittensorError::InsufficientBalance(_)));
+
+        assert!(plan_transfer(TransferMode::KeepAlive, None, Rao::new(1000), ED).is_err());
+        assert!(
+            plan_transfer(TransferMode::KeepAlive, Some(Rao::ZERO), Rao::new(1000), ED).is_err()
+        );
+    }
+
+    #[test]
+    fn test_plan_allow_death() {
+        let plan = plan_transfer(
+            TransferMode::AllowDeath,
+            Some(Rao::new(600)),
+            Rao::new(1000),
+            ED,
+        )
+        .unwrap();
+        assert_eq!(plan.remaining, Rao::new(400));
+        assert!(plan.reaps_sender);
+
+        let plan = plan_transfer(
+            TransferMode::AllowDeath,
+            Some(Rao::new(100)),
+            Rao::new(1000),
+            ED,
+        )
+        .unwrap();
+        assert!(!plan.reaps_sender);
+
+        assert!(plan_transfer(
+            TransferMode::AllowDeath,
+            Some(Rao::new(1001)),
+            Rao::new(1000),
+            ED
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_plan_transfer_all() {
+        let keep = TransferMode::All { keep_alive: true };
+        let plan = plan_transfer(keep, None, Rao::new(1000), ED).unwrap();
+        assert_eq!(plan.amount, Rao::new(500));
+        assert_eq!(plan.remaining, ED);
+        assert!(!plan.reaps_sender);
+
+        // The amount is ignored for a sweep
+        let sweep = TransferMode::All { keep_alive: false };
+        let plan = plan_transfer(sweep, Some(Rao::new(1)), Rao::new(1000), ED).unwrap();
+        assert_eq!(plan.amount, Rao::new(1000));
+        assert_eq!(plan.remaining, Rao::ZERO);
+        assert!(plan.reaps_sender);
+
+        assert!(plan_transfer(keep, None, ED, ED).is_err());
+    }
+
+    /// Required and available amounts of an `InsufficientBalance` error
+    fn shortfall(err: BittensorError) -> (u128, u128) {
+        match err {
+            BittensorError::InsufficientBalance(e) => (e.required.unwrap(), e.available.unwrap()),
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn test_plan_safe_transfer_keep_alive() {
+        let free = Rao::new(3 * TAO);
+        let fee = Rao::new(TAO / 4);
+        let ed = Rao::new(TAO / 2);
+
+        // Exactly the existential deposit stays behind
+        let preview = plan_safe_transfer(Tao::new(2.25).into(), true, free, fee, ed).unwrap();
+        assert_eq!(preview.amount, Rao::new(2_250_000_000));
+        assert_eq!(preview.fee, fee);
+        assert_eq!(preview.remaining, ed);
+        assert!(!preview.reaps_sender);
+
+        let err = plan_safe_transfer(Tao::new(2.5).into(), true, free, fee, ed).unwrap_err();
+        assert_eq!(shortfall(err), (3_250_000_000, 3 * TAO));
+
+        let max = plan_safe_transfer(TransferAmount::Max, true, free, fee, ed).unwrap();
+        assert_eq!(max, preview);
+
+        // Only the fee and deposit are left
+        let err = plan_safe_transfer(TransferAmount::Max, true, Rao::new(750_000_000), fee, ed)
+            .unwrap_err();
+        assert_eq!(shortfall(err), (750_000_001, 750_000_000));
+    }
+
+    #[test]
+    fn test_plan_safe_transfer_allow_death() {
+        let free = Rao::new(3 * TAO);
+        let fee = Rao::new(TAO / 4);
+        let ed = Rao::new(TAO / 2);
+
+        let max = plan_safe_transfer(TransferAmount::Max, false, free, fee, ed).unwrap();
+        assert_eq!(max.amount, Rao::new(2_750_000_000));
+        assert_eq!(max.remaining, Rao::ZERO);
+        assert!(max.reaps_sender);
+
+        let exact = plan_safe_transfer(Tao::new(2.75).into(), false, free, fee, ed).unwrap();
+        assert_eq!(exact, max);
+
+        let err = plan_safe_transfer(Tao::new(2.875).into(), false, free, fee, ed).unwrap_err();
+        assert_eq!(shortfall(err), (3_125_000_000, 3 * TAO));
+
+        for invalid in [0.0, -1.0, f64::NAN] {
+            let err =
+                plan_safe_transfer(Tao::new(invalid).into(), false, free, fee, ed).unwrap_err();
+            assert!(!matches!(err, BittensorError::InsufficientBalance(_)));
+        }
+    }
+
+    #[test]
+    fn test_call_names() {
+        assert_eq!(TransferMode::KeepAlive.call_name(), "transfer_keep_alive");
+        assert_eq!(TransferMode::AllowDeath.call_name(), "transfer_allow_death");
+        assert_eq!(
+            TransferMode::All { keep_alive: true }.call_name(),
+            "transfer_all"
+        );
+    }
 }
@@ -1,5 +1,7 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
-use anyhow::Result;
+use crate::chain::{
+    BittensorClient, BittensorSigner, ChainBackend, ExtrinsicResult, ExtrinsicWait,
+};
+use crate::errors::{BittensorError, BittensorResult, ExtrinsicError};
 use subxt::dynamic::Value;
 
 const UTILITY_MODULE: &str = "Utility";
@@ -63,6 +65,27 @@ impl BatchCall {
         )
     }
 
+    /// Create a commit_timelocked_mechanism_weights call (CRv4) for batching
+    pub fn commit_timelocked_mechanism_weights(
+        netuid: u16,
+        mechanism_id: u8,
+        commit: &[u8],
+        reveal_round: u64,
+        commit_reveal_version: u16,
+    ) -> Self {
+        Self::new(
+            "SubtensorModule",
+            "commit_timelocked_mechanism_weights",
+            vec![
+                Value::from(netuid),
+                Value::from(mechanism_id),
+                Value::from_bytes(commit),
+                Value::from(reveal_round),
+                Value::from(commit_reveal_version),
+            ],
+        )
+    }
+
     /// Create a commit_mechanism_weights call for batching
     pub fn commit_mechanism_weights(netuid: u16, mechanism_id: u8, commit_hash: &[u8; 32]) -> Self {
         Self::new(
@@ -80,13 +103,13 @@ impl BatchCall {
 /// Execute a batch of calls atomically (all succeed or all fail)
 /// Uses Utility.batch_all which rolls back if any call fails
 pub async fn batch_all(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     calls: Vec<BatchCall>,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if calls.is_empty() {
-        return Err(anyhow::anyhow!("Cannot batch empty call list"));
+        return Err(ExtrinsicError::new("Cannot batch empty call list").into());
     }
 
     let call_values = build_batch_call_values(&calls);
@@ -95,7 +118,7 @@ pub async fn batch_all(
     let tx_hash = client
         .submit_extrinsic(UTILITY_MODULE, "batch_all", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to submit batch_all: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to submit batch_all", e))?;
 
     Ok(tx_hash)
 }
@@ -104,13 +127,13 @@ pub async fn batch_all(
 /// reverting the entire batch).
 /// Uses Utility.batch which continues executing even if some calls fail.
 pub async fn batch(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     calls: Vec<BatchCall>,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if calls.is_empty() {
-        return Err(anyhow::anyhow!("Cannot batch empty call list"));
+        return Err(ExtrinsicError::new("Cannot batch empty call list").into());
     }
 
     let call_values = build_batch_call_values(&calls);
@@ -119,7 +142,7 @@ pub async fn batch(
     let tx_hash = client
         .submit_extrinsic(UTILITY_MODULE, "batch", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to submit batch: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to submit batch", e))?;
 
     Ok(tx_hash)
 }
@@ -128,13 +151,13 @@ pub async fn batch(
 /// dispatches calls with root origin when possible. Individual calls may fail
 /// without reverting the batch.
 pub async fn force_batch(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     calls: Vec<BatchCall>,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if calls.is_empty() {
-        return Err(anyhow::anyhow!("Cannot batch empty call list"));
+        return Err(ExtrinsicError::new("Cannot batch empty call list").into());
     }
 
     let call_values = build_batch_call_values(&calls);
@@ -143,11 +166,118 @@ pub async fn force_batch(
     let tx_hash = client
         .submit_extrinsic(UTILITY_MODULE, "force_batch", args, signer, wait_for)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to submit force_batch: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to submit force_batch", e))?;
 
     Ok(tx_hash)
 }
 
+/// How a batch handles a call that fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchMode {
+    /// `Utility.batch_all`: a failing call reverts the whole batch
+    #[default]
+    All,
+    /// `Utility.batch`: stops at the first failing call; the calls before it
+    /// stay applied
+    StopOnError,
+    /// `Utility.force_batch`: every call is attempted and failing calls are
+    /// skipped
+    Force,
+}
+
+impl BatchMode {
+    /// Name of the `Utility` call
+    pub fn function(&self) -> &'static str {
+        match self {
+            BatchMode::All => "batch_all",
+            BatchMode::StopOnError => "batch",
+            BatchMode::Force => "force_batch",
+        }
+    }
+}
+
+/// Result of a batch extrinsic
+#[derive(Debug, Clone)]
+pub struct BatchOutcome {
+    pub tx_hash: String,
+    /// Whether each call succeeded, in submission order
+    pub succeeded: Vec<bool>,
+}
+
+impl BatchOutcome {
+    /// Whether every call succeeded
+    pub fn all_succeeded(&self) -> bool {
+        self.succeeded.iter().all(|&ok| ok)
+    }
+}
+
+/// Submit `calls` as one batch extrinsic and report which calls succeeded
+///
+/// Fails only if the extrinsic could not be submitted; a failing call is
+/// reported in [`BatchOutcome::succeeded`] according to `mode`. With
+/// [`BatchMode::All`] a failing call marks every call as failed. With
+/// [`ExtrinsicWait::None`] there are no events, so every call is reported as
+/// succeeded.
+pub async fn submit_batch(
+    client: &BittensorClient,
+    signer: &BittensorSigner,
+    calls: Vec<BatchCall>,
+    mode: BatchMode,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<BatchOutcome> {
+    if calls.is_empty() {
+        return Err(ExtrinsicError::new("Cannot batch empty call list").into());
+    }
+
+    let call_values = build_batch_call_values(&calls);
+    let args = vec![Value::unnamed_composite(call_values)];
+
+    let result = client
+        .submit_extrinsic_with_result(UTILITY_MODULE, mode.function(), args, signer, wait_for)
+        .await
+        .map_err(|e| {
+            BittensorError::from_chain(format!("Failed to submit {}", mode.function()), e)
+        })?;
+
+    Ok(BatchOutcome {
+        succeeded: batch_item_results(&result, calls.len()),
+        tx_hash: result.tx_hash,
+    })
+}
+
+/// Per-call success of a batch extrinsic, from its `Utility` events
+///
+/// Each executed call emits `ItemCompleted` or, in `force_batch`,
+/// `ItemFailed`. `batch` emits `BatchInterrupted` at the first failure and
+/// skips the remaining calls. Calls without an event count as succeeded
+/// only if the extrinsic succeeded and was not interrupted.
+pub fn batch_item_results(result: &ExtrinsicResult, len: usize) -> Vec<bool> {
+    if !result.is_success() {
+        return vec![false; len];
+    }
+
+    let mut succeeded = Vec::with_capacity(len);
+    let mut interrupted = false;
+    for event in result
+        .events
+        .iter()
+        .filter(|e| e.pallet_name == UTILITY_MODULE)
+    {
+        match event.variant_name.as_str() {
+            "ItemCompleted" => succeeded.push(true),
+            "ItemFailed" => succeeded.push(false),
+            "BatchInterrupted" => {
+                interrupted = true;
+                break;
+            }
+            _ => {}
+        }
+    }
+    succeeded.truncate(len);
+    succeeded.resize(len, !interrupted);
+    succeeded
+}
+
 fn build_batch_call_values(calls: &[BatchCall]) -> Vec<Value> {
     calls
         .iter()
@@ -205,6 +335,28 @@ fn build_batch_call_values(calls: &[BatchCall]) -> Vec<Value> {
                         call.args.get(3).cloned().unwrap_or(Value::from(0u64)),
                     ),
                 ],
+                "commit_timelocked_mechanism_weights" => vec![
+                    (
+                        "netuid",
+                        call.args.first().cloned().unwrap_or(Value::from(0u16)),
+                    ),
+                    (
+                        "mecid",
+                        call.args.get(1).cloned().unwrap_or(Value::from(0u8)),
+                    ),
+                    (
+                        "commit",
+                        call.args.get(2).cloned().unwrap_or(Value::from_bytes([])),
+                    ),
+                    (
+                        "reveal_round",
+                        call.args.get(3).cloned().unwrap_or(Value::from(0u64)),
+                    ),
+                    (
+                        "commit_reveal_version",
+                        call.args.get(4).cloned().unwrap_or(Value::from(0u16)),
+                    ),
+                ],
                 "commit_mechanism_weights" => vec![
                     (
                         "netuid",
@@ -240,3 +392,85 @@ fn build_batch_call_values(calls: &[BatchCall]) -> Vec<Value> {
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::{ChainEvent, DispatchResult};
+
+    fn event(pallet: &str, variant: &str) -> ChainEvent {
+        ChainEvent {
+            pallet_name: pallet.to_string(),
+            variant_name: variant.to_string(),
+            pallet_index: 0,
+            variant_index: 0,
+            field_bytes: Vec::new(),
+        }
+    }
+
+    fn result(events: &[(&str, &str)], dispatch_result: DispatchResult) -> ExtrinsicResult {
+        ExtrinsicResult {
+            tx_hash: "0x01".to_string(),
+            block_hash: None,
+            events: events.iter().map(|(p, v)| event(p, v)).collect(),
+            dispatch_result,
+        }
+    }
+
+    #[test]
+    fn test_batch_all_results() {
+        let ok = result(
+            &[
+                ("SubtensorModule", "TimelockedWeightsCommitted"),
+                ("Utility", "ItemCompleted"),
+                ("SubtensorModule", "TimelockedWeightsCommitted"),
+                ("Utility", "ItemCompleted"),
+                ("Utility", "BatchCompleted"),
+            ],
+            DispatchResult::Success,
+        );
+        assert_eq!(batch_item_results(&ok, 2), vec![true, true]);
+
+        let reverted = result(
+            &[],
+            DispatchResult::Error {
+                pallet_name: "SubtensorModule".to_string(),
+                error_name: "CommittingWeightsTooFast".to_string(),
+                pallet_index: 7,
+                error_index: 1,
+                description: String::new(),
+            },
+        );
+        assert_eq!(batch_item_results(&reverted, 2), vec![false, false]);
+    }
+
+    #[test]
+    fn test_batch_interrupted_results() {
+        let interrupted = result(
+            &[
+                ("Utility", "ItemCompleted"),
+                ("Utility", "BatchInterrupted"),
+            ],
+            DispatchResult::Success,
+        );
+        assert_eq!(
+            batch_item_results(&interrupted, 3),
+            vec![true, false, false]
+        );
+    }
+
+    #[test]
+    fn test_force_batch_results() {
+        let partial = result(
+            &[
+                ("Utility", "ItemFailed"),
+                ("Utility", "ItemCompleted"),
+                ("Utility", "BatchCompletedWithErrors"),
+            ],
+            DispatchResult::Success,
+        );
+        assert_eq!(batch_item_results(&partial, 2), vec![false, true]);
+        assert_eq!(BatchMode::Force.function(), "force_batch");
+        assert_eq!(BatchMode::default().function(), "batch_all");
+    }
+}
@@ -1,8 +1,12 @@
-use crate::chain::{BittensorClient, BittensorSigner, ExtrinsicWait};
+use crate::chain::{BittensorSigner, ChainBackend, ExtrinsicWait};
+use crate::errors::{
+    BittensorError, BittensorResult, InvalidWeights, WeightVersionMismatch, WeightsError,
+};
+use crate::queries::hyperparameters::get_weights_version_key;
 use crate::utils::{
     commit_hash_to_hex, generate_salt, generate_subtensor_commit_hash, salt_u8_to_u16,
+    WeightBounds, WeightValidation,
 };
-use anyhow::Result;
 use subxt::dynamic::Value;
 
 const SUBTENSOR_MODULE: &str = "SubtensorModule";
@@ -11,23 +15,110 @@ const COMMIT_WEIGHTS_FUNCTION: &str = "commit_weights";
 const REVEAL_WEIGHTS_FUNCTION: &str = "reveal_weights";
 
 /// Set weights for specified UIDs on the subnet
+///
+/// Inputs are checked against the subnet's `WeightBounds` first, so bad UIDs
+/// fail locally instead of after fees. `validation` selects whether
+/// violations are errors or are dropped with a warning.
+#[allow(clippy::too_many_arguments)]
 pub async fn set_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     uids: &[u16],
     weights: &[u16],
     version_key: u64,
+    validation: WeightValidation,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
+    let (uids, weights) =
+        check_weight_inputs(client, signer, netuid, uids, weights, validation).await?;
+    submit_set_weights(
+        client,
+        signer,
+        netuid,
+        &uids,
+        &weights,
+        version_key,
+        wait_for,
+    )
+    .await
+}
+
+/// Check weight inputs against `netuid`'s bounds, returning what to submit
+///
+/// The signer's own UID is only looked up for single-entry self-weights.
+pub async fn check_weight_inputs(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    uids: &[u16],
+    weights: &[u16],
+    validation: WeightValidation,
+) -> BittensorResult<(Vec<u16>, Vec<u16>)> {
+    let bounds = WeightBounds::fetch(client, netuid).await?;
+    let self_uid = if uids.len() == 1 {
+        let hotkey = sp_core::crypto::AccountId32::from(signer.account_id().0);
+        crate::queries::neurons::get_uid_for_hotkey(client, netuid, &hotkey).await?
+    } else {
+        None
+    };
+    bounds.check(uids, weights, self_uid, validation)
+}
+
+/// The `version_key` to submit weights with on `netuid`
+///
+/// `None` uses the subnet's `WeightsVersionKey`, read now. The chain ignores
+/// weights whose version is below that key, so an explicit version lower
+/// than a non-zero key fails with [`WeightVersionMismatch`] instead.
+///
+/// [`WeightVersionMismatch`]: crate::errors::WeightVersionMismatch
+pub async fn resolve_version_key(
+    client: &impl ChainBackend,
+    netuid: u16,
+    version_key: Option<u64>,
+) -> BittensorResult<u64> {
+    let required = get_weights_version_key(client, netuid).await?;
+    Ok(check_version_key(netuid, required, version_key)?)
+}
+
+/// Pick the `version_key` to submit given the subnet's `required` key
+///
+/// See [`resolve_version_key`].
+pub fn check_version_key(
+    netuid: u16,
+    required: u64,
+    version_key: Option<u64>,
+) -> std::result::Result<u64, WeightVersionMismatch> {
+    match version_key {
+        None => Ok(required),
+        Some(provided) if provided < required => Err(WeightVersionMismatch::with_versions(
+            format!(
+                "version_key {} is below the minimum {} of netuid {}",
+                provided, required, netuid
+            ),
+            required,
+            provided,
+        )),
+        Some(provided) => Ok(provided),
+    }
+}
+
+/// Submit `set_weights` without checking the inputs against subnet bounds
+pub(crate) async fn submit_set_weights(
+    client: &impl ChainBackend,
+    signer: &BittensorSigner,
+    netuid: u16,
+    uids: &[u16],
+    weights: &[u16],
+    version_key: u64,
+    wait_for: ExtrinsicWait,
+) -> BittensorResult<String> {
     if uids.len() != weights.len() {
-        return Err(anyhow::anyhow!(
-            "UIDS and weights must have the same length"
-        ));
+        return Err(InvalidWeights::new("UIDS and weights must have the same length").into());
     }
 
     if uids.is_empty() {
-        return Err(anyhow::anyhow!("No valid weights to set"));
+        return Err(InvalidWeights::new("No valid weights to set").into());
     }
 
     let uid_values: Vec<Value> = uids.iter().map(|uid| Value::from(*uid)).collect();
@@ -48,28 +139,32 @@ pub async fn set_weights(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to set weights: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to set weights", e))?;
 
     Ok(tx_hash)
 }
 
 /// Commit weights hash for reveal pattern
+///
+/// The hash hides the UIDs, so check them with [`check_weight_inputs`] before
+/// hashing; reveal must use the checked UIDs and weights.
 pub async fn commit_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     commit_hash: &str,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     // Decode hex commit hash
     let hash_bytes = hex::decode(commit_hash)
-        .map_err(|e| anyhow::anyhow!("Invalid commit hash format: {}", e))?;
+        .map_err(|e| WeightsError::new(format!("Invalid commit hash format: {}", e)))?;
 
     if hash_bytes.len() != 32 {
-        return Err(anyhow::anyhow!(
+        return Err(WeightsError::new(format!(
             "Commit hash must be 32 bytes, got {}",
             hash_bytes.len()
-        ));
+        ))
+        .into());
     }
 
     let args = vec![Value::from(netuid), Value::from_bytes(&hash_bytes)];
@@ -83,7 +178,7 @@ pub async fn commit_weights(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to commit weights: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to commit weights", e))?;
 
     Ok(tx_hash)
 }
@@ -92,7 +187,7 @@ pub async fn commit_weights(
 /// Subtensor expects: uids: Vec<u16>, values: Vec<u16>, salt: Vec<u16>
 #[allow(clippy::too_many_arguments)]
 pub async fn reveal_weights(
-    client: &BittensorClient,
+    client: &impl ChainBackend,
     signer: &BittensorSigner,
     netuid: u16,
     uids: &[u16],
@@ -100,15 +195,13 @@ pub async fn reveal_weights(
     salt: &[u16],
     version_key: u64,
     wait_for: ExtrinsicWait,
-) -> Result<String> {
+) -> BittensorResult<String> {
     if uids.len() != weights.len() {
-        return Err(anyhow::anyhow!(
-            "UIDS and weights must have the same length"
-        ));
+        return Err(InvalidWeights::new("UIDS and weights must have the same length").into());
     }
 
     if uids.is_empty() {
-        return Err(anyhow::anyhow!("No valid weights to reveal"));
+        return Err(InvalidWeights::new("No valid weights to reveal").into());
     }
 
     let uid_values: Vec<Value> = uids.iter().map(|uid| Value::from(*uid)).collect();
@@ -131,7 +224,7 @@ pub async fn reveal_weights(
             wait_for,
         )
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to reveal weights: {}", e))?;
+        .map_err(|e| BittensorError::from_chain("Failed to reveal weights", e))?;
 
     Ok(tx_hash)
 }
@@ -140,7 +233,7 @@ pub async fn reveal_weights(
 ///
 /// This function uses a legacy hash format. For proper subtensor compatibility,
 /// use `generate_commit_hash_v2` which matches subtensor's exact hash format.
-pub fn generate_commit_hash(uids: &[u64], weights: &[u16], salt: &[u8]) -> Result<String> {
+pub fn generate_commit_hash(uids: &[u64], weights: &[u16], salt: &[u8]) -> BittensorResult<String> {
     // Convert to u16 format
     let uid_u16: Vec<u16> = uids.iter().map(|u| *u as u16).collect();
     let salt_u16 = salt_u8_to_u16(salt);
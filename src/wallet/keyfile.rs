@@ -28,10 +28,23 @@
 //!     "privateKey": "0x2ec306fc1c5bc2f0e3a2c7a6ec6014ca4a0823a7d7d42ad5e9d7f376a1c36c0d...",
 //!     "secretSeed": "0x4ed8d4b17698ddeaa1f1559f152f87b5d472f725ca86d341bd0276f1b61197e2",
 //!     "secretPhrase": "abandon abandon abandon ...",
-//!     "accountId": "0x66933bd1f37070ef87bd1198af3dacceb095237f803f3d32b173e6b425ed7972"
+//!     "accountId": "0x66933bd1f37070ef87bd1198af3dacceb095237f803f3d32b173e6b425ed7972",
+//!     "keyType": "sr25519"
 //! }
 //! ```
 //!
+//! `keyType` is one of `sr25519`, `ed25519` or `ecdsa`. Keyfiles without it
+//! are SR25519. Keys derived with a path (e.g. `//default//0`) also record it
+//! as `derivationPath`. Encrypted SR25519 keyfiles hold the raw private key;
+//! other schemes and derived keys encrypt the JSON above so this metadata is
+//! preserved.
+//!
+//! ### Legacy Formats
+//! Keyfiles encrypted by older Python SDKs (ansible vault, Fernet, and the
+//! PyNaCl `$NACL` layout without a salt) are decrypted transparently by
+//! [`Keyfile::get_keypair`]; see [`crate::wallet::legacy`].
+//! [`migrate_legacy_keyfile`] rewrites them in the format above.
+//!
 //! ### Argon2id Parameters (PyNaCl Compatible)
 //! - Memory: 64 MiB (67108864 bytes = 65536 KiB blocks)
 //! - Iterations: 2 (OPSLIMIT_INTERACTIVE)
@@ -40,7 +53,10 @@
 //! - Key length: 32 bytes
 //! - Algorithm: Argon2id v1.3
 
-use crate::wallet::keypair::{Keypair, KeypairError};
+use crate::errors::KeyExists;
+use crate::wallet::keypair::{KeyScheme, Keypair, KeypairError};
+use crate::wallet::legacy;
+use crate::wallet::secret::REDACTED;
 use argon2::{Argon2, Params, Version};
 use crypto_secretbox::{
     aead::{Aead, KeyInit},
@@ -51,7 +67,7 @@ use std::fs;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 /// NaCl header for encrypted keyfiles (matches Python bittensor-wallet)
 pub const NACL_HEADER: &[u8] = b"$NACL";
@@ -73,8 +89,9 @@ pub enum KeyfileError {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// Only the position is kept for data errors, which can quote key material
     #[error("JSON parsing error: {0}")]
-    Json(#[from] serde_json::Error),
+    Json(String),
 
     #[error("Invalid keyfile format: {0}")]
     InvalidFormat(String),
@@ -88,8 +105,8 @@ pub enum KeyfileError {
     #[error("Key derivation failed: {0}")]
     KeyDerivationFailed(String),
 
-    #[error("Keyfile already exists and overwrite is not enabled")]
-    AlreadyExists,
+    #[error(transparent)]
+    KeyExists(#[from] KeyExists),
 
     #[error("Keypair error: {0}")]
     Keypair(#[from] KeypairError),
@@ -113,8 +130,23 @@ pub enum KeyfileError {
     InvalidNaclHeader,
 }
 
+impl From<serde_json::Error> for KeyfileError {
+    fn from(e: serde_json::Error) -> Self {
+        match e.classify() {
+            serde_json::error::Category::Data => KeyfileError::Json(format!(
+                "invalid keyfile data at line {} column {}",
+                e.line(),
+                e.column()
+            )),
+            _ => KeyfileError::Json(e.to_string()),
+        }
+    }
+}
+
 /// Data structure for unencrypted keyfile (JSON format) matching Python SDK.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Debug` output redacts the private key, seed and phrase.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct KeyfileJsonData {
     #[serde(rename = "ss58Address")]
     pub ss58_address: String,
@@ -128,6 +160,56 @@ pub struct KeyfileJsonData {
     pub secret_phrase: Option<String>,
     #[serde(rename = "accountId", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+    #[serde(rename = "keyType", default)]
+    pub key_type: KeyScheme,
+    #[serde(
+        rename = "derivationPath",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub derivation_path: Option<String>,
+}
+
+impl std::fmt::Debug for KeyfileJsonData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redacted = |secret: &Option<String>| secret.as_ref().map(|_| REDACTED);
+        f.debug_struct("KeyfileJsonData")
+            .field("ss58_address", &self.ss58_address)
+            .field("public_key", &self.public_key)
+            .field("private_key", &REDACTED)
+            .field("secret_seed", &redacted(&self.secret_seed))
+            .field("secret_phrase", &redacted(&self.secret_phrase))
+            .field("account_id", &self.account_id)
+            .field("key_type", &self.key_type)
+            .field("derivation_path", &self.derivation_path)
+            .finish()
+    }
+}
+
+impl KeyfileJsonData {
+    /// Build the keyfile JSON for a keypair.
+    pub fn from_keypair(keypair: &Keypair) -> Self {
+        let secret_seed = keypair.secret_seed().ok();
+        Self {
+            ss58_address: keypair.ss58_address().to_string(),
+            public_key: format!("0x{}", hex::encode(keypair.public_bytes())),
+            private_key: format!("0x{}", hex::encode(keypair.to_full_bytes())),
+            secret_seed: secret_seed.map(|seed| format!("0x{}", hex::encode(seed.expose_secret()))),
+            secret_phrase: None,
+            account_id: Some(format!("0x{}", hex::encode(keypair.public_key()))),
+            key_type: keypair.scheme(),
+            derivation_path: keypair.derivation_path().map(str::to_string),
+        }
+    }
+
+    /// Restore the keypair, using the scheme from `keyType`.
+    pub fn to_keypair(&self) -> Result<Keypair, KeyfileError> {
+        let private_key_hex = self.private_key.trim_start_matches("0x");
+        let key_bytes = hex::decode(private_key_hex)
+            .map_err(|e| KeyfileError::InvalidFormat(format!("Invalid privateKey: {}", e)))?;
+        let keypair = Keypair::from_bytes_with_scheme(&key_bytes, self.key_type)?;
+        Ok(keypair.with_derivation_path(self.derivation_path.clone()))
+    }
 }
 
 /// Data structure for encrypted key material.
@@ -191,13 +273,16 @@ impl Keyfile {
         }
 
         match self.read_raw() {
-            Ok(data) => data.starts_with(NACL_HEADER),
+            Ok(data) => data.starts_with(NACL_HEADER) || legacy::is_legacy_encrypted(&data),
             Err(_) => false,
         }
     }
 
     /// Get the keypair, decrypting if necessary.
     ///
+    /// Legacy encrypted keyfiles from the Python SDK are decrypted with the
+    /// same password.
+    ///
     /// # Arguments
     /// * `password` - Password for decryption (required if encrypted)
     ///
@@ -224,15 +309,48 @@ impl Keyfile {
     /// * `overwrite` - Whether to overwrite an existing keyfile
     ///
     /// # Returns
-    /// Ok(()) on success, or an error.
+    /// Ok(()) on success, or `KeyExists` if the keyfile exists and `overwrite`
+    /// is false.
     pub fn set_keypair(
         &mut self,
         keypair: Keypair,
         password: Option<&str>,
         overwrite: bool,
     ) -> Result<(), KeyfileError> {
+        self.set_keypair_with_backup(keypair, password, overwrite, false)
+            .map(|_| ())
+    }
+
+    /// Store a keypair, optionally keeping the keyfile it replaces.
+    ///
+    /// The new keyfile is written to `<path>.tmp`, fsynced and renamed over
+    /// the old one, so a failure at any point leaves the previous keyfile
+    /// intact. With `backup_existing`, the old keyfile is moved to
+    /// `<name>.bak.<timestamp>` just before the new one takes its place; a
+    /// crash between the two renames leaves it at the backup path.
+    ///
+    /// # Arguments
+    /// * `keypair` - The keypair to store
+    /// * `password` - Optional password for encryption (if None, stores unencrypted)
+    /// * `overwrite` - Whether to overwrite an existing keyfile
+    /// * `backup_existing` - Whether to back up an existing keyfile first
+    ///
+    /// # Returns
+    /// The backup path if one was written, or `KeyExists` if the keyfile
+    /// exists and `overwrite` is false.
+    pub fn set_keypair_with_backup(
+        &mut self,
+        keypair: Keypair,
+        password: Option<&str>,
+        overwrite: bool,
+        backup_existing: bool,
+    ) -> Result<Option<PathBuf>, KeyfileError> {
         if self.exists() && !overwrite {
-            return Err(KeyfileError::AlreadyExists);
+            return Err(KeyExists::with_path(
+                "Keyfile already exists and overwrite is not enabled",
+                self.path.display().to_string(),
+            )
+            .into());
         }
 
         // Ensure parent directory exists
@@ -240,12 +358,18 @@ impl Keyfile {
             fs::create_dir_all(parent)?;
         }
 
-        let raw_key = keypair.to_full_bytes();
-
-        let content = match password {
+        let content = Zeroizing::new(match password {
             Some(pass) => {
+                // Plain SR25519 keys are encrypted as raw bytes for compatibility;
+                // other schemes and derived keys encrypt the JSON so the scheme
+                // and derivation path are preserved
+                let plaintext =
+                    Zeroizing::new(match (keypair.scheme(), keypair.derivation_path()) {
+                        (KeyScheme::Sr25519, None) => keypair.to_full_bytes(),
+                        _ => serde_json::to_vec(&KeyfileJsonData::from_keypair(&keypair))?,
+                    });
                 // Encrypt and create binary format with $NACL header
-                let encrypted_data = self.encrypt(&raw_key, pass)?;
+                let encrypted_data = self.encrypt(&plaintext, pass)?;
                 self.to_binary_format(&encrypted_data)?
             }
             None => {
@@ -256,42 +380,19 @@ impl Keyfile {
                     self.path
                 );
                 // Store as JSON format matching Python SDK
-                let secret_seed = keypair.secret_seed().ok();
-                let json_data = KeyfileJsonData {
-                    ss58_address: keypair.ss58_address().to_string(),
-                    public_key: format!("0x{}", hex::encode(keypair.public_key())),
-                    private_key: format!("0x{}", hex::encode(&raw_key)),
-                    secret_seed: secret_seed.map(|seed| format!("0x{}", hex::encode(seed))),
-                    secret_phrase: None,
-                    account_id: Some(format!("0x{}", hex::encode(keypair.public_key()))),
-                };
-                serde_json::to_vec_pretty(&json_data)?
+                serde_json::to_vec_pretty(&KeyfileJsonData::from_keypair(&keypair))?
             }
-        };
+        });
 
-        // Write atomically by writing to temp file first
-        let temp_path = self.path.with_extension("tmp");
-        {
-            #[cfg(unix)]
-            let mut file = {
-                use std::os::unix::fs::OpenOptionsExt;
-                fs::OpenOptions::new()
-                    .write(true)
-                    .create(true)
-                    .truncate(true)
-                    .mode(0o600)
-                    .open(&temp_path)?
-            };
-            #[cfg(not(unix))]
-            let mut file = fs::File::create(&temp_path)?;
-
-            file.write_all(&content)?;
-            file.sync_all()?;
-        }
-        fs::rename(&temp_path, &self.path)?;
+        let backup = if backup_existing && self.exists() {
+            Some(backup_path(&self.path))
+        } else {
+            None
+        };
+        write_atomic(&self.path, &content, backup.as_deref())?;
 
         self.keypair = Some(keypair);
-        Ok(())
+        Ok(backup)
     }
 
     /// Encrypt data using Argon2id + XSalsa20Poly1305.
@@ -412,8 +513,33 @@ impl Keyfile {
         old_password: Option<&str>,
         new_password: &str,
     ) -> Result<(), KeyfileError> {
-        let keypair = self.get_keypair(old_password)?;
-        self.set_keypair(keypair, Some(new_password), true)
+        self.change_password(old_password, Some(new_password))
+    }
+
+    /// Change the password of the keyfile.
+    ///
+    /// The keyfile is always decrypted from disk with `old`, so a wrong password
+    /// fails even if the keypair is already loaded. It is then rewritten
+    /// atomically (temp file, fsync, rename) with 0600 permissions.
+    ///
+    /// # Arguments
+    /// * `old` - Current password, or None if the keyfile is unencrypted
+    /// * `new` - New password, or None to store the keyfile unencrypted
+    ///
+    /// # Returns
+    /// Ok(()) on success, or an error if decryption with `old` fails.
+    pub fn change_password(
+        &mut self,
+        old: Option<&str>,
+        new: Option<&str>,
+    ) -> Result<(), KeyfileError> {
+        if !self.exists() {
+            return Err(KeyfileError::NotFound(self.path.clone()));
+        }
+
+        let data = self.read_raw()?;
+        let keypair = self.decrypt_keypair(&data, old)?;
+        self.set_keypair(keypair, new, true)
     }
 
     /// Read raw bytes from the keyfile.
@@ -435,13 +561,17 @@ impl Keyfile {
             return self.decrypt_nacl(data, password);
         }
 
+        // Ansible vault and Fernet keyfiles from older Python SDKs
+        if legacy::is_legacy_encrypted(data) {
+            let password = password.ok_or(KeyfileError::PasswordRequired)?;
+            let plaintext = legacy::decrypt_legacy(data, password)?;
+            return legacy::keypair_from_python_json(&plaintext);
+        }
+
         // Try as unencrypted JSON format
         if let Ok(json) = serde_json::from_slice::<KeyfileJsonData>(data) {
             // Extract private key from JSON and create keypair
-            let private_key_hex = json.private_key.trim_start_matches("0x");
-            if let Ok(key_bytes) = hex::decode(private_key_hex) {
-                return Keypair::from_bytes(&key_bytes).map_err(KeyfileError::Keypair);
-            }
+            return json.to_keypair();
         }
 
         // Try legacy hex format
@@ -474,16 +604,119 @@ impl Keyfile {
     }
 
     /// Decrypt keypair from NACL binary format.
+    ///
+    /// Falls back to the PyNaCl layout when the salted layout doesn't
+    /// authenticate.
     fn decrypt_nacl(&self, data: &[u8], password: Option<&str>) -> Result<Keypair, KeyfileError> {
         let password = password.ok_or(KeyfileError::PasswordRequired)?;
 
         let keyfile_data = Self::parse_nacl_format(data).ok_or(KeyfileError::InvalidNaclHeader)?;
 
-        let key_bytes = self.decrypt(&keyfile_data, password)?;
+        let key_bytes = Zeroizing::new(match self.decrypt(&keyfile_data, password) {
+            Err(KeyfileError::DecryptionFailed) => legacy::decrypt_pynacl(data, password)?,
+            result => result?,
+        });
+        if serde_json::from_slice::<serde_json::Value>(&key_bytes).is_ok() {
+            return legacy::keypair_from_python_json(&key_bytes);
+        }
         Keypair::from_bytes(&key_bytes).map_err(KeyfileError::Keypair)
     }
 }
 
+/// Write a file atomically with 0600 permissions.
+///
+/// The content goes to `<path>.tmp` and is fsynced before being renamed over
+/// `path`. If `backup` is given, the current file is renamed there once the
+/// temp file is durable, so the backup is the original file itself rather
+/// than an unsynced copy. On error the temp file is removed and `path` is
+/// left as it was.
+pub(crate) fn write_atomic(
+    path: &Path,
+    content: &[u8],
+    backup: Option<&Path>,
+) -> Result<(), KeyfileError> {
+    let mut temp_name = path.as_os_str().to_owned();
+    temp_name.push(".tmp");
+    let temp_path = PathBuf::from(temp_name);
+
+    let result = write_temp(&temp_path, content).and_then(|_| {
+        #[cfg(test)]
+        tests::fail_point(tests::FailPoint::BeforeRename)?;
+        let Some(backup) = backup else {
+            fs::rename(&temp_path, path)?;
+            // Persist the rename itself
+            return sync_parent(path);
+        };
+        fs::rename(path, backup)?;
+        let replaced = (|| -> std::io::Result<()> {
+            sync_parent(path)?;
+            #[cfg(test)]
+            tests::fail_point(tests::FailPoint::BetweenRenames)?;
+            fs::rename(&temp_path, path)?;
+            sync_parent(path)
+        })();
+        if replaced.is_err() {
+            // Put the original back, whichever step failed
+            let _ = fs::rename(backup, path);
+        }
+        replaced
+    });
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// Persist renames in the directory holding `path`
+fn sync_parent(path: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::File::open(parent)?.sync_all()?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+fn write_temp(temp_path: &Path, content: &[u8]) -> std::io::Result<()> {
+    #[cfg(unix)]
+    let mut file = {
+        use std::os::unix::fs::OpenOptionsExt;
+        fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(temp_path)?
+    };
+    #[cfg(not(unix))]
+    let mut file = fs::File::create(temp_path)?;
+
+    file.write_all(content)?;
+    file.sync_all()
+}
+
+/// Unused `<name>.bak.<timestamp>` path next to `path`.
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(
+        ".bak.{}",
+        chrono::Utc::now().format("%Y%m%d%H%M%S")
+    ));
+    let base = PathBuf::from(name);
+
+    let mut candidate = base.clone();
+    let mut index = 1;
+    while candidate.exists() {
+        let mut name = base.as_os_str().to_owned();
+        name.push(format!(".{}", index));
+        candidate = PathBuf::from(name);
+        index += 1;
+    }
+    candidate
+}
+
 /// Derive an encryption key using Argon2id with PyNaCl-compatible parameters.
 fn derive_key(password: &str, salt: &[u8; 16]) -> Result<[u8; 32], KeyfileError> {
     let params = Params::new(
@@ -506,12 +739,19 @@ fn derive_key(password: &str, salt: &[u8; 16]) -> Result<[u8; 32], KeyfileError>
 
 /// Check if data is in a legacy (pre-v4) format.
 ///
+/// Ansible vault and Fernet encrypted keyfiles are legacy. PyNaCl `$NACL`
+/// keyfiles share the current header and are only told apart on decryption.
+///
 /// # Arguments
 /// * `data` - The raw keyfile data
 ///
 /// # Returns
 /// `true` if the data appears to be in a legacy format.
 pub fn is_legacy_format(data: &[u8]) -> bool {
+    if legacy::is_legacy_encrypted(data) {
+        return true;
+    }
+
     // Check for old JSON formats with different structure
     if let Ok(value) = serde_json::from_slice::<serde_json::Value>(data) {
         if let Some(obj) = value.as_object() {
@@ -529,44 +769,52 @@ pub fn is_legacy_format(data: &[u8]) -> bool {
 
 /// Migrate a legacy keyfile to the current format.
 ///
+/// The keyfile is decrypted with `password` and, only once that succeeds,
+/// rewritten atomically as a `$NACL` keyfile encrypted with the same password.
+///
 /// # Arguments
 /// * `path` - Path to the legacy keyfile
-/// * `password` - Password for encryption (may be needed for old encrypted formats)
-/// * `new_password` - Password for the new format
+/// * `password` - Password of the legacy keyfile, reused for the new one
 ///
 /// # Returns
-/// Ok(()) on success.
-pub fn migrate_legacy_keyfile(
-    path: &Path,
-    password: Option<&str>,
-    new_password: &str,
-) -> Result<(), KeyfileError> {
+/// Ok(()) on success, or `InvalidFormat` if the keyfile is already current.
+pub fn migrate_legacy_keyfile(path: &Path, password: &str) -> Result<(), KeyfileError> {
     let mut data = Vec::new();
     fs::File::open(path)?.read_to_end(&mut data)?;
 
-    if !is_legacy_format(&data) {
+    let mut keyfile = Keyfile::new(path);
+    let keypair = if is_legacy_format(&data) {
+        parse_legacy_keyfile(&data, password)?
+    } else if is_pynacl_keyfile(&keyfile, &data, password) {
+        legacy::keypair_from_python_json(&legacy::decrypt_pynacl(&data, password)?)?
+    } else {
         return Err(KeyfileError::InvalidFormat(
             "Not a legacy format keyfile".to_string(),
         ));
-    }
-
-    // Try to extract keypair from legacy format
-    let keypair = parse_legacy_keyfile(&data, password)?;
+    };
 
-    // Create new keyfile with current format
-    let mut keyfile = Keyfile::new(path);
-    keyfile.set_keypair(keypair, Some(new_password), true)?;
+    keyfile.set_keypair(keypair, Some(password), true)
+}
 
-    Ok(())
+/// Check if a `$NACL` keyfile is in the PyNaCl layout rather than ours.
+fn is_pynacl_keyfile(keyfile: &Keyfile, data: &[u8], password: &str) -> bool {
+    data.starts_with(NACL_HEADER)
+        && Keyfile::parse_nacl_format(data)
+            .is_some_and(|parsed| keyfile.decrypt(&parsed, password).is_err())
 }
 
 /// Parse a legacy keyfile to extract the keypair.
-fn parse_legacy_keyfile(data: &[u8], password: Option<&str>) -> Result<Keypair, KeyfileError> {
+fn parse_legacy_keyfile(data: &[u8], password: &str) -> Result<Keypair, KeyfileError> {
+    if legacy::is_legacy_encrypted(data) {
+        let plaintext = legacy::decrypt_legacy(data, password)?;
+        return legacy::keypair_from_python_json(&plaintext);
+    }
+
     if let Ok(value) = serde_json::from_slice::<serde_json::Value>(data) {
         if let Some(obj) = value.as_object() {
             // Handle secretPhrase format
             if let Some(phrase) = obj.get("secretPhrase").and_then(|v| v.as_str()) {
-                return Keypair::from_mnemonic(phrase, password).map_err(KeyfileError::Keypair);
+                return Keypair::from_mnemonic(phrase, None).map_err(KeyfileError::Keypair);
             }
 
             // Handle old encrypted format with "data" field
@@ -586,8 +834,91 @@ fn parse_legacy_keyfile(data: &[u8], password: Option<&str>) -> Result<Keypair,
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
     use tempfile::tempdir;
 
+    /// Steps of an atomic write where a failure can be injected
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub(super) enum FailPoint {
+        /// After the temp file is written
+        BeforeRename,
+        /// After the original is moved to its backup
+        BetweenRenames,
+    }
+
+    thread_local! {
+        static FAIL_AT: Cell<Option<FailPoint>> = const { Cell::new(None) };
+    }
+
+    /// Fail the next atomic write that reaches `point`
+    pub(super) fn fail_point(point: FailPoint) -> std::io::Result<()> {
+        if FAIL_AT.with(|fail| fail.get()) == Some(point) {
+            FAIL_AT.with(|fail| fail.set(None));
+            return Err(std::io::Error::other("injected write failure"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_write_keeps_original() {
+        for point in [FailPoint::BeforeRename, FailPoint::BetweenRenames] {
+            let dir = tempdir().unwrap();
+            let path = dir.path().join("coldkey");
+
+            let original = Keypair::generate();
+            let mut keyfile = Keyfile::new(&path);
+            keyfile
+                .set_keypair(original.clone(), Some("password"), false)
+                .unwrap();
+            let before = fs::read(&path).unwrap();
+
+            FAIL_AT.with(|fail| fail.set(Some(point)));
+            let result =
+                keyfile.set_keypair_with_backup(Keypair::generate(), Some("password"), true, true);
+            assert!(matches!(result, Err(KeyfileError::Io(_))), "{:?}", point);
+
+            // Original in place, no temp or backup left behind
+            assert_eq!(fs::read(&path).unwrap(), before, "{:?}", point);
+            assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1, "{:?}", point);
+            let loaded = Keyfile::new(&path).get_keypair(Some("password")).unwrap();
+            assert_eq!(loaded.public_key(), original.public_key());
+        }
+    }
+
+    #[test]
+    fn test_overwrite_with_backup() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("coldkey");
+
+        let original = Keypair::generate();
+        let mut keyfile = Keyfile::new(&path);
+        keyfile.set_keypair(original.clone(), None, false).unwrap();
+        let before = fs::read(&path).unwrap();
+
+        let replacement = Keypair::generate();
+        let backup = keyfile
+            .set_keypair_with_backup(replacement.clone(), None, true, true)
+            .unwrap()
+            .unwrap();
+        assert!(backup
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("coldkey.bak."));
+        assert_eq!(fs::read(&backup).unwrap(), before);
+
+        let loaded = Keyfile::new(&path).get_keypair(None).unwrap();
+        assert_eq!(loaded.public_key(), replacement.public_key());
+
+        // A second backup in the same second gets its own name
+        let second = keyfile
+            .set_keypair_with_backup(original, None, true, true)
+            .unwrap()
+            .unwrap();
+        assert_ne!(second, backup);
+        assert!(backup.exists());
+    }
+
     #[test]
     fn test_encrypt_decrypt() {
         let keyfile = Keyfile::new("/tmp/test");
@@ -670,7 +1001,7 @@ mod tests {
 
         // Should fail without overwrite
         let result = keyfile.set_keypair(keypair.clone(), None, false);
-        assert!(matches!(result, Err(KeyfileError::AlreadyExists)));
+        assert!(matches!(result, Err(KeyfileError::KeyExists(_))));
 
         // Should succeed with overwrite
         keyfile.set_keypair(keypair, None, true).unwrap();
@@ -770,4 +1101,128 @@ mod tests {
         let loaded = keyfile.get_keypair(None).unwrap();
         assert_eq!(original.public_key(), loaded.public_key());
     }
+
+    #[test]
+    fn test_keyfile_change_password() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test_key");
+        let original = Keypair::generate();
+
+        let mut keyfile = Keyfile::new(&path);
+        keyfile.set_keypair(original.clone(), None, false).unwrap();
+
+        // Add a password to an unencrypted keyfile
+        keyfile.change_password(None, Some("first")).unwrap();
+        assert!(keyfile.is_encrypted());
+
+        // The old password is checked against the file on disk
+        assert!(matches!(
+            keyfile.change_password(Some("wrong"), Some("second")),
+            Err(KeyfileError::DecryptionFailed)
+        ));
+        assert!(matches!(
+            keyfile.change_password(None, Some("second")),
+            Err(KeyfileError::PasswordRequired)
+        ));
+
+        keyfile
+            .change_password(Some("first"), Some("second"))
+            .unwrap();
+        let reloaded = Keyfile::new(&path);
+        assert!(reloaded.get_keypair(Some("first")).is_err());
+        assert_eq!(
+            reloaded.get_keypair(Some("second")).unwrap().public_key(),
+            original.public_key()
+        );
+
+        // Remove the password entirely
+        keyfile.change_password(Some("second"), None).unwrap();
+        assert!(!keyfile.is_encrypted());
+        let loaded = Keyfile::new(&path).get_keypair(None).unwrap();
+        assert_eq!(loaded.public_key(), original.public_key());
+        assert!(!path.with_extension("tmp").exists());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn test_keyfile_scheme_roundtrip() {
+        let dir = tempdir().unwrap();
+
+        for scheme in [KeyScheme::Sr25519, KeyScheme::Ed25519, KeyScheme::Ecdsa] {
+            let original = Keypair::from_uri_with_scheme("//Alice", scheme).unwrap();
+
+            for password in [None, Some("password")] {
+                let path = dir
+                    .path()
+                    .join(format!("{}_{}", scheme, password.is_some()));
+                let mut keyfile = Keyfile::new(&path);
+                keyfile
+                    .set_keypair(original.clone(), password, false)
+                    .unwrap();
+
+                let loaded = Keyfile::new(&path).get_keypair(password).unwrap();
+                assert_eq!(loaded.scheme(), scheme);
+                assert_eq!(loaded.public_bytes(), original.public_bytes());
+                assert_eq!(loaded.ss58_address(), original.ss58_address());
+            }
+
+            let content = fs::read_to_string(dir.path().join(format!("{}_false", scheme))).unwrap();
+            let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+            assert_eq!(json["keyType"], scheme.as_str());
+        }
+    }
+
+    #[test]
+    fn test_keyfile_without_key_type_is_sr25519() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("python_key");
+
+        let original = Keypair::from_uri("//Alice").unwrap();
+        let mut json = serde_json::to_value(KeyfileJsonData::from_keypair(&original)).unwrap();
+        json.as_object_mut().unwrap().remove("keyType");
+        fs::write(&path, serde_json::to_vec(&json).unwrap()).unwrap();
+
+        let loaded = Keyfile::new(&path).get_keypair(None).unwrap();
+        assert_eq!(loaded.scheme(), KeyScheme::Sr25519);
+        assert_eq!(loaded.public_key(), original.public_key());
+    }
+
+    #[test]
+    fn test_errors_and_debug_do_not_leak_seed() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("coldkey");
+        let keypair = Keypair::from_uri("//Alice").unwrap();
+        let seed_hex = hex::encode(keypair.secret_seed().unwrap().expose_secret());
+        let mut keyfile = Keyfile::new(&path);
+        keyfile
+            .set_keypair(keypair.clone(), Some("password"), false)
+            .unwrap();
+
+        let failed = Keyfile::new(&path).get_keypair(Some("wrong")).unwrap_err();
+        assert!(matches!(failed, KeyfileError::DecryptionFailed));
+
+        // A data error quoting the seed keeps only its position
+        let json = KeyfileJsonData::from_keypair(&keypair);
+        let mut value = serde_json::to_value(&json).unwrap();
+        value["keyType"] = serde_json::json!(json.secret_seed.clone().unwrap());
+        let bad_type =
+            KeyfileError::from(serde_json::from_value::<KeyfileJsonData>(value).unwrap_err());
+
+        for formatted in [
+            failed.to_string(),
+            format!("{:?}", failed),
+            bad_type.to_string(),
+            format!("{:?}", bad_type),
+            format!("{:?}", json),
+        ] {
+            assert!(!formatted.contains(&seed_hex), "{}", formatted);
+        }
+        assert!(format!("{:?}", json).contains(REDACTED));
+    }
 }
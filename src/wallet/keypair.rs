@@ -1,17 +1,38 @@
 //! Keypair management for Bittensor wallets.
 //!
-//! This module provides SR25519 keypair functionality for signing and verification,
-//! compatible with the Substrate ecosystem and the Python Bittensor SDK.
+//! This module provides SR25519, ED25519 and ECDSA keypair functionality for signing
+//! and verification, compatible with the Substrate ecosystem and the Python Bittensor SDK.
+//! SR25519 is the default scheme used by Bittensor wallets.
+//!
+//! # Breaking changes
+//!
+//! Supporting several schemes changed two signatures of the SR25519-only API:
+//!
+//! - [`Keypair::sign`] returns a `Vec<u8>` instead of `[u8; 64]`, as ECDSA
+//!   signatures are 65 bytes. For SR25519 and ED25519 keys the vector is
+//!   always 64 bytes, so `<[u8; 64]>::try_from(signature)` recovers the array.
+//! - [`Keypair::pair`] returns a [`SchemePair`] instead of an
+//!   `sr25519::Pair`. Use [`Keypair::sr25519_pair`] where an `sr25519::Pair`
+//!   is needed; it is `None` only for ED25519 and ECDSA keys.
+//!
+//! `PairSigner::signer` likewise returns a [`SchemePair`]. Functions that took
+//! an `sr25519::Pair` now take `impl Into<SchemePair>` and accept it unchanged.
 
 // Allow unused_assignments - the ZeroizeOnDrop derive macro generates code that clippy
 // incorrectly flags as unused assignments when it reads/writes struct fields for zeroization
 #![allow(unused_assignments)]
 
 use crate::wallet::mnemonic::{Mnemonic, MnemonicError};
+use crate::wallet::secret::Secret;
+use serde::{Deserialize, Serialize};
 use sp_core::{
-    crypto::{Ss58AddressFormat, Ss58Codec},
+    crypto::{AccountId32, SecretUri, Ss58AddressFormat, Ss58Codec},
+    ecdsa, ed25519,
+    hashing::blake2_256,
     sr25519, Pair,
 };
+use std::fmt;
+use std::str::FromStr;
 use thiserror::Error;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -38,15 +59,190 @@ pub enum KeypairError {
 
     #[error("Key derivation error: {0}")]
     DerivationError(String),
+
+    #[error("Unknown key scheme: {0}")]
+    UnknownScheme(String),
+}
+
+/// Signature scheme of a keypair.
+///
+/// The string form (`"sr25519"`, `"ed25519"`, `"ecdsa"`) is what gets stored in
+/// the `keyType` field of keyfiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyScheme {
+    /// Schnorrkel/Ristretto signatures, the Substrate and Bittensor default
+    #[default]
+    Sr25519,
+    /// Edwards curve signatures, as generated by `subkey --scheme ed25519`
+    Ed25519,
+    /// secp256k1 signatures, used for EVM-linked keys
+    Ecdsa,
+}
+
+impl KeyScheme {
+    /// Get the scheme name as stored in keyfiles.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyScheme::Sr25519 => "sr25519",
+            KeyScheme::Ed25519 => "ed25519",
+            KeyScheme::Ecdsa => "ecdsa",
+        }
+    }
+
+    /// Get the length in bytes of a signature made with this scheme.
+    pub fn signature_len(&self) -> usize {
+        match self {
+            KeyScheme::Sr25519 | KeyScheme::Ed25519 => 64,
+            KeyScheme::Ecdsa => 65,
+        }
+    }
+}
+
+impl fmt::Display for KeyScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for KeyScheme {
+    type Err = KeypairError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "sr25519" => Ok(KeyScheme::Sr25519),
+            "ed25519" => Ok(KeyScheme::Ed25519),
+            "ecdsa" => Ok(KeyScheme::Ecdsa),
+            _ => Err(KeypairError::UnknownScheme(s.to_string())),
+        }
+    }
+}
+
+/// The underlying sp_core pair of a [`Keypair`], tagged with its scheme.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+pub enum SchemePair {
+    Sr25519(sr25519::Pair),
+    Ed25519(ed25519::Pair),
+    Ecdsa(ecdsa::Pair),
+}
+
+impl SchemePair {
+    /// Get the signature scheme of this pair.
+    pub fn scheme(&self) -> KeyScheme {
+        match self {
+            SchemePair::Sr25519(_) => KeyScheme::Sr25519,
+            SchemePair::Ed25519(_) => KeyScheme::Ed25519,
+            SchemePair::Ecdsa(_) => KeyScheme::Ecdsa,
+        }
+    }
+
+    /// Get the raw public key (33 bytes compressed for ECDSA, 32 bytes otherwise).
+    pub fn public_bytes(&self) -> Vec<u8> {
+        match self {
+            SchemePair::Sr25519(pair) => pair.public().0.to_vec(),
+            SchemePair::Ed25519(pair) => pair.public().0.to_vec(),
+            SchemePair::Ecdsa(pair) => pair.public().0.to_vec(),
+        }
+    }
+
+    /// Get the 32-byte account id of this pair.
+    ///
+    /// This is the public key itself for SR25519 and ED25519, and the
+    /// blake2-256 hash of the compressed public key for ECDSA.
+    pub fn account_id(&self) -> [u8; 32] {
+        match self {
+            SchemePair::Sr25519(pair) => pair.public().0,
+            SchemePair::Ed25519(pair) => pair.public().0,
+            SchemePair::Ecdsa(pair) => blake2_256(&pair.public().0),
+        }
+    }
+
+    /// Sign a message, returning the raw signature bytes.
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            SchemePair::Sr25519(pair) => pair.sign(message).0.to_vec(),
+            SchemePair::Ed25519(pair) => pair.sign(message).0.to_vec(),
+            SchemePair::Ecdsa(pair) => pair.sign(message).0.to_vec(),
+        }
+    }
+
+    /// Get the SR25519 pair, or None for other schemes.
+    pub fn as_sr25519(&self) -> Option<&sr25519::Pair> {
+        match self {
+            SchemePair::Sr25519(pair) => Some(pair),
+            _ => None,
+        }
+    }
+
+    /// Apply derivation junctions to this pair.
+    fn derive(&self, uri: SecretUri) -> Result<Self, KeypairError> {
+        let junctions = uri.junctions.into_iter();
+        let derived = match self {
+            SchemePair::Sr25519(pair) => pair.derive(junctions, None).map(|(p, _)| p.into()),
+            SchemePair::Ed25519(pair) => pair.derive(junctions, None).map(|(p, _)| p.into()),
+            SchemePair::Ecdsa(pair) => pair.derive(junctions, None).map(|(p, _)| p.into()),
+        };
+        derived.map_err(|e| KeypairError::DerivationError(format!("{:?}", e)))
+    }
+
+    /// Export the raw secret key material.
+    fn to_raw_vec(&self) -> Vec<u8> {
+        match self {
+            SchemePair::Sr25519(pair) => pair.to_raw_vec(),
+            SchemePair::Ed25519(pair) => pair.to_raw_vec(),
+            SchemePair::Ecdsa(pair) => pair.to_raw_vec(),
+        }
+    }
+}
+
+impl From<sr25519::Pair> for SchemePair {
+    fn from(pair: sr25519::Pair) -> Self {
+        SchemePair::Sr25519(pair)
+    }
+}
+
+impl From<ed25519::Pair> for SchemePair {
+    fn from(pair: ed25519::Pair) -> Self {
+        SchemePair::Ed25519(pair)
+    }
+}
+
+impl From<ecdsa::Pair> for SchemePair {
+    fn from(pair: ecdsa::Pair) -> Self {
+        SchemePair::Ecdsa(pair)
+    }
+}
+
+/// Evaluate `$body` with `$pair` bound to the sp_core pair type for `$scheme`.
+macro_rules! with_pair_type {
+    ($scheme:expr, $pair:ident => $body:expr) => {
+        match $scheme {
+            KeyScheme::Sr25519 => {
+                type $pair = sr25519::Pair;
+                $body
+            }
+            KeyScheme::Ed25519 => {
+                type $pair = ed25519::Pair;
+                $body
+            }
+            KeyScheme::Ecdsa => {
+                type $pair = ecdsa::Pair;
+                $body
+            }
+        }
+    };
 }
 
-/// An SR25519 keypair for signing transactions and messages.
+/// A keypair for signing transactions and messages.
 ///
 /// This provides full keypair functionality including signing and verification.
+/// Keypairs default to SR25519; ED25519 and ECDSA keypairs can be created with
+/// the `*_with_scheme` constructors.
 ///
 /// # Security Note
 ///
-/// The underlying `sr25519::Pair` type from sp_core does not implement `Zeroize`,
+/// The underlying sp_core pair types do not implement `Zeroize`,
 /// meaning the private key material may remain in memory after this struct is dropped.
 /// For maximum security in sensitive applications, consider:
 /// - Using short-lived Keypair instances
@@ -56,15 +252,18 @@ pub enum KeypairError {
 /// The `public_key` field IS properly zeroized on drop.
 #[derive(ZeroizeOnDrop)]
 pub struct Keypair {
-    /// The underlying sr25519 pair. Note: This is NOT zeroized on drop as
-    /// sp_core::sr25519::Pair does not implement Zeroize.
+    /// The underlying pair. Note: This is NOT zeroized on drop as
+    /// the sp_core pair types do not implement Zeroize.
     #[zeroize(skip)]
-    pair: sr25519::Pair,
-    /// The 32-byte public key. This field IS zeroized on drop.
+    pair: SchemePair,
+    /// The 32-byte public key (account id for ECDSA). This field IS zeroized on drop.
     public_key: [u8; 32],
     /// The SS58-encoded address. Skipped from zeroization as it's derived from public key.
     #[zeroize(skip)]
     ss58_address: String,
+    /// The derivation path (e.g. `//hotkey//0`) this keypair was derived with, if any.
+    #[zeroize(skip)]
+    derivation_path: Option<String>,
 }
 
 impl Clone for Keypair {
@@ -73,6 +272,7 @@ impl Clone for Keypair {
             pair: self.pair.clone(),
             public_key: self.public_key,
             ss58_address: self.ss58_address.clone(),
+            derivation_path: self.derivation_path.clone(),
         }
     }
 }
@@ -81,27 +281,29 @@ impl std::fmt::Debug for Keypair {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // Don't expose the private key in debug output
         f.debug_struct("Keypair")
+            .field("scheme", &self.scheme())
             .field("ss58_address", &self.ss58_address)
             .finish()
     }
 }
 
 impl Keypair {
-    /// Create a keypair from an sr25519 pair.
-    fn from_pair(pair: sr25519::Pair) -> Self {
-        let public = pair.public();
-        let public_key: [u8; 32] = public.0;
-        let ss58_address =
-            public.to_ss58check_with_version(Ss58AddressFormat::custom(BITTENSOR_SS58_FORMAT));
+    /// Create a keypair from a pair of any supported scheme.
+    fn from_pair(pair: impl Into<SchemePair>) -> Self {
+        let pair = pair.into();
+        let public_key = pair.account_id();
+        let ss58_address = AccountId32::from(public_key)
+            .to_ss58check_with_version(Ss58AddressFormat::custom(BITTENSOR_SS58_FORMAT));
 
         Self {
             pair,
             public_key,
             ss58_address,
+            derivation_path: None,
         }
     }
 
-    /// Generate a new random keypair.
+    /// Generate a new random SR25519 keypair.
     ///
     /// # Returns
     /// A new randomly generated keypair.
@@ -113,8 +315,22 @@ impl Keypair {
     /// println!("Address: {}", keypair.ss58_address());
     /// ```
     pub fn generate() -> Self {
-        let (pair, _) = sr25519::Pair::generate();
-        Self::from_pair(pair)
+        Self::generate_with_scheme(KeyScheme::Sr25519)
+    }
+
+    /// Generate a new random keypair for the given scheme.
+    ///
+    /// # Arguments
+    /// * `scheme` - The signature scheme of the new keypair
+    ///
+    /// # Example
+    /// ```
+    /// use bittensor_rs::wallet::{KeyScheme, Keypair};
+    /// let keypair = Keypair::generate_with_scheme(KeyScheme::Ed25519);
+    /// assert_eq!(keypair.scheme(), KeyScheme::Ed25519);
+    /// ```
+    pub fn generate_with_scheme(scheme: KeyScheme) -> Self {
+        with_pair_type!(scheme, P => Self::from_pair(P::generate().0))
     }
 
     /// Create a keypair from a BIP39 mnemonic phrase.
@@ -133,8 +349,27 @@ impl Keypair {
     /// let keypair = Keypair::from_mnemonic(phrase, None).unwrap();
     /// ```
     pub fn from_mnemonic(mnemonic: &str, password: Option<&str>) -> Result<Self, KeypairError> {
+        Self::from_mnemonic_with_scheme(mnemonic, password, KeyScheme::Sr25519)
+    }
+
+    /// Create a keypair for the given scheme from a BIP39 mnemonic phrase.
+    ///
+    /// The derivation matches `subkey inspect --scheme <scheme>` for the same phrase.
+    ///
+    /// # Arguments
+    /// * `mnemonic` - A valid BIP39 mnemonic phrase
+    /// * `password` - Optional password for additional security
+    /// * `scheme` - The signature scheme of the derived keypair
+    ///
+    /// # Returns
+    /// The derived keypair or an error.
+    pub fn from_mnemonic_with_scheme(
+        mnemonic: &str,
+        password: Option<&str>,
+        scheme: KeyScheme,
+    ) -> Result<Self, KeypairError> {
         let mnemonic_obj = Mnemonic::from_phrase(mnemonic)?;
-        Self::from_mnemonic_obj(&mnemonic_obj, password)
+        Self::from_phrase_with_scheme(mnemonic_obj.phrase().expose_secret(), password, scheme)
     }
 
     /// Create a keypair from a Mnemonic object.
@@ -148,17 +383,31 @@ impl Keypair {
     pub fn from_mnemonic_obj(
         mnemonic: &Mnemonic,
         password: Option<&str>,
+    ) -> Result<Self, KeypairError> {
+        Self::from_phrase_with_scheme(
+            mnemonic.phrase().expose_secret(),
+            password,
+            KeyScheme::Sr25519,
+        )
+    }
+
+    /// Derive a keypair from a validated phrase.
+    fn from_phrase_with_scheme(
+        phrase: &str,
+        password: Option<&str>,
+        scheme: KeyScheme,
     ) -> Result<Self, KeypairError> {
         // Use the mnemonic phrase directly with sp_core's from_phrase
         // This matches the Substrate/Polkadot standard derivation
         let pass = password.unwrap_or("");
-        let (pair, _seed) = sr25519::Pair::from_phrase(mnemonic.phrase(), Some(pass))
-            .map_err(|e| KeypairError::DerivationError(format!("{:?}", e)))?;
-
-        Ok(Self::from_pair(pair))
+        with_pair_type!(scheme, P => {
+            let (pair, _seed) = P::from_phrase(phrase, Some(pass))
+                .map_err(|e| KeypairError::DerivationError(format!("{:?}", e)))?;
+            Ok(Self::from_pair(pair))
+        })
     }
 
-    /// Create a keypair from a 32-byte seed.
+    /// Create an SR25519 keypair from a 32-byte seed.
     ///
     /// # Arguments
     /// * `seed` - A 32-byte seed
@@ -173,6 +422,18 @@ impl Keypair {
     /// let keypair = Keypair::from_seed(&seed).unwrap();
     /// ```
     pub fn from_seed(seed: &[u8]) -> Result<Self, KeypairError> {
+        Self::from_seed_with_scheme(seed, KeyScheme::Sr25519)
+    }
+
+    /// Create a keypair for the given scheme from a 32-byte seed.
+    ///
+    /// # Arguments
+    /// * `seed` - A 32-byte seed (the secret key for ED25519 and ECDSA)
+    /// * `scheme` - The signature scheme of the keypair
+    ///
+    /// # Returns
+    /// The derived keypair or an error if the seed is invalid.
+    pub fn from_seed_with_scheme(seed: &[u8], scheme: KeyScheme) -> Result<Self, KeypairError> {
         if seed.len() != 32 {
             return Err(KeypairError::InvalidSeedLength(seed.len()));
         }
@@ -180,18 +441,23 @@ impl Keypair {
         let mut seed_arr = [0u8; 32];
         seed_arr.copy_from_slice(seed);
 
-        let pair = sr25519::Pair::from_seed(&seed_arr);
+        let pair = with_pair_type!(scheme, P => P::from_seed_slice(&seed_arr).map(Self::from_pair));
 
         // Zeroize the seed copy
         seed_arr.zeroize();
 
-        Ok(Self::from_pair(pair))
+        pair.map_err(|e| KeypairError::DerivationError(format!("{:?}", e)))
     }
 
-    /// Create a keypair from a Substrate URI (secret phrase with optional derivation path).
+    /// Create an SR25519 keypair from a Substrate URI (secret phrase with optional derivation path).
+    ///
+    /// Full SURI strings are supported: `phrase//hard/soft///password`, where `//` starts a
+    /// hard junction, `/` a soft junction and `///` the BIP39 password. The result matches
+    /// `subkey inspect` for the same URI, and the junctions are recorded as the keypair's
+    /// [derivation path](Keypair::derivation_path).
     ///
     /// # Arguments
-    /// * `uri` - A secret URI (e.g., "//Alice" or "word word word//derive/path")
+    /// * `uri` - A secret URI (e.g., "//Alice" or "word word word//derive/path///password")
     ///
     /// # Returns
     /// The derived keypair or an error.
@@ -202,19 +468,99 @@ impl Keypair {
     /// let keypair = Keypair::from_uri("//Alice").unwrap();
     /// ```
     pub fn from_uri(uri: &str) -> Result<Self, KeypairError> {
-        let pair = sr25519::Pair::from_string(uri, None)
-            .map_err(|e| KeypairError::InvalidUri(format!("{:?}", e)))?;
-        Ok(Self::from_pair(pair))
+        Self::from_uri_with_scheme(uri, KeyScheme::Sr25519)
+    }
+
+    /// Create a keypair for the given scheme from a Substrate URI.
+    ///
+    /// ED25519 and ECDSA only support hard derivation (`//path`).
+    ///
+    /// # Arguments
+    /// * `uri` - A secret URI (e.g., "//Alice" or "word word word//derive/path")
+    /// * `scheme` - The signature scheme of the keypair
+    ///
+    /// # Returns
+    /// The derived keypair or an error.
+    pub fn from_uri_with_scheme(uri: &str, scheme: KeyScheme) -> Result<Self, KeypairError> {
+        let keypair: Self = with_pair_type!(scheme, P => {
+            let pair = P::from_string(uri, None)
+                .map_err(|e| KeypairError::InvalidUri(format!("{:?}", e)))?;
+            Self::from_pair(pair)
+        });
+        Ok(keypair.with_derivation_path(uri_derivation_path(uri)))
+    }
+
+    /// Derive a child keypair by applying a derivation path.
+    ///
+    /// `keypair.derive("//hotkey//0")` gives the same key as `from_uri("<phrase>//hotkey//0")`
+    /// when `keypair` was created from `<phrase>`. ED25519 and ECDSA only support hard
+    /// junctions.
+    ///
+    /// # Arguments
+    /// * `path` - The junctions to apply (e.g. "//hotkey//0" or "//hard/soft"), without a phrase
+    ///   or password
+    ///
+    /// # Returns
+    /// The derived keypair, with the path appended to its derivation path.
+    ///
+    /// # Example
+    /// ```
+    /// use bittensor_rs::wallet::Keypair;
+    /// let alice = Keypair::from_uri("//Alice").unwrap();
+    /// let stash = alice.derive("//stash").unwrap();
+    /// assert_eq!(stash.ss58_address(), Keypair::from_uri("//Alice//stash").unwrap().ss58_address());
+    /// ```
+    pub fn derive(&self, path: &str) -> Result<Self, KeypairError> {
+        // The path is not echoed back: a misplaced phrase or password would
+        // end up in the error
+        if !path.starts_with('/') || path.contains("///") {
+            return Err(KeypairError::InvalidUri(
+                "Invalid derivation path: expected junctions such as //hotkey//0".to_string(),
+            ));
+        }
+        let uri =
+            SecretUri::from_str(path).map_err(|e| KeypairError::InvalidUri(format!("{:?}", e)))?;
+
+        let derived = Self::from_pair(self.pair.derive(uri)?);
+        let full_path = format!("{}{}", self.derivation_path.as_deref().unwrap_or(""), path);
+        Ok(derived.with_derivation_path(Some(full_path)))
+    }
+
+    /// Set the recorded derivation path.
+    pub(crate) fn with_derivation_path(mut self, path: Option<String>) -> Self {
+        self.derivation_path = path;
+        self
+    }
+
+    /// Get the derivation path this keypair was derived with, if any.
+    ///
+    /// This contains only the junctions (e.g. `//hotkey//0`), never the phrase or password.
+    pub fn derivation_path(&self) -> Option<&str> {
+        self.derivation_path.as_deref()
+    }
+
+    /// Get the signature scheme of this keypair.
+    pub fn scheme(&self) -> KeyScheme {
+        self.pair.scheme()
     }
 
     /// Get the public key as raw bytes.
     ///
+    /// For ECDSA keypairs the 33-byte compressed public key does not fit, so this
+    /// is the 32-byte account id instead; use [`Keypair::public_bytes`] for the
+    /// raw key.
+    ///
     /// # Returns
     /// A reference to the 32-byte public key.
     pub fn public_key(&self) -> &[u8; 32] {
         &self.public_key
     }
 
+    /// Get the raw public key (33 bytes compressed for ECDSA, 32 bytes otherwise).
+    pub fn public_bytes(&self) -> Vec<u8> {
+        self.pair.public_bytes()
+    }
+
     /// Get the SS58 address with Bittensor format (prefix 42).
     ///
     /// # Returns
@@ -223,20 +569,29 @@ impl Keypair {
         &self.ss58_address
     }
 
-    /// Get the underlying sr25519 pair.
+    /// Get the underlying pair.
     ///
     /// This can be used for advanced operations or integration with other Substrate libraries.
-    pub fn pair(&self) -> &sr25519::Pair {
+    pub fn pair(&self) -> &SchemePair {
         &self.pair
     }
 
+    /// Get the underlying SR25519 pair, as `pair()` returned before other
+    /// schemes were supported.
+    ///
+    /// # Returns
+    /// The pair, or None for ED25519 and ECDSA keypairs.
+    pub fn sr25519_pair(&self) -> Option<&sr25519::Pair> {
+        self.pair.as_sr25519()
+    }
+
     /// Sign a message and return the signature.
     ///
     /// # Arguments
     /// * `message` - The message to sign
     ///
     /// # Returns
-    /// A 64-byte signature.
+    /// The signature: 64 bytes for SR25519 and ED25519, 65 bytes for ECDSA.
     ///
     /// # Example
     /// ```
@@ -246,32 +601,40 @@ impl Keypair {
     /// let signature = keypair.sign(message);
     /// assert!(keypair.verify(message, &signature));
     /// ```
-    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
-        let signature = self.pair.sign(message);
-        signature.0
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        self.pair.sign(message)
+    }
+
+    /// Sign a message, optionally wrapped the way polkadot-js `signRaw` does.
+    ///
+    /// With `wrap_bytes` the message is signed as `<Bytes>message</Bytes>`,
+    /// which is what browser wallets produce for raw messages. Both forms
+    /// verify with [`crate::utils::crypto::verify_signature`].
+    ///
+    /// # Arguments
+    /// * `message` - The message to sign
+    /// * `wrap_bytes` - Whether to wrap the message in `<Bytes>` tags
+    pub fn sign_message(&self, message: &[u8], wrap_bytes: bool) -> Vec<u8> {
+        if wrap_bytes {
+            self.sign(&crate::utils::crypto::wrap_bytes(message))
+        } else {
+            self.sign(message)
+        }
     }
 
     /// Verify a signature against a message using this keypair's public key.
     ///
     /// # Arguments
     /// * `message` - The original message
-    /// * `signature` - The signature to verify (64 bytes)
+    /// * `signature` - The signature to verify
     ///
     /// # Returns
     /// `true` if the signature is valid.
     pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
-        if signature.len() != 64 {
-            return false;
-        }
-
-        let mut sig_arr = [0u8; 64];
-        sig_arr.copy_from_slice(signature);
-
-        let sig = sr25519::Signature::from_raw(sig_arr);
-        sr25519::Pair::verify(&sig, message, &self.pair.public())
+        Self::verify_with_scheme(self.scheme(), message, signature, &self.public_bytes())
     }
 
-    /// Verify a signature against a message using a public key.
+    /// Verify an SR25519 signature against a message using a public key.
     ///
     /// # Arguments
     /// * `message` - The original message
@@ -281,41 +644,61 @@ impl Keypair {
     /// # Returns
     /// `true` if the signature is valid.
     pub fn verify_with_public(message: &[u8], signature: &[u8], public_key: &[u8; 32]) -> bool {
-        if signature.len() != 64 {
-            return false;
-        }
-
-        let mut sig_arr = [0u8; 64];
-        sig_arr.copy_from_slice(signature);
-
-        let sig = sr25519::Signature::from_raw(sig_arr);
-        let public = sr25519::Public::from_raw(*public_key);
-
-        sr25519::Pair::verify(&sig, message, &public)
+        Self::verify_with_scheme(KeyScheme::Sr25519, message, signature, public_key)
     }
 
-    /// Export the full keypair as bytes (64 bytes for SR25519).
+    /// Verify a signature against a message using a public key of the given scheme.
     ///
-    /// This returns the full keypair suitable for storage in Python-compatible
-    /// keyfile format. The private key is 64 bytes (32 bytes seed + 32 bytes public key).
+    /// # Arguments
+    /// * `scheme` - The signature scheme
+    /// * `message` - The original message
+    /// * `signature` - The signature to verify
+    /// * `public_key` - The raw public key (33 bytes compressed for ECDSA, 32 bytes otherwise)
     ///
-    /// WARNING: This exposes the private key. Handle with care.
+    /// # Returns
+    /// `true` if the signature is valid.
+    pub fn verify_with_scheme(
+        scheme: KeyScheme,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> bool {
+        with_pair_type!(scheme, P => {
+            match (
+                <P as Pair>::Signature::try_from(signature),
+                <P as Pair>::Public::try_from(public_key),
+            ) {
+                (Ok(sig), Ok(public)) => P::verify(&sig, message, &public),
+                _ => false,
+            }
+        })
+    }
+
     /// Get the 32-byte secret seed for this keypair.
     ///
-    /// This derives the seed from the underlying keypair bytes.
-    pub fn secret_seed(&self) -> Result<[u8; 32], KeypairError> {
+    /// This derives the seed from the underlying keypair bytes. The seed is
+    /// zeroized on drop and printed as `[REDACTED]`.
+    pub fn secret_seed(&self) -> Result<Secret<[u8; 32]>, KeypairError> {
         let mut raw = self.pair.to_raw_vec();
         if raw.len() < 32 {
-            return Err(KeypairError::InvalidSeedLength(raw.len()));
+            let len = raw.len();
+            raw.zeroize();
+            return Err(KeypairError::InvalidSeedLength(len));
         }
-        let mut seed = [0u8; 32];
-        seed.copy_from_slice(&raw[..32]);
+        let mut seed = Secret::new([0u8; 32]);
+        seed.expose_secret_mut().copy_from_slice(&raw[..32]);
         raw.zeroize();
         Ok(seed)
     }
+
+    /// Export the full keypair as bytes.
+    ///
+    /// This returns the private key suitable for storage in Python-compatible
+    /// keyfile format. For SR25519 this is 64 bytes (32-byte seed + 32-byte
+    /// public key); for ED25519 and ECDSA it is the 32-byte secret key.
+    ///
+    /// WARNING: This exposes the private key. Handle with care.
     pub fn to_full_bytes(&self) -> Vec<u8> {
-        // SR25519 stores private key as 64 bytes: 32-byte seed + 32-byte public key
-        // The to_raw_vec() returns this format
         self.pair.to_raw_vec()
     }
 
@@ -324,7 +707,7 @@ impl Keypair {
         self.to_full_bytes()
     }
 
-    /// Create a keypair from exported bytes.
+    /// Create an SR25519 keypair from exported bytes.
     ///
     /// # Arguments
     /// * `bytes` - The raw keypair bytes
@@ -332,12 +715,32 @@ impl Keypair {
     /// # Returns
     /// The restored keypair or an error.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, KeypairError> {
-        // Try to restore from raw vec
-        let pair = sr25519::Pair::from_seed_slice(bytes).map_err(|e| {
-            KeypairError::DerivationError(format!("Failed to restore keypair: {:?}", e))
-        })?;
-        Ok(Self::from_pair(pair))
+        Self::from_bytes_with_scheme(bytes, KeyScheme::Sr25519)
     }
+
+    /// Create a keypair for the given scheme from exported bytes.
+    ///
+    /// # Arguments
+    /// * `bytes` - The raw keypair bytes, as returned by [`Keypair::to_full_bytes`]
+    /// * `scheme` - The signature scheme of the keypair
+    ///
+    /// # Returns
+    /// The restored keypair or an error.
+    pub fn from_bytes_with_scheme(bytes: &[u8], scheme: KeyScheme) -> Result<Self, KeypairError> {
+        with_pair_type!(scheme, P => {
+            let pair = P::from_seed_slice(bytes).map_err(|e| {
+                KeypairError::DerivationError(format!("Failed to restore keypair: {:?}", e))
+            })?;
+            Ok(Self::from_pair(pair))
+        })
+    }
+}
+
+/// Extract the junctions from a secret URI, dropping the phrase and password.
+fn uri_derivation_path(uri: &str) -> Option<String> {
+    let without_password = uri.split("///").next().unwrap_or(uri);
+    let path = &without_password[without_password.find('/')?..];
+    Some(path.to_string())
 }
 
 #[cfg(test)]
@@ -412,6 +815,24 @@ mod tests {
         assert!(!keypair.verify(b"Wrong message", &signature));
     }
 
+    #[test]
+    fn test_sr25519_pair() {
+        let keypair = Keypair::generate();
+        let pair = keypair.sr25519_pair().unwrap();
+        assert_eq!(&pair.public().0, keypair.public_key());
+
+        // Signatures convert back to the fixed size array
+        let signature = <[u8; 64]>::try_from(keypair.sign(b"message")).unwrap();
+        assert!(sr25519::Pair::verify(
+            &sr25519::Signature::from_raw(signature),
+            b"message",
+            &pair.public()
+        ));
+
+        let ed25519 = Keypair::generate_with_scheme(KeyScheme::Ed25519);
+        assert!(ed25519.sr25519_pair().is_none());
+    }
+
     #[test]
     fn test_verify_with_public() {
         let keypair = Keypair::generate();
@@ -439,6 +860,224 @@ mod tests {
         assert!(restored.verify(message, &sig));
     }
 
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_scheme_uri_vectors() {
+        // Public keys from `subkey inspect --scheme <scheme> //Alice`
+        let sr = Keypair::from_uri_with_scheme("//Alice", KeyScheme::Sr25519).unwrap();
+        assert_eq!(
+            hex::encode(sr.public_key()),
+            "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+        );
+        assert_eq!(
+            sr.ss58_address(),
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+        );
+
+        let ed = Keypair::from_uri_with_scheme("//Alice", KeyScheme::Ed25519).unwrap();
+        assert_eq!(ed.scheme(), KeyScheme::Ed25519);
+        assert_eq!(
+            hex::encode(ed.public_key()),
+            "88dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee"
+        );
+        assert_eq!(
+            ed.ss58_address(),
+            "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu"
+        );
+
+        let ecdsa = Keypair::from_uri_with_scheme("//Alice", KeyScheme::Ecdsa).unwrap();
+        assert_eq!(ecdsa.scheme(), KeyScheme::Ecdsa);
+        assert_eq!(
+            hex::encode(ecdsa.public_bytes()),
+            "020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1"
+        );
+        assert_eq!(
+            hex::encode(ecdsa.public_key()),
+            "01e552298e47454041ea31273b4b630c64c104e4514aa3643490b8aaca9cf8ed"
+        );
+        assert_eq!(
+            ecdsa.ss58_address(),
+            "5C7C2Z5sWbytvHpuLTvzKunnnRwQxft1jiqrLD5rhucQ5S9X"
+        );
+    }
+
+    #[test]
+    fn test_scheme_mnemonic_vectors() {
+        let cases = [
+            (
+                KeyScheme::Sr25519,
+                "66933bd1f37070ef87bd1198af3dacceb095237f803f3d32b173e6b425ed7972",
+            ),
+            (
+                KeyScheme::Ed25519,
+                "9125f505bdef2cb5825b9931769316d3e2f22150786489a04f39b434ec9fb294",
+            ),
+            (
+                KeyScheme::Ecdsa,
+                "035c080d535720870caab8d33acdbc59f4cede44fe599980289a1c01fcec2a4112",
+            ),
+        ];
+
+        for (scheme, public) in cases {
+            let keypair = Keypair::from_mnemonic_with_scheme(PHRASE, None, scheme).unwrap();
+            assert_eq!(keypair.scheme(), scheme);
+            assert_eq!(hex::encode(keypair.public_bytes()), public);
+        }
+
+        // The default constructor stays sr25519
+        let default = Keypair::from_mnemonic(PHRASE, None).unwrap();
+        assert_eq!(default.scheme(), KeyScheme::Sr25519);
+    }
+
+    #[test]
+    fn test_ed25519_rfc8032_vector() {
+        // RFC 8032 section 7.1, test 1
+        let seed = hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+            .unwrap();
+        let keypair = Keypair::from_seed_with_scheme(&seed, KeyScheme::Ed25519).unwrap();
+        assert_eq!(
+            hex::encode(keypair.public_key()),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+
+        let signature = keypair.sign(b"");
+        assert_eq!(
+            hex::encode(&signature),
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        );
+        assert!(keypair.verify(b"", &signature));
+    }
+
+    #[test]
+    fn test_scheme_sign_verify_and_bytes_roundtrip() {
+        for scheme in [KeyScheme::Sr25519, KeyScheme::Ed25519, KeyScheme::Ecdsa] {
+            let keypair = Keypair::from_uri_with_scheme("//Bob", scheme).unwrap();
+            let message = b"Hello, Bittensor!";
+
+            let signature = keypair.sign(message);
+            assert_eq!(signature.len(), scheme.signature_len());
+            assert!(keypair.verify(message, &signature));
+            assert!(!keypair.verify(b"Wrong message", &signature));
+            assert!(Keypair::verify_with_scheme(
+                scheme,
+                message,
+                &signature,
+                &keypair.public_bytes()
+            ));
+
+            let restored =
+                Keypair::from_bytes_with_scheme(&keypair.to_full_bytes(), scheme).unwrap();
+            assert_eq!(restored.scheme(), scheme);
+            assert_eq!(restored.public_bytes(), keypair.public_bytes());
+            assert_eq!(restored.ss58_address(), keypair.ss58_address());
+            assert!(restored.verify(message, &signature));
+        }
+
+        // Signatures do not verify under another scheme
+        let ed = Keypair::from_uri_with_scheme("//Bob", KeyScheme::Ed25519).unwrap();
+        let signature = ed.sign(b"Test");
+        assert!(!Keypair::verify_with_public(
+            b"Test",
+            &signature,
+            ed.public_key()
+        ));
+    }
+
+    #[test]
+    fn test_key_scheme_strings() {
+        for scheme in [KeyScheme::Sr25519, KeyScheme::Ed25519, KeyScheme::Ecdsa] {
+            assert_eq!(scheme.as_str().parse::<KeyScheme>().unwrap(), scheme);
+            assert_eq!(
+                serde_json::to_string(&scheme).unwrap(),
+                format!("\"{}\"", scheme)
+            );
+        }
+        assert_eq!("ED25519".parse::<KeyScheme>().unwrap(), KeyScheme::Ed25519);
+        assert!("secp256r1".parse::<KeyScheme>().is_err());
+        assert_eq!(KeyScheme::default(), KeyScheme::Sr25519);
+    }
+
+    #[test]
+    fn test_suri_vectors() {
+        // Addresses from `subkey inspect <suri>`
+        let cases = [
+            (
+                "//Alice//stash".to_string(),
+                "5GNJqTPyNqANBkUVMN1LPPrxXnFouWXoe2wNSmmEoLctxiZY",
+                Some("//Alice//stash"),
+            ),
+            (
+                format!("{}//hotkey//0", PHRASE),
+                "5D9xPwHpEWWb1TSbQutyd6NrfsNak1BQa7cp4gHUU4eDLWGg",
+                Some("//hotkey//0"),
+            ),
+            (
+                format!("{}//hard/soft", PHRASE),
+                "5FncfQd6TSSpUMsaoMFpibpTtF8wRnrfEk8yx9HSZWvr1HQ9",
+                Some("//hard/soft"),
+            ),
+            (
+                format!("{}//hard/soft///password", PHRASE),
+                "5CoGSeJ3GB2WE7XBTekSzooVpuEtVDFKyv4PAU1G4hjXhgUp",
+                Some("//hard/soft"),
+            ),
+            (
+                format!("{}///password", PHRASE),
+                "5D2mgJK6NJZNBeKs8oCrjWDE9nW2uWgwBY2P1MmWndCaHdzV",
+                None,
+            ),
+        ];
+
+        for (suri, address, path) in cases {
+            let keypair = Keypair::from_uri(&suri).unwrap();
+            assert_eq!(keypair.ss58_address(), address, "{}", suri);
+            assert_eq!(keypair.derivation_path(), path, "{}", suri);
+        }
+
+        // The password in a SURI is the BIP39 password
+        let with_password = Keypair::from_mnemonic(PHRASE, Some("password")).unwrap();
+        assert_eq!(
+            with_password.ss58_address(),
+            "5D2mgJK6NJZNBeKs8oCrjWDE9nW2uWgwBY2P1MmWndCaHdzV"
+        );
+    }
+
+    #[test]
+    fn test_derive_matches_suri() {
+        let root = Keypair::from_mnemonic(PHRASE, None).unwrap();
+        assert_eq!(root.derivation_path(), None);
+
+        let hotkey = root.derive("//hotkey//0").unwrap();
+        assert_eq!(
+            hotkey.ss58_address(),
+            "5D9xPwHpEWWb1TSbQutyd6NrfsNak1BQa7cp4gHUU4eDLWGg"
+        );
+        assert_eq!(hotkey.derivation_path(), Some("//hotkey//0"));
+
+        // Derivation works from restored key bytes, without the phrase
+        let restored = Keypair::from_bytes(&root.to_bytes()).unwrap();
+        let soft = restored.derive("//hard").unwrap().derive("/soft").unwrap();
+        assert_eq!(
+            soft.ss58_address(),
+            "5FncfQd6TSSpUMsaoMFpibpTtF8wRnrfEk8yx9HSZWvr1HQ9"
+        );
+        assert_eq!(soft.derivation_path(), Some("//hard/soft"));
+
+        // ED25519 derives hard junctions only
+        let ed = Keypair::from_mnemonic_with_scheme(PHRASE, None, KeyScheme::Ed25519).unwrap();
+        let ed_hotkey = ed.derive("//hotkey//0").unwrap();
+        let ed_suri =
+            Keypair::from_uri_with_scheme(&format!("{}//hotkey//0", PHRASE), KeyScheme::Ed25519)
+                .unwrap();
+        assert_eq!(ed_hotkey.public_key(), ed_suri.public_key());
+        assert!(ed.derive("/soft").is_err());
+
+        // Paths must not carry a phrase or password
+        assert!(root.derive("hotkey").is_err());
+        assert!(root.derive("//hotkey///password").is_err());
+    }
+
     #[test]
     fn test_invalid_signature_length() {
         let keypair = Keypair::generate();
@@ -450,4 +1089,25 @@ mod tests {
         // Too long
         assert!(!keypair.verify(message, &[0u8; 128]));
     }
+
+    #[test]
+    fn test_formatting_does_not_leak_seed() {
+        let keypair = Keypair::from_mnemonic(PHRASE, None).unwrap();
+        let seed = keypair.secret_seed().unwrap();
+        let seed_hex = hex::encode(seed.expose_secret());
+
+        for formatted in [
+            format!("{:?}", keypair),
+            format!("{:?}", seed),
+            format!("{}", seed),
+            format!("{:?}", keypair.derive(PHRASE).unwrap_err()),
+            keypair
+                .derive(&format!("{}//0", PHRASE))
+                .unwrap_err()
+                .to_string(),
+        ] {
+            assert!(!formatted.contains(&seed_hex), "{}", formatted);
+            assert!(!formatted.contains("abandon"), "{}", formatted);
+        }
+    }
 }
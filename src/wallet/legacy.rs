@@ -0,0 +1,325 @@
+//! Decryption of keyfiles written by older Python Bittensor SDKs.
+//!
+//! The Python bittensor-wallet still reads three encrypted layouts besides the
+//! current `$NACL` format:
+//!
+//! - **Ansible vault** (bittensor 2.x): `$ANSIBLE_VAULT;1.1;AES256` followed
+//!   by hex lines of `salt`, `hmac` and `ciphertext`. The key material is
+//!   PBKDF2-SHA256 (10,000 rounds, 80 bytes) split into an AES-256-CTR key,
+//!   an HMAC-SHA256 key and the initial counter.
+//! - **Fernet** (bittensor 3.x): a base64url token starting with `gAAAAA`,
+//!   keyed with PBKDF2-SHA256 (10,000,000 rounds) over a fixed salt.
+//! - **PyNaCl `$NACL`**: `$NACL` + nonce + ciphertext, without the salt this
+//!   crate writes. The key is Argon2i (`OPSLIMIT_SENSITIVE`,
+//!   `MEMLIMIT_SENSITIVE`) over a fixed salt.
+//!
+//! All of them decrypt to the Python keyfile JSON, see
+//! [`keypair_from_python_json`].
+
+use crate::wallet::keyfile::{KeyfileError, KeyfileJsonData, NACL_HEADER};
+use crate::wallet::keypair::Keypair;
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit, StreamCipher};
+use argon2::{Argon2, Params, Version};
+use base64::Engine;
+use crypto_secretbox::{
+    aead::{Aead, KeyInit},
+    XSalsa20Poly1305,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+/// Header of ansible-vault encrypted keyfiles
+pub const ANSIBLE_VAULT_HEADER: &[u8] = b"$ANSIBLE_VAULT";
+
+/// Prefix of Fernet tokens (version byte and the high timestamp bytes)
+pub const FERNET_PREFIX: &[u8] = b"gAAAAA";
+
+/// PBKDF2 rounds used by ansible-vault
+const ANSIBLE_PBKDF2_ROUNDS: u32 = 10_000;
+
+/// PBKDF2 rounds used for Fernet keyfiles
+const FERNET_PBKDF2_ROUNDS: u32 = 10_000_000;
+
+/// Fixed salt for Fernet keyfiles
+const FERNET_SALT: &[u8] = b"Iguesscyborgslikemyselfhaveatendencytobeparanoidaboutourorigins";
+
+/// Fixed salt for PyNaCl keyfiles
+const PYNACL_SALT: [u8; 16] = *b"\x13q\x83\xdf\xf1Z\t\xbc\x9c\x90\xb5Q\x879\xe9\xb1";
+
+/// libsodium `crypto_pwhash_argon2i_OPSLIMIT_SENSITIVE`
+const PYNACL_OPSLIMIT: u32 = 8;
+
+/// libsodium `crypto_pwhash_argon2i_MEMLIMIT_SENSITIVE` (1 GiB) in KiB blocks
+const PYNACL_MEMLIMIT_KIB: u32 = 1024 * 1024;
+
+type HmacSha256 = Hmac<Sha256>;
+type Aes256Ctr = ctr::Ctr128BE<aes::Aes256>;
+type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
+
+/// Check if data is an ansible-vault encrypted keyfile.
+pub fn is_ansible_vault(data: &[u8]) -> bool {
+    data.starts_with(ANSIBLE_VAULT_HEADER)
+}
+
+/// Check if data is a Fernet encrypted keyfile.
+pub fn is_fernet(data: &[u8]) -> bool {
+    data.starts_with(FERNET_PREFIX)
+}
+
+/// Check if data is encrypted in a legacy Python format.
+pub fn is_legacy_encrypted(data: &[u8]) -> bool {
+    is_ansible_vault(data) || is_fernet(data)
+}
+
+/// Decrypt a legacy ansible-vault or Fernet keyfile.
+///
+/// # Returns
+/// The decrypted keyfile JSON, or `InvalidFormat` for any other layout.
+pub fn decrypt_legacy(data: &[u8], password: &str) -> Result<Vec<u8>, KeyfileError> {
+    if is_ansible_vault(data) {
+        decrypt_ansible_vault(data, password)
+    } else if is_fernet(data) {
+        decrypt_fernet(data, password)
+    } else {
+        Err(KeyfileError::InvalidFormat(
+            "Not a legacy encrypted keyfile".to_string(),
+        ))
+    }
+}
+
+/// Decrypt an ansible-vault 1.1 AES256 keyfile.
+pub fn decrypt_ansible_vault(data: &[u8], password: &str) -> Result<Vec<u8>, KeyfileError> {
+    let text = std::str::from_utf8(data)
+        .map_err(|_| KeyfileError::InvalidFormat("Ansible vault is not UTF-8".to_string()))?;
+    let mut lines = text.lines();
+    let header = lines.next().unwrap_or_default();
+    let fields: Vec<&str> = header.trim().split(';').collect();
+    if fields.len() < 3 || fields[2] != "AES256" {
+        return Err(KeyfileError::InvalidFormat(format!(
+            "Unsupported ansible vault header '{}'",
+            header
+        )));
+    }
+
+    let body: String = lines.map(str::trim).collect();
+    let envelope = hex::decode(body).map_err(|e| {
+        KeyfileError::InvalidFormat(format!("Invalid ansible vault encoding: {}", e))
+    })?;
+    let parts: Vec<&[u8]> = envelope.split(|b| *b == b'\n').collect();
+    let [salt, mac, ciphertext] = parts[..] else {
+        return Err(KeyfileError::InvalidFormat(
+            "Ansible vault must hold salt, hmac and ciphertext".to_string(),
+        ));
+    };
+    let decode = |part: &[u8]| {
+        hex::decode(part)
+            .map_err(|e| KeyfileError::InvalidFormat(format!("Invalid ansible vault field: {}", e)))
+    };
+    let (salt, mac, mut ciphertext) = (decode(salt)?, decode(mac)?, decode(ciphertext)?);
+
+    let mut derived = [0u8; 80];
+    pbkdf2::pbkdf2_hmac::<Sha256>(
+        password.as_bytes(),
+        &salt,
+        ANSIBLE_PBKDF2_ROUNDS,
+        &mut derived,
+    );
+    let (cipher_key, rest) = derived.split_at(32);
+    let (mac_key, iv) = rest.split_at(32);
+
+    let mut verifier = <HmacSha256 as Mac>::new_from_slice(mac_key)
+        .map_err(|e| KeyfileError::KeyDerivationFailed(e.to_string()))?;
+    verifier.update(&ciphertext);
+    let verified = verifier.verify_slice(&mac);
+    if verified.is_ok() {
+        let mut cipher = Aes256Ctr::new(cipher_key.into(), iv.into());
+        cipher.apply_keystream(&mut ciphertext);
+    }
+    derived.zeroize();
+    verified.map_err(|_| KeyfileError::DecryptionFailed)?;
+
+    let plaintext = strip_pkcs7(ciphertext)?;
+    unwrap_yaml_binary(plaintext)
+}
+
+/// Decrypt a Fernet keyfile.
+pub fn decrypt_fernet(data: &[u8], password: &str) -> Result<Vec<u8>, KeyfileError> {
+    let token = base64::engine::general_purpose::URL_SAFE.decode(data.trim_ascii())?;
+    // version (1) + timestamp (8) + iv (16) + ciphertext (>= 16) + hmac (32)
+    if token.len() < 1 + 8 + 16 + 16 + 32 || token[0] != 0x80 {
+        return Err(KeyfileError::InvalidFormat(
+            "Invalid Fernet token".to_string(),
+        ));
+    }
+    let (signed, mac) = token.split_at(token.len() - 32);
+    let iv = &signed[9..25];
+    let ciphertext = &signed[25..];
+
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(
+        password.as_bytes(),
+        FERNET_SALT,
+        FERNET_PBKDF2_ROUNDS,
+        &mut key,
+    );
+    let (signing_key, encryption_key) = key.split_at(16);
+
+    let mut verifier = <HmacSha256 as Mac>::new_from_slice(signing_key)
+        .map_err(|e| KeyfileError::KeyDerivationFailed(e.to_string()))?;
+    verifier.update(signed);
+    let plaintext = match verifier.verify_slice(mac) {
+        Ok(()) => Aes128CbcDec::new(encryption_key.into(), iv.into())
+            .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+            .map_err(|_| KeyfileError::DecryptionFailed),
+        Err(_) => Err(KeyfileError::DecryptionFailed),
+    };
+    key.zeroize();
+    plaintext
+}
+
+/// Decrypt a `$NACL` keyfile in the PyNaCl layout (no salt after the header).
+pub fn decrypt_pynacl(data: &[u8], password: &str) -> Result<Vec<u8>, KeyfileError> {
+    let body = data
+        .strip_prefix(NACL_HEADER)
+        .filter(|body| body.len() >= 24 + 16)
+        .ok_or(KeyfileError::InvalidNaclHeader)?;
+    let (nonce, ciphertext) = body.split_at(24);
+
+    let params = Params::new(PYNACL_MEMLIMIT_KIB, PYNACL_OPSLIMIT, 1, Some(32))
+        .map_err(|e| KeyfileError::KeyDerivationFailed(e.to_string()))?;
+    let argon2 = Argon2::new(argon2::Algorithm::Argon2i, Version::V0x13, params);
+    let mut key = [0u8; 32];
+    argon2
+        .hash_password_into(password.as_bytes(), &PYNACL_SALT, &mut key)
+        .map_err(|e| KeyfileError::KeyDerivationFailed(e.to_string()))?;
+
+    let cipher = XSalsa20Poly1305::new_from_slice(&key)
+        .map_err(|e| KeyfileError::EncryptionFailed(e.to_string()));
+    key.zeroize();
+    cipher?
+        .decrypt(nonce.into(), ciphertext)
+        .map_err(|_| KeyfileError::DecryptionFailed)
+}
+
+/// Restore a keypair from Python keyfile JSON.
+///
+/// Current keyfiles are read through [`KeyfileJsonData`]. Older SDKs wrote
+/// `secretSeed` and `secretPhrase` without `privateKey`; the seed is preferred
+/// as the phrase may be `null`.
+pub fn keypair_from_python_json(data: &[u8]) -> Result<Keypair, KeyfileError> {
+    if let Ok(json) = serde_json::from_slice::<KeyfileJsonData>(data) {
+        return json.to_keypair();
+    }
+
+    let value: serde_json::Value = serde_json::from_slice(data)?;
+    if let Some(seed) = value.get("secretSeed").and_then(|v| v.as_str()) {
+        let mut seed = hex::decode(seed.trim_start_matches("0x"))
+            .map_err(|e| KeyfileError::InvalidFormat(format!("Invalid secretSeed: {}", e)))?;
+        let keypair = Keypair::from_seed(&seed);
+        seed.zeroize();
+        return Ok(keypair?);
+    }
+    if let Some(phrase) = value.get("secretPhrase").and_then(|v| v.as_str()) {
+        return Ok(Keypair::from_mnemonic(phrase, None)?);
+    }
+
+    Err(KeyfileError::InvalidFormat(
+        "Keyfile JSON has no privateKey, secretSeed or secretPhrase".to_string(),
+    ))
+}
+
+/// Remove PKCS#7 padding from decrypted ansible vault data.
+fn strip_pkcs7(mut data: Vec<u8>) -> Result<Vec<u8>, KeyfileError> {
+    let pad = data.last().copied().unwrap_or(0) as usize;
+    let valid = (1..=16).contains(&pad)
+        && pad <= data.len()
+        && data[data.len() - pad..].iter().all(|b| *b as usize == pad);
+    if !valid {
+        return Err(KeyfileError::DecryptionFailed);
+    }
+    data.truncate(data.len() - pad);
+    Ok(data)
+}
+
+/// Unwrap YAML `!!binary` data written by `Vault.dump` on bytes.
+///
+/// Keyfiles encrypted with `vault.encrypt` hold the JSON directly and are
+/// returned unchanged.
+fn unwrap_yaml_binary(data: Vec<u8>) -> Result<Vec<u8>, KeyfileError> {
+    let Some(rest) = data.trim_ascii_start().strip_prefix(b"!!binary") else {
+        return Ok(data);
+    };
+    let encoded: Vec<u8> = rest
+        .iter()
+        .copied()
+        .filter(|b| !b.is_ascii_whitespace() && *b != b'|')
+        .collect();
+    Ok(base64::engine::general_purpose::STANDARD.decode(encoded)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_detection() {
+        assert!(is_ansible_vault(b"$ANSIBLE_VAULT;1.1;AES256\n6162"));
+        assert!(is_fernet(b"gAAAAABhZ"));
+        assert!(!is_legacy_encrypted(b"$NACL0123"));
+        assert!(!is_legacy_encrypted(br#"{"privateKey": "0x00"}"#));
+    }
+
+    #[test]
+    fn test_ansible_vault_rejects_bad_envelopes() {
+        let unsupported = decrypt_ansible_vault(b"$ANSIBLE_VAULT;1.1;AES\n00", "pw");
+        assert!(matches!(unsupported, Err(KeyfileError::InvalidFormat(_))));
+
+        // Hex of "00\n11", missing the ciphertext
+        let short = decrypt_ansible_vault(b"$ANSIBLE_VAULT;1.1;AES256\n30300a3131", "pw");
+        assert!(matches!(short, Err(KeyfileError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_strip_pkcs7() {
+        assert_eq!(strip_pkcs7(b"abc\x02\x02".to_vec()).unwrap(), b"abc");
+        assert!(strip_pkcs7(b"abc\x02\x03".to_vec()).is_err());
+        assert!(strip_pkcs7(b"abc\x00".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_unwrap_yaml_binary() {
+        let yaml = b"!!binary |\n  eyJhIjogMX0=\n".to_vec();
+        assert_eq!(unwrap_yaml_binary(yaml).unwrap(), br#"{"a": 1}"#);
+
+        let json = br#"{"a": 1}"#.to_vec();
+        assert_eq!(unwrap_yaml_binary(json.clone()).unwrap(), json);
+    }
+
+    #[test]
+    fn test_keypair_from_python_json_seed() {
+        let seed = format!("0x{}01", "00".repeat(31));
+        let json = format!(
+            r#"{{"accountId": "{0}", "publicKey": "{0}", "secretPhrase": null, "secretSeed": "{0}", "ss58Address": null}}"#,
+            seed
+        );
+        let keypair = keypair_from_python_json(json.as_bytes()).unwrap();
+
+        let mut expected = [0u8; 32];
+        expected[31] = 1;
+        assert_eq!(
+            keypair.public_key(),
+            Keypair::from_seed(&expected).unwrap().public_key()
+        );
+    }
+
+    #[test]
+    fn test_keypair_from_python_json_phrase() {
+        let json = br#"{"secretPhrase": "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", "secretSeed": null}"#;
+        let keypair = keypair_from_python_json(json).unwrap();
+        assert_eq!(
+            keypair.ss58_address(),
+            "5EPCUjPxiHAcNooYipQFWr9NmmXJKpNG5RhcntXwbtUySrgH"
+        );
+    }
+}
@@ -7,6 +7,7 @@
 // incorrectly flags as unused assignments when it reads/writes struct fields for zeroization
 #![allow(unused_assignments)]
 
+use crate::wallet::secret::{Secret, SecretString};
 use bip39::Mnemonic as Bip39Mnemonic;
 use thiserror::Error;
 use zeroize::{Zeroize, ZeroizeOnDrop};
@@ -31,7 +32,9 @@ pub enum MnemonicError {
 pub struct Mnemonic {
     #[zeroize(skip)]
     inner: Bip39Mnemonic,
-    phrase: String,
+    /// Zeroized by its own `Drop`
+    #[zeroize(skip)]
+    phrase: SecretString,
     words: Vec<String>,
 }
 
@@ -94,8 +97,12 @@ impl Mnemonic {
         // Zeroize entropy after use
         entropy.zeroize();
 
-        let phrase = inner.to_string();
-        let words: Vec<String> = phrase.split_whitespace().map(String::from).collect();
+        let phrase = SecretString::new(inner.to_string());
+        let words: Vec<String> = phrase
+            .expose_secret()
+            .split_whitespace()
+            .map(String::from)
+            .collect();
 
         Ok(Self {
             inner,
@@ -123,8 +130,12 @@ impl Mnemonic {
         let inner = Bip39Mnemonic::parse_normalized(&normalized)
             .map_err(|e| MnemonicError::InvalidPhrase(e.to_string()))?;
 
-        let phrase = inner.to_string();
-        let words: Vec<String> = phrase.split_whitespace().map(String::from).collect();
+        let phrase = SecretString::new(inner.to_string());
+        let words: Vec<String> = phrase
+            .expose_secret()
+            .split_whitespace()
+            .map(String::from)
+            .collect();
 
         Ok(Self {
             inner,
@@ -145,11 +156,12 @@ impl Mnemonic {
         Bip39Mnemonic::parse_normalized(&normalized).is_ok()
     }
 
-    /// Get the mnemonic phrase as a string.
+    /// Get the mnemonic phrase.
     ///
     /// # Returns
-    /// The mnemonic phrase.
-    pub fn phrase(&self) -> &str {
+    /// The mnemonic phrase; formatting it prints `[REDACTED]`, use
+    /// [`Secret::expose_secret`] to read it.
+    pub fn phrase(&self) -> &SecretString {
         &self.phrase
     }
 
@@ -175,18 +187,18 @@ impl Mnemonic {
     /// * `password` - Optional password for additional security (BIP39 passphrase)
     ///
     /// # Returns
-    /// A 64-byte seed suitable for key derivation.
+    /// A 64-byte seed suitable for key derivation, zeroized on drop.
     ///
     /// # Example
     /// ```
     /// use bittensor_rs::wallet::Mnemonic;
     /// let mnemonic = Mnemonic::generate();
     /// let seed = mnemonic.to_seed(None);
-    /// assert_eq!(seed.len(), 64);
+    /// assert_eq!(seed.expose_secret().len(), 64);
     /// ```
-    pub fn to_seed(&self, password: Option<&str>) -> [u8; 64] {
+    pub fn to_seed(&self, password: Option<&str>) -> Secret<[u8; 64]> {
         let passphrase = password.unwrap_or("");
-        self.inner.to_seed(passphrase)
+        Secret::new(self.inner.to_seed(passphrase))
     }
 
     /// Convert the mnemonic to entropy bytes.
@@ -214,14 +226,14 @@ mod tests {
     fn test_generate_12_words() {
         let mnemonic = Mnemonic::generate();
         assert_eq!(mnemonic.word_count(), 12);
-        assert!(Mnemonic::validate(mnemonic.phrase()));
+        assert!(Mnemonic::validate(mnemonic.phrase().expose_secret()));
     }
 
     #[test]
     fn test_generate_24_words() {
         let mnemonic = Mnemonic::generate_with_words(24).unwrap();
         assert_eq!(mnemonic.word_count(), 24);
-        assert!(Mnemonic::validate(mnemonic.phrase()));
+        assert!(Mnemonic::validate(mnemonic.phrase().expose_secret()));
     }
 
     #[test]
@@ -235,7 +247,7 @@ mod tests {
         let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
         let mnemonic = Mnemonic::from_phrase(phrase).unwrap();
         assert_eq!(mnemonic.word_count(), 12);
-        assert_eq!(mnemonic.phrase(), phrase);
+        assert_eq!(mnemonic.phrase().expose_secret(), phrase);
     }
 
     #[test]
@@ -268,11 +280,28 @@ mod tests {
         let seed_no_pass = mnemonic.to_seed(None);
         let seed_with_pass = mnemonic.to_seed(Some("password"));
 
-        assert_eq!(seed_no_pass.len(), 64);
-        assert_eq!(seed_with_pass.len(), 64);
+        assert_eq!(seed_no_pass.expose_secret().len(), 64);
+        assert_eq!(seed_with_pass.expose_secret().len(), 64);
         assert_ne!(seed_no_pass, seed_with_pass);
     }
 
+    #[test]
+    fn test_phrase_and_seed_redacted() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let mnemonic = Mnemonic::from_phrase(phrase).unwrap();
+        let seed_hex = hex::encode(mnemonic.to_seed(None).expose_secret());
+
+        for formatted in [
+            format!("{:?}", mnemonic),
+            format!("{}", mnemonic.phrase()),
+            format!("{:?}", mnemonic.phrase()),
+            format!("{:?}", mnemonic.to_seed(None)),
+        ] {
+            assert!(!formatted.contains("abandon"), "{}", formatted);
+            assert!(!formatted.contains(&seed_hex[..16]), "{}", formatted);
+        }
+    }
+
     #[test]
     fn test_deterministic_seed() {
         let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
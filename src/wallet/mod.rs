@@ -4,9 +4,10 @@
 //! Python Bittensor SDK, including:
 //!
 //! - **Mnemonic generation and recovery** (BIP39)
-//! - **Keypair management** (SR25519)
+//! - **Keypair management** (SR25519, ED25519 and ECDSA)
 //! - **Keyfile encryption and storage** (Argon2id + NaCl secretbox)
 //! - **Wallet creation and management** (coldkey/hotkey)
+//! - **Password sources** for unattended unlocking (environment, 0600 file)
 //!
 //! ## Quick Start
 //!
@@ -42,7 +43,7 @@
 //!
 //! // Generate 12-word mnemonic
 //! let mnemonic = Mnemonic::generate();
-//! println!("Save this: {}", mnemonic.phrase());
+//! println!("Save this: {}", mnemonic.phrase().expose_secret());
 //!
 //! // Generate 24-word mnemonic for extra security
 //! let mnemonic24 = Mnemonic::generate_with_words(24).unwrap();
@@ -90,13 +91,18 @@
 //!
 //! - All sensitive data (seeds, private keys, mnemonics) is securely zeroed from
 //!   memory when dropped using the `zeroize` crate.
+//! - Mnemonic phrases and seeds are returned as [`Secret`], which prints as
+//!   `[REDACTED]`; errors built from keyfile contents never quote key material.
 //! - Keyfiles use Argon2id for key derivation (memory-hard, resistant to GPU attacks)
 //! - Encryption uses XSalsa20-Poly1305 (NaCl secretbox)
 //! - File permissions are set to 0600 on Unix systems
 
 pub mod keyfile;
 pub mod keypair;
+pub mod legacy;
 pub mod mnemonic;
+pub mod password;
+pub mod secret;
 #[allow(clippy::module_inception)]
 pub mod wallet;
 
@@ -105,8 +111,10 @@ pub use keyfile::{
     is_legacy_format, migrate_legacy_keyfile, Keyfile, KeyfileData, KeyfileError, KeyfileJsonData,
     NACL_HEADER,
 };
-pub use keypair::{Keypair, KeypairError, BITTENSOR_SS58_FORMAT};
+pub use keypair::{KeyScheme, Keypair, KeypairError, SchemePair, BITTENSOR_SS58_FORMAT};
 pub use mnemonic::{Mnemonic, MnemonicError};
+pub use password::{PasswordError, PasswordSource, PASSWORD_ENV_PREFIX};
+pub use secret::{Secret, SecretString};
 pub use wallet::{
     default_wallet_path, list_wallets, list_wallets_at, wallet_path, Wallet, WalletError,
 };
@@ -128,10 +136,18 @@ mod tests {
         // Create wallet with mnemonics
         let mut wallet = Wallet::new("test_wallet", "default", Some(base_path)).unwrap();
         wallet
-            .create_coldkey(Some("password"), Some(coldkey_mnemonic.phrase()), false)
+            .create_coldkey(
+                Some("password"),
+                Some(coldkey_mnemonic.phrase().expose_secret().as_str()),
+                false,
+            )
             .unwrap();
         wallet
-            .create_hotkey(Some("password"), Some(hotkey_mnemonic.phrase()), false)
+            .create_hotkey(
+                Some("password"),
+                Some(hotkey_mnemonic.phrase().expose_secret().as_str()),
+                false,
+            )
             .unwrap();
 
         // Verify wallet exists
@@ -153,7 +169,11 @@ mod tests {
         // Recover wallet with same mnemonic
         let mut recovered = Wallet::new("recovered", "default", Some(base_path)).unwrap();
         recovered
-            .create_coldkey(Some("password"), Some(coldkey_mnemonic.phrase()), false)
+            .create_coldkey(
+                Some("password"),
+                Some(coldkey_mnemonic.phrase().expose_secret().as_str()),
+                false,
+            )
             .unwrap();
 
         let recovered_coldkey = recovered.coldkey_keypair(Some("password")).unwrap();
@@ -0,0 +1,265 @@
+//! Password sources for unlocking encrypted keyfiles.
+//!
+//! Validators running as services cannot type passwords interactively, and
+//! passing them as command line arguments leaks them through process lists.
+//! A [`PasswordSource`] describes where a password comes from instead:
+//!
+//! - **Env**: an environment variable, by default `BT_PW_<WALLET>` for the
+//!   coldkey and `BT_PW_<WALLET>_HOTKEY_<HOTKEY>` for a hotkey
+//! - **File**: a file readable only by its owner (mode 0600 or stricter).
+//!   A password file holds the password of one key; to unlock several keys,
+//!   pass a directory holding one file per key, named after the key's
+//!   environment variable (e.g. `BT_PW_VALIDATOR`), like systemd's
+//!   `$CREDENTIALS_DIRECTORY`
+//! - **Prompt**: an interactive terminal prompt
+//! - **None**: no password (unencrypted keyfile)
+//!
+//! [`PasswordSource::select`] picks the first available source in the order
+//! password file > environment variable > prompt.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Prefix of the password environment variables
+pub const PASSWORD_ENV_PREFIX: &str = "BT_PW_";
+
+/// Errors that can occur while resolving a password.
+#[derive(Debug, Error)]
+pub enum PasswordError {
+    #[error("Password environment variable not set: {0}")]
+    EnvNotSet(String),
+
+    #[error("Failed to read password file {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+
+    #[error("Password file {0} is accessible by other users (mode {1:o}), expected 0600")]
+    InsecurePermissions(PathBuf, u32),
+
+    #[error("Password prompt failed: {0}")]
+    Prompt(String),
+}
+
+/// Where to obtain the password of an encrypted keyfile.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PasswordSource {
+    /// Read the password from the named environment variable
+    Env(String),
+    /// Read the password from a file that only its owner can access
+    File(PathBuf),
+    /// Prompt for the password on the terminal
+    Prompt,
+    /// No password
+    #[default]
+    None,
+}
+
+impl PasswordSource {
+    /// Get the environment variable holding a wallet's coldkey password.
+    ///
+    /// # Example
+    /// ```
+    /// use bittensor_rs::wallet::PasswordSource;
+    /// assert_eq!(PasswordSource::coldkey_env_var("my-wallet"), "BT_PW_MY_WALLET");
+    /// ```
+    pub fn coldkey_env_var(wallet_name: &str) -> String {
+        format!("{}{}", PASSWORD_ENV_PREFIX, env_var_component(wallet_name))
+    }
+
+    /// Get the environment variable holding a hotkey password.
+    pub fn hotkey_env_var(wallet_name: &str, hotkey_name: &str) -> String {
+        format!(
+            "{}_HOTKEY_{}",
+            Self::coldkey_env_var(wallet_name),
+            env_var_component(hotkey_name)
+        )
+    }
+
+    /// Select a password source in the order file > environment > prompt.
+    ///
+    /// If `password_file` is a directory, the file named `env_var` inside it
+    /// is used when it exists.
+    ///
+    /// # Arguments
+    /// * `password_file` - Password file or directory given explicitly (e.g. `--password-file`)
+    /// * `env_var` - Environment variable to use if it is set
+    pub fn select(password_file: Option<&Path>, env_var: &str) -> Self {
+        match password_file {
+            Some(dir) if dir.is_dir() => {
+                let path = dir.join(env_var);
+                if path.is_file() {
+                    return PasswordSource::File(path);
+                }
+            }
+            Some(path) => return PasswordSource::File(path.to_path_buf()),
+            None => {}
+        }
+        if std::env::var_os(env_var).is_some() {
+            return PasswordSource::Env(env_var.to_string());
+        }
+        PasswordSource::Prompt
+    }
+
+    /// Obtain the password from this source.
+    ///
+    /// An empty password is treated as no password.
+    ///
+    /// # Arguments
+    /// * `prompt` - Message shown when prompting interactively
+    ///
+    /// # Returns
+    /// The password, or None if there is none.
+    pub fn resolve(&self, prompt: &str) -> Result<Option<String>, PasswordError> {
+        let password = match self {
+            PasswordSource::Env(var) => {
+                std::env::var(var).map_err(|_| PasswordError::EnvNotSet(var.clone()))?
+            }
+            PasswordSource::File(path) => read_password_file(path)?,
+            PasswordSource::Prompt => dialoguer::Password::new()
+                .with_prompt(prompt)
+                .allow_empty_password(true)
+                .interact()
+                .map_err(|e| PasswordError::Prompt(e.to_string()))?,
+            PasswordSource::None => return Ok(None),
+        };
+
+        Ok(Some(password).filter(|p| !p.is_empty()))
+    }
+}
+
+/// Read a password file, rejecting files other users can access.
+fn read_password_file(path: &Path) -> Result<String, PasswordError> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let metadata = fs::metadata(path).map_err(|e| PasswordError::Io(path.to_path_buf(), e))?;
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            return Err(PasswordError::InsecurePermissions(path.to_path_buf(), mode));
+        }
+    }
+
+    let content = fs::read_to_string(path).map_err(|e| PasswordError::Io(path.to_path_buf(), e))?;
+    Ok(content.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Upper-case a name for use in an environment variable name.
+fn env_var_component(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_env_var_names() {
+        assert_eq!(PasswordSource::coldkey_env_var("default"), "BT_PW_DEFAULT");
+        assert_eq!(
+            PasswordSource::coldkey_env_var("my.wallet-1"),
+            "BT_PW_MY_WALLET_1"
+        );
+        assert_eq!(
+            PasswordSource::hotkey_env_var("validator", "hk0"),
+            "BT_PW_VALIDATOR_HOTKEY_HK0"
+        );
+    }
+
+    #[test]
+    fn test_env_source() {
+        let var = "BT_PW_PASSWORD_TEST_ENV_SOURCE";
+        std::env::set_var(var, "secret");
+        assert_eq!(
+            PasswordSource::select(None, var),
+            PasswordSource::Env(var.to_string())
+        );
+        assert_eq!(
+            PasswordSource::Env(var.to_string()).resolve("").unwrap(),
+            Some("secret".to_string())
+        );
+
+        std::env::remove_var(var);
+        assert_eq!(PasswordSource::select(None, var), PasswordSource::Prompt);
+        assert!(matches!(
+            PasswordSource::Env(var.to_string()).resolve(""),
+            Err(PasswordError::EnvNotSet(_))
+        ));
+    }
+
+    #[test]
+    fn test_file_source_takes_precedence() {
+        let var = "BT_PW_PASSWORD_TEST_FILE_PRECEDENCE";
+        std::env::set_var(var, "from-env");
+        let path = Path::new("/run/secrets/password");
+        assert_eq!(
+            PasswordSource::select(Some(path), var),
+            PasswordSource::File(path.to_path_buf())
+        );
+        std::env::remove_var(var);
+    }
+
+    #[test]
+    fn test_password_directory_holds_one_file_per_key() {
+        let dir = tempdir().unwrap();
+        let coldkey = PasswordSource::coldkey_env_var("validator");
+        let hotkey = PasswordSource::hotkey_env_var("validator", "default");
+        fs::write(dir.path().join(&coldkey), "coldkey-secret").unwrap();
+
+        assert_eq!(
+            PasswordSource::select(Some(dir.path()), &coldkey),
+            PasswordSource::File(dir.path().join(&coldkey))
+        );
+        // Keys without a file fall back to the environment, then a prompt
+        assert_eq!(
+            PasswordSource::select(Some(dir.path()), &hotkey),
+            PasswordSource::Prompt
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_password_file_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("password");
+        fs::write(&path, "secret\n").unwrap();
+        let source = PasswordSource::File(path.clone());
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+        assert_eq!(source.resolve("").unwrap(), Some("secret".to_string()));
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o400)).unwrap();
+        assert_eq!(source.resolve("").unwrap(), Some("secret".to_string()));
+
+        for mode in [0o644, 0o640, 0o604, 0o660] {
+            fs::set_permissions(&path, fs::Permissions::from_mode(mode)).unwrap();
+            assert!(matches!(
+                source.resolve(""),
+                Err(PasswordError::InsecurePermissions(_, m)) if m == mode
+            ));
+        }
+    }
+
+    #[test]
+    fn test_missing_password_file() {
+        let dir = tempdir().unwrap();
+        let source = PasswordSource::File(dir.path().join("missing"));
+        assert!(matches!(source.resolve(""), Err(PasswordError::Io(_, _))));
+    }
+
+    #[test]
+    fn test_none_source() {
+        assert_eq!(PasswordSource::None.resolve("").unwrap(), None);
+        assert_eq!(PasswordSource::default(), PasswordSource::None);
+    }
+}
@@ -0,0 +1,103 @@
+//! Wrappers for secret key material.
+//!
+//! [`Secret`] holds a mnemonic phrase or seed so it cannot end up in logs or
+//! error messages by accident: `Debug` and `Display` print `[REDACTED]`, and
+//! the value is zeroized on drop. Read it with [`Secret::expose_secret`].
+
+use std::fmt;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Text printed in place of a secret
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A secret value, zeroized on drop and never printed.
+///
+/// # Example
+/// ```
+/// use bittensor_rs::wallet::Mnemonic;
+///
+/// let mnemonic = Mnemonic::generate();
+/// assert_eq!(format!("{}", mnemonic.phrase()), "[REDACTED]");
+/// assert_eq!(mnemonic.phrase().expose_secret().split(' ').count(), 12);
+/// ```
+pub struct Secret<T: Zeroize>(T);
+
+/// A secret string, such as a mnemonic phrase
+pub type SecretString = Secret<String>;
+
+impl<T: Zeroize> Secret<T> {
+    /// Wrap a secret value.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Access the secret value.
+    ///
+    /// Avoid formatting the result into errors or log messages.
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+
+    /// Mutable access to the secret value, e.g. to fill it in place.
+    pub fn expose_secret_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Zeroize + Clone> Clone for Secret<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Zeroize + PartialEq> PartialEq for Secret<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Zeroize + Eq> Eq for Secret<T> {}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for Secret<T> {}
+
+impl<T: Zeroize> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl<T: Zeroize> fmt::Display for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_is_redacted() {
+        let secret = SecretString::new("correct horse battery staple".to_string());
+        assert_eq!(format!("{}", secret), REDACTED);
+        assert_eq!(format!("{:?}", secret), REDACTED);
+        assert_eq!(format!("{:?}", Some(secret.clone())), "Some([REDACTED])");
+        assert_eq!(secret.expose_secret(), "correct horse battery staple");
+
+        let seed = Secret::new([7u8; 32]);
+        assert!(!format!("{:?}", seed).contains('7'));
+        assert_eq!(seed.expose_secret(), &[7u8; 32]);
+    }
+}
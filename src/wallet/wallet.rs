@@ -19,11 +19,11 @@
 //!           └── <hotkey_name> # Encrypted hotkey
 //! ```
 
-use crate::wallet::keyfile::{Keyfile, KeyfileError};
+use crate::wallet::keyfile::{write_atomic, Keyfile, KeyfileError};
 use crate::wallet::keypair::{Keypair, KeypairError};
 use crate::wallet::mnemonic::{Mnemonic, MnemonicError};
+use crate::wallet::password::{PasswordError, PasswordSource};
 use std::fs;
-use std::io::Write;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -67,6 +67,9 @@ pub enum WalletError {
     #[error("Mnemonic error: {0}")]
     Mnemonic(#[from] MnemonicError),
 
+    #[error("Password error: {0}")]
+    Password(#[from] PasswordError),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -133,6 +136,8 @@ pub struct Wallet {
     pub hotkey_name: String,
     coldkey: Keyfile,
     hotkey: Keyfile,
+    backup_existing: bool,
+    backups: Vec<PathBuf>,
 }
 
 impl std::fmt::Debug for Wallet {
@@ -185,9 +190,22 @@ impl Wallet {
             hotkey_name: hotkey.to_string(),
             coldkey: Keyfile::new(coldkey_path),
             hotkey: Keyfile::new(hotkey_path),
+            backup_existing: false,
+            backups: Vec::new(),
         })
     }
 
+    /// Back up keyfiles to `<name>.bak.<timestamp>` before overwriting them.
+    pub fn with_backup_existing(mut self, backup_existing: bool) -> Self {
+        self.backup_existing = backup_existing;
+        self
+    }
+
+    /// Backups written by this handle, oldest first.
+    pub fn backups(&self) -> &[PathBuf] {
+        &self.backups
+    }
+
     /// Create a new wallet with both coldkey and hotkey.
     ///
     /// # Arguments
@@ -262,14 +280,20 @@ impl Wallet {
         let keypair = Keypair::from_mnemonic_obj(&mnemonic_obj, password)?;
 
         // Store the mnemonic phrase before potentially moving it
-        let phrase = provided_phrase.unwrap_or_else(|| mnemonic_obj.phrase().to_string());
+        let phrase =
+            provided_phrase.unwrap_or_else(|| mnemonic_obj.phrase().expose_secret().clone());
 
         // Ensure wallet directory exists
         fs::create_dir_all(&self.path)?;
 
         // Save coldkey
-        self.coldkey
-            .set_keypair(keypair.clone(), password, overwrite)?;
+        let backup = self.coldkey.set_keypair_with_backup(
+            keypair.clone(),
+            password,
+            overwrite,
+            self.backup_existing,
+        )?;
+        self.backups.extend(backup);
 
         // Save public key file
         self.save_coldkey_pub(&keypair)?;
@@ -298,18 +322,76 @@ impl Wallet {
         };
 
         let keypair = Keypair::from_mnemonic_obj(&mnemonic_obj, password)?;
-        let phrase = provided_phrase.unwrap_or_else(|| mnemonic_obj.phrase().to_string());
-
-        // Ensure hotkeys directory exists
-        let hotkeys_dir = self.path.join(HOTKEYS_DIR);
-        fs::create_dir_all(&hotkeys_dir)?;
+        let phrase =
+            provided_phrase.unwrap_or_else(|| mnemonic_obj.phrase().expose_secret().clone());
 
-        // Save hotkey
-        self.hotkey.set_keypair(keypair, password, overwrite)?;
+        self.save_hotkey(keypair, password, overwrite)?;
 
         Ok(phrase)
     }
 
+    /// Create the hotkey by deriving it from the coldkey.
+    ///
+    /// The hotkey is derived with the path `//<hotkey_name>//<index>`, so the coldkey
+    /// mnemonic alone is enough to recover every derived hotkey. The result matches
+    /// `subkey inspect "<coldkey mnemonic>//<hotkey_name>//<index>"`, and the path is
+    /// recorded in the hotkey keyfile.
+    ///
+    /// # Arguments
+    /// * `index` - The hotkey index
+    /// * `password` - Password of the coldkey, also used to encrypt the hotkey
+    /// * `overwrite` - Whether to overwrite an existing hotkey
+    ///
+    /// # Returns
+    /// The derivation path used.
+    pub fn create_hotkey_derived(
+        &mut self,
+        index: u32,
+        password: Option<&str>,
+        overwrite: bool,
+    ) -> Result<String, WalletError> {
+        let path = format!("//{}//{}", self.hotkey_name, index);
+        let keypair = self.coldkey_keypair(password)?.derive(&path)?;
+        self.save_hotkey(keypair, password, overwrite)?;
+        Ok(path)
+    }
+
+    /// Recreate a derived hotkey from the coldkey mnemonic and its derivation path.
+    ///
+    /// # Arguments
+    /// * `mnemonic` - The coldkey mnemonic phrase
+    /// * `derivation_path` - The recorded derivation path (e.g. `//default//0`)
+    /// * `password` - Optional password for derivation and encryption
+    /// * `overwrite` - Whether to overwrite an existing hotkey
+    pub fn create_hotkey_from_path(
+        &mut self,
+        mnemonic: &str,
+        derivation_path: &str,
+        password: Option<&str>,
+        overwrite: bool,
+    ) -> Result<(), WalletError> {
+        let keypair = Keypair::from_mnemonic(mnemonic, password)?.derive(derivation_path)?;
+        self.save_hotkey(keypair, password, overwrite)
+    }
+
+    /// Store a keypair as the current hotkey, creating the hotkeys directory if needed.
+    fn save_hotkey(
+        &mut self,
+        keypair: Keypair,
+        password: Option<&str>,
+        overwrite: bool,
+    ) -> Result<(), WalletError> {
+        fs::create_dir_all(self.path.join(HOTKEYS_DIR))?;
+        let backup = self.hotkey.set_keypair_with_backup(
+            keypair,
+            password,
+            overwrite,
+            self.backup_existing,
+        )?;
+        self.backups.extend(backup);
+        Ok(())
+    }
+
     /// Get a reference to the coldkey keyfile.
     pub fn coldkey(&self) -> &Keyfile {
         &self.coldkey
@@ -340,6 +422,78 @@ impl Wallet {
             .map_err(WalletError::Keyfile)
     }
 
+    /// Get the coldkey keypair, obtaining the password from a source.
+    ///
+    /// The source is only consulted if the coldkey is encrypted.
+    ///
+    /// # Arguments
+    /// * `source` - Where to obtain the password
+    pub fn coldkey_keypair_with(&self, source: &PasswordSource) -> Result<Keypair, WalletError> {
+        let password = match self.coldkey.is_encrypted() {
+            true => source.resolve("Coldkey password")?,
+            false => None,
+        };
+        self.coldkey_keypair(password.as_deref())
+    }
+
+    /// Get the hotkey keypair, obtaining the password from a source.
+    ///
+    /// The source is only consulted if the hotkey is encrypted.
+    ///
+    /// # Arguments
+    /// * `source` - Where to obtain the password
+    pub fn hotkey_keypair_with(&self, source: &PasswordSource) -> Result<Keypair, WalletError> {
+        let password = match self.hotkey.is_encrypted() {
+            true => source.resolve("Hotkey password")?,
+            false => None,
+        };
+        self.hotkey_keypair(password.as_deref())
+    }
+
+    /// Select the coldkey password source: `password_file` if given, then the
+    /// `BT_PW_<WALLET>` environment variable if set, then an interactive prompt.
+    pub fn coldkey_password_source(&self, password_file: Option<&Path>) -> PasswordSource {
+        PasswordSource::select(password_file, &PasswordSource::coldkey_env_var(&self.name))
+    }
+
+    /// Select the hotkey password source: `password_file` if given, then the
+    /// `BT_PW_<WALLET>_HOTKEY_<HOTKEY>` environment variable if set, then an
+    /// interactive prompt.
+    pub fn hotkey_password_source(&self, password_file: Option<&Path>) -> PasswordSource {
+        PasswordSource::select(
+            password_file,
+            &PasswordSource::hotkey_env_var(&self.name, &self.hotkey_name),
+        )
+    }
+
+    /// Change the coldkey password.
+    ///
+    /// # Arguments
+    /// * `old` - Current password, or None if the coldkey is unencrypted
+    /// * `new` - New password, or None to store the coldkey unencrypted
+    pub fn change_coldkey_password(
+        &mut self,
+        old: Option<&str>,
+        new: Option<&str>,
+    ) -> Result<(), WalletError> {
+        self.coldkey.change_password(old, new)?;
+        Ok(())
+    }
+
+    /// Change the hotkey password.
+    ///
+    /// # Arguments
+    /// * `old` - Current password, or None if the hotkey is unencrypted
+    /// * `new` - New password, or None to store the hotkey unencrypted
+    pub fn change_hotkey_password(
+        &mut self,
+        old: Option<&str>,
+        new: Option<&str>,
+    ) -> Result<(), WalletError> {
+        self.hotkey.change_password(old, new)?;
+        Ok(())
+    }
+
     /// Get the coldkey SS58 address.
     ///
     /// This reads from the coldkeypub.txt file if available, otherwise
@@ -422,6 +576,29 @@ impl Wallet {
         Ok(wallet)
     }
 
+    /// Regenerate a derived hotkey from the coldkey mnemonic.
+    ///
+    /// # Arguments
+    /// * `name` - Wallet name
+    /// * `hotkey_name` - Hotkey name
+    /// * `mnemonic` - The coldkey mnemonic phrase
+    /// * `derivation_path` - The derivation path recorded in the hotkey keyfile
+    /// * `password` - Optional password for derivation and encryption
+    ///
+    /// # Returns
+    /// A wallet handle with the regenerated hotkey.
+    pub fn regenerate_hotkey_derived(
+        name: &str,
+        hotkey_name: &str,
+        mnemonic: &str,
+        derivation_path: &str,
+        password: Option<&str>,
+    ) -> Result<Self, WalletError> {
+        let mut wallet = Self::new(name, hotkey_name, None)?;
+        wallet.create_hotkey_from_path(mnemonic, derivation_path, password, true)?;
+        Ok(wallet)
+    }
+
     /// List all hotkeys for this wallet.
     ///
     /// # Returns
@@ -467,21 +644,12 @@ impl Wallet {
     /// Save the coldkey public address to coldkeypub.txt.
     ///
     /// # Security
-    /// The file is created with restrictive permissions (0o600 on Unix)
-    /// to prevent unauthorized access to the public key.
+    /// The file is written atomically with restrictive permissions (0o600 on
+    /// Unix) to prevent unauthorized access to the public key.
     fn save_coldkey_pub(&self, keypair: &Keypair) -> Result<(), WalletError> {
         let pub_path = self.path.join(COLDKEYPUB_FILENAME);
-        let mut file = fs::File::create(&pub_path)?;
-        writeln!(file, "{}", keypair.ss58_address())?;
-
-        // Set restrictive permissions on Unix (readable by owner only)
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let permissions = fs::Permissions::from_mode(0o600);
-            fs::set_permissions(&pub_path, permissions)?;
-        }
-
+        let content = format!("{}\n", keypair.ss58_address());
+        write_atomic(&pub_path, content.as_bytes(), None)?;
         Ok(())
     }
 }
@@ -624,6 +792,168 @@ mod tests {
         assert_eq!(keypair1.ss58_address(), keypair2.ss58_address());
     }
 
+    #[test]
+    fn test_overwrite_coldkey_with_backup() {
+        let dir = tempdir().unwrap();
+        let base_path = dir.path().to_str().unwrap();
+
+        let mut wallet = Wallet::new("test_wallet", "default", Some(base_path))
+            .unwrap()
+            .with_backup_existing(true);
+        wallet.create_coldkey(None, None, false).unwrap();
+        let original = wallet.coldkey_keypair(None).unwrap();
+        assert!(wallet.backups().is_empty());
+
+        let result = wallet.create_coldkey(None, None, false);
+        assert!(matches!(
+            result,
+            Err(WalletError::Keyfile(KeyfileError::KeyExists(_)))
+        ));
+
+        wallet.create_coldkey(None, None, true).unwrap();
+        assert_eq!(wallet.backups().len(), 1);
+        let backup = Keyfile::new(&wallet.backups()[0])
+            .get_keypair(None)
+            .unwrap();
+        assert_eq!(backup.public_key(), original.public_key());
+    }
+
+    #[test]
+    fn test_create_hotkey_derived() {
+        let dir = tempdir().unwrap();
+        let base_path = dir.path().to_str().unwrap();
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let mut wallet = Wallet::new("test_wallet", "hotkey", Some(base_path)).unwrap();
+        wallet.create_coldkey(None, Some(mnemonic), false).unwrap();
+        let path = wallet.create_hotkey_derived(0, None, false).unwrap();
+        assert_eq!(path, "//hotkey//0");
+
+        // Matches `subkey inspect "<mnemonic>//hotkey//0"`
+        let reloaded = Wallet::new("test_wallet", "hotkey", Some(base_path)).unwrap();
+        let hotkey = reloaded.hotkey_keypair(None).unwrap();
+        assert_eq!(
+            hotkey.ss58_address(),
+            "5D9xPwHpEWWb1TSbQutyd6NrfsNak1BQa7cp4gHUU4eDLWGg"
+        );
+        assert_eq!(hotkey.derivation_path(), Some("//hotkey//0"));
+
+        // The recorded path regenerates the same hotkey from the mnemonic
+        let mut restored = Wallet::new("restored", "hotkey", Some(base_path)).unwrap();
+        restored
+            .create_hotkey_from_path(mnemonic, hotkey.derivation_path().unwrap(), None, false)
+            .unwrap();
+        assert_eq!(restored.hotkey_ss58(None).unwrap(), hotkey.ss58_address());
+
+        // Another index gives another hotkey
+        wallet.use_hotkey("hotkey").unwrap();
+        wallet.create_hotkey_derived(1, None, true).unwrap();
+        assert_ne!(wallet.hotkey_ss58(None).unwrap(), hotkey.ss58_address());
+    }
+
+    #[test]
+    fn test_create_hotkey_derived_encrypted() {
+        let dir = tempdir().unwrap();
+        let base_path = dir.path().to_str().unwrap();
+        let password = "test_password";
+
+        let mut wallet = Wallet::new("test_wallet", "default", Some(base_path)).unwrap();
+        wallet.create_coldkey(Some(password), None, false).unwrap();
+        wallet
+            .create_hotkey_derived(3, Some(password), false)
+            .unwrap();
+
+        let reloaded = Wallet::new("test_wallet", "default", Some(base_path)).unwrap();
+        let hotkey = reloaded.hotkey_keypair(Some(password)).unwrap();
+        assert_eq!(hotkey.derivation_path(), Some("//default//3"));
+    }
+
+    #[test]
+    fn test_change_coldkey_password() {
+        let dir = tempdir().unwrap();
+        let base_path = dir.path().to_str().unwrap();
+
+        let mut wallet =
+            Wallet::create_at_path("test_wallet", "default", base_path, Some("old")).unwrap();
+        let address = wallet.coldkey_ss58(Some("old")).unwrap();
+
+        assert!(wallet
+            .change_coldkey_password(Some("wrong"), Some("new"))
+            .is_err());
+        wallet
+            .change_coldkey_password(Some("old"), Some("new"))
+            .unwrap();
+
+        let reloaded = Wallet::new("test_wallet", "default", Some(base_path)).unwrap();
+        assert!(reloaded.coldkey_keypair(Some("old")).is_err());
+        assert_eq!(
+            reloaded
+                .coldkey_keypair(Some("new"))
+                .unwrap()
+                .ss58_address(),
+            address
+        );
+        // The hotkey keeps its own password
+        assert!(reloaded.hotkey_keypair(Some("old")).is_ok());
+    }
+
+    #[test]
+    fn test_keypair_with_password_source() {
+        let dir = tempdir().unwrap();
+        let base_path = dir.path().to_str().unwrap();
+        let password = "test_password";
+
+        let wallet =
+            Wallet::create_at_path("source_wallet", "default", base_path, Some(password)).unwrap();
+        let address = wallet.coldkey_ss58(Some(password)).unwrap();
+
+        let password_file = dir.path().join("password");
+        fs::write(&password_file, format!("{}\n", password)).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&password_file, fs::Permissions::from_mode(0o600)).unwrap();
+        }
+
+        let reloaded = Wallet::new("source_wallet", "default", Some(base_path)).unwrap();
+        let source = reloaded.coldkey_password_source(Some(&password_file));
+        assert_eq!(source, PasswordSource::File(password_file.clone()));
+        assert_eq!(
+            reloaded
+                .coldkey_keypair_with(&source)
+                .unwrap()
+                .ss58_address(),
+            address
+        );
+        assert!(reloaded.hotkey_keypair_with(&source).is_ok());
+
+        // Files readable by other users are rejected
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&password_file, fs::Permissions::from_mode(0o644)).unwrap();
+            assert!(matches!(
+                reloaded.coldkey_keypair_with(&source),
+                Err(WalletError::Password(PasswordError::InsecurePermissions(
+                    _,
+                    _
+                )))
+            ));
+        }
+
+        let env_var = PasswordSource::coldkey_env_var("source_wallet");
+        std::env::set_var(&env_var, password);
+        let source = reloaded.coldkey_password_source(None);
+        assert_eq!(source, PasswordSource::Env(env_var.clone()));
+        assert!(reloaded.coldkey_keypair_with(&source).is_ok());
+        std::env::remove_var(&env_var);
+
+        // Unencrypted keys never consult the source
+        let plain = Wallet::create_at_path("plain_wallet", "default", base_path, None).unwrap();
+        let missing = PasswordSource::Env("BT_PW_WALLET_TEST_UNSET".to_string());
+        assert!(plain.coldkey_keypair_with(&missing).is_ok());
+    }
+
     #[test]
     fn test_wallet_list_hotkeys() {
         let dir = tempdir().unwrap();
@@ -0,0 +1,138 @@
+//! Axon blacklist store and admin routes
+//!
+//! These start a local Axon on an ephemeral port with a [`BlacklistStore`]
+//! and change its bans through `/admin/blacklist` while it serves. No chain
+//! connection is required.
+
+use bittensor_rs::axon::{Axon, AxonConfig, AxonHandle, BlacklistStore};
+use bittensor_rs::dendrite::request::header_names;
+use bittensor_rs::wallet::Keypair;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+const BOB: &str = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+const ADMIN_TOKEN: &str = "test-admin-token";
+
+async fn serve(store: Option<Arc<BlacklistStore>>) -> AxonHandle {
+    let keypair = Keypair::from_uri("//Alice").expect("Failed to create test keypair");
+    let config = AxonConfig::new()
+        .with_ip("127.0.0.1")
+        .with_port(0)
+        .with_signature_verification(false);
+    let mut axon = Axon::new(keypair, config);
+    axon.attach("Query", |synapse| async move { synapse });
+    if let Some(store) = store {
+        axon.set_blacklist_store(store);
+    }
+    axon.serve_admin(ADMIN_TOKEN);
+    axon.serve().await.expect("Failed to start axon")
+}
+
+async fn query(handle: &AxonHandle) -> u16 {
+    reqwest::Client::new()
+        .post(format!("http://{}/Query", handle.local_addr()))
+        .header(header_names::NAME, "Query")
+        .header(header_names::DENDRITE_HOTKEY, BOB)
+        .body("{}")
+        .send()
+        .await
+        .unwrap()
+        .status()
+        .as_u16()
+}
+
+async fn admin(handle: &AxonHandle, body: Option<Value>) -> (u16, Value) {
+    admin_with_token(handle, Some(ADMIN_TOKEN), body).await
+}
+
+async fn admin_with_token(
+    handle: &AxonHandle,
+    token: Option<&str>,
+    body: Option<Value>,
+) -> (u16, Value) {
+    let client = reqwest::Client::new();
+    let url = format!("http://{}/admin/blacklist", handle.local_addr());
+    let mut request = match body {
+        Some(body) => client.post(url).json(&body),
+        None => client.get(url),
+    };
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().await.unwrap();
+    let status = response.status().as_u16();
+    (status, response.json().await.unwrap())
+}
+
+#[tokio::test]
+async fn test_admin_routes_manage_bans_while_serving() {
+    let store = Arc::new(BlacklistStore::new());
+    let handle = serve(Some(store.clone())).await;
+    assert_eq!(query(&handle).await, 200);
+
+    let (status, body) = admin(&handle, Some(json!({ "action": "ban", "hotkey": BOB }))).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["entries"][0]["kind"], "hotkey");
+    assert_eq!(body["entries"][0]["value"], BOB);
+    assert_eq!(query(&handle).await, 403);
+
+    let (status, _) = admin(&handle, Some(json!({ "action": "unban", "hotkey": BOB }))).await;
+    assert_eq!(status, 200);
+    assert_eq!(query(&handle).await, 200);
+
+    // Without proxy headers the peer address is checked
+    let (status, body) = admin(
+        &handle,
+        Some(json!({ "action": "ban", "ip": "127.0.0.1", "ttl_secs": 3600 })),
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert!(body["entries"][0]["expires_at_ms"].is_u64());
+    assert_eq!(query(&handle).await, 403);
+    assert!(store.is_ip_banned(&"127.0.0.1".parse().unwrap()));
+
+    let (status, body) = admin(&handle, None).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["entries"].as_array().unwrap().len(), 1);
+
+    let (status, body) = admin(&handle, Some(json!({ "action": "ban" }))).await;
+    assert_eq!(status, 400);
+    assert!(body["error"].is_string());
+
+    handle.shutdown();
+    handle.await_terminated().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_admin_routes_require_token() {
+    let store = Arc::new(BlacklistStore::new());
+    let handle = serve(Some(store.clone())).await;
+    let ban = json!({ "action": "ban", "hotkey": BOB });
+
+    for token in [None, Some("wrong-token")] {
+        let (status, body) = admin_with_token(&handle, token, Some(ban.clone())).await;
+        assert_eq!(status, 401);
+        assert!(body["error"].is_string());
+        let (status, _) = admin_with_token(&handle, token, None).await;
+        assert_eq!(status, 401);
+    }
+    assert!(!store.is_hotkey_banned(BOB));
+
+    let (status, _) = admin(&handle, Some(ban)).await;
+    assert_eq!(status, 200);
+    assert!(store.is_hotkey_banned(BOB));
+
+    handle.shutdown();
+    handle.await_terminated().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_admin_blacklist_requires_store() {
+    let handle = serve(None).await;
+    let (status, body) = admin(&handle, None).await;
+    assert_eq!(status, 404);
+    assert!(body["error"].is_string());
+
+    handle.shutdown();
+    handle.await_terminated().await.unwrap();
+}
@@ -0,0 +1,189 @@
+//! Axon body hash verification
+//!
+//! These start local Axons on ephemeral ports and send signed requests whose
+//! body may differ from the one the `computed_body_hash` header was made
+//! for. No chain connection is required.
+
+use axum::body::Bytes;
+use axum::http::{HeaderMap, HeaderValue};
+use bittensor_rs::axon::{compute_synapse_body_hash, Axon, AxonConfig, AxonHandle};
+use bittensor_rs::dendrite::request::{create_signature_message, header_names, sign_message};
+use bittensor_rs::wallet::Keypair;
+use sp_core::crypto::Ss58Codec;
+use sp_core::{sr25519, Pair};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+const UUID: &str = "6f1e2d3c-1111-11ef-9c1a-0242ac120002";
+
+async fn serve(config: AxonConfig) -> AxonHandle {
+    let keypair = Keypair::from_uri("//Alice").expect("Failed to create test keypair");
+    let mut axon = Axon::new(keypair, config.with_ip("127.0.0.1").with_port(0));
+    axon.attach("Query", |synapse| async move { synapse });
+    axon.attach("Dummy", |synapse| async move { synapse });
+    axon.attach_streaming("Count", |_synapse| {
+        futures::stream::iter((0..3).map(|i| Bytes::from(format!("chunk-{}\n", i))))
+    });
+    axon.serve().await.expect("Failed to start axon")
+}
+
+fn now_ns() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64
+}
+
+/// Python body hash over the `required_hash_fields` a body lists
+fn listed_hash(body: &[u8]) -> String {
+    let value: serde_json::Value = serde_json::from_slice(body).unwrap();
+    let fields: Vec<&str> = value["required_hash_fields"]
+        .as_array()
+        .map(|fields| fields.iter().filter_map(|f| f.as_str()).collect())
+        .unwrap_or_default();
+    compute_synapse_body_hash(body, &fields)
+}
+
+/// Headers for a `name` request from `//Bob` signed over `body`
+fn signed_headers(name: &str, body: &[u8]) -> HeaderMap {
+    signed_headers_with_hash(name, &listed_hash(body))
+}
+
+/// Headers for a `name` request from `//Bob` signed over `body_hash`
+fn signed_headers_with_hash(name: &str, body_hash: &str) -> HeaderMap {
+    let pair = sr25519::Pair::from_string("//Bob", None).unwrap();
+    let hotkey = pair.public().to_ss58check();
+    let nonce = now_ns();
+    let message = create_signature_message(nonce, &hotkey, ALICE, UUID, body_hash);
+
+    let mut headers = HeaderMap::new();
+    for (header, value) in [
+        (header_names::NAME, name.to_string()),
+        (header_names::DENDRITE_NONCE, nonce.to_string()),
+        (header_names::DENDRITE_UUID, UUID.to_string()),
+        (header_names::DENDRITE_HOTKEY, hotkey),
+        (header_names::BODY_HASH, body_hash.to_string()),
+        (
+            header_names::DENDRITE_SIGNATURE,
+            sign_message(&pair, message.as_bytes()),
+        ),
+    ] {
+        headers.insert(header, HeaderValue::from_str(&value).unwrap());
+    }
+    headers
+}
+
+/// Send a request, returning its status, status message and body
+async fn send(
+    handle: &AxonHandle,
+    route: &str,
+    headers: HeaderMap,
+    body: &[u8],
+) -> (u16, String, String) {
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/{}", handle.local_addr(), route))
+        .headers(headers)
+        .body(body.to_vec())
+        .send()
+        .await
+        .unwrap();
+    let status = response.status().as_u16();
+    let message = response
+        .headers()
+        .get(header_names::AXON_STATUS_MESSAGE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    (status, message, response.text().await.unwrap())
+}
+
+#[tokio::test]
+async fn test_axon_rejects_tampered_body() {
+    let handle = serve(AxonConfig::new()).await;
+    let body = br#"{"query": "ping", "required_hash_fields": ["query"]}"#;
+
+    let (status, _, _) = send(&handle, "Query", signed_headers("Query", body), body).await;
+    assert_eq!(status, 200);
+
+    // Body replaced after signing, headers kept
+    let headers = signed_headers("Query", body);
+    let tampered = br#"{"query": "pong", "required_hash_fields": ["query"]}"#;
+    let (status, message, _) = send(&handle, "Query", headers, tampered).await;
+    assert_eq!((status, message.as_str()), (400, "Body hash mismatch"));
+
+    handle.shutdown();
+}
+
+#[tokio::test]
+async fn test_streaming_requests_check_body_hash() {
+    let handle = serve(AxonConfig::new()).await;
+    let body = br#"{"count": 3, "required_hash_fields": ["count"]}"#;
+
+    let (status, _, chunks) = send(&handle, "Count", signed_headers("Count", body), body).await;
+    assert_eq!(status, 200);
+    assert_eq!(chunks, "chunk-0\nchunk-1\nchunk-2\n");
+
+    let headers = signed_headers("Count", body);
+    let tampered = br#"{"count": 4, "required_hash_fields": ["count"]}"#;
+    let (status, message, _) = send(&handle, "Count", headers, tampered).await;
+    assert_eq!((status, message.as_str()), (400, "Body hash mismatch"));
+
+    handle.shutdown();
+}
+
+#[tokio::test]
+async fn test_empty_body_hash_escape_hatch() {
+    let body = br#"{"query": "ping", "required_hash_fields": ["query"]}"#;
+    let unhashed = || signed_headers_with_hash("Query", "");
+
+    let strict = serve(AxonConfig::new()).await;
+    let (status, message, _) = send(&strict, "Query", unhashed(), body).await;
+    assert_eq!((status, message.as_str()), (400, "Body hash mismatch"));
+    strict.shutdown();
+
+    let lenient = serve(AxonConfig::new().with_empty_body_hash_allowed(true)).await;
+    let (status, _, _) = send(&lenient, "Query", unhashed(), body).await;
+    assert_eq!(status, 200);
+
+    // A hash that is sent must still match
+    let headers = signed_headers("Query", body);
+    let tampered = br#"{"query": "pong", "required_hash_fields": ["query"]}"#;
+    let (status, _, _) = send(&lenient, "Query", headers, tampered).await;
+    assert_eq!(status, 400);
+    lenient.shutdown();
+}
+
+/// A `Dummy(dummy_input=5)` request body as the Python SDK's dendrite sends it
+/// (`synapse.model_dump()`); its synapse lists no `required_hash_fields`
+const PYTHON_DUMMY_BODY: &str = r#"{"name": "Dummy", "timeout": 12.0, "total_size": 0, "header_size": 0, "dendrite": {"status_code": null, "status_message": null, "process_time": null, "ip": "127.0.0.1", "port": null, "version": 9100, "nonce": 1718000000000000000, "uuid": "6f1e2d3c-1111-11ef-9c1a-0242ac120002", "hotkey": "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty", "signature": null}, "axon": {"status_code": null, "status_message": null, "process_time": null, "ip": "127.0.0.1", "port": 8091, "version": null, "nonce": null, "uuid": null, "hotkey": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", "signature": null}, "computed_body_hash": "", "dummy_input": 5, "dummy_output": null}"#;
+
+#[tokio::test]
+async fn test_python_format_requests() {
+    // Python: Dummy(dummy_input=5).body_hash == sha3_256(b"").hexdigest()
+    let empty = "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a";
+    let handle = serve(AxonConfig::new()).await;
+    let body = PYTHON_DUMMY_BODY.as_bytes();
+    assert_eq!(listed_hash(body), empty);
+
+    let mut headers = signed_headers_with_hash("Dummy", empty);
+    headers.insert(header_names::TIMEOUT, HeaderValue::from_static("12.0"));
+    let (status, _, response) = send(&handle, "Dummy", headers, body).await;
+    assert_eq!(status, 200);
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(response["dummy_input"], 5);
+
+    // Older SDKs serialize the hash fields into the body
+    let mut value: serde_json::Value = serde_json::from_slice(body).unwrap();
+    value["required_hash_fields"] = serde_json::json!(["dummy_input"]);
+    let body = serde_json::to_vec(&value).unwrap();
+    let headers = signed_headers("Dummy", &body);
+    let (status, _, _) = send(&handle, "Dummy", headers, &body).await;
+    assert_eq!(status, 200);
+
+    // Signing the empty-fields hash does not cover a listed field
+    let headers = signed_headers_with_hash("Dummy", empty);
+    let (status, message, _) = send(&handle, "Dummy", headers, &body).await;
+    assert_eq!((status, message.as_str()), (400, "Body hash mismatch"));
+
+    handle.shutdown();
+}
@@ -0,0 +1,171 @@
+//! Axon body limits and request/response compression
+//!
+//! These start local Axons on ephemeral ports and call them with a Dendrite
+//! or a raw HTTP client. No chain connection is required.
+
+use bittensor_rs::axon::{Axon, AxonConfig, AxonHandle, ContentEncoding};
+use bittensor_rs::dendrite::{Dendrite, DendriteConfig};
+use bittensor_rs::types::Synapse;
+use bittensor_rs::wallet::Keypair;
+use sp_core::{sr25519, Pair};
+
+async fn serve(config: AxonConfig) -> AxonHandle {
+    let keypair = Keypair::from_uri("//Alice").expect("Failed to create test keypair");
+    let mut axon = Axon::new(keypair, config.with_ip("127.0.0.1").with_port(0));
+    axon.attach("Echo", |mut synapse| async move {
+        let input = synapse.get_field("input").cloned().unwrap_or_default();
+        synapse.set_field("output", input);
+        synapse
+    });
+    axon.serve().await.expect("Failed to start axon")
+}
+
+fn dendrite(encoding: Option<ContentEncoding>) -> Dendrite {
+    let pair = sr25519::Pair::from_string("//Bob", None).unwrap();
+    let config = DendriteConfig::new().with_request_compression(encoding);
+    Dendrite::with_config(Some(pair), config).unwrap()
+}
+
+fn echo(input: &str) -> Synapse {
+    let mut synapse = Synapse::new().with_name("Echo");
+    synapse.set_field("input", serde_json::json!(input));
+    synapse
+}
+
+fn status_code(synapse: &Synapse) -> Option<i32> {
+    synapse.dendrite.as_ref().and_then(|d| d.status_code)
+}
+
+#[tokio::test]
+async fn test_signed_compressed_requests_verify() {
+    let handle = serve(AxonConfig::new()).await;
+    let info = handle.info(0).unwrap();
+    let input = "the quick brown fox ".repeat(500);
+
+    for encoding in [
+        None,
+        Some(ContentEncoding::Gzip),
+        Some(ContentEncoding::Zstd),
+    ] {
+        let response = dendrite(encoding).call(&info, echo(&input)).await.unwrap();
+        assert!(response.is_success(), "{:?}: {:?}", encoding, response);
+        assert_eq!(
+            response.get_field("output"),
+            Some(&serde_json::json!(input)),
+            "{:?}",
+            encoding
+        );
+    }
+
+    handle.shutdown();
+}
+
+#[tokio::test]
+async fn test_oversized_bodies_rejected() {
+    let handle = serve(
+        AxonConfig::new()
+            .with_signature_verification(false)
+            .with_max_body_bytes(16 * 1024),
+    )
+    .await;
+    let info = handle.info(0).unwrap();
+    // Compresses to well under the limit but inflates past it
+    let input = "a".repeat(64 * 1024);
+
+    for encoding in [
+        None,
+        Some(ContentEncoding::Gzip),
+        Some(ContentEncoding::Zstd),
+    ] {
+        let response = dendrite(encoding).call(&info, echo(&input)).await.unwrap();
+        assert_eq!(status_code(&response), Some(413), "{:?}", encoding);
+    }
+
+    // Small requests still go through
+    let response = dendrite(Some(ContentEncoding::Gzip))
+        .call(&info, echo("hello"))
+        .await
+        .unwrap();
+    assert!(response.is_success());
+
+    handle.shutdown();
+}
+
+#[tokio::test]
+async fn test_unknown_encoding_rejected() {
+    let handle = serve(AxonConfig::new().with_signature_verification(false)).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/Echo", handle.local_addr()))
+        .header("content-encoding", "br")
+        .body("{}")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status().as_u16(), 415);
+
+    handle.shutdown();
+}
+
+#[tokio::test]
+async fn test_responses_compressed_on_request() {
+    let handle = serve(AxonConfig::new().with_signature_verification(false)).await;
+    let url = format!("http://{}/Echo", handle.local_addr());
+    let body = serde_json::to_vec(&echo(&"response ".repeat(200))).unwrap();
+    // Leave decoding to the test so the Content-Encoding header is visible
+    let client = reqwest::Client::builder()
+        .no_gzip()
+        .no_zstd()
+        .build()
+        .unwrap();
+
+    for encoding in ["gzip", "zstd"] {
+        let response = client
+            .post(&url)
+            .header("accept-encoding", encoding)
+            .body(body.clone())
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        assert_eq!(response.headers()["content-encoding"], encoding);
+
+        let compressed = response.bytes().await.unwrap();
+        let decoded = ContentEncoding::from_header(encoding)
+            .unwrap()
+            .decode(&compressed, 1024 * 1024)
+            .unwrap();
+        let synapse: Synapse = serde_json::from_slice(&decoded).unwrap();
+        assert!(synapse.get_field("output").is_some());
+    }
+
+    let response = client.post(&url).body(body).send().await.unwrap();
+    assert!(response.headers().get("content-encoding").is_none());
+
+    handle.shutdown();
+}
+
+#[tokio::test]
+async fn test_compression_can_be_disabled() {
+    let handle = serve(
+        AxonConfig::new()
+            .with_signature_verification(false)
+            .with_compression(false),
+    )
+    .await;
+    let body = serde_json::to_vec(&echo(&"response ".repeat(200))).unwrap();
+
+    let response = reqwest::Client::builder()
+        .no_gzip()
+        .build()
+        .unwrap()
+        .post(format!("http://{}/Echo", handle.local_addr()))
+        .header("accept-encoding", "gzip")
+        .body(body)
+        .send()
+        .await
+        .unwrap();
+    assert!(response.headers().get("content-encoding").is_none());
+
+    handle.shutdown();
+}
@@ -0,0 +1,128 @@
+//! Request deadlines passed from the Axon to its handlers
+//!
+//! These start a local Axon with deliberately slow handlers and call it over
+//! plain HTTP with a short timeout header. No chain connection is required.
+
+use axum::body::Bytes;
+use bittensor_rs::axon::streaming::deadline_error_chunk;
+use bittensor_rs::axon::{Axon, AxonConfig, AxonHandle};
+use bittensor_rs::dendrite::request::header_names;
+use bittensor_rs::wallet::Keypair;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Sets its flag when dropped, i.e. when the handler is cancelled
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Start an axon with a 200ms deadline margin
+///
+/// "Slow" never finishes in time, "Budget" reports the time it was given and
+/// "Ticker" streams forever. The flags are set when "Slow" and "Ticker" are
+/// dropped.
+async fn start_axon() -> (AxonHandle, Arc<AtomicBool>, Arc<AtomicBool>) {
+    let keypair = Keypair::from_uri("//Alice").expect("Failed to create test keypair");
+    let config = AxonConfig::new()
+        .with_ip("127.0.0.1")
+        .with_port(0)
+        .with_signature_verification(false)
+        .with_deadline_margin(200);
+    let mut axon = Axon::new(keypair, config);
+
+    let slow_dropped = Arc::new(AtomicBool::new(false));
+    let flag = slow_dropped.clone();
+    axon.attach("Slow", move |synapse| {
+        let guard = DropFlag(flag.clone());
+        async move {
+            tokio::time::sleep(Duration::from_secs(30)).await;
+            drop(guard);
+            synapse
+        }
+    });
+
+    axon.attach_with_context("Budget", |mut synapse, ctx| async move {
+        let remaining = ctx.remaining().as_millis() as u64;
+        synapse.set_field("remaining_ms", serde_json::json!(remaining));
+        synapse
+    });
+
+    let ticker_dropped = Arc::new(AtomicBool::new(false));
+    let flag = ticker_dropped.clone();
+    axon.attach_streaming_with_context("Ticker", move |_synapse, _ctx| {
+        let guard = DropFlag(flag.clone());
+        futures::stream::unfold((0u64, guard), |(i, guard)| async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Some((Bytes::from(format!("tick-{}\n", i)), (i + 1, guard)))
+        })
+    });
+
+    let handle = axon.serve().await.expect("Failed to start axon");
+    (handle, slow_dropped, ticker_dropped)
+}
+
+async fn post(handle: &AxonHandle, name: &str, timeout: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("http://{}/{}", handle.local_addr(), name))
+        .header(header_names::TIMEOUT, timeout)
+        .send()
+        .await
+        .expect("Request failed")
+}
+
+#[tokio::test]
+async fn test_slow_handler_cancelled_with_408() {
+    let (handle, slow_dropped, _) = start_axon().await;
+
+    let start = Instant::now();
+    let response = post(&handle, "Slow", "1.0").await;
+    let elapsed = start.elapsed();
+
+    assert_eq!(response.status(), reqwest::StatusCode::REQUEST_TIMEOUT);
+    assert_eq!(
+        response
+            .headers()
+            .get(header_names::AXON_STATUS_CODE)
+            .unwrap(),
+        "408"
+    );
+    // Answered before the caller's own timeout
+    assert!(elapsed < Duration::from_secs(1), "{:?}", elapsed);
+    assert!(
+        slow_dropped.load(Ordering::SeqCst),
+        "handler kept running after its deadline"
+    );
+}
+
+#[tokio::test]
+async fn test_handler_sees_remaining_budget() {
+    let (handle, _, _) = start_axon().await;
+
+    let response = post(&handle, "Budget", "3.0").await;
+    assert!(response.status().is_success());
+    let body: serde_json::Value = response.json().await.unwrap();
+    let remaining = body["remaining_ms"].as_u64().unwrap();
+    assert!(remaining <= 2_800 && remaining > 2_000, "{}", remaining);
+}
+
+#[tokio::test]
+async fn test_stream_truncated_at_deadline() {
+    let (handle, _, ticker_dropped) = start_axon().await;
+
+    let response = post(&handle, "Ticker", "1.0").await;
+    assert!(response.status().is_success());
+    let body = response.bytes().await.expect("Stream was not finished");
+
+    // Ticks up to the deadline, then the error chunk
+    assert!(body.starts_with(b"tick-0\n"));
+    assert!(body.ends_with(&deadline_error_chunk()));
+    assert!(
+        ticker_dropped.load(Ordering::SeqCst),
+        "stream kept running after its deadline"
+    );
+}
@@ -0,0 +1,137 @@
+//! Axon start, shutdown and port binding
+//!
+//! These start local Axons on ephemeral ports. No chain connection is
+//! required.
+
+use bittensor_rs::axon::{Axon, AxonConfig, AxonHandle};
+use bittensor_rs::dendrite::Dendrite;
+use bittensor_rs::types::Synapse;
+use bittensor_rs::wallet::Keypair;
+use std::time::{Duration, Instant};
+
+fn axon(config: AxonConfig) -> Axon {
+    let keypair = Keypair::from_uri("//Alice").expect("Failed to create test keypair");
+    let config = config
+        .with_ip("127.0.0.1")
+        .with_signature_verification(false);
+    let mut axon = Axon::new(keypair, config);
+    axon.attach("Slow", |synapse| async move {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        synapse
+    });
+    axon.attach("Stuck", |synapse| async move {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        synapse
+    });
+    axon
+}
+
+async fn health(handle: &AxonHandle) -> bool {
+    reqwest::get(format!("http://{}/health", handle.local_addr()))
+        .await
+        .map(|r| r.status().is_success())
+        .unwrap_or(false)
+}
+
+#[tokio::test]
+async fn test_ephemeral_port_reported() {
+    let handle = axon(AxonConfig::new().with_port(0)).serve().await.unwrap();
+
+    let port = handle.local_addr().port();
+    assert_ne!(port, 0);
+    assert_eq!(handle.info(0).unwrap().port, port);
+    assert!(health(&handle).await);
+
+    handle.shutdown();
+    handle.await_terminated().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_shutdown_drains_in_flight_requests() {
+    let handle = axon(AxonConfig::new().with_port(0)).serve().await.unwrap();
+    let info = handle.info(0).unwrap();
+
+    // Build the client up front so the request is in flight before shutdown
+    let dendrite = Dendrite::new(None);
+    let request =
+        tokio::spawn(async move { dendrite.call(&info, Synapse::new().with_name("Slow")).await });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    handle.shutdown();
+    let response = request.await.unwrap().expect("In-flight request dropped");
+    assert!(response.is_success());
+    handle.await_terminated().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_shutdown_timeout_drops_stuck_requests() {
+    let handle = axon(AxonConfig::new().with_port(0).with_shutdown_timeout(1))
+        .serve()
+        .await
+        .unwrap();
+    let info = handle.info(0).unwrap();
+    let dendrite = Dendrite::new(None);
+    tokio::spawn(async move {
+        dendrite
+            .call(&info, Synapse::new().with_name("Stuck"))
+            .await
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let started = Instant::now();
+    handle.shutdown();
+    tokio::time::timeout(Duration::from_secs(5), handle.await_terminated())
+        .await
+        .expect("Shutdown ignored its timeout")
+        .unwrap();
+    assert!(started.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_rebind_after_shutdown() {
+    let first = axon(AxonConfig::new().with_port(0)).serve().await.unwrap();
+    let port = first.local_addr().port();
+
+    // The port is taken while the first axon runs
+    assert!(axon(AxonConfig::new().with_port(port))
+        .serve()
+        .await
+        .is_err());
+
+    first.shutdown();
+    first.await_terminated().await.unwrap();
+
+    let second = axon(AxonConfig::new().with_port(port))
+        .serve()
+        .await
+        .unwrap();
+    assert_eq!(second.local_addr().port(), port);
+    assert!(health(&second).await);
+}
+
+#[tokio::test]
+async fn test_drop_keeps_serving_unless_requested() {
+    let handle = axon(AxonConfig::new().with_port(0)).serve().await.unwrap();
+    let addr = handle.local_addr();
+    drop(handle);
+    let url = format!("http://{}/health", addr);
+    assert!(reqwest::get(&url).await.is_ok());
+
+    let handle = axon(AxonConfig::new().with_port(0))
+        .serve()
+        .await
+        .unwrap()
+        .shutdown_on_drop(true);
+    let url = format!("http://{}/health", handle.local_addr());
+    drop(handle);
+
+    let mut stopped = false;
+    for _ in 0..50 {
+        if reqwest::get(&url).await.is_err() {
+            stopped = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(stopped);
+}
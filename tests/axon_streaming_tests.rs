@@ -0,0 +1,227 @@
+//! End-to-end tests for streaming synapses
+//!
+//! These start a local Axon with streaming handlers attached and consume the
+//! output through the Dendrite's `StreamingSynapse` support and its chunk
+//! callback API. No chain connection is required.
+
+use axum::body::Bytes;
+use bittensor_rs::axon::{Axon, AxonConfig};
+use bittensor_rs::dendrite::client::DendriteError;
+use bittensor_rs::dendrite::streaming::TextStreamingSynapse;
+use bittensor_rs::dendrite::{Dendrite, DendriteConfig};
+use bittensor_rs::types::{AxonInfo, Synapse};
+use bittensor_rs::wallet::Keypair;
+use futures::StreamExt;
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+const CHUNK_COUNT: usize = 100;
+
+/// Start an axon with a finite "Count" stream and an endless "Ticker" stream
+///
+/// Returns the axon info to call and a flag set once the ticker's producer
+/// notices the client has gone away.
+async fn start_streaming_axon() -> (AxonInfo, Arc<AtomicBool>) {
+    let keypair = Keypair::from_uri("//Alice").expect("Failed to create test keypair");
+    let config = AxonConfig::new()
+        .with_ip("127.0.0.1")
+        .with_port(0)
+        .with_signature_verification(false);
+    let mut axon = Axon::new(keypair, config);
+
+    axon.attach_streaming("Count", |_synapse| {
+        futures::stream::unfold(0usize, |i| async move {
+            if i == CHUNK_COUNT {
+                return None;
+            }
+            tokio::time::sleep(Duration::from_millis(2)).await;
+            Some((Bytes::from(format!("chunk-{}\n", i)), i + 1))
+        })
+    });
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let producer_cancelled = cancelled.clone();
+    axon.attach_streaming("Ticker", move |_synapse| {
+        let (tx, rx) = tokio::sync::mpsc::channel::<Bytes>(1);
+        let cancelled = producer_cancelled.clone();
+        tokio::spawn(async move {
+            let mut i = 0u64;
+            loop {
+                if tx.send(Bytes::from(format!("tick-{}\n", i))).await.is_err() {
+                    cancelled.store(true, Ordering::SeqCst);
+                    return;
+                }
+                i += 1;
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        });
+        futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|chunk| (chunk, rx))
+        })
+    });
+
+    // Listening once `serve` returns; the server outlives the dropped handle
+    let handle = axon.serve().await.expect("Failed to start axon");
+    let info = handle.info(0).expect("Failed to build axon info");
+
+    (info, cancelled)
+}
+
+#[tokio::test]
+async fn test_streaming_synapse_ordering_and_termination() {
+    let (info, _) = start_streaming_axon().await;
+    let dendrite = Dendrite::new(None);
+
+    let stream = dendrite
+        .call_stream(&info, TextStreamingSynapse::new("Count"))
+        .await
+        .expect("Streaming call failed");
+    futures::pin_mut!(stream);
+
+    let mut chunks = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        chunks.push(chunk.expect("Stream error"));
+    }
+
+    let expected: Vec<String> = (0..CHUNK_COUNT).map(|i| format!("chunk-{}", i)).collect();
+    assert_eq!(chunks, expected);
+
+    // The stream stays terminated once it has ended
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_streaming_client_disconnect_cancels_handler() {
+    let (info, cancelled) = start_streaming_axon().await;
+    let dendrite = Dendrite::new(None);
+
+    let stream = dendrite
+        .call_stream(&info, TextStreamingSynapse::new("Ticker"))
+        .await
+        .expect("Streaming call failed");
+    let received: Vec<String> = stream
+        .take(3)
+        .map(|c| c.expect("Stream error"))
+        .collect()
+        .await;
+    assert_eq!(received, vec!["tick-0", "tick-1", "tick-2"]);
+
+    // Dropping the stream above closes the connection
+    for _ in 0..200 {
+        if cancelled.load(Ordering::SeqCst) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(
+        cancelled.load(Ordering::SeqCst),
+        "handler kept producing after the client disconnected"
+    );
+}
+
+#[tokio::test]
+async fn test_call_stream_chunks_summary() {
+    let (info, _) = start_streaming_axon().await;
+    let config = DendriteConfig::new().with_stream_buffer(1);
+    let dendrite = Dendrite::with_config(None, config).expect("Failed to build dendrite");
+
+    let mut received = Vec::new();
+    let summary = dendrite
+        .call_stream_chunks(&info, Synapse::new().with_name("Count"), |chunk| {
+            received.extend_from_slice(&chunk);
+            ControlFlow::Continue(())
+        })
+        .await
+        .expect("Streaming call failed");
+
+    let expected: String = (0..CHUNK_COUNT).map(|i| format!("chunk-{}\n", i)).collect();
+    assert_eq!(String::from_utf8(received).unwrap(), expected);
+    assert_eq!(summary.bytes, expected.len() as u64);
+    assert!(summary.chunks >= 1 && summary.chunks <= CHUNK_COUNT as u64);
+    assert!(!summary.cancelled);
+    assert!(summary.is_success());
+    assert!(summary.axon.unwrap().process_time.is_some());
+}
+
+#[tokio::test]
+async fn test_call_stream_chunks_break_cancels_handler() {
+    let (info, cancelled) = start_streaming_axon().await;
+    let dendrite = Dendrite::new(None);
+
+    let mut ticks = 0;
+    let summary = dendrite
+        .call_stream_chunks(&info, Synapse::new().with_name("Ticker"), |_chunk| {
+            ticks += 1;
+            if ticks == 3 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .await
+        .expect("Streaming call failed");
+    assert!(summary.cancelled);
+    assert_eq!(summary.chunks, 3);
+    assert!(summary.axon.is_none());
+
+    for _ in 0..200 {
+        if cancelled.load(Ordering::SeqCst) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(
+        cancelled.load(Ordering::SeqCst),
+        "handler kept producing after the callback stopped the stream"
+    );
+}
+
+#[tokio::test]
+async fn test_call_stream_chunks_without_trailers() {
+    // A server that ends a chunked stream without the status trailers
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind test listener");
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = vec![0u8; 8192];
+        let _ = socket.read(&mut request).await;
+        socket
+            .write_all(
+                b"HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\n\r\n\
+                  6\r\nhello\n\r\n0\r\n\r\n",
+            )
+            .await
+            .unwrap();
+    });
+    let info = AxonInfo {
+        hotkey: None,
+        block: 0,
+        version: 0,
+        ip: addr.ip(),
+        port: addr.port(),
+        ip_type: 4,
+        protocol: 0,
+        placeholder1: 0,
+        placeholder2: 0,
+    };
+
+    let mut received = Vec::new();
+    let err = Dendrite::new(None)
+        .call_stream_chunks(&info, Synapse::new().with_name("Count"), |chunk| {
+            received.extend_from_slice(&chunk);
+            ControlFlow::Continue(())
+        })
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DendriteError::InvalidResponse(_)),
+        "{:?}",
+        err
+    );
+    assert_eq!(received, b"hello\n");
+}
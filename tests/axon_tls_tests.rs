@@ -0,0 +1,123 @@
+//! Serving an axon over TLS and pinning its published certificate
+//!
+//! Starts a local HTTPS axon, publishes its certificate to a `MockChain`
+//! with `serve_axon_tls` and connects with `Dendrite::call_tls` using the
+//! certificate read back from storage. Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use bittensor_rs::axon::{Axon, AxonConfig, AxonTlsConfig};
+use bittensor_rs::chain::{signer_from_seed, ExtrinsicWait, MockChain};
+use bittensor_rs::dendrite::Dendrite;
+use bittensor_rs::queries::{get_neuron_certificate, Certificate};
+use bittensor_rs::types::{Synapse, AXON_PROTOCOL_HTTPS};
+use bittensor_rs::validator::serve_axon_tls;
+use bittensor_rs::wallet::Keypair;
+use parity_scale_codec::Encode;
+use sp_core::crypto::AccountId32;
+use subxt::dynamic::Value;
+
+const NETUID: u16 = 1;
+
+#[tokio::test]
+async fn test_serve_https_round_trip() {
+    let keypair = Keypair::from_uri("//Alice").unwrap();
+    let tls = AxonTlsConfig::generate(keypair.ss58_address()).unwrap();
+    let config = AxonConfig::new()
+        .with_ip("127.0.0.1")
+        .with_port(0)
+        .with_signature_verification(false);
+    let mut axon = Axon::new(keypair, config);
+    axon.attach("Echo", |synapse| async move { synapse });
+    let handle = axon.serve_https(&tls).await.unwrap();
+    let info = handle.info(0).unwrap();
+    assert_eq!(info.protocol, AXON_PROTOCOL_HTTPS);
+
+    // Publish the certificate and store it as the chain would
+    let chain = MockChain::new();
+    let signer = signer_from_seed("//Alice").unwrap();
+    let certificate = tls.chain_certificate().unwrap();
+    serve_axon_tls(
+        &chain,
+        &signer,
+        NETUID,
+        info.version,
+        info.ip,
+        info.port,
+        info.protocol,
+        &certificate,
+        ExtrinsicWait::Included,
+    )
+    .await
+    .unwrap();
+
+    let submitted = chain.submitted();
+    assert_eq!(submitted[0].function, "serve_axon_tls");
+    let encoded = certificate.encode();
+    assert_eq!(submitted[0].args[8], Value::from_bytes(&encoded));
+
+    let stored = Certificate::decode(&encoded).unwrap();
+    let hotkey = AccountId32::from(signer.account_id().0);
+    chain.set_storage(
+        "SubtensorModule",
+        "NeuronCertificates",
+        vec![
+            Value::u128(NETUID as u128),
+            Value::from_bytes(hotkey.encode()),
+        ],
+        Value::named_composite([
+            ("public_key", Value::from_bytes(stored.as_der())),
+            ("algorithm", Value::u128(stored.algorithm as u128)),
+        ]),
+    );
+
+    let fetched = get_neuron_certificate(&chain, &hotkey, NETUID)
+        .await
+        .unwrap()
+        .expect("Certificate not stored");
+    assert_eq!(fetched, certificate);
+
+    let dendrite = Dendrite::new(None);
+    let response = dendrite
+        .call_tls(&info, Synapse::new().with_name("Echo"), &fetched)
+        .await
+        .unwrap();
+    assert!(response.is_success(), "{:?}", response.dendrite);
+
+    // A different key is rejected during the handshake
+    let other = AxonTlsConfig::generate("other").unwrap();
+    let response = dendrite
+        .call_tls(
+            &info,
+            Synapse::new().with_name("Echo"),
+            &other.chain_certificate().unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(!response.is_success());
+
+    handle.shutdown();
+    handle.await_terminated().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_serve_axon_tls_rejects_oversized_certificate() {
+    let chain = MockChain::new();
+    let signer = signer_from_seed("//Alice").unwrap();
+    let certificate = Certificate::from_der(1, vec![0u8; 65]);
+
+    let result = serve_axon_tls(
+        &chain,
+        &signer,
+        NETUID,
+        1,
+        "127.0.0.1".parse().unwrap(),
+        8091,
+        AXON_PROTOCOL_HTTPS,
+        &certificate,
+        ExtrinsicWait::Included,
+    )
+    .await;
+    assert!(result.is_err());
+    assert!(chain.submitted().is_empty());
+}
@@ -0,0 +1,49 @@
+//! Chain constants decoded from the vendored Finney metadata
+//!
+//! `metadata/finney.scale` is the runtime metadata the decoders are checked
+//! against; no chain connection is required.
+
+use bittensor_rs::chain::ChainConstants;
+use bittensor_rs::utils::balance_newtypes::Rao;
+use parity_scale_codec::Decode;
+use std::path::Path;
+use std::time::Duration;
+
+fn finney_metadata() -> subxt::Metadata {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("metadata/finney.scale");
+    let bytes = std::fs::read(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    subxt::Metadata::decode(&mut &bytes[..]).expect("Failed to decode metadata")
+}
+
+#[test]
+fn test_constants_from_finney_metadata() {
+    let constants = ChainConstants::from_metadata(&finney_metadata());
+
+    assert_eq!(constants.existential_deposit, Rao::from(500u128));
+    assert_eq!(constants.ss58_prefix, 42);
+    // Aura runtime: twice Timestamp.MinimumPeriod (6000ms)
+    assert_eq!(constants.block_time, Duration::from_secs(12));
+    assert_eq!(constants.block_time_secs(), 12.0);
+}
+
+#[test]
+fn test_subtensor_constants_from_finney_metadata() {
+    let constants = ChainConstants::from_metadata(&finney_metadata());
+
+    assert_eq!(constants.subtensor_constant("InitialTempo"), Some(360));
+    assert!(!constants.subtensor.is_empty());
+    assert_eq!(constants.subtensor_constant("NotAConstant"), None);
+}
+
+#[test]
+fn test_constants_match_defaults() {
+    // The fallbacks used on older runtimes agree with Finney
+    let from_metadata = ChainConstants::from_metadata(&finney_metadata());
+    let defaults = ChainConstants::default();
+    assert_eq!(
+        from_metadata.existential_deposit,
+        defaults.existential_deposit
+    );
+    assert_eq!(from_metadata.block_time, defaults.block_time);
+    assert_eq!(from_metadata.ss58_prefix, defaults.ss58_prefix);
+}
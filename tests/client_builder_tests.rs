@@ -0,0 +1,82 @@
+//! Connection settings of `BittensorClientBuilder`
+//!
+//! These never reach a chain: they connect to addresses that do not answer,
+//! or to a local TCP listener that records the WebSocket handshake.
+
+use bittensor_rs::BittensorClient;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpListener;
+
+const TIMEOUT: Duration = Duration::from_millis(500);
+/// Allowance for scheduling on a loaded machine
+const SLACK: Duration = Duration::from_secs(2);
+
+#[tokio::test]
+async fn test_non_routable_endpoint_fails_within_timeout() {
+    let start = Instant::now();
+    let result = BittensorClient::builder("ws://10.255.255.1:9944")
+        .timeout(TIMEOUT)
+        .build()
+        .await;
+    assert!(result.is_err());
+    assert!(start.elapsed() < TIMEOUT + SLACK, "{:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn test_silent_endpoint_fails_within_timeout() {
+    // Accepts connections but never completes the handshake
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            held.push(socket);
+        }
+    });
+
+    let start = Instant::now();
+    let result = BittensorClient::builder(format!("ws://{}", addr))
+        .timeout(TIMEOUT)
+        .build()
+        .await;
+    let error = result.expect_err("connection should fail").to_string();
+    assert!(error.contains("timed out"), "{}", error);
+    assert!(start.elapsed() < TIMEOUT + SLACK, "{:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn test_headers_sent_with_handshake() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handshake = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let n = socket.read(&mut buf).await.unwrap();
+            if n == 0 {
+                break;
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+        String::from_utf8(request).unwrap().to_lowercase()
+    });
+
+    // The listener drops the connection after reading, so this fails
+    let result = BittensorClient::builder(format!("ws://{}", addr))
+        .timeout(TIMEOUT)
+        .header("Authorization", "Basic dXNlcjpwYXNz")
+        .header("x-api-key", "secret")
+        .build()
+        .await;
+    assert!(result.is_err());
+
+    let request = handshake.await.unwrap();
+    assert!(
+        request.contains("authorization: basic dxnlcjpwyxnz"),
+        "{}",
+        request
+    );
+    assert!(request.contains("x-api-key: secret"), "{}", request);
+}
@@ -23,7 +23,8 @@ fn test_reveal_round_always_greater_than_chain_last() {
     let chain_last = 24_000_000u64;
     for tempo in [100u16, 360, 720] {
         for reveal_period in [1u64, 2, 5] {
-            let rr = calculate_reveal_round(tempo, 5000, 1, reveal_period, 12.0, chain_last);
+            let rr =
+                calculate_reveal_round(tempo, 5000, 1, reveal_period, 12.0, chain_last).unwrap();
             assert!(
                 rr > chain_last,
                 "reveal_round {} must be > chain_last {} (tempo={}, rp={})",
@@ -38,9 +39,9 @@ fn test_reveal_round_always_greater_than_chain_last() {
 
 #[test]
 fn test_reveal_round_zero_reveal_period() {
+    // The chain never reveals such commits
     let chain_last = 24_000_000u64;
-    let rr = calculate_reveal_round(360, 5000, 1, 0, 12.0, chain_last);
-    assert_eq!(rr, chain_last + 1);
+    assert!(calculate_reveal_round(360, 5000, 1, 0, 12.0, chain_last).is_err());
 }
 
 #[test]
@@ -49,8 +50,10 @@ fn test_reveal_round_scales_with_chain_state() {
     let current_block = 5000u64;
     let reveal_period = 1u64;
 
-    let rr1 = calculate_reveal_round(tempo, current_block, 1, reveal_period, 12.0, 24_000_000);
-    let rr2 = calculate_reveal_round(tempo, current_block, 1, reveal_period, 12.0, 25_000_000);
+    let rr1 =
+        calculate_reveal_round(tempo, current_block, 1, reveal_period, 12.0, 24_000_000).unwrap();
+    let rr2 =
+        calculate_reveal_round(tempo, current_block, 1, reveal_period, 12.0, 25_000_000).unwrap();
 
     assert_eq!(
         rr2 - rr1,
@@ -62,8 +65,8 @@ fn test_reveal_round_scales_with_chain_state() {
 #[test]
 fn test_reveal_round_increases_with_reveal_period() {
     let chain_last = 24_000_000u64;
-    let rr1 = calculate_reveal_round(360, 5000, 1, 1, 12.0, chain_last);
-    let rr2 = calculate_reveal_round(360, 5000, 1, 2, 12.0, chain_last);
+    let rr1 = calculate_reveal_round(360, 5000, 1, 1, 12.0, chain_last).unwrap();
+    let rr2 = calculate_reveal_round(360, 5000, 1, 2, 12.0, chain_last).unwrap();
     assert!(
         rr2 > rr1,
         "longer reveal period should give a later reveal round"
@@ -73,8 +76,8 @@ fn test_reveal_round_increases_with_reveal_period() {
 #[test]
 fn test_reveal_round_varies_with_tempo() {
     let chain_last = 24_000_000u64;
-    let rr_short = calculate_reveal_round(100, 5000, 1, 1, 12.0, chain_last);
-    let rr_long = calculate_reveal_round(720, 5000, 1, 1, 12.0, chain_last);
+    let rr_short = calculate_reveal_round(100, 5000, 1, 1, 12.0, chain_last).unwrap();
+    let rr_long = calculate_reveal_round(720, 5000, 1, 1, 12.0, chain_last).unwrap();
     assert!(
         rr_short > chain_last && rr_long > chain_last,
         "both reveal rounds should exceed chain_last"
@@ -82,9 +85,10 @@ fn test_reveal_round_varies_with_tempo() {
 }
 
 #[test]
-fn test_reveal_round_saturating_arithmetic() {
-    let rr = calculate_reveal_round(360, u64::MAX - 10, 1, 1, 12.0, u64::MAX - 100);
-    assert!(rr > 0, "should not panic on near-max values");
+fn test_reveal_round_overflow_is_an_error() {
+    // Near-max blocks are fine; a round past u64::MAX is reported, not wrapped
+    assert!(calculate_reveal_round(360, u64::MAX - 10, 1, 1, 12.0, 24_000_000).is_ok());
+    assert!(calculate_reveal_round(360, u64::MAX - 10, 1, 1, 12.0, u64::MAX - 100).is_err());
 }
 
 // ============================================================================
@@ -330,7 +334,8 @@ async fn test_crv4_flow_against_finney() {
         reveal_period,
         12.0,
         chain_last_round,
-    );
+    )
+    .expect("reveal round");
     assert!(reveal_round > chain_last_round);
 
     let hotkey = vec![0xAA; 32];
@@ -0,0 +1,86 @@
+//! Connection reuse tests for the Dendrite
+//!
+//! These start a plain local HTTP server that counts accepted connections
+//! and check that the Dendrite's pooled client reuses them. No chain
+//! connection is required.
+
+use axum::routing::post;
+use axum::serve::ListenerExt;
+use axum::Router;
+use bittensor_rs::dendrite::{Dendrite, DendriteConfig};
+use bittensor_rs::types::{AxonInfo, Synapse};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Start a server answering every synapse, returning its axon info and a
+/// counter of accepted TCP connections
+async fn start_counting_server() -> (AxonInfo, Arc<AtomicUsize>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind test listener");
+    let addr: SocketAddr = listener.local_addr().expect("Failed to get local address");
+
+    let connections = Arc::new(AtomicUsize::new(0));
+    let counter = connections.clone();
+    let listener = listener.tap_io(move |_stream| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let app = Router::new().route("/{name}", post(|| async { "{}" }));
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    let info = AxonInfo {
+        hotkey: None,
+        block: 0,
+        version: 0,
+        ip: addr.ip(),
+        port: addr.port(),
+        ip_type: 4,
+        protocol: 0,
+        placeholder1: 0,
+        placeholder2: 0,
+    };
+    (info, connections)
+}
+
+#[tokio::test]
+async fn test_dendrite_reuses_connections() {
+    let (info, connections) = start_counting_server().await;
+    let dendrite = Dendrite::new(None);
+
+    for _ in 0..10 {
+        let response = dendrite
+            .call(&info, Synapse::new().with_name("Query"))
+            .await
+            .expect("Call failed");
+        assert_eq!(response.dendrite.unwrap().status_code, Some(200));
+    }
+
+    // Clones share the same pool
+    let clone = dendrite.clone();
+    clone
+        .call(&info, Synapse::new().with_name("Query"))
+        .await
+        .expect("Call failed");
+
+    // The pool may open one extra connection while the first is still being
+    // returned to it, but every later request reuses an idle connection
+    assert!(connections.load(Ordering::SeqCst) <= 2);
+}
+
+#[tokio::test]
+async fn test_dendrite_without_pool_reconnects() {
+    let (info, connections) = start_counting_server().await;
+    let config = DendriteConfig::new().with_pool_size(0);
+    let dendrite = Dendrite::with_config(None, config).expect("Failed to build dendrite");
+
+    for _ in 0..3 {
+        dendrite
+            .call(&info, Synapse::new().with_name("Query"))
+            .await
+            .expect("Call failed");
+    }
+
+    assert_eq!(connections.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,199 @@
+//! Retry tests for the Dendrite
+//!
+//! These start a plain local HTTP server that fails a set number of
+//! requests before answering, and record the signatures of every attempt.
+//! No chain connection is required.
+
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use bittensor_rs::dendrite::request::header_names;
+use bittensor_rs::dendrite::{Backoff, Dendrite, DendriteConfig, RetryOn, RetryPolicy};
+use bittensor_rs::types::{AxonInfo, Synapse};
+use sp_core::{sr25519, Pair};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+fn axon_info(addr: SocketAddr) -> AxonInfo {
+    AxonInfo {
+        hotkey: None,
+        block: 0,
+        version: 0,
+        ip: addr.ip(),
+        port: addr.port(),
+        ip_type: 4,
+        protocol: 0,
+        placeholder1: 0,
+        placeholder2: 0,
+    }
+}
+
+/// Start a server answering `status` to the first `failures` requests and
+/// 200 afterwards, returning its axon info and the nonces it received
+async fn start_flaky_server(
+    failures: usize,
+    status: StatusCode,
+) -> (AxonInfo, Arc<Mutex<Vec<String>>>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind test listener");
+    let addr = listener.local_addr().expect("Failed to get local address");
+
+    let nonces = Arc::new(Mutex::new(Vec::new()));
+    let seen = nonces.clone();
+    let app = Router::new().route(
+        "/{name}",
+        post(move |headers: HeaderMap| {
+            let seen = seen.clone();
+            async move {
+                let nonce = headers
+                    .get(header_names::DENDRITE_NONCE)
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                let mut seen = seen.lock().unwrap();
+                seen.push(nonce);
+                if seen.len() <= failures {
+                    (status, "{}")
+                } else {
+                    (StatusCode::OK, "{}")
+                }
+            }
+        }),
+    );
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    (axon_info(addr), nonces)
+}
+
+fn dendrite(policy: RetryPolicy, timeout: Duration) -> Dendrite {
+    let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+    let config = DendriteConfig::new()
+        .with_timeout(timeout)
+        .with_retry(policy);
+    Dendrite::with_config(Some(pair), config).expect("Failed to build dendrite")
+}
+
+fn fast_retries(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy::new(max_attempts).with_backoff(Backoff::new(
+        Duration::from_millis(10),
+        Duration::from_millis(50),
+    ))
+}
+
+fn status_and_attempts(response: &Synapse) -> (Option<i32>, Option<u32>) {
+    let dendrite = response.dendrite.as_ref().expect("dendrite info");
+    (dendrite.status_code, dendrite.attempts)
+}
+
+#[tokio::test]
+async fn test_retries_until_success_with_fresh_nonces() {
+    let (info, nonces) = start_flaky_server(2, StatusCode::BAD_GATEWAY).await;
+    let dendrite = dendrite(fast_retries(5), Duration::from_secs(5));
+
+    let response = dendrite
+        .call(&info, Synapse::new().with_name("Query"))
+        .await
+        .expect("Call failed");
+    assert_eq!(status_and_attempts(&response), (Some(200), Some(3)));
+
+    let nonces = nonces.lock().unwrap();
+    assert_eq!(nonces.len(), 3);
+    assert!(nonces[0] != nonces[1] && nonces[1] != nonces[2]);
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts() {
+    let (info, nonces) = start_flaky_server(10, StatusCode::SERVICE_UNAVAILABLE).await;
+    let dendrite = dendrite(fast_retries(3), Duration::from_secs(5));
+
+    let response = dendrite
+        .call(&info, Synapse::new().with_name("Query"))
+        .await
+        .expect("Call failed");
+    assert_eq!(status_and_attempts(&response), (Some(503), Some(3)));
+    assert_eq!(nonces.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_opt_out_and_non_retryable_statuses() {
+    let (info, nonces) = start_flaky_server(1, StatusCode::BAD_GATEWAY).await;
+    let dendrite = dendrite(fast_retries(5), Duration::from_secs(5));
+
+    // Per-call opt-out for a non-idempotent synapse
+    let response = dendrite
+        .call_with_retry(
+            &info,
+            Synapse::new().with_name("Submit"),
+            &RetryPolicy::none(),
+        )
+        .await
+        .expect("Call failed");
+    assert_eq!(status_and_attempts(&response), (Some(502), Some(1)));
+
+    // Client errors are never retried
+    let (info, nonces_4xx) = start_flaky_server(1, StatusCode::TOO_MANY_REQUESTS).await;
+    let response = dendrite
+        .call(&info, Synapse::new().with_name("Query"))
+        .await
+        .expect("Call failed");
+    assert_eq!(status_and_attempts(&response), (Some(429), Some(1)));
+
+    // Server errors excluded from the policy are not retried either
+    let no_5xx = fast_retries(5).with_retry_on(RetryOn {
+        server_error: false,
+        ..RetryOn::all()
+    });
+    let (info, nonces_5xx) = start_flaky_server(1, StatusCode::BAD_GATEWAY).await;
+    let response = dendrite
+        .call_with_retry(&info, Synapse::new().with_name("Query"), &no_5xx)
+        .await
+        .expect("Call failed");
+    assert_eq!(status_and_attempts(&response), (Some(502), Some(1)));
+
+    assert_eq!(nonces.lock().unwrap().len(), 1);
+    assert_eq!(nonces_4xx.lock().unwrap().len(), 1);
+    assert_eq!(nonces_5xx.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_retries_stay_within_timeout() {
+    let (info, nonces) = start_flaky_server(usize::MAX, StatusCode::BAD_GATEWAY).await;
+    let policy = RetryPolicy::new(100).with_backoff(Backoff::new(
+        Duration::from_millis(200),
+        Duration::from_millis(200),
+    ));
+    let dendrite = dendrite(policy, Duration::from_secs(1));
+
+    let start = Instant::now();
+    let response = dendrite
+        .call(&info, Synapse::new().with_name("Query"))
+        .await
+        .expect("Call failed");
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    let (status, attempts) = status_and_attempts(&response);
+    assert_eq!(status, Some(502));
+    // Backoff is 100-200ms, so at most 10 attempts fit in a second
+    let attempts = attempts.unwrap();
+    assert!((2..=10).contains(&attempts), "{} attempts", attempts);
+    assert_eq!(nonces.lock().unwrap().len(), attempts as usize);
+}
+
+#[tokio::test]
+async fn test_connection_failures_retried() {
+    // Bind and drop a listener to get a port nothing listens on
+    let addr = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let dendrite = dendrite(fast_retries(3), Duration::from_secs(5));
+
+    let response = dendrite
+        .call(&axon_info(addr), Synapse::new().with_name("Query"))
+        .await
+        .expect("Call failed");
+    assert_eq!(status_and_attempts(&response), (Some(503), Some(3)));
+}
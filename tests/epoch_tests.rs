@@ -0,0 +1,63 @@
+//! Property tests for the subnet epoch schedule in `core::epoch`
+//!
+//! Checks that the epoch index, epoch start block and blocks until the next
+//! epoch agree with each other for random netuid/tempo/block triples.
+
+use bittensor_rs::core::epoch::{
+    blocks_until_next_epoch, epoch_index, epoch_start_block, next_epoch_start_block,
+};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn prop_index_increments_at_start_block(
+        netuid in 0u16..=u16::MAX,
+        tempo in 1u64..=u16::MAX as u64,
+        block in 0u64..1_000_000_000u64,
+    ) {
+        let index = epoch_index(netuid, tempo, block);
+        let start = epoch_start_block(netuid, tempo, index);
+        let next_start = epoch_start_block(netuid, tempo, index + 1);
+
+        prop_assert!(start <= block);
+        prop_assert!(block < next_start);
+        prop_assert_eq!(epoch_index(netuid, tempo, next_start), index + 1);
+        prop_assert_eq!(epoch_index(netuid, tempo, next_start - 1), index);
+        if start > 0 {
+            prop_assert_eq!(epoch_index(netuid, tempo, start), index);
+            prop_assert_eq!(epoch_index(netuid, tempo, start - 1), index - 1);
+        }
+    }
+
+    #[test]
+    fn prop_epoch_runs_before_next_start(
+        netuid in 0u16..=u16::MAX,
+        tempo in 1u64..=u16::MAX as u64,
+        block in 0u64..1_000_000_000u64,
+    ) {
+        let blocks = blocks_until_next_epoch(netuid, tempo, block);
+        prop_assert!(blocks <= tempo);
+
+        let run_block = block + blocks;
+        prop_assert_eq!(blocks_until_next_epoch(netuid, tempo, run_block), 0);
+        prop_assert_eq!(epoch_index(netuid, tempo, run_block), epoch_index(netuid, tempo, block));
+        prop_assert_eq!(next_epoch_start_block(netuid, tempo, block), run_block + 1);
+        prop_assert_eq!(blocks_until_next_epoch(netuid, tempo, run_block + 1), tempo);
+    }
+
+    #[test]
+    fn prop_start_block_round_trips(
+        netuid in 0u16..=u16::MAX,
+        tempo in 0u64..=u16::MAX as u64,
+        index in 0u64..1_000_000u64,
+    ) {
+        let start = epoch_start_block(netuid, tempo, index);
+        let netuid_plus_one = netuid as u64 + 1;
+        // Epochs that would start before block 0 saturate to it
+        if index * (tempo + 1) >= netuid_plus_one {
+            prop_assert_eq!(epoch_index(netuid, tempo, start), index);
+        } else {
+            prop_assert_eq!(start, 0);
+        }
+    }
+}
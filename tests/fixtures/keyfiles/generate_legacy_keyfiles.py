@@ -0,0 +1,96 @@
+#!/usr/bin/env python3
+"""Generate legacy-encrypted keyfiles written by older Python bittensor SDKs.
+
+The encryption routines are copied from the SDK so the fixtures are byte-for-byte
+what it writes, minus the random salt/IV:
+
+- ``python_ansible_coldkey``: bittensor 2.x, ``Vault(password).vault.encrypt(data)``
+  (ansible-vault 1.1, AES256: PBKDF2-SHA256 x10000, AES-256-CTR, HMAC-SHA256)
+- ``python_fernet_coldkey``: bittensor 3.x ``legacy_encrypt_keyfile_data``
+  (PBKDF2-SHA256 x10000000 with a fixed salt, Fernet)
+
+``python_nacl_coldkey`` is ``bittensor-wallet/scripts/test_coldkey``, written by
+PyNaCl via ``create_test_coldkey.py``.
+
+All three hold the same keyfile JSON (secret seed 0x00..01) and share the
+password ``testpassword123``.
+
+Usage: python3 generate_legacy_keyfiles.py
+"""
+
+import base64
+import json
+import os
+from binascii import hexlify
+
+from cryptography.fernet import Fernet
+from cryptography.hazmat.primitives import hashes, hmac, padding
+from cryptography.hazmat.primitives.ciphers import Cipher, algorithms, modes
+from cryptography.hazmat.primitives.kdf.pbkdf2 import PBKDF2HMAC
+
+PASSWORD = "testpassword123"
+SEED = "0x" + "00" * 31 + "01"
+
+# Legacy SDK salt for Fernet keyfiles
+FERNET_SALT = b"Iguesscyborgslikemyselfhaveatendencytobeparanoidaboutourorigins"
+
+
+def keyfile_data() -> bytes:
+    """``serialized_keypair_to_keyfile_data`` for a seed-only keypair."""
+    return json.dumps(
+        {
+            "accountId": SEED,
+            "publicKey": SEED,
+            "secretPhrase": None,
+            "secretSeed": SEED,
+            "ss58Address": None,
+        }
+    ).encode()
+
+
+def ansible_vault_encrypt(data: bytes, password: str) -> bytes:
+    """ansible ``VaultLib.encrypt`` with the AES256 cipher."""
+    salt = os.urandom(32)
+    derived = PBKDF2HMAC(
+        algorithm=hashes.SHA256(), length=80, salt=salt, iterations=10000
+    ).derive(password.encode())
+    key1, key2, iv = derived[:32], derived[32:64], derived[64:]
+
+    padder = padding.PKCS7(algorithms.AES.block_size).padder()
+    padded = padder.update(data) + padder.finalize()
+    encryptor = Cipher(algorithms.AES(key1), modes.CTR(iv)).encryptor()
+    ciphertext = encryptor.update(padded) + encryptor.finalize()
+
+    mac = hmac.HMAC(key2, hashes.SHA256())
+    mac.update(ciphertext)
+
+    vaulttext = hexlify(
+        b"\n".join([hexlify(salt), hexlify(mac.finalize()), hexlify(ciphertext)])
+    )
+    lines = [vaulttext[i : i + 80] for i in range(0, len(vaulttext), 80)]
+    return b"\n".join([b"$ANSIBLE_VAULT;1.1;AES256"] + lines) + b"\n"
+
+
+def fernet_encrypt(data: bytes, password: str) -> bytes:
+    """bittensor ``legacy_encrypt_keyfile_data``."""
+    kdf = PBKDF2HMAC(
+        algorithm=hashes.SHA256(), length=32, salt=FERNET_SALT, iterations=10000000
+    )
+    key = base64.urlsafe_b64encode(kdf.derive(password.encode()))
+    return Fernet(key).encrypt(data)
+
+
+def main():
+    here = os.path.dirname(os.path.abspath(__file__))
+    data = keyfile_data()
+    for name, content in [
+        ("python_ansible_coldkey", ansible_vault_encrypt(data, PASSWORD)),
+        ("python_fernet_coldkey", fernet_encrypt(data, PASSWORD)),
+    ]:
+        with open(os.path.join(here, name), "wb") as f:
+            f.write(content)
+        print(f"Wrote {name} ({len(content)} bytes)")
+
+
+if __name__ == "__main__":
+    main()
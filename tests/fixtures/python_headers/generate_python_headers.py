@@ -0,0 +1,153 @@
+#!/usr/bin/env python3
+"""Dump synapse headers and bodies from the Python bittensor SDK.
+
+Each shape below is a ``bittensor.Synapse`` subclass. A fixture holds
+``synapse.to_headers()`` and ``json.dumps(synapse.model_dump())``, which is
+what the dendrite sends and the axon returns, and the SDK version it came
+from.
+
+The script refuses to run against any SDK version other than
+``PINNED_SDK_VERSION`` (see ``requirements.txt``), so fixtures always come
+from a known release. The only value it changes is ``header_size``. The SDK
+fills that header from ``sys.getsizeof`` of the header dict, which depends on
+the interpreter, so it is pinned to ``HEADER_SIZE``.
+
+Usage:
+    python3 -m venv .venv && . .venv/bin/activate
+    pip install -r requirements.txt
+    python3 generate_python_headers.py
+"""
+
+import json
+import sys
+from typing import ClassVar, Optional
+
+import bittensor as bt
+
+PINNED_SDK_VERSION = "9.0.0"
+HEADER_SIZE = 640
+
+DENDRITE = dict(
+    ip="203.0.113.7",
+    version=9001000,
+    nonce=1700000000123456789,
+    uuid="0b5e7c1a-7d5c-11ee-9f2b-0242ac120002",
+    hotkey="5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty",
+    signature="566af0d2882b1db35845b70e0c7b038f1aed9d7be8155b67aa0fad94841e6b3e"
+    "3b0bd9d2c029f4ab8503e9b58e507a8db9e88f28e143a28d8f2d1b38ef1dd489",
+)
+
+AXON = dict(
+    ip="127.0.0.1",
+    port=8091,
+    hotkey="5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+)
+
+
+class Query(bt.Synapse):
+    """Minimal synapse with a single string field"""
+
+    query: str
+    response: Optional[str] = None
+
+
+class TextPrompting(bt.Synapse):
+    """Lists and an unset output"""
+
+    roles: list[str]
+    messages: list[str]
+    completion: Optional[str] = None
+    required_hash_fields: ClassVar[tuple[str, ...]] = ("messages",)
+
+
+class Scores(bt.Synapse):
+    """Numbers, booleans and a nested object"""
+
+    uids: list[int]
+    weights: list[float]
+    scale: float
+    round: int
+    normalize: bool
+    config: dict
+    required_hash_fields: ClassVar[tuple[str, ...]] = ("uids", "weights")
+
+
+def shapes() -> dict:
+    return {
+        "query_request": Query(
+            name="Query",
+            query="ping",
+            timeout=12.0,
+            dendrite=bt.TerminalInfo(**DENDRITE),
+            axon=bt.TerminalInfo(**AXON),
+        ),
+        "text_prompting_request": TextPrompting(
+            name="TextPrompting",
+            roles=["system", "user"],
+            messages=["You are terse.", "Hello?"],
+            timeout=7.5,
+            dendrite=bt.TerminalInfo(**DENDRITE),
+            axon=bt.TerminalInfo(**AXON),
+        ),
+        # A timeout below 1e-4 makes Python print it in exponent notation
+        "numeric_request": Scores(
+            name="Scores",
+            uids=[0, 1, 2],
+            weights=[0.5, 0.25, 1e-05],
+            scale=1.0,
+            round=42,
+            normalize=True,
+            config={"alpha": 0.1, "tags": ["a"]},
+            timeout=5e-05,
+            total_size=1024,
+            dendrite=bt.TerminalInfo(**DENDRITE),
+            axon=bt.TerminalInfo(**AXON),
+        ),
+        # Axon response: both terminals carry status and process time
+        "query_response": Query(
+            name="Query",
+            query="ping",
+            response="pong",
+            timeout=12.0,
+            dendrite=bt.TerminalInfo(
+                **DENDRITE,
+                status_code=200,
+                status_message="Success",
+                process_time=0.0234,
+            ),
+            axon=bt.TerminalInfo(
+                **AXON,
+                status_code=200,
+                status_message="Success",
+                process_time=0.0012,
+                version=9001000,
+                nonce=1700000000223456789,
+                uuid="4c1e0a92-7d5c-11ee-9f2b-0242ac120002",
+            ),
+        ),
+    }
+
+
+def main():
+    if bt.__version__ != PINNED_SDK_VERSION:
+        sys.exit(
+            f"bittensor {bt.__version__} is installed, but the fixtures are "
+            f"pinned to {PINNED_SDK_VERSION}; install requirements.txt or "
+            "update PINNED_SDK_VERSION together with the fixtures"
+        )
+    for name, synapse in shapes().items():
+        headers = synapse.to_headers()
+        headers["header_size"] = str(HEADER_SIZE)
+        fixture = {
+            "sdk_version": bt.__version__,
+            "headers": headers,
+            "body": json.dumps(synapse.model_dump()),
+        }
+        with open(f"{name}.json", "w") as f:
+            json.dump(fixture, f, indent=2)
+            f.write("\n")
+        print(f"{name}.json: {len(headers)} headers")
+
+
+if __name__ == "__main__":
+    main()
@@ -7,7 +7,7 @@ use bittensor_rs::logging::{init_default_logging, is_initialized};
 use bittensor_rs::wallet::Wallet;
 use bittensor_rs::{
     chain::ConnectionManager, get_commit_reveal_version, sync_metagraph, BittensorClient, Config,
-    Metagraph, Subtensor,
+    Metagraph, Subtensor, SubtensorBuilder,
 };
 use bittensor_rs::{queries, utils::weights::normalize_weights};
 use bittensor_rs::{validator, ExtrinsicWait, DEFAULT_COMMIT_REVEAL_VERSION};
@@ -46,6 +46,36 @@ async fn test_client_connection() {
     }
 }
 
+const DEAD_ENDPOINT: &str = "ws://127.0.0.1:1";
+
+#[tokio::test]
+#[ignore = "requires network access to Finney"]
+async fn test_subtensor_builder_falls_back_to_second_endpoint() {
+    init_logging_once();
+    let subtensor = match SubtensorBuilder::new(DEAD_ENDPOINT)
+        .endpoints(vec![DEAD_ENDPOINT, FINNEY_ENDPOINT])
+        .build()
+        .await
+    {
+        Ok(subtensor) => subtensor,
+        Err(err) => {
+            eprintln!("Skipping test: unable to connect ({err})");
+            return;
+        }
+    };
+    assert_eq!(subtensor.current_endpoint(), FINNEY_ENDPOINT);
+}
+
+#[tokio::test]
+async fn test_subtensor_builder_fails_when_all_endpoints_dead() {
+    let result = SubtensorBuilder::new(DEAD_ENDPOINT)
+        .endpoints(vec![DEAD_ENDPOINT, "ws://127.0.0.1:2"])
+        .retry_forever(false)
+        .build()
+        .await;
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn test_metagraph_creation() {
     let metagraph = Metagraph::new(1);
@@ -109,6 +139,7 @@ async fn test_keypair_generation() {
 }
 
 #[tokio::test]
+#[ignore = "requires network access to Finney"]
 async fn test_chain_connection() {
     init_logging_once();
     let result = BittensorClient::new(FINNEY_ENDPOINT).await;
@@ -230,6 +261,7 @@ async fn test_transfer_and_stake_flow_requires_funded_keys() {
 }
 
 #[tokio::test]
+#[ignore = "requires network access to Finney"]
 async fn test_subtensor_set_weights_crv4_branching() {
     init_logging_once();
     let subtensor = Subtensor::new(FINNEY_ENDPOINT)
@@ -254,7 +286,15 @@ async fn test_subtensor_set_weights_crv4_branching() {
     let weights: Vec<f32> = vec![0.5, 0.3, 0.2];
     let (uid_u16, weight_u16) = normalize_weights(&uids, &weights).expect("normalize weights");
     let result = subtensor
-        .set_weights(&signer, 1, &uid_u16, &weight_u16, 0, ExtrinsicWait::None)
+        .set_weights(
+            &signer,
+            1,
+            &uid_u16,
+            &weight_u16,
+            None,
+            ExtrinsicWait::None,
+            false,
+        )
         .await;
     assert!(result.is_err());
 }
@@ -313,7 +353,8 @@ async fn test_crv4_commit_payload_uses_chain_drand() {
         reveal_period,
         12.0,
         chain_last_round,
-    );
+    )
+    .expect("reveal round");
     assert!(reveal_round >= chain_last_round);
 
     let (pair, _, _) = sr25519::Pair::generate_with_phrase(None);
@@ -326,6 +367,7 @@ async fn test_crv4_commit_payload_uses_chain_drand() {
 }
 
 #[tokio::test]
+#[ignore = "requires network access to Finney"]
 async fn test_connection_manager_retry_path() {
     init_logging_once();
     let manager = ConnectionManager::new(FINNEY_ENDPOINT);
@@ -0,0 +1,64 @@
+//! Property tests for the chain's IP address encoding
+//!
+//! Axons store their IP as a `u128` plus an IP type (4 or 6). These check
+//! that random addresses of both families survive the round trip through
+//! the encoding and through `AxonInfo`.
+
+use bittensor_rs::types::AxonInfo;
+use bittensor_rs::utils::networking::{int_to_ip, ip_to_int, ip_version};
+use proptest::prelude::*;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+proptest! {
+    #[test]
+    fn prop_ipv4_roundtrip(bits in any::<u32>()) {
+        let ip = IpAddr::V4(Ipv4Addr::from(bits));
+        prop_assert_eq!(ip_version(&ip), 4);
+        prop_assert_eq!(ip_to_int(&ip), bits as u128);
+        prop_assert_eq!(int_to_ip(ip_to_int(&ip), 4), Some(ip));
+    }
+
+    #[test]
+    fn prop_ipv6_roundtrip(bits in any::<u128>()) {
+        let ip = IpAddr::V6(Ipv6Addr::from(bits));
+        prop_assert_eq!(ip_version(&ip), 6);
+        prop_assert_eq!(ip_to_int(&ip), bits);
+        prop_assert_eq!(int_to_ip(ip_to_int(&ip), 6), Some(ip));
+    }
+
+    #[test]
+    fn prop_axon_info_socket_addr_roundtrip(bits in any::<u128>(), v4 in any::<bool>(), port in any::<u16>()) {
+        let ip = if v4 {
+            IpAddr::V4(Ipv4Addr::from(bits as u32))
+        } else {
+            IpAddr::V6(Ipv6Addr::from(bits))
+        };
+        let addr = SocketAddr::new(ip, port);
+        let axon = AxonInfo::from_socket_addr(addr, 4);
+
+        // Mapped IPv6 addresses are stored as their IPv4 equivalent
+        let canonical = ip.to_canonical();
+        prop_assert_eq!(axon.ip, canonical);
+        prop_assert_eq!(axon.ip_type, ip_version(&canonical));
+        prop_assert_eq!(int_to_ip(ip_to_int(&axon.ip), axon.ip_type), Some(canonical));
+
+        if canonical.is_unspecified() {
+            prop_assert_eq!(axon.socket_addr(), None);
+        } else {
+            prop_assert_eq!(axon.socket_addr(), Some(SocketAddr::new(canonical, port)));
+            let endpoint = axon.to_endpoint();
+            let parsed: SocketAddr = endpoint.trim_start_matches("http://").parse().unwrap();
+            prop_assert_eq!(parsed, SocketAddr::new(canonical, port));
+        }
+    }
+
+    #[test]
+    fn prop_mapped_ipv6_roundtrip(bits in any::<u32>()) {
+        let mapped = IpAddr::V6(Ipv4Addr::from(bits).to_ipv6_mapped());
+        prop_assert_eq!(int_to_ip(ip_to_int(&mapped), 6), Some(mapped));
+        prop_assert_eq!(
+            AxonInfo::from_socket_addr(SocketAddr::new(mapped, 8091), 4).ip,
+            IpAddr::V4(Ipv4Addr::from(bits))
+        );
+    }
+}
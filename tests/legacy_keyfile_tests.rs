@@ -0,0 +1,116 @@
+//! Legacy Python keyfile tests
+//!
+//! Validates that keyfiles encrypted by older Python SDKs load and migrate:
+//! - Ansible vault (bittensor 2.x)
+//! - Fernet (bittensor 3.x)
+//! - PyNaCl `$NACL` without a salt (bittensor-wallet)
+//!
+//! Fixtures live in `tests/fixtures/keyfiles`; see `generate_legacy_keyfiles.py`.
+//! All hold secret seed 0x00..01 under the password `testpassword123`.
+
+use bittensor_rs::wallet::{
+    is_legacy_format, migrate_legacy_keyfile, Keyfile, KeyfileError, Keypair, NACL_HEADER,
+};
+use std::path::{Path, PathBuf};
+use tempfile::tempdir;
+
+const PASSWORD: &str = "testpassword123";
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/keyfiles")
+        .join(name)
+}
+
+fn expected_keypair() -> Keypair {
+    let mut seed = [0u8; 32];
+    seed[31] = 1;
+    Keypair::from_seed(&seed).unwrap()
+}
+
+fn assert_loads(name: &str) {
+    let keyfile = Keyfile::new(fixture(name));
+    assert!(keyfile.is_encrypted());
+
+    let keypair = keyfile.get_keypair(Some(PASSWORD)).unwrap();
+    assert_eq!(keypair.public_key(), expected_keypair().public_key());
+}
+
+// ============================================================================
+// Ansible vault
+// ============================================================================
+
+#[test]
+fn test_ansible_vault_keyfile_loads() {
+    assert_loads("python_ansible_coldkey");
+}
+
+#[test]
+fn test_ansible_vault_keyfile_password_errors() {
+    let keyfile = Keyfile::new(fixture("python_ansible_coldkey"));
+    assert!(matches!(
+        keyfile.get_keypair(None),
+        Err(KeyfileError::PasswordRequired)
+    ));
+    assert!(matches!(
+        keyfile.get_keypair(Some("wrong")),
+        Err(KeyfileError::DecryptionFailed)
+    ));
+}
+
+#[test]
+fn test_migrate_ansible_vault_keyfile() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("coldkey");
+    std::fs::copy(fixture("python_ansible_coldkey"), &path).unwrap();
+    assert!(is_legacy_format(&std::fs::read(&path).unwrap()));
+
+    // A wrong password leaves the keyfile untouched
+    assert!(migrate_legacy_keyfile(&path, "wrong").is_err());
+    assert_eq!(
+        std::fs::read(&path).unwrap(),
+        std::fs::read(fixture("python_ansible_coldkey")).unwrap()
+    );
+
+    migrate_legacy_keyfile(&path, PASSWORD).unwrap();
+    let data = std::fs::read(&path).unwrap();
+    assert!(data.starts_with(NACL_HEADER));
+    assert!(!is_legacy_format(&data));
+
+    let keypair = Keyfile::new(&path).get_keypair(Some(PASSWORD)).unwrap();
+    assert_eq!(keypair.public_key(), expected_keypair().public_key());
+
+    // Already current
+    assert!(matches!(
+        migrate_legacy_keyfile(&path, PASSWORD),
+        Err(KeyfileError::InvalidFormat(_))
+    ));
+}
+
+// ============================================================================
+// Fernet and PyNaCl (slow key derivation)
+// ============================================================================
+
+#[test]
+#[ignore = "derives a key with 10M PBKDF2 rounds; run with --release --ignored"]
+fn test_fernet_keyfile_loads() {
+    assert_loads("python_fernet_coldkey");
+}
+
+#[test]
+#[ignore = "derives a key with 1 GiB Argon2i; run with --release --ignored"]
+fn test_pynacl_keyfile_loads() {
+    assert_loads("python_nacl_coldkey");
+}
+
+#[test]
+#[ignore = "derives a key with 1 GiB Argon2i; run with --release --ignored"]
+fn test_migrate_pynacl_keyfile() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("coldkey");
+    std::fs::copy(fixture("python_nacl_coldkey"), &path).unwrap();
+
+    migrate_legacy_keyfile(&path, PASSWORD).unwrap();
+    let keypair = Keyfile::new(&path).get_keypair(Some(PASSWORD)).unwrap();
+    assert_eq!(keypair.public_key(), expected_keypair().public_key());
+}
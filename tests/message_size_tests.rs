@@ -0,0 +1,111 @@
+//! WebSocket message size limit of clients from `BittensorClientBuilder`
+//!
+//! A local JSON-RPC server answers `big` with a result larger than the
+//! client's limit and `small` with a short one. No chain connection is
+//! required.
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::routing::get;
+use axum::Router;
+use bittensor_rs::chain::is_message_too_large_error;
+use bittensor_rs::BittensorClient;
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use subxt::ext::subxt_rpcs::rpc_params;
+
+const BIG_RESULT: usize = 2 * 1024 * 1024;
+
+async fn answer(mut socket: WebSocket) {
+    while let Some(Ok(message)) = socket.recv().await {
+        let Message::Text(text) = message else {
+            continue;
+        };
+        let request: Value = serde_json::from_str(&text).unwrap();
+        let result = match request["method"].as_str() {
+            Some("big") => "x".repeat(BIG_RESULT),
+            _ => "ok".to_string(),
+        };
+        let response = json!({"jsonrpc": "2.0", "id": request["id"], "result": result});
+        if socket
+            .send(Message::Text(response.to_string().into()))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+/// Start the server; the counter tracks WebSocket connections opened
+async fn serve() -> (SocketAddr, Arc<AtomicUsize>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let connections = Arc::new(AtomicUsize::new(0));
+    let counter = connections.clone();
+    let app = Router::new().route(
+        "/",
+        get(move |ws: WebSocketUpgrade| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { ws.on_upgrade(answer) }
+        }),
+    );
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    (addr, connections)
+}
+
+#[tokio::test]
+async fn test_oversized_response_detected_and_connection_reopened() {
+    let (addr, connections) = serve().await;
+    let rpc = BittensorClient::builder(format!("ws://{}", addr))
+        .max_message_size(BIG_RESULT / 2)
+        .build_rpc()
+        .await
+        .unwrap();
+
+    let small: String = rpc.request("small", rpc_params![]).await.unwrap();
+    assert_eq!(small, "ok");
+
+    let error = rpc
+        .request::<String>("big", rpc_params![])
+        .await
+        .expect_err("response is over the limit")
+        .to_string();
+    assert!(is_message_too_large_error(&error), "{}", error);
+
+    // The oversized frame closed the connection; the next request reopens it
+    let small: String = rpc.request("small", rpc_params![]).await.unwrap();
+    assert_eq!(small, "ok");
+    assert_eq!(connections.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_default_limit_accepts_large_response() {
+    let (addr, connections) = serve().await;
+    let rpc = BittensorClient::builder(format!("ws://{}", addr))
+        .build_rpc()
+        .await
+        .unwrap();
+
+    let big: String = rpc.request("big", rpc_params![]).await.unwrap();
+    assert_eq!(big.len(), BIG_RESULT);
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_message_too_large_errors() {
+    for message in [
+        "RPC error: The background task closed message too large: len >= 1048576, maximum = 1048576; restart required",
+        "RPC error: The message was too large",
+        "exceeded maximum message size",
+    ] {
+        assert!(is_message_too_large_error(message), "{}", message);
+    }
+    for message in [
+        "The background task closed connection closed; restart required",
+        "Failed to call get_subnet_state: Storage not found",
+    ] {
+        assert!(!is_message_too_large_error(message), "{}", message);
+    }
+}
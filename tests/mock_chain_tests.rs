@@ -0,0 +1,1290 @@
+//! SDK paths exercised against the in-memory `MockChain`
+//!
+//! Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use bittensor_rs::chain::{
+    signer_from_seed, BittensorSigner, ChainBackend, Error, ExtrinsicWait, MockChain,
+    RuntimeUpgrade,
+};
+use bittensor_rs::crv4::commit_timelocked_weights;
+use bittensor_rs::errors::BittensorError;
+use bittensor_rs::export::{export_snapshot_from, ExportFormat, SNAPSHOT_COLUMNS};
+use bittensor_rs::metagraph::{sync_metagraph_with_options, SyncOptions, SyncStage, SyncWarning};
+use bittensor_rs::queries::metagraph_queries;
+use bittensor_rs::queries::subnets::get_transfer_toggle;
+use bittensor_rs::queries::{
+    get_block_at_registration, get_recent_registrations, get_uid_weights,
+    immunity_blocks_remaining, neurons, neurons_filtered, NeuronFilter,
+};
+use bittensor_rs::utils::balance_newtypes::{Rao, Tao};
+use bittensor_rs::utils::{
+    resolve_hotkey_weights, ResolvedWeight, WeightDiff, WeightValidation, WeightVerification,
+};
+use bittensor_rs::validator::transfer::{preview_transfer, transfer_safe, TransferAmount};
+use bittensor_rs::{
+    sync_metagraph, transfer_stake, validator_children, validator_weights, Subtensor,
+};
+use parity_scale_codec::Encode;
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use subxt::dynamic::Value;
+
+const SUBTENSOR: &str = "SubtensorModule";
+const NETUID: u16 = 3;
+
+fn netuid_key() -> Vec<Value> {
+    vec![Value::u128(NETUID as u128)]
+}
+
+fn account_value(account: &AccountId32) -> Value {
+    Value::from_bytes(<[u8; 32]>::from(account.clone()))
+}
+
+/// A subnet with `hotkeys.len()` neurons and their per-UID storage
+fn seed_subnet(chain: &MockChain, hotkeys: &[AccountId32], coldkeys: &[AccountId32]) {
+    let n = hotkeys.len() as u128;
+    chain.set_storage(SUBTENSOR, "SubnetworkN", netuid_key(), Value::u128(n));
+    chain.set_storage(
+        SUBTENSOR,
+        "Incentive",
+        netuid_key(),
+        Value::unnamed_composite(
+            (0..n)
+                .map(|uid| Value::u128(uid * 1000))
+                .collect::<Vec<_>>(),
+        ),
+    );
+    chain.set_storage(
+        SUBTENSOR,
+        "ValidatorPermit",
+        netuid_key(),
+        Value::unnamed_composite((0..n).map(|uid| Value::bool(uid == 0)).collect::<Vec<_>>()),
+    );
+    chain.set_storage(
+        SUBTENSOR,
+        "Active",
+        netuid_key(),
+        Value::unnamed_composite((0..n).map(|_| Value::bool(true)).collect::<Vec<_>>()),
+    );
+
+    for (uid, (hotkey, coldkey)) in hotkeys.iter().zip(coldkeys).enumerate() {
+        let uid_key = vec![Value::u128(NETUID as u128), Value::u128(uid as u128)];
+        chain.set_storage(SUBTENSOR, "Keys", uid_key.clone(), account_value(hotkey));
+        chain.set_storage(
+            SUBTENSOR,
+            "BlockAtRegistration",
+            uid_key,
+            Value::u128(100 + uid as u128),
+        );
+        chain.set_storage(
+            SUBTENSOR,
+            "Owner",
+            vec![Value::from_bytes(hotkey.encode())],
+            account_value(coldkey),
+        );
+        chain.set_storage(
+            SUBTENSOR,
+            "TotalHotkeyAlpha",
+            vec![
+                Value::from_bytes(hotkey.encode()),
+                Value::u128(NETUID as u128),
+            ],
+            Value::u128((uid as u128 + 1) * 1_000_000_000),
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_sync_metagraph_from_mock() {
+    let chain = MockChain::new();
+    let hotkeys = [AccountId32::new([1u8; 32]), AccountId32::new([2u8; 32])];
+    let coldkeys = [AccountId32::new([11u8; 32]), AccountId32::new([12u8; 32])];
+    seed_subnet(&chain, &hotkeys, &coldkeys);
+    chain.advance_blocks(4_200);
+
+    let metagraph = sync_metagraph(&chain, NETUID).await.unwrap();
+
+    assert_eq!(metagraph.block, 4_200);
+    assert_eq!(metagraph.n, 2);
+    assert_eq!(metagraph.hotkeys, hotkeys);
+    assert_eq!(metagraph.coldkeys, coldkeys);
+    assert_eq!(metagraph.validator_permit, [true, false]);
+    assert_eq!(metagraph.active, [true, true]);
+    assert_eq!(metagraph.block_at_registration, [100, 101]);
+
+    // No runtime API mocked: stakes come from storage
+    let neuron = &metagraph.neurons[&1];
+    assert_eq!(u128::from(neuron.stake), 2_000_000_000);
+    assert!((neuron.incentive - 1000.0 / 65535.0).abs() < 1e-12);
+}
+
+#[tokio::test]
+async fn test_resync_reindexes_replaced_hotkey() {
+    let chain = MockChain::new();
+    let old = AccountId32::new([1u8; 32]);
+    let new = AccountId32::new([3u8; 32]);
+    let coldkeys = [AccountId32::new([11u8; 32]), AccountId32::new([12u8; 32])];
+    seed_subnet(
+        &chain,
+        &[old.clone(), AccountId32::new([2u8; 32])],
+        &coldkeys,
+    );
+
+    let mut metagraph = sync_metagraph(&chain, NETUID).await.unwrap();
+    let view = metagraph.neuron_by_hotkey(&old.to_ss58check()).unwrap();
+    assert_eq!(view.uid, 0);
+    assert!(metagraph.neuron_by_hotkey(&new.to_ss58check()).is_none());
+
+    // UID 0 is deregistered and taken by a new hotkey
+    chain.set_storage(
+        SUBTENSOR,
+        "Keys",
+        vec![Value::u128(NETUID as u128), Value::u128(0)],
+        account_value(&new),
+    );
+    chain.set_storage(
+        SUBTENSOR,
+        "Owner",
+        vec![Value::from_bytes(new.encode())],
+        account_value(&coldkeys[0]),
+    );
+    metagraph = sync_metagraph(&chain, NETUID).await.unwrap();
+
+    assert!(metagraph.neuron_by_hotkey(&old.to_ss58check()).is_none());
+    let view = metagraph.neuron_by_hotkey(&new.to_ss58check()).unwrap();
+    assert_eq!(view.uid, 0);
+    assert_eq!(view.hotkey, new);
+    assert_eq!(view.block_at_registration, 100);
+    assert_eq!(
+        metagraph.validators().map(|v| v.uid).collect::<Vec<_>>(),
+        [0]
+    );
+}
+
+#[tokio::test]
+async fn test_sync_metagraph_missing_subnet() {
+    let chain = MockChain::new();
+    assert!(sync_metagraph(&chain, NETUID).await.is_err());
+}
+
+#[tokio::test]
+async fn test_export_snapshot_csv() {
+    let chain = MockChain::new();
+    let hotkeys = [AccountId32::new([1u8; 32]), AccountId32::new([2u8; 32])];
+    let coldkeys = [AccountId32::new([11u8; 32]), AccountId32::new([12u8; 32])];
+    seed_subnet(&chain, &hotkeys, &coldkeys);
+    chain.set_storage("Timestamp", "Now", vec![], Value::u128(1_700_000_000_000));
+    chain.advance_blocks(4_200);
+
+    let mut out = Vec::new();
+    let summary = export_snapshot_from(&chain, NETUID, ExportFormat::Csv, &mut out)
+        .await
+        .unwrap();
+    assert_eq!(summary.rows, 2);
+    assert_eq!(summary.block, 4_200);
+    assert_eq!(
+        summary.timestamp.as_deref(),
+        Some("2023-11-14T22:13:20.000Z")
+    );
+
+    let csv = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], SNAPSHOT_COLUMNS.join(","));
+
+    let column = |row: &str, name: &str| -> String {
+        let index = SNAPSHOT_COLUMNS.iter().position(|c| *c == name).unwrap();
+        row.split(',').nth(index).unwrap().to_string()
+    };
+    for (uid, row) in lines[1..].iter().enumerate() {
+        assert_eq!(row.split(',').count(), SNAPSHOT_COLUMNS.len());
+        assert_eq!(column(row, "netuid"), NETUID.to_string());
+        assert_eq!(column(row, "block"), "4200");
+        assert_eq!(column(row, "timestamp"), "2023-11-14T22:13:20.000Z");
+        assert_eq!(column(row, "uid"), uid.to_string());
+        assert_eq!(column(row, "hotkey"), hotkeys[uid].to_string());
+        assert_eq!(column(row, "coldkey"), coldkeys[uid].to_string());
+        assert_eq!(
+            column(row, "block_at_registration"),
+            (100 + uid).to_string()
+        );
+        // No axon served
+        assert_eq!(column(row, "axon_ip"), "");
+    }
+    assert_eq!(column(lines[1], "validator_permit"), "true");
+    assert_eq!(column(lines[2], "validator_permit"), "false");
+    assert_eq!(column(lines[2], "stake_rao"), "2000000000");
+    assert_eq!(column(lines[2], "stake_tao"), "2.000000000");
+
+    let mut out = Vec::new();
+    export_snapshot_from(&chain, NETUID, ExportFormat::JsonLines, &mut out)
+        .await
+        .unwrap();
+    let rows: Vec<serde_json::Value> = String::from_utf8(out)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[1]["uid"], 1);
+    assert_eq!(rows[1]["stake_tao"], "2.000000000");
+    assert!(rows[1]["axon_ip"].is_null());
+}
+
+/// A [`MockChain`] whose reads of `entry` with `key` among their keys fail
+struct FailingChain {
+    inner: MockChain,
+    entry: &'static str,
+    key: Value,
+    /// Failures left before reads succeed again
+    failures: AtomicU32,
+}
+
+impl FailingChain {
+    fn new(inner: MockChain, entry: &'static str, key: Value, failures: u32) -> Self {
+        Self {
+            inner,
+            entry,
+            key,
+            failures: AtomicU32::new(failures),
+        }
+    }
+}
+
+impl ChainBackend for FailingChain {
+    async fn storage_with_keys(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> Result<Option<Value>, Error> {
+        if entry == self.entry
+            && keys.contains(&self.key)
+            && self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok()
+        {
+            return Err(Error::Rpc("connection reset by peer".into()));
+        }
+        self.inner.storage_with_keys(module, entry, keys).await
+    }
+
+    async fn storage_iter(
+        &self,
+        module: &str,
+        entry: &str,
+        keys: Vec<Value>,
+    ) -> Result<Vec<(Vec<u8>, Value)>, Error> {
+        self.inner.storage_iter(module, entry, keys).await
+    }
+
+    async fn runtime_api_call(
+        &self,
+        runtime_api: &str,
+        method: &str,
+        params: Option<Vec<u8>>,
+    ) -> Result<Vec<u8>, Error> {
+        self.inner
+            .runtime_api_call(runtime_api, method, params)
+            .await
+    }
+
+    async fn submit_extrinsic(
+        &self,
+        module: &str,
+        function: &str,
+        args: Vec<Value>,
+        signer: &BittensorSigner,
+        wait_for: ExtrinsicWait,
+    ) -> Result<String, Error> {
+        self.inner
+            .submit_extrinsic(module, function, args, signer, wait_for)
+            .await
+    }
+
+    async fn block_number(&self) -> Result<u64, Error> {
+        self.inner.block_number().await
+    }
+}
+
+fn three_neurons() -> (MockChain, [AccountId32; 3], [AccountId32; 3]) {
+    let chain = MockChain::new();
+    let hotkeys = [1u8, 2, 3].map(|b| AccountId32::new([b; 32]));
+    let coldkeys = [11u8, 12, 13].map(|b| AccountId32::new([b; 32]));
+    seed_subnet(&chain, &hotkeys, &coldkeys);
+    (chain, hotkeys, coldkeys)
+}
+
+#[tokio::test]
+async fn test_partial_sync_records_failed_fetches() {
+    let (chain, hotkeys, coldkeys) = three_neurons();
+    let chain = FailingChain::new(chain, "Owner", account_value(&hotkeys[1]), u32::MAX);
+
+    // Without partial results the failure aborts the sync
+    let error = sync_metagraph_with_options(&chain, NETUID, None, &SyncOptions::new())
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("coldkey of UID 1"), "{}", error);
+
+    let options = SyncOptions::new().with_allow_partial(true);
+    let metagraph = sync_metagraph_with_options(&chain, NETUID, None, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(metagraph.n, 3);
+    assert_eq!(metagraph.hotkeys, hotkeys);
+    assert_eq!(metagraph.coldkeys[0], coldkeys[0]);
+    assert_eq!(metagraph.coldkeys[1], AccountId32::new([0u8; 32]));
+    assert_eq!(metagraph.coldkeys[2], coldkeys[2]);
+    assert_eq!(u128::from(metagraph.neurons[&1].stake), 2_000_000_000);
+    assert_eq!(metagraph.validator_permit, [true, false, false]);
+    assert_eq!(metagraph.block_at_registration, [100, 101, 102]);
+    assert_eq!(metagraph.sync_warnings.len(), 1);
+    let warning = &metagraph.sync_warnings[0];
+    assert_eq!((warning.uid, warning.field), (Some(1), "coldkey"));
+    assert!(warning.error.contains("connection reset"));
+}
+
+#[tokio::test]
+async fn test_partial_sync_keeps_previous_values() {
+    let (chain, hotkeys, coldkeys) = three_neurons();
+    let mut metagraph = sync_metagraph(&chain, NETUID).await.unwrap();
+    assert!(metagraph.sync_warnings.is_empty());
+
+    // UID 2's hotkey and the incentive vector cannot be read
+    chain.set_storage(
+        SUBTENSOR,
+        "Incentive",
+        netuid_key(),
+        Value::unnamed_composite(vec![Value::u128(7), Value::u128(7), Value::u128(7)]),
+    );
+    let chain = FailingChain::new(chain, "Keys", Value::u128(2), u32::MAX);
+    let options = SyncOptions::new().with_allow_partial(true);
+    metagraph.sync_with_options(&chain, &options).await.unwrap();
+
+    assert_eq!(metagraph.hotkeys, hotkeys);
+    assert_eq!(metagraph.coldkeys, coldkeys);
+    assert!(!metagraph.neurons[&2].is_null);
+    assert!((metagraph.neurons[&0].incentive - 7.0 / 65535.0).abs() < 1e-12);
+    assert_eq!(
+        metagraph.sync_warnings,
+        [SyncWarning {
+            uid: Some(2),
+            field: "hotkey",
+            error: metagraph.sync_warnings[0].error.clone(),
+        }]
+    );
+
+    // Without a previous sync the UID is left out
+    let fresh = sync_metagraph_with_options(&chain, NETUID, None, &options)
+        .await
+        .unwrap();
+    assert!(!fresh.neurons.contains_key(&2));
+    assert_eq!(fresh.hotkeys, hotkeys[..2]);
+    assert_eq!(fresh.neurons[&0].hotkey, hotkeys[0]);
+}
+
+/// Serve an IPv4 axon for `hotkey`
+fn set_axon(chain: &MockChain, hotkey: &AccountId32, ip: [u8; 4], port: u16) {
+    chain.set_storage(
+        SUBTENSOR,
+        "Axons",
+        vec![
+            Value::u128(NETUID as u128),
+            Value::from_bytes(hotkey.encode()),
+        ],
+        Value::named_composite([
+            ("block", Value::u128(1)),
+            ("version", Value::u128(1)),
+            ("ip", Value::u128(u32::from_be_bytes(ip) as u128)),
+            ("port", Value::u128(port as u128)),
+            ("ip_type", Value::u128(4)),
+            ("protocol", Value::u128(4)),
+            ("placeholder1", Value::u128(0)),
+            ("placeholder2", Value::u128(0)),
+        ]),
+    );
+}
+
+#[tokio::test]
+async fn test_resync_diff_reports_axon_changes() {
+    let (chain, hotkeys, _) = three_neurons();
+    set_axon(&chain, &hotkeys[0], [10, 0, 0, 1], 8091);
+
+    // Axons are only read when asked for
+    let default = sync_metagraph_with_options(&chain, NETUID, None, &SyncOptions::new())
+        .await
+        .unwrap();
+    assert!(default.axons.is_empty());
+
+    // As `btcli subnet watch` syncs each tempo
+    let options = SyncOptions::new()
+        .with_max_retries_per_stage(2)
+        .with_axons(true);
+    let previous = sync_metagraph_with_options(&chain, NETUID, None, &options)
+        .await
+        .unwrap();
+    assert_eq!(previous.axons[&0].port, 8091);
+    assert!(!previous.axons.contains_key(&1));
+
+    set_axon(&chain, &hotkeys[0], [10, 0, 0, 1], 9000);
+    set_axon(&chain, &hotkeys[1], [10, 0, 0, 2], 8091);
+    chain.advance_blocks(360);
+    let current = sync_metagraph_with_options(&chain, NETUID, Some(&previous), &options)
+        .await
+        .unwrap();
+
+    let diff = previous.diff(&current);
+    let changes: Vec<_> = diff
+        .axon_changes
+        .iter()
+        .map(|change| (change.uid, change.old, change.new))
+        .collect();
+    assert_eq!(
+        changes,
+        [
+            (
+                0,
+                Some("10.0.0.1:8091".parse().unwrap()),
+                Some("10.0.0.1:9000".parse().unwrap())
+            ),
+            (1, None, Some("10.0.0.2:8091".parse().unwrap())),
+        ]
+    );
+    assert!(diff.registered.is_empty() && diff.deregistered.is_empty());
+}
+
+#[tokio::test]
+async fn test_sync_retries_and_reports_progress() {
+    let (chain, hotkeys, _) = three_neurons();
+    let chain = FailingChain::new(chain, "TotalHotkeyAlpha", account_value(&hotkeys[0]), 1);
+
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let recorded = progress.clone();
+    let options = SyncOptions::new()
+        .with_max_retries_per_stage(1)
+        .with_axons(true)
+        .with_weights(true)
+        .with_progress(move |stage, completed, total| {
+            recorded.lock().unwrap().push((stage, completed, total));
+        });
+    let metagraph = sync_metagraph_with_options(&chain, NETUID, None, &options)
+        .await
+        .unwrap();
+
+    // The failed stake read succeeded on retry
+    assert!(metagraph.sync_warnings.is_empty());
+    assert_eq!(u128::from(metagraph.neurons[&0].stake), 1_000_000_000);
+
+    let progress = progress.lock().unwrap();
+    for stage in SyncStage::ALL {
+        let updates: Vec<_> = progress.iter().filter(|(s, _, _)| *s == stage).collect();
+        assert_eq!(updates.first().map(|u| u.1), Some(0), "{}", stage);
+        let (_, completed, total) = updates.last().unwrap();
+        assert_eq!(completed, total, "{}", stage);
+    }
+    assert!(progress.contains(&(SyncStage::Stake, 6, 6)));
+}
+
+#[tokio::test]
+async fn test_profile_report_for_sync_and_crv4_commit() {
+    let (chain, _, _) = three_neurons();
+    let signer = signer_from_seed("//Alice").unwrap();
+    chain.set_delay("SubtensorModule.Keys", Duration::from_millis(20));
+    chain.set_delay(
+        "SubtensorModule.commit_timelocked_weights",
+        Duration::from_millis(150),
+    );
+
+    // Nothing is recorded until profiling is enabled
+    sync_metagraph(&chain, NETUID).await.unwrap();
+    assert!(chain.profile_report().is_empty());
+
+    chain.enable_profiling(true);
+    sync_metagraph(&chain, NETUID).await.unwrap();
+    commit_timelocked_weights(
+        &chain,
+        &signer,
+        NETUID,
+        b"encrypted",
+        1_000,
+        4,
+        ExtrinsicWait::Included,
+    )
+    .await
+    .unwrap();
+    chain.enable_profiling(false);
+
+    let report = chain.profile_report();
+    let keys = report.get("SubtensorModule.Keys").unwrap();
+    assert_eq!(keys.count, 3);
+    assert_eq!(keys.failures, 0);
+    assert!(keys.mean >= Duration::from_millis(20), "{:?}", keys);
+    assert!(keys.p95 >= Duration::from_millis(20), "{:?}", keys);
+    assert!(keys.total >= Duration::from_millis(60), "{:?}", keys);
+
+    let commit = report
+        .get("SubtensorModule.commit_timelocked_weights")
+        .unwrap();
+    assert_eq!(commit.count, 1);
+    assert!(commit.p95 >= Duration::from_millis(150), "{:?}", commit);
+
+    // Slowest call types come first
+    assert_eq!(
+        report.calls[0].call,
+        "SubtensorModule.commit_timelocked_weights"
+    );
+    assert_eq!(report.calls[1].call, "SubtensorModule.Keys");
+    assert!(report.get("SubtensorModule.SubnetworkN").is_some());
+    let table = report.to_string();
+    assert!(table
+        .lines()
+        .nth(1)
+        .unwrap()
+        .starts_with("SubtensorModule.commit_timelocked_weights"));
+    assert_eq!(table.lines().count(), report.calls.len() + 1);
+}
+
+fn seed_weight_bounds(chain: &MockChain, n: u128) {
+    chain.set_storage(SUBTENSOR, "SubnetworkN", netuid_key(), Value::u128(n));
+    chain.set_storage(SUBTENSOR, "MinAllowedWeights", netuid_key(), Value::u128(1));
+    chain.set_storage(
+        SUBTENSOR,
+        "MaxWeightsLimit",
+        netuid_key(),
+        Value::u128(u16::MAX as u128),
+    );
+}
+
+#[tokio::test]
+async fn test_set_weights_against_mock() {
+    let chain = MockChain::new();
+    seed_weight_bounds(&chain, 4);
+    let signer = signer_from_seed("//Alice").unwrap();
+
+    let tx_hash = validator_weights::set_weights(
+        &chain,
+        &signer,
+        NETUID,
+        &[0, 2],
+        &[30_000, 35_535],
+        7,
+        WeightValidation::Strict,
+        ExtrinsicWait::Included,
+    )
+    .await
+    .unwrap();
+    assert!(tx_hash.starts_with("0x"));
+
+    let submitted = chain.submitted();
+    assert_eq!(submitted.len(), 1);
+    let call = &submitted[0];
+    assert_eq!(
+        (call.module.as_str(), call.function.as_str()),
+        (SUBTENSOR, "set_weights")
+    );
+    assert_eq!(call.signer, AccountId32::from(signer.account_id().0));
+    assert_eq!(
+        call.args,
+        vec![
+            Value::from(NETUID),
+            Value::unnamed_composite(vec![Value::from(0u16), Value::from(2u16)]),
+            Value::unnamed_composite(vec![Value::from(30_000u16), Value::from(35_535u16)]),
+            Value::from(7u64),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_submissions_survive_runtime_upgrade() {
+    let chain = MockChain::new();
+    seed_weight_bounds(&chain, 4);
+    chain.set_runtime_version(300, 1);
+    chain.refresh_runtime();
+    let signer = signer_from_seed("//Alice").unwrap();
+
+    let upgrades = Arc::new(Mutex::new(Vec::new()));
+    let recorded = upgrades.clone();
+    chain.on_runtime_upgrade(move |upgrade| recorded.lock().unwrap().push(*upgrade));
+
+    let set_weights = || {
+        validator_weights::set_weights(
+            &chain,
+            &signer,
+            NETUID,
+            &[0, 2],
+            &[30_000, 35_535],
+            7,
+            WeightValidation::Strict,
+            ExtrinsicWait::Included,
+        )
+    };
+    set_weights().await.unwrap();
+    assert!(upgrades.lock().unwrap().is_empty());
+
+    chain.set_runtime_version(301, 2);
+    assert_eq!(chain.client_runtime_version(), (300, 1));
+    set_weights().await.unwrap();
+
+    assert_eq!(chain.client_runtime_version(), (301, 2));
+    assert_eq!(
+        *upgrades.lock().unwrap(),
+        [RuntimeUpgrade {
+            old_spec_version: 300,
+            new_spec_version: 301,
+            old_transaction_version: 1,
+            new_transaction_version: 2,
+        }]
+    );
+    assert_eq!(chain.submitted().len(), 2);
+    assert_eq!(chain.refresh_runtime(), None);
+}
+
+#[tokio::test]
+async fn test_set_weights_rejected_before_submission() {
+    let chain = MockChain::new();
+    seed_weight_bounds(&chain, 2);
+    let signer = signer_from_seed("//Alice").unwrap();
+
+    // UID 5 is outside a 2-neuron subnet
+    let result = validator_weights::set_weights(
+        &chain,
+        &signer,
+        NETUID,
+        &[0, 5],
+        &[1, 1],
+        0,
+        WeightValidation::Strict,
+        ExtrinsicWait::Included,
+    )
+    .await;
+    assert!(result.is_err());
+    assert!(chain.submitted().is_empty());
+}
+
+#[tokio::test]
+async fn test_set_weights_dispatch_error() {
+    let chain = MockChain::new();
+    seed_weight_bounds(&chain, 4);
+    chain.push_extrinsic_result(Err(Error::DispatchError(
+        "SubtensorModule.SettingWeightsTooFast: rate limited".into(),
+    )));
+    let signer = signer_from_seed("//Alice").unwrap();
+
+    let err = validator_weights::set_weights(
+        &chain,
+        &signer,
+        NETUID,
+        &[0, 1],
+        &[1, 1],
+        0,
+        WeightValidation::Strict,
+        ExtrinsicWait::Included,
+    )
+    .await
+    .unwrap_err();
+    assert!(err.to_string().contains("SettingWeightsTooFast"));
+    assert_eq!(chain.submitted().len(), 1);
+}
+
+#[tokio::test]
+async fn test_version_key_resolution() {
+    let chain = MockChain::new();
+    chain.set_storage(
+        SUBTENSOR,
+        "WeightsVersionKey",
+        netuid_key(),
+        Value::u128(42),
+    );
+
+    // Auto: the subnet's key
+    let key = validator_weights::resolve_version_key(&chain, NETUID, None)
+        .await
+        .unwrap();
+    assert_eq!(key, 42);
+
+    // Explicit: kept when at least the subnet's key
+    for explicit in [42, 50] {
+        let key = validator_weights::resolve_version_key(&chain, NETUID, Some(explicit))
+            .await
+            .unwrap();
+        assert_eq!(key, explicit);
+    }
+
+    let err = validator_weights::resolve_version_key(&chain, NETUID, Some(41))
+        .await
+        .unwrap_err();
+    let BittensorError::WeightVersionMismatch(mismatch) = &err else {
+        panic!("expected WeightVersionMismatch, got {:?}", err);
+    };
+    assert_eq!(mismatch.expected_version, Some(42));
+    assert_eq!(mismatch.provided_version, Some(41));
+
+    // Subnets without a key accept any version
+    let key = validator_weights::resolve_version_key(&chain, NETUID + 1, Some(0))
+        .await
+        .unwrap();
+    assert_eq!(key, 0);
+    assert!(chain.submitted().is_empty());
+}
+
+#[tokio::test]
+async fn test_last_update_and_permits() {
+    let chain = MockChain::new();
+    chain.set_storage(
+        SUBTENSOR,
+        "LastUpdate",
+        netuid_key(),
+        Value::unnamed_composite(vec![Value::u128(900), Value::u128(1_150)]),
+    );
+    chain.set_storage(
+        SUBTENSOR,
+        "ValidatorPermit",
+        netuid_key(),
+        Value::unnamed_composite(vec![Value::bool(false), Value::bool(true)]),
+    );
+    chain.advance_blocks(1_200);
+
+    let last = metagraph_queries::get_last_update(&chain, NETUID, 1)
+        .await
+        .unwrap();
+    assert_eq!(last, 1_150);
+    let since = metagraph_queries::blocks_since_last_update(&chain, NETUID, 0)
+        .await
+        .unwrap();
+    assert_eq!(since, 300);
+    assert!(metagraph_queries::get_last_update(&chain, NETUID, 2)
+        .await
+        .is_err());
+
+    let permits = metagraph_queries::get_validator_permits(&chain, NETUID)
+        .await
+        .unwrap();
+    assert_eq!(permits, [false, true]);
+}
+
+#[tokio::test]
+async fn test_childkey_take() {
+    let chain = MockChain::new();
+    let signer = signer_from_seed("//Alice").unwrap();
+    let hotkey = AccountId32::new([5; 32]);
+    let take_key = vec![
+        Value::from_bytes(hotkey.encode()),
+        Value::u128(NETUID as u128),
+    ];
+
+    assert_eq!(
+        validator_children::get_childkey_take(&chain, &hotkey, NETUID)
+            .await
+            .unwrap(),
+        0
+    );
+    chain.set_storage(SUBTENSOR, "ChildkeyTake", take_key, Value::u128(1_000));
+    assert_eq!(
+        validator_children::get_childkey_take(&chain, &hotkey, NETUID)
+            .await
+            .unwrap(),
+        1_000
+    );
+
+    // Bounds come from storage, not the runtime defaults
+    chain.set_storage(SUBTENSOR, "MinChildkeyTake", vec![], Value::u128(500));
+    chain.set_storage(SUBTENSOR, "MaxChildkeyTake", vec![], Value::u128(6_000));
+    for take in [499, 6_001] {
+        let err = validator_children::set_childkey_take(
+            &chain,
+            &signer,
+            &hotkey,
+            NETUID,
+            take,
+            ExtrinsicWait::Included,
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("outside the allowed range"),
+            "{}",
+            err
+        );
+    }
+    assert!(chain.submitted().is_empty());
+
+    // Changed at block 100 with a 50-block limit
+    chain.set_storage(
+        SUBTENSOR,
+        "LastTxBlockChildKeyTake",
+        vec![Value::from_bytes(hotkey.encode())],
+        Value::u128(100),
+    );
+    chain.set_storage(
+        SUBTENSOR,
+        "TxChildkeyTakeRateLimit",
+        vec![],
+        Value::u128(50),
+    );
+    chain.set_block(120);
+    let err = validator_children::set_childkey_take(
+        &chain,
+        &signer,
+        &hotkey,
+        NETUID,
+        6_000,
+        ExtrinsicWait::Included,
+    )
+    .await
+    .unwrap_err();
+    let BittensorError::RateLimited(limited) = &err else {
+        panic!("expected RateLimited, got {:?}", err);
+    };
+    assert_eq!(limited.retry_after_blocks, Some(30));
+    assert!(chain.submitted().is_empty());
+
+    chain.set_block(150);
+    validator_children::set_childkey_take(
+        &chain,
+        &signer,
+        &hotkey,
+        NETUID,
+        6_000,
+        ExtrinsicWait::Included,
+    )
+    .await
+    .unwrap();
+    let submitted = chain.submitted();
+    assert_eq!(submitted.len(), 1);
+    assert_eq!(submitted[0].function, "set_childkey_take");
+    assert_eq!(submitted[0].args[1], Value::from(NETUID));
+    assert_eq!(submitted[0].args[2], Value::from(6_000u16));
+}
+
+#[tokio::test]
+async fn test_transfer_stake_checks() {
+    let chain = MockChain::new();
+    let signer = signer_from_seed("//Alice").unwrap();
+    let coldkey = AccountId32::from(signer.account_id().0);
+    let hotkey = AccountId32::new([5; 32]);
+    let dest = AccountId32::new([6; 32]);
+    let amount = Rao::from(1_000u128);
+    let (chain_ref, signer_ref, hotkey_ref) = (&chain, &signer, &hotkey);
+    let transfer = move |destination: AccountId32| async move {
+        transfer_stake(
+            chain_ref,
+            signer_ref,
+            &destination,
+            hotkey_ref,
+            NETUID,
+            NETUID,
+            amount,
+            ExtrinsicWait::Included,
+        )
+        .await
+    };
+
+    // Toggle defaults to enabled
+    assert!(get_transfer_toggle(&chain, NETUID).await.unwrap());
+    chain.set_storage(
+        SUBTENSOR,
+        "TransferToggle",
+        netuid_key(),
+        Value::bool(false),
+    );
+    assert!(!get_transfer_toggle(&chain, NETUID).await.unwrap());
+    let err = transfer(dest.clone()).await.unwrap_err();
+    let BittensorError::StakeTransferDisabled(disabled) = &err else {
+        panic!("expected StakeTransferDisabled, got {:?}", err);
+    };
+    assert_eq!(disabled.netuid, NETUID);
+
+    // No alpha on the origin subnet yet
+    chain.set_storage(SUBTENSOR, "TransferToggle", netuid_key(), Value::bool(true));
+    let err = transfer(dest.clone()).await.unwrap_err();
+    let BittensorError::InsufficientStake(short) = &err else {
+        panic!("expected InsufficientStake, got {:?}", err);
+    };
+    assert_eq!(short.current_stake, Some(0));
+
+    // Same coldkey and subnet is a no-op
+    assert!(transfer(coldkey.clone()).await.is_err());
+    assert!(chain.submitted().is_empty());
+
+    chain.set_storage(
+        SUBTENSOR,
+        "Alpha",
+        vec![
+            account_value(&hotkey),
+            account_value(&coldkey),
+            Value::u128(NETUID as u128),
+        ],
+        Value::u128(5_000),
+    );
+    transfer(dest.clone()).await.unwrap();
+    let submitted = chain.submitted();
+    assert_eq!(submitted.len(), 1);
+    assert_eq!(submitted[0].function, "transfer_stake");
+    assert_eq!(submitted[0].args[0], account_value(&dest));
+    assert_eq!(submitted[0].args[4], Value::u128(1_000));
+}
+
+#[tokio::test]
+async fn test_transfer_safe_fee_headroom() {
+    const ED: Rao = Rao::new(500_000_000);
+    let chain = MockChain::new();
+    let signer = signer_from_seed("//Alice").unwrap();
+    let source = AccountId32::from(signer.account_id().0);
+    let dest = AccountId32::new([6; 32]);
+    chain.set_storage(
+        "System",
+        "Account",
+        vec![account_value(&source)],
+        Value::named_composite([(
+            "data",
+            Value::named_composite([("free", Value::u128(3_000_000_000))]),
+        )]),
+    );
+    chain.set_fee("Balances", "transfer_keep_alive", 250_000_000);
+
+    let preview = preview_transfer(&chain, &signer, &dest, TransferAmount::Max, true, ED)
+        .await
+        .unwrap();
+    assert_eq!(preview.amount, Rao::new(2_250_000_000));
+    assert_eq!(preview.fee, Rao::new(250_000_000));
+    assert_eq!(preview.remaining, ED);
+    assert!(chain.submitted().is_empty());
+
+    // One rao more than the headroom allows
+    let err = transfer_safe(
+        &chain,
+        &signer,
+        &dest,
+        Tao::new(2.25).into(),
+        true,
+        ED.saturating_add(Rao::new(1)),
+        ExtrinsicWait::Included,
+    )
+    .await
+    .unwrap_err();
+    let BittensorError::InsufficientBalance(short) = &err else {
+        panic!("expected InsufficientBalance, got {:?}", err);
+    };
+    assert_eq!(short.required, Some(3_000_000_001));
+    assert_eq!(short.available, Some(3_000_000_000));
+    assert!(chain.submitted().is_empty());
+
+    let (_, sent) = transfer_safe(
+        &chain,
+        &signer,
+        &dest,
+        TransferAmount::Max,
+        true,
+        ED,
+        ExtrinsicWait::Included,
+    )
+    .await
+    .unwrap();
+    assert_eq!(sent, preview);
+    let submitted = chain.submitted();
+    assert_eq!(submitted.len(), 1);
+    assert_eq!(submitted[0].function, "transfer_keep_alive");
+    assert_eq!(submitted[0].args[0], account_value(&dest));
+    assert_eq!(submitted[0].args[1], Value::u128(2_250_000_000));
+
+    // Unscripted fees are zero, so a sweep empties the account
+    let preview = preview_transfer(&chain, &signer, &dest, TransferAmount::Max, false, ED)
+        .await
+        .unwrap();
+    assert_eq!(preview.amount, Rao::new(3_000_000_000));
+    assert!(preview.reaps_sender);
+}
+
+#[tokio::test]
+async fn test_subtensor_on_mock_backend() {
+    let chain = MockChain::new();
+    chain.set_block(1_000);
+    chain.set_storage(SUBTENSOR, "Tempo", netuid_key(), Value::u128(360));
+    let signer = signer_from_seed("//Alice").unwrap();
+    let source = AccountId32::from(signer.account_id().0);
+    chain.set_storage(
+        "System",
+        "Account",
+        vec![account_value(&source)],
+        Value::named_composite([(
+            "data",
+            Value::named_composite([("free", Value::u128(3_000_000_000))]),
+        )]),
+    );
+    chain.set_fee("Balances", "transfer_keep_alive", 250_000_000);
+
+    let subtensor = Subtensor::from_backend(chain);
+    assert_eq!(subtensor.get_current_block().await.unwrap(), 1_000);
+    assert_eq!(subtensor.tempo(NETUID).await.unwrap(), 360);
+    // 1000 + 3 + 1 = 1004 blocks at 361 per epoch
+    assert_eq!(subtensor.get_current_epoch(NETUID).await.unwrap(), 2);
+
+    let dest = [6; 32];
+    let preview = subtensor
+        .preview_transfer(&signer, &dest, TransferAmount::Max, true)
+        .await
+        .unwrap();
+    let (_, sent) = subtensor
+        .transfer_safe(
+            &signer,
+            &dest,
+            TransferAmount::Max,
+            true,
+            ExtrinsicWait::Included,
+        )
+        .await
+        .unwrap();
+    assert_eq!(sent, preview);
+    let submitted = subtensor.client().submitted();
+    assert_eq!(submitted.len(), 1);
+    assert_eq!(submitted[0].function, "transfer_keep_alive");
+    assert_eq!(submitted[0].args[1], Value::u128(preview.amount.as_u128()));
+}
+
+#[tokio::test]
+async fn test_stored_weights_verification() {
+    let chain = MockChain::new();
+    // UID 2's weight was dropped; the others were upscaled by the chain
+    chain.set_storage(
+        SUBTENSOR,
+        "Weights",
+        vec![Value::u128(NETUID as u128), Value::u128(4)],
+        Value::unnamed_composite(vec![
+            Value::unnamed_composite(vec![Value::u128(0), Value::u128(65_535)]),
+            Value::unnamed_composite(vec![Value::u128(1), Value::u128(32_767)]),
+        ]),
+    );
+
+    let stored = get_uid_weights(&chain, NETUID, 0, 4).await.unwrap();
+    assert_eq!(stored, [(0, 65_535), (1, 32_767)]);
+    assert!(get_uid_weights(&chain, NETUID, 0, 5)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let verification = WeightVerification::compare(&[0, 1], &[1_000, 500], &stored).unwrap();
+    assert!(verification.matches);
+
+    let verification =
+        WeightVerification::compare(&[0, 1, 2], &[1_000, 500, 250], &stored).unwrap();
+    assert!(!verification.matches);
+    assert_eq!(
+        verification.differences,
+        [WeightDiff {
+            uid: 2,
+            submitted: Some(16_384),
+            stored: None,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_recent_registrations() {
+    let chain = MockChain::new();
+    let hotkeys: Vec<AccountId32> = (1..=4).map(|i| AccountId32::new([i; 32])).collect();
+    seed_subnet(&chain, &hotkeys, &hotkeys);
+    // UID 1 was replaced in the same block UID 3 registered
+    chain.set_storage(
+        SUBTENSOR,
+        "BlockAtRegistration",
+        vec![Value::u128(NETUID as u128), Value::u128(1)],
+        Value::u128(103),
+    );
+
+    let recent = get_recent_registrations(&chain, NETUID, 3).await.unwrap();
+    assert_eq!(recent, [(3, 103), (1, 103), (2, 102)]);
+    assert_eq!(
+        get_recent_registrations(&chain, NETUID, 10)
+            .await
+            .unwrap()
+            .len(),
+        4
+    );
+    assert!(get_recent_registrations(&chain, NETUID + 1, 5)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_block_at_registration_and_immunity() {
+    let chain = MockChain::new();
+    let hotkeys: Vec<AccountId32> = (1..=2).map(|i| AccountId32::new([i; 32])).collect();
+    seed_subnet(&chain, &hotkeys, &hotkeys);
+
+    let registered = get_block_at_registration(&chain, NETUID, 1).await.unwrap();
+    assert_eq!(registered, 101);
+    assert_eq!(
+        get_block_at_registration(&chain, NETUID, 9).await.unwrap(),
+        0
+    );
+
+    assert_eq!(immunity_blocks_remaining(registered, 100, 150), Some(51));
+    assert_eq!(immunity_blocks_remaining(registered, 100, 200), Some(1));
+    assert_eq!(immunity_blocks_remaining(registered, 100, 201), None);
+    assert_eq!(immunity_blocks_remaining(registered, 0, 101), None);
+    // Recycled after the current block was read: a fresh registration
+    assert_eq!(immunity_blocks_remaining(registered, 100, 90), Some(100));
+}
+
+#[tokio::test]
+async fn test_resolve_hotkey_weights_skips_unregistered() {
+    let chain = MockChain::new();
+    let registered = [
+        (AccountId32::new([1u8; 32]), 2u16),
+        (AccountId32::new([2u8; 32]), 0),
+    ];
+    for (hotkey, uid) in &registered {
+        chain.set_storage(
+            SUBTENSOR,
+            "Uids",
+            vec![
+                Value::u128(NETUID as u128),
+                Value::from_bytes(hotkey.encode()),
+            ],
+            Value::u128(*uid as u128),
+        );
+    }
+    let ss58 = |seed: u8| AccountId32::new([seed; 32]).to_ss58check();
+    let weights = HashMap::from([(ss58(1), 0.5), (ss58(2), 1.0), (ss58(3), 0.8)]);
+
+    let resolved = resolve_hotkey_weights(&chain, NETUID, &weights)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        resolved.resolved,
+        [
+            ResolvedWeight {
+                hotkey: ss58(2),
+                uid: 0,
+                weight: 65_535,
+            },
+            ResolvedWeight {
+                hotkey: ss58(1),
+                uid: 2,
+                weight: 32_768,
+            },
+        ]
+    );
+    assert_eq!(resolved.skipped, [ss58(3)]);
+    assert_eq!(
+        resolved.uids_and_weights(),
+        (vec![0, 2], vec![65_535, 32_768])
+    );
+
+    let invalid = HashMap::from([("not an address".to_string(), 1.0)]);
+    assert!(resolve_hotkey_weights(&chain, NETUID, &invalid)
+        .await
+        .is_err());
+}
+
+/// UIDs and hotkeys named by the per-neuron storage reads so far
+fn per_neuron_reads(chain: &MockChain) -> (Vec<u128>, Vec<Value>) {
+    let mut uids = Vec::new();
+    let mut hotkeys = Vec::new();
+    for read in chain.storage_reads() {
+        match read.entry.as_str() {
+            "Keys" => uids.extend(read.keys[1].as_u128()),
+            "Owner" | "TotalHotkeyAlpha" => hotkeys.push(read.keys[0].clone()),
+            "Axons" => hotkeys.push(read.keys[1].clone()),
+            _ => {}
+        }
+    }
+    uids.sort_unstable();
+    (uids, hotkeys)
+}
+
+#[tokio::test]
+async fn test_neurons_filtered_reads_only_selected_uids() {
+    let chain = MockChain::new();
+    let hotkeys: Vec<AccountId32> = (1u8..=6).map(|b| AccountId32::new([b; 32])).collect();
+    let coldkeys: Vec<AccountId32> = (11u8..=16).map(|b| AccountId32::new([b; 32])).collect();
+    seed_subnet(&chain, &hotkeys, &coldkeys);
+    // UIDs 0, 2 and 4 are validators; UID 4 is inactive
+    let flags = |set: &[bool]| {
+        Value::unnamed_composite(set.iter().map(|&b| Value::bool(b)).collect::<Vec<_>>())
+    };
+    chain.set_storage(
+        SUBTENSOR,
+        "ValidatorPermit",
+        netuid_key(),
+        flags(&[true, false, true, false, true, false]),
+    );
+    chain.set_storage(
+        SUBTENSOR,
+        "Active",
+        netuid_key(),
+        flags(&[true, true, true, true, false, true]),
+    );
+
+    let all = neurons(&chain, NETUID, None).await.unwrap();
+    let uids: Vec<u64> = all.iter().map(|n| n.uid).collect();
+    assert_eq!(uids, [0, 1, 2, 3, 4, 5]);
+
+    // Active validators only
+    chain.clear_storage_reads();
+    let filter = NeuronFilter::new()
+        .with_only_validators(true)
+        .with_active_only(true);
+    let selected = neurons_filtered(&chain, NETUID, filter).await.unwrap();
+    let uids: Vec<u64> = selected.iter().map(|n| n.uid).collect();
+    assert_eq!(uids, [0, 2]);
+    assert_eq!(selected[1].coldkey, coldkeys[2]);
+    assert_eq!(u128::from(selected[1].total_stake), 3_000_000_000);
+    let (read_uids, read_hotkeys) = per_neuron_reads(&chain);
+    assert_eq!(read_uids, [0, 2]);
+    assert_eq!(read_hotkeys.len(), 6);
+    for (uid, hotkey) in hotkeys.iter().enumerate() {
+        let reads = read_hotkeys
+            .iter()
+            .filter(|key| **key == Value::from_bytes(hotkey.encode()))
+            .count();
+        assert_eq!(
+            reads,
+            if uid == 0 || uid == 2 { 3 } else { 0 },
+            "UID {}",
+            uid
+        );
+    }
+
+    // Explicit UIDs are deduplicated, bounded by the subnet size and paged
+    chain.clear_storage_reads();
+    let filter = NeuronFilter::new()
+        .with_uids([5, 1, 9, 5])
+        .with_offset(1)
+        .with_limit(1)
+        .with_axons(true);
+    let selected = neurons_filtered(&chain, NETUID, filter).await.unwrap();
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].hotkey, hotkeys[5]);
+    let (read_uids, read_hotkeys) = per_neuron_reads(&chain);
+    assert_eq!(read_uids, [5]);
+    assert!(read_hotkeys
+        .iter()
+        .all(|key| *key == Value::from_bytes(hotkeys[5].encode())));
+    assert_eq!(
+        chain
+            .storage_reads()
+            .iter()
+            .filter(|read| read.entry == "Axons")
+            .count(),
+        1
+    );
+
+    // Without the stake runtime API, stake filters use storage stakes
+    let filter = NeuronFilter::new()
+        .with_min_stake(Rao::from(4_000_000_000u128))
+        .with_order_by_stake(true)
+        .with_limit(2);
+    let selected = neurons_filtered(&chain, NETUID, filter).await.unwrap();
+    let uids: Vec<u64> = selected.iter().map(|n| n.uid).collect();
+    assert_eq!(uids, [5, 4]);
+}
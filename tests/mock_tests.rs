@@ -49,6 +49,7 @@ fn test_subnet_hyperparameters() {
         difficulty: 0,
         activity_cutoff: 0,
         registration_allowed: false,
+        pow_registration_allowed: false,
         max_validators: 0,
         max_allowed_uids: 4096,
         serving_rate_limit: 0,
@@ -97,6 +97,46 @@ async fn test_read_only_crv4_tempo_and_reveal_period() {
     assert!(version >= DEFAULT_COMMIT_REVEAL_VERSION);
 }
 
+#[tokio::test]
+async fn test_read_only_storage_pinned_at_height() {
+    use bittensor_rs::utils::decoders::decode_u64;
+
+    let Some(client) = connect_default_or_skip().await else {
+        return;
+    };
+    let head = client.block_number().await.expect("block number");
+
+    // Recent enough that non-archive nodes still hold the state
+    let older = client.at_height(head - 10).await.expect("older block");
+    let newer = client.at_height(head - 1).await.expect("newer block");
+    assert_eq!(older.number(), head - 10);
+    assert_ne!(older.hash(), newer.hash());
+
+    let mut timestamps = Vec::new();
+    for block in [&older, &newer] {
+        let value = block
+            .storage("Timestamp", "Now", vec![])
+            .await
+            .expect("Timestamp.Now query")
+            .expect("Timestamp.Now value");
+        timestamps.push(decode_u64(&value).expect("decode timestamp"));
+    }
+    let (older_ts, newer_ts) = (timestamps[0], timestamps[1]);
+    assert!(
+        newer_ts > older_ts,
+        "Timestamp.Now should advance between heights"
+    );
+
+    let err = client
+        .at_height(head + 1_000_000)
+        .await
+        .expect_err("future block should not resolve");
+    assert!(matches!(
+        err,
+        bittensor_rs::chain::Error::BlockNotFound(ref e) if e.block_number == Some(head + 1_000_000)
+    ));
+}
+
 async fn env_lock() -> tokio::sync::MutexGuard<'static, ()> {
     static ENV_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
     ENV_LOCK.get_or_init(|| Mutex::new(())).lock().await
@@ -733,5 +773,7 @@ fn test_get_mechanism_count_export() {
     // Verify the function is exported from queries module
     use bittensor_rs::queries::get_mechanism_count;
     // The function exists and is accessible (actual network call tested in integration tests)
-    let _ = get_mechanism_count;
+    async fn _mechanism_count(client: &BittensorClient) {
+        let _ = get_mechanism_count(client, 1).await;
+    }
 }
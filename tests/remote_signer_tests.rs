@@ -0,0 +1,171 @@
+//! Signing through a remote signer service
+//!
+//! Starts a local HTTP signer holding `//Alice` and checks `RemoteSigner`
+//! against it. The transfer test also needs a local dev chain at
+//! `BITTENSOR_LOCAL_ENDPOINT` (default `ws://127.0.0.1:9944`) and is skipped
+//! when none is running.
+
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::{Json, Router};
+use bittensor_rs::chain::{BittensorSigner, ExtrinsicWait, RemoteSigner, TransactionSigner};
+use bittensor_rs::core::constants::LOCAL_ENDPOINT;
+use bittensor_rs::queries::balances::get_balance;
+use bittensor_rs::utils::balance_newtypes::Rao;
+use bittensor_rs::validator::transfer::transfer;
+use bittensor_rs::BittensorClient;
+use serde_json::{json, Value};
+use sp_core::{sr25519, Pair};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use subxt::config::substrate::{AccountId32, MultiSignature};
+
+const TOKEN: &str = "test-token";
+
+struct SignerService {
+    pair: sr25519::Pair,
+    delay: Duration,
+    requests: AtomicUsize,
+}
+
+async fn sign(
+    State(service): State<Arc<SignerService>>,
+    headers: HeaderMap,
+    Json(request): Json<Value>,
+) -> Result<Json<Value>, StatusCode> {
+    service.requests.fetch_add(1, Ordering::SeqCst);
+    let expected = format!("Bearer {}", TOKEN);
+    if headers.get("authorization").and_then(|v| v.to_str().ok()) != Some(expected.as_str()) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    tokio::time::sleep(service.delay).await;
+
+    let payload = request["payload"]
+        .as_str()
+        .and_then(|p| hex::decode(p.trim_start_matches("0x")).ok())
+        .ok_or(StatusCode::BAD_REQUEST)?;
+    let signature = service.pair.sign(&payload);
+    Ok(Json(json!({
+        "signature": format!("0x{}", hex::encode(signature.0)),
+        "scheme": "sr25519",
+    })))
+}
+
+/// Start a signer service for `//Alice`, returning its URL
+async fn start_signer(delay: Duration) -> (String, Arc<SignerService>) {
+    let service = Arc::new(SignerService {
+        pair: sr25519::Pair::from_string("//Alice", None).unwrap(),
+        delay,
+        requests: AtomicUsize::new(0),
+    });
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind test listener");
+    let addr: SocketAddr = listener.local_addr().expect("Failed to get local address");
+    let app = Router::new()
+        .route("/sign", post(sign))
+        .with_state(service.clone());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    (format!("http://{}/sign", addr), service)
+}
+
+fn alice() -> sr25519::Pair {
+    sr25519::Pair::from_string("//Alice", None).unwrap()
+}
+
+fn remote_signer(url: &str, pair: &sr25519::Pair) -> RemoteSigner {
+    RemoteSigner::new(url, AccountId32(pair.public().0))
+        .unwrap()
+        .with_bearer_token(TOKEN)
+}
+
+#[tokio::test]
+async fn test_remote_signer_signs_payload() {
+    let (url, service) = start_signer(Duration::ZERO).await;
+    let pair = alice();
+    let signer = remote_signer(&url, &pair);
+
+    let payload = b"signer payload";
+    let MultiSignature::Sr25519(signature) = signer.sign(payload).await.unwrap() else {
+        panic!("Expected an sr25519 signature");
+    };
+    assert!(sr25519::Pair::verify(
+        &sr25519::Signature::from_raw(signature),
+        payload,
+        &pair.public()
+    ));
+
+    // Usable wherever a BittensorSigner is expected
+    let signer = BittensorSigner::from(signer);
+    assert_eq!(signer.account_id().0, pair.public().0);
+    assert!(TransactionSigner::sign(&signer, payload).await.is_ok());
+    assert_eq!(service.requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_remote_signer_errors() {
+    let (url, _service) = start_signer(Duration::ZERO).await;
+    let pair = alice();
+
+    let unauthorized = RemoteSigner::new(&url, AccountId32(pair.public().0)).unwrap();
+    let err = unauthorized.sign(b"payload").await.unwrap_err();
+    assert!(err.message.contains("401"), "{}", err);
+
+    // The service signs with Alice's key, not Bob's
+    let bob = sr25519::Pair::from_string("//Bob", None).unwrap();
+    let err = remote_signer(&url, &bob)
+        .sign(b"payload")
+        .await
+        .unwrap_err();
+    assert!(err.message.contains("not valid for"), "{}", err);
+
+    let (slow_url, _service) = start_signer(Duration::from_secs(5)).await;
+    let err = remote_signer(&slow_url, &pair)
+        .with_timeout(Duration::from_millis(100))
+        .sign(b"payload")
+        .await
+        .unwrap_err();
+    assert!(err.message.contains("timed out"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_remote_signer_transfer_on_local_chain() {
+    let endpoint =
+        std::env::var("BITTENSOR_LOCAL_ENDPOINT").unwrap_or_else(|_| LOCAL_ENDPOINT.to_string());
+    let client = match BittensorClient::new(endpoint.as_str()).await {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("Skipping test: unable to connect to {} ({err})", endpoint);
+            return;
+        }
+    };
+
+    let (url, service) = start_signer(Duration::ZERO).await;
+    let signer = BittensorSigner::from(remote_signer(&url, &alice()));
+    let bob = sp_core::crypto::AccountId32::from(
+        sr25519::Pair::from_string("//Bob", None)
+            .unwrap()
+            .public()
+            .0,
+    );
+
+    let before = get_balance(&client, &bob).await.unwrap();
+    let amount = Rao::from(1_000_000_000u64);
+    transfer(
+        &client,
+        &signer,
+        &bob,
+        amount,
+        true,
+        ExtrinsicWait::Included,
+    )
+    .await
+    .unwrap();
+
+    let after = get_balance(&client, &bob).await.unwrap();
+    assert_eq!(after.as_u128(), before.as_u128() + amount.as_u128());
+    assert!(service.requests.load(Ordering::SeqCst) >= 1);
+}
@@ -0,0 +1,102 @@
+//! Static and dynamic call encoding against the vendored Finney metadata
+//!
+//! Requires the `static-metadata` feature; no chain connection is required.
+
+#![cfg(feature = "static-metadata")]
+
+use bittensor_rs::chain::static_metadata::is_compatible;
+use bittensor_rs::chain::{CallPayload, StaticItem};
+use parity_scale_codec::{Decode, Encode};
+use sp_core::crypto::AccountId32;
+use std::path::Path;
+use subxt::dynamic::Value;
+use subxt::tx::Payload;
+
+fn finney_metadata() -> subxt::Metadata {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("metadata/finney.scale");
+    let bytes = std::fs::read(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    subxt::Metadata::decode(&mut &bytes[..]).expect("Failed to decode metadata")
+}
+
+/// Call data of `SubtensorModule::function(args)` built statically and dynamically
+fn encode_both(function: &str, args: Vec<Value>) -> (Vec<u8>, Vec<u8>) {
+    let metadata = finney_metadata();
+    let typed = CallPayload::new(true, "SubtensorModule", function, args.clone());
+    assert!(typed.is_static(), "{} has no static path", function);
+    let dynamic = CallPayload::new(false, "SubtensorModule", function, args);
+    assert!(!dynamic.is_static());
+    (
+        typed.encode_call_data(&metadata).unwrap(),
+        dynamic.encode_call_data(&metadata).unwrap(),
+    )
+}
+
+#[test]
+fn test_generated_code_matches_vendored_metadata() {
+    let metadata = finney_metadata();
+    for item in StaticItem::ALL {
+        assert!(is_compatible(&metadata, item), "{:?}", item);
+    }
+}
+
+#[test]
+fn test_static_items_for_calls() {
+    assert_eq!(
+        StaticItem::call("SubtensorModule", "set_weights"),
+        Some(StaticItem::SetWeights)
+    );
+    assert_eq!(
+        StaticItem::call("SubtensorModule", "add_stake"),
+        Some(StaticItem::AddStake)
+    );
+    assert_eq!(StaticItem::call("SubtensorModule", "commit_weights"), None);
+    assert_eq!(StaticItem::call("System", "set_weights"), None);
+}
+
+#[test]
+fn test_set_weights_call_data_identical() {
+    // Same argument shapes as `validator::weights::set_weights`
+    let uids: [u16; 3] = [0, 7, 1023];
+    let weights: [u16; 3] = [65535, 1, 0];
+    let args = vec![
+        Value::from(3u16),
+        Value::unnamed_composite(uids.iter().map(|uid| Value::from(*uid))),
+        Value::unnamed_composite(weights.iter().map(|weight| Value::from(*weight))),
+        Value::from(1_002_003u64),
+    ];
+
+    let (typed, dynamic) = encode_both("set_weights", args);
+    assert_eq!(typed, dynamic);
+
+    let empty = vec![
+        Value::from(3u16),
+        Value::unnamed_composite(Vec::<Value>::new()),
+        Value::unnamed_composite(Vec::<Value>::new()),
+        Value::from(0u64),
+    ];
+    let (typed, dynamic) = encode_both("set_weights", empty);
+    assert_eq!(typed, dynamic);
+}
+
+#[test]
+fn test_add_stake_call_data_identical() {
+    let hotkey = AccountId32::new([7; 32]);
+    let args = vec![
+        Value::from_bytes(hotkey.encode()),
+        Value::from(1u16),
+        Value::u128(1_000_000_000),
+    ];
+
+    let (typed, dynamic) = encode_both("add_stake", args);
+    assert_eq!(typed, dynamic);
+}
+
+#[test]
+fn test_unsupported_calls_stay_dynamic() {
+    let args = vec![Value::from(3u16), Value::from_bytes([0u8; 32])];
+    assert!(!CallPayload::new(true, "SubtensorModule", "commit_weights", args).is_static());
+
+    // Arguments that do not fit the generated types
+    let args = vec![Value::from(3u16)];
+    assert!(!CallPayload::new(true, "SubtensorModule", "set_weights", args).is_static());
+}
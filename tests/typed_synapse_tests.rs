@@ -0,0 +1,102 @@
+//! Interoperability tests for typed synapses
+//!
+//! These start a local Axon and check that typed and dynamic synapses can be
+//! mixed freely between the Dendrite and the Axon. No chain connection is
+//! required.
+
+use bittensor_rs::axon::{Axon, AxonConfig};
+use bittensor_rs::dendrite::Dendrite;
+use bittensor_rs::types::{AxonInfo, Synapse, SynapseType};
+use bittensor_rs::wallet::Keypair;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Doubler {
+    #[serde(flatten)]
+    base: Synapse,
+    input: u64,
+    output: Option<u64>,
+}
+
+impl Doubler {
+    fn new(input: u64) -> Self {
+        Self {
+            base: Synapse::new(),
+            input,
+            output: None,
+        }
+    }
+}
+
+impl SynapseType for Doubler {
+    fn name() -> &'static str {
+        "Doubler"
+    }
+
+    fn required_hash_fields() -> Vec<&'static str> {
+        vec!["input"]
+    }
+}
+
+/// Start an axon with handlers attached by `setup`
+async fn start_axon(setup: impl FnOnce(&mut Axon)) -> AxonInfo {
+    let keypair = Keypair::from_uri("//Alice").expect("Failed to create test keypair");
+    let config = AxonConfig::new()
+        .with_ip("127.0.0.1")
+        .with_port(0)
+        .with_signature_verification(false);
+    let mut axon = Axon::new(keypair, config);
+    setup(&mut axon);
+
+    // Listening once `serve` returns; the server outlives the dropped handle
+    let handle = axon.serve().await.expect("Failed to start axon");
+    handle.info(0).expect("Failed to build axon info")
+}
+
+#[tokio::test]
+async fn test_typed_dendrite_with_dynamic_handler() {
+    let info = start_axon(|axon| {
+        axon.attach("Doubler", |mut synapse| async move {
+            let input = synapse
+                .get_field("input")
+                .and_then(|v| v.as_u64())
+                .unwrap_or_default();
+            synapse.set_field("output", serde_json::json!(input * 2));
+            synapse
+        });
+    })
+    .await;
+
+    let response = Dendrite::new(None)
+        .call_typed(&info, Doubler::new(21))
+        .await
+        .expect("Typed call failed");
+
+    assert_eq!(response.input, 21);
+    assert_eq!(response.output, Some(42));
+}
+
+#[tokio::test]
+async fn test_dynamic_dendrite_with_typed_handler() {
+    let info = start_axon(|axon| {
+        axon.attach_typed(|mut doubler: Doubler| async move {
+            doubler.output = Some(doubler.input * 2);
+            doubler
+        });
+    })
+    .await;
+    let dendrite = Dendrite::new(None);
+
+    let mut synapse = Synapse::new().with_name("Doubler");
+    synapse.set_field("input", serde_json::json!(5));
+    let response = dendrite.call(&info, synapse).await.expect("Call failed");
+    assert!(response.is_success());
+    assert_eq!(response.get_field("output"), Some(&serde_json::json!(10)));
+
+    // Typed clients get the same result
+    let response = dendrite
+        .call_typed(&info, Doubler::new(7))
+        .await
+        .expect("Typed call failed");
+    assert_eq!(response.output, Some(14));
+}
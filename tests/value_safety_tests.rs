@@ -13,14 +13,17 @@
 //! # Property-Based Testing
 //! Uses proptest to verify properties hold across a wide range of inputs.
 
+use bittensor_rs::chain::{BittensorSigner, ExtrinsicWait};
 use bittensor_rs::core::constants::{EXISTENTIAL_DEPOSIT_RAO, RAOPERTAO};
 use bittensor_rs::utils::balance_newtypes::{
     format_rao_as_tao, is_lossless_conversion, is_valid_tao_amount, parse_tao_string, rao_to_tao,
     tao_to_rao, Balance, Rao, Tao,
 };
+use bittensor_rs::utils::weights::WeightValidation;
 use bittensor_rs::validator::{staking, transfer, weights as validator_weights};
 use bittensor_rs::{queries, utils::balance_newtypes::get_unit_symbol, BittensorClient};
 use proptest::prelude::*;
+use sp_core::crypto::AccountId32;
 use std::any::type_name;
 use std::str::FromStr;
 
@@ -51,10 +54,36 @@ fn test_transfer_and_stake_requires_rao_inputs() {
     assert_u128_input::<u128>(Rao::ZERO.as_u128());
     assert_u128_input::<u128>(RAOPERTAO);
 
-    let _transfer_fn = transfer::transfer;
-    let _transfer_stake_fn = transfer::transfer_stake;
-    let _add_stake_fn = staking::add_stake;
-    let _unstake_fn = staking::unstake;
+    // Generic over the chain backend, so checked with a concrete client
+    async fn _transfer(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        dest: &AccountId32,
+        amount: Rao,
+    ) {
+        let _ =
+            transfer::transfer(client, signer, dest, amount, true, ExtrinsicWait::Finalized).await;
+    }
+    async fn _transfer_stake(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        dest: &AccountId32,
+        hotkey: &AccountId32,
+        amount: Rao,
+    ) {
+        let wait = ExtrinsicWait::Finalized;
+        let _ = transfer::transfer_stake(client, signer, dest, hotkey, 1, 2, amount, wait).await;
+    }
+    async fn _stake(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        amount: Rao,
+    ) {
+        let wait = ExtrinsicWait::Finalized;
+        let _ = staking::add_stake(client, signer, hotkey, 1, amount, wait).await;
+        let _ = staking::unstake(client, signer, hotkey, 1, amount, wait).await;
+    }
 }
 
 #[test]
@@ -80,9 +109,31 @@ fn test_weight_inputs_require_u16_not_tao() {
     let uids: Vec<u16> = vec![0, 1, 2];
     let weights: Vec<u16> = vec![10_000, 20_000, 30_000];
 
-    let _set_weights_fn = validator_weights::set_weights;
-    let _commit_weights_fn = validator_weights::commit_weights;
-    let _reveal_weights_fn = validator_weights::reveal_weights;
+    async fn _set_weights(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        uids: &[u16],
+        weights: &[u16],
+    ) {
+        let (check, wait) = (WeightValidation::Strict, ExtrinsicWait::Finalized);
+        let _ =
+            validator_weights::set_weights(client, signer, 1, uids, weights, 0, check, wait).await;
+    }
+    async fn _commit_weights(client: &BittensorClient, signer: &BittensorSigner, hash: &str) {
+        let wait = ExtrinsicWait::Finalized;
+        let _ = validator_weights::commit_weights(client, signer, 1, hash, wait).await;
+    }
+    async fn _reveal_weights(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        uids: &[u16],
+        weights: &[u16],
+        salt: &[u16],
+    ) {
+        let wait = ExtrinsicWait::Finalized;
+        let _ = validator_weights::reveal_weights(client, signer, 1, uids, weights, salt, 0, wait)
+            .await;
+    }
 
     assert_eq!(uids.len(), weights.len());
 }
@@ -624,9 +675,16 @@ fn test_tao_is_display_only_never_extrinsic() {
     let rao_val = tao_val.as_rao();
     assert_eq!(rao_val.as_u128(), 1_500_000_000);
 
-    let _transfer_fn = transfer::transfer;
-    let _add_stake_fn = staking::add_stake;
-    let _unstake_fn = staking::unstake;
+    async fn _stake(
+        client: &BittensorClient,
+        signer: &BittensorSigner,
+        hotkey: &AccountId32,
+        amount: Rao,
+    ) {
+        let wait = ExtrinsicWait::Finalized;
+        let _ = staking::add_stake(client, signer, hotkey, 1, amount, wait).await;
+        let _ = staking::unstake(client, signer, hotkey, 1, amount, wait).await;
+    }
 }
 
 #[test]
@@ -213,8 +213,8 @@ fn test_mnemonic_validate_invalid_phrase() {
 #[test]
 fn test_mnemonic_generate_is_valid() {
     let m = Mnemonic::generate();
-    assert!(Mnemonic::validate(m.phrase()));
-    let words: Vec<&str> = m.phrase().split_whitespace().collect();
+    assert!(Mnemonic::validate(m.phrase().expose_secret()));
+    let words: Vec<&str> = m.phrase().expose_secret().split_whitespace().collect();
     assert_eq!(words.len(), 12);
 }
 